@@ -0,0 +1,86 @@
+//! Scaling benchmark for whole-graph analytics.
+//!
+//! Times PageRank, label-propagation communities, and co-occurrence
+//! detection on synthetic graphs of increasing size, at 1, 2, 4, ... threads
+//! up to the machine's parallelism:
+//!
+//!     cargo bench --features parallel --bench analytics
+//!
+//! Without `--features parallel` everything runs single-threaded. Set
+//! `PLEXUS_BENCH_EDGES` (comma-separated) to change the sizes; the default
+//! tops out at one million edges.
+
+use plexus::adapter::{CoOccurrenceEnrichment, Enrichment, GraphEvent};
+use plexus::query::{communities, pagerank, PageRankConfig, DEFAULT_COMMUNITY_ITERATIONS};
+use plexus::synthetic;
+use std::time::{Duration, Instant};
+
+/// Tags per fragment in the co-occurrence graph.
+const TAGS_PER_FRAGMENT: usize = 4;
+
+fn time<T>(f: impl FnOnce() -> T) -> Duration {
+    let start = Instant::now();
+    std::hint::black_box(f());
+    start.elapsed()
+}
+
+fn thread_counts() -> Vec<usize> {
+    if cfg!(not(feature = "parallel")) {
+        return vec![1];
+    }
+    let available = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+    let mut counts: Vec<usize> = std::iter::successors(Some(1), |t| Some(t * 2)).take_while(|t| *t < available).collect();
+    counts.push(available);
+    counts
+}
+
+#[cfg(feature = "parallel")]
+fn with_threads<T: Send>(threads: usize, f: impl FnOnce() -> T + Send) -> T {
+    rayon::ThreadPoolBuilder::new().num_threads(threads).build().expect("thread pool").install(f)
+}
+
+#[cfg(not(feature = "parallel"))]
+fn with_threads<T: Send>(_threads: usize, f: impl FnOnce() -> T + Send) -> T {
+    f()
+}
+
+fn main() {
+    let sizes: Vec<usize> = std::env::var("PLEXUS_BENCH_EDGES")
+        .unwrap_or_else(|_| "10000,100000,1000000".to_string())
+        .split(',')
+        .filter_map(|s| s.trim().parse().ok())
+        .collect();
+    let config = PageRankConfig { max_iterations: 20, tolerance: 0.0, ..PageRankConfig::default() };
+
+    println!("{:>9} {:>7} {:>12} {:>15} {:>16}", "edges", "threads", "pagerank_ms", "communities_ms", "cooccurrence_ms");
+    for edges in sizes {
+        // Average degree 16; fragments sharing concepts from a pool of edges / 40
+        let graph = synthetic::uniform((edges / 8).max(2), edges, 1);
+        let concepts = (edges / 40).max(TAGS_PER_FRAGMENT);
+        let tagged = synthetic::tagged_fragments(edges / TAGS_PER_FRAGMENT, concepts, TAGS_PER_FRAGMENT, 2);
+        let trigger = [GraphEvent::NodesAdded {
+            node_ids: Vec::new(),
+            adapter_id: "bench".to_string(),
+            context_id: "bench".to_string(),
+        }];
+        let enrichment = CoOccurrenceEnrichment::new();
+
+        for threads in thread_counts() {
+            let (pr, cd, co) = with_threads(threads, || {
+                (
+                    time(|| pagerank(&graph, &config)),
+                    time(|| communities(&graph, DEFAULT_COMMUNITY_ITERATIONS)),
+                    time(|| enrichment.enrich(&trigger, &tagged)),
+                )
+            });
+            println!(
+                "{:>9} {:>7} {:>12} {:>15} {:>16}",
+                edges,
+                threads,
+                pr.as_millis(),
+                cd.as_millis(),
+                co.as_millis()
+            );
+        }
+    }
+}
@@ -0,0 +1,131 @@
+//! Criterion suite for the engine's hot paths.
+//!
+//! Baselines for performance work (adjacency indexes, partial hydration):
+//!
+//! - `ingest`: fragments/sec through the default pipeline, enrichments on
+//! - `emit`: one emission into a context already holding N edges
+//!   (the sink's `emit_inner` path)
+//! - `traverse`: depth-3 traversal of a scale-free graph, from a hub and
+//!   from a leaf
+//! - `sqlite_load`: `SqliteStore::load_context` and `PlexusEngine::load_all`
+//!
+//! Run with `cargo bench --bench engine`; compare against a saved baseline
+//! with `-- --save-baseline main` then `-- --baseline main`.
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use plexus::adapter::{
+    AdapterSink, AnnotatedEdge, Emission, EngineSink, FragmentInput, FrameworkContext, PipelineBuilder,
+};
+use plexus::query::TraverseQuery;
+use plexus::storage::{GraphStore, OpenStore, SqliteStore};
+use plexus::{synthetic, Context, Edge, NodeId, PlexusEngine};
+use std::sync::{Arc, Mutex};
+
+/// Fragments per `ingest` iteration.
+const INGEST_BATCH: usize = 50;
+/// Tag vocabulary for ingested fragments.
+const TAGS: [&str; 12] = [
+    "rust", "graphs", "storage", "sqlite", "indexes", "queries", "traversal", "ingest", "enrichment",
+    "provenance", "lenses", "analytics",
+];
+
+fn runtime() -> tokio::runtime::Runtime {
+    tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap()
+}
+
+fn ingest(c: &mut Criterion) {
+    let rt = runtime();
+    let mut group = c.benchmark_group("ingest");
+    group.throughput(Throughput::Elements(INGEST_BATCH as u64));
+    group.sample_size(20);
+    group.bench_function("fragments", |b| {
+        b.iter_batched(
+            || {
+                let engine = Arc::new(PlexusEngine::new());
+                let ctx_id = engine.upsert_context(Context::new("bench")).unwrap();
+                let pipeline =
+                    PipelineBuilder::new(engine.clone()).with_default_adapters().with_default_enrichments().build();
+                (pipeline, ctx_id)
+            },
+            |(pipeline, ctx_id)| {
+                rt.block_on(async {
+                    for i in 0..INGEST_BATCH {
+                        let tags = (0..3).map(|k| TAGS[(i * 5 + k * 7) % TAGS.len()].to_string()).collect();
+                        let input = FragmentInput::new(format!("fragment {i} about several topics"), tags);
+                        pipeline.ingest(ctx_id.as_str(), "content", Box::new(input)).await.unwrap();
+                    }
+                })
+            },
+            BatchSize::PerIteration,
+        )
+    });
+    group.finish();
+}
+
+fn emit(c: &mut Criterion) {
+    let rt = runtime();
+    let mut group = c.benchmark_group("emit");
+    for edges in [1_000, 10_000, 100_000] {
+        // Three edges per node, so `edges / 3` nodes
+        let base = synthetic::scale_free(edges / 3 + 3, 3, 11);
+        let ids: Vec<NodeId> = (0..10).map(|i| NodeId::from_string(format!("concept:{}", i * 7 + 1))).collect();
+        group.bench_with_input(BenchmarkId::from_parameter(edges), &base, |b, base| {
+            b.iter_batched(
+                || {
+                    let framework = FrameworkContext {
+                        adapter_id: "bench".to_string(),
+                        context_id: "bench".to_string(),
+                        input_summary: None,
+                    };
+                    let sink = EngineSink::new(Arc::new(Mutex::new(base.clone()))).with_framework_context(framework);
+                    let emission = ids.windows(2).fold(Emission::new(), |emission, pair| {
+                        let edge = Edge::new(pair[0].clone(), pair[1].clone(), "related_to");
+                        emission.with_edge(AnnotatedEdge::new(edge))
+                    });
+                    (sink, emission)
+                },
+                |(sink, emission)| rt.block_on(sink.emit(emission)).unwrap(),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn traverse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("traverse");
+    let graph = synthetic::scale_free(20_000, 3, 5);
+    // Preferential attachment makes the earliest nodes hubs, the latest leaves
+    for (label, origin) in [("hub", "concept:0"), ("leaf", "concept:19999")] {
+        let query = TraverseQuery::from(NodeId::from_string(origin)).depth(3);
+        group.bench_function(BenchmarkId::new("depth_3", label), |b| b.iter(|| query.execute(&graph)));
+    }
+    group.finish();
+}
+
+fn sqlite_load(c: &mut Criterion) {
+    let dir = tempfile::tempdir().unwrap();
+    let mut group = c.benchmark_group("sqlite_load");
+    group.sample_size(10);
+    for edges in [10_000, 100_000] {
+        let path = dir.path().join(format!("load-{edges}.db"));
+        let store = SqliteStore::open(&path).unwrap();
+        let ctx = synthetic::scale_free(edges / 3 + 3, 3, 13);
+        store.save_context(&ctx).unwrap();
+
+        group.throughput(Throughput::Elements(ctx.edge_count() as u64));
+        group.bench_function(BenchmarkId::new("load_context", edges), |b| {
+            b.iter(|| store.load_context(&ctx.id).unwrap().unwrap())
+        });
+        group.bench_function(BenchmarkId::new("load_all", edges), |b| {
+            b.iter(|| {
+                let engine = PlexusEngine::with_store(Arc::new(SqliteStore::open(&path).unwrap()));
+                engine.load_all().unwrap()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, ingest, emit, traverse, sqlite_load);
+criterion_main!(benches);
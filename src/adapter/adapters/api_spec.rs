@@ -0,0 +1,389 @@
+//! API specs — OpenAPI, Swagger, and AsyncAPI documents
+//!
+//! `ApiSpecModule` is a structural module for YAML and JSON files that
+//! turn out to be API descriptions (a top-level `openapi`, `swagger`, or
+//! `asyncapi` key); other YAML and JSON yields nothing. It maps a spec to:
+//! - An `api` node (title, version, spec) `contains`ed by the file node
+//! - An `endpoint` node per path (OpenAPI) or channel (AsyncAPI), which
+//!   the api `contains`
+//! - An `operation` node per method on a path, or per publish/subscribe
+//!   (AsyncAPI 2) or send/receive (AsyncAPI 3) on a channel, which its
+//!   endpoint `contains`
+//! - A `schema` node per named schema (`components/schemas`,
+//!   `definitions`) or message (`components/messages`)
+//!
+//! `$ref`s become edges: an operation `returns` the schemas its responses
+//! (or the messages it sends) refer to, and `uses_schema` those its
+//! parameters and request body (or received messages) refer to; a schema
+//! `uses_schema` the schemas it is built from. References to other
+//! components (`#/components/responses/...`) are followed through to the
+//! schemas they name. All nodes live in the structure dimension.
+
+use crate::adapter::adapters::structural::{ModuleEmission, StructuralModule, StructuralOutput};
+use crate::adapter::types::{AnnotatedEdge, AnnotatedNode};
+use crate::graph::{dimension, ContentType, Edge, Node, NodeId, PropertyValue};
+use async_trait::async_trait;
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// Relationship from an operation or schema to a schema it consumes.
+pub const USES_SCHEMA: &str = "uses_schema";
+/// Relationship from an operation to a schema it responds with or sends.
+pub const RETURNS: &str = "returns";
+
+const HTTP_METHODS: &[&str] = &["get", "put", "post", "delete", "options", "head", "patch", "trace"];
+
+/// Structural module for OpenAPI/AsyncAPI documents.
+///
+/// One instance per MIME type: `ApiSpecModule::yaml()` for `text/yaml`,
+/// `ApiSpecModule::json()` for `application/json`.
+#[derive(Debug)]
+pub struct ApiSpecModule {
+    mime: &'static str,
+}
+
+impl ApiSpecModule {
+    pub fn yaml() -> Self {
+        Self { mime: "text/yaml" }
+    }
+
+    pub fn json() -> Self {
+        Self { mime: "application/json" }
+    }
+}
+
+/// The named schema a `$ref` points at, for `#/components/schemas/X`,
+/// `#/components/messages/X`, and `#/definitions/X`.
+fn schema_name(reference: &str) -> Option<&str> {
+    ["#/components/schemas/", "#/components/messages/", "#/definitions/"]
+        .iter()
+        .find_map(|prefix| reference.strip_prefix(prefix))
+        .filter(|name| !name.contains('/'))
+}
+
+/// Schemas a spec fragment refers to, following `$ref`s to other local
+/// components (responses, request bodies, parameters, ...) through to the
+/// schemas they name.
+fn referenced_schemas(spec: &Value, fragment: &Value) -> Vec<String> {
+    fn walk<'a>(spec: &'a Value, value: &'a Value, visited: &mut HashSet<&'a str>, found: &mut Vec<String>) {
+        match value {
+            Value::Object(map) => {
+                if let Some(reference) = map.get("$ref").and_then(|v| v.as_str()) {
+                    if let Some(name) = schema_name(reference) {
+                        if !found.iter().any(|f| f == name) {
+                            found.push(name.to_string());
+                        }
+                    } else if visited.insert(reference) {
+                        if let Some(target) = reference.strip_prefix('#').and_then(|p| spec.pointer(p)) {
+                            walk(spec, target, visited, found);
+                        }
+                    }
+                }
+                for child in map.values() {
+                    walk(spec, child, visited, found);
+                }
+            }
+            Value::Array(items) => items.iter().for_each(|item| walk(spec, item, visited, found)),
+            _ => {}
+        }
+    }
+    let mut found = Vec::new();
+    walk(spec, fragment, &mut HashSet::new(), &mut found);
+    found
+}
+
+/// Collects nodes and edges for one spec.
+struct SpecGraph<'a> {
+    file_path: &'a str,
+    spec: &'a Value,
+    nodes: Vec<AnnotatedNode>,
+    edges: Vec<AnnotatedEdge>,
+    vocabulary: Vec<String>,
+}
+
+impl<'a> SpecGraph<'a> {
+    fn id(&self, kind: &str, name: &str) -> NodeId {
+        NodeId::from_string(format!("{}:{}:{}", kind, self.file_path, name))
+    }
+
+    fn node(&mut self, node_type: &str, id: NodeId, properties: Vec<(&str, Option<&str>)>) {
+        let mut node = Node::new_in_dimension(node_type, ContentType::Document, dimension::STRUCTURE);
+        node.id = id;
+        for (key, value) in properties {
+            if let Some(value) = value {
+                node.properties.insert(key.to_string(), PropertyValue::String(value.to_string()));
+            }
+        }
+        self.nodes.push(AnnotatedNode::new(node));
+    }
+
+    fn edge(&mut self, source: &NodeId, target: NodeId, relationship: &str) {
+        let edge = Edge::new_in_dimension(source.clone(), target, relationship, dimension::STRUCTURE);
+        if !self.edges.iter().any(|e| e.edge.source == edge.source
+            && e.edge.target == edge.target
+            && e.edge.relationship == edge.relationship)
+        {
+            self.edges.push(AnnotatedEdge::new(edge));
+        }
+    }
+
+    fn term(&mut self, term: &str) {
+        let term = term.to_lowercase();
+        if !self.vocabulary.contains(&term) {
+            self.vocabulary.push(term);
+        }
+    }
+
+    fn schemas(&mut self, group: &Value, kind: &str) {
+        let Some(group) = group.as_object() else { return };
+        for (name, schema) in group {
+            let id = self.id("schema", name);
+            let description = schema.get("description").or_else(|| schema.get("summary")).and_then(|v| v.as_str());
+            self.node("schema", id.clone(), vec![("label", Some(name)), ("kind", Some(kind)), ("description", description)]);
+            self.term(name);
+            for used in referenced_schemas(self.spec, schema) {
+                if &used != name {
+                    self.edge(&id, self.id("schema", &used), USES_SCHEMA);
+                }
+            }
+        }
+    }
+
+    /// An operation node under an endpoint, its inputs `uses_schema` and
+    /// its outputs `returns`.
+    fn operation(&mut self, endpoint: &NodeId, route: &str, method: &str, op: &Value, inputs: &[&Value], outputs: &[&Value]) {
+        let id = self.id("operation", &format!("{} {}", method.to_uppercase(), route));
+        let string = |key| op.get(key).and_then(|v: &Value| v.as_str());
+        self.node(
+            "operation",
+            id.clone(),
+            vec![
+                ("label", Some(&format!("{} {}", method.to_uppercase(), route))),
+                ("method", Some(method)),
+                ("route", Some(route)),
+                ("operation_id", string("operationId")),
+                ("summary", string("summary").or_else(|| string("description"))),
+            ],
+        );
+        self.edge(endpoint, id.clone(), "contains");
+        let tags = op.get("tags").and_then(|v| v.as_array()).into_iter().flatten();
+        for tag in tags.filter_map(|t| t.as_str().or_else(|| t.get("name").and_then(|n| n.as_str()))) {
+            self.term(tag);
+        }
+        for (fragments, relationship) in [(inputs, USES_SCHEMA), (outputs, RETURNS)] {
+            for fragment in fragments {
+                for schema in referenced_schemas(self.spec, fragment) {
+                    self.edge(&id, self.id("schema", &schema), relationship);
+                }
+            }
+        }
+    }
+
+    fn endpoint(&mut self, api: &NodeId, route: &str, kind: &str) -> NodeId {
+        let id = self.id("endpoint", route);
+        if !self.nodes.iter().any(|n| n.node.id == id) {
+            self.node("endpoint", id.clone(), vec![("label", Some(route)), ("kind", Some(kind))]);
+            self.edge(api, id.clone(), "contains");
+        }
+        id
+    }
+
+    fn openapi(&mut self, api: &NodeId) {
+        let paths = self.spec.get("paths").and_then(|v| v.as_object()).into_iter().flatten();
+        for (route, item) in paths {
+            let endpoint = self.endpoint(api, route, "path");
+            let shared = item.get("parameters");
+            for method in HTTP_METHODS {
+                let Some(op) = item.get(*method) else { continue };
+                let inputs: Vec<&Value> =
+                    [shared, op.get("parameters"), op.get("requestBody")].into_iter().flatten().collect();
+                let outputs: Vec<&Value> = op.get("responses").into_iter().collect();
+                self.operation(&endpoint, route, method, op, &inputs, &outputs);
+            }
+        }
+    }
+
+    fn asyncapi(&mut self, api: &NodeId) {
+        let channels = self.spec.get("channels").and_then(|v| v.as_object()).into_iter().flatten();
+        for (channel, item) in channels {
+            let route = item.get("address").and_then(|v| v.as_str()).unwrap_or(channel);
+            let endpoint = self.endpoint(api, route, "channel");
+            // AsyncAPI 2: `subscribe` is what the application sends
+            for (action, sends) in [("publish", false), ("subscribe", true)] {
+                let Some(op) = item.get(action) else { continue };
+                let message: Vec<&Value> = op.get("message").into_iter().collect();
+                let (inputs, outputs) = if sends { (&[][..], &message[..]) } else { (&message[..], &[][..]) };
+                self.operation(&endpoint, route, action, op, inputs, outputs);
+            }
+        }
+        // AsyncAPI 3: operations name their channel by reference
+        let operations = self.spec.get("operations").and_then(|v| v.as_object()).into_iter().flatten();
+        for (name, op) in operations {
+            let Some(reference) = op.pointer("/channel/$ref").and_then(|v| v.as_str()) else { continue };
+            let key = reference.rsplit('/').next().unwrap_or(reference);
+            let channel = reference.strip_prefix('#').and_then(|p| self.spec.pointer(p));
+            let route = channel.and_then(|c| c.get("address")).and_then(|v| v.as_str()).unwrap_or(key).to_string();
+            let endpoint = self.endpoint(api, &route, "channel");
+            let action = op.get("action").and_then(|v| v.as_str()).unwrap_or("send");
+            // Messages are listed on the operation, or else on its channel
+            let messages: Vec<&Value> = op.get("messages").or_else(|| channel?.get("messages")).into_iter().collect();
+            let (inputs, outputs) =
+                if action == "send" { (&[][..], &messages[..]) } else { (&messages[..], &[][..]) };
+            let op_name = op.get("operationId").and_then(|v| v.as_str()).unwrap_or(name);
+            let mut op = op.clone();
+            if let Some(map) = op.as_object_mut() {
+                map.entry("operationId").or_insert_with(|| Value::String(op_name.to_string()));
+            }
+            self.operation(&endpoint, &route, action, &op, inputs, outputs);
+        }
+    }
+}
+
+#[async_trait]
+impl StructuralModule for ApiSpecModule {
+    fn id(&self) -> &str {
+        "extract-analysis-api-spec"
+    }
+
+    fn mime_affinity(&self) -> &str {
+        self.mime
+    }
+
+    async fn analyze(&self, file_path: &str, content: &str) -> StructuralOutput {
+        // YAML is a superset of JSON, so one parser reads both
+        let Ok(spec) = serde_yaml::from_str::<Value>(content) else {
+            return StructuralOutput::default();
+        };
+        let Some((family, version)) = ["openapi", "swagger", "asyncapi"]
+            .iter()
+            .find_map(|key| spec.get(*key).map(|v| (*key, v.as_str().map(str::to_string).unwrap_or(v.to_string()))))
+        else {
+            return StructuralOutput::default();
+        };
+
+        let mut graph =
+            SpecGraph { file_path, spec: &spec, nodes: Vec::new(), edges: Vec::new(), vocabulary: Vec::new() };
+        let api = NodeId::from_string(format!("api:{}", file_path));
+        let info = |key| spec.pointer(&format!("/info/{}", key)).and_then(|v| v.as_str());
+        graph.node(
+            "api",
+            api.clone(),
+            vec![
+                ("label", info("title")),
+                ("version", info("version")),
+                ("spec", Some(&format!("{} {}", family, version))),
+                ("description", info("description")),
+            ],
+        );
+        graph.edge(&NodeId::from_string(format!("file:{}", file_path)), api.clone(), "contains");
+
+        graph.schemas(spec.pointer("/components/schemas").unwrap_or(&Value::Null), "schema");
+        graph.schemas(spec.get("definitions").unwrap_or(&Value::Null), "schema");
+        graph.schemas(spec.pointer("/components/messages").unwrap_or(&Value::Null), "message");
+        if family == "asyncapi" {
+            graph.asyncapi(&api);
+        } else {
+            graph.openapi(&api);
+        }
+
+        StructuralOutput {
+            vocabulary: graph.vocabulary,
+            emissions: vec![ModuleEmission { module_id: self.id().to_string(), nodes: graph.nodes, edges: graph.edges }],
+            ..StructuralOutput::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edges<'a>(output: &'a StructuralOutput, relationship: &str) -> Vec<(&'a str, &'a str)> {
+        let mut edges: Vec<_> = output.emissions[0]
+            .edges
+            .iter()
+            .filter(|e| e.edge.relationship == relationship)
+            .map(|e| (e.edge.source.as_str(), e.edge.target.as_str()))
+            .collect();
+        edges.sort();
+        edges
+    }
+
+    // === Scenario: OpenAPI operations use and return schemas ===
+    #[tokio::test]
+    async fn openapi_operations_link_to_schemas() {
+        let spec = r##"
+openapi: 3.1.0
+info: {title: Sites API, version: "2.0"}
+paths:
+  /sites/{id}:
+    parameters:
+      - {name: id, in: path, schema: {$ref: "#/components/schemas/SiteId"}}
+    get:
+      operationId: getSite
+      tags: [sites]
+      responses:
+        "200": {$ref: "#/components/responses/SiteResponse"}
+    put:
+      requestBody:
+        content: {application/json: {schema: {$ref: "#/components/schemas/Site"}}}
+      responses: {"204": {description: updated}}
+components:
+  responses:
+    SiteResponse:
+      content: {application/json: {schema: {$ref: "#/components/schemas/Site"}}}
+  schemas:
+    SiteId: {type: string}
+    Site:
+      type: object
+      properties:
+        id: {$ref: "#/components/schemas/SiteId"}
+"##;
+        let output = ApiSpecModule::yaml().analyze("api.yaml", spec).await;
+        let nodes = &output.emissions[0].nodes;
+        assert_eq!(nodes.iter().filter(|n| n.node.node_type == "operation").count(), 2);
+        assert_eq!(
+            edges(&output, RETURNS),
+            vec![("operation:api.yaml:GET /sites/{id}", "schema:api.yaml:Site")],
+            "response components are followed to their schemas"
+        );
+        assert_eq!(
+            edges(&output, USES_SCHEMA),
+            vec![
+                ("operation:api.yaml:GET /sites/{id}", "schema:api.yaml:SiteId"),
+                ("operation:api.yaml:PUT /sites/{id}", "schema:api.yaml:Site"),
+                ("operation:api.yaml:PUT /sites/{id}", "schema:api.yaml:SiteId"),
+                ("schema:api.yaml:Site", "schema:api.yaml:SiteId"),
+            ]
+        );
+        assert!(edges(&output, "contains").contains(&("endpoint:api.yaml:/sites/{id}", "operation:api.yaml:GET /sites/{id}")));
+        assert!(output.vocabulary.contains(&"sites".to_string()));
+        assert!(ApiSpecModule::yaml().analyze("ci.yaml", "jobs: {build: {}}").await.emissions.is_empty());
+    }
+
+    // === Scenario: AsyncAPI channels carry sent and received messages ===
+    #[tokio::test]
+    async fn asyncapi_channels_and_messages() {
+        let spec = serde_json::json!({
+            "asyncapi": "2.6.0",
+            "info": {"title": "Survey events", "version": "1"},
+            "channels": {
+                "finds/recorded": {
+                    "subscribe": {"message": {"$ref": "#/components/messages/FindRecorded"}},
+                    "publish": {"message": {"$ref": "#/components/messages/RecordFind"}}
+                }
+            },
+            "components": {"messages": {"FindRecorded": {"payload": {"type": "object"}}, "RecordFind": {}}}
+        })
+        .to_string();
+        let output = ApiSpecModule::json().analyze("events.json", &spec).await;
+        assert_eq!(
+            edges(&output, RETURNS),
+            vec![("operation:events.json:SUBSCRIBE finds/recorded", "schema:events.json:FindRecorded")]
+        );
+        assert_eq!(
+            edges(&output, USES_SCHEMA),
+            vec![("operation:events.json:PUBLISH finds/recorded", "schema:events.json:RecordFind")]
+        );
+    }
+}
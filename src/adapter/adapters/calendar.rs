@@ -0,0 +1,540 @@
+//! CalendarAdapter — calendar and task export ingestion
+//!
+//! Maps iCalendar exports (`VEVENT`, `VTODO`) and JSON task lists to the
+//! temporal dimension:
+//! - An event or task node per item, with `scheduled_for` (start) and
+//!   `scheduled_until` (end) as RFC 3339 strings; tasks also keep `due`
+//! - A concept node per category or tag and a `tagged_with` edge to it,
+//!   tags passing the context's `TagPolicy` as content tags do
+//! - A chain per export source and a mark per item (Invariant 7)
+//!
+//! An item without an end is scheduled for an hour, or for the whole day
+//! when it is dated without a time. `ScheduleWindowEnrichment` links the
+//! fragments created inside an item's window to it (`occurred_during`),
+//! which is what lets "what was I working on when this idea appeared"
+//! be answered by traversal.
+//!
+//! Times with a `TZID` or no zone are read as UTC; only explicit `Z`
+//! times and RFC 3339 offsets are exact.
+
+use crate::adapter::sink::{AdapterError, AdapterSink};
+use crate::adapter::traits::{Adapter, AdapterInput};
+use crate::adapter::types::{chain_node, concept_node, mark_node, Emission};
+use crate::graph::{dimension, ContentType, Edge, Node, NodeId, PropertyValue};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
+use uuid::Uuid;
+
+/// Whether an item is an appointment or a to-do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemKind {
+    Event,
+    Task,
+}
+
+impl ItemKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            ItemKind::Event => "event",
+            ItemKind::Task => "task",
+        }
+    }
+}
+
+/// One calendar event or task.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduledItem {
+    pub kind: ItemKind,
+    /// Stable ID from the export (`UID`); derived from title and start when absent
+    pub uid: Option<String>,
+    pub title: String,
+    pub description: Option<String>,
+    pub start: Option<DateTime<Utc>>,
+    pub end: Option<DateTime<Utc>>,
+    /// Tasks only
+    pub due: Option<DateTime<Utc>>,
+    /// Dated without a time of day
+    pub all_day: bool,
+    pub status: Option<String>,
+    pub location: Option<String>,
+    /// Categories or tags (each produces a concept node)
+    pub tags: Vec<String>,
+}
+
+impl ScheduledItem {
+    pub fn new(kind: ItemKind, title: impl Into<String>) -> Self {
+        Self {
+            kind,
+            uid: None,
+            title: title.into(),
+            description: None,
+            start: None,
+            end: None,
+            due: None,
+            all_day: false,
+            status: None,
+            location: None,
+            tags: Vec::new(),
+        }
+    }
+
+    /// `[scheduled_for, scheduled_until]`, or `None` for an undated item.
+    pub fn window(&self) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        let start = self.start.or(self.due)?;
+        let default_length = if self.all_day { Duration::days(1) } else { Duration::hours(1) };
+        let end = match (self.end, self.start.and(self.due)) {
+            (Some(end), _) if end > start => end,
+            (_, Some(due)) if due > start => due,
+            _ => start + default_length,
+        };
+        Some((start, end))
+    }
+}
+
+/// Input data for the CalendarAdapter.
+#[derive(Debug, Clone, Default)]
+pub struct CalendarInput {
+    pub items: Vec<ScheduledItem>,
+    /// Where the export came from (e.g., "work.ics", "taskwarrior")
+    pub source: Option<String>,
+}
+
+impl CalendarInput {
+    pub fn new(items: Vec<ScheduledItem>) -> Self {
+        Self { items, source: None }
+    }
+
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Parse the `VEVENT` and `VTODO` components of an iCalendar file.
+    pub fn from_ics(text: &str) -> Self {
+        let mut items = Vec::new();
+        let mut current: Option<ScheduledItem> = None;
+        for line in unfold(text) {
+            let Some((name, params, value)) = split_property(&line) else { continue };
+            match (name.as_str(), value) {
+                ("BEGIN", "VEVENT") => current = Some(ScheduledItem::new(ItemKind::Event, "")),
+                ("BEGIN", "VTODO") => current = Some(ScheduledItem::new(ItemKind::Task, "")),
+                ("END", "VEVENT" | "VTODO") => items.extend(current.take()),
+                _ => {
+                    let Some(ref mut item) = current else { continue };
+                    let date = || parse_datetime(value);
+                    match name.as_str() {
+                        "UID" => item.uid = Some(value.to_string()),
+                        "SUMMARY" => item.title = unescape(value),
+                        "DESCRIPTION" => item.description = Some(unescape(value)),
+                        "LOCATION" => item.location = Some(unescape(value)),
+                        "STATUS" => item.status = Some(value.to_lowercase()),
+                        "CATEGORIES" => item.tags.extend(split_list(value)),
+                        "DTSTART" => {
+                            if let Some((start, all_day)) = date() {
+                                item.start = Some(start);
+                                item.all_day = all_day || params.contains("VALUE=DATE");
+                            }
+                        }
+                        "DTEND" => item.end = date().map(|(end, _)| end),
+                        "DUE" => {
+                            if let Some((due, all_day)) = date() {
+                                item.due = Some(due);
+                                if item.start.is_none() {
+                                    item.all_day = all_day || params.contains("VALUE=DATE");
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        Self { items, source: None }
+    }
+
+    /// Parse a JSON export.
+    ///
+    /// Either `{"ics": "BEGIN:VCALENDAR..."}`, or lists under `events`
+    /// and `tasks` whose items carry a title (`title`, `summary`, or
+    /// `description`) and optionally `id`/`uid`, `start`/`scheduled`,
+    /// `end`, `due`, `status`, `location`, and `tags`. Dates are RFC 3339
+    /// or iCalendar basic format (`20260301T100000Z`, `20260301`).
+    /// Optional: `source` (string).
+    pub fn from_json(json: &serde_json::Value) -> Result<Self, AdapterError> {
+        let source = json.get("source").and_then(|v| v.as_str()).map(|s| s.to_string());
+        if let Some(ics) = json.get("ics").and_then(|v| v.as_str()) {
+            return Ok(Self { source, ..Self::from_ics(ics) });
+        }
+        let mut items = Vec::new();
+        let mut found = false;
+        for (key, kind) in [("events", ItemKind::Event), ("tasks", ItemKind::Task)] {
+            if let Some(list) = json.get(key).and_then(|v| v.as_array()) {
+                found = true;
+                items.extend(list.iter().filter_map(|item| item_from_json(item, kind)));
+            }
+        }
+        if !found {
+            return Err(AdapterError::Internal(
+                "calendar input requires 'ics' text or an 'events' or 'tasks' array".into(),
+            ));
+        }
+        Ok(Self { items, source })
+    }
+}
+
+fn item_from_json(json: &serde_json::Value, kind: ItemKind) -> Option<ScheduledItem> {
+    let string = |keys: &[&str]| {
+        keys.iter().find_map(|k| json.get(*k).and_then(|v| v.as_str())).map(|s| s.to_string())
+    };
+    let date = |keys: &[&str]| string(keys).and_then(|s| parse_datetime(&s));
+    let title = string(&["title", "summary", "description"])?;
+    let mut item = ScheduledItem::new(kind, title);
+    item.uid = string(&["uid", "id", "uuid"]);
+    if let Some((start, all_day)) = date(&["start", "scheduled"]) {
+        item.start = Some(start);
+        item.all_day = all_day;
+    }
+    item.end = date(&["end"]).map(|(end, _)| end);
+    item.due = date(&["due"]).map(|(due, _)| due);
+    if item.start.is_none() {
+        item.all_day = date(&["due"]).is_some_and(|(_, all_day)| all_day);
+    }
+    item.status = string(&["status"]).map(|s| s.to_lowercase());
+    item.location = string(&["location"]);
+    if json.get("title").is_some() || json.get("summary").is_some() {
+        item.description = string(&["description", "notes"]);
+    }
+    item.tags = json
+        .get("tags")
+        .and_then(|v| v.as_array())
+        .map(|arr| arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect())
+        .unwrap_or_default();
+    Some(item)
+}
+
+/// Join folded content lines (RFC 5545 §3.1).
+fn unfold(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in text.lines() {
+        match (raw.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(raw.to_string()),
+        }
+    }
+    lines
+}
+
+/// `NAME;PARAMS:VALUE` → (upper-cased name, params, value).
+fn split_property(line: &str) -> Option<(String, &str, &str)> {
+    let mut quoted = false;
+    let colon = line.char_indices().find_map(|(i, c)| match c {
+        '"' => {
+            quoted = !quoted;
+            None
+        }
+        ':' if !quoted => Some(i),
+        _ => None,
+    })?;
+    let (head, value) = (&line[..colon], line[colon + 1..].trim_end());
+    let (name, params) = head.split_once(';').unwrap_or((head, ""));
+    Some((name.trim().to_uppercase(), params, value))
+}
+
+fn unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out
+}
+
+/// Split a comma list, honouring `\,` escapes.
+fn split_list(value: &str) -> Vec<String> {
+    let mut items = vec![String::new()];
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => items.last_mut().unwrap().extend(chars.next()),
+            ',' => items.push(String::new()),
+            _ => items.last_mut().unwrap().push(c),
+        }
+    }
+    items.into_iter().map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect()
+}
+
+/// A time and whether it was a bare date.
+fn parse_datetime(value: &str) -> Option<(DateTime<Utc>, bool)> {
+    let value = value.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some((dt.with_timezone(&Utc), false));
+    }
+    let basic = value.trim_end_matches('Z');
+    if let Ok(naive) = NaiveDateTime::parse_from_str(basic, "%Y%m%dT%H%M%S") {
+        return Some((naive.and_utc(), false));
+    }
+    ["%Y%m%d", "%Y-%m-%d"]
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+        .map(|date| (date.and_hms_opt(0, 0, 0).expect("midnight exists").and_utc(), true))
+}
+
+/// Calendar and task export adapter.
+pub struct CalendarAdapter {
+    adapter_id: String,
+}
+
+impl CalendarAdapter {
+    pub fn new(adapter_id: impl Into<String>) -> Self {
+        Self { adapter_id: adapter_id.into() }
+    }
+
+    fn item_id(&self, item: &ScheduledItem) -> NodeId {
+        let key = match item.uid {
+            Some(ref uid) => uid.clone(),
+            None => {
+                // UUID v5 namespace for Plexus calendar items (stable, arbitrary)
+                const CALENDAR_NS: Uuid = Uuid::from_bytes([
+                    0x8e, 0x52, 0x0b, 0x7d, 0x61, 0x3a, 0x47, 0x19,
+                    0xb2, 0x0c, 0x94, 0x5e, 0x2d, 0x71, 0xa8, 0x36,
+                ]);
+                let start = item.start.or(item.due).map(|t| t.to_rfc3339()).unwrap_or_default();
+                let hash_input = format!("{}:{}:{}", self.adapter_id, item.title, start);
+                Uuid::new_v5(&CALENDAR_NS, hash_input.as_bytes()).to_string()
+            }
+        };
+        NodeId::from_string(format!("{}:{}", item.kind.as_str(), key))
+    }
+}
+
+#[async_trait]
+impl Adapter for CalendarAdapter {
+    fn id(&self) -> &str {
+        &self.adapter_id
+    }
+
+    fn input_kind(&self) -> &str {
+        "calendar"
+    }
+
+    fn input_from_json(&self, json: &serde_json::Value) -> Result<Box<dyn std::any::Any + Send + Sync>, AdapterError> {
+        Ok(Box::new(CalendarInput::from_json(json)?))
+    }
+
+    fn input_schema(&self) -> Option<serde_json::Value> {
+        let items = serde_json::json!({"type": "array", "items": {"type": "object"}});
+        Some(serde_json::json!({
+            "type": "object",
+            "anyOf": [{"required": ["ics"]}, {"required": ["events"]}, {"required": ["tasks"]}],
+            "properties": {
+                "ics": {"type": "string"},
+                "events": items,
+                "tasks": items,
+                "source": {"type": "string"},
+            }
+        }))
+    }
+
+    async fn process(
+        &self,
+        input: &AdapterInput,
+        sink: &dyn AdapterSink,
+    ) -> Result<(), AdapterError> {
+        // Accept both typed CalendarInput and raw JSON
+        let owned: CalendarInput;
+        let calendar: &CalendarInput = if let Some(c) = input.downcast_data::<CalendarInput>() {
+            c
+        } else if let Some(json) = input.downcast_data::<serde_json::Value>() {
+            owned = CalendarInput::from_json(json)?;
+            &owned
+        } else {
+            return Err(AdapterError::InvalidInput);
+        };
+        if calendar.items.is_empty() {
+            return Ok(());
+        }
+
+        let source = calendar.source.as_deref().unwrap_or("default");
+        let chain_id = format!("chain:{}:{}", self.adapter_id, source);
+        let mut chain = chain_node(&chain_id);
+        chain.properties.insert(
+            "name".to_string(),
+            PropertyValue::String(format!("{} — {}", self.adapter_id, source)),
+        );
+        chain.properties.insert("status".to_string(), PropertyValue::String("active".to_string()));
+        let mut emission = Emission::new().with_node(chain);
+
+        for (index, item) in calendar.items.iter().enumerate() {
+            let item_id = self.item_id(item);
+            let mut node = Node::new_in_dimension(item.kind.as_str(), ContentType::Document, dimension::TEMPORAL);
+            node.id = item_id.clone();
+            let mut set = |key: &str, value: PropertyValue| {
+                node.properties.insert(key.to_string(), value);
+            };
+            set("title", PropertyValue::String(item.title.clone()));
+            if let Some((start, end)) = item.window() {
+                set("scheduled_for", PropertyValue::String(start.to_rfc3339()));
+                set("scheduled_until", PropertyValue::String(end.to_rfc3339()));
+            }
+            if let Some(due) = item.due {
+                set("due", PropertyValue::String(due.to_rfc3339()));
+            }
+            if item.all_day {
+                set("all_day", PropertyValue::Bool(true));
+            }
+            for (key, value) in [("description", &item.description), ("status", &item.status), ("location", &item.location)] {
+                if let Some(value) = value {
+                    set(key, PropertyValue::String(value.clone()));
+                }
+            }
+            emission = emission.with_node(node);
+
+            let tags = match input.tag_policy {
+                Some(ref policy) => policy.apply(&item.tags),
+                None => item.tags.clone(),
+            };
+            for tag in &tags {
+                let (concept_id, concept) = concept_node(tag);
+                let mut edge = Edge::new_cross_dimensional(
+                    item_id.clone(),
+                    dimension::TEMPORAL,
+                    concept_id,
+                    dimension::SEMANTIC,
+                    "tagged_with",
+                );
+                edge.combined_weight = 1.0;
+                emission = emission.with_node(concept).with_edge(edge);
+            }
+
+            let mut mark = mark_node(&format!("mark:{}:{}", self.adapter_id, item_id));
+            mark.properties.insert("chain_id".to_string(), PropertyValue::String(chain_id.clone()));
+            mark.properties.insert("annotation".to_string(), PropertyValue::String(item.title.clone()));
+            mark.properties.insert("file".to_string(), PropertyValue::String(source.to_string()));
+            mark.properties.insert("line".to_string(), PropertyValue::Int(index as i64 + 1));
+            if !tags.is_empty() {
+                let tag_vals = tags.iter().map(|t| PropertyValue::String(t.to_lowercase())).collect();
+                mark.properties.insert("tags".to_string(), PropertyValue::Array(tag_vals));
+            }
+            let contains = Edge::new_in_dimension(
+                NodeId::from_string(&chain_id),
+                mark.id.clone(),
+                "contains",
+                dimension::PROVENANCE,
+            );
+            emission = emission.with_node(mark).with_edge(contains);
+        }
+
+        sink.emit(emission).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::{EngineSink, FrameworkContext};
+    use crate::graph::Context;
+    use std::sync::{Arc, Mutex};
+
+    const ICS: &str = "BEGIN:VCALENDAR\r\n\
+BEGIN:VEVENT\r\n\
+UID:standup-1\r\n\
+SUMMARY:Index design\\, part 2\r\n\
+DESCRIPTION:Walk through the\r\n  closure rebuild\r\n\
+DTSTART:20260301T100000Z\r\n\
+DTEND:20260301T113000Z\r\n\
+CATEGORIES:indexing,design\r\n\
+END:VEVENT\r\n\
+BEGIN:VTODO\r\n\
+SUMMARY:Write release notes\r\n\
+DUE;VALUE=DATE:20260305\r\n\
+STATUS:NEEDS-ACTION\r\n\
+END:VTODO\r\n\
+END:VCALENDAR\r\n";
+
+    fn make_sink(adapter_id: &str) -> (EngineSink, Arc<Mutex<Context>>) {
+        let ctx = Arc::new(Mutex::new(Context::new("test")));
+        let fw = FrameworkContext {
+            adapter_id: adapter_id.to_string(),
+            context_id: "test".to_string(),
+            input_summary: None,
+        };
+        let sink = EngineSink::new(ctx.clone()).with_framework_context(fw);
+        (sink, ctx)
+    }
+
+    // === Scenario: iCalendar events and to-dos parse with their windows ===
+    #[test]
+    fn ics_exports_parse_events_and_todos() {
+        let calendar = CalendarInput::from_ics(ICS);
+        assert_eq!(calendar.items.len(), 2);
+
+        let event = &calendar.items[0];
+        assert_eq!(event.title, "Index design, part 2");
+        assert_eq!(event.description.as_deref(), Some("Walk through the closure rebuild"));
+        assert_eq!(event.tags, vec!["indexing", "design"]);
+        let (start, end) = event.window().unwrap();
+        assert_eq!(start.to_rfc3339(), "2026-03-01T10:00:00+00:00");
+        assert_eq!(end - start, Duration::minutes(90));
+
+        let task = &calendar.items[1];
+        assert_eq!(task.kind, ItemKind::Task);
+        assert_eq!(task.status.as_deref(), Some("needs-action"));
+        let (start, end) = task.window().unwrap();
+        assert_eq!(end - start, Duration::days(1), "a dated task is scheduled for its day");
+    }
+
+    // === Scenario: Items become temporal nodes tagged with concepts ===
+    #[tokio::test]
+    async fn items_become_scheduled_nodes_with_concepts_and_marks() {
+        let adapter = CalendarAdapter::new("calendar");
+        let (sink, ctx) = make_sink("calendar");
+        let json = serde_json::json!({
+            "source": "work.ics",
+            "ics": ICS,
+            "tasks": [{"description": "ignored when ics is given"}],
+        });
+        adapter.process(&AdapterInput::new("calendar", json, "test"), &sink).await.unwrap();
+
+        let ctx = ctx.lock().unwrap();
+        let event = ctx.get_node(&NodeId::from_string("event:standup-1")).unwrap();
+        assert_eq!(event.dimension, dimension::TEMPORAL);
+        assert_eq!(event.properties.get("scheduled_for"), Some(&PropertyValue::from("2026-03-01T10:00:00+00:00")));
+        let tagged: Vec<&str> = ctx
+            .edges
+            .iter()
+            .filter(|e| e.source == event.id && e.relationship == "tagged_with")
+            .map(|e| e.target.as_str())
+            .collect();
+        assert_eq!(tagged, vec!["concept:indexing", "concept:design"]);
+        assert_eq!(ctx.nodes.values().filter(|n| n.node_type == "task").count(), 1);
+        assert_eq!(ctx.nodes.values().filter(|n| n.node_type == "mark").count(), 2);
+        assert!(ctx.get_node(&NodeId::from_string("chain:calendar:work.ics")).is_some());
+    }
+
+    // === Scenario: JSON task lists in Taskwarrior style ===
+    #[test]
+    fn json_task_lists_parse() {
+        let json = serde_json::json!({"tasks": [
+            {"uuid": "t-1", "description": "Review closure PR", "due": "20260302T170000Z", "tags": ["review"]},
+            {"title": "Plan", "start": "2026-03-02T09:00:00+01:00", "notes": "q2"},
+            {"due": "20260302"}
+        ]});
+        let calendar = CalendarInput::from_json(&json).unwrap();
+        assert_eq!(calendar.items.len(), 2, "items without a title are skipped");
+        assert_eq!(calendar.items[0].uid.as_deref(), Some("t-1"));
+        assert_eq!(calendar.items[0].title, "Review closure PR");
+        assert!(calendar.items[0].description.is_none());
+        assert_eq!(calendar.items[1].start.unwrap().to_rfc3339(), "2026-03-02T08:00:00+00:00");
+        assert_eq!(calendar.items[1].description.as_deref(), Some("q2"));
+        assert!(CalendarInput::from_json(&serde_json::json!({"text": "x"})).is_err());
+    }
+}
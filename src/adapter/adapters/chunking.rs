@@ -0,0 +1,248 @@
+//! Chunking long documents into addressable pieces
+//!
+//! Semantic extraction and embedding models have bounded context windows;
+//! a whole file either gets truncated (`semantic::MAX_CONTENT_CHARS`) or
+//! dilutes everything it mentions into one vector. The chunker splits a
+//! document along the section boundaries structural analysis found, or
+//! into windows of a token budget with some overlap. Each chunk becomes a
+//! `chunk` node in the structure dimension with a stable ID
+//! (`chunk:{path}:{index}`), its text and line span, and a `contains`
+//! edge from its file node. Re-chunking a file that got shorter removes
+//! the chunks past the new count, and their edges with them. The semantic
+//! adapter processes each chunk separately and tags concepts from the
+//! chunk they were found in.
+//!
+//! Tokens are approximated as whitespace-separated words.
+
+use super::structural::{SectionBoundary, REGION_END_PROPERTY, REGION_START_PROPERTY};
+use crate::adapter::types::Emission;
+use crate::graph::{dimension, ContentType, Context, Edge, Node, NodeId, PropertyValue};
+
+/// Adapter ID credited with chunk nodes.
+pub const CHUNKING_ID: &str = "extract-chunking";
+/// Node type of document chunks.
+pub const CHUNK_NODE_TYPE: &str = "chunk";
+/// Chunk node property holding the chunk's text.
+pub const CHUNK_TEXT_PROPERTY: &str = "text";
+
+/// How a document is split.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChunkStrategy {
+    /// One chunk per section from structural analysis, plus any text
+    /// before the first section. A document without sections is one chunk.
+    Headings,
+    /// Windows of at most `max_tokens` tokens, each repeating the last
+    /// `overlap` tokens of the one before.
+    TokenBudget { max_tokens: usize, overlap: usize },
+}
+
+/// A piece of a document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chunk {
+    pub id: NodeId,
+    /// Position in the document, from 0
+    pub index: usize,
+    /// Section label, or the chunk's line range
+    pub label: String,
+    /// First line, 1-based
+    pub start_line: usize,
+    /// Last line, inclusive
+    pub end_line: usize,
+    pub text: String,
+}
+
+/// Split `content` of `file_path` into chunks. Blank chunks are dropped.
+pub fn chunk_document(
+    file_path: &str,
+    content: &str,
+    sections: &[SectionBoundary],
+    strategy: &ChunkStrategy,
+) -> Vec<Chunk> {
+    let spans = match strategy {
+        ChunkStrategy::Headings => heading_spans(content, sections),
+        ChunkStrategy::TokenBudget { max_tokens, overlap } => token_spans(content, *max_tokens, *overlap),
+    };
+    spans
+        .into_iter()
+        .filter(|span| !span.text.trim().is_empty())
+        .enumerate()
+        .map(|(index, span)| Chunk {
+            id: NodeId::from_string(format!("chunk:{}:{}", file_path, index)),
+            index,
+            label: span.label.unwrap_or_else(|| format!("lines {}-{}", span.start_line, span.end_line)),
+            start_line: span.start_line,
+            end_line: span.end_line,
+            text: span.text,
+        })
+        .collect()
+}
+
+/// Chunk nodes for `file_path`, each with a `contains` edge from the
+/// file node.
+pub fn chunk_emission(file_path: &str, chunks: &[Chunk]) -> Emission {
+    let file_id = NodeId::from_string(format!("file:{}", file_path));
+    chunks.iter().fold(Emission::new(), |emission, chunk| {
+        let mut node = Node::new_in_dimension(CHUNK_NODE_TYPE, ContentType::Document, dimension::STRUCTURE);
+        node.id = chunk.id.clone();
+        let properties = [
+            ("label", PropertyValue::String(chunk.label.clone())),
+            (CHUNK_TEXT_PROPERTY, PropertyValue::String(chunk.text.clone())),
+            ("file_path", PropertyValue::String(file_path.to_string())),
+            ("index", PropertyValue::Int(chunk.index as i64)),
+            (REGION_START_PROPERTY, PropertyValue::Int(chunk.start_line as i64)),
+            (REGION_END_PROPERTY, PropertyValue::Int(chunk.end_line as i64)),
+        ];
+        for (key, value) in properties {
+            node.properties.insert(key.to_string(), value);
+        }
+        let edge = Edge::new_in_dimension(file_id.clone(), chunk.id.clone(), "contains", dimension::STRUCTURE);
+        emission.with_node(node).with_edge(edge)
+    })
+}
+
+/// Chunks of `file_path` in `context` left from a longer chunking: those
+/// whose index is `count` or more.
+pub fn stale_chunks(context: &Context, file_path: &str, count: usize) -> Vec<NodeId> {
+    context
+        .nodes()
+        .filter(|n| n.node_type == CHUNK_NODE_TYPE)
+        .filter(|n| n.properties.get("file_path") == Some(&PropertyValue::String(file_path.to_string())))
+        .filter(|n| matches!(n.properties.get("index"), Some(PropertyValue::Int(i)) if *i >= count as i64))
+        .map(|n| n.id.clone())
+        .collect()
+}
+
+struct Span {
+    label: Option<String>,
+    start_line: usize,
+    end_line: usize,
+    text: String,
+}
+
+fn line_span(lines: &[&str], start_line: usize, end_line: usize, label: Option<String>) -> Span {
+    let text = lines[start_line - 1..end_line].join("\n");
+    Span { label, start_line, end_line, text }
+}
+
+fn heading_spans(content: &str, sections: &[SectionBoundary]) -> Vec<Span> {
+    let lines: Vec<&str> = content.lines().collect();
+    if lines.is_empty() {
+        return Vec::new();
+    }
+    let total = lines.len();
+    let mut sections: Vec<&SectionBoundary> =
+        sections.iter().filter(|s| s.start_line >= 1 && s.start_line <= total).collect();
+    sections.sort_by_key(|s| s.start_line);
+
+    let mut spans = Vec::new();
+    let mut next_line = 1;
+    for section in sections {
+        if section.start_line < next_line {
+            continue;
+        }
+        if section.start_line > next_line {
+            spans.push(line_span(&lines, next_line, section.start_line - 1, None));
+        }
+        let end_line = section.end_line.clamp(section.start_line, total);
+        spans.push(line_span(&lines, section.start_line, end_line, Some(section.label.clone())));
+        next_line = end_line + 1;
+    }
+    if next_line <= total {
+        spans.push(line_span(&lines, next_line, total, None));
+    }
+    spans
+}
+
+fn token_spans(content: &str, max_tokens: usize, overlap: usize) -> Vec<Span> {
+    let max_tokens = max_tokens.max(1);
+    let step = max_tokens.saturating_sub(overlap).max(1);
+
+    // (line, start byte, end byte) of each token
+    let mut tokens = Vec::new();
+    let mut line_start = 0;
+    for (line_index, line) in content.split_inclusive('\n').enumerate() {
+        let mut offset = 0;
+        for word in line.split_whitespace() {
+            let start = offset + line[offset..].find(word).unwrap_or(0);
+            offset = start + word.len();
+            tokens.push((line_index + 1, line_start + start, line_start + offset));
+        }
+        line_start += line.len();
+    }
+
+    let mut spans = Vec::new();
+    let mut first = 0;
+    while first < tokens.len() {
+        let last = (first + max_tokens).min(tokens.len()) - 1;
+        let (start_line, start_byte, _) = tokens[first];
+        let (end_line, _, end_byte) = tokens[last];
+        spans.push(Span { label: None, start_line, end_line, text: content[start_byte..end_byte].to_string() });
+        if last + 1 == tokens.len() {
+            break;
+        }
+        first += step;
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // === Scenario: Chunks follow section boundaries ===
+    #[test]
+    fn heading_chunks_cover_preamble_and_sections() {
+        let content = "intro text\n\n# One\nfirst\n# Two\nsecond\nmore";
+        let sections = vec![
+            SectionBoundary { label: "One".into(), start_line: 3, end_line: 4 },
+            SectionBoundary { label: "Two".into(), start_line: 5, end_line: 7 },
+        ];
+        let chunks = chunk_document("/docs/a.md", content, &sections, &ChunkStrategy::Headings);
+
+        let labels: Vec<&str> = chunks.iter().map(|c| c.label.as_str()).collect();
+        assert_eq!(labels, vec!["lines 1-2", "One", "Two"]);
+        assert_eq!(chunks[2].text, "# Two\nsecond\nmore");
+        assert_eq!((chunks[2].start_line, chunks[2].end_line), (5, 7));
+        assert_eq!(chunks[1].id, NodeId::from_string("chunk:/docs/a.md:1"));
+
+        let whole = chunk_document("/docs/a.md", content, &[], &ChunkStrategy::Headings);
+        assert_eq!(whole.len(), 1);
+        assert_eq!(whole[0].text, content);
+    }
+
+    // === Scenario: Token windows overlap and stay within budget ===
+    #[test]
+    fn token_budget_windows_overlap() {
+        let content = "one two three\nfour five\nsix seven";
+        let strategy = ChunkStrategy::TokenBudget { max_tokens: 3, overlap: 1 };
+        let chunks = chunk_document("/docs/b.txt", content, &[], &strategy);
+
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["one two three", "three\nfour five", "five\nsix seven"]);
+        assert_eq!((chunks[1].start_line, chunks[1].end_line), (1, 2));
+        assert_eq!(chunks, chunk_document("/docs/b.txt", content, &[], &strategy), "IDs are stable across runs");
+
+        let emission = chunk_emission("/docs/b.txt", &chunks);
+        assert_eq!(emission.nodes.len(), 3);
+        assert!(emission.edges.iter().all(|e| e.edge.source.as_str() == "file:/docs/b.txt"
+            && e.edge.relationship == "contains"));
+    }
+
+    // === Scenario: Chunks past a shorter re-chunking are stale ===
+    #[test]
+    fn stale_chunks_are_those_past_the_new_count() {
+        let content = "one two three four five six";
+        let strategy = ChunkStrategy::TokenBudget { max_tokens: 2, overlap: 0 };
+        let mut context = Context::new("docs");
+        for path in ["/docs/b.txt", "/docs/c.txt"] {
+            for node in chunk_emission(path, &chunk_document(path, content, &[], &strategy)).nodes {
+                context.add_node(node.node);
+            }
+        }
+
+        let mut stale = stale_chunks(&context, "/docs/b.txt", 1);
+        stale.sort_by_key(|id| id.to_string());
+        assert_eq!(stale, vec![NodeId::from_string("chunk:/docs/b.txt:1"), NodeId::from_string("chunk:/docs/b.txt:2")]);
+        assert!(stale_chunks(&context, "/docs/b.txt", 3).is_empty());
+    }
+}
@@ -8,8 +8,14 @@
 //! - A mark node (Provenance, provenance dimension) — source evidence for the fragment
 //! - A contains edge (chain → mark, within provenance)
 //!
+//! Tags pass through the context's `TagPolicy`, when one is set, before any
+//! concept is created; the mark records the tags that survived.
+//!
 //! All node IDs are deterministic. Re-ingesting the same fragment produces the same
-//! nodes, triggering upsert rather than creating duplicates.
+//! nodes, triggering upsert rather than creating duplicates. Fragments also carry a
+//! `content_hash` of adapter + source + text; with a `DedupPolicy` set, a re-ingest
+//! matching it is skipped, timestamped, or stored as a new version that `supersedes`
+//! the last.
 //!
 //! ## Shipped-adapter dimension conventions
 //!
@@ -32,8 +38,8 @@
 use crate::graph::events::GraphEvent;
 use crate::adapter::sink::{AdapterError, AdapterSink};
 use crate::adapter::traits::{Adapter, AdapterInput};
-use crate::adapter::types::{Emission, OutboundEvent, concept_node, rfc3339_now};
-use crate::graph::{dimension, ContentType, Context, Edge, Node, NodeId, PropertyValue};
+use crate::adapter::types::{DedupPolicy, Emission, OutboundEvent, PropertyUpdate, concept_node, rfc3339_now};
+use crate::graph::{dimension, ContentType, Context, Edge, Node, NodeId, PropertyValue, CONTENT_HASH_PROPERTY, SUPERSEDES};
 use async_trait::async_trait;
 use uuid::Uuid;
 
@@ -142,14 +148,32 @@ impl FragmentInput {
 /// adapter ID.
 pub struct ContentAdapter {
     adapter_id: String,
+    dedup: Option<DedupPolicy>,
 }
 
 impl ContentAdapter {
     pub fn new(adapter_id: impl Into<String>) -> Self {
         Self {
             adapter_id: adapter_id.into(),
+            dedup: None,
         }
     }
+
+    /// Deduplicate re-ingested fragments — same adapter, source, and
+    /// text — by `policy`. Without one, a re-ingest upserts the fragment
+    /// (same tags) or adds a sibling (different tags).
+    pub fn with_dedup(mut self, policy: DedupPolicy) -> Self {
+        self.dedup = Some(policy);
+        self
+    }
+}
+
+/// The version number a fragment node records, 1 when unversioned.
+fn fragment_version(node: &Node) -> i64 {
+    match node.properties.get("version") {
+        Some(PropertyValue::Int(v)) => *v,
+        _ => 1,
+    }
 }
 
 #[async_trait]
@@ -162,6 +186,28 @@ impl Adapter for ContentAdapter {
         "content"
     }
 
+    fn input_from_json(&self, json: &serde_json::Value) -> Result<Box<dyn std::any::Any + Send + Sync>, AdapterError> {
+        Ok(Box::new(FragmentInput::from_json(json)?))
+    }
+
+    fn input_schema(&self) -> Option<serde_json::Value> {
+        let optional = |kind: &str| serde_json::json!({"type": [kind, "null"]});
+        Some(serde_json::json!({
+            "type": "object",
+            "required": ["text"],
+            "properties": {
+                "text": {"type": "string"},
+                "tags": {"type": ["array", "null"], "items": {"type": "string"}},
+                "source": optional("string"),
+                "date": optional("string"),
+                "chain_name": optional("string"),
+                "file": optional("string"),
+                "line": {"type": ["integer", "null"], "minimum": 0, "maximum": u32::MAX},
+                "column": {"type": ["integer", "null"], "minimum": 0, "maximum": u32::MAX},
+            }
+        }))
+    }
+
     async fn process(
         &self,
         input: &AdapterInput,
@@ -194,9 +240,37 @@ impl Adapter for ContentAdapter {
             0x6b, 0xa7, 0xb8, 0x10, 0x9d, 0xad, 0x11, 0xd1,
             0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8,
         ]);
-        let fragment_id = NodeId::from_string(
+        let mut fragment_id = NodeId::from_string(
             format!("fragment:{}", Uuid::new_v5(&FRAGMENT_NS, hash_input.as_bytes()))
         );
+
+        // Dedup key: the same text from the same source, whatever its tags
+        let content_hash = Uuid::new_v5(
+            &FRAGMENT_NS,
+            format!("{}:{}:{}", self.adapter_id, fragment.source.as_deref().unwrap_or(""), fragment.text).as_bytes(),
+        )
+        .to_string();
+        let mut superseded: Option<(NodeId, i64)> = None;
+        if let Some(policy) = self.dedup {
+            let existing = sink.find_by_content_hash("fragment", &content_hash).await?;
+            if let Some(latest) = existing.iter().max_by_key(|n| fragment_version(n)) {
+                match policy {
+                    DedupPolicy::Skip => return Ok(()),
+                    DedupPolicy::UpdateTimestamp => {
+                        let update = PropertyUpdate::new(latest.id.clone())
+                            .with_property("last_ingested_at", rfc3339_now());
+                        sink.emit(Emission::new().with_property_update(update)).await?;
+                        return Ok(());
+                    }
+                    DedupPolicy::NewVersion => {
+                        let version = fragment_version(latest) + 1;
+                        fragment_id = NodeId::from_string(format!("{}:v{}", fragment_id, version));
+                        superseded = Some((latest.id.clone(), version));
+                    }
+                }
+            }
+        }
+
         let mut fragment_node =
             Node::new_in_dimension("fragment", ContentType::Document, dimension::STRUCTURE);
         fragment_node.id = fragment_id.clone();
@@ -204,6 +278,10 @@ impl Adapter for ContentAdapter {
             "text".to_string(),
             PropertyValue::String(fragment.text.clone()),
         );
+        fragment_node.properties.insert(
+            CONTENT_HASH_PROPERTY.to_string(),
+            PropertyValue::String(content_hash),
+        );
         // ADR-039: authoritative created_at on properties for temporal enrichments.
         fragment_node
             .properties
@@ -221,10 +299,28 @@ impl Adapter for ContentAdapter {
             );
         }
 
+        if let Some((_, version)) = superseded {
+            fragment_node.properties.insert("version".to_string(), PropertyValue::Int(version));
+        }
+
         let mut emission = Emission::new().with_node(fragment_node);
+        if let Some((previous, _)) = superseded {
+            let mut supersedes =
+                Edge::new_in_dimension(fragment_id.clone(), previous, SUPERSEDES, dimension::STRUCTURE);
+            supersedes.combined_weight = 1.0;
+            emission = emission.with_edge(supersedes);
+        }
+
+        // The context's tag policy decides which tags become concepts. The
+        // fragment ID above hashes the raw tags so it stays stable when the
+        // policy changes.
+        let tags = match input.tag_policy {
+            Some(ref policy) => policy.apply(&fragment.tags),
+            None => fragment.tags.clone(),
+        };
 
         // Build concept nodes and tagged_with edges
-        for tag in &fragment.tags {
+        for tag in &tags {
             let (concept_id, node) = concept_node(tag);
 
             // tagged_with edge: fragment → concept, cross-dimensional
@@ -309,9 +405,8 @@ impl Adapter for ContentAdapter {
                 PropertyValue::Int(col as i64),
             );
         }
-        if !fragment.tags.is_empty() {
-            let tag_vals: Vec<PropertyValue> = fragment
-                .tags
+        if !tags.is_empty() {
+            let tag_vals: Vec<PropertyValue> = tags
                 .iter()
                 .map(|t| PropertyValue::String(t.to_lowercase()))
                 .collect();
@@ -395,6 +490,7 @@ pub fn normalize_chain_name(name: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::graph::TagPolicy;
     use crate::adapter::EngineSink;
     use crate::adapter::FrameworkContext;
     use crate::graph::{dimension, Context};
@@ -411,6 +507,39 @@ mod tests {
         (sink, ctx)
     }
 
+    // === Scenario: Tag policy filters tags before concepts are created ===
+    #[tokio::test]
+    async fn tag_policy_filters_concepts_and_mark_tags() {
+        let adapter = ContentAdapter::new("manual-fragment");
+        let (sink, ctx) = make_sink("manual-fragment");
+
+        let policy = TagPolicy::new()
+            .with_stop("misc")
+            .with_rewrite("ml", "machine learning")
+            .with_max_tags(2);
+        let input = AdapterInput::new(
+            "content",
+            FragmentInput::new(
+                "Notes on models",
+                vec!["misc".to_string(), "ML".to_string(), "travel".to_string(), "paris".to_string()],
+            ),
+            "test",
+        )
+        .with_tag_policy(Some(policy));
+
+        adapter.process(&input, &sink).await.unwrap();
+
+        let ctx = ctx.lock().unwrap();
+        assert!(ctx.get_node(&NodeId::from_string("concept:misc")).is_none());
+        assert!(ctx.get_node(&NodeId::from_string("concept:machine learning")).is_some());
+        assert!(ctx.get_node(&NodeId::from_string("concept:travel")).is_some());
+        assert!(ctx.get_node(&NodeId::from_string("concept:paris")).is_none(), "capped at 2");
+        assert_eq!(ctx.edges.iter().filter(|e| e.relationship == "tagged_with").count(), 2);
+
+        let mark = ctx.nodes.values().find(|n| n.node_type == "mark").unwrap();
+        assert_eq!(mark.tags(), vec!["machine learning", "travel"]);
+    }
+
     // === Scenario: Single fragment with tags produces fragment node, concept nodes, and edges ===
     #[tokio::test]
     async fn single_fragment_with_tags() {
@@ -1100,4 +1229,73 @@ mod tests {
         chrono::DateTime::parse_from_rfc3339(&concept_ts)
             .expect("concept created_at must be parseable ISO-8601 UTC (RFC-3339)");
     }
+
+    fn fragments(ctx: &Context) -> Vec<&Node> {
+        ctx.nodes.values().filter(|n| n.node_type == "fragment").collect()
+    }
+
+    fn retagged(tags: &[&str]) -> AdapterInput {
+        AdapterInput::new(
+            "content",
+            FragmentInput::new("Walked through Avignon", tags.iter().map(|t| t.to_string()).collect())
+                .with_source("journal.md"),
+            "test",
+        )
+    }
+
+    // === Scenario: Skip policy ignores a re-ingest with new tags ===
+    #[tokio::test]
+    async fn dedup_skip_ignores_same_content() {
+        let adapter = ContentAdapter::new("manual-fragment").with_dedup(DedupPolicy::Skip);
+        let (sink, ctx) = make_sink("manual-fragment");
+
+        adapter.process(&retagged(&["travel"]), &sink).await.unwrap();
+        adapter.process(&retagged(&["travel", "france"]), &sink).await.unwrap();
+
+        let ctx = ctx.lock().unwrap();
+        assert_eq!(fragments(&ctx).len(), 1);
+        assert!(ctx.get_node(&NodeId::from_string("concept:france")).is_none());
+    }
+
+    // === Scenario: Update-timestamp policy touches the existing fragment ===
+    #[tokio::test]
+    async fn dedup_update_timestamp_touches_existing() {
+        let adapter = ContentAdapter::new("manual-fragment").with_dedup(DedupPolicy::UpdateTimestamp);
+        let (sink, ctx) = make_sink("manual-fragment");
+
+        adapter.process(&retagged(&["travel"]), &sink).await.unwrap();
+        assert!(!fragments(&ctx.lock().unwrap())[0].properties.contains_key("last_ingested_at"));
+        adapter.process(&retagged(&["travel", "france"]), &sink).await.unwrap();
+
+        let ctx = ctx.lock().unwrap();
+        let all = fragments(&ctx);
+        assert_eq!(all.len(), 1);
+        assert!(matches!(all[0].properties.get("last_ingested_at"), Some(PropertyValue::String(_))));
+    }
+
+    // === Scenario: New-version policy links versions by supersedes ===
+    #[tokio::test]
+    async fn dedup_new_version_supersedes_previous() {
+        let adapter = ContentAdapter::new("manual-fragment").with_dedup(DedupPolicy::NewVersion);
+        let (sink, ctx) = make_sink("manual-fragment");
+
+        for _ in 0..3 {
+            adapter.process(&retagged(&["travel"]), &sink).await.unwrap();
+        }
+
+        let ctx = ctx.lock().unwrap();
+        let all = fragments(&ctx);
+        assert_eq!(all.len(), 3);
+        let hash = all[0].properties.get(CONTENT_HASH_PROPERTY);
+        assert!(hash.is_some());
+        assert!(all.iter().all(|n| n.properties.get(CONTENT_HASH_PROPERTY) == hash), "every version shares the content hash");
+
+        let latest = all.iter().max_by_key(|n| fragment_version(n)).unwrap();
+        assert_eq!(fragment_version(latest), 3);
+        assert!(latest.id.as_str().ends_with(":v3"));
+        let supersedes: Vec<_> = ctx.edges().filter(|e| e.relationship == SUPERSEDES).collect();
+        assert_eq!(supersedes.len(), 2);
+        let previous = supersedes.iter().find(|e| e.source == latest.id).expect("v3 supersedes v2");
+        assert!(previous.target.as_str().ends_with(":v2"));
+    }
 }
@@ -0,0 +1,439 @@
+//! ConversationAdapter — chat transcript ingestion
+//!
+//! Maps a transcript (a sequence of speaker turns) to graph structure:
+//! - A conversation node (Document, structure dimension)
+//! - A turn node per turn (Document, structure dimension), `contains`ed by
+//!   the conversation; each turn `follows` the one before it
+//! - A speaker node per distinct speaker (relational dimension), which
+//!   each of their turns is `spoken_by`
+//! - A concept node per turn tag and a `tagged_with` edge from the turn,
+//!   tags passing the context's `TagPolicy` as content tags do
+//!
+//! Provenance: one chain per conversation and a mark per turn, the mark's
+//! line being the turn's position in the transcript.
+//!
+//! IDs are deterministic: a conversation without an explicit ID is named
+//! by a hash of its turns, so re-ingesting a transcript upserts it, and a
+//! transcript ingested again with more turns under the same ID extends it.
+
+use crate::adapter::sink::{AdapterError, AdapterSink};
+use crate::adapter::traits::{Adapter, AdapterInput};
+use crate::adapter::types::{chain_node, concept_node, mark_node, rfc3339_now, Emission, OutboundEvent};
+use crate::graph::events::GraphEvent;
+use crate::graph::{dimension, ContentType, Context, Edge, Node, NodeId, PropertyValue};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// One turn of a conversation.
+#[derive(Debug, Clone)]
+pub struct Turn {
+    pub speaker: String,
+    pub text: String,
+    /// Tags applied to the turn (each produces a concept node)
+    pub tags: Vec<String>,
+    /// When the turn was spoken, RFC 3339
+    pub timestamp: Option<String>,
+}
+
+impl Turn {
+    pub fn new(speaker: impl Into<String>, text: impl Into<String>) -> Self {
+        Self { speaker: speaker.into(), text: text.into(), tags: Vec::new(), timestamp: None }
+    }
+
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    pub fn with_timestamp(mut self, timestamp: impl Into<String>) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+
+    /// Parse one turn: `speaker` (or `role`, `author`) and `text` (or
+    /// `content`, a string or an array of `{"text": ...}` parts), with
+    /// optional `tags` and `timestamp` (or `time`).
+    fn from_json(json: &serde_json::Value) -> Option<Self> {
+        let field = |keys: &[&str]| keys.iter().find_map(|k| json.get(*k));
+        let speaker = field(&["speaker", "role", "author"])?.as_str()?.trim().to_string();
+        let text = match field(&["text", "content"])? {
+            serde_json::Value::String(s) => s.clone(),
+            serde_json::Value::Array(parts) => parts
+                .iter()
+                .filter_map(|p| p.as_str().or_else(|| p.get("text").and_then(|t| t.as_str())))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            _ => return None,
+        };
+        if speaker.is_empty() || text.trim().is_empty() {
+            return None;
+        }
+        let tags = json
+            .get("tags")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect())
+            .unwrap_or_default();
+        let timestamp = field(&["timestamp", "time"]).and_then(|v| v.as_str()).map(|s| s.to_string());
+        Some(Self { speaker, text, tags, timestamp })
+    }
+}
+
+/// Input data for the ConversationAdapter.
+#[derive(Debug, Clone)]
+pub struct ConversationInput {
+    /// Stable ID; derived from the turns when absent
+    pub conversation_id: Option<String>,
+    pub title: Option<String>,
+    /// Where the transcript came from (e.g., "slack", "agent-session")
+    pub source: Option<String>,
+    pub turns: Vec<Turn>,
+}
+
+impl ConversationInput {
+    pub fn new(turns: Vec<Turn>) -> Self {
+        Self { conversation_id: None, title: None, source: None, turns }
+    }
+
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.conversation_id = Some(id.into());
+        self
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Parse a JSON transcript.
+    ///
+    /// Required: `turns` or `messages` (array of turns, see below)
+    /// Optional: `conversation_id` (or `id`), `title`, `source` (strings)
+    ///
+    /// Each turn needs a speaker (`speaker`, `role`, or `author`) and text
+    /// (`text` or `content`); turns missing either are skipped.
+    pub fn from_json(json: &serde_json::Value) -> Result<Self, AdapterError> {
+        let turns = json
+            .get("turns")
+            .or_else(|| json.get("messages"))
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| AdapterError::Internal("conversation input requires a 'turns' or 'messages' array".into()))?
+            .iter()
+            .filter_map(Turn::from_json)
+            .collect();
+        let string = |key: &str| json.get(key).and_then(|v| v.as_str()).map(|s| s.to_string());
+        Ok(Self {
+            conversation_id: string("conversation_id").or_else(|| string("id")),
+            title: string("title"),
+            source: string("source"),
+            turns,
+        })
+    }
+}
+
+/// Chat transcript adapter.
+pub struct ConversationAdapter {
+    adapter_id: String,
+}
+
+impl ConversationAdapter {
+    pub fn new(adapter_id: impl Into<String>) -> Self {
+        Self { adapter_id: adapter_id.into() }
+    }
+
+    /// The conversation's ID: given, or a hash of its turns.
+    fn conversation_id(&self, conversation: &ConversationInput) -> String {
+        if let Some(ref id) = conversation.conversation_id {
+            return id.clone();
+        }
+        // UUID v5 namespace for Plexus conversations (stable, arbitrary)
+        const CONVERSATION_NS: Uuid = Uuid::from_bytes([
+            0x3c, 0x1e, 0x5f, 0x2a, 0x7b, 0x44, 0x4d, 0x0e,
+            0x9a, 0x61, 0x2f, 0x8d, 0x10, 0xc3, 0x55, 0xe7,
+        ]);
+        let mut hash_input = self.adapter_id.clone();
+        for turn in &conversation.turns {
+            hash_input.push('\u{1e}');
+            hash_input.push_str(&turn.speaker);
+            hash_input.push('\u{1f}');
+            hash_input.push_str(&turn.text);
+        }
+        Uuid::new_v5(&CONVERSATION_NS, hash_input.as_bytes()).to_string()
+    }
+}
+
+/// A speaker's node ID: `speaker:{lowercased name}`.
+pub fn speaker_id(speaker: &str) -> NodeId {
+    NodeId::from_string(format!("speaker:{}", speaker.trim().to_lowercase()))
+}
+
+#[async_trait]
+impl Adapter for ConversationAdapter {
+    fn id(&self) -> &str {
+        &self.adapter_id
+    }
+
+    fn input_kind(&self) -> &str {
+        "conversation"
+    }
+
+    fn input_from_json(&self, json: &serde_json::Value) -> Result<Box<dyn std::any::Any + Send + Sync>, AdapterError> {
+        Ok(Box::new(ConversationInput::from_json(json)?))
+    }
+
+    fn input_schema(&self) -> Option<serde_json::Value> {
+        let turns = serde_json::json!({"type": "array", "items": {"type": "object"}});
+        Some(serde_json::json!({
+            "type": "object",
+            "anyOf": [{"required": ["turns"]}, {"required": ["messages"]}],
+            "properties": {
+                "turns": turns,
+                "messages": turns,
+                "conversation_id": {"type": "string"},
+                "id": {"type": "string"},
+                "title": {"type": "string"},
+                "source": {"type": "string"},
+            }
+        }))
+    }
+
+    async fn process(
+        &self,
+        input: &AdapterInput,
+        sink: &dyn AdapterSink,
+    ) -> Result<(), AdapterError> {
+        // Accept both typed ConversationInput and raw JSON
+        let owned: ConversationInput;
+        let conversation: &ConversationInput = if let Some(c) = input.downcast_data::<ConversationInput>() {
+            c
+        } else if let Some(json) = input.downcast_data::<serde_json::Value>() {
+            owned = ConversationInput::from_json(json)?;
+            &owned
+        } else {
+            return Err(AdapterError::InvalidInput);
+        };
+        if conversation.turns.is_empty() {
+            return Err(AdapterError::Internal("conversation has no turns".into()));
+        }
+
+        let conversation_key = self.conversation_id(conversation);
+        let conversation_id = NodeId::from_string(format!("conversation:{}", conversation_key));
+        let mut conversation_node =
+            Node::new_in_dimension("conversation", ContentType::Document, dimension::STRUCTURE);
+        conversation_node.id = conversation_id.clone();
+        conversation_node
+            .properties
+            .insert("turn_count".to_string(), PropertyValue::Int(conversation.turns.len() as i64));
+        conversation_node.properties.insert("created_at".to_string(), rfc3339_now());
+        if let Some(ref title) = conversation.title {
+            conversation_node.properties.insert("title".to_string(), PropertyValue::String(title.clone()));
+        }
+        if let Some(ref source) = conversation.source {
+            conversation_node.properties.insert("source".to_string(), PropertyValue::String(source.clone()));
+        }
+
+        let chain_id = format!("chain:{}:{}", self.adapter_id, conversation_key);
+        let mut chain = chain_node(&chain_id);
+        let chain_name = conversation
+            .title
+            .clone()
+            .unwrap_or_else(|| format!("{} — {}", self.adapter_id, conversation_key));
+        chain.properties.insert("name".to_string(), PropertyValue::String(chain_name));
+        chain.properties.insert("status".to_string(), PropertyValue::String("active".to_string()));
+
+        let mut emission = Emission::new().with_node(conversation_node).with_node(chain);
+        let mut previous: Option<NodeId> = None;
+        for (index, turn) in conversation.turns.iter().enumerate() {
+            let position = index + 1;
+            let turn_id = NodeId::from_string(format!("turn:{}:{}", conversation_key, position));
+            let mut turn_node = Node::new_in_dimension("turn", ContentType::Document, dimension::STRUCTURE);
+            turn_node.id = turn_id.clone();
+            turn_node.properties.insert("text".to_string(), PropertyValue::String(turn.text.clone()));
+            turn_node.properties.insert("speaker".to_string(), PropertyValue::String(turn.speaker.clone()));
+            turn_node.properties.insert("index".to_string(), PropertyValue::Int(position as i64));
+            // ADR-039: created_at drives temporal enrichments — the turn's
+            // own time when the transcript has one
+            let created_at = match turn.timestamp {
+                Some(ref ts) => PropertyValue::String(ts.clone()),
+                None => rfc3339_now(),
+            };
+            turn_node.properties.insert("created_at".to_string(), created_at);
+
+            let mut speaker = Node::new_in_dimension(
+                "speaker",
+                ContentType::Other("speaker".to_string()),
+                dimension::RELATIONAL,
+            );
+            speaker.id = speaker_id(&turn.speaker);
+            speaker.properties.insert("name".to_string(), PropertyValue::String(turn.speaker.clone()));
+            let spoken_by = Edge::new_cross_dimensional(
+                turn_id.clone(),
+                dimension::STRUCTURE,
+                speaker.id.clone(),
+                dimension::RELATIONAL,
+                "spoken_by",
+            );
+            let contains =
+                Edge::new_in_dimension(conversation_id.clone(), turn_id.clone(), "contains", dimension::STRUCTURE);
+            emission = emission.with_node(turn_node).with_node(speaker).with_edge(spoken_by).with_edge(contains);
+            if let Some(before) = previous.replace(turn_id.clone()) {
+                emission = emission.with_edge(Edge::new_in_dimension(
+                    turn_id.clone(),
+                    before,
+                    "follows",
+                    dimension::STRUCTURE,
+                ));
+            }
+
+            let tags = match input.tag_policy {
+                Some(ref policy) => policy.apply(&turn.tags),
+                None => turn.tags.clone(),
+            };
+            for tag in &tags {
+                let (concept_id, node) = concept_node(tag);
+                let mut edge = Edge::new_cross_dimensional(
+                    turn_id.clone(),
+                    dimension::STRUCTURE,
+                    concept_id,
+                    dimension::SEMANTIC,
+                    "tagged_with",
+                );
+                edge.combined_weight = 1.0;
+                emission = emission.with_node(node).with_edge(edge);
+            }
+
+            // Provenance: the turn's place in the transcript
+            let mark_id = format!("mark:{}:{}", self.adapter_id, turn_id);
+            let mut mark = mark_node(&mark_id);
+            mark.properties.insert("chain_id".to_string(), PropertyValue::String(chain_id.clone()));
+            mark.properties.insert(
+                "annotation".to_string(),
+                PropertyValue::String(format!("{}: {}", turn.speaker, turn.text)),
+            );
+            let file = conversation.source.clone().unwrap_or_else(|| conversation_id.to_string());
+            mark.properties.insert("file".to_string(), PropertyValue::String(file));
+            mark.properties.insert("line".to_string(), PropertyValue::Int(position as i64));
+            if !tags.is_empty() {
+                let tag_vals = tags.iter().map(|t| PropertyValue::String(t.to_lowercase())).collect();
+                mark.properties.insert("tags".to_string(), PropertyValue::Array(tag_vals));
+            }
+            let contains_mark = Edge::new_in_dimension(
+                NodeId::from_string(&chain_id),
+                mark.id.clone(),
+                "contains",
+                dimension::PROVENANCE,
+            );
+            emission = emission.with_node(mark).with_edge(contains_mark);
+        }
+
+        sink.emit(emission).await?;
+        Ok(())
+    }
+
+    fn transform_events(&self, events: &[GraphEvent], _context: &Context) -> Vec<OutboundEvent> {
+        let mut outbound = Vec::new();
+        for event in events {
+            if let GraphEvent::NodesAdded { node_ids, adapter_id, .. } = event {
+                if adapter_id != &self.adapter_id {
+                    continue;
+                }
+                for id in node_ids.iter().filter(|id| id.as_str().starts_with("conversation:")) {
+                    outbound.push(OutboundEvent::new("conversation_indexed", id.to_string()));
+                }
+                let speakers: Vec<&str> =
+                    node_ids.iter().filter_map(|id| id.as_str().strip_prefix("speaker:")).collect();
+                if !speakers.is_empty() {
+                    outbound.push(OutboundEvent::new("speakers_detected", speakers.join(", ")));
+                }
+            }
+        }
+        outbound
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::{EngineSink, FrameworkContext};
+    use std::sync::{Arc, Mutex};
+
+    fn make_sink(adapter_id: &str) -> (EngineSink, Arc<Mutex<Context>>) {
+        let ctx = Arc::new(Mutex::new(Context::new("test")));
+        let fw = FrameworkContext {
+            adapter_id: adapter_id.to_string(),
+            context_id: "test".to_string(),
+            input_summary: None,
+        };
+        let sink = EngineSink::new(ctx.clone()).with_framework_context(fw);
+        (sink, ctx)
+    }
+
+    fn edge<'a>(ctx: &'a Context, source: &str, relationship: &str) -> Vec<&'a str> {
+        ctx.edges
+            .iter()
+            .filter(|e| e.source.as_str() == source && e.relationship == relationship)
+            .map(|e| e.target.as_str())
+            .collect()
+    }
+
+    // === Scenario: Turns are ordered, attributed, and tagged ===
+    #[tokio::test]
+    async fn transcript_becomes_turns_speakers_and_concepts() {
+        let adapter = ConversationAdapter::new("conversation");
+        let (sink, ctx) = make_sink("conversation");
+        let input = ConversationInput::new(vec![
+            Turn::new("Ada", "Should the index be incremental?").with_tags(vec!["indexing".into()]),
+            Turn::new("agent", "Yes — rebuild on removals only.").with_timestamp("2026-03-01T10:00:00Z"),
+            Turn::new("ada", "Agreed.").with_tags(vec!["Indexing".into(), "decisions".into()]),
+        ])
+        .with_id("design-chat")
+        .with_title("Index design");
+
+        adapter.process(&AdapterInput::new("conversation", input, "test"), &sink).await.unwrap();
+
+        let ctx = ctx.lock().unwrap();
+        assert_eq!(edge(&ctx, "conversation:design-chat", "contains").len(), 3);
+        assert_eq!(edge(&ctx, "turn:design-chat:2", "follows"), vec!["turn:design-chat:1"]);
+        assert!(edge(&ctx, "turn:design-chat:1", "follows").is_empty(), "the first turn follows nothing");
+        assert_eq!(edge(&ctx, "turn:design-chat:3", "spoken_by"), vec!["speaker:ada"], "speakers fold case");
+        assert_eq!(ctx.nodes.values().filter(|n| n.node_type == "speaker").count(), 2);
+        assert_eq!(edge(&ctx, "turn:design-chat:3", "tagged_with").len(), 2);
+        assert!(ctx.get_node(&NodeId::from_string("concept:indexing")).is_some());
+
+        let turn = ctx.get_node(&NodeId::from_string("turn:design-chat:2")).unwrap();
+        assert_eq!(turn.properties.get("created_at"), Some(&PropertyValue::from("2026-03-01T10:00:00Z")));
+        let marks: Vec<_> = ctx.nodes.values().filter(|n| n.node_type == "mark").collect();
+        assert_eq!(marks.len(), 3);
+        assert_eq!(edge(&ctx, "chain:conversation:design-chat", "contains").len(), 3);
+    }
+
+    // === Scenario: JSON chat logs in either common shape ===
+    #[tokio::test]
+    async fn json_transcripts_parse_and_reingest_idempotently() {
+        let json = serde_json::json!({
+            "messages": [
+                {"role": "user", "content": "Where are the notes?"},
+                {"role": "assistant", "content": [{"type": "text", "text": "In docs/."}]},
+                {"role": "system"}
+            ]
+        });
+        let parsed = ConversationInput::from_json(&json).unwrap();
+        assert_eq!(parsed.turns.len(), 2, "turns without text are skipped");
+        assert_eq!(parsed.turns[1].text, "In docs/.");
+        assert!(ConversationInput::from_json(&serde_json::json!({"text": "x"})).is_err());
+
+        let adapter = ConversationAdapter::new("conversation");
+        let (sink, ctx) = make_sink("conversation");
+        for _ in 0..2 {
+            let input = AdapterInput::new("conversation", json.clone(), "test");
+            adapter.process(&input, &sink).await.unwrap();
+        }
+        let ctx = ctx.lock().unwrap();
+        assert_eq!(ctx.nodes.values().filter(|n| n.node_type == "conversation").count(), 1);
+        assert_eq!(ctx.nodes.values().filter(|n| n.node_type == "turn").count(), 2);
+    }
+}
@@ -126,6 +126,10 @@ pub struct CreateNodePrimitive {
     pub id: IdStrategy,
     #[serde(rename = "type")]
     pub node_type: String,
+    /// Explicit content type; any name outside the built-ins becomes
+    /// `ContentType::Other`. Defaults to inference from `node_type`.
+    #[serde(default)]
+    pub content_type: Option<String>,
     pub dimension: String,
     #[serde(default)]
     pub properties: HashMap<String, String>,
@@ -211,6 +215,11 @@ pub struct EnrichmentDeclaration {
     pub model_name: Option<String>,
     /// Similarity threshold for embedding_similarity enrichment (ADR-026).
     pub similarity_threshold: Option<f32>,
+    /// Keywords proposed per node for keyword_extraction.
+    pub max_keywords: Option<usize>,
+    /// Pattern and output for a `rule` enrichment.
+    #[serde(default)]
+    pub rule: Option<crate::adapter::rule::Rule>,
 }
 
 // ---------------------------------------------------------------------------
@@ -476,6 +485,47 @@ impl DeclarativeAdapter {
                     }
                     Arc::new(enrichment)
                 }
+                "keyword_extraction" => {
+                    let mut enrichment = crate::adapter::keyword::KeywordExtractionEnrichment::new();
+                    if let Some(ref output) = decl.output_relationship {
+                        enrichment = enrichment.with_output_relationship(output);
+                    }
+                    if let Some(ref types) = decl.node_types {
+                        enrichment = enrichment.with_node_types(types.clone());
+                    }
+                    if let Some(max) = decl.max_keywords {
+                        enrichment = enrichment.with_max_keywords(max);
+                    }
+                    Arc::new(enrichment)
+                }
+                "citation" => {
+                    let mut enrichment = crate::adapter::citation::CitationEnrichment::new();
+                    if let Some(ref types) = decl.node_types {
+                        enrichment = enrichment.with_node_types(types.clone());
+                    }
+                    Arc::new(enrichment)
+                }
+                "near_duplicate" => {
+                    let mut enrichment = crate::adapter::near_duplicate::NearDuplicateEnrichment::new();
+                    if let Some(threshold) = decl.similarity_threshold {
+                        enrichment = enrichment.with_threshold(threshold as f64);
+                    }
+                    if let Some(ref types) = decl.node_types {
+                        enrichment = enrichment.with_node_types(types.clone());
+                    }
+                    Arc::new(enrichment)
+                }
+                "rule" => {
+                    let rule = decl.rule.clone().ok_or_else(|| {
+                        AdapterError::Internal("rule enrichment requires rule".into())
+                    })?;
+                    if rule.steps.is_empty() {
+                        return Err(AdapterError::Internal(
+                            format!("rule '{}' requires at least one step", rule.id),
+                        ));
+                    }
+                    Arc::new(crate::adapter::rule::RuleEnrichment::new(rule))
+                }
                 "embedding_similarity" => {
                     let model_name = decl.model_name.as_deref().ok_or_else(|| {
                         AdapterError::Internal("embedding_similarity enrichment requires model_name".into())
@@ -874,7 +924,10 @@ fn interpret_create_node(
 ) -> Result<Node, AdapterError> {
     let node_id = resolve_id(&cn.id, ctx)?;
     validate_dimension_syntax(&cn.dimension)?;
-    let content_type = resolve_content_type(&cn.node_type);
+    let content_type = match &cn.content_type {
+        Some(name) => ContentType::custom(name),
+        None => resolve_content_type(&cn.node_type),
+    };
 
     let mut node = Node::new_in_dimension(&cn.node_type, content_type, &cn.dimension);
     node.id = NodeId::from_string(node_id);
@@ -1137,6 +1190,7 @@ mod tests {
             emit: vec![Primitive::CreateNode(CreateNodePrimitive {
                 id: IdStrategy::Template("artifact:{input.file_path}".to_string()),
                 node_type: "artifact".to_string(),
+                content_type: None,
                 dimension: "structure".to_string(),
                 properties: HashMap::from([
                     ("mime_type".to_string(), "{input.mime_type}".to_string()),
@@ -1182,6 +1236,7 @@ mod tests {
             emit: vec![Primitive::CreateNode(CreateNodePrimitive {
                 id: IdStrategy::Template("artifact:{input.file_path}".to_string()),
                 node_type: "artifact".to_string(),
+                content_type: None,
                 dimension: "structure".to_string(),
                 properties: HashMap::from([(
                     "mime_type".to_string(),
@@ -1234,6 +1289,7 @@ mod tests {
             emit: vec![Primitive::CreateNode(CreateNodePrimitive {
                 id: IdStrategy::Template("artifact:{input.file_path}".to_string()),
                 node_type: "artifact".to_string(),
+                content_type: None,
                 dimension: "structure".to_string(),
                 properties: HashMap::from([(
                     "created_at".to_string(),
@@ -1279,6 +1335,7 @@ mod tests {
                 Primitive::CreateNode(CreateNodePrimitive {
                     id: IdStrategy::Template("item:source".to_string()),
                     node_type: "item".to_string(),
+                    content_type: None,
                     dimension: "structure".to_string(),
                     properties: HashMap::new(),
                 }),
@@ -1290,6 +1347,7 @@ mod tests {
                         Primitive::CreateNode(CreateNodePrimitive {
                             id: IdStrategy::Template("concept:{input.tag}".to_string()),
                             node_type: "concept".to_string(),
+                            content_type: None,
                             dimension: "semantic".to_string(),
                             properties: HashMap::new(),
                         }),
@@ -1439,6 +1497,42 @@ emit:
         );
     }
 
+    // --- Scenario: create_node declares a custom content type ---
+
+    #[tokio::test]
+    async fn create_node_honors_declared_content_type() {
+        let yaml = r#"
+adapter_id: chat-log
+input_kind: chat.message
+emit:
+  - create_node:
+      id: "message:{input.id}"
+      type: message
+      content_type: chat-log
+      dimension: structure
+  - create_node:
+      id: "speaker:{input.speaker}"
+      type: agent
+      dimension: structure
+"#;
+        let adapter = DeclarativeAdapter::from_yaml(yaml).unwrap();
+        let ctx = Arc::new(Mutex::new(Context::new("test")));
+        let sink = test_sink(ctx.clone());
+        let input = AdapterInput::new(
+            "chat.message",
+            serde_json::json!({"id": "m1", "speaker": "ada"}),
+            "test",
+        );
+        adapter.process(&input, &sink).await.unwrap();
+
+        let snapshot = ctx.lock().unwrap();
+        let message = snapshot.get_node(&NodeId::from_string("message:m1")).unwrap();
+        assert_eq!(message.content_type, ContentType::Other("chat-log".to_string()));
+        // Without a declaration, content type is still inferred from node type
+        let speaker = snapshot.get_node(&NodeId::from_string("speaker:ada")).unwrap();
+        assert_eq!(speaker.content_type, ContentType::Agent);
+    }
+
     // --- Scenario: DeclarativeAdapter interprets hash_id for deterministic node IDs ---
 
     #[tokio::test]
@@ -1456,6 +1550,7 @@ emit:
                     "{input.file_path}".to_string(),
                 ] },
                 node_type: "artifact".to_string(),
+                content_type: None,
                 dimension: "structure".to_string(),
                 properties: HashMap::from([
                     ("path".to_string(), "{input.file_path}".to_string()),
@@ -1514,6 +1609,7 @@ emit:
                 Primitive::CreateNode(CreateNodePrimitive {
                     id: IdStrategy::Template("concept:{input.topic}".to_string()),
                     node_type: "concept".to_string(),
+                    content_type: None,
                     dimension: "semantic".to_string(),
                     properties: HashMap::new(),
                 }),
@@ -1565,7 +1661,7 @@ emit:
             mark.properties.get("annotation"),
             Some(&PropertyValue::String("Design notes".to_string()))
         );
-        assert!(mark.properties.get("tags").is_some(), "mark should have tags");
+        assert!(mark.properties.contains_key("tags"), "mark should have tags");
 
         // Contains edge: chain → mark
         let has_contains = snapshot.edges().any(|e| {
@@ -1601,6 +1697,7 @@ emit:
             emit: vec![Primitive::CreateNode(CreateNodePrimitive {
                 id: IdStrategy::Template("artifact:{input.file_path}".to_string()),
                 node_type: "artifact".to_string(),
+                content_type: None,
                 dimension: "structure".to_string(),
                 properties: HashMap::new(),
             })],
@@ -2159,6 +2256,106 @@ emit:
         assert_eq!(enrichments[0].id(), "co_occurrence:exhibits:co_exhibited");
     }
 
+    // --- Scenario: keyword_extraction enrichment declared in spec ---
+
+    #[test]
+    fn exposes_keyword_extraction_enrichment() {
+        let yaml = r#"
+adapter_id: test-adapter
+input_kind: test.input
+enrichments:
+  - type: keyword_extraction
+    output_relationship: mentions
+    max_keywords: 5
+emit:
+  - create_node:
+      id: "concept:{input.tag}"
+      type: concept
+      dimension: semantic
+"#;
+
+        let adapter = DeclarativeAdapter::from_yaml(yaml).unwrap();
+        let enrichments = adapter.enrichments().unwrap();
+        assert_eq!(enrichments.len(), 1);
+        assert_eq!(enrichments[0].id(), "keyword_extraction:mentions");
+    }
+
+    // --- Scenario: citation enrichment declared in spec ---
+
+    #[test]
+    fn exposes_citation_enrichment() {
+        let yaml = r#"
+adapter_id: test-adapter
+input_kind: test.input
+enrichments:
+  - type: citation
+    node_types: [note]
+emit:
+  - create_node:
+      id: "concept:{input.tag}"
+      type: concept
+      dimension: semantic
+"#;
+
+        let adapter = DeclarativeAdapter::from_yaml(yaml).unwrap();
+        let enrichments = adapter.enrichments().unwrap();
+        assert_eq!(enrichments.len(), 1);
+        assert_eq!(enrichments[0].id(), "citation:cites");
+    }
+
+    // --- Scenario: near-duplicate enrichment declared in spec ---
+
+    #[test]
+    fn exposes_near_duplicate_enrichment() {
+        let yaml = r#"
+adapter_id: test-adapter
+input_kind: test.input
+enrichments:
+  - type: near_duplicate
+    similarity_threshold: 0.9
+emit:
+  - create_node:
+      id: "concept:{input.tag}"
+      type: concept
+      dimension: semantic
+"#;
+
+        let adapter = DeclarativeAdapter::from_yaml(yaml).unwrap();
+        let enrichments = adapter.enrichments().unwrap();
+        assert_eq!(enrichments.len(), 1);
+        assert_eq!(enrichments[0].id(), "near_duplicate:duplicate_of");
+    }
+
+    // --- Scenario: rule enrichment declared in spec ---
+
+    #[test]
+    fn exposes_rule_enrichment() {
+        let yaml = r#"
+adapter_id: test-adapter
+input_kind: test.input
+enrichments:
+  - type: rule
+    rule:
+      id: cross_chain_reading
+      start: { node_type: mark }
+      steps:
+        - { relationship: references, direction: outgoing, node: { node_type: concept } }
+        - { relationship: references, direction: incoming, node: { node_type: mark } }
+      differ_on: [chain_id]
+      output_relationship: suggests_reading
+emit:
+  - create_node:
+      id: "concept:{input.tag}"
+      type: concept
+      dimension: semantic
+"#;
+
+        let adapter = DeclarativeAdapter::from_yaml(yaml).unwrap();
+        let enrichments = adapter.enrichments().unwrap();
+        assert_eq!(enrichments.len(), 1);
+        assert_eq!(enrichments[0].id(), "rule:cross_chain_reading");
+    }
+
     // --- Scenario: Unknown enrichment type is rejected ---
 
     #[test]
@@ -2305,6 +2502,7 @@ emit:
                 Primitive::CreateNode(CreateNodePrimitive {
                     id: IdStrategy::Template("concept:{input.name | lowercase}".to_string()),
                     node_type: "concept".to_string(),
+                    content_type: None,
                     dimension: "semantic".to_string(),
                     properties: HashMap::new(),
                 }),
@@ -2395,6 +2593,7 @@ emit:
                         Primitive::CreateNode(CreateNodePrimitive {
                             id: IdStrategy::Template("concept:{input.item.label | lowercase}".to_string()),
                             node_type: "concept".to_string(),
+                            content_type: None,
                             dimension: "semantic".to_string(),
                             properties: HashMap::new(),
                         }),
@@ -2486,6 +2685,7 @@ emit:
                 Primitive::CreateNode(CreateNodePrimitive {
                     id: IdStrategy::Template("concept:{input.name | lowercase}".to_string()),
                     node_type: "concept".to_string(),
+                    content_type: None,
                     dimension: "semantic".to_string(),
                     properties: HashMap::new(),
                 }),
@@ -2539,6 +2739,7 @@ emit:
                         Primitive::CreateNode(CreateNodePrimitive {
                             id: IdStrategy::Template("concept:{input.item.label | lowercase}".to_string()),
                             node_type: "concept".to_string(),
+                            content_type: None,
                             dimension: "semantic".to_string(),
                             properties: HashMap::new(),
                         }),
@@ -5,12 +5,19 @@
 //! tokio tasks.
 //!
 //! Registration (instant, blocking):
-//!   File node (MIME type, size, path) + concept nodes from YAML frontmatter
+//!   File node (MIME type, size, path) + concept nodes from YAML frontmatter.
+//!   With a `FrontmatterMapping`, frontmatter also types the file node,
+//!   records its aliases, and relates it to other documents.
 //!
 //! Structural analysis (moderate, background):
 //!   MIME-dispatched fan-out to registered structural modules (ADR-030).
 //!   Coordinator reads file once, dispatches to all matching modules,
-//!   merges outputs (Invariant 53), emits module emissions.
+//!   merges outputs (Invariant 53), emits module emissions. With
+//!   `with_colocation_edges`, nodes that different modules derive from
+//!   overlapping line regions are linked across dimensions. With
+//!   `with_link_resolution`, document links become `links_to` edges.
+//!   Module failures are recorded per file on the extraction-status node
+//!   (see `AnalysisFailurePolicy` and `files_needing_reanalysis`).
 //!
 //! Semantic extraction (slow, background, LLM):
 //!   Abstract concept extraction via llm-orc (ADR-021).
@@ -31,20 +38,35 @@
 //!
 //! See `docs/references/spec-author-guide.md` §"Shipped-adapter conventions".
 
+use crate::adapter::chunking::{chunk_document, chunk_emission, stale_chunks, ChunkStrategy, CHUNKING_ID};
 use crate::adapter::EngineSink;
 use crate::adapter::FrameworkContext;
+use crate::adapter::links::{
+    resolve_links as resolve_links_in, resolve_links_as, LinkKind, LinkReference, ALIASES_PROPERTY, LINK_RESOLUTION_ID,
+};
 use crate::adapter::semantic::SemanticInput;
 use crate::adapter::sink::{AdapterError, AdapterSink};
-use crate::adapter::structural::{StructuralModule, StructuralOutput};
+use crate::adapter::structural::{
+    colocated_edges, AnalysisFailure, AnalysisFailurePolicy, StructuralModule, StructuralOutput, COLOCATION_MODULE_ID,
+};
 use crate::adapter::traits::{Adapter, AdapterInput};
 use crate::adapter::types::{AnnotatedEdge, AnnotatedNode, Emission, OutboundEvent, concept_node, rfc3339_now};
-use crate::graph::{dimension, ContentType, Context, Edge, Node, NodeId, PropertyValue};
+use crate::graph::{
+    dimension, file_content_hash, file_inode, ContentType, Context, Edge, Node, NodeId, PropertyValue, CONTENT_HASH_PROPERTY,
+    INODE_PROPERTY,
+};
 use async_trait::async_trait;
 use serde_json::Value;
 use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::Mutex as TokioMutex;
 
+/// Handle to a spawned background extraction phase.
+type BackgroundTask = tokio::task::JoinHandle<Result<(), AdapterError>>;
+
+/// Frontmatter-related documents as `(relationship, link)` pairs.
+type RelatedDocuments = Vec<(String, LinkReference)>;
+
 /// Input for the extraction coordinator.
 #[derive(Debug, Clone)]
 pub struct ExtractFileInput {
@@ -70,6 +92,9 @@ pub struct ExtractionCoordinator {
     /// Registered structural modules — dispatched by MIME affinity (ADR-030).
     /// Fan-out: all matching modules run for each file (Invariant 51).
     structural_modules: Vec<Arc<dyn StructuralModule>>,
+    /// Content types assigned to file nodes by MIME prefix. Files with no
+    /// matching registration are typed `ContentType::Document`.
+    content_types: Vec<(String, ContentType)>,
     /// Semantic extraction adapter — runs after structural analysis
     semantic_adapter: Option<Arc<dyn Adapter>>,
     /// Shared context for creating background phase sinks (test path, no persistence)
@@ -84,13 +109,26 @@ pub struct ExtractionCoordinator {
     /// Concurrency semaphore for semantic extraction tasks
     semantic_semaphore: Arc<tokio::sync::Semaphore>,
     /// Background task handles (for testing / coordination)
-    background_tasks: Arc<TokioMutex<Vec<tokio::task::JoinHandle<Result<(), AdapterError>>>>>,
+    background_tasks: Arc<TokioMutex<Vec<BackgroundTask>>>,
     /// Shared handle to the owning pipeline's enrichment registry
     /// (issue #5): background phases run the enrichment loop over their
     /// emissions with whatever enrichments — including consumer lenses —
     /// the pipeline currently holds. None (direct construction, tests,
     /// mutex path) preserves the old no-loop behavior.
     enrichment_cell: Option<Arc<std::sync::RwLock<Arc<crate::adapter::enrichment::EnrichmentRegistry>>>>,
+    /// Relationship for co-location edges between module outputs; None
+    /// leaves module emissions unlinked
+    colocation_relationship: Option<String>,
+    /// Resolve modules' document links into `links_to` edges
+    resolve_links: bool,
+    /// Frontmatter keys that type the file node and relate it to other
+    /// documents; None reads only `tags`
+    frontmatter_mapping: Option<FrontmatterMapping>,
+    /// Split documents into chunk nodes for semantic extraction; None
+    /// extracts from the whole file
+    chunking: Option<ChunkStrategy>,
+    /// Whether a failing structural module stops the file's analysis
+    failure_policy: AnalysisFailurePolicy,
 }
 
 impl Default for ExtractionCoordinator {
@@ -103,6 +141,7 @@ impl ExtractionCoordinator {
     pub fn new() -> Self {
         Self {
             structural_modules: Vec::new(),
+            content_types: Vec::new(),
             semantic_adapter: None,
             shared_context: None,
             engine: None,
@@ -110,6 +149,11 @@ impl ExtractionCoordinator {
             semantic_semaphore: Arc::new(tokio::sync::Semaphore::new(2)),
             background_tasks: Arc::new(TokioMutex::new(Vec::new())),
             enrichment_cell: None,
+            colocation_relationship: None,
+            resolve_links: false,
+            frontmatter_mapping: None,
+            chunking: None,
+            failure_policy: AnalysisFailurePolicy::default(),
         }
     }
 
@@ -139,6 +183,74 @@ impl ExtractionCoordinator {
         self
     }
 
+    /// Link nodes that different structural modules derive from the same
+    /// file region with cross-dimensional `relationship` edges (see
+    /// `structural::colocated_edges`).
+    pub fn with_colocation_edges(mut self, relationship: impl Into<String>) -> Self {
+        self.colocation_relationship = Some(relationship.into());
+        self
+    }
+
+    /// Turn the document links structural modules report into `links_to`
+    /// edges, resolved against the context (see `links::resolve_links`).
+    pub fn with_link_resolution(mut self) -> Self {
+        self.resolve_links = true;
+        self
+    }
+
+    /// Read frontmatter keys beyond `tags` as graph structure (see
+    /// `FrontmatterMapping`).
+    pub fn with_frontmatter_mapping(mut self, mapping: FrontmatterMapping) -> Self {
+        self.frontmatter_mapping = Some(mapping);
+        self
+    }
+
+    /// Split each document into chunk nodes (see `chunking`) and run
+    /// semantic extraction per chunk, so concepts attach to the chunk
+    /// they were found in.
+    pub fn with_chunking(mut self, strategy: ChunkStrategy) -> Self {
+        self.chunking = Some(strategy);
+        self
+    }
+
+    /// Choose between collecting structural module failures (the default)
+    /// and stopping a file's analysis at the first one.
+    pub fn with_failure_policy(mut self, policy: AnalysisFailurePolicy) -> Self {
+        self.failure_policy = policy;
+        self
+    }
+
+    /// Edges for frontmatter-related documents, resolved against the
+    /// context when a backend is configured.
+    fn resolve_related(
+        &self,
+        file_path: &str,
+        context_id: &str,
+        related: &[(String, LinkReference)],
+    ) -> Result<Emission, AdapterError> {
+        let resolve = |ctx: &Context| {
+            let mut relationships: Vec<&String> = related.iter().map(|(r, _)| r).collect();
+            relationships.dedup();
+            relationships.into_iter().fold(Emission::new(), |mut acc, relationship| {
+                let links: Vec<LinkReference> =
+                    related.iter().filter(|(r, _)| r == relationship).map(|(_, l)| l.clone()).collect();
+                let emission = resolve_links_as(file_path, &links, ctx, relationship);
+                acc.nodes.extend(emission.nodes);
+                acc.edges.extend(emission.edges);
+                acc
+            })
+        };
+        if let Some(ref engine) = self.engine {
+            let ctx_id = crate::graph::ContextId::from_string(context_id);
+            engine.with_context(&ctx_id, resolve).map_err(|e| AdapterError::Internal(e.to_string()))
+        } else if let Some(ref ctx) = self.shared_context {
+            let ctx = ctx.lock().map_err(|e| AdapterError::Internal(format!("lock poisoned: {}", e)))?;
+            Ok(resolve(&ctx))
+        } else {
+            Ok(resolve(&Context::new(context_id)))
+        }
+    }
+
     /// Register a structural module (ADR-030).
     ///
     /// Modules are dispatched by MIME affinity — all modules whose
@@ -148,6 +260,19 @@ impl ExtractionCoordinator {
         self.structural_modules.push(module);
     }
 
+    /// Assign a content type to file nodes whose MIME type starts with
+    /// `mime_prefix`.
+    ///
+    /// Lets domain modules route their modality through a custom
+    /// `ContentType::Other` (e.g., `audio/` → `transcript`). The longest
+    /// matching prefix wins; a later registration of the same prefix
+    /// replaces the earlier one.
+    pub fn register_content_type(&mut self, mime_prefix: impl Into<String>, content_type: ContentType) {
+        let mime_prefix = mime_prefix.into();
+        self.content_types.retain(|(prefix, _)| *prefix != mime_prefix);
+        self.content_types.push((mime_prefix, content_type));
+    }
+
     /// Register the semantic extraction adapter.
     ///
     /// Runs sequentially after structural analysis completes, within the same
@@ -201,6 +326,7 @@ impl ExtractionCoordinator {
 /// - Vocabulary: unioned case-insensitively
 /// - Sections: concatenated, sorted by start_line
 /// - Emissions: kept separate (per-module)
+/// - Links: concatenated
 fn merge_structural_outputs(mut base: StructuralOutput, other: StructuralOutput) -> StructuralOutput {
     for term in other.vocabulary {
         let lower = term.to_lowercase();
@@ -211,6 +337,7 @@ fn merge_structural_outputs(mut base: StructuralOutput, other: StructuralOutput)
     base.sections.extend(other.sections);
     base.sections.sort_by_key(|s| s.start_line);
     base.emissions.extend(other.emissions);
+    base.links.extend(other.links);
     base
 }
 
@@ -226,6 +353,7 @@ fn detect_mime_type(path: &Path) -> &'static str {
         Some("html") | Some("htm") => "text/html",
         Some("css") => "text/css",
         Some("json") => "application/json",
+        Some("ipynb") => "application/x-ipynb+json",
         Some("yaml") | Some("yml") => "text/yaml",
         Some("toml") => "text/toml",
         Some("mp3") => "audio/mpeg",
@@ -252,7 +380,7 @@ fn detect_mime_type(path: &Path) -> &'static str {
 /// ```
 ///
 /// Returns the parsed YAML as a serde_yaml::Value, or None if no frontmatter.
-fn parse_frontmatter(content: &str) -> Option<Result<Value, String>> {
+pub(crate) fn parse_frontmatter(content: &str) -> Option<Result<Value, String>> {
     let trimmed = content.trim_start();
     if !trimmed.starts_with("---") {
         return None;
@@ -278,8 +406,88 @@ fn parse_frontmatter(content: &str) -> Option<Result<Value, String>> {
     }
 }
 
+/// Which frontmatter keys carry graph semantics, beyond `tags`.
+///
+/// `FrontmatterMapping::default()` reads `type:` as the file node's
+/// node_type, `related:` as `related_to` edges to other documents, and
+/// `aliases:` as names wikilinks may use for the file (see
+/// `links::ALIASES_PROPERTY`). Related documents are paths relative to
+/// the file, or names resolved like wikilinks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrontmatterMapping {
+    /// Key whose string value replaces the file node's `node_type`
+    pub type_key: Option<String>,
+    /// Key naming alternative titles for the document
+    pub aliases_key: Option<String>,
+    /// `(key, relationship)`: each document listed under `key` gets a
+    /// `relationship` edge from the file
+    pub relations: Vec<(String, String)>,
+}
+
+impl Default for FrontmatterMapping {
+    fn default() -> Self {
+        Self {
+            type_key: Some("type".to_string()),
+            aliases_key: Some("aliases".to_string()),
+            relations: vec![("related".to_string(), "related_to".to_string())],
+        }
+    }
+}
+
+impl FrontmatterMapping {
+    /// Add a key whose documents get `relationship` edges.
+    pub fn with_relation(mut self, key: impl Into<String>, relationship: impl Into<String>) -> Self {
+        self.relations.push((key.into(), relationship.into()));
+        self
+    }
+}
+
+/// Strings under a frontmatter key: a list, or one comma-separated string.
+fn frontmatter_strings(frontmatter: &Value, key: &str) -> Vec<String> {
+    let items: Vec<String> = match frontmatter.get(key) {
+        Some(Value::Array(arr)) => arr.iter().filter_map(|v| v.as_str()).map(str::to_string).collect(),
+        Some(Value::String(s)) => s.split(',').map(str::to_string).collect(),
+        _ => Vec::new(),
+    };
+    items.into_iter().map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect()
+}
+
+/// Apply `type`/`aliases` keys to the file node and collect related
+/// documents as `(relationship, link)` pairs for resolution.
+fn apply_frontmatter_mapping(
+    frontmatter: &Value,
+    mapping: &FrontmatterMapping,
+    file_node: &mut Node,
+) -> RelatedDocuments {
+    if let Some(node_type) = mapping.type_key.as_deref().and_then(|key| frontmatter.get(key)).and_then(Value::as_str) {
+        if !node_type.trim().is_empty() {
+            file_node.node_type = node_type.trim().to_string();
+        }
+    }
+    if let Some(key) = &mapping.aliases_key {
+        let aliases = frontmatter_strings(frontmatter, key);
+        if !aliases.is_empty() {
+            file_node.properties.insert(
+                ALIASES_PROPERTY.to_string(),
+                PropertyValue::Array(aliases.into_iter().map(PropertyValue::String).collect()),
+            );
+        }
+    }
+    mapping
+        .relations
+        .iter()
+        .flat_map(|(key, relationship)| {
+            frontmatter_strings(frontmatter, key).into_iter().filter_map(move |target| {
+                // Obsidian-style `"[[Other]]"` values name the document
+                let target = target.trim_start_matches("[[").trim_end_matches("]]");
+                LinkReference::new(target, LinkKind::Frontmatter).map(|link| (relationship.clone(), link))
+            })
+        })
+        .collect()
+}
+
 /// Extract tags from parsed frontmatter.
-fn extract_tags_from_frontmatter(frontmatter: &Value) -> Vec<String> {
+pub(crate) fn extract_tags_from_frontmatter(frontmatter: &Value) -> Vec<String> {
     let mut tags = Vec::new();
 
     if let Some(tag_val) = frontmatter.get("tags") {
@@ -307,6 +515,18 @@ fn extract_tags_from_frontmatter(frontmatter: &Value) -> Vec<String> {
     tags
 }
 
+/// Resolve the file node's content type from MIME-prefix registrations.
+///
+/// Longest matching prefix wins; `Document` when nothing matches.
+fn resolve_file_content_type(mime_type: &str, content_types: &[(String, ContentType)]) -> ContentType {
+    content_types
+        .iter()
+        .filter(|(prefix, _)| mime_type.starts_with(prefix.as_str()))
+        .max_by_key(|(prefix, _)| prefix.len())
+        .map(|(_, ct)| ct.clone())
+        .unwrap_or(ContentType::Document)
+}
+
 /// Run registration: file registration + metadata extraction.
 ///
 /// Creates:
@@ -317,9 +537,12 @@ fn extract_tags_from_frontmatter(frontmatter: &Value) -> Vec<String> {
 fn run_registration(
     file_path: &str,
     _adapter_id: &str,
-) -> Result<(Emission, String, Option<String>), AdapterError> {
+    content_types: &[(String, ContentType)],
+    frontmatter_mapping: Option<&FrontmatterMapping>,
+) -> Result<(Emission, String, Option<String>, RelatedDocuments), AdapterError> {
     let path = Path::new(file_path);
     let mime_type = detect_mime_type(path);
+    let content_type = resolve_file_content_type(mime_type, content_types);
 
     // Read file metadata
     let metadata = std::fs::metadata(path)
@@ -329,7 +552,7 @@ fn run_registration(
     // File node
     let file_node_id = NodeId::from_string(format!("file:{}", file_path));
     let mut file_node =
-        Node::new_in_dimension("file", ContentType::Document, dimension::STRUCTURE);
+        Node::new_in_dimension("file", content_type, dimension::STRUCTURE);
     file_node.id = file_node_id.clone();
     file_node.properties.insert(
         "path".to_string(),
@@ -347,37 +570,53 @@ fn run_registration(
     file_node
         .properties
         .insert("created_at".to_string(), rfc3339_now());
-
-    let mut emission = Emission::new().with_node(AnnotatedNode::new(file_node));
+    // Fingerprints for pairing the file with its new path after a rename
+    if let Some(inode) = file_inode(&metadata) {
+        file_node.properties.insert(INODE_PROPERTY.to_string(), PropertyValue::Int(inode));
+    }
+    let bytes = std::fs::read(path).ok();
+    if let Some(ref bytes) = bytes {
+        file_node.properties.insert(
+            CONTENT_HASH_PROPERTY.to_string(),
+            PropertyValue::String(file_content_hash(bytes)),
+        );
+    }
 
     // Try to read file content for frontmatter
     let mut metadata_warning: Option<String> = None;
-    if let Ok(content) = std::fs::read_to_string(path) {
-        if let Some(fm_result) = parse_frontmatter(&content) {
-            match fm_result {
-                Ok(frontmatter) => {
-                    let tags = extract_tags_from_frontmatter(&frontmatter);
-                    for tag in &tags {
-                        let (cid, node) = concept_node(tag);
-
-                        emission = emission.with_node(AnnotatedNode::new(node));
-
-                        // tagged_with edge: file → concept
-                        let mut edge = Edge::new_cross_dimensional(
-                            file_node_id.clone(),
-                            dimension::STRUCTURE,
-                            cid,
-                            dimension::SEMANTIC,
-                            "tagged_with",
-                        );
-                        edge.combined_weight = 1.0;
-                        emission = emission.with_edge(AnnotatedEdge::new(edge));
-                    }
-                }
-                Err(warning) => {
-                    metadata_warning = Some(warning);
+    let mut related = Vec::new();
+    let frontmatter = bytes
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .and_then(|content| parse_frontmatter(&content));
+    if let (Some(Ok(frontmatter)), Some(mapping)) = (&frontmatter, frontmatter_mapping) {
+        related = apply_frontmatter_mapping(frontmatter, mapping, &mut file_node);
+    }
+    let mut emission = Emission::new().with_node(AnnotatedNode::new(file_node));
+
+    if let Some(fm_result) = frontmatter {
+        match fm_result {
+            Ok(frontmatter) => {
+                let tags = extract_tags_from_frontmatter(&frontmatter);
+                for tag in &tags {
+                    let (cid, node) = concept_node(tag);
+
+                    emission = emission.with_node(AnnotatedNode::new(node));
+
+                    // tagged_with edge: file → concept
+                    let mut edge = Edge::new_cross_dimensional(
+                        file_node_id.clone(),
+                        dimension::STRUCTURE,
+                        cid,
+                        dimension::SEMANTIC,
+                        "tagged_with",
+                    );
+                    edge.combined_weight = 1.0;
+                    emission = emission.with_edge(AnnotatedEdge::new(edge));
                 }
             }
+            Err(warning) => {
+                metadata_warning = Some(warning);
+            }
         }
     }
 
@@ -414,7 +653,7 @@ fn run_registration(
     }
     emission = emission.with_node(AnnotatedNode::new(status_node));
 
-    Ok((emission, mime_type.to_string(), metadata_warning))
+    Ok((emission, mime_type.to_string(), metadata_warning, related))
 }
 
 /// Update a phase status on the extraction status node (Mutex path).
@@ -453,6 +692,84 @@ fn update_extraction_status_via_engine(
     });
 }
 
+/// Set a property on the extraction status node through whichever
+/// backend the background phase has.
+fn set_extraction_status(
+    engine: &Option<Arc<crate::graph::PlexusEngine>>,
+    context_id: &Option<crate::graph::ContextId>,
+    mutex: &Option<Arc<std::sync::Mutex<Context>>>,
+    file_path: &str,
+    key: &str,
+    value: PropertyValue,
+) {
+    let status_id = NodeId::from_string(format!("extraction-status:{}", file_path));
+    let set = |ctx: &mut Context| {
+        if let Some(node) = ctx.get_node_mut(&status_id) {
+            node.properties.insert(key.to_string(), value.clone());
+        }
+    };
+    if let (Some(engine), Some(ctx_id)) = (engine, context_id) {
+        let _ = engine.with_context_mut(ctx_id, set);
+    } else if let Some(ctx) = mutex {
+        set(&mut ctx.lock().unwrap());
+    }
+}
+
+/// Read the context through whichever backend the background phase has.
+fn read_context<R>(
+    engine: &Option<Arc<crate::graph::PlexusEngine>>,
+    context_id: &Option<crate::graph::ContextId>,
+    mutex: &Option<Arc<std::sync::Mutex<Context>>>,
+    read: impl FnOnce(&Context) -> R,
+) -> Option<R> {
+    if let (Some(engine), Some(ctx_id)) = (engine, context_id) {
+        engine.get_context(ctx_id).map(|ctx| read(&ctx))
+    } else {
+        mutex.as_ref().map(|ctx| read(&ctx.lock().unwrap()))
+    }
+}
+
+/// Extraction-status property listing the structural modules that failed
+/// on the file: an array of `{module_id, message}` objects.
+pub const STRUCTURAL_ERRORS_PROPERTY: &str = "structural_analysis_errors";
+
+fn failure_report(failures: &[AnalysisFailure]) -> PropertyValue {
+    PropertyValue::Array(
+        failures
+            .iter()
+            .map(|f| {
+                PropertyValue::Object(
+                    [
+                        ("module_id".to_string(), PropertyValue::String(f.module_id.clone())),
+                        ("message".to_string(), PropertyValue::String(f.message.clone())),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )
+            })
+            .collect(),
+    )
+}
+
+/// Files whose structural analysis failed in whole or part, per their
+/// extraction-status nodes — the ones to ingest again once fixed.
+pub fn files_needing_reanalysis(context: &Context) -> Vec<String> {
+    let mut files: Vec<String> = context
+        .nodes()
+        .filter(|n| n.node_type == "extraction-status")
+        .filter(|n| match n.properties.get("structural_analysis") {
+            Some(PropertyValue::String(status)) => status == "partial" || status.starts_with("failed"),
+            _ => false,
+        })
+        .filter_map(|n| match n.properties.get("file_path") {
+            Some(PropertyValue::String(path)) => Some(path.clone()),
+            _ => None,
+        })
+        .collect();
+    files.sort();
+    files
+}
+
 /// Run the enrichment loop over background-phase emissions (issue #5).
 ///
 /// Uses the pipeline's live registry cell so runtime-loaded and
@@ -520,6 +837,18 @@ impl Adapter for ExtractionCoordinator {
         "extract-file"
     }
 
+    fn input_from_json(&self, json: &serde_json::Value) -> Result<Box<dyn std::any::Any + Send + Sync>, AdapterError> {
+        Ok(Box::new(ExtractFileInput::from_json(json)?))
+    }
+
+    fn input_schema(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({
+            "type": "object",
+            "required": ["file_path"],
+            "properties": {"file_path": {"type": "string"}}
+        }))
+    }
+
     async fn process(
         &self,
         input: &AdapterInput,
@@ -540,15 +869,18 @@ impl Adapter for ExtractionCoordinator {
         let context_id = input.context_id.clone();
 
         // Registration: synchronous
-        let (emission, mime_type, _metadata_warning) =
-            run_registration(&file_path, self.id())?;
+        let (emission, mime_type, _metadata_warning, related) =
+            run_registration(&file_path, self.id(), &self.content_types, self.frontmatter_mapping.as_ref())?;
 
         sink.emit(emission).await?;
+        if !related.is_empty() {
+            sink.emit(self.resolve_related(&file_path, &context_id, &related)?).await?;
+        }
 
         // Structural analysis + semantic extraction: spawn background task
         // if we have a backend and there's work to do (modules or semantic extraction).
         let matching = self.matching_modules(&mime_type);
-        let has_work = !matching.is_empty() || self.semantic_adapter.is_some();
+        let has_work = !matching.is_empty() || self.semantic_adapter.is_some() || self.chunking.is_some();
 
         if has_work {
             let bg_engine = self.engine.clone();
@@ -575,6 +907,10 @@ impl Adapter for ExtractionCoordinator {
                 let context_id_bg = context_id.clone();
                 let semantic_opt = self.semantic_adapter.clone();
                 let bg_cell = self.enrichment_cell.clone();
+                let colocation = self.colocation_relationship.clone();
+                let resolve_links = self.resolve_links;
+                let chunking = self.chunking.clone();
+                let failure_policy = self.failure_policy;
 
                 let handle = tokio::spawn(async move {
                     // Structural analysis: acquire analysis semaphore
@@ -583,7 +919,7 @@ impl Adapter for ExtractionCoordinator {
                     })?;
 
                     // Run structural modules: read file once, fan-out to all matching
-                    let structural_output = if !modules.is_empty() {
+                    let (structural_output, failures) = if !modules.is_empty() {
                         let content = std::fs::read_to_string(&file_path_bg)
                             .unwrap_or_else(|e| {
                                 tracing::warn!(
@@ -595,10 +931,23 @@ impl Adapter for ExtractionCoordinator {
                             });
 
                         let mut merged = StructuralOutput::default();
+                        let mut failures = Vec::new();
                         let mut structural_events: Vec<crate::graph::events::GraphEvent> = Vec::new();
                         for module in &modules {
-                            let output = module.analyze(&file_path_bg, &content).await;
+                            let (output, module_failures) = module.analyze_reporting(&file_path_bg, &content).await;
                             merged = merge_structural_outputs(merged, output);
+                            failures.extend(module_failures);
+                            // Fail fast: the modules after the first failure don't run
+                            if failure_policy == AnalysisFailurePolicy::FailFast {
+                                if let Some(failure) = failures.first() {
+                                    let message = format!("{}: {}", failure.module_id, failure.message);
+                                    set_extraction_status(
+                                        &bg_engine, &bg_context_id, &bg_mutex, &file_path_bg,
+                                        "structural_analysis", PropertyValue::String(format!("failed: {}", message)),
+                                    );
+                                    return Err(AdapterError::Internal(format!("structural analysis failed: {}", message)));
+                                }
+                            }
                         }
 
                         // Emit module emissions with per-module adapter IDs
@@ -614,9 +963,55 @@ impl Adapter for ExtractionCoordinator {
                                 edge_removals: Vec::new(),
                                 property_updates: Vec::new(),
                             };
+                            if emission.is_empty() {
+                                continue;
+                            }
+                            match module_sink.emit(emission).await {
+                                Ok(_) => structural_events.extend(module_sink.drain_events()),
+                                Err(e) if failure_policy == AnalysisFailurePolicy::CollectErrors => {
+                                    failures.push(AnalysisFailure::new(&module_emission.module_id, e.to_string()));
+                                }
+                                Err(e) => {
+                                    set_extraction_status(
+                                        &bg_engine, &bg_context_id, &bg_mutex, &file_path_bg, "structural_analysis",
+                                        PropertyValue::String(format!("failed: {}: {}", module_emission.module_id, e)),
+                                    );
+                                    return Err(e);
+                                }
+                            }
+                        }
+
+                        // Link co-located results across modules, once all
+                        // their nodes are committed
+                        if let Some(ref relationship) = colocation {
+                            let edges = colocated_edges(&merged.emissions, relationship);
+                            if !edges.is_empty() {
+                                let colocation_sink = create_sink(
+                                    &bg_engine, &bg_context_id, &bg_mutex,
+                                    COLOCATION_MODULE_ID, &context_id_bg,
+                                );
+                                let emission = edges.into_iter().fold(Emission::new(), Emission::with_edge);
+                                colocation_sink.emit(emission).await?;
+                                structural_events.extend(colocation_sink.drain_events());
+                            }
+                        }
+
+                        if resolve_links && !merged.links.is_empty() {
+                            let link_sink = create_sink(
+                                &bg_engine, &bg_context_id, &bg_mutex,
+                                LINK_RESOLUTION_ID, &context_id_bg,
+                            );
+                            let emission = if let (Some(ref engine), Some(ref ctx_id)) = (&bg_engine, &bg_context_id) {
+                                engine.with_context(ctx_id, |ctx| resolve_links_in(&file_path_bg, &merged.links, ctx))
+                                    .map_err(|e| AdapterError::Internal(e.to_string()))?
+                            } else {
+                                let ctx = bg_mutex.as_ref().expect("a backend is configured").lock()
+                                    .map_err(|e| AdapterError::Internal(format!("lock poisoned: {}", e)))?;
+                                resolve_links_in(&file_path_bg, &merged.links, &ctx)
+                            };
                             if !emission.is_empty() {
-                                module_sink.emit(emission).await?;
-                                structural_events.extend(module_sink.drain_events());
+                                link_sink.emit(emission).await?;
+                                structural_events.extend(link_sink.drain_events());
                             }
                         }
 
@@ -628,20 +1023,59 @@ impl Adapter for ExtractionCoordinator {
                             &structural_events, "structural_analysis",
                         );
 
-                        merged
+                        (merged, failures)
                     } else {
                         // No matching modules — empty passthrough (Invariant 52)
-                        StructuralOutput::default()
+                        (StructuralOutput::default(), Vec::new())
                     };
 
-                    // Update structural analysis status
-                    let analysis_status = "complete".to_string();
+                    // Update structural analysis status; failed modules
+                    // leave it partial, with their errors alongside
+                    let analysis_status = if failures.is_empty() { "complete" } else { "partial" };
                     if let (Some(ref engine), Some(ref ctx_id)) = (&bg_engine, &bg_context_id) {
-                        update_extraction_status_via_engine(engine, ctx_id, &file_path_bg, "structural_analysis", &analysis_status);
+                        update_extraction_status_via_engine(engine, ctx_id, &file_path_bg, "structural_analysis", analysis_status);
                     } else if let Some(ref ctx) = bg_mutex {
-                        update_extraction_status(ctx, &file_path_bg, "structural_analysis", &analysis_status);
+                        update_extraction_status(ctx, &file_path_bg, "structural_analysis", analysis_status);
+                    }
+                    if !failures.is_empty() {
+                        tracing::warn!(
+                            file_path = %file_path_bg,
+                            failures = failures.len(),
+                            "structural analysis partially failed"
+                        );
+                        set_extraction_status(
+                            &bg_engine, &bg_context_id, &bg_mutex, &file_path_bg,
+                            STRUCTURAL_ERRORS_PROPERTY, failure_report(&failures),
+                        );
                     }
 
+                    // Chunk the document along its sections (or a token budget)
+                    let chunks = match chunking {
+                        Some(ref strategy) => {
+                            let content = std::fs::read_to_string(&file_path_bg).unwrap_or_default();
+                            let chunks = chunk_document(&file_path_bg, &content, &structural_output.sections, strategy);
+                            // A shorter document drops the chunks past its new count
+                            let stale = read_context(&bg_engine, &bg_context_id, &bg_mutex, |ctx| {
+                                stale_chunks(ctx, &file_path_bg, chunks.len())
+                            })
+                            .unwrap_or_default();
+                            if !chunks.is_empty() || !stale.is_empty() {
+                                let chunk_sink = create_sink(
+                                    &bg_engine, &bg_context_id, &bg_mutex,
+                                    CHUNKING_ID, &context_id_bg,
+                                );
+                                let emission = stale.into_iter().fold(chunk_emission(&file_path_bg, &chunks), Emission::with_removal);
+                                chunk_sink.emit(emission).await?;
+                                run_background_enrichment(
+                                    &bg_engine, &bg_context_id, &bg_cell,
+                                    &chunk_sink.drain_events(), "chunking",
+                                );
+                            }
+                            chunks
+                        }
+                        None => Vec::new(),
+                    };
+
                     // Release analysis permit before semantic extraction
                     drop(_permit);
 
@@ -663,7 +1097,8 @@ impl Adapter for ExtractionCoordinator {
                                 file_path_bg.clone(),
                                 structural_output.sections,
                                 structural_output.vocabulary,
-                            ),
+                            )
+                            .with_chunks(chunks),
                             &context_id_bg,
                         );
 
@@ -810,6 +1245,36 @@ mod tests {
         }
     }
 
+    /// A structural module that always fails.
+    struct FailingModule;
+
+    #[async_trait]
+    impl StructuralModule for FailingModule {
+        fn id(&self) -> &str { "extract-analysis-text-broken" }
+        fn mime_affinity(&self) -> &str { "text/" }
+        async fn analyze(&self, _file_path: &str, _content: &str) -> StructuralOutput {
+            StructuralOutput::default()
+        }
+        async fn analyze_reporting(&self, _file_path: &str, _content: &str) -> (StructuralOutput, Vec<AnalysisFailure>) {
+            (StructuralOutput::default(), vec![AnalysisFailure::new(self.id(), "parser crashed")])
+        }
+    }
+
+    /// A structural module that counts the files it analyzes.
+    struct CountingModule {
+        calls: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl StructuralModule for CountingModule {
+        fn id(&self) -> &str { "extract-analysis-text-counting" }
+        fn mime_affinity(&self) -> &str { "text/" }
+        async fn analyze(&self, _file_path: &str, _content: &str) -> StructuralOutput {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            StructuralOutput::default()
+        }
+    }
+
     /// A structural module that emits a concept node via ModuleEmission.
     struct EmittingModule {
         id: &'static str,
@@ -831,6 +1296,36 @@ mod tests {
                     nodes: vec![AnnotatedNode::new(node)],
                     edges: vec![],
                 }],
+                links: vec![],
+            }
+        }
+    }
+
+    /// A structural module that emits one node tied to a line region.
+    struct RegionModule {
+        id: &'static str,
+        node_id: &'static str,
+        dimension: &'static str,
+        lines: (i64, i64),
+    }
+
+    #[async_trait]
+    impl StructuralModule for RegionModule {
+        fn id(&self) -> &str { self.id }
+        fn mime_affinity(&self) -> &str { "text/" }
+        async fn analyze(&self, _file_path: &str, _content: &str) -> StructuralOutput {
+            use crate::adapter::structural::{REGION_END_PROPERTY, REGION_START_PROPERTY};
+            let mut node = Node::new_in_dimension("region", ContentType::Document, self.dimension);
+            node.id = NodeId::from_string(self.node_id);
+            node.properties.insert(REGION_START_PROPERTY.into(), PropertyValue::Int(self.lines.0));
+            node.properties.insert(REGION_END_PROPERTY.into(), PropertyValue::Int(self.lines.1));
+            StructuralOutput {
+                emissions: vec![ModuleEmission {
+                    module_id: self.id.to_string(),
+                    nodes: vec![AnnotatedNode::new(node)],
+                    edges: vec![],
+                }],
+                ..Default::default()
             }
         }
     }
@@ -852,6 +1347,7 @@ mod tests {
                 vocabulary: self.terms.iter().map(|t| t.to_string()).collect(),
                 sections: self.sections.clone(),
                 emissions: vec![],
+                links: vec![],
             }
         }
     }
@@ -893,7 +1389,7 @@ mod tests {
             file_node.properties.get("mime_type"),
             Some(&PropertyValue::String("text/markdown".to_string()))
         );
-        assert!(file_node.properties.get("file_size").is_some());
+        assert!(file_node.properties.contains_key("file_size"));
 
         // Concept nodes from frontmatter tags
         assert!(
@@ -924,6 +1420,47 @@ mod tests {
         );
     }
 
+    // --- Scenario: Registered content type routes file nodes by MIME prefix ---
+
+    #[tokio::test]
+    async fn registration_applies_registered_content_type() {
+        let mut coordinator = ExtractionCoordinator::new();
+        coordinator.register_content_type("text/", ContentType::Narrative);
+        coordinator.register_content_type("text/plain", ContentType::custom("transcript"));
+        let ctx = Arc::new(Mutex::new(Context::new("test")));
+        let sink = test_sink(ctx.clone(), "extract-coordinator");
+
+        let dir = create_temp_file("session.txt", "speaker 1: hello");
+        let txt_path = dir.path().join("session.txt").to_str().unwrap().to_string();
+        std::fs::write(dir.path().join("notes.md"), "# Notes").unwrap();
+        let md_path = dir.path().join("notes.md").to_str().unwrap().to_string();
+        std::fs::write(dir.path().join("clip.png"), [0u8; 4]).unwrap();
+        let png_path = dir.path().join("clip.png").to_str().unwrap().to_string();
+
+        for path in [&txt_path, &md_path, &png_path] {
+            let input = AdapterInput::new(
+                "extract-file",
+                ExtractFileInput { file_path: path.clone() },
+                "test",
+            );
+            coordinator.process(&input, &sink).await.unwrap();
+        }
+
+        let snapshot = ctx.lock().unwrap();
+        let content_type_of = |path: &str| {
+            snapshot
+                .get_node(&NodeId::from_string(format!("file:{}", path)))
+                .expect("file node should exist")
+                .content_type
+                .clone()
+        };
+        // Longest prefix wins
+        assert_eq!(content_type_of(&txt_path), ContentType::Other("transcript".to_string()));
+        assert_eq!(content_type_of(&md_path), ContentType::Narrative);
+        // Unregistered MIME types keep the Document default
+        assert_eq!(content_type_of(&png_path), ContentType::Document);
+    }
+
     // --- ADR-039: registration phase nodes carry created_at in properties ---
     //
     // Both the file node and the extraction-status node must carry
@@ -1035,7 +1572,7 @@ mod tests {
             Some(&PropertyValue::String("complete".to_string()))
         );
         assert!(
-            status.properties.get("registration_warning").is_some(),
+            status.properties.contains_key("registration_warning"),
             "should have metadata warning"
         );
     }
@@ -1222,6 +1759,62 @@ mod tests {
         );
     }
 
+    // --- Scenario: A failing module doesn't waste the other modules' work ---
+
+    #[tokio::test]
+    async fn module_failures_are_collected_or_fail_fast() {
+        for policy in [AnalysisFailurePolicy::CollectErrors, AnalysisFailurePolicy::FailFast] {
+            let ctx = Arc::new(Mutex::new(Context::new("test")));
+            let sink = test_sink(ctx.clone(), "extract-coordinator");
+            let mut coordinator = ExtractionCoordinator::new().with_context(ctx.clone()).with_failure_policy(policy);
+            coordinator.register_structural_module(Arc::new(FailingModule));
+            coordinator.register_structural_module(Arc::new(EmittingModule {
+                id: "extract-analysis-text-headings",
+                mime: "text/",
+                concept_label: "survivor",
+            }));
+            let later_calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+            coordinator.register_structural_module(Arc::new(CountingModule { calls: later_calls.clone() }));
+
+            let dir = create_temp_file("partial.md", "# Partial\n");
+            let file_path = dir.path().join("partial.md").to_str().unwrap().to_string();
+            let input = AdapterInput::new("extract-file", ExtractFileInput { file_path: file_path.clone() }, "test");
+            coordinator.process(&input, &sink).await.unwrap();
+            let results = coordinator.wait_for_background().await;
+
+            let snapshot = ctx.lock().unwrap();
+            let status = snapshot
+                .get_node(&NodeId::from_string(format!("extraction-status:{}", file_path)))
+                .expect("status should exist");
+            let survivor = snapshot.get_node(&NodeId::from_string("concept:survivor"));
+            assert_eq!(files_needing_reanalysis(&snapshot), vec![file_path.clone()]);
+            match policy {
+                AnalysisFailurePolicy::CollectErrors => {
+                    assert!(results.iter().all(|r| r.is_ok()));
+                    assert!(survivor.is_some(), "the healthy module's output is kept");
+                    assert_eq!(later_calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+                    assert_eq!(status.properties.get("structural_analysis"), Some(&PropertyValue::from("partial")));
+                    let Some(PropertyValue::Array(errors)) = status.properties.get(STRUCTURAL_ERRORS_PROPERTY) else {
+                        panic!("expected an error report, got {:?}", status.properties.get(STRUCTURAL_ERRORS_PROPERTY));
+                    };
+                    assert_eq!(errors.len(), 1);
+                }
+                AnalysisFailurePolicy::FailFast => {
+                    assert!(results.iter().any(|r| r.is_err()));
+                    assert!(survivor.is_none(), "nothing is emitted after the first failure");
+                    assert_eq!(
+                        later_calls.load(std::sync::atomic::Ordering::SeqCst), 0,
+                        "modules after the failing one don't run"
+                    );
+                    assert_eq!(
+                        status.properties.get("structural_analysis"),
+                        Some(&PropertyValue::from("failed: extract-analysis-text-broken: parser crashed"))
+                    );
+                }
+            }
+        }
+    }
+
     // --- Scenario: Semantic extraction runs after structural analysis with context (ADR-031) ---
 
     #[tokio::test]
@@ -1556,11 +2149,13 @@ mod tests {
             vocabulary: vec!["Plexus".to_string(), "Graph".to_string()],
             sections: vec![],
             emissions: vec![],
+            links: vec![],
         };
         let b = StructuralOutput {
             vocabulary: vec!["plexus".to_string(), "Knowledge".to_string()],
             sections: vec![],
             emissions: vec![],
+            links: vec![],
         };
 
         let merged = merge_structural_outputs(a, b);
@@ -1578,11 +2173,13 @@ mod tests {
             vocabulary: vec![],
             sections: vec![SectionBoundary { label: "Second".into(), start_line: 50, end_line: 100 }],
             emissions: vec![],
+            links: vec![],
         };
         let b = StructuralOutput {
             vocabulary: vec![],
             sections: vec![SectionBoundary { label: "First".into(), start_line: 1, end_line: 49 }],
             emissions: vec![],
+            links: vec![],
         };
 
         let merged = merge_structural_outputs(a, b);
@@ -1946,4 +2543,217 @@ mod tests {
         let ctx2 = engine2.get_context(&context_id).expect("should survive reload");
         assert!(ctx2.get_node(&NodeId::from_string("concept:integration")).is_some());
     }
+
+    // --- Scenario: Co-located module results are linked across dimensions ---
+
+    #[tokio::test]
+    async fn colocation_edges_link_module_results_from_the_same_section() {
+        for enabled in [false, true] {
+            let ctx = Arc::new(Mutex::new(Context::new("test")));
+            let sink = test_sink(ctx.clone(), "extract-coordinator");
+            let mut coordinator = ExtractionCoordinator::new().with_context(ctx.clone());
+            if enabled {
+                coordinator = coordinator.with_colocation_edges("co_located");
+            }
+            coordinator.register_structural_module(Arc::new(RegionModule {
+                id: "extract-analysis-text-headings",
+                node_id: "heading:intro",
+                dimension: dimension::STRUCTURE,
+                lines: (1, 3),
+            }));
+            coordinator.register_structural_module(Arc::new(RegionModule {
+                id: "extract-analysis-text-terms",
+                node_id: "concept:federation",
+                dimension: dimension::SEMANTIC,
+                lines: (2, 2),
+            }));
+
+            let dir = create_temp_file("co.md", "# Intro\nFederation.\n");
+            let file_path = dir.path().join("co.md");
+            let input = AdapterInput::new(
+                "extract-file",
+                ExtractFileInput { file_path: file_path.to_str().unwrap().to_string() },
+                "test",
+            );
+            coordinator.process(&input, &sink).await.unwrap();
+            assert!(coordinator.wait_for_background().await.iter().all(|r| r.is_ok()));
+
+            let snapshot = ctx.lock().unwrap();
+            let linked: Vec<_> = snapshot.edges().filter(|e| e.relationship == "co_located").collect();
+            if !enabled {
+                assert!(linked.is_empty(), "off by default");
+                continue;
+            }
+            assert_eq!(linked.len(), 1);
+            assert_eq!(linked[0].source, NodeId::from_string("heading:intro"));
+            assert_eq!(linked[0].target, NodeId::from_string("concept:federation"));
+            assert!(linked[0].is_cross_dimensional());
+            assert!(linked[0].contributions.contains_key(COLOCATION_MODULE_ID));
+        }
+    }
+
+    // --- Scenario: Document links resolve to registered files or placeholders ---
+
+    #[tokio::test]
+    async fn link_resolution_links_registered_documents_and_flags_missing_ones() {
+        use crate::adapter::links::UNRESOLVED_PROPERTY;
+        use crate::adapter::structural::MarkdownStructureModule;
+
+        let ctx = Arc::new(Mutex::new(Context::new("test")));
+        let sink = test_sink(ctx.clone(), "extract-coordinator");
+        let mut coordinator = ExtractionCoordinator::new().with_context(ctx.clone()).with_link_resolution();
+        coordinator.register_structural_module(Arc::new(MarkdownStructureModule::new()));
+
+        let dir = tempfile::tempdir().unwrap();
+        let write = |name: &str, content: &str| {
+            let path = dir.path().join(name);
+            std::fs::write(&path, content).unwrap();
+            path.to_str().unwrap().to_string()
+        };
+        let target = write("target.md", "# Target\n");
+        let source = write(
+            "source.md",
+            "See [the target](target.md), [[Later]], and [ref][t].\n\n[t]: ./target.md\n\nA claim.[^1]\n\n[^1]: Per [notes](notes.md).\n",
+        );
+        for path in [&target, &source] {
+            let input = AdapterInput::new("extract-file", ExtractFileInput { file_path: path.clone() }, "test");
+            coordinator.process(&input, &sink).await.unwrap();
+            assert!(coordinator.wait_for_background().await.iter().all(|r| r.is_ok()));
+        }
+
+        let snapshot = ctx.lock().unwrap();
+        let source_id = NodeId::from_string(format!("file:{}", source));
+        let mut targets: Vec<String> = snapshot
+            .edges()
+            .filter(|e| e.relationship == "links_to" && e.source == source_id)
+            .map(|e| e.target.to_string())
+            .collect();
+        targets.sort();
+        let dir_path = dir.path().to_str().unwrap();
+        assert_eq!(
+            targets,
+            vec![
+                format!("file:{}/Later.md", dir_path),
+                format!("file:{}/notes.md", dir_path),
+                format!("file:{}", target),
+            ],
+            "inline and reference links to the target collapse into one edge"
+        );
+
+        let target_node = snapshot.get_node(&NodeId::from_string(format!("file:{}", target))).unwrap();
+        assert!(!target_node.properties.contains_key(UNRESOLVED_PROPERTY), "the registered file is untouched");
+        let later = snapshot.get_node(&NodeId::from_string(format!("file:{}/Later.md", dir_path))).unwrap();
+        assert_eq!(later.properties.get(UNRESOLVED_PROPERTY), Some(&PropertyValue::Bool(true)));
+    }
+
+    // --- Scenario: Frontmatter keys type the file node and relate documents ---
+
+    #[tokio::test]
+    async fn frontmatter_mapping_types_relates_and_aliases_documents() {
+        use crate::adapter::links::UNRESOLVED_PROPERTY;
+        use crate::adapter::structural::MarkdownStructureModule;
+
+        let ctx = Arc::new(Mutex::new(Context::new("test")));
+        let sink = test_sink(ctx.clone(), "extract-coordinator");
+        let mut coordinator = ExtractionCoordinator::new()
+            .with_context(ctx.clone())
+            .with_link_resolution()
+            .with_frontmatter_mapping(FrontmatterMapping::default().with_relation("mentors", "mentored_by"));
+        coordinator.register_structural_module(Arc::new(MarkdownStructureModule::new()));
+
+        let dir = tempfile::tempdir().unwrap();
+        let write = |name: &str, content: &str| {
+            let path = dir.path().join(name);
+            std::fs::write(&path, content).unwrap();
+            path.to_str().unwrap().to_string()
+        };
+        let babbage = write("babbage.md", "# Charles Babbage\n");
+        let ada = write(
+            "lovelace.md",
+            "---\ntype: person\naliases: [Ada, Countess of Lovelace]\nrelated: [babbage.md, \"[[Menabrea]]\"]\nmentors: De Morgan\ntags: [computing]\n---\n# Ada Lovelace\n",
+        );
+        let essay = write("essay.md", "Notes on [[Ada]].\n");
+        for path in [&babbage, &ada, &essay] {
+            let input = AdapterInput::new("extract-file", ExtractFileInput { file_path: path.clone() }, "test");
+            coordinator.process(&input, &sink).await.unwrap();
+            assert!(coordinator.wait_for_background().await.iter().all(|r| r.is_ok()));
+        }
+
+        let snapshot = ctx.lock().unwrap();
+        let ada_id = NodeId::from_string(format!("file:{}", ada));
+        let ada_node = snapshot.get_node(&ada_id).unwrap();
+        assert_eq!(ada_node.node_type, "person");
+        assert!(matches!(ada_node.properties.get(ALIASES_PROPERTY), Some(PropertyValue::Array(a)) if a.len() == 2));
+        assert!(snapshot.get_node(&NodeId::from_string("concept:computing")).is_some(), "tags still apply");
+
+        let from_ada = |relationship: &str| -> Vec<String> {
+            let mut targets: Vec<String> = snapshot
+                .edges()
+                .filter(|e| e.source == ada_id && e.relationship == relationship)
+                .map(|e| e.target.to_string())
+                .collect();
+            targets.sort();
+            targets
+        };
+        let dir_path = dir.path().to_str().unwrap();
+        assert_eq!(from_ada("related_to"), vec![format!("file:{}/Menabrea.md", dir_path), format!("file:{}", babbage)]);
+        assert_eq!(from_ada("mentored_by"), vec![format!("file:{}/De Morgan.md", dir_path)]);
+        let menabrea = snapshot.get_node(&NodeId::from_string(format!("file:{}/Menabrea.md", dir_path))).unwrap();
+        assert_eq!(menabrea.properties.get(UNRESOLVED_PROPERTY), Some(&PropertyValue::Bool(true)));
+
+        // The alias registry: [[Ada]] resolves to lovelace.md
+        let essay_id = NodeId::from_string(format!("file:{}", essay));
+        let essay_links: Vec<_> = snapshot.edges().filter(|e| e.source == essay_id && e.relationship == "links_to").collect();
+        assert_eq!(essay_links.len(), 1);
+        assert_eq!(essay_links[0].target, ada_id);
+    }
+
+    // --- Scenario: Chunking splits a document into chunk nodes under its file ---
+
+    #[tokio::test]
+    async fn chunking_emits_section_chunks_under_the_file_node() {
+        use crate::adapter::chunking::{CHUNK_NODE_TYPE, CHUNK_TEXT_PROPERTY};
+        use crate::adapter::structural::MarkdownStructureModule;
+
+        let ctx = Arc::new(Mutex::new(Context::new("test")));
+        let sink = test_sink(ctx.clone(), "extract-coordinator");
+        let mut coordinator = ExtractionCoordinator::new().with_context(ctx.clone()).with_chunking(ChunkStrategy::Headings);
+        coordinator.register_structural_module(Arc::new(MarkdownStructureModule::new()));
+
+        let dir = create_temp_file("long.md", "Preamble.\n\n# Setup\nInstall it.\n\n# Usage\nRun it.\n");
+        let file_path = dir.path().join("long.md").to_str().unwrap().to_string();
+        let input = AdapterInput::new("extract-file", ExtractFileInput { file_path: file_path.clone() }, "test");
+        coordinator.process(&input, &sink).await.unwrap();
+        assert!(coordinator.wait_for_background().await.iter().all(|r| r.is_ok()));
+
+        let file_id = NodeId::from_string(format!("file:{}", file_path));
+        {
+            let snapshot = ctx.lock().unwrap();
+            let mut chunks: Vec<_> = snapshot.nodes().filter(|n| n.node_type == CHUNK_NODE_TYPE).collect();
+            chunks.sort_by_key(|n| n.id.to_string());
+            let labels: Vec<_> = chunks.iter().map(|n| n.properties.get("label").cloned().unwrap()).collect();
+            assert_eq!(
+                labels,
+                ["lines 1-2", "Setup", "Usage"].map(|l| PropertyValue::String(l.to_string())).to_vec()
+            );
+            assert_eq!(
+                chunks[2].properties.get(CHUNK_TEXT_PROPERTY),
+                Some(&PropertyValue::String("# Usage\nRun it.".to_string()))
+            );
+            let contained = snapshot
+                .edges()
+                .filter(|e| e.source == file_id && e.relationship == "contains" && e.target.as_str().starts_with("chunk:"))
+                .count();
+            assert_eq!(contained, 3);
+        }
+
+        // Re-ingesting a shorter document drops the chunks past its end
+        std::fs::write(&file_path, "Preamble only.\n").unwrap();
+        coordinator.process(&input, &sink).await.unwrap();
+        assert!(coordinator.wait_for_background().await.iter().all(|r| r.is_ok()));
+        let snapshot = ctx.lock().unwrap();
+        let chunks: Vec<_> = snapshot.nodes().filter(|n| n.node_type == CHUNK_NODE_TYPE).map(|n| n.id.to_string()).collect();
+        assert_eq!(chunks, vec![format!("chunk:{}:0", file_path)]);
+        assert_eq!(snapshot.edges().filter(|e| e.source == file_id && e.target.as_str().starts_with("chunk:")).count(), 1);
+    }
 }
@@ -0,0 +1,499 @@
+//! GraphImportAdapter — Neo4j and ArangoDB dump ingestion
+//!
+//! Reads the dumps teams already have:
+//! - Neo4j `apoc.export.json.*` output: JSON lines (or a JSON array) of
+//!   `{"type": "node", "id", "labels", "properties"}` and
+//!   `{"type": "relationship", "label", "start": {"id"}, "end": {"id"},
+//!   "properties"}` records
+//! - ArangoDB `arangoexport`/`arangodump` output: documents per
+//!   collection, keyed by `_key`/`_id`; edge documents carry `_from` and
+//!   `_to` (`collection/key`). Dump envelopes (`{"type": 2300, "data"}`)
+//!   are unwrapped.
+//!
+//! An `ImportMapping` maps Neo4j labels and ArangoDB collections to a
+//! node type and dimension, and relationship types and edge collections
+//! to relationships. Unmapped names pass through lowercased, into the
+//! mapping's default dimension, unless `skip_unmapped` is set. A node's
+//! ID is `{node_type}:{key}`, where the key is the mapped `id_property`
+//! when present — Neo4j's internal IDs are not stable across exports —
+//! else the dump's own ID. An edge's `weight` property becomes the
+//! importer's contribution to it. Like the calendar adapter, each dump
+//! gets a chain and each imported node a mark (Invariant 7).
+
+use crate::adapter::sink::{AdapterError, AdapterSink};
+use crate::adapter::traits::{Adapter, AdapterInput};
+use crate::adapter::types::{chain_node, mark_node, Emission};
+use crate::graph::{dimension, ContentType, Edge, Node, NodeId, PropertyValue};
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// Which database wrote the dump.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DumpFormat {
+    Neo4jApoc,
+    ArangoDb,
+}
+
+impl DumpFormat {
+    fn as_str(self) -> &'static str {
+        match self {
+            DumpFormat::Neo4jApoc => "neo4j",
+            DumpFormat::ArangoDb => "arangodb",
+        }
+    }
+}
+
+/// Where one label or collection lands.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct NodeMapping {
+    pub node_type: Option<String>,
+    pub dimension: Option<String>,
+    /// Property whose value keys the node ID
+    pub id_property: Option<String>,
+}
+
+/// Mapping from dump names to Plexus names.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ImportMapping {
+    /// Neo4j label or ArangoDB collection → node type and dimension
+    pub nodes: HashMap<String, NodeMapping>,
+    /// Neo4j relationship type or ArangoDB edge collection → relationship
+    pub relationships: HashMap<String, String>,
+    pub default_dimension: String,
+    /// Drop nodes and edges whose label or collection is not mapped
+    pub skip_unmapped: bool,
+}
+
+impl Default for ImportMapping {
+    fn default() -> Self {
+        Self {
+            nodes: HashMap::new(),
+            relationships: HashMap::new(),
+            default_dimension: dimension::STRUCTURE.to_string(),
+            skip_unmapped: false,
+        }
+    }
+}
+
+/// A node read from a dump, before mapping.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DumpNode {
+    /// The dump's own ID (Neo4j `id`, ArangoDB `_id`)
+    pub id: String,
+    /// Neo4j labels, or the one ArangoDB collection
+    pub labels: Vec<String>,
+    pub properties: serde_json::Map<String, serde_json::Value>,
+}
+
+/// An edge read from a dump, before mapping.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DumpEdge {
+    /// Neo4j relationship type, or the ArangoDB edge collection
+    pub label: String,
+    pub source: String,
+    pub target: String,
+    pub properties: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Input data for the GraphImportAdapter.
+#[derive(Debug, Clone)]
+pub struct GraphImportInput {
+    pub format: DumpFormat,
+    pub nodes: Vec<DumpNode>,
+    pub edges: Vec<DumpEdge>,
+    pub mapping: ImportMapping,
+    /// Where the dump came from (e.g., "prod-2026-03.json")
+    pub source: Option<String>,
+}
+
+impl GraphImportInput {
+    /// Parse `apoc.export.json` output: JSON lines, or a JSON array.
+    pub fn from_neo4j(text: &str) -> Result<Self, AdapterError> {
+        let records: Vec<serde_json::Value> = match text.trim_start().starts_with('[') {
+            true => serde_json::from_str(text).map_err(|e| AdapterError::Internal(format!("neo4j export: {}", e)))?,
+            false => text
+                .lines()
+                .filter(|l| !l.trim().is_empty())
+                .map(serde_json::from_str)
+                .collect::<Result<_, _>>()
+                .map_err(|e| AdapterError::Internal(format!("neo4j export: {}", e)))?,
+        };
+        let mut input = Self::empty(DumpFormat::Neo4jApoc);
+        for record in &records {
+            let id = |v: Option<&serde_json::Value>| v.and_then(|v| v.as_str().map(String::from).or_else(|| v.as_i64().map(|i| i.to_string())));
+            let properties = record.get("properties").and_then(|p| p.as_object()).cloned().unwrap_or_default();
+            match record.get("type").and_then(|t| t.as_str()) {
+                Some("node") => {
+                    let Some(node_id) = id(record.get("id")) else { continue };
+                    let labels = record
+                        .get("labels")
+                        .and_then(|l| l.as_array())
+                        .map(|l| l.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+                        .unwrap_or_default();
+                    input.nodes.push(DumpNode { id: node_id, labels, properties });
+                }
+                Some("relationship") => {
+                    let (Some(source), Some(target)) = (
+                        id(record.get("start").and_then(|s| s.get("id"))),
+                        id(record.get("end").and_then(|e| e.get("id"))),
+                    ) else {
+                        continue;
+                    };
+                    let label = record.get("label").and_then(|l| l.as_str()).unwrap_or("related_to").to_string();
+                    input.edges.push(DumpEdge { label, source, target, properties });
+                }
+                _ => {}
+            }
+        }
+        Ok(input)
+    }
+
+    /// Parse ArangoDB documents grouped by collection. Each collection is
+    /// an array of documents or the JSON-lines text of a dump file.
+    pub fn from_arangodb(collections: &serde_json::Map<String, serde_json::Value>) -> Result<Self, AdapterError> {
+        let mut input = Self::empty(DumpFormat::ArangoDb);
+        for (collection, docs) in collections {
+            let docs: Vec<serde_json::Value> = match docs {
+                serde_json::Value::Array(docs) => docs.clone(),
+                serde_json::Value::String(text) => text
+                    .lines()
+                    .filter(|l| !l.trim().is_empty())
+                    .map(serde_json::from_str)
+                    .collect::<Result<_, _>>()
+                    .map_err(|e| AdapterError::Internal(format!("arangodb collection '{}': {}", collection, e)))?,
+                _ => return Err(AdapterError::Internal(format!("arangodb collection '{}' must be an array or JSON lines", collection))),
+            };
+            for doc in docs {
+                // arangodump envelopes: {"type": 2300, "data": {...}}
+                let doc = match doc.get("data") {
+                    Some(data) if doc.get("type").is_some_and(|t| t.is_number()) => data.clone(),
+                    _ => doc,
+                };
+                let Some(mut fields) = doc.as_object().cloned() else { continue };
+                let string = |key: &str| fields.get(key).and_then(|v| v.as_str()).map(String::from);
+                let key = string("_id").or_else(|| string("_key").map(|k| format!("{}/{}", collection, k)));
+                match (string("_from"), string("_to")) {
+                    (Some(source), Some(target)) => {
+                        fields.retain(|k, _| !k.starts_with('_'));
+                        input.edges.push(DumpEdge { label: collection.clone(), source, target, properties: fields });
+                    }
+                    _ => {
+                        let Some(id) = key else { continue };
+                        fields.retain(|k, _| !k.starts_with('_'));
+                        input.nodes.push(DumpNode { id, labels: vec![collection.clone()], properties: fields });
+                    }
+                }
+            }
+        }
+        Ok(input)
+    }
+
+    /// Parse a JSON ingest: `{"neo4j": <JSON lines or array>}` or
+    /// `{"arangodb": {collection: [documents] | "JSON lines"}}`, with
+    /// optional `mapping` (an `ImportMapping`) and `source` (string).
+    pub fn from_json(json: &serde_json::Value) -> Result<Self, AdapterError> {
+        let mut input = match (json.get("neo4j"), json.get("arangodb").and_then(|v| v.as_object())) {
+            (Some(serde_json::Value::String(text)), _) => Self::from_neo4j(text)?,
+            (Some(records @ serde_json::Value::Array(_)), _) => Self::from_neo4j(&records.to_string())?,
+            (_, Some(collections)) => Self::from_arangodb(collections)?,
+            _ => {
+                return Err(AdapterError::Internal(
+                    "graph import requires a 'neo4j' export or an 'arangodb' object of collections".into(),
+                ))
+            }
+        };
+        if let Some(mapping) = json.get("mapping") {
+            input.mapping = serde_json::from_value(mapping.clone())
+                .map_err(|e| AdapterError::Internal(format!("invalid import mapping: {}", e)))?;
+        }
+        input.source = json.get("source").and_then(|v| v.as_str()).map(String::from);
+        Ok(input)
+    }
+
+    pub fn with_mapping(mut self, mapping: ImportMapping) -> Self {
+        self.mapping = mapping;
+        self
+    }
+
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    fn empty(format: DumpFormat) -> Self {
+        Self { format, nodes: Vec::new(), edges: Vec::new(), mapping: ImportMapping::default(), source: None }
+    }
+}
+
+/// A dump value as a property; `null` has none.
+fn property_from_json(value: &serde_json::Value) -> Option<PropertyValue> {
+    Some(match value {
+        serde_json::Value::Null => return None,
+        serde_json::Value::Bool(b) => PropertyValue::Bool(*b),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => PropertyValue::Int(i),
+            None => PropertyValue::Float(n.as_f64()?),
+        },
+        serde_json::Value::String(s) => PropertyValue::String(s.clone()),
+        serde_json::Value::Array(items) => PropertyValue::Array(items.iter().filter_map(property_from_json).collect()),
+        serde_json::Value::Object(map) => {
+            PropertyValue::Object(map.iter().filter_map(|(k, v)| Some((k.clone(), property_from_json(v)?))).collect())
+        }
+    })
+}
+
+/// Neo4j and ArangoDB dump importer.
+pub struct GraphImportAdapter {
+    adapter_id: String,
+}
+
+impl GraphImportAdapter {
+    pub fn new(adapter_id: impl Into<String>) -> Self {
+        Self { adapter_id: adapter_id.into() }
+    }
+}
+
+#[async_trait]
+impl Adapter for GraphImportAdapter {
+    fn id(&self) -> &str {
+        &self.adapter_id
+    }
+
+    fn input_kind(&self) -> &str {
+        "graph-import"
+    }
+
+    fn input_from_json(&self, json: &serde_json::Value) -> Result<Box<dyn std::any::Any + Send + Sync>, AdapterError> {
+        Ok(Box::new(GraphImportInput::from_json(json)?))
+    }
+
+    fn input_schema(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({
+            "type": "object",
+            "anyOf": [{"required": ["neo4j"]}, {"required": ["arangodb"]}],
+            "properties": {
+                "neo4j": {"type": ["string", "array"]},
+                "arangodb": {"type": "object", "additionalProperties": {"type": ["array", "string"]}},
+                "mapping": {"type": "object"},
+                "source": {"type": "string"},
+            }
+        }))
+    }
+
+    async fn process(
+        &self,
+        input: &AdapterInput,
+        sink: &dyn AdapterSink,
+    ) -> Result<(), AdapterError> {
+        // Accept both typed GraphImportInput and raw JSON
+        let owned: GraphImportInput;
+        let dump: &GraphImportInput = if let Some(d) = input.downcast_data::<GraphImportInput>() {
+            d
+        } else if let Some(json) = input.downcast_data::<serde_json::Value>() {
+            owned = GraphImportInput::from_json(json)?;
+            &owned
+        } else {
+            return Err(AdapterError::InvalidInput);
+        };
+        if dump.nodes.is_empty() {
+            return Ok(());
+        }
+        let mapping = &dump.mapping;
+
+        let source = dump.source.as_deref().unwrap_or(dump.format.as_str());
+        let chain_id = format!("chain:{}:{}", self.adapter_id, source);
+        let mut chain = chain_node(&chain_id);
+        chain.properties.insert(
+            "name".to_string(),
+            PropertyValue::String(format!("{} — {}", self.adapter_id, source)),
+        );
+        chain.properties.insert("status".to_string(), PropertyValue::String("active".to_string()));
+        let mut emission = Emission::new().with_node(chain);
+
+        // Dump ID → (node ID, dimension), for resolving edge endpoints
+        let mut imported: HashMap<&str, (NodeId, String)> = HashMap::new();
+        for (index, dump_node) in dump.nodes.iter().enumerate() {
+            let mapped = dump_node.labels.iter().find_map(|l| mapping.nodes.get(l).map(|m| (l, m)));
+            if mapped.is_none() && mapping.skip_unmapped {
+                continue;
+            }
+            let label = mapped.map(|(l, _)| l.as_str()).or(dump_node.labels.first().map(String::as_str));
+            let node_mapping = mapped.map(|(_, m)| m.clone()).unwrap_or_default();
+            let node_type = node_mapping
+                .node_type
+                .unwrap_or_else(|| label.map_or_else(|| "node".to_string(), |l| l.to_lowercase()));
+            let node_dimension = node_mapping.dimension.unwrap_or_else(|| mapping.default_dimension.clone());
+            let key = node_mapping
+                .id_property
+                .and_then(|p| dump_node.properties.get(&p).and_then(|v| v.as_str().map(String::from).or_else(|| v.as_i64().map(|i| i.to_string()))))
+                .unwrap_or_else(|| dump_node.id.clone());
+
+            let mut node = Node::new_in_dimension(&node_type, ContentType::Document, &node_dimension);
+            node.id = NodeId::from_string(format!("{}:{}", node_type, key));
+            for (k, v) in &dump_node.properties {
+                if let Some(value) = property_from_json(v) {
+                    node.properties.insert(k.clone(), value);
+                }
+            }
+            if dump_node.labels.len() > 1 {
+                let labels = dump_node.labels.iter().map(|l| PropertyValue::String(l.clone())).collect();
+                node.properties.insert("labels".to_string(), PropertyValue::Array(labels));
+            }
+
+            let mut mark = mark_node(&format!("mark:{}:{}", self.adapter_id, node.id));
+            mark.properties.insert("chain_id".to_string(), PropertyValue::String(chain_id.clone()));
+            mark.properties.insert(
+                "annotation".to_string(),
+                PropertyValue::String(format!("{} {} {}", dump.format.as_str(), label.unwrap_or("node"), dump_node.id)),
+            );
+            mark.properties.insert("file".to_string(), PropertyValue::String(source.to_string()));
+            mark.properties.insert("line".to_string(), PropertyValue::Int(index as i64 + 1));
+            let contains = Edge::new_in_dimension(
+                NodeId::from_string(&chain_id),
+                mark.id.clone(),
+                "contains",
+                dimension::PROVENANCE,
+            );
+
+            imported.insert(dump_node.id.as_str(), (node.id.clone(), node_dimension));
+            emission = emission.with_node(node).with_node(mark).with_edge(contains);
+        }
+
+        for dump_edge in &dump.edges {
+            let relationship = match mapping.relationships.get(&dump_edge.label) {
+                Some(relationship) => relationship.clone(),
+                None if mapping.skip_unmapped => continue,
+                None => dump_edge.label.to_lowercase(),
+            };
+            let (Some((source, source_dim)), Some((target, target_dim))) =
+                (imported.get(dump_edge.source.as_str()), imported.get(dump_edge.target.as_str()))
+            else {
+                tracing::warn!(source = %dump_edge.source, target = %dump_edge.target, "import: edge endpoint not imported; skipped");
+                continue;
+            };
+            let mut edge = Edge::new_cross_dimensional(source.clone(), source_dim, target.clone(), target_dim, &relationship);
+            for (k, v) in &dump_edge.properties {
+                match (k.as_str(), v.as_f64()) {
+                    ("weight", Some(weight)) => edge.combined_weight = weight as f32,
+                    _ => {
+                        if let Some(value) = property_from_json(v) {
+                            edge.properties.insert(k.clone(), value);
+                        }
+                    }
+                }
+            }
+            emission = emission.with_edge(edge);
+        }
+
+        sink.emit(emission).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::{EngineSink, FrameworkContext};
+    use crate::graph::Context;
+    use std::sync::{Arc, Mutex};
+
+    const APOC: &str = r#"{"type":"node","id":"0","labels":["Person"],"properties":{"name":"Ada","email":"ada@example.org"}}
+{"type":"node","id":"1","labels":["Paper","Published"],"properties":{"title":"Notes","year":1843}}
+{"type":"node","id":"2","labels":["Venue"],"properties":{"name":"Nowhere"}}
+{"type":"relationship","id":"0","label":"AUTHORED","properties":{"weight":2.5,"role":"sole"},"start":{"id":"0","labels":["Person"]},"end":{"id":"1","labels":["Paper"]}}
+{"type":"relationship","id":"1","label":"CITES","properties":{},"start":{"id":"1"},"end":{"id":"9"}}
+"#;
+
+    fn make_sink(adapter_id: &str) -> (EngineSink, Arc<Mutex<Context>>) {
+        let ctx = Arc::new(Mutex::new(Context::new("test")));
+        let fw = FrameworkContext {
+            adapter_id: adapter_id.to_string(),
+            context_id: "test".to_string(),
+            input_summary: None,
+        };
+        let sink = EngineSink::new(ctx.clone()).with_framework_context(fw);
+        (sink, ctx)
+    }
+
+    fn mapping() -> ImportMapping {
+        serde_json::from_value(serde_json::json!({
+            "nodes": {
+                "Person": {"node_type": "person", "dimension": "relational", "id_property": "email"},
+                "Paper": {"node_type": "document"}
+            },
+            "relationships": {"AUTHORED": "authored"}
+        }))
+        .unwrap()
+    }
+
+    // === Scenario: An APOC export parses into nodes and relationships ===
+    #[test]
+    fn neo4j_apoc_lines_and_arrays_parse() {
+        let dump = GraphImportInput::from_neo4j(APOC).unwrap();
+        assert_eq!((dump.nodes.len(), dump.edges.len()), (3, 2));
+        assert_eq!(dump.nodes[1].labels, vec!["Paper", "Published"]);
+        assert_eq!((dump.edges[0].source.as_str(), dump.edges[0].target.as_str()), ("0", "1"));
+
+        let array = format!("[{}]", APOC.trim().lines().collect::<Vec<_>>().join(","));
+        assert_eq!(GraphImportInput::from_neo4j(&array).unwrap().nodes, dump.nodes);
+        assert!(GraphImportInput::from_neo4j("{not json").is_err());
+    }
+
+    // === Scenario: Mapped labels land in their dimension with stable IDs ===
+    #[tokio::test]
+    async fn neo4j_import_applies_mapping() {
+        let adapter = GraphImportAdapter::new("graph-import");
+        let (sink, ctx) = make_sink("graph-import");
+        let dump = GraphImportInput::from_neo4j(APOC).unwrap().with_mapping(mapping()).with_source("prod.json");
+        adapter.process(&AdapterInput::new("graph-import", dump, "test"), &sink).await.unwrap();
+
+        let ctx = ctx.lock().unwrap();
+        let ada = ctx.get_node(&NodeId::from_string("person:ada@example.org")).expect("keyed by id_property");
+        assert_eq!(ada.dimension, "relational");
+        let paper = ctx.get_node(&NodeId::from_string("document:1")).expect("keyed by dump id");
+        assert_eq!(paper.properties.get("year"), Some(&PropertyValue::Int(1843)));
+        assert!(paper.properties.contains_key("labels"));
+        let venue = ctx.get_node(&NodeId::from_string("venue:2")).expect("unmapped label passes through");
+        assert_eq!(venue.dimension, dimension::STRUCTURE);
+
+        let authored = ctx.edges().find(|e| e.relationship == "authored").expect("mapped relationship");
+        assert_eq!(authored.source.as_str(), "person:ada@example.org");
+        assert_eq!(authored.contributions.get("graph-import"), Some(&2.5), "weight is the import's contribution");
+        assert_eq!(authored.properties.get("role"), Some(&PropertyValue::from("sole")));
+        assert!(ctx.edges().all(|e| e.relationship != "cites"), "dangling endpoint skipped");
+        assert_eq!(ctx.nodes().filter(|n| n.node_type == "mark").count(), 3);
+    }
+
+    // === Scenario: ArangoDB collections, skipping what isn't mapped ===
+    #[tokio::test]
+    async fn arangodb_collections_import_through_json() {
+        let adapter = GraphImportAdapter::new("graph-import");
+        let (sink, ctx) = make_sink("graph-import");
+        let json = serde_json::json!({
+            "arangodb": {
+                "users": [{"_key": "u1", "_id": "users/u1", "_rev": "x", "name": "Grace"}],
+                "logs": "{\"_key\": \"l1\", \"line\": \"noise\"}\n",
+                "topics": "{\"type\": 2300, \"data\": {\"_key\": \"t1\", \"name\": \"compilers\"}}\n",
+                "follows": [{"_from": "users/u1", "_to": "topics/t1", "since": 1952}]
+            },
+            "mapping": {
+                "nodes": {"users": {"node_type": "person"}, "topics": {"node_type": "concept", "dimension": "semantic", "id_property": "name"}},
+                "relationships": {"follows": "interested_in"},
+                "skip_unmapped": true
+            }
+        });
+        adapter.process(&AdapterInput::new("graph-import", json, "test"), &sink).await.unwrap();
+
+        let ctx = ctx.lock().unwrap();
+        let grace = ctx.get_node(&NodeId::from_string("person:users/u1")).unwrap();
+        assert!(!grace.properties.contains_key("_rev"), "system attributes dropped");
+        assert!(ctx.get_node(&NodeId::from_string("concept:compilers")).is_some());
+        assert!(ctx.nodes().all(|n| n.node_type != "logs"), "unmapped collection skipped");
+        let edge = ctx.edges().find(|e| e.relationship == "interested_in").unwrap();
+        assert_eq!(edge.target_dimension, "semantic");
+        assert_eq!(edge.properties.get("since"), Some(&PropertyValue::Int(1952)));
+    }
+}
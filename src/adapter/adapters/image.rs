@@ -0,0 +1,454 @@
+//! Image metadata — EXIF and caption sidecars for image files
+//!
+//! `ImageMetadataModule` is a structural module for `image/` files. The
+//! coordinator hands modules file text, so this one reads the image
+//! itself: the EXIF block of a JPEG (`APP1`) or PNG (`eXIf`) gives the
+//! capture time, GPS position, and camera. A caption sidecar beside the
+//! image — `photo.jpg.txt`, `photo.txt`, or `photo.jpg.caption` — gives
+//! its caption and tags: YAML frontmatter `tags:` plus any `#hashtags`.
+//!
+//! Output is an `image` structure node `contains`ed by the file node, and
+//! a concept per tag that the image node is `tagged_with`, as frontmatter
+//! tags are for documents. The capture time is the image node's
+//! `created_at` (ADR-039), so temporal enrichments place it when it was
+//! taken rather than when it was ingested. EXIF times carry no zone and
+//! are read as UTC.
+
+use crate::adapter::adapters::extraction::{extract_tags_from_frontmatter, parse_frontmatter};
+use crate::adapter::adapters::structural::{ModuleEmission, StructuralModule, StructuralOutput};
+use crate::adapter::types::{concept_node, AnnotatedEdge, AnnotatedNode};
+use crate::graph::{dimension, ContentType, Edge, Node, NodeId, PropertyValue};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use std::path::{Path, PathBuf};
+
+/// What the EXIF block says about an image.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExifData {
+    /// `DateTimeOriginal`, else `DateTime`
+    pub taken_at: Option<DateTime<Utc>>,
+    /// Decimal degrees, south negative
+    pub latitude: Option<f64>,
+    /// Decimal degrees, west negative
+    pub longitude: Option<f64>,
+    /// Metres; below sea level negative
+    pub altitude: Option<f64>,
+    pub camera_make: Option<String>,
+    pub camera_model: Option<String>,
+}
+
+impl ExifData {
+    /// Read the EXIF block of JPEG or PNG bytes, if there is one.
+    pub fn from_image(bytes: &[u8]) -> Option<Self> {
+        let tiff = if bytes.starts_with(&[0xFF, 0xD8]) {
+            jpeg_exif(bytes)?
+        } else if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+            png_exif(bytes)?
+        } else {
+            return None;
+        };
+        parse_tiff(tiff)
+    }
+}
+
+/// The TIFF payload of a JPEG's `Exif` APP1 segment.
+fn jpeg_exif(bytes: &[u8]) -> Option<&[u8]> {
+    let mut at = 2;
+    while at + 4 <= bytes.len() && bytes[at] == 0xFF {
+        let marker = bytes[at + 1];
+        // Start of scan: image data follows, no more metadata segments
+        if marker == 0xDA {
+            return None;
+        }
+        let length = u16::from_be_bytes([bytes[at + 2], bytes[at + 3]]) as usize;
+        let segment = bytes.get(at + 4..at + 2 + length)?;
+        if marker == 0xE1 && segment.starts_with(b"Exif\0\0") {
+            return Some(&segment[6..]);
+        }
+        at += 2 + length;
+    }
+    None
+}
+
+/// The payload of a PNG's `eXIf` chunk.
+fn png_exif(bytes: &[u8]) -> Option<&[u8]> {
+    let mut at = 8;
+    while at + 8 <= bytes.len() {
+        let length = u32::from_be_bytes(bytes[at..at + 4].try_into().ok()?) as usize;
+        let kind = &bytes[at + 4..at + 8];
+        let data = bytes.get(at + 8..at + 8 + length)?;
+        if kind == b"eXIf" {
+            return Some(data);
+        }
+        if kind == b"IDAT" || kind == b"IEND" {
+            return None;
+        }
+        at += 12 + length;
+    }
+    None
+}
+
+struct Tiff<'a> {
+    data: &'a [u8],
+    little_endian: bool,
+}
+
+/// One IFD entry: tag, type, count, and the 4-byte value/offset field.
+struct Entry {
+    tag: u16,
+    kind: u16,
+    count: u32,
+    field: usize,
+}
+
+impl<'a> Tiff<'a> {
+    fn u16(&self, at: usize) -> Option<u16> {
+        let b: [u8; 2] = self.data.get(at..at + 2)?.try_into().ok()?;
+        Some(if self.little_endian { u16::from_le_bytes(b) } else { u16::from_be_bytes(b) })
+    }
+
+    fn u32(&self, at: usize) -> Option<u32> {
+        let b: [u8; 4] = self.data.get(at..at + 4)?.try_into().ok()?;
+        Some(if self.little_endian { u32::from_le_bytes(b) } else { u32::from_be_bytes(b) })
+    }
+
+    fn entries(&self, ifd: usize) -> Vec<Entry> {
+        let Some(count) = self.u16(ifd) else { return Vec::new() };
+        (0..count as usize)
+            .map_while(|i| {
+                let at = ifd + 2 + i * 12;
+                Some(Entry { tag: self.u16(at)?, kind: self.u16(at + 2)?, count: self.u32(at + 4)?, field: at + 8 })
+            })
+            .collect()
+    }
+
+    /// Where an entry's value lives: inline when it fits in 4 bytes.
+    fn value_offset(&self, entry: &Entry) -> Option<usize> {
+        let unit = match entry.kind {
+            1 | 2 | 7 => 1,
+            3 => 2,
+            4 | 9 => 4,
+            5 | 10 => 8,
+            _ => return None,
+        };
+        if unit * entry.count as usize <= 4 {
+            Some(entry.field)
+        } else {
+            self.u32(entry.field).map(|o| o as usize)
+        }
+    }
+
+    fn ascii(&self, entry: &Entry) -> Option<String> {
+        let at = self.value_offset(entry)?;
+        let raw = self.data.get(at..at + entry.count as usize)?;
+        let text = String::from_utf8_lossy(raw).trim_end_matches('\0').trim().to_string();
+        (!text.is_empty()).then_some(text)
+    }
+
+    fn long(&self, entry: &Entry) -> Option<u32> {
+        match entry.kind {
+            3 => self.u16(entry.field).map(u32::from),
+            4 => self.u32(entry.field),
+            _ => None,
+        }
+    }
+
+    fn rationals(&self, entry: &Entry) -> Option<Vec<f64>> {
+        if entry.kind != 5 {
+            return None;
+        }
+        let at = self.value_offset(entry)?;
+        (0..entry.count as usize)
+            .map(|i| {
+                let (n, d) = (self.u32(at + i * 8)?, self.u32(at + i * 8 + 4)?);
+                Some(if d == 0 { 0.0 } else { n as f64 / d as f64 })
+            })
+            .collect()
+    }
+}
+
+fn parse_tiff(data: &[u8]) -> Option<ExifData> {
+    let little_endian = match data.get(..2)? {
+        b"II" => true,
+        b"MM" => false,
+        _ => return None,
+    };
+    let tiff = Tiff { data, little_endian };
+    if tiff.u16(2)? != 42 {
+        return None;
+    }
+    let mut exif = ExifData::default();
+    let mut modified = None;
+    let (mut exif_ifd, mut gps_ifd) = (None, None);
+    for entry in tiff.entries(tiff.u32(4)? as usize) {
+        match entry.tag {
+            0x010F => exif.camera_make = tiff.ascii(&entry),
+            0x0110 => exif.camera_model = tiff.ascii(&entry),
+            0x0132 => modified = tiff.ascii(&entry).and_then(|s| exif_time(&s)),
+            0x8769 => exif_ifd = tiff.long(&entry),
+            0x8825 => gps_ifd = tiff.long(&entry),
+            _ => {}
+        }
+    }
+    if let Some(ifd) = exif_ifd {
+        exif.taken_at = tiff
+            .entries(ifd as usize)
+            .iter()
+            .find(|e| e.tag == 0x9003)
+            .and_then(|e| tiff.ascii(e))
+            .and_then(|s| exif_time(&s));
+    }
+    exif.taken_at = exif.taken_at.or(modified);
+
+    if let Some(ifd) = gps_ifd {
+        let entries = tiff.entries(ifd as usize);
+        let find = |tag: u16| entries.iter().find(|e| e.tag == tag);
+        let reference = |tag| find(tag).and_then(|e| tiff.ascii(e));
+        let degrees = |tag| {
+            let dms = find(tag).and_then(|e| tiff.rationals(e))?;
+            Some(dms.first()? + dms.get(1).unwrap_or(&0.0) / 60.0 + dms.get(2).unwrap_or(&0.0) / 3600.0)
+        };
+        exif.latitude = degrees(2).map(|d| if reference(1).as_deref() == Some("S") { -d } else { d });
+        exif.longitude = degrees(4).map(|d| if reference(3).as_deref() == Some("W") { -d } else { d });
+        exif.altitude = find(6).and_then(|e| tiff.rationals(e)).and_then(|r| r.first().copied()).map(|a| {
+            let below = find(5).and_then(|e| tiff.value_offset(e)).and_then(|at| data.get(at)) == Some(&1);
+            if below { -a } else { a }
+        });
+    }
+    Some(exif)
+}
+
+/// `YYYY:MM:DD HH:MM:SS`, read as UTC.
+fn exif_time(value: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(value.trim(), "%Y:%m:%d %H:%M:%S").ok().map(|t| t.and_utc())
+}
+
+/// A caption sidecar's text and tags.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Caption {
+    pub text: String,
+    pub tags: Vec<String>,
+}
+
+impl Caption {
+    /// Parse sidecar content: optional YAML frontmatter with `tags:`,
+    /// then the caption, whose `#hashtags` are tags too. Tags are
+    /// lowercased, as frontmatter tags are.
+    pub fn parse(content: &str) -> Self {
+        let mut tags = parse_frontmatter(content)
+            .and_then(Result::ok)
+            .map(|front| extract_tags_from_frontmatter(&front))
+            .unwrap_or_default();
+        let mut body = content.trim();
+        if let Some(end) = body.strip_prefix("---").and_then(|after| after.find("\n---")) {
+            body = body[3 + end + 4..].trim();
+        }
+        for word in body.split_whitespace() {
+            if let Some(tag) = word.strip_prefix('#') {
+                let tag = tag.trim_end_matches(|c: char| !c.is_alphanumeric() && c != '-' && c != '_');
+                if !tag.is_empty() {
+                    tags.push(tag.to_lowercase());
+                }
+            }
+        }
+        let mut seen = std::collections::HashSet::new();
+        tags.retain(|t| seen.insert(t.clone()));
+        Self { text: body.to_string(), tags }
+    }
+}
+
+/// The caption sidecar paths checked for an image, in order.
+pub fn caption_sidecars(image_path: &Path) -> Vec<PathBuf> {
+    let with_suffix = |suffix: &str| {
+        let mut name = image_path.as_os_str().to_os_string();
+        name.push(suffix);
+        PathBuf::from(name)
+    };
+    vec![with_suffix(".txt"), image_path.with_extension("txt"), with_suffix(".caption")]
+}
+
+/// Structural module for image metadata (MIME affinity `image/`).
+#[derive(Debug, Default)]
+pub struct ImageMetadataModule;
+
+impl ImageMetadataModule {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl StructuralModule for ImageMetadataModule {
+    fn id(&self) -> &str {
+        "extract-analysis-image-metadata"
+    }
+
+    fn mime_affinity(&self) -> &str {
+        "image/"
+    }
+
+    async fn analyze(&self, file_path: &str, _content: &str) -> StructuralOutput {
+        let path = Path::new(file_path);
+        let exif = std::fs::read(path).ok().and_then(|bytes| ExifData::from_image(&bytes));
+        let caption = caption_sidecars(path)
+            .into_iter()
+            .find_map(|sidecar| std::fs::read_to_string(sidecar).ok())
+            .map(|content| Caption::parse(&content));
+        if exif.is_none() && caption.is_none() {
+            return StructuralOutput::default();
+        }
+
+        let image_id = NodeId::from_string(format!("image:{}", file_path));
+        let mut image = Node::new_in_dimension("image", ContentType::Document, dimension::STRUCTURE);
+        image.id = image_id.clone();
+        let mut set = |key: &str, value: PropertyValue| {
+            image.properties.insert(key.to_string(), value);
+        };
+        if let Some(ref exif) = exif {
+            if let Some(taken_at) = exif.taken_at {
+                set("taken_at", PropertyValue::String(taken_at.to_rfc3339()));
+                set("created_at", PropertyValue::String(taken_at.to_rfc3339()));
+            }
+            for (key, value) in [("latitude", exif.latitude), ("longitude", exif.longitude), ("altitude", exif.altitude)] {
+                if let Some(value) = value {
+                    set(key, PropertyValue::Float(value));
+                }
+            }
+            for (key, value) in [("camera_make", &exif.camera_make), ("camera_model", &exif.camera_model)] {
+                if let Some(value) = value {
+                    set(key, PropertyValue::String(value.clone()));
+                }
+            }
+        }
+        if let Some(ref caption) = caption {
+            if !caption.text.is_empty() {
+                set("caption", PropertyValue::String(caption.text.clone()));
+            }
+        }
+
+        let file_id = NodeId::from_string(format!("file:{}", file_path));
+        let mut nodes = vec![AnnotatedNode::new(image)];
+        let mut edges = vec![AnnotatedEdge::new(Edge::new_in_dimension(
+            file_id,
+            image_id.clone(),
+            "contains",
+            dimension::STRUCTURE,
+        ))];
+        let tags = caption.map(|c| c.tags).unwrap_or_default();
+        for tag in &tags {
+            let (concept_id, concept) = concept_node(tag);
+            let mut edge = Edge::new_cross_dimensional(
+                image_id.clone(),
+                dimension::STRUCTURE,
+                concept_id,
+                dimension::SEMANTIC,
+                "tagged_with",
+            );
+            edge.combined_weight = 1.0;
+            nodes.push(AnnotatedNode::new(concept));
+            edges.push(AnnotatedEdge::new(edge));
+        }
+
+        StructuralOutput {
+            vocabulary: tags.clone(),
+            emissions: vec![ModuleEmission { module_id: self.id().to_string(), nodes, edges }],
+            ..StructuralOutput::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A little-endian TIFF block: camera make, a capture time, and a GPS
+    /// position of 37°58'12"N 23°43'36"E.
+    fn tiff() -> Vec<u8> {
+        let mut t: Vec<u8> = b"II\x2a\x00\x08\x00\x00\x00".to_vec();
+        let entry = |t: &mut Vec<u8>, tag: u16, kind: u16, count: u32, value: u32| {
+            t.extend(tag.to_le_bytes());
+            t.extend(kind.to_le_bytes());
+            t.extend(count.to_le_bytes());
+            t.extend(value.to_le_bytes());
+        };
+        // IFD0 at 8: 3 entries → ends at 8 + 2 + 36 + 4 = 50
+        t.extend(3u16.to_le_bytes());
+        entry(&mut t, 0x010F, 2, 4, u32::from_le_bytes(*b"Leic"));
+        entry(&mut t, 0x8769, 4, 1, 50);
+        entry(&mut t, 0x8825, 4, 1, 90);
+        t.extend(0u32.to_le_bytes());
+        // Exif IFD at 50: 1 entry → ends at 68; the time string at 68
+        t.extend(1u16.to_le_bytes());
+        entry(&mut t, 0x9003, 2, 20, 68);
+        t.extend(0u32.to_le_bytes());
+        assert_eq!(t.len(), 68);
+        t.extend(b"2025:06:14 09:30:00\0");
+        t.extend([0u8; 2]);
+        // GPS IFD at 90: 4 entries → ends at 90 + 2 + 48 + 4 = 144
+        assert_eq!(t.len(), 90);
+        t.extend(4u16.to_le_bytes());
+        entry(&mut t, 1, 2, 2, u32::from_le_bytes(*b"N\0\0\0"));
+        entry(&mut t, 2, 5, 3, 144);
+        entry(&mut t, 3, 2, 2, u32::from_le_bytes(*b"E\0\0\0"));
+        entry(&mut t, 4, 5, 3, 168);
+        t.extend(0u32.to_le_bytes());
+        for (n, d) in [(37, 1), (58, 1), (12, 1), (23, 1), (43, 1), (36, 1)] {
+            t.extend((n as u32).to_le_bytes());
+            t.extend((d as u32).to_le_bytes());
+        }
+        t
+    }
+
+    fn jpeg(tiff: &[u8]) -> Vec<u8> {
+        let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE1];
+        jpeg.extend(((tiff.len() + 8) as u16).to_be_bytes());
+        jpeg.extend(b"Exif\0\0");
+        jpeg.extend(tiff);
+        jpeg.extend([0xFF, 0xDA, 0x00, 0x02, 0xFF, 0xD9]);
+        jpeg
+    }
+
+    // === Scenario: EXIF capture time and position are read from a JPEG ===
+    #[test]
+    fn exif_reads_time_camera_and_gps() {
+        let exif = ExifData::from_image(&jpeg(&tiff())).expect("exif block");
+        assert_eq!(exif.taken_at.unwrap().to_rfc3339(), "2025-06-14T09:30:00+00:00");
+        assert_eq!(exif.camera_make.as_deref(), Some("Leic"));
+        assert!((exif.latitude.unwrap() - 37.97).abs() < 1e-9);
+        assert!((exif.longitude.unwrap() - 23.7266).abs() < 1e-3);
+        assert!(ExifData::from_image(b"not an image").is_none());
+    }
+
+    // === Scenario: Sidecar captions carry frontmatter tags and hashtags ===
+    #[test]
+    fn captions_parse_frontmatter_and_hashtags() {
+        let caption = Caption::parse("---\ntags: [ruins, Athens]\n---\nColumns of the #Erechtheion, #ruins again.");
+        assert_eq!(caption.text, "Columns of the #Erechtheion, #ruins again.");
+        assert_eq!(caption.tags, vec!["ruins", "athens", "erechtheion"]);
+        assert_eq!(Caption::parse("plain").tags, Vec::<String>::new());
+    }
+
+    // === Scenario: The module emits an image node tagged from its sidecar ===
+    #[tokio::test]
+    async fn module_emits_image_node_and_concepts() {
+        let dir = tempfile::tempdir().unwrap();
+        let image = dir.path().join("temple.jpg");
+        std::fs::write(&image, jpeg(&tiff())).unwrap();
+        std::fs::write(dir.path().join("temple.txt"), "Field notes #ruins").unwrap();
+        let path = image.to_str().unwrap();
+
+        let output = ImageMetadataModule::new().analyze(path, "").await;
+        assert_eq!(output.vocabulary, vec!["ruins"]);
+        let emission = &output.emissions[0];
+        let node = &emission.nodes[0].node;
+        assert_eq!(node.id.as_str(), format!("image:{path}"));
+        assert_eq!(node.properties.get("created_at"), Some(&PropertyValue::from("2025-06-14T09:30:00+00:00")));
+        assert_eq!(node.properties.get("caption"), Some(&PropertyValue::from("Field notes #ruins")));
+        assert!(emission.edges.iter().any(|e| e.edge.relationship == "tagged_with"
+            && e.edge.target.as_str() == "concept:ruins"));
+
+        let bare = dir.path().join("bare.png");
+        std::fs::write(&bare, b"\x89PNG\r\n\x1a\n").unwrap();
+        assert!(ImageMetadataModule::new().analyze(bare.to_str().unwrap(), "").await.emissions.is_empty());
+    }
+}
@@ -0,0 +1,247 @@
+//! Link resolution for structural analysis
+//!
+//! Structural modules report the document links they find as
+//! `LinkReference`s (relative paths, reference-style links, links inside
+//! footnotes, and `[[wikilinks]]`). Modules can't see the graph, so the
+//! extraction coordinator resolves them against the context: a link to a
+//! document already registered as a `file:{path}` node becomes a
+//! `links_to` edge to it; otherwise the edge goes to a placeholder file
+//! node flagged `unresolved`, which the real document's registration
+//! later replaces.
+//!
+//! Wikilinks name a document rather than a path. They resolve to a file
+//! node or `Source::File` whose name matches, then to `{name}.md` inside
+//! a `Source::Directory`, and otherwise to `{name}.md` beside the linking
+//! file. A file node's `aliases` property (set from frontmatter) is the
+//! alias registry: wikilinks to any listed name resolve to that file.
+
+use crate::adapter::types::{file_node, Emission};
+use crate::graph::{dimension, Context, Edge, NodeId, PropertyValue, Source};
+use std::collections::HashSet;
+use std::path::{Component, Path, PathBuf};
+
+/// Adapter ID credited with resolved link edges.
+pub const LINK_RESOLUTION_ID: &str = "extract-link-resolution";
+/// Property set to `true` on placeholder nodes for missing link targets.
+pub const UNRESOLVED_PROPERTY: &str = "unresolved";
+/// File node property listing other names wikilinks may use for it.
+pub const ALIASES_PROPERTY: &str = "aliases";
+
+/// How a link was written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkKind {
+    /// `[text](path)`
+    Inline,
+    /// `[text][ref]`, `[text][]`, or `[ref]` with a `[ref]: path` definition
+    Reference,
+    /// `[[name]]` or `[[name|text]]`
+    Wiki,
+    /// Any link inside a footnote definition
+    Footnote,
+    /// A document named in frontmatter (`related: [other, ../b.md]`)
+    Frontmatter,
+}
+
+impl LinkKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            LinkKind::Inline => "inline",
+            LinkKind::Reference => "reference",
+            LinkKind::Wiki => "wiki",
+            LinkKind::Footnote => "footnote",
+            LinkKind::Frontmatter => "frontmatter",
+        }
+    }
+}
+
+/// A document link found by a structural module.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkReference {
+    /// Link destination as written, minus any `#fragment` or `?query`
+    pub target: String,
+    pub kind: LinkKind,
+}
+
+impl LinkReference {
+    /// A link to another document, or `None` for URLs, mail links, and
+    /// same-document anchors.
+    pub fn new(destination: &str, kind: LinkKind) -> Option<Self> {
+        let target = destination.split(['#', '?']).next().unwrap_or("").trim();
+        if target.is_empty() || target.contains("://") || target.starts_with("mailto:") {
+            return None;
+        }
+        Some(Self { target: target.to_string(), kind })
+    }
+
+    /// Whether the target names a document (wikilink style) rather than
+    /// giving a path to it.
+    fn by_name(&self) -> bool {
+        match self.kind {
+            LinkKind::Wiki => true,
+            LinkKind::Frontmatter => !self.target.contains('/') && Path::new(&self.target).extension().is_none(),
+            _ => false,
+        }
+    }
+}
+
+/// `links_to` edges (and placeholder nodes) from `source_path`'s file
+/// node to each linked document, resolved against `context`.
+pub fn resolve_links(source_path: &str, links: &[LinkReference], context: &Context) -> Emission {
+    resolve_links_as(source_path, links, context, "links_to")
+}
+
+/// `resolve_links` with edges of `relationship` instead of `links_to`.
+pub fn resolve_links_as(source_path: &str, links: &[LinkReference], context: &Context, relationship: &str) -> Emission {
+    let source_id = NodeId::from_string(format!("file:{}", source_path));
+    let base = Path::new(source_path).parent().unwrap_or(Path::new(""));
+    let mut emission = Emission::new();
+    let mut linked = HashSet::new();
+
+    for link in links {
+        let path = if link.by_name() {
+            resolve_wiki(&link.target, base, context)
+        } else {
+            normalize(&base.join(&link.target))
+        };
+        if path == source_path || !linked.insert(path.clone()) {
+            continue;
+        }
+        let target_id = NodeId::from_string(format!("file:{}", path));
+        if context.get_node(&target_id).is_none() {
+            let mut placeholder = file_node(&path);
+            placeholder.properties.insert("path".to_string(), PropertyValue::String(path.clone()));
+            placeholder.properties.insert(UNRESOLVED_PROPERTY.to_string(), PropertyValue::Bool(true));
+            emission = emission.with_node(placeholder);
+        }
+        let edge = Edge::new_in_dimension(source_id.clone(), target_id, relationship, dimension::STRUCTURE)
+            .with_property("link_kind", link.kind.as_str());
+        emission = emission.with_edge(edge);
+    }
+    emission
+}
+
+/// Resolve `.` and `..` lexically.
+fn normalize(path: &Path) -> String {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push("..");
+                }
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized.to_string_lossy().into_owned()
+}
+
+/// The path a `[[name]]` wikilink refers to. Registered documents also
+/// answer to their `ALIASES_PROPERTY` names.
+fn resolve_wiki(name: &str, base: &Path, context: &Context) -> String {
+    let file_name = if Path::new(name).extension().is_some() { name.to_string() } else { format!("{}.md", name) };
+    let matches = |path: &str| {
+        let path = Path::new(path);
+        let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+        let full = path.file_name().and_then(|s| s.to_str()).unwrap_or("");
+        stem.eq_ignore_ascii_case(name) || full.eq_ignore_ascii_case(&file_name) || path.ends_with(&file_name)
+    };
+
+    let is_alias = |node: &crate::graph::Node| match node.properties.get(ALIASES_PROPERTY) {
+        Some(PropertyValue::Array(aliases)) => {
+            aliases.iter().any(|a| matches!(a, PropertyValue::String(a) if a.eq_ignore_ascii_case(name)))
+        }
+        _ => false,
+    };
+    // File nodes may be retyped by frontmatter; the ID prefix stays
+    let registered = context
+        .nodes
+        .values()
+        .filter(|n| !matches!(n.properties.get(UNRESOLVED_PROPERTY), Some(PropertyValue::Bool(true))))
+        .filter_map(|n| n.id.as_str().strip_prefix("file:").map(|path| (n, path)))
+        .filter(|(n, path)| matches(path) || is_alias(n))
+        .map(|(_, path)| path)
+        .min();
+    if let Some(path) = registered {
+        return path.to_string();
+    }
+    for source in &context.metadata.sources {
+        match source {
+            Source::File { path } if matches(path) => return path.clone(),
+            Source::Directory { path, .. } => {
+                let candidate = Path::new(path).join(&file_name);
+                if candidate.is_file() {
+                    return normalize(&candidate);
+                }
+            }
+            _ => {}
+        }
+    }
+    normalize(&base.join(file_name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link(target: &str, kind: LinkKind) -> LinkReference {
+        LinkReference::new(target, kind).unwrap()
+    }
+
+    #[test]
+    fn external_and_anchor_links_are_not_document_links() {
+        assert!(LinkReference::new("https://example.com/a.md", LinkKind::Inline).is_none());
+        assert!(LinkReference::new("mailto:someone@example.com", LinkKind::Inline).is_none());
+        assert!(LinkReference::new("#usage", LinkKind::Inline).is_none());
+        assert_eq!(link("guide.md#install", LinkKind::Inline).target, "guide.md");
+    }
+
+    #[test]
+    fn links_resolve_to_existing_nodes_or_unresolved_placeholders() {
+        let mut ctx = Context::new("notes");
+        ctx.add_node(file_node("/notes/guide.md"));
+        ctx.add_node(file_node("/notes/people/ada.md"));
+        ctx.metadata.sources.push(Source::File { path: "/archive/Glossary.md".into() });
+
+        let links = [
+            link("../guide.md", LinkKind::Inline),
+            link("./missing.md", LinkKind::Reference),
+            link("Ada", LinkKind::Wiki),
+            link("glossary", LinkKind::Wiki),
+            link("Nowhere", LinkKind::Wiki),
+            link("guide.md", LinkKind::Footnote),
+        ];
+        let emission = resolve_links("/notes/daily/today.md", &links, &ctx);
+
+        let targets: Vec<&str> = emission.edges.iter().map(|e| e.edge.target.as_str()).collect();
+        assert_eq!(
+            targets,
+            vec![
+                "file:/notes/guide.md",
+                "file:/notes/daily/missing.md",
+                "file:/notes/people/ada.md",
+                "file:/archive/Glossary.md",
+                "file:/notes/daily/Nowhere.md",
+                "file:/notes/daily/guide.md",
+            ]
+        );
+        assert!(emission.edges.iter().all(|e| e.edge.source.as_str() == "file:/notes/daily/today.md"));
+        assert_eq!(emission.edges[2].edge.properties.get("link_kind"), Some(&PropertyValue::String("wiki".into())));
+
+        let placeholders: Vec<&str> = emission.nodes.iter().map(|n| n.node.id.as_str()).collect();
+        assert_eq!(
+            placeholders,
+            vec![
+                "file:/notes/daily/missing.md",
+                "file:/archive/Glossary.md",
+                "file:/notes/daily/Nowhere.md",
+                "file:/notes/daily/guide.md",
+            ]
+        );
+        assert!(emission
+            .nodes
+            .iter()
+            .all(|n| n.node.properties.get(UNRESOLVED_PROPERTY) == Some(&PropertyValue::Bool(true))));
+    }
+}
@@ -4,10 +4,20 @@
 //! See ADR-001 (sink-based emission), ADR-022 (phased extraction),
 //! ADR-028 (declarative adapter specs).
 
+pub mod api_spec;
+pub mod calendar;
+pub mod chunking;
 pub mod content;
+pub mod conversation;
 pub mod declarative;
 pub mod extraction;
 pub mod graph_analysis;
+pub mod graph_import;
+pub mod image;
+pub mod links;
+pub mod notebook;
+pub mod pkm;
 pub mod provenance_adapter;
 pub mod semantic;
 pub mod structural;
+pub mod transcript;
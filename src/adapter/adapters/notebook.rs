@@ -0,0 +1,314 @@
+//! Jupyter notebooks — cells, execution order, imports, and outputs
+//!
+//! `NotebookStructureModule` is a structural module for `.ipynb` files
+//! (nbformat 4). It maps a notebook to:
+//! - A `cell` node per cell (structure dimension), `contains`ed by the
+//!   file node, each `follows` the cell above it
+//! - `executed_after` edges in execution-count order, which is often not
+//!   document order — the order the notebook's state was actually built in
+//! - A `module` node per imported module (relational dimension) that the
+//!   importing cell `imports`, from Python `import`/`from` lines and R
+//!   `library()`/`require()` calls
+//! - An `output` node per cell output (structure dimension) that the cell
+//!   `produces`: text is kept (truncated), rich outputs by MIME type only
+//!
+//! Markdown cell headings and imported module names become vocabulary.
+
+use crate::adapter::adapters::structural::{AnalysisFailure, ModuleEmission, StructuralModule, StructuralOutput};
+use crate::adapter::types::{AnnotatedEdge, AnnotatedNode};
+use crate::graph::{dimension, ContentType, Edge, Node, NodeId, PropertyValue};
+use async_trait::async_trait;
+use serde_json::Value;
+
+/// Longest output text kept on an output node, in characters.
+pub const MAX_OUTPUT_CHARS: usize = 2000;
+
+/// A cell's `source`: a string, or an array of lines.
+fn joined(value: Option<&Value>) -> String {
+    match value {
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Array(lines)) => lines.iter().filter_map(|l| l.as_str()).collect(),
+        _ => String::new(),
+    }
+}
+
+/// Modules a code cell imports: Python `import a, b.c as d` and
+/// `from a.b import c`, and R `library(x)`/`require(x)`. Relative
+/// imports and shell/magic lines are skipped.
+pub fn cell_imports(source: &str) -> Vec<String> {
+    let mut modules: Vec<String> = Vec::new();
+    let mut push = |name: &str| {
+        let name = name.trim().trim_matches(|c| c == '"' || c == '\'');
+        let valid = !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '.');
+        if valid && !name.starts_with('.') && !modules.iter().any(|m| m == name) {
+            modules.push(name.to_string());
+        }
+    };
+    for line in source.lines().map(str::trim) {
+        if let Some(rest) = line.strip_prefix("import ") {
+            for part in rest.split(',') {
+                push(part.split_whitespace().next().unwrap_or(""));
+            }
+        } else if let Some(rest) = line.strip_prefix("from ") {
+            if let Some((module, _)) = rest.split_once(" import ") {
+                push(module);
+            }
+        } else {
+            for call in ["library(", "require("] {
+                if let Some(at) = line.find(call) {
+                    let rest = &line[at + call.len()..];
+                    push(rest.split([')', ',']).next().unwrap_or(""));
+                }
+            }
+        }
+    }
+    modules
+}
+
+/// An output's kind and text: stream text, `text/plain` data, or an
+/// error's `ename: evalue`; plus the MIME types of rich data.
+fn describe_output(output: &Value) -> (String, Option<String>, Vec<String>) {
+    let kind = output.get("output_type").and_then(|v| v.as_str()).unwrap_or("unknown").to_string();
+    let data = output.get("data").and_then(|v| v.as_object());
+    let mime_types = data.map(|d| d.keys().cloned().collect()).unwrap_or_default();
+    let text = match kind.as_str() {
+        "stream" => Some(joined(output.get("text"))),
+        "error" => {
+            let field = |k| output.get(k).and_then(|v: &Value| v.as_str()).unwrap_or("");
+            Some(format!("{}: {}", field("ename"), field("evalue")))
+        }
+        _ => data.map(|d| joined(d.get("text/plain"))),
+    };
+    let text = text
+        .map(|t| t.trim().chars().take(MAX_OUTPUT_CHARS).collect::<String>())
+        .filter(|t| !t.is_empty());
+    (kind, text, mime_types)
+}
+
+/// Structural module for Jupyter notebooks (MIME affinity
+/// `application/x-ipynb+json`).
+#[derive(Debug, Default)]
+pub struct NotebookStructureModule;
+
+impl NotebookStructureModule {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl StructuralModule for NotebookStructureModule {
+    fn id(&self) -> &str {
+        "extract-analysis-notebook-structure"
+    }
+
+    fn mime_affinity(&self) -> &str {
+        "application/x-ipynb+json"
+    }
+
+    async fn analyze(&self, file_path: &str, content: &str) -> StructuralOutput {
+        self.analyze_reporting(file_path, content).await.0
+    }
+
+    async fn analyze_reporting(&self, file_path: &str, content: &str) -> (StructuralOutput, Vec<AnalysisFailure>) {
+        let notebook = match serde_json::from_str::<Value>(content) {
+            Ok(notebook) => notebook,
+            Err(e) => {
+                let failure = AnalysisFailure::new(self.id(), format!("invalid notebook JSON: {}", e));
+                return (StructuralOutput::default(), vec![failure]);
+            }
+        };
+        let Some(cells) = notebook.get("cells").and_then(|v| v.as_array()) else {
+            return (StructuralOutput::default(), Vec::new());
+        };
+        let language = notebook
+            .pointer("/metadata/language_info/name")
+            .or_else(|| notebook.pointer("/metadata/kernelspec/language"))
+            .and_then(|v| v.as_str());
+
+        let file_id = NodeId::from_string(format!("file:{}", file_path));
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        let mut vocabulary: Vec<String> = Vec::new();
+        let mut add_term = |term: &str| {
+            let term = term.trim().to_lowercase();
+            if !term.is_empty() && !vocabulary.contains(&term) {
+                vocabulary.push(term);
+            }
+        };
+        let mut executed: Vec<(i64, NodeId)> = Vec::new();
+        let mut previous: Option<NodeId> = None;
+
+        for (index, cell) in cells.iter().enumerate() {
+            let position = index + 1;
+            let cell_type = cell.get("cell_type").and_then(|v| v.as_str()).unwrap_or("raw");
+            let source = joined(cell.get("source"));
+            let cell_id = NodeId::from_string(format!("cell:{}:{}", file_path, position));
+            let mut node = Node::new_in_dimension("cell", ContentType::Document, dimension::STRUCTURE);
+            node.id = cell_id.clone();
+            node.properties.insert("cell_type".into(), PropertyValue::String(cell_type.to_string()));
+            node.properties.insert("index".into(), PropertyValue::Int(position as i64));
+            if let Some(label) = source.lines().map(str::trim).find(|l| !l.is_empty()) {
+                node.properties.insert("label".into(), PropertyValue::String(label.to_string()));
+            }
+            node.properties.insert("text".into(), PropertyValue::String(source.clone()));
+            if let Some(count) = cell.get("execution_count").and_then(|v| v.as_i64()) {
+                node.properties.insert("execution_count".into(), PropertyValue::Int(count));
+                executed.push((count, cell_id.clone()));
+            }
+            if cell_type == "code" {
+                if let Some(language) = language {
+                    node.properties.insert("language".into(), PropertyValue::String(language.to_string()));
+                }
+            }
+            nodes.push(AnnotatedNode::new(node));
+            edges.push(AnnotatedEdge::new(Edge::new_in_dimension(
+                file_id.clone(),
+                cell_id.clone(),
+                "contains",
+                dimension::STRUCTURE,
+            )));
+            if let Some(before) = previous.replace(cell_id.clone()) {
+                edges.push(AnnotatedEdge::new(Edge::new_in_dimension(
+                    cell_id.clone(),
+                    before,
+                    "follows",
+                    dimension::STRUCTURE,
+                )));
+            }
+
+            match cell_type {
+                "markdown" => {
+                    for heading in source.lines().filter_map(|l| l.trim_start().strip_prefix('#')) {
+                        add_term(heading.trim_start_matches('#'));
+                    }
+                }
+                "code" => {
+                    for module in cell_imports(&source) {
+                        add_term(&module);
+                        let mut module_node = Node::new_in_dimension(
+                            "module",
+                            ContentType::Other("module".to_string()),
+                            dimension::RELATIONAL,
+                        );
+                        module_node.id = NodeId::from_string(format!("module:{}", module));
+                        module_node.properties.insert("name".into(), PropertyValue::String(module));
+                        edges.push(AnnotatedEdge::new(Edge::new_cross_dimensional(
+                            cell_id.clone(),
+                            dimension::STRUCTURE,
+                            module_node.id.clone(),
+                            dimension::RELATIONAL,
+                            "imports",
+                        )));
+                        nodes.push(AnnotatedNode::new(module_node));
+                    }
+                    let outputs = cell.get("outputs").and_then(|v| v.as_array()).into_iter().flatten();
+                    for (k, output) in outputs.enumerate() {
+                        let (kind, text, mime_types) = describe_output(output);
+                        let output_id = NodeId::from_string(format!("output:{}:{}:{}", file_path, position, k + 1));
+                        let mut output_node =
+                            Node::new_in_dimension("output", ContentType::Document, dimension::STRUCTURE);
+                        output_node.id = output_id.clone();
+                        output_node.properties.insert("output_type".into(), PropertyValue::String(kind));
+                        if let Some(text) = text {
+                            output_node.properties.insert("text".into(), PropertyValue::String(text));
+                        }
+                        if !mime_types.is_empty() {
+                            let mime_types = mime_types.into_iter().map(PropertyValue::String).collect();
+                            output_node.properties.insert("mime_types".into(), PropertyValue::Array(mime_types));
+                        }
+                        nodes.push(AnnotatedNode::new(output_node));
+                        edges.push(AnnotatedEdge::new(Edge::new_in_dimension(
+                            cell_id.clone(),
+                            output_id,
+                            "produces",
+                            dimension::STRUCTURE,
+                        )));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        executed.sort_by_key(|(count, _)| *count);
+        for pair in executed.windows(2) {
+            edges.push(AnnotatedEdge::new(Edge::new_in_dimension(
+                pair[1].1.clone(),
+                pair[0].1.clone(),
+                "executed_after",
+                dimension::STRUCTURE,
+            )));
+        }
+
+        let output = StructuralOutput {
+            vocabulary,
+            emissions: vec![ModuleEmission { module_id: self.id().to_string(), nodes, edges }],
+            ..StructuralOutput::default()
+        };
+        (output, Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edges<'a>(output: &'a StructuralOutput, relationship: &str) -> Vec<(&'a str, &'a str)> {
+        output.emissions[0]
+            .edges
+            .iter()
+            .filter(|e| e.edge.relationship == relationship)
+            .map(|e| (e.edge.source.as_str(), e.edge.target.as_str()))
+            .collect()
+    }
+
+    // === Scenario: Imports are read from Python and R cells ===
+    #[test]
+    fn imports_from_python_and_r() {
+        let source = "import numpy as np, os\nfrom pandas.io import json\nfrom . import local\n!pip install x\nlibrary(ggplot2)";
+        assert_eq!(cell_imports(source), vec!["numpy", "os", "pandas.io", "ggplot2"]);
+    }
+
+    // === Scenario: A notebook maps to cells, execution order, imports, outputs ===
+    #[tokio::test]
+    async fn notebook_becomes_cells_and_edges() {
+        let notebook = serde_json::json!({
+            "metadata": {"language_info": {"name": "python"}},
+            "nbformat": 4,
+            "cells": [
+                {"cell_type": "markdown", "source": ["# Survey Analysis\n", "Site counts."]},
+                {"cell_type": "code", "execution_count": 2, "source": "import pandas as pd\ndf.describe()",
+                 "outputs": [{"output_type": "execute_result", "data": {"text/plain": ["count 12"], "text/html": "<table/>"}}]},
+                {"cell_type": "code", "execution_count": 1, "source": ["df = load()"],
+                 "outputs": [{"output_type": "error", "ename": "NameError", "evalue": "load"}]},
+                {"cell_type": "code", "execution_count": null, "source": "", "outputs": []}
+            ]
+        })
+        .to_string();
+        let output = NotebookStructureModule::new().analyze("survey.ipynb", &notebook).await;
+
+        assert_eq!(output.vocabulary, vec!["survey analysis", "pandas"]);
+        assert_eq!(edges(&output, "contains").len(), 4);
+        assert_eq!(edges(&output, "follows").len(), 3);
+        assert_eq!(
+            edges(&output, "executed_after"),
+            vec![("cell:survey.ipynb:2", "cell:survey.ipynb:3")],
+            "execution order, not document order"
+        );
+        assert_eq!(edges(&output, "imports"), vec![("cell:survey.ipynb:2", "module:pandas")]);
+        let nodes = &output.emissions[0].nodes;
+        let output_node = |id: &str| nodes.iter().find(|n| n.node.id.as_str() == id).unwrap();
+        assert_eq!(
+            output_node("output:survey.ipynb:2:1").node.properties.get("text"),
+            Some(&PropertyValue::from("count 12"))
+        );
+        assert_eq!(
+            output_node("output:survey.ipynb:3:1").node.properties.get("text"),
+            Some(&PropertyValue::from("NameError: load"))
+        );
+
+        let (broken, failures) = NotebookStructureModule::new().analyze_reporting("x.ipynb", "{not json").await;
+        assert!(broken.emissions.is_empty());
+        assert_eq!(failures.len(), 1, "malformed notebooks are reported, not silently skipped");
+    }
+}
@@ -0,0 +1,828 @@
+//! PkmAdapter — Roam Research and Logseq graph migration
+//!
+//! Reads the exports outliner users already have:
+//! - Roam JSON (`[{"title", "children": [{"string", "uid", "children"}]}]`)
+//!   and Roam EDN (the `#datascript/DB` datom dump)
+//! - Logseq EDN or JSON (`{:blocks [{:block/page-name, :block/children}]}`)
+//! - Markdown outlines, one page per file, as both tools export them
+//!
+//! and maps them to the graph:
+//! - A page node (`page:{lowercased title}`) and a block node per block
+//!   (`block:{uid}`), both in the structure dimension; pages and parent
+//!   blocks `contains` their child blocks in order
+//! - `[[Page]]` links become `links_to` edges from the block to the page
+//!   (created if the export never defines it), `((uid))` block
+//!   references `block_ref` edges between blocks
+//! - `#tag`, `#[[multi word tag]]` and `tags::` properties become concept
+//!   nodes and `tagged_with` edges, passing the context's `TagPolicy`
+//!
+//! Provenance is per block: one chain per export, and a mark per block
+//! recording its page and its position in the page (the mark's `line`),
+//! so every migrated thought still cites where it was written.
+
+use crate::adapter::sink::{AdapterError, AdapterSink};
+use crate::adapter::traits::{Adapter, AdapterInput};
+use crate::adapter::types::{chain_node, concept_node, mark_node, Emission};
+use crate::graph::{dimension, ContentType, Edge, Node, NodeId, PropertyValue};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// One outline block.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PkmBlock {
+    pub uid: String,
+    pub content: String,
+    pub properties: Vec<(String, String)>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub children: Vec<PkmBlock>,
+}
+
+/// One page and its block tree.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PkmPage {
+    pub title: String,
+    pub properties: Vec<(String, String)>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub blocks: Vec<PkmBlock>,
+}
+
+impl PkmPage {
+    /// Parse a markdown outline: `- ` bullets nested by indentation, with
+    /// `key:: value` property lines. Lines before the first bullet are
+    /// page properties; an `id::` property sets a block's UID.
+    pub fn from_markdown(title: impl Into<String>, text: &str) -> Self {
+        let title = title.into();
+        let mut page = PkmPage { title, ..Default::default() };
+        // (indent, path of child indices from the page root)
+        let mut stack: Vec<(usize, Vec<usize>)> = Vec::new();
+        for line in text.lines() {
+            // A tab indents as far as two spaces
+            let indent: usize = line
+                .chars()
+                .take_while(|c| *c == ' ' || *c == '\t')
+                .map(|c| if c == '\t' { 2 } else { 1 })
+                .sum();
+            let trimmed = line.trim();
+            if let Some(content) = trimmed.strip_prefix("- ").or((trimmed == "-").then_some("")) {
+                while stack.last().is_some_and(|(i, _)| *i >= indent) {
+                    stack.pop();
+                }
+                let parent_path = stack.last().map(|(_, p)| p.clone()).unwrap_or_default();
+                let siblings = children_at(&mut page.blocks, &parent_path);
+                siblings.push(PkmBlock { content: content.to_string(), ..Default::default() });
+                let mut path = parent_path;
+                path.push(siblings.len() - 1);
+                stack.push((indent, path));
+                continue;
+            }
+            if trimmed.is_empty() {
+                continue;
+            }
+            let property = trimmed.split_once(":: ").map(|(k, v)| (k.trim().to_string(), v.trim().to_string()));
+            match stack.last() {
+                None => page.properties.extend(property),
+                Some((_, path)) => {
+                    let block = block_at(&mut page.blocks, path);
+                    match property {
+                        Some((key, value)) if key == "id" => block.uid = value,
+                        Some(property) => block.properties.push(property),
+                        None => {
+                            block.content.push('\n');
+                            block.content.push_str(trimmed);
+                        }
+                    }
+                }
+            }
+        }
+        page
+    }
+}
+
+fn children_at<'a>(blocks: &'a mut Vec<PkmBlock>, path: &[usize]) -> &'a mut Vec<PkmBlock> {
+    match path.split_first() {
+        None => blocks,
+        Some((i, rest)) => children_at(&mut blocks[*i].children, rest),
+    }
+}
+
+fn block_at<'a>(blocks: &'a mut [PkmBlock], path: &[usize]) -> &'a mut PkmBlock {
+    let (last, parents) = path.split_last().expect("non-empty block path");
+    let mut blocks = blocks;
+    for i in parents {
+        blocks = &mut blocks[*i].children;
+    }
+    &mut blocks[*last]
+}
+
+/// Input data for the PkmAdapter.
+#[derive(Debug, Clone, Default)]
+pub struct PkmInput {
+    pub pages: Vec<PkmPage>,
+    /// Where the export came from (e.g., "roam-2026-03.json")
+    pub source: Option<String>,
+}
+
+impl PkmInput {
+    pub fn new(pages: Vec<PkmPage>) -> Self {
+        Self { pages, source: None }
+    }
+
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Parse a Roam JSON export.
+    pub fn from_roam_json(json: &Value) -> Result<Self, AdapterError> {
+        let pages = json
+            .as_array()
+            .ok_or_else(|| AdapterError::Internal("roam export must be an array of pages".into()))?;
+        fn block(json: &Value) -> Option<PkmBlock> {
+            Some(PkmBlock {
+                uid: json.get("uid")?.as_str()?.to_string(),
+                content: json.get("string").and_then(|s| s.as_str()).unwrap_or_default().to_string(),
+                properties: Vec::new(),
+                created_at: json.get("create-time").and_then(|t| t.as_i64()).and_then(DateTime::from_timestamp_millis),
+                children: children(json),
+            })
+        }
+        fn children(json: &Value) -> Vec<PkmBlock> {
+            json.get("children").and_then(|c| c.as_array()).map(|c| c.iter().filter_map(block).collect()).unwrap_or_default()
+        }
+        let pages = pages
+            .iter()
+            .filter_map(|page| {
+                Some(PkmPage {
+                    title: page.get("title")?.as_str()?.to_string(),
+                    properties: Vec::new(),
+                    created_at: page.get("create-time").and_then(|t| t.as_i64()).and_then(DateTime::from_timestamp_millis),
+                    blocks: children(page),
+                })
+            })
+            .collect();
+        Ok(Self::new(pages))
+    }
+
+    /// Parse a Logseq JSON export, or an EDN export read by `edn_to_json`.
+    pub fn from_logseq_json(json: &Value) -> Result<Self, AdapterError> {
+        let blocks = json
+            .get("blocks")
+            .and_then(|b| b.as_array())
+            .ok_or_else(|| AdapterError::Internal("logseq export requires a 'blocks' array".into()))?;
+        // EDN keys keep their `block/` namespace; JSON keys don't
+        let get = |json: &'_ Value, key: &str| -> Option<Value> {
+            json.get(key).or_else(|| json.get(format!("block/{}", key))).cloned()
+        };
+        fn properties(value: Option<Value>) -> Vec<(String, String)> {
+            let Some(Value::Object(map)) = value else { return Vec::new() };
+            map.into_iter()
+                .map(|(k, v)| {
+                    let value = match v {
+                        Value::String(s) => s,
+                        Value::Array(items) => items.iter().filter_map(|i| i.as_str()).collect::<Vec<_>>().join(", "),
+                        other => other.to_string(),
+                    };
+                    (k, value)
+                })
+                .collect()
+        }
+        fn block(json: &Value, get: &dyn Fn(&Value, &str) -> Option<Value>) -> Option<PkmBlock> {
+            let uid = get(json, "id")?.as_str()?.to_string();
+            Some(PkmBlock {
+                uid,
+                content: get(json, "content").and_then(|c| c.as_str().map(String::from)).unwrap_or_default(),
+                properties: properties(get(json, "properties")),
+                created_at: get(json, "created-at").and_then(|t| t.as_i64()).and_then(DateTime::from_timestamp_millis),
+                children: children(json, get),
+            })
+        }
+        fn children(json: &Value, get: &dyn Fn(&Value, &str) -> Option<Value>) -> Vec<PkmBlock> {
+            match get(json, "children") {
+                Some(Value::Array(c)) => c.iter().filter_map(|b| block(b, get)).collect(),
+                _ => Vec::new(),
+            }
+        }
+        let pages = blocks
+            .iter()
+            .filter_map(|page| {
+                let title = get(page, "page-name").or_else(|| get(page, "original-name"))?.as_str()?.to_string();
+                Some(PkmPage {
+                    title,
+                    properties: properties(get(page, "properties")),
+                    created_at: get(page, "created-at").and_then(|t| t.as_i64()).and_then(DateTime::from_timestamp_millis),
+                    blocks: children(page, &get),
+                })
+            })
+            .collect();
+        Ok(Self::new(pages))
+    }
+
+    /// Parse EDN: a Roam `#datascript/DB` dump or a Logseq EDN export.
+    pub fn from_edn(text: &str) -> Result<Self, AdapterError> {
+        let json = edn_to_json(text).map_err(|e| AdapterError::Internal(format!("invalid EDN: {}", e)))?;
+        match json.get("datoms").and_then(|d| d.as_array()) {
+            Some(datoms) => Ok(Self::from_datoms(datoms)),
+            None => Self::from_logseq_json(&json),
+        }
+    }
+
+    /// Rebuild Roam pages from `[entity attribute value tx]` datoms.
+    fn from_datoms(datoms: &[Value]) -> Self {
+        let mut entities: HashMap<i64, HashMap<&str, Vec<&Value>>> = HashMap::new();
+        for datom in datoms {
+            let (Some(e), Some(a), Some(v)) =
+                (datom.get(0).and_then(|e| e.as_i64()), datom.get(1).and_then(|a| a.as_str()), datom.get(2))
+            else {
+                continue;
+            };
+            entities.entry(e).or_default().entry(a).or_default().push(v);
+        }
+        let attr = |e: i64, a: &str| entities.get(&e).and_then(|attrs| attrs.get(a)).and_then(|v| v.first().copied());
+        // `seen` holds every entity placed so far: a block listed under two
+        // parents, or under its own descendant, is placed once
+        fn tree<'a>(
+            e: i64,
+            entities: &HashMap<i64, HashMap<&'a str, Vec<&'a Value>>>,
+            attr: &dyn Fn(i64, &str) -> Option<&'a Value>,
+            seen: &mut HashSet<i64>,
+        ) -> Vec<PkmBlock> {
+            let mut children: Vec<(i64, i64)> = entities
+                .get(&e)
+                .and_then(|attrs| attrs.get("block/children"))
+                .map(|refs| refs.iter().filter_map(|r| r.as_i64()).map(|c| (attr(c, "block/order").and_then(|o| o.as_i64()).unwrap_or(0), c)).collect())
+                .unwrap_or_default();
+            children.sort();
+            children.retain(|(_, c)| seen.insert(*c));
+            children
+                .into_iter()
+                .filter_map(|(_, c)| {
+                    Some(PkmBlock {
+                        uid: attr(c, "block/uid")?.as_str()?.to_string(),
+                        content: attr(c, "block/string").and_then(|s| s.as_str()).unwrap_or_default().to_string(),
+                        properties: Vec::new(),
+                        created_at: attr(c, "create/time").and_then(|t| t.as_i64()).and_then(DateTime::from_timestamp_millis),
+                        children: tree(c, entities, attr, seen),
+                    })
+                })
+                .collect()
+        }
+        let mut titled: Vec<(i64, &str)> = entities
+            .keys()
+            .filter_map(|e| Some((*e, attr(*e, "node/title")?.as_str()?)))
+            .collect();
+        titled.sort();
+        let mut seen: HashSet<i64> = titled.iter().map(|(e, _)| *e).collect();
+        let pages = titled
+            .into_iter()
+            .map(|(e, title)| PkmPage {
+                title: title.to_string(),
+                properties: Vec::new(),
+                created_at: attr(e, "create/time").and_then(|t| t.as_i64()).and_then(DateTime::from_timestamp_millis),
+                blocks: tree(e, &entities, &attr, &mut seen),
+            })
+            .collect();
+        Self::new(pages)
+    }
+
+    /// Parse a JSON ingest: `{"roam": [pages]}`, `{"logseq": {...}}`,
+    /// `{"edn": "..."}` or `{"markdown_pages": {title: markdown}}`.
+    /// Optional: `source` (string).
+    pub fn from_json(json: &Value) -> Result<Self, AdapterError> {
+        let mut input = if let Some(roam) = json.get("roam") {
+            Self::from_roam_json(roam)?
+        } else if let Some(logseq) = json.get("logseq") {
+            Self::from_logseq_json(logseq)?
+        } else if let Some(edn) = json.get("edn").and_then(|e| e.as_str()) {
+            Self::from_edn(edn)?
+        } else if let Some(pages) = json.get("markdown_pages").and_then(|p| p.as_object()) {
+            Self::new(
+                pages.iter().filter_map(|(title, md)| Some(PkmPage::from_markdown(title.as_str(), md.as_str()?))).collect(),
+            )
+        } else {
+            return Err(AdapterError::Internal(
+                "pkm input requires 'roam', 'logseq', 'edn' or 'markdown_pages'".into(),
+            ));
+        };
+        input.source = json.get("source").and_then(|v| v.as_str()).map(String::from);
+        Ok(input)
+    }
+}
+
+/// Read EDN into JSON: keywords and symbols become their names without
+/// the colon, `#uuid`/`#inst` and other tags their tagged value, maps
+/// objects (keys as strings), and vectors, lists and sets arrays.
+pub fn edn_to_json(text: &str) -> Result<Value, String> {
+    let mut reader = EdnReader { chars: text.chars().collect(), pos: 0, depth: 0 };
+    let value = reader.value()?.ok_or("empty EDN")?;
+    Ok(value)
+}
+
+/// Collections nested deeper than this are rejected rather than
+/// recursed into.
+const MAX_EDN_DEPTH: usize = 256;
+
+struct EdnReader {
+    chars: Vec<char>,
+    pos: usize,
+    /// Collections currently open
+    depth: usize,
+}
+
+impl EdnReader {
+    fn skip_space(&mut self) {
+        while let Some(&c) = self.chars.get(self.pos) {
+            match c {
+                ';' => {
+                    while self.chars.get(self.pos).is_some_and(|c| *c != '\n') {
+                        self.pos += 1;
+                    }
+                }
+                c if c.is_whitespace() || c == ',' => self.pos += 1,
+                _ => break,
+            }
+        }
+    }
+
+    fn collection(&mut self, close: char) -> Result<Vec<Value>, String> {
+        if self.depth >= MAX_EDN_DEPTH {
+            return Err(format!("collections nested deeper than {}", MAX_EDN_DEPTH));
+        }
+        self.depth += 1;
+        let items = self.items(close);
+        self.depth -= 1;
+        items
+    }
+
+    fn items(&mut self, close: char) -> Result<Vec<Value>, String> {
+        let mut items = Vec::new();
+        loop {
+            self.skip_space();
+            match self.chars.get(self.pos) {
+                None => return Err(format!("unclosed collection, expected '{}'", close)),
+                Some(c) if *c == close => {
+                    self.pos += 1;
+                    return Ok(items);
+                }
+                _ => items.extend(self.value()?),
+            }
+        }
+    }
+
+    /// The next value; `None` for a `#_` discard.
+    fn value(&mut self) -> Result<Option<Value>, String> {
+        self.skip_space();
+        let Some(&c) = self.chars.get(self.pos) else { return Err("unexpected end of input".into()) };
+        self.pos += 1;
+        Ok(Some(match c {
+            '[' => Value::Array(self.collection(']')?),
+            '(' => Value::Array(self.collection(')')?),
+            '{' => {
+                let items = self.collection('}')?;
+                let mut map = serde_json::Map::new();
+                for pair in items.chunks(2) {
+                    let key = match &pair[0] {
+                        Value::String(s) => s.clone(),
+                        other => other.to_string(),
+                    };
+                    map.insert(key, pair.get(1).cloned().unwrap_or(Value::Null));
+                }
+                Value::Object(map)
+            }
+            '"' => {
+                let mut s = String::new();
+                loop {
+                    match self.chars.get(self.pos) {
+                        None => return Err("unterminated string".into()),
+                        Some('"') => break,
+                        Some('\\') => {
+                            self.pos += 1;
+                            match self.chars.get(self.pos) {
+                                Some('n') => s.push('\n'),
+                                Some('t') => s.push('\t'),
+                                Some('r') => s.push('\r'),
+                                Some(other) => s.push(*other),
+                                None => return Err("unterminated string".into()),
+                            }
+                        }
+                        Some(other) => s.push(*other),
+                    }
+                    self.pos += 1;
+                }
+                self.pos += 1;
+                Value::String(s)
+            }
+            '#' => match self.chars.get(self.pos) {
+                Some('{') => {
+                    self.pos += 1;
+                    Value::Array(self.collection('}')?)
+                }
+                Some('_') => {
+                    self.pos += 1;
+                    self.value()?;
+                    return Ok(None);
+                }
+                // Tagged literal: read the tag, keep the value
+                _ => {
+                    self.token();
+                    return self.value();
+                }
+            },
+            '\\' => Value::String(self.token()),
+            ')' | ']' | '}' => return Err(format!("unexpected '{}' at offset {}", c, self.pos - 1)),
+            _ => {
+                self.pos -= 1;
+                let token = self.token();
+                match token.as_str() {
+                    "nil" => Value::Null,
+                    "true" => Value::Bool(true),
+                    "false" => Value::Bool(false),
+                    _ => match (token.parse::<i64>(), token.trim_end_matches('M').parse::<f64>()) {
+                        (Ok(i), _) => Value::from(i),
+                        (_, Ok(f)) if token.starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '+') => Value::from(f),
+                        _ => Value::String(token.trim_start_matches(':').to_string()),
+                    },
+                }
+            }
+        }))
+    }
+
+    fn token(&mut self) -> String {
+        let start = self.pos;
+        while self
+            .chars
+            .get(self.pos)
+            .is_some_and(|c| !c.is_whitespace() && !matches!(c, ',' | '[' | ']' | '(' | ')' | '{' | '}' | '"' | ';'))
+        {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+}
+
+/// Links, references and tags in one block's text.
+#[derive(Debug, Default, PartialEq)]
+struct BlockRefs {
+    pages: Vec<String>,
+    blocks: Vec<String>,
+    tags: Vec<String>,
+}
+
+fn scan(content: &str) -> BlockRefs {
+    let mut refs = BlockRefs::default();
+    let mut rest = content;
+    while let Some(i) = rest.find(['[', '(', '#']) {
+        let (head, tail) = rest.split_at(i);
+        let at_word_start = head.chars().last().is_none_or(|c| c.is_whitespace() || c == '(' || c == '[');
+        if let Some(inner) = tail.strip_prefix("#[[").and_then(|t| t.split_once("]]")) {
+            refs.tags.push(inner.0.to_string());
+            rest = inner.1;
+        } else if let Some(inner) = tail.strip_prefix("[[").and_then(|t| t.split_once("]]")) {
+            refs.pages.push(inner.0.to_string());
+            rest = inner.1;
+        } else if let Some(inner) = tail.strip_prefix("((").and_then(|t| t.split_once("))")) {
+            refs.blocks.push(inner.0.to_string());
+            rest = inner.1;
+        } else if let (Some(word), true) = (tail.strip_prefix('#'), at_word_start) {
+            let end = word.find(|c: char| c.is_whitespace() || matches!(c, ',' | '.' | ';' | ')' | ']')).unwrap_or(word.len());
+            if end > 0 {
+                refs.tags.push(word[..end].to_string());
+            }
+            rest = &word[end..];
+        } else {
+            rest = &tail[1..];
+        }
+    }
+    refs
+}
+
+fn page_id(title: &str) -> NodeId {
+    NodeId::from_string(format!("page:{}", title.trim().to_lowercase()))
+}
+
+/// Roam and Logseq export adapter.
+pub struct PkmAdapter {
+    adapter_id: String,
+}
+
+impl PkmAdapter {
+    pub fn new(adapter_id: impl Into<String>) -> Self {
+        Self { adapter_id: adapter_id.into() }
+    }
+}
+
+#[async_trait]
+impl Adapter for PkmAdapter {
+    fn id(&self) -> &str {
+        &self.adapter_id
+    }
+
+    fn input_kind(&self) -> &str {
+        "pkm"
+    }
+
+    fn input_from_json(&self, json: &serde_json::Value) -> Result<Box<dyn std::any::Any + Send + Sync>, AdapterError> {
+        Ok(Box::new(PkmInput::from_json(json)?))
+    }
+
+    fn input_schema(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({
+            "type": "object",
+            "anyOf": [
+                {"required": ["roam"]},
+                {"required": ["logseq"]},
+                {"required": ["edn"]},
+                {"required": ["markdown_pages"]},
+            ],
+            "properties": {
+                "edn": {"type": "string"},
+                "markdown_pages": {"type": "object", "additionalProperties": {"type": "string"}},
+                "source": {"type": "string"},
+            }
+        }))
+    }
+
+    async fn process(
+        &self,
+        input: &AdapterInput,
+        sink: &dyn AdapterSink,
+    ) -> Result<(), AdapterError> {
+        // Accept both typed PkmInput and raw JSON
+        let owned: PkmInput;
+        let export: &PkmInput = if let Some(p) = input.downcast_data::<PkmInput>() {
+            p
+        } else if let Some(json) = input.downcast_data::<Value>() {
+            owned = PkmInput::from_json(json)?;
+            &owned
+        } else {
+            return Err(AdapterError::InvalidInput);
+        };
+        if export.pages.is_empty() {
+            return Ok(());
+        }
+
+        let source = export.source.as_deref().unwrap_or("default");
+        let chain_id = format!("chain:{}:{}", self.adapter_id, source);
+        let mut chain = chain_node(&chain_id);
+        chain.properties.insert(
+            "name".to_string(),
+            PropertyValue::String(format!("{} — {}", self.adapter_id, source)),
+        );
+        chain.properties.insert("status".to_string(), PropertyValue::String("active".to_string()));
+        let mut emission = Emission::new().with_node(chain);
+
+        let page_node = |title: &str| {
+            let mut node = Node::new_in_dimension("page", ContentType::Document, dimension::STRUCTURE);
+            node.id = page_id(title);
+            node.properties.insert("title".to_string(), PropertyValue::String(title.trim().to_string()));
+            node
+        };
+        let tag_edges = |from: &NodeId, tags: &[String], emission: Emission| -> (Emission, Vec<String>) {
+            let tags = match input.tag_policy {
+                Some(ref policy) => policy.apply(tags),
+                None => tags.to_vec(),
+            };
+            let mut emission = emission;
+            for tag in &tags {
+                let (concept_id, concept) = concept_node(tag);
+                let mut edge = Edge::new_cross_dimensional(
+                    from.clone(),
+                    dimension::STRUCTURE,
+                    concept_id,
+                    dimension::SEMANTIC,
+                    "tagged_with",
+                );
+                edge.combined_weight = 1.0;
+                emission = emission.with_node(concept).with_edge(edge);
+            }
+            (emission, tags)
+        };
+        let property_tags = |properties: &[(String, String)]| -> Vec<String> {
+            properties
+                .iter()
+                .filter(|(k, _)| k == "tags")
+                .flat_map(|(_, v)| v.split(',').map(|t| t.trim().trim_start_matches('#').trim_start_matches("[[").trim_end_matches("]]").to_string()))
+                .filter(|t| !t.is_empty())
+                .collect()
+        };
+
+        let mut linked_pages: Vec<String> = Vec::new();
+        for page in &export.pages {
+            let mut node = page_node(&page.title);
+            if let Some(at) = page.created_at {
+                node.properties.insert("created_at".to_string(), PropertyValue::String(at.to_rfc3339()));
+            }
+            for (key, value) in page.properties.iter().filter(|(k, _)| k != "tags") {
+                node.properties.insert(key.clone(), PropertyValue::String(value.clone()));
+            }
+            let page_node_id = node.id.clone();
+            emission = emission.with_node(node);
+            emission = tag_edges(&page_node_id, &property_tags(&page.properties), emission).0;
+
+            // Depth-first, so `line` is the block's position in the page
+            let mut position = 0;
+            let mut pending: Vec<(&PkmBlock, NodeId, usize)> =
+                page.blocks.iter().enumerate().rev().map(|(i, b)| (b, page_node_id.clone(), i)).collect();
+            while let Some((block, parent, order)) = pending.pop() {
+                position += 1;
+                let uid = match block.uid.is_empty() {
+                    false => block.uid.clone(),
+                    true => Uuid::new_v5(&Uuid::NAMESPACE_OID, format!("{}#{}", page.title, position).as_bytes()).to_string(),
+                };
+                let block_id = NodeId::from_string(format!("block:{}", uid));
+                let mut node = Node::new_in_dimension("block", ContentType::Document, dimension::STRUCTURE);
+                node.id = block_id.clone();
+                node.properties.insert("text".to_string(), PropertyValue::String(block.content.clone()));
+                node.properties.insert("page".to_string(), PropertyValue::String(page.title.clone()));
+                node.properties.insert("order".to_string(), PropertyValue::Int(order as i64));
+                if let Some(at) = block.created_at {
+                    node.properties.insert("created_at".to_string(), PropertyValue::String(at.to_rfc3339()));
+                }
+                for (key, value) in block.properties.iter().filter(|(k, _)| k != "tags") {
+                    node.properties.insert(key.clone(), PropertyValue::String(value.clone()));
+                }
+                let contains = Edge::new_in_dimension(parent, block_id.clone(), "contains", dimension::STRUCTURE);
+                emission = emission.with_node(node).with_edge(contains);
+
+                let refs = scan(&block.content);
+                for title in &refs.pages {
+                    linked_pages.push(title.clone());
+                    emission = emission.with_edge(Edge::new_in_dimension(
+                        block_id.clone(),
+                        page_id(title),
+                        "links_to",
+                        dimension::STRUCTURE,
+                    ));
+                }
+                for target in &refs.blocks {
+                    emission = emission.with_edge(Edge::new_in_dimension(
+                        block_id.clone(),
+                        NodeId::from_string(format!("block:{}", target)),
+                        "block_ref",
+                        dimension::STRUCTURE,
+                    ));
+                }
+                let mut tags = refs.tags;
+                tags.extend(property_tags(&block.properties));
+                let (next, tags) = tag_edges(&block_id, &tags, emission);
+                emission = next;
+
+                let mut mark = mark_node(&format!("mark:{}:{}", self.adapter_id, block_id));
+                mark.properties.insert("chain_id".to_string(), PropertyValue::String(chain_id.clone()));
+                mark.properties.insert("annotation".to_string(), PropertyValue::String(block.content.clone()));
+                mark.properties.insert("file".to_string(), PropertyValue::String(page.title.clone()));
+                mark.properties.insert("line".to_string(), PropertyValue::Int(position as i64));
+                mark.properties.insert("block_uid".to_string(), PropertyValue::String(uid));
+                if !tags.is_empty() {
+                    let tag_vals = tags.iter().map(|t| PropertyValue::String(t.to_lowercase())).collect();
+                    mark.properties.insert("tags".to_string(), PropertyValue::Array(tag_vals));
+                }
+                let contains_mark = Edge::new_in_dimension(
+                    NodeId::from_string(&chain_id),
+                    mark.id.clone(),
+                    "contains",
+                    dimension::PROVENANCE,
+                );
+                emission = emission.with_node(mark).with_edge(contains_mark);
+
+                pending.extend(block.children.iter().enumerate().rev().map(|(i, child)| (child, block_id.clone(), i)));
+            }
+        }
+        // Pages only ever linked to still get a node to link to
+        let defined: HashSet<NodeId> = export.pages.iter().map(|p| page_id(&p.title)).collect();
+        for title in linked_pages {
+            if !defined.contains(&page_id(&title)) {
+                emission = emission.with_node(page_node(&title));
+            }
+        }
+
+        sink.emit(emission).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::{EngineSink, FrameworkContext};
+    use crate::graph::Context;
+    use std::sync::{Arc, Mutex};
+
+    fn make_sink(adapter_id: &str) -> (EngineSink, Arc<Mutex<Context>>) {
+        let ctx = Arc::new(Mutex::new(Context::new("test")));
+        let fw = FrameworkContext {
+            adapter_id: adapter_id.to_string(),
+            context_id: "test".to_string(),
+            input_summary: None,
+        };
+        let sink = EngineSink::new(ctx.clone()).with_framework_context(fw);
+        (sink, ctx)
+    }
+
+    fn edges<'a>(ctx: &'a Context, relationship: &str) -> Vec<(&'a str, &'a str)> {
+        let mut edges: Vec<(&str, &str)> = ctx
+            .edges()
+            .filter(|e| e.relationship == relationship)
+            .map(|e| (e.source.as_str(), e.target.as_str()))
+            .collect();
+        edges.sort();
+        edges
+    }
+
+    // === Scenario: Markdown outlines nest by indentation ===
+    #[test]
+    fn markdown_outline_parses_blocks_and_properties() {
+        let page = PkmPage::from_markdown(
+            "Reading",
+            "tags:: books\n- Dune #scifi\n  id:: 6400-aa\n\t- see [[Frank Herbert]]\n  second line\n- ((6400-aa)) again\n",
+        );
+        assert_eq!(page.properties, vec![("tags".to_string(), "books".to_string())]);
+        assert_eq!(page.blocks.len(), 2);
+        assert_eq!(page.blocks[0].uid, "6400-aa");
+        assert_eq!(page.blocks[0].children[0].content, "see [[Frank Herbert]]\nsecond line");
+        assert_eq!(
+            scan("see [[Frank Herbert]], #scifi and #[[hard sf]] per ((6400-aa)); not a#tag"),
+            BlockRefs {
+                pages: vec!["Frank Herbert".into()],
+                blocks: vec!["6400-aa".into()],
+                tags: vec!["scifi".into(), "hard sf".into()],
+            }
+        );
+    }
+
+    // === Scenario: Logseq and Roam EDN exports read into pages ===
+    #[test]
+    fn edn_exports_parse() {
+        let logseq = r#"{:version 1
+            :blocks [{:block/id #uuid "p1" :block/page-name "dune" :block/properties {:tags ["books"]}
+                      :block/children [{:block/id #uuid "b1" :block/content "Spice, \"melange\"" :block/children []}]}]}"#;
+        let input = PkmInput::from_edn(logseq).unwrap();
+        assert_eq!(input.pages[0].title, "dune");
+        assert_eq!(input.pages[0].properties, vec![("tags".to_string(), "books".to_string())]);
+        assert_eq!(input.pages[0].blocks[0].content, "Spice, \"melange\"");
+
+        let roam = r#"#datascript/DB {:schema {} :datoms [[1 :node/title "Dune" 536870913]
+            [1 :block/children 3 536870913] [1 :block/children 2 536870913]
+            [2 :block/uid "a" 536870913] [2 :block/string "first" 536870913] [2 :block/order 0 536870913]
+            [3 :block/uid "b" 536870913] [3 :block/string "second" 536870913] [3 :block/order 1 536870913]
+            #_ [9 :ignored true 0]]}"#;
+        let input = PkmInput::from_edn(roam).unwrap();
+        let contents: Vec<&str> = input.pages[0].blocks.iter().map(|b| b.content.as_str()).collect();
+        assert_eq!(contents, vec!["first", "second"], "children ordered by :block/order");
+        assert!(PkmInput::from_edn("{:blocks [").is_err());
+    }
+
+    // === Scenario: Malformed and cyclic EDN fails or terminates ===
+    #[test]
+    fn malformed_and_cyclic_edn_terminates() {
+        let err = PkmInput::from_json(&serde_json::json!({"edn": "{:blocks [)}"})).unwrap_err();
+        assert!(err.to_string().contains("unexpected ')'"), "{err}");
+        assert!(edn_to_json(&"[".repeat(100_000)).unwrap_err().contains("nested deeper"));
+
+        let cyclic = r#"{:datoms [[1 :node/title "Loop" 0] [1 :block/children 2 0]
+            [2 :block/uid "a" 0] [2 :block/children 3 0] [3 :block/uid "b" 0] [3 :block/children 2 0]]}"#;
+        let input = PkmInput::from_edn(cyclic).unwrap();
+        let a = &input.pages[0].blocks[0];
+        assert_eq!((a.uid.as_str(), a.children[0].uid.as_str()), ("a", "b"));
+        assert!(a.children[0].children.is_empty(), "the cycle back to 'a' is cut");
+    }
+
+    // === Scenario: A Roam graph migrates with block-level provenance ===
+    #[tokio::test]
+    async fn roam_export_maps_pages_blocks_links_and_tags() {
+        let adapter = PkmAdapter::new("pkm");
+        let (sink, ctx) = make_sink("pkm");
+        let json = serde_json::json!({
+            "source": "roam.json",
+            "roam": [{
+                "title": "Dune",
+                "create-time": 1700000000000i64,
+                "children": [
+                    {"uid": "b1", "string": "Read by [[Frank Herbert]] #scifi", "children": [
+                        {"uid": "b2", "string": "Quoted in ((b1))"}
+                    ]},
+                    {"uid": "b3", "string": "Plain note"}
+                ]
+            }]
+        });
+        adapter.process(&AdapterInput::new("pkm", json, "test"), &sink).await.unwrap();
+
+        let ctx = ctx.lock().unwrap();
+        assert!(ctx.get_node(&NodeId::from_string("page:frank herbert")).is_some(), "linked page created");
+        assert_eq!(
+            edges(&ctx, "contains").into_iter().filter(|(s, _)| !s.starts_with("chain:")).collect::<Vec<_>>(),
+            vec![("block:b1", "block:b2"), ("page:dune", "block:b1"), ("page:dune", "block:b3")]
+        );
+        assert_eq!(edges(&ctx, "links_to"), vec![("block:b1", "page:frank herbert")]);
+        assert_eq!(edges(&ctx, "block_ref"), vec![("block:b2", "block:b1")]);
+        assert_eq!(edges(&ctx, "tagged_with"), vec![("block:b1", "concept:scifi")]);
+
+        let mark = ctx.get_node(&NodeId::from_string("mark:pkm:block:b2")).expect("mark per block");
+        assert_eq!(mark.properties.get("file"), Some(&PropertyValue::from("Dune")));
+        assert_eq!(mark.properties.get("line"), Some(&PropertyValue::Int(2)), "depth-first position");
+        let page = ctx.get_node(&NodeId::from_string("page:dune")).unwrap();
+        assert!(page.properties.contains_key("created_at"));
+    }
+}
@@ -257,11 +257,11 @@ impl Adapter for ProvenanceAdapter {
                         outbound.push(OutboundEvent::new("provenance_updated", ids.join(", ")));
                     }
                 }
-                GraphEvent::EdgesAdded { edge_ids, adapter_id, .. } if adapter_id == "provenance" => {
-                    if !edge_ids.is_empty() {
-                        let ids: Vec<String> = edge_ids.iter().map(|id| id.to_string()).collect();
-                        outbound.push(OutboundEvent::new("edges_added", ids.join(", ")));
-                    }
+                GraphEvent::EdgesAdded { edge_ids, adapter_id, .. }
+                    if adapter_id == "provenance" && !edge_ids.is_empty() =>
+                {
+                    let ids: Vec<String> = edge_ids.iter().map(|id| id.to_string()).collect();
+                    outbound.push(OutboundEvent::new("edges_added", ids.join(", ")));
                 }
                 GraphEvent::NodesRemoved { node_ids, adapter_id, .. } if adapter_id == "provenance" => {
                     let ids: Vec<String> = node_ids.iter().map(|id| id.to_string()).collect();
@@ -269,11 +269,11 @@ impl Adapter for ProvenanceAdapter {
                         outbound.push(OutboundEvent::new("provenance_removed", ids.join(", ")));
                     }
                 }
-                GraphEvent::EdgesRemoved { edge_ids, adapter_id, .. } if adapter_id == "provenance" => {
-                    if !edge_ids.is_empty() {
-                        let ids: Vec<String> = edge_ids.iter().map(|id| id.to_string()).collect();
-                        outbound.push(OutboundEvent::new("edges_removed", ids.join(", ")));
-                    }
+                GraphEvent::EdgesRemoved { edge_ids, adapter_id, .. }
+                    if adapter_id == "provenance" && !edge_ids.is_empty() =>
+                {
+                    let ids: Vec<String> = edge_ids.iter().map(|id| id.to_string()).collect();
+                    outbound.push(OutboundEvent::new("edges_removed", ids.join(", ")));
                 }
                 _ => {}
             }
@@ -362,7 +362,7 @@ mod tests {
 
         let ctx = ctx.lock().unwrap();
         let chain = ctx.get_node(&NodeId::from("chain-2")).unwrap();
-        assert!(chain.properties.get("description").is_none());
+        assert!(!chain.properties.contains_key("description"));
     }
 
     // === AddMark ===
@@ -488,9 +488,9 @@ mod tests {
 
         let ctx = ctx.lock().unwrap();
         let mark = ctx.get_node(&NodeId::from("mark-2")).unwrap();
-        assert!(mark.properties.get("column").is_none());
-        assert!(mark.properties.get("type").is_none());
-        assert!(mark.properties.get("tags").is_none());
+        assert!(!mark.properties.contains_key("column"));
+        assert!(!mark.properties.contains_key("type"));
+        assert!(!mark.properties.contains_key("tags"));
     }
 
     // === LinkMarks ===
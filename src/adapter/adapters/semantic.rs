@@ -15,12 +15,13 @@
 use crate::adapter::sink::{AdapterError, AdapterSink};
 use crate::adapter::traits::{Adapter, AdapterInput};
 use crate::adapter::types::{AnnotatedEdge, AnnotatedNode, Emission, concept_node};
-use crate::graph::{dimension, ContentType, Context, Edge, Node, NodeId, PropertyValue};
-use crate::llm_orc::{LlmOrcClient, LlmOrcError};
+use crate::graph::{dimension, ContentType, Context, Edge, Node, NodeId, PropertyValue, CONFIDENCE_PROPERTY};
+use crate::llm_orc::{LlmBudget, LlmOrcClient, LlmOrcError, LlmUsage};
 use async_trait::async_trait;
 use std::sync::Arc;
 
 pub use super::structural::SectionBoundary;
+use super::chunking::Chunk;
 
 /// Cap on document content included in the ensemble payload. Local
 /// model context windows (mistral at num_ctx 8192) truncate anyway;
@@ -43,6 +44,10 @@ pub struct SemanticInput {
     /// link targets. Passed to llm-orc as a glossary hint (not a constraint).
     /// Empty when no structural modules matched or produced vocabulary.
     pub vocabulary: Vec<String>,
+    /// Chunks of the document, already committed as chunk nodes. When
+    /// present, each chunk is extracted separately and its concepts are
+    /// tagged from the chunk rather than the file.
+    pub chunks: Vec<Chunk>,
 }
 
 impl SemanticInput {
@@ -52,6 +57,7 @@ impl SemanticInput {
             file_path: file_path.into(),
             sections: Vec::new(),
             vocabulary: Vec::new(),
+            chunks: Vec::new(),
         }
     }
 
@@ -64,6 +70,7 @@ impl SemanticInput {
             file_path: file_path.into(),
             sections,
             vocabulary: Vec::new(),
+            chunks: Vec::new(),
         }
     }
 
@@ -78,8 +85,15 @@ impl SemanticInput {
             file_path: file_path.into(),
             sections,
             vocabulary,
+            chunks: Vec::new(),
         }
     }
+
+    /// Extract from `chunks` one at a time instead of the whole file.
+    pub fn with_chunks(mut self, chunks: Vec<Chunk>) -> Self {
+        self.chunks = chunks;
+        self
+    }
 }
 
 /// Extract a JSON object from LLM response text.
@@ -89,7 +103,7 @@ impl SemanticInput {
 /// 1. Direct parse (response is pure JSON)
 /// 2. Extract from ```json ... ``` or ``` ... ``` fenced block
 /// 3. Find the first `{` to last `}` span and parse that
-fn extract_json(text: &str) -> Option<serde_json::Value> {
+pub(crate) fn extract_json(text: &str) -> Option<serde_json::Value> {
     let trimmed = text.trim();
 
     // Try 1: Direct parse
@@ -143,6 +157,10 @@ pub struct SemanticAdapter {
     client: Arc<dyn LlmOrcClient>,
     /// The ensemble to invoke for semantic extraction
     ensemble_name: String,
+    /// Engine recording each invocation's tokens and cost
+    cost_engine: Option<Arc<crate::graph::PlexusEngine>>,
+    /// Run budget; invocations stop once it is exhausted
+    budget: Option<Arc<LlmBudget>>,
 }
 
 impl SemanticAdapter {
@@ -150,6 +168,35 @@ impl SemanticAdapter {
         Self {
             client,
             ensemble_name: ensemble_name.into(),
+            cost_engine: None,
+            budget: None,
+        }
+    }
+
+    /// Record the usage llm-orc reports for each invocation against the
+    /// input's context and file (see `PlexusEngine::llm_costs`).
+    pub fn with_cost_tracking(mut self, engine: Arc<crate::graph::PlexusEngine>) -> Self {
+        self.cost_engine = Some(engine);
+        self
+    }
+
+    /// Charge invocations to `budget` and skip extraction once it is
+    /// exhausted.
+    pub fn with_budget(mut self, budget: Arc<LlmBudget>) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// Charge the budget and record the cost of one invocation.
+    fn account(&self, input: &AdapterInput, file_path: &str, usage: LlmUsage) {
+        if let Some(ref budget) = self.budget {
+            budget.charge(usage);
+        }
+        if let Some(ref engine) = self.cost_engine {
+            let context_id = crate::graph::ContextId::from_string(&input.context_id);
+            if let Err(e) = engine.record_llm_cost(&context_id, "extract-semantic", file_path, &self.ensemble_name, usage) {
+                tracing::warn!(file_path, error = %e, "semantic extraction: could not record llm cost");
+            }
         }
     }
 
@@ -250,6 +297,25 @@ impl SemanticAdapter {
         serde_json::to_string(&payload).expect("structural analysis output serialization should not fail")
     }
 
+    /// Build the payload for one chunk: its text in place of the file's,
+    /// with its span and the document's vocabulary.
+    fn build_chunk_input(&self, input: &SemanticInput, chunk: &Chunk) -> String {
+        let content: String = chunk.text.chars().take(MAX_CONTENT_CHARS).collect();
+        let mut payload = serde_json::json!({
+            "file_path": input.file_path,
+            "chunk_id": chunk.id.as_str(),
+            "content": content,
+            "sections": [{
+                "label": chunk.label,
+                "start_line": chunk.start_line,
+                "end_line": chunk.end_line,
+            }],
+        });
+        if !input.vocabulary.is_empty() {
+            payload["vocabulary"] = serde_json::json!(input.vocabulary);
+        }
+        serde_json::to_string(&payload).expect("chunk payload serialization should not fail")
+    }
 }
 
 #[async_trait]
@@ -280,39 +346,63 @@ impl Adapter for SemanticAdapter {
             ));
         }
 
-        // Build input from structural analysis context, including section boundaries
-        let input_text = self.build_input(semantic_input, None);
-
-        // Invoke llm-orc ensemble
-        let response = self
-            .client
-            .invoke(&self.ensemble_name, &input_text)
-            .await
-            .map_err(|e| match e {
-                LlmOrcError::Unavailable(msg) => AdapterError::Skipped(msg),
-                other => AdapterError::Internal(other.to_string()),
-            })?;
-
-        if response.is_failed() {
-            return Err(AdapterError::Internal(
-                "llm-orc ensemble execution failed".to_string(),
-            ));
-        }
+        // Build input from structural analysis context, including section
+        // boundaries — or one bounded input per chunk
+        let file_node_id = NodeId::from_string(format!("file:{}", file_path));
+        let requests: Vec<(NodeId, String)> = if semantic_input.chunks.is_empty() {
+            vec![(file_node_id, self.build_input(semantic_input, None))]
+        } else {
+            semantic_input
+                .chunks
+                .iter()
+                .map(|chunk| (chunk.id.clone(), self.build_chunk_input(semantic_input, chunk)))
+                .collect()
+        };
 
-        // Multi-agent parsing: iterate all agent results, merge into single emission.
-        // Each agent's edges carry per-agent contribution keys (Invariant 45).
         let mut emission = Emission::new();
-        for (agent_name, agent_result) in &response.results {
-            if let Some(ref text) = agent_result.response {
-                if let Some(parsed) = extract_json(text) {
-                    let contribution_key = format!("extract-semantic:{}", agent_name);
-                    let agent_emission =
-                        self.parse_agent_response(&parsed, file_path, &contribution_key);
-                    emission = emission.merge(agent_emission);
+        let mut over_budget = false;
+        for (source_id, input_text) in requests {
+            if self.budget.as_ref().is_some_and(|b| b.is_exhausted()) {
+                tracing::warn!(file_path = %file_path, "semantic extraction: llm budget exhausted");
+                over_budget = true;
+                break;
+            }
+
+            // Invoke llm-orc ensemble
+            let response = self
+                .client
+                .invoke(&self.ensemble_name, &input_text)
+                .await
+                .map_err(|e| match e {
+                    LlmOrcError::Unavailable(msg) => AdapterError::Skipped(msg),
+                    other => AdapterError::Internal(other.to_string()),
+                })?;
+
+            self.account(input, file_path, response.usage());
+            if response.is_failed() {
+                return Err(AdapterError::Internal(
+                    "llm-orc ensemble execution failed".to_string(),
+                ));
+            }
+
+            // Multi-agent parsing: iterate all agent results, merge into single emission.
+            // Each agent's edges carry per-agent contribution keys (Invariant 45).
+            for (agent_name, agent_result) in &response.results {
+                if let Some(ref text) = agent_result.response {
+                    if let Some(parsed) = extract_json(text) {
+                        let contribution_key = format!("extract-semantic:{}", agent_name);
+                        let agent_emission =
+                            self.parse_agent_response(&parsed, &source_id, &contribution_key);
+                        emission = emission.merge(agent_emission);
+                    }
                 }
             }
         }
 
+        if over_budget && emission.is_empty() {
+            return Err(AdapterError::Skipped("llm budget exhausted".to_string()));
+        }
+
         // Add provenance trail (Invariant 7 — dual obligation)
         if !emission.is_empty() {
             self.add_provenance(&mut emission, semantic_input);
@@ -325,12 +415,15 @@ impl Adapter for SemanticAdapter {
 
 /// Build a tagged_with edge from a file node (structure) to a concept node (semantic).
 ///
-/// Sets combined_weight = 1.0 and inserts a contribution under `contribution_key`.
+/// The contribution under `contribution_key` is the extractor's
+/// confidence in the concept (1.0 when it reported none), which is also
+/// kept as the edge's `confidence` property.
 /// Used by all semantic extraction parsers (standard, SpaCy, themes).
 fn tagged_with_edge(
     file_node_id: &NodeId,
     concept_id: NodeId,
     contribution_key: &str,
+    confidence: Option<f64>,
 ) -> AnnotatedEdge {
     let mut edge = Edge::new_cross_dimensional(
         file_node_id.clone(),
@@ -339,12 +432,21 @@ fn tagged_with_edge(
         dimension::SEMANTIC,
         "tagged_with",
     );
-    edge.combined_weight = 1.0;
+    let weight = confidence.unwrap_or(1.0) as f32;
+    edge.combined_weight = weight;
     edge.contributions
-        .insert(contribution_key.to_string(), 1.0);
+        .insert(contribution_key.to_string(), weight);
+    if let Some(confidence) = confidence {
+        edge.properties.insert(CONFIDENCE_PROPERTY.to_string(), PropertyValue::Float(confidence));
+    }
     AnnotatedEdge::new(edge)
 }
 
+/// The `confidence` an extractor reported for an item, clamped to 0.0–1.0.
+fn reported_confidence(item: &serde_json::Value) -> Option<f64> {
+    item.get("confidence").and_then(|v| v.as_f64()).map(|c| c.clamp(0.0, 1.0))
+}
+
 impl SemanticAdapter {
     /// Parse a single agent's JSON response into an emission with per-agent contribution keys.
     ///
@@ -352,13 +454,15 @@ impl SemanticAdapter {
     /// - `"entities"` key → SpaCy script output (parse_spacy_response)
     /// - `"themes"` key → theme extraction (parse_themes)
     /// - `"concepts"` / `"relationships"` → standard LLM extraction (inline)
+    ///
+    /// Concepts are tagged from `file_node_id` — the file node, or the
+    /// chunk node the response covers.
     fn parse_agent_response(
         &self,
         parsed: &serde_json::Value,
-        file_path: &str,
+        file_node_id: &NodeId,
         contribution_key: &str,
     ) -> Emission {
-        let file_node_id = NodeId::from_string(format!("file:{}", file_path));
 
         // Dispatch by response shape
         // SpaCy script wraps output in {"success": ..., "data": {"entities": ...}}
@@ -368,14 +472,14 @@ impl SemanticAdapter {
                 .and_then(|d| d.get("entities"))
                 .is_some();
         if has_entities {
-            return self.parse_spacy_response(parsed, &file_node_id, contribution_key);
+            return self.parse_spacy_response(parsed, file_node_id, contribution_key);
         }
 
         if parsed.get("themes").is_some()
             && parsed.get("concepts").is_none()
             && parsed.get("relationships").is_none()
         {
-            return self.parse_themes(parsed, &file_node_id, contribution_key);
+            return self.parse_themes(parsed, file_node_id, contribution_key);
         }
 
         // Standard: concepts + relationships
@@ -399,14 +503,15 @@ impl SemanticAdapter {
                         PropertyValue::String(concept_type.to_string()),
                     );
                 }
-                if let Some(confidence) = concept.get("confidence").and_then(|v| v.as_f64()) {
+                let confidence = reported_confidence(concept);
+                if let Some(confidence) = confidence {
                     node.properties.insert(
                         "confidence".to_string(),
                         PropertyValue::Float(confidence),
                     );
                 }
                 emission = emission.with_node(AnnotatedNode::new(node));
-                emission = emission.with_edge(tagged_with_edge(&file_node_id, concept_id, contribution_key));
+                emission = emission.with_edge(tagged_with_edge(file_node_id, concept_id, contribution_key, confidence));
             }
         }
 
@@ -418,11 +523,13 @@ impl SemanticAdapter {
                     .get("relationship")
                     .and_then(|v| v.as_str())
                     .unwrap_or("related_to");
+                // A relationship's weight is scaled by the confidence in it
+                let confidence = reported_confidence(rel);
                 let weight = rel
                     .get("weight")
-                    .or_else(|| rel.get("confidence"))
                     .and_then(|v| v.as_f64())
-                    .unwrap_or(1.0);
+                    .unwrap_or(1.0)
+                    * confidence.unwrap_or(1.0);
 
                 if source.is_empty() || target.is_empty() {
                     continue;
@@ -439,6 +546,9 @@ impl SemanticAdapter {
                 edge.combined_weight = weight as f32;
                 edge.contributions
                     .insert(contribution_key.to_string(), weight as f32);
+                if let Some(confidence) = confidence {
+                    edge.properties.insert(CONFIDENCE_PROPERTY.to_string(), PropertyValue::Float(confidence));
+                }
                 emission = emission.with_edge(AnnotatedEdge::new(edge));
             }
         }
@@ -477,7 +587,12 @@ impl SemanticAdapter {
                     PropertyValue::String("spacy".to_string()),
                 );
                 emission = emission.with_node(AnnotatedNode::new(node));
-                emission = emission.with_edge(tagged_with_edge(file_node_id, concept_id, contribution_key));
+                emission = emission.with_edge(tagged_with_edge(
+                    file_node_id,
+                    concept_id,
+                    contribution_key,
+                    reported_confidence(entity),
+                ));
             }
         }
 
@@ -503,9 +618,13 @@ impl SemanticAdapter {
                 let mut edge = Edge::new(source_id, target_id, relationship);
                 edge.source_dimension = dimension::SEMANTIC.to_string();
                 edge.target_dimension = dimension::SEMANTIC.to_string();
-                edge.combined_weight = 1.0;
+                let confidence = reported_confidence(rel);
+                edge.combined_weight = confidence.unwrap_or(1.0) as f32;
                 edge.contributions
-                    .insert(contribution_key.to_string(), 1.0);
+                    .insert(contribution_key.to_string(), edge.combined_weight);
+                if let Some(confidence) = confidence {
+                    edge.properties.insert(CONFIDENCE_PROPERTY.to_string(), PropertyValue::Float(confidence));
+                }
                 emission = emission.with_edge(AnnotatedEdge::new(edge));
             }
         }
@@ -586,7 +705,7 @@ impl SemanticAdapter {
                 }
                 emission = emission.with_node(AnnotatedNode::new(node));
 
-                emission = emission.with_edge(tagged_with_edge(file_node_id, concept_id, contribution_key));
+                emission = emission.with_edge(tagged_with_edge(file_node_id, concept_id, contribution_key, reported_confidence(theme)));
             }
         }
 
@@ -1474,6 +1593,121 @@ mod tests {
         }
     }
 
+    // --- Scenario: Chunked input tags concepts from each chunk ---
+
+    #[tokio::test]
+    async fn chunked_input_tags_concepts_from_chunks() {
+        use crate::adapter::chunking::{chunk_document, chunk_emission, ChunkStrategy};
+        let adapter = provenance_test_adapter(
+            r#"{"concepts": [{"label": "revenge", "confidence": 0.9}], "relationships": []}"#,
+        );
+
+        let ctx = Arc::new(Mutex::new(Context::new("test")));
+        let strategy = ChunkStrategy::TokenBudget { max_tokens: 4, overlap: 0 };
+        let chunks = chunk_document("/docs/hamlet.txt", "to be or not to be that is", &[], &strategy);
+        assert_eq!(chunks.len(), 2);
+        ctx.lock().unwrap().add_node(crate::adapter::file_node("/docs/hamlet.txt"));
+        let sink = test_sink(ctx.clone());
+        sink.emit(chunk_emission("/docs/hamlet.txt", &chunks)).await.unwrap();
+
+        let input = AdapterInput::new(
+            "extract-semantic",
+            SemanticInput::for_file("/docs/hamlet.txt").with_chunks(chunks.clone()),
+            "test",
+        );
+        adapter.process(&input, &sink).await.unwrap();
+
+        let payload = adapter.build_chunk_input(&SemanticInput::for_file("/docs/hamlet.txt"), &chunks[1]);
+        let payload: serde_json::Value = serde_json::from_str(&payload).unwrap();
+        assert_eq!(payload["content"], "to be that is");
+        assert_eq!(payload["chunk_id"], "chunk:/docs/hamlet.txt:1");
+
+        let snapshot = ctx.lock().unwrap();
+        let mut tagged_from: Vec<&str> = snapshot
+            .edges()
+            .filter(|e| e.relationship == "tagged_with")
+            .map(|e| e.source.as_str())
+            .collect();
+        tagged_from.sort();
+        assert_eq!(tagged_from, vec!["chunk:/docs/hamlet.txt:0", "chunk:/docs/hamlet.txt:1"]);
+    }
+
+    // --- Scenario: Invocation costs are recorded and budgets enforced ---
+
+    #[tokio::test]
+    async fn llm_costs_are_recorded_until_the_budget_runs_out() {
+        let mut response = crate::llm_orc::mock_response(vec![("synthesizer", r#"{"concepts": [{"label": "revenge"}]}"#)]);
+        response.metadata = serde_json::json!({"usage": {"input_tokens": 300, "output_tokens": 50, "cost_usd": 0.02}});
+        let client = Arc::new(MockClient::available().with_response("semantic-extraction", response));
+        let engine = Arc::new(crate::graph::PlexusEngine::new());
+        let budget = Arc::new(LlmBudget::new().with_max_tokens(500));
+        let adapter = SemanticAdapter::new(client, "semantic-extraction")
+            .with_cost_tracking(engine.clone())
+            .with_budget(budget.clone());
+
+        let ctx = Arc::new(Mutex::new(Context::new("test")));
+        ctx.lock().unwrap().add_node(crate::adapter::file_node("/docs/a.md"));
+        let sink = test_sink(ctx.clone());
+        let since = chrono::Utc::now() - chrono::Duration::seconds(1);
+
+        let input = AdapterInput::new("extract-semantic", SemanticInput::for_file("/docs/a.md"), "test");
+        adapter.process(&input, &sink).await.unwrap();
+        // 350 of 500 tokens spent: one more call fits, then the budget is gone
+        adapter.process(&input, &sink).await.unwrap();
+        let err = adapter.process(&input, &sink).await.unwrap_err();
+        assert!(matches!(err, AdapterError::Skipped(ref reason) if reason.contains("budget")));
+        assert_eq!(budget.spent().total_tokens(), 700);
+
+        let report = engine.llm_costs(&crate::graph::ContextId::from_string("test"), since).unwrap();
+        assert_eq!(report.records.len(), 2);
+        assert_eq!(report.records[0].item, "/docs/a.md");
+        assert_eq!(report.total(), LlmUsage { input_tokens: 600, output_tokens: 100, cost_usd: 0.04 });
+        assert!(engine
+            .llm_costs(&crate::graph::ContextId::from_string("test"), chrono::Utc::now() + chrono::Duration::seconds(1))
+            .unwrap()
+            .records
+            .is_empty());
+    }
+
+    // --- Scenario: Extractor confidence reaches edge contributions ---
+
+    #[tokio::test]
+    async fn concept_and_relationship_confidence_flow_into_edges() {
+        let ctx = Arc::new(Mutex::new(Context::new("test")));
+        ctx.lock().unwrap().add_node(crate::adapter::file_node("/docs/a.md"));
+        let sink = test_sink(ctx.clone());
+        let input = AdapterInput::new("extract-semantic", SemanticInput::for_file("/docs/a.md"), "test");
+
+        for confidence in ["0.8", "0.6"] {
+            let adapter = provenance_test_adapter(&format!(
+                r#"{{"concepts": [{{"label": "revenge", "confidence": {confidence}}}, {{"label": "grief"}}],
+                    "relationships": [{{"source": "grief", "target": "revenge", "relationship": "fuels",
+                                        "weight": 0.5, "confidence": 0.5}}]}}"#
+            ));
+            adapter.process(&input, &sink).await.unwrap();
+        }
+
+        let snapshot = ctx.lock().unwrap();
+        let tagged = |label: &str| {
+            snapshot
+                .edges()
+                .find(|e| e.relationship == "tagged_with" && e.target.as_str() == format!("concept:{label}"))
+                .unwrap()
+        };
+        let revenge = tagged("revenge");
+        assert_eq!(revenge.contributions["extract-semantic:synthesizer"], 0.6, "latest extraction's contribution");
+        assert_eq!(revenge.confidence(), Some(0.8), "the strongest confidence is kept");
+        assert_eq!(tagged("grief").confidence(), None);
+        assert_eq!(tagged("grief").contributions["extract-semantic:synthesizer"], 1.0);
+
+        let fuels = snapshot.edges().find(|e| e.relationship == "fuels").unwrap();
+        assert_eq!(fuels.contributions["extract-semantic:synthesizer"], 0.25, "weight scaled by confidence");
+
+        let filter = crate::query::QueryFilter { min_confidence: Some(0.7), ..Default::default() };
+        assert!(filter.edge_passes(revenge));
+        assert!(!filter.edge_passes(fuels));
+    }
+
     // --- Scenario: SemanticAdapter produces contains edges ---
 
     #[tokio::test]
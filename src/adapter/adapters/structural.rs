@@ -10,8 +10,11 @@
 //! hands vocabulary + sections to semantic extraction (ADR-031).
 
 use crate::adapter::types::{AnnotatedEdge, AnnotatedNode};
+use crate::graph::{dimension, ContentType, Edge, Node, NodeId, PropertyValue};
+use std::collections::HashSet;
 use async_trait::async_trait;
-use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use crate::adapter::links::{LinkKind, LinkReference};
+use pulldown_cmark::{Event, LinkType, Options, Parser, Tag, TagEnd};
 
 /// A registered component for heuristic structural analysis.
 ///
@@ -36,13 +39,21 @@ pub trait StructuralModule: Send + Sync {
     ///
     /// An empty output is normal — not every file yields useful structure.
     async fn analyze(&self, file_path: &str, content: &str) -> StructuralOutput;
+
+    /// `analyze`, also reporting the problems the module hit on the file.
+    /// Whatever output it did produce is still emitted; the coordinator's
+    /// `AnalysisFailurePolicy` decides whether the rest of the file's
+    /// analysis goes ahead. The default reports none.
+    async fn analyze_reporting(&self, file_path: &str, content: &str) -> (StructuralOutput, Vec<AnalysisFailure>) {
+        (self.analyze(file_path, content).await, Vec::new())
+    }
 }
 
 /// Combined output from structural analysis modules for a single file.
 ///
 /// Merged by the coordinator when multiple modules match (Invariant 53):
 /// vocabulary unioned case-insensitively, sections sorted by start_line,
-/// emissions kept per-module.
+/// emissions kept per-module, links concatenated.
 #[derive(Debug, Clone, Default)]
 pub struct StructuralOutput {
     /// Vocabulary terms discovered by structural analysis.
@@ -59,6 +70,36 @@ pub struct StructuralOutput {
     /// Concept nodes, structural edges — emitted by the coordinator
     /// on behalf of each module with the module's adapter ID.
     pub emissions: Vec<ModuleEmission>,
+
+    /// Links to other documents, resolved against the context by the
+    /// coordinator when link resolution is on (see `links`).
+    pub links: Vec<LinkReference>,
+}
+
+/// One module's failure on one file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnalysisFailure {
+    pub module_id: String,
+    pub message: String,
+}
+
+impl AnalysisFailure {
+    pub fn new(module_id: impl Into<String>, message: impl Into<String>) -> Self {
+        Self { module_id: module_id.into(), message: message.into() }
+    }
+}
+
+/// What the coordinator does when a structural module fails on a file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AnalysisFailurePolicy {
+    /// Keep going: emit what the other modules produced, mark the file's
+    /// structural analysis `partial` and record the failures on its
+    /// extraction-status node so it can be re-analyzed later.
+    #[default]
+    CollectErrors,
+    /// Stop the file's background analysis at the first failure (marked
+    /// `failed: …`); later phases don't run.
+    FailFast,
 }
 
 /// A structural boundary identified by structural analysis.
@@ -80,14 +121,76 @@ pub struct ModuleEmission {
     pub edges: Vec<AnnotatedEdge>,
 }
 
+/// Node property naming the first line of the file region a module
+/// node was derived from (1-based, Int).
+pub const REGION_START_PROPERTY: &str = "start_line";
+/// Node property naming the last line of that region; defaults to the start.
+pub const REGION_END_PROPERTY: &str = "end_line";
+/// Adapter ID credited with co-location edges.
+pub const COLOCATION_MODULE_ID: &str = "extract-analysis-colocation";
+
+fn region(node: &Node) -> Option<(i64, i64)> {
+    let line = |key| match node.properties.get(key) {
+        Some(PropertyValue::Int(n)) => Some(*n),
+        _ => None,
+    };
+    let start = line(REGION_START_PROPERTY)?;
+    Some((start, line(REGION_END_PROPERTY).unwrap_or(start).max(start)))
+}
+
+/// Cross-dimensional edges between nodes that different modules derived
+/// from overlapping regions of the same file — a heading's structure node
+/// and a concept found in its section, say.
+///
+/// Only nodes carrying `REGION_START_PROPERTY` take part, and only pairs
+/// from different modules in different dimensions are linked; each edge
+/// runs from the earlier module's node to the later one's.
+pub fn colocated_edges(emissions: &[ModuleEmission], relationship: &str) -> Vec<AnnotatedEdge> {
+    let located: Vec<(usize, &Node, (i64, i64))> = emissions
+        .iter()
+        .enumerate()
+        .flat_map(|(i, e)| e.nodes.iter().filter_map(move |n| region(&n.node).map(|r| (i, &n.node, r))))
+        .collect();
+
+    let mut seen = HashSet::new();
+    let mut edges = Vec::new();
+    for (i, (module_a, a, (start_a, end_a))) in located.iter().enumerate() {
+        for (module_b, b, (start_b, end_b)) in &located[i + 1..] {
+            let overlaps = start_a <= end_b && start_b <= end_a;
+            if module_a == module_b || a.dimension == b.dimension || !overlaps {
+                continue;
+            }
+            if !seen.insert((a.id.clone(), b.id.clone())) {
+                continue;
+            }
+            let edge = Edge::new_cross_dimensional(
+                a.id.clone(),
+                a.dimension.clone(),
+                b.id.clone(),
+                b.dimension.clone(),
+                relationship,
+            );
+            edges.push(AnnotatedEdge::new(edge));
+        }
+    }
+    edges
+}
+
 /// Built-in structural module for markdown files (ADR-032).
 ///
 /// Uses pulldown-cmark to extract:
 /// - Section boundaries from ATX headings
 /// - Vocabulary from heading text, link display text, and code block languages
+/// - Document links: inline, reference-style, `[[wikilinks]]`, and links
+///   inside footnote definitions (as `LinkReference`s)
+/// - With `with_section_anchors`: heading, code block, and (top-level) list
+///   nodes carrying byte and line spans, linked by `contains` edges from
+///   the file node down the heading hierarchy
 ///
 /// MIME affinity: `text/markdown` — does not match `text/plain` or other text types.
-pub struct MarkdownStructureModule;
+pub struct MarkdownStructureModule {
+    anchors: bool,
+}
 
 impl Default for MarkdownStructureModule {
     fn default() -> Self {
@@ -97,8 +200,83 @@ impl Default for MarkdownStructureModule {
 
 impl MarkdownStructureModule {
     pub fn new() -> Self {
-        Self
+        Self { anchors: false }
     }
+
+    /// Emit anchor nodes for sections, code blocks, and lists, so marks
+    /// and concepts can attach to a section rather than the whole file.
+    pub fn with_section_anchors(mut self) -> Self {
+        self.anchors = true;
+        self
+    }
+}
+
+/// A heading, code block, or list located in the source.
+struct Anchor {
+    node_type: &'static str,
+    bytes: std::ops::Range<usize>,
+    /// Heading level; 0 for blocks
+    level: usize,
+    properties: Vec<(&'static str, PropertyValue)>,
+}
+
+/// Anchor nodes plus `contains` edges: file → top-level headings, each
+/// heading → its subheadings and the blocks in its own section.
+/// `anchors` is in document order.
+fn anchor_emission(
+    module_id: &str,
+    file_path: &str,
+    content_len: usize,
+    anchors: &[Anchor],
+    byte_to_line: impl Fn(usize) -> usize,
+) -> ModuleEmission {
+    let file_id = NodeId::from_string(format!("file:{}", file_path));
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+    let mut open_headings: Vec<(usize, NodeId)> = Vec::new();
+
+    for (i, anchor) in anchors.iter().enumerate() {
+        // A heading's span runs to the next heading at its level or above
+        let end_byte = if anchor.level > 0 {
+            anchors[i + 1..]
+                .iter()
+                .find(|next| next.level > 0 && next.level <= anchor.level)
+                .map_or(content_len, |next| next.bytes.start)
+        } else {
+            anchor.bytes.end
+        };
+        let start_line = byte_to_line(anchor.bytes.start);
+        let end_line = byte_to_line(end_byte.saturating_sub(1).max(anchor.bytes.start));
+
+        let id = NodeId::from_string(format!("{}:{}:{}", anchor.node_type, file_path, start_line));
+        let mut node = Node::new_in_dimension(anchor.node_type, ContentType::Document, dimension::STRUCTURE);
+        node.id = id.clone();
+        for (key, value) in &anchor.properties {
+            node.properties.insert(key.to_string(), value.clone());
+        }
+        for (key, value) in [
+            (REGION_START_PROPERTY, start_line),
+            (REGION_END_PROPERTY, end_line),
+            ("start_byte", anchor.bytes.start),
+            ("end_byte", end_byte),
+        ] {
+            node.properties.insert(key.to_string(), PropertyValue::Int(value as i64));
+        }
+        nodes.push(AnnotatedNode::new(node));
+
+        if anchor.level > 0 {
+            while open_headings.last().is_some_and(|(level, _)| *level >= anchor.level) {
+                open_headings.pop();
+            }
+        }
+        let parent = open_headings.last().map_or(&file_id, |(_, id)| id).clone();
+        edges.push(AnnotatedEdge::new(Edge::new_in_dimension(parent, id.clone(), "contains", dimension::STRUCTURE)));
+        if anchor.level > 0 {
+            open_headings.push((anchor.level, id));
+        }
+    }
+
+    ModuleEmission { module_id: module_id.to_string(), nodes, edges }
 }
 
 #[async_trait]
@@ -111,7 +289,7 @@ impl StructuralModule for MarkdownStructureModule {
         "text/markdown"
     }
 
-    async fn analyze(&self, _file_path: &str, content: &str) -> StructuralOutput {
+    async fn analyze(&self, file_path: &str, content: &str) -> StructuralOutput {
         let mut vocabulary: Vec<String> = Vec::new();
         let mut sections: Vec<SectionBoundary> = Vec::new();
         let mut in_heading = false;
@@ -136,52 +314,74 @@ impl StructuralModule for MarkdownStructureModule {
             start_line: usize,
         }
         let mut heading_infos: Vec<HeadingInfo> = Vec::new();
+        let mut anchors: Vec<Anchor> = Vec::new();
+        let mut heading_level = 0;
+        let mut list_depth = 0usize;
 
         let mut in_link = false;
         let mut link_text = String::new();
+        let mut links: Vec<LinkReference> = Vec::new();
+        let mut in_footnote = false;
 
-        let parser = Parser::new_ext(content, Options::empty()).into_offset_iter();
+        let options = Options::ENABLE_WIKILINKS | Options::ENABLE_FOOTNOTES;
+        let parser = Parser::new_ext(content, options).into_offset_iter();
 
         for (event, range) in parser {
             match event {
-                Event::Start(Tag::Heading { .. }) => {
+                Event::Start(Tag::Heading { level, .. }) => {
+                    heading_level = level as usize;
                     in_heading = true;
                     current_heading_text.clear();
                 }
-                Event::End(TagEnd::Heading(_)) => {
-                    if in_heading {
-                        let heading_line = byte_to_line(range.start);
-                        let text = current_heading_text.trim().to_string();
-                        if !text.is_empty() {
-                            let lower = text.to_lowercase();
-                            if !vocabulary.iter().any(|v| v.to_lowercase() == lower) {
-                                vocabulary.push(lower);
-                            }
-                            heading_infos.push(HeadingInfo {
-                                label: text,
-                                start_line: heading_line,
-                            });
+                Event::End(TagEnd::Heading(_)) if in_heading => {
+                    let heading_line = byte_to_line(range.start);
+                    let text = current_heading_text.trim().to_string();
+                    if !text.is_empty() {
+                        let lower = text.to_lowercase();
+                        if !vocabulary.iter().any(|v| v.to_lowercase() == lower) {
+                            vocabulary.push(lower);
                         }
-                        in_heading = false;
-                        current_heading_text.clear();
+                        anchors.push(Anchor {
+                            node_type: "heading",
+                            bytes: range.clone(),
+                            level: heading_level,
+                            properties: vec![
+                                ("label", PropertyValue::String(text.clone())),
+                                ("level", PropertyValue::Int(heading_level as i64)),
+                            ],
+                        });
+                        heading_infos.push(HeadingInfo {
+                            label: text,
+                            start_line: heading_line,
+                        });
                     }
+                    in_heading = false;
+                    current_heading_text.clear();
                 }
-                Event::Start(Tag::Link { .. }) => {
+                Event::Start(Tag::Link { link_type, dest_url, .. }) => {
+                    let kind = match link_type {
+                        _ if in_footnote => Some(LinkKind::Footnote),
+                        LinkType::Inline => Some(LinkKind::Inline),
+                        LinkType::Reference | LinkType::Collapsed | LinkType::Shortcut => Some(LinkKind::Reference),
+                        LinkType::WikiLink { .. } => Some(LinkKind::Wiki),
+                        _ => None,
+                    };
+                    links.extend(kind.and_then(|kind| LinkReference::new(&dest_url, kind)));
                     in_link = true;
                     link_text.clear();
                 }
-                Event::End(TagEnd::Link) => {
-                    if in_link {
-                        let text = link_text.trim().to_string();
-                        if !text.is_empty() {
-                            let lower = text.to_lowercase();
-                            if !vocabulary.iter().any(|v| v.to_lowercase() == lower) {
-                                vocabulary.push(lower);
-                            }
+                Event::Start(Tag::FootnoteDefinition(_)) => in_footnote = true,
+                Event::End(TagEnd::FootnoteDefinition) => in_footnote = false,
+                Event::End(TagEnd::Link) if in_link => {
+                    let text = link_text.trim().to_string();
+                    if !text.is_empty() {
+                        let lower = text.to_lowercase();
+                        if !vocabulary.iter().any(|v| v.to_lowercase() == lower) {
+                            vocabulary.push(lower);
                         }
-                        in_link = false;
-                        link_text.clear();
                     }
+                    in_link = false;
+                    link_text.clear();
                 }
                 Event::Code(code) if in_heading => {
                     current_heading_text.push_str(&code);
@@ -195,14 +395,30 @@ impl StructuralModule for MarkdownStructureModule {
                 Event::Text(text) if in_link => {
                     link_text.push_str(&text);
                 }
-                Event::Start(Tag::CodeBlock(pulldown_cmark::CodeBlockKind::Fenced(lang))) => {
-                    let lang_str = lang.trim().to_string();
-                    if !lang_str.is_empty() {
-                        let lower = lang_str.to_lowercase();
-                        if !vocabulary.iter().any(|v| v.to_lowercase() == lower) {
-                            vocabulary.push(lower);
+                Event::Start(Tag::CodeBlock(kind)) => {
+                    let mut properties = Vec::new();
+                    if let pulldown_cmark::CodeBlockKind::Fenced(lang) = kind {
+                        let lang_str = lang.trim().to_string();
+                        if !lang_str.is_empty() {
+                            let lower = lang_str.to_lowercase();
+                            if !vocabulary.iter().any(|v| v.to_lowercase() == lower) {
+                                vocabulary.push(lower.clone());
+                            }
+                            properties.push(("language", PropertyValue::String(lower)));
                         }
                     }
+                    anchors.push(Anchor { node_type: "code_block", bytes: range, level: 0, properties });
+                }
+                Event::Start(Tag::List(first)) => {
+                    // Nested lists belong to their outermost list's anchor
+                    if list_depth == 0 {
+                        let properties = vec![("ordered", PropertyValue::Bool(first.is_some()))];
+                        anchors.push(Anchor { node_type: "list", bytes: range, level: 0, properties });
+                    }
+                    list_depth += 1;
+                }
+                Event::End(TagEnd::List(_)) => {
+                    list_depth = list_depth.saturating_sub(1);
                 }
                 _ => {}
             }
@@ -226,10 +442,17 @@ impl StructuralModule for MarkdownStructureModule {
             });
         }
 
+        let emissions = if self.anchors && !anchors.is_empty() {
+            vec![anchor_emission(self.id(), file_path, content.len(), &anchors, byte_to_line)]
+        } else {
+            Vec::new()
+        };
+
         StructuralOutput {
             vocabulary,
             sections,
-            emissions: Vec::new(),
+            emissions,
+            links,
         }
     }
 }
@@ -410,4 +633,110 @@ mod tests {
         assert!(!output.vocabulary.is_empty());
         assert!(!output.sections.is_empty());
     }
+
+    #[tokio::test]
+    async fn section_anchors_record_spans_and_heading_hierarchy() {
+        let content = "# Guide\nIntro.\n## Install\n```sh\ncargo add plexus\n```\n## Usage\n- one\n  - nested\n- two\n# Appendix\n";
+        let m = MarkdownStructureModule::new().with_section_anchors();
+        let output = m.analyze("guide.md", content).await;
+        assert_eq!(output.sections.len(), 4, "sections are unchanged");
+        assert_eq!(output.emissions.len(), 1);
+        let emission = &output.emissions[0];
+        assert_eq!(emission.module_id, m.id());
+
+        let node = |id: &str| {
+            emission.nodes.iter().map(|n| &n.node).find(|n| n.id.as_str() == id).unwrap_or_else(|| panic!("{id}"))
+        };
+        let int = |id: &str, key: &str| match node(id).properties.get(key) {
+            Some(PropertyValue::Int(v)) => *v,
+            other => panic!("{id}.{key}: {other:?}"),
+        };
+        assert_eq!(emission.nodes.len(), 6, "4 headings, 1 code block, 1 top-level list");
+
+        // "Guide" spans everything up to the next level-1 heading
+        assert_eq!((int("heading:guide.md:1", "start_line"), int("heading:guide.md:1", "end_line")), (1, 10));
+        assert_eq!(int("heading:guide.md:3", "end_line"), 6);
+        assert_eq!(int("code_block:guide.md:4", "start_line"), 4);
+        assert_eq!(int("code_block:guide.md:4", "end_line"), 6);
+        let code_start = content.find("```").unwrap() as i64;
+        assert_eq!(int("code_block:guide.md:4", "start_byte"), code_start);
+        assert_eq!(node("code_block:guide.md:4").properties.get("language"), Some(&PropertyValue::String("sh".into())));
+        assert_eq!(int("list:guide.md:8", "end_line"), 10);
+        assert_eq!(int("heading:guide.md:11", "end_byte"), content.len() as i64);
+
+        let mut contains: Vec<(&str, &str)> =
+            emission.edges.iter().map(|e| (e.edge.source.as_str(), e.edge.target.as_str())).collect();
+        contains.sort();
+        assert_eq!(
+            contains,
+            vec![
+                ("file:guide.md", "heading:guide.md:1"),
+                ("file:guide.md", "heading:guide.md:11"),
+                ("heading:guide.md:1", "heading:guide.md:3"),
+                ("heading:guide.md:1", "heading:guide.md:7"),
+                ("heading:guide.md:3", "code_block:guide.md:4"),
+                ("heading:guide.md:7", "list:guide.md:8"),
+            ]
+        );
+        assert!(emission.edges.iter().all(|e| e.edge.relationship == "contains"));
+    }
+
+    #[tokio::test]
+    async fn markdown_links_cover_inline_reference_wiki_and_footnote_links() {
+        let content = "[a](a.md) [b][bee] [[Cee|see]] [web](https://example.com) [top](#top)[^n]\n\n[bee]: b.md#part\n\n[^n]: From [d](d.md).\n";
+        let output = MarkdownStructureModule::new().analyze("x.md", content).await;
+        let links: Vec<(&str, LinkKind)> = output.links.iter().map(|l| (l.target.as_str(), l.kind)).collect();
+        assert_eq!(
+            links,
+            vec![
+                ("a.md", LinkKind::Inline),
+                ("b.md", LinkKind::Reference),
+                ("Cee", LinkKind::Wiki),
+                ("d.md", LinkKind::Footnote),
+            ]
+        );
+    }
+
+    // --- Co-location inference ---
+
+    fn located(id: &str, dimension: &str, lines: (i64, i64)) -> AnnotatedNode {
+        let mut node = Node::new_in_dimension("section", crate::graph::ContentType::Document, dimension);
+        node.id = crate::graph::NodeId::from_string(id);
+        node.properties.insert(REGION_START_PROPERTY.into(), PropertyValue::Int(lines.0));
+        node.properties.insert(REGION_END_PROPERTY.into(), PropertyValue::Int(lines.1));
+        AnnotatedNode::new(node)
+    }
+
+    #[test]
+    fn colocated_edges_link_overlapping_nodes_across_modules_and_dimensions() {
+        use crate::graph::dimension;
+        let headings = ModuleEmission {
+            module_id: "headings".into(),
+            nodes: vec![
+                located("heading:intro", dimension::STRUCTURE, (1, 10)),
+                located("heading:usage", dimension::STRUCTURE, (11, 20)),
+            ],
+            edges: vec![],
+        };
+        let terms = ModuleEmission {
+            module_id: "terms".into(),
+            nodes: vec![
+                located("concept:graph", dimension::SEMANTIC, (4, 4)),
+                // Same dimension as the headings: not cross-dimensional
+                located("heading:aside", dimension::STRUCTURE, (5, 6)),
+            ],
+            edges: vec![],
+        };
+        let mut unlocated = headings.clone();
+        unlocated.nodes.push(AnnotatedNode::new(Node::new("concept", crate::graph::ContentType::Concept)));
+
+        let edges = colocated_edges(&[unlocated, terms], "co_located");
+        assert_eq!(edges.len(), 1);
+        let edge = &edges[0].edge;
+        assert_eq!((edge.source.as_str(), edge.target.as_str()), ("heading:intro", "concept:graph"));
+        assert_eq!(edge.source_dimension, dimension::STRUCTURE);
+        assert_eq!(edge.target_dimension, dimension::SEMANTIC);
+
+        assert!(colocated_edges(&[headings], "co_located").is_empty(), "one module never links to itself");
+    }
 }
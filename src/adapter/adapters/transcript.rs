@@ -0,0 +1,572 @@
+//! TranscriptAdapter — timed audio transcripts (WebVTT, SRT, whisper JSON)
+//!
+//! Maps a transcript of a recording to graph structure:
+//! - A transcript node (Document, structure dimension)
+//! - A segment node per cue (Document, structure dimension) carrying its
+//!   `start_secs`/`end_secs` and `timecode`, `contains`ed by the
+//!   transcript; each segment `follows` the one before it
+//! - A speaker node per labelled speaker (relational dimension, shared
+//!   with `ConversationAdapter`), which their segments are `spoken_by`
+//! - A concept node per segment tag and a `tagged_with` edge from the
+//!   segment, tags passing the context's `TagPolicy`
+//!
+//! Provenance: one chain per transcript and a mark per segment. Marks point
+//! at timecodes rather than lines: `file` is the recording (the input's
+//! `source`), `timecode` the segment's start, and there is no `line`.
+//!
+//! Speakers come from WebVTT voice spans (`<v Ada>...`), a whisper
+//! segment's `speaker`, or a short `Name:` prefix on the cue text. When
+//! the recording time is known (`recorded_at`), each segment's
+//! `created_at` is that time plus its offset (ADR-039).
+
+use super::conversation::speaker_id;
+use crate::adapter::sink::{AdapterError, AdapterSink};
+use crate::adapter::traits::{Adapter, AdapterInput};
+use crate::adapter::types::{chain_node, concept_node, mark_node, rfc3339_now, Emission, OutboundEvent};
+use crate::graph::events::GraphEvent;
+use crate::graph::{dimension, ContentType, Context, Edge, Node, NodeId, PropertyValue};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+/// One timed segment of a transcript.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segment {
+    /// Offset into the recording, in seconds
+    pub start: f64,
+    pub end: f64,
+    pub speaker: Option<String>,
+    pub text: String,
+    /// Tags applied to the segment (each produces a concept node)
+    pub tags: Vec<String>,
+}
+
+impl Segment {
+    pub fn new(start: f64, end: f64, text: impl Into<String>) -> Self {
+        Self { start, end, speaker: None, text: text.into(), tags: Vec::new() }
+    }
+
+    pub fn with_speaker(mut self, speaker: impl Into<String>) -> Self {
+        self.speaker = Some(speaker.into());
+        self
+    }
+
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Parse a whisper-style segment: `start`, `end` (seconds), `text`,
+    /// optional `speaker` and `tags`.
+    fn from_json(json: &serde_json::Value) -> Option<Self> {
+        let start = json.get("start")?.as_f64()?;
+        let end = json.get("end").and_then(|v| v.as_f64()).unwrap_or(start);
+        let raw = json.get("text")?.as_str()?;
+        let (label, text) = split_speaker(raw);
+        if text.is_empty() {
+            return None;
+        }
+        let speaker = json.get("speaker").and_then(|v| v.as_str()).map(|s| s.to_string()).or(label);
+        let tags = json
+            .get("tags")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect())
+            .unwrap_or_default();
+        Some(Self { start, end, speaker, text, tags })
+    }
+}
+
+/// Input data for the TranscriptAdapter.
+#[derive(Debug, Clone)]
+pub struct TranscriptInput {
+    /// Stable ID; derived from the segments when absent
+    pub transcript_id: Option<String>,
+    pub title: Option<String>,
+    /// The recording the transcript is of (e.g., "memos/2026-03-01.m4a")
+    pub source: Option<String>,
+    /// When the recording started, RFC 3339
+    pub recorded_at: Option<String>,
+    pub segments: Vec<Segment>,
+}
+
+impl TranscriptInput {
+    pub fn new(segments: Vec<Segment>) -> Self {
+        Self { transcript_id: None, title: None, source: None, recorded_at: None, segments }
+    }
+
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.transcript_id = Some(id.into());
+        self
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    pub fn with_recorded_at(mut self, recorded_at: impl Into<String>) -> Self {
+        self.recorded_at = Some(recorded_at.into());
+        self
+    }
+
+    /// Parse WebVTT or SRT text (the two share a cue layout).
+    ///
+    /// Cues are blocks separated by blank lines, each with a
+    /// `start --> end` timing line; cue identifiers, cue settings, and
+    /// WebVTT `NOTE`/`STYLE`/`REGION` blocks are skipped.
+    pub fn parse(text: &str) -> Self {
+        let text = text.trim_start_matches('\u{feff}').replace("\r\n", "\n");
+        let segments = text
+            .split("\n\n")
+            .filter_map(|block| {
+                let mut lines = block.lines().skip_while(|l| !l.contains("-->"));
+                let (start, end) = lines.next()?.split_once("-->")?;
+                let start = parse_timecode(start.trim())?;
+                let end = parse_timecode(end.split_whitespace().next()?)?;
+                let raw = lines.map(str::trim).filter(|l| !l.is_empty()).collect::<Vec<_>>().join(" ");
+                let (speaker, text) = split_speaker(&raw);
+                (!text.is_empty()).then(|| Segment { start, end, speaker, text, tags: Vec::new() })
+            })
+            .collect();
+        Self::new(segments)
+    }
+
+    /// Parse a JSON transcript.
+    ///
+    /// Required: `segments` (whisper-style array, see `Segment`), or
+    /// `vtt`/`srt` (the transcript file's text)
+    /// Optional: `transcript_id` (or `id`), `title`, `source`,
+    /// `recorded_at` (strings)
+    pub fn from_json(json: &serde_json::Value) -> Result<Self, AdapterError> {
+        let mut input = if let Some(segments) = json.get("segments").and_then(|v| v.as_array()) {
+            Self::new(segments.iter().filter_map(Segment::from_json).collect())
+        } else if let Some(text) = ["vtt", "srt"].iter().find_map(|k| json.get(*k).and_then(|v| v.as_str())) {
+            Self::parse(text)
+        } else {
+            return Err(AdapterError::Internal(
+                "transcript input requires a 'segments' array or 'vtt'/'srt' text".into(),
+            ));
+        };
+        let string = |key: &str| json.get(key).and_then(|v| v.as_str()).map(|s| s.to_string());
+        input.transcript_id = string("transcript_id").or_else(|| string("id"));
+        input.title = string("title");
+        input.source = string("source");
+        input.recorded_at = string("recorded_at");
+        Ok(input)
+    }
+}
+
+/// `HH:MM:SS.mmm`, `MM:SS.mmm`, or SRT's `HH:MM:SS,mmm`, in seconds.
+/// `None` unless every part is a finite, non-negative number.
+fn parse_timecode(value: &str) -> Option<f64> {
+    let value = value.replace(',', ".");
+    let mut seconds = 0.0;
+    for part in value.split(':') {
+        let part = part.parse::<f64>().ok().filter(|p| valid_offset(*p))?;
+        seconds = seconds * 60.0 + part;
+    }
+    valid_offset(seconds).then_some(seconds)
+}
+
+/// Whether `seconds` can be an offset into a recording.
+fn valid_offset(seconds: f64) -> bool {
+    seconds.is_finite() && seconds >= 0.0
+}
+
+/// Seconds as `HH:MM:SS.mmm`.
+pub fn timecode(seconds: f64) -> String {
+    let millis = (seconds.max(0.0) * 1000.0).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        millis / 3_600_000,
+        millis / 60_000 % 60,
+        millis / 1000 % 60,
+        millis % 1000
+    )
+}
+
+/// Split a speaker label from cue text — a WebVTT voice span
+/// (`<v Ada>`, `<v.loud Ada>`) or a `Name:` prefix of at most three
+/// capitalised words — and strip any remaining markup.
+fn split_speaker(raw: &str) -> (Option<String>, String) {
+    let mut speaker = None;
+    if let Some(at) = raw.find("<v") {
+        if let Some(close) = raw[at..].find('>') {
+            let name = raw[at + 2..at + close].split_once(' ').map(|(_, n)| n.trim()).unwrap_or("");
+            if !name.is_empty() {
+                speaker = Some(name.to_string());
+            }
+        }
+    }
+    let mut text = String::with_capacity(raw.len());
+    let mut in_tag = false;
+    for c in raw.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    let mut text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if speaker.is_none() {
+        if let Some((name, rest)) = text.split_once(": ") {
+            let is_label = name.len() <= 32
+                && name.split(' ').count() <= 3
+                && name.chars().all(|c| c.is_alphanumeric() || " .-_'".contains(c))
+                && name.split(' ').all(|w| w.starts_with(|c: char| c.is_uppercase() || c.is_ascii_digit()));
+            if is_label && !name.is_empty() {
+                speaker = Some(name.to_string());
+                text = rest.trim().to_string();
+            }
+        }
+    }
+    (speaker, text)
+}
+
+/// Timed transcript adapter.
+pub struct TranscriptAdapter {
+    adapter_id: String,
+}
+
+impl TranscriptAdapter {
+    pub fn new(adapter_id: impl Into<String>) -> Self {
+        Self { adapter_id: adapter_id.into() }
+    }
+
+    /// The transcript's ID: given, or a hash of its segments.
+    fn transcript_id(&self, transcript: &TranscriptInput) -> String {
+        if let Some(ref id) = transcript.transcript_id {
+            return id.clone();
+        }
+        // UUID v5 namespace for Plexus transcripts (stable, arbitrary)
+        const TRANSCRIPT_NS: Uuid = Uuid::from_bytes([
+            0x8e, 0x27, 0x0b, 0x6d, 0x51, 0xc9, 0x4a, 0x13,
+            0xb4, 0x7f, 0x62, 0x1d, 0xe8, 0x35, 0x9c, 0x40,
+        ]);
+        let mut hash_input = self.adapter_id.clone();
+        for segment in &transcript.segments {
+            hash_input.push('\u{1e}');
+            hash_input.push_str(&segment.start.to_string());
+            hash_input.push('\u{1f}');
+            hash_input.push_str(&segment.text);
+        }
+        Uuid::new_v5(&TRANSCRIPT_NS, hash_input.as_bytes()).to_string()
+    }
+}
+
+#[async_trait]
+impl Adapter for TranscriptAdapter {
+    fn id(&self) -> &str {
+        &self.adapter_id
+    }
+
+    fn input_kind(&self) -> &str {
+        "transcript"
+    }
+
+    fn input_from_json(&self, json: &serde_json::Value) -> Result<Box<dyn std::any::Any + Send + Sync>, AdapterError> {
+        Ok(Box::new(TranscriptInput::from_json(json)?))
+    }
+
+    fn input_schema(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({
+            "type": "object",
+            "anyOf": [{"required": ["segments"]}, {"required": ["vtt"]}, {"required": ["srt"]}],
+            "properties": {
+                "segments": {"type": "array", "items": {"type": "object"}},
+                "vtt": {"type": "string"},
+                "srt": {"type": "string"},
+                "transcript_id": {"type": "string"},
+                "id": {"type": "string"},
+                "title": {"type": "string"},
+                "source": {"type": "string"},
+                "recorded_at": {"type": "string"},
+            }
+        }))
+    }
+
+    async fn process(
+        &self,
+        input: &AdapterInput,
+        sink: &dyn AdapterSink,
+    ) -> Result<(), AdapterError> {
+        // Accept both typed TranscriptInput and raw JSON
+        let owned: TranscriptInput;
+        let transcript: &TranscriptInput = if let Some(t) = input.downcast_data::<TranscriptInput>() {
+            t
+        } else if let Some(json) = input.downcast_data::<serde_json::Value>() {
+            owned = TranscriptInput::from_json(json)?;
+            &owned
+        } else {
+            return Err(AdapterError::InvalidInput);
+        };
+        if transcript.segments.is_empty() {
+            return Err(AdapterError::Internal("transcript has no segments".into()));
+        }
+        let recorded_at = match transcript.recorded_at {
+            Some(ref ts) => Some(
+                DateTime::parse_from_rfc3339(ts)
+                    .map_err(|e| AdapterError::Internal(format!("invalid recorded_at '{}': {}", ts, e)))?
+                    .with_timezone(&Utc),
+            ),
+            None => None,
+        };
+        if let Some(i) = transcript.segments.iter().position(|s| !valid_offset(s.start) || !valid_offset(s.end)) {
+            return Err(AdapterError::Internal(format!("segment {} has a negative or non-finite offset", i)));
+        }
+        let at_offset = |seconds: f64| match recorded_at {
+            Some(start) => Duration::try_milliseconds((seconds * 1000.0).round() as i64)
+                .and_then(|offset| start.checked_add_signed(offset))
+                .map(|at| PropertyValue::String(at.to_rfc3339()))
+                .ok_or_else(|| AdapterError::Internal(format!("offset {}s is past the representable range", seconds))),
+            None => Ok(rfc3339_now()),
+        };
+
+        let transcript_key = self.transcript_id(transcript);
+        let transcript_id = NodeId::from_string(format!("transcript:{}", transcript_key));
+        let mut transcript_node =
+            Node::new_in_dimension("transcript", ContentType::Document, dimension::STRUCTURE);
+        transcript_node.id = transcript_id.clone();
+        let duration = transcript.segments.iter().map(|s| s.end).fold(0.0, f64::max);
+        transcript_node.properties.insert("duration_secs".to_string(), PropertyValue::Float(duration));
+        transcript_node
+            .properties
+            .insert("segment_count".to_string(), PropertyValue::Int(transcript.segments.len() as i64));
+        transcript_node.properties.insert("created_at".to_string(), at_offset(0.0)?);
+        if let Some(ref title) = transcript.title {
+            transcript_node.properties.insert("title".to_string(), PropertyValue::String(title.clone()));
+        }
+        if let Some(ref source) = transcript.source {
+            transcript_node.properties.insert("source".to_string(), PropertyValue::String(source.clone()));
+        }
+
+        let chain_id = format!("chain:{}:{}", self.adapter_id, transcript_key);
+        let mut chain = chain_node(&chain_id);
+        let chain_name = transcript
+            .title
+            .clone()
+            .unwrap_or_else(|| format!("{} — {}", self.adapter_id, transcript_key));
+        chain.properties.insert("name".to_string(), PropertyValue::String(chain_name));
+        chain.properties.insert("status".to_string(), PropertyValue::String("active".to_string()));
+
+        let mut emission = Emission::new().with_node(transcript_node).with_node(chain);
+        let mut previous: Option<NodeId> = None;
+        for (index, segment) in transcript.segments.iter().enumerate() {
+            let position = index + 1;
+            let segment_id = NodeId::from_string(format!("segment:{}:{}", transcript_key, position));
+            let mut segment_node = Node::new_in_dimension("segment", ContentType::Document, dimension::STRUCTURE);
+            segment_node.id = segment_id.clone();
+            let props = &mut segment_node.properties;
+            props.insert("text".to_string(), PropertyValue::String(segment.text.clone()));
+            props.insert("index".to_string(), PropertyValue::Int(position as i64));
+            props.insert("start_secs".to_string(), PropertyValue::Float(segment.start));
+            props.insert("end_secs".to_string(), PropertyValue::Float(segment.end));
+            props.insert("timecode".to_string(), PropertyValue::String(timecode(segment.start)));
+            props.insert("created_at".to_string(), at_offset(segment.start)?);
+            if let Some(ref speaker) = segment.speaker {
+                props.insert("speaker".to_string(), PropertyValue::String(speaker.clone()));
+            }
+
+            let contains =
+                Edge::new_in_dimension(transcript_id.clone(), segment_id.clone(), "contains", dimension::STRUCTURE);
+            emission = emission.with_node(segment_node).with_edge(contains);
+            if let Some(ref name) = segment.speaker {
+                let mut speaker = Node::new_in_dimension(
+                    "speaker",
+                    ContentType::Other("speaker".to_string()),
+                    dimension::RELATIONAL,
+                );
+                speaker.id = speaker_id(name);
+                speaker.properties.insert("name".to_string(), PropertyValue::String(name.clone()));
+                let spoken_by = Edge::new_cross_dimensional(
+                    segment_id.clone(),
+                    dimension::STRUCTURE,
+                    speaker.id.clone(),
+                    dimension::RELATIONAL,
+                    "spoken_by",
+                );
+                emission = emission.with_node(speaker).with_edge(spoken_by);
+            }
+            if let Some(before) = previous.replace(segment_id.clone()) {
+                emission = emission.with_edge(Edge::new_in_dimension(
+                    segment_id.clone(),
+                    before,
+                    "follows",
+                    dimension::STRUCTURE,
+                ));
+            }
+
+            let tags = match input.tag_policy {
+                Some(ref policy) => policy.apply(&segment.tags),
+                None => segment.tags.clone(),
+            };
+            for tag in &tags {
+                let (concept_id, node) = concept_node(tag);
+                let mut edge = Edge::new_cross_dimensional(
+                    segment_id.clone(),
+                    dimension::STRUCTURE,
+                    concept_id,
+                    dimension::SEMANTIC,
+                    "tagged_with",
+                );
+                edge.combined_weight = 1.0;
+                emission = emission.with_node(node).with_edge(edge);
+            }
+
+            // Provenance: the segment's place in the recording
+            let mark_id = format!("mark:{}:{}", self.adapter_id, segment_id);
+            let mut mark = mark_node(&mark_id);
+            mark.properties.insert("chain_id".to_string(), PropertyValue::String(chain_id.clone()));
+            let annotation = match segment.speaker {
+                Some(ref speaker) => format!("{}: {}", speaker, segment.text),
+                None => segment.text.clone(),
+            };
+            mark.properties.insert("annotation".to_string(), PropertyValue::String(annotation));
+            let file = transcript.source.clone().unwrap_or_else(|| transcript_id.to_string());
+            mark.properties.insert("file".to_string(), PropertyValue::String(file));
+            mark.properties.insert("timecode".to_string(), PropertyValue::String(timecode(segment.start)));
+            mark.properties.insert("start_secs".to_string(), PropertyValue::Float(segment.start));
+            mark.properties.insert("end_secs".to_string(), PropertyValue::Float(segment.end));
+            if !tags.is_empty() {
+                let tag_vals = tags.iter().map(|t| PropertyValue::String(t.to_lowercase())).collect();
+                mark.properties.insert("tags".to_string(), PropertyValue::Array(tag_vals));
+            }
+            let contains_mark = Edge::new_in_dimension(
+                NodeId::from_string(&chain_id),
+                mark.id.clone(),
+                "contains",
+                dimension::PROVENANCE,
+            );
+            emission = emission.with_node(mark).with_edge(contains_mark);
+        }
+
+        sink.emit(emission).await?;
+        Ok(())
+    }
+
+    fn transform_events(&self, events: &[GraphEvent], _context: &Context) -> Vec<OutboundEvent> {
+        let mut outbound = Vec::new();
+        for event in events {
+            if let GraphEvent::NodesAdded { node_ids, adapter_id, .. } = event {
+                if adapter_id != &self.adapter_id {
+                    continue;
+                }
+                for id in node_ids.iter().filter(|id| id.as_str().starts_with("transcript:")) {
+                    outbound.push(OutboundEvent::new("transcript_indexed", id.to_string()));
+                }
+                let speakers: Vec<&str> =
+                    node_ids.iter().filter_map(|id| id.as_str().strip_prefix("speaker:")).collect();
+                if !speakers.is_empty() {
+                    outbound.push(OutboundEvent::new("speakers_detected", speakers.join(", ")));
+                }
+            }
+        }
+        outbound
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::{EngineSink, FrameworkContext};
+    use std::sync::{Arc, Mutex};
+
+    fn make_sink(adapter_id: &str) -> (EngineSink, Arc<Mutex<Context>>) {
+        let ctx = Arc::new(Mutex::new(Context::new("test")));
+        let fw = FrameworkContext {
+            adapter_id: adapter_id.to_string(),
+            context_id: "test".to_string(),
+            input_summary: None,
+        };
+        let sink = EngineSink::new(ctx.clone()).with_framework_context(fw);
+        (sink, ctx)
+    }
+
+    // === Scenario: WebVTT and SRT cues parse with their speakers ===
+    #[test]
+    fn vtt_and_srt_parse_to_segments() {
+        let vtt = "WEBVTT\n\nNOTE recorded on site\n\nintro\n00:00.000 --> 00:04.250 align:start\n<v Ada>The north wall\nhas collapsed.</v>\n\n00:01:05.000 --> 00:01:09.000\nMore rubble here.\n";
+        let parsed = TranscriptInput::parse(vtt);
+        assert_eq!(
+            parsed.segments,
+            vec![
+                Segment::new(0.0, 4.25, "The north wall has collapsed.").with_speaker("Ada"),
+                Segment::new(65.0, 69.0, "More rubble here."),
+            ]
+        );
+
+        let srt = "1\r\n00:00:01,500 --> 00:00:03,000\r\nInterviewer: Where were you?\r\n\r\n2\r\n00:00:03,000 --> 00:00:06,000\r\nAt the dig: by the gate.\r\n";
+        let parsed = TranscriptInput::parse(srt);
+        assert_eq!(parsed.segments[0].speaker.as_deref(), Some("Interviewer"));
+        assert_eq!(parsed.segments[0].start, 1.5);
+        assert_eq!(parsed.segments[1].speaker, None, "only capitalised prefixes read as labels");
+        assert_eq!(parsed.segments[1].text, "At the dig: by the gate.");
+        assert_eq!(timecode(3725.5), "01:02:05.500");
+    }
+
+    // === Scenario: Out-of-range offsets fail instead of panicking ===
+    #[tokio::test]
+    async fn out_of_range_offsets_are_rejected() {
+        assert_eq!(parse_timecode("inf"), None);
+        assert_eq!(parse_timecode("00:NaN"), None);
+        assert_eq!(parse_timecode("-1:00"), None);
+
+        let (sink, ctx) = make_sink("transcript");
+        let adapter = TranscriptAdapter::new("transcript");
+        let far = serde_json::json!({"segments": [{"start": 1e15, "text": "x"}], "recorded_at": "2026-01-01T00:00:00Z"});
+        let input = AdapterInput::new("transcript", far, "test");
+        let err = adapter.process(&input, &sink).await.unwrap_err();
+        assert!(err.to_string().contains("representable range"), "{err}");
+
+        let negative = TranscriptInput::new(vec![Segment::new(-1.0, 2.0, "before the start")]);
+        let input = AdapterInput::new("transcript", negative, "test");
+        assert!(adapter.process(&input, &sink).await.is_err());
+        assert_eq!(ctx.lock().unwrap().node_count(), 0);
+    }
+
+    // === Scenario: Segments become timed nodes with timecode provenance ===
+    #[tokio::test]
+    async fn segments_become_nodes_with_timecode_marks() {
+        let json = serde_json::json!({
+            "id": "memo-1",
+            "source": "memos/memo-1.m4a",
+            "recorded_at": "2026-03-01T10:00:00Z",
+            "text": "whisper's full text is ignored",
+            "segments": [
+                {"start": 0.0, "end": 2.5, "text": "Check the lintel.", "speaker": "SPEAKER_00", "tags": ["Lintel"]},
+                {"start": 2.5, "end": 90.0, "text": "  "},
+                {"start": 90.0, "end": 95.0, "text": "Photographed it."}
+            ]
+        });
+        let adapter = TranscriptAdapter::new("transcript");
+        let (sink, ctx) = make_sink("transcript");
+        adapter.process(&AdapterInput::new("transcript", json, "test"), &sink).await.unwrap();
+
+        let ctx = ctx.lock().unwrap();
+        let segments: Vec<_> = ctx.nodes.values().filter(|n| n.node_type == "segment").collect();
+        assert_eq!(segments.len(), 2, "empty segments are skipped");
+        let second = ctx.get_node(&NodeId::from_string("segment:memo-1:2")).unwrap();
+        assert_eq!(second.properties.get("created_at"), Some(&PropertyValue::from("2026-03-01T10:01:30+00:00")));
+        assert!(ctx.edges.iter().any(|e| e.source.as_str() == "segment:memo-1:2"
+            && e.target.as_str() == "segment:memo-1:1"
+            && e.relationship == "follows"));
+        assert!(ctx.edges.iter().any(|e| e.source.as_str() == "segment:memo-1:1"
+            && e.target.as_str() == "speaker:speaker_00"));
+        assert!(ctx.get_node(&NodeId::from_string("concept:lintel")).is_some());
+
+        let mark = ctx
+            .nodes
+            .values()
+            .find(|n| n.node_type == "mark" && n.id.as_str().ends_with("segment:memo-1:2"))
+            .unwrap();
+        assert_eq!(mark.properties.get("timecode"), Some(&PropertyValue::from("00:01:30.000")));
+        assert_eq!(mark.properties.get("file"), Some(&PropertyValue::from("memos/memo-1.m4a")));
+        assert!(!mark.properties.contains_key("line"));
+    }
+}
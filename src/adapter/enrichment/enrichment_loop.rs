@@ -5,6 +5,7 @@
 //! `enrichment` (for `EnrichmentRegistry`).
 
 use crate::adapter::sink::{EngineSink, FrameworkContext, AdapterError, EmitResult};
+use crate::adapter::types::Emission;
 use super::traits::EnrichmentRegistry;
 use crate::graph::events::GraphEvent;
 use crate::graph::{ContextId, PlexusEngine};
@@ -18,6 +19,9 @@ pub(crate) struct EnrichmentLoopResult {
     pub quiesced: bool,
 }
 
+/// An enrichment's emission for the round: (enrichment ID, confidence, emission).
+type RoundEmission = (String, Option<f32>, Emission);
+
 /// Run the enrichment loop after a primary emission (ADR-010).
 ///
 /// Per-round events: each round sees only events from the previous round.
@@ -25,6 +29,12 @@ pub(crate) struct EnrichmentLoopResult {
 /// The loop terminates when all enrichments return None (quiescence)
 /// or the safety valve (max rounds) is reached.
 ///
+/// A round's emissions commit together, in units of work of at most
+/// `EnrichmentRegistry::max_unit_of_work` items: one context write and
+/// save per unit, so a bulk import's many small emissions don't each
+/// cost a save. Each unit is all or nothing; units committed before a
+/// failing one keep their events and records.
+///
 /// Returns an EnrichmentLoopResult with the accumulated EmitResult
 /// plus convergence telemetry (rounds, quiesced).
 pub(crate) fn run_enrichment_loop(
@@ -44,10 +54,13 @@ pub(crate) fn run_enrichment_loop(
             .ok_or_else(|| AdapterError::ContextNotFound(context_id.to_string()))?;
 
         // Run all enrichments with the same snapshot
-        let mut round_emissions: Vec<(String, crate::adapter::types::Emission)> = Vec::new();
+        let mut round_emissions: Vec<RoundEmission> = Vec::new();
         for enrichment in registry.enrichments() {
+            if !snapshot.metadata.runs_enrichment(enrichment.id()) {
+                continue;
+            }
             if let Some(emission) = enrichment.enrich(&round_events, &snapshot) {
-                round_emissions.push((enrichment.id().to_string(), emission));
+                round_emissions.push((enrichment.id().to_string(), enrichment.confidence(), emission));
             }
         }
 
@@ -57,32 +70,52 @@ pub(crate) fn run_enrichment_loop(
             break;
         }
 
-        // Commit each enrichment's emission through the same path
+        // Commit each enrichment's emission through the same path, a
+        // unit of work per context write; a unit that fails to commit
+        // leaves nothing behind
         let mut new_events: Vec<GraphEvent> = Vec::new();
-        for (enrichment_id, emission) in round_emissions {
-            let enrichment_framework = Some(FrameworkContext {
-                adapter_id: enrichment_id,
-                context_id: context_id.to_string(),
-                input_summary: None,
-            });
-
-            let enrichment_result = engine.with_context_mut(context_id, |ctx| {
-                EngineSink::emit_inner(ctx, emission, &enrichment_framework)
+        for unit in units_of_work(round_emissions, registry.max_unit_of_work()) {
+            let committed = engine.with_context_atomic(context_id, |ctx, indexes| {
+                let mut committed = Vec::new();
+                for (enrichment_id, confidence, emission) in unit {
+                    let enrichment_framework = Some(FrameworkContext {
+                        adapter_id: enrichment_id.clone(),
+                        context_id: context_id.to_string(),
+                        input_summary: None,
+                    });
+                    // A vetoed enrichment emission is dropped; the round goes on
+                    let mut emission = emission;
+                    if let Err(e) = engine.pre_commit(ctx, &mut emission) {
+                        tracing::warn!(error = %e, "enrichment emission vetoed");
+                        continue;
+                    }
+                    if let Some(confidence) = confidence {
+                        if ctx.contribution_confidence(&enrichment_id) != confidence {
+                            ctx.set_contribution_confidence(enrichment_id.clone(), confidence);
+                        }
+                    }
+                    let result = EngineSink::emit_indexed(ctx, indexes, emission, &enrichment_framework)?;
+                    for event in &result.events {
+                        if let GraphEvent::EdgesAdded { edge_ids, adapter_id, .. } = event {
+                            ctx.mark_derived(adapter_id, edge_ids);
+                        }
+                    }
+                    committed.push((enrichment_framework, result));
+                }
+                Ok::<_, AdapterError>(committed)
             }).map_err(EngineSink::map_engine_error)??;
 
-            // Persist enrichment events to event log (ADR-035)
-            engine.persist_events(&enrichment_result.events);
+            for (enrichment_framework, enrichment_result) in committed {
+                // Record what the enrichment added, then persist its events
+                // to the event log (ADR-035)
+                EngineSink::record_emission(engine, context_id, &enrichment_framework, &enrichment_result);
+                engine.persist_events(&enrichment_result.events);
 
-            new_events.extend(enrichment_result.events.clone());
+                new_events.extend(enrichment_result.events.clone());
 
-            // Accumulate enrichment results
-            accumulated.nodes_committed += enrichment_result.nodes_committed;
-            accumulated.edges_committed += enrichment_result.edges_committed;
-            accumulated.removals_committed += enrichment_result.removals_committed;
-            accumulated.edge_removals_committed += enrichment_result.edge_removals_committed;
-            accumulated.rejections.extend(enrichment_result.rejections);
-            accumulated.provenance.extend(enrichment_result.provenance);
-            accumulated.events.extend(enrichment_result.events);
+                // Accumulate enrichment results
+                accumulated.absorb(enrichment_result);
+            }
         }
 
         round_events = new_events;
@@ -108,6 +141,30 @@ pub(crate) fn run_enrichment_loop(
     })
 }
 
+/// Split a round's emissions, in order, into units of at most `limit`
+/// items. An emission larger than `limit` is a unit of its own.
+fn units_of_work(emissions: Vec<RoundEmission>, limit: Option<usize>) -> Vec<Vec<RoundEmission>> {
+    let Some(limit) = limit else {
+        return vec![emissions];
+    };
+    let mut units: Vec<Vec<RoundEmission>> = Vec::new();
+    let mut size = 0;
+    for emission in emissions {
+        let items = emission.2.len();
+        match units.last_mut() {
+            Some(unit) if size + items <= limit => {
+                size += items;
+                unit.push(emission);
+            }
+            _ => {
+                size = items;
+                units.push(vec![emission]);
+            }
+        }
+    }
+    units
+}
+
 #[cfg(test)]
 mod tests {
     //! Enrichment loop scenarios (ADR-010), relocated from
@@ -395,6 +452,31 @@ mod tests {
         assert_eq!(edge.contributions.get("co-occurrence"), Some(&0.75), "enrichment contribution value is 0.75");
     }
 
+    // === Scenario: Pre-commit hooks see enrichment emissions ===
+    #[tokio::test]
+    async fn vetoed_enrichment_emission_is_dropped() {
+        let engine = Arc::new(PlexusEngine::new());
+        let ctx_id = ContextId::from("provence-research");
+        let mut ctx = Context::with_id(ctx_id.clone(), "provence-research");
+        ctx.add_node(node("concept:travel"));
+        ctx.add_node(node("concept:avignon"));
+        engine.upsert_context(ctx).unwrap();
+        engine.on_pre_commit("no-guesses", |_, emission| match emission.edges.iter().any(|e| e.edge.relationship == "may_be_related") {
+            true => Err("no speculative edges".into()),
+            false => Ok(()),
+        });
+
+        let registry = Arc::new(EnrichmentRegistry::new(vec![
+            Arc::new(OneShotEdgeEnrichment::new("co-occurrence", "concept:travel", "concept:avignon")) as Arc<dyn Enrichment>,
+        ]));
+        let sink = EngineSink::for_engine(engine.clone(), ctx_id.clone());
+        let primary = sink.emit(Emission::new().with_node(node("trigger-node"))).await.unwrap();
+
+        let enrichment_result = run_enrichment_loop(&engine, &ctx_id, &registry, &primary.events).unwrap();
+        assert_eq!(enrichment_result.result.edges_committed, 0);
+        assert!(engine.get_context(&ctx_id).unwrap().edges.is_empty());
+    }
+
     // === Scenario: Enrichment returning None means quiescent ===
     #[tokio::test]
     async fn enrichment_returning_none_means_quiescent() {
@@ -680,4 +762,195 @@ mod tests {
         assert_eq!(enrichment_a.call_count(), 1, "first enrichment instance called once");
         assert_eq!(enrichment_b.call_count(), 0, "duplicate enrichment id skipped");
     }
+
+    // === Scenario: The enrichment report follows derived edges to their fate ===
+    #[tokio::test]
+    async fn enrichment_report_tracks_confirmed_and_removed_edges() {
+        let engine = Arc::new(PlexusEngine::new());
+        let ctx_id = ContextId::from("effectiveness");
+        let mut ctx = Context::with_id(ctx_id.clone(), "effectiveness");
+        for id in ["A", "B", "C"] {
+            ctx.add_node(node(id));
+        }
+        engine.upsert_context(ctx).unwrap();
+
+        let lens = crate::adapter::adapters::declarative::LensSpec {
+            consumer: "trellis".into(),
+            translations: vec![crate::adapter::adapters::declarative::TranslationRule {
+                from: vec!["may_be_related".into()],
+                to: "thematic_connection".into(),
+                min_weight: None,
+                min_corroboration: None,
+                involving: None,
+            }],
+        };
+        let registry = EnrichmentRegistry::new(vec![
+            Arc::new(OneShotEdgeEnrichment::new("guesser", "A", "B")) as Arc<dyn Enrichment>,
+            Arc::new(OneShotEdgeEnrichment::new("hunch", "B", "C")) as Arc<dyn Enrichment>,
+            Arc::new(crate::adapter::LensEnrichment::new(lens)) as Arc<dyn Enrichment>,
+        ]);
+        let sink = EngineSink::for_engine(engine.clone(), ctx_id.clone()).with_framework_context(FrameworkContext {
+            adapter_id: "reader".to_string(),
+            context_id: "effectiveness".to_string(),
+            input_summary: None,
+        });
+        let primary = sink.emit(Emission::new().with_node(node("trigger"))).await.unwrap();
+        run_enrichment_loop(&engine, &ctx_id, &registry, &primary.events).unwrap();
+
+        // A reader later asserts A→B on its own evidence; hunch is retracted
+        sink.emit(Emission::new().with_edge(Edge::new_in_dimension(
+            NodeId::from_string("A"),
+            NodeId::from_string("B"),
+            "may_be_related",
+            "semantic",
+        )))
+        .await
+        .unwrap();
+        engine.retract_contributions(&ctx_id, "hunch").unwrap();
+
+        let report = engine.enrichment_report(&ctx_id).unwrap();
+        let stats = |id: &str| report.iter().find(|s| s.enrichment_id == id).cloned().unwrap();
+        let guesser = stats("guesser");
+        assert_eq!((guesser.derived, guesser.live, guesser.confirmed), (1, 1, 1));
+        assert_eq!(guesser.confirmation_rate(), 1.0);
+        let hunch = stats("hunch");
+        assert_eq!((hunch.derived, hunch.live, hunch.retracted), (1, 0, 1));
+        assert_eq!(hunch.removal_rate(), 1.0);
+        assert!(report.iter().all(|s| s.enrichment_id != "reader"), "primary adapters derive nothing");
+        let trellis = stats("lens:trellis");
+        assert_eq!((trellis.derived, trellis.live, trellis.confirmed), (2, 2, 0), "lens translations are tracked");
+
+        // Derivations live in metadata; the edges themselves are untouched
+        let ctx = engine.get_context(&ctx_id).unwrap();
+        assert!(ctx.edges.iter().all(|e| !e.properties.contains_key("derived_by")));
+        assert_eq!(ctx.derived_by(&ctx.edges.iter().find(|e| e.source.as_str() == "A").unwrap().id), Some("guesser"));
+
+        // Hiding a translation through an overlay tallies it too
+        let translated = ctx.edges.iter().find(|e| e.relationship.starts_with("lens:") && e.source.as_str() == "B").unwrap();
+        engine.stage_overlay(&ctx_id, "tidy", crate::graph::Overlay::new().hiding_edge(translated.id.clone())).unwrap();
+        engine.commit_overlay(&ctx_id, "tidy").unwrap();
+        let report = engine.enrichment_report(&ctx_id).unwrap();
+        let trellis = report.iter().find(|s| s.enrichment_id == "lens:trellis").unwrap();
+        assert_eq!((trellis.derived, trellis.live, trellis.pruned), (2, 1, 1));
+    }
+
+    /// A SQLite store counting context saves, and failing those of a
+    /// context holding `fail_on`.
+    struct CountingStore {
+        inner: crate::storage::SqliteStore,
+        saves: std::sync::atomic::AtomicUsize,
+        fail_on: Option<NodeId>,
+    }
+
+    impl CountingStore {
+        fn new(fail_on: Option<&str>) -> Self {
+            use crate::storage::OpenStore;
+            Self {
+                inner: crate::storage::SqliteStore::open_in_memory().unwrap(),
+                saves: Default::default(),
+                fail_on: fail_on.map(NodeId::from_string),
+            }
+        }
+    }
+
+    impl crate::storage::GraphStore for CountingStore {
+        fn save_context(&self, context: &Context) -> crate::storage::StorageResult<()> {
+            self.saves.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if self.fail_on.as_ref().is_some_and(|id| context.get_node(id).is_some()) {
+                return Err(crate::storage::StorageError::Internal("disk full".into()));
+            }
+            self.inner.save_context(context)
+        }
+        fn persist_emission(&self, emission: &crate::storage::PersistedEmission) -> crate::storage::StorageResult<()> {
+            self.inner.persist_emission(emission)
+        }
+        fn query_emissions(
+            &self,
+            context_id: &str,
+            filter: &crate::storage::EmissionFilter,
+        ) -> crate::storage::StorageResult<Vec<crate::storage::PersistedEmission>> {
+            self.inner.query_emissions(context_id, filter)
+        }
+        fn save_context_metadata(&self, context: &Context) -> crate::storage::StorageResult<()> {
+            self.inner.save_context_metadata(context)
+        }
+        fn load_context(&self, id: &ContextId) -> crate::storage::StorageResult<Option<Context>> {
+            self.inner.load_context(id)
+        }
+        fn delete_context(&self, id: &ContextId) -> crate::storage::StorageResult<bool> {
+            self.inner.delete_context(id)
+        }
+        fn list_contexts(&self) -> crate::storage::StorageResult<Vec<ContextId>> {
+            self.inner.list_contexts()
+        }
+    }
+
+    // === Scenario: A round commits in units of work, one save each ===
+    #[test]
+    fn round_commits_in_bounded_units_of_work() {
+        let saves_for = |registry: EnrichmentRegistry| {
+            let store = Arc::new(CountingStore::new(None));
+            let engine = PlexusEngine::with_store(store.clone());
+            let ctx_id = engine.upsert_context(Context::new("marks")).unwrap();
+            let trigger = vec![GraphEvent::NodesAdded {
+                node_ids: vec![NodeId::from_string("mark")],
+                adapter_id: "import".into(),
+                context_id: ctx_id.to_string(),
+            }];
+            let before = store.saves.load(std::sync::atomic::Ordering::SeqCst);
+            let outcome = run_enrichment_loop(&engine, &ctx_id, &registry, &trigger).unwrap();
+            assert_eq!(outcome.result.nodes_committed, 3);
+            assert_eq!(engine.get_context(&ctx_id).unwrap().node_count(), 3);
+            store.saves.load(std::sync::atomic::Ordering::SeqCst) - before
+        };
+        let bridgers = || {
+            EnrichmentRegistry::new(
+                ["tag:a", "tag:b", "tag:c"]
+                    .into_iter()
+                    .map(|id| Arc::new(RoundZeroNodeEnrichment::new(id, id)) as Arc<dyn Enrichment>)
+                    .collect(),
+            )
+        };
+
+        assert_eq!(saves_for(bridgers()), 1, "by default a round is one unit");
+        assert_eq!(saves_for(bridgers().with_max_unit_of_work(2)), 2);
+        assert_eq!(saves_for(bridgers().with_max_unit_of_work(1)), 3);
+        assert_eq!(units_of_work(vec![("big".into(), None, Emission::new().with_node(node("a")).with_node(node("b")))], Some(1)).len(), 1,
+            "an emission past the limit is a unit of its own");
+    }
+
+    // === Scenario: A unit that fails to commit leaves nothing behind ===
+    #[test]
+    fn failed_unit_of_work_rolls_back_whole() {
+        let run = |registry: EnrichmentRegistry| {
+            // The unit holding tag:b can't be saved
+            let engine = PlexusEngine::with_store(Arc::new(CountingStore::new(Some("tag:b"))));
+            let ctx_id = engine.upsert_context(Context::new("marks")).unwrap();
+            let trigger = vec![GraphEvent::NodesAdded {
+                node_ids: vec![NodeId::from_string("mark")],
+                adapter_id: "import".into(),
+                context_id: ctx_id.to_string(),
+            }];
+            assert!(run_enrichment_loop(&engine, &ctx_id, &registry, &trigger).is_err());
+            let ctx = engine.get_context(&ctx_id).unwrap();
+            let recorded = engine.emissions(&ctx_id, &crate::storage::EmissionFilter::new()).unwrap();
+            let mut nodes: Vec<String> = ctx.nodes.keys().map(|id| id.to_string()).collect();
+            nodes.sort();
+            let adapters: Vec<String> = recorded.into_iter().map(|r| r.adapter_id).collect();
+            (nodes, adapters)
+        };
+        let bridgers = || {
+            EnrichmentRegistry::new(
+                ["tag:a", "tag:b"]
+                    .into_iter()
+                    .map(|id| Arc::new(RoundZeroNodeEnrichment::new(id, id)) as Arc<dyn Enrichment>)
+                    .collect(),
+            )
+        };
+
+        // One unit: the first emission is rolled back with the second
+        assert_eq!(run(bridgers()), (vec![], vec![]));
+        // A unit each: the first stays committed, and recorded
+        assert_eq!(run(bridgers().with_max_unit_of_work(1)), (vec!["tag:a".to_string()], vec!["tag:a".to_string()]));
+    }
 }
@@ -6,8 +6,8 @@
 //! consumer's domain and the graph.
 
 use crate::graph::events::GraphEvent;
-use crate::adapter::types::Emission;
-use crate::graph::Context;
+use crate::adapter::types::{Emission, OutboundEvent};
+use crate::graph::{Context, ContextId, NodeId};
 use std::collections::HashSet;
 use std::sync::Arc;
 
@@ -32,6 +32,31 @@ pub trait Enrichment: Send + Sync {
     ///
     /// Returns `Some(Emission)` to produce mutations, `None` if quiescent.
     fn enrich(&self, events: &[GraphEvent], context: &Context) -> Option<Emission>;
+
+    /// Confidence applied to this enrichment's contributions after
+    /// normalization (`ContextMetadata::contribution_confidence`).
+    /// Default: `None`, counting fully.
+    fn confidence(&self) -> Option<f32> {
+        None
+    }
+
+    /// Drop any state kept for a deleted context. Default: nothing.
+    fn forget_context(&self, context_id: &ContextId) {
+        let _ = context_id;
+    }
+
+    /// Follow nodes whose IDs changed in a relocation, as (old, new).
+    /// Default: nothing.
+    fn rename_nodes(&self, context_id: &ContextId, renamed: &[(NodeId, NodeId)]) {
+        let _ = (context_id, renamed);
+    }
+
+    /// Translate an ingest's events (primary and enrichment) into outbound
+    /// events for consumers, like `Adapter::transform_events`. Default: none.
+    fn transform_events(&self, events: &[GraphEvent], context: &Context) -> Vec<OutboundEvent> {
+        let _ = (events, context);
+        Vec::new()
+    }
 }
 
 /// Registry of enrichments for the enrichment loop.
@@ -41,6 +66,7 @@ pub trait Enrichment: Send + Sync {
 pub struct EnrichmentRegistry {
     enrichments: Vec<Arc<dyn Enrichment>>,
     max_rounds: usize,
+    max_unit_of_work: Option<usize>,
 }
 
 /// Default maximum enrichment loop rounds (safety valve).
@@ -58,6 +84,7 @@ impl EnrichmentRegistry {
         Self {
             enrichments: deduped,
             max_rounds: DEFAULT_MAX_ROUNDS,
+            max_unit_of_work: None,
         }
     }
 
@@ -66,6 +93,7 @@ impl EnrichmentRegistry {
         Self {
             enrichments: Vec::new(),
             max_rounds: DEFAULT_MAX_ROUNDS,
+            max_unit_of_work: None,
         }
     }
 
@@ -75,6 +103,14 @@ impl EnrichmentRegistry {
         self
     }
 
+    /// Commit each round's emissions in units of at most `items` items
+    /// (see `Emission::len`), one context write and save per unit. By
+    /// default a round commits as one unit.
+    pub fn with_max_unit_of_work(mut self, items: usize) -> Self {
+        self.max_unit_of_work = Some(items.max(1));
+        self
+    }
+
     /// Access the registered enrichments.
     pub fn enrichments(&self) -> &[Arc<dyn Enrichment>] {
         &self.enrichments
@@ -84,6 +120,11 @@ impl EnrichmentRegistry {
     pub fn max_rounds(&self) -> usize {
         self.max_rounds
     }
+
+    /// Items per unit of work a round commits in, if bounded.
+    pub fn max_unit_of_work(&self) -> Option<usize> {
+        self.max_unit_of_work
+    }
 }
 
 #[cfg(test)]
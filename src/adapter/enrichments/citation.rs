@@ -0,0 +1,477 @@
+//! CitationEnrichment — `cites` edges from citations in text
+//!
+//! Recognizes three citation forms in fragment text and mark annotations:
+//! DOIs (`10.1145/3290605`, `doi:...`, `https://doi.org/...`), arXiv IDs
+//! (`arXiv:2401.01234v2`, `arxiv.org/abs/...`), and author-year references
+//! (`Chen 2025`, `Chen et al. (2025)`, `(Chen, 2025)`, `Chen and Li 2025`).
+//!
+//! Each citation resolves to a paper already in the context — a node with
+//! a matching `doi` or `arxiv_id` property, or a paper node whose `year`
+//! and first author's surname (`authors`/`author`) match — and the citing
+//! node `cites` it. An unresolved citation gets a placeholder paper node
+//! (`placeholder: true`) that later citations of the same work resolve
+//! to. An author-year reference matching several papers is ambiguous and
+//! left alone rather than guessed, as is one that could be a paper missing
+//! its `year` or authors — a placeholder would duplicate it.
+//!
+//! Fires only for nodes named in `NodesAdded` events. Idempotent: existing
+//! edges are not re-emitted, so the enrichment loop reaches quiescence.
+
+use crate::adapter::enrichment::Enrichment;
+use crate::adapter::types::{AnnotatedEdge, Emission};
+use crate::graph::events::GraphEvent;
+use crate::graph::{dimension, ContentType, Context, Edge, Node, NodeId, PropertyValue};
+use std::collections::HashSet;
+
+/// Relationship from a citing node to the paper it cites.
+pub const CITES: &str = "cites";
+
+/// Capitalised words that precede a year without being an author.
+const NOT_AUTHORS: &[&str] = &[
+    "In", "Since", "By", "Until", "From", "Before", "After", "During", "Of", "The", "Spring",
+    "Summer", "Fall", "Autumn", "Winter", "Q1", "Q2", "Q3", "Q4", "January", "February",
+    "March", "April", "May", "June", "July", "August", "September", "October", "November",
+    "December", "Jan", "Feb", "Mar", "Apr", "Jun", "Jul", "Aug", "Sep", "Sept", "Oct", "Nov",
+    "Dec", "Version", "Release", "Windows", "Office",
+];
+
+/// A citation found in text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Citation {
+    /// Lowercased, without a `doi:` or resolver prefix
+    Doi(String),
+    /// Without a version suffix
+    Arxiv(String),
+    AuthorYear { author: String, year: i64 },
+}
+
+impl Citation {
+    /// The placeholder paper node ID for this citation.
+    pub fn placeholder_id(&self) -> NodeId {
+        NodeId::from_string(match self {
+            Citation::Doi(doi) => format!("paper:doi:{}", doi),
+            Citation::Arxiv(id) => format!("paper:arxiv:{}", id),
+            Citation::AuthorYear { author, year } => format!("paper:{}-{}", author.to_lowercase(), year),
+        })
+    }
+
+    fn label(&self) -> String {
+        match self {
+            Citation::Doi(doi) => format!("doi:{}", doi),
+            Citation::Arxiv(id) => format!("arXiv:{}", id),
+            Citation::AuthorYear { author, year } => format!("{} {}", author, year),
+        }
+    }
+}
+
+fn trim_trailing(token: &str) -> &str {
+    token.trim_end_matches(|c: char| ".,;:)]}>\"'".contains(c))
+}
+
+/// Find the citations in `text`: DOIs, then arXiv IDs, then author-year
+/// references, each deduplicated.
+pub fn find_citations(text: &str) -> Vec<Citation> {
+    let mut found: Vec<Citation> = Vec::new();
+    let mut push = |citation: Citation| {
+        if !found.contains(&citation) {
+            found.push(citation);
+        }
+    };
+    let lower = text.to_lowercase();
+
+    // DOIs: `10.` + registrant digits + `/` + suffix, not inside a number
+    for (at, _) in lower.match_indices("10.") {
+        if lower[..at].chars().next_back().is_some_and(|c| c.is_ascii_alphanumeric() || c == '.') {
+            continue;
+        }
+        let token = lower[at..].split(|c: char| c.is_whitespace() || c == '<').next().unwrap_or("");
+        let Some((prefix, suffix)) = token.split_once('/') else { continue };
+        let registrant = &prefix[3..];
+        let suffix = trim_trailing(suffix);
+        if (4..=9).contains(&registrant.len()) && registrant.chars().all(|c| c.is_ascii_digit() || c == '.') && !suffix.is_empty() {
+            push(Citation::Doi(format!("{}/{}", prefix, suffix)));
+        }
+    }
+
+    // arXiv IDs: only behind an explicit `arxiv:` or arxiv.org prefix
+    for marker in ["arxiv:", "arxiv.org/abs/", "arxiv.org/pdf/"] {
+        for (at, _) in lower.match_indices(marker) {
+            let rest = lower[at + marker.len()..].trim_start();
+            let token = trim_trailing(rest.split(|c: char| c.is_whitespace() || c == ',' || c == ';').next().unwrap_or(""));
+            let token = token.strip_suffix(".pdf").unwrap_or(token);
+            let id = match token.rfind('v') {
+                Some(v) if v > 0 && token[v + 1..].chars().all(|c| c.is_ascii_digit()) && v + 1 < token.len() => &token[..v],
+                _ => token,
+            };
+            let new_style = id.split_once('.').is_some_and(|(a, b)| {
+                a.len() == 4 && (4..=5).contains(&b.len()) && a.chars().chain(b.chars()).all(|c| c.is_ascii_digit())
+            });
+            let old_style = id.split_once('/').is_some_and(|(archive, number)| {
+                !archive.is_empty() && number.len() == 7 && number.chars().all(|c| c.is_ascii_digit())
+            });
+            if new_style || old_style {
+                push(Citation::Arxiv(id.to_string()));
+            }
+        }
+    }
+
+    // Author-year: a year, preceded by a capitalised surname, optionally
+    // with `et al.` or `and`/`&` a second author between them
+    let words: Vec<&str> = text.split_whitespace().collect();
+    for (i, word) in words.iter().enumerate() {
+        let core = trim_trailing(word.trim_start_matches(['(', '[']));
+        let digits = core.trim_end_matches(|c: char| c.is_ascii_lowercase());
+        let year = match digits.parse::<i64>() {
+            Ok(y) if digits.len() == 4 && (1900..=2099).contains(&y) && core.len() <= 5 => y,
+            _ => continue,
+        };
+        let mut back = i;
+        let prev = |back: &mut usize| -> Option<&str> {
+            *back = back.checked_sub(1)?;
+            Some(words[*back].trim_start_matches(['(', '[']))
+        };
+        let Some(mut candidate) = prev(&mut back) else { continue };
+        if candidate == "al." || candidate == "al.," {
+            if prev(&mut back) != Some("et") {
+                continue;
+            }
+            let Some(c) = prev(&mut back) else { continue };
+            candidate = c;
+        } else if matches!(prev(&mut back.clone()), Some("and" | "&")) {
+            // The first of two authors — when it reads as a surname
+            if let Some(first) = back.checked_sub(2).map(|b| words[b].trim_start_matches(['(', '['])) {
+                if surname(first).is_some() {
+                    candidate = first;
+                }
+            }
+        }
+        if let Some(author) = surname(candidate) {
+            push(Citation::AuthorYear { author: author.to_string(), year });
+        }
+    }
+    found
+}
+
+/// A word as a cited surname: capitalised, alphabetic, not a month or
+/// other common word before a year; any possessive or comma dropped.
+fn surname(word: &str) -> Option<&str> {
+    let author = word.trim_end_matches([',', '\'', '’']).trim_end_matches("’s").trim_end_matches("'s");
+    let capitalised = author.chars().next().is_some_and(|c| c.is_uppercase())
+        && author.chars().count() >= 2
+        && author.chars().all(|c| c.is_alphabetic() || c == '-' || c == '\'');
+    (capitalised && !NOT_AUTHORS.contains(&author)).then_some(author)
+}
+
+fn string_prop<'a>(node: &'a Node, key: &str) -> Option<&'a str> {
+    match node.properties.get(key) {
+        Some(PropertyValue::String(s)) => Some(s.as_str()),
+        _ => None,
+    }
+}
+
+/// The first author's surname: the part before a comma (`Chen, W.`),
+/// else the last word (`Wei Chen`).
+fn first_surname(node: &Node) -> Option<String> {
+    let first = match node.properties.get("authors").or_else(|| node.properties.get("author"))? {
+        PropertyValue::Array(authors) => match authors.first()? {
+            PropertyValue::String(s) => s.clone(),
+            _ => return None,
+        },
+        PropertyValue::String(s) => s.split([';', '&']).next()?.split(" and ").next()?.to_string(),
+        _ => return None,
+    };
+    let surname = match first.split_once(',') {
+        Some((surname, _)) => surname.trim(),
+        None => first.split_whitespace().last()?,
+    };
+    Some(surname.to_lowercase())
+}
+
+fn node_year(node: &Node) -> Option<i64> {
+    match node.properties.get("year")? {
+        PropertyValue::Int(y) => Some(*y),
+        PropertyValue::String(s) => s.trim().get(..4)?.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Enrichment that links citing nodes to the papers they cite.
+///
+/// Defaults: fragments' `text` and marks' `annotation`; author-year
+/// references match nodes of type `paper`.
+pub struct CitationEnrichment {
+    node_types: Vec<String>,
+    text_properties: Vec<String>,
+    paper_types: Vec<String>,
+    placeholders: bool,
+    id: String,
+}
+
+impl Default for CitationEnrichment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CitationEnrichment {
+    pub fn new() -> Self {
+        Self {
+            node_types: vec!["fragment".to_string(), "mark".to_string()],
+            text_properties: vec!["text".to_string(), "annotation".to_string()],
+            paper_types: vec!["paper".to_string()],
+            placeholders: true,
+            id: format!("citation:{}", CITES),
+        }
+    }
+
+    /// Scan nodes of these types for citations.
+    pub fn with_node_types(mut self, node_types: Vec<String>) -> Self {
+        self.node_types = node_types;
+        self
+    }
+
+    /// Node types author-year references resolve against.
+    pub fn with_paper_types(mut self, paper_types: Vec<String>) -> Self {
+        self.paper_types = paper_types;
+        self
+    }
+
+    /// Leave unresolved citations alone instead of creating placeholders.
+    pub fn without_placeholders(mut self) -> Self {
+        self.placeholders = false;
+        self
+    }
+
+    /// The papers a citation resolves to in the context.
+    pub fn resolve(&self, citation: &Citation, context: &Context) -> Vec<NodeId> {
+        let matches = |node: &Node| match citation {
+            Citation::Doi(doi) => string_prop(node, "doi").is_some_and(|d| {
+                let d = d.trim().to_lowercase();
+                let d = d.strip_prefix("https://doi.org/").or_else(|| d.strip_prefix("doi:")).unwrap_or(&d);
+                d == doi
+            }),
+            Citation::Arxiv(id) => ["arxiv_id", "arxiv"].iter().any(|key| {
+                string_prop(node, key).is_some_and(|a| {
+                    let a = a.trim().to_lowercase();
+                    a == *id || a.strip_prefix(id.as_str()).is_some_and(|v| v.starts_with('v'))
+                })
+            }),
+            Citation::AuthorYear { author, year } => {
+                self.paper_types.iter().any(|t| t == &node.node_type)
+                    && node_year(node) == Some(*year)
+                    && first_surname(node).is_some_and(|s| s == author.to_lowercase())
+            }
+        };
+        let placeholder = citation.placeholder_id();
+        let mut found: Vec<NodeId> = context.nodes().filter(|n| matches(n)).map(|n| n.id.clone()).collect();
+        if found.is_empty() && context.get_node(&placeholder).is_some() {
+            found.push(placeholder);
+        }
+        found
+    }
+
+    /// Whether an author-year citation could be a paper that lacks the
+    /// year or authors needed to resolve it, but agrees on the other.
+    fn may_cite_incomplete(&self, citation: &Citation, context: &Context) -> bool {
+        let Citation::AuthorYear { author, year } = citation else { return false };
+        let author = author.to_lowercase();
+        context.nodes().filter(|n| self.paper_types.iter().any(|t| t == &n.node_type)).any(|paper| {
+            match (node_year(paper), first_surname(paper)) {
+                (None, Some(surname)) => surname == author,
+                (Some(y), None) => y == *year,
+                _ => false,
+            }
+        })
+    }
+
+    fn placeholder(&self, citation: &Citation) -> Node {
+        let mut node = Node::new_in_dimension("paper", ContentType::Document, dimension::STRUCTURE);
+        node.id = citation.placeholder_id();
+        let props = &mut node.properties;
+        props.insert("label".to_string(), PropertyValue::String(citation.label()));
+        props.insert("placeholder".to_string(), PropertyValue::Bool(true));
+        match citation {
+            Citation::Doi(doi) => {
+                props.insert("doi".to_string(), PropertyValue::String(doi.clone()));
+            }
+            Citation::Arxiv(id) => {
+                props.insert("arxiv_id".to_string(), PropertyValue::String(id.clone()));
+            }
+            Citation::AuthorYear { author, year } => {
+                props.insert("authors".to_string(), PropertyValue::Array(vec![PropertyValue::String(author.clone())]));
+                props.insert("year".to_string(), PropertyValue::Int(*year));
+            }
+        }
+        node
+    }
+}
+
+impl Enrichment for CitationEnrichment {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn enrich(&self, events: &[GraphEvent], context: &Context) -> Option<Emission> {
+        let added: HashSet<&NodeId> = events
+            .iter()
+            .filter_map(|e| match e {
+                GraphEvent::NodesAdded { node_ids, .. } => Some(node_ids),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        if added.is_empty() {
+            return None;
+        }
+
+        let mut emission = Emission::new();
+        let mut proposed: HashSet<NodeId> = HashSet::new();
+        for id in added {
+            let Some(node) = context.get_node(id) else { continue };
+            if !self.node_types.iter().any(|t| t == &node.node_type) {
+                continue;
+            }
+            let Some(text) = self.text_properties.iter().find_map(|key| string_prop(node, key)) else {
+                continue;
+            };
+
+            for citation in find_citations(text) {
+                let mut papers = self.resolve(&citation, context);
+                let placeholder = citation.placeholder_id();
+                if papers.is_empty() && proposed.contains(&placeholder) {
+                    papers.push(placeholder);
+                } else if papers.is_empty() && self.may_cite_incomplete(&citation, context) {
+                    continue;
+                } else if papers.is_empty() && self.placeholders {
+                    proposed.insert(placeholder.clone());
+                    emission = emission.with_node(self.placeholder(&citation));
+                    papers.push(placeholder);
+                }
+                // Several author-year matches: ambiguous, don't guess
+                if papers.len() != 1 || papers[0] == node.id {
+                    continue;
+                }
+                let paper = &papers[0];
+                let exists =
+                    context.edges().any(|e| e.source == node.id && e.target == *paper && e.relationship == CITES);
+                if exists {
+                    continue;
+                }
+                let paper_dimension =
+                    context.get_node(paper).map(|p| p.dimension.clone()).unwrap_or_else(|| dimension::STRUCTURE.to_string());
+                let edge = Edge::new_cross_dimensional(
+                    node.id.clone(),
+                    node.dimension.clone(),
+                    paper.clone(),
+                    paper_dimension,
+                    CITES,
+                );
+                emission = emission.with_edge(AnnotatedEdge::new(edge));
+            }
+        }
+
+        if emission.is_empty() {
+            None
+        } else {
+            Some(emission)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: &str, node_type: &str, properties: Vec<(&str, PropertyValue)>) -> Node {
+        let mut node = Node::new_in_dimension(node_type, ContentType::Document, dimension::STRUCTURE);
+        node.id = NodeId::from_string(id);
+        for (key, value) in properties {
+            node.properties.insert(key.to_string(), value);
+        }
+        node
+    }
+
+    fn added(ids: &[&str]) -> Vec<GraphEvent> {
+        vec![GraphEvent::NodesAdded {
+            node_ids: ids.iter().map(|id| NodeId::from_string(*id)).collect(),
+            adapter_id: "test".into(),
+            context_id: "test".into(),
+        }]
+    }
+
+    // === Scenario: DOIs, arXiv IDs, and author-year references are recognized ===
+    #[test]
+    fn citations_are_found_in_text() {
+        let text = "Builds on Chen et al. (2025) and (Okafor, 2023b), see https://doi.org/10.1145/3290605.3300233. \
+                    Also arXiv:2401.01234v2; compare Ruiz and Patel 2021. In 2024 nothing; 110.1234/x isn't one.";
+        assert_eq!(
+            find_citations(text),
+            vec![
+                Citation::Doi("10.1145/3290605.3300233".into()),
+                Citation::Arxiv("2401.01234".into()),
+                Citation::AuthorYear { author: "Chen".into(), year: 2025 },
+                Citation::AuthorYear { author: "Okafor".into(), year: 2023 },
+                Citation::AuthorYear { author: "Ruiz".into(), year: 2021 },
+            ]
+        );
+    }
+
+    // === Scenario: Citations resolve to papers, or to shared placeholders ===
+    #[test]
+    fn citations_resolve_or_create_placeholders() {
+        let mut ctx = Context::new("citations");
+        ctx.add_node(node(
+            "paper:chen-graphs",
+            "paper",
+            vec![
+                ("year", PropertyValue::Int(2025)),
+                ("authors", PropertyValue::Array(vec!["Wei Chen".into(), "Ana Ruiz".into()])),
+            ],
+        ));
+        ctx.add_node(node("paper:doi-one", "paper", vec![("doi", "https://doi.org/10.1000/XYZ".into())]));
+        ctx.add_node(node(
+            "fragment:a",
+            "fragment",
+            vec![("text", "As Chen 2025 argued (doi:10.1000/xyz), unlike Okafor 2023.".into())],
+        ));
+        let enrichment = CitationEnrichment::new();
+        let emission = enrichment.enrich(&added(&["fragment:a"]), &ctx).expect("citations");
+
+        let mut targets: Vec<&str> = emission.edges.iter().map(|e| e.edge.target.as_str()).collect();
+        targets.sort();
+        assert_eq!(targets, vec!["paper:chen-graphs", "paper:doi-one", "paper:okafor-2023"]);
+        assert_eq!(emission.nodes.len(), 1, "one placeholder, for the unresolved citation");
+        assert_eq!(emission.nodes[0].node.properties.get("placeholder"), Some(&PropertyValue::Bool(true)));
+
+        for n in &emission.nodes {
+            ctx.add_node(n.node.clone());
+        }
+        for e in &emission.edges {
+            ctx.add_edge(e.edge.clone());
+        }
+        assert!(enrichment.enrich(&added(&["fragment:a"]), &ctx).is_none(), "quiescent once linked");
+
+        ctx.add_node(node("mark:b", "mark", vec![("annotation", "Okafor (2023) again".into())]));
+        let emission = enrichment.enrich(&added(&["mark:b"]), &ctx).unwrap();
+        assert!(emission.nodes.is_empty(), "the placeholder is reused");
+        assert_eq!(emission.edges[0].edge.target.as_str(), "paper:okafor-2023");
+    }
+
+    // === Scenario: A paper missing its year or authors isn't duplicated by a placeholder ===
+    #[test]
+    fn citations_of_incomplete_papers_are_skipped() {
+        let mut ctx = Context::new("citations");
+        ctx.add_node(node("paper:chen-draft", "paper", vec![("authors", PropertyValue::Array(vec!["Wei Chen".into()]))]));
+        ctx.add_node(node("paper:untitled", "paper", vec![("year", PropertyValue::Int(2019))]));
+        ctx.add_node(node(
+            "fragment:a",
+            "fragment",
+            vec![("text", "Chen 2025 and Okafor 2019 disagree with Ruiz 2021.".into())],
+        ));
+
+        let emission = CitationEnrichment::new().enrich(&added(&["fragment:a"]), &ctx).unwrap();
+        let placeholders: Vec<&str> = emission.nodes.iter().map(|n| n.node.id.as_str()).collect();
+        assert_eq!(placeholders, vec!["paper:ruiz-2021"], "only the citation nothing could match gets one");
+        assert_eq!(emission.edges.len(), 1);
+    }
+}
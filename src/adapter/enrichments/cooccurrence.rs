@@ -10,12 +10,23 @@
 //!
 //! Idempotent: checks for existing edges before emitting, so the enrichment
 //! loop reaches quiescence.
+//!
+//! Incremental: per context, each target keeps a neighbor sketch — the
+//! sparse set of its source nodes. Added source edges update the sketches,
+//! and only pairs through the touched source nodes are recounted (the
+//! intersection of two sketches), so large ingests don't rescan every
+//! source's group each round. Removals, or edges that came or went without
+//! events, rebuild the sketches from the context. A context's sketches are
+//! locked on their own and dropped with the context (`forget_context`).
 
 use crate::adapter::enrichment::Enrichment;
 use crate::graph::events::GraphEvent;
 use crate::adapter::types::{AnnotatedEdge, Emission};
-use crate::graph::{dimension, Context, Edge, NodeId};
+use crate::graph::{dimension, Context, ContextId, Edge, EdgeId, NodeId};
+use crate::parallel;
+use dashmap::DashMap;
 use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
 
 /// Enrichment that detects co-occurrence via shared source nodes.
 ///
@@ -31,6 +42,7 @@ pub struct CoOccurrenceEnrichment {
     source_relationship: String,
     output_relationship: String,
     id: String,
+    sketches: DashMap<ContextId, Arc<Mutex<NeighborSketch>>>,
 }
 
 impl Default for CoOccurrenceEnrichment {
@@ -45,6 +57,7 @@ impl CoOccurrenceEnrichment {
             source_relationship: "tagged_with".to_string(),
             output_relationship: "may_be_related".to_string(),
             id: "co_occurrence:tagged_with:may_be_related".to_string(),
+            sketches: DashMap::new(),
         }
     }
 
@@ -53,6 +66,7 @@ impl CoOccurrenceEnrichment {
             id: format!("co_occurrence:{}:{}", source_relationship, output_relationship),
             source_relationship: source_relationship.to_string(),
             output_relationship: output_relationship.to_string(),
+            sketches: DashMap::new(),
         }
     }
 }
@@ -63,24 +77,30 @@ impl Enrichment for CoOccurrenceEnrichment {
     }
 
     fn enrich(&self, events: &[GraphEvent], context: &Context) -> Option<Emission> {
+        let sketch = self.sketches.entry(context.id.clone()).or_default().clone();
+        let mut sketch = sketch.lock().unwrap_or_else(|e| e.into_inner());
+        if has_removal_events(events) {
+            sketch.stale = true;
+        }
         // Only run when structural changes could affect co-occurrence
         if !has_structural_events(events) {
             return None;
         }
 
-        let pairs = detect_cooccurrence_pairs(context, &self.source_relationship);
+        let pairs = sketch.affected_pairs(context, events, &self.source_relationship);
         if pairs.is_empty() {
             return None;
         }
 
-        let max_count = *pairs.values().max().unwrap_or(&1) as f32;
+        let max_count = sketch.max_count.max(1) as f32;
+        let existing = output_edges(context, &self.output_relationship);
         let mut emission = Emission::new();
 
         for ((a, b), count) in &pairs {
             let score = *count as f32 / max_count;
 
             // Idempotent: skip edges that already exist
-            if !output_edge_exists(context, a, b, &self.output_relationship) {
+            if !existing.contains(&(a, b)) {
                 let mut edge = Edge::new_in_dimension(
                     a.clone(),
                     b.clone(),
@@ -91,7 +111,7 @@ impl Enrichment for CoOccurrenceEnrichment {
                 emission = emission.with_edge(AnnotatedEdge::new(edge));
             }
 
-            if !output_edge_exists(context, b, a, &self.output_relationship) {
+            if !existing.contains(&(b, a)) {
                 let mut edge = Edge::new_in_dimension(
                     b.clone(),
                     a.clone(),
@@ -109,6 +129,10 @@ impl Enrichment for CoOccurrenceEnrichment {
             Some(emission)
         }
     }
+
+    fn forget_context(&self, context_id: &ContextId) {
+        self.sketches.remove(context_id);
+    }
 }
 
 /// Check if events include structural changes that could affect co-occurrence.
@@ -121,10 +145,140 @@ fn has_structural_events(events: &[GraphEvent]) -> bool {
     })
 }
 
+fn has_removal_events(events: &[GraphEvent]) -> bool {
+    events.iter().any(|e| {
+        matches!(
+            e,
+            GraphEvent::NodesRemoved { .. } | GraphEvent::EdgesRemoved { .. }
+        )
+    })
+}
+
+/// One context's neighbor sketches: per target, the set of source nodes
+/// linking to it.
+#[derive(Debug, Default)]
+struct NeighborSketch {
+    /// Source node → its number in the sketches
+    source_numbers: HashMap<NodeId, u32>,
+    /// Source number → the source's targets
+    members: Vec<HashSet<NodeId>>,
+    /// Target → the numbers of its sources
+    sketches: HashMap<NodeId, HashSet<u32>>,
+    /// Source-relationship edges folded in so far
+    indexed: HashSet<EdgeId>,
+    /// Largest shared-source count of any pair
+    max_count: usize,
+    built: bool,
+    stale: bool,
+}
+
+impl NeighborSketch {
+    /// Pairs whose count may have changed since the last call, with their
+    /// current counts: every pair on the first call or after a rebuild,
+    /// otherwise only pairs through the sources of newly added edges.
+    fn affected_pairs(
+        &mut self,
+        context: &Context,
+        events: &[GraphEvent],
+        source_relationship: &str,
+    ) -> Vec<((NodeId, NodeId), usize)> {
+        if !self.built || self.stale {
+            return self.rebuild(context, source_relationship);
+        }
+        let added: HashSet<&EdgeId> = events
+            .iter()
+            .filter_map(|e| match e {
+                GraphEvent::EdgesAdded { edge_ids, .. } => Some(edge_ids),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        let mut known = 0;
+        let mut touched: Vec<(NodeId, NodeId)> = Vec::new();
+        let mut fresh: Vec<EdgeId> = Vec::new();
+        for edge in context.edges().filter(|e| e.relationship == source_relationship) {
+            if self.indexed.contains(&edge.id) {
+                known += 1;
+            } else if added.contains(&edge.id) {
+                touched.push((edge.source.clone(), edge.target.clone()));
+                fresh.push(edge.id.clone());
+            } else {
+                // An edge arrived without an event
+                return self.rebuild(context, source_relationship);
+            }
+        }
+        if known != self.indexed.len() {
+            // An indexed edge went without an event
+            return self.rebuild(context, source_relationship);
+        }
+        self.indexed.extend(fresh);
+
+        let mut pairs: HashSet<(NodeId, NodeId)> = HashSet::new();
+        for (source, target) in &touched {
+            let number = self.link(source, target);
+            for other in &self.members[number as usize] {
+                if other != target {
+                    pairs.insert(canonical(target, other));
+                }
+            }
+        }
+        let mut counted: Vec<((NodeId, NodeId), usize)> = pairs
+            .into_iter()
+            .map(|(a, b)| {
+                let count = shared_sources(&self.sketches[&a], &self.sketches[&b]);
+                ((a, b), count)
+            })
+            .collect();
+        counted.sort_by(|x, y| (x.0.0.as_str(), x.0.1.as_str()).cmp(&(y.0.0.as_str(), y.0.1.as_str())));
+        self.max_count = counted.iter().map(|(_, count)| *count).fold(self.max_count, usize::max);
+        counted
+    }
+
+    /// Re-index every source edge and recount every pair.
+    fn rebuild(&mut self, context: &Context, source_relationship: &str) -> Vec<((NodeId, NodeId), usize)> {
+        *self = Self { built: true, ..Self::default() };
+        for edge in context.edges().filter(|e| e.relationship == source_relationship) {
+            self.indexed.insert(edge.id.clone());
+            self.link(&edge.source, &edge.target);
+        }
+        let pairs = detect_cooccurrence_pairs(context, source_relationship);
+        self.max_count = pairs.iter().map(|(_, count)| *count).max().unwrap_or(0);
+        pairs
+    }
+
+    /// Record `source → target`, returning the source's number.
+    fn link(&mut self, source: &NodeId, target: &NodeId) -> u32 {
+        let next = self.source_numbers.len() as u32;
+        let number = *self.source_numbers.entry(source.clone()).or_insert(next);
+        if number as usize == self.members.len() {
+            self.members.push(HashSet::new());
+        }
+        self.members[number as usize].insert(target.clone());
+        self.sketches.entry(target.clone()).or_default().insert(number);
+        number
+    }
+}
+
+fn canonical(a: &NodeId, b: &NodeId) -> (NodeId, NodeId) {
+    if a.as_str() <= b.as_str() {
+        (a.clone(), b.clone())
+    } else {
+        (b.clone(), a.clone())
+    }
+}
+
+/// Number of sources two sketches share.
+fn shared_sources(a: &HashSet<u32>, b: &HashSet<u32>) -> usize {
+    let (smaller, larger) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    smaller.iter().filter(|source| larger.contains(source)).count()
+}
+
 /// Build a reverse index (source → targets) and count shared sources
-/// for each target pair. Returns canonical pairs with counts.
-fn detect_cooccurrence_pairs(context: &Context, source_relationship: &str) -> HashMap<(NodeId, NodeId), usize> {
-    let mut source_to_targets: HashMap<NodeId, HashSet<NodeId>> = HashMap::new();
+/// for each target pair. Returns canonical pairs with counts, in pair
+/// order. Pair generation and counting run in parallel under the
+/// `parallel` feature.
+fn detect_cooccurrence_pairs(context: &Context, source_relationship: &str) -> Vec<((NodeId, NodeId), usize)> {
+    let mut source_to_targets: HashMap<&NodeId, HashSet<&NodeId>> = HashMap::new();
 
     for edge in context.edges() {
         if edge.relationship != source_relationship {
@@ -132,35 +286,58 @@ fn detect_cooccurrence_pairs(context: &Context, source_relationship: &str) -> Ha
         }
         // Structure-aware: fire based on relationship, not node content type (Invariant 50)
         source_to_targets
-            .entry(edge.source.clone())
+            .entry(&edge.source)
             .or_default()
-            .insert(edge.target.clone());
+            .insert(&edge.target);
     }
 
-    let mut pair_counts: HashMap<(NodeId, NodeId), usize> = HashMap::new();
-
-    for concepts in source_to_targets.values() {
-        let concepts_vec: Vec<_> = concepts.iter().collect();
-        for i in 0..concepts_vec.len() {
-            for j in (i + 1)..concepts_vec.len() {
-                let (a, b) = if concepts_vec[i].as_str() <= concepts_vec[j].as_str() {
-                    (concepts_vec[i].clone(), concepts_vec[j].clone())
-                } else {
-                    (concepts_vec[j].clone(), concepts_vec[i].clone())
-                };
-                *pair_counts.entry((a, b)).or_insert(0) += 1;
-            }
+    // Dense indices in ID order, so a canonical pair is an ordered index pair
+    let mut targets: Vec<&NodeId> = source_to_targets
+        .values()
+        .flatten()
+        .copied()
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    targets.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+    let index: HashMap<&NodeId, u32> = targets.iter().enumerate().map(|(i, id)| (*id, i as u32)).collect();
+    let groups: Vec<Vec<u32>> = source_to_targets
+        .values()
+        .map(|concepts| {
+            let mut group: Vec<u32> = concepts.iter().map(|id| index[id]).collect();
+            group.sort_unstable();
+            group
+        })
+        .collect();
+
+    let mut pairs: Vec<(u32, u32)> = parallel::map(&groups, |group| {
+        let mut pairs = Vec::with_capacity(group.len() * group.len().saturating_sub(1) / 2);
+        for (i, a) in group.iter().enumerate() {
+            pairs.extend(group[i + 1..].iter().map(|b| (*a, *b)));
         }
-    }
-
-    pair_counts
+        pairs
+    })
+    .into_iter()
+    .flatten()
+    .collect();
+    parallel::sort(&mut pairs);
+
+    pairs
+        .chunk_by(|x, y| x == y)
+        .map(|run| {
+            let (a, b) = run[0];
+            ((targets[a as usize].clone(), targets[b as usize].clone()), run.len())
+        })
+        .collect()
 }
 
-/// Check if an output edge from source to target already exists.
-fn output_edge_exists(context: &Context, source: &NodeId, target: &NodeId, relationship: &str) -> bool {
-    context.edges().any(|e| {
-        e.source == *source && e.target == *target && e.relationship == relationship
-    })
+/// Existing `relationship` edges as (source, target) pairs.
+fn output_edges<'a>(context: &'a Context, relationship: &str) -> HashSet<(&'a NodeId, &'a NodeId)> {
+    context
+        .edges()
+        .filter(|e| e.relationship == relationship)
+        .map(|e| (&e.source, &e.target))
+        .collect()
 }
 
 #[cfg(test)]
@@ -665,6 +842,87 @@ mod tests {
         );
     }
 
+    // === Scenario: Sketches recount only pairs through newly tagged sources ===
+    #[test]
+    fn incremental_rounds_match_a_full_rescan() {
+        fn tag(ctx: &mut Context, source: &str, concept: &str) -> EdgeId {
+            let edge = Edge::new_in_dimension(
+                NodeId::from_string(source),
+                NodeId::from_string(concept),
+                "tagged_with",
+                dimension::SEMANTIC,
+            );
+            let id = edge.id.clone();
+            ctx.add_edge(edge);
+            id
+        }
+        fn commit(ctx: &mut Context, emission: Option<Emission>) -> Vec<(String, String, f32)> {
+            let mut edges: Vec<(String, String, f32)> = emission
+                .map(|e| e.edges)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|ae| {
+                    let out = (ae.edge.source.to_string(), ae.edge.target.to_string(), ae.edge.combined_weight);
+                    ctx.add_edge(ae.edge);
+                    out
+                })
+                .collect();
+            edges.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+            edges
+        }
+        let added = |edge_ids: Vec<EdgeId>| GraphEvent::EdgesAdded {
+            edge_ids,
+            adapter_id: "test".to_string(),
+            context_id: "test".to_string(),
+        };
+
+        let enrichment = CoOccurrenceEnrichment::new();
+        let mut ctx = Context::new("test");
+        let first = vec![tag(&mut ctx, "f1", "concept:a"), tag(&mut ctx, "f1", "concept:b")];
+        let emission = enrichment.enrich(&[added(first)], &ctx);
+        assert_eq!(commit(&mut ctx, emission).len(), 2);
+
+        // f2 pairs b with c and strengthens a↔b; only pairs through f2 are recounted
+        let second = vec![
+            tag(&mut ctx, "f2", "concept:a"),
+            tag(&mut ctx, "f2", "concept:b"),
+            tag(&mut ctx, "f2", "concept:c"),
+        ];
+        let mut rescan = ctx.clone();
+        let expected = commit(&mut rescan, CoOccurrenceEnrichment::new().enrich(&[added(vec![])], &ctx));
+        let emission = enrichment.enrich(&[added(second)], &ctx);
+        let incremental = commit(&mut ctx, emission);
+        assert_eq!(incremental, expected);
+        assert_eq!(
+            incremental.iter().map(|(s, t, w)| (s.as_str(), t.as_str(), *w)).collect::<Vec<_>>(),
+            vec![
+                ("concept:a", "concept:c", 0.5),
+                ("concept:b", "concept:c", 0.5),
+                ("concept:c", "concept:a", 0.5),
+                ("concept:c", "concept:b", 0.5),
+            ],
+            "a↔b now shares two sources, so the new pairs score against it"
+        );
+
+        // A tag added without an event is caught by the edge count
+        tag(&mut ctx, "f3", "concept:d");
+        tag(&mut ctx, "f3", "concept:a");
+        let emission = enrichment.enrich(&[edges_added_event()], &ctx);
+        let caught = commit(&mut ctx, emission);
+        assert_eq!(caught.len(), 2, "a↔d found by a rebuild");
+
+        // One tag swapped for another without events keeps the edge count
+        let f3_a = ctx.edges.iter().position(|e| e.source.as_str() == "f3" && e.target.as_str() == "concept:a").unwrap();
+        ctx.edges.remove(f3_a);
+        tag(&mut ctx, "f3", "concept:e");
+        let emission = enrichment.enrich(&[edges_added_event()], &ctx);
+        let swapped = commit(&mut ctx, emission);
+        assert_eq!(swapped.len(), 2, "d↔e found by a rebuild");
+
+        enrichment.forget_context(&ctx.id);
+        assert!(enrichment.sketches.is_empty());
+    }
+
     #[test]
     fn distinct_instances_not_deduplicated_in_registry() {
         use crate::adapter::enrichment::EnrichmentRegistry;
@@ -10,13 +10,15 @@
 //! Structure-aware: filters nodes by dimension (Invariant 50).
 //! Idempotent: checks for existing edges before emitting.
 
+use crate::adapter::chunking::{CHUNK_NODE_TYPE, CHUNK_TEXT_PROPERTY};
 use crate::adapter::enrichment::Enrichment;
 use crate::graph::events::GraphEvent;
 use crate::adapter::types::{AnnotatedEdge, Emission};
-use crate::graph::{dimension, Context, Edge, Node, NodeId, PropertyValue};
-use std::collections::HashMap;
+use crate::graph::{dimension, Context, ContextId, Edge, Node, NodeId, PropertyValue};
+use crate::storage::{GraphStore, PersistedEmbedding};
+use std::collections::{HashMap, HashSet};
 use std::fmt;
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
 
 /// Error type for embedding operations.
 #[derive(Debug)]
@@ -45,6 +47,12 @@ pub trait Embedder: Send + Sync {
     fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, EmbeddingError>;
 }
 
+impl<E: Embedder + ?Sized> Embedder for Arc<E> {
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        (**self).embed_batch(texts)
+    }
+}
+
 /// Trait for storing and querying embedding vectors.
 ///
 /// Implementations range from in-memory (tests/fallback) to sqlite-vec
@@ -57,6 +65,27 @@ pub trait VectorStore: Send + Sync {
     fn has(&self, context_id: &str, node_id: &NodeId) -> bool;
     /// Find nodes with vectors similar to the query above the threshold.
     fn find_similar(&self, context_id: &str, query: &[f32], threshold: f32) -> Vec<(NodeId, f32)>;
+    /// Store a node's vector along with the text it embeds. Defaults to
+    /// `store`; stores that track text override it.
+    fn store_text(&self, context_id: &str, node_id: &NodeId, text: &str, vector: Vec<f32>) {
+        let _ = text;
+        self.store(context_id, node_id, vector)
+    }
+    /// Whether a node's stored vector embeds `text`. Defaults to `has`.
+    fn has_text(&self, context_id: &str, node_id: &NodeId, text: &str) -> bool {
+        let _ = text;
+        self.has(context_id, node_id)
+    }
+    /// Move a node's vector to its new ID after a relocation. Default
+    /// no-op: the node is embedded afresh under its new ID.
+    fn rename(&self, context_id: &str, from: &NodeId, to: &NodeId) {
+        let _ = (context_id, from, to);
+    }
+    /// Drop every vector stored for a context. Default no-op: stores
+    /// that can't drop vectors keep serving the old ones.
+    fn clear(&self, context_id: &str) {
+        let _ = context_id;
+    }
 }
 
 /// In-memory vector store for embedding cache.
@@ -117,6 +146,171 @@ impl VectorStore for InMemoryVectorStore {
         }
         results
     }
+
+    fn clear(&self, context_id: &str) {
+        self.vectors.write().unwrap().remove(context_id);
+    }
+
+    fn rename(&self, context_id: &str, from: &NodeId, to: &NodeId) {
+        let mut vectors = self.vectors.write().unwrap();
+        let Some(ctx) = vectors.get_mut(context_id) else { return };
+        if let Some(vector) = ctx.remove(from.as_str()) {
+            ctx.insert(to.as_str().to_string(), vector);
+        }
+    }
+}
+
+impl<V: VectorStore + ?Sized> VectorStore for Arc<V> {
+    fn store(&self, context_id: &str, node_id: &NodeId, vector: Vec<f32>) {
+        (**self).store(context_id, node_id, vector)
+    }
+
+    fn has(&self, context_id: &str, node_id: &NodeId) -> bool {
+        (**self).has(context_id, node_id)
+    }
+
+    fn find_similar(&self, context_id: &str, query: &[f32], threshold: f32) -> Vec<(NodeId, f32)> {
+        (**self).find_similar(context_id, query, threshold)
+    }
+
+    fn clear(&self, context_id: &str) {
+        (**self).clear(context_id)
+    }
+
+    fn store_text(&self, context_id: &str, node_id: &NodeId, text: &str, vector: Vec<f32>) {
+        (**self).store_text(context_id, node_id, text, vector)
+    }
+
+    fn has_text(&self, context_id: &str, node_id: &NodeId, text: &str) -> bool {
+        (**self).has_text(context_id, node_id, text)
+    }
+
+    fn rename(&self, context_id: &str, from: &NodeId, to: &NodeId) {
+        (**self).rename(context_id, from, to)
+    }
+}
+
+/// Vector store writing through to a `GraphStore`, so embeddings survive
+/// restarts.
+///
+/// Vectors live in memory and are saved per model with a hash of the text
+/// they embed. A context's saved vectors are loaded on its first use, and
+/// a node whose text changed since is re-embedded.
+pub struct PersistedVectorStore {
+    store: Arc<dyn GraphStore>,
+    model: String,
+    vectors: InMemoryVectorStore,
+    /// context_id → node_id → text hash
+    hashes: RwLock<HashMap<String, HashMap<String, String>>>,
+    warmed: RwLock<HashSet<String>>,
+}
+
+impl PersistedVectorStore {
+    pub fn new(store: Arc<dyn GraphStore>, model: &str) -> Self {
+        Self {
+            store,
+            model: model.to_string(),
+            vectors: InMemoryVectorStore::new(),
+            hashes: RwLock::new(HashMap::new()),
+            warmed: RwLock::new(HashSet::new()),
+        }
+    }
+
+    /// Load a context's saved vectors, once.
+    fn warm(&self, context_id: &str) {
+        if self.warmed.read().unwrap().contains(context_id) {
+            return;
+        }
+        let mut warmed = self.warmed.write().unwrap();
+        if !warmed.insert(context_id.to_string()) {
+            return;
+        }
+        match self.store.load_embeddings(context_id, &self.model) {
+            Ok(saved) => {
+                let mut hashes = self.hashes.write().unwrap();
+                let hashes = hashes.entry(context_id.to_string()).or_default();
+                for embedding in saved {
+                    let node_id = NodeId::from_string(embedding.node_id);
+                    self.vectors.store(context_id, &node_id, embedding.vector);
+                    hashes.insert(node_id.as_str().to_string(), embedding.text_hash);
+                }
+            }
+            Err(e) => tracing::warn!(context = context_id, model = %self.model, error = %e, "failed to load embeddings"),
+        }
+    }
+}
+
+/// Stable hash of embedded text, for spotting stale vectors.
+fn text_hash(text: &str) -> String {
+    uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_OID, text.as_bytes()).to_string()
+}
+
+impl VectorStore for PersistedVectorStore {
+    fn store(&self, context_id: &str, node_id: &NodeId, vector: Vec<f32>) {
+        self.store_text(context_id, node_id, "", vector)
+    }
+
+    fn has(&self, context_id: &str, node_id: &NodeId) -> bool {
+        self.warm(context_id);
+        self.vectors.has(context_id, node_id)
+    }
+
+    fn find_similar(&self, context_id: &str, query: &[f32], threshold: f32) -> Vec<(NodeId, f32)> {
+        self.warm(context_id);
+        self.vectors.find_similar(context_id, query, threshold)
+    }
+
+    fn clear(&self, context_id: &str) {
+        self.warm(context_id);
+        self.vectors.clear(context_id);
+        self.hashes.write().unwrap().remove(context_id);
+        if let Err(e) = self.store.delete_embeddings(context_id, &self.model) {
+            tracing::warn!(context = context_id, model = %self.model, error = %e, "failed to delete embeddings");
+        }
+    }
+
+    fn store_text(&self, context_id: &str, node_id: &NodeId, text: &str, vector: Vec<f32>) {
+        self.warm(context_id);
+        let embedding = PersistedEmbedding {
+            context_id: context_id.to_string(),
+            node_id: node_id.as_str().to_string(),
+            model: self.model.clone(),
+            vector,
+            text_hash: text_hash(text),
+        };
+        if let Err(e) = self.store.persist_embedding(&embedding) {
+            tracing::warn!(context = context_id, model = %self.model, error = %e, "failed to persist embedding");
+        }
+        self.hashes
+            .write()
+            .unwrap()
+            .entry(context_id.to_string())
+            .or_default()
+            .insert(embedding.node_id, embedding.text_hash);
+        self.vectors.store(context_id, node_id, embedding.vector);
+    }
+
+    fn has_text(&self, context_id: &str, node_id: &NodeId, text: &str) -> bool {
+        self.warm(context_id);
+        self.hashes
+            .read()
+            .unwrap()
+            .get(context_id)
+            .and_then(|hashes| hashes.get(node_id.as_str()))
+            .is_some_and(|hash| *hash == text_hash(text))
+    }
+
+    /// Moves the cached vector; the graph store moves the saved row as
+    /// part of the relocation (`GraphStore::rename_nodes`).
+    fn rename(&self, context_id: &str, from: &NodeId, to: &NodeId) {
+        self.warm(context_id);
+        self.vectors.rename(context_id, from, to);
+        if let Some(hashes) = self.hashes.write().unwrap().get_mut(context_id) {
+            if let Some(hash) = hashes.remove(from.as_str()) {
+                hashes.insert(to.as_str().to_string(), hash);
+            }
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -192,10 +386,16 @@ fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
 /// `similar_to` edge pairs above a configurable threshold.
 ///
 /// Enrichment ID encodes the model name: `embedding:{model_name}`.
+///
+/// A context whose `EmbeddingConfig` names a model is served only by the
+/// enrichment for that model, with the config's threshold and dimensions;
+/// contexts without one are served by the default model.
 pub struct EmbeddingSimilarityEnrichment {
     similarity_threshold: f32,
     output_relationship: String,
     id: String,
+    model_name: String,
+    serves_unconfigured: bool,
     embedder: Box<dyn Embedder>,
     cache: Box<dyn VectorStore>,
     dimension_filter: String,
@@ -216,6 +416,8 @@ impl EmbeddingSimilarityEnrichment {
     ) -> Self {
         Self {
             id: format!("embedding:{}", model_name),
+            model_name: model_name.to_string(),
+            serves_unconfigured: true,
             similarity_threshold,
             output_relationship: output_relationship.to_string(),
             embedder,
@@ -234,6 +436,8 @@ impl EmbeddingSimilarityEnrichment {
     ) -> Self {
         Self {
             id: format!("embedding:{}", model_name),
+            model_name: model_name.to_string(),
+            serves_unconfigured: true,
             similarity_threshold,
             output_relationship: output_relationship.to_string(),
             embedder,
@@ -248,22 +452,54 @@ impl EmbeddingSimilarityEnrichment {
         self
     }
 
-    /// Get the embeddable text for a node — its "label" property.
+    /// Serve only contexts whose `EmbeddingConfig` names this model —
+    /// for every model but the pipeline's default.
+    pub fn configured_only(mut self) -> Self {
+        self.serves_unconfigured = false;
+        self
+    }
+
+    /// The model this enrichment embeds with.
+    pub fn model_name(&self) -> &str {
+        &self.model_name
+    }
+
+    /// The threshold and expected dimensions for `context`, or `None` when
+    /// another model serves it.
+    fn settings_for(&self, context: &Context) -> Option<(f32, Option<usize>)> {
+        match &context.metadata.embedding {
+            Some(config) if config.model == self.model_name => {
+                Some((config.threshold.unwrap_or(self.similarity_threshold), config.dimensions))
+            }
+            Some(_) => None,
+            None => self.serves_unconfigured.then_some((self.similarity_threshold, None)),
+        }
+    }
+
+    /// Get the embeddable text for a node — its "label" property, or a
+    /// chunk's text (chunks live in the structure dimension; see
+    /// `with_dimension_filter`).
     fn node_text(node: &Node) -> Option<&str> {
-        node.properties.get("label").and_then(|v| match v {
-            PropertyValue::String(s) => Some(s.as_str()),
-            _ => None,
-        })
+        node_text(node)
     }
 }
 
+fn node_text(node: &Node) -> Option<&str> {
+    let key = if node.node_type == CHUNK_NODE_TYPE { CHUNK_TEXT_PROPERTY } else { "label" };
+    node.properties.get(key).and_then(|v| match v {
+        PropertyValue::String(s) => Some(s.as_str()),
+        _ => None,
+    })
+}
+
 impl Enrichment for EmbeddingSimilarityEnrichment {
     fn id(&self) -> &str {
         &self.id
     }
 
     fn enrich(&self, events: &[GraphEvent], context: &Context) -> Option<Emission> {
-        let ctx_name = &context.name;
+        let ctx_id = context.id.as_str();
+        let (threshold, dimensions) = self.settings_for(context)?;
 
         // Only fire on NodesAdded events
         let new_node_ids: Vec<&NodeId> = events
@@ -287,10 +523,10 @@ impl Enrichment for EmbeddingSimilarityEnrichment {
                 if node.dimension != self.dimension_filter {
                     continue;
                 }
-                if self.cache.has(ctx_name, &node.id) {
-                    continue;
-                }
                 if let Some(text) = Self::node_text(node) {
+                    if self.cache.has_text(ctx_id, &node.id, text) {
+                        continue;
+                    }
                     to_embed.push((node.id.clone(), text.to_string()));
                 }
             }
@@ -310,9 +546,13 @@ impl Enrichment for EmbeddingSimilarityEnrichment {
         // Store embeddings and find similar pairs
         let mut emission = Emission::new();
 
-        for ((node_id, _), embedding) in to_embed.iter().zip(embeddings.iter()) {
+        for ((node_id, text), embedding) in to_embed.iter().zip(embeddings.iter()) {
+            if dimensions.is_some_and(|d| embedding.len() != d) {
+                tracing::warn!(node = %node_id, model = %self.model_name, len = embedding.len(), "embedding has unexpected dimensions; skipped");
+                continue;
+            }
             // Find similar nodes already in cache
-            let similar = self.cache.find_similar(ctx_name, embedding, self.similarity_threshold);
+            let similar = self.cache.find_similar(ctx_id, embedding, threshold);
 
             for (other_id, similarity) in &similar {
                 // Idempotency: skip if edges already exist
@@ -344,7 +584,7 @@ impl Enrichment for EmbeddingSimilarityEnrichment {
             }
 
             // Cache the new embedding after comparisons
-            self.cache.store(ctx_name, node_id, embedding.clone());
+            self.cache.store_text(ctx_id, node_id, text, embedding.clone());
         }
 
         if emission.is_empty() {
@@ -353,6 +593,12 @@ impl Enrichment for EmbeddingSimilarityEnrichment {
             Some(emission)
         }
     }
+
+    fn rename_nodes(&self, context_id: &ContextId, renamed: &[(NodeId, NodeId)]) {
+        for (from, to) in renamed {
+            self.cache.rename(context_id.as_str(), from, to);
+        }
+    }
 }
 
 /// Check if an edge from source to target with the given relationship already exists.
@@ -367,6 +613,109 @@ fn output_edge_exists(
     })
 }
 
+/// On-demand similarity search over the same embedder and vector store
+/// the enrichment fills.
+///
+/// Nodes embedded before the search was created (or loaded from storage
+/// without their vectors) are embedded lazily on first search, so results
+/// cover every eligible node in the context.
+pub struct SimilaritySearch {
+    embedder: Arc<dyn Embedder>,
+    store: Arc<dyn VectorStore>,
+    dimension_filter: String,
+    model_name: String,
+}
+
+impl SimilaritySearch {
+    pub fn new(embedder: Arc<dyn Embedder>, store: Arc<dyn VectorStore>) -> Self {
+        Self { embedder, store, dimension_filter: dimension::SEMANTIC.to_string(), model_name: String::new() }
+    }
+
+    /// Name the model the embedder runs, matched against a context's
+    /// `EmbeddingConfig`.
+    pub fn with_model_name(mut self, model_name: &str) -> Self {
+        self.model_name = model_name.to_string();
+        self
+    }
+
+    pub fn model_name(&self) -> &str {
+        &self.model_name
+    }
+
+    /// The dimension searched.
+    pub fn dimension_filter(&self) -> &str {
+        &self.dimension_filter
+    }
+
+    /// Drop `context`'s vectors, so they are re-embedded on next use.
+    pub fn clear(&self, context: &Context) {
+        self.store.clear(context.id.as_str());
+    }
+
+    /// Set the dimension searched (default: semantic).
+    pub fn with_dimension_filter(mut self, dim: &str) -> Self {
+        self.dimension_filter = dim.to_string();
+        self
+    }
+
+    /// The `k` nodes most similar to `id`, best first, excluding `id`.
+    pub fn similar_to_node(&self, context: &Context, id: &NodeId, k: usize) -> Result<Vec<(NodeId, f32)>, EmbeddingError> {
+        let text = context
+            .get_node(id)
+            .and_then(node_text)
+            .ok_or_else(|| EmbeddingError::ModelError(format!("node {id} has no embeddable text")))?;
+        let mut results = self.similar_to_text(context, text, k.saturating_add(1))?;
+        results.retain(|(other, _)| other != id);
+        results.truncate(k);
+        Ok(results)
+    }
+
+    /// The `k` nodes most similar to `text`, best first.
+    pub fn similar_to_text(&self, context: &Context, text: &str, k: usize) -> Result<Vec<(NodeId, f32)>, EmbeddingError> {
+        self.embed_missing(context)?;
+        let query = self.embed(text)?;
+        let mut results: Vec<(NodeId, f32)> = self
+            .store
+            .find_similar(context.id.as_str(), &query, f32::MIN)
+            .into_iter()
+            .filter(|(id, _)| context.get_node(id).is_some())
+            .collect();
+        results.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.as_str().cmp(b.0.as_str())));
+        results.truncate(k);
+        Ok(results)
+    }
+
+    /// Embed `text` with the search's embedder.
+    pub fn embed(&self, text: &str) -> Result<Vec<f32>, EmbeddingError> {
+        self.embedder.embed_batch(&[text])?.into_iter().next().ok_or(EmbeddingError::EmptyResult)
+    }
+
+    /// The vectors searched, with every eligible node of `context` embedded.
+    pub fn vectors(&self, context: &Context) -> Result<&dyn VectorStore, EmbeddingError> {
+        self.embed_missing(context)?;
+        Ok(self.store.as_ref())
+    }
+
+    /// Embed eligible nodes the store doesn't hold yet.
+    fn embed_missing(&self, context: &Context) -> Result<(), EmbeddingError> {
+        let missing: Vec<(&NodeId, &str)> = context
+            .nodes()
+            .filter(|n| n.dimension == self.dimension_filter)
+            .filter_map(|n| node_text(n).map(|text| (&n.id, text)))
+            .filter(|(id, text)| !self.store.has_text(context.id.as_str(), id, text))
+            .collect();
+        if missing.is_empty() {
+            return Ok(());
+        }
+        let texts: Vec<&str> = missing.iter().map(|(_, text)| *text).collect();
+        let vectors = self.embedder.embed_batch(&texts)?;
+        for ((id, text), vector) in missing.into_iter().zip(vectors) {
+            self.store.store_text(context.id.as_str(), id, text, vector);
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -776,6 +1125,117 @@ mod tests {
         );
     }
 
+    // === Scenario: A context's embedding config selects model and threshold ===
+
+    #[test]
+    fn context_embedding_config_selects_model_and_threshold() {
+        use crate::graph::EmbeddingConfig;
+
+        let default = EmbeddingSimilarityEnrichment::new("model-a", 0.999, "similar_to", Box::new(MockEmbedder::simple(test_vectors())));
+        let other = EmbeddingSimilarityEnrichment::new("model-b", 0.999, "similar_to", Box::new(MockEmbedder::simple(test_vectors())))
+            .configured_only();
+        let mut ctx = Context::new("test");
+        ctx.add_node(concept_node("concept:travel", "travel"));
+        ctx.add_node(concept_node("concept:journey", "journey"));
+        let burst = nodes_added_event(&["concept:travel", "concept:journey"]);
+
+        assert!(other.enrich(std::slice::from_ref(&burst), &ctx).is_none(), "unconfigured contexts belong to the default");
+        assert!(default.enrich(std::slice::from_ref(&burst), &ctx).is_none(), "travel/journey is below 0.999");
+
+        ctx.metadata.embedding = Some(EmbeddingConfig::new("model-b").with_threshold(0.7).with_dimensions(4));
+        assert!(default.enrich(std::slice::from_ref(&burst), &ctx).is_none(), "another model serves the context");
+        assert!(other.enrich(std::slice::from_ref(&burst), &ctx).is_none(), "3-dimensional vectors are dropped");
+
+        ctx.metadata.embedding = Some(EmbeddingConfig::new("model-b").with_threshold(0.7).with_dimensions(3));
+        let emission = other.enrich(&[burst], &ctx).expect("config threshold applies");
+        assert_eq!(emission.edges.len(), 2);
+    }
+
+    // === Scenario: Persisted embeddings survive a restart ===
+
+    #[test]
+    fn persisted_vectors_warm_lazily_after_restart() {
+        use crate::storage::{OpenStore, SqliteStore};
+
+        let dir = tempfile::tempdir().unwrap();
+        let db = dir.path().join("vectors.db");
+        let mut ctx = Context::new("test");
+        ctx.add_node(concept_node("concept:travel", "travel"));
+        {
+            let store: Arc<dyn GraphStore> = Arc::new(SqliteStore::open(&db).unwrap());
+            let enrichment = EmbeddingSimilarityEnrichment::with_vector_store(
+                "test-model",
+                0.7,
+                "similar_to",
+                Box::new(MockEmbedder::simple(test_vectors())),
+                Box::new(PersistedVectorStore::new(store, "test-model")),
+            );
+            assert!(enrichment.enrich(&[nodes_added_event(&["concept:travel"])], &ctx).is_none());
+        }
+
+        // After the restart: travel's vector is loaded, not re-embedded
+        let store: Arc<dyn GraphStore> = Arc::new(SqliteStore::open(&db).unwrap());
+        let vectors = Arc::new(PersistedVectorStore::new(store.clone(), "test-model"));
+        let (embedder, calls) = MockEmbedder::new(test_vectors());
+        let enrichment = EmbeddingSimilarityEnrichment::with_vector_store(
+            "test-model",
+            0.7,
+            "similar_to",
+            Box::new(embedder),
+            Box::new(vectors.clone()),
+        );
+        ctx.add_node(concept_node("concept:journey", "journey"));
+        let emission = enrichment
+            .enrich(&[nodes_added_event(&["concept:travel", "concept:journey"])], &ctx)
+            .expect("journey finds the pre-restart travel");
+        assert_eq!(emission.edges.len(), 2);
+        assert_eq!(calls.get(), 1, "one batch, for journey only");
+
+        let travel = NodeId::from_string("concept:travel");
+        assert!(vectors.has_text(ctx.id.as_str(), &travel, "travel"));
+        assert!(!vectors.has_text(ctx.id.as_str(), &travel, "voyage"), "changed text is stale");
+        assert!(store.load_embeddings(ctx.id.as_str(), "other-model").unwrap().is_empty(), "scoped by model");
+        vectors.clear(ctx.id.as_str());
+        assert!(store.load_embeddings(ctx.id.as_str(), "test-model").unwrap().is_empty());
+    }
+
+    // === Scenario: Similarity search shares the enrichment's vectors ===
+
+    #[test]
+    fn similarity_search_ranks_nodes_and_embeds_missing_ones() {
+        let embedder: Arc<dyn Embedder> = Arc::new(MockEmbedder::simple(test_vectors()));
+        let store: Arc<dyn VectorStore> = Arc::new(InMemoryVectorStore::new());
+        let enrichment = EmbeddingSimilarityEnrichment::with_vector_store(
+            "test-model",
+            0.7,
+            "similar_to",
+            Box::new(embedder.clone()),
+            Box::new(store.clone()),
+        );
+        let search = SimilaritySearch::new(embedder, store.clone());
+
+        let mut ctx = Context::new("test");
+        ctx.add_node(concept_node("concept:travel", "travel"));
+        enrichment.enrich(&[nodes_added_event(&["concept:travel"])], &ctx);
+        // Added without an event: the search embeds it on demand
+        ctx.add_node(concept_node("concept:journey", "journey"));
+        ctx.add_node(concept_node("concept:democracy", "democracy"));
+
+        let ranked = search.similar_to_node(&ctx, &NodeId::from_string("concept:voyage"), 2);
+        assert!(ranked.is_err(), "unknown nodes have no text");
+
+        let ranked = search.similar_to_node(&ctx, &NodeId::from_string("concept:travel"), 2).unwrap();
+        let ids: Vec<&str> = ranked.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, vec!["concept:journey", "concept:democracy"]);
+        assert!(ranked[0].1 > ranked[1].1);
+        assert!(store.has(ctx.id.as_str(), &NodeId::from_string("concept:democracy")));
+        let everything = search.similar_to_node(&ctx, &NodeId::from_string("concept:travel"), usize::MAX).unwrap();
+        assert_eq!(everything.len(), 2, "an unbounded k doesn't overflow");
+
+        let by_text = search.similar_to_text(&ctx, "voyage", 1).unwrap();
+        assert_eq!(by_text[0].0.as_str(), "concept:travel");
+    }
+
     // === Scenario: FastEmbedEmbedder loads model and embeds text ===
 
     #[cfg(feature = "embeddings")]
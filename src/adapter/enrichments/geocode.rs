@@ -0,0 +1,139 @@
+//! GeocodingEnrichment — coordinates for places that lack them
+//!
+//! The geocoding hook: a `Geocoder` resolves a place name to coordinates,
+//! and this enrichment asks it about each new `place` node (see
+//! `place_node`) that has no `latitude`/`longitude`, writing the answer
+//! back as a property update. Located places then answer radius and
+//! bounding-box queries (`FindQuery::within_radius`, `within_bounds`).
+//!
+//! Enrichments run synchronously in the enrichment loop, so a geocoder
+//! backed by a remote service should cache, or be a `Gazetteer` filled
+//! ahead of time. Fires only for nodes named in `NodesAdded` events;
+//! unknown names are left unlocated.
+
+use crate::adapter::enrichment::Enrichment;
+use crate::adapter::types::{Emission, PropertyUpdate};
+use crate::graph::events::GraphEvent;
+use crate::graph::{normalize_natural_key, Context, PropertyValue};
+use crate::query::{coordinates, LATITUDE_PROPERTY, LONGITUDE_PROPERTY};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Resolves place names to `(latitude, longitude)` in decimal degrees.
+pub trait Geocoder: Send + Sync {
+    fn geocode(&self, name: &str) -> Option<(f64, f64)>;
+}
+
+/// A fixed name → coordinates table; names match case- and
+/// whitespace-insensitively.
+#[derive(Debug, Clone, Default)]
+pub struct Gazetteer {
+    places: HashMap<String, (f64, f64)>,
+}
+
+impl Gazetteer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_place(mut self, name: &str, latitude: f64, longitude: f64) -> Self {
+        self.places.insert(normalize_natural_key(name), (latitude, longitude));
+        self
+    }
+}
+
+impl Geocoder for Gazetteer {
+    fn geocode(&self, name: &str) -> Option<(f64, f64)> {
+        self.places.get(&normalize_natural_key(name)).copied()
+    }
+}
+
+/// Enrichment locating unlocated place nodes through a `Geocoder`.
+pub struct GeocodingEnrichment {
+    geocoder: Arc<dyn Geocoder>,
+    node_types: Vec<String>,
+}
+
+impl GeocodingEnrichment {
+    /// Locate nodes of type `place` by their `name`.
+    pub fn new(geocoder: Arc<dyn Geocoder>) -> Self {
+        Self { geocoder, node_types: vec!["place".to_string()] }
+    }
+
+    pub fn with_node_types(mut self, node_types: Vec<String>) -> Self {
+        self.node_types = node_types;
+        self
+    }
+}
+
+impl Enrichment for GeocodingEnrichment {
+    fn id(&self) -> &str {
+        "geocoding"
+    }
+
+    fn enrich(&self, events: &[GraphEvent], context: &Context) -> Option<Emission> {
+        let mut emission = Emission::new();
+        for event in events {
+            let GraphEvent::NodesAdded { node_ids, .. } = event else { continue };
+            for id in node_ids {
+                let Some(node) = context.get_node(id) else { continue };
+                if !self.node_types.iter().any(|t| t == &node.node_type) || coordinates(node).is_some() {
+                    continue;
+                }
+                let name = match node.properties.get("name").or_else(|| node.properties.get("label")) {
+                    Some(PropertyValue::String(name)) => name,
+                    _ => continue,
+                };
+                if let Some((latitude, longitude)) = self.geocoder.geocode(name) {
+                    let update = PropertyUpdate::new(id.clone())
+                        .with_property(LATITUDE_PROPERTY, PropertyValue::Float(latitude))
+                        .with_property(LONGITUDE_PROPERTY, PropertyValue::Float(longitude));
+                    emission = emission.with_property_update(update);
+                }
+            }
+        }
+
+        if emission.is_empty() {
+            None
+        } else {
+            Some(emission)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::types::place_node;
+    use crate::query::FindQuery;
+
+    // === Scenario: New places are geocoded, then found by radius ===
+    #[test]
+    fn places_are_geocoded_and_queryable() {
+        let mut ctx = Context::new("travel");
+        let (avignon, node) = place_node("Avignon", None);
+        ctx.add_node(node);
+        let (nowhere, node) = place_node("Atlantis", None);
+        ctx.add_node(node);
+        let (arles, node) = place_node("Arles", Some((43.6768, 4.6303)));
+        ctx.add_node(node);
+        let events = [GraphEvent::NodesAdded {
+            node_ids: vec![avignon.clone(), nowhere, arles],
+            adapter_id: "test".into(),
+            context_id: "travel".into(),
+        }];
+
+        let gazetteer = Gazetteer::new().with_place("  AVIGNON ", 43.9493, 4.8055);
+        let emission = GeocodingEnrichment::new(Arc::new(gazetteer)).enrich(&events, &ctx).expect("one update");
+        assert_eq!(emission.property_updates.len(), 1, "only unlocated, known places");
+        let update = &emission.property_updates[0];
+        assert_eq!(update.node_id, avignon);
+
+        let node = ctx.get_node_mut(&avignon).unwrap();
+        node.properties.extend(update.properties.clone());
+        let near = FindQuery::new().within_radius(43.95, 4.81, 50.0).execute(&ctx);
+        assert_eq!(near.nodes.len(), 2, "Avignon and Arles, not unlocated Atlantis");
+        let north = FindQuery::new().within_bounds(43.8, 4.0, 44.5, 5.5).execute(&ctx);
+        assert_eq!(north.nodes.iter().map(|n| n.id.clone()).collect::<Vec<_>>(), vec![avignon]);
+    }
+}
@@ -0,0 +1,386 @@
+//! KeywordExtractionEnrichment — programmatic keyword extraction (RAKE)
+//!
+//! Untagged fragments otherwise never reach the semantic dimension unless
+//! an LLM extraction runs. This enrichment scores candidate phrases in a
+//! node's text with RAKE (phrases split on stopwords and punctuation;
+//! word score = degree / frequency; phrase score = sum of word scores)
+//! and proposes the top phrases as concepts.
+//!
+//! Proposals are deliberately weak: each edge contributes its phrase's
+//! score relative to the node's best, and the enrichment's confidence
+//! scales its contributions after normalization (ADR-003), so a human tag
+//! or an LLM extraction on the same concept dominates.
+//!
+//! Fires only for nodes named in `NodesAdded` events and skips nodes that
+//! already carry tags from another contributor. Idempotent: existing
+//! edges are not re-emitted, so the enrichment loop reaches quiescence.
+
+use crate::adapter::enrichment::Enrichment;
+use crate::adapter::types::{concept_node, AnnotatedEdge, Emission};
+use crate::graph::events::GraphEvent;
+use crate::graph::{dimension, Context, Edge, Node, NodeId, PropertyValue};
+use std::collections::{HashMap, HashSet};
+
+/// Words that split candidate phrases and never start one.
+const STOPWORDS: &[&str] = &[
+    "a", "about", "above", "after", "again", "against", "all", "also", "am", "an", "and",
+    "any", "are", "as", "at", "be", "because", "been", "before", "being", "below",
+    "between", "both", "but", "by", "can", "could", "did", "do", "does", "doing", "down",
+    "during", "each", "even", "few", "for", "from", "further", "get", "got", "had", "has",
+    "have", "having", "he", "her", "here", "hers", "herself", "him", "himself", "his",
+    "how", "i", "if", "in", "into", "is", "it", "its", "itself", "just", "like", "me",
+    "more", "most", "much", "my", "myself", "no", "nor", "not", "now", "of", "off", "on",
+    "once", "only", "or", "other", "our", "ours", "ourselves", "out", "over", "own",
+    "really", "same", "she", "should", "so", "some", "still", "such", "than", "that",
+    "the", "their", "theirs", "them", "themselves", "then", "there", "these", "they",
+    "this", "those", "through", "to", "too", "under", "until", "up", "very", "was", "we",
+    "were", "what", "when", "where", "which", "while", "who", "whom", "why", "will",
+    "with", "would", "you", "your", "yours", "yourself", "yourselves",
+];
+
+/// Enrichment that proposes concepts from keywords in node text.
+///
+/// Defaults: fragments' `text` property, top 3 phrases of at most 3 words,
+/// contribution confidence 0.3, emitted as cross-dimensional `tagged_with`
+/// edges so keyword concepts join co-occurrence like human tags do.
+pub struct KeywordExtractionEnrichment {
+    node_types: Vec<String>,
+    text_properties: Vec<String>,
+    output_relationship: String,
+    max_keywords: usize,
+    max_phrase_words: usize,
+    confidence: f32,
+    id: String,
+}
+
+impl Default for KeywordExtractionEnrichment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeywordExtractionEnrichment {
+    pub fn new() -> Self {
+        Self {
+            node_types: vec!["fragment".to_string()],
+            text_properties: vec!["text".to_string()],
+            output_relationship: "tagged_with".to_string(),
+            max_keywords: 3,
+            max_phrase_words: 3,
+            confidence: 0.3,
+            id: "keyword_extraction:tagged_with".to_string(),
+        }
+    }
+
+    /// Extract from nodes of these types (e.g., add `"mark"`, with the
+    /// `annotation` text property).
+    pub fn with_node_types(mut self, node_types: Vec<String>) -> Self {
+        self.node_types = node_types;
+        self
+    }
+
+    /// Read text from the first of these properties present on a node.
+    pub fn with_text_properties(mut self, properties: Vec<String>) -> Self {
+        self.text_properties = properties;
+        self
+    }
+
+    pub fn with_output_relationship(mut self, relationship: &str) -> Self {
+        self.id = format!("keyword_extraction:{}", relationship);
+        self.output_relationship = relationship.to_string();
+        self
+    }
+
+    pub fn with_max_keywords(mut self, max: usize) -> Self {
+        self.max_keywords = max;
+        self
+    }
+
+    /// How much the enrichment's contributions count after
+    /// normalization, relative to a contributor at full confidence.
+    pub fn with_confidence(mut self, confidence: f32) -> Self {
+        self.confidence = confidence;
+        self
+    }
+
+    fn node_text<'a>(&self, node: &'a Node) -> Option<&'a str> {
+        self.text_properties.iter().find_map(|key| match node.properties.get(key) {
+            Some(PropertyValue::String(text)) if !text.trim().is_empty() => Some(text.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Whether another contributor has already tagged this node.
+    fn tagged_by_others(&self, context: &Context, id: &NodeId) -> bool {
+        context.edges().any(|e| {
+            e.source == *id
+                && e.relationship == self.output_relationship
+                && e.contributions.keys().any(|k| k != &self.id)
+        })
+    }
+}
+
+impl Enrichment for KeywordExtractionEnrichment {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn confidence(&self) -> Option<f32> {
+        Some(self.confidence)
+    }
+
+    fn enrich(&self, events: &[GraphEvent], context: &Context) -> Option<Emission> {
+        let added: HashSet<&NodeId> = events
+            .iter()
+            .filter_map(|e| match e {
+                GraphEvent::NodesAdded { node_ids, .. } => Some(node_ids),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        if added.is_empty() {
+            return None;
+        }
+
+        let mut emission = Emission::new();
+        let mut proposed_concepts: HashSet<NodeId> = HashSet::new();
+
+        for id in added {
+            let Some(node) = context.get_node(id) else {
+                continue;
+            };
+            if !self.node_types.iter().any(|t| t == &node.node_type) {
+                continue;
+            }
+            let Some(text) = self.node_text(node) else {
+                continue;
+            };
+            if self.tagged_by_others(context, id) {
+                continue;
+            }
+
+            let keywords = extract_keywords(text, self.max_phrase_words, self.max_keywords);
+            let Some(best) = keywords.first().map(|(_, score)| *score) else {
+                continue;
+            };
+
+            for (phrase, score) in &keywords {
+                let (concept_id, concept) = concept_node(phrase);
+                let exists = context.edges().any(|e| {
+                    e.source == *id
+                        && e.target == concept_id
+                        && e.relationship == self.output_relationship
+                });
+                if exists {
+                    continue;
+                }
+                if context.get_node(&concept_id).is_none()
+                    && proposed_concepts.insert(concept_id.clone())
+                {
+                    emission = emission.with_node(concept);
+                }
+
+                let mut edge = Edge::new_cross_dimensional(
+                    id.clone(),
+                    node.dimension.clone(),
+                    concept_id,
+                    dimension::SEMANTIC,
+                    &self.output_relationship,
+                );
+                edge.combined_weight = score / best;
+                emission = emission.with_edge(AnnotatedEdge::new(edge));
+            }
+        }
+
+        if emission.is_empty() {
+            None
+        } else {
+            Some(emission)
+        }
+    }
+}
+
+/// Score candidate phrases in `text` with RAKE. Returns the top `limit`
+/// phrases (lowercase) with their scores, best first.
+pub fn extract_keywords(text: &str, max_phrase_words: usize, limit: usize) -> Vec<(String, f32)> {
+    let stopwords: HashSet<&str> = STOPWORDS.iter().copied().collect();
+
+    // Candidate phrases: runs of content words between stopwords/punctuation
+    let mut phrases: Vec<Vec<String>> = Vec::new();
+    for chunk in text.split(|c: char| !(c.is_alphanumeric() || c == '\'' || c == '-' || c.is_whitespace())) {
+        let mut current: Vec<String> = Vec::new();
+        for raw in chunk.split_whitespace() {
+            let word = raw.trim_matches(|c: char| c == '\'' || c == '-').to_lowercase();
+            let is_content = word.chars().count() >= 3
+                && !stopwords.contains(word.as_str())
+                && !word.chars().all(|c| c.is_numeric());
+            if is_content {
+                current.push(word);
+            } else if !current.is_empty() {
+                phrases.push(std::mem::take(&mut current));
+            }
+        }
+        if !current.is_empty() {
+            phrases.push(current);
+        }
+    }
+    phrases.retain(|p| p.len() <= max_phrase_words);
+
+    let mut frequency: HashMap<&str, f32> = HashMap::new();
+    let mut degree: HashMap<&str, f32> = HashMap::new();
+    for phrase in &phrases {
+        for word in phrase {
+            *frequency.entry(word).or_default() += 1.0;
+            *degree.entry(word).or_default() += phrase.len() as f32;
+        }
+    }
+
+    let mut scored: HashMap<String, f32> = HashMap::new();
+    for phrase in &phrases {
+        let score = phrase.iter().map(|w| degree[w.as_str()] / frequency[w.as_str()]).sum();
+        scored.insert(phrase.join(" "), score);
+    }
+
+    let mut ranked: Vec<(String, f32)> = scored.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked.truncate(limit);
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::content::{ContentAdapter, FragmentInput};
+    use crate::adapter::traits::{Adapter, AdapterInput};
+    use crate::adapter::{EngineSink, FrameworkContext};
+    use std::sync::{Arc, Mutex};
+
+    async fn ingest(fragments: Vec<(&str, Vec<&str>)>) -> (Context, Vec<GraphEvent>) {
+        let ctx = Arc::new(Mutex::new(Context::new("test")));
+        let sink = EngineSink::new(ctx.clone()).with_framework_context(FrameworkContext {
+            adapter_id: "manual-fragment".to_string(),
+            context_id: "test".to_string(),
+            input_summary: None,
+        });
+        let adapter = ContentAdapter::new("manual-fragment");
+        for (text, tags) in fragments {
+            let input = AdapterInput::new(
+                "content",
+                FragmentInput::new(text, tags.into_iter().map(String::from).collect()),
+                "test",
+            );
+            adapter.process(&input, &sink).await.unwrap();
+        }
+        let events = sink.drain_events();
+        let snapshot = ctx.lock().unwrap().clone();
+        (snapshot, events)
+    }
+
+    // === Scenario: RAKE ranks multi-word content phrases first ===
+    #[test]
+    fn extract_keywords_ranks_phrases() {
+        let keywords = extract_keywords(
+            "Compatibility of systems of linear constraints over the set of natural numbers.",
+            3,
+            3,
+        );
+        let phrases: Vec<&str> = keywords.iter().map(|(p, _)| p.as_str()).collect();
+        assert_eq!(phrases, vec!["linear constraints", "natural numbers", "compatibility"]);
+    }
+
+    #[test]
+    fn extract_keywords_skips_short_and_numeric_words() {
+        let keywords = extract_keywords("In 2024 we go to it.", 3, 5);
+        assert!(keywords.is_empty(), "got {:?}", keywords);
+    }
+
+    // === Scenario: Untagged fragment gets low-confidence keyword concepts ===
+    #[tokio::test]
+    async fn untagged_fragment_receives_keyword_concepts() {
+        let (ctx, events) = ingest(vec![("Fed the sourdough starter with rye flour at dawn.", vec![])]).await;
+        let enrichment = KeywordExtractionEnrichment::new();
+
+        let emission = enrichment.enrich(&events, &ctx).expect("keywords proposed");
+
+        assert!(emission
+            .nodes
+            .iter()
+            .any(|n| n.node.id == NodeId::from_string("concept:sourdough starter")));
+        assert!(!emission.edges.is_empty());
+        assert!(emission.edges.iter().all(|e| {
+            e.edge.relationship == "tagged_with"
+                && e.edge.combined_weight > 0.0
+                && e.edge.combined_weight <= 1.0
+        }));
+        assert_eq!(enrichment.confidence(), Some(0.3));
+    }
+
+    // === Scenario: Human tags outweigh keyword proposals after normalization ===
+    #[tokio::test]
+    async fn human_tags_dominate_keyword_proposals() {
+        use crate::adapter::enrichment::{run_enrichment_loop, EnrichmentRegistry};
+        use crate::graph::{ContextId, PlexusEngine};
+
+        let engine = Arc::new(PlexusEngine::new());
+        let ctx_id = ContextId::from("bakery");
+        engine.upsert_context(Context::with_id(ctx_id.clone(), "bakery")).unwrap();
+        let sink = EngineSink::for_engine(engine.clone(), ctx_id.clone()).with_framework_context(FrameworkContext {
+            adapter_id: "manual-fragment".to_string(),
+            context_id: ctx_id.to_string(),
+            input_summary: None,
+        });
+        let adapter = ContentAdapter::new("manual-fragment");
+        let tagged = FragmentInput::new("Notes on feeding.", vec!["sourdough starter".to_string()]);
+        let untagged = FragmentInput::new("Fed the sourdough starter at dawn.", vec![]);
+        for fragment in [tagged, untagged] {
+            adapter.process(&AdapterInput::new("content", fragment, ctx_id.as_str()), &sink).await.unwrap();
+        }
+        let events = sink.drain_events();
+
+        let registry = EnrichmentRegistry::new(vec![Arc::new(KeywordExtractionEnrichment::new()) as Arc<dyn Enrichment>]);
+        run_enrichment_loop(&engine, &ctx_id, &registry, &events).unwrap();
+
+        let ctx = engine.get_context(&ctx_id).unwrap();
+        let concept = NodeId::from_string("concept:sourdough starter");
+        let weights: Vec<(bool, f32)> = ctx
+            .edges()
+            .filter(|e| e.target == concept && e.relationship == "tagged_with")
+            .map(|e| (e.contributions.contains_key("keyword_extraction:tagged_with"), e.combined_weight))
+            .collect();
+        let human = weights.iter().find(|(keyword, _)| !keyword).expect("human tag edge").1;
+        let keyword = weights.iter().find(|(keyword, _)| *keyword).expect("keyword edge").1;
+        assert!((keyword - 0.3).abs() < 1e-6, "the keyword's best phrase counts at its confidence, got {keyword}");
+        assert!(human > keyword);
+    }
+
+    // === Scenario: Human-tagged fragments are left alone ===
+    #[tokio::test]
+    async fn tagged_fragment_is_skipped() {
+        let (ctx, events) = ingest(vec![("Fed the sourdough starter with rye flour at dawn.", vec!["baking"])]).await;
+        assert!(KeywordExtractionEnrichment::new().enrich(&events, &ctx).is_none());
+    }
+
+    // === Scenario: Re-running after commit is quiescent ===
+    #[tokio::test]
+    async fn enrichment_is_idempotent_after_commit() {
+        let (ctx, events) = ingest(vec![("Fed the sourdough starter with rye flour at dawn.", vec![])]).await;
+        let enrichment = KeywordExtractionEnrichment::new();
+        let emission = enrichment.enrich(&events, &ctx).unwrap();
+
+        let shared = Arc::new(Mutex::new(ctx));
+        let sink = EngineSink::new(shared.clone()).with_framework_context(FrameworkContext {
+            adapter_id: enrichment.id().to_string(),
+            context_id: "test".to_string(),
+            input_summary: None,
+        });
+        crate::adapter::AdapterSink::emit(&sink, emission).await.unwrap();
+
+        let ctx = shared.lock().unwrap().clone();
+        assert!(enrichment.enrich(&events, &ctx).is_none());
+        let keyword_edge = ctx
+            .edges()
+            .find(|e| e.contributions.contains_key(enrichment.id()))
+            .expect("keyword edge committed");
+        assert_eq!(keyword_edge.source_dimension, dimension::STRUCTURE);
+    }
+}
@@ -3,9 +3,26 @@
 //! Four core enrichments define what kind of knowledge graph engine Plexus is:
 //! CoOccurrenceEnrichment, DiscoveryGapEnrichment,
 //! TemporalProximityEnrichment, EmbeddingSimilarityEnrichment.
+//! KeywordExtractionEnrichment is opt-in: a non-LLM source of concepts
+//! for untagged fragments. SummaryEnrichment (also opt-in) maintains
+//! per-concept and per-chain rollup nodes. RuleEnrichment executes a
+//! declared graph pattern, one enrichment per rule. ScheduleWindowEnrichment
+//! (opt-in) links fragments to the calendar items they were written during.
+//! CitationEnrichment (opt-in) turns DOIs, arXiv IDs, and author-year
+//! references in text into `cites` edges to paper nodes. GeocodingEnrichment
+//! (opt-in) locates place nodes through a pluggable `Geocoder`.
+//! NearDuplicateEnrichment (opt-in) links near-identical fragments with
+//! `duplicate_of` and announces them as outbound events.
 
+pub mod citation;
 pub mod cooccurrence;
 pub mod discovery_gap;
 pub mod embedding;
+pub mod geocode;
+pub mod keyword;
 pub mod lens;
+pub mod near_duplicate;
+pub mod rule;
+pub mod schedule;
+pub mod summary;
 pub mod temporal_proximity;
@@ -0,0 +1,314 @@
+//! NearDuplicateEnrichment — flags near-identical fragments
+//!
+//! Each new fragment's MinHash signature over word shingles is compared
+//! with every other fragment's in the context; pairs whose estimated
+//! Jaccard similarity reaches the threshold get a `duplicate_of` edge from
+//! the newer fragment to the older, weighted by the similarity. Each new
+//! pair is also announced as a `near_duplicate_detected` outbound event,
+//! so consumers can prompt the user to merge or discard one of them.
+//!
+//! Idempotent: pairs already linked (either way) are skipped.
+
+use crate::adapter::enrichment::Enrichment;
+use crate::adapter::types::{AnnotatedEdge, Emission, OutboundEvent};
+use crate::graph::events::GraphEvent;
+use crate::graph::{Context, Edge, Node, NodeId, PropertyValue};
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
+
+/// Relationship linking a near-duplicate to the fragment it repeats.
+pub const DUPLICATE_OF: &str = "duplicate_of";
+
+/// Words per shingle.
+const SHINGLE_WORDS: usize = 3;
+
+/// Hash functions per signature; estimates are within about ±0.09.
+const SIGNATURE_LEN: usize = 128;
+
+/// A MinHash signature of a text's word shingles.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinHash(Vec<u64>);
+
+impl MinHash {
+    /// The signature of `text`'s lowercased word shingles; `None` for
+    /// text without words. Texts shorter than a shingle are one shingle.
+    pub fn of(text: &str) -> Option<Self> {
+        let words: Vec<String> = text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        if words.is_empty() {
+            return None;
+        }
+        let shingles: HashSet<u64> = words.windows(SHINGLE_WORDS.min(words.len())).map(|w| fnv1a(&w.join(" "))).collect();
+        let signature = (0..SIGNATURE_LEN as u64)
+            .map(|seed| shingles.iter().map(|h| splitmix64(h ^ splitmix64(seed))).min().unwrap_or(u64::MAX))
+            .collect();
+        Some(Self(signature))
+    }
+
+    /// Estimated Jaccard similarity of the two shingle sets.
+    pub fn similarity(&self, other: &MinHash) -> f64 {
+        let equal = self.0.iter().zip(&other.0).filter(|(a, b)| a == b).count();
+        equal as f64 / SIGNATURE_LEN as f64
+    }
+}
+
+fn fnv1a(s: &str) -> u64 {
+    s.bytes().fold(0xcbf2_9ce4_8422_2325, |h, b| (h ^ b as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Enrichment linking near-identical fragments with `duplicate_of`.
+pub struct NearDuplicateEnrichment {
+    threshold: f64,
+    node_types: Vec<String>,
+    text_property: String,
+    id: String,
+    /// (context name, node) → (text hash, signature)
+    signatures: RwLock<HashMap<(String, NodeId), (u64, MinHash)>>,
+}
+
+impl Default for NearDuplicateEnrichment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NearDuplicateEnrichment {
+    /// Compare fragments' `text`, flagging pairs at 0.8 similarity or more.
+    pub fn new() -> Self {
+        Self {
+            threshold: 0.8,
+            node_types: vec!["fragment".to_string()],
+            text_property: "text".to_string(),
+            id: format!("near_duplicate:{}", DUPLICATE_OF),
+            signatures: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Minimum estimated Jaccard similarity to flag a pair.
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    pub fn with_node_types(mut self, node_types: Vec<String>) -> Self {
+        self.node_types = node_types;
+        self
+    }
+
+    fn text<'a>(&self, node: &'a Node) -> Option<&'a str> {
+        if !self.node_types.iter().any(|t| t == &node.node_type) {
+            return None;
+        }
+        node.properties.get(&self.text_property).and_then(PropertyValue::as_str)
+    }
+
+    /// `node`'s signature, cached until its text changes.
+    fn signature(&self, context: &Context, node: &Node, text: &str) -> Option<MinHash> {
+        let key = (context.name.clone(), node.id.clone());
+        let hash = fnv1a(text);
+        if let Some((cached, signature)) = self.signatures.read().unwrap().get(&key) {
+            if *cached == hash {
+                return Some(signature.clone());
+            }
+        }
+        let signature = MinHash::of(text)?;
+        self.signatures.write().unwrap().insert(key, (hash, signature.clone()));
+        Some(signature)
+    }
+}
+
+/// Ordering key for which of a pair is newer: `created_at`, then ID.
+fn age(node: &Node) -> (Option<chrono::DateTime<chrono::Utc>>, &str) {
+    (node.properties.get("created_at").and_then(PropertyValue::as_datetime), node.id.as_str())
+}
+
+impl Enrichment for NearDuplicateEnrichment {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn enrich(&self, events: &[GraphEvent], context: &Context) -> Option<Emission> {
+        let added: HashSet<&NodeId> = events
+            .iter()
+            .filter_map(|e| match e {
+                GraphEvent::NodesAdded { node_ids, .. } => Some(node_ids),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        let new: Vec<(&Node, MinHash)> = added
+            .iter()
+            .filter_map(|id| context.get_node(id))
+            .filter_map(|node| Some((node, self.signature(context, node, self.text(node)?)?)))
+            .collect();
+        if new.is_empty() {
+            return None;
+        }
+        let others: Vec<(&Node, MinHash)> = context
+            .nodes()
+            .filter_map(|node| Some((node, self.signature(context, node, self.text(node)?)?)))
+            .collect();
+        let linked: HashSet<(&NodeId, &NodeId)> = context
+            .edges()
+            .filter(|e| e.relationship == DUPLICATE_OF)
+            .flat_map(|e| [(&e.source, &e.target), (&e.target, &e.source)])
+            .collect();
+
+        let mut emission = Emission::new();
+        for (node, signature) in &new {
+            for (other, other_signature) in &others {
+                // A pair of new fragments is compared once, from the newer
+                if other.id == node.id || (added.contains(&other.id) && age(other) > age(node)) {
+                    continue;
+                }
+                if linked.contains(&(&node.id, &other.id)) {
+                    continue;
+                }
+                let similarity = signature.similarity(other_signature);
+                if similarity < self.threshold {
+                    continue;
+                }
+                let (newer, older) = if age(node) >= age(other) { (node, other) } else { (other, node) };
+                let mut edge = Edge::new_cross_dimensional(
+                    newer.id.clone(),
+                    newer.dimension.clone(),
+                    older.id.clone(),
+                    older.dimension.clone(),
+                    DUPLICATE_OF,
+                );
+                edge.combined_weight = similarity as f32;
+                emission = emission.with_edge(AnnotatedEdge::new(edge));
+            }
+        }
+
+        if emission.is_empty() {
+            None
+        } else {
+            Some(emission)
+        }
+    }
+
+    fn transform_events(&self, events: &[GraphEvent], context: &Context) -> Vec<OutboundEvent> {
+        let added: HashSet<_> = events
+            .iter()
+            .filter_map(|e| match e {
+                GraphEvent::EdgesAdded { edge_ids, adapter_id, .. } if *adapter_id == self.id => Some(edge_ids),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        context
+            .edges()
+            .filter(|e| e.relationship == DUPLICATE_OF && added.contains(&e.id))
+            .map(|e| OutboundEvent::new("near_duplicate_detected", format!("{} {} {}", e.source, DUPLICATE_OF, e.target)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{dimension, ContentType};
+
+    fn fragment(ctx: &mut Context, id: &str, text: &str, at: &str) {
+        let mut node = Node::new_in_dimension("fragment", ContentType::Document, dimension::STRUCTURE);
+        node.id = NodeId::from_string(id);
+        node.properties.insert("text".into(), PropertyValue::from(text));
+        node.properties.insert("created_at".into(), PropertyValue::from(at));
+        ctx.add_node(node);
+    }
+
+    fn added(ids: &[&str]) -> GraphEvent {
+        GraphEvent::NodesAdded {
+            node_ids: ids.iter().map(|id| NodeId::from_string(*id)).collect(),
+            adapter_id: "content".into(),
+            context_id: "notes".into(),
+        }
+    }
+
+    const NOTE: &str = "Federated averaging converges slowly when client data is not identically distributed, \
+        so we weight updates by sample count and clip them before aggregation on the server.";
+
+    // === Scenario: MinHash estimates shingle overlap ===
+    #[test]
+    fn minhash_separates_edits_from_different_notes() {
+        let original = MinHash::of(NOTE).unwrap();
+        let edited = MinHash::of(&NOTE.replace("slowly", "very slowly")).unwrap();
+        let different = MinHash::of("Sourdough needs a warm kitchen and a patient baker.").unwrap();
+        assert_eq!(original.similarity(&original), 1.0);
+        assert!(original.similarity(&edited) > 0.7, "{}", original.similarity(&edited));
+        assert!(original.similarity(&different) < 0.1);
+        assert!(MinHash::of(" -- ").is_none());
+        assert_eq!(MinHash::of("Hello, World"), MinHash::of("hello world"), "case and punctuation don't matter");
+    }
+
+    // === Scenario: A re-pasted note is flagged as a duplicate of the original ===
+    #[test]
+    fn newer_near_duplicate_links_to_older_and_is_announced() {
+        let mut ctx = Context::new("notes");
+        fragment(&mut ctx, "fragment:original", NOTE, "2026-03-01T09:00:00Z");
+        fragment(&mut ctx, "fragment:other", "Sourdough needs a warm kitchen.", "2026-03-02T09:00:00Z");
+        fragment(&mut ctx, "fragment:copy", &format!("{NOTE} (copied)"), "2026-03-05T09:00:00Z");
+        let enrichment = NearDuplicateEnrichment::new();
+
+        let emission = enrichment.enrich(&[added(&["fragment:copy"])], &ctx).expect("one duplicate");
+        assert_eq!(emission.edges.len(), 1);
+        let edge = &emission.edges[0].edge;
+        assert_eq!((edge.source.as_str(), edge.target.as_str()), ("fragment:copy", "fragment:original"));
+        assert!(edge.combined_weight >= 0.8);
+
+        // Both new in one batch: still one edge, from the newer
+        let batch = enrichment.enrich(&[added(&["fragment:original", "fragment:copy"])], &ctx).expect("one duplicate");
+        assert_eq!(batch.edges.len(), 1);
+        assert_eq!(batch.edges[0].edge.source.as_str(), "fragment:copy");
+
+        let committed = emission.edges[0].edge.clone();
+        ctx.add_edge(committed.clone());
+        assert!(enrichment.enrich(&[added(&["fragment:copy"])], &ctx).is_none(), "idempotent");
+
+        let events = [GraphEvent::EdgesAdded {
+            edge_ids: vec![committed.id.clone()],
+            adapter_id: enrichment.id().to_string(),
+            context_id: "notes".into(),
+        }];
+        let outbound = enrichment.transform_events(&events, &ctx);
+        assert_eq!(outbound.len(), 1);
+        assert_eq!(outbound[0].kind, "near_duplicate_detected");
+        assert_eq!(outbound[0].detail, "fragment:copy duplicate_of fragment:original");
+    }
+
+    // === Scenario: Ingest returns the near-duplicate outbound event ===
+    #[tokio::test]
+    async fn ingest_announces_near_duplicates() {
+        use crate::adapter::{FragmentInput, PipelineBuilder};
+        use crate::graph::PlexusEngine;
+        use std::sync::Arc;
+
+        let engine = Arc::new(PlexusEngine::new());
+        let ctx = Context::new("notes");
+        let ctx_id = ctx.id.to_string();
+        engine.upsert_context(ctx).unwrap();
+        let pipeline = PipelineBuilder::new(engine.clone())
+            .with_default_adapters()
+            .with_enrichment(Arc::new(NearDuplicateEnrichment::new()))
+            .build();
+
+        let first = pipeline.ingest(&ctx_id, "content", Box::new(FragmentInput::new(NOTE, vec![]))).await.unwrap();
+        assert!(first.iter().all(|e| e.kind != "near_duplicate_detected"));
+        let again = format!("{NOTE} Repeated from last week.");
+        let second = pipeline.ingest(&ctx_id, "content", Box::new(FragmentInput::new(&again, vec![]))).await.unwrap();
+        let flagged: Vec<_> = second.iter().filter(|e| e.kind == "near_duplicate_detected").collect();
+        assert_eq!(flagged.len(), 1, "{second:?}");
+        assert!(flagged[0].detail.contains(" duplicate_of fragment:"));
+    }
+}
@@ -0,0 +1,373 @@
+//! RuleEnrichment — declarative graph patterns that create edges
+//!
+//! A rule matches a path pattern and links its two ends, e.g.
+//! `(mark)-[references]->(concept)<-[references]-(mark')` where the marks
+//! sit in different chains ⇒ `mark -[suggests_reading]-> mark'`. This
+//! generalizes the hand-written bridging enrichments: the pattern, the
+//! endpoint condition, and the output relationship are all data.
+//!
+//! Each rule registers as its own enrichment with id `rule:{rule_id}`, so
+//! the contribution slot on every edge it creates credits the rule.
+//! Idempotent: an existing output edge between the ends is never re-emitted.
+
+use crate::adapter::enrichment::Enrichment;
+use crate::adapter::types::{AnnotatedEdge, Emission};
+use crate::graph::events::GraphEvent;
+use crate::graph::{Context, Edge, Node, NodeId, PropertyValue};
+use crate::query::Direction;
+use serde::Deserialize;
+use std::collections::HashSet;
+
+/// Constraints on a node in a pattern. Unset fields match anything.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct NodePattern {
+    #[serde(default)]
+    pub node_type: Option<String>,
+    #[serde(default)]
+    pub dimension: Option<String>,
+}
+
+impl NodePattern {
+    pub fn any() -> Self {
+        Self::default()
+    }
+
+    pub fn of_type(node_type: &str) -> Self {
+        Self {
+            node_type: Some(node_type.to_string()),
+            dimension: None,
+        }
+    }
+
+    fn matches(&self, node: &Node) -> bool {
+        self.node_type.as_ref().is_none_or(|t| node.node_type == *t)
+            && self.dimension.as_ref().is_none_or(|d| node.dimension == *d)
+    }
+}
+
+/// One hop of a pattern: follow `relationship` in `direction` to a node
+/// matching `node`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PatternStep {
+    pub relationship: String,
+    #[serde(default)]
+    pub direction: Direction,
+    #[serde(default)]
+    pub node: NodePattern,
+}
+
+/// A rule: a path pattern and the edge its matches create.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Rule {
+    pub id: String,
+    #[serde(default)]
+    pub start: NodePattern,
+    pub steps: Vec<PatternStep>,
+    /// Properties the two ends must both carry, with different values
+    /// (e.g. `chain_id` for "from a different chain").
+    #[serde(default)]
+    pub differ_on: Vec<String>,
+    /// Relationship of the created edge, from the start node to the end.
+    pub output_relationship: String,
+    /// Also create the reverse edge.
+    #[serde(default)]
+    pub symmetric: bool,
+    #[serde(default = "default_weight")]
+    pub weight: f32,
+}
+
+fn default_weight() -> f32 {
+    1.0
+}
+
+impl Rule {
+    pub fn new(id: &str, output_relationship: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            start: NodePattern::any(),
+            steps: Vec::new(),
+            differ_on: Vec::new(),
+            output_relationship: output_relationship.to_string(),
+            symmetric: false,
+            weight: default_weight(),
+        }
+    }
+
+    pub fn starting_at(mut self, node: NodePattern) -> Self {
+        self.start = node;
+        self
+    }
+
+    /// Append a hop to the pattern.
+    pub fn step(mut self, relationship: &str, direction: Direction, node: NodePattern) -> Self {
+        self.steps.push(PatternStep {
+            relationship: relationship.to_string(),
+            direction,
+            node,
+        });
+        self
+    }
+
+    /// Require the ends to differ on `property`.
+    pub fn differing_on(mut self, property: &str) -> Self {
+        self.differ_on.push(property.to_string());
+        self
+    }
+
+    pub fn symmetric(mut self) -> Self {
+        self.symmetric = true;
+        self
+    }
+
+    pub fn with_weight(mut self, weight: f32) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Ends of every match of the pattern, start first. Each pair appears
+    /// once however many paths connect it; paths never revisit a node.
+    pub fn matches(&self, context: &Context) -> Vec<(NodeId, NodeId)> {
+        let mut seen = HashSet::new();
+        let mut pairs = Vec::new();
+        for start in context.nodes().filter(|n| self.start.matches(n)) {
+            let mut path = vec![&start.id];
+            self.extend(context, &mut path, &mut |end| {
+                if seen.insert((start.id.clone(), end.clone())) {
+                    pairs.push((start.id.clone(), end.clone()));
+                }
+            });
+        }
+        pairs.retain(|(start, end)| start != end && self.ends_differ(context, start, end));
+        pairs
+    }
+
+    fn extend<'a>(
+        &self,
+        context: &'a Context,
+        path: &mut Vec<&'a NodeId>,
+        on_match: &mut impl FnMut(&NodeId),
+    ) {
+        let Some(step) = self.steps.get(path.len() - 1) else {
+            on_match(path[path.len() - 1]);
+            return;
+        };
+        let current = path[path.len() - 1];
+        for edge in context.edges().filter(|e| e.relationship == step.relationship) {
+            let next = match step.direction {
+                Direction::Outgoing if edge.source == *current => &edge.target,
+                Direction::Incoming if edge.target == *current => &edge.source,
+                Direction::Both if edge.source == *current => &edge.target,
+                Direction::Both if edge.target == *current => &edge.source,
+                _ => continue,
+            };
+            if path.contains(&next) || !context.get_node(next).is_some_and(|n| step.node.matches(n)) {
+                continue;
+            }
+            path.push(next);
+            self.extend(context, path, on_match);
+            path.pop();
+        }
+    }
+
+    fn ends_differ(&self, context: &Context, start: &NodeId, end: &NodeId) -> bool {
+        let (Some(a), Some(b)) = (context.get_node(start), context.get_node(end)) else {
+            return false;
+        };
+        self.differ_on.iter().all(|key| {
+            match (a.properties.get(key), b.properties.get(key)) {
+                (Some(x), Some(y)) => x != y,
+                _ => false,
+            }
+        })
+    }
+}
+
+/// Enrichment executing one `Rule`.
+pub struct RuleEnrichment {
+    rule: Rule,
+    id: String,
+}
+
+impl RuleEnrichment {
+    pub fn new(rule: Rule) -> Self {
+        Self {
+            id: format!("rule:{}", rule.id),
+            rule,
+        }
+    }
+
+    pub fn rule(&self) -> &Rule {
+        &self.rule
+    }
+
+    fn output_edge(&self, context: &Context, source: &NodeId, target: &NodeId) -> Edge {
+        let dim = |id: &NodeId| {
+            context
+                .get_node(id)
+                .map(|n| n.dimension.clone())
+                .unwrap_or_default()
+        };
+        let (source_dim, target_dim) = (dim(source), dim(target));
+        let mut edge = if source_dim == target_dim {
+            Edge::new_in_dimension(source.clone(), target.clone(), &self.rule.output_relationship, &source_dim)
+        } else {
+            Edge::new_cross_dimensional(
+                source.clone(),
+                source_dim,
+                target.clone(),
+                target_dim,
+                &self.rule.output_relationship,
+            )
+        };
+        edge.combined_weight = self.rule.weight;
+        edge.properties
+            .insert("rule".to_string(), PropertyValue::String(self.rule.id.clone()));
+        edge
+    }
+}
+
+impl Enrichment for RuleEnrichment {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn enrich(&self, events: &[GraphEvent], context: &Context) -> Option<Emission> {
+        let structural_change = events.iter().any(|e| {
+            matches!(e, GraphEvent::NodesAdded { .. } | GraphEvent::EdgesAdded { .. })
+        });
+        if !structural_change || self.rule.steps.is_empty() {
+            return None;
+        }
+
+        let exists = |source: &NodeId, target: &NodeId| {
+            context.edges().any(|e| {
+                e.source == *source
+                    && e.target == *target
+                    && e.relationship == self.rule.output_relationship
+            })
+        };
+
+        let mut emitted: HashSet<(NodeId, NodeId)> = HashSet::new();
+        let mut emission = Emission::new();
+        for (start, end) in self.rule.matches(context) {
+            let mut directed = vec![(start.clone(), end.clone())];
+            if self.rule.symmetric {
+                directed.push((end, start));
+            }
+            for (source, target) in directed {
+                if exists(&source, &target) || !emitted.insert((source.clone(), target.clone())) {
+                    continue;
+                }
+                let edge = self.output_edge(context, &source, &target);
+                emission = emission.with_edge(AnnotatedEdge::new(edge));
+            }
+        }
+
+        if emission.is_empty() {
+            None
+        } else {
+            Some(emission)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{dimension, ContentType, EdgeId};
+
+    fn node(ctx: &mut Context, id: &str, node_type: &str, chain: Option<&str>) -> NodeId {
+        let mut n = Node::new_in_dimension(node_type, ContentType::Provenance, dimension::PROVENANCE);
+        n.id = NodeId::from_string(id);
+        if let Some(chain) = chain {
+            n.properties.insert("chain_id".into(), PropertyValue::from(chain));
+        }
+        ctx.add_node(n)
+    }
+
+    fn edges_added() -> GraphEvent {
+        GraphEvent::EdgesAdded {
+            edge_ids: vec![EdgeId::from_string("e1")],
+            adapter_id: "test".to_string(),
+            context_id: "test".to_string(),
+        }
+    }
+
+    fn suggests_reading() -> Rule {
+        Rule::new("cross_chain_reading", "suggests_reading")
+            .starting_at(NodePattern::of_type("mark"))
+            .step("references", Direction::Outgoing, NodePattern::of_type("concept"))
+            .step("references", Direction::Incoming, NodePattern::of_type("mark"))
+            .differing_on("chain_id")
+    }
+
+    fn reading_graph() -> Context {
+        let mut ctx = Context::new("test");
+        let concept = node(&mut ctx, "concept:rye", "concept", None);
+        for (mark, chain) in [("mark:a", "chain:1"), ("mark:b", "chain:2"), ("mark:c", "chain:1")] {
+            let id = node(&mut ctx, mark, "mark", Some(chain));
+            ctx.add_edge(Edge::new(id, concept.clone(), "references"));
+        }
+        ctx
+    }
+
+    // === Scenario: A two-hop pattern links marks across chains ===
+    #[test]
+    fn rule_links_marks_referencing_a_concept_from_different_chains() {
+        let ctx = reading_graph();
+        let enrichment = RuleEnrichment::new(suggests_reading());
+
+        let emission = enrichment.enrich(&[edges_added()], &ctx).expect("rule fires");
+
+        let mut pairs: Vec<(String, String)> = emission
+            .edges
+            .iter()
+            .map(|ae| (ae.edge.source.to_string(), ae.edge.target.to_string()))
+            .collect();
+        pairs.sort();
+        // a and c share chain:1, so only cross-chain pairs appear
+        assert_eq!(
+            pairs,
+            vec![
+                ("mark:a".to_string(), "mark:b".to_string()),
+                ("mark:b".to_string(), "mark:a".to_string()),
+                ("mark:b".to_string(), "mark:c".to_string()),
+                ("mark:c".to_string(), "mark:b".to_string()),
+            ]
+        );
+        let edge = &emission.edges[0].edge;
+        assert_eq!(edge.relationship, "suggests_reading");
+        assert_eq!(edge.properties.get("rule"), Some(&PropertyValue::from("cross_chain_reading")));
+        assert_eq!(enrichment.id(), "rule:cross_chain_reading");
+    }
+
+    // === Scenario: Existing output edges make the rule quiescent ===
+    #[test]
+    fn rule_skips_existing_output_edges() {
+        let mut ctx = reading_graph();
+        let enrichment = RuleEnrichment::new(suggests_reading().symmetric());
+        let emission = enrichment.enrich(&[edges_added()], &ctx).unwrap();
+        assert_eq!(emission.edges.len(), 4, "symmetric output doesn't duplicate matched pairs");
+        for ae in emission.edges {
+            ctx.add_edge(ae.edge);
+        }
+
+        assert!(enrichment.enrich(&[edges_added()], &ctx).is_none());
+    }
+
+    // === Scenario: Rules parse from declarative config ===
+    #[test]
+    fn rule_deserializes_from_yaml() {
+        let yaml = r#"
+id: cross_chain_reading
+start: { node_type: mark }
+steps:
+  - { relationship: references, direction: outgoing, node: { node_type: concept } }
+  - { relationship: references, direction: incoming, node: { node_type: mark } }
+differ_on: [chain_id]
+output_relationship: suggests_reading
+"#;
+        let rule: Rule = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(rule, suggests_reading());
+    }
+}
@@ -0,0 +1,147 @@
+//! ScheduleWindowEnrichment — what happened during scheduled time
+//!
+//! Links each node created inside a scheduled item's window (its
+//! `scheduled_for`..`scheduled_until`, as `CalendarAdapter` writes them)
+//! to the item with an `occurred_during` edge. A fragment written during
+//! a meeting then reaches the meeting, and the meeting's concepts, by
+//! traversal.
+//!
+//! Scoped to fragments, conversation turns, and transcript segments by
+//! default: concepts carry a re-emission `created_at` that says nothing
+//! about when work happened (ADR-039, issue #6). Idempotent: checks for
+//! existing edges before emitting.
+
+use super::temporal_proximity::extract_timestamp;
+use crate::adapter::enrichment::Enrichment;
+use crate::adapter::types::{AnnotatedEdge, Emission};
+use crate::graph::events::GraphEvent;
+use crate::graph::{Context, Edge};
+
+/// Relationship from a node to the item it was created during.
+pub const OCCURRED_DURING: &str = "occurred_during";
+
+/// Enrichment linking timestamped nodes to the scheduled items whose
+/// window contains them.
+pub struct ScheduleWindowEnrichment {
+    timestamp_property: String,
+    node_types: Vec<String>,
+    id: String,
+}
+
+impl ScheduleWindowEnrichment {
+    /// Link fragments, turns, and segments by their `created_at`.
+    pub fn new() -> Self {
+        let node_types = ["fragment", "turn", "segment"].map(String::from).to_vec();
+        Self::for_node_types("created_at", node_types)
+    }
+
+    pub fn for_node_types(timestamp_property: &str, node_types: Vec<String>) -> Self {
+        Self {
+            id: format!("schedule:{}:{}:{}", timestamp_property, node_types.join("+"), OCCURRED_DURING),
+            timestamp_property: timestamp_property.to_string(),
+            node_types,
+        }
+    }
+}
+
+impl Default for ScheduleWindowEnrichment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Enrichment for ScheduleWindowEnrichment {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn enrich(&self, events: &[GraphEvent], context: &Context) -> Option<Emission> {
+        if !events.iter().any(|e| matches!(e, GraphEvent::NodesAdded { .. })) {
+            return None;
+        }
+
+        let windows: Vec<_> = context
+            .nodes()
+            .filter_map(|n| {
+                let start = extract_timestamp(&n.properties, "scheduled_for")?;
+                let end = extract_timestamp(&n.properties, "scheduled_until").unwrap_or(start);
+                Some((n, start, end))
+            })
+            .collect();
+        if windows.is_empty() {
+            return None;
+        }
+
+        let mut emission = Emission::new();
+        for node in context.nodes().filter(|n| self.node_types.iter().any(|t| t == &n.node_type)) {
+            let Some(at) = extract_timestamp(&node.properties, &self.timestamp_property) else { continue };
+            for (item, start, end) in &windows {
+                if at < *start || at > *end {
+                    continue;
+                }
+                let exists = context
+                    .edges()
+                    .any(|e| e.source == node.id && e.target == item.id && e.relationship == OCCURRED_DURING);
+                if !exists {
+                    let edge = Edge::new_cross_dimensional(
+                        node.id.clone(),
+                        &node.dimension,
+                        item.id.clone(),
+                        &item.dimension,
+                        OCCURRED_DURING,
+                    );
+                    emission = emission.with_edge(AnnotatedEdge::new(edge));
+                }
+            }
+        }
+
+        if emission.is_empty() {
+            None
+        } else {
+            Some(emission)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{dimension, ContentType, Node, NodeId, PropertyValue};
+
+    fn node(id: &str, node_type: &str, properties: &[(&str, &str)]) -> Node {
+        let mut node = Node::new_in_dimension(node_type, ContentType::Document, dimension::STRUCTURE);
+        node.id = NodeId::from_string(id);
+        for (key, value) in properties {
+            node.properties.insert(key.to_string(), PropertyValue::from(*value));
+        }
+        node
+    }
+
+    // === Scenario: Fragments written during a meeting link to it ===
+    #[test]
+    fn fragments_inside_a_window_occur_during_it() {
+        let mut ctx = Context::new("schedule");
+        ctx.add_node(node(
+            "event:standup",
+            "event",
+            &[("scheduled_for", "2026-03-01T10:00:00Z"), ("scheduled_until", "2026-03-01T11:00:00Z")],
+        ));
+        ctx.add_node(node("fragment:during", "fragment", &[("created_at", "2026-03-01T10:20:00Z")]));
+        ctx.add_node(node("fragment:after", "fragment", &[("created_at", "2026-03-01T12:00:00Z")]));
+        ctx.add_node(node("concept:idea", "concept", &[("created_at", "2026-03-01T10:20:00Z")]));
+        let events = [GraphEvent::NodesAdded {
+            node_ids: vec![NodeId::from_string("fragment:during")],
+            adapter_id: "test".into(),
+            context_id: "schedule".into(),
+        }];
+
+        let enrichment = ScheduleWindowEnrichment::new();
+        let emission = enrichment.enrich(&events, &ctx).expect("one edge");
+        assert_eq!(emission.edges.len(), 1);
+        let edge = &emission.edges[0].edge;
+        assert_eq!((edge.source.as_str(), edge.target.as_str()), ("fragment:during", "event:standup"));
+
+        ctx.add_edge(edge.clone());
+        assert!(enrichment.enrich(&events, &ctx).is_none(), "quiescent once linked");
+    }
+}
@@ -0,0 +1,387 @@
+//! SummaryEnrichment — maintained rollup nodes per concept and per chain
+//!
+//! Consumers asking "what do we know about travel?" otherwise walk every
+//! `tagged_with` edge and count co-occurrences per request. This
+//! enrichment keeps a pre-digested summary node for each subject and
+//! refreshes it from graph events:
+//!
+//! - `concept-summary:{label}` — fragment count, top co-occurring
+//!   concepts, representative fragments (strongest `tagged_with` edges)
+//! - `chain-summary:{chain_id}` — mark count, files, top mark tags
+//!
+//! Incremental: additions refresh only the subjects they touch; removals
+//! (whose endpoints are no longer known) refresh every summary and drop
+//! summaries whose subject is gone. Idempotent: a summary is re-emitted
+//! only when its content changed, so the enrichment loop reaches quiescence.
+
+use crate::adapter::enrichment::Enrichment;
+use crate::adapter::types::Emission;
+use crate::graph::events::GraphEvent;
+use crate::graph::{ContentType, Context, EdgeId, Node, NodeId, PropertyValue};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+/// Dimension holding summary nodes, kept apart from the content they digest.
+pub const SUMMARY_DIMENSION: &str = "summary";
+
+/// Node type of per-concept summaries.
+pub const CONCEPT_SUMMARY_TYPE: &str = "concept_summary";
+
+/// Node type of per-chain summaries.
+pub const CHAIN_SUMMARY_TYPE: &str = "chain_summary";
+
+/// Enrichment that maintains concept and chain summary nodes.
+pub struct SummaryEnrichment {
+    top_n: usize,
+}
+
+impl Default for SummaryEnrichment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SummaryEnrichment {
+    pub fn new() -> Self {
+        Self { top_n: 5 }
+    }
+
+    /// Entries kept in each top-N list (co-occurring concepts,
+    /// representative fragments, files, tags).
+    pub fn with_top_n(mut self, top_n: usize) -> Self {
+        self.top_n = top_n;
+        self
+    }
+}
+
+/// Summary node ID for a concept (`concept:travel` → `concept-summary:travel`).
+pub fn concept_summary_id(concept_id: &NodeId) -> NodeId {
+    let label = concept_id.as_str().strip_prefix("concept:").unwrap_or(concept_id.as_str());
+    NodeId::from_string(format!("concept-summary:{}", label))
+}
+
+/// Summary node ID for a chain.
+pub fn chain_summary_id(chain_id: &NodeId) -> NodeId {
+    NodeId::from_string(format!("chain-summary:{}", chain_id))
+}
+
+fn is_summary(node: &Node) -> bool {
+    node.node_type == CONCEPT_SUMMARY_TYPE || node.node_type == CHAIN_SUMMARY_TYPE
+}
+
+impl Enrichment for SummaryEnrichment {
+    fn id(&self) -> &str {
+        "summary"
+    }
+
+    fn enrich(&self, events: &[GraphEvent], context: &Context) -> Option<Emission> {
+        let mut touched: HashSet<NodeId> = HashSet::new();
+        let mut added_edges: HashSet<&EdgeId> = HashSet::new();
+        let mut full_pass = false;
+        for event in events {
+            match event {
+                GraphEvent::NodesAdded { node_ids, .. } => touched.extend(node_ids.iter().cloned()),
+                GraphEvent::EdgesAdded { edge_ids, .. } => added_edges.extend(edge_ids),
+                GraphEvent::NodesRemoved { .. } | GraphEvent::EdgesRemoved { .. } => full_pass = true,
+                _ => {}
+            }
+        }
+        if !added_edges.is_empty() {
+            for edge in context.edges().filter(|e| added_edges.contains(&e.id)) {
+                touched.insert(edge.source.clone());
+                touched.insert(edge.target.clone());
+            }
+        }
+        if touched.is_empty() && !full_pass {
+            return None;
+        }
+
+        let concepts = self.affected_concepts(context, &touched, full_pass);
+        let chains = self.affected_chains(context, &touched, full_pass);
+
+        let mut emission = Emission::new();
+        for concept in &concepts {
+            let summary = self.concept_summary(context, concept);
+            if changed(context, &summary) {
+                emission = emission.with_node(summary);
+            }
+        }
+        for chain in &chains {
+            let summary = self.chain_summary(context, chain);
+            if changed(context, &summary) {
+                emission = emission.with_node(summary);
+            }
+        }
+
+        if full_pass {
+            for orphan in context.nodes().filter(|n| is_summary(n)) {
+                let subject_exists = match orphan.properties.get("subject") {
+                    Some(PropertyValue::String(subject)) => {
+                        context.get_node(&NodeId::from_string(subject.as_str())).is_some()
+                    }
+                    _ => false,
+                };
+                if !subject_exists {
+                    emission = emission.with_removal(orphan.id.clone());
+                }
+            }
+        }
+
+        if emission.is_empty() {
+            None
+        } else {
+            Some(emission)
+        }
+    }
+}
+
+impl SummaryEnrichment {
+    /// Concepts whose summaries may have changed: touched concepts plus
+    /// every concept tagged on a touched source (its co-occurrence moved).
+    fn affected_concepts(&self, context: &Context, touched: &HashSet<NodeId>, all: bool) -> HashSet<NodeId> {
+        let is_concept = |id: &NodeId| context.get_node(id).is_some_and(|n| n.node_type == "concept");
+        if all {
+            return context
+                .nodes()
+                .filter(|n| n.node_type == "concept")
+                .map(|n| n.id.clone())
+                .collect();
+        }
+        let mut concepts: HashSet<NodeId> = touched.iter().filter(|id| is_concept(id)).cloned().collect();
+        for edge in context.edges().filter(|e| e.relationship == "tagged_with") {
+            if touched.contains(&edge.source) && is_concept(&edge.target) {
+                concepts.insert(edge.target.clone());
+            }
+        }
+        concepts
+    }
+
+    /// Chains whose summaries may have changed: touched chains plus chains
+    /// containing a touched mark.
+    fn affected_chains(&self, context: &Context, touched: &HashSet<NodeId>, all: bool) -> HashSet<NodeId> {
+        let is_chain = |id: &NodeId| context.get_node(id).is_some_and(|n| n.node_type == "chain");
+        if all {
+            return context
+                .nodes()
+                .filter(|n| n.node_type == "chain")
+                .map(|n| n.id.clone())
+                .collect();
+        }
+        let mut chains: HashSet<NodeId> = touched.iter().filter(|id| is_chain(id)).cloned().collect();
+        for edge in context.edges().filter(|e| e.relationship == "contains") {
+            if touched.contains(&edge.target) && is_chain(&edge.source) {
+                chains.insert(edge.source.clone());
+            }
+        }
+        chains
+    }
+
+    fn concept_summary(&self, context: &Context, concept: &NodeId) -> Node {
+        // Sources tagged with this concept, strongest first
+        let mut sources: Vec<(&NodeId, f32)> = context
+            .edges()
+            .filter(|e| e.relationship == "tagged_with" && e.target == *concept)
+            .map(|e| (&e.source, e.combined_weight))
+            .collect();
+        sources.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.as_str().cmp(b.0.as_str())));
+        let mut source_set: HashSet<&NodeId> = HashSet::new();
+        sources.retain(|(id, _)| source_set.insert(*id));
+
+        let mut cooccurring: HashMap<&NodeId, i64> = HashMap::new();
+        for edge in context.edges().filter(|e| e.relationship == "tagged_with") {
+            if edge.target != *concept && source_set.contains(&edge.source) {
+                *cooccurring.entry(&edge.target).or_default() += 1;
+            }
+        }
+        let mut cooccurring: Vec<(&NodeId, i64)> = cooccurring.into_iter().collect();
+        cooccurring.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.as_str().cmp(b.0.as_str())));
+
+        let mut properties = HashMap::new();
+        properties.insert("subject".to_string(), PropertyValue::from(concept.as_str()));
+        properties.insert("fragment_count".to_string(), PropertyValue::Int(sources.len() as i64));
+        properties.insert(
+            "top_cooccurring".to_string(),
+            id_list(cooccurring.iter().map(|(id, _)| *id), self.top_n),
+        );
+        properties.insert(
+            "representative_fragments".to_string(),
+            id_list(sources.iter().map(|(id, _)| *id), self.top_n),
+        );
+        summary_node(concept_summary_id(concept), CONCEPT_SUMMARY_TYPE, properties)
+    }
+
+    fn chain_summary(&self, context: &Context, chain: &NodeId) -> Node {
+        let marks: Vec<&Node> = context
+            .edges()
+            .filter(|e| e.relationship == "contains" && e.source == *chain)
+            .filter_map(|e| context.get_node(&e.target))
+            .filter(|n| n.node_type == "mark")
+            .collect();
+
+        let files: BTreeSet<&str> = marks.iter().filter_map(|m| m.get_str("file")).collect();
+        let mut tag_counts: BTreeMap<String, i64> = BTreeMap::new();
+        for mark in &marks {
+            for tag in mark.tags() {
+                *tag_counts.entry(tag.to_string()).or_default() += 1;
+            }
+        }
+        let mut tags: Vec<(String, i64)> = tag_counts.into_iter().collect();
+        tags.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        let mut properties = HashMap::new();
+        properties.insert("subject".to_string(), PropertyValue::from(chain.as_str()));
+        properties.insert("mark_count".to_string(), PropertyValue::Int(marks.len() as i64));
+        properties.insert(
+            "files".to_string(),
+            PropertyValue::Array(files.into_iter().take(self.top_n).map(PropertyValue::from).collect()),
+        );
+        properties.insert(
+            "top_tags".to_string(),
+            PropertyValue::Array(tags.into_iter().take(self.top_n).map(|(t, _)| PropertyValue::from(t)).collect()),
+        );
+        summary_node(chain_summary_id(chain), CHAIN_SUMMARY_TYPE, properties)
+    }
+}
+
+fn id_list<'a>(ids: impl Iterator<Item = &'a NodeId>, limit: usize) -> PropertyValue {
+    PropertyValue::Array(ids.take(limit).map(|id| PropertyValue::from(id.as_str())).collect())
+}
+
+fn summary_node(id: NodeId, node_type: &str, properties: HashMap<String, PropertyValue>) -> Node {
+    let mut node = Node::new_in_dimension(node_type, ContentType::Document, SUMMARY_DIMENSION);
+    node.id = id;
+    node.properties = properties;
+    node
+}
+
+/// Whether `summary` differs from the stored summary node (or none exists).
+fn changed(context: &Context, summary: &Node) -> bool {
+    context
+        .get_node(&summary.id)
+        .is_none_or(|existing| existing.properties != summary.properties)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::content::{ContentAdapter, FragmentInput};
+    use crate::adapter::traits::{Adapter, AdapterInput};
+    use crate::adapter::{AdapterSink, EngineSink, FrameworkContext};
+    use std::sync::{Arc, Mutex};
+
+    fn sink_for(ctx: &Arc<Mutex<Context>>, adapter_id: &str) -> EngineSink {
+        EngineSink::new(ctx.clone()).with_framework_context(FrameworkContext {
+            adapter_id: adapter_id.to_string(),
+            context_id: "test".to_string(),
+            input_summary: None,
+        })
+    }
+
+    /// Ingest fragments, then run the enrichment to quiescence.
+    async fn ingest_and_summarize(ctx: &Arc<Mutex<Context>>, fragments: Vec<(&str, Vec<&str>)>) {
+        let sink = sink_for(ctx, "manual-fragment");
+        let adapter = ContentAdapter::new("manual-fragment");
+        for (text, tags) in fragments {
+            let input = AdapterInput::new(
+                "content",
+                FragmentInput::new(text, tags.into_iter().map(String::from).collect()),
+                "test",
+            );
+            adapter.process(&input, &sink).await.unwrap();
+        }
+        let mut events = sink.drain_events();
+        let enrichment = SummaryEnrichment::new();
+        let enrichment_sink = sink_for(ctx, "summary");
+        for _ in 0..5 {
+            let snapshot = ctx.lock().unwrap().clone();
+            let Some(emission) = enrichment.enrich(&events, &snapshot) else {
+                return;
+            };
+            enrichment_sink.emit(emission).await.unwrap();
+            events = enrichment_sink.drain_events();
+        }
+        panic!("summary enrichment did not reach quiescence");
+    }
+
+    fn strings(value: Option<&[PropertyValue]>) -> Vec<String> {
+        value
+            .map(|v| v.iter().filter_map(|p| p.as_str().map(String::from)).collect())
+            .unwrap_or_default()
+    }
+
+    // === Scenario: Concept summary aggregates counts and co-occurrence ===
+    #[tokio::test]
+    async fn concept_summary_rolls_up_fragments() {
+        let ctx = Arc::new(Mutex::new(Context::new("test")));
+        ingest_and_summarize(&ctx, vec![
+            ("Walked through Avignon", vec!["travel", "avignon"]),
+            ("Train to Paris", vec!["travel", "paris"]),
+            ("Avignon bridge at dusk", vec!["travel", "avignon"]),
+        ]).await;
+
+        let ctx = ctx.lock().unwrap();
+        let summary = ctx
+            .get_node(&NodeId::from_string("concept-summary:travel"))
+            .expect("travel summary");
+        assert_eq!(summary.dimension, SUMMARY_DIMENSION);
+        assert_eq!(summary.get_int("fragment_count"), Some(3));
+        assert_eq!(
+            strings(summary.get_array("top_cooccurring")),
+            vec!["concept:avignon", "concept:paris"]
+        );
+        assert_eq!(strings(summary.get_array("representative_fragments")).len(), 3);
+    }
+
+    // === Scenario: New fragment refreshes only the summaries it touches ===
+    #[tokio::test]
+    async fn summaries_refresh_incrementally() {
+        let ctx = Arc::new(Mutex::new(Context::new("test")));
+        ingest_and_summarize(&ctx, vec![("Walked through Avignon", vec!["travel", "avignon"])]).await;
+        ingest_and_summarize(&ctx, vec![("Paris trip", vec!["travel", "paris"])]).await;
+
+        let ctx = ctx.lock().unwrap();
+        let travel = ctx.get_node(&NodeId::from_string("concept-summary:travel")).unwrap();
+        assert_eq!(travel.get_int("fragment_count"), Some(2));
+        let avignon = ctx.get_node(&NodeId::from_string("concept-summary:avignon")).unwrap();
+        assert_eq!(avignon.get_int("fragment_count"), Some(1));
+        assert_eq!(strings(avignon.get_array("top_cooccurring")), vec!["concept:travel"]);
+    }
+
+    // === Scenario: Chain summary counts marks and tags ===
+    #[tokio::test]
+    async fn chain_summary_counts_marks() {
+        let ctx = Arc::new(Mutex::new(Context::new("test")));
+        ingest_and_summarize(&ctx, vec![
+            ("First note", vec!["travel"]),
+            ("Second note", vec!["travel", "food"]),
+        ]).await;
+
+        let ctx = ctx.lock().unwrap();
+        let summary = ctx
+            .nodes()
+            .find(|n| n.node_type == CHAIN_SUMMARY_TYPE)
+            .expect("chain summary");
+        assert_eq!(summary.get_int("mark_count"), Some(2));
+        assert_eq!(strings(summary.get_array("top_tags")), vec!["travel", "food"]);
+    }
+
+    // === Scenario: Removing a concept drops its summary ===
+    #[tokio::test]
+    async fn removed_subject_drops_summary() {
+        let ctx = Arc::new(Mutex::new(Context::new("test")));
+        ingest_and_summarize(&ctx, vec![("Walked through Avignon", vec!["travel", "avignon"])]).await;
+
+        let sink = sink_for(&ctx, "manual-fragment");
+        sink.emit(Emission::new().with_removal(NodeId::from_string("concept:avignon")))
+            .await
+            .unwrap();
+        let events = sink.drain_events();
+        let snapshot = ctx.lock().unwrap().clone();
+        let emission = SummaryEnrichment::new().enrich(&events, &snapshot).unwrap();
+
+        assert!(emission
+            .removals
+            .iter()
+            .any(|r| r.node_id == NodeId::from_string("concept-summary:avignon")));
+        assert!(emission.nodes.iter().any(|n| n.node.id == NodeId::from_string("concept-summary:travel")));
+    }
+}
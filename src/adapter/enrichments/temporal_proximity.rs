@@ -137,16 +137,18 @@ fn has_node_events(events: &[GraphEvent]) -> bool {
 /// Strings that fail RFC-3339 parsing are silently skipped (the pair is
 /// not considered by the enrichment — graceful-degradation contract per
 /// ADR-039 §"TemporalProximityEnrichment reads from properties").
-fn extract_timestamp(
+/// Timestamps are signed, so dates before 1970 order correctly.
+pub(crate) fn extract_timestamp(
     properties: &std::collections::HashMap<String, PropertyValue>,
     property_name: &str,
-) -> Option<u64> {
+) -> Option<i64> {
     match properties.get(property_name)? {
-        PropertyValue::Int(n) => Some(*n as u64),
-        PropertyValue::Float(n) => Some(*n as u64),
+        PropertyValue::Int(n) => Some(*n),
+        PropertyValue::Float(n) if n.is_finite() => Some(*n as i64),
+        PropertyValue::DateTime(dt) => Some(dt.timestamp_millis()),
         PropertyValue::String(s) => chrono::DateTime::parse_from_rfc3339(s)
             .ok()
-            .map(|dt| dt.timestamp_millis() as u64),
+            .map(|dt| dt.timestamp_millis()),
         _ => None,
     }
 }
@@ -386,6 +388,26 @@ mod tests {
         assert_eq!(emission.edges.len(), 2, "symmetric edge pair expected");
     }
 
+    // === Scenario: Timestamps either side of 1970 pair by their real distance ===
+
+    #[test]
+    fn pre_epoch_timestamps_pair_across_1970() {
+        let enrichment = TemporalProximityEnrichment::new("created_at", 500, "temporal_proximity");
+
+        let mut ctx = Context::new("test");
+        ctx.add_node(node_with_timestamp("node-a", "created_at", "1969-12-31T23:59:59.900Z"));
+        let mut node_b = node_with_timestamp("node-b", "created_at", "");
+        let at = chrono::DateTime::parse_from_rfc3339("1970-01-01T00:00:00.100Z").unwrap();
+        node_b.properties.insert("created_at".to_string(), PropertyValue::DateTime(at.with_timezone(&chrono::Utc)));
+        ctx.add_node(node_b);
+        ctx.add_node(node_with_timestamp("node-c", "created_at", "1969-06-01T00:00:00Z"));
+
+        let emission = enrichment
+            .enrich(&[nodes_added_event(vec!["node-a", "node-b", "node-c"])], &ctx)
+            .expect("200ms apart across the epoch");
+        assert_eq!(emission.edges.len(), 2, "only the close pair links");
+    }
+
     // === Scenario: ISO-8601 UTC strings are the authoritative format (ADR-039) ===
 
     #[test]
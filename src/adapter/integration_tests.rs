@@ -345,6 +345,47 @@ mod tests {
         let _: Vec<OutboundEvent> = outbound;
     }
 
+    // === Scenario: Simulated ingest previews without persisting ===
+    #[tokio::test]
+    async fn simulate_reports_would_be_changes_without_persisting() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = Arc::new(SqliteStore::open(dir.path().join("simulate.db")).unwrap());
+        let engine = Arc::new(PlexusEngine::with_store(store.clone()));
+        let ctx_id = ContextId::from("provence-research");
+        engine
+            .upsert_context(Context::with_id(ctx_id.clone(), "provence-research"))
+            .unwrap();
+
+        let registry = Arc::new(EnrichmentRegistry::new(vec![Arc::new(OneShotEdgeEnrichment::new(
+            "co-occurrence",
+            "concept:travel",
+            "concept:avignon",
+        )) as Arc<dyn Enrichment>]));
+        let pipeline = IngestPipeline::new(engine.clone()).with_enrichments(registry);
+        pipeline.register_adapter(Arc::new(EmittingAdapter::new("fragment-adapter", "fragment")));
+
+        let data: Box<dyn std::any::Any + Send + Sync> =
+            Box::new(vec!["travel".to_string(), "avignon".to_string()]);
+        let simulation = pipeline
+            .simulate("provence-research", "fragment", data)
+            .await
+            .unwrap();
+
+        // Would-be result covers the adapter and the enrichment round
+        assert_eq!(simulation.result.nodes_committed, 2);
+        assert_eq!(simulation.result.edges_committed, 1);
+        assert_eq!(simulation.enrichment_rounds, 1);
+        assert_eq!(simulation.diff.nodes_added.len(), 2);
+        assert_eq!(simulation.diff.edges_added[0].relationship, "may_be_related");
+        assert!(!simulation.outbound.is_empty());
+
+        // Nothing reached the live context or the store
+        assert_eq!(engine.get_context(&ctx_id).unwrap().node_count(), 0);
+        let reloaded = PlexusEngine::with_store(store);
+        reloaded.load_all().unwrap();
+        assert_eq!(reloaded.get_context(&ctx_id).unwrap().node_count(), 0);
+    }
+
     // === Scenario: Fan-out — multiple adapters matching same input_kind ===
     #[tokio::test]
     async fn ingest_fan_out_multiple_adapters() {
@@ -433,6 +474,12 @@ mod tests {
             .edges
             .iter()
             .any(|e| e.relationship == "may_be_related"), "enrichment fired via register_integration");
+
+        // Its output is recorded under the enrichment's ID
+        let filter = crate::storage::EmissionFilter::new().with_adapter("co-occurrence");
+        let recorded = engine.emissions(&ctx_id, &filter).unwrap();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].edge_ids.len(), 1);
     }
 
     // === Scenario: Enrichments from multiple integrations are deduplicated ===
@@ -2040,7 +2087,7 @@ mod tests {
             *concept_frequency.entry(tgt.clone()).or_default() += 1;
         }
         let mut hub_concepts: Vec<_> = concept_frequency.into_iter().collect();
-        hub_concepts.sort_by(|a, b| b.1.cmp(&a.1));
+        hub_concepts.sort_by_key(|c| std::cmp::Reverse(c.1));
 
         // ================================================================
         // Summary output
@@ -2891,6 +2938,141 @@ mod tests {
     // Feature: Layered Provenance (ADR-028)
     // ================================================================
 
+    // === Scenario: JSON payloads are validated against the adapter's input schema ===
+    #[tokio::test]
+    async fn json_payloads_breaking_the_input_schema_are_rejected() {
+        use crate::adapter::IngestPipeline;
+
+        let engine = Arc::new(PlexusEngine::new());
+        let ctx_id = engine.upsert_context(Context::new("test")).unwrap();
+        let pipeline = IngestPipeline::new(engine.clone());
+        pipeline.register_adapter(Arc::new(ContentAdapter::new("content")));
+        assert!(pipeline.input_schema("content").is_some());
+        assert!(pipeline.input_schema("fragment").is_none());
+
+        let bad = serde_json::json!({"tags": ["rye", 4], "line": -1});
+        let err = pipeline.ingest(ctx_id.as_str(), "content", Box::new(bad)).await.unwrap_err();
+        let AdapterError::Validation(ref violations) = err else { panic!("expected a validation error, got {err:?}") };
+        let paths: Vec<&str> = violations.iter().map(|v| v.path.as_str()).collect();
+        assert_eq!(paths, vec!["", "/line", "/tags/1"]);
+        assert!(err.to_string().contains("missing required field 'text'"), "{err}");
+        assert_eq!(engine.get_context(&ctx_id).unwrap().node_count(), 0, "nothing is processed");
+
+        let good = serde_json::json!({"text": "Rye sours fast.", "tags": ["rye"], "source": null});
+        pipeline.ingest(ctx_id.as_str(), "content", Box::new(good)).await.unwrap();
+        let typed = FragmentInput::new("Spelt is older.", vec!["spelt".into()]);
+        pipeline.ingest(ctx_id.as_str(), "content", Box::new(typed)).await.unwrap();
+        assert_eq!(engine.get_context(&ctx_id).unwrap().nodes().filter(|n| n.node_type == "fragment").count(), 2);
+    }
+
+    // === Scenario: Every JSON adapter declares the shapes its decoder accepts ===
+    #[test]
+    fn json_adapters_declare_schemas_matching_their_decoders() {
+        use crate::adapter::{
+            validate_input, CalendarAdapter, ConversationAdapter, ExtractionCoordinator, GraphImportAdapter,
+            PkmAdapter, TranscriptAdapter,
+        };
+        use serde_json::json;
+
+        let cases: Vec<(Arc<dyn Adapter>, serde_json::Value, serde_json::Value)> = vec![
+            (Arc::new(ConversationAdapter::new("c")), json!({"messages": [{"role": "ana", "content": "rye?"}]}), json!({"turns": "ana: rye?"})),
+            (Arc::new(TranscriptAdapter::new("t")), json!({"vtt": "WEBVTT", "title": "bake"}), json!({"segments": [], "title": 3})),
+            (Arc::new(PkmAdapter::new("p")), json!({"markdown_pages": {"Rye": "- sour"}}), json!({"source": "roam"})),
+            (Arc::new(CalendarAdapter::new("k")), json!({"tasks": [{"title": "feed starter"}]}), json!({"ics": ["BEGIN:VCALENDAR"]})),
+            (Arc::new(ExtractionCoordinator::new()), json!({"file_path": "notes/rye.md"}), json!({"path": "notes/rye.md"})),
+            (Arc::new(GraphImportAdapter::new("g")), json!({"neo4j": [], "mapping": {}}), json!({"arangodb": "docs"})),
+        ];
+        for (adapter, good, bad) in cases {
+            let kind = adapter.input_kind().to_string();
+            let schema = adapter.input_schema().unwrap_or_else(|| panic!("{kind} declares no schema"));
+            assert!(validate_input(&schema, &good).is_empty(), "{kind}: {:?}", validate_input(&schema, &good));
+            assert!(!validate_input(&schema, &bad).is_empty(), "{kind} accepts {bad}");
+            adapter.input_from_json(&good).unwrap_or_else(|e| panic!("{kind} rejects a valid payload: {e}"));
+        }
+
+        let content = ContentAdapter::new("content").input_schema().unwrap();
+        let violations = validate_input(&content, &json!({"text": "rye", "line": 4294967296u64}));
+        assert_eq!(violations.len(), 1, "line numbers are u32");
+    }
+
+    // === Scenario: JSON ingest decodes through the adapter before writing ===
+    #[tokio::test]
+    async fn ingest_json_decodes_payloads_with_the_adapters_constructor() {
+        use crate::adapter::conversation::ConversationAdapter;
+        use crate::adapter::IngestPipeline;
+
+        let engine = Arc::new(PlexusEngine::new());
+        let ctx_id = engine.upsert_context(Context::new("test")).unwrap();
+        let pipeline = IngestPipeline::new(engine.clone());
+        pipeline.register_adapter(Arc::new(ContentAdapter::new("content")));
+        pipeline.register_adapter(Arc::new(ConversationAdapter::new("conversation")));
+
+        let fragment = serde_json::json!({"text": "Rye sours fast.", "tags": ["rye"]});
+        let outbound = pipeline.ingest_json(ctx_id.as_str(), "content", fragment).await.unwrap();
+        assert!(!outbound.is_empty());
+        let ctx = engine.get_context(&ctx_id).unwrap();
+        assert!(ctx.get_node(&NodeId::from_string("concept:rye")).is_some());
+
+        let nodes_before = ctx.node_count();
+        let err = pipeline
+            .ingest_json(ctx_id.as_str(), "conversation", serde_json::json!({"title": "no turns"}))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("'turns'"), "{err}");
+        assert_eq!(engine.get_context(&ctx_id).unwrap().node_count(), nodes_before);
+
+        let unknown = pipeline.ingest_json(ctx_id.as_str(), "fax", serde_json::json!({})).await;
+        assert!(unknown.is_err(), "unrouted kinds still fail");
+    }
+
+    // === Scenario: Fanned-out JSON ingest decodes once per adapter ===
+    #[tokio::test]
+    async fn ingest_json_fan_out_decodes_for_each_adapter() {
+        use crate::adapter::IngestPipeline;
+
+        /// Reads only the tags, as a typed `Vec<String>`.
+        struct TagAdapter;
+
+        #[async_trait::async_trait]
+        impl Adapter for TagAdapter {
+            fn id(&self) -> &str {
+                "tags"
+            }
+            fn input_kind(&self) -> &str {
+                "content"
+            }
+            fn input_from_json(&self, json: &serde_json::Value) -> Result<Box<dyn std::any::Any + Send + Sync>, AdapterError> {
+                let tags: Vec<String> = serde_json::from_value(json["tags"].clone())
+                    .map_err(|e| AdapterError::Serialization(e.to_string()))?;
+                Ok(Box::new(tags))
+            }
+            async fn process(&self, input: &AdapterInput, sink: &dyn AdapterSink) -> Result<(), AdapterError> {
+                let tags = input.downcast_data::<Vec<String>>().ok_or(AdapterError::InvalidInput)?;
+                for tag in tags {
+                    sink.emit(Emission::new().with_node(node(&format!("tag:{tag}")))).await?;
+                }
+                Ok(())
+            }
+        }
+
+        let engine = Arc::new(PlexusEngine::new());
+        let ctx_id = engine.upsert_context(Context::new("test")).unwrap();
+        let pipeline = IngestPipeline::new(engine.clone());
+        pipeline.register_adapter(Arc::new(ContentAdapter::new("content")));
+        pipeline.register_adapter(Arc::new(TagAdapter));
+
+        let fragment = serde_json::json!({"text": "Rye sours fast.", "tags": ["rye"]});
+        pipeline.ingest_json(ctx_id.as_str(), "content", fragment).await.unwrap();
+        let ctx = engine.get_context(&ctx_id).unwrap();
+        assert!(ctx.get_node(&NodeId::from_string("tag:rye")).is_some(), "the typed adapter got its own decoding");
+        assert_eq!(ctx.nodes().filter(|n| n.node_type == "fragment").count(), 1);
+
+        let nodes_before = ctx.node_count();
+        let untagged = serde_json::json!({"text": "Spelt is older.", "tags": "spelt"});
+        assert!(pipeline.ingest_json(ctx_id.as_str(), "content", untagged).await.is_err());
+        assert_eq!(engine.get_context(&ctx_id).unwrap().node_count(), nodes_before, "any failed decoding writes nothing");
+    }
+
     // === Scenario: Location-specific provenance (Carrel annotation) ===
     #[tokio::test]
     async fn layered_provenance_location_specific() {
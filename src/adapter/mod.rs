@@ -9,14 +9,17 @@
 //! - pipeline/    — ingest pipeline, input routing
 //! - adapters/    — domain adapter implementations
 //! - enrichments/ — core enrichment implementations
-//! - types, traits, cancel — shared types at module root
+//! - sync         — two-way sync with external stores
+//! - types, traits, cancel, schema — shared types at module root
 
 mod cancel;
 mod enrichment;
 #[cfg(test)]
 mod integration_tests;
 mod pipeline;
+mod schema;
 mod sink;
+pub mod sync;
 mod traits;
 mod types;
 
@@ -29,43 +32,80 @@ pub use sink::{EngineSink, FrameworkContext, ProvenanceEntry};
 pub(crate) use enrichment::run_enrichment_loop;
 pub use enrichment::{Enrichment, EnrichmentRegistry};
 pub use crate::graph::events::GraphEvent;
-pub use pipeline::{classify_input, gather_persisted_specs, ClassifyError, IngestPipeline, PipelineBuilder};
+pub use pipeline::{
+    classify_input, gather_persisted_specs, ClassifyError, IngestPipeline, PipelineBuilder, ReplayEntry,
+    ReplayLog, ReplaySummary, Simulation,
+};
+pub use sync::{ConflictPolicy, ExternalStore, RemoteChange, RemoteChanges, SyncConflict, SyncReport, Synchronizer};
+pub use schema::{validate as validate_input, SchemaViolation};
 pub use traits::{Adapter, AdapterInput};
 pub use sink::{AdapterError, AdapterSink, EmitResult, Rejection, RejectionReason};
 pub use types::{
-    Annotation, AnnotatedEdge, AnnotatedNode, EdgeRemoval, Emission, OutboundEvent,
-    PropertyUpdate, Removal, chain_node, concept_node, file_node, mark_node, rfc3339_now,
+    Annotation, AnnotatedEdge, AnnotatedNode, DedupPolicy, EdgeRemoval, Emission, OutboundEvent,
+    PropertyUpdate, Removal, chain_node, concept_node, file_node, mark_node, place_node, rfc3339_now,
 };
 
 // Adapter submodule re-exports (preserve crate::adapter::<name>::* paths)
+pub use adapters::api_spec;
+pub use adapters::calendar;
+pub use adapters::chunking;
 pub use adapters::content;
+pub use adapters::conversation;
 pub use adapters::declarative;
 pub use adapters::extraction;
 pub use adapters::graph_analysis;
+pub use adapters::graph_import;
+pub use adapters::image;
+pub use adapters::links;
+pub use adapters::notebook;
+pub use adapters::pkm;
 pub use adapters::provenance_adapter;
 pub use adapters::semantic;
 pub use adapters::structural;
+pub use adapters::transcript;
 
 // Flat adapter type re-exports
+pub use api_spec::ApiSpecModule;
+pub use calendar::{CalendarAdapter, CalendarInput, ItemKind, ScheduledItem};
 pub use content::{ContentAdapter, FragmentInput, normalize_chain_name};
+pub use conversation::{ConversationAdapter, ConversationInput, Turn};
 pub use declarative::DeclarativeAdapter;
-pub use extraction::ExtractionCoordinator;
+pub use extraction::{files_needing_reanalysis, ExtractionCoordinator, FrontmatterMapping};
 pub use graph_analysis::{GraphAnalysisAdapter, run_analysis, export_graph_for_analysis};
-pub use structural::MarkdownStructureModule;
+pub use graph_import::{DumpEdge, DumpFormat, DumpNode, GraphImportAdapter, GraphImportInput, ImportMapping, NodeMapping};
+pub use image::ImageMetadataModule;
+pub use notebook::NotebookStructureModule;
+pub use pkm::{PkmAdapter, PkmBlock, PkmInput, PkmPage, edn_to_json};
+pub use structural::{AnalysisFailure, AnalysisFailurePolicy, MarkdownStructureModule};
 pub use provenance_adapter::{ProvenanceAdapter, ProvenanceInput};
+pub use transcript::{Segment, TranscriptAdapter, TranscriptInput};
 
 // Enrichment submodule re-exports (preserve crate::adapter::<name>::* paths)
+pub use enrichments::citation;
 pub use enrichments::cooccurrence;
 pub use enrichments::discovery_gap;
 pub use enrichments::embedding;
+pub use enrichments::geocode;
+pub use enrichments::keyword;
 pub use enrichments::lens;
+pub use enrichments::near_duplicate;
+pub use enrichments::rule;
+pub use enrichments::schedule;
+pub use enrichments::summary;
 pub use enrichments::temporal_proximity;
 
 // Flat enrichment type re-exports
+pub use citation::CitationEnrichment;
 pub use cooccurrence::CoOccurrenceEnrichment;
+pub use geocode::{Gazetteer, Geocoder, GeocodingEnrichment};
+pub use near_duplicate::{MinHash, NearDuplicateEnrichment};
+pub use keyword::KeywordExtractionEnrichment;
 pub use lens::LensEnrichment;
+pub use rule::{NodePattern, PatternStep, Rule, RuleEnrichment};
+pub use schedule::ScheduleWindowEnrichment;
+pub use summary::SummaryEnrichment;
 pub use discovery_gap::DiscoveryGapEnrichment;
-pub use embedding::{Embedder, EmbeddingError, EmbeddingSimilarityEnrichment, InMemoryVectorStore, VectorStore};
+pub use embedding::{Embedder, EmbeddingError, EmbeddingSimilarityEnrichment, InMemoryVectorStore, PersistedVectorStore, SimilaritySearch, VectorStore};
 #[cfg(feature = "embeddings")]
 pub use embedding::FastEmbedEmbedder;
 pub use temporal_proximity::TemporalProximityEnrichment;
@@ -7,15 +7,26 @@
 
 use super::ingest::IngestPipeline;
 use crate::adapter::enrichment::Enrichment;
+use crate::adapter::adapters::api_spec::ApiSpecModule;
 use crate::adapter::adapters::content::ContentAdapter;
+use crate::adapter::adapters::calendar::CalendarAdapter;
+use crate::adapter::adapters::graph_import::GraphImportAdapter;
+use crate::adapter::adapters::pkm::PkmAdapter;
+use crate::adapter::adapters::conversation::ConversationAdapter;
 use crate::adapter::adapters::extraction::ExtractionCoordinator;
+use crate::adapter::adapters::image::ImageMetadataModule;
+use crate::adapter::adapters::notebook::NotebookStructureModule;
 use crate::adapter::adapters::provenance_adapter::ProvenanceAdapter;
 use crate::adapter::adapters::semantic::SemanticAdapter;
 use crate::adapter::adapters::structural::{MarkdownStructureModule, StructuralModule};
+use crate::adapter::adapters::transcript::TranscriptAdapter;
 use crate::adapter::enrichments::cooccurrence::CoOccurrenceEnrichment;
 use crate::adapter::enrichments::discovery_gap::DiscoveryGapEnrichment;
+use crate::adapter::enrichments::embedding::{
+    Embedder, EmbeddingSimilarityEnrichment, InMemoryVectorStore, PersistedVectorStore, SimilaritySearch, VectorStore,
+};
 use crate::adapter::enrichments::temporal_proximity::TemporalProximityEnrichment;
-use crate::graph::PlexusEngine;
+use crate::graph::{ContentType, PlexusEngine};
 use crate::llm_orc::LlmOrcClient;
 use crate::storage::PersistedSpec;
 use std::sync::Arc;
@@ -44,7 +55,9 @@ impl PipelineBuilder {
         }
     }
 
-    /// Register the core adapters: ContentAdapter, ExtractionCoordinator, ProvenanceAdapter.
+    /// Register the core adapters: ContentAdapter, ConversationAdapter,
+    /// CalendarAdapter, TranscriptAdapter, GraphImportAdapter, PkmAdapter,
+    /// ExtractionCoordinator, ProvenanceAdapter.
     ///
     /// The `ExtractionCoordinator` is held by the builder until `build()` so
     /// that structural modules can be registered on it via `with_structural_module()`
@@ -53,6 +66,11 @@ impl PipelineBuilder {
     /// via `EngineSink` (Invariant 30).
     pub fn with_default_adapters(mut self) -> Self {
         self.pipeline.register_adapter(Arc::new(ContentAdapter::new("content")));
+        self.pipeline.register_adapter(Arc::new(ConversationAdapter::new("conversation")));
+        self.pipeline.register_adapter(Arc::new(CalendarAdapter::new("calendar")));
+        self.pipeline.register_adapter(Arc::new(TranscriptAdapter::new("transcript")));
+        self.pipeline.register_adapter(Arc::new(GraphImportAdapter::new("graph-import")));
+        self.pipeline.register_adapter(Arc::new(PkmAdapter::new("pkm")));
         self.coordinator = Some(ExtractionCoordinator::new().with_engine(self.engine.clone()));
         // ProvenanceAdapter is registered via register_integration in build()
         self
@@ -69,12 +87,29 @@ impl PipelineBuilder {
         self
     }
 
-    /// Register the default structural modules (currently: MarkdownStructureModule).
+    /// Assign a content type to file nodes by MIME prefix.
+    ///
+    /// Must be called after `with_default_adapters()`. See
+    /// `ExtractionCoordinator::register_content_type`.
+    pub fn with_content_type(mut self, mime_prefix: &str, content_type: ContentType) -> Self {
+        if let Some(ref mut coordinator) = self.coordinator {
+            coordinator.register_content_type(mime_prefix, content_type);
+        }
+        self
+    }
+
+    /// Register the default structural modules (currently: MarkdownStructureModule,
+    /// ImageMetadataModule, NotebookStructureModule, and ApiSpecModule for
+    /// YAML and JSON).
     ///
     /// Called automatically by `default_pipeline()`. Consumers who want
     /// different modules can skip this and call `with_structural_module()` directly.
     pub fn with_default_structural_modules(self) -> Self {
         self.with_structural_module(Arc::new(MarkdownStructureModule::new()))
+            .with_structural_module(Arc::new(ImageMetadataModule::new()))
+            .with_structural_module(Arc::new(NotebookStructureModule::new()))
+            .with_structural_module(Arc::new(ApiSpecModule::yaml()))
+            .with_structural_module(Arc::new(ApiSpecModule::json()))
     }
 
     /// Register the domain-agnostic enrichments.
@@ -105,22 +140,42 @@ impl PipelineBuilder {
 
         #[cfg(feature = "embeddings")]
         {
-            use crate::adapter::enrichments::embedding::{
-                EmbeddingSimilarityEnrichment, FastEmbedEmbedder,
-            };
+            use crate::adapter::enrichments::embedding::FastEmbedEmbedder;
             if let Ok(embedder) = FastEmbedEmbedder::default_model() {
-                self.enrichments.push(Arc::new(EmbeddingSimilarityEnrichment::new(
-                    "nomic-embed-text-v1.5",
-                    0.7,
-                    "similar_to",
-                    Box::new(embedder),
-                )));
+                self = self.with_embedder("nomic-embed-text-v1.5", 0.7, Arc::new(embedder));
             }
         }
 
         self
     }
 
+    /// Configure an embedder: registers `EmbeddingSimilarity` (emitting
+    /// `similar_to` above `threshold`) and enables similarity search over
+    /// the same vectors (`IngestPipeline::similarity`).
+    ///
+    /// The first embedder is the default model; later ones serve only
+    /// contexts whose `EmbeddingConfig` names them. With persistent storage
+    /// on the engine, vectors are saved there and reloaded after a restart.
+    pub fn with_embedder(mut self, model_name: &str, threshold: f32, embedder: Arc<dyn Embedder>) -> Self {
+        let store: Arc<dyn VectorStore> = match self.engine.store() {
+            Some(graph_store) => Arc::new(PersistedVectorStore::new(graph_store.clone(), model_name)),
+            None => Arc::new(InMemoryVectorStore::new()),
+        };
+        let mut enrichment = EmbeddingSimilarityEnrichment::with_vector_store(
+            model_name,
+            threshold,
+            "similar_to",
+            Box::new(embedder.clone()),
+            Box::new(store.clone()),
+        );
+        if !self.pipeline.similarity.is_empty() {
+            enrichment = enrichment.configured_only();
+        }
+        self.enrichments.push(Arc::new(enrichment));
+        self.pipeline.similarity.push(Arc::new(SimilaritySearch::new(embedder, store).with_model_name(model_name)));
+        self
+    }
+
     /// Add a custom enrichment.
     pub fn with_enrichment(mut self, enrichment: Arc<dyn Enrichment>) -> Self {
         self.enrichments.push(enrichment);
@@ -138,7 +193,7 @@ impl PipelineBuilder {
     ///    `DeclarativeAdapter` whose spec declares an `ensemble:` field.
     ///
     /// Without this call, `extract-file` ingest produces only registration
-    /// + structural analysis output, and `load_spec` will reject specs that
+    /// and structural analysis output, and `load_spec` will reject specs that
     /// declare an ensemble (`AdapterError::Skipped("ensemble declared but
     /// no LlmOrcClient configured")`). `default_pipeline` calls this with
     /// `SubprocessClient::new()` so production hosts get both behaviors by
@@ -9,14 +9,84 @@
 //! 4. Each adapter transforms all accumulated events → outbound events
 //! 5. Return merged outbound events
 
-use crate::adapter::sink::{EngineSink, FrameworkContext, AdapterError};
+use crate::adapter::sink::{AdapterSink, EmitResult, EngineSink, FrameworkContext, AdapterError};
 use crate::adapter::enrichment::{Enrichment, EnrichmentRegistry};
+use crate::adapter::enrichments::embedding::SimilaritySearch;
 use crate::graph::events::GraphEvent;
 use crate::adapter::traits::{Adapter, AdapterInput};
-use crate::adapter::types::OutboundEvent;
-use crate::graph::{ContextId, PlexusEngine};
+use crate::adapter::types::{Emission, OutboundEvent};
+use crate::graph::{Context, ContextId, GraphDiff, Node, PlexusEngine, PlexusError, QUOTA_WARNING_EVENT};
 use crate::llm_orc::LlmOrcClient;
-use std::sync::{Arc, RwLock};
+use crate::storage::PersistedIngestResult;
+use super::replay::ReplayLog;
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex, RwLock};
+
+/// The would-be outcome of an ingest, from `IngestPipeline::simulate`.
+#[derive(Debug, Clone)]
+pub struct Simulation {
+    /// Adapter and enrichment emissions combined
+    pub result: EmitResult,
+    /// Changes the ingest would make to the context
+    pub diff: GraphDiff,
+    /// Outbound events the matched adapters would return
+    pub outbound: Vec<OutboundEvent>,
+    /// Enrichment rounds run before quiescence
+    pub enrichment_rounds: usize,
+}
+
+/// Sink wrapper that keeps a copy of every result, so a simulation can
+/// report what adapters' emissions would commit.
+struct RecordingSink {
+    inner: EngineSink,
+    recorded: Mutex<EmitResult>,
+}
+
+#[async_trait]
+impl AdapterSink for RecordingSink {
+    async fn emit(&self, emission: Emission) -> Result<EmitResult, AdapterError> {
+        let result = self.inner.emit(emission).await?;
+        self.recorded.lock().unwrap().absorb(result.clone());
+        Ok(result)
+    }
+
+    async fn emit_batch(&self, emissions: Vec<Emission>) -> Result<EmitResult, AdapterError> {
+        let result = self.inner.emit_batch(emissions).await?;
+        self.recorded.lock().unwrap().absorb(result.clone());
+        Ok(result)
+    }
+
+    async fn find_by_content_hash(&self, node_type: &str, hash: &str) -> Result<Vec<Node>, AdapterError> {
+        self.inner.find_by_content_hash(node_type, hash).await
+    }
+}
+
+/// A keyed ingest's payload: typed, or JSON for the adapters to decode.
+enum IngestData {
+    Typed(Box<dyn std::any::Any + Send + Sync>),
+    Json(serde_json::Value),
+}
+
+/// Validate a JSON payload against the schemas `adapters` declare. Other
+/// payloads are typed and left to the adapters' own downcasts.
+fn check_input_schemas(adapters: &[Arc<dyn Adapter>], data: &(dyn std::any::Any + Send + Sync)) -> Result<(), AdapterError> {
+    let Some(json) = data.downcast_ref::<serde_json::Value>() else { return Ok(()) };
+    let mut violations = Vec::new();
+    for schema in adapters.iter().filter_map(|a| a.input_schema()) {
+        for violation in crate::adapter::validate_input(&schema, json) {
+            if !violations.contains(&violation) {
+                violations.push(violation);
+            }
+        }
+    }
+    match violations.is_empty() {
+        true => Ok(()),
+        false => Err(AdapterError::Validation(violations)),
+    }
+}
+
+/// In-flight idempotent ingests, by (context, key).
+type IdempotencyLocks = std::collections::HashMap<(String, String), Arc<tokio::sync::Mutex<()>>>;
 
 /// The unified ingest pipeline.
 ///
@@ -47,6 +117,14 @@ pub struct IngestPipeline {
     /// (unload_spec in another process, issue #11) deregisters its lens
     /// when no other examined row still references it.
     synced_specs: RwLock<std::collections::HashMap<(String, String, String), Option<String>>>,
+    /// When set, every ingest call is appended here for replay.
+    replay_log: Option<Arc<ReplayLog>>,
+    /// Similarity search over each configured embedder's vectors, the
+    /// default model first; set by `PipelineBuilder::with_embedder`
+    pub(crate) similarity: Vec<Arc<SimilaritySearch>>,
+    /// One lock per in-flight (context, idempotency key), so concurrent
+    /// retries of a call ingest once
+    idempotency_locks: Mutex<IdempotencyLocks>,
 }
 
 impl IngestPipeline {
@@ -58,9 +136,40 @@ impl IngestPipeline {
             enrichments: Arc::new(RwLock::new(Arc::new(EnrichmentRegistry::empty()))),
             llm_client: None,
             synced_specs: RwLock::new(std::collections::HashMap::new()),
+            replay_log: None,
+            similarity: Vec::new(),
+            idempotency_locks: Mutex::new(std::collections::HashMap::new()),
         }
     }
 
+    /// Record every ingest call to `log` (see `replay`).
+    pub fn with_replay_log(mut self, log: ReplayLog) -> Self {
+        self.replay_log = Some(Arc::new(log));
+        self
+    }
+
+    /// Similarity search over the default model, when an embedder is configured.
+    pub fn similarity(&self) -> Option<&SimilaritySearch> {
+        self.similarity.first().map(|s| s.as_ref())
+    }
+
+    /// Similarity search over `model`'s vectors, or the default model's
+    /// for `None`.
+    pub fn similarity_for(&self, model: Option<&str>) -> Option<&SimilaritySearch> {
+        match model {
+            Some(model) => self.similarity.iter().find(|s| s.model_name() == model).map(|s| s.as_ref()),
+            None => self.similarity(),
+        }
+    }
+
+    pub(crate) fn replay_log(&self) -> Option<&ReplayLog> {
+        self.replay_log.as_deref()
+    }
+
+    pub(crate) fn engine(&self) -> &Arc<PlexusEngine> {
+        &self.engine
+    }
+
     /// Sync lens enrichments from the context's specs table before an
     /// ingest (Invariant 62 across processes). A spec loaded by another
     /// consumer in another process persists a row this pipeline has never
@@ -234,6 +343,20 @@ impl IngestPipeline {
         *enrichment_lock = Arc::new(EnrichmentRegistry::new(filtered));
     }
 
+    /// Let every enrichment drop its state for a deleted context.
+    pub fn forget_context(&self, context_id: &ContextId) {
+        for enrichment in self.enrichment_registry().enrichments() {
+            enrichment.forget_context(context_id);
+        }
+    }
+
+    /// Let every enrichment follow nodes a relocation renamed.
+    pub fn rename_nodes(&self, context_id: &ContextId, renamed: &[(crate::graph::NodeId, crate::graph::NodeId)]) {
+        for enrichment in self.enrichment_registry().enrichments() {
+            enrichment.rename_nodes(context_id, renamed);
+        }
+    }
+
     /// Get the enrichment registry (for running enrichment loop outside ingest).
     pub fn enrichment_registry(&self) -> Arc<EnrichmentRegistry> {
         self.enrichments.read().expect("enrichments lock poisoned").clone()
@@ -254,6 +377,12 @@ impl IngestPipeline {
             .iter().map(|a| a.input_kind().to_string()).collect()
     }
 
+    /// The input schema declared by an adapter for `input_kind`, if any.
+    pub fn input_schema(&self, input_kind: &str) -> Option<serde_json::Value> {
+        self.adapters.read().expect("adapters lock poisoned")
+            .iter().filter(|a| a.input_kind() == input_kind).find_map(|a| a.input_schema())
+    }
+
     /// Ingest with an explicit adapter, skipping input_kind routing.
     ///
     /// Same pipeline steps as `ingest()` but uses the provided adapter
@@ -274,10 +403,13 @@ impl IngestPipeline {
 
         // Invariant 62 across processes — same sync as `ingest()`.
         self.sync_spec_lenses(context_id);
+        check_input_schemas(std::slice::from_ref(&adapter), data.as_ref())?;
 
-        let input = AdapterInput::from_boxed(adapter.input_kind(), data, context_id);
+        let input = AdapterInput::from_boxed(adapter.input_kind(), data, context_id)
+            .with_tag_policy(self.engine.tag_policy(&ctx_id));
 
         // Step 1: Process the adapter
+        let size_before = self.engine.context_size(&ctx_id)?;
         let sink = EngineSink::for_engine(self.engine.clone(), ctx_id.clone())
             .with_framework_context(FrameworkContext {
                 adapter_id: adapter.id().to_string(),
@@ -311,24 +443,73 @@ impl IngestPipeline {
             .get_context(&ctx_id)
             .ok_or_else(|| AdapterError::ContextNotFound(context_id.to_string()))?;
 
-        let outbound = adapter.transform_events(&all_events, &snapshot);
+        let mut outbound = adapter.transform_events(&all_events, &snapshot);
+        outbound.extend(enrichment_outbound(&enrichments, &all_events, &snapshot));
+        outbound.extend(quota_warning(&snapshot, size_before));
+        self.enqueue_outbound(&ctx_id, &outbound);
 
         Ok(outbound)
     }
 
+    /// Keep outbound events in the context's delivery queue for
+    /// subscribers that aren't listening now, dropping the oldest past
+    /// the queue's bound. The ingest is already committed, so a failure
+    /// here is logged rather than failing it.
+    fn enqueue_outbound(&self, ctx_id: &ContextId, outbound: &[OutboundEvent]) {
+        for event in outbound {
+            if let Err(e) = self.engine.enqueue_outbound(ctx_id, &event.kind, &event.detail) {
+                tracing::warn!(context = %ctx_id, kind = %event.kind, error = %e, "failed to queue outbound event");
+            }
+        }
+        if !outbound.is_empty() {
+            if let Err(e) = self.engine.prune_outbound(ctx_id) {
+                tracing::warn!(context = %ctx_id, error = %e, "failed to prune the outbound queue");
+            }
+        }
+    }
+
     /// The single write endpoint (ADR-012).
     ///
     /// 1. Routes to adapters matching `input_kind` (fan-out if multiple)
     /// 2. Each adapter processes via its own sink → primary events
     /// 3. Enrichment loop runs once with combined events
     /// 4. Each adapter's `transform_events()` translates all events
-    /// 5. Returns merged outbound events
+    /// 5. Queues and returns merged outbound events
     pub async fn ingest(
         &self,
         context_id: &str,
         input_kind: &str,
         data: Box<dyn std::any::Any + Send + Sync>,
     ) -> Result<Vec<OutboundEvent>, AdapterError> {
+        let (ctx_id, matching) = self.route(context_id, input_kind, data.as_ref())?;
+        self.record_ingest(context_id, input_kind, data.as_ref());
+        self.process_routed(&ctx_id, input_kind, matching, vec![data]).await
+    }
+
+    /// `ingest()` for JSON payloads, the form remote callers and scripts
+    /// have. The payload is checked against the adapter's input schema
+    /// and decoded with its `input_from_json` before anything is written;
+    /// when several adapters share the input kind, each gets its own
+    /// decoding.
+    pub async fn ingest_json(
+        &self,
+        context_id: &str,
+        input_kind: &str,
+        data: serde_json::Value,
+    ) -> Result<Vec<OutboundEvent>, AdapterError> {
+        let (ctx_id, matching) = self.route(context_id, input_kind, &data)?;
+        let decoded = matching.iter().map(|adapter| adapter.input_from_json(&data)).collect::<Result<Vec<_>, _>>()?;
+        self.record_ingest(context_id, input_kind, &data);
+        self.process_routed(&ctx_id, input_kind, matching, decoded).await
+    }
+
+    /// Resolve an ingest's context and adapters, and validate its payload.
+    fn route(
+        &self,
+        context_id: &str,
+        input_kind: &str,
+        data: &(dyn std::any::Any + Send + Sync),
+    ) -> Result<(ContextId, Vec<Arc<dyn Adapter>>), AdapterError> {
         let ctx_id = ContextId::from(context_id);
 
         // Verify context exists
@@ -340,33 +521,35 @@ impl IngestPipeline {
         // loaded onto this context since this pipeline was constructed.
         self.sync_spec_lenses(context_id);
 
-        let input = AdapterInput::from_boxed(input_kind, data, context_id);
-
         // Step 1: Find matching adapters — snapshot refs, release read lock
-        let matching: Vec<Arc<dyn Adapter>> = {
-            let adapters = self.adapters.read().expect("adapters lock poisoned");
-            adapters.iter()
-                .filter(|a| a.input_kind() == input_kind)
-                .cloned()
-                .collect()
-        };
-
-        tracing::debug!(
-            input_kind,
-            adapter_count = matching.len(),
-            "routing ingest"
-        );
+        let matching = self.matching_adapters(input_kind)?;
+        check_input_schemas(&matching, data)?;
+        Ok((ctx_id, matching))
+    }
 
-        if matching.is_empty() {
-            return Err(AdapterError::Internal(format!(
-                "no adapter registered for input_kind '{}'",
-                input_kind
-            )));
-        }
+    /// Steps 2–5 of `ingest()`, for routed adapters. `payloads` is one
+    /// payload every adapter shares, or one per adapter in `matching`'s
+    /// order.
+    async fn process_routed(
+        &self,
+        ctx_id: &ContextId,
+        input_kind: &str,
+        matching: Vec<Arc<dyn Adapter>>,
+        payloads: Vec<Box<dyn std::any::Any + Send + Sync>>,
+    ) -> Result<Vec<OutboundEvent>, AdapterError> {
+        debug_assert!(payloads.len() == 1 || payloads.len() == matching.len());
+        let context_id = ctx_id.as_str();
+        let tag_policy = self.engine.tag_policy(ctx_id);
+        let inputs: Vec<AdapterInput> = payloads
+            .into_iter()
+            .map(|data| AdapterInput::from_boxed(input_kind, data, context_id).with_tag_policy(tag_policy.clone()))
+            .collect();
 
         // Step 2: Process each adapter, collecting events (no lock held)
+        let size_before = self.engine.context_size(ctx_id)?;
         let mut all_events: Vec<GraphEvent> = Vec::new();
-        for adapter in &matching {
+        for (i, adapter) in matching.iter().enumerate() {
+            let input = inputs.get(i).unwrap_or(&inputs[0]);
             let sink = EngineSink::for_engine(self.engine.clone(), ctx_id.clone())
                 .with_framework_context(FrameworkContext {
                     adapter_id: adapter.id().to_string(),
@@ -374,7 +557,7 @@ impl IngestPipeline {
                     input_summary: None,
                 });
 
-            adapter.process(&input, &sink).await?;
+            adapter.process(input, &sink).await?;
             all_events.extend(sink.drain_events());
         }
 
@@ -383,7 +566,7 @@ impl IngestPipeline {
         if !enrichments.enrichments().is_empty() && !all_events.is_empty() {
             let enrichment_result = crate::adapter::enrichment::run_enrichment_loop(
                 &self.engine,
-                &ctx_id,
+                ctx_id,
                 &enrichments,
                 &all_events,
             )?;
@@ -398,14 +581,261 @@ impl IngestPipeline {
         // Step 4: Transform events through each matched adapter (no lock held)
         let snapshot = self
             .engine
-            .get_context(&ctx_id)
+            .get_context(ctx_id)
             .ok_or_else(|| AdapterError::ContextNotFound(context_id.to_string()))?;
 
         let mut outbound = Vec::new();
         for adapter in &matching {
             outbound.extend(adapter.transform_events(&all_events, &snapshot));
         }
+        outbound.extend(enrichment_outbound(&enrichments, &all_events, &snapshot));
+        outbound.extend(quota_warning(&snapshot, size_before));
+        self.enqueue_outbound(ctx_id, &outbound);
 
         Ok(outbound)
     }
+
+    /// `ingest()` under an idempotency key. The first call with `key`
+    /// ingests and records its outbound events with the context; a retry
+    /// with the same key returns them without touching the graph, so a
+    /// client retrying after a lost response doesn't create fragments,
+    /// marks or enrichment output twice. Reusing a key for a different
+    /// input kind, or for different JSON data, is an error. Only JSON
+    /// payloads (`serde_json::Value`) are compared: other typed inputs
+    /// can't be hashed, so a reused key answers them from the recorded
+    /// result whatever their data.
+    ///
+    /// The key is claimed in the store before ingesting: concurrent calls
+    /// in this process run one at a time, and a call in another process
+    /// fails as unavailable (retryable) until the first finishes. Failed
+    /// ingests release the key. A process dying mid-ingest leaves its
+    /// claim, which a retry takes over after `INGEST_CLAIM_LEASE_SECS`.
+    /// Results are kept for `INGEST_RESULT_RETENTION_DAYS`.
+    pub async fn ingest_idempotent(
+        &self,
+        context_id: &str,
+        input_kind: &str,
+        data: Box<dyn std::any::Any + Send + Sync>,
+        key: &str,
+    ) -> Result<Vec<OutboundEvent>, AdapterError> {
+        self.ingest_keyed(context_id, input_kind, IngestData::Typed(data), key).await
+    }
+
+    /// `ingest_json()` under an idempotency key (see `ingest_idempotent`).
+    pub async fn ingest_json_idempotent(
+        &self,
+        context_id: &str,
+        input_kind: &str,
+        data: serde_json::Value,
+        key: &str,
+    ) -> Result<Vec<OutboundEvent>, AdapterError> {
+        self.ingest_keyed(context_id, input_kind, IngestData::Json(data), key).await
+    }
+
+    async fn ingest_keyed(
+        &self,
+        context_id: &str,
+        input_kind: &str,
+        data: IngestData,
+        key: &str,
+    ) -> Result<Vec<OutboundEvent>, AdapterError> {
+        let ctx_id = ContextId::from(context_id);
+        let slot = (context_id.to_string(), key.to_string());
+        let lock = self
+            .idempotency_locks
+            .lock()
+            .expect("idempotency locks poisoned")
+            .entry(slot.clone())
+            .or_default()
+            .clone();
+        let result = {
+            let _guard = lock.lock().await;
+            self.ingest_once(&ctx_id, input_kind, data, key).await
+        };
+        let mut locks = self.idempotency_locks.lock().expect("idempotency locks poisoned");
+        if Arc::strong_count(&lock) == 2 {
+            locks.remove(&slot);
+        }
+        result
+    }
+
+    async fn ingest_once(
+        &self,
+        ctx_id: &ContextId,
+        input_kind: &str,
+        data: IngestData,
+        key: &str,
+    ) -> Result<Vec<OutboundEvent>, AdapterError> {
+        let payload_hash = match &data {
+            IngestData::Typed(data) => data.downcast_ref::<serde_json::Value>(),
+            IngestData::Json(value) => Some(value),
+        }
+        .map(|value| crate::graph::file_content_hash(value.to_string().as_bytes()));
+        if let Some(recorded) = self.engine.ingest_result(ctx_id, key).map_err(EngineSink::map_engine_error)? {
+            if recorded.input_kind != input_kind {
+                return Err(PlexusError::InvalidInput(format!(
+                    "idempotency key '{}' was used for a '{}' ingest",
+                    key, recorded.input_kind
+                ))
+                .into());
+            }
+            if recorded.payload_hash.is_some() && payload_hash.is_some() && recorded.payload_hash != payload_hash {
+                return Err(PlexusError::InvalidInput(format!("idempotency key '{}' was used for different data", key)).into());
+            }
+            if recorded.completed {
+                tracing::debug!(key, "idempotency key seen before; returning the recorded result");
+                return serde_json::from_str(&recorded.outbound_json).map_err(|e| AdapterError::Serialization(e.to_string()));
+            }
+        }
+
+        // Claim the key in the store, so another process retrying the
+        // same call waits for this one instead of ingesting twice
+        let record = |outbound_json: String, completed: bool| PersistedIngestResult {
+            context_id: ctx_id.to_string(),
+            key: key.to_string(),
+            input_kind: input_kind.to_string(),
+            payload_hash: payload_hash.clone(),
+            outbound_json,
+            completed,
+            recorded_at: chrono::Utc::now().to_rfc3339(),
+        };
+        if !self.engine.claim_ingest_key(record(String::new(), false)).map_err(EngineSink::map_engine_error)? {
+            return Err(PlexusError::Unavailable(format!("an ingest under idempotency key '{}' is in progress", key)).into());
+        }
+
+        let ingested = match data {
+            IngestData::Typed(data) => self.ingest(ctx_id.as_str(), input_kind, data).await,
+            IngestData::Json(value) => self.ingest_json(ctx_id.as_str(), input_kind, value).await,
+        };
+        let outbound = match ingested {
+            Ok(outbound) => outbound,
+            Err(e) => {
+                if let Err(release) = self.engine.release_ingest_key(ctx_id, key) {
+                    tracing::warn!(key, error = %release, "failed to release idempotency key");
+                }
+                return Err(e);
+            }
+        };
+        let outbound_json = serde_json::to_string(&outbound).map_err(|e| AdapterError::Serialization(e.to_string()))?;
+        self.engine.record_ingest_result(record(outbound_json, true)).map_err(EngineSink::map_engine_error)?;
+        Ok(outbound)
+    }
+
+    /// Dry-run an ingest: route, process, and enrich exactly as `ingest()`
+    /// would, but against a clone of the context in a scratch in-memory
+    /// engine. Nothing is persisted and the live context is untouched.
+    ///
+    /// Adapters that reach outside their sink (llm-orc ensembles) still
+    /// make those calls; only graph writes are simulated.
+    pub async fn simulate(
+        &self,
+        context_id: &str,
+        input_kind: &str,
+        data: Box<dyn std::any::Any + Send + Sync>,
+    ) -> Result<Simulation, AdapterError> {
+        let ctx_id = ContextId::from(context_id);
+        let before = self
+            .engine
+            .get_context(&ctx_id)
+            .ok_or_else(|| AdapterError::ContextNotFound(context_id.to_string()))?;
+
+        self.sync_spec_lenses(context_id);
+
+        let input = AdapterInput::from_boxed(input_kind, data, context_id)
+            .with_tag_policy(before.metadata.tag_policy.clone());
+        let matching = self.matching_adapters(input_kind)?;
+        check_input_schemas(&matching, input.data.as_ref())?;
+
+        let scratch = Arc::new(PlexusEngine::new());
+        scratch
+            .upsert_context(before.clone())
+            .map_err(EngineSink::map_engine_error)?;
+
+        let mut result = EmitResult::empty();
+        for adapter in &matching {
+            let sink = RecordingSink {
+                inner: EngineSink::for_engine(scratch.clone(), ctx_id.clone())
+                    .with_framework_context(FrameworkContext {
+                        adapter_id: adapter.id().to_string(),
+                        context_id: context_id.to_string(),
+                        input_summary: None,
+                    }),
+                recorded: Mutex::new(EmitResult::empty()),
+            };
+            adapter.process(&input, &sink).await?;
+            result.absorb(sink.recorded.into_inner().unwrap());
+        }
+
+        let mut enrichment_rounds = 0;
+        let enrichments = self.enrichment_registry();
+        if !enrichments.enrichments().is_empty() && !result.events.is_empty() {
+            let enrichment_result = crate::adapter::enrichment::run_enrichment_loop(
+                &scratch,
+                &ctx_id,
+                &enrichments,
+                &result.events,
+            )?;
+            enrichment_rounds = enrichment_result.rounds;
+            result.absorb(enrichment_result.result);
+        }
+
+        let after = scratch
+            .get_context(&ctx_id)
+            .ok_or_else(|| AdapterError::ContextNotFound(context_id.to_string()))?;
+        let mut outbound = Vec::new();
+        for adapter in &matching {
+            outbound.extend(adapter.transform_events(&result.events, &after));
+        }
+        outbound.extend(quota_warning(&after, (before.node_count(), before.edge_count())));
+
+        Ok(Simulation {
+            diff: GraphDiff::between(&before, &after),
+            result,
+            outbound,
+            enrichment_rounds,
+        })
+    }
+
+    /// Adapters registered for `input_kind` — refs snapshotted, read lock released.
+    fn matching_adapters(&self, input_kind: &str) -> Result<Vec<Arc<dyn Adapter>>, AdapterError> {
+        let matching: Vec<Arc<dyn Adapter>> = {
+            let adapters = self.adapters.read().expect("adapters lock poisoned");
+            adapters.iter()
+                .filter(|a| a.input_kind() == input_kind)
+                .cloned()
+                .collect()
+        };
+
+        tracing::debug!(
+            input_kind,
+            adapter_count = matching.len(),
+            "routing ingest"
+        );
+
+        if matching.is_empty() {
+            return Err(AdapterError::Internal(format!(
+                "no adapter registered for input_kind '{}'",
+                input_kind
+            )));
+        }
+        Ok(matching)
+    }
+}
+
+/// Outbound events from the enrichments that ran on the context.
+fn enrichment_outbound(registry: &EnrichmentRegistry, events: &[GraphEvent], context: &crate::graph::Context) -> Vec<OutboundEvent> {
+    registry
+        .enrichments()
+        .iter()
+        .filter(|e| context.metadata.runs_enrichment(e.id()))
+        .flat_map(|e| e.transform_events(events, context))
+        .collect()
+}
+
+/// The advisory outbound event for a context the ingest took past its
+/// quota's warning threshold, if it did. `before` is the (nodes, edges)
+/// the context held when the ingest started.
+fn quota_warning(context: &Context, before: (usize, usize)) -> Option<OutboundEvent> {
+    let warning = context.metadata.quota.as_ref()?.crossed_warning(context, before)?;
+    Some(OutboundEvent::new(QUOTA_WARNING_EVENT, warning))
 }
@@ -5,8 +5,10 @@
 
 mod builder;
 mod ingest;
+mod replay;
 mod router;
 
 pub use builder::{gather_persisted_specs, PipelineBuilder};
-pub use ingest::IngestPipeline;
+pub use ingest::{IngestPipeline, Simulation};
+pub use replay::{ReplayEntry, ReplayLog, ReplaySummary};
 pub use router::{classify_input, ClassifyError};
@@ -3,16 +3,47 @@
 /// Classify input JSON to determine the appropriate `input_kind` (ADR-028).
 ///
 /// Detection precedence:
-/// 1. `{text}` or `{annotation}` → `"content"`
-/// 2. `{file_path}` → `"extract-file"`
-/// 3. No match → error with guidance
+/// 1. `{segments: [...]}`, `{vtt}` or `{srt}` → `"transcript"` (whisper
+///    JSON also carries a top-level `text`, so this comes first)
+/// 2. `{text}` or `{annotation}` → `"content"`
+/// 3. `{file_path}` → `"extract-file"`
+/// 4. `{turns: [...]}` or `{messages: [...]}` → `"conversation"`
+/// 5. `{ics}`, `{events: [...]}` or `{tasks: [...]}` → `"calendar"`
+/// 6. `{neo4j}` or `{arangodb: {...}}` → `"graph-import"`
+/// 7. `{roam}`, `{logseq}`, `{edn}` or `{markdown_pages}` → `"pkm"`
+/// 8. No match → error with guidance
 pub fn classify_input(data: &serde_json::Value) -> Result<&'static str, ClassifyError> {
+    if data.get("segments").is_some_and(|v| v.is_array())
+        || ["vtt", "srt"].iter().any(|k| data.get(*k).is_some_and(|v| v.is_string()))
+    {
+        return Ok("transcript");
+    }
     if data.get("text").is_some() || data.get("annotation").is_some() {
         return Ok("content");
     }
     if data.get("file_path").is_some() {
         return Ok("extract-file");
     }
+    if ["turns", "messages"].iter().any(|k| data.get(*k).is_some_and(|v| v.is_array())) {
+        return Ok("conversation");
+    }
+    if data.get("ics").is_some_and(|v| v.is_string())
+        || ["events", "tasks"].iter().any(|k| data.get(*k).is_some_and(|v| v.is_array()))
+    {
+        return Ok("calendar");
+    }
+    if data.get("neo4j").is_some_and(|v| v.is_string() || v.is_array())
+        || data.get("arangodb").is_some_and(|v| v.is_object())
+    {
+        return Ok("graph-import");
+    }
+    if data.get("roam").is_some_and(|v| v.is_array())
+        || data.get("logseq").is_some_and(|v| v.is_object())
+        || data.get("edn").is_some_and(|v| v.is_string())
+        || data.get("markdown_pages").is_some_and(|v| v.is_object())
+    {
+        return Ok("pkm");
+    }
     Err(ClassifyError)
 }
 
@@ -26,7 +57,12 @@ impl std::fmt::Display for ClassifyError {
             f,
             "unrecognized input shape — expected one of: \
              {{\"text\": ...}} for content, \
-             {{\"file_path\": ...}} for file extraction"
+             {{\"file_path\": ...}} for file extraction, \
+             {{\"turns\": [...]}} for a conversation, \
+             {{\"ics\": ...}} or {{\"tasks\": [...]}} for a calendar, \
+             {{\"segments\": [...]}} or {{\"vtt\": ...}} for a transcript, \
+             {{\"neo4j\": ...}} or {{\"arangodb\": {{...}}}} for a graph database dump, \
+             {{\"roam\": [...]}}, {{\"logseq\": {{...}}}} or {{\"edn\": ...}} for an outliner export"
         )
     }
 }
@@ -58,6 +94,51 @@ mod tests {
         assert_eq!(classify_input(&json).unwrap(), "extract-file");
     }
 
+    // === Scenario: Classifier detects chat transcripts ===
+    #[test]
+    fn classify_conversation() {
+        let json = serde_json::json!({"messages": [{"role": "user", "content": "hi"}]});
+        assert_eq!(classify_input(&json).unwrap(), "conversation");
+        let json = serde_json::json!({"turns": "not a list"});
+        assert!(classify_input(&json).is_err());
+    }
+
+    // === Scenario: Classifier detects calendar exports ===
+    #[test]
+    fn classify_calendar() {
+        let json = serde_json::json!({"ics": "BEGIN:VCALENDAR"});
+        assert_eq!(classify_input(&json).unwrap(), "calendar");
+        let json = serde_json::json!({"tasks": [{"description": "review"}]});
+        assert_eq!(classify_input(&json).unwrap(), "calendar");
+    }
+
+    // === Scenario: Classifier detects graph database dumps ===
+    #[test]
+    fn classify_graph_import() {
+        let json = serde_json::json!({"neo4j": "{\"type\":\"node\",\"id\":\"0\"}", "mapping": {}});
+        assert_eq!(classify_input(&json).unwrap(), "graph-import");
+        let json = serde_json::json!({"arangodb": {"users": []}});
+        assert_eq!(classify_input(&json).unwrap(), "graph-import");
+    }
+
+    // === Scenario: Classifier detects outliner exports ===
+    #[test]
+    fn classify_pkm() {
+        let json = serde_json::json!({"roam": [{"title": "Dune"}]});
+        assert_eq!(classify_input(&json).unwrap(), "pkm");
+        let json = serde_json::json!({"markdown_pages": {"Dune": "- spice"}});
+        assert_eq!(classify_input(&json).unwrap(), "pkm");
+    }
+
+    // === Scenario: Classifier detects timed transcripts ===
+    #[test]
+    fn classify_transcript() {
+        let json = serde_json::json!({"text": "full text", "segments": [{"start": 0.0, "text": "hi"}]});
+        assert_eq!(classify_input(&json).unwrap(), "transcript");
+        let json = serde_json::json!({"vtt": "WEBVTT"});
+        assert_eq!(classify_input(&json).unwrap(), "transcript");
+    }
+
     // === Scenario: Unrecognized input returns error ===
     #[test]
     fn classify_unrecognized_returns_error() {
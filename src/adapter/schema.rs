@@ -0,0 +1,289 @@
+//! Adapter input schemas — validating JSON payloads before they're processed
+//!
+//! `AdapterInput` data is type-erased, so a malformed payload only shows
+//! up as a downcast or field error deep inside an adapter. Adapters may
+//! declare a JSON Schema for their input kind (`Adapter::input_schema`,
+//! hand-written or from `schemars::schema_for!`); the pipeline checks
+//! JSON payloads against it and reports every violation with its path.
+//!
+//! The validator covers the subset adapters' schemas use: `type`, `enum`,
+//! `const`, `properties`, `required`, `additionalProperties`, `items`,
+//! `minItems`/`maxItems`, `minLength`/`maxLength`, `minimum`/`maximum`,
+//! `allOf`/`anyOf`/`oneOf` and local `$ref`s into `$defs` or
+//! `definitions`. Other keywords are ignored.
+
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+
+/// One way a payload breaks its schema.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SchemaViolation {
+    /// JSON Pointer to the offending value ("" for the payload itself)
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.path.is_empty() {
+            true => write!(f, "{}", self.message),
+            false => write!(f, "{}: {}", self.path, self.message),
+        }
+    }
+}
+
+/// Check `value` against `schema`, returning every violation found.
+pub fn validate(schema: &Value, value: &Value) -> Vec<SchemaViolation> {
+    let mut violations = Vec::new();
+    Validator { root: schema }.check_at(schema, value, "", &mut violations, 0);
+    violations
+}
+
+/// Violations as one line, for error messages.
+pub(crate) fn describe(violations: &[SchemaViolation]) -> String {
+    violations.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")
+}
+
+/// References followed before a schema is taken to be cyclic.
+const MAX_REF_DEPTH: usize = 32;
+
+struct Validator<'a> {
+    root: &'a Value,
+}
+
+impl<'a> Validator<'a> {
+    fn check_at(&self, schema: &'a Value, value: &Value, path: &str, out: &mut Vec<SchemaViolation>, depth: usize) {
+        let violation = |message: String| SchemaViolation { path: path.to_string(), message };
+        let schema = match schema {
+            Value::Bool(true) => return,
+            Value::Bool(false) => return out.push(violation("no value is allowed here".into())),
+            Value::Object(schema) => schema,
+            _ => return,
+        };
+
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            match self.resolve(reference) {
+                Some(_) if depth >= MAX_REF_DEPTH => return out.push(violation(format!("schema reference '{reference}' is cyclic"))),
+                Some(target) => self.check_at(target, value, path, out, depth + 1),
+                None => return out.push(violation(format!("schema reference '{reference}' doesn't resolve"))),
+            }
+        }
+
+        if let Some(expected) = schema.get("type") {
+            let allowed: Vec<&str> = match expected {
+                Value::String(t) => vec![t.as_str()],
+                Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+                _ => Vec::new(),
+            };
+            if !allowed.is_empty() && !allowed.iter().any(|t| has_type(value, t)) {
+                out.push(violation(format!("expected {}, found {}", allowed.join(" or "), type_name(value))));
+                // Nothing below applies to a value of the wrong type
+                return;
+            }
+        }
+        if let Some(Value::Array(options)) = schema.get("enum") {
+            if !options.contains(value) {
+                out.push(violation(format!("must be one of {}", Value::Array(options.clone()))));
+            }
+        }
+        if let Some(constant) = schema.get("const") {
+            if constant != value {
+                out.push(violation(format!("must be {constant}")));
+            }
+        }
+
+        match value {
+            Value::Object(fields) => {
+                let properties = schema.get("properties").and_then(Value::as_object);
+                for required in schema.get("required").and_then(Value::as_array).into_iter().flatten().filter_map(Value::as_str) {
+                    if !fields.contains_key(required) {
+                        out.push(violation(format!("missing required field '{required}'")));
+                    }
+                }
+                for (key, field) in fields {
+                    let field_path = format!("{path}/{}", escape_pointer(key));
+                    match properties.and_then(|p| p.get(key)) {
+                        Some(property) => self.check_at(property, field, &field_path, out, depth),
+                        None => match schema.get("additionalProperties") {
+                            Some(Value::Bool(false)) => out.push(SchemaViolation { path: field_path, message: "unexpected field".into() }),
+                            Some(additional) => self.check_at(additional, field, &field_path, out, depth),
+                            None => {}
+                        },
+                    }
+                }
+            }
+            Value::Array(items) => {
+                if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+                    if (items.len() as u64) < min {
+                        out.push(violation(format!("expected at least {min} items, found {}", items.len())));
+                    }
+                }
+                if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+                    if items.len() as u64 > max {
+                        out.push(violation(format!("expected at most {max} items, found {}", items.len())));
+                    }
+                }
+                if let Some(item_schema) = schema.get("items") {
+                    for (i, item) in items.iter().enumerate() {
+                        self.check_at(item_schema, item, &format!("{path}/{i}"), out, depth);
+                    }
+                }
+            }
+            Value::String(s) => {
+                let length = s.chars().count() as u64;
+                if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+                    if length < min {
+                        out.push(violation(format!("expected at least {min} characters")));
+                    }
+                }
+                if let Some(max) = schema.get("maxLength").and_then(Value::as_u64) {
+                    if length > max {
+                        out.push(violation(format!("expected at most {max} characters")));
+                    }
+                }
+            }
+            Value::Number(n) => {
+                let n = n.as_f64().unwrap_or(f64::NAN);
+                if let Some(min) = schema.get("minimum").and_then(Value::as_f64) {
+                    if n < min {
+                        out.push(violation(format!("must be at least {min}")));
+                    }
+                }
+                if let Some(max) = schema.get("maximum").and_then(Value::as_f64) {
+                    if n > max {
+                        out.push(violation(format!("must be at most {max}")));
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        if let Some(Value::Array(all)) = schema.get("allOf") {
+            for sub in all {
+                self.check_at(sub, value, path, out, depth);
+            }
+        }
+        for keyword in ["anyOf", "oneOf"] {
+            let Some(Value::Array(branches)) = schema.get(keyword) else { continue };
+            let failures: Vec<Vec<SchemaViolation>> = branches.iter().map(|sub| self.failures(sub, value, depth)).collect();
+            let passing = failures.iter().filter(|f| f.is_empty()).count();
+            let ok = match keyword {
+                "anyOf" => passing > 0,
+                _ => passing == 1,
+            };
+            if !ok {
+                out.push(violation(match passing {
+                    // Say what each shape wanted, e.g. which field was missing
+                    0 => format!(
+                        "matches none of the allowed shapes ({})",
+                        failures.iter().map(|f| describe(f)).collect::<Vec<_>>().join(" | ")
+                    ),
+                    n => format!("matches {n} of the allowed shapes, expected exactly one"),
+                }));
+            }
+        }
+    }
+
+    fn failures(&self, schema: &'a Value, value: &Value, depth: usize) -> Vec<SchemaViolation> {
+        let mut scratch = Vec::new();
+        self.check_at(schema, value, "", &mut scratch, depth);
+        scratch
+    }
+
+    /// Resolve a local reference like `#/$defs/Turn`.
+    fn resolve(&self, reference: &str) -> Option<&'a Value> {
+        let pointer = reference.strip_prefix('#')?;
+        self.root.pointer(pointer)
+    }
+}
+
+fn has_type(value: &Value, name: &str) -> bool {
+    match name {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0),
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn escape_pointer(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn paths(violations: &[SchemaViolation]) -> Vec<&str> {
+        violations.iter().map(|v| v.path.as_str()).collect()
+    }
+
+    // === Scenario: Violations name their path and what was expected ===
+    #[test]
+    fn validation_reports_every_violation_with_its_path() {
+        let schema = json!({
+            "type": "object",
+            "required": ["text"],
+            "additionalProperties": false,
+            "properties": {
+                "text": {"type": "string", "minLength": 1},
+                "tags": {"type": "array", "items": {"type": "string"}},
+                "line": {"type": ["integer", "null"], "minimum": 0},
+                "kind": {"enum": ["note", "quote"]}
+            }
+        });
+        assert!(validate(&schema, &json!({"text": "rye", "tags": ["bread"], "line": null})).is_empty());
+
+        let violations = validate(&schema, &json!({"tags": ["bread", 7], "line": -1, "kind": "poem", "extra": true}));
+        assert_eq!(paths(&violations), vec!["", "/extra", "/kind", "/line", "/tags/1"]);
+        assert_eq!(violations[0].to_string(), "missing required field 'text'");
+        assert_eq!(violations[4].to_string(), "/tags/1: expected string, found number");
+
+        let wrong_shape = validate(&schema, &json!(["rye"]));
+        assert_eq!(wrong_shape.len(), 1, "a value of the wrong type isn't checked further");
+        assert_eq!(wrong_shape[0].message, "expected object, found array");
+    }
+
+    // === Scenario: schemars-generated schemas validate, references included ===
+    #[test]
+    fn derived_schemas_follow_references_and_alternatives() {
+        #[derive(schemars::JsonSchema)]
+        #[allow(dead_code)]
+        struct Turn {
+            speaker: String,
+            text: String,
+        }
+
+        #[derive(schemars::JsonSchema)]
+        #[allow(dead_code)]
+        struct Conversation {
+            turns: Vec<Turn>,
+            lead: Option<Turn>,
+        }
+
+        let schema = serde_json::to_value(schemars::schema_for!(Conversation)).unwrap();
+        let turn = json!({"speaker": "ana", "text": "rye?"});
+        assert!(validate(&schema, &json!({"turns": [turn], "lead": null})).is_empty());
+
+        let violations = validate(&schema, &json!({"turns": [{"speaker": "ana"}], "lead": {"text": 4}}));
+        assert_eq!(paths(&violations), vec!["/lead", "/turns/0"]);
+        assert_eq!(violations[1].message, "missing required field 'text'");
+    }
+}
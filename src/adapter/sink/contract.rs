@@ -6,7 +6,7 @@
 use crate::graph::events::GraphEvent;
 use super::provenance::ProvenanceEntry;
 use crate::adapter::types::Emission;
-use crate::graph::NodeId;
+use crate::graph::{Node, NodeId};
 use async_trait::async_trait;
 use thiserror::Error;
 
@@ -15,6 +15,10 @@ use thiserror::Error;
 pub enum RejectionReason {
     /// Edge references a node that doesn't exist in the graph or emission
     MissingEndpoint(NodeId),
+    /// The item is outside the adapter's write scope for the context
+    OutOfScope(String),
+    /// The item would take the context past its `ContextQuota`
+    QuotaExceeded(String),
     /// Adapter-side error (e.g., downcast failure)
     Other(String),
 }
@@ -23,6 +27,8 @@ impl std::fmt::Display for RejectionReason {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::MissingEndpoint(id) => write!(f, "missing endpoint {}", id),
+            Self::OutOfScope(why) => write!(f, "out of scope: {}", why),
+            Self::QuotaExceeded(why) => write!(f, "quota exceeded: {}", why),
             Self::Other(msg) => write!(f, "{}", msg),
         }
     }
@@ -82,6 +88,17 @@ impl EmitResult {
         }
     }
 
+    /// Fold another result into this one (counts summed, lists appended).
+    pub fn absorb(&mut self, other: EmitResult) {
+        self.nodes_committed += other.nodes_committed;
+        self.edges_committed += other.edges_committed;
+        self.removals_committed += other.removals_committed;
+        self.edge_removals_committed += other.edge_removals_committed;
+        self.rejections.extend(other.rejections);
+        self.provenance.extend(other.provenance);
+        self.events.extend(other.events);
+    }
+
     /// True if no items were rejected
     pub fn is_fully_committed(&self) -> bool {
         self.rejections.is_empty()
@@ -99,9 +116,13 @@ impl EmitResult {
 
 /// Errors from adapter processing (not from individual item rejection).
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum AdapterError {
     #[error("invalid input: expected different data type")]
     InvalidInput,
+    /// The payload breaks the adapter's input schema
+    #[error("invalid input: {}", crate::adapter::schema::describe(.0))]
+    Validation(Vec<crate::adapter::SchemaViolation>),
     #[error("adapter cancelled")]
     Cancelled,
     #[error("context not found: {0}")]
@@ -114,6 +135,18 @@ pub enum AdapterError {
     Internal(String),
     #[error("skipped: {0}")]
     Skipped(String),
+    /// An engine failure, kept whole so its code and source chain survive
+    #[error("engine error: {0}")]
+    Engine(#[source] Box<crate::graph::PlexusError>),
+}
+
+impl From<crate::graph::PlexusError> for AdapterError {
+    fn from(e: crate::graph::PlexusError) -> Self {
+        match e {
+            crate::graph::PlexusError::ContextNotFound(id) => AdapterError::ContextNotFound(id.to_string()),
+            other => AdapterError::Engine(Box::new(other)),
+        }
+    }
 }
 
 /// The interface through which adapters push graph mutations into the engine.
@@ -128,6 +161,26 @@ pub trait AdapterSink: Send + Sync {
     /// Returns a result describing what was committed and what was rejected.
     /// The adapter can inspect rejections and act on them or ignore them.
     async fn emit(&self, emission: Emission) -> Result<EmitResult, AdapterError>;
+
+    /// Push several emissions as one batch, applied in order.
+    ///
+    /// The default emits each in turn. Sinks that can do better (one
+    /// storage transaction, one weight recompute) override this; the
+    /// combined result is the same either way.
+    async fn emit_batch(&self, emissions: Vec<Emission>) -> Result<EmitResult, AdapterError> {
+        let mut combined = EmitResult::empty();
+        for emission in emissions {
+            combined.absorb(self.emit(emission).await?);
+        }
+        Ok(combined)
+    }
+
+    /// Live nodes of `node_type` whose `content_hash` property is `hash`,
+    /// for adapters that deduplicate re-ingested content. Sinks that
+    /// can't read the graph find none.
+    async fn find_by_content_hash(&self, _node_type: &str, _hash: &str) -> Result<Vec<Node>, AdapterError> {
+        Ok(Vec::new())
+    }
 }
 
 #[cfg(test)]
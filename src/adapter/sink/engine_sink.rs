@@ -13,17 +13,21 @@
 //! scale normalization, and event firing are the same in both paths.
 //!
 //! The only difference is persistence: `Mutex` is ephemeral (unit tests),
-//! while `Engine` persists per-emission via `PlexusEngine::with_context_mut`
-//! (ADR-006). This design lets tests exercise the full validation/emission
+//! while `Engine` persists per-emission via `PlexusEngine::with_context_indexed`
+//! (ADR-006) and runs the engine's mutation hooks around each commit. This design lets tests exercise the full validation/emission
 //! pipeline without requiring a storage backend.
 
-use crate::graph::events::GraphEvent;
+use crate::graph::events::{changed_keys, GraphEvent};
 use super::provenance::{FrameworkContext, ProvenanceEntry};
 use super::contract::{AdapterError, AdapterSink, EmitResult, Rejection, RejectionReason};
 use crate::adapter::types::{AnnotatedEdge, AnnotatedNode, Emission};
-use crate::graph::{Context, ContextId, EdgeId, NodeId, PlexusEngine};
+use crate::graph::{
+    natural_key_slot, normalize_natural_key, resolve_head, Context, ContextId, ContextQuota, EdgeId, EdgePolicy, EmitIndexes, NaturalKeySlot,
+    Node, NodeId, PlexusEngine, WriteScope, SUPERSEDES,
+};
 use async_trait::async_trait;
 use chrono::Utc;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
 
 /// The backend that provides mutable context access.
@@ -52,6 +56,7 @@ enum SinkBackend {
 /// Two construction paths (ADR-006):
 /// - `new()`: test path using `Arc<Mutex<Context>>`, no persistence
 /// - `for_engine()`: engine path using PlexusEngine with persist-per-emission
+///
 /// EngineSink validates and commits emissions. It does NOT run enrichment.
 /// The enrichment loop is owned by IngestPipeline (ADR-029 Decision 2).
 pub struct EngineSink {
@@ -73,7 +78,7 @@ impl EngineSink {
 
     /// Create a sink backed by PlexusEngine (ADR-006).
     ///
-    /// Emissions route through `engine.with_context_mut()`, which persists
+    /// Emissions route through `engine.with_context_indexed()`, which persists
     /// the context to storage after each emission completes.
     pub fn for_engine(engine: Arc<PlexusEngine>, context_id: ContextId) -> Self {
         Self {
@@ -104,6 +109,59 @@ impl EngineSink {
         ctx: &mut Context,
         emission: Emission,
         framework: &Option<FrameworkContext>,
+    ) -> Result<EmitResult, AdapterError> {
+        let mut indexes = EmitIndexes::build(ctx);
+        Self::emit_indexed(ctx, &mut indexes, emission, framework)
+    }
+
+    /// `emit_inner` against the context's emit indexes, which it keeps
+    /// current (see `PlexusEngine::with_context_indexed`).
+    pub(crate) fn emit_indexed(
+        ctx: &mut Context,
+        indexes: &mut EmitIndexes,
+        emission: Emission,
+        framework: &Option<FrameworkContext>,
+    ) -> Result<EmitResult, AdapterError> {
+        Self::emit_phases(ctx, indexes, emission, framework, true)
+    }
+
+    /// Apply a batch of emissions in order against one context borrow.
+    ///
+    /// Each emission runs the same phases as `emit_inner`, except that the
+    /// scale-normalization pass (ADR-003) runs once at the end rather than
+    /// per emission. Events are concatenated in emission order.
+    pub(crate) fn emit_batch_inner(
+        ctx: &mut Context,
+        emissions: Vec<Emission>,
+        framework: &Option<FrameworkContext>,
+    ) -> Result<EmitResult, AdapterError> {
+        let mut indexes = EmitIndexes::build(ctx);
+        Self::emit_batch_indexed(ctx, &mut indexes, emissions, framework)
+    }
+
+    /// `emit_batch_inner` against the context's emit indexes.
+    fn emit_batch_indexed(
+        ctx: &mut Context,
+        indexes: &mut EmitIndexes,
+        emissions: Vec<Emission>,
+        framework: &Option<FrameworkContext>,
+    ) -> Result<EmitResult, AdapterError> {
+        let mut combined = EmitResult::empty();
+        for emission in emissions {
+            combined.absorb(Self::emit_phases(ctx, indexes, emission, framework, false)?);
+        }
+        if combined.edges_committed > 0 {
+            ctx.recompute_combined_weights();
+        }
+        Ok(combined)
+    }
+
+    fn emit_phases(
+        ctx: &mut Context,
+        indexes: &mut EmitIndexes,
+        emission: Emission,
+        framework: &Option<FrameworkContext>,
+        recompute_weights: bool,
     ) -> Result<EmitResult, AdapterError> {
         if emission.is_empty() {
             return Ok(EmitResult::empty());
@@ -116,29 +174,65 @@ impl EngineSink {
         let context_id = framework.as_ref().map(|fw| fw.context_id.clone())
             .unwrap_or_default();
 
+        // Phase 0: Drop what the adapter's write scope excludes
+        let mut emission = emission;
+        let scope = ctx.metadata.write_scopes.get(&adapter_id).cloned();
+        let mut early_rejections = match &scope {
+            Some(scope) => enforce_write_scope(ctx, indexes, &mut emission, scope),
+            None => Vec::new(),
+        };
+
+        // Phase 0.5: Drop nodes and property updates past the context quota
+        let quota = ctx.metadata.quota.clone();
+        if let Some(ref quota) = quota {
+            early_rejections.extend(enforce_node_quota(ctx, indexes, &mut emission, quota));
+        }
+
         // Phase 1: Commit nodes
-        let (mut committed_node_ids, provenance) = commit_nodes(ctx, emission.nodes, framework);
+        let mut property_changes = Vec::new();
+        let (mut committed_node_ids, provenance, aliases) =
+            commit_nodes(ctx, indexes, std::mem::take(&mut emission.nodes), framework, &mut property_changes);
         result.nodes_committed += committed_node_ids.len();
         result.provenance = provenance;
 
+        // Phase 1.5: Rewrite references to natural-key aliases
+        if !aliases.is_empty() {
+            resolve_aliases(&mut emission, &aliases);
+        }
+
+        // Phase 1.6: Drop new edges past the context quota
+        if let Some(ref quota) = quota {
+            early_rejections.extend(enforce_edge_quota(ctx, &mut emission.edges, quota));
+        }
+
         // Phase 2: Validate and commit edges
+        let mut annotated_edge_ids = Vec::new();
         let (committed_edge_ids, weights_changed_edge_ids, edge_rejections) =
-            commit_edges(ctx, emission.edges, &adapter_id);
+            commit_edges(ctx, indexes, emission.edges, &adapter_id, scope.as_ref(), recompute_weights, &mut annotated_edge_ids);
         result.edges_committed += committed_edge_ids.len();
-        result.rejections = edge_rejections;
+        result.rejections = early_rejections;
+        result.rejections.extend(edge_rejections);
 
         // Phase 2.5: Property updates (merge, not replace) — ADR-023
-        let updated_node_ids = apply_property_updates(ctx, emission.property_updates);
+        let updated_node_ids = apply_property_updates(ctx, emission.property_updates, &mut property_changes);
+        for id in &updated_node_ids {
+            indexes.sync_node(ctx, id);
+        }
         result.nodes_committed += updated_node_ids.len();
         committed_node_ids.extend(updated_node_ids);
 
         // Phase 3: Process edge removals (targeted)
         let (explicitly_removed_edge_ids, edge_removals_count) =
             remove_edges(ctx, emission.edge_removals);
+        indexes.remove_edges(&explicitly_removed_edge_ids);
         result.edge_removals_committed += edge_removals_count;
 
         // Phase 4: Process node removals (cascade connected edges)
         let (removed_node_ids, cascaded_edge_ids) = remove_nodes(ctx, emission.removals);
+        for id in &removed_node_ids {
+            indexes.sync_node(ctx, id);
+        }
+        indexes.remove_edges(&cascaded_edge_ids);
         result.removals_committed += removed_node_ids.len();
 
         // Phase 5: Fire graph events
@@ -186,38 +280,299 @@ impl EngineSink {
                 context_id: context_id.clone(),
             });
         }
+        if !property_changes.is_empty() {
+            result.events.push(GraphEvent::NodePropertiesChanged {
+                changes: property_changes,
+                adapter_id: adapter_id.clone(),
+                context_id: context_id.clone(),
+            });
+        }
+        if !annotated_edge_ids.is_empty() {
+            result.events.push(GraphEvent::EdgeAnnotated {
+                edge_ids: annotated_edge_ids,
+                adapter_id: adapter_id.clone(),
+                context_id: context_id.clone(),
+            });
+        }
+
+        // Phase 6: Maintain materialized views in the same commit
+        crate::query::maintain_views(ctx, &result.events);
+        crate::query::maintain_closures(ctx, &result.events);
 
         Ok(result)
     }
 
+    /// Commit a batch to an engine context atomically: it applies to a
+    /// copy of the context that replaces it only if every emission passes
+    /// the hooks and commits and the save succeeds. Events are persisted
+    /// once, for the whole batch.
+    pub(crate) fn commit_batch(
+        engine: &PlexusEngine,
+        context_id: &ContextId,
+        emissions: Vec<Emission>,
+        framework: &Option<FrameworkContext>,
+    ) -> Result<EmitResult, AdapterError> {
+        let result = engine.with_context_atomic(context_id, |ctx, indexes| {
+            // A veto of any emission fails the whole batch
+            let mut emissions = emissions;
+            for emission in &mut emissions {
+                engine.pre_commit(ctx, emission).map_err(Self::map_engine_error)?;
+            }
+            Self::emit_batch_indexed(ctx, indexes, emissions, framework)
+        }).map_err(Self::map_engine_error)??;
+        Self::record_emission(engine, context_id, framework, &result);
+        engine.persist_events(&result.events);
+        Ok(result)
+    }
+
+    /// Record what a committed emission produced, best-effort: an
+    /// emission that added or updated nothing leaves no record. Call it
+    /// before persisting the emission's events, so a record about a
+    /// deferred save commits with it.
+    pub(crate) fn record_emission(engine: &PlexusEngine, context_id: &ContextId, framework: &Option<FrameworkContext>, result: &EmitResult) {
+        let (mut node_ids, mut edge_ids) = (Vec::new(), Vec::new());
+        for event in &result.events {
+            match event {
+                GraphEvent::NodesAdded { node_ids: ids, .. } => node_ids.extend(ids.iter().cloned()),
+                GraphEvent::NodePropertiesChanged { changes, .. } => node_ids.extend(changes.iter().map(|(id, _)| id.clone())),
+                GraphEvent::EdgesAdded { edge_ids: ids, .. }
+                | GraphEvent::WeightsChanged { edge_ids: ids, .. }
+                | GraphEvent::EdgeAnnotated { edge_ids: ids, .. } => edge_ids.extend(ids.iter().cloned()),
+                _ => {}
+            }
+        }
+        let mut seen = HashSet::new();
+        node_ids.retain(|id| seen.insert(id.clone()));
+        let mut seen = HashSet::new();
+        edge_ids.retain(|id| seen.insert(id.clone()));
+        if node_ids.is_empty() && edge_ids.is_empty() {
+            return;
+        }
+        let adapter_id = framework.as_ref().map_or("", |fw| fw.adapter_id.as_str());
+        let input_summary = framework.as_ref().and_then(|fw| fw.input_summary.as_deref());
+        if let Err(e) = engine.record_emission(context_id, adapter_id, input_summary, &node_ids, &edge_ids) {
+            tracing::warn!(adapter_id, error = %e, "could not record emission");
+        }
+    }
+
     /// Map a PlexusError to an AdapterError.
     pub(crate) fn map_engine_error(e: crate::graph::PlexusError) -> AdapterError {
-        match e {
-            crate::graph::PlexusError::ContextNotFound(id) =>
-                AdapterError::ContextNotFound(id.to_string()),
-            other => AdapterError::Storage(other.to_string()),
-        }
+        AdapterError::from(e)
     }
 
 }
 
 // === emit_inner phase helpers ===
 
-/// Phase 1: Commit nodes (upsert semantics). Returns committed IDs and provenance entries.
+/// Emitted node ID → canonical node ID, for natural-key upserts.
+type NodeAliases = HashMap<NodeId, NodeId>;
+
+/// Phase 0: Remove the nodes, property updates and removals outside the
+/// adapter's write scope. Edges are checked in phase 2, once their
+/// endpoints' dimensions are known.
+fn enforce_write_scope(ctx: &Context, indexes: &EmitIndexes, emission: &mut Emission, scope: &WriteScope) -> Vec<Rejection> {
+    let mut rejections = Vec::new();
+    let mut admit = |description: String, check: Result<(), String>| match check {
+        Ok(()) => true,
+        Err(reason) => {
+            rejections.push(Rejection::new(description, RejectionReason::OutOfScope(reason)));
+            false
+        }
+    };
+    // Nodes not in the graph yet are judged when emitted, not here
+    let existing = |id: &NodeId| ctx.get_node(id).map_or(Ok(()), |node| scope.check_node(node));
+    // An upsert lands on the node of its ID, or on the one owning its
+    // natural key, and may move it: both dimensions must be in scope
+    let upserted = |node: &Node| {
+        node.natural_key()
+            .and_then(|key| indexes.natural_key_owner(&(node.dimension.clone(), node.node_type.clone(), normalize_natural_key(key))))
+            .map_or_else(|| existing(&node.id), existing)
+    };
+    // Removing a node trashes its edges too; each must be in scope
+    let cascade = |id: &NodeId| {
+        ctx.edges
+            .iter()
+            .filter(|e| &e.source == id || &e.target == id)
+            .try_for_each(|e| scope.check_edge(e).map_err(|reason| format!("removal would take edge {} with it: {}", e.id, reason)))
+    };
+
+    emission.nodes.retain(|n| admit(format!("node {}", n.node.id), scope.check_node(&n.node).and_then(|()| upserted(&n.node))));
+    emission
+        .property_updates
+        .retain(|u| admit(format!("property update of node {}", u.node_id), existing(&u.node_id)));
+    emission
+        .removals
+        .retain(|r| admit(format!("removal of node {}", r.node_id), existing(&r.node_id).and_then(|()| cascade(&r.node_id))));
+    emission.edge_removals.retain(|r| {
+        let check = if !scope.allows_relationship(&r.relationship) {
+            Err(format!("relationship '{}' is outside the write scope", r.relationship))
+        } else {
+            existing(&r.source).or_else(|_| existing(&r.target))
+        };
+        admit(format!("removal of edge {}→{}", r.source, r.target), check)
+    });
+    rejections
+}
+
+/// Phase 0.5: Drop nodes with oversized properties or beyond `max_nodes`,
+/// and property updates that would make a node oversized. Upserts of
+/// existing nodes — by ID or by natural key — don't count against
+/// `max_nodes`.
+fn enforce_node_quota(ctx: &Context, indexes: &EmitIndexes, emission: &mut Emission, quota: &ContextQuota) -> Vec<Rejection> {
+    let mut rejections = Vec::new();
+    let mut room = quota.max_nodes.map(|max| max.saturating_sub(ctx.node_count()));
+    let mut admitted = HashSet::new();
+    // Slots claimed by new nodes admitted earlier in the emission
+    let mut claimed: HashSet<NaturalKeySlot> = HashSet::new();
+    emission.nodes.retain(|n| {
+        let id = &n.node.id;
+        let slot = natural_key_slot(&n.node);
+        let check = quota.check_node(&n.node).and_then(|()| {
+            if ctx.get_node(id).is_some() || admitted.contains(id) {
+                return Ok(());
+            }
+            // Commits onto the node already owning its natural key
+            if let Some(slot) = slot.as_ref() {
+                if indexes.natural_key_owner(slot).is_some() || claimed.contains(slot) {
+                    return Ok(());
+                }
+            }
+            match room.as_mut() {
+                Some(0) => Err(format!("context holds {} nodes (limit {})", ctx.node_count(), quota.max_nodes.unwrap_or(0))),
+                Some(left) => {
+                    *left -= 1;
+                    if let Some(slot) = slot {
+                        claimed.insert(slot);
+                    }
+                    Ok(())
+                }
+                None => Ok(()),
+            }
+        });
+        match check {
+            Ok(()) => {
+                admitted.insert(id.clone());
+                true
+            }
+            Err(reason) => {
+                rejections.push(Rejection::new(format!("node {}", id), RejectionReason::QuotaExceeded(reason)));
+                false
+            }
+        }
+    });
+    if quota.max_property_bytes.is_some() {
+        emission.property_updates.retain(|u| {
+            let Some(node) = ctx.get_node(&u.node_id) else { return true };
+            let mut merged = node.properties.clone();
+            merged.extend(u.properties.iter().map(|(k, v)| (k.clone(), v.clone())));
+            match quota.check_properties(&merged) {
+                Ok(()) => true,
+                Err(reason) => {
+                    rejections.push(Rejection::new(
+                        format!("property update of node {}", u.node_id),
+                        RejectionReason::QuotaExceeded(reason),
+                    ));
+                    false
+                }
+            }
+        });
+    }
+    rejections
+}
+
+/// Phase 1.6: Drop edges beyond `max_edges`. Edges that merge into an
+/// existing one (or an earlier one in the emission) don't count.
+fn enforce_edge_quota(ctx: &Context, edges: &mut Vec<AnnotatedEdge>, quota: &ContextQuota) -> Vec<Rejection> {
+    let Some(max) = quota.max_edges else {
+        return Vec::new();
+    };
+    let mut rejections = Vec::new();
+    let mut room = max.saturating_sub(ctx.edge_count());
+    let mut admitted = HashSet::new();
+    edges.retain(|e| {
+        let edge = &e.edge;
+        if ctx.find_edge_identity(edge).is_some() {
+            return true;
+        }
+        let key = match ctx.edge_policy(&edge.relationship) {
+            EdgePolicy::Merge => format!(
+                "{}\u{1f}{}\u{1f}{}\u{1f}{}\u{1f}{}",
+                edge.source, edge.source_dimension, edge.target, edge.target_dimension, edge.relationship
+            ),
+            EdgePolicy::Parallel => edge.id.to_string(),
+        };
+        if admitted.contains(&key) {
+            return true;
+        }
+        if room == 0 {
+            rejections.push(Rejection::new(
+                format!("edge {}→{}", edge.source, edge.target),
+                RejectionReason::QuotaExceeded(format!("context holds {} edges (limit {})", ctx.edge_count(), max)),
+            ));
+            return false;
+        }
+        room -= 1;
+        admitted.insert(key);
+        true
+    });
+    rejections
+}
+
+/// Phase 1: Commit nodes (upsert semantics). Returns committed IDs,
+/// provenance entries, and natural-key aliases (emitted ID → existing ID).
+///
+/// A node whose natural key is already owned by a node with a different ID
+/// upserts onto that node instead of creating a duplicate.
 fn commit_nodes(
     ctx: &mut Context,
+    indexes: &mut EmitIndexes,
     nodes: Vec<AnnotatedNode>,
     framework: &Option<FrameworkContext>,
-) -> (Vec<NodeId>, Vec<(NodeId, ProvenanceEntry)>) {
+    property_changes: &mut Vec<(NodeId, Vec<String>)>,
+) -> (Vec<NodeId>, Vec<(NodeId, ProvenanceEntry)>, NodeAliases) {
     let timestamp = Utc::now();
     let mut committed = Vec::new();
     let mut provenance = Vec::new();
+    let mut aliases = HashMap::new();
 
     for annotated_node in nodes {
-        let node_id = annotated_node.node.id.clone();
+        let mut node = annotated_node.node;
         let annotation = annotated_node.annotation;
 
-        ctx.add_node(annotated_node.node);
+        if let Some(existing) = natural_key_slot(&node).and_then(|slot| indexes.natural_key_owner(&slot)) {
+            if *existing != node.id {
+                tracing::debug!(
+                    emitted = %node.id,
+                    existing = %existing,
+                    "natural key matched existing node; upserting onto it"
+                );
+                aliases.insert(node.id.clone(), existing.clone());
+                node.id = existing.clone();
+            }
+        }
+
+        // A re-emitted node may move dimension; incident edges follow it
+        let moved = ctx.get_node(&node.id).is_some_and(|old| old.dimension != node.dimension);
+        if let Some(old) = ctx.get_node(&node.id) {
+            let keys = changed_keys(&old.properties, &node.properties);
+            if !keys.is_empty() {
+                property_changes.push((node.id.clone(), keys));
+            }
+        }
+        let node_id = node.id.clone();
+        let dimension = node.dimension.clone();
+        ctx.add_node(node);
+        indexes.sync_node(ctx, &node_id);
+        if moved {
+            for edge in ctx.edges.iter_mut() {
+                if edge.source == node_id {
+                    edge.source_dimension = dimension.clone();
+                }
+                if edge.target == node_id {
+                    edge.target_dimension = dimension.clone();
+                }
+            }
+        }
         committed.push(node_id.clone());
 
         if let Some(ref fw) = framework {
@@ -226,20 +581,57 @@ fn commit_nodes(
         }
     }
 
-    (committed, provenance)
+    (committed, provenance, aliases)
+}
+
+/// Phase 1.5: Point edges, updates, and removals at canonical node IDs.
+fn resolve_aliases(emission: &mut Emission, aliases: &NodeAliases) {
+    let resolve = |id: &mut NodeId| {
+        if let Some(canonical) = aliases.get(id) {
+            *id = canonical.clone();
+        }
+    };
+    for annotated_edge in &mut emission.edges {
+        resolve(&mut annotated_edge.edge.source);
+        resolve(&mut annotated_edge.edge.target);
+    }
+    for update in &mut emission.property_updates {
+        resolve(&mut update.node_id);
+    }
+    for removal in &mut emission.edge_removals {
+        resolve(&mut removal.source);
+        resolve(&mut removal.target);
+    }
+    for removal in &mut emission.removals {
+        resolve(&mut removal.node_id);
+    }
 }
 
-/// Phase 2: Validate and commit edges. Returns (committed IDs, weight-changed IDs, rejections).
+/// Phase 2: Validate and commit edges. Returns (committed IDs, weight-changed IDs, rejections);
+/// existing edges whose properties changed are recorded in `annotated`.
 fn commit_edges(
     ctx: &mut Context,
+    indexes: &mut EmitIndexes,
     edges: Vec<AnnotatedEdge>,
     adapter_id: &str,
+    scope: Option<&WriteScope>,
+    recompute_weights: bool,
+    annotated: &mut Vec<EdgeId>,
 ) -> (Vec<EdgeId>, Vec<EdgeId>, Vec<Rejection>) {
     let mut committed = Vec::new();
     let mut weights_changed = Vec::new();
     let mut rejections = Vec::new();
 
-    for annotated_edge in edges {
+    // Edges emitted against a superseded node land on its head version
+    let successors = indexes.successors();
+    // Version edges committed here, indexed once the loop is done
+    let mut versions = Vec::new();
+
+    for mut annotated_edge in edges {
+        if !successors.is_empty() && annotated_edge.edge.relationship != SUPERSEDES {
+            annotated_edge.edge.source = resolve_head(successors, &annotated_edge.edge.source);
+            annotated_edge.edge.target = resolve_head(successors, &annotated_edge.edge.target);
+        }
         let edge = &annotated_edge.edge;
 
         if ctx.get_node(&edge.source).is_none() {
@@ -257,8 +649,48 @@ fn commit_edges(
             continue;
         }
 
+        if !edge.combined_weight.is_finite() || edge.contributions.values().any(|v| !v.is_finite()) {
+            rejections.push(Rejection::new(
+                format!("edge {}→{}", edge.source, edge.target),
+                RejectionReason::Other("non-finite weight".to_string()),
+            ));
+            continue;
+        }
+
         let mut edge_to_commit = annotated_edge.edge;
 
+        // Edge dimensions name the endpoints' dimensions
+        edge_to_commit.source_dimension = ctx.nodes[&edge_to_commit.source].dimension.clone();
+        edge_to_commit.target_dimension = ctx.nodes[&edge_to_commit.target].dimension.clone();
+
+        // Edges named by an inverse are stored under the declared
+        // relationship, reversed; the ontology may then reject them
+        if let Some(ontology) = &ctx.metadata.relationship_ontology {
+            let resolved = ontology.resolve(&edge_to_commit.relationship);
+            if resolved.reversed {
+                let declared = resolved.name.to_string();
+                let e = &mut edge_to_commit;
+                std::mem::swap(&mut e.source, &mut e.target);
+                std::mem::swap(&mut e.source_dimension, &mut e.target_dimension);
+                e.relationship = declared;
+            }
+            if let Err(reason) = ontology.check(&edge_to_commit) {
+                rejections.push(Rejection::new(
+                    format!("edge {}→{}", edge_to_commit.source, edge_to_commit.target),
+                    RejectionReason::Other(reason),
+                ));
+                continue;
+            }
+        }
+
+        if let Some(Err(reason)) = scope.map(|s| s.check_edge(&edge_to_commit)) {
+            rejections.push(Rejection::new(
+                format!("edge {}→{}", edge_to_commit.source, edge_to_commit.target),
+                RejectionReason::OutOfScope(reason),
+            ));
+            continue;
+        }
+
         // ADR-003: Set contribution for the emitting adapter — unless the
         // edge already carries an explicit contributions map (enrichments
         // like the lens populate per-source keys; adding the emitter's
@@ -271,57 +703,84 @@ fn commit_edges(
             );
         }
 
-        // ADR-003: Detect contribution change for WeightsChanged event
-        let mut contribution_changed = false;
-        if !adapter_id.is_empty() {
-            if let Some(idx) = ctx.find_edge_exact(
-                &edge_to_commit.source,
-                &edge_to_commit.target,
-                &edge_to_commit.relationship,
-                &edge_to_commit.source_dimension,
-                &edge_to_commit.target_dimension,
-            ) {
-                let old_value = ctx.edges[idx].contributions.get(adapter_id);
-                let new_value = Some(&contribution_value);
-                contribution_changed = old_value != new_value;
-            }
-        }
+        // ADR-003: Detect contribution change for WeightsChanged event,
+        // comparing the slot after the adapter's ContributionMode applies
+        let identity = ctx.find_edge_identity(&edge_to_commit);
+        let existing = identity
+            .filter(|_| !adapter_id.is_empty())
+            .map(|idx| (idx, ctx.edges[idx].contributions.get(adapter_id).copied()));
+        let old_properties = identity.map(|idx| (idx, ctx.edges[idx].properties.clone()));
 
         let edge_id = edge_to_commit.id.clone();
+        if edge_to_commit.relationship == SUPERSEDES {
+            // Under the ID of the edge it merges into, if any
+            let mut version = edge_to_commit.clone();
+            if let Some(idx) = identity {
+                version.id = ctx.edges[idx].id.clone();
+            }
+            versions.push(version);
+        }
         ctx.add_edge(edge_to_commit);
         committed.push(edge_id.clone());
+        let contribution_changed = existing
+            .is_some_and(|(idx, old_value)| ctx.edges[idx].contributions.get(adapter_id).copied() != old_value);
+        if old_properties.is_some_and(|(idx, old)| ctx.edges[idx].properties != old) {
+            annotated.push(edge_id.clone());
+        }
 
         if contribution_changed {
             weights_changed.push(edge_id);
         }
     }
 
+    for version in &versions {
+        indexes.sync_version_edge(version);
+    }
+
     // ADR-003: Recompute raw weights via scale normalization
-    if !committed.is_empty() {
+    // (deferred to the end of the batch for `emit_batch_inner`)
+    if recompute_weights && !committed.is_empty() {
         ctx.recompute_combined_weights();
     }
 
     (committed, weights_changed, rejections)
 }
 
-/// Phase 2.5: Apply property updates (merge, not replace). Returns updated node IDs.
+/// Phase 2.5: Apply property updates (merge, not replace). Returns updated
+/// node IDs; nodes whose values changed are recorded in `property_changes`.
 fn apply_property_updates(
     ctx: &mut Context,
     updates: Vec<crate::adapter::types::PropertyUpdate>,
+    property_changes: &mut Vec<(NodeId, Vec<String>)>,
 ) -> Vec<NodeId> {
     let mut updated = Vec::new();
     for update in updates {
         if let Some(node) = ctx.get_node_mut(&update.node_id) {
+            let mut keys = Vec::new();
             for (key, value) in update.properties {
+                if node.properties.get(&key) != Some(&value) {
+                    keys.push(key.clone());
+                }
                 node.properties.insert(key, value);
             }
+            if !keys.is_empty() {
+                keys.sort();
+                match property_changes.iter_mut().find(|(id, _)| *id == update.node_id) {
+                    Some((_, changed)) => {
+                        changed.extend(keys);
+                        changed.sort();
+                        changed.dedup();
+                    }
+                    None => property_changes.push((update.node_id.clone(), keys)),
+                }
+            }
             updated.push(update.node_id);
         }
     }
     updated
 }
 
-/// Phase 3: Process targeted edge removals. Returns (removed IDs, removal count).
+/// Phase 3: Move targeted edge removals to the trash. Returns (removed IDs, removal count).
 fn remove_edges(
     ctx: &mut Context,
     edge_removals: Vec<crate::adapter::types::EdgeRemoval>,
@@ -329,24 +788,18 @@ fn remove_edges(
     let mut removed = Vec::new();
     let mut count = 0;
     for edge_removal in edge_removals {
-        let before = ctx.edges.len();
-        ctx.edges.retain(|e| {
-            if e.source == edge_removal.source
+        let trashed = ctx.trash_edges_where(|e| {
+            e.source == edge_removal.source
                 && e.target == edge_removal.target
                 && e.relationship == edge_removal.relationship
-            {
-                removed.push(e.id.clone());
-                false
-            } else {
-                true
-            }
         });
-        count += before - ctx.edges.len();
+        count += trashed.len();
+        removed.extend(trashed);
     }
     (removed, count)
 }
 
-/// Phase 4: Process node removals with edge cascade. Returns (removed node IDs, cascaded edge IDs).
+/// Phase 4: Move removed nodes, and their edges, to the trash. Returns (removed node IDs, cascaded edge IDs).
 fn remove_nodes(
     ctx: &mut Context,
     removals: Vec<crate::adapter::types::Removal>,
@@ -354,16 +807,8 @@ fn remove_nodes(
     let mut removed_nodes = Vec::new();
     let mut cascaded_edges = Vec::new();
     for removal in removals {
-        if ctx.get_node(&removal.node_id).is_some() {
-            for edge in ctx.edges.iter() {
-                if edge.source == removal.node_id || edge.target == removal.node_id {
-                    cascaded_edges.push(edge.id.clone());
-                }
-            }
-            ctx.nodes.remove(&removal.node_id);
-            ctx.edges.retain(|e| {
-                e.source != removal.node_id && e.target != removal.node_id
-            });
+        if let Some(cascaded) = ctx.trash_node(&removal.node_id) {
+            cascaded_edges.extend(cascaded);
             removed_nodes.push(removal.node_id);
         }
     }
@@ -391,8 +836,10 @@ impl AdapterSink for EngineSink {
             }
             SinkBackend::Engine { engine, context_id } => {
                 let framework = self.framework.clone();
-                let result = engine.with_context_mut(context_id, |ctx| {
-                    Self::emit_inner(ctx, emission, &framework)
+                let result = engine.with_context_indexed(context_id, |ctx, indexes| {
+                    let mut emission = emission;
+                    engine.pre_commit(ctx, &mut emission).map_err(Self::map_engine_error)?;
+                    Self::emit_indexed(ctx, indexes, emission, &framework)
                 }).map_err(Self::map_engine_error)??;
 
                 tracing::debug!(
@@ -403,6 +850,7 @@ impl AdapterSink for EngineSink {
                 );
 
                 // Persist events to event log (ADR-035, best-effort)
+                Self::record_emission(engine, context_id, &self.framework, &result);
                 engine.persist_events(&result.events);
 
                 // Accumulate events for pipeline collection
@@ -413,13 +861,50 @@ impl AdapterSink for EngineSink {
             }
         }
     }
+
+    async fn find_by_content_hash(&self, node_type: &str, hash: &str) -> Result<Vec<Node>, AdapterError> {
+        let find = |ctx: &Context| ctx.find_by_content_hash(node_type, hash).into_iter().cloned().collect();
+        match &self.backend {
+            SinkBackend::Mutex(context) => {
+                let ctx = context.lock().map_err(|e| AdapterError::Internal(format!("lock poisoned: {}", e)))?;
+                Ok(find(&ctx))
+            }
+            SinkBackend::Engine { engine, context_id } => {
+                engine.with_context(context_id, find).map_err(Self::map_engine_error)
+            }
+        }
+    }
+
+    /// Batch emission: one context borrow, one weight recompute, and on the
+    /// engine path one persistence transaction and one event-log write.
+    async fn emit_batch(&self, emissions: Vec<Emission>) -> Result<EmitResult, AdapterError> {
+        let result = match &self.backend {
+            SinkBackend::Mutex(context) => {
+                let mut ctx = context.lock().map_err(|e| {
+                    AdapterError::Internal(format!("lock poisoned: {}", e))
+                })?;
+                Self::emit_batch_inner(&mut ctx, emissions, &self.framework)?
+            }
+            SinkBackend::Engine { engine, context_id } => Self::commit_batch(engine, context_id, emissions, &self.framework)?,
+        };
+
+        tracing::debug!(
+            nodes = result.nodes_committed,
+            edges = result.edges_committed,
+            rejections = result.rejections.len(),
+            "emission batch committed"
+        );
+        self.accumulated_events.lock().unwrap()
+            .extend(result.events.clone());
+        Ok(result)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::adapter::Emission;
-    use crate::graph::{ContentType, Edge, Node, NodeId};
+    use crate::graph::{dimension, ContentType, Edge, EdgePolicy, Node, NodeId, PropertyValue};
 
     fn make_sink() -> (EngineSink, Arc<Mutex<Context>>) {
         let ctx = Arc::new(Mutex::new(Context::new("test")));
@@ -1055,7 +1540,7 @@ mod tests {
         let ctx = ctx.lock().unwrap();
         let edge = &ctx.edges[0];
         assert!(
-            edge.contributions.get("lens:probe").is_none(),
+            !edge.contributions.contains_key("lens:probe"),
             "emitter slot must not be added when explicit contributions exist: {:?}",
             edge.contributions
         );
@@ -1216,6 +1701,28 @@ mod tests {
         assert!(weights_changed, "cross-source reinforcement should fire WeightsChanged");
     }
 
+    // === Scenario: Parallel-policy relationship keeps one edge per emitted ID ===
+    #[tokio::test]
+    async fn parallel_policy_emissions_keep_distinct_edges() {
+        let (sink, ctx) = make_sink_with_adapter("citations");
+        {
+            let mut c = ctx.lock().unwrap();
+            c.set_edge_policy("related_to", EdgePolicy::Parallel);
+            c.add_node(node("A"));
+            c.add_node(node("B"));
+        }
+
+        let first = edge("A", "B");
+        let repeat = first.clone();
+        sink.emit(Emission::new().with_edge(first).with_edge(edge("A", "B"))).await.unwrap();
+        let result = sink.emit(Emission::new().with_edge(repeat)).await.unwrap();
+
+        let ctx = ctx.lock().unwrap();
+        assert_eq!(ctx.edge_count(), 2, "distinct IDs coexist; same ID is idempotent");
+        assert!(ctx.edges.iter().all(|e| e.contributions.get("citations") == Some(&1.0)));
+        assert!(!result.events.iter().any(|e| matches!(e, GraphEvent::WeightsChanged { .. })));
+    }
+
     // === Scenario: Re-processing with unchanged results is idempotent across all edges ===
     #[tokio::test]
     async fn reprocessing_unchanged_is_idempotent() {
@@ -1896,4 +2403,475 @@ mod tests {
         assert_eq!(ctx2.node_count(), 3, "three nodes survive storage round-trip");
         assert_eq!(ctx2.edge_count(), 2, "two edges survive storage round-trip");
     }
+
+    // ================================================================
+    // Batch emission
+    // ================================================================
+
+    fn weighted_edge(source: &str, target: &str, weight: f32) -> Edge {
+        let mut e = edge(source, target);
+        e.combined_weight = weight;
+        e
+    }
+
+    fn weight_batch() -> Vec<Emission> {
+        vec![
+            Emission::new()
+                .with_node(node("A"))
+                .with_node(node("B"))
+                .with_node(node("C")),
+            Emission::new().with_edge(weighted_edge("A", "B", 2.0)),
+            Emission::new().with_edge(weighted_edge("B", "C", 8.0)),
+        ]
+    }
+
+    // === Scenario: Batch applies emissions in order ===
+    #[tokio::test]
+    async fn batch_applies_emissions_in_order() {
+        let (sink, ctx) = make_sink_with_adapter("bulk");
+
+        let result = sink
+            .emit_batch(vec![
+                Emission::new().with_node(node("A")).with_node(node("B")),
+                // Endpoints committed by an earlier emission in the batch
+                Emission::new().with_edge(edge("A", "B")),
+                Emission::new().with_removal(NodeId::from_string("B")),
+                Emission::new().with_node(node("B")),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(result.nodes_committed, 3);
+        assert_eq!(result.edges_committed, 1);
+        assert_eq!(result.removals_committed, 1);
+        let ctx = ctx.lock().unwrap();
+        assert!(ctx.get_node(&NodeId::from_string("B")).is_some(), "re-added after removal");
+        assert_eq!(ctx.edge_count(), 0, "edge cascaded by the removal");
+    }
+
+    // === Scenario: Batch weights match sequential emission ===
+    #[tokio::test]
+    async fn batch_weights_match_sequential_emission() {
+        let (batch_sink, batch_ctx) = make_sink_with_adapter("bulk");
+        batch_sink.emit_batch(weight_batch()).await.unwrap();
+
+        let (seq_sink, seq_ctx) = make_sink_with_adapter("bulk");
+        for emission in weight_batch() {
+            seq_sink.emit(emission).await.unwrap();
+        }
+
+        let weights = |ctx: &Arc<Mutex<Context>>| {
+            let ctx = ctx.lock().unwrap();
+            let mut w: Vec<(String, f32)> = ctx
+                .edges()
+                .map(|e| (format!("{}->{}", e.source, e.target), e.combined_weight))
+                .collect();
+            w.sort_by(|a, b| a.0.cmp(&b.0));
+            w
+        };
+        assert_eq!(weights(&batch_ctx), weights(&seq_ctx));
+    }
+
+    // === Scenario: Batch events are concatenated in emission order ===
+    #[tokio::test]
+    async fn batch_events_concatenate_in_emission_order() {
+        let (sink, _ctx) = make_sink_with_adapter("bulk");
+        let result = sink.emit_batch(weight_batch()).await.unwrap();
+
+        let kinds: Vec<&str> = result
+            .events
+            .iter()
+            .map(|e| match e {
+                GraphEvent::NodesAdded { .. } => "nodes_added",
+                GraphEvent::EdgesAdded { .. } => "edges_added",
+                _ => "other",
+            })
+            .collect();
+        assert_eq!(kinds, vec!["nodes_added", "edges_added", "edges_added"]);
+        assert_eq!(sink.drain_events().len(), 3, "batch events accumulate for the pipeline");
+    }
+
+    // === Scenario: Engine-backed batch persists once and logs events ===
+    #[tokio::test]
+    async fn engine_batch_persists_and_logs_events() {
+        let store = Arc::new(SqliteStore::open_in_memory().unwrap());
+        let engine = Arc::new(PlexusEngine::with_store(store.clone()));
+        let ctx_id = ContextId::from("bulk-import");
+        engine.upsert_context(Context::with_id(ctx_id.clone(), "bulk-import")).unwrap();
+
+        let sink = make_engine_sink(&engine, &ctx_id, "bulk");
+        sink.emit_batch(weight_batch()).await.unwrap();
+
+        assert_eq!(engine.latest_sequence(ctx_id.as_str()).unwrap(), 3);
+        let engine2 = PlexusEngine::with_store(store);
+        engine2.load_all().unwrap();
+        let ctx2 = engine2.get_context(&ctx_id).unwrap();
+        assert_eq!(ctx2.node_count(), 3);
+        assert_eq!(ctx2.edge_count(), 2);
+    }
+
+    // === Scenario: Default emit_batch emits each emission ===
+    #[tokio::test]
+    async fn default_emit_batch_delegates_to_emit() {
+        struct CountingSink(Mutex<usize>);
+
+        #[async_trait]
+        impl AdapterSink for CountingSink {
+            async fn emit(&self, _emission: Emission) -> Result<EmitResult, AdapterError> {
+                *self.0.lock().unwrap() += 1;
+                let mut result = EmitResult::empty();
+                result.nodes_committed = 1;
+                Ok(result)
+            }
+        }
+
+        let sink = CountingSink(Mutex::new(0));
+        let result = sink.emit_batch(weight_batch()).await.unwrap();
+        assert_eq!(*sink.0.lock().unwrap(), 3);
+        assert_eq!(result.nodes_committed, 3);
+    }
+
+    // ================================================================
+    // Natural-key upsert
+    // ================================================================
+
+    fn keyed(id: &str, key: &str) -> Node {
+        node(id).with_natural_key(key)
+    }
+
+    // === Scenario: Keyed node with a different ID upserts onto the existing node ===
+    #[tokio::test]
+    async fn natural_key_folds_differently_spelled_ids() {
+        let (sink, ctx) = make_sink_with_adapter("a");
+        sink.emit(Emission::new().with_node(keyed("concept:machine-learning", "Machine Learning")))
+            .await
+            .unwrap();
+        sink.emit(
+            Emission::new()
+                .with_node(keyed("concept:machine_learning", "machine  learning"))
+                .with_node(node("doc"))
+                // References the alias — rewritten to the canonical ID
+                .with_edge(edge("doc", "concept:machine_learning")),
+        )
+        .await
+        .unwrap();
+
+        let ctx = ctx.lock().unwrap();
+        assert!(ctx.get_node(&NodeId::from_string("concept:machine_learning")).is_none());
+        assert_eq!(ctx.node_count(), 2);
+        let e = ctx.edges().next().expect("edge committed against canonical node");
+        assert_eq!(e.target.as_str(), "concept:machine-learning");
+        assert_eq!(
+            ctx.find_by_natural_key(dimension::DEFAULT, "concept", "MACHINE LEARNING"),
+            Some(&NodeId::from_string("concept:machine-learning"))
+        );
+    }
+
+    // === Scenario: Natural keys are scoped by dimension and node type ===
+    #[tokio::test]
+    async fn natural_key_scoped_by_dimension_and_type() {
+        let (sink, ctx) = make_sink_with_adapter("a");
+        let mut other_type = keyed("tag:travel", "travel");
+        other_type.node_type = "tag".to_string();
+        let other_dim = keyed("concept:travel-structure", "travel").with_dimension(dimension::STRUCTURE);
+        sink.emit(
+            Emission::new()
+                .with_node(keyed("concept:travel", "travel"))
+                .with_node(other_type)
+                .with_node(other_dim),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(ctx.lock().unwrap().node_count(), 3);
+    }
+
+    // === Scenario: Keyed duplicates within one emission collapse to the first ===
+    #[tokio::test]
+    async fn natural_key_collapses_within_one_emission() {
+        let (sink, ctx) = make_sink_with_adapter("a");
+        let result = sink
+            .emit(
+                Emission::new()
+                    .with_node(keyed("concept:a", "same"))
+                    .with_node(keyed("concept:b", "same").with_property("extra", true)),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.nodes_committed, 2, "both emitted nodes commit (second as upsert)");
+        let ctx = ctx.lock().unwrap();
+        assert_eq!(ctx.node_count(), 1);
+        let merged = ctx.get_node(&NodeId::from_string("concept:a")).unwrap();
+        assert_eq!(merged.get_bool("extra"), Some(true), "later emission wins, as with ID upsert");
+    }
+
+    // === Scenario: Committed edges record their endpoints' dimensions ===
+    #[tokio::test]
+    async fn edge_dimensions_follow_endpoints() {
+        let (sink, ctx) = make_sink_with_adapter("test");
+        let fragment = node("F").with_dimension(dimension::STRUCTURE);
+        let concept = node("C").with_dimension(dimension::SEMANTIC);
+        let claimed = Edge::new_in_dimension(
+            NodeId::from_string("F"),
+            NodeId::from_string("C"),
+            "tagged_with",
+            dimension::TEMPORAL,
+        );
+        sink.emit(Emission::new().with_node(fragment).with_node(concept).with_edge(claimed)).await.unwrap();
+        {
+            let ctx = ctx.lock().unwrap();
+            assert_eq!(ctx.edges[0].source_dimension, dimension::STRUCTURE);
+            assert_eq!(ctx.edges[0].target_dimension, dimension::SEMANTIC);
+        }
+
+        // Re-emitting a node into another dimension carries its edges along
+        sink.emit(Emission::new().with_node(node("C").with_dimension(dimension::RELATIONAL))).await.unwrap();
+        let ctx = ctx.lock().unwrap();
+        assert_eq!(ctx.edges[0].target_dimension, dimension::RELATIONAL);
+        assert_eq!(ctx.check_invariants(), Ok(()));
+    }
+
+    // === Scenario: Edges with non-finite weights are rejected ===
+    #[tokio::test]
+    async fn non_finite_weights_are_rejected() {
+        let (sink, ctx) = make_sink_with_adapter("test");
+        let mut nan = edge("A", "B");
+        nan.combined_weight = f32::NAN;
+        let result = sink.emit(Emission::new().with_node(node("A")).with_node(node("B")).with_edge(nan)).await.unwrap();
+
+        assert_eq!(result.edges_committed, 0);
+        assert_eq!(result.rejections.len(), 1);
+        assert!(ctx.lock().unwrap().edges.is_empty());
+    }
+
+    // === Scenario: A strict ontology rejects unknown relationships and stores inverses canonically ===
+    #[tokio::test]
+    async fn strict_ontology_validates_relationships() {
+        use crate::graph::{RelationshipOntology, RelationshipType};
+
+        let (sink, ctx) = make_sink_with_adapter("test");
+        ctx.lock().unwrap().metadata.relationship_ontology = Some(
+            RelationshipOntology::new()
+                .with_type("contains", RelationshipType::directed().with_inverse("contained_in"))
+                .strict(),
+        );
+        let result = sink
+            .emit(
+                Emission::new()
+                    .with_node(node("A"))
+                    .with_node(node("B"))
+                    .with_edge(Edge::new(NodeId::from_string("B"), NodeId::from_string("A"), "contained_in"))
+                    .with_edge(Edge::new(NodeId::from_string("A"), NodeId::from_string("B"), "cites")),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.edges_committed, 1);
+        assert_eq!(result.rejections.len(), 1);
+        assert!(matches!(&result.rejections[0].reason, RejectionReason::Other(r) if r.contains("cites")));
+        let ctx = ctx.lock().unwrap();
+        assert_eq!(ctx.edges.len(), 1);
+        assert_eq!(ctx.edges[0].relationship, "contains");
+        assert_eq!((ctx.edges[0].source.as_str(), ctx.edges[0].target.as_str()), ("A", "B"));
+    }
+
+    // === Scenario: A write scope confines an adapter to its dimensions and relationships ===
+    #[tokio::test]
+    async fn write_scope_rejects_out_of_scope_items() {
+        use crate::graph::WriteScope;
+
+        let (sink, ctx) = make_sink_with_adapter("llm-extractor");
+        {
+            let mut ctx = ctx.lock().unwrap();
+            ctx.add_node(node("doc").with_dimension(dimension::STRUCTURE));
+            ctx.metadata.write_scopes.insert(
+                "llm-extractor".to_string(),
+                WriteScope::new().with_dimension(dimension::SEMANTIC).with_relationship("tagged_with"),
+            );
+        }
+        let result = sink
+            .emit(
+                Emission::new()
+                    .with_node(node("ml").with_dimension(dimension::SEMANTIC))
+                    .with_node(node("section").with_dimension(dimension::STRUCTURE))
+                    .with_edge(Edge::new(NodeId::from_string("doc"), NodeId::from_string("ml"), "tagged_with"))
+                    .with_edge(Edge::new(NodeId::from_string("doc"), NodeId::from_string("ml"), "cites"))
+                    .with_removal(NodeId::from_string("doc")),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.nodes_committed, 1);
+        assert_eq!(result.edges_committed, 1, "a cross-dimensional edge is in scope through its semantic end");
+        assert_eq!(result.removals_committed, 0);
+        assert_eq!(result.rejections.len(), 3);
+        assert!(result.rejections.iter().all(|r| matches!(r.reason, RejectionReason::OutOfScope(_))));
+        let ctx = ctx.lock().unwrap();
+        assert!(ctx.get_node(&NodeId::from_string("doc")).is_some());
+        assert!(ctx.get_node(&NodeId::from_string("section")).is_none());
+        assert_eq!(ctx.check_invariants(), Ok(()));
+    }
+
+    // === Scenario: A write scope covers the nodes and edges an emission lands on ===
+    #[tokio::test]
+    async fn write_scope_checks_upsert_targets_and_removal_cascades() {
+        use crate::graph::WriteScope;
+
+        let (sink, ctx) = make_sink_with_adapter("llm-extractor");
+        {
+            let mut ctx = ctx.lock().unwrap();
+            ctx.add_node(node("doc").with_dimension(dimension::STRUCTURE));
+            ctx.add_node(node("ml").with_dimension(dimension::SEMANTIC));
+            ctx.add_node(node("ai").with_dimension(dimension::SEMANTIC));
+            ctx.add_edge(Edge::new(NodeId::from_string("ml"), NodeId::from_string("ai"), "cites"));
+            ctx.metadata.write_scopes.insert(
+                "llm-extractor".to_string(),
+                WriteScope::new().with_dimension(dimension::SEMANTIC).with_relationship("tagged_with"),
+            );
+        }
+        let result = sink
+            .emit(
+                Emission::new()
+                    .with_node(node("doc").with_dimension(dimension::SEMANTIC))
+                    .with_removal(NodeId::from_string("ml")),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.nodes_committed, 0, "an upsert can't pull a structure node into its own dimension");
+        assert_eq!(result.removals_committed, 0, "the removal would cascade to an out-of-scope 'cites' edge");
+        assert_eq!(result.rejections.len(), 2);
+        let ctx = ctx.lock().unwrap();
+        assert_eq!(ctx.get_node(&NodeId::from_string("doc")).unwrap().dimension, dimension::STRUCTURE);
+        assert_eq!(ctx.edges.len(), 1);
+    }
+
+    // === Scenario: A context quota caps growth without failing the emission ===
+    #[tokio::test]
+    async fn context_quota_rejects_growth_past_its_limits() {
+        use crate::graph::ContextQuota;
+
+        let (sink, ctx) = make_sink_with_adapter("runaway");
+        {
+            let mut ctx = ctx.lock().unwrap();
+            ctx.add_node(node("A"));
+            ctx.metadata.quota = Some(ContextQuota::new().with_max_nodes(3).with_max_edges(1).with_max_property_bytes(64));
+        }
+        let result = sink
+            .emit(
+                Emission::new()
+                    .with_node(node("A").with_property("seen", true))
+                    .with_node(node("B"))
+                    .with_node(node("huge").with_property("text", "x".repeat(100).as_str()))
+                    .with_node(node("C"))
+                    .with_node(node("D"))
+                    .with_edge(edge("A", "B"))
+                    .with_edge(edge("A", "B"))
+                    .with_edge(edge("B", "C")),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.nodes_committed, 3, "the upsert of A is free; B and C fill the quota");
+        assert_eq!(result.edges_committed, 2, "the repeated A→B merges rather than counting twice");
+        let reasons: Vec<String> = result.rejections.iter().map(|r| r.reason.to_string()).collect();
+        assert_eq!(reasons.len(), 3, "{reasons:?}");
+        assert!(reasons.iter().all(|r| r.starts_with("quota exceeded")), "{reasons:?}");
+        let ctx = ctx.lock().unwrap();
+        assert_eq!((ctx.node_count(), ctx.edge_count()), (3, 1));
+        assert!(ctx.get_node(&NodeId::from_string("huge")).is_none());
+        assert!(ctx.metadata.quota.as_ref().unwrap().warning(&ctx).is_some());
+    }
+
+    // === Scenario: Natural-key upserts don't count against the node quota ===
+    #[tokio::test]
+    async fn context_quota_admits_natural_key_upserts() {
+        use crate::graph::ContextQuota;
+
+        let (sink, ctx) = make_sink_with_adapter("runaway");
+        {
+            let mut ctx = ctx.lock().unwrap();
+            ctx.add_node(node("paper-1").with_natural_key("doi:10.1/x"));
+            ctx.metadata.quota = Some(ContextQuota::new().with_max_nodes(1));
+        }
+        let result = sink
+            .emit(Emission::new().with_node(node("paper-2").with_natural_key("doi:10.1/x")).with_node(node("other")))
+            .await
+            .unwrap();
+
+        assert_eq!(result.nodes_committed, 1, "the keyed node lands on paper-1");
+        assert_eq!(result.rejections.len(), 1, "only the new node is over quota");
+        let ctx = ctx.lock().unwrap();
+        assert_eq!(ctx.node_count(), 1);
+        assert!(ctx.get_node(&NodeId::from_string("other")).is_none());
+    }
+
+    // === Scenario: Re-emissions accumulate by the adapter's mode ===
+    #[tokio::test]
+    async fn reemission_accumulates_by_contribution_mode() {
+        use crate::graph::ContributionMode;
+
+        let (sink, ctx) = make_sink_with_adapter("scanner");
+        ctx.lock().unwrap().set_contribution_mode("scanner", ContributionMode::Sum);
+        let weighted = |w: f32| {
+            let mut e = edge("A", "B");
+            e.combined_weight = w;
+            e
+        };
+        sink.emit(Emission::new().with_node(node("A")).with_node(node("B")).with_edge(weighted(0.5))).await.unwrap();
+        let result = sink.emit(Emission::new().with_edge(weighted(0.25))).await.unwrap();
+        assert_eq!(ctx.lock().unwrap().edges[0].contributions["scanner"], 0.75);
+        assert!(result.events.iter().any(|e| matches!(e, GraphEvent::WeightsChanged { .. })));
+
+        ctx.lock().unwrap().set_contribution_mode("scanner", ContributionMode::Max);
+        let result = sink.emit(Emission::new().with_edge(weighted(0.5))).await.unwrap();
+        assert_eq!(ctx.lock().unwrap().edges[0].contributions["scanner"], 0.75);
+        assert!(
+            !result.events.iter().any(|e| matches!(e, GraphEvent::WeightsChanged { .. })),
+            "a weaker emission under Max leaves the slot unchanged"
+        );
+    }
+
+    // === Scenario: Updates to existing nodes and edges fire change events ===
+    #[tokio::test]
+    async fn updates_fire_property_and_annotation_events() {
+        let (sink, _ctx) = make_sink();
+        let mut a = node("A");
+        a.properties.insert("label".into(), PropertyValue::from("rye"));
+        sink.emit(Emission::new().with_node(a.clone()).with_node(node("B")).with_edge(edge("A", "B"))).await.unwrap();
+
+        let first = sink.emit(Emission::new().with_node(a.clone())).await.unwrap();
+        assert!(
+            !first.events.iter().any(|e| matches!(e, GraphEvent::NodePropertiesChanged { .. })),
+            "an identical re-emission changes nothing"
+        );
+
+        a.properties.insert("label".into(), PropertyValue::from("spelt"));
+        a.properties.insert("lang".into(), PropertyValue::from("en"));
+        let mut annotated = edge("A", "B");
+        annotated.properties.insert("note".into(), PropertyValue::from("see p. 4"));
+        let update = crate::adapter::types::PropertyUpdate {
+            node_id: NodeId::from_string("B"),
+            properties: [("stale".to_string(), PropertyValue::Bool(true))].into_iter().collect(),
+        };
+        let result = sink
+            .emit(Emission::new().with_node(a).with_edge(annotated).with_property_update(update))
+            .await
+            .unwrap();
+
+        let changes = result.events.iter().find_map(|e| match e {
+            GraphEvent::NodePropertiesChanged { changes, .. } => Some(changes.clone()),
+            _ => None,
+        });
+        assert_eq!(
+            changes,
+            Some(vec![
+                (NodeId::from_string("A"), vec!["label".to_string(), "lang".to_string()]),
+                (NodeId::from_string("B"), vec!["stale".to_string()]),
+            ])
+        );
+        assert!(result.events.iter().any(|e| matches!(e, GraphEvent::EdgeAnnotated { edge_ids, .. } if edge_ids.len() == 1)));
+        assert!(result.events.iter().any(|e| matches!(e, GraphEvent::NodesAdded { .. })), "upserts still fire NodesAdded");
+    }
 }
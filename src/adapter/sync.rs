@@ -0,0 +1,414 @@
+//! Two-way sync with external stores
+//!
+//! One-shot ingestion can't keep Plexus and an external system (a Trellis
+//! or Carrel store) in step. A `Synchronizer` runs rounds against an
+//! `ExternalStore`: it pulls the store's changes since its cursor and
+//! ingests them, then pushes the outbound events of local ingests made
+//! through it since the last round. Pulled changes don't echo back.
+//!
+//! A pulled change conflicts when the nodes it touched last time it was
+//! synced have changed locally since. The `ConflictPolicy` decides who
+//! wins. Change fingerprints live with the synchronizer, so a new one
+//! starts without conflict history; the pull cursor is kept in the
+//! context's properties (`sync:{store}:cursor`) and survives restarts.
+
+use super::pipeline::IngestPipeline;
+use super::sink::AdapterError;
+use super::types::OutboundEvent;
+use crate::graph::{file_content_hash, Context, ContextId, NodeId, PlexusEngine};
+use async_trait::async_trait;
+use chrono::Utc;
+use serde_json::{json, Value};
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A change in an external store, as input for the pipeline.
+#[derive(Debug)]
+pub struct RemoteChange {
+    /// The store's identity for the changed item; repeated changes to the
+    /// same item share it
+    pub key: String,
+    pub input_kind: String,
+    pub data: Box<dyn Any + Send + Sync>,
+    /// Graph nodes the change writes, for conflict detection
+    pub node_ids: Vec<NodeId>,
+}
+
+impl RemoteChange {
+    pub fn new(key: impl Into<String>, input_kind: impl Into<String>, data: impl Any + Send + Sync) -> Self {
+        Self { key: key.into(), input_kind: input_kind.into(), data: Box::new(data), node_ids: Vec::new() }
+    }
+
+    /// Declare the nodes the change writes.
+    pub fn touching(mut self, node_ids: impl IntoIterator<Item = NodeId>) -> Self {
+        self.node_ids.extend(node_ids);
+        self
+    }
+}
+
+/// Changes after a cursor, and the cursor to resume from.
+#[derive(Debug, Default)]
+pub struct RemoteChanges {
+    pub changes: Vec<RemoteChange>,
+    /// `None` keeps the previous cursor
+    pub cursor: Option<String>,
+}
+
+/// An external system Plexus syncs with.
+#[async_trait]
+pub trait ExternalStore: Send + Sync {
+    /// Stable identifier, used to key the stored cursor
+    fn id(&self) -> &str;
+
+    /// Changes after `cursor` (`None` for everything).
+    async fn fetch_changes(&self, cursor: Option<&str>) -> Result<RemoteChanges, AdapterError>;
+
+    /// Deliver outbound events of local ingests.
+    async fn push(&self, events: &[OutboundEvent]) -> Result<(), AdapterError>;
+}
+
+/// Who wins when a pulled change meets a local edit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConflictPolicy {
+    /// Skip the pulled change and keep the local edit
+    #[default]
+    LocalWins,
+    /// Apply the pulled change over the local edit
+    RemoteWins,
+}
+
+/// A pulled change that met a local edit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncConflict {
+    pub key: String,
+    /// Whether the pulled change was applied
+    pub applied: bool,
+}
+
+/// Outcome of one sync round.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SyncReport {
+    pub pulled: usize,
+    pub applied: usize,
+    pub conflicts: Vec<SyncConflict>,
+    pub pushed: usize,
+    /// Cursor after the round
+    pub cursor: Option<String>,
+}
+
+/// Runs sync rounds between one context and one external store.
+pub struct Synchronizer {
+    engine: Arc<PlexusEngine>,
+    pipeline: Arc<IngestPipeline>,
+    context_id: ContextId,
+    store: Arc<dyn ExternalStore>,
+    policy: ConflictPolicy,
+    /// Nodes each synced key touched, and their fingerprint then
+    synced: HashMap<String, (Vec<NodeId>, String)>,
+    /// Outbound events of local ingests awaiting push
+    pending: Vec<OutboundEvent>,
+}
+
+impl Synchronizer {
+    pub fn new(
+        engine: Arc<PlexusEngine>,
+        pipeline: Arc<IngestPipeline>,
+        context_id: ContextId,
+        store: Arc<dyn ExternalStore>,
+    ) -> Self {
+        Self {
+            engine,
+            pipeline,
+            context_id,
+            store,
+            policy: ConflictPolicy::default(),
+            synced: HashMap::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    pub fn with_conflict_policy(mut self, policy: ConflictPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Ingest local input, queueing its outbound events for the next push.
+    pub async fn ingest_local(
+        &mut self,
+        input_kind: &str,
+        data: Box<dyn Any + Send + Sync>,
+    ) -> Result<Vec<OutboundEvent>, AdapterError> {
+        let events = self.pipeline.ingest(self.context_id.as_str(), input_kind, data).await?;
+        self.pending.extend(events.iter().cloned());
+        Ok(events)
+    }
+
+    /// Outbound events awaiting push.
+    pub fn pending(&self) -> &[OutboundEvent] {
+        &self.pending
+    }
+
+    /// The stored pull cursor.
+    pub fn cursor(&self) -> Option<String> {
+        let context = self.engine.get_context(&self.context_id)?;
+        context.metadata.properties.get(&self.cursor_key()).cloned()
+    }
+
+    /// Pull and apply remote changes, then push pending local events.
+    /// Pending events stay queued if the push fails.
+    pub async fn sync(&mut self) -> Result<SyncReport, AdapterError> {
+        let cursor = self.cursor();
+        let remote = self.store.fetch_changes(cursor.as_deref()).await?;
+        let mut report = SyncReport { pulled: remote.changes.len(), ..SyncReport::default() };
+
+        for change in remote.changes {
+            let context = self
+                .engine
+                .get_context(&self.context_id)
+                .ok_or_else(|| AdapterError::ContextNotFound(self.context_id.to_string()))?;
+            let edited_locally = self
+                .synced
+                .get(&change.key)
+                .is_some_and(|(node_ids, fingerprint)| fingerprint_nodes(&context, node_ids) != *fingerprint);
+            if edited_locally {
+                let applied = self.policy == ConflictPolicy::RemoteWins;
+                report.conflicts.push(SyncConflict { key: change.key.clone(), applied });
+                if !applied {
+                    // The local edit is the new baseline, so later pulls of
+                    // this key only conflict with later local edits
+                    let fingerprint = fingerprint_nodes(&context, &change.node_ids);
+                    self.synced.insert(change.key, (change.node_ids, fingerprint));
+                    continue;
+                }
+            }
+            self.pipeline.ingest(self.context_id.as_str(), &change.input_kind, change.data).await?;
+            report.applied += 1;
+
+            let context = self
+                .engine
+                .get_context(&self.context_id)
+                .ok_or_else(|| AdapterError::ContextNotFound(self.context_id.to_string()))?;
+            let fingerprint = fingerprint_nodes(&context, &change.node_ids);
+            self.synced.insert(change.key, (change.node_ids, fingerprint));
+        }
+
+        if let Some(next) = remote.cursor {
+            let key = self.cursor_key();
+            self.engine
+                .with_context_mut(&self.context_id, |ctx| {
+                    ctx.metadata.properties.insert(key, next);
+                    ctx.metadata.updated_at = Some(Utc::now());
+                })
+                .map_err(AdapterError::from)?;
+        }
+
+        if !self.pending.is_empty() {
+            self.store.push(&self.pending).await?;
+            report.pushed = self.pending.len();
+            self.pending.clear();
+        }
+        report.cursor = self.cursor();
+        Ok(report)
+    }
+
+    fn cursor_key(&self) -> String {
+        format!("sync:{}:cursor", self.store.id())
+    }
+}
+
+/// Hash of the nodes' current types and properties (missing nodes count),
+/// over a canonical serialization: JSON objects keep their keys sorted, so
+/// equal nodes hash equally whatever their maps' iteration order.
+fn fingerprint_nodes(context: &Context, node_ids: &[NodeId]) -> String {
+    let nodes: Vec<Value> = node_ids
+        .iter()
+        .map(|id| match context.get_node(id) {
+            Some(node) => json!([id.as_str(), node.node_type, node.properties]),
+            None => json!([id.as_str(), Value::Null]),
+        })
+        .collect();
+    file_content_hash(Value::Array(nodes).to_string().as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::{Adapter, AdapterInput, AdapterSink, Emission};
+    use crate::graph::{ContentType, Node, PropertyValue};
+    use std::sync::Mutex;
+
+    struct Note {
+        id: String,
+        text: String,
+    }
+
+    fn note(id: &str, text: &str) -> Note {
+        Note { id: id.into(), text: text.into() }
+    }
+
+    struct NoteAdapter;
+
+    #[async_trait]
+    impl Adapter for NoteAdapter {
+        fn id(&self) -> &str {
+            "notes"
+        }
+
+        fn input_kind(&self) -> &str {
+            "note"
+        }
+
+        async fn process(&self, input: &AdapterInput, sink: &dyn AdapterSink) -> Result<(), AdapterError> {
+            let note = input.downcast_data::<Note>().ok_or(AdapterError::InvalidInput)?;
+            let mut node = Node::new("note", ContentType::Document);
+            node.id = NodeId::from_string(format!("note:{}", note.id));
+            node.properties.insert("text".into(), PropertyValue::from(note.text.as_str()));
+            sink.emit(Emission::new().with_node(node)).await?;
+            Ok(())
+        }
+
+        fn transform_events(&self, _events: &[crate::graph::events::GraphEvent], _context: &Context) -> Vec<OutboundEvent> {
+            vec![OutboundEvent::new("note_saved", "")]
+        }
+    }
+
+    /// (key, note id, text)
+    type Batch = Vec<(&'static str, &'static str, &'static str)>;
+
+    /// Serves queued batches, numbering cursors.
+    #[derive(Default)]
+    struct FakeStore {
+        batches: Mutex<Vec<Batch>>,
+        cursors_seen: Mutex<Vec<Option<String>>>,
+        pushed: Mutex<Vec<OutboundEvent>>,
+    }
+
+    #[async_trait]
+    impl ExternalStore for FakeStore {
+        fn id(&self) -> &str {
+            "trellis"
+        }
+
+        async fn fetch_changes(&self, cursor: Option<&str>) -> Result<RemoteChanges, AdapterError> {
+            self.cursors_seen.lock().unwrap().push(cursor.map(String::from));
+            let mut batches = self.batches.lock().unwrap();
+            if batches.is_empty() {
+                return Ok(RemoteChanges::default());
+            }
+            let batch = batches.remove(0);
+            let next = cursor.map_or(0, |c| c.parse::<u32>().unwrap()) + 1;
+            Ok(RemoteChanges {
+                changes: batch
+                    .into_iter()
+                    .map(|(key, id, text)| {
+                        RemoteChange::new(key, "note", note(id, text))
+                            .touching([NodeId::from_string(format!("note:{}", id))])
+                    })
+                    .collect(),
+                cursor: Some(next.to_string()),
+            })
+        }
+
+        async fn push(&self, events: &[OutboundEvent]) -> Result<(), AdapterError> {
+            self.pushed.lock().unwrap().extend_from_slice(events);
+            Ok(())
+        }
+    }
+
+    fn setup(store: Arc<FakeStore>, policy: ConflictPolicy) -> (Arc<PlexusEngine>, Synchronizer) {
+        let engine = Arc::new(PlexusEngine::new());
+        let context_id = engine.upsert_context(Context::new("synced")).unwrap();
+        let pipeline = Arc::new(IngestPipeline::new(engine.clone()));
+        pipeline.register_adapter(Arc::new(NoteAdapter));
+        let sync = Synchronizer::new(engine.clone(), pipeline, context_id, store).with_conflict_policy(policy);
+        (engine, sync)
+    }
+
+    fn text(engine: &PlexusEngine, sync: &Synchronizer, id: &str) -> String {
+        let context = engine.get_context(&sync.context_id).unwrap();
+        let node = context.get_node(&NodeId::from_string(format!("note:{}", id))).unwrap();
+        node.get_str("text").unwrap().to_string()
+    }
+
+    // === Scenario: Rounds pull from the cursor and push local events once ===
+    #[tokio::test]
+    async fn sync_pulls_from_cursor_and_pushes_local_events() {
+        let store = Arc::new(FakeStore::default());
+        store.batches.lock().unwrap().push(vec![("a", "a", "remote a"), ("b", "b", "remote b")]);
+        let (engine, mut sync) = setup(store.clone(), ConflictPolicy::LocalWins);
+
+        sync.ingest_local("note", Box::new(note("c", "local c"))).await.unwrap();
+        let report = sync.sync().await.unwrap();
+        assert_eq!((report.pulled, report.applied, report.pushed), (2, 2, 1));
+        assert_eq!(report.cursor.as_deref(), Some("1"));
+        assert_eq!(text(&engine, &sync, "a"), "remote a");
+        assert!(sync.pending().is_empty());
+
+        let quiet = sync.sync().await.unwrap();
+        assert_eq!((quiet.pulled, quiet.pushed), (0, 0), "pulled changes aren't pushed back");
+        assert_eq!(store.pushed.lock().unwrap().len(), 1);
+        assert_eq!(*store.cursors_seen.lock().unwrap(), vec![None, Some("1".to_string())]);
+    }
+
+    // === Scenario: The conflict policy decides between a local edit and a pulled change ===
+    #[tokio::test]
+    async fn conflicting_changes_follow_policy() {
+        for (policy, expected) in [(ConflictPolicy::LocalWins, "local edit"), (ConflictPolicy::RemoteWins, "remote v2")] {
+            let store = Arc::new(FakeStore::default());
+            store.batches.lock().unwrap().push(vec![("a", "a", "remote v1"), ("b", "b", "remote b")]);
+            let (engine, mut sync) = setup(store.clone(), policy);
+            sync.sync().await.unwrap();
+
+            sync.ingest_local("note", Box::new(note("a", "local edit"))).await.unwrap();
+            store.batches.lock().unwrap().push(vec![("a", "a", "remote v2"), ("b", "b", "remote b2")]);
+            let report = sync.sync().await.unwrap();
+
+            assert_eq!(
+                report.conflicts,
+                vec![SyncConflict { key: "a".into(), applied: policy == ConflictPolicy::RemoteWins }]
+            );
+            assert_eq!(text(&engine, &sync, "a"), expected);
+            assert_eq!(text(&engine, &sync, "b"), "remote b2", "unedited items still sync");
+        }
+    }
+
+    // === Scenario: A kept local edit becomes the baseline for later pulls ===
+    #[tokio::test]
+    async fn local_wins_rebaselines_the_kept_edit() {
+        let store = Arc::new(FakeStore::default());
+        store.batches.lock().unwrap().push(vec![("a", "a", "remote v1")]);
+        let (engine, mut sync) = setup(store.clone(), ConflictPolicy::LocalWins);
+        sync.sync().await.unwrap();
+
+        sync.ingest_local("note", Box::new(note("a", "local edit"))).await.unwrap();
+        store.batches.lock().unwrap().push(vec![("a", "a", "remote v2")]);
+        assert_eq!(sync.sync().await.unwrap().conflicts.len(), 1);
+
+        store.batches.lock().unwrap().push(vec![("a", "a", "remote v3")]);
+        let report = sync.sync().await.unwrap();
+        assert!(report.conflicts.is_empty(), "the kept edit isn't flagged again");
+        assert_eq!(text(&engine, &sync, "a"), "remote v3");
+    }
+
+    // === Scenario: Fingerprints don't depend on property map order ===
+    #[test]
+    fn fingerprints_are_canonical() {
+        let mut context = Context::new("fp");
+        let mut node = Node::new("note", ContentType::Document);
+        node.id = NodeId::from_string("note:a");
+        for (k, v) in [("x", 1), ("y", 2), ("z", 3), ("w", 4)] {
+            node.properties.insert(k.into(), PropertyValue::Int(v));
+        }
+        let mut reordered = node.clone();
+        let mut entries: Vec<_> = node.properties.clone().into_iter().collect();
+        entries.reverse();
+        reordered.properties = entries.into_iter().collect();
+        let ids = [node.id.clone()];
+
+        context.add_node(node);
+        let before = fingerprint_nodes(&context, &ids);
+        context.add_node(reordered);
+        assert_eq!(fingerprint_nodes(&context, &ids), before);
+    }
+}
@@ -6,7 +6,7 @@
 use crate::graph::events::GraphEvent;
 use super::sink::{AdapterError, AdapterSink};
 use super::types::OutboundEvent;
-use crate::graph::Context;
+use crate::graph::{Context, TagPolicy};
 use async_trait::async_trait;
 use std::any::Any;
 
@@ -19,6 +19,8 @@ pub struct AdapterInput {
     pub data: Box<dyn Any + Send + Sync>,
     /// Processing context ID
     pub context_id: String,
+    /// The context's tag policy, for adapters that turn tags into concepts
+    pub tag_policy: Option<TagPolicy>,
 }
 
 impl AdapterInput {
@@ -27,6 +29,7 @@ impl AdapterInput {
             kind: kind.into(),
             data: Box::new(data),
             context_id: context_id.into(),
+            tag_policy: None,
         }
     }
 
@@ -36,9 +39,16 @@ impl AdapterInput {
             kind: kind.into(),
             data,
             context_id: context_id.into(),
+            tag_policy: None,
         }
     }
 
+    /// Attach the context's tag policy.
+    pub fn with_tag_policy(mut self, policy: Option<TagPolicy>) -> Self {
+        self.tag_policy = policy;
+        self
+    }
+
     /// Attempt to downcast the data payload to a specific type.
     pub fn downcast_data<T: 'static>(&self) -> Option<&T> {
         self.data.downcast_ref::<T>()
@@ -68,6 +78,26 @@ pub trait Adapter: Send + Sync {
         sink: &dyn AdapterSink,
     ) -> Result<(), AdapterError>;
 
+    /// JSON Schema for this adapter's input kind. When declared, the
+    /// pipeline validates JSON payloads against it before `process` and
+    /// rejects them with `AdapterError::Validation`; typed payloads pass
+    /// through unchecked.
+    ///
+    /// Default: no schema.
+    fn input_schema(&self) -> Option<serde_json::Value> {
+        None
+    }
+
+    /// Decode a JSON payload into the input `process` downcasts
+    /// (`IngestPipeline::ingest_json`). Adapters with a typed input
+    /// override this with its `from_json` constructor, so malformed JSON
+    /// fails before anything is written.
+    ///
+    /// Default: the JSON value itself, for adapters that read it directly.
+    fn input_from_json(&self, json: &serde_json::Value) -> Result<Box<dyn Any + Send + Sync>, AdapterError> {
+        Ok(Box::new(json.clone()))
+    }
+
     /// Outbound: translate raw graph events into domain-meaningful events (ADR-011).
     ///
     /// Called after the enrichment loop completes with all accumulated events
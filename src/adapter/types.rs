@@ -8,6 +8,7 @@
 //! - Removal: a node ID to remove (edges cascade)
 
 use crate::graph::{Edge, Node, NodeId, PropertyValue};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Adapter-provided metadata about a single extraction.
@@ -226,6 +227,11 @@ impl Emission {
             && self.property_updates.is_empty()
     }
 
+    /// Items carried: nodes, edges, removals and property updates.
+    pub fn len(&self) -> usize {
+        self.nodes.len() + self.edges.len() + self.removals.len() + self.edge_removals.len() + self.property_updates.len()
+    }
+
     /// Merge another emission into this one by appending all vectors.
     pub fn merge(mut self, other: Emission) -> Self {
         self.nodes.extend(other.nodes);
@@ -249,7 +255,7 @@ impl Default for Emission {
 /// The consumer receives outbound events, never raw graph events.
 /// Deliberately unstructured — the consumer defines what `kind` values
 /// it cares about.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct OutboundEvent {
     /// Event type in the consumer's vocabulary (e.g., "concepts_detected")
     pub kind: String,
@@ -266,6 +272,18 @@ impl OutboundEvent {
     }
 }
 
+/// What an adapter does when it re-ingests content it has seen before
+/// (matched by `CONTENT_HASH_PROPERTY` via `AdapterSink::find_by_content_hash`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DedupPolicy {
+    /// Emit nothing
+    Skip,
+    /// Stamp `last_ingested_at` on the existing node, emit nothing else
+    UpdateTimestamp,
+    /// Emit a new version node linked to the previous one by `supersedes`
+    NewVersion,
+}
+
 // === Construction helpers ===
 
 /// Current UTC time as an ISO-8601 / RFC-3339 string — the authoritative
@@ -307,9 +325,31 @@ pub fn concept_node(label: &str) -> (NodeId, Node) {
         .insert("label".to_string(), PropertyValue::String(normalized));
     node.properties
         .insert("created_at".to_string(), rfc3339_now());
+    // Natural key lets the sink fold spelling variants of the same label
+    // ("machine  learning", " Machine Learning") onto one concept.
+    let node = node.with_natural_key(label);
     (id, node)
 }
 
+/// Create a place node with a deterministic `place:{lowercased name}` ID.
+///
+/// Sets `node_type = "place"` in `dimension::SPATIAL`, with `name` and, when
+/// known, `latitude`/`longitude` (decimal degrees). Places without
+/// coordinates can be located later by `GeocodingEnrichment`.
+pub fn place_node(name: &str, coordinates: Option<(f64, f64)>) -> (NodeId, Node) {
+    use crate::graph::{dimension, ContentType};
+    let normalized = crate::graph::normalize_natural_key(name);
+    let id = NodeId::from_string(format!("place:{}", normalized));
+    let mut node = Node::new_in_dimension("place", ContentType::Other("place".to_string()), dimension::SPATIAL);
+    node.id = id.clone();
+    node.properties.insert("name".to_string(), PropertyValue::String(name.trim().to_string()));
+    if let Some((latitude, longitude)) = coordinates {
+        node.properties.insert("latitude".to_string(), PropertyValue::Float(latitude));
+        node.properties.insert("longitude".to_string(), PropertyValue::Float(longitude));
+    }
+    (id, node.with_natural_key(name))
+}
+
 /// Create a chain node with the given ID.
 ///
 /// Sets `node_type = "chain"`, `ContentType::Provenance`, `dimension::PROVENANCE`.
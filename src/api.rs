@@ -9,14 +9,14 @@
 //! # Async vs sync boundary
 //!
 //! **Async** (`async fn`): operations that route through `IngestPipeline` —
-//! `ingest`, `update_mark`, `archive_chain`, `delete_mark`, `delete_chain`,
+//! `ingest`, `bootstrap`, `update_mark`, `archive_chain`, `delete_mark`, `delete_chain`,
 //! `link_marks`, `unlink_marks`. These involve adapter execution and
 //! potentially I/O-bound enrichment.
 //!
 //! **Sync** (`fn`): read-only operations that query the in-memory `DashMap`
-//! cache — `list_chains`, `get_chain`, `list_marks`, `list_tags`, `get_links`,
-//! `evidence_trail`, `find_nodes`, `traverse`, `find_path`, `context_*`.
-//! Also `retract_contributions` (mutates in-memory state synchronously).
+//! cache — `list_chains`, `get_chain`, `list_marks`, `list_tags`, `vocabulary`, `get_links`,
+//! `evidence_trail`, `find_similar`, `find_nodes`, `traverse`, `find_path`, `run_saved_query`, `read_view`, `context_*`.
+//! Also `retract_contributions` and `relocate_path` (mutate in-memory state synchronously).
 //!
 //! This split is intentional: reads are fast cache lookups with no I/O,
 //! while writes go through the async adapter pipeline.
@@ -25,31 +25,48 @@ use std::sync::Arc;
 
 use crate::adapter::{
     Adapter, AdapterError, AdapterSink, EngineSink, FrameworkContext,
-    IngestPipeline, OutboundEvent, ProvenanceInput,
+    IngestPipeline, OutboundEvent, ProvenanceInput, Simulation,
 };
 use crate::adapter::declarative::DeclarativeAdapter;
 use crate::graph::{
-    Context, ContextId, NodeId, PlexusEngine, PlexusError, PlexusResult, PropertyValue, Source,
+    Context, ContextId, ContextSync, ContributionAggregation, NodeId, Overlay, PlexusEngine, PlexusError, PlexusResult, PropertyValue, PrunePolicy,
+    EmbeddingConfig, PruneReport, PublishFilter, PublishManifest, Source, TagPolicy, WriteScope, ContextQuota, EnrichmentStats,
+    Relocation, RelocationReport, RelocationWatcher, Durability,
 };
 use crate::graph::events::GraphEvent;
 use crate::provenance::{ChainView, MarkView, ProvenanceApi};
 use crate::query::{
-    self, EvidenceTrailResult, FindQuery, PathQuery, PathResult, QueryFilter, QueryResult,
-    RankBy, TraversalResult, TraverseQuery,
+    self, EvidenceTrailResult, FindQuery, PathQuery, PathResult, PathScoring, QueryFilter, QueryResult,
+    RankBy, ReachabilityFilter, SavedQuery, SavedQueryResult, ScoredPath, TraversalResult, TraverseQuery, ViewSnapshot,
 };
-use crate::storage::PersistedSpec;
+use crate::storage::{CompactionReport, ManifestEntry, PersistedOutboundEvent, PersistedSpec};
+use std::collections::BTreeMap;
 
 /// Single entry point for all consumer-facing operations.
 #[derive(Clone)]
 pub struct PlexusApi {
     engine: Arc<PlexusEngine>,
     pipeline: Arc<IngestPipeline>,
+    /// Tenant whose contexts this API sees; `None` sees untenanted ones
+    tenant: Option<String>,
 }
 
 impl PlexusApi {
     /// Create a new API instance.
     pub fn new(engine: Arc<PlexusEngine>, pipeline: Arc<IngestPipeline>) -> Self {
-        Self { engine, pipeline }
+        Self { engine, pipeline, tenant: None }
+    }
+
+    /// Scope this API to `tenant`: context names resolve, list, and are
+    /// created within it, and writes are checked against its quota.
+    pub fn with_tenant(mut self, tenant: impl Into<String>) -> Self {
+        self.tenant = Some(tenant.into());
+        self
+    }
+
+    /// The tenant this API is scoped to, if any.
+    pub fn tenant(&self) -> Option<&str> {
+        self.tenant.as_deref()
     }
 
     // --- Write ---
@@ -79,9 +96,16 @@ impl PlexusApi {
         self.engine
             .reload_if_changed()
             .map_err(|e| AdapterError::Internal(format!("cache coherence reload failed: {}", e)))?;
-        self.engine
-            .resolve_by_name(context_name)
-            .ok_or_else(|| AdapterError::ContextNotFound(context_name.to_string()))
+        let ctx_id = self
+            .engine
+            .resolve_in_tenant(self.tenant(), context_name)
+            .ok_or_else(|| AdapterError::ContextNotFound(context_name.to_string()))?;
+        if let Some(tenant) = self.tenant() {
+            self.engine
+                .check_tenant_quota(tenant)
+                .map_err(|e| AdapterError::Internal(e.to_string()))?;
+        }
+        Ok(ctx_id)
     }
 
     /// The single write endpoint (ADR-012).
@@ -99,6 +123,55 @@ impl PlexusApi {
         self.pipeline.ingest(ctx_id.as_str(), input_kind, data).await
     }
 
+    /// Ingest a JSON payload, decoded by the matching adapter (see
+    /// `IngestPipeline::ingest_json`).
+    pub async fn ingest_json(
+        &self,
+        context_name: &str,
+        input_kind: &str,
+        data: serde_json::Value,
+    ) -> Result<Vec<OutboundEvent>, AdapterError> {
+        let ctx_id = self.resolve_for_ingest(context_name)?;
+        self.pipeline.ingest_json(ctx_id.as_str(), input_kind, data).await
+    }
+
+    /// Ingest under an idempotency key, so a retried call is answered
+    /// from the first call's result (see `IngestPipeline::ingest_idempotent`).
+    pub async fn ingest_idempotent(
+        &self,
+        context_name: &str,
+        input_kind: &str,
+        data: Box<dyn std::any::Any + Send + Sync>,
+        key: &str,
+    ) -> Result<Vec<OutboundEvent>, AdapterError> {
+        let ctx_id = self.resolve_for_ingest(context_name)?;
+        self.pipeline.ingest_idempotent(ctx_id.as_str(), input_kind, data, key).await
+    }
+
+    /// `ingest_json` under an idempotency key.
+    pub async fn ingest_json_idempotent(
+        &self,
+        context_name: &str,
+        input_kind: &str,
+        data: serde_json::Value,
+        key: &str,
+    ) -> Result<Vec<OutboundEvent>, AdapterError> {
+        let ctx_id = self.resolve_for_ingest(context_name)?;
+        self.pipeline.ingest_json_idempotent(ctx_id.as_str(), input_kind, data, key).await
+    }
+
+    /// Preview an ingest: the would-be result and graph diff, with
+    /// nothing persisted.
+    pub async fn simulate_ingest(
+        &self,
+        context_name: &str,
+        input_kind: &str,
+        data: Box<dyn std::any::Any + Send + Sync>,
+    ) -> Result<Simulation, AdapterError> {
+        let ctx_id = self.resolve_for_ingest(context_name)?;
+        self.pipeline.simulate(ctx_id.as_str(), input_kind, data).await
+    }
+
     // --- Provenance reads ---
 
     /// List chains in a context, optionally filtered by status.
@@ -136,6 +209,63 @@ impl PlexusApi {
         self.prov(context_id)?.list_tags()
     }
 
+    /// Tag vocabulary across every dimension — concepts and mark tags with
+    /// usage counts, first/last seen, contributing adapters, and trend.
+    pub fn vocabulary(&self, context_id: &str) -> PlexusResult<Vec<query::TagStats>> {
+        let ctx_id = self.resolve(context_id)?;
+        self.engine.vocabulary(&ctx_id)
+    }
+
+    /// Degree, edge-weight, and contribution-count histograms, plus the
+    /// highest-degree nodes — for spotting runaway super-nodes.
+    pub fn distributions(&self, context_id: &str) -> PlexusResult<query::GraphDistributions> {
+        let ctx_id = self.resolve(context_id)?;
+        self.engine.distributions(&ctx_id)
+    }
+
+    /// A consistent snapshot of a context for analytics that run
+    /// alongside ingest (see `PlexusEngine::reader`).
+    pub fn reader(&self, context_id: &str) -> PlexusResult<crate::graph::ContextReader> {
+        let ctx_id = self.resolve(context_id)?;
+        self.engine.reader(&ctx_id)
+    }
+
+    /// The context as it stood at `as_of` (see `PlexusEngine::context_as_of`).
+    pub fn context_as_of(
+        &self,
+        context_id: &str,
+        as_of: chrono::DateTime<chrono::Utc>,
+    ) -> PlexusResult<crate::graph::HistoricalView> {
+        let ctx_id = self.resolve(context_id)?;
+        self.engine.context_as_of(&ctx_id, as_of)
+    }
+
+    /// PageRank scores, computed on a snapshot so ingest isn't blocked.
+    pub fn pagerank(
+        &self,
+        context_id: &str,
+        config: &query::PageRankConfig,
+    ) -> PlexusResult<std::collections::HashMap<NodeId, f64>> {
+        let reader = self.reader(context_id)?;
+        Ok(query::pagerank(&reader, config))
+    }
+
+    /// Label-propagation communities, computed on a snapshot.
+    pub fn communities(
+        &self,
+        context_id: &str,
+        max_iterations: usize,
+    ) -> PlexusResult<std::collections::HashMap<NodeId, usize>> {
+        let reader = self.reader(context_id)?;
+        Ok(query::communities(&reader, max_iterations))
+    }
+
+    /// Nodes with at least `min_degree` edges, highest degree first.
+    pub fn super_nodes(&self, context_id: &str, min_degree: usize) -> PlexusResult<Vec<query::NodeDegree>> {
+        let ctx_id = self.resolve(context_id)?;
+        self.engine.super_nodes(&ctx_id, min_degree)
+    }
+
     /// Get incoming and outgoing links for a mark.
     pub fn get_links(
         &self,
@@ -168,6 +298,196 @@ impl PlexusApi {
         Ok(query::evidence_trail(node_id, &context, filter))
     }
 
+    /// The `k` nodes most similar to a node or a piece of text, by the
+    /// pipeline's configured embedder, each with its evidence trail's
+    /// fragments, marks, and chains as provenance.
+    pub fn find_similar(&self, context_id: &str, query: SimilarTo, k: usize) -> PlexusResult<Vec<SimilarNode>> {
+        let ctx_id = self.resolve(context_id)?;
+        let context = self
+            .engine
+            .get_context(&ctx_id)
+            .ok_or_else(|| PlexusError::ContextNotFound(ctx_id))?;
+        let search = self.similarity_search(Some(&context))?;
+        let ranked = match query {
+            SimilarTo::Node(ref id) => search.similar_to_node(&context, id, k),
+            SimilarTo::Text(ref text) => search.similar_to_text(&context, text, k),
+        }
+        .map_err(|e| PlexusError::Other(e.to_string()))?;
+        Ok(ranked
+            .into_iter()
+            .filter_map(|(id, score)| {
+                let node = context.get_node(&id)?;
+                let trail = query::evidence_trail(id.clone(), &context, None);
+                let ids = |nodes: Vec<crate::graph::Node>| nodes.into_iter().map(|n| n.id).collect();
+                Some(SimilarNode {
+                    label: node.properties.get("label").and_then(|v| v.as_str()).map(str::to_string),
+                    node_type: node.node_type.clone(),
+                    id,
+                    score,
+                    fragments: ids(trail.fragments),
+                    marks: ids(trail.marks),
+                    chains: ids(trail.chains),
+                })
+            })
+            .collect())
+    }
+
+    /// Embed `text` with the pipeline's configured embedder, e.g. as a
+    /// `HybridQuery` vector.
+    pub fn embed_query(&self, text: &str) -> PlexusResult<Vec<f32>> {
+        self.similarity_search(None)?.embed(text).map_err(|e| PlexusError::Other(e.to_string()))
+    }
+
+    /// Answer `question` from the context's graph through the pipeline's
+    /// llm-orc client (see `qa`).
+    pub async fn ask(&self, context_id: &str, question: &str) -> PlexusResult<crate::qa::Answer> {
+        let client = self
+            .pipeline
+            .llm_client()
+            .ok_or_else(|| PlexusError::Unavailable("no llm-orc client configured".into()))?;
+        crate::qa::QuestionAnswerer::new(client).ask(self, context_id, question).await
+    }
+
+    /// Record what one LLM invocation for `item` used against a context.
+    pub(crate) fn record_llm_cost(
+        &self,
+        context_id: &str,
+        adapter_id: &str,
+        item: &str,
+        ensemble: &str,
+        usage: crate::llm_orc::LlmUsage,
+    ) -> PlexusResult<()> {
+        let ctx_id = self.resolve(context_id)?;
+        self.engine.record_llm_cost(&ctx_id, adapter_id, item, ensemble, usage)
+    }
+
+    /// Rank nodes by embedding similarity to the query vector blended
+    /// with graph proximity to its seeds.
+    pub fn hybrid_search(&self, context_id: &str, query: query::HybridQuery) -> PlexusResult<query::HybridResult> {
+        let ctx_id = self.resolve(context_id)?;
+        let context = self
+            .engine
+            .get_context(&ctx_id)
+            .ok_or_else(|| PlexusError::ContextNotFound(ctx_id))?;
+        let search = self.similarity_search(Some(&context))?;
+        let vectors = search.vectors(&context).map_err(|e| PlexusError::Other(e.to_string()))?;
+        Ok(query.execute(&context, vectors))
+    }
+
+    /// The most relevant subgraph around the pack's seeds, fit to its
+    /// token budget for an LLM prompt.
+    pub fn pack_context(&self, context_id: &str, pack: query::ContextPack) -> PlexusResult<query::PackedContext> {
+        let ctx_id = self.resolve(context_id)?;
+        let context = self
+            .engine
+            .get_context(&ctx_id)
+            .ok_or_else(|| PlexusError::ContextNotFound(ctx_id))?;
+        Ok(pack.execute(&context))
+    }
+
+    /// Everything the graph knows about a concept (an ID or a label),
+    /// listing up to `related_limit` related concepts — see
+    /// `query::ConceptDossier`.
+    pub fn dossier(&self, context_id: &str, concept: &str, related_limit: usize) -> PlexusResult<query::ConceptDossier> {
+        let ctx_id = self.resolve(context_id)?;
+        self.engine.dossier(&ctx_id, concept, related_limit)
+    }
+
+    /// What the graph knows about one file — see `query::Backlinks`.
+    pub fn backlinks(&self, context_id: &str, path: &str) -> PlexusResult<query::Backlinks> {
+        let ctx_id = self.resolve(context_id)?;
+        self.engine.backlinks(&ctx_id, path)
+    }
+
+    /// Nodes in time order, filtered by concept and optionally bucketed —
+    /// how thinking about a topic evolved.
+    pub fn timeline(&self, context_id: &str, query: query::TimelineQuery) -> PlexusResult<query::TimelineResult> {
+        let ctx_id = self.resolve(context_id)?;
+        let context = self
+            .engine
+            .get_context(&ctx_id)
+            .ok_or_else(|| PlexusError::ContextNotFound(ctx_id))?;
+        Ok(query.execute(&context))
+    }
+
+    /// Export a context as integer-indexed CSV tables for GNN training.
+    pub fn export_ml(&self, context_id: &str, export: &query::MlExport) -> PlexusResult<query::MlExportFiles> {
+        let ctx_id = self.resolve(context_id)?;
+        let context = self
+            .engine
+            .get_context(&ctx_id)
+            .ok_or_else(|| PlexusError::ContextNotFound(ctx_id))?;
+        Ok(export.execute(&context))
+    }
+
+    /// Serialize a context as Turtle or N-Triples.
+    pub fn export_rdf(&self, context_id: &str, export: &query::RdfExport) -> PlexusResult<String> {
+        let ctx_id = self.resolve(context_id)?;
+        let context = self
+            .engine
+            .get_context(&ctx_id)
+            .ok_or_else(|| PlexusError::ContextNotFound(ctx_id))?;
+        Ok(export.execute(&context))
+    }
+
+    /// JSON-LD for the given nodes and the edges between them (the whole
+    /// context when `node_ids` is `None`).
+    pub fn jsonld(
+        &self,
+        context_id: &str,
+        jsonld: &query::JsonLd,
+        node_ids: Option<&[NodeId]>,
+    ) -> PlexusResult<serde_json::Value> {
+        let ctx_id = self.resolve(context_id)?;
+        let context = self
+            .engine
+            .get_context(&ctx_id)
+            .ok_or_else(|| PlexusError::ContextNotFound(ctx_id))?;
+        Ok(jsonld.subgraph(&context, node_ids))
+    }
+
+    /// How a concept's co-occurring concepts changed between two time windows.
+    pub fn concept_drift(&self, context_id: &str, drift: &query::ConceptDrift) -> PlexusResult<query::DriftReport> {
+        let ctx_id = self.resolve(context_id)?;
+        let context = self
+            .engine
+            .get_context(&ctx_id)
+            .ok_or_else(|| PlexusError::ContextNotFound(ctx_id))?;
+        Ok(drift.execute(&context))
+    }
+
+    /// Concept drift between the context as it stood at `as_of` and now;
+    /// the drift's windows apply to each side.
+    pub fn concept_drift_since(
+        &self,
+        context_id: &str,
+        drift: &query::ConceptDrift,
+        as_of: chrono::DateTime<chrono::Utc>,
+    ) -> PlexusResult<query::DriftReport> {
+        let ctx_id = self.resolve(context_id)?;
+        let then = self.engine.context_as_of(&ctx_id, as_of)?;
+        let now = self
+            .engine
+            .get_context(&ctx_id)
+            .ok_or_else(|| PlexusError::ContextNotFound(ctx_id))?;
+        Ok(drift.compare(then.context(), &now))
+    }
+
+    /// The similarity search for the model serving `context` (see
+    /// `EmbeddingConfig`), or the default model's.
+    fn similarity_search(&self, context: Option<&Context>) -> PlexusResult<&crate::adapter::SimilaritySearch> {
+        match context.and_then(|c| c.metadata.embedding.as_ref()) {
+            Some(config) => self
+                .pipeline
+                .similarity_for(Some(&config.model))
+                .ok_or_else(|| PlexusError::NotFound(format!("no embedder registered for model '{}'", config.model))),
+            None => self
+                .pipeline
+                .similarity()
+                .ok_or_else(|| PlexusError::Unsupported("no embedder configured".into())),
+        }
+    }
+
     /// Find nodes matching a query.
     pub fn find_nodes(
         &self,
@@ -178,6 +498,18 @@ impl PlexusApi {
         self.engine.find_nodes(&ctx_id, query)
     }
 
+    /// Count nodes matching a query, ignoring limit and offset.
+    pub fn count_nodes(&self, context_id: &str, query: &FindQuery) -> PlexusResult<usize> {
+        let ctx_id = self.resolve(context_id)?;
+        self.engine.count_nodes(&ctx_id, query)
+    }
+
+    /// Count nodes reachable from a traversal's origin.
+    pub fn count_reachable(&self, context_id: &str, query: &TraverseQuery) -> PlexusResult<usize> {
+        let ctx_id = self.resolve(context_id)?;
+        self.engine.count_reachable(&ctx_id, query)
+    }
+
     /// Traverse edges from a starting node.
     pub fn traverse(
         &self,
@@ -221,7 +553,31 @@ impl PlexusApi {
         self.engine.find_path(&ctx_id, query)
     }
 
+    /// The shortest path between two nodes, with its hops scored.
+    pub fn find_scored_path(
+        &self,
+        context_id: &str,
+        query: PathQuery,
+        scoring: PathScoring,
+    ) -> PlexusResult<ScoredPath> {
+        let ctx_id = self.resolve(context_id)?;
+        self.engine.find_scored_path(&ctx_id, query, scoring)
+    }
+
+    /// Up to `limit` paths between two nodes, most trustworthy first.
+    pub fn find_paths(
+        &self,
+        context_id: &str,
+        query: PathQuery,
+        limit: usize,
+        scoring: PathScoring,
+    ) -> PlexusResult<Vec<ScoredPath>> {
+        let ctx_id = self.resolve(context_id)?;
+        self.engine.find_paths(&ctx_id, query, limit, scoring)
+    }
+
     /// Update a mark's metadata. Routes through ingest pipeline.
+    #[allow(clippy::too_many_arguments)]
     pub async fn update_mark(
         &self,
         context_id: &str,
@@ -234,7 +590,7 @@ impl PlexusApi {
     ) -> Result<(), AdapterError> {
         let ctx_id = self
             .resolve(context_id)
-            .map_err(|e| AdapterError::Storage(e.to_string()))?;
+            .map_err(AdapterError::from)?;
 
         let mut node = {
             let ctx = self
@@ -285,7 +641,7 @@ impl PlexusApi {
     ) -> Result<(), AdapterError> {
         let ctx_id = self
             .resolve(context_id)
-            .map_err(|e| AdapterError::Storage(e.to_string()))?;
+            .map_err(AdapterError::from)?;
 
         let mut node = {
             let ctx = self
@@ -315,7 +671,7 @@ impl PlexusApi {
     ) -> Result<(), AdapterError> {
         let ctx_id = self
             .resolve(context_id)
-            .map_err(|e| AdapterError::Storage(e.to_string()))?;
+            .map_err(AdapterError::from)?;
         let input = ProvenanceInput::DeleteMark {
             mark_id: mark_id.to_string(),
         };
@@ -405,7 +761,7 @@ impl PlexusApi {
     ) -> Result<(), AdapterError> {
         let ctx_id = self
             .resolve(context_id)
-            .map_err(|e| AdapterError::Storage(e.to_string()))?;
+            .map_err(AdapterError::from)?;
         let input = ProvenanceInput::UnlinkMarks {
             source_id: source_id.to_string(),
             target_id: target_id.to_string(),
@@ -617,6 +973,63 @@ impl PlexusApi {
         Ok(edges_affected)
     }
 
+    /// Re-embed every eligible node of a context with the model serving
+    /// it, dropping the context's stored vectors first, then run the
+    /// enrichment loop so `similar_to` is recomputed.
+    pub fn reembed(&self, context_id: &str) -> PlexusResult<ReembedReport> {
+        use crate::adapter::run_enrichment_loop;
+
+        let ctx_id = self.resolve(context_id)?;
+        let context = self
+            .engine
+            .get_context(&ctx_id)
+            .ok_or_else(|| PlexusError::ContextNotFound(ctx_id.clone()))?;
+        let search = self.similarity_search(Some(&context))?;
+        search.clear(&context);
+
+        let node_ids: Vec<NodeId> = context
+            .nodes()
+            .filter(|n| n.dimension == search.dimension_filter())
+            .map(|n| n.id.clone())
+            .collect();
+        let registry = self.pipeline.enrichment_registry();
+        if !node_ids.is_empty() {
+            let events = [GraphEvent::NodesAdded {
+                node_ids: node_ids.clone(),
+                adapter_id: "reembed".to_string(),
+                context_id: ctx_id.as_str().to_string(),
+            }];
+            run_enrichment_loop(&self.engine, &ctx_id, &registry, &events)
+                .map_err(|e| PlexusError::Other(e.to_string()))?;
+        }
+
+        let enrichment_id = format!("embedding:{}", search.model_name());
+        let similar_edges = self
+            .engine
+            .get_context(&ctx_id)
+            .map_or(0, |ctx| ctx.edges().filter(|e| e.contributions.contains_key(&enrichment_id)).count());
+        Ok(ReembedReport {
+            model: search.model_name().to_string(),
+            nodes: node_ids.len(),
+            similar_edges,
+            retracted_edges: 0,
+        })
+    }
+
+    /// Restore soft-deleted nodes and edges by ID (see
+    /// `PlexusEngine::restore_deleted`).
+    pub fn restore_deleted(&self, context_id: &str, ids: &[String]) -> PlexusResult<crate::graph::RestoreReport> {
+        let ctx_id = self.resolve(context_id)?;
+        self.engine.restore_deleted(&ctx_id, ids)
+    }
+
+    /// Mark `new` as the next version of `old` (see
+    /// `PlexusEngine::supersede_node`).
+    pub fn supersede_node(&self, context_id: &str, old: &str, new: &str) -> PlexusResult<crate::graph::EdgeId> {
+        let ctx_id = self.resolve(context_id)?;
+        self.engine.supersede_node(&ctx_id, &NodeId::from_string(old), &NodeId::from_string(new))
+    }
+
     // --- Context management ---
 
     /// Create a context. Returns error if name is already taken.
@@ -625,9 +1038,24 @@ impl PlexusApi {
     /// infrastructure, not content ingestion — no enrichment applies.
     pub fn context_create(&self, name: &str) -> PlexusResult<ContextId> {
         if self.resolve(name).is_ok() {
-            return Err(PlexusError::Other(format!("context '{}' already exists", name)));
+            return Err(PlexusError::AlreadyExists(format!("context '{}' already exists", name)));
         }
-        let context = Context::new(name);
+        let mut context = Context::new(name);
+        context.metadata.tenant = self.tenant.clone();
+        self.engine.upsert_context(context)
+    }
+
+    /// Create a context configured by a built-in or saved template.
+    pub fn context_create_from_template(&self, name: &str, template: &str) -> PlexusResult<ContextId> {
+        if self.resolve(name).is_ok() {
+            return Err(PlexusError::AlreadyExists(format!("context '{}' already exists", name)));
+        }
+        let template = self
+            .engine
+            .template(template)?
+            .ok_or_else(|| PlexusError::NotFound(format!("no template named '{}'", template)))?;
+        let mut context = template.instantiate(name);
+        context.metadata.tenant = self.tenant.clone();
         self.engine.upsert_context(context)
     }
 
@@ -642,6 +1070,9 @@ impl PlexusApi {
             name: ctx.name.clone(),
             id: ctx_id,
             sources: ctx.metadata.sources.clone(),
+            node_count: ctx.node_count(),
+            edge_count: ctx.edge_count(),
+            updated_at: ctx.metadata.updated_at.or(ctx.metadata.created_at),
         })
     }
 
@@ -649,6 +1080,7 @@ impl PlexusApi {
     pub fn context_delete(&self, name: &str) -> PlexusResult<()> {
         let ctx_id = self.resolve(name)?;
         self.engine.remove_context(&ctx_id)?;
+        self.pipeline.forget_context(&ctx_id);
         Ok(())
     }
 
@@ -664,33 +1096,248 @@ impl PlexusApi {
             }
             None => {
                 self.engine.reload_if_changed()?;
-                Ok(self.engine.list_contexts())
+                Ok(self.engine.list_contexts_in_tenant(self.tenant()))
             }
         }
     }
 
-    /// List all contexts with metadata.
-    pub fn context_list_info(&self) -> PlexusResult<Vec<ContextInfo>> {
-        let mut result = Vec::new();
-        for cid in self.engine.list_contexts() {
-            if let Some(ctx) = self.engine.get_context(&cid) {
-                result.push(ContextInfo {
-                    name: ctx.name.clone(),
-                    id: cid,
-                    sources: ctx.metadata.sources.clone(),
-                });
-            }
-        }
-        Ok(result)
+    /// List all contexts with metadata.
+    ///
+    /// Answers from the manifest for contexts still hydrating after a
+    /// warm start, so it never waits on a context load.
+    pub fn context_list_info(&self) -> PlexusResult<Vec<ContextInfo>> {
+        Ok(self
+            .context_summaries()?
+            .into_iter()
+            .map(|entry| ContextInfo {
+                name: entry.name,
+                id: entry.id,
+                sources: entry.sources,
+                node_count: entry.node_count,
+                edge_count: entry.edge_count,
+                updated_at: entry.updated_at,
+            })
+            .collect())
+    }
+
+    /// Manifest entries (name, sources, counts, last change) of this
+    /// tenant's contexts, without hydrating any.
+    pub fn context_summaries(&self) -> PlexusResult<Vec<ManifestEntry>> {
+        self.engine.reload_if_changed()?;
+        let tenant = self.tenant();
+        Ok(self
+            .engine
+            .context_manifest()
+            .into_iter()
+            .filter(|entry| entry.tenant.as_deref() == tenant)
+            .collect())
+    }
+
+    /// Rename a context. Returns error if new name is already taken.
+    pub fn context_rename(&self, old_name: &str, new_name: &str) -> PlexusResult<()> {
+        if self.resolve(new_name).is_ok() {
+            return Err(PlexusError::AlreadyExists(format!("context '{}' already exists", new_name)));
+        }
+        let ctx_id = self.resolve(old_name)?;
+        self.engine.rename_context(&ctx_id, new_name)
+    }
+
+    /// Set or clear (with `None`) the tag policy applied to a context's
+    /// ingested tags.
+    pub fn context_set_tag_policy(&self, name: &str, policy: Option<TagPolicy>) -> PlexusResult<()> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.set_tag_policy(&ctx_id, policy)
+    }
+
+    /// Set or clear (with `None`, meaning strict) a context's durability.
+    pub fn context_set_durability(&self, name: &str, durability: Option<Durability>) -> PlexusResult<()> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.set_durability(&ctx_id, durability)
+    }
+
+    /// Set or clear (with `None`) what `adapter_id` may write to a context.
+    pub fn context_set_write_scope(&self, name: &str, adapter_id: &str, scope: Option<WriteScope>) -> PlexusResult<()> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.set_write_scope(&ctx_id, adapter_id, scope)
+    }
+
+    /// How each enrichment's derived edges have fared on a context (see
+    /// `PlexusEngine::enrichment_report`).
+    pub fn enrichment_report(&self, context: &str) -> PlexusResult<Vec<EnrichmentStats>> {
+        let ctx_id = self.resolve(context)?;
+        self.engine.enrichment_report(&ctx_id)
+    }
+
+    /// Set or clear (with `None`) a context's size limits.
+    pub fn context_set_quota(&self, name: &str, quota: Option<ContextQuota>) -> PlexusResult<()> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.set_context_quota(&ctx_id, quota)
+    }
+
+    /// Set or clear (with `None`) the embedding model serving a context.
+    ///
+    /// When the settings change, the previous model's `similar_to`
+    /// contributions are retracted and the context is re-embedded with the
+    /// new one (see `reembed`). Returns `None` when nothing changed.
+    pub fn context_set_embedding(&self, name: &str, config: Option<EmbeddingConfig>) -> PlexusResult<Option<ReembedReport>> {
+        let ctx_id = self.resolve(name)?;
+        let old = self.engine.embedding_config(&ctx_id);
+        if old == config {
+            return Ok(None);
+        }
+        let default_model = self.pipeline.similarity().map(|s| s.model_name().to_string());
+        if let Some(ref config) = config {
+            if self.pipeline.similarity_for(Some(&config.model)).is_none() {
+                return Err(PlexusError::NotFound(format!("no embedder registered for model '{}'", config.model)));
+            }
+        }
+        self.engine.set_embedding_config(&ctx_id, config)?;
+        let Some(old_model) = old.map(|c| c.model).or(default_model) else {
+            return Ok(None);
+        };
+
+        let retracted_edges = self.retract_contributions(name, &format!("embedding:{}", old_model))?;
+        let mut report = self.reembed(name)?;
+        report.retracted_edges = retracted_edges;
+        Ok(Some(report))
+    }
+
+    /// Record timestamped contribution history on a context's edges,
+    /// aggregated into contribution slots by `aggregation`; `None` stops
+    /// recording.
+    pub fn context_set_contribution_history(
+        &self,
+        name: &str,
+        aggregation: Option<ContributionAggregation>,
+    ) -> PlexusResult<()> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.set_contribution_history(&ctx_id, aggregation)
+    }
+
+    /// Store (or replace) a named query on a context.
+    pub fn save_query(&self, name: &str, query_name: &str, query: SavedQuery) -> PlexusResult<()> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.save_query(&ctx_id, query_name, query)
+    }
+
+    /// Remove a named query. Returns whether it existed.
+    pub fn delete_saved_query(&self, name: &str, query_name: &str) -> PlexusResult<bool> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.delete_saved_query(&ctx_id, query_name)
+    }
+
+    /// Named queries stored on a context.
+    pub fn saved_queries(&self, name: &str) -> PlexusResult<BTreeMap<String, SavedQuery>> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.saved_queries(&ctx_id)
+    }
+
+    /// Run a named query stored on a context.
+    pub fn run_saved_query(&self, name: &str, query_name: &str) -> PlexusResult<SavedQueryResult> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.run_saved_query(&ctx_id, query_name)
+    }
+
+    /// Compact storage, scoped to one context or (with `None`) all of them.
+    pub fn maintain(&self, name: Option<&str>) -> PlexusResult<CompactionReport> {
+        let ctx_id = name.map(|n| self.resolve(n)).transpose()?;
+        self.engine.maintain(ctx_id.as_ref())
+    }
+
+    /// Prune edges by policy (see `PlexusEngine::prune`). For
+    /// `UnreferencedDerived` with an empty `derived_by`, edges count as
+    /// derived when every contributor is a registered enrichment.
+    pub fn prune(&self, name: &str, policy: PrunePolicy, dry_run: bool) -> PlexusResult<PruneReport> {
+        let ctx_id = self.resolve(name)?;
+        let policy = match policy {
+            PrunePolicy::UnreferencedDerived { derived_by } if derived_by.is_empty() => {
+                let registry = self.pipeline.enrichment_registry();
+                PrunePolicy::UnreferencedDerived {
+                    derived_by: registry.enrichments().iter().map(|e| e.id().to_string()).collect(),
+                }
+            }
+            policy => policy,
+        };
+        self.engine.prune(&ctx_id, &policy, dry_run)
+    }
+
+    /// Publish a filtered, read-only bundle of a context (see
+    /// `PlexusEngine::publish`).
+    pub fn publish(&self, name: &str, filter: &PublishFilter, path: impl AsRef<std::path::Path>) -> PlexusResult<PublishManifest> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.publish(&ctx_id, filter, path)
+    }
+
+    /// Stage (or replace) a named overlay on a context.
+    pub fn stage_overlay(&self, name: &str, overlay_name: &str, overlay: Overlay) -> PlexusResult<()> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.stage_overlay(&ctx_id, overlay_name, overlay)
+    }
+
+    /// A context's staged overlays, by name.
+    pub fn overlays(&self, name: &str) -> PlexusResult<BTreeMap<String, Overlay>> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.overlays(&ctx_id)
+    }
+
+    /// The context with the named overlay applied (see `PlexusEngine::overlay_view`).
+    pub fn overlay_view(&self, name: &str, overlay_name: &str) -> PlexusResult<Context> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.overlay_view(&ctx_id, overlay_name)
+    }
+
+    /// Drop a staged overlay. Returns whether it existed.
+    pub fn discard_overlay(&self, name: &str, overlay_name: &str) -> PlexusResult<bool> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.discard_overlay(&ctx_id, overlay_name)
+    }
+
+    /// Commit the named overlay into the context.
+    pub fn commit_overlay(&self, name: &str, overlay_name: &str) -> PlexusResult<ContextSync> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.commit_overlay(&ctx_id, overlay_name)
+    }
+
+    /// Materialize (or replace) a named view on a context.
+    pub fn materialize_view(&self, name: &str, view_name: &str, query: SavedQuery) -> PlexusResult<()> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.materialize_view(&ctx_id, view_name, query)
+    }
+
+    /// Remove a materialized view. Returns whether it existed.
+    pub fn drop_view(&self, name: &str, view_name: &str) -> PlexusResult<bool> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.drop_view(&ctx_id, view_name)
+    }
+
+    /// Read a materialized view, refreshing it first if stale and asked to.
+    pub fn read_view(&self, name: &str, view_name: &str, refresh_if_stale: bool) -> PlexusResult<ViewSnapshot> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.read_view(&ctx_id, view_name, refresh_if_stale)
+    }
+
+    /// Maintain the transitive closure of `relationship` on a context.
+    pub fn maintain_closure(&self, name: &str, relationship: &str) -> PlexusResult<()> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.maintain_closure(&ctx_id, relationship)
     }
 
-    /// Rename a context. Returns error if new name is already taken.
-    pub fn context_rename(&self, old_name: &str, new_name: &str) -> PlexusResult<()> {
-        if self.resolve(new_name).is_ok() {
-            return Err(PlexusError::Other(format!("context '{}' already exists", new_name)));
-        }
-        let ctx_id = self.resolve(old_name)?;
-        self.engine.rename_context(&ctx_id, new_name)
+    /// Everything under `node` through a maintained closure, with depth.
+    pub fn descendants(&self, name: &str, relationship: &str, node: &NodeId) -> PlexusResult<Vec<(NodeId, usize)>> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.descendants(&ctx_id, relationship, node)
+    }
+
+    /// Everything above `node` through a maintained closure, with depth.
+    pub fn ancestors(&self, name: &str, relationship: &str, node: &NodeId) -> PlexusResult<Vec<(NodeId, usize)>> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.ancestors(&ctx_id, relationship, node)
+    }
+
+    /// Whether `from` reaches `to` along edges passing `filter`.
+    pub fn is_reachable(&self, name: &str, from: &NodeId, to: &NodeId, filter: &ReachabilityFilter) -> PlexusResult<bool> {
+        let ctx_id = self.resolve(name)?;
+        self.engine.is_reachable(&ctx_id, from, to, filter)
     }
 
     /// Add sources to a context.
@@ -711,6 +1358,131 @@ impl PlexusApi {
         Ok(())
     }
 
+    /// Turn a directory into a populated context in one call: create the
+    /// context (from `options.template` when given), record the directory
+    /// as a source, and ingest every file through `extract-file`, which
+    /// runs registration, the analyzers, and the enrichment loop.
+    ///
+    /// Reuses an existing context of the same name. Files that fail to
+    /// ingest are reported rather than aborting the run. Structural and
+    /// semantic phases of extraction may still be running in the
+    /// background when this returns.
+    pub async fn bootstrap(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        options: BootstrapOptions,
+    ) -> PlexusResult<BootstrapReport> {
+        let root = path.as_ref().canonicalize().map_err(|e| {
+            PlexusError::Other(format!("cannot read {}: {}", path.as_ref().display(), e))
+        })?;
+        if !root.is_dir() {
+            return Err(PlexusError::InvalidInput(format!("{} is not a directory", root.display())));
+        }
+        let name = match options.context {
+            Some(ref name) => name.clone(),
+            None => root
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .ok_or_else(|| PlexusError::InvalidInput(format!("cannot name a context after {}", root.display())))?,
+        };
+
+        let created = self.resolve(&name).is_err();
+        let context_id = match (created, options.template.as_deref()) {
+            (true, Some(template)) => self.context_create_from_template(&name, template)?,
+            (true, None) => self.context_create(&name)?,
+            (false, _) => self.resolve(&name)?,
+        };
+        let root_str = root.to_string_lossy().into_owned();
+        let source = Source::Directory { path: root_str, recursive: options.recursive };
+        let known = self.engine.get_context(&context_id).is_some_and(|ctx| ctx.metadata.sources.contains(&source));
+        if !known {
+            self.engine.add_source(&context_id, source)?;
+        }
+
+        let mut files = Vec::new();
+        collect_files(&root, &options, &mut files)?;
+        files.sort();
+        let skipped = options.max_files.map_or(0, |max| files.len().saturating_sub(max));
+        files.truncate(options.max_files.unwrap_or(usize::MAX));
+
+        let mut report = BootstrapReport {
+            context: name.clone(),
+            context_id: context_id.clone(),
+            created,
+            skipped,
+            ..BootstrapReport::default()
+        };
+        for file in files {
+            let file_path = file.to_string_lossy().into_owned();
+            let data = serde_json::json!({ "file_path": file_path });
+            match self.ingest(&name, "extract-file", Box::new(data)).await {
+                Ok(events) => {
+                    report.outbound_events += events.len();
+                    report.ingested.push(file_path);
+                }
+                Err(e) => report.failed.push((file_path, e.to_string())),
+            }
+        }
+        if let Some(ctx) = self.engine.get_context(&context_id) {
+            report.nodes = ctx.node_count();
+            report.edges = ctx.edge_count();
+        }
+        Ok(report)
+    }
+
+    /// Move everything a context records at the file or directory `old`
+    /// to `new` — see `PlexusEngine::relocate_path`.
+    pub fn relocate_path(&self, context_id: &str, old: &str, new: &str) -> PlexusResult<RelocationReport> {
+        let ctx_id = self.resolve(context_id)?;
+        let report = self.engine.relocate_path(&ctx_id, old, new)?;
+        let renamed: Vec<(NodeId, NodeId)> = report.all_renames().cloned().collect();
+        if !renamed.is_empty() {
+            self.pipeline.rename_nodes(&ctx_id, &renamed);
+        }
+        Ok(report)
+    }
+
+    /// Tracked files whose paths no longer exist, each paired with the
+    /// file it became (see `graph::detect_relocations`). `watch_relocations`
+    /// polls this and hands each result to `relocate_path`.
+    pub fn detect_relocations(&self, context_id: &str) -> PlexusResult<Vec<Relocation>> {
+        let ctx_id = self.resolve(context_id)?;
+        let context = self.engine.get_context(&ctx_id).ok_or(PlexusError::ContextNotFound(ctx_id))?;
+        Ok(crate::graph::detect_relocations(&context))
+    }
+
+    /// Detect moved files and relocate each — `detect_relocations`
+    /// followed by `relocate_path`.
+    pub fn relocate_moved_files(&self, context_id: &str) -> PlexusResult<Vec<RelocationReport>> {
+        self.detect_relocations(context_id)?
+            .into_iter()
+            .map(|relocation| self.relocate_path(context_id, &relocation.from, &relocation.to))
+            .collect()
+    }
+
+    /// Every `interval`, relocate moved files in each of this tenant's
+    /// contexts that has sources, until the watcher is dropped. Failures
+    /// are logged and retried on the next poll.
+    pub fn watch_relocations(&self, interval: std::time::Duration) -> RelocationWatcher {
+        let api = self.clone();
+        RelocationWatcher::spawn(interval, move || {
+            let contexts = match api.context_summaries() {
+                Ok(contexts) => contexts,
+                Err(e) => return tracing::warn!(error = %e, "relocation watch: failed to list contexts"),
+            };
+            for context in contexts.iter().filter(|c| !c.sources.is_empty()) {
+                match api.relocate_moved_files(&context.name) {
+                    Ok(reports) => {
+                        for report in reports {
+                            tracing::info!(context = %context.name, from = %report.from, to = %report.to, "relocated moved file");
+                        }
+                    }
+                    Err(e) => tracing::warn!(context = %context.name, error = %e, "relocation watch failed"),
+                }
+            }
+        })
+    }
+
     // --- Internal ---
 
     /// Resolve a context name to its ContextId (O(1) via name index).
@@ -721,9 +1493,12 @@ impl PlexusApi {
     /// choke point for multi-process consumers.
     fn resolve(&self, name: &str) -> PlexusResult<ContextId> {
         self.engine.reload_if_changed()?;
-        self.engine
-            .resolve_by_name(name)
-            .ok_or_else(|| PlexusError::ContextNotFound(ContextId::from(name)))
+        let ctx_id = self
+            .engine
+            .resolve_in_tenant(self.tenant(), name)
+            .ok_or_else(|| PlexusError::ContextNotFound(ContextId::from(name)))?;
+        self.engine.hydrate(&ctx_id)?;
+        Ok(ctx_id)
     }
 
     /// Explain every piece of evidence between a node pair (issue #14).
@@ -751,7 +1526,7 @@ impl PlexusApi {
             &crate::graph::NodeId::from(target),
             relationship,
         )
-        .ok_or_else(|| PlexusError::Other(format!(
+        .ok_or_else(|| PlexusError::NotFound(format!(
             "node pair not found: {} / {}", source, target
         )))
     }
@@ -803,6 +1578,41 @@ impl PlexusApi {
         })
     }
 
+    /// Queued outbound events after `after`, for replay from an offset.
+    pub fn outbound_since(
+        &self,
+        context_name: &str,
+        after: u64,
+        limit: Option<usize>,
+    ) -> PlexusResult<Vec<PersistedOutboundEvent>> {
+        let context_id = self.resolve(context_name)?;
+        self.engine.outbound_since(&context_id, after, limit)
+    }
+
+    /// Outbound events `subscriber` hasn't acknowledged; redelivered until
+    /// acknowledged.
+    pub fn next_outbound(
+        &self,
+        context_name: &str,
+        subscriber: &str,
+        limit: Option<usize>,
+    ) -> PlexusResult<Vec<PersistedOutboundEvent>> {
+        let context_id = self.resolve(context_name)?;
+        self.engine.next_outbound(&context_id, subscriber, limit)
+    }
+
+    /// Acknowledge outbound delivery up to and including `offset`.
+    pub fn ack_outbound(&self, context_name: &str, subscriber: &str, offset: u64) -> PlexusResult<()> {
+        let context_id = self.resolve(context_name)?;
+        self.engine.ack_outbound(&context_id, subscriber, offset)
+    }
+
+    /// Replay outbound delivery to `subscriber` from after `offset`.
+    pub fn rewind_outbound(&self, context_name: &str, subscriber: &str, offset: u64) -> PlexusResult<()> {
+        let context_id = self.resolve(context_name)?;
+        self.engine.rewind_outbound(&context_id, subscriber, offset)
+    }
+
     fn prov(&self, context_name: &str) -> PlexusResult<ProvenanceApi<'_>> {
         let cid = self.resolve(context_name)?;
         Ok(ProvenanceApi::new(&self.engine, cid))
@@ -861,6 +1671,157 @@ pub struct ContextInfo {
     pub name: String,
     pub id: ContextId,
     pub sources: Vec<Source>,
+    pub node_count: usize,
+    pub edge_count: usize,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// What `PlexusApi::find_similar` compares against.
+#[derive(Debug, Clone)]
+pub enum SimilarTo {
+    Node(NodeId),
+    Text(String),
+}
+
+/// A `find_similar` hit and its provenance.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SimilarNode {
+    pub id: NodeId,
+    /// Cosine similarity to the query
+    pub score: f32,
+    pub node_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Fragments tagged with the node
+    pub fragments: Vec<NodeId>,
+    /// Marks referencing the node
+    pub marks: Vec<NodeId>,
+    /// Chains containing those marks
+    pub chains: Vec<NodeId>,
+}
+
+/// Which files `PlexusApi::bootstrap` ingests, and into what context.
+#[derive(Debug, Clone)]
+pub struct BootstrapOptions {
+    /// Context name; defaults to the directory's name
+    pub context: Option<String>,
+    /// Template for a newly created context
+    pub template: Option<String>,
+    /// Descend into subdirectories
+    pub recursive: bool,
+    /// File extensions to ingest (without the dot); empty ingests all
+    pub extensions: Vec<String>,
+    /// Skip files and directories whose names start with `.`
+    pub skip_hidden: bool,
+    /// Ingest at most this many files, in path order
+    pub max_files: Option<usize>,
+}
+
+impl Default for BootstrapOptions {
+    fn default() -> Self {
+        Self {
+            context: None,
+            template: None,
+            recursive: true,
+            extensions: Vec::new(),
+            skip_hidden: true,
+            max_files: None,
+        }
+    }
+}
+
+impl BootstrapOptions {
+    pub fn with_context(mut self, name: impl Into<String>) -> Self {
+        self.context = Some(name.into());
+        self
+    }
+
+    pub fn with_template(mut self, template: impl Into<String>) -> Self {
+        self.template = Some(template.into());
+        self
+    }
+
+    pub fn with_extensions(mut self, extensions: &[&str]) -> Self {
+        self.extensions = extensions.iter().map(|e| e.trim_start_matches('.').to_string()).collect();
+        self
+    }
+
+    pub fn with_max_files(mut self, max: usize) -> Self {
+        self.max_files = Some(max);
+        self
+    }
+
+    pub fn non_recursive(mut self) -> Self {
+        self.recursive = false;
+        self
+    }
+
+    fn wants(&self, path: &std::path::Path) -> bool {
+        self.extensions.is_empty()
+            || path
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|ext| self.extensions.iter().any(|wanted| wanted.eq_ignore_ascii_case(ext)))
+    }
+}
+
+/// What `PlexusApi::reembed` (or an embedding model switch) did.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ReembedReport {
+    /// Model the context was re-embedded with
+    pub model: String,
+    /// Nodes re-embedded
+    pub nodes: usize,
+    /// Edges carrying the model's `similar_to` contributions afterwards
+    pub similar_edges: usize,
+    /// Edges the previous model's contributions were retracted from
+    pub retracted_edges: usize,
+}
+
+/// What `PlexusApi::bootstrap` did.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct BootstrapReport {
+    pub context: String,
+    pub context_id: ContextId,
+    /// Whether the context was created by this run
+    pub created: bool,
+    /// Files ingested, in path order
+    pub ingested: Vec<String>,
+    /// Files that failed to ingest, with the error
+    pub failed: Vec<(String, String)>,
+    /// Matching files left out by `max_files`
+    pub skipped: usize,
+    /// Outbound events the ingests returned
+    pub outbound_events: usize,
+    /// Context size after the run
+    pub nodes: usize,
+    pub edges: usize,
+}
+
+fn collect_files(
+    dir: &std::path::Path,
+    options: &BootstrapOptions,
+    files: &mut Vec<std::path::PathBuf>,
+) -> PlexusResult<()> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| PlexusError::Other(format!("cannot read {}: {}", dir.display(), e)))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if options.skip_hidden && entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+        // The entry's own type: symlinked directories aren't followed, so
+        // a link back up the tree can't recurse forever
+        let Ok(file_type) = entry.file_type() else { continue };
+        if file_type.is_dir() {
+            if options.recursive {
+                collect_files(&path, options, files)?;
+            }
+        } else if path.is_file() && options.wants(&path) {
+            files.push(path);
+        }
+    }
+    Ok(())
 }
 
 /// Result of a successful `load_spec` call (ADR-037).
@@ -926,7 +1887,7 @@ impl std::error::Error for SpecUnloadError {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::graph::{ContentType, Edge, Node, NodeId, PropertyValue, dimension};
+    use crate::graph::{ContentType, Edge, Node, NodeId, PropertyValue, TenantQuota, dimension};
 
     fn setup() -> (Arc<PlexusEngine>, PlexusApi) {
         let engine = Arc::new(PlexusEngine::new());
@@ -1211,6 +2172,221 @@ emit:
         );
     }
 
+    #[tokio::test]
+    async fn tag_policy_persists_and_applies_to_another_engines_ingest() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = dir.path().join("coherence-tag-policy.db");
+
+        let (engine_a, api_a) = setup_shared_db(&db);
+        let (_engine_b, api_b) = setup_shared_db(&db);
+        api_a.context_create("studio").unwrap();
+
+        // B configures the policy; A's next ingest must honor it
+        api_b
+            .context_set_tag_policy("studio", Some(TagPolicy::new().with_stop("misc")))
+            .unwrap();
+        api_a
+            .ingest_with_adapter(
+                "studio",
+                Arc::new(crate::adapter::ContentAdapter::new("content")),
+                Box::new(FragmentInput::new("filed away", vec!["misc".into(), "archive".into()])),
+            )
+            .await
+            .unwrap();
+
+        let ctx_id = api_a.context_list(Some("studio")).unwrap()[0].clone();
+        let ctx = engine_a.get_context(&ctx_id).unwrap();
+        assert!(ctx.get_node(&NodeId::from("concept:misc")).is_none());
+        assert!(ctx.get_node(&NodeId::from("concept:archive")).is_some());
+        assert!(ctx.metadata.tag_policy.is_some());
+    }
+
+    #[tokio::test]
+    async fn idempotency_key_dedupes_retries_across_engines() {
+        use crate::error::ErrorCoded;
+
+        let dir = tempfile::tempdir().unwrap();
+        let db = dir.path().join("idempotency.db");
+
+        let (engine_a, api_a) = setup_shared_db(&db);
+        let (_engine_b, api_b) = setup_shared_db(&db);
+        for api in [&api_a, &api_b] {
+            api.pipeline.register_integration(Arc::new(crate::adapter::ContentAdapter::new("content")), vec![]);
+        }
+        api_a.context_create("studio").unwrap();
+        let fragment = || Box::new(FragmentInput::new("retried note", vec!["retry".into()]));
+
+        let first = api_a.ingest_idempotent("studio", "content", fragment(), "call-1").await.unwrap();
+        let retried = api_b.ingest_idempotent("studio", "content", fragment(), "call-1").await.unwrap();
+        assert_eq!(retried, first, "the retry is answered from the recorded result");
+
+        let ctx_id = api_a.context_list(Some("studio")).unwrap()[0].clone();
+        let fragments = |engine: &Arc<PlexusEngine>| {
+            engine.reload_if_changed().unwrap();
+            engine.get_context(&ctx_id).unwrap().nodes.values().filter(|n| n.node_type == "fragment").count()
+        };
+        assert_eq!(fragments(&engine_a), 1);
+
+        let other = Box::new(FragmentInput::new("another note", vec!["retry".into()]));
+        api_a.ingest_idempotent("studio", "content", other, "call-2").await.unwrap();
+        assert_eq!(fragments(&engine_a), 2, "a new key ingests again");
+
+        let reused = api_a.ingest_idempotent("studio", "provenance", fragment(), "call-1").await.unwrap_err();
+        assert_eq!(reused.code(), crate::error::ErrorCode::InvalidInput, "a key can't be reused for another input kind");
+    }
+
+    // === Scenario: An idempotency key is bound to its payload and claimed across processes ===
+    #[tokio::test]
+    async fn idempotency_key_rejects_other_data_and_waits_for_a_claim() {
+        use crate::error::ErrorCoded;
+
+        let dir = tempfile::tempdir().unwrap();
+        let db = dir.path().join("idempotency-claims.db");
+        let (_engine_a, api_a) = setup_shared_db(&db);
+        let (engine_b, _api_b) = setup_shared_db(&db);
+        api_a.pipeline.register_integration(Arc::new(crate::adapter::ContentAdapter::new("content")), vec![]);
+        let ctx_id = api_a.context_create("studio").unwrap();
+        let note = |text: &str| Box::new(serde_json::json!({"text": text, "tags": ["retry"]}));
+
+        let first = api_a.ingest_idempotent("studio", "content", note("rye"), "json-1").await.unwrap();
+        assert_eq!(api_a.ingest_idempotent("studio", "content", note("rye"), "json-1").await.unwrap(), first);
+        let mismatch = api_a.ingest_idempotent("studio", "content", note("spelt"), "json-1").await.unwrap_err();
+        assert_eq!(mismatch.code(), crate::error::ErrorCode::InvalidInput, "{mismatch}");
+
+        // The JSON path decodes through the adapter and shares the key's record
+        let json = serde_json::json!({"text": "rye", "tags": ["retry"]});
+        assert_eq!(api_a.ingest_json_idempotent("studio", "content", json, "json-1").await.unwrap(), first);
+        let malformed = api_a.ingest_json_idempotent("studio", "content", serde_json::json!({"tags": []}), "json-2").await;
+        assert!(matches!(malformed, Err(AdapterError::Validation(_))), "{malformed:?}");
+        assert!(engine_b.ingest_result(&ctx_id, "json-2").unwrap().is_none(), "a failed ingest releases its key");
+
+        // Another process is mid-ingest under this key
+        let claim = crate::storage::PersistedIngestResult {
+            context_id: ctx_id.to_string(),
+            key: "held".into(),
+            input_kind: "content".into(),
+            payload_hash: None,
+            outbound_json: String::new(),
+            completed: false,
+            recorded_at: chrono::Utc::now().to_rfc3339(),
+        };
+        assert!(engine_b.claim_ingest_key(claim.clone()).unwrap());
+        let busy = api_a.ingest_idempotent("studio", "content", note("oat"), "held").await.unwrap_err();
+        assert!(busy.is_retryable(), "{busy}");
+
+        // Its claim lapses, and the retry takes over
+        engine_b.release_ingest_key(&ctx_id, "held").unwrap();
+        assert!(api_a.ingest_idempotent("studio", "content", note("oat"), "held").await.is_ok());
+    }
+
+    // === Scenario: Ingests warn once a context nears its quota ===
+    #[tokio::test]
+    async fn ingest_warns_when_a_context_nears_its_quota() {
+        let (_engine, api) = setup();
+        api.pipeline.register_integration(Arc::new(crate::adapter::ContentAdapter::new("content")), vec![]);
+        api.context_create("small").unwrap();
+        api.context_set_quota("small", Some(ContextQuota::new().with_max_nodes(1000))).unwrap();
+
+        let quiet = api.ingest("small", "content", Box::new(FragmentInput::new("first", vec!["a".into()]))).await.unwrap();
+        assert!(quiet.iter().all(|e| e.kind != crate::graph::QUOTA_WARNING_EVENT));
+
+        // Warn from one node past what the context holds now
+        let max = 2 * (api.context_info("small").unwrap().node_count + 1);
+        api.context_set_quota("small", Some(ContextQuota::new().with_max_nodes(max).with_warn_ratio(0.5))).unwrap();
+        let warned = api.ingest("small", "content", Box::new(FragmentInput::new("second", vec!["b".into()]))).await.unwrap();
+        let warning = warned.iter().find(|e| e.kind == crate::graph::QUOTA_WARNING_EVENT).expect("quota warning");
+        assert!(warning.detail.contains(&format!("of {max} nodes")), "{}", warning.detail);
+
+        let again = api.ingest("small", "content", Box::new(FragmentInput::new("third", vec!["c".into()]))).await.unwrap();
+        assert!(again.iter().all(|e| e.kind != crate::graph::QUOTA_WARNING_EVENT), "the warning comes once per crossing");
+        assert!(api.context_set_quota("small", Some(ContextQuota::new().with_warn_ratio(-0.1))).is_err());
+    }
+
+    #[tokio::test]
+    async fn saved_query_persists_and_runs_by_name_on_another_engine() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = dir.path().join("coherence-saved-query.db");
+
+        let (_engine_a, api_a) = setup_shared_db(&db);
+        let (_engine_b, api_b) = setup_shared_db(&db);
+        api_a.context_create("studio").unwrap();
+        api_a
+            .ingest_with_adapter(
+                "studio",
+                Arc::new(crate::adapter::ContentAdapter::new("content")),
+                Box::new(FragmentInput::new("open question", vec!["thread".into()])),
+            )
+            .await
+            .unwrap();
+
+        api_a
+            .save_query(
+                "studio",
+                "concepts",
+                SavedQuery::from(FindQuery::new().with_node_type("concept")),
+            )
+            .unwrap();
+
+        assert!(api_b.saved_queries("studio").unwrap().contains_key("concepts"));
+        let SavedQueryResult::Find(found) = api_b.run_saved_query("studio", "concepts").unwrap()
+        else {
+            panic!("expected find result")
+        };
+        assert_eq!(found.nodes[0].id, NodeId::from("concept:thread"));
+
+        assert!(api_b.run_saved_query("studio", "missing").is_err());
+        assert!(api_b.delete_saved_query("studio", "concepts").unwrap());
+        assert!(!api_a.delete_saved_query("studio", "concepts").unwrap());
+    }
+
+    #[tokio::test]
+    async fn materialized_view_tracks_ingest_and_refreshes_when_stale() {
+        let (_engine, api) = setup();
+        api.context_create("studio").unwrap();
+        let content = Arc::new(crate::adapter::ContentAdapter::new("content"));
+        api.ingest_with_adapter(
+            "studio",
+            content.clone(),
+            Box::new(FragmentInput::new("first", vec!["rye".into()])),
+        )
+        .await
+        .unwrap();
+
+        api.materialize_view("studio", "concepts", FindQuery::new().with_node_type("concept").into())
+            .unwrap();
+        api.materialize_view(
+            "studio",
+            "rye-neighborhood",
+            TraverseQuery::from(NodeId::from("concept:rye"))
+                .direction(crate::query::Direction::Both)
+                .into(),
+        )
+        .unwrap();
+
+        api.ingest_with_adapter(
+            "studio",
+            content,
+            Box::new(FragmentInput::new("second", vec!["rye".into(), "spelt".into()])),
+        )
+        .await
+        .unwrap();
+
+        let concepts = api.read_view("studio", "concepts", false).unwrap();
+        assert_eq!(concepts.nodes.len(), 2, "maintained incrementally");
+        assert!(!concepts.stale);
+        assert!(concepts.events_since_refresh > 0);
+
+        let served = api.read_view("studio", "rye-neighborhood", false).unwrap();
+        assert!(served.stale);
+        assert_eq!(served.nodes.len(), 2, "origin + first fragment as stored");
+        let refreshed = api.read_view("studio", "rye-neighborhood", true).unwrap();
+        assert!(!refreshed.stale);
+        assert_eq!(refreshed.nodes.len(), 3);
+
+        assert!(api.drop_view("studio", "concepts").unwrap());
+        assert!(api.read_view("studio", "concepts", false).is_err());
+    }
+
     #[test]
     fn api_context_listing_sees_another_engines_new_context() {
         let dir = tempfile::tempdir().unwrap();
@@ -1350,6 +2526,7 @@ emit:
     // --- Ingest-based annotation workflow (ADR-015 / ADR-028) ---
 
     use crate::adapter::{ContentAdapter, FragmentInput, ProvenanceAdapter, normalize_chain_name};
+    use crate::graph::ContextTemplate;
 
     // === Regression: api.ingest accepts context name, not UUID ===
     // Ensures consistency with all other PlexusApi methods (find_nodes,
@@ -1459,6 +2636,76 @@ emit:
         assert_eq!(marks[0].line, 42);
     }
 
+    // === Scenario: A maintained closure lists the marks under a chain ===
+    #[tokio::test]
+    async fn closure_answers_marks_under_chain() {
+        let (engine, api) = setup_with_provenance();
+        engine.upsert_context(Context::new("research")).unwrap();
+        let chain_id = normalize_chain_name("field notes");
+        api.ingest(
+            "research",
+            "provenance",
+            Box::new(ProvenanceInput::CreateChain { chain_id: chain_id.clone(), name: "field notes".into(), description: None }),
+        )
+        .await
+        .unwrap();
+        api.maintain_closure("research", "contains").unwrap();
+        let chain = NodeId::from_string(chain_id.as_str());
+        assert!(api.descendants("research", "contains", &chain).unwrap().is_empty());
+        assert!(api.descendants("research", "part_of", &chain).is_err(), "only maintained closures answer");
+
+        let mark_input = ProvenanceInput::AddMark {
+            mark_id: "mark:provenance:closure-1".to_string(),
+            chain_id: chain_id.clone(),
+            file: "src/main.rs".to_string(),
+            line: 7,
+            annotation: "seen".to_string(),
+            column: None,
+            mark_type: None,
+            tags: None,
+        };
+        api.ingest("research", "provenance", Box::new(mark_input)).await.unwrap();
+        let mark = NodeId::from_string("mark:provenance:closure-1");
+        assert_eq!(api.descendants("research", "contains", &chain).unwrap(), vec![(mark.clone(), 1)]);
+        assert_eq!(api.ancestors("research", "contains", &mark).unwrap(), vec![(chain.clone(), 1)]);
+
+        api.delete_mark("research", mark.as_str()).await.unwrap();
+        assert!(api.descendants("research", "contains", &chain).unwrap().is_empty());
+    }
+
+    // === Scenario: Templates create preconfigured contexts ===
+    #[tokio::test]
+    async fn context_from_template_has_chains_and_ontology() {
+        let (engine, api) = setup_with_provenance();
+        api.context_create_from_template("novel", "writing-project").unwrap();
+        let chain_id = normalize_chain_name("outline");
+        let mark_input = ProvenanceInput::AddMark {
+            mark_id: "mark:provenance:chapter-1".to_string(),
+            chain_id: chain_id.clone(),
+            file: "drafts/one.md".to_string(),
+            line: 1,
+            annotation: "opening".to_string(),
+            column: None,
+            mark_type: None,
+            tags: None,
+        };
+        api.ingest("novel", "provenance", Box::new(mark_input)).await.unwrap();
+        let ctx = engine.get_context(&api.resolve("novel").unwrap()).unwrap();
+        assert!(ctx.edges.iter().any(|e| e.relationship == "contains" && e.source.as_str() == chain_id));
+        assert!(ctx.metadata.relationship_ontology.is_some());
+        assert!(api.context_create_from_template("novel", "codebase").is_err(), "names stay unique");
+        assert!(api.context_create_from_template("other", "spreadsheet").is_err());
+
+        engine.save_template(ContextTemplate::new("lab notebook").with_chains(&["experiments"])).unwrap();
+        assert!(engine.save_template(ContextTemplate::new("codebase")).is_err(), "built-ins are reserved");
+        assert_eq!(engine.list_templates().unwrap().last().map(String::as_str), Some("lab notebook"));
+        let id = api.context_create_from_template("march", "lab notebook").unwrap();
+        let march = engine.get_context(&id).unwrap();
+        assert!(march.get_node(&NodeId::from_string(normalize_chain_name("experiments").as_str())).is_some());
+        assert!(engine.delete_template("lab notebook").unwrap());
+        assert!(engine.template("lab notebook").unwrap().is_none());
+    }
+
     // === Scenario: Second ingest reuses existing chain ===
     #[tokio::test]
     async fn ingest_reuses_existing_chain() {
@@ -1589,6 +2836,34 @@ emit:
         let _ = api; // use to prevent unused warning
     }
 
+    // === Scenario: Offline subscribers catch up from the outbound queue ===
+    #[tokio::test]
+    async fn outbound_queue_redelivers_until_acknowledged() {
+        let (engine, api) = setup_with_provenance();
+        engine.upsert_context(Context::new("research")).unwrap();
+        let returned = api
+            .ingest("research", "content", Box::new(FragmentInput::new("note", vec!["rye".into()])))
+            .await
+            .unwrap();
+
+        let queued = api.next_outbound("research", "carrel", None).unwrap();
+        let kinds: Vec<&str> = queued.iter().map(|e| e.kind.as_str()).collect();
+        assert_eq!(kinds, returned.iter().map(|e| e.kind.as_str()).collect::<Vec<_>>());
+        assert_eq!(api.next_outbound("research", "carrel", None).unwrap(), queued, "unacknowledged events are retried");
+
+        let first = queued[0].offset;
+        api.ack_outbound("research", "carrel", first).unwrap();
+        assert_eq!(api.next_outbound("research", "carrel", None).unwrap(), queued[1..].to_vec());
+        api.ack_outbound("research", "carrel", queued.last().unwrap().offset).unwrap();
+        api.ack_outbound("research", "carrel", first).unwrap();
+        assert!(api.next_outbound("research", "carrel", None).unwrap().is_empty(), "acks never move back");
+        assert_eq!(api.next_outbound("research", "trellis", Some(1)).unwrap(), queued[..1].to_vec(), "subscribers are independent");
+
+        api.rewind_outbound("research", "carrel", 0).unwrap();
+        assert_eq!(api.next_outbound("research", "carrel", None).unwrap(), queued);
+        assert_eq!(api.outbound_since("research", first, None).unwrap(), queued[1..].to_vec());
+    }
+
     // === Scenario: Each ingest step produces outbound events ===
     #[tokio::test]
     async fn ingest_steps_produce_outbound_events() {
@@ -1696,6 +2971,70 @@ emit:
         assert!(result.is_err());
     }
 
+    // === Scenario: Switching a context's embedding model re-embeds it ===
+
+    struct TableEmbedder(Vec<(&'static str, Vec<f32>)>);
+
+    impl crate::adapter::Embedder for TableEmbedder {
+        fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, crate::adapter::EmbeddingError> {
+            Ok(texts
+                .iter()
+                .map(|text| self.0.iter().find(|(t, _)| t == text).map_or(vec![0.0, 0.0], |(_, v)| v.clone()))
+                .collect())
+        }
+    }
+
+    #[test]
+    fn switching_embedding_model_retracts_and_reembeds() {
+        use crate::adapter::PipelineBuilder;
+
+        let engine = Arc::new(PlexusEngine::new());
+        let model_a = TableEmbedder(vec![("travel", vec![1.0, 0.0]), ("journey", vec![0.99, 0.1]), ("democracy", vec![0.0, 1.0])]);
+        let model_b = TableEmbedder(vec![("travel", vec![1.0, 0.0]), ("journey", vec![0.0, 1.0]), ("democracy", vec![0.98, 0.1])]);
+        let pipeline = PipelineBuilder::new(engine.clone())
+            .with_embedder("model-a", 0.9, Arc::new(model_a))
+            .with_embedder("model-b", 0.9, Arc::new(model_b))
+            .build();
+        let api = PlexusApi::new(engine.clone(), Arc::new(pipeline));
+
+        let mut ctx = Context::new("research");
+        for label in ["travel", "journey", "democracy"] {
+            let mut node = Node::new_in_dimension("concept", ContentType::Concept, dimension::SEMANTIC);
+            node.id = NodeId::from(format!("concept:{}", label));
+            node.properties.insert("label".into(), PropertyValue::from(label));
+            ctx.nodes.insert(node.id.clone(), node);
+        }
+        engine.upsert_context(ctx).unwrap();
+        let similar = |api: &PlexusApi| {
+            let ctx = api.engine.get_context(&api.resolve("research").unwrap()).unwrap();
+            let mut pairs: Vec<(String, String, Vec<String>)> = ctx
+                .edges()
+                .filter(|e| e.relationship == "similar_to")
+                .map(|e| (e.source.to_string(), e.target.to_string(), e.contributions.keys().cloned().collect()))
+                .collect();
+            pairs.sort();
+            pairs
+        };
+
+        let report = api.reembed("research").unwrap();
+        assert_eq!((report.model.as_str(), report.nodes, report.similar_edges), ("model-a", 3, 2), "default model");
+        assert_eq!(similar(&api)[0], ("concept:journey".into(), "concept:travel".into(), vec!["embedding:model-a".into()]));
+
+        let report = api
+            .context_set_embedding("research", Some(EmbeddingConfig::new("model-b").with_dimensions(2)))
+            .unwrap()
+            .expect("model changed");
+        assert_eq!((report.model.as_str(), report.retracted_edges, report.similar_edges), ("model-b", 2, 2));
+        let pairs = similar(&api);
+        assert_eq!(pairs.len(), 2, "only model-b's pair: {pairs:?}");
+        assert_eq!(pairs[0], ("concept:democracy".into(), "concept:travel".into(), vec!["embedding:model-b".into()]));
+
+        assert!(api.context_set_embedding("research", Some(EmbeddingConfig::new("model-b").with_dimensions(2))).unwrap().is_none());
+        assert!(api.context_set_embedding("research", Some(EmbeddingConfig::new("model-c"))).is_err());
+        let ctx = engine.get_context(&api.resolve("research").unwrap()).unwrap();
+        assert_eq!(ctx.metadata.embedding.unwrap().model, "model-b", "a rejected model leaves the config alone");
+    }
+
     // === ADR-027: Contribution Retraction via PlexusApi ===
 
     #[test]
@@ -1796,4 +3135,119 @@ emit:
         let ctx = engine.get_context(&api.resolve("research").unwrap()).unwrap();
         assert_eq!(ctx.edge_count(), 0, "edge should be pruned after retraction");
     }
+
+    // === Scenario: Tenant-scoped APIs see only their own contexts ===
+    #[tokio::test]
+    async fn tenant_scoped_api_isolates_contexts_and_enforces_quota() {
+        let (engine, untenanted) = setup();
+        let acme = untenanted.clone().with_tenant("acme");
+        let globex = untenanted.clone().with_tenant("globex");
+
+        let acme_notes = acme.context_create("notes").unwrap();
+        let globex_notes = globex.context_create("notes").unwrap();
+        assert_ne!(acme_notes, globex_notes, "names are unique per tenant");
+        assert_eq!(engine.get_context(&acme_notes).unwrap().metadata.tenant.as_deref(), Some("acme"));
+
+        assert_eq!(acme.context_list(None).unwrap(), vec![acme_notes.clone()]);
+        assert_eq!(globex.context_list_info().unwrap().len(), 1);
+        assert!(untenanted.context_list(None).unwrap().is_empty());
+        assert!(untenanted.context_info("notes").is_err(), "other tenants' contexts don't resolve");
+
+        engine.set_tenant_quota("acme", Some(TenantQuota { max_contexts: Some(2), max_nodes: Some(1), max_edges: None }));
+        acme.context_create("journal").unwrap();
+        assert!(matches!(acme.context_create("drafts"), Err(PlexusError::QuotaExceeded { .. })));
+        globex.context_create("drafts").unwrap();
+
+        engine.add_node(&acme_notes, Node::new("fragment", ContentType::Document)).unwrap();
+        let err = acme.ingest("notes", "content", Box::new(())).await.unwrap_err();
+        assert!(err.to_string().contains("quota exceeded"), "{err}");
+    }
+
+    // === Scenario: Renamed and copied-then-deleted files are paired with their new paths ===
+    #[test]
+    fn moved_files_are_detected_and_relocated() {
+        use crate::graph::{file_content_hash, file_inode, CONTENT_HASH_PROPERTY, INODE_PROPERTY};
+
+        let (engine, api) = setup();
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().to_string_lossy().into_owned();
+        let ctx_id = api.context_create("notes").unwrap();
+        engine.add_source(&ctx_id, Source::Directory { path: root.clone(), recursive: true }).unwrap();
+        for (name, text) in [("a.md", "rye"), ("b.md", "spelt")] {
+            let path = format!("{}/{}", root, name);
+            std::fs::write(&path, text).unwrap();
+            let mut file = crate::adapter::file_node(&path);
+            file.properties.insert("path".into(), PropertyValue::from(path.as_str()));
+            file.properties.insert(CONTENT_HASH_PROPERTY.into(), PropertyValue::from(file_content_hash(text.as_bytes()).as_str()));
+            let inode = file_inode(&std::fs::metadata(&path).unwrap()).unwrap_or(0);
+            file.properties.insert(INODE_PROPERTY.into(), PropertyValue::Int(inode));
+            engine.add_node(&ctx_id, file).unwrap();
+        }
+        let mut mark = crate::adapter::mark_node("mark:1");
+        mark.properties.insert("file".into(), PropertyValue::from(format!("{}/b.md", root).as_str()));
+        engine.add_node(&ctx_id, mark).unwrap();
+        assert!(api.detect_relocations("notes").unwrap().is_empty(), "nothing has moved yet");
+
+        std::fs::create_dir(dir.path().join("sub")).unwrap();
+        std::fs::rename(dir.path().join("a.md"), dir.path().join("sub/renamed.md")).unwrap();
+        std::fs::copy(dir.path().join("b.md"), dir.path().join("copied.md")).unwrap();
+        std::fs::remove_file(dir.path().join("b.md")).unwrap();
+
+        let found = api.detect_relocations("notes").unwrap();
+        let pairs: Vec<(&str, &str)> = found.iter().map(|r| (r.to.as_str(), r.matched_by.as_str())).collect();
+        let expect_inode = if cfg!(unix) { "inode" } else { "content_hash" };
+        assert_eq!(pairs, vec![(format!("{}/sub/renamed.md", root).as_str(), expect_inode), (format!("{}/copied.md", root).as_str(), "content_hash")]);
+
+        let reports = api.relocate_moved_files("notes").unwrap();
+        assert_eq!(reports.len(), 2);
+        let ctx = engine.get_context(&ctx_id).unwrap();
+        assert!(ctx.get_node(&NodeId::from_string(format!("file:{}/copied.md", root))).is_some());
+        assert_eq!(ctx.get_node(&NodeId::from("mark:1")).unwrap().get_str("file"), Some(format!("{}/copied.md", root).as_str()));
+        assert!(api.detect_relocations("notes").unwrap().is_empty());
+    }
+
+    // === Scenario: The relocation watcher picks up a rename without being asked ===
+    #[test]
+    fn relocation_watcher_relocates_renamed_files() {
+        use crate::graph::{file_inode, INODE_PROPERTY};
+
+        let (engine, api) = setup();
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().to_string_lossy().into_owned();
+        let ctx_id = api.context_create("notes").unwrap();
+        engine.add_source(&ctx_id, Source::Directory { path: root.clone(), recursive: true }).unwrap();
+        let path = format!("{}/a.md", root);
+        std::fs::write(&path, "rye").unwrap();
+        let mut file = crate::adapter::file_node(&path);
+        file.properties.insert("path".into(), PropertyValue::from(path.as_str()));
+        let inode = file_inode(&std::fs::metadata(&path).unwrap()).unwrap_or(0);
+        file.properties.insert(INODE_PROPERTY.into(), PropertyValue::Int(inode));
+        engine.add_node(&ctx_id, file).unwrap();
+
+        let watcher = api.watch_relocations(std::time::Duration::from_millis(10));
+        std::fs::rename(&path, dir.path().join("b.md")).unwrap();
+        let moved = NodeId::from_string(format!("file:{}/b.md", root));
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+        while engine.get_context(&ctx_id).unwrap().get_node(&moved).is_none() {
+            assert!(std::time::Instant::now() < deadline, "the watcher never relocated the file");
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        drop(watcher);
+    }
+
+    // === Scenario: Bootstrap doesn't follow symlinked directories ===
+    #[cfg(unix)]
+    #[test]
+    fn collect_files_skips_symlinked_directories() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("notes")).unwrap();
+        std::fs::write(dir.path().join("notes/rye.md"), "# Rye").unwrap();
+        std::os::unix::fs::symlink(dir.path(), dir.path().join("notes/loop")).unwrap();
+        std::os::unix::fs::symlink(dir.path().join("notes/rye.md"), dir.path().join("linked.md")).unwrap();
+
+        let mut files = Vec::new();
+        collect_files(dir.path(), &BootstrapOptions::default(), &mut files).unwrap();
+        files.sort();
+        assert_eq!(files, vec![dir.path().join("linked.md"), dir.path().join("notes/rye.md")], "linked files are still read");
+    }
 }
@@ -3,11 +3,13 @@
 //! Usage:
 //!   plexus mcp [--transport stdio] [--db path]
 //!   plexus context <subcommand> [--db path]
+//!   plexus replicate <serve|follow> [--db path]
 
 use clap::{Parser, Subcommand};
-use plexus::{Context, ContextId, OpenStore, PlexusEngine, Source, SqliteStore};
-use plexus::adapter::{GraphAnalysisAdapter, IngestPipeline, run_analysis};
+use plexus::{Context, ContextId, MlExport, OpenStore, PlexusEngine, Source, SqliteStore, TenantDirectory};
+use plexus::adapter::{GraphAnalysisAdapter, IngestPipeline, PipelineBuilder, ReplayLog, run_analysis};
 use plexus::llm_orc::SubprocessClient;
+use plexus::replication::{Replica, ReplicationServer};
 use std::path::PathBuf;
 use std::sync::Arc;
 use tracing::{error, warn};
@@ -33,6 +35,23 @@ enum Commands {
         /// Path to SQLite database file
         #[arg(long)]
         db: Option<PathBuf>,
+        /// Append every ingest call to this replay log
+        #[arg(long)]
+        record: Option<PathBuf>,
+        /// Tenant directory (JSON); the tenant is selected by PLEXUS_API_KEY
+        #[arg(long)]
+        tenant_keys: Option<PathBuf>,
+        /// Relocate moved source files, polling every this many seconds
+        #[arg(long, value_name = "SECONDS")]
+        watch_relocations: Option<u64>,
+    },
+    /// Rebuild graphs by re-running the ingests in a replay log
+    Replay {
+        /// Replay log written by `plexus mcp --record`
+        log: PathBuf,
+        /// Path to SQLite database file (use a fresh one to rebuild from scratch)
+        #[arg(long)]
+        db: Option<PathBuf>,
     },
     /// Run on-demand external enrichment on a context via llm-orc (ADR-024)
     Analyze {
@@ -54,6 +73,41 @@ enum Commands {
         #[arg(long, global = true)]
         db: Option<PathBuf>,
     },
+    /// Mirror contexts with another Plexus instance over TCP or WebSocket
+    Replicate {
+        #[command(subcommand)]
+        action: ReplicateAction,
+        /// Path to SQLite database file
+        #[arg(long, global = true)]
+        db: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ReplicateAction {
+    /// Serve this database's contexts to replicating peers
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1:7878")]
+        listen: String,
+        /// Tenant directory file; peers then authenticate with its API keys
+        #[arg(long)]
+        tenants: Option<PathBuf>,
+        /// Speak WebSocket instead of newline-delimited TCP
+        #[arg(long)]
+        websocket: bool,
+    },
+    /// Push to and pull from a peer continuously
+    Follow {
+        /// The peer's `host:port`, or a `ws://` URL
+        peer: String,
+        /// Seconds between rounds
+        #[arg(long, default_value_t = 30)]
+        interval: u64,
+        /// API key for a server with a tenant directory
+        #[arg(long)]
+        api_key: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -93,6 +147,19 @@ enum ContextAction {
         #[arg(required = true)]
         path: PathBuf,
     },
+    /// Export a context as CSV tables for GNN training (PyG, DGL)
+    ExportMl {
+        /// Name of the context
+        name: String,
+        /// Directory to write nodes.csv, edges.csv, node_ids.csv, categories.csv
+        out: PathBuf,
+        /// Numeric node properties to export as feature columns
+        #[arg(long, value_delimiter = ',')]
+        features: Vec<String>,
+        /// Only nodes of these types
+        #[arg(long, value_delimiter = ',')]
+        node_types: Vec<String>,
+    },
 }
 
 /// Get the default database path: platform data dir + plexus/plexus.db
@@ -288,6 +355,30 @@ fn cmd_context_remove_source(engine: &PlexusEngine, name: &str, path: &std::path
     }
 }
 
+fn cmd_context_export_ml(engine: &PlexusEngine, name: &str, out: &std::path::Path, export: MlExport) -> i32 {
+    let Some(ctx) = find_context_by_name(engine, name).and_then(|id| engine.get_context(&id)) else {
+        error!(name, "context not found");
+        return 1;
+    };
+    let files = export.execute(&ctx);
+    match files.write_to(out) {
+        Ok(()) => {
+            println!(
+                "Exported {} nodes and {} edges from '{}' to {}",
+                files.node_count,
+                files.edge_count,
+                name,
+                out.display()
+            );
+            0
+        }
+        Err(e) => {
+            error!(path = %out.display(), error = %e, "cannot write export");
+            1
+        }
+    }
+}
+
 async fn cmd_analyze(engine: Arc<PlexusEngine>, context_name: &str, ensemble: &str) -> i32 {
     let ctx_id = match find_context_by_name(&engine, context_name) {
         Some(id) => id,
@@ -355,6 +446,84 @@ async fn cmd_analyze(engine: Arc<PlexusEngine>, context_name: &str, ensemble: &s
     0
 }
 
+async fn cmd_replay(engine: Arc<PlexusEngine>, log: &std::path::Path) -> i32 {
+    let entries = match ReplayLog::read(log) {
+        Ok(entries) => entries,
+        Err(e) => {
+            error!(path = %log.display(), error = %e, "cannot read replay log");
+            return 1;
+        }
+    };
+    println!("Replaying {} ingests from {}...", entries.len(), log.display());
+
+    let pipeline = PipelineBuilder::default_pipeline(engine);
+    let summary = match pipeline.replay(&entries).await {
+        Ok(s) => s,
+        Err(e) => {
+            error!(error = %e, "operation failed");
+            return 1;
+        }
+    };
+
+    for name in &summary.contexts_created {
+        println!("  created context '{}'", name);
+    }
+    for (index, e) in &summary.failures {
+        warn!(entry = index, error = %e, "ingest failed during replay");
+    }
+    println!(
+        "Done. {} replayed, {} failed.",
+        summary.replayed,
+        summary.failures.len()
+    );
+    if summary.failures.is_empty() { 0 } else { 1 }
+}
+
+async fn cmd_replicate(engine: Arc<PlexusEngine>, action: ReplicateAction) -> i32 {
+    match action {
+        ReplicateAction::Serve { listen, tenants, websocket } => {
+            let mut server = ReplicationServer::new(engine.clone());
+            if let Some(path) = tenants {
+                match TenantDirectory::load(&path) {
+                    Ok(directory) => server = server.with_tenants(directory),
+                    Err(e) => {
+                        error!(path = %path.display(), error = %e, "cannot load tenant directory");
+                        return 1;
+                    }
+                }
+            }
+            let listener = match tokio::net::TcpListener::bind(&listen).await {
+                Ok(l) => l,
+                Err(e) => {
+                    error!(address = %listen, error = %e, "cannot listen");
+                    return 1;
+                }
+            };
+            println!("Serving {} contexts on {}", engine.context_count(), listen);
+            let served = match websocket {
+                true => server.serve_websocket(listener).await,
+                false => server.serve(listener).await,
+            };
+            match served {
+                Ok(()) => 0,
+                Err(e) => {
+                    error!(error = %e, "replication server stopped");
+                    1
+                }
+            }
+        }
+        ReplicateAction::Follow { peer, interval, api_key } => {
+            println!("Replicating with {} every {}s", peer, interval);
+            let mut replica = Replica::new(engine, peer);
+            if let Some(key) = api_key {
+                replica = replica.with_api_key(key);
+            }
+            replica.run(std::time::Duration::from_secs(interval)).await;
+            0
+        }
+    }
+}
+
 fn main() {
     tracing_subscriber::fmt()
         .with_writer(std::io::stderr)
@@ -362,13 +531,31 @@ fn main() {
 
     let cli = Cli::parse();
     match cli.command {
-        Commands::Mcp { transport, db } => {
+        Commands::Mcp { transport, db, record, tenant_keys, watch_relocations } => {
             if transport != "stdio" {
                 error!("only 'stdio' transport is currently supported");
                 std::process::exit(1);
             }
             let db_path = db.unwrap_or_else(default_db_path);
-            let code = plexus::mcp::run_mcp_server(db_path);
+            let code = plexus::mcp::run_mcp_server(
+                db_path,
+                record,
+                tenant_keys,
+                watch_relocations.map(std::time::Duration::from_secs),
+            );
+            std::process::exit(code);
+        }
+        Commands::Replay { log, db } => {
+            let engine = match open_engine(db) {
+                Ok(e) => e,
+                Err(e) => {
+                    error!(error = %e, "operation failed");
+                    std::process::exit(1);
+                }
+            };
+            let code = tokio::runtime::Runtime::new()
+                .unwrap()
+                .block_on(cmd_replay(Arc::new(engine), &log));
             std::process::exit(code);
         }
         Commands::Analyze { name, ensemble, db } => {
@@ -400,8 +587,25 @@ fn main() {
                 ContextAction::Rename { old, new } => cmd_context_rename(&engine, &old, &new),
                 ContextAction::AddSource { name, path } => cmd_context_add_source(&engine, &name, &path),
                 ContextAction::RemoveSource { name, path } => cmd_context_remove_source(&engine, &name, &path),
+                ContextAction::ExportMl { name, out, features, node_types } => {
+                    let export = MlExport::new().with_feature_properties(features).with_node_types(node_types);
+                    cmd_context_export_ml(&engine, &name, &out, export)
+                }
             };
             std::process::exit(code);
         }
+        Commands::Replicate { action, db } => {
+            let engine = match open_engine(db) {
+                Ok(e) => Arc::new(e),
+                Err(e) => {
+                    error!(error = %e, "operation failed");
+                    std::process::exit(1);
+                }
+            };
+            let code = tokio::runtime::Runtime::new()
+                .unwrap()
+                .block_on(cmd_replicate(engine, action));
+            std::process::exit(code);
+        }
     }
 }
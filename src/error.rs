@@ -0,0 +1,281 @@
+//! Error taxonomy shared by the engine, adapter and storage layers
+//!
+//! `PlexusError`, `AdapterError`, `StorageError` and the spec lifecycle
+//! errors each map to a stable [`ErrorCode`] and say whether retrying may
+//! succeed, so callers (and MCP clients, via [`ErrorPayload`]) can branch
+//! on the kind of failure rather than on message text. Codes are part of
+//! the public contract: add new ones, never rename them.
+
+use crate::adapter::AdapterError;
+use crate::api::{LinkError, SpecLoadError, SpecUnloadError};
+use crate::graph::PlexusError;
+use crate::storage::StorageError;
+use serde::Serialize;
+use std::error::Error;
+use std::fmt;
+
+/// Stable, machine-readable error codes (serialized in snake_case).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ErrorCode {
+    ContextNotFound,
+    NodeNotFound,
+    EdgeNotFound,
+    /// Some other named thing (saved query, view, overlay, template…)
+    NotFound,
+    AlreadyExists,
+    InvalidInput,
+    /// The engine or store isn't configured for the operation
+    Unsupported,
+    QuotaExceeded,
+    /// A peer or external service couldn't be reached
+    Unavailable,
+    Cancelled,
+    /// An adapter declined the input (no ensemble, budget spent);
+    /// retrying unchanged declines again
+    Skipped,
+    Serialization,
+    Storage,
+    /// The database is locked by another writer
+    StorageBusy,
+    UnsupportedSchema,
+    Io,
+    Internal,
+}
+
+impl ErrorCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::ContextNotFound => "context_not_found",
+            ErrorCode::NodeNotFound => "node_not_found",
+            ErrorCode::EdgeNotFound => "edge_not_found",
+            ErrorCode::NotFound => "not_found",
+            ErrorCode::AlreadyExists => "already_exists",
+            ErrorCode::InvalidInput => "invalid_input",
+            ErrorCode::Unsupported => "unsupported",
+            ErrorCode::QuotaExceeded => "quota_exceeded",
+            ErrorCode::Unavailable => "unavailable",
+            ErrorCode::Cancelled => "cancelled",
+            ErrorCode::Skipped => "skipped",
+            ErrorCode::Serialization => "serialization",
+            ErrorCode::Storage => "storage",
+            ErrorCode::StorageBusy => "storage_busy",
+            ErrorCode::UnsupportedSchema => "unsupported_schema",
+            ErrorCode::Io => "io",
+            ErrorCode::Internal => "internal",
+        }
+    }
+
+    /// Whether failures with this code are transient by nature.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, ErrorCode::Unavailable | ErrorCode::StorageBusy)
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An error with a stable code and a retryability flag.
+pub trait ErrorCoded: Error {
+    fn code(&self) -> ErrorCode;
+
+    /// Whether the same call may succeed if retried unchanged.
+    fn is_retryable(&self) -> bool {
+        self.code().is_retryable()
+    }
+}
+
+impl ErrorCoded for StorageError {
+    fn code(&self) -> ErrorCode {
+        match self {
+            StorageError::Database(e) => match e.sqlite_error_code() {
+                Some(rusqlite::ErrorCode::DatabaseBusy | rusqlite::ErrorCode::DatabaseLocked) => ErrorCode::StorageBusy,
+                _ => ErrorCode::Storage,
+            },
+            StorageError::Serialization(_) | StorageError::DateParse(_) => ErrorCode::Serialization,
+            StorageError::ContextNotFound(_) => ErrorCode::ContextNotFound,
+            StorageError::NodeNotFound(_) => ErrorCode::NodeNotFound,
+            StorageError::Io(_) => ErrorCode::Io,
+            StorageError::Internal(_) => ErrorCode::Storage,
+            StorageError::UnsupportedSchemaVersion { .. } => ErrorCode::UnsupportedSchema,
+        }
+    }
+
+    fn is_retryable(&self) -> bool {
+        match self {
+            StorageError::Io(e) => is_transient_io(e),
+            other => other.code().is_retryable(),
+        }
+    }
+}
+
+impl ErrorCoded for PlexusError {
+    fn code(&self) -> ErrorCode {
+        match self {
+            PlexusError::ContextNotFound(_) => ErrorCode::ContextNotFound,
+            PlexusError::NodeNotFound(_) => ErrorCode::NodeNotFound,
+            PlexusError::EdgeNotFound(_) => ErrorCode::EdgeNotFound,
+            PlexusError::NotFound(_) => ErrorCode::NotFound,
+            PlexusError::AlreadyExists(_) => ErrorCode::AlreadyExists,
+            PlexusError::InvalidInput(_) => ErrorCode::InvalidInput,
+            PlexusError::Unsupported(_) => ErrorCode::Unsupported,
+            PlexusError::Unavailable(_) => ErrorCode::Unavailable,
+            PlexusError::Serialization(_) => ErrorCode::Serialization,
+            PlexusError::Storage(e) => e.code(),
+            PlexusError::QuotaExceeded { .. } | PlexusError::ContextQuotaExceeded { .. } => ErrorCode::QuotaExceeded,
+            PlexusError::Other(_) => ErrorCode::Internal,
+        }
+    }
+
+    fn is_retryable(&self) -> bool {
+        match self {
+            PlexusError::Storage(e) => e.is_retryable(),
+            other => other.code().is_retryable(),
+        }
+    }
+}
+
+impl ErrorCoded for AdapterError {
+    fn code(&self) -> ErrorCode {
+        match self {
+            AdapterError::InvalidInput | AdapterError::Validation(_) => ErrorCode::InvalidInput,
+            AdapterError::Cancelled => ErrorCode::Cancelled,
+            AdapterError::ContextNotFound(_) => ErrorCode::ContextNotFound,
+            AdapterError::Storage(_) => ErrorCode::Storage,
+            AdapterError::Serialization(_) => ErrorCode::Serialization,
+            AdapterError::Internal(_) => ErrorCode::Internal,
+            AdapterError::Skipped(_) => ErrorCode::Skipped,
+            AdapterError::Engine(e) => e.code(),
+        }
+    }
+
+    fn is_retryable(&self) -> bool {
+        match self {
+            AdapterError::Engine(e) => e.is_retryable(),
+            other => other.code().is_retryable(),
+        }
+    }
+}
+
+impl ErrorCoded for SpecLoadError {
+    fn code(&self) -> ErrorCode {
+        match self {
+            SpecLoadError::ContextNotFound(_) => ErrorCode::ContextNotFound,
+            SpecLoadError::Validation(_) => ErrorCode::InvalidInput,
+            SpecLoadError::Registration(_) | SpecLoadError::LensSweep(_) => ErrorCode::Internal,
+            SpecLoadError::Persistence(_) => ErrorCode::Storage,
+        }
+    }
+}
+
+impl ErrorCoded for SpecUnloadError {
+    fn code(&self) -> ErrorCode {
+        match self {
+            SpecUnloadError::ContextNotFound(_) => ErrorCode::ContextNotFound,
+            SpecUnloadError::Persistence(_) => ErrorCode::Storage,
+        }
+    }
+}
+
+impl ErrorCoded for LinkError {
+    fn code(&self) -> ErrorCode {
+        match self {
+            LinkError::MarkNotFound(_) => ErrorCode::NodeNotFound,
+            LinkError::Adapter(e) => e.code(),
+            LinkError::Plexus(e) => e.code(),
+        }
+    }
+
+    fn is_retryable(&self) -> bool {
+        match self {
+            LinkError::MarkNotFound(_) => false,
+            LinkError::Adapter(e) => e.is_retryable(),
+            LinkError::Plexus(e) => e.is_retryable(),
+        }
+    }
+}
+
+fn is_transient_io(e: &std::io::Error) -> bool {
+    use std::io::ErrorKind;
+    matches!(
+        e.kind(),
+        ErrorKind::Interrupted | ErrorKind::TimedOut | ErrorKind::WouldBlock | ErrorKind::ConnectionReset | ErrorKind::ConnectionRefused
+    )
+}
+
+/// The wire form of an error, for MCP tool results and other clients.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ErrorPayload {
+    pub code: ErrorCode,
+    pub message: String,
+    pub retryable: bool,
+    /// Messages of the underlying causes, outermost first
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub causes: Vec<String>,
+}
+
+impl ErrorPayload {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self { code, message: message.into(), retryable: code.is_retryable(), causes: Vec::new() }
+    }
+
+    /// Describe `error`, following its source chain.
+    pub fn from_error<E: ErrorCoded + ?Sized>(error: &E) -> Self {
+        let mut causes = Vec::new();
+        let mut source = error.source();
+        while let Some(cause) = source {
+            causes.push(cause.to_string());
+            source = cause.source();
+        }
+        Self { code: error.code(), message: error.to_string(), retryable: error.is_retryable(), causes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::ContextId;
+
+    // === Scenario: Errors keep their code across layers ===
+    #[test]
+    fn adapter_errors_wrapping_the_engine_keep_its_code_and_chain() {
+        let storage = StorageError::Io(std::io::Error::new(std::io::ErrorKind::TimedOut, "disk stalled"));
+        let err = AdapterError::from(PlexusError::Storage(storage));
+        assert_eq!(err.code(), ErrorCode::Io);
+        assert!(err.is_retryable(), "a timed-out read may succeed next time");
+
+        let payload = ErrorPayload::from_error(&err);
+        assert_eq!(payload.code, ErrorCode::Io);
+        assert!(payload.causes.iter().any(|c| c.contains("disk stalled")), "{:?}", payload.causes);
+
+        let missing = AdapterError::from(PlexusError::ContextNotFound(ContextId::from("gone")));
+        assert!(matches!(missing, AdapterError::ContextNotFound(_)));
+        assert!(!missing.is_retryable());
+    }
+
+    // === Scenario: Busy databases are retryable, schema mismatches are not ===
+    #[test]
+    fn storage_codes_and_retryability() {
+        let busy = StorageError::Database(rusqlite::Error::SqliteFailure(
+            rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_BUSY),
+            None,
+        ));
+        assert_eq!(busy.code(), ErrorCode::StorageBusy);
+        assert!(busy.is_retryable());
+
+        let newer = StorageError::UnsupportedSchemaVersion { found: 99, supported: 14 };
+        assert_eq!(newer.code(), ErrorCode::UnsupportedSchema);
+        assert!(!newer.is_retryable());
+
+        let json = serde_json::to_value(ErrorPayload::from_error(&PlexusError::NotFound("saved query 'q' not found".into()))).unwrap();
+        assert_eq!(json["code"], "not_found");
+        assert_eq!(json["retryable"], false);
+        assert!(json.get("causes").is_none());
+
+        assert!(!AdapterError::Skipped("llm budget exhausted".into()).is_retryable(), "skipping again is certain");
+    }
+}
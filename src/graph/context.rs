@@ -1,7 +1,16 @@
 //! Context: A bounded subgraph representing a workspace or project
 
-use super::edge::Edge;
-use super::node::{Node, NodeId};
+use super::contribution::{ContributionAggregation, ContributionMode};
+use super::edge::{AdapterId, Edge, EdgePolicy, CONFIDENCE_PROPERTY};
+use super::ontology::RelationshipOntology;
+use super::tag_policy::TagPolicy;
+use super::trash::Trash;
+use super::overlay::Overlay;
+use super::enrichment_stats::{EdgeFate, EnrichmentOutcome};
+use super::quota::ContextQuota;
+use super::write_scope::WriteScope;
+use crate::query::{MaterializedView, SavedQuery, TransitiveClosure};
+use super::node::{normalize_natural_key, Node, NodeId, PropertyValue};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -67,6 +76,53 @@ pub enum Source {
     ContextRef { context_id: String },
 }
 
+/// A context's embedding model settings, overriding the pipeline's
+/// default embedder (see `PlexusApi::context_set_embedding`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddingConfig {
+    /// Model name, as registered with `PipelineBuilder::with_embedder`
+    pub model: String,
+    /// Expected vector length; vectors of any other length are dropped
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<usize>,
+    /// Minimum cosine similarity for `similar_to`; `None` keeps the
+    /// threshold the model was registered with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<f32>,
+}
+
+impl EmbeddingConfig {
+    pub fn new(model: impl Into<String>) -> Self {
+        Self { model: model.into(), dimensions: None, threshold: None }
+    }
+
+    pub fn with_dimensions(mut self, dimensions: usize) -> Self {
+        self.dimensions = Some(dimensions);
+        self
+    }
+
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+}
+
+/// How promptly a context's writes reach disk. Stores that can't defer
+/// writes treat every level as `Strict`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "level", rename_all = "snake_case")]
+pub enum Durability {
+    /// Every save commits and syncs before returning
+    #[default]
+    Strict,
+    /// Saves are held and group-committed once the oldest has waited
+    /// `interval_ms`; a crash loses at most that window
+    Batched { interval_ms: u64 },
+    /// Saves are held until the store goes idle or shuts down; for
+    /// scratch contexts that can be rebuilt
+    Relaxed,
+}
+
 /// Metadata about a context
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ContextMetadata {
@@ -84,8 +140,99 @@ pub struct ContextMetadata {
     /// Application-specific properties (generic key-value bag)
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub properties: BTreeMap<String, String>,
+    /// Per-relationship edge identity policy; unlisted relationships merge
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub edge_policies: BTreeMap<String, EdgePolicy>,
+    /// Tag policy applied to ingested tags; `None` keeps every tag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag_policy: Option<TagPolicy>,
+    /// Relationship declarations (inverses, directionality, legal
+    /// dimensions); `None` accepts any relationship
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relationship_ontology: Option<RelationshipOntology>,
+    /// Named query definitions, runnable by name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub saved_queries: BTreeMap<String, SavedQuery>,
+    /// Named materialized views, maintained on every commit
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub materialized_views: BTreeMap<String, MaterializedView>,
+    /// Transitive closures maintained on every commit, by relationship
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub closures: BTreeMap<String, TransitiveClosure>,
+    /// Named hypothesis layers, applied only when viewed or committed
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub overlays: BTreeMap<String, Overlay>,
+    /// When set, edges keep a timestamped contribution log and each
+    /// contribution slot holds the aggregate of its entries
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contribution_history: Option<ContributionAggregation>,
+    /// Per-adapter accumulation of repeated contributions; unlisted
+    /// adapters replace
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub contribution_modes: BTreeMap<AdapterId, ContributionMode>,
+    /// Per-adapter confidence (0..=1) scaling its contributions after
+    /// normalization, so a weak proposer can't match stronger evidence;
+    /// unlisted adapters count fully
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub contribution_confidence: BTreeMap<AdapterId, f32>,
+    /// Per-adapter limits on what an adapter may write; unlisted adapters
+    /// write anywhere
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub write_scopes: BTreeMap<AdapterId, WriteScope>,
+    /// Size limits enforced on writes; `None` leaves the context unbounded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quota: Option<ContextQuota>,
+    /// The enrichment that derived each live derived edge, by edge ID
+    /// (see `enrichment_stats`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub derived_edges: BTreeMap<String, String>,
+    /// Derived edges removed since, per enrichment (see `enrichment_stats`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub enrichment_outcomes: BTreeMap<String, EnrichmentOutcome>,
+    /// Owning tenant; `None` for single-tenant deployments
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
+    /// Days trashed items are kept before `purge_trash` drops them
+    /// (default `DEFAULT_TRASH_RETENTION_DAYS`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trash_retention_days: Option<u32>,
+    /// Dimensions the context is laid out in, as declared by its template
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dimensions: Vec<String>,
+    /// Which of the `GATED_ENRICHMENT_FAMILIES` run on this context (an
+    /// enrichment ID's prefix before the first `:`); `None` runs them all
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enrichments: Option<Vec<String>>,
+    /// Embedding model serving this context; `None` uses the pipeline's
+    /// default embedder
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embedding: Option<EmbeddingConfig>,
+    /// How promptly writes reach disk; `None` is `Durability::Strict`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub durability: Option<Durability>,
 }
 
+/// Enrichment families a context's `enrichments` list chooses among.
+/// Any other family — lenses, rules, consumer-registered enrichments —
+/// runs on every context.
+pub const GATED_ENRICHMENT_FAMILIES: &[&str] =
+    &["co_occurrence", "discovery_gap", "temporal", "embedding", "citation", "keyword_extraction", "near_duplicate"];
+
+impl ContextMetadata {
+    /// Whether the enrichment `id` runs on this context.
+    pub fn runs_enrichment(&self, id: &str) -> bool {
+        let family = id.split(':').next().unwrap_or(id);
+        if !GATED_ENRICHMENT_FAMILIES.contains(&family) {
+            return true;
+        }
+        self.enrichments.as_ref().is_none_or(|families| families.iter().any(|f| f == family))
+    }
+}
+
+/// Node property holding a hash of the content a node was ingested
+/// from, for deduplicating re-ingested content.
+pub const CONTENT_HASH_PROPERTY: &str = "content_hash";
+
 /// A bounded subgraph representing a workspace or project
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Context {
@@ -101,6 +248,9 @@ pub struct Context {
     pub edges: Vec<Edge>,
     /// Context metadata
     pub metadata: ContextMetadata,
+    /// Soft-deleted nodes and edges, excluded from queries
+    #[serde(default, skip_serializing_if = "Trash::is_empty")]
+    pub trash: Trash,
 }
 
 impl Context {
@@ -112,6 +262,7 @@ impl Context {
             description: None,
             nodes: HashMap::new(),
             edges: Vec::new(),
+            trash: Trash::default(),
             metadata: ContextMetadata {
                 created_at: Some(Utc::now()),
                 ..Default::default()
@@ -127,6 +278,7 @@ impl Context {
             description: None,
             nodes: HashMap::new(),
             edges: Vec::new(),
+            trash: Trash::default(),
             metadata: ContextMetadata {
                 created_at: Some(Utc::now()),
                 ..Default::default()
@@ -146,6 +298,17 @@ impl Context {
         self
     }
 
+    /// Assign the context to a tenant
+    pub fn with_tenant(mut self, tenant: impl Into<String>) -> Self {
+        self.metadata.tenant = Some(tenant.into());
+        self
+    }
+
+    /// This context's key in the engine's name index
+    pub(crate) fn index_name(&self) -> String {
+        super::tenant::scoped_name(self.metadata.tenant.as_deref(), &self.name)
+    }
+
     /// Add a node to the context
     pub fn add_node(&mut self, node: Node) -> NodeId {
         let id = node.id.clone();
@@ -154,6 +317,20 @@ impl Context {
         id
     }
 
+    /// Find the node declaring `key` as its natural key within
+    /// `(dimension, node_type)`. The key is normalized before matching.
+    pub fn find_by_natural_key(&self, dimension: &str, node_type: &str, key: &str) -> Option<&NodeId> {
+        let key = normalize_natural_key(key);
+        self.nodes
+            .values()
+            .find(|n| {
+                n.dimension == dimension
+                    && n.node_type == node_type
+                    && n.natural_key() == Some(key.as_str())
+            })
+            .map(|n| &n.id)
+    }
+
     /// Find the index of an edge that exactly matches all 5 fields:
     /// source, target, relationship, source_dimension, target_dimension.
     pub fn find_edge_exact(
@@ -173,6 +350,157 @@ impl Context {
         })
     }
 
+    /// Live nodes of `node_type` whose `content_hash` property is `hash`
+    /// (see `CONTENT_HASH_PROPERTY`).
+    pub fn find_by_content_hash(&self, node_type: &str, hash: &str) -> Vec<&Node> {
+        self.nodes
+            .values()
+            .filter(|n| n.node_type == node_type)
+            .filter(|n| matches!(n.properties.get(CONTENT_HASH_PROPERTY), Some(PropertyValue::String(h)) if h == hash))
+            .collect()
+    }
+
+    /// The edge identity policy for a relationship.
+    pub fn edge_policy(&self, relationship: &str) -> EdgePolicy {
+        self.metadata
+            .edge_policies
+            .get(relationship)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// How `adapter_id`'s repeated contributions accumulate.
+    pub fn contribution_mode(&self, adapter_id: &str) -> ContributionMode {
+        self.metadata.contribution_modes.get(adapter_id).copied().unwrap_or_default()
+    }
+
+    /// How much `adapter_id`'s normalized contributions count (default 1).
+    pub fn contribution_confidence(&self, adapter_id: &str) -> f32 {
+        self.metadata.contribution_confidence.get(adapter_id).copied().unwrap_or(1.0)
+    }
+
+    /// Scale `adapter_id`'s normalized contributions by `confidence`
+    /// (clamped to 0..=1). Applies from the next weight recomputation.
+    pub fn set_contribution_confidence(&mut self, adapter_id: impl Into<AdapterId>, confidence: f32) {
+        let adapter_id = adapter_id.into();
+        let confidence = if confidence.is_nan() { 1.0 } else { confidence.clamp(0.0, 1.0) };
+        if confidence == 1.0 {
+            self.metadata.contribution_confidence.remove(&adapter_id);
+        } else {
+            self.metadata.contribution_confidence.insert(adapter_id, confidence);
+        }
+        self.touch();
+    }
+
+    /// Set how `adapter_id`'s repeated contributions accumulate. Applies
+    /// from the next emission; existing slots are left as they are.
+    pub fn set_contribution_mode(&mut self, adapter_id: impl Into<AdapterId>, mode: ContributionMode) {
+        let adapter_id = adapter_id.into();
+        if mode == ContributionMode::default() {
+            self.metadata.contribution_modes.remove(&adapter_id);
+        } else {
+            self.metadata.contribution_modes.insert(adapter_id, mode);
+        }
+        self.touch();
+    }
+
+    /// Set the edge identity policy for a relationship.
+    ///
+    /// Switching to `Merge` consolidates any parallel edges already stored
+    /// for the relationship. Returns the number of edges folded away.
+    pub fn set_edge_policy(&mut self, relationship: impl Into<String>, policy: EdgePolicy) -> usize {
+        let relationship = relationship.into();
+        if policy == EdgePolicy::default() {
+            self.metadata.edge_policies.remove(&relationship);
+        } else {
+            self.metadata.edge_policies.insert(relationship, policy);
+        }
+        self.touch();
+        self.consolidate_edges()
+    }
+
+    /// Index of the stored edge that `edge` would update under its
+    /// relationship's policy: the exact 5-field match for `Merge`, the
+    /// same `EdgeId` for `Parallel`.
+    pub fn find_edge_identity(&self, edge: &Edge) -> Option<usize> {
+        match self.edge_policy(&edge.relationship) {
+            EdgePolicy::Merge => self.find_edge_exact(
+                &edge.source,
+                &edge.target,
+                &edge.relationship,
+                &edge.source_dimension,
+                &edge.target_dimension,
+            ),
+            EdgePolicy::Parallel => self.edges.iter().position(|e| e.id == edge.id),
+        }
+    }
+
+    /// Merge exact-duplicate edges of `Merge`-policy relationships.
+    ///
+    /// Stores written before per-relationship policies (or while a
+    /// relationship was `Parallel`) can hold several edges for one
+    /// (source, target, relationship, dimensions). The first stored edge
+    /// keeps its ID and absorbs later duplicates' contributions and
+    /// properties. A slot both carry resolves as `add_edge` resolves an
+    /// update, the later duplicate arriving second: by the contributor's
+    /// mode, or from the merged log under contribution history.
+    /// Recomputes combined weights when anything merged. Returns the
+    /// number of edges removed.
+    pub fn consolidate_edges(&mut self) -> usize {
+        let mut first_of: HashMap<(NodeId, NodeId, String, String, String), usize> = HashMap::new();
+        let mut merged: Vec<Edge> = Vec::with_capacity(self.edges.len());
+        let mut removed = 0;
+        for edge in std::mem::take(&mut self.edges) {
+            if self.edge_policy(&edge.relationship) == EdgePolicy::Parallel {
+                merged.push(edge);
+                continue;
+            }
+            let key = (
+                edge.source.clone(),
+                edge.target.clone(),
+                edge.relationship.clone(),
+                edge.source_dimension.clone(),
+                edge.target_dimension.clone(),
+            );
+            match first_of.get(&key) {
+                Some(&idx) => {
+                    let existing = &mut merged[idx];
+                    // The surviving edge inherits a duplicate's derivation
+                    if let Some(by) = self.metadata.derived_edges.remove(edge.id.as_str()) {
+                        self.metadata.derived_edges.entry(existing.id.to_string()).or_insert(by);
+                    }
+                    let modes = &self.metadata.contribution_modes;
+                    existing.merge_contributions(&edge, modes);
+                    existing.contribution_log.extend(edge.contribution_log);
+                    existing.contribution_log.sort_by_key(|e| e.at);
+                    if let Some(policy) = self.metadata.contribution_history {
+                        let now = Utc::now();
+                        for adapter_id in edge.contributions.keys().filter(|id| !modes.contains_key(*id)) {
+                            if let Some(value) = existing.aggregate_contribution(adapter_id, policy, now) {
+                                existing.contributions.insert(adapter_id.clone(), value);
+                            }
+                        }
+                    }
+                    existing.combined_weight = existing.combined_weight.max(edge.combined_weight);
+                    for (k, v) in edge.properties {
+                        existing.properties.entry(k).or_insert(v);
+                    }
+                    removed += 1;
+                }
+                None => {
+                    first_of.insert(key, merged.len());
+                    merged.push(edge);
+                }
+            }
+        }
+        self.edges = merged;
+        if removed > 0 {
+            self.recompute_combined_weights();
+            self.touch();
+        }
+        removed
+    }
+
     /// Add an edge to the context.
     ///
     /// Merges contribution slots on exact duplicates but does **not** recompute
@@ -190,19 +518,15 @@ impl Context {
     ///   For edges without contributions, combined_weight falls back to max for backward compat.
     /// - **Cross-dimensional**: When the same logical edge appears in multiple dimensions,
     ///   a `_cross_dim_count` property tracks how many dimensions it spans.
+    /// - **Parallel policy**: For relationships set to `EdgePolicy::Parallel`, only an
+    ///   edge with the same `EdgeId` counts as a duplicate; other edges between the
+    ///   same endpoints are stored alongside it.
     pub fn add_edge(&mut self, edge: Edge) {
-        use super::PropertyValue;
-
-        let exact_match_idx = self.find_edge_exact(
-            &edge.source,
-            &edge.target,
-            &edge.relationship,
-            &edge.source_dimension,
-            &edge.target_dimension,
-        );
+        let parallel = self.edge_policy(&edge.relationship) == EdgePolicy::Parallel;
+        let exact_match_idx = self.find_edge_identity(&edge);
 
         // Collect cross-dimensional matches (same logical edge, different dimensions)
-        let cross_dim_indices: Vec<usize> = if exact_match_idx.is_none() {
+        let cross_dim_indices: Vec<usize> = if exact_match_idx.is_none() && !parallel {
             self.edges.iter().enumerate()
                 .filter(|(_, e)| {
                     e.source == edge.source
@@ -220,8 +544,11 @@ impl Context {
         if let Some(idx) = exact_match_idx {
             // Exact duplicate - merge contributions per-adapter (ADR-003)
             let existing = &mut self.edges[idx];
-            for (adapter_id, value) in &edge.contributions {
-                existing.contributions.insert(adapter_id.clone(), *value);
+            let modes = &self.metadata.contribution_modes;
+            if let Some(policy) = self.metadata.contribution_history {
+                existing.record_contributions(&edge, policy, modes, Utc::now());
+            } else {
+                existing.merge_contributions(&edge, modes);
             }
             // combined_weight: for edges with contributions, the caller is responsible
             // for calling recompute_combined_weights() after all edges are committed.
@@ -229,12 +556,24 @@ impl Context {
             if edge.contributions.is_empty() {
                 existing.combined_weight = existing.combined_weight.max(edge.combined_weight);
             }
+            // The strongest reported confidence survives re-extraction
+            let confidence = match (existing.confidence(), edge.confidence()) {
+                (Some(old), Some(new)) => Some(old.max(new)),
+                _ => None,
+            };
             for (k, v) in edge.properties {
                 existing.properties.insert(k, v);
             }
+            if let Some(confidence) = confidence {
+                existing.properties.insert(CONFIDENCE_PROPERTY.to_string(), PropertyValue::Float(confidence));
+            }
         } else {
             let cross_dim_count = cross_dim_indices.len();
             let mut new_edge = edge;
+            if let Some(policy) = self.metadata.contribution_history {
+                let incoming = new_edge.clone();
+                new_edge.record_contributions(&incoming, policy, &self.metadata.contribution_modes, Utc::now());
+            }
 
             if cross_dim_count > 0 {
                 // Track cross-dimensional presence
@@ -327,7 +666,8 @@ impl Context {
             for (adapter_id, value) in &edge.contributions {
                 if let Some(&m) = max_abs.get(adapter_id) {
                     if m > 0.0 {
-                        sum += value / m;
+                        let confidence = self.metadata.contribution_confidence.get(adapter_id).copied().unwrap_or(1.0);
+                        sum += value / m * confidence;
                     }
                     // m == 0.0: all-zero contributor asserts zero strength
                 }
@@ -340,31 +680,34 @@ impl Context {
     ///
     /// Removes the adapter's contribution slot from every edge in the context.
     /// Recomputes combined weights from remaining contributions. Prunes edges
-    /// whose contributions map becomes empty (zero evidence).
+    /// whose contributions map becomes empty (zero evidence) into the trash.
     ///
     /// Returns (edges_affected, pruned_edge_ids).
     pub fn retract_contributions(&mut self, adapter_id: &str) -> (usize, Vec<super::EdgeId>) {
-        // Phase 1: Remove contribution slots, track which edges were affected
+        // Phase 1: Remove contribution slots, track which edges were affected.
+        // Edges left with no evidence are pruned whole, evidence intact, so
+        // restoring one from the trash undoes the retraction.
         let mut edges_affected = 0;
-        let mut was_affected = vec![false; self.edges.len()];
-        for (i, edge) in self.edges.iter_mut().enumerate() {
-            if edge.contributions.remove(adapter_id).is_some() {
-                edges_affected += 1;
-                was_affected[i] = true;
-            }
-        }
-
-        // Phase 2: Collect pruned edge IDs, then retain non-pruned edges
         let mut pruned_ids = Vec::new();
-        for (i, edge) in self.edges.iter().enumerate() {
-            if was_affected[i] && edge.contributions.is_empty() {
+        for edge in self.edges.iter_mut() {
+            if !edge.contributions.contains_key(adapter_id) {
+                continue;
+            }
+            edges_affected += 1;
+            if edge.contributions.len() == 1 {
                 pruned_ids.push(edge.id.clone());
+                continue;
             }
+            edge.contributions.remove(adapter_id);
+            edge.contribution_log.retain(|e| e.contributor != adapter_id);
         }
+
+        // Phase 2: Move pruned edges to the trash
         if !pruned_ids.is_empty() {
             let pruned_set: std::collections::HashSet<&super::EdgeId> =
                 pruned_ids.iter().collect();
-            self.edges.retain(|e| !pruned_set.contains(&e.id));
+            self.record_edge_fates(&pruned_ids, EdgeFate::Retracted);
+            self.trash_edges_where(|e| pruned_set.contains(&e.id));
         }
 
         // Phase 3: Recompute combined weights from remaining contributions
@@ -783,4 +1126,103 @@ mod tests {
             "source node should not exist"
         );
     }
+
+    // === Scenario: Edge identity policy ===
+
+    #[test]
+    fn parallel_policy_keeps_edges_with_distinct_ids() {
+        let mut ctx = Context::new("test");
+        ctx.set_edge_policy("cites", EdgePolicy::Parallel);
+        let a = NodeId::from_string("a");
+        let b = NodeId::from_string("b");
+
+        ctx.add_edge(Edge::new(a.clone(), b.clone(), "cites"));
+        ctx.add_edge(Edge::new(a.clone(), b.clone(), "cites"));
+        // Other relationships still merge
+        ctx.add_edge(Edge::new(a.clone(), b.clone(), "related_to"));
+        ctx.add_edge(Edge::new(a.clone(), b.clone(), "related_to"));
+
+        assert_eq!(ctx.edges.iter().filter(|e| e.relationship == "cites").count(), 2);
+        assert_eq!(ctx.edges.iter().filter(|e| e.relationship == "related_to").count(), 1);
+    }
+
+    #[test]
+    fn parallel_policy_merges_same_edge_id() {
+        let mut ctx = Context::new("test");
+        ctx.set_edge_policy("cites", EdgePolicy::Parallel);
+        let mut edge = Edge::new(NodeId::from_string("a"), NodeId::from_string("b"), "cites");
+        edge.contributions.insert("adapter-1".to_string(), 1.0);
+        let mut again = edge.clone();
+        again.contributions.insert("adapter-2".to_string(), 2.0);
+
+        ctx.add_edge(edge);
+        ctx.add_edge(again);
+
+        assert_eq!(ctx.edge_count(), 1);
+        assert_eq!(ctx.edges[0].contributions.len(), 2);
+    }
+
+    #[test]
+    fn switching_to_merge_consolidates_parallel_edges() {
+        let mut ctx = Context::new("test");
+        ctx.set_edge_policy("cites", EdgePolicy::Parallel);
+        let a = NodeId::from_string("a");
+        let b = NodeId::from_string("b");
+        let mut first = Edge::new(a.clone(), b.clone(), "cites");
+        first.contributions.insert("adapter-1".to_string(), 1.0);
+        let mut second = Edge::new(a.clone(), b.clone(), "cites");
+        second.contributions.insert("adapter-2".to_string(), 3.0);
+        second.properties.insert("page".to_string(), PropertyValue::Int(12));
+        let first_id = first.id.clone();
+        ctx.add_edge(first);
+        ctx.add_edge(second);
+
+        let folded = ctx.set_edge_policy("cites", EdgePolicy::Merge);
+
+        assert_eq!(folded, 1);
+        assert_eq!(ctx.edge_count(), 1);
+        let edge = &ctx.edges[0];
+        assert_eq!(edge.id, first_id);
+        assert_eq!(edge.contributions.len(), 2);
+        assert_eq!(edge.get_int("page"), Some(12));
+        assert!(ctx.metadata.edge_policies.is_empty());
+    }
+
+    #[test]
+    fn consolidation_resolves_a_shared_slot_by_contribution_mode() {
+        let consolidated = |mode: Option<ContributionMode>| {
+            let mut ctx = Context::new("test");
+            ctx.set_edge_policy("cites", EdgePolicy::Parallel);
+            if let Some(mode) = mode {
+                ctx.metadata.contribution_modes.insert("adapter-1".to_string(), mode);
+            }
+            for value in [3.0, 1.0] {
+                let mut edge = Edge::new(NodeId::from_string("a"), NodeId::from_string("b"), "cites");
+                edge.contributions.insert("adapter-1".to_string(), value);
+                ctx.add_edge(edge);
+            }
+            ctx.set_edge_policy("cites", EdgePolicy::Merge);
+            assert_eq!(ctx.edge_count(), 1);
+            ctx.edges[0].contributions["adapter-1"]
+        };
+
+        assert_eq!(consolidated(None), 1.0, "the later duplicate replaces, as an update would");
+        assert_eq!(consolidated(Some(ContributionMode::Max)), 3.0);
+        assert_eq!(consolidated(Some(ContributionMode::Sum)), 4.0);
+    }
+
+    #[test]
+    fn edge_policies_round_trip_through_metadata_json() {
+        let mut ctx = Context::new("test");
+        ctx.set_edge_policy("cites", EdgePolicy::Parallel);
+        let json = serde_json::to_string(&ctx.metadata).unwrap();
+        let back: ContextMetadata = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.edge_policies.get("cites"), Some(&EdgePolicy::Parallel));
+
+        // Metadata written before edge policies existed still loads
+        let legacy = serde_json::to_value(&Context::new("legacy").metadata).unwrap();
+        assert!(legacy.get("edge_policies").is_none());
+        let legacy: ContextMetadata = serde_json::from_value(legacy).unwrap();
+        assert!(legacy.edge_policies.is_empty());
+    }
 }
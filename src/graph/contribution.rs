@@ -0,0 +1,273 @@
+//! Contribution history: timestamped contribution entries per edge
+//!
+//! An edge's `contributions` map holds one value per contributor (ADR-003),
+//! so repeated reinforcement is invisible. A context that sets a
+//! `ContributionAggregation` also keeps every contribution in the edge's
+//! `contribution_log`, and each contributor's slot becomes the
+//! aggregate of its entries rather than the latest value.
+//!
+//! Independently of history, a context can set a `ContributionMode` per
+//! adapter: how that adapter's re-emission of an edge it already
+//! contributed to updates its slot (replace, sum, max, or a moving
+//! average). A configured mode takes precedence over the history
+//! aggregation for that adapter's slot; its entries are still logged.
+
+use super::edge::{AdapterId, Edge};
+use std::collections::BTreeMap;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// One recorded contribution. Serializes compactly as
+/// `[contributor, value, unix_millis]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContributionEntry {
+    pub contributor: AdapterId,
+    pub value: f32,
+    pub at: DateTime<Utc>,
+}
+
+impl Serialize for ContributionEntry {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (&self.contributor, self.value, self.at.timestamp_millis()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ContributionEntry {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (contributor, value, millis) = <(AdapterId, f32, i64)>::deserialize(deserializer)?;
+        let at = Utc
+            .timestamp_millis_opt(millis)
+            .single()
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid timestamp: {millis}")))?;
+        Ok(Self { contributor, value, at })
+    }
+}
+
+/// How a contributor's logged entries fold into its contribution slot.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum ContributionAggregation {
+    /// The most recent entry, matching the map-only behavior
+    Latest,
+    /// Every entry added up: each reinforcement strengthens the edge
+    Sum,
+    /// Entries added up, each halved for every `half_life_days` of age
+    Decay { half_life_days: f64 },
+}
+
+impl ContributionAggregation {
+    /// Fold `entries` into one value as of `now`, or `None` when empty.
+    pub fn aggregate<'a>(
+        &self,
+        entries: impl IntoIterator<Item = &'a ContributionEntry>,
+        now: DateTime<Utc>,
+    ) -> Option<f32> {
+        let mut entries = entries.into_iter().peekable();
+        entries.peek()?;
+        Some(match self {
+            Self::Latest => entries.max_by_key(|e| e.at).map(|e| e.value).unwrap_or_default(),
+            Self::Sum => entries.map(|e| e.value).sum(),
+            Self::Decay { half_life_days } => entries
+                .map(|e| {
+                    let age_days = (now - e.at).num_milliseconds().max(0) as f64
+                        / Duration::days(1).num_milliseconds() as f64;
+                    e.value * 0.5f64.powf(age_days / half_life_days.max(f64::MIN_POSITIVE)) as f32
+                })
+                .sum(),
+        })
+    }
+}
+
+/// How an adapter's repeated `raw_weight` for the same edge updates its
+/// contribution slot. Applied wherever edges merge (`Context::add_edge`,
+/// and so the sink's `emit_inner`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum ContributionMode {
+    /// The latest emission wins (ADR-003's default)
+    #[default]
+    Replace,
+    /// Every emission adds to the slot
+    Sum,
+    /// The strongest emission so far
+    Max,
+    /// Exponential moving average: `alpha * new + (1 - alpha) * old`
+    MovingAverage { alpha: f32 },
+}
+
+impl ContributionMode {
+    /// The slot value after `incoming` arrives on a slot holding
+    /// `previous` (`None` for a first contribution).
+    pub fn accumulate(&self, previous: Option<f32>, incoming: f32) -> f32 {
+        let Some(previous) = previous else {
+            return incoming;
+        };
+        match self {
+            Self::Replace => incoming,
+            Self::Sum => previous + incoming,
+            Self::Max => previous.max(incoming),
+            Self::MovingAverage { alpha } => {
+                let alpha = alpha.clamp(0.0, 1.0);
+                alpha * incoming + (1.0 - alpha) * previous
+            }
+        }
+    }
+}
+
+impl Edge {
+    /// Merge `incoming`'s contributions into this edge's slots, each by
+    /// its contributor's mode in `modes` (default `Replace`).
+    pub(crate) fn merge_contributions(&mut self, incoming: &Edge, modes: &BTreeMap<AdapterId, ContributionMode>) {
+        for (contributor, value) in &incoming.contributions {
+            let mode = modes.get(contributor).copied().unwrap_or_default();
+            let previous = self.contributions.get(contributor).copied();
+            self.contributions.insert(contributor.clone(), mode.accumulate(previous, *value));
+        }
+    }
+
+    /// Logged entries from `contributor`, oldest first.
+    pub fn contribution_history<'a>(
+        &'a self,
+        contributor: &'a str,
+    ) -> impl Iterator<Item = &'a ContributionEntry> + 'a {
+        self.contribution_log.iter().filter(move |e| e.contributor == contributor)
+    }
+
+    /// `contributor`'s logged entries aggregated as of `now`.
+    pub fn aggregate_contribution(
+        &self,
+        contributor: &str,
+        policy: ContributionAggregation,
+        now: DateTime<Utc>,
+    ) -> Option<f32> {
+        policy.aggregate(self.contribution_history(contributor), now)
+    }
+
+    /// Log `incoming`'s contributions at `at` and set each slot to its
+    /// aggregate — or, for contributors with a mode in `modes`, to the
+    /// mode's accumulation. Entries keep millisecond precision, as persisted.
+    pub(crate) fn record_contributions(
+        &mut self,
+        incoming: &Edge,
+        policy: ContributionAggregation,
+        modes: &BTreeMap<AdapterId, ContributionMode>,
+        at: DateTime<Utc>,
+    ) {
+        let at = Utc.timestamp_millis_opt(at.timestamp_millis()).single().unwrap_or(at);
+        for (contributor, value) in &incoming.contributions {
+            if let Some(mode) = modes.get(contributor) {
+                // A slot without entries is a first contribution, even on
+                // a new edge that already carries the incoming value
+                let previous = match self.contribution_history(contributor).next() {
+                    Some(_) => self.contributions.get(contributor).copied(),
+                    None => None,
+                };
+                self.contributions.insert(contributor.clone(), mode.accumulate(previous, *value));
+            }
+            self.contribution_log.push(ContributionEntry { contributor: contributor.clone(), value: *value, at });
+        }
+        for contributor in incoming.contributions.keys().filter(|c| !modes.contains_key(*c)) {
+            if let Some(value) = self.aggregate_contribution(contributor, policy, at) {
+                self.contributions.insert(contributor.clone(), value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{Context, ContentType, Node};
+
+    fn entry(value: f32, days_ago: i64, now: DateTime<Utc>) -> ContributionEntry {
+        ContributionEntry { contributor: "content".into(), value, at: now - Duration::days(days_ago) }
+    }
+
+    // === Scenario: Each aggregation policy folds the same history ===
+    #[test]
+    fn aggregation_policies_fold_entries() {
+        let now = Utc::now();
+        let log = [entry(1.0, 20, now), entry(2.0, 10, now), entry(0.5, 0, now)];
+
+        assert_eq!(ContributionAggregation::Latest.aggregate(&log, now), Some(0.5));
+        assert_eq!(ContributionAggregation::Sum.aggregate(&log, now), Some(3.5));
+        let decayed = ContributionAggregation::Decay { half_life_days: 10.0 }.aggregate(&log, now).unwrap();
+        assert!((decayed - (0.25 + 1.0 + 0.5)).abs() < 1e-4, "{decayed}");
+        assert_eq!(ContributionAggregation::Sum.aggregate(&[], now), None);
+    }
+
+    // === Scenario: Each contribution mode accumulates re-emissions ===
+    #[test]
+    fn contribution_modes_accumulate() {
+        let fold = |mode: ContributionMode| [0.4, 1.0, 0.6].into_iter().fold(None, |slot, v| Some(mode.accumulate(slot, v)));
+        assert_eq!(fold(ContributionMode::Replace), Some(0.6));
+        assert_eq!(fold(ContributionMode::Sum), Some(2.0));
+        assert_eq!(fold(ContributionMode::Max), Some(1.0));
+        let average = fold(ContributionMode::MovingAverage { alpha: 0.5 }).unwrap();
+        assert!((average - 0.65).abs() < 1e-6, "{average}");
+        assert_eq!(ContributionMode::Sum.accumulate(None, 0.3), 0.3, "a first contribution is taken as is");
+    }
+
+    // === Scenario: A per-adapter mode overrides the history aggregation ===
+    #[test]
+    fn contribution_mode_takes_precedence_over_history() {
+        let mut ctx = Context::new("modes");
+        let a = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let b = ctx.add_node(Node::new("concept", ContentType::Concept));
+        ctx.metadata.contribution_history = Some(ContributionAggregation::Sum);
+        ctx.set_contribution_mode("scan", ContributionMode::Max);
+        for value in [0.2, 0.9, 0.5] {
+            let edge = Edge::new(a.clone(), b.clone(), "related_to")
+                .with_contribution("scan", value)
+                .with_contribution("notes", value);
+            ctx.add_edge(edge);
+        }
+        let edge = &ctx.edges[0];
+        assert_eq!(edge.contributions["scan"], 0.9);
+        assert!((edge.contributions["notes"] - 1.6).abs() < 1e-6, "unlisted adapters use the history aggregation");
+        assert_eq!(edge.contribution_history("scan").count(), 3, "entries are still logged");
+    }
+
+    // === Scenario: Entries serialize as compact tuples ===
+    #[test]
+    fn entries_round_trip_as_tuples() {
+        let at = Utc.timestamp_millis_opt(1_700_000_000_123).unwrap();
+        let entry = ContributionEntry { contributor: "content".into(), value: 0.5, at };
+        let json = serde_json::to_string(&entry).unwrap();
+        assert_eq!(json, r#"["content",0.5,1700000000123]"#);
+        assert_eq!(serde_json::from_str::<ContributionEntry>(&json).unwrap(), entry);
+    }
+
+    // === Scenario: Repeated emissions accumulate when history is on ===
+    #[test]
+    fn context_with_history_logs_and_aggregates_reinforcement() {
+        let mut ctx = Context::new("history");
+        let a = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let b = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let emit = |ctx: &mut Context| {
+            ctx.add_edge(Edge::new(a.clone(), b.clone(), "related_to").with_contribution("content", 1.0));
+        };
+
+        emit(&mut ctx);
+        emit(&mut ctx);
+        assert!(ctx.edges[0].contribution_log.is_empty(), "history is opt-in");
+        assert_eq!(ctx.edges[0].contributions["content"], 1.0);
+
+        ctx.metadata.contribution_history = Some(ContributionAggregation::Sum);
+        emit(&mut ctx);
+        emit(&mut ctx);
+        emit(&mut ctx);
+
+        let edge = &ctx.edges[0];
+        assert_eq!(edge.contribution_history("content").count(), 3);
+        assert_eq!(edge.contributions["content"], 3.0);
+        assert_eq!(
+            edge.aggregate_contribution("content", ContributionAggregation::Latest, Utc::now()),
+            Some(1.0)
+        );
+
+        ctx.edges[0].contributions.insert("extraction".into(), 1.0);
+        ctx.retract_contributions("content");
+        assert!(ctx.edges[0].contribution_log.is_empty(), "retraction drops the adapter's history");
+    }
+}
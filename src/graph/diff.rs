@@ -0,0 +1,117 @@
+//! Structural difference between two states of a context
+
+use super::context::Context;
+use super::edge::{Edge, EdgeId};
+use super::node::{Node, NodeId};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// What changed between a `before` and an `after` context.
+///
+/// Nodes match by ID; edges match by edge ID, so a re-weighted merge
+/// edge shows as updated rather than removed and re-added.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GraphDiff {
+    pub nodes_added: Vec<Node>,
+    pub nodes_removed: Vec<NodeId>,
+    /// Nodes whose type, dimension, or properties changed (after state)
+    pub nodes_updated: Vec<Node>,
+    pub edges_added: Vec<Edge>,
+    pub edges_removed: Vec<EdgeId>,
+    /// Edges whose weight, contributions, or properties changed (after state)
+    pub edges_updated: Vec<Edge>,
+}
+
+fn node_changed(before: &Node, after: &Node) -> bool {
+    before.node_type != after.node_type
+        || before.dimension != after.dimension
+        || before.content_type != after.content_type
+        || before.properties != after.properties
+}
+
+fn edge_changed(before: &Edge, after: &Edge) -> bool {
+    before.combined_weight != after.combined_weight
+        || before.contributions != after.contributions
+        || before.properties != after.properties
+}
+
+impl GraphDiff {
+    pub fn between(before: &Context, after: &Context) -> Self {
+        let mut diff = Self::default();
+
+        for node in after.nodes() {
+            match before.get_node(&node.id) {
+                None => diff.nodes_added.push(node.clone()),
+                Some(prior) if node_changed(prior, node) => diff.nodes_updated.push(node.clone()),
+                Some(_) => {}
+            }
+        }
+        diff.nodes_removed = before
+            .nodes()
+            .filter(|n| after.get_node(&n.id).is_none())
+            .map(|n| n.id.clone())
+            .collect();
+
+        let before_edges: HashMap<&EdgeId, &Edge> = before.edges().map(|e| (&e.id, e)).collect();
+        let after_ids: HashSet<&EdgeId> = after.edges().map(|e| &e.id).collect();
+        for edge in after.edges() {
+            match before_edges.get(&edge.id) {
+                None => diff.edges_added.push(edge.clone()),
+                Some(prior) if edge_changed(prior, edge) => diff.edges_updated.push(edge.clone()),
+                Some(_) => {}
+            }
+        }
+        diff.edges_removed = before
+            .edges()
+            .filter(|e| !after_ids.contains(&e.id))
+            .map(|e| e.id.clone())
+            .collect();
+
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes_added.is_empty()
+            && self.nodes_removed.is_empty()
+            && self.nodes_updated.is_empty()
+            && self.edges_added.is_empty()
+            && self.edges_removed.is_empty()
+            && self.edges_updated.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{ContentType, PropertyValue};
+
+    // === Scenario: Diff separates added, removed, and updated items ===
+    #[test]
+    fn diff_classifies_node_and_edge_changes() {
+        let mut before = Context::new("diff");
+        let kept = before.add_node(Node::new("fragment", ContentType::Document));
+        let dropped = before.add_node(Node::new("fragment", ContentType::Document));
+        let edge = Edge::new(kept.clone(), dropped.clone(), "links_to");
+        let edge_id = edge.id.clone();
+        before.add_edge(edge);
+
+        let mut after = before.clone();
+        after.nodes.remove(&dropped);
+        after.edges.retain(|e| e.id != edge_id);
+        after
+            .get_node_mut(&kept)
+            .unwrap()
+            .properties
+            .insert("text".into(), PropertyValue::from("edited"));
+        let added = after.add_node(Node::new("concept", ContentType::Concept));
+
+        let diff = GraphDiff::between(&before, &after);
+
+        assert_eq!(diff.nodes_added.len(), 1);
+        assert_eq!(diff.nodes_added[0].id, added);
+        assert_eq!(diff.nodes_removed, vec![dropped]);
+        assert_eq!(diff.nodes_updated[0].id, kept);
+        assert_eq!(diff.edges_removed, vec![edge_id]);
+        assert!(GraphDiff::between(&after, &after).is_empty());
+    }
+}
@@ -1,6 +1,7 @@
 //! Edge representation for the knowledge graph
 
-use super::node::{dimension, NodeId, Properties};
+use super::contribution::ContributionEntry;
+use super::node::{dimension, NodeId, Properties, PropertyValue};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -9,6 +10,10 @@ use uuid::Uuid;
 /// Adapter ID type for contribution tracking (ADR-003)
 pub type AdapterId = String;
 
+/// Edge property holding the extractor's confidence (0.0–1.0) in the
+/// relationship. Edges from deterministic sources carry none.
+pub const CONFIDENCE_PROPERTY: &str = "confidence";
+
 /// Unique identifier for an edge
 ///
 /// Serializes as a plain string (UUID or semantic ID)
@@ -57,6 +62,20 @@ impl From<String> for EdgeId {
     }
 }
 
+/// How edges sharing `(source, target, relationship)` relate, configured
+/// per relationship on a context.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EdgePolicy {
+    /// One edge per (source, target, relationship, dimensions); repeat
+    /// emissions merge contributions into it (ADR-003).
+    #[default]
+    Merge,
+    /// Edges are identified by `EdgeId`; distinct IDs between the same
+    /// endpoints coexist as parallel edges (e.g., one per citation).
+    Parallel,
+}
+
 /// A directed edge in the knowledge graph
 ///
 /// Carries per-adapter contributions and a relationship type.
@@ -92,6 +111,10 @@ pub struct Edge {
     pub created_at: DateTime<Utc>,
     /// Additional properties
     pub properties: Properties,
+    /// Append-only, timestamped contribution history. Recorded only when
+    /// the context sets a `ContributionAggregation`; empty otherwise.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contribution_log: Vec<ContributionEntry>,
 }
 
 /// Default dimension for backwards compatibility with existing edges
@@ -113,6 +136,7 @@ impl Edge {
             combined_weight: 1.0,
             created_at: Utc::now(),
             properties: HashMap::new(),
+            contribution_log: Vec::new(),
         }
     }
 
@@ -135,6 +159,7 @@ impl Edge {
             combined_weight: 1.0,
             created_at: Utc::now(),
             properties: HashMap::new(),
+            contribution_log: Vec::new(),
         }
     }
 
@@ -157,6 +182,7 @@ impl Edge {
             combined_weight: 1.0,
             created_at: Utc::now(),
             properties: HashMap::new(),
+            contribution_log: Vec::new(),
         }
     }
 
@@ -192,4 +218,127 @@ impl Edge {
         self.target_dimension = d;
         self
     }
+
+    /// Add a property to the edge (builder pattern)
+    pub fn with_property(mut self, key: impl Into<String>, value: impl Into<PropertyValue>) -> Self {
+        self.properties.insert(key.into(), value.into());
+        self
+    }
+
+    /// The extractor's confidence in this edge, if it reported one.
+    pub fn confidence(&self) -> Option<f64> {
+        match self.properties.get(CONFIDENCE_PROPERTY)? {
+            PropertyValue::Float(c) => Some(*c),
+            PropertyValue::Int(c) => Some(*c as f64),
+            _ => None,
+        }
+    }
+
+    /// Start a fluent builder for an edge in the default dimension.
+    pub fn builder(
+        source: impl Into<NodeId>,
+        target: impl Into<NodeId>,
+        relationship: impl Into<String>,
+    ) -> EdgeBuilder {
+        EdgeBuilder {
+            edge: Edge::new(source.into(), target.into(), relationship),
+        }
+    }
+
+    /// String property value
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        self.properties.get(key).and_then(PropertyValue::as_str)
+    }
+
+    /// Integer property value
+    pub fn get_int(&self, key: &str) -> Option<i64> {
+        self.properties.get(key).and_then(PropertyValue::as_int)
+    }
+
+    /// Numeric property value (`Int` or `Float`)
+    pub fn get_float(&self, key: &str) -> Option<f64> {
+        self.properties.get(key).and_then(PropertyValue::as_float)
+    }
+
+    /// Boolean property value
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        self.properties.get(key).and_then(PropertyValue::as_bool)
+    }
+
+    /// Array property value
+    pub fn get_array(&self, key: &str) -> Option<&[PropertyValue]> {
+        self.properties.get(key).and_then(PropertyValue::as_array)
+    }
+
+    /// Timestamp property value (`DateTime` or RFC-3339 string)
+    pub fn get_datetime(&self, key: &str) -> Option<DateTime<Utc>> {
+        self.properties.get(key).and_then(PropertyValue::as_datetime)
+    }
+
+    /// Nested object property value
+    pub fn get_object(&self, key: &str) -> Option<&HashMap<String, PropertyValue>> {
+        self.properties.get(key).and_then(PropertyValue::as_object)
+    }
+
+    /// A single adapter's contribution, if present (ADR-003)
+    pub fn contribution(&self, adapter_id: &str) -> Option<f32> {
+        self.contributions.get(adapter_id).copied()
+    }
+}
+
+/// Fluent builder for `Edge`, started with `Edge::builder`.
+#[derive(Debug, Clone)]
+pub struct EdgeBuilder {
+    edge: Edge,
+}
+
+impl EdgeBuilder {
+    /// Set the edge ID
+    pub fn id(mut self, id: impl Into<EdgeId>) -> Self {
+        self.edge.id = id.into();
+        self
+    }
+
+    /// Set both endpoint dimensions
+    pub fn dimension(mut self, dim: impl Into<String>) -> Self {
+        let d = dim.into();
+        self.edge.source_dimension = d.clone();
+        self.edge.target_dimension = d;
+        self
+    }
+
+    /// Set the source endpoint's dimension
+    pub fn source_dimension(mut self, dim: impl Into<String>) -> Self {
+        self.edge.source_dimension = dim.into();
+        self
+    }
+
+    /// Set the target endpoint's dimension
+    pub fn target_dimension(mut self, dim: impl Into<String>) -> Self {
+        self.edge.target_dimension = dim.into();
+        self
+    }
+
+    /// Record an adapter contribution (ADR-003)
+    pub fn contribution(mut self, adapter_id: impl Into<String>, value: f32) -> Self {
+        self.edge.contributions.insert(adapter_id.into(), value);
+        self
+    }
+
+    /// Set the combined weight directly
+    pub fn weight(mut self, weight: f32) -> Self {
+        self.edge.combined_weight = weight;
+        self
+    }
+
+    /// Set a property
+    pub fn prop(mut self, key: impl Into<String>, value: impl Into<PropertyValue>) -> Self {
+        self.edge.properties.insert(key.into(), value.into());
+        self
+    }
+
+    /// Finish building
+    pub fn build(self) -> Edge {
+        self.edge
+    }
 }
@@ -0,0 +1,174 @@
+//! Indexes the sink consults on every emission
+//!
+//! Resolving emitted nodes against natural keys, and redirecting edges
+//! to head versions, take a lookup per item; building those maps from the
+//! context's nodes and edges on every emission costs a pass over the
+//! whole graph. The engine instead keeps them per context, beside it like
+//! the reachability cache. The sink updates them as each phase commits,
+//! so they stay current from one emission to the next; any other write to
+//! the context drops them, and the next emission rebuilds them.
+
+use super::context::Context;
+use super::edge::{Edge, EdgeId};
+use super::node::{Node, NodeId};
+use super::versioning::SUPERSEDES;
+use std::collections::HashMap;
+
+/// Natural-key slot: `(dimension, node_type, key)`.
+pub(crate) type NaturalKeySlot = (String, String, String);
+
+/// The natural-key slot `node` declares, if any.
+pub(crate) fn natural_key_slot(node: &Node) -> Option<NaturalKeySlot> {
+    let key = node.natural_key()?;
+    Some((node.dimension.clone(), node.node_type.clone(), key.to_string()))
+}
+
+/// Per-context lookups for the emission phases.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct EmitIndexes {
+    /// Slot → the nodes declaring it
+    natural_keys: HashMap<NaturalKeySlot, Vec<NodeId>>,
+    /// Node → the slot it declares
+    keyed: HashMap<NodeId, NaturalKeySlot>,
+    /// Superseded node → the node superseding it
+    successors: HashMap<NodeId, NodeId>,
+    /// Version edge → the node it supersedes
+    version_edges: HashMap<EdgeId, NodeId>,
+}
+
+impl EmitIndexes {
+    pub(crate) fn build(context: &Context) -> Self {
+        let mut indexes = Self::default();
+        for node in context.nodes.values() {
+            if let Some(slot) = natural_key_slot(node) {
+                indexes.natural_keys.entry(slot.clone()).or_default().push(node.id.clone());
+                indexes.keyed.insert(node.id.clone(), slot);
+            }
+        }
+        for edge in &context.edges {
+            indexes.sync_version_edge(edge);
+        }
+        indexes
+    }
+
+    /// The node owning `slot`. A slot several nodes declare (legacy
+    /// data, a restored node) is owned by the one with the smallest ID.
+    pub(crate) fn natural_key_owner(&self, slot: &NaturalKeySlot) -> Option<&NodeId> {
+        self.natural_keys.get(slot)?.iter().min_by(|a, b| a.as_str().cmp(b.as_str()))
+    }
+
+    /// Bring `id`'s entry in line with `context`, after the node was
+    /// added, changed or removed.
+    pub(crate) fn sync_node(&mut self, context: &Context, id: &NodeId) {
+        if let Some(slot) = self.keyed.remove(id) {
+            if let Some(holders) = self.natural_keys.get_mut(&slot) {
+                holders.retain(|holder| holder != id);
+                if holders.is_empty() {
+                    self.natural_keys.remove(&slot);
+                }
+            }
+        }
+        let Some(slot) = context.get_node(id).and_then(natural_key_slot) else { return };
+        self.natural_keys.entry(slot.clone()).or_default().push(id.clone());
+        self.keyed.insert(id.clone(), slot);
+    }
+
+    /// Map from each superseded node to the node that supersedes it, as
+    /// `Context::version_successors`.
+    pub(crate) fn successors(&self) -> &HashMap<NodeId, NodeId> {
+        &self.successors
+    }
+
+    /// Record `edge`, as committed, if it's a version edge.
+    pub(crate) fn sync_version_edge(&mut self, edge: &Edge) {
+        if edge.relationship == SUPERSEDES {
+            self.successors.insert(edge.target.clone(), edge.source.clone());
+            self.version_edges.insert(edge.id.clone(), edge.target.clone());
+        }
+    }
+
+    /// Forget the version edges among `ids`, after they were removed.
+    pub(crate) fn remove_edges(&mut self, ids: &[EdgeId]) {
+        for id in ids {
+            if let Some(superseded) = self.version_edges.remove(id) {
+                self.successors.remove(&superseded);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{dimension, ContentType};
+
+    fn keyed(id: &str, key: &str) -> Node {
+        let mut node = Node::new_in_dimension("concept", ContentType::Concept, dimension::SEMANTIC).with_natural_key(key);
+        node.id = NodeId::from_string(id);
+        node
+    }
+
+    fn slot(key: &str) -> NaturalKeySlot {
+        (dimension::SEMANTIC.to_string(), "concept".to_string(), key.to_string())
+    }
+
+    // === Scenario: Synced nodes keep the natural-key index current ===
+    #[test]
+    fn synced_nodes_keep_natural_keys_current() {
+        let mut ctx = Context::new("test");
+        ctx.add_node(keyed("concept:travel", "travel"));
+        let mut indexes = EmitIndexes::build(&ctx);
+        assert_eq!(indexes.natural_key_owner(&slot("travel")), Some(&NodeId::from_string("concept:travel")));
+
+        // Re-keyed: the old slot frees up
+        let id = ctx.add_node(keyed("concept:travel", "journey"));
+        indexes.sync_node(&ctx, &id);
+        assert_eq!(indexes.natural_key_owner(&slot("travel")), None);
+        assert_eq!(indexes.natural_key_owner(&slot("journey")), Some(&id));
+
+        ctx.nodes.remove(&id);
+        indexes.sync_node(&ctx, &id);
+        assert_eq!(indexes.natural_key_owner(&slot("journey")), None);
+    }
+
+    // === Scenario: A shared slot passes to the next node when its owner goes ===
+    #[test]
+    fn shared_slot_passes_to_remaining_node() {
+        let mut ctx = Context::new("test");
+        let owner = ctx.add_node(keyed("concept:a", "travel"));
+        let other = ctx.add_node(keyed("concept:b", "travel"));
+        let built = EmitIndexes::build(&ctx);
+        assert_eq!(built.natural_key_owner(&slot("travel")), Some(&owner));
+
+        // Reached incrementally, the same node owns it
+        let mut indexes = EmitIndexes::default();
+        for id in [&other, &owner] {
+            indexes.sync_node(&ctx, id);
+        }
+        assert_eq!(indexes.natural_key_owner(&slot("travel")), Some(&owner));
+
+        ctx.nodes.remove(&owner);
+        indexes.sync_node(&ctx, &owner);
+        assert_eq!(indexes.natural_key_owner(&slot("travel")), Some(&other));
+    }
+
+    // === Scenario: Version edges keep the successor index current ===
+    #[test]
+    fn version_edges_keep_successors_current() {
+        let mut ctx = Context::new("test");
+        let old = ctx.add_node(keyed("doc:v1", "v1"));
+        let new = ctx.add_node(keyed("doc:v2", "v2"));
+        let version = Edge::new(new.clone(), old.clone(), SUPERSEDES);
+        let version_id = version.id.clone();
+        ctx.add_edge(version);
+        ctx.add_edge(Edge::new(new.clone(), old.clone(), "cites"));
+        let mut indexes = EmitIndexes::build(&ctx);
+        assert_eq!(indexes.successors(), &ctx.version_successors());
+        assert_eq!(indexes.successors().get(&old), Some(&new));
+
+        indexes.remove_edges(&[version_id]);
+        assert!(indexes.successors().is_empty());
+        indexes.sync_version_edge(&Edge::new(old.clone(), new.clone(), "cites"));
+        assert!(indexes.successors().is_empty(), "other relationships aren't version edges");
+    }
+}
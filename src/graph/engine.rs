@@ -1,15 +1,37 @@
 //! PlexusEngine: The main entry point for the knowledge graph
 
-use super::context::{Context, ContextId, ContextMetadata, Source};
-use super::edge::Edge;
+use super::context::{Context, ContextId, ContextMetadata, Durability, EmbeddingConfig, Source};
+use super::contribution::{ContributionAggregation, ContributionMode};
+use super::edge::{Edge, EdgeId, EdgePolicy};
+use super::emit_index::EmitIndexes;
+use super::prune::{PrunePolicy, PruneReport};
+use super::publish::{PublishFilter, PublishManifest};
+use super::sync::{merge_contexts, ContextSync, SyncReport};
+use super::reader::ContextReader;
+use super::history::HistoricalView;
+use super::hooks::{FnHook, MutationHook};
+use super::ontology::RelationshipOntology;
+use super::overlay::Overlay;
+use super::tag_policy::TagPolicy;
+use super::enrichment_stats::{EdgeFate, EnrichmentStats};
+use super::quota::ContextQuota;
+use super::write_scope::WriteScope;
+use super::template::{ContextTemplate, BUILTIN_TEMPLATES};
+use super::tenant::{scoped_name, TenantQuota, TenantUsage};
+use super::trash::RestoreReport;
+use super::relocate::RelocationReport;
 use super::node::NodeId;
-use super::events::GraphEvent;
-use crate::query::{FindQuery, PathQuery, QueryResult, PathResult, TraversalResult, TraverseQuery};
-use crate::storage::{GraphStore, StorageError};
-use chrono::Utc;
+use super::events::{changed_keys, property_changes, GraphEvent};
+use crate::query::{
+    Backlinks, ConceptDossier, FindQuery, GraphDistributions, MaterializedView, NodeDegree, PathQuery, QueryResult, PathResult, PathScoring, SavedQuery, SavedQueryResult, TagStats,
+    ReachabilityCache, ReachabilityFilter, ScoredPath, TransitiveClosure, TraversalResult, TraverseQuery, ViewSnapshot,
+};
+use crate::llm_orc::{LlmCostReport, LlmUsage};
+use crate::storage::{CompactionReport, EmissionFilter, EventSourcedStore, GraphStore, JournalEntry, ManifestEntry, PersistedEmission, PersistedIngestResult, PersistedLlmCost, PersistedOutboundEvent, PersistedTemplate, StorageError};
+use chrono::{DateTime, Utc};
 use dashmap::DashMap;
-use std::collections::HashSet;
-use std::sync::Arc;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Arc, OnceLock};
 use std::sync::atomic::AtomicU64;
 use thiserror::Error;
 
@@ -25,12 +47,36 @@ pub enum PlexusError {
     #[error("Edge not found: {0}")]
     EdgeNotFound(String),
 
+    /// A named item other than a context, node or edge doesn't exist
+    #[error("{0}")]
+    NotFound(String),
+
+    #[error("{0}")]
+    AlreadyExists(String),
+
+    #[error("{0}")]
+    InvalidInput(String),
+
+    /// The engine or its store can't do this (e.g. no persistent store)
+    #[error("{0}")]
+    Unsupported(String),
+
+    /// A peer or external service couldn't be reached; may succeed later
+    #[error("{0}")]
+    Unavailable(String),
+
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
 
     #[error("Storage error: {0}")]
     Storage(#[from] StorageError),
 
+    #[error("Tenant '{tenant}' quota exceeded: {detail}")]
+    QuotaExceeded { tenant: String, detail: String },
+
+    #[error("Context '{context}' quota exceeded: {detail}")]
+    ContextQuotaExceeded { context: String, detail: String },
+
     #[error("{0}")]
     Other(String),
 }
@@ -49,8 +95,99 @@ pub struct PlexusEngine {
     name_index: DashMap<String, ContextId>,
     /// Optional persistent storage backend
     store: Option<Arc<dyn GraphStore>>,
+    /// Set by `event_sourced`: the journal `store` writes through
+    journal: Option<Arc<EventSourcedStore>>,
     /// Last observed data_version for cache coherence (ADR-017 §2)
     last_data_version: AtomicU64,
+    /// Per-tenant quotas, configured by the hosting layer (not persisted)
+    tenant_quotas: DashMap<String, TenantQuota>,
+    /// Set by `load_tenant`: the only tenant this engine caches
+    tenant_scope: OnceLock<String>,
+    /// Contexts listed by `load_manifest` but not yet loaded, by ID
+    manifest: DashMap<ContextId, ManifestEntry>,
+    /// Per-context gates held while a context hydrates or is removed, so
+    /// on-demand and background hydration never load it twice and a
+    /// removal can't race a load
+    hydrating: DashMap<ContextId, Arc<std::sync::Mutex<()>>>,
+    /// Reachability indexes per context, kept current from commit events
+    reachability: DashMap<ContextId, ReachabilityCache>,
+    /// Emission lookups per context, kept by the sink and dropped by any
+    /// other write
+    emit_indexes: DashMap<ContextId, EmitIndexes>,
+    /// Mutation hooks, in registration order
+    hooks: std::sync::RwLock<Vec<Arc<dyn MutationHook>>>,
+    /// LLM cost records, kept here only when there is no store
+    llm_costs: std::sync::Mutex<Vec<PersistedLlmCost>>,
+    /// Emission records, kept here only when there is no store
+    emissions: std::sync::Mutex<Vec<PersistedEmission>>,
+    /// Outbound delivery queue, kept here only when there is no store
+    outbound: std::sync::Mutex<OutboundQueue>,
+    /// User-defined context templates, kept here only when there is no store
+    templates: std::sync::Mutex<BTreeMap<String, ContextTemplate>>,
+    /// Results of ingests under idempotency keys, kept here only when there is no store
+    ingest_results: std::sync::Mutex<HashMap<(String, String), PersistedIngestResult>>,
+    /// Commits the store's held saves when due, if it defers any
+    _deferred_flush: Option<DeferredFlush>,
+}
+
+/// How often the engine asks its store to commit saves held back by
+/// batched and relaxed contexts.
+const DEFERRED_FLUSH_TICK: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// How long a retry under an idempotency key is answered from the first
+/// call's result.
+pub const INGEST_RESULT_RETENTION_DAYS: i64 = 7;
+
+/// How long emission records are kept for `PlexusEngine::emissions`.
+pub const EMISSION_RETENTION_DAYS: i64 = 30;
+
+/// How long a claimed idempotency key stays claimed without a result
+/// before another call may take it over (its ingest is taken to have died).
+pub const INGEST_CLAIM_LEASE_SECS: i64 = 600;
+
+/// Most outbound events a context's delivery queue keeps; older ones are
+/// dropped, so a subscriber further behind than this misses them.
+pub const MAX_OUTBOUND_EVENTS: usize = 10_000;
+
+/// Background thread calling `GraphStore::flush_due` every
+/// `DEFERRED_FLUSH_TICK`. Dropping it stops and joins the thread, so the
+/// store is released by the time the engine is.
+struct DeferredFlush {
+    stop: Option<std::sync::mpsc::Sender<()>>,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+impl DeferredFlush {
+    fn spawn(store: Arc<dyn GraphStore>) -> Self {
+        let (stop, stopped) = std::sync::mpsc::channel::<()>();
+        let thread = std::thread::spawn(move || {
+            while let Err(std::sync::mpsc::RecvTimeoutError::Timeout) = stopped.recv_timeout(DEFERRED_FLUSH_TICK) {
+                if let Err(e) = store.flush_due() {
+                    tracing::warn!(error = %e, "deferred flush failed");
+                }
+            }
+        });
+        Self { stop: Some(stop), thread: Some(thread) }
+    }
+}
+
+impl Drop for DeferredFlush {
+    fn drop(&mut self) {
+        self.stop.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// In-memory outbound queue: events in offset order, and each
+/// (context, subscriber)'s acknowledged offset.
+#[derive(Debug, Default)]
+struct OutboundQueue {
+    events: Vec<PersistedOutboundEvent>,
+    acks: HashMap<(String, String), u64>,
+    /// Offset of the last event appended, pruned or not
+    last_offset: u64,
 }
 
 impl std::fmt::Debug for PlexusEngine {
@@ -75,7 +212,21 @@ impl PlexusEngine {
             contexts: DashMap::new(),
             name_index: DashMap::new(),
             store: None,
+            journal: None,
             last_data_version: AtomicU64::new(0),
+            tenant_quotas: DashMap::new(),
+            tenant_scope: OnceLock::new(),
+            manifest: DashMap::new(),
+            hydrating: DashMap::new(),
+            reachability: DashMap::new(),
+            emit_indexes: DashMap::new(),
+            hooks: std::sync::RwLock::new(Vec::new()),
+            llm_costs: std::sync::Mutex::new(Vec::new()),
+            emissions: std::sync::Mutex::new(Vec::new()),
+            outbound: std::sync::Mutex::new(OutboundQueue::default()),
+            templates: std::sync::Mutex::new(BTreeMap::new()),
+            ingest_results: std::sync::Mutex::new(HashMap::new()),
+            _deferred_flush: None,
         }
     }
 
@@ -87,11 +238,34 @@ impl PlexusEngine {
         Self {
             contexts: DashMap::new(),
             name_index: DashMap::new(),
-            store: Some(store),
+            journal: None,
             last_data_version: AtomicU64::new(0),
+            tenant_quotas: DashMap::new(),
+            tenant_scope: OnceLock::new(),
+            manifest: DashMap::new(),
+            hydrating: DashMap::new(),
+            reachability: DashMap::new(),
+            emit_indexes: DashMap::new(),
+            hooks: std::sync::RwLock::new(Vec::new()),
+            llm_costs: std::sync::Mutex::new(Vec::new()),
+            emissions: std::sync::Mutex::new(Vec::new()),
+            outbound: std::sync::Mutex::new(OutboundQueue::default()),
+            templates: std::sync::Mutex::new(BTreeMap::new()),
+            ingest_results: std::sync::Mutex::new(HashMap::new()),
+            _deferred_flush: store.defers_saves().then(|| DeferredFlush::spawn(store.clone())),
+            store: Some(store),
         }
     }
 
+    /// Create a PlexusEngine whose source of truth is `store`'s change
+    /// journal: contexts load as projections of it, and `context_as_of`
+    /// replays it exactly rather than rewinding the live state.
+    pub fn event_sourced(store: Arc<EventSourcedStore>) -> Self {
+        let mut engine = Self::with_store(store.clone());
+        engine.journal = Some(store);
+        engine
+    }
+
     /// Load all contexts from storage into memory
     ///
     /// Call this on startup to hydrate the in-memory cache from
@@ -105,9 +279,14 @@ impl PlexusEngine {
         let mut loaded = 0;
 
         for id in context_ids {
-            if let Some(context) = store.load_context(&id)? {
-                self.name_index.insert(context.name.clone(), id.clone());
-                self.contexts.insert(id, context);
+            if let Some(mut context) = store.load_context(&id)? {
+                // Legacy stores may hold exact-duplicate edges for
+                // merge-policy relationships; fold them once on load.
+                if context.consolidate_edges() > 0 {
+                    store.save_context(&context)?;
+                }
+                self.name_index.insert(context.index_name(), id.clone());
+                self.install(id, context);
                 loaded += 1;
             }
         }
@@ -120,12 +299,198 @@ impl PlexusEngine {
         Ok(loaded)
     }
 
+    /// List every stored context from the store's manifest without
+    /// loading any, so names resolve and listings answer at once on a
+    /// large database. Each context hydrates on first access (see
+    /// `hydrate`) or through `hydrate_all`. Returns the number listed.
+    pub fn load_manifest(&self) -> PlexusResult<usize> {
+        let Some(ref store) = self.store else {
+            return Ok(0);
+        };
+        let entries = store.load_manifest()?;
+        let listed = entries.len();
+        for entry in entries {
+            if self.contexts.contains_key(&entry.id) {
+                continue;
+            }
+            self.name_index.insert(scoped_name(entry.tenant.as_deref(), &entry.name), entry.id.clone());
+            self.manifest.insert(entry.id.clone(), entry);
+        }
+        if let Ok(v) = store.data_version() {
+            self.last_data_version.store(v, std::sync::atomic::Ordering::Release);
+        }
+        Ok(listed)
+    }
+
+    /// `load_manifest`, then hydrate every listed context on a background
+    /// thread. The fast alternative to `load_all` for serving processes.
+    pub fn warm_start(self: &Arc<Self>) -> PlexusResult<usize> {
+        let listed = self.load_manifest()?;
+        let engine = Arc::clone(self);
+        std::thread::spawn(move || {
+            if let Err(e) = engine.hydrate_all() {
+                tracing::warn!(error = %e, "warm start: background hydration failed");
+            }
+        });
+        Ok(listed)
+    }
+
+    /// Load a context listed by the manifest, if it isn't loaded yet.
+    /// Returns whether it was loaded by this call.
+    pub fn hydrate(&self, id: &ContextId) -> PlexusResult<bool> {
+        reject_reentry()?;
+        if !self.manifest.contains_key(id) {
+            return Ok(false);
+        }
+        let Some(ref store) = self.store else {
+            return Ok(false);
+        };
+        let gate = self.hydration_gate(id);
+        let _hydrating = gate.lock().map_err(|e| PlexusError::Other(format!("hydration lock poisoned: {e}")))?;
+        if !self.manifest.contains_key(id) {
+            return Ok(false);
+        }
+        if let Some(mut context) = store.load_context(id)? {
+            if context.consolidate_edges() > 0 {
+                store.save_context(&context)?;
+            }
+            // A copy written since the manifest was read is newer
+            self.contexts.entry(id.clone()).or_insert(context);
+        }
+        self.manifest.remove(id);
+        self.hydrating.remove(id);
+        Ok(true)
+    }
+
+    fn hydration_gate(&self, id: &ContextId) -> Arc<std::sync::Mutex<()>> {
+        self.hydrating.entry(id.clone()).or_default().clone()
+    }
+
+    /// A context, hydrated first if the manifest lists it. Every lookup
+    /// by ID goes through here or `loaded_mut`.
+    fn loaded(&self, id: &ContextId) -> PlexusResult<dashmap::mapref::one::Ref<'_, ContextId, Context>> {
+        self.hydrate(id)?;
+        self.contexts.get(id).ok_or_else(|| PlexusError::ContextNotFound(id.clone()))
+    }
+
+    fn loaded_mut(&self, id: &ContextId) -> PlexusResult<dashmap::mapref::one::RefMut<'_, ContextId, Context>> {
+        let context = self.write_guard(id)?;
+        self.emit_indexes.remove(id);
+        Ok(context)
+    }
+
+    /// A context to write, its reachability cache told of the write.
+    /// `loaded_mut` also drops the context's emit indexes; emissions,
+    /// which keep them current, lock it through here.
+    fn write_guard(&self, id: &ContextId) -> PlexusResult<dashmap::mapref::one::RefMut<'_, ContextId, Context>> {
+        self.hydrate(id)?;
+        let context = self.contexts.get_mut(id).ok_or_else(|| PlexusError::ContextNotFound(id.clone()))?;
+        if let Some(mut cache) = self.reachability.get_mut(id) {
+            cache.begin_write();
+        }
+        Ok(context)
+    }
+
+    /// Put `context` in the cache, replacing any loaded copy.
+    fn install(&self, id: ContextId, context: Context) {
+        let installed = self.contexts.entry(id).insert(context);
+        if let Some(mut cache) = self.reachability.get_mut(installed.key()) {
+            cache.invalidate();
+        }
+        self.emit_indexes.remove(installed.key());
+    }
+
+    /// `loaded`, for lookups that answer `None` for a missing context;
+    /// other failures are logged.
+    fn loaded_or_log(&self, id: &ContextId) -> Option<dashmap::mapref::one::Ref<'_, ContextId, Context>> {
+        match self.loaded(id) {
+            Ok(context) => Some(context),
+            Err(PlexusError::ContextNotFound(_)) => None,
+            Err(e) => {
+                tracing::error!(error = %e, context = %id, "context lookup failed");
+                None
+            }
+        }
+    }
+
+    /// Hydrate every context still waiting on the manifest. Returns the
+    /// number loaded.
+    pub fn hydrate_all(&self) -> PlexusResult<usize> {
+        let pending: Vec<ContextId> = self.manifest.iter().map(|r| r.key().clone()).collect();
+        let mut loaded = 0;
+        for id in pending {
+            loaded += usize::from(self.hydrate(&id)?);
+        }
+        Ok(loaded)
+    }
+
+    /// Contexts listed by the manifest and not yet hydrated.
+    pub fn pending_hydration(&self) -> usize {
+        self.manifest.len()
+    }
+
+    /// A manifest entry for every context: current counts for loaded
+    /// contexts, the stored manifest's for those still hydrating.
+    pub fn context_manifest(&self) -> Vec<ManifestEntry> {
+        let mut entries: Vec<ManifestEntry> = self.contexts.iter().map(|r| ManifestEntry::of(r.value())).collect();
+        entries.extend(
+            self.manifest
+                .iter()
+                .filter(|r| !self.contexts.contains_key(r.key()))
+                .map(|r| r.value().clone()),
+        );
+        entries.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.as_str().cmp(b.id.as_str())));
+        entries
+    }
+
     /// Create or update a context
     ///
     /// If a context with the same ID already exists, it will be replaced.
     /// Automatically persists to storage if configured.
     pub fn upsert_context(&self, context: Context) -> PlexusResult<ContextId> {
         let id = context.id.clone();
+        self.hydrate(&id)?;
+        let hooks = self.hook_list();
+        let events = match hooks.is_empty() {
+            true => Vec::new(),
+            false => self.replacement_events(&context),
+        };
+        self.replace_context(context, &hooks, &events)?;
+        // Upserts aren't logged, but hooks still observe what changed
+        super::hooks::run_post_commit(&hooks, &events);
+        Ok(id)
+    }
+
+    /// Events describing `context` replacing the engine's copy of it.
+    fn replacement_events(&self, context: &Context) -> Vec<GraphEvent> {
+        let empty = Context::with_id(context.id.clone(), &context.name);
+        let current = self.contexts.get(&context.id);
+        super::sync::diff(current.as_deref().unwrap_or(&empty), context, Vec::new()).events(context.id.as_str(), "engine")
+    }
+
+    /// Store and cache `context`, replacing any copy, once the pre-change
+    /// hooks accept `events`.
+    fn replace_context(&self, context: Context, hooks: &[Arc<dyn MutationHook>], events: &[GraphEvent]) -> PlexusResult<()> {
+        let id = context.id.clone();
+        self.hydrate(&id)?;
+
+        // A tenant at quota can't gain contexts
+        if let Some(ref tenant) = context.metadata.tenant {
+            if !self.contexts.contains_key(&id) {
+                self.check_tenant_quota(tenant)?;
+            }
+        }
+        {
+            let empty = Context::with_id(id.clone(), &context.name);
+            let current = self.contexts.get(&id);
+            check_context_quota(current.as_deref().unwrap_or(&empty), &context)?;
+        }
+
+        if !hooks.is_empty() {
+            let empty = Context::with_id(id.clone(), &context.name);
+            let current = self.contexts.get(&id);
+            super::hooks::run_pre_change(hooks, current.as_deref().unwrap_or(&empty), events)?;
+        }
 
         // Persist to storage first (if configured)
         if let Some(ref store) = self.store {
@@ -134,29 +499,146 @@ impl PlexusEngine {
 
         // Update name index (remove old name if replacing an existing context)
         if let Some(old) = self.contexts.get(&id) {
-            if old.name != context.name {
-                self.name_index.remove(&old.name);
+            if old.index_name() != context.index_name() {
+                self.name_index.remove(&old.index_name());
             }
         }
-        self.name_index.insert(context.name.clone(), id.clone());
+        self.name_index.insert(context.index_name(), id.clone());
 
         // Update in-memory cache
-        self.contexts.insert(id.clone(), context);
-        Ok(id)
+        self.install(id.clone(), context);
+        self.manifest.remove(&id);
+        Ok(())
+    }
+
+    /// The shared commit path of engine operations that aren't emissions.
+    ///
+    /// `f` changes the context and returns the events describing the
+    /// change; with hooks registered it runs on a copy, swapped in once
+    /// the pre-change hooks accept the events. A change with events is
+    /// stamped, maintained, saved, and its events persisted once the
+    /// context is released; without events nothing is saved.
+    fn commit_change<R>(
+        &self,
+        id: &ContextId,
+        f: impl FnOnce(&mut Context) -> PlexusResult<(R, Vec<GraphEvent>)>,
+    ) -> PlexusResult<R> {
+        let hooks = self.hook_list();
+        let mut context = self.loaded_mut(id)?;
+        let (result, events) = match hooks.is_empty() {
+            true => f(&mut context)?,
+            false => {
+                let mut next = context.clone();
+                let (result, events) = f(&mut next)?;
+                super::hooks::run_pre_change(&hooks, &context, &events)?;
+                *context = next;
+                (result, events)
+            }
+        };
+        if events.is_empty() {
+            return Ok(result);
+        }
+
+        context.metadata.updated_at = Some(Utc::now());
+        crate::query::maintain_views(&mut context, &events);
+        crate::query::maintain_closures(&mut context, &events);
+        if let Some(ref store) = self.store {
+            store.save_context(&context)?;
+        }
+        drop(context);
+        self.persist_events(&events);
+        Ok(result)
+    }
+
+    /// `commit_change` for a change that must land in `mirror` too. The
+    /// changed context is saved there first and only then here; if saving
+    /// here fails, the mirror gets `mirror_before` back. Either way the
+    /// two copies don't diverge.
+    fn commit_mirrored<R>(
+        &self,
+        id: &ContextId,
+        mirror: &dyn GraphStore,
+        mirror_before: &Context,
+        f: impl FnOnce(&mut Context) -> PlexusResult<(R, Vec<GraphEvent>)>,
+    ) -> PlexusResult<R> {
+        let hooks = self.hook_list();
+        let mut context = self.loaded_mut(id)?;
+        let mut next = context.clone();
+        let (result, events) = f(&mut next)?;
+        if !hooks.is_empty() {
+            super::hooks::run_pre_change(&hooks, &context, &events)?;
+        }
+        if events.is_empty() {
+            // Nothing changes here, but the mirror may still lack this side's writes
+            mirror.save_context(&next)?;
+            return Ok(result);
+        }
+
+        next.metadata.updated_at = Some(Utc::now());
+        crate::query::maintain_views(&mut next, &events);
+        crate::query::maintain_closures(&mut next, &events);
+        mirror.save_context(&next)?;
+        if let Some(ref store) = self.store {
+            if let Err(e) = store.save_context(&next) {
+                if let Err(undo) = mirror.save_context(mirror_before) {
+                    tracing::error!(context = %id, error = %undo, "could not restore the mirrored copy; the copies have diverged");
+                }
+                return Err(e.into());
+            }
+        }
+        *context = next;
+        drop(context);
+        self.persist_events(&events);
+        Ok(result)
     }
 
     /// Get a context by ID
     ///
-    /// Returns from in-memory cache. Use `load_all()` on startup
-    /// to populate cache from storage.
+    /// Returns from in-memory cache, hydrating the context first if only
+    /// the manifest lists it. Failures other than a missing context are
+    /// logged.
     pub fn get_context(&self, id: &ContextId) -> Option<Context> {
-        self.contexts.get(id).map(|r| r.clone())
+        self.loaded_or_log(id).map(|r| r.clone())
+    }
+
+    /// An immutable snapshot of a context for background analytics.
+    ///
+    /// Copies the context once, holding its shard lock only for the copy;
+    /// the returned handle is cheap to clone and never sees later writes.
+    pub fn reader(&self, id: &ContextId) -> PlexusResult<ContextReader> {
+        let context = self.loaded(id)?;
+        Ok(ContextReader::new(context.clone()))
+    }
+
+    /// The context as it stood at `as_of`, rewound from its live state
+    /// through the event log (see `HistoricalView`). Only events up to the
+    /// log's head when the live state is read take part, so later writes
+    /// can't leak into the rewind. Without a store there is no log, and
+    /// only the graph's own timestamps are used.
+    pub fn context_as_of(&self, id: &ContextId, as_of: chrono::DateTime<chrono::Utc>) -> PlexusResult<HistoricalView> {
+        if let Some(ref journal) = self.journal {
+            if let Some(projected) = journal.project_as_of(id, as_of)? {
+                return Ok(HistoricalView::projected(projected, as_of));
+            }
+        }
+        let (context, head) = {
+            let context = self.loaded(id)?;
+            (context.clone(), self.latest_sequence(id.as_str())?)
+        };
+        let mut events = self.query_events_since(id.as_str(), 0, None)?;
+        events.retain(|event| event.sequence <= head);
+        Ok(HistoricalView::rewind(&context, as_of, &events))
     }
 
     /// Remove a context
     ///
     /// Removes from both in-memory cache and persistent storage.
     pub fn remove_context(&self, id: &ContextId) -> PlexusResult<Option<Context>> {
+        // Hydrated first, so the removed copy is returned
+        self.hydrate(id)?;
+        let gate = self.hydration_gate(id);
+        let _removing = gate.lock().map_err(|e| PlexusError::Other(format!("hydration lock poisoned: {e}")))?;
+
         // Remove from storage first (if configured)
         if let Some(ref store) = self.store {
             store.delete_context(id)?;
@@ -165,24 +647,36 @@ impl PlexusEngine {
         // Remove from in-memory cache and name index
         let removed = self.contexts.remove(id).map(|(_, ctx)| ctx);
         if let Some(ref ctx) = removed {
-            self.name_index.remove(&ctx.name);
+            self.name_index.remove(&ctx.index_name());
         }
+        if let Some((_, entry)) = self.manifest.remove(id) {
+            self.name_index.remove(&scoped_name(entry.tenant.as_deref(), &entry.name));
+        }
+        self.hydrating.remove(id);
+        self.reachability.remove(id);
+        self.emit_indexes.remove(id);
+        self.ingest_results.lock().unwrap_or_else(|e| e.into_inner()).retain(|(context, _), _| context != id.as_str());
+        self.emissions.lock().unwrap_or_else(|e| e.into_inner()).retain(|r| r.context_id != id.as_str());
         Ok(removed)
     }
 
     /// List all context IDs
+    ///
+    /// Includes contexts the manifest lists that haven't hydrated yet.
     pub fn list_contexts(&self) -> Vec<ContextId> {
-        self.contexts.iter().map(|r| r.key().clone()).collect()
+        let mut ids: Vec<ContextId> = self.contexts.iter().map(|r| r.key().clone()).collect();
+        ids.extend(self.manifest.iter().map(|r| r.key().clone()).filter(|id| !self.contexts.contains_key(id)));
+        ids
     }
 
     /// Get the number of contexts
     pub fn context_count(&self) -> usize {
-        self.contexts.len()
+        self.list_contexts().len()
     }
 
     /// Check if a context exists
     pub fn has_context(&self, id: &ContextId) -> bool {
-        self.contexts.contains_key(id)
+        self.contexts.contains_key(id) || self.manifest.contains_key(id)
     }
 
     /// Check if engine has persistent storage configured
@@ -190,147 +684,759 @@ impl PlexusEngine {
         self.store.is_some()
     }
 
-    /// Resolve a context name to its ID in O(1) time.
-    pub fn resolve_by_name(&self, name: &str) -> Option<ContextId> {
-        self.name_index.get(name).map(|r| r.value().clone())
+    /// Whether contexts are projections of a change journal (`event_sourced`).
+    pub fn is_event_sourced(&self) -> bool {
+        self.journal.is_some()
     }
 
-    /// Execute a closure with mutable access to a context (ADR-006).
-    ///
-    /// Keeps DashMap internals private. After the closure completes,
-    /// the context is automatically persisted to storage (if configured).
-    /// This is the integration point for EngineSink's emission protocol.
-    pub fn with_context_mut<R>(
-        &self,
-        id: &ContextId,
-        f: impl FnOnce(&mut Context) -> R,
-    ) -> PlexusResult<R> {
-        let mut context = self.contexts.get_mut(id)
-            .ok_or_else(|| PlexusError::ContextNotFound(id.clone()))?;
+    /// The engine's persistent storage, if configured.
+    pub(crate) fn store(&self) -> Option<&Arc<dyn GraphStore>> {
+        self.store.as_ref()
+    }
 
-        let result = f(&mut context);
+    /// Resolve a context name to its ID in O(1) time. Only untenanted
+    /// contexts resolve; see `resolve_in_tenant`.
+    pub fn resolve_by_name(&self, name: &str) -> Option<ContextId> {
+        self.name_index.get(name).map(|r| r.value().clone())
+    }
 
-        // Persist-per-emission: save after mutation completes
-        if let Some(ref store) = self.store {
-            store.save_context(&context)?;
-        }
+    // === Tenancy ===
 
-        Ok(result)
+    /// Resolve a context name within a tenant (`None`: untenanted).
+    pub fn resolve_in_tenant(&self, tenant: Option<&str>, name: &str) -> Option<ContextId> {
+        self.name_index.get(&scoped_name(tenant, name)).map(|r| r.value().clone())
     }
 
-    /// Query events after the given cursor (ADR-035).
-    ///
-    /// Delegates to the store's `query_events_since`. Returns empty vec if no store.
-    pub fn query_events_since(
-        &self,
-        context_id: &str,
-        cursor: u64,
-        filter: Option<&crate::query::CursorFilter>,
-    ) -> PlexusResult<Vec<crate::query::PersistedEvent>> {
-        let Some(ref store) = self.store else {
-            return Ok(Vec::new());
-        };
-        store.query_events_since(context_id, cursor, filter)
-            .map_err(PlexusError::from)
+    /// IDs of the contexts belonging to `tenant` (`None`: untenanted).
+    pub fn list_contexts_in_tenant(&self, tenant: Option<&str>) -> Vec<ContextId> {
+        let mut ids: Vec<ContextId> = self
+            .contexts
+            .iter()
+            .filter(|r| r.metadata.tenant.as_deref() == tenant)
+            .map(|r| r.key().clone())
+            .collect();
+        ids.extend(
+            self.manifest
+                .iter()
+                .filter(|r| r.tenant.as_deref() == tenant && !self.contexts.contains_key(r.key()))
+                .map(|r| r.key().clone()),
+        );
+        ids
     }
 
-    /// Return the latest event sequence number for a context (ADR-035).
-    pub fn latest_sequence(&self, context_id: &str) -> PlexusResult<u64> {
+    /// Load only `tenant`'s contexts from storage, for a process serving
+    /// a single tenant; later coherence reloads stay within it. Returns
+    /// the number loaded.
+    pub fn load_tenant(&self, tenant: &str) -> PlexusResult<usize> {
         let Some(ref store) = self.store else {
             return Ok(0);
         };
-        store.latest_sequence(context_id)
-            .map_err(PlexusError::from)
-    }
-
-    /// Persist graph events to the event log (ADR-035).
-    ///
-    /// Best-effort: logs and continues on failure. Event persistence should
-    /// not fail the emission — a missing event degrades the cursor but not
-    /// the graph.
-    pub fn persist_events(&self, events: &[crate::graph::events::GraphEvent]) {
-        let Some(ref store) = self.store else { return };
-        for event in events {
-            let (context_id, event_type, node_ids, edge_ids, adapter_id) = match event {
-                crate::graph::events::GraphEvent::NodesAdded { node_ids, adapter_id, context_id } => {
-                    let nids: Vec<String> = node_ids.iter().map(|n| n.as_str().to_string()).collect();
-                    (context_id.as_str(), "NodesAdded", nids, vec![], adapter_id.as_str())
-                }
-                crate::graph::events::GraphEvent::EdgesAdded { edge_ids, adapter_id, context_id } => {
-                    let eids: Vec<String> = edge_ids.iter().map(|e| e.as_str().to_string()).collect();
-                    (context_id.as_str(), "EdgesAdded", vec![], eids, adapter_id.as_str())
-                }
-                crate::graph::events::GraphEvent::NodesRemoved { node_ids, adapter_id, context_id } => {
-                    let nids: Vec<String> = node_ids.iter().map(|n| n.as_str().to_string()).collect();
-                    (context_id.as_str(), "NodesRemoved", nids, vec![], adapter_id.as_str())
-                }
-                crate::graph::events::GraphEvent::EdgesRemoved { edge_ids, adapter_id, context_id, .. } => {
-                    let eids: Vec<String> = edge_ids.iter().map(|e| e.as_str().to_string()).collect();
-                    (context_id.as_str(), "EdgesRemoved", vec![], eids, adapter_id.as_str())
-                }
-                crate::graph::events::GraphEvent::WeightsChanged { edge_ids, adapter_id, context_id } => {
-                    let eids: Vec<String> = edge_ids.iter().map(|e| e.as_str().to_string()).collect();
-                    (context_id.as_str(), "WeightsChanged", vec![], eids, adapter_id.as_str())
-                }
-                crate::graph::events::GraphEvent::ContributionsRetracted { adapter_id, context_id, .. } => {
-                    (context_id.as_str(), "ContributionsRetracted", vec![], vec![], adapter_id.as_str())
+        let scope = self.tenant_scope.get_or_init(|| tenant.to_string());
+        if scope != tenant {
+            return Err(PlexusError::InvalidInput(format!("engine already scoped to tenant '{scope}'")));
+        }
+        let mut loaded = 0;
+        for id in store.list_contexts_for_tenant(Some(tenant))? {
+            if let Some(mut context) = store.load_context(&id)? {
+                if context.consolidate_edges() > 0 {
+                    store.save_context(&context)?;
                 }
-            };
-            if let Err(e) = store.persist_event(context_id, event_type, &node_ids, &edge_ids, adapter_id) {
-                tracing::warn!(error = %e, "failed to persist event to event log (best-effort)");
+                self.name_index.insert(context.index_name(), id.clone());
+                self.install(id, context);
+                loaded += 1;
             }
         }
+        if let Ok(v) = store.data_version() {
+            self.last_data_version.store(v, std::sync::atomic::Ordering::Release);
+        }
+        Ok(loaded)
     }
 
-    // === Spec Persistence (ADR-037) ===
-
-    /// Persist a loaded spec to the specs table.
-    pub fn persist_spec(&self, spec: &crate::storage::PersistedSpec) -> PlexusResult<()> {
-        let Some(ref store) = self.store else {
-            return Ok(());
+    /// Set or clear (with `None`) a tenant's quota.
+    pub fn set_tenant_quota(&self, tenant: &str, quota: Option<TenantQuota>) {
+        match quota {
+            Some(quota) => self.tenant_quotas.insert(tenant.to_string(), quota),
+            None => self.tenant_quotas.remove(tenant).map(|(_, q)| q),
         };
-        store.persist_spec(spec).map_err(PlexusError::from)
     }
 
-    /// Query all persisted specs for a context.
-    pub fn query_specs_for_context(&self, context_id: &str) -> PlexusResult<Vec<crate::storage::PersistedSpec>> {
-        let Some(ref store) = self.store else {
-            return Ok(Vec::new());
-        };
-        store.query_specs_for_context(context_id).map_err(PlexusError::from)
+    /// Contexts, nodes, and edges `tenant` holds in the cache.
+    pub fn tenant_usage(&self, tenant: &str) -> TenantUsage {
+        self.contexts
+            .iter()
+            .filter(|r| r.metadata.tenant.as_deref() == Some(tenant))
+            .fold(TenantUsage::default(), |usage, r| TenantUsage {
+                contexts: usage.contexts + 1,
+                nodes: usage.nodes + r.node_count(),
+                edges: usage.edges + r.edge_count(),
+            })
     }
 
-    /// Delete a persisted spec.
-    pub fn delete_spec(&self, context_id: &str, adapter_id: &str) -> PlexusResult<bool> {
-        let Some(ref store) = self.store else {
-            return Ok(false);
+    /// Fail with `QuotaExceeded` if `tenant` has reached any limit.
+    pub fn check_tenant_quota(&self, tenant: &str) -> PlexusResult<()> {
+        let Some(quota) = self.tenant_quotas.get(tenant).map(|q| q.clone()) else {
+            return Ok(());
         };
-        store.delete_spec(context_id, adapter_id).map_err(PlexusError::from)
+        match quota.exceeded_by(&self.tenant_usage(tenant)) {
+            Some(detail) => Err(PlexusError::QuotaExceeded { tenant: tenant.to_string(), detail }),
+            None => Ok(()),
+        }
     }
 
-    /// Retract all contributions from an adapter/enrichment (ADR-027).
-    ///
-    /// Removes the adapter's contribution slot from every edge in the context,
-    /// prunes zero-evidence edges, recomputes combined weights, and persists.
-    /// Returns graph events (ContributionsRetracted + EdgesRemoved for pruned edges).
-    ///
-    /// Bypasses the adapter pipeline intentionally — retraction is an engine-level
-    /// operation that reverses a prior adapter's effect without re-running the pipeline.
-    pub fn retract_contributions(
+    /// Record what one LLM invocation for `item` (usually a file path)
+    /// used, in the store's `llm_costs` table.
+    pub fn record_llm_cost(
         &self,
         context_id: &ContextId,
         adapter_id: &str,
-    ) -> PlexusResult<Vec<GraphEvent>> {
-        let mut context = self.contexts.get_mut(context_id)
-            .ok_or_else(|| PlexusError::ContextNotFound(context_id.clone()))?;
-
-        let (edges_affected, pruned_ids) = context.retract_contributions(adapter_id);
-
-        // Persist after mutation
+        item: &str,
+        ensemble: &str,
+        usage: LlmUsage,
+    ) -> PlexusResult<()> {
+        let record = PersistedLlmCost {
+            context_id: context_id.to_string(),
+            adapter_id: adapter_id.to_string(),
+            item: item.to_string(),
+            ensemble: ensemble.to_string(),
+            input_tokens: usage.input_tokens,
+            output_tokens: usage.output_tokens,
+            cost_usd: usage.cost_usd,
+            recorded_at: Utc::now().to_rfc3339(),
+        };
+        match self.store {
+            Some(ref store) => store.persist_llm_cost(&record)?,
+            None => self.llm_costs.lock().unwrap_or_else(|e| e.into_inner()).push(record),
+        }
+        Ok(())
+    }
+
+    /// LLM costs recorded for a context since `since`.
+    pub fn llm_costs(&self, context_id: &ContextId, since: DateTime<Utc>) -> PlexusResult<LlmCostReport> {
+        let since = since.to_rfc3339();
+        let records = match self.store {
+            Some(ref store) => store.query_llm_costs_since(context_id.as_str(), &since)?,
+            None => self
+                .llm_costs
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .iter()
+                .filter(|r| r.context_id == context_id.as_str() && r.recorded_at >= since)
+                .cloned()
+                .collect(),
+        };
+        Ok(LlmCostReport { records })
+    }
+
+    /// Record that `adapter_id` committed `node_ids` and `edge_ids` to a
+    /// context, in the store's `emissions` table, and drop the context's
+    /// records past retention (`EMISSION_RETENTION_DAYS`).
+    pub fn record_emission(
+        &self,
+        context_id: &ContextId,
+        adapter_id: &str,
+        input_summary: Option<&str>,
+        node_ids: &[NodeId],
+        edge_ids: &[EdgeId],
+    ) -> PlexusResult<()> {
+        let record = PersistedEmission {
+            context_id: context_id.to_string(),
+            adapter_id: adapter_id.to_string(),
+            input_summary: input_summary.map(str::to_string),
+            node_ids: node_ids.iter().map(|id| id.to_string()).collect(),
+            edge_ids: edge_ids.iter().map(|id| id.to_string()).collect(),
+            recorded_at: Utc::now().to_rfc3339(),
+        };
+        let cutoff = (Utc::now() - chrono::Duration::days(EMISSION_RETENTION_DAYS)).to_rfc3339();
+        match self.store {
+            Some(ref store) => {
+                store.persist_emission(&record)?;
+                if let Err(e) = store.prune_emissions(context_id.as_str(), &cutoff) {
+                    tracing::warn!(error = %e, "failed to prune expired emission records");
+                }
+            }
+            None => {
+                let mut emissions = self.emissions.lock().unwrap_or_else(|e| e.into_inner());
+                emissions.retain(|r| r.context_id != context_id.as_str() || r.recorded_at >= cutoff);
+                emissions.push(record);
+            }
+        }
+        Ok(())
+    }
+
+    /// Emissions committed to a context that match `filter`, oldest first:
+    /// what an adapter added, and when.
+    pub fn emissions(&self, context_id: &ContextId, filter: &EmissionFilter) -> PlexusResult<Vec<PersistedEmission>> {
+        Ok(match self.store {
+            Some(ref store) => store.query_emissions(context_id.as_str(), filter)?,
+            None => self
+                .emissions
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .iter()
+                .filter(|r| r.context_id == context_id.as_str() && filter.matches(r))
+                .cloned()
+                .collect(),
+        })
+    }
+
+    /// Append an ingest's outbound event to the context's delivery queue.
+    /// Returns its offset. The queue keeps the newest
+    /// `MAX_OUTBOUND_EVENTS` (see `prune_outbound`).
+    pub fn enqueue_outbound(&self, context_id: &ContextId, kind: &str, detail: &str) -> PlexusResult<u64> {
+        let mut event = PersistedOutboundEvent {
+            offset: 0,
+            context_id: context_id.to_string(),
+            kind: kind.to_string(),
+            detail: detail.to_string(),
+            recorded_at: Utc::now().to_rfc3339(),
+        };
+        match self.store {
+            Some(ref store) => Ok(store.append_outbound_event(&event)?),
+            None => {
+                let mut queue = self.outbound.lock().unwrap_or_else(|e| e.into_inner());
+                queue.last_offset += 1;
+                event.offset = queue.last_offset;
+                queue.events.push(event);
+                Ok(queue.last_offset)
+            }
+        }
+    }
+
+    /// Drop all but the context's newest `MAX_OUTBOUND_EVENTS` queued
+    /// events. Returns how many were dropped.
+    pub fn prune_outbound(&self, context_id: &ContextId) -> PlexusResult<usize> {
+        match self.store {
+            Some(ref store) => Ok(store.prune_outbound(context_id.as_str(), MAX_OUTBOUND_EVENTS)?),
+            None => {
+                let mut queue = self.outbound.lock().unwrap_or_else(|e| e.into_inner());
+                let queued = queue.events.iter().filter(|e| e.context_id == context_id.as_str()).count();
+                let mut excess = queued.saturating_sub(MAX_OUTBOUND_EVENTS);
+                let dropped = excess;
+                queue.events.retain(|e| {
+                    let drop = excess > 0 && e.context_id == context_id.as_str();
+                    excess -= drop as usize;
+                    !drop
+                });
+                Ok(dropped)
+            }
+        }
+    }
+
+    /// Queued outbound events after `after`, oldest first: replay from
+    /// any offset, regardless of acknowledgements.
+    pub fn outbound_since(
+        &self,
+        context_id: &ContextId,
+        after: u64,
+        limit: Option<usize>,
+    ) -> PlexusResult<Vec<PersistedOutboundEvent>> {
+        match self.store {
+            Some(ref store) => Ok(store.query_outbound_since(context_id.as_str(), after, limit)?),
+            None => Ok(self
+                .outbound
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .events
+                .iter()
+                .filter(|e| e.context_id == context_id.as_str() && e.offset > after)
+                .take(limit.unwrap_or(usize::MAX))
+                .cloned()
+                .collect()),
+        }
+    }
+
+    /// The offset `subscriber` has acknowledged up to (0 for nothing).
+    pub fn outbound_acked(&self, context_id: &ContextId, subscriber: &str) -> PlexusResult<u64> {
+        let acked = match self.store {
+            Some(ref store) => store.outbound_ack(context_id.as_str(), subscriber)?,
+            None => self
+                .outbound
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .acks
+                .get(&(context_id.to_string(), subscriber.to_string()))
+                .copied(),
+        };
+        Ok(acked.unwrap_or(0))
+    }
+
+    /// Events `subscriber` hasn't acknowledged, oldest first. Until they
+    /// are acknowledged, every call delivers them again.
+    pub fn next_outbound(
+        &self,
+        context_id: &ContextId,
+        subscriber: &str,
+        limit: Option<usize>,
+    ) -> PlexusResult<Vec<PersistedOutboundEvent>> {
+        let acked = self.outbound_acked(context_id, subscriber)?;
+        self.outbound_since(context_id, acked, limit)
+    }
+
+    /// Acknowledge delivery up to and including `offset`. Acknowledging
+    /// an earlier offset than already acknowledged changes nothing.
+    pub fn ack_outbound(&self, context_id: &ContextId, subscriber: &str, offset: u64) -> PlexusResult<()> {
+        if offset > self.outbound_acked(context_id, subscriber)? {
+            self.set_outbound_ack(context_id, subscriber, offset)?;
+        }
+        Ok(())
+    }
+
+    /// Move a subscriber's acknowledgement back (or forward) to `offset`,
+    /// so delivery replays from there.
+    pub fn rewind_outbound(&self, context_id: &ContextId, subscriber: &str, offset: u64) -> PlexusResult<()> {
+        self.set_outbound_ack(context_id, subscriber, offset)
+    }
+
+    fn set_outbound_ack(&self, context_id: &ContextId, subscriber: &str, offset: u64) -> PlexusResult<()> {
+        match self.store {
+            Some(ref store) => store.set_outbound_ack(context_id.as_str(), subscriber, offset)?,
+            None => {
+                self.outbound
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .acks
+                    .insert((context_id.to_string(), subscriber.to_string()), offset);
+            }
+        }
+        Ok(())
+    }
+
+    /// Claim an idempotency key ahead of ingesting under it (see
+    /// `GraphStore::claim_ingest_key`). Fails only on storage errors;
+    /// `Ok(false)` means another call holds the key or already finished.
+    pub fn claim_ingest_key(&self, claim: PersistedIngestResult) -> PlexusResult<bool> {
+        let stale_before = (Utc::now() - chrono::Duration::seconds(INGEST_CLAIM_LEASE_SECS)).to_rfc3339();
+        match self.store {
+            Some(ref store) => Ok(store.claim_ingest_key(&claim, &stale_before)?),
+            None => {
+                let mut results = self.ingest_results.lock().unwrap_or_else(|e| e.into_inner());
+                let key = (claim.context_id.clone(), claim.key.clone());
+                let free = results.get(&key).is_none_or(|held| !held.completed && held.recorded_at < stale_before);
+                if free {
+                    results.insert(key, claim);
+                }
+                Ok(free)
+            }
+        }
+    }
+
+    /// Record the result of an ingest made under an idempotency key,
+    /// completing its claim, and drop results past retention
+    /// (`INGEST_RESULT_RETENTION_DAYS`). A completed result is kept.
+    pub fn record_ingest_result(&self, result: PersistedIngestResult) -> PlexusResult<()> {
+        let cutoff = (Utc::now() - chrono::Duration::days(INGEST_RESULT_RETENTION_DAYS)).to_rfc3339();
+        match self.store {
+            Some(ref store) => {
+                store.persist_ingest_result(&result)?;
+                if let Err(e) = store.prune_ingest_results(&cutoff) {
+                    tracing::warn!(error = %e, "failed to prune expired ingest results");
+                }
+            }
+            None => {
+                let mut results = self.ingest_results.lock().unwrap_or_else(|e| e.into_inner());
+                let key = (result.context_id.clone(), result.key.clone());
+                if results.get(&key).is_none_or(|held| !held.completed) {
+                    results.insert(key, result);
+                }
+                results.retain(|_, held| held.recorded_at >= cutoff);
+            }
+        }
+        Ok(())
+    }
+
+    /// Give up a claim on an idempotency key after its ingest failed, so
+    /// a retry runs the ingest again.
+    pub fn release_ingest_key(&self, context_id: &ContextId, key: &str) -> PlexusResult<()> {
+        match self.store {
+            Some(ref store) => store.release_ingest_key(context_id.as_str(), key)?,
+            None => {
+                let mut results = self.ingest_results.lock().unwrap_or_else(|e| e.into_inner());
+                let slot = (context_id.to_string(), key.to_string());
+                if results.get(&slot).is_some_and(|held| !held.completed) {
+                    results.remove(&slot);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The result or claim recorded for an idempotency key on a context,
+    /// if any within retention.
+    pub fn ingest_result(&self, context_id: &ContextId, key: &str) -> PlexusResult<Option<PersistedIngestResult>> {
+        let cutoff = (Utc::now() - chrono::Duration::days(INGEST_RESULT_RETENTION_DAYS)).to_rfc3339();
+        let recorded = match self.store {
+            Some(ref store) => store.load_ingest_result(context_id.as_str(), key)?,
+            None => self
+                .ingest_results
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .get(&(context_id.to_string(), key.to_string()))
+                .cloned(),
+        };
+        Ok(recorded.filter(|r| r.recorded_at >= cutoff))
+    }
+
+    /// Save a user-defined context template, replacing one of the same
+    /// name. Built-in template names are reserved.
+    pub fn save_template(&self, template: ContextTemplate) -> PlexusResult<()> {
+        if BUILTIN_TEMPLATES.contains(&template.name.as_str()) {
+            return Err(PlexusError::InvalidInput(format!("'{}' is a built-in template", template.name)));
+        }
+        match self.store {
+            Some(ref store) => store.persist_template(&PersistedTemplate {
+                name: template.name.clone(),
+                template_json: serde_json::to_string(&template)?,
+                saved_at: Utc::now().to_rfc3339(),
+            })?,
+            None => {
+                self.templates.lock().unwrap_or_else(|e| e.into_inner()).insert(template.name.clone(), template);
+            }
+        }
+        Ok(())
+    }
+
+    /// A template by name: built-in first, then user-defined.
+    pub fn template(&self, name: &str) -> PlexusResult<Option<ContextTemplate>> {
+        if let Some(template) = ContextTemplate::builtin(name) {
+            return Ok(Some(template));
+        }
+        match self.store {
+            Some(ref store) => match store.load_template(name)? {
+                Some(row) => Ok(Some(serde_json::from_str(&row.template_json)?)),
+                None => Ok(None),
+            },
+            None => Ok(self.templates.lock().unwrap_or_else(|e| e.into_inner()).get(name).cloned()),
+        }
+    }
+
+    /// Names of all templates: the built-ins, then user-defined ones sorted.
+    pub fn list_templates(&self) -> PlexusResult<Vec<String>> {
+        let mut names: Vec<String> = BUILTIN_TEMPLATES.iter().map(|n| n.to_string()).collect();
+        match self.store {
+            Some(ref store) => names.extend(store.list_templates()?),
+            None => names.extend(self.templates.lock().unwrap_or_else(|e| e.into_inner()).keys().cloned()),
+        }
+        Ok(names)
+    }
+
+    /// Delete a user-defined template. Returns whether it existed.
+    pub fn delete_template(&self, name: &str) -> PlexusResult<bool> {
+        match self.store {
+            Some(ref store) => Ok(store.delete_template(name)?),
+            None => Ok(self.templates.lock().unwrap_or_else(|e| e.into_inner()).remove(name).is_some()),
+        }
+    }
+
+    /// Execute a closure with read access to a context, without cloning it.
+    pub(crate) fn with_context<R>(&self, id: &ContextId, f: impl FnOnce(&Context) -> R) -> PlexusResult<R> {
+        let context = self.loaded(id)?;
+        Ok(f(&context))
+    }
+
+    /// Execute a closure with mutable access to a context (ADR-006).
+    ///
+    /// Keeps DashMap internals private. After the closure completes,
+    /// the context is automatically persisted to storage (if configured).
+    /// This is the integration point for EngineSink's emission protocol.
+    pub fn with_context_mut<R>(
+        &self,
+        id: &ContextId,
+        f: impl FnOnce(&mut Context) -> R,
+    ) -> PlexusResult<R> {
+        let mut context = self.loaded_mut(id)?;
+
+        let result = f(&mut context);
+
+        // Persist-per-emission: save after mutation completes
         if let Some(ref store) = self.store {
             store.save_context(&context)?;
         }
 
+        Ok(result)
+    }
+
+    /// Like `with_context_mut`, for emissions: `f` also gets the context's
+    /// emit indexes and keeps them current, so the next emission reuses
+    /// them. A failed `f` drops them, and the next emission rebuilds.
+    pub(crate) fn with_context_indexed<R, E>(
+        &self,
+        id: &ContextId,
+        f: impl FnOnce(&mut Context, &mut EmitIndexes) -> Result<R, E>,
+    ) -> PlexusResult<Result<R, E>> {
+        let mut context = self.write_guard(id)?;
+        let mut indexes = self.take_emit_indexes(id, &context);
+
+        let result = f(&mut context, &mut indexes);
+        if result.is_ok() {
+            self.emit_indexes.insert(id.clone(), indexes);
+        }
+
+        if let Some(ref store) = self.store {
+            store.save_context(&context)?;
+        }
+        Ok(result)
+    }
+
+    /// Like `with_context_indexed`, but all or nothing: `f` works on a
+    /// copy of the context, which replaces it only once `f` succeeds and
+    /// the copy is saved.
+    pub(crate) fn with_context_atomic<R, E>(
+        &self,
+        id: &ContextId,
+        f: impl FnOnce(&mut Context, &mut EmitIndexes) -> Result<R, E>,
+    ) -> PlexusResult<Result<R, E>> {
+        let mut context = self.write_guard(id)?;
+        // Updated alongside the copy, so kept only if the copy is
+        let mut indexes = self.take_emit_indexes(id, &context);
+
+        let mut next = context.clone();
+        let result = match f(&mut next, &mut indexes) {
+            Ok(result) => result,
+            Err(e) => return Ok(Err(e)),
+        };
+        if let Some(ref store) = self.store {
+            store.save_context(&next)?;
+        }
+        *context = next;
+        self.emit_indexes.insert(id.clone(), indexes);
+        Ok(Ok(result))
+    }
+
+    /// The emit indexes of `context`, taken out of the engine for the
+    /// length of a write (built if there are none).
+    fn take_emit_indexes(&self, id: &ContextId, context: &Context) -> EmitIndexes {
+        match self.emit_indexes.remove(id) {
+            Some((_, indexes)) => indexes,
+            None => EmitIndexes::build(context),
+        }
+    }
+
+    /// Commit `emissions` to a context as one unit attributed to
+    /// `adapter_id`: one weight recompute, one save and one event batch,
+    /// and nothing at all if any emission is vetoed or the save fails.
+    /// For analyzers whose output covers many files at once.
+    pub fn apply_mutations(
+        &self,
+        context_id: &ContextId,
+        adapter_id: &str,
+        emissions: Vec<crate::adapter::Emission>,
+    ) -> Result<crate::adapter::EmitResult, crate::adapter::AdapterError> {
+        let framework = Some(crate::adapter::FrameworkContext {
+            adapter_id: adapter_id.to_string(),
+            context_id: context_id.to_string(),
+            input_summary: None,
+        });
+        crate::adapter::EngineSink::commit_batch(self, context_id, emissions, &framework)
+    }
+
+    /// Query events after the given cursor (ADR-035).
+    ///
+    /// Delegates to the store's `query_events_since`. Returns empty vec if no store.
+    pub fn query_events_since(
+        &self,
+        context_id: &str,
+        cursor: u64,
+        filter: Option<&crate::query::CursorFilter>,
+    ) -> PlexusResult<Vec<crate::query::PersistedEvent>> {
+        let Some(ref store) = self.store else {
+            return Ok(Vec::new());
+        };
+        store.query_events_since(context_id, cursor, filter)
+            .map_err(PlexusError::from)
+    }
+
+    /// Return the latest event sequence number for a context (ADR-035).
+    pub fn latest_sequence(&self, context_id: &str) -> PlexusResult<u64> {
+        let Some(ref store) = self.store else {
+            return Ok(0);
+        };
+        store.latest_sequence(context_id)
+            .map_err(PlexusError::from)
+    }
+
+    /// Change a context's configuration through `f`. Returns whether
+    /// anything changed: the metadata is compared before and after, and
+    /// only a change stamps `updated_at`, persists, and fires
+    /// `ContextMetadataChanged` for the fields that differ.
+    fn update_config(&self, id: &ContextId, f: impl FnOnce(&mut Context)) -> PlexusResult<bool> {
+        self.commit_change(id, |ctx| {
+            let before = ctx.metadata.clone();
+            f(ctx);
+            let keys = changed_metadata_fields(&before, &ctx.metadata);
+            if keys.is_empty() {
+                return Ok((false, Vec::new()));
+            }
+            let event = GraphEvent::ContextMetadataChanged {
+                keys,
+                adapter_id: "engine".to_string(),
+                context_id: id.to_string(),
+            };
+            Ok((true, vec![event]))
+        })
+    }
+
+    /// Register a mutation hook after those already registered, replacing
+    /// any hook with the same ID in place.
+    pub fn add_hook(&self, hook: Arc<dyn MutationHook>) {
+        let mut hooks = self.hooks.write().unwrap_or_else(|e| e.into_inner());
+        match hooks.iter_mut().find(|h| h.id() == hook.id()) {
+            Some(existing) => *existing = hook,
+            None => hooks.push(hook),
+        }
+    }
+
+    /// Unregister the hook `id`. Returns whether one was registered.
+    pub fn remove_hook(&self, id: &str) -> bool {
+        let mut hooks = self.hooks.write().unwrap_or_else(|e| e.into_inner());
+        let before = hooks.len();
+        hooks.retain(|h| h.id() != id);
+        hooks.len() < before
+    }
+
+    /// Register a closure run before each emission commits; see
+    /// `MutationHook::pre_commit`.
+    pub fn on_pre_commit(
+        &self,
+        id: impl Into<String>,
+        f: impl Fn(&Context, &mut crate::adapter::Emission) -> Result<(), String> + Send + Sync + 'static,
+    ) {
+        self.add_hook(Arc::new(FnHook::new(id).with_pre_commit(f)));
+    }
+
+    /// Register a closure run before each engine operation that isn't an
+    /// emission commits; see `MutationHook::pre_change`.
+    pub fn on_pre_change(
+        &self,
+        id: impl Into<String>,
+        f: impl Fn(&Context, &[crate::graph::events::GraphEvent]) -> Result<(), String> + Send + Sync + 'static,
+    ) {
+        self.add_hook(Arc::new(FnHook::new(id).with_pre_change(f)));
+    }
+
+    /// Register a closure run after each commit with its events.
+    pub fn on_post_commit(
+        &self,
+        id: impl Into<String>,
+        f: impl Fn(&[crate::graph::events::GraphEvent]) + Send + Sync + 'static,
+    ) {
+        self.add_hook(Arc::new(FnHook::new(id).with_post_commit(f)));
+    }
+
+    /// Run the pre-commit hooks over an emission about to commit to
+    /// `context`. Errors with `InvalidInput` naming the vetoing hook.
+    pub(crate) fn pre_commit(&self, context: &Context, emission: &mut crate::adapter::Emission) -> PlexusResult<()> {
+        super::hooks::run_pre_commit(&self.hook_list(), context, emission)
+    }
+
+    /// The registered hooks, in order.
+    fn hook_list(&self) -> Vec<Arc<dyn MutationHook>> {
+        self.hooks.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Persist graph events to the event log (ADR-035), after running the
+    /// post-commit hooks over them.
+    ///
+    /// Best-effort: logs and continues on failure. Event persistence should
+    /// not fail the emission — a missing event degrades the cursor but not
+    /// the graph.
+    pub fn persist_events(&self, events: &[crate::graph::events::GraphEvent]) {
+        super::hooks::run_post_commit(&self.hook_list(), events);
+        self.commit_reachability(events);
+        let Some(ref store) = self.store else { return };
+        for event in events {
+            let (context_id, event_type, node_ids, edge_ids, adapter_id) = match event {
+                crate::graph::events::GraphEvent::NodesAdded { node_ids, adapter_id, context_id } => {
+                    let nids: Vec<String> = node_ids.iter().map(|n| n.as_str().to_string()).collect();
+                    (context_id.as_str(), "NodesAdded", nids, vec![], adapter_id.as_str())
+                }
+                crate::graph::events::GraphEvent::EdgesAdded { edge_ids, adapter_id, context_id } => {
+                    let eids: Vec<String> = edge_ids.iter().map(|e| e.as_str().to_string()).collect();
+                    (context_id.as_str(), "EdgesAdded", vec![], eids, adapter_id.as_str())
+                }
+                crate::graph::events::GraphEvent::NodesRemoved { node_ids, adapter_id, context_id } => {
+                    let nids: Vec<String> = node_ids.iter().map(|n| n.as_str().to_string()).collect();
+                    (context_id.as_str(), "NodesRemoved", nids, vec![], adapter_id.as_str())
+                }
+                crate::graph::events::GraphEvent::EdgesRemoved { edge_ids, adapter_id, context_id, .. } => {
+                    let eids: Vec<String> = edge_ids.iter().map(|e| e.as_str().to_string()).collect();
+                    (context_id.as_str(), "EdgesRemoved", vec![], eids, adapter_id.as_str())
+                }
+                crate::graph::events::GraphEvent::WeightsChanged { edge_ids, adapter_id, context_id } => {
+                    let eids: Vec<String> = edge_ids.iter().map(|e| e.as_str().to_string()).collect();
+                    (context_id.as_str(), "WeightsChanged", vec![], eids, adapter_id.as_str())
+                }
+                crate::graph::events::GraphEvent::ContributionsRetracted { adapter_id, context_id, .. } => {
+                    (context_id.as_str(), "ContributionsRetracted", vec![], vec![], adapter_id.as_str())
+                }
+                crate::graph::events::GraphEvent::NodePropertiesChanged { changes, adapter_id, context_id } => {
+                    let nids: Vec<String> = changes.iter().map(|(n, _)| n.as_str().to_string()).collect();
+                    (context_id.as_str(), "NodePropertiesChanged", nids, vec![], adapter_id.as_str())
+                }
+                crate::graph::events::GraphEvent::EdgeAnnotated { edge_ids, adapter_id, context_id } => {
+                    let eids: Vec<String> = edge_ids.iter().map(|e| e.as_str().to_string()).collect();
+                    (context_id.as_str(), "EdgeAnnotated", vec![], eids, adapter_id.as_str())
+                }
+                crate::graph::events::GraphEvent::ContextMetadataChanged { adapter_id, context_id, .. } => {
+                    (context_id.as_str(), "ContextMetadataChanged", vec![], vec![], adapter_id.as_str())
+                }
+            };
+            let keys: Vec<String> = match event {
+                crate::graph::events::GraphEvent::NodePropertiesChanged { changes, .. } => {
+                    let keys: std::collections::BTreeSet<&String> = changes.iter().flat_map(|(_, keys)| keys).collect();
+                    keys.into_iter().cloned().collect()
+                }
+                crate::graph::events::GraphEvent::ContextMetadataChanged { keys, .. } => keys.clone(),
+                _ => Vec::new(),
+            };
+            if let Err(e) = store.persist_event(context_id, event_type, &node_ids, &edge_ids, &keys, adapter_id) {
+                tracing::warn!(error = %e, "failed to persist event to event log (best-effort)");
+            }
+        }
+    }
+
+    // === Spec Persistence (ADR-037) ===
+
+    /// Persist a loaded spec to the specs table.
+    pub fn persist_spec(&self, spec: &crate::storage::PersistedSpec) -> PlexusResult<()> {
+        let Some(ref store) = self.store else {
+            return Ok(());
+        };
+        store.persist_spec(spec).map_err(PlexusError::from)
+    }
+
+    /// Query all persisted specs for a context.
+    pub fn query_specs_for_context(&self, context_id: &str) -> PlexusResult<Vec<crate::storage::PersistedSpec>> {
+        let Some(ref store) = self.store else {
+            return Ok(Vec::new());
+        };
+        store.query_specs_for_context(context_id).map_err(PlexusError::from)
+    }
+
+    /// Delete a persisted spec.
+    pub fn delete_spec(&self, context_id: &str, adapter_id: &str) -> PlexusResult<bool> {
+        let Some(ref store) = self.store else {
+            return Ok(false);
+        };
+        store.delete_spec(context_id, adapter_id).map_err(PlexusError::from)
+    }
+
+    /// Retract all contributions from an adapter/enrichment (ADR-027).
+    ///
+    /// Removes the adapter's contribution slot from every edge in the context,
+    /// prunes zero-evidence edges, recomputes combined weights, and persists.
+    /// Returns graph events (ContributionsRetracted + EdgesRemoved for pruned edges).
+    ///
+    /// Bypasses the adapter pipeline intentionally — retraction is an engine-level
+    /// operation that reverses a prior adapter's effect without re-running the pipeline.
+    pub fn retract_contributions(
+        &self,
+        context_id: &ContextId,
+        adapter_id: &str,
+    ) -> PlexusResult<Vec<GraphEvent>> {
+        let mut context = self.loaded_mut(context_id)?;
+
+        let (edges_affected, pruned_ids) = context.retract_contributions(adapter_id);
+
         // Build events
         let mut events = Vec::new();
         events.push(GraphEvent::ContributionsRetracted {
@@ -339,139 +1445,914 @@ impl PlexusEngine {
             edges_affected,
         });
 
-        if !pruned_ids.is_empty() {
-            events.push(GraphEvent::EdgesRemoved {
-                edge_ids: pruned_ids,
-                adapter_id: adapter_id.to_string(),
-                context_id: context_id.as_str().to_string(),
-                reason: "retraction".to_string(),
-            });
-        }
+        if !pruned_ids.is_empty() {
+            events.push(GraphEvent::EdgesRemoved {
+                edge_ids: pruned_ids,
+                adapter_id: adapter_id.to_string(),
+                context_id: context_id.as_str().to_string(),
+                reason: "retraction".to_string(),
+            });
+        }
+
+        crate::query::maintain_views(&mut context, &events);
+        crate::query::maintain_closures(&mut context, &events);
+
+        // Persist after mutation
+        if let Some(ref store) = self.store {
+            store.save_context(&context)?;
+        }
+
+        Ok(events)
+    }
+
+    /// Remove the edges `policy` selects, emitting and persisting an
+    /// `EdgesRemoved` event (reason: the policy name). A dry run only
+    /// reports what would be removed.
+    pub fn prune(&self, context_id: &ContextId, policy: &PrunePolicy, dry_run: bool) -> PlexusResult<PruneReport> {
+        self.commit_change(context_id, |context| {
+            let edge_ids = context.prunable_edges(policy, Utc::now());
+            let report = PruneReport { policy: policy.name().to_string(), dry_run, edge_ids };
+            if dry_run || report.edge_ids.is_empty() {
+                return Ok((report, Vec::new()));
+            }
+
+            let pruned: HashSet<&EdgeId> = report.edge_ids.iter().collect();
+            context.edges.retain(|e| !pruned.contains(&e.id));
+            // Normalization is per contributor across all edges: the survivors' weights shift
+            context.recompute_combined_weights();
+            context.record_edge_fates(&report.edge_ids, EdgeFate::Pruned);
+
+            let events = vec![GraphEvent::EdgesRemoved {
+                edge_ids: report.edge_ids.clone(),
+                adapter_id: "prune".to_string(),
+                context_id: context_id.as_str().to_string(),
+                reason: policy.name().to_string(),
+            }];
+            Ok((report, events))
+        })
+    }
+
+    /// Write the part of a context `filter` selects to a read-only
+    /// bundle at `path` (SQLite, or JSON for `.json` paths), with private
+    /// properties stripped and a verification manifest beside it.
+    pub fn publish(
+        &self,
+        context_id: &ContextId,
+        filter: &PublishFilter,
+        path: impl AsRef<std::path::Path>,
+    ) -> PlexusResult<PublishManifest> {
+        let context = self.loaded(context_id)?;
+        super::publish::write_bundle(&context, filter, path.as_ref())
+    }
+
+    /// Reconcile every context with its copy in `other` (another
+    /// machine's store) and write the merged result to both sides; see
+    /// `merge_contexts` for the merge rules. Contexts only one side has
+    /// are copied to the other. A merged context is saved to `other`
+    /// before this engine commits it, and put back if the commit fails.
+    pub fn sync_with(&self, other: &dyn GraphStore) -> PlexusResult<SyncReport> {
+        let theirs = other.list_contexts()?;
+        let mut report = SyncReport::default();
+
+        let ours: Vec<ContextId> = self.contexts.iter().map(|c| c.key().clone()).collect();
+        for id in ours.iter().filter(|id| !theirs.contains(id)) {
+            if let Some(context) = self.get_context(id) {
+                other.save_context(&context)?;
+                report.pushed.push(context.name);
+            }
+        }
+
+        for id in theirs {
+            let Some(remote) = other.load_context(&id)? else { continue };
+            let name = remote.name.clone();
+            match self.merge_into(remote, "sync", Some(other))? {
+                Some(sync) => report.merged.push(sync),
+                None => report.pulled.push(name),
+            }
+        }
+        report.pushed.sort();
+        report.pulled.sort();
+        Ok(report)
+    }
+
+    /// Merge another copy of a context (or a partial copy holding only
+    /// what changed) into this engine's, emitting events attributed to
+    /// `adapter_id` for what changed here. A context this engine doesn't
+    /// have is adopted as is and returns `None`.
+    pub fn merge_copy(&self, remote: Context, adapter_id: &str) -> PlexusResult<Option<ContextSync>> {
+        self.merge_into(remote, adapter_id, None)
+    }
+
+    /// `merge_copy`, also saving the merged context to `mirror` (the
+    /// store `remote` came from) when both sides had it.
+    fn merge_into(
+        &self,
+        remote: Context,
+        adapter_id: &str,
+        mirror: Option<&dyn GraphStore>,
+    ) -> PlexusResult<Option<ContextSync>> {
+        self.hydrate(&remote.id)?;
+        if !self.contexts.contains_key(&remote.id) {
+            self.upsert_context(remote)?;
+            return Ok(None);
+        }
+        // Merged under the context's lock, so writes racing the merge
+        // aren't overwritten by a copy taken before them
+        let id = remote.id.clone();
+        let merge = |local: &mut Context| {
+            let (merged, conflicts) = merge_contexts(local, &remote);
+            check_context_quota(local, &merged)?;
+            let sync = super::sync::diff(local, &merged, conflicts);
+            let mut events = sync.events(id.as_str(), adapter_id);
+            let changes = property_changes(local, &merged);
+            if !changes.is_empty() {
+                events.push(GraphEvent::NodePropertiesChanged {
+                    changes,
+                    adapter_id: adapter_id.to_string(),
+                    context_id: id.as_str().to_string(),
+                });
+            }
+            let keys = changed_metadata_fields(&local.metadata, &merged.metadata);
+            if !keys.is_empty() {
+                events.push(GraphEvent::ContextMetadataChanged {
+                    keys,
+                    adapter_id: adapter_id.to_string(),
+                    context_id: id.as_str().to_string(),
+                });
+            }
+            *local = merged;
+            Ok((Some(sync), events))
+        };
+        match mirror {
+            Some(mirror) => self.commit_mirrored(&id, mirror, &remote, merge),
+            None => self.commit_change(&id, merge),
+        }
+    }
+
+    /// Check `data_version` and reload all contexts if the database
+    /// has been modified by another engine (ADR-017 §2).
+    ///
+    /// Returns `true` if a reload occurred, `false` if the cache was fresh.
+    pub fn reload_if_changed(&self) -> PlexusResult<bool> {
+        let Some(ref store) = self.store else {
+            return Ok(false);
+        };
+
+        let current = store.data_version()?;
+        let last = self.last_data_version.load(std::sync::atomic::Ordering::Acquire);
+
+        if current == last {
+            return Ok(false);
+        }
+
+        // Reload all contexts (or the scoped tenant's) from storage
+        let context_ids = match self.tenant_scope.get() {
+            Some(tenant) => store.list_contexts_for_tenant(Some(tenant))?,
+            None => store.list_contexts()?,
+        };
+        self.name_index.clear();
+        for id in &context_ids {
+            if let Some(context) = store.load_context(id)? {
+                self.name_index.insert(context.index_name(), id.clone());
+                self.install(id.clone(), context);
+            }
+        }
+
+        // Remove contexts that no longer exist in storage
+        let stored_ids: HashSet<ContextId> = context_ids.into_iter().collect();
+        self.contexts.retain(|id, _| stored_ids.contains(id));
+        self.emit_indexes.retain(|id, _| stored_ids.contains(id));
+        self.manifest.clear();
+
+        self.last_data_version.store(current, std::sync::atomic::Ordering::Release);
+        Ok(true)
+    }
+
+    /// Persist a specific context to storage
+    ///
+    /// Useful when modifying a context's contents (nodes/edges)
+    /// after retrieving it with `get_context`.
+    pub fn persist_context(&self, context: &Context) -> PlexusResult<()> {
+        if let Some(ref store) = self.store {
+            store.save_context(context)?;
+        }
+        Ok(())
+    }
+
+    /// Write a consistent snapshot of the store to `path`. Ingestion
+    /// can continue while the snapshot is taken.
+    pub fn backup(&self, path: impl AsRef<std::path::Path>) -> PlexusResult<()> {
+        let Some(ref store) = self.store else {
+            return Err(PlexusError::Unsupported("backup requires a persistent store".to_string()));
+        };
+        store.backup(path.as_ref())?;
+        Ok(())
+    }
+
+    /// A context's change journal after sequence `after`, oldest first.
+    pub fn journal(&self, id: &ContextId, after: u64) -> PlexusResult<Vec<JournalEntry>> {
+        Ok(self.require_journal()?.journal(id, after)?)
+    }
+
+    /// Journal a full snapshot of a context, so replay starts there.
+    /// Returns the snapshot's sequence number.
+    pub fn snapshot_context(&self, id: &ContextId) -> PlexusResult<u64> {
+        let journal = self.require_journal()?;
+        let context = self.loaded(id)?;
+        Ok(journal.snapshot(&context)?)
+    }
+
+    /// Snapshot a context and drop its journal entries before the
+    /// snapshot. History older than it can no longer be projected.
+    pub fn compact_journal(&self, id: &ContextId) -> PlexusResult<usize> {
+        if !self.has_context(id) {
+            return Err(PlexusError::ContextNotFound(id.clone()));
+        }
+        Ok(self.require_journal()?.compact_journal(id)?)
+    }
+
+    fn require_journal(&self) -> PlexusResult<&Arc<EventSourcedStore>> {
+        self.journal
+            .as_ref()
+            .ok_or_else(|| PlexusError::Unsupported("the change journal requires an event-sourced engine".to_string()))
+    }
+
+    /// Replace the store's contents with the snapshot at `path` and
+    /// reload every context from it.
+    pub fn restore(&self, path: impl AsRef<std::path::Path>) -> PlexusResult<usize> {
+        let Some(ref store) = self.store else {
+            return Err(PlexusError::Unsupported("restore requires a persistent store".to_string()));
+        };
+        store.restore(path.as_ref())?;
+        self.contexts.clear();
+        self.emit_indexes.clear();
+        self.name_index.clear();
+        self.load_all()
+    }
+
+    /// Bring soft-deleted nodes and edges back from a context's trash,
+    /// emitting `NodesAdded`/`EdgesAdded` for what was restored.
+    pub fn restore_deleted(&self, context_id: &ContextId, ids: &[String]) -> PlexusResult<RestoreReport> {
+        self.commit_change(context_id, |context| {
+            let report = context.restore_from_trash(ids);
+            let mut events = Vec::new();
+            if !report.nodes.is_empty() {
+                events.push(GraphEvent::NodesAdded {
+                    node_ids: report.nodes.clone(),
+                    adapter_id: "restore".to_string(),
+                    context_id: context_id.as_str().to_string(),
+                });
+            }
+            if !report.edges.is_empty() {
+                events.push(GraphEvent::EdgesAdded {
+                    edge_ids: report.edges.clone(),
+                    adapter_id: "restore".to_string(),
+                    context_id: context_id.as_str().to_string(),
+                });
+            }
+            Ok((report, events))
+        })
+    }
+
+    /// Mark `new` as the next version of `old` (a `supersedes` edge).
+    /// Later edges emitted against `old` land on `new`. Fails if either
+    /// node is missing, `old` is already superseded, or `new` is one of
+    /// `old`'s earlier versions.
+    pub fn supersede_node(&self, context_id: &ContextId, old: &NodeId, new: &NodeId) -> PlexusResult<EdgeId> {
+        self.commit_change(context_id, |context| {
+            for id in [old, new] {
+                if context.get_node(id).is_none() {
+                    return Err(PlexusError::NodeNotFound(id.to_string()));
+                }
+            }
+            if let Some(by) = context.version_successors().get(old) {
+                return Err(PlexusError::InvalidInput(format!("{} is already superseded by {}", old, by)));
+            }
+            if old == new || context.version_history(old).contains(new) {
+                return Err(PlexusError::InvalidInput(format!("{} superseding {} would form a version cycle", new, old)));
+            }
+
+            let edge_id = context.supersede_node(old, new).expect("both nodes checked above");
+            let events = vec![GraphEvent::EdgesAdded {
+                edge_ids: vec![edge_id.clone()],
+                adapter_id: "versioning".to_string(),
+                context_id: context_id.as_str().to_string(),
+            }];
+            Ok((edge_id, events))
+        })
+    }
+
+    /// Move everything a context records at the file or directory `old`
+    /// to `new` — sources, path properties, path-derived node IDs and the
+    /// edges on them — in one commit. See `Context::relocate_path`.
+    pub fn relocate_path(&self, context_id: &ContextId, old: &str, new: &str) -> PlexusResult<RelocationReport> {
+        let report = self.commit_change(context_id, |context| {
+            let report = context.relocate_path(old, new)?;
+            let mut events = Vec::new();
+            if !report.renamed.is_empty() {
+                events.push(GraphEvent::NodesRemoved {
+                    node_ids: report.renamed.iter().map(|(from, _)| from.clone()).collect(),
+                    adapter_id: "relocate".to_string(),
+                    context_id: context_id.as_str().to_string(),
+                });
+            }
+            let mut added: Vec<NodeId> = report.renamed.iter().map(|(_, to)| to.clone()).collect();
+            added.extend(report.updated.iter().filter(|id| !added.contains(id)).cloned().collect::<Vec<_>>());
+            if !added.is_empty() {
+                events.push(GraphEvent::NodesAdded {
+                    node_ids: added,
+                    adapter_id: "relocate".to_string(),
+                    context_id: context_id.as_str().to_string(),
+                });
+            }
+            if !report.changed_keys.is_empty() {
+                events.push(GraphEvent::NodePropertiesChanged {
+                    changes: report.changed_keys.clone(),
+                    adapter_id: "relocate".to_string(),
+                    context_id: context_id.as_str().to_string(),
+                });
+            }
+            // Trashed nodes aren't in the graph; their move is a change
+            // to the context's records all the same
+            let keys: Vec<String> = [(report.sources > 0, "sources"), (!report.trashed.is_empty(), "trash")]
+                .into_iter()
+                .filter(|(changed, _)| *changed)
+                .map(|(_, key)| key.to_string())
+                .collect();
+            if !keys.is_empty() {
+                events.push(GraphEvent::ContextMetadataChanged {
+                    keys,
+                    adapter_id: "relocate".to_string(),
+                    context_id: context_id.as_str().to_string(),
+                });
+            }
+            Ok((report, events))
+        })?;
+        self.rename_side_records(context_id, &report);
+        Ok(report)
+    }
+
+    /// Move embeddings and emission records to a relocation's new node
+    /// IDs. The relocation has committed, so a failure is only logged.
+    fn rename_side_records(&self, context_id: &ContextId, report: &RelocationReport) {
+        let renamed: Vec<(String, String)> = report.all_renames().map(|(from, to)| (from.to_string(), to.to_string())).collect();
+        if renamed.is_empty() {
+            return;
+        }
+        match self.store {
+            Some(ref store) => {
+                if let Err(e) = store.rename_nodes(context_id.as_str(), &renamed) {
+                    tracing::warn!(context = %context_id, error = %e, "failed to move records to relocated node IDs");
+                }
+            }
+            None => {
+                let renames: HashMap<&str, &str> = renamed.iter().map(|(from, to)| (from.as_str(), to.as_str())).collect();
+                let mut emissions = self.emissions.lock().unwrap_or_else(|e| e.into_inner());
+                for record in emissions.iter_mut().filter(|r| r.context_id == context_id.as_str()) {
+                    for node_id in &mut record.node_ids {
+                        if let Some(to) = renames.get(node_id.as_str()) {
+                            *node_id = to.to_string();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Permanently drop trashed items older than each context's
+    /// retention (`Context::trash_retention`), for one context or all.
+    /// Returns how many were purged.
+    pub fn purge_trash(&self, context_id: Option<&ContextId>) -> PlexusResult<usize> {
+        let ids = match context_id {
+            Some(id) => vec![id.clone()],
+            None => self.list_contexts(),
+        };
+        let now = Utc::now();
+        let mut purged = 0;
+        for id in ids {
+            let mut context = self.loaded_mut(&id)?;
+            let cutoff = now - context.trash_retention();
+            let count = context.purge_trash(cutoff);
+            if count > 0 {
+                if let Some(ref store) = self.store {
+                    store.save_context(&context)?;
+                }
+            }
+            purged += count;
+        }
+        Ok(purged)
+    }
+
+    /// Purge expired trash, then compact storage after retraction/decay
+    /// cycles (see `GraphStore::compact`), scoped to one context or all
+    /// of them.
+    ///
+    /// Contexts in scope are reloaded from the store afterwards so the
+    /// cache drops any dangling edges compaction removed.
+    pub fn maintain(&self, context_id: Option<&ContextId>) -> PlexusResult<CompactionReport> {
+        if let Some(id) = context_id {
+            if !self.has_context(id) {
+                return Err(PlexusError::ContextNotFound(id.clone()));
+            }
+        }
+        let trash_purged = self.purge_trash(context_id)?;
+        let Some(ref store) = self.store else {
+            return Ok(CompactionReport { trash_purged, ..Default::default() });
+        };
+
+        let mut report = store.compact(context_id)?;
+        report.trash_purged = trash_purged;
+        if report.dangling_edges > 0 {
+            let ids = match context_id {
+                Some(id) => vec![id.clone()],
+                None => self.list_contexts(),
+            };
+            for id in ids {
+                if let Some(context) = store.load_context(&id)? {
+                    self.install(id, context);
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    // === Context Metadata Operations ===
+
+    /// Rename a context
+    pub fn rename_context(&self, id: &ContextId, new_name: &str) -> PlexusResult<()> {
+        let mut context = self.loaded_mut(id)?;
+
+        let old_name = context.index_name();
+        context.name = new_name.to_string();
+        context.metadata.updated_at = Some(Utc::now());
+
+        // Update name index
+        self.name_index.remove(&old_name);
+        self.name_index.insert(context.index_name(), id.clone());
+
+        if let Some(ref store) = self.store {
+            store.save_context_metadata(&context)?;
+        }
+
+        Ok(())
+    }
+
+    /// Delete a context (alias for remove_context that returns bool)
+    pub fn delete_context(&self, id: &ContextId) -> PlexusResult<bool> {
+        Ok(self.remove_context(id)?.is_some())
+    }
+
+    /// Get a context's metadata
+    pub fn get_context_metadata(&self, id: &ContextId) -> Option<ContextMetadata> {
+        self.loaded_or_log(id).map(|r| r.metadata.clone())
+    }
+
+    /// Update a context's metadata
+    pub fn update_context_metadata(&self, id: &ContextId, metadata: ContextMetadata) -> PlexusResult<()> {
+        let mut context = self.loaded_mut(id)?;
+
+        let keys = changed_metadata_fields(&context.metadata, &metadata);
+        context.metadata = metadata;
+        context.metadata.updated_at = Some(Utc::now());
+
+        if let Some(ref store) = self.store {
+            store.save_context_metadata(&context)?;
+        }
+        drop(context);
+
+        if !keys.is_empty() {
+            self.persist_events(&[GraphEvent::ContextMetadataChanged {
+                keys,
+                adapter_id: "engine".to_string(),
+                context_id: id.to_string(),
+            }]);
+        }
+        Ok(())
+    }
+
+    /// Set a relationship's edge identity policy on a context and persist it.
+    ///
+    /// Switching to `EdgePolicy::Merge` folds existing parallel edges for
+    /// the relationship; returns the number folded.
+    pub fn set_edge_policy(
+        &self,
+        id: &ContextId,
+        relationship: &str,
+        policy: EdgePolicy,
+    ) -> PlexusResult<usize> {
+        let mut folded = 0;
+        self.update_config(id, |ctx| {
+            folded = ctx.set_edge_policy(relationship, policy);
+        })?;
+        Ok(folded)
+    }
+
+    /// The tag policy configured on a context, if any.
+    pub fn tag_policy(&self, id: &ContextId) -> Option<TagPolicy> {
+        self.loaded_or_log(id)?.metadata.tag_policy.clone()
+    }
+
+    /// Set (or clear, with `None`) a context's tag policy and persist it.
+    pub fn set_tag_policy(&self, id: &ContextId, policy: Option<TagPolicy>) -> PlexusResult<()> {
+        self.update_config(id, |ctx| {
+            ctx.metadata.tag_policy = policy;
+        })
+        .map(|_| ())
+    }
+
+    /// Set (or clear, with `None`) how promptly a context's writes reach
+    /// disk. Saved at the new level.
+    pub fn set_durability(&self, id: &ContextId, durability: Option<Durability>) -> PlexusResult<()> {
+        self.update_config(id, |ctx| {
+            ctx.metadata.durability = durability;
+        })
+        .map(|_| ())
+    }
+
+    /// The write scope of `adapter_id` on a context, if any.
+    pub fn write_scope(&self, id: &ContextId, adapter_id: &str) -> Option<WriteScope> {
+        self.loaded_or_log(id)?.metadata.write_scopes.get(adapter_id).cloned()
+    }
+
+    /// Set (or clear, with `None`) the write scope of `adapter_id` on a
+    /// context and persist it. Existing items aren't touched;
+    /// `Context::check_invariants` reports those out of scope.
+    pub fn set_write_scope(&self, id: &ContextId, adapter_id: &str, scope: Option<WriteScope>) -> PlexusResult<()> {
+        self.update_config(id, |ctx| {
+            match scope {
+                Some(scope) => ctx.metadata.write_scopes.insert(adapter_id.to_string(), scope),
+                None => ctx.metadata.write_scopes.remove(adapter_id),
+            };
+        })
+        .map(|_| ())
+    }
+
+    /// How the edges each enrichment derived on a context have fared:
+    /// confirmed by other contributors, still unconfirmed, or pruned or
+    /// retracted since.
+    pub fn enrichment_report(&self, id: &ContextId) -> PlexusResult<Vec<EnrichmentStats>> {
+        self.with_context(id, |ctx| ctx.enrichment_report())
+    }
+
+    /// A context's size limits, if any.
+    pub fn context_quota(&self, id: &ContextId) -> Option<ContextQuota> {
+        self.loaded_or_log(id)?.metadata.quota.clone()
+    }
+
+    /// A context's node and edge counts, without copying it.
+    pub fn context_size(&self, id: &ContextId) -> PlexusResult<(usize, usize)> {
+        let context = self.loaded(id)?;
+        Ok((context.node_count(), context.edge_count()))
+    }
+
+    /// Set (or clear, with `None`) a context's size limits and persist
+    /// them. A context already past a limit keeps what it has; further
+    /// growth is rejected. Fails with `InvalidInput` for a `warn_ratio`
+    /// outside 0..=1.
+    pub fn set_context_quota(&self, id: &ContextId, quota: Option<ContextQuota>) -> PlexusResult<()> {
+        if let Some(ref quota) = quota {
+            quota.validate().map_err(PlexusError::InvalidInput)?;
+        }
+        self.update_config(id, |ctx| {
+            ctx.metadata.quota = quota;
+        })
+        .map(|_| ())
+    }
+
+    /// The embedding model settings configured on a context, if any.
+    pub fn embedding_config(&self, id: &ContextId) -> Option<EmbeddingConfig> {
+        self.loaded_or_log(id)?.metadata.embedding.clone()
+    }
+
+    /// Set (or clear, with `None`) a context's embedding model settings and
+    /// persist them. Existing vectors and `similar_to` edges are left as
+    /// they are; `PlexusApi::context_set_embedding` re-embeds.
+    pub fn set_embedding_config(&self, id: &ContextId, config: Option<EmbeddingConfig>) -> PlexusResult<()> {
+        self.update_config(id, |ctx| {
+            ctx.metadata.embedding = config;
+        })
+        .map(|_| ())
+    }
+
+    /// The relationship ontology configured on a context, if any.
+    pub fn relationship_ontology(&self, id: &ContextId) -> Option<RelationshipOntology> {
+        self.loaded_or_log(id)?.metadata.relationship_ontology.clone()
+    }
+
+    /// Set (or clear, with `None`) a context's relationship ontology and
+    /// persist it. Existing edges aren't re-checked.
+    pub fn set_relationship_ontology(&self, id: &ContextId, ontology: Option<RelationshipOntology>) -> PlexusResult<()> {
+        self.update_config(id, |ctx| {
+            ctx.metadata.relationship_ontology = ontology;
+        })
+        .map(|_| ())
+    }
+
+    /// Turn contribution history on (with how slots aggregate it) or off.
+    /// History starts with the next contribution; turning it off keeps
+    /// existing logs and slots as they are.
+    pub fn set_contribution_history(
+        &self,
+        id: &ContextId,
+        aggregation: Option<ContributionAggregation>,
+    ) -> PlexusResult<()> {
+        self.update_config(id, |ctx| {
+            ctx.metadata.contribution_history = aggregation;
+        })
+        .map(|_| ())
+    }
+
+    /// Set how an adapter's repeated contributions accumulate on a
+    /// context (see `ContributionMode`) and persist it.
+    pub fn set_contribution_mode(&self, id: &ContextId, adapter_id: &str, mode: ContributionMode) -> PlexusResult<()> {
+        self.update_config(id, |ctx| {
+            ctx.set_contribution_mode(adapter_id, mode);
+        })
+        .map(|_| ())
+    }
+
+    // === Query Operations ===
+
+    /// Find nodes in a context matching the query criteria
+    pub fn find_nodes(&self, context_id: &ContextId, query: FindQuery) -> PlexusResult<QueryResult> {
+        let context = self.loaded(context_id)?;
+        Ok(query.execute(&context))
+    }
+
+    /// Count nodes matching a query (ignoring paging) without building a result
+    pub fn count_nodes(&self, context_id: &ContextId, query: &FindQuery) -> PlexusResult<usize> {
+        let context = self.loaded(context_id)?;
+        Ok(query.count(&context))
+    }
+
+    /// Count nodes reachable by a traversal without building a result
+    pub fn count_reachable(&self, context_id: &ContextId, query: &TraverseQuery) -> PlexusResult<usize> {
+        let context = self.loaded(context_id)?;
+        Ok(query.count_reachable(&context))
+    }
+
+    /// Traverse the graph from a starting node
+    pub fn traverse(&self, context_id: &ContextId, query: TraverseQuery) -> PlexusResult<TraversalResult> {
+        let context = self.loaded(context_id)?;
+        Ok(query.execute(&context))
+    }
+
+    /// Find a path between two nodes
+    pub fn find_path(&self, context_id: &ContextId, query: PathQuery) -> PlexusResult<PathResult> {
+        let context = self.loaded(context_id)?;
+        Ok(query.execute(&context))
+    }
+
+    /// The shortest path between two nodes, scored under `scoring`
+    pub fn find_scored_path(&self, context_id: &ContextId, query: PathQuery, scoring: PathScoring) -> PlexusResult<ScoredPath> {
+        let context = self.loaded(context_id)?;
+        Ok(query.execute(&context).scored(&context, scoring))
+    }
 
-        Ok(events)
+    /// Up to `limit` paths between two nodes, best score first
+    pub fn find_paths(&self, context_id: &ContextId, query: PathQuery, limit: usize, scoring: PathScoring) -> PlexusResult<Vec<ScoredPath>> {
+        let context = self.loaded(context_id)?;
+        Ok(query.execute_ranked(&context, limit, scoring))
     }
 
-    /// Check `data_version` and reload all contexts if the database
-    /// has been modified by another engine (ADR-017 §2).
-    ///
-    /// Returns `true` if a reload occurred, `false` if the cache was fresh.
-    pub fn reload_if_changed(&self) -> PlexusResult<bool> {
-        let Some(ref store) = self.store else {
-            return Ok(false);
-        };
+    /// Everything known about a concept (an ID or a label), with up to
+    /// `related_limit` related concepts
+    pub fn dossier(&self, context_id: &ContextId, concept: &str, related_limit: usize) -> PlexusResult<ConceptDossier> {
+        let context = self.loaded(context_id)?;
+        ConceptDossier::build(&context, concept, related_limit)
+            .ok_or_else(|| PlexusError::NodeNotFound(format!("no concept {}", concept)))
+    }
 
-        let current = store.data_version()?;
-        let last = self.last_data_version.load(std::sync::atomic::Ordering::Acquire);
+    /// Marks, fragments and structure nodes referencing the file at
+    /// `path`, with the concepts they connect to
+    pub fn backlinks(&self, context_id: &ContextId, path: &str) -> PlexusResult<Backlinks> {
+        let context = self.loaded(context_id)?;
+        Ok(Backlinks::collect(&context, path))
+    }
 
-        if current == last {
-            return Ok(false);
-        }
+    /// Tag vocabulary across all dimensions with usage statistics
+    pub fn vocabulary(&self, context_id: &ContextId) -> PlexusResult<Vec<TagStats>> {
+        let context = self.loaded(context_id)?;
+        Ok(crate::query::vocabulary(&context, Utc::now()))
+    }
 
-        // Reload all contexts from storage
-        let context_ids = store.list_contexts()?;
-        self.name_index.clear();
-        for id in &context_ids {
-            if let Some(context) = store.load_context(id)? {
-                self.name_index.insert(context.name.clone(), id.clone());
-                self.contexts.insert(id.clone(), context);
-            }
-        }
+    /// Degree, edge-weight, and contribution-count histograms
+    pub fn distributions(&self, context_id: &ContextId) -> PlexusResult<GraphDistributions> {
+        let context = self.loaded(context_id)?;
+        Ok(crate::query::distributions(&context))
+    }
 
-        // Remove contexts that no longer exist in storage
-        let stored_ids: HashSet<ContextId> = context_ids.into_iter().collect();
-        self.contexts.retain(|id, _| stored_ids.contains(id));
+    /// Nodes with at least `min_degree` edges — candidates for hub
+    /// dampening in traversal — highest degree first
+    pub fn super_nodes(&self, context_id: &ContextId, min_degree: usize) -> PlexusResult<Vec<NodeDegree>> {
+        let context = self.loaded(context_id)?;
+        Ok(crate::query::super_nodes(&context, min_degree))
+    }
 
-        self.last_data_version.store(current, std::sync::atomic::Ordering::Release);
-        Ok(true)
+    /// Store (or replace) a named query definition on a context.
+    pub fn save_query(&self, id: &ContextId, name: &str, query: SavedQuery) -> PlexusResult<()> {
+        self.update_config(id, |ctx| {
+            ctx.metadata.saved_queries.insert(name.to_string(), query);
+        })
+        .map(|_| ())
     }
 
-    /// Persist a specific context to storage
-    ///
-    /// Useful when modifying a context's contents (nodes/edges)
-    /// after retrieving it with `get_context`.
-    pub fn persist_context(&self, context: &Context) -> PlexusResult<()> {
-        if let Some(ref store) = self.store {
-            store.save_context(context)?;
-        }
-        Ok(())
+    /// Remove a named query. Returns whether it existed.
+    pub fn delete_saved_query(&self, id: &ContextId, name: &str) -> PlexusResult<bool> {
+        self.update_config(id, |ctx| {
+            ctx.metadata.saved_queries.remove(name);
+        })
     }
 
-    // === Context Metadata Operations ===
+    /// Named query definitions on a context, by name.
+    pub fn saved_queries(&self, id: &ContextId) -> PlexusResult<BTreeMap<String, SavedQuery>> {
+        let context = self.loaded(id)?;
+        Ok(context.metadata.saved_queries.clone())
+    }
 
-    /// Rename a context
-    pub fn rename_context(&self, id: &ContextId, new_name: &str) -> PlexusResult<()> {
-        let mut context = self.contexts.get_mut(id)
-            .ok_or_else(|| PlexusError::ContextNotFound(id.clone()))?;
+    /// Run a named query against the context's current graph.
+    pub fn run_saved_query(&self, id: &ContextId, name: &str) -> PlexusResult<SavedQueryResult> {
+        let context = self.loaded(id)?;
+        let query = context.metadata.saved_queries.get(name).ok_or_else(|| {
+            PlexusError::NotFound(format!("saved query '{}' not found", name))
+        })?;
+        Ok(query.execute(&context))
+    }
 
-        let old_name = context.name.clone();
-        context.name = new_name.to_string();
-        context.metadata.updated_at = Some(Utc::now());
+    /// Materialize (or replace) a named view: run `query` now and keep
+    /// its result maintained on every commit to the context.
+    pub fn materialize_view(&self, id: &ContextId, name: &str, query: SavedQuery) -> PlexusResult<()> {
+        self.update_config(id, |ctx| {
+            let view = MaterializedView::new(query, ctx);
+            ctx.metadata.materialized_views.insert(name.to_string(), view);
+        })
+        .map(|_| ())
+    }
 
-        // Update name index
-        self.name_index.remove(&old_name);
-        self.name_index.insert(new_name.to_string(), id.clone());
+    /// Remove a materialized view. Returns whether it existed.
+    pub fn drop_view(&self, id: &ContextId, name: &str) -> PlexusResult<bool> {
+        self.update_config(id, |ctx| {
+            ctx.metadata.materialized_views.remove(name);
+        })
+    }
 
-        if let Some(ref store) = self.store {
-            store.save_context_metadata(&context)?;
+    /// Read a materialized view. With `refresh_if_stale`, a stale view is
+    /// recomputed (and persisted) first; otherwise it's served as stored.
+    pub fn read_view(&self, id: &ContextId, name: &str, refresh_if_stale: bool) -> PlexusResult<ViewSnapshot> {
+        let not_found = || PlexusError::NotFound(format!("materialized view '{}' not found", name));
+        let stale = {
+            let context = self.loaded(id)?;
+            context.metadata.materialized_views.get(name).ok_or_else(not_found)?.stale
+        };
+        if stale && refresh_if_stale {
+            self.with_context_mut(id, |ctx| {
+                let mut views = std::mem::take(&mut ctx.metadata.materialized_views);
+                if let Some(view) = views.get_mut(name) {
+                    view.refresh(ctx);
+                }
+                ctx.metadata.materialized_views = views;
+            })?;
         }
+        let context = self.loaded(id)?;
+        let view = context.metadata.materialized_views.get(name).ok_or_else(not_found)?;
+        Ok(view.snapshot(name, &context))
+    }
 
-        Ok(())
+    /// Stage (or replace) a named overlay on a context. The context
+    /// itself is unchanged until the overlay is committed.
+    pub fn stage_overlay(&self, id: &ContextId, name: &str, overlay: Overlay) -> PlexusResult<()> {
+        {
+            let context = self.loaded(id)?;
+            overlay.validate(&context).map_err(|e| PlexusError::InvalidInput(format!("overlay '{}': {}", name, e)))?;
+        }
+        self.update_config(id, |ctx| {
+            ctx.metadata.overlays.insert(name.to_string(), overlay);
+        })
+        .map(|_| ())
     }
 
-    /// Delete a context (alias for remove_context that returns bool)
-    pub fn delete_context(&self, id: &ContextId) -> PlexusResult<bool> {
-        Ok(self.remove_context(id)?.is_some())
+    /// A context's staged overlays, by name.
+    pub fn overlays(&self, id: &ContextId) -> PlexusResult<BTreeMap<String, Overlay>> {
+        let context = self.loaded(id)?;
+        Ok(context.metadata.overlays.clone())
     }
 
-    /// Get a context's metadata
-    pub fn get_context_metadata(&self, id: &ContextId) -> Option<ContextMetadata> {
-        self.contexts.get(id).map(|r| r.metadata.clone())
+    /// The context as it would be with the named overlay committed, for
+    /// running queries against the hypothesis.
+    pub fn overlay_view(&self, id: &ContextId, name: &str) -> PlexusResult<Context> {
+        let context = self.loaded(id)?;
+        let overlay = context.metadata.overlays.get(name)
+            .ok_or_else(|| PlexusError::NotFound(format!("overlay '{}' not found", name)))?;
+        Ok(overlay.apply(&context))
     }
 
-    /// Update a context's metadata
-    pub fn update_context_metadata(&self, id: &ContextId, metadata: ContextMetadata) -> PlexusResult<()> {
-        let mut context = self.contexts.get_mut(id)
-            .ok_or_else(|| PlexusError::ContextNotFound(id.clone()))?;
+    /// Drop a staged overlay. Returns whether it existed.
+    pub fn discard_overlay(&self, id: &ContextId, name: &str) -> PlexusResult<bool> {
+        self.update_config(id, |ctx| {
+            ctx.metadata.overlays.remove(name);
+        })
+    }
 
-        context.metadata = metadata;
-        context.metadata.updated_at = Some(Utc::now());
+    /// Apply the named overlay to the context for real and drop it,
+    /// emitting graph events for what it changed. The overlay is applied
+    /// under the context's write lock, so no write lands in between.
+    pub fn commit_overlay(&self, id: &ContextId, name: &str) -> PlexusResult<ContextSync> {
+        self.commit_change(id, |ctx| {
+            let overlay = ctx.metadata.overlays.get(name)
+                .ok_or_else(|| PlexusError::NotFound(format!("overlay '{}' not found", name)))?;
+            let mut committed = overlay.apply(ctx);
+            check_context_quota(ctx, &committed)?;
+            ctx.metadata.overlays.remove(name);
+            committed.metadata.overlays.remove(name);
+            let sync = super::sync::diff(ctx, &committed, Vec::new());
+            let mut events = sync.events(id.as_str(), "overlay");
+            let changes = property_changes(ctx, &committed);
+            if !changes.is_empty() {
+                events.push(GraphEvent::NodePropertiesChanged {
+                    changes,
+                    adapter_id: "overlay".to_string(),
+                    context_id: id.as_str().to_string(),
+                });
+            }
+            *ctx = committed;
+            // Dropping the overlay is a change even when it changed nothing else
+            events.push(GraphEvent::ContextMetadataChanged {
+                keys: vec!["overlays".to_string()],
+                adapter_id: "overlay".to_string(),
+                context_id: id.as_str().to_string(),
+            });
+            Ok((sync, events))
+        })
+    }
 
-        if let Some(ref store) = self.store {
-            store.save_context_metadata(&context)?;
-        }
+    /// Maintain the transitive closure of `relationship` on a context:
+    /// compute it now and keep it current on every commit.
+    pub fn maintain_closure(&self, id: &ContextId, relationship: &str) -> PlexusResult<()> {
+        self.update_config(id, |ctx| {
+            let closure = TransitiveClosure::new(relationship, ctx);
+            ctx.metadata.closures.insert(relationship.to_string(), closure);
+        })
+        .map(|_| ())
+    }
 
-        Ok(())
+    /// Stop maintaining a closure. Returns whether it existed.
+    pub fn drop_closure(&self, id: &ContextId, relationship: &str) -> PlexusResult<bool> {
+        self.update_config(id, |ctx| {
+            ctx.metadata.closures.remove(relationship);
+        })
     }
 
-    // === Query Operations ===
+    /// Nodes `node` reaches through `relationship`, with depth, nearest
+    /// first. The closure must be maintained (`maintain_closure`).
+    pub fn descendants(&self, id: &ContextId, relationship: &str, node: &NodeId) -> PlexusResult<Vec<(NodeId, usize)>> {
+        self.closure(id, relationship, |c| c.descendants(node))
+    }
 
-    /// Find nodes in a context matching the query criteria
-    pub fn find_nodes(&self, context_id: &ContextId, query: FindQuery) -> PlexusResult<QueryResult> {
-        let context = self.contexts.get(context_id)
-            .ok_or_else(|| PlexusError::ContextNotFound(context_id.clone()))?;
-        Ok(query.execute(&context))
+    /// Nodes reaching `node` through `relationship`, with depth, nearest
+    /// first. The closure must be maintained (`maintain_closure`).
+    pub fn ancestors(&self, id: &ContextId, relationship: &str, node: &NodeId) -> PlexusResult<Vec<(NodeId, usize)>> {
+        self.closure(id, relationship, |c| c.ancestors(node))
     }
 
-    /// Traverse the graph from a starting node
-    pub fn traverse(&self, context_id: &ContextId, query: TraverseQuery) -> PlexusResult<TraversalResult> {
-        let context = self.contexts.get(context_id)
-            .ok_or_else(|| PlexusError::ContextNotFound(context_id.clone()))?;
-        Ok(query.execute(&context))
+    fn closure<T>(&self, id: &ContextId, relationship: &str, read: impl FnOnce(&TransitiveClosure) -> T) -> PlexusResult<T> {
+        let context = self.loaded(id)?;
+        let closure = context.metadata.closures.get(relationship).ok_or_else(|| {
+            PlexusError::NotFound(format!("no closure maintained for '{}'", relationship))
+        })?;
+        Ok(read(closure))
     }
 
-    /// Find a path between two nodes
-    pub fn find_path(&self, context_id: &ContextId, query: PathQuery) -> PlexusResult<PathResult> {
-        let context = self.contexts.get(context_id)
-            .ok_or_else(|| PlexusError::ContextNotFound(context_id.clone()))?;
-        Ok(query.execute(&context))
+    /// Whether `from` reaches `to` along edges passing `filter`. Answered
+    /// from an index per context and filter, built on the first check and
+    /// kept current from each commit's events.
+    pub fn is_reachable(&self, id: &ContextId, from: &NodeId, to: &NodeId, filter: &ReachabilityFilter) -> PlexusResult<bool> {
+        let context = self.loaded(id)?;
+        let mut cache = self.reachability.entry(id.clone()).or_default();
+        Ok(cache.is_reachable(&context, from, to, filter))
+    }
+
+    /// Apply committed events to the reachability indexes of their
+    /// contexts. A context or cache that's busy is skipped; its index
+    /// falls behind and rebuilds on the next check.
+    fn commit_reachability(&self, events: &[crate::graph::events::GraphEvent]) {
+        if self.reachability.is_empty() {
+            return;
+        }
+        let mut ids: Vec<ContextId> = events.iter().map(|e| ContextId::from_string(e.context_id())).collect();
+        ids.dedup();
+        for id in ids {
+            let Some(context) = self.contexts.try_get(&id).try_unwrap() else { continue };
+            let Some(mut cache) = self.reachability.try_get_mut(&id).try_unwrap() else { continue };
+            let own: Vec<_> = events.iter().filter(|e| e.context_id() == id.as_str()).cloned().collect();
+            cache.commit(&context, &own);
+        }
     }
 
     // === Source Management ===
 
     /// Add a source to a context
     pub fn add_source(&self, context_id: &ContextId, source: Source) -> PlexusResult<()> {
-        let mut context = self.contexts.get_mut(context_id)
-            .ok_or_else(|| PlexusError::ContextNotFound(context_id.clone()))?;
+        let mut context = self.loaded_mut(context_id)?;
 
         if !context.metadata.sources.contains(&source) {
             context.metadata.sources.push(source);
@@ -487,8 +2368,7 @@ impl PlexusEngine {
 
     /// Remove a source from a context. Returns true if the source was found and removed.
     pub fn remove_source(&self, context_id: &ContextId, source: &Source) -> PlexusResult<bool> {
-        let mut context = self.contexts.get_mut(context_id)
-            .ok_or_else(|| PlexusError::ContextNotFound(context_id.clone()))?;
+        let mut context = self.loaded_mut(context_id)?;
 
         let before = context.metadata.sources.len();
         context.metadata.sources.retain(|s| s != source);
@@ -506,56 +2386,147 @@ impl PlexusEngine {
 
     /// List all sources in a context
     pub fn list_sources(&self, context_id: &ContextId) -> PlexusResult<Vec<Source>> {
-        let context = self.contexts.get(context_id)
-            .ok_or_else(|| PlexusError::ContextNotFound(context_id.clone()))?;
+        let context = self.loaded(context_id)?;
         Ok(context.metadata.sources.clone())
     }
 
     // === Mutation Helpers ===
 
-    /// Add a node to a context (bypasses adapter pipeline).
+    /// Add a node to a context (bypasses adapter pipeline, but not the
+    /// mutation hooks).
     ///
     /// Used only in engine-level tests. Production writes go through
     /// `IngestPipeline::ingest()` → `EngineSink::emit()`.
     pub fn add_node(&self, context_id: &ContextId, node: super::node::Node) -> PlexusResult<NodeId> {
-        let mut context = self.contexts.get_mut(context_id)
-            .ok_or_else(|| PlexusError::ContextNotFound(context_id.clone()))?;
+        let mut id = node.id.clone();
+        let mut emission = crate::adapter::Emission::new().with_node(node);
+        let mut context = self.loaded_mut(context_id)?;
+        self.pre_commit(&context, &mut emission)?;
+        if let Some(ref quota) = context.metadata.quota {
+            for annotated in &emission.nodes {
+                quota.check_insert(&context, &annotated.node).map_err(|detail| PlexusError::ContextQuotaExceeded {
+                    context: context.name.clone(),
+                    detail,
+                })?;
+            }
+        }
 
-        let id = context.add_node(node);
+        let mut changes = Vec::new();
+        let mut added = Vec::new();
+        for annotated in emission.nodes {
+            // An upsert over an existing node may change its properties
+            let before = context.get_node(&annotated.node.id).map(|n| n.properties.clone());
+            if let Some(before) = before {
+                let keys = changed_keys(&before, &annotated.node.properties);
+                if !keys.is_empty() {
+                    changes.push((annotated.node.id.clone(), keys));
+                }
+            }
+            added.push(context.add_node(annotated.node));
+        }
+        if let Some(first) = added.first() {
+            id = first.clone();
+        }
 
         // Persist if storage configured
         if let Some(ref store) = self.store {
             store.save_context(&context)?;
         }
+        drop(context);
+        let mut events = vec![GraphEvent::NodesAdded {
+            node_ids: added,
+            adapter_id: "engine".to_string(),
+            context_id: context_id.to_string(),
+        }];
+        if !changes.is_empty() {
+            events.push(GraphEvent::NodePropertiesChanged {
+                changes,
+                adapter_id: "engine".to_string(),
+                context_id: context_id.to_string(),
+            });
+        }
+        self.persist_events(&events);
 
         Ok(id)
     }
 
-    /// Add an edge to a context (bypasses adapter pipeline).
+    /// Add an edge to a context (bypasses adapter pipeline, but not the
+    /// mutation hooks).
     ///
     /// Used only in engine-level tests. Production writes go through
     /// `IngestPipeline::ingest()` → `EngineSink::emit()`.
     pub fn add_edge(&self, context_id: &ContextId, edge: Edge) -> PlexusResult<()> {
-        let mut context = self.contexts.get_mut(context_id)
-            .ok_or_else(|| PlexusError::ContextNotFound(context_id.clone()))?;
-
-        context.add_edge(edge);
+        let mut emission = crate::adapter::Emission::new().with_edge(edge);
+        let mut context = self.loaded_mut(context_id)?;
+        self.pre_commit(&context, &mut emission)?;
+
+        let mut added = Vec::new();
+        for annotated in emission.edges {
+            added.push(annotated.edge.id.clone());
+            context.add_edge(annotated.edge);
+        }
         context.recompute_combined_weights();
 
         // Persist if storage configured
         if let Some(ref store) = self.store {
             store.save_context(&context)?;
         }
+        drop(context);
+        self.persist_events(&[GraphEvent::EdgesAdded {
+            edge_ids: added,
+            adapter_id: "engine".to_string(),
+            context_id: context_id.to_string(),
+        }]);
 
         Ok(())
     }
 
 }
 
+/// Fail if this thread is running a pre-commit hook, which holds a
+/// context lock that the caller would deadlock on.
+fn reject_reentry() -> PlexusResult<()> {
+    match super::hooks::in_pre_hook() {
+        true => Err(PlexusError::Other("pre-commit hooks can't call back into the engine".to_string())),
+        false => Ok(()),
+    }
+}
+
+/// Fail with `ContextQuotaExceeded` if replacing `before` with `after`
+/// grows the context past its quota.
+fn check_context_quota(before: &Context, after: &Context) -> PlexusResult<()> {
+    let Some(quota) = before.metadata.quota.as_ref().or(after.metadata.quota.as_ref()) else {
+        return Ok(());
+    };
+    quota
+        .check_growth(before, after)
+        .map_err(|detail| PlexusError::ContextQuotaExceeded { context: after.name.clone(), detail })
+}
+
+/// Top-level `ContextMetadata` fields that differ, by their serialized
+/// names; `updated_at` is ignored.
+fn changed_metadata_fields(before: &ContextMetadata, after: &ContextMetadata) -> Vec<String> {
+    let (Ok(serde_json::Value::Object(before)), Ok(serde_json::Value::Object(after))) =
+        (serde_json::to_value(before), serde_json::to_value(after))
+    else {
+        return Vec::new();
+    };
+    let mut keys: Vec<String> = before
+        .keys()
+        .chain(after.keys())
+        .filter(|key| key.as_str() != "updated_at" && before.get(*key) != after.get(*key))
+        .cloned()
+        .collect();
+    keys.sort();
+    keys.dedup();
+    keys
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::storage::{OpenStore, SqliteStore};
+    use crate::graph::{ContentType, Node, PropertyValue};
 
     #[test]
     fn test_create_engine() {
@@ -731,6 +2702,80 @@ mod tests {
         assert!(!result.levels.is_empty());
     }
 
+    // === Scenario: is_reachable stays correct across direct edge writes ===
+    #[test]
+    fn reachability_index_catches_up_with_direct_writes() {
+        use crate::graph::{ContentType, Edge, Node};
+
+        let engine = PlexusEngine::new();
+        let mut ctx = Context::new("test");
+        let id_a = ctx.add_node(Node::new("node", ContentType::Code));
+        let id_b = ctx.add_node(Node::new("node", ContentType::Code));
+        let id_c = ctx.add_node(Node::new("node", ContentType::Code));
+        ctx.add_edge(Edge::new(id_a.clone(), id_b.clone(), "calls"));
+        let ctx_id = engine.upsert_context(ctx).unwrap();
+
+        let calls = ReachabilityFilter::new().with_relationship("calls");
+        assert!(engine.is_reachable(&ctx_id, &id_a, &id_b, &calls).unwrap());
+        assert!(!engine.is_reachable(&ctx_id, &id_a, &id_c, &calls).unwrap());
+
+        engine.add_edge(&ctx_id, Edge::new(id_b.clone(), id_c.clone(), "calls")).unwrap();
+        assert!(engine.is_reachable(&ctx_id, &id_a, &id_c, &calls).unwrap());
+
+        // A write without events, swapping one edge for another, leaves
+        // the index behind; the next check rebuilds it
+        engine
+            .with_context_mut(&ctx_id, |ctx| {
+                ctx.edges.retain(|e| e.source != id_a);
+                ctx.add_edge(Edge::new(id_c.clone(), id_a.clone(), "calls"));
+            })
+            .unwrap();
+        assert!(!engine.is_reachable(&ctx_id, &id_a, &id_c, &calls).unwrap());
+        assert!(engine.is_reachable(&ctx_id, &id_b, &id_a, &calls).unwrap());
+        let missing = ContextId::from("missing");
+        assert!(matches!(engine.is_reachable(&missing, &id_a, &id_c, &calls), Err(PlexusError::ContextNotFound(_))));
+    }
+
+    // === Scenario: Emit indexes carry across emissions and drop on other writes ===
+    #[test]
+    fn emit_indexes_survive_emissions_and_drop_on_direct_writes() {
+        use crate::adapter::{AnnotatedNode, EngineSink, Emission};
+        use crate::graph::NATURAL_KEY_PROPERTY;
+
+        let engine = PlexusEngine::new();
+        let ctx_id = engine.upsert_context(Context::new("test")).unwrap();
+        let concept = |id: &str, key: &str| {
+            let mut node = Node::new("concept", ContentType::Concept).with_natural_key(key);
+            node.id = NodeId::from_string(id);
+            Emission::new().with_node(AnnotatedNode::new(node))
+        };
+        let emit = |emission: Emission| {
+            engine
+                .with_context_indexed(&ctx_id, |ctx, indexes| EngineSink::emit_indexed(ctx, indexes, emission, &None))
+                .unwrap()
+                .unwrap()
+        };
+
+        emit(concept("concept:travel", "travel"));
+        assert!(engine.emit_indexes.contains_key(&ctx_id), "kept after the emission");
+        emit(concept("concept:Travel", "travel"));
+        assert_eq!(engine.get_context(&ctx_id).unwrap().node_count(), 1, "second id upserts onto the first");
+
+        // A direct write re-keys the node; the next emission sees it
+        engine
+            .with_context_mut(&ctx_id, |ctx| {
+                let node = ctx.get_node_mut(&NodeId::from_string("concept:travel")).unwrap();
+                node.properties.insert(NATURAL_KEY_PROPERTY.to_string(), PropertyValue::String("journey".into()));
+            })
+            .unwrap();
+        assert!(!engine.emit_indexes.contains_key(&ctx_id), "dropped by the direct write");
+        emit(concept("concept:trip", "travel"));
+        emit(concept("concept:journey", "journey"));
+        let ctx = engine.get_context(&ctx_id).unwrap();
+        assert_eq!(ctx.node_count(), 2);
+        assert!(ctx.get_node(&NodeId::from_string("concept:trip")).is_some());
+    }
+
     #[test]
     fn test_find_path_via_engine() {
         use crate::graph::{ContentType, Edge, Node};
@@ -893,4 +2938,546 @@ mod tests {
         let reloaded = engine.reload_if_changed().unwrap();
         assert!(!reloaded, "should not reload when no external writes occurred");
     }
+
+    // === Scenario: Configuration changes fire ContextMetadataChanged ===
+    #[test]
+    fn configuration_changes_fire_metadata_events() {
+        let engine = PlexusEngine::new();
+        let id = engine.upsert_context(Context::new("configured")).unwrap();
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let log = seen.clone();
+        engine.on_post_commit("audit", move |events| {
+            for event in events {
+                if let GraphEvent::ContextMetadataChanged { keys, .. } = event {
+                    log.lock().unwrap().push(keys.clone());
+                }
+            }
+        });
+
+        engine.set_tag_policy(&id, Some(TagPolicy::new().with_stop("misc"))).unwrap();
+        engine.set_tag_policy(&id, Some(TagPolicy::new().with_stop("misc"))).unwrap();
+        assert!(!engine.drop_view(&id, "missing").unwrap());
+        let mut metadata = engine.get_context_metadata(&id).unwrap();
+        metadata.owner = Some("ana".into());
+        metadata.tags.push("bread".into());
+        engine.update_context_metadata(&id, metadata).unwrap();
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(
+            *seen,
+            vec![vec!["tag_policy".to_string()], vec!["owner".to_string(), "tags".to_string()]],
+            "re-setting a policy and dropping a missing view change nothing"
+        );
+    }
+
+    // === Scenario: Upserts log the property keys they changed ===
+    #[test]
+    fn upserts_persist_node_property_changes_with_their_keys() {
+        let engine = PlexusEngine::with_store(Arc::new(SqliteStore::open_in_memory().unwrap()));
+        let id = engine.upsert_context(Context::new("upserts")).unwrap();
+        let mut node = Node::new("concept", ContentType::Concept);
+        node.properties.insert("label".into(), PropertyValue::from("rye"));
+        engine.add_node(&id, node.clone()).unwrap();
+        engine.add_node(&id, node.clone()).unwrap();
+        node.properties.insert("label".into(), PropertyValue::from("spelt"));
+        node.properties.insert("origin".into(), PropertyValue::from("field"));
+        engine.add_node(&id, node.clone()).unwrap();
+
+        let filter = crate::query::CursorFilter {
+            event_types: Some(vec!["NodePropertiesChanged".into()]),
+            ..Default::default()
+        };
+        let changed = engine.query_events_since(id.as_str(), 0, Some(&filter)).unwrap();
+        assert_eq!(changed.len(), 1, "an identical upsert changes nothing");
+        assert_eq!(changed[0].node_ids, vec![node.id.to_string()]);
+        assert_eq!(changed[0].keys, vec!["label".to_string(), "origin".to_string()]);
+    }
+
+    // === Scenario: A warm start lists contexts before they hydrate ===
+    #[test]
+    fn warm_start_answers_from_the_manifest_and_hydrates_lazily() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("warm.db");
+        let mut ctx = Context::new("notes");
+        ctx.add_node(Node::new("concept", ContentType::Concept));
+        let id = ctx.id.clone();
+        {
+            let store = Arc::new(SqliteStore::open(&db_path).unwrap());
+            let writer = PlexusEngine::with_store(store);
+            writer.upsert_context(ctx).unwrap();
+            writer.upsert_context(Context::new("drafts")).unwrap();
+        }
+
+        let engine = PlexusEngine::with_store(Arc::new(SqliteStore::open(&db_path).unwrap()));
+        assert_eq!(engine.load_manifest().unwrap(), 2);
+        assert_eq!(engine.pending_hydration(), 2);
+        assert_eq!(engine.context_count(), 2);
+        assert!(engine.has_context(&id));
+        assert_eq!(engine.resolve_by_name("notes"), Some(id.clone()));
+        let notes = engine.context_manifest().into_iter().find(|m| m.id == id).unwrap();
+        assert_eq!(notes.node_count, 1, "counts come from the manifest");
+        assert_eq!(engine.pending_hydration(), 2, "listing hydrates nothing");
+
+        let loaded = engine.get_context(&id).expect("hydrated on first access");
+        assert_eq!(loaded.node_count(), 1);
+        assert_eq!(engine.pending_hydration(), 1);
+        assert!(!engine.hydrate(&id).unwrap(), "a context hydrates once");
+
+        assert_eq!(engine.hydrate_all().unwrap(), 1);
+        assert_eq!(engine.pending_hydration(), 0);
+        assert_eq!(engine.context_count(), 2);
+    }
+
+    // === Scenario: Every lookup hydrates a context only the manifest lists ===
+    #[test]
+    fn queries_writes_and_removals_hydrate_listed_contexts() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("lazy.db");
+        let (mut notes, mut drafts, mut scratch) = (Context::new("notes"), Context::new("drafts"), Context::new("scratch"));
+        notes.add_node(Node::new("concept", ContentType::Concept));
+        drafts.add_node(Node::new("concept", ContentType::Concept));
+        let (notes_id, drafts_id, scratch_id) = (notes.id.clone(), drafts.id.clone(), scratch.id.clone());
+        scratch.add_node(Node::new("concept", ContentType::Concept));
+        {
+            let writer = PlexusEngine::with_store(Arc::new(SqliteStore::open(&db_path).unwrap()));
+            for context in [notes, drafts, scratch] {
+                writer.upsert_context(context).unwrap();
+            }
+        }
+
+        let engine = PlexusEngine::with_store(Arc::new(SqliteStore::open(&db_path).unwrap()));
+        engine.load_manifest().unwrap();
+        assert_eq!(engine.count_nodes(&notes_id, &FindQuery::new()).unwrap(), 1, "queries see the stored graph");
+        engine.set_tag_policy(&drafts_id, Some(TagPolicy::new())).unwrap();
+        assert_eq!(engine.get_context(&drafts_id).unwrap().node_count(), 1, "a write keeps the stored graph");
+        assert!(engine.delete_context(&scratch_id).unwrap());
+        assert_eq!(engine.pending_hydration(), 0);
+        assert!(!engine.has_context(&scratch_id));
+        assert!(!engine.hydrate(&scratch_id).unwrap(), "a removed context doesn't come back");
+    }
+
+    #[test]
+    fn load_all_consolidates_legacy_duplicate_edges() {
+        let store = Arc::new(SqliteStore::open_in_memory().unwrap());
+
+        // A legacy store holding two rows for the same logical edge
+        let mut ctx = Context::new("legacy");
+        let id = ctx.id.clone();
+        let a = NodeId::from_string("a");
+        let b = NodeId::from_string("b");
+        let mut first = Edge::new(a.clone(), b.clone(), "related_to");
+        first.contributions.insert("adapter-1".to_string(), 1.0);
+        let mut second = Edge::new(a, b, "related_to");
+        second.contributions.insert("adapter-2".to_string(), 1.0);
+        ctx.edges.push(first);
+        ctx.edges.push(second);
+        store.save_context(&ctx).unwrap();
+
+        let engine = PlexusEngine::with_store(store.clone());
+        engine.load_all().unwrap();
+
+        let loaded = engine.get_context(&id).unwrap();
+        assert_eq!(loaded.edge_count(), 1);
+        assert_eq!(loaded.edges[0].contributions.len(), 2);
+        assert_eq!(store.load_context(&id).unwrap().unwrap().edge_count(), 1);
+    }
+
+    #[test]
+    fn maintain_drops_dangling_edges_and_reclaims_space() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = Arc::new(SqliteStore::open(dir.path().join("compact.db")).unwrap());
+        let engine = PlexusEngine::with_store(store.clone());
+
+        let mut ctx = Context::new("kept");
+        let a = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let b = ctx.add_node(Node::new("concept", ContentType::Concept));
+        ctx.add_edge(Edge::new(a.clone(), b, "related_to"));
+        ctx.edges.push(Edge::new(a, NodeId::from_string("ghost"), "related_to"));
+        let kept = engine.upsert_context(ctx).unwrap();
+
+        let mut bulk = Context::new("bulk");
+        for i in 0..500 {
+            bulk.add_node(
+                Node::new("fragment", ContentType::Document)
+                    .with_property("text", PropertyValue::from("x".repeat(200 + i % 7))),
+            );
+        }
+        let bulk = engine.upsert_context(bulk).unwrap();
+        engine.delete_context(&bulk).unwrap();
+
+        let report = engine.maintain(Some(&kept)).unwrap();
+
+        assert_eq!(report.dangling_edges, 1);
+        assert!(report.bytes_reclaimed() > 0, "{:?}", report);
+        assert_eq!(engine.get_context(&kept).unwrap().edge_count(), 1);
+        assert_eq!(store.load_context(&kept).unwrap().unwrap().edge_count(), 1);
+        assert!(engine.maintain(Some(&bulk)).is_err());
+    }
+
+    #[test]
+    fn prune_removes_selected_edges_and_logs_the_event() {
+        let store = Arc::new(SqliteStore::open_in_memory().unwrap());
+        let engine = PlexusEngine::with_store(store.clone());
+        let mut ctx = Context::new("derived");
+        let a = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let b = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let mut weak = Edge::new(a.clone(), b.clone(), "may_be_related");
+        weak.contributions.insert("cooccurrence".into(), -4.0);
+        weak.combined_weight = -1.0;
+        ctx.add_edge(weak);
+        let mut kept = Edge::new(b, a, "related_to");
+        kept.contributions.insert("cooccurrence".into(), 2.0);
+        kept.combined_weight = 0.5;
+        ctx.add_edge(kept);
+        let id = engine.upsert_context(ctx).unwrap();
+        let policy = PrunePolicy::WeightBelow { threshold: 0.1 };
+
+        let preview = engine.prune(&id, &policy, true).unwrap();
+        assert!(preview.dry_run);
+        assert_eq!(preview.edge_ids.len(), 1);
+        assert_eq!(engine.get_context(&id).unwrap().edge_count(), 2, "dry run removes nothing");
+        assert_eq!(engine.latest_sequence(id.as_str()).unwrap(), 0);
+
+        let report = engine.prune(&id, &policy, false).unwrap();
+        assert_eq!(report.edge_ids, preview.edge_ids);
+        let pruned = engine.get_context(&id).unwrap();
+        assert_eq!(pruned.edge_count(), 1);
+        assert_eq!(pruned.edges[0].combined_weight, 1.0, "survivors renormalize without the pruned evidence");
+        assert_eq!(store.load_context(&id).unwrap().unwrap().edge_count(), 1);
+        let events = engine.query_events_since(id.as_str(), 0, None).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, "EdgesRemoved");
+        assert_eq!(events[0].edge_ids, vec![report.edge_ids[0].as_str().to_string()]);
+    }
+
+    #[test]
+    fn apply_mutations_commits_all_or_nothing() {
+        use crate::adapter::Emission;
+
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("batch.db");
+        let store = Arc::new(SqliteStore::open(&db_path).unwrap());
+        let engine = PlexusEngine::with_store(store);
+        let id = engine.upsert_context(Context::new("analysis")).unwrap();
+        let file = |name: &str| {
+            let mut node = Node::new("file", ContentType::Document);
+            node.id = NodeId::from_string(name);
+            node
+        };
+
+        let result = engine
+            .apply_mutations(&id, "analyzer", vec![Emission::new().with_node(file("a.rs")), Emission::new().with_node(file("b.rs"))])
+            .unwrap();
+        assert_eq!(result.nodes_committed, 2);
+        assert_eq!(engine.query_events_since(id.as_str(), 0, None).unwrap().len(), 2);
+
+        // A save that fails leaves the context as it was
+        rusqlite::Connection::open(&db_path).unwrap().execute_batch("DROP TABLE nodes").unwrap();
+        let failed = engine.apply_mutations(&id, "analyzer", vec![Emission::new().with_node(file("c.rs"))]);
+        assert!(failed.is_err());
+        assert_eq!(engine.get_context(&id).unwrap().node_count(), 2);
+    }
+
+    #[test]
+    fn engine_commits_held_saves_in_the_background() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("deferred.db");
+        let store = Arc::new(SqliteStore::open(&db_path).unwrap());
+        let other = SqliteStore::open(&db_path).unwrap();
+        let engine = PlexusEngine::with_store(store.clone());
+        let mut ctx = Context::new("batched");
+        ctx.metadata.durability = Some(Durability::Batched { interval_ms: 10 });
+        engine.upsert_context(ctx.clone()).unwrap();
+        assert_eq!(store.deferred_contexts(), 1);
+
+        std::thread::sleep(DEFERRED_FLUSH_TICK * 3);
+        assert_eq!(store.deferred_contexts(), 0, "no caller had to flush");
+        assert!(other.list_contexts().unwrap().contains(&ctx.id));
+    }
+
+    #[test]
+    fn backup_snapshot_restores_earlier_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let snapshot = dir.path().join("snapshot.db");
+        let store = Arc::new(SqliteStore::open(dir.path().join("live.db")).unwrap());
+        let engine = PlexusEngine::with_store(store);
+        let id = engine.upsert_context(Context::new("journal")).unwrap();
+        engine.add_node(&id, Node::new("fragment", ContentType::Document)).unwrap();
+
+        engine.backup(&snapshot).unwrap();
+        engine.add_node(&id, Node::new("fragment", ContentType::Document)).unwrap();
+        engine.upsert_context(Context::new("later")).unwrap();
+
+        assert_eq!(engine.restore(&snapshot).unwrap(), 1);
+        assert_eq!(engine.get_context(&id).unwrap().node_count(), 1);
+        assert!(engine.resolve_by_name("later").is_none());
+
+        // A snapshot also seeds a fresh engine
+        let other = PlexusEngine::with_store(Arc::new(SqliteStore::open_in_memory().unwrap()));
+        other.restore(&snapshot).unwrap();
+        assert_eq!(other.resolve_by_name("journal"), Some(id));
+        assert!(PlexusEngine::new().backup(&snapshot).is_err());
+    }
+
+    #[test]
+    fn set_edge_policy_persists() {
+        let store = Arc::new(SqliteStore::open_in_memory().unwrap());
+        let engine = PlexusEngine::with_store(store.clone());
+        let id = engine.upsert_context(Context::new("policies")).unwrap();
+
+        engine.set_edge_policy(&id, "cites", EdgePolicy::Parallel).unwrap();
+
+        let stored = store.load_context(&id).unwrap().unwrap();
+        assert_eq!(stored.edge_policy("cites"), EdgePolicy::Parallel);
+        assert_eq!(stored.edge_policy("related_to"), EdgePolicy::Merge);
+    }
+
+    // === Scenario: A tenant-scoped engine loads and reloads only its tenant ===
+    #[test]
+    fn load_tenant_caches_only_that_tenant() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = dir.path().join("tenants.db");
+        let writer = PlexusEngine::with_store(Arc::new(SqliteStore::open(&db).unwrap()));
+        let acme = writer.upsert_context(Context::new("notes").with_tenant("acme")).unwrap();
+        writer.upsert_context(Context::new("notes").with_tenant("globex")).unwrap();
+        writer.upsert_context(Context::new("notes")).unwrap();
+
+        let engine = PlexusEngine::with_store(Arc::new(SqliteStore::open(&db).unwrap()));
+        assert_eq!(engine.load_tenant("acme").unwrap(), 1);
+        assert_eq!(engine.resolve_in_tenant(Some("acme"), "notes"), Some(acme.clone()));
+        assert!(engine.resolve_by_name("notes").is_none());
+        assert!(engine.load_tenant("globex").is_err());
+
+        writer.upsert_context(Context::new("journal").with_tenant("acme")).unwrap();
+        writer.upsert_context(Context::new("journal").with_tenant("globex")).unwrap();
+        assert!(engine.reload_if_changed().unwrap());
+        assert_eq!(engine.list_contexts_in_tenant(Some("acme")).len(), 2);
+        assert_eq!(engine.context_count(), 2, "other tenants stay out of the cache");
+
+        engine.set_tenant_quota("acme", Some(TenantQuota { max_contexts: None, max_nodes: None, max_edges: Some(0) }));
+        assert!(matches!(engine.check_tenant_quota("acme"), Err(PlexusError::QuotaExceeded { .. })));
+        engine.set_tenant_quota("acme", None);
+        assert!(engine.check_tenant_quota("acme").is_ok());
+    }
+
+    // === Scenario: Engine writes outside the sink respect the context quota ===
+    #[test]
+    fn engine_writes_fail_past_the_context_quota() {
+        use crate::graph::{ContextQuota, Overlay};
+
+        let engine = PlexusEngine::new();
+        let id = engine.upsert_context(Context::new("small")).unwrap();
+        let bad = ContextQuota::new().with_max_nodes(2).with_warn_ratio(1.5);
+        assert!(matches!(engine.set_context_quota(&id, Some(bad)), Err(PlexusError::InvalidInput(_))));
+        assert!(engine.set_context_quota(&id, Some(ContextQuota::new().with_warn_ratio(f64::NAN))).is_err());
+        engine.set_context_quota(&id, Some(ContextQuota::new().with_max_nodes(2))).unwrap();
+
+        let first = engine.add_node(&id, Node::new("note", ContentType::Document)).unwrap();
+        engine.add_node(&id, Node::new("note", ContentType::Document)).unwrap();
+        let over = engine.add_node(&id, Node::new("note", ContentType::Document));
+        assert!(matches!(over, Err(PlexusError::ContextQuotaExceeded { .. })), "{over:?}");
+        let mut again = Node::new("note", ContentType::Document);
+        again.id = first;
+        assert!(engine.add_node(&id, again).is_ok(), "an upsert isn't growth");
+
+        let mut grown = engine.get_context(&id).unwrap();
+        grown.add_node(Node::new("note", ContentType::Document));
+        assert!(matches!(engine.upsert_context(grown.clone()), Err(PlexusError::ContextQuotaExceeded { .. })));
+        assert!(matches!(engine.merge_copy(grown, "sync"), Err(PlexusError::ContextQuotaExceeded { .. })));
+
+        engine.stage_overlay(&id, "more", Overlay::new().with_node(Node::new("note", ContentType::Document))).unwrap();
+        assert!(matches!(engine.commit_overlay(&id, "more"), Err(PlexusError::ContextQuotaExceeded { .. })));
+        assert_eq!(engine.overlays(&id).unwrap().len(), 1, "a rejected overlay stays staged");
+        assert_eq!(engine.get_context(&id).unwrap().node_count(), 2);
+    }
+
+    // === Scenario: Querying a context as of an earlier time ===
+    #[test]
+    fn context_as_of_rewinds_through_the_event_log() {
+        use crate::query::FindQuery;
+
+        let dir = tempfile::tempdir().unwrap();
+        let engine = PlexusEngine::with_store(Arc::new(SqliteStore::open(dir.path().join("history.db")).unwrap()));
+        let id = engine.upsert_context(Context::new("research")).unwrap();
+        let added = |node: Node| {
+            let node_id = engine.add_node(&id, node).unwrap();
+            engine.persist_events(&[GraphEvent::NodesAdded {
+                node_ids: vec![node_id.clone()],
+                adapter_id: "test".into(),
+                context_id: id.to_string(),
+            }]);
+            node_id
+        };
+        let early = added(Node::new("concept", ContentType::Concept));
+        let doomed = added(Node::new("concept", ContentType::Concept));
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        let cutoff = chrono::Utc::now();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        added(Node::new("concept", ContentType::Concept));
+        engine.with_context_mut(&id, |ctx| ctx.nodes.remove(&doomed)).unwrap();
+        engine.persist_events(&[GraphEvent::NodesRemoved {
+            node_ids: vec![doomed.clone()],
+            adapter_id: "test".into(),
+            context_id: id.to_string(),
+        }]);
+
+        let view = engine.context_as_of(&id, cutoff).unwrap();
+        let concepts = FindQuery::new().with_node_type("concept");
+        assert_eq!(concepts.against(&view).total_count, 1);
+        assert!(view.get_node(&early).is_some());
+        assert_eq!(view.unrecoverable_nodes(), &[doomed]);
+        assert_eq!(concepts.execute(&engine.get_context(&id).unwrap()).total_count, 2);
+        assert!(matches!(
+            engine.context_as_of(&ContextId::from_string("missing"), cutoff),
+            Err(PlexusError::ContextNotFound(_))
+        ));
+    }
+
+    // === Scenario: Retraction pruning can be undone from the trash ===
+    #[test]
+    fn retracted_edges_are_restorable() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = dir.path().join("trash.db");
+        let engine = PlexusEngine::with_store(Arc::new(SqliteStore::open(&db).unwrap()));
+        let id = engine.upsert_context(Context::new("research")).unwrap();
+        let a = engine.add_node(&id, Node::new("concept", ContentType::Concept)).unwrap();
+        let b = engine.add_node(&id, Node::new("concept", ContentType::Concept)).unwrap();
+        let edge = Edge::new(a, b, "related_to").with_contribution("scan", 1.0);
+        let edge_id = edge.id.clone();
+        engine.add_edge(&id, edge).unwrap();
+
+        engine.retract_contributions(&id, "scan").unwrap();
+        assert_eq!(engine.get_context(&id).unwrap().edge_count(), 0);
+
+        let report = engine.restore_deleted(&id, &[edge_id.to_string()]).unwrap();
+        assert_eq!(report.edges, vec![edge_id.clone()]);
+        let reloaded = PlexusEngine::with_store(Arc::new(SqliteStore::open(&db).unwrap()));
+        reloaded.load_all().unwrap();
+        assert_eq!(reloaded.get_context(&id).unwrap().edge_count(), 1, "the restore was persisted");
+
+        // Past retention, maintenance purges for good
+        engine.retract_contributions(&id, "scan").unwrap();
+        engine.with_context_mut(&id, |ctx| ctx.metadata.trash_retention_days = Some(0)).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        assert_eq!(engine.maintain(Some(&id)).unwrap().trash_purged, 1);
+        assert_eq!(engine.restore_deleted(&id, &[edge_id.to_string()]).unwrap().skipped, vec![edge_id.to_string()]);
+    }
+
+    // === Scenario: A regenerated section takes over its predecessor's edges ===
+    #[tokio::test]
+    async fn superseded_nodes_redirect_edges_and_resolve_in_queries() {
+        use crate::adapter::{AdapterSink, AnnotatedEdge, Emission, EngineSink, FrameworkContext};
+
+        let engine = Arc::new(PlexusEngine::new());
+        let id = engine.upsert_context(Context::new("docs")).unwrap();
+        let mut old = Node::new("section", ContentType::Document);
+        old.id = NodeId::from_string("section:intro:v1");
+        let mut new = Node::new("section", ContentType::Document);
+        new.id = NodeId::from_string("section:intro:v2");
+        let old = engine.add_node(&id, old).unwrap();
+        let new = engine.add_node(&id, new).unwrap();
+        let topic = engine.add_node(&id, Node::new("concept", ContentType::Concept)).unwrap();
+
+        engine.supersede_node(&id, &old, &new).unwrap();
+        assert!(engine.supersede_node(&id, &old, &new).is_err(), "already superseded");
+        assert!(engine.supersede_node(&id, &new, &old).is_err(), "would form a cycle");
+        assert!(matches!(
+            engine.supersede_node(&id, &new, &"section:missing".into()),
+            Err(PlexusError::NodeNotFound(_))
+        ));
+
+        let sink = EngineSink::for_engine(engine.clone(), id.clone()).with_framework_context(FrameworkContext {
+            adapter_id: "outline".to_string(),
+            context_id: id.to_string(),
+            input_summary: None,
+        });
+        let edge = Edge::new(old.clone(), topic.clone(), "discusses");
+        sink.emit(Emission::new().with_edge(AnnotatedEdge::new(edge))).await.unwrap();
+
+        let ctx = engine.get_context(&id).unwrap();
+        let discusses = ctx.edges().find(|e| e.relationship == "discusses").unwrap();
+        assert_eq!(discusses.source, new, "redirected to the head version");
+
+        let plain = FindQuery::new().with_node_type("section");
+        assert_eq!(plain.execute(&ctx).total_count, 2);
+        let heads = plain.resolve_to_head().execute(&ctx);
+        assert_eq!(heads.nodes.iter().map(|n| n.id.clone()).collect::<Vec<_>>(), vec![new.clone()]);
+
+        let reach = TraverseQuery::from(old.clone()).with_relationship("discusses");
+        assert_eq!(reach.execute(&ctx).at_depth(1).len(), 0);
+        let reach = reach.resolve_to_head();
+        assert_eq!(reach.execute(&ctx).origin, new);
+        assert_eq!(reach.count_reachable(&ctx), 1);
+    }
+
+    // === Scenario: Emission records answer what an adapter added, and when ===
+    #[tokio::test]
+    async fn emissions_record_what_each_adapter_committed() {
+        use crate::adapter::{AdapterSink, AnnotatedEdge, Emission, EngineSink, FrameworkContext};
+
+        let store = Arc::new(SqliteStore::open_in_memory().unwrap());
+        let engine = Arc::new(PlexusEngine::with_store(store));
+        let id = engine.upsert_context(Context::new("notes")).unwrap();
+        let sink = |adapter: &str| {
+            EngineSink::for_engine(engine.clone(), id.clone()).with_framework_context(FrameworkContext {
+                adapter_id: adapter.to_string(),
+                context_id: id.to_string(),
+                input_summary: Some("notes/bread.md".to_string()),
+            })
+        };
+        let concept = |name: &str| {
+            let mut node = Node::new("concept", ContentType::Concept);
+            node.id = NodeId::from_string(name);
+            node
+        };
+
+        let before = Utc::now();
+        sink("llm-extract")
+            .emit(
+                Emission::new()
+                    .with_node(concept("concept:rye"))
+                    .with_node(concept("concept:spelt"))
+                    .with_edge(AnnotatedEdge::new(Edge::new("concept:rye".into(), "concept:spelt".into(), "may_be_related"))),
+            )
+            .await
+            .unwrap();
+        sink("content").emit(Emission::new().with_node(concept("concept:oven"))).await.unwrap();
+        sink("content").emit(Emission::new()).await.unwrap();
+
+        let extracted = engine.emissions(&id, &EmissionFilter::new().with_adapter("llm-extract").since(before)).unwrap();
+        assert_eq!(extracted.len(), 1);
+        assert_eq!(extracted[0].node_ids, vec!["concept:rye", "concept:spelt"]);
+        assert_eq!(extracted[0].edge_ids.len(), 1);
+        assert_eq!(extracted[0].input_summary.as_deref(), Some("notes/bread.md"));
+        assert_eq!(engine.emissions(&id, &EmissionFilter::new()).unwrap().len(), 2, "empty emissions leave no record");
+        assert!(engine.emissions(&id, &EmissionFilter::new().until(before)).unwrap().is_empty());
+
+        // Updates are recorded too
+        let mut proofed = concept("concept:oven");
+        proofed.properties.insert("heat".into(), PropertyValue::Int(230));
+        sink("content").emit(Emission::new().with_node(proofed)).await.unwrap();
+        let updates = engine.emissions(&id, &EmissionFilter::new().with_adapter("content")).unwrap();
+        assert_eq!(updates.len(), 2);
+        assert_eq!(updates[1].node_ids, vec!["concept:oven"]);
+
+        engine.delete_context(&id).unwrap();
+        assert!(engine.emissions(&id, &EmissionFilter::new()).unwrap().is_empty(), "records go with their context");
+    }
+
+    // === Scenario: The in-memory outbound queue keeps only the newest events ===
+    #[test]
+    fn outbound_queue_drops_the_oldest_past_its_bound() {
+        let engine = PlexusEngine::new();
+        let (a, b) = (ContextId::from("a"), ContextId::from("b"));
+        engine.enqueue_outbound(&b, "other", "").unwrap();
+        for _ in 0..MAX_OUTBOUND_EVENTS + 2 {
+            engine.enqueue_outbound(&a, "tick", "").unwrap();
+        }
+        assert_eq!(engine.prune_outbound(&a).unwrap(), 2);
+        let kept = engine.outbound_since(&a, 0, None).unwrap();
+        assert_eq!(kept.len(), MAX_OUTBOUND_EVENTS);
+        assert_eq!(kept[0].offset, 4, "the two oldest of a's events are gone");
+        assert_eq!(engine.outbound_since(&b, 0, None).unwrap().len(), 1);
+        assert_eq!(engine.enqueue_outbound(&a, "tick", "").unwrap(), MAX_OUTBOUND_EVENTS as u64 + 4);
+    }
 }
@@ -0,0 +1,143 @@
+//! Enrichment effectiveness: what became of the edges enrichments derive
+//!
+//! The enrichment loop records each edge an enrichment (lenses included)
+//! creates on `ContextMetadata::derived_edges`, leaving the edge itself
+//! untouched. Live derived edges count as confirmed once another
+//! contributor has reinforced them; derived edges removed later — by a
+//! prune policy, an explicit removal, a cascade, an overlay or any other
+//! trip to the trash — are tallied on `ContextMetadata::enrichment_outcomes`
+//! as pruned or retracted, since the removed edges themselves don't stay
+//! in the graph. `PlexusEngine::enrichment_report` puts the two together
+//! per enrichment.
+
+use super::context::Context;
+use super::edge::EdgeId;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Removed derived edges of one enrichment, by how they went.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnrichmentOutcome {
+    #[serde(default)]
+    pub pruned: usize,
+    #[serde(default)]
+    pub retracted: usize,
+}
+
+/// How a derived edge left the graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EdgeFate {
+    Pruned,
+    Retracted,
+}
+
+/// One enrichment's track record on a context.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct EnrichmentStats {
+    pub enrichment_id: String,
+    /// Edges it derived, live or since removed
+    pub derived: usize,
+    /// Derived edges still in the graph
+    pub live: usize,
+    /// Live derived edges other contributors have reinforced
+    pub confirmed: usize,
+    pub pruned: usize,
+    pub retracted: usize,
+}
+
+impl EnrichmentStats {
+    /// Share of derived edges that were confirmed (0 when none derived).
+    pub fn confirmation_rate(&self) -> f64 {
+        if self.derived == 0 {
+            0.0
+        } else {
+            self.confirmed as f64 / self.derived as f64
+        }
+    }
+
+    /// Share of derived edges that were pruned or retracted.
+    pub fn removal_rate(&self) -> f64 {
+        if self.derived == 0 {
+            0.0
+        } else {
+            (self.pruned + self.retracted) as f64 / self.derived as f64
+        }
+    }
+}
+
+/// Whether contribution key `key` is `enrichment_id`'s own, or one of
+/// its namespaced per-source keys (a lens writes `lens:<consumer>:...`).
+fn contributes_as(key: &str, enrichment_id: &str) -> bool {
+    key.strip_prefix(enrichment_id).is_some_and(|rest| rest.is_empty() || rest.starts_with(':'))
+}
+
+impl Context {
+    /// The enrichment that derived the edge `id`, if one did.
+    pub fn derived_by(&self, id: &EdgeId) -> Option<&str> {
+        self.metadata.derived_edges.get(id.as_str()).map(String::as_str)
+    }
+
+    /// Record the edges among `edge_ids` that `enrichment_id` alone
+    /// supports and that aren't recorded yet — the ones it just created.
+    pub(crate) fn mark_derived(&mut self, enrichment_id: &str, edge_ids: &[EdgeId]) {
+        if edge_ids.is_empty() {
+            return;
+        }
+        let ids: std::collections::HashSet<&EdgeId> = edge_ids.iter().collect();
+        for edge in self.edges.iter().filter(|e| ids.contains(&e.id)) {
+            let sole = !edge.contributions.is_empty() && edge.contributions.keys().all(|c| contributes_as(c, enrichment_id));
+            if sole && !self.metadata.derived_edges.contains_key(edge.id.as_str()) {
+                self.metadata.derived_edges.insert(edge.id.to_string(), enrichment_id.to_string());
+            }
+        }
+    }
+
+    /// Tally removed edges (by ID) against the enrichments that derived
+    /// them, dropping their derivation records.
+    pub(crate) fn record_edge_fates<'a>(&mut self, removed: impl IntoIterator<Item = &'a EdgeId>, fate: EdgeFate) {
+        if self.metadata.derived_edges.is_empty() {
+            return;
+        }
+        let mut tallies: BTreeMap<String, usize> = BTreeMap::new();
+        for id in removed {
+            if let Some(enrichment_id) = self.metadata.derived_edges.remove(id.as_str()) {
+                *tallies.entry(enrichment_id).or_default() += 1;
+            }
+        }
+        for (id, count) in tallies {
+            let outcome = self.metadata.enrichment_outcomes.entry(id).or_default();
+            match fate {
+                EdgeFate::Pruned => outcome.pruned += count,
+                EdgeFate::Retracted => outcome.retracted += count,
+            }
+        }
+    }
+
+    /// Per-enrichment effectiveness, sorted by enrichment ID.
+    pub fn enrichment_report(&self) -> Vec<EnrichmentStats> {
+        let mut stats: BTreeMap<&str, EnrichmentStats> = BTreeMap::new();
+        for edge in &self.edges {
+            let Some(id) = self.derived_by(&edge.id) else { continue };
+            let confirmed = edge.contributions.keys().any(|c| !contributes_as(c, id));
+            let s = stats_for(&mut stats, id);
+            s.live += 1;
+            s.confirmed += usize::from(confirmed);
+        }
+        for (id, outcome) in &self.metadata.enrichment_outcomes {
+            let s = stats_for(&mut stats, id);
+            s.pruned += outcome.pruned;
+            s.retracted += outcome.retracted;
+        }
+        stats
+            .into_values()
+            .map(|mut s| {
+                s.derived = s.live + s.pruned + s.retracted;
+                s
+            })
+            .collect()
+    }
+}
+
+fn stats_for<'a, 'm>(stats: &'m mut BTreeMap<&'a str, EnrichmentStats>, id: &'a str) -> &'m mut EnrichmentStats {
+    stats.entry(id).or_insert_with(|| EnrichmentStats { enrichment_id: id.to_string(), ..Default::default() })
+}
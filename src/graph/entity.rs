@@ -0,0 +1,155 @@
+//! Serde-based mapping between domain structs and graph nodes
+//!
+//! A consumer struct that derives `Serialize`/`Deserialize` implements
+//! `GraphEntity` by naming its node type and ID; field-to-property mapping
+//! comes from serde (so `#[serde(rename)]`, `skip`, and `default` apply).
+
+use super::engine::{PlexusError, PlexusResult};
+use super::node::{dimension, ContentType, Node, NodeId, Properties, PropertyValue};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// A domain type that converts to and from a `Node`.
+///
+/// ```ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Fragment { text: String, tags: Vec<String> }
+///
+/// impl GraphEntity for Fragment {
+///     const NODE_TYPE: &'static str = "fragment";
+///     const DIMENSION: &'static str = dimension::STRUCTURE;
+///     fn node_id(&self) -> NodeId { NodeId::from_string(format!("fragment:{}", self.text)) }
+/// }
+/// ```
+///
+/// Each serialized field becomes one property. `None` fields are omitted
+/// (PropertyValue has no null), so optional fields should carry
+/// `#[serde(default)]` to read back.
+pub trait GraphEntity: Serialize + DeserializeOwned {
+    /// Node type for converted nodes; `from_node` rejects other types.
+    const NODE_TYPE: &'static str;
+
+    /// Dimension for converted nodes.
+    const DIMENSION: &'static str = dimension::DEFAULT;
+
+    /// Content type for converted nodes.
+    fn content_type() -> ContentType {
+        ContentType::Document
+    }
+
+    /// Deterministic node ID for this value.
+    fn node_id(&self) -> NodeId;
+
+    /// Convert to a node carrying this value's fields as properties.
+    fn to_node(&self) -> PlexusResult<Node> {
+        let mut node = Node::new_in_dimension(Self::NODE_TYPE, Self::content_type(), Self::DIMENSION);
+        node.id = self.node_id();
+        node.properties = to_properties(self)?;
+        Ok(node)
+    }
+
+    /// Read a value back from a node's properties.
+    fn from_node(node: &Node) -> PlexusResult<Self> {
+        if node.node_type != Self::NODE_TYPE {
+            return Err(PlexusError::InvalidInput(format!(
+                "node {} has type '{}', expected '{}'",
+                node.id, node.node_type, Self::NODE_TYPE
+            )));
+        }
+        from_properties(&node.properties)
+    }
+}
+
+/// Serialize a value into a property map. The value must serialize as a
+/// JSON object; null fields are dropped.
+fn to_properties<T: Serialize>(value: &T) -> PlexusResult<Properties> {
+    let serde_json::Value::Object(fields) = serde_json::to_value(value)? else {
+        return Err(PlexusError::Other(
+            "graph entity must serialize as a struct or map".to_string(),
+        ));
+    };
+    let mut properties = Properties::new();
+    for (key, field) in fields {
+        if field.is_null() {
+            continue;
+        }
+        properties.insert(key, serde_json::from_value::<PropertyValue>(field)?);
+    }
+    Ok(properties)
+}
+
+/// Deserialize a value from a property map.
+fn from_properties<T: DeserializeOwned>(properties: &Properties) -> PlexusResult<T> {
+    Ok(serde_json::from_value(serde_json::to_value(properties)?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Fragment {
+        text: String,
+        tags: Vec<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        source: Option<String>,
+        #[serde(rename = "word_count")]
+        words: i64,
+    }
+
+    impl GraphEntity for Fragment {
+        const NODE_TYPE: &'static str = "fragment";
+        const DIMENSION: &'static str = dimension::STRUCTURE;
+
+        fn node_id(&self) -> NodeId {
+            NodeId::from_string(format!("fragment:{}", self.text))
+        }
+    }
+
+    fn fragment() -> Fragment {
+        Fragment {
+            text: "walked to the river".to_string(),
+            tags: vec!["travel".to_string(), "avignon".to_string()],
+            source: None,
+            words: 4,
+        }
+    }
+
+    #[test]
+    fn to_node_maps_fields_to_properties() {
+        let node = fragment().to_node().unwrap();
+
+        assert_eq!(node.id.as_str(), "fragment:walked to the river");
+        assert_eq!(node.node_type, "fragment");
+        assert_eq!(node.dimension, dimension::STRUCTURE);
+        assert_eq!(node.content_type, ContentType::Document);
+        assert_eq!(node.get_str("text"), Some("walked to the river"));
+        assert_eq!(node.tags(), vec!["travel", "avignon"]);
+        assert_eq!(node.get_int("word_count"), Some(4));
+        assert!(!node.properties.contains_key("source"));
+    }
+
+    #[test]
+    fn from_node_round_trips() {
+        let original = fragment();
+        let node = original.to_node().unwrap();
+        assert_eq!(Fragment::from_node(&node).unwrap(), original);
+    }
+
+    #[test]
+    fn from_node_rejects_other_node_types() {
+        let node = Node::builder("concept").prop("text", "x").build();
+        assert!(Fragment::from_node(&node).is_err());
+    }
+
+    #[test]
+    fn from_node_reports_missing_fields() {
+        let mut node = fragment().to_node().unwrap();
+        node.properties.remove("tags");
+        assert!(matches!(
+            Fragment::from_node(&node),
+            Err(PlexusError::Serialization(_))
+        ));
+    }
+}
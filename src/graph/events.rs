@@ -1,10 +1,13 @@
 //! Graph events fired when emissions are committed
 //!
-//! Six low-level event types, one per mutation kind.
+//! Low-level event types, one per mutation kind: additions, removals and
+//! weight changes, plus updates to existing nodes' properties, existing
+//! edges' properties and the context's own configuration.
 //! Higher-level events are modeled as nodes/edges from reflexive adapters.
 
+use super::context::Context;
 use super::edge::EdgeId;
-use super::node::NodeId;
+use super::node::{NodeId, Properties};
 
 /// A graph event fired when an emission is committed.
 #[derive(Debug, Clone, PartialEq)]
@@ -47,6 +50,74 @@ pub enum GraphEvent {
         context_id: String,
         edges_affected: usize,
     },
+    /// Existing nodes had properties set, changed or removed. Upserts
+    /// still fire `NodesAdded` too.
+    NodePropertiesChanged {
+        /// Each changed node with the keys that changed on it
+        changes: Vec<(NodeId, Vec<String>)>,
+        adapter_id: String,
+        context_id: String,
+    },
+    /// Existing edges were re-emitted with changed properties
+    EdgeAnnotated {
+        edge_ids: Vec<EdgeId>,
+        adapter_id: String,
+        context_id: String,
+    },
+    /// The context's configuration changed (policies, scopes, saved
+    /// queries, views…)
+    ContextMetadataChanged {
+        /// The `ContextMetadata` fields that changed
+        keys: Vec<String>,
+        adapter_id: String,
+        context_id: String,
+    },
+}
+
+impl GraphEvent {
+    /// The context the event happened in.
+    pub fn context_id(&self) -> &str {
+        match self {
+            GraphEvent::NodesAdded { context_id, .. }
+            | GraphEvent::EdgesAdded { context_id, .. }
+            | GraphEvent::NodesRemoved { context_id, .. }
+            | GraphEvent::EdgesRemoved { context_id, .. }
+            | GraphEvent::WeightsChanged { context_id, .. }
+            | GraphEvent::ContributionsRetracted { context_id, .. }
+            | GraphEvent::NodePropertiesChanged { context_id, .. }
+            | GraphEvent::EdgeAnnotated { context_id, .. }
+            | GraphEvent::ContextMetadataChanged { context_id, .. } => context_id,
+        }
+    }
+}
+
+/// Keys that differ between two property maps, sorted.
+pub(crate) fn changed_keys(before: &Properties, after: &Properties) -> Vec<String> {
+    let mut keys: Vec<String> = before
+        .keys()
+        .chain(after.keys())
+        .filter(|key| before.get(*key) != after.get(*key))
+        .cloned()
+        .collect();
+    keys.sort();
+    keys.dedup();
+    keys
+}
+
+/// Nodes live in both contexts whose properties differ, each with the
+/// keys that changed, sorted by ID.
+pub(crate) fn property_changes(before: &Context, after: &Context) -> Vec<(NodeId, Vec<String>)> {
+    let mut changes: Vec<(NodeId, Vec<String>)> = after
+        .nodes
+        .iter()
+        .filter_map(|(id, node)| {
+            let previous = before.nodes.get(id)?;
+            let keys = changed_keys(&previous.properties, &node.properties);
+            (!keys.is_empty()).then(|| (id.clone(), keys))
+        })
+        .collect();
+    changes.sort_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+    changes
 }
 
 #[cfg(test)]
@@ -0,0 +1,312 @@
+//! Historical views: a context as it stood at an earlier time
+//!
+//! `PlexusEngine::context_as_of` starts from the live context (the newest
+//! snapshot) and replays the event log (ADR-035) backwards: nodes and
+//! edges first added after the cutoff are dropped, and logged
+//! contributions (`Edge::contribution_log`) roll back to what their
+//! entries before it fold to under the context's aggregation and modes.
+//! Items soft-deleted after the cutoff come back from the trash. Events
+//! record IDs, not payloads, so something purged or hard-deleted after
+//! the cutoff can't be brought back — the view lists those IDs rather
+//! than silently omitting them.
+
+use super::context::Context;
+use super::contribution::ContributionAggregation;
+use super::edge::EdgeId;
+use super::node::NodeId;
+use crate::query::PersistedEvent;
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::ops::Deref;
+
+/// A read-only context rewound to `as_of`.
+#[derive(Debug, Clone)]
+pub struct HistoricalView {
+    snapshot: Context,
+    as_of: DateTime<Utc>,
+    unrecoverable_nodes: Vec<NodeId>,
+    unrecoverable_edges: Vec<EdgeId>,
+}
+
+fn event_time(event: &PersistedEvent) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(&event.created_at).ok().map(|t| t.with_timezone(&Utc))
+}
+
+impl HistoricalView {
+    /// Rewind `current` to `as_of` using its persisted `events`.
+    pub(crate) fn rewind(current: &Context, as_of: DateTime<Utc>, events: &[PersistedEvent]) -> Self {
+        let mut snapshot = current.clone();
+
+        // Items trashed after the cutoff were still live at it
+        let trash = std::mem::take(&mut snapshot.trash);
+        for (id, tombstone) in trash.nodes {
+            if tombstone.deleted_at > as_of && !snapshot.nodes.contains_key(&id) {
+                snapshot.nodes.insert(id, tombstone.item);
+            }
+        }
+        snapshot.edges.extend(trash.edges.into_iter().filter(|t| t.deleted_at > as_of).map(|t| t.item));
+
+        // When each ID first appeared, and which IDs went away after the cutoff
+        let mut node_added: HashMap<&str, DateTime<Utc>> = HashMap::new();
+        let mut edge_added: HashMap<&str, DateTime<Utc>> = HashMap::new();
+        let mut nodes_removed_later: BTreeSet<&str> = BTreeSet::new();
+        let mut edges_removed_later: BTreeSet<&str> = BTreeSet::new();
+        for event in events {
+            let Some(at) = event_time(event) else { continue };
+            match event.event_type.as_str() {
+                "NodesAdded" => {
+                    for id in &event.node_ids {
+                        node_added.entry(id).or_insert(at);
+                    }
+                }
+                "EdgesAdded" => {
+                    for id in &event.edge_ids {
+                        edge_added.entry(id).or_insert(at);
+                    }
+                }
+                "NodesRemoved" if at > as_of => nodes_removed_later.extend(event.node_ids.iter().map(String::as_str)),
+                "EdgesRemoved" if at > as_of => edges_removed_later.extend(event.edge_ids.iter().map(String::as_str)),
+                _ => {}
+            }
+        }
+
+        // A node's own timestamp wins; the log covers nodes without one
+        let late_nodes: HashSet<NodeId> = snapshot
+            .nodes
+            .values()
+            .filter(|node| match node.metadata.created_at {
+                Some(created) => created > as_of,
+                None => node_added.get(node.id.as_str()).is_some_and(|at| *at > as_of),
+            })
+            .map(|node| node.id.clone())
+            .collect();
+        snapshot.nodes.retain(|id, _| !late_nodes.contains(id));
+
+        let edges_before = snapshot.edges.len();
+        snapshot.edges.retain(|edge| {
+            edge.created_at <= as_of && !late_nodes.contains(&edge.source) && !late_nodes.contains(&edge.target)
+        });
+        let mut changed = !late_nodes.is_empty() || snapshot.edges.len() != edges_before;
+
+        let aggregation = snapshot.metadata.contribution_history.unwrap_or(ContributionAggregation::Latest);
+        let modes = snapshot.metadata.contribution_modes.clone();
+        for edge in &mut snapshot.edges {
+            if edge.contribution_log.is_empty() {
+                continue;
+            }
+            edge.contribution_log.retain(|entry| entry.at <= as_of);
+            // Fold the surviving entries the way they were folded live:
+            // by the contributor's mode, else the context's aggregation
+            let mut contributions = HashMap::new();
+            for contributor in edge.contribution_log.iter().map(|entry| &entry.contributor) {
+                if contributions.contains_key(contributor) {
+                    continue;
+                }
+                let value = match modes.get(contributor) {
+                    Some(mode) => edge
+                        .contribution_history(contributor)
+                        .fold(None, |slot, entry| Some(mode.accumulate(slot, entry.value))),
+                    None => aggregation.aggregate(edge.contribution_history(contributor), as_of),
+                };
+                if let Some(value) = value {
+                    contributions.insert(contributor.clone(), value);
+                }
+            }
+            if contributions != edge.contributions {
+                edge.contributions = contributions;
+                changed = true;
+            }
+        }
+        if changed {
+            snapshot.recompute_combined_weights();
+        }
+
+        // Removed after the cutoff, but present before it: gone for good
+        let unrecoverable_nodes = nodes_removed_later
+            .into_iter()
+            .filter(|id| !snapshot.nodes.contains_key(&NodeId::from_string(*id)))
+            .filter(|id| node_added.get(id).is_none_or(|at| *at <= as_of))
+            .map(NodeId::from_string)
+            .collect();
+        let present_edges: HashSet<&str> = snapshot.edges.iter().map(|e| e.id.as_str()).collect();
+        let unrecoverable_edges = edges_removed_later
+            .into_iter()
+            .filter(|id| !present_edges.contains(id))
+            .filter(|id| edge_added.get(id).is_none_or(|at| *at <= as_of))
+            .map(EdgeId::from_string)
+            .collect();
+
+        Self { snapshot, as_of, unrecoverable_nodes, unrecoverable_edges }
+    }
+
+    /// A view of `snapshot`, already replayed exactly to `as_of`.
+    pub(crate) fn projected(snapshot: Context, as_of: DateTime<Utc>) -> Self {
+        Self { snapshot, as_of, unrecoverable_nodes: Vec::new(), unrecoverable_edges: Vec::new() }
+    }
+
+    /// The point in time this view reconstructs.
+    pub fn as_of(&self) -> DateTime<Utc> {
+        self.as_of
+    }
+
+    /// The reconstructed state as a plain context reference.
+    pub fn context(&self) -> &Context {
+        &self.snapshot
+    }
+
+    /// Nodes that existed at `as_of` but were removed since; their
+    /// content isn't in the log, so the view lacks them.
+    pub fn unrecoverable_nodes(&self) -> &[NodeId] {
+        &self.unrecoverable_nodes
+    }
+
+    /// Edges that existed at `as_of` but were removed since.
+    pub fn unrecoverable_edges(&self) -> &[EdgeId] {
+        &self.unrecoverable_edges
+    }
+
+    /// Whether the view is a faithful reconstruction — nothing removed
+    /// after `as_of` is missing from it.
+    pub fn is_complete(&self) -> bool {
+        self.unrecoverable_nodes.is_empty() && self.unrecoverable_edges.is_empty()
+    }
+}
+
+impl Deref for HistoricalView {
+    type Target = Context;
+
+    fn deref(&self) -> &Context {
+        &self.snapshot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{dimension, ContentType, ContributionEntry, Edge, Node};
+    use chrono::Duration;
+
+    fn concept(ctx: &mut Context, label: &str, created: DateTime<Utc>) -> NodeId {
+        let mut node = Node::new_in_dimension("concept", ContentType::Concept, dimension::SEMANTIC);
+        node.id = NodeId::from_string(format!("concept:{label}"));
+        node.metadata.created_at = Some(created);
+        ctx.add_node(node)
+    }
+
+    fn persisted(event_type: &str, nodes: &[&str], edges: &[&str], at: DateTime<Utc>) -> PersistedEvent {
+        PersistedEvent {
+            sequence: 0,
+            context_id: "research".into(),
+            event_type: event_type.into(),
+            node_ids: nodes.iter().map(|s| s.to_string()).collect(),
+            edge_ids: edges.iter().map(|s| s.to_string()).collect(),
+            keys: Vec::new(),
+            adapter_id: "test".into(),
+            created_at: at.to_rfc3339(),
+        }
+    }
+
+    // === Scenario: Asking what the graph believed before a later scan ===
+    #[test]
+    fn rewind_drops_later_nodes_edges_and_contributions() {
+        let january = Utc::now() - Duration::days(60);
+        let february = Utc::now() - Duration::days(30);
+        let cutoff = february - Duration::days(1);
+
+        let mut ctx = Context::new("research");
+        let fl = concept(&mut ctx, "federated-learning", january);
+        let privacy = concept(&mut ctx, "privacy", january);
+        let dp = concept(&mut ctx, "differential-privacy", february);
+
+        let mut early = Edge::new_in_dimension(fl.clone(), privacy.clone(), "related_to", dimension::SEMANTIC)
+            .with_contribution("notes", 0.4)
+            .with_contribution("lit-scan", 0.9);
+        early.created_at = january;
+        early.contribution_log = vec![
+            ContributionEntry { contributor: "notes".into(), value: 0.4, at: january },
+            ContributionEntry { contributor: "lit-scan".into(), value: 0.9, at: february },
+        ];
+        let mut late = Edge::new_in_dimension(fl.clone(), dp.clone(), "related_to", dimension::SEMANTIC)
+            .with_contribution("lit-scan", 1.0);
+        late.created_at = february;
+        ctx.edges.extend([early, late]);
+        ctx.recompute_combined_weights();
+
+        let view = HistoricalView::rewind(&ctx, cutoff, &[]);
+        assert!(view.get_node(&fl).is_some());
+        assert!(view.get_node(&dp).is_none(), "added by the February scan");
+        assert_eq!(view.edge_count(), 1);
+        let edge = view.edges().next().unwrap();
+        assert_eq!(edge.contributions.len(), 1, "the lit-scan contribution came later");
+        assert_eq!(edge.contributions["notes"], 0.4);
+        assert_eq!(edge.combined_weight, 1.0);
+        assert!(view.is_complete());
+
+        // The live context is untouched
+        assert_eq!(ctx.edge_count(), 2);
+    }
+
+    // === Scenario: Removals after the cutoff are reported, not hidden ===
+    #[test]
+    fn rewind_reports_removals_it_cannot_restore() {
+        let before = Utc::now() - Duration::days(10);
+        let cutoff = Utc::now() - Duration::days(5);
+        let after = Utc::now() - Duration::days(1);
+
+        let mut ctx = Context::new("research");
+        let kept = concept(&mut ctx, "kept", before);
+        let mut unstamped = Node::new("concept", ContentType::Concept);
+        unstamped.id = NodeId::from_string("concept:unstamped");
+        unstamped.metadata.created_at = None;
+        ctx.add_node(unstamped);
+
+        let events = [
+            persisted("NodesAdded", &["concept:kept", "concept:gone"], &[], before),
+            persisted("NodesAdded", &["concept:unstamped"], &[], after),
+            persisted("NodesRemoved", &["concept:gone"], &[], after),
+            // Added and removed after the cutoff: never part of the view
+            persisted("NodesAdded", &["concept:transient"], &[], after),
+            persisted("NodesRemoved", &["concept:transient"], &[], after),
+        ];
+        let view = HistoricalView::rewind(&ctx, cutoff, &events);
+        assert!(view.get_node(&kept).is_some());
+        assert!(view.get_node(&"concept:unstamped".into()).is_none(), "the log dates nodes without a timestamp");
+        assert_eq!(view.unrecoverable_nodes(), &[NodeId::from_string("concept:gone")]);
+        assert!(!view.is_complete());
+    }
+
+    // === Scenario: Rolled-back contributions fold as the context folds them ===
+    #[test]
+    fn rewind_folds_contributions_by_aggregation_and_mode() {
+        let january = Utc::now() - Duration::days(60);
+        let cutoff = Utc::now() - Duration::days(30);
+        let later = Utc::now() - Duration::days(1);
+
+        let mut ctx = Context::new("research");
+        let fl = concept(&mut ctx, "federated-learning", january);
+        let privacy = concept(&mut ctx, "privacy", january);
+        ctx.metadata.contribution_history = Some(ContributionAggregation::Sum);
+        ctx.metadata.contribution_modes.insert("lit-scan".into(), crate::graph::ContributionMode::Max);
+
+        let mut edge = Edge::new_in_dimension(fl, privacy, "related_to", dimension::SEMANTIC)
+            .with_contribution("notes", 1.0)
+            .with_contribution("lit-scan", 1.0);
+        edge.created_at = january;
+        let entry = |contributor: &str, value: f32, at| ContributionEntry { contributor: contributor.into(), value, at };
+        edge.contribution_log = vec![
+            entry("notes", 0.2, january),
+            entry("lit-scan", 0.9, january),
+            entry("notes", 0.3, january + Duration::days(1)),
+            entry("lit-scan", 0.4, january + Duration::days(1)),
+            entry("notes", 0.5, later),
+            entry("lit-scan", 1.0, later),
+        ];
+        ctx.edges.push(edge);
+
+        let view = HistoricalView::rewind(&ctx, cutoff, &[]);
+        let edge = view.edges().next().unwrap();
+        assert!((edge.contributions["notes"] - 0.5).abs() < 1e-6, "summed up to the cutoff");
+        assert!((edge.contributions["lit-scan"] - 0.9).abs() < 1e-6, "the strongest before the cutoff");
+        assert_eq!(edge.contribution_log.len(), 4);
+    }
+}
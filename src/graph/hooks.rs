@@ -0,0 +1,275 @@
+//! Mutation hooks — middleware around the engine's commits
+//!
+//! A `MutationHook` registered on `PlexusEngine` sees every emission
+//! before it commits — through an engine-backed `EngineSink`, the
+//! enrichment loop, or `add_node`/`add_edge` — with the context as it
+//! stands: it may rewrite the emission in place or veto it outright.
+//! Engine operations that aren't emissions (`upsert_context`,
+//! `merge_copy`, `commit_overlay`, `relocate_path`, `restore_deleted`,
+//! `supersede_node`, `prune`) pass their events to `pre_change` instead,
+//! which may veto them. After any commit it observes the resulting
+//! events. Validation, audit shipping and metrics plug in here rather
+//! than into the sink, and so can later features like schemas and quotas.
+//!
+//! Hooks run in registration order. The first veto stops the change;
+//! later hooks don't see it. A vetoed enrichment emission is dropped and
+//! the round goes on. Post-commit hooks can't fail the commit: it
+//! already happened.
+//!
+//! Pre-commit hooks run while the engine holds the context's lock, so
+//! they must work from the context they're given: the engine's context
+//! accessors fail with `PlexusError::Other` when called from one instead
+//! of deadlocking.
+
+use super::context::Context;
+use super::engine::{PlexusError, PlexusResult};
+use super::events::GraphEvent;
+use crate::adapter::Emission;
+use std::cell::Cell;
+
+/// Veto, rewrite or observe the mutations committed through an engine.
+pub trait MutationHook: Send + Sync {
+    /// Stable name, for veto messages and `PlexusEngine::remove_hook`.
+    fn id(&self) -> &str;
+
+    /// Inspect or rewrite `emission` before it commits to `context`.
+    /// `Err(reason)` vetoes it: nothing is committed. Runs while the
+    /// engine holds the context, so it can't call back into the engine.
+    fn pre_commit(&self, _context: &Context, _emission: &mut Emission) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Inspect an engine operation that isn't an emission before it
+    /// commits: `context` as it stands and the events the operation will
+    /// emit. `Err(reason)` vetoes it. Same restrictions as `pre_commit`.
+    fn pre_change(&self, _context: &Context, _events: &[GraphEvent]) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Observe the events of a commit. Each event names its context.
+    fn post_commit(&self, _events: &[GraphEvent]) {}
+}
+
+type PreCommitFn = Box<dyn Fn(&Context, &mut Emission) -> Result<(), String> + Send + Sync>;
+type PreChangeFn = Box<dyn Fn(&Context, &[GraphEvent]) -> Result<(), String> + Send + Sync>;
+type PostCommitFn = Box<dyn Fn(&[GraphEvent]) + Send + Sync>;
+
+/// A hook built from closures (see `PlexusEngine::on_pre_commit`,
+/// `on_pre_change` and `on_post_commit`).
+pub struct FnHook {
+    id: String,
+    pre: Option<PreCommitFn>,
+    change: Option<PreChangeFn>,
+    post: Option<PostCommitFn>,
+}
+
+impl FnHook {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into(), pre: None, change: None, post: None }
+    }
+
+    pub fn with_pre_commit(
+        mut self,
+        f: impl Fn(&Context, &mut Emission) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.pre = Some(Box::new(f));
+        self
+    }
+
+    pub fn with_pre_change(
+        mut self,
+        f: impl Fn(&Context, &[GraphEvent]) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.change = Some(Box::new(f));
+        self
+    }
+
+    pub fn with_post_commit(mut self, f: impl Fn(&[GraphEvent]) + Send + Sync + 'static) -> Self {
+        self.post = Some(Box::new(f));
+        self
+    }
+}
+
+impl MutationHook for FnHook {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn pre_commit(&self, context: &Context, emission: &mut Emission) -> Result<(), String> {
+        self.pre.as_ref().map_or(Ok(()), |f| f(context, emission))
+    }
+
+    fn pre_change(&self, context: &Context, events: &[GraphEvent]) -> Result<(), String> {
+        self.change.as_ref().map_or(Ok(()), |f| f(context, events))
+    }
+
+    fn post_commit(&self, events: &[GraphEvent]) {
+        if let Some(f) = &self.post {
+            f(events);
+        }
+    }
+}
+
+thread_local! {
+    /// Set while this thread runs pre-commit or pre-change hooks
+    static IN_PRE_HOOK: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Whether this thread is inside a pre-commit or pre-change hook, where
+/// the engine holds a context lock.
+pub(crate) fn in_pre_hook() -> bool {
+    IN_PRE_HOOK.with(Cell::get)
+}
+
+/// Run `f` flagged as inside a pre hook, clearing the flag even if it panics.
+fn in_pre_hooks<T>(f: impl FnOnce() -> T) -> T {
+    struct Reset(bool);
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            IN_PRE_HOOK.with(|flag| flag.set(self.0));
+        }
+    }
+    let _reset = Reset(IN_PRE_HOOK.with(|flag| flag.replace(true)));
+    f()
+}
+
+/// Run `hooks`' pre-commit step over `emission`, in order.
+pub(crate) fn run_pre_commit(
+    hooks: &[std::sync::Arc<dyn MutationHook>],
+    context: &Context,
+    emission: &mut Emission,
+) -> PlexusResult<()> {
+    in_pre_hooks(|| {
+        for hook in hooks {
+            hook.pre_commit(context, emission)
+                .map_err(|reason| PlexusError::InvalidInput(format!("emission vetoed by hook '{}': {}", hook.id(), reason)))?;
+        }
+        Ok(())
+    })
+}
+
+/// Run `hooks`' pre-change step over an engine operation's events, in order.
+pub(crate) fn run_pre_change(
+    hooks: &[std::sync::Arc<dyn MutationHook>],
+    context: &Context,
+    events: &[GraphEvent],
+) -> PlexusResult<()> {
+    in_pre_hooks(|| {
+        for hook in hooks {
+            hook.pre_change(context, events)
+                .map_err(|reason| PlexusError::InvalidInput(format!("change vetoed by hook '{}': {}", hook.id(), reason)))?;
+        }
+        Ok(())
+    })
+}
+
+/// Run `hooks`' post-commit step over one commit's events.
+pub(crate) fn run_post_commit(hooks: &[std::sync::Arc<dyn MutationHook>], events: &[GraphEvent]) {
+    if events.is_empty() {
+        return;
+    }
+    for hook in hooks {
+        hook.post_commit(events);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adapter::{AdapterSink, Emission, EngineSink, GraphEvent};
+    use crate::graph::{ContentType, Context, Node, NodeId, PlexusEngine, PropertyValue};
+    use crate::PlexusError;
+    use std::sync::{Arc, Mutex};
+
+    fn concept(id: &str) -> Node {
+        let mut node = Node::new("concept", ContentType::Concept);
+        node.id = NodeId::from_string(id);
+        node
+    }
+
+    // === Scenario: Hooks rewrite, veto and observe emissions ===
+    #[tokio::test]
+    async fn hooks_rewrite_veto_and_observe_commits() {
+        let engine = Arc::new(PlexusEngine::new());
+        let id = engine.upsert_context(Context::new("hooked")).unwrap();
+        engine.on_pre_commit("stamp", |_, emission| {
+            for annotated in &mut emission.nodes {
+                annotated.node.properties.insert("reviewed".into(), PropertyValue::Bool(true));
+            }
+            Ok(())
+        });
+        engine.on_pre_commit("no-drafts", |_, emission| match emission.nodes.iter().any(|n| n.node.id.as_str().starts_with("draft")) {
+            true => Err("drafts stay out of the graph".into()),
+            false => Ok(()),
+        });
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let log = seen.clone();
+        engine.on_post_commit("audit", move |events| log.lock().unwrap().extend(events.iter().cloned()));
+
+        let sink = EngineSink::for_engine(engine.clone(), id.clone());
+        sink.emit(Emission::new().with_node(concept("rye"))).await.unwrap();
+        let ctx = engine.get_context(&id).unwrap();
+        assert_eq!(ctx.get_node(&NodeId::from_string("rye")).unwrap().properties.get("reviewed"), Some(&PropertyValue::Bool(true)));
+        assert!(matches!(seen.lock().unwrap().as_slice(), [GraphEvent::NodesAdded { .. }]));
+
+        let vetoed = sink.emit(Emission::new().with_node(concept("draft:1"))).await.unwrap_err();
+        assert!(vetoed.to_string().contains("no-drafts"), "{vetoed}");
+        assert!(engine.get_context(&id).unwrap().get_node(&NodeId::from_string("draft:1")).is_none());
+        assert_eq!(seen.lock().unwrap().len(), 1, "a vetoed emission commits nothing");
+
+        assert!(engine.remove_hook("no-drafts"));
+        assert!(!engine.remove_hook("no-drafts"));
+        sink.emit(Emission::new().with_node(concept("draft:1"))).await.unwrap();
+        assert_eq!(seen.lock().unwrap().len(), 2);
+    }
+
+    // === Scenario: Engine operations pass through the hooks ===
+    #[test]
+    fn engine_operations_run_the_hooks() {
+        let engine = PlexusEngine::new();
+        let id = engine.upsert_context(Context::new("hooked")).unwrap();
+        engine.on_pre_commit("no-drafts", |_, emission| match emission.nodes.iter().any(|n| n.node.id.as_str().starts_with("draft")) {
+            true => Err("drafts stay out of the graph".into()),
+            false => Ok(()),
+        });
+        engine.on_pre_change("keep-nodes", |_, events| match events.iter().any(|e| matches!(e, GraphEvent::NodesRemoved { .. })) {
+            true => Err("nodes are never dropped".into()),
+            false => Ok(()),
+        });
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let log = seen.clone();
+        engine.on_post_commit("audit", move |events| log.lock().unwrap().extend(events.iter().cloned()));
+
+        engine.add_node(&id, concept("rye")).unwrap();
+        engine.add_node(&id, concept("spelt")).unwrap();
+        assert!(engine.add_node(&id, concept("draft:1")).is_err());
+        assert_eq!(seen.lock().unwrap().len(), 2, "adds are observed, vetoes commit nothing");
+
+        let vetoed = engine.upsert_context(Context::with_id(id.clone(), "hooked")).unwrap_err();
+        assert!(vetoed.to_string().contains("keep-nodes"), "{vetoed}");
+        assert_eq!(engine.get_context(&id).unwrap().node_count(), 2);
+
+        let (rye, spelt) = (NodeId::from_string("rye"), NodeId::from_string("spelt"));
+        engine.supersede_node(&id, &rye, &spelt).unwrap();
+        assert!(matches!(seen.lock().unwrap().last(), Some(GraphEvent::EdgesAdded { .. })));
+    }
+
+    // === Scenario: Pre-commit hooks can't deadlock on the engine ===
+    #[tokio::test]
+    async fn reentrant_hook_calls_fail_instead_of_deadlocking() {
+        let engine = Arc::new(PlexusEngine::new());
+        let id = engine.upsert_context(Context::new("hooked")).unwrap();
+        let reentered = Arc::new(Mutex::new(Vec::new()));
+        let (weak, log, target) = (Arc::downgrade(&engine), reentered.clone(), id.clone());
+        engine.on_pre_commit("reentrant", move |_, _| {
+            let engine = weak.upgrade().expect("engine alive");
+            log.lock().unwrap().push((engine.get_context(&target).is_none(), engine.with_context(&target, |_| ()).err()));
+            Ok(())
+        });
+
+        EngineSink::for_engine(engine.clone(), id.clone()).emit(Emission::new().with_node(concept("rye"))).await.unwrap();
+        let reentered = reentered.lock().unwrap();
+        assert!(reentered[0].0, "get_context yields nothing inside a hook");
+        assert!(matches!(reentered[0].1, Some(PlexusError::Other(_))));
+        assert!(engine.get_context(&id).is_some(), "outside hooks the engine answers again");
+    }
+}
@@ -0,0 +1,172 @@
+//! Structural invariants of a context
+//!
+//! `Context::check_invariants` verifies what every committed graph should
+//! satisfy, whatever adapters emitted, and audits edges against their
+//! contributors' write scopes. The sink upholds these; the checker
+//! lets adapter authors (and fuzz tests, see `plexus::testing`) confirm it.
+
+use super::context::Context;
+use super::edge::{AdapterId, EdgeId};
+use super::node::NodeId;
+use std::collections::HashSet;
+use std::fmt;
+
+/// One way a context breaks its invariants.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InvariantViolation {
+    /// An edge endpoint isn't a node in the context
+    DanglingEndpoint { edge: EdgeId, node: NodeId },
+    /// A contribution is NaN or infinite. Signed values are valid
+    /// (ADR-043); only non-finite ones are not.
+    InvalidContribution { edge: EdgeId, contributor: AdapterId, value: f32 },
+    /// An edge's recorded dimension differs from its endpoint's dimension
+    DimensionMismatch { edge: EdgeId, node: NodeId, edge_dimension: String, node_dimension: String },
+    /// A node is stored under a key other than its own ID
+    MisfiledNode { key: NodeId, node: NodeId },
+    /// Two edges share an ID
+    DuplicateEdgeId(EdgeId),
+    /// An edge carries a contribution from an adapter whose write scope
+    /// excludes it (see `WriteScope`)
+    OutOfScope { edge: EdgeId, contributor: AdapterId, reason: String },
+}
+
+impl fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DanglingEndpoint { edge, node } => write!(f, "edge {} references missing node {}", edge, node),
+            Self::InvalidContribution { edge, contributor, value } => {
+                write!(f, "edge {} has non-finite contribution {} from {}", edge, value, contributor)
+            }
+            Self::DimensionMismatch { edge, node, edge_dimension, node_dimension } => write!(
+                f,
+                "edge {} records node {} in dimension '{}', but it is in '{}'",
+                edge, node, edge_dimension, node_dimension
+            ),
+            Self::MisfiledNode { key, node } => write!(f, "node {} is stored under key {}", node, key),
+            Self::DuplicateEdgeId(edge) => write!(f, "edge ID {} is used more than once", edge),
+            Self::OutOfScope { edge, contributor, reason } => {
+                write!(f, "edge {} has a contribution from {}, but {}", edge, contributor, reason)
+            }
+        }
+    }
+}
+
+impl Context {
+    /// Every invariant violation in the context, or `Ok` if there are none.
+    ///
+    /// Checks that edge endpoints exist, contributions are finite, edge
+    /// dimensions match their endpoints', nodes are keyed by their own
+    /// ID, edge IDs are unique, and contributors are within their write
+    /// scopes.
+    pub fn check_invariants(&self) -> Result<(), Vec<InvariantViolation>> {
+        let mut violations = Vec::new();
+
+        for (key, node) in &self.nodes {
+            if key != &node.id {
+                violations.push(InvariantViolation::MisfiledNode { key: key.clone(), node: node.id.clone() });
+            }
+        }
+
+        let mut edge_ids = HashSet::new();
+        for edge in &self.edges {
+            if !edge_ids.insert(&edge.id) {
+                violations.push(InvariantViolation::DuplicateEdgeId(edge.id.clone()));
+            }
+            for (endpoint, recorded) in [(&edge.source, &edge.source_dimension), (&edge.target, &edge.target_dimension)] {
+                match self.nodes.get(endpoint) {
+                    None => violations.push(InvariantViolation::DanglingEndpoint {
+                        edge: edge.id.clone(),
+                        node: endpoint.clone(),
+                    }),
+                    Some(node) if &node.dimension != recorded => violations.push(InvariantViolation::DimensionMismatch {
+                        edge: edge.id.clone(),
+                        node: endpoint.clone(),
+                        edge_dimension: recorded.clone(),
+                        node_dimension: node.dimension.clone(),
+                    }),
+                    Some(_) => {}
+                }
+            }
+            let mut contributions: Vec<_> = edge.contributions.iter().filter(|(_, v)| !v.is_finite()).collect();
+            contributions.sort_by(|a, b| a.0.cmp(b.0));
+            for (contributor, value) in contributions {
+                violations.push(InvariantViolation::InvalidContribution {
+                    edge: edge.id.clone(),
+                    contributor: contributor.clone(),
+                    value: *value,
+                });
+            }
+            let mut contributors: Vec<_> = edge.contributions.keys().collect();
+            contributors.sort();
+            for contributor in contributors {
+                let Some(scope) = self.metadata.write_scopes.get(contributor) else { continue };
+                if let Err(reason) = scope.check_edge(edge) {
+                    violations.push(InvariantViolation::OutOfScope {
+                        edge: edge.id.clone(),
+                        contributor: contributor.clone(),
+                        reason,
+                    });
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{dimension, ContentType, Edge, Node};
+
+    // === Scenario: The checker reports each kind of violation ===
+    #[test]
+    fn check_invariants_reports_violations() {
+        let mut ctx = Context::new("invariants");
+        let a = ctx.add_node(Node::new_in_dimension("concept", ContentType::Concept, dimension::SEMANTIC));
+        let b = ctx.add_node(Node::new_in_dimension("concept", ContentType::Concept, dimension::SEMANTIC));
+        let signed = Edge::new_in_dimension(a.clone(), b.clone(), "related_to", dimension::SEMANTIC)
+            .with_contribution("sentiment", -0.5);
+        ctx.edges.push(signed.clone());
+        assert_eq!(ctx.check_invariants(), Ok(()), "signed contributions are valid");
+
+        let missing = NodeId::from_string("concept:missing");
+        let dangling = Edge::new_in_dimension(a.clone(), missing.clone(), "related_to", dimension::SEMANTIC);
+        let mut mismatched = Edge::new(a.clone(), b.clone(), "cites");
+        mismatched.contributions.insert("broken".into(), f32::NAN);
+        ctx.edges.extend([dangling.clone(), mismatched.clone(), signed.clone()]);
+
+        let violations = ctx.check_invariants().unwrap_err();
+        assert!(violations.contains(&InvariantViolation::DanglingEndpoint { edge: dangling.id, node: missing }));
+        assert!(violations.contains(&InvariantViolation::DuplicateEdgeId(signed.id)));
+        assert!(violations.iter().any(|v| matches!(v, InvariantViolation::InvalidContribution { value, .. } if value.is_nan())));
+        assert_eq!(
+            violations.iter().filter(|v| matches!(v, InvariantViolation::DimensionMismatch { .. })).count(),
+            2,
+            "both endpoints of the default-dimension edge mismatch"
+        );
+        assert_eq!(violations.len(), 5);
+    }
+
+    // === Scenario: The audit reports contributions outside a write scope ===
+    #[test]
+    fn check_invariants_reports_out_of_scope_contributions() {
+        use crate::graph::WriteScope;
+
+        let mut ctx = Context::new("scoped");
+        let a = ctx.add_node(Node::new_in_dimension("concept", ContentType::Concept, dimension::SEMANTIC));
+        let b = ctx.add_node(Node::new_in_dimension("concept", ContentType::Concept, dimension::SEMANTIC));
+        let edge = Edge::new_in_dimension(a, b, "cites", dimension::SEMANTIC).with_contribution("llm", 0.8);
+        ctx.edges.push(edge.clone());
+        assert_eq!(ctx.check_invariants(), Ok(()));
+
+        ctx.metadata.write_scopes.insert("llm".into(), WriteScope::new().with_relationship("related_to"));
+        let violations = ctx.check_invariants().unwrap_err();
+        assert!(matches!(&violations[..], [InvariantViolation::OutOfScope { edge: e, contributor, .. }]
+            if *e == edge.id && contributor == "llm"));
+    }
+}
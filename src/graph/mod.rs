@@ -1,18 +1,69 @@
 //! Core graph data structures
 
 mod context;
+mod contribution;
+mod diff;
 mod edge;
+mod emit_index;
 mod engine;
+mod enrichment_stats;
+mod entity;
+mod history;
+mod hooks;
+mod invariants;
 pub(crate) mod events;
 mod node;
+mod ontology;
+mod overlay;
+mod prune;
+mod quota;
+mod publish;
+mod reader;
+mod relocate;
+mod sample;
+pub mod synthetic;
+mod sync;
+mod tag_policy;
+mod template;
+mod tenant;
+mod trash;
+mod versioning;
+mod write_scope;
 
 #[cfg(test)]
 mod tests;
 
-pub use context::{Context, ContextId, ContextMetadata, Source};
-pub use edge::{Edge, EdgeId};
-pub use engine::{PlexusEngine, PlexusError, PlexusResult};
-pub use node::{Node, NodeId, PropertyValue};
+pub use context::{Context, ContextId, ContextMetadata, Durability, EmbeddingConfig, Source, CONTENT_HASH_PROPERTY, GATED_ENRICHMENT_FAMILIES};
+pub use contribution::{ContributionAggregation, ContributionEntry, ContributionMode};
+pub use diff::GraphDiff;
+pub use edge::{Edge, EdgeBuilder, EdgeId, EdgePolicy, CONFIDENCE_PROPERTY};
+pub use engine::{PlexusEngine, PlexusError, PlexusResult, EMISSION_RETENTION_DAYS, INGEST_CLAIM_LEASE_SECS, INGEST_RESULT_RETENTION_DAYS, MAX_OUTBOUND_EVENTS};
+pub use entity::GraphEntity;
+pub use history::HistoricalView;
+pub use hooks::{FnHook, MutationHook};
+pub use invariants::InvariantViolation;
+pub use ontology::{RelationshipOntology, RelationshipType, ResolvedRelationship};
+pub use tag_policy::TagPolicy;
+pub use write_scope::WriteScope;
+pub use enrichment_stats::{EnrichmentOutcome, EnrichmentStats};
+pub use quota::{ContextQuota, DEFAULT_QUOTA_WARN_RATIO, QUOTA_WARNING_EVENT};
+pub use template::{ContextTemplate, BUILTIN_TEMPLATES, TEMPLATE_PROPERTY};
+pub use tenant::{TenantDirectory, TenantEntry, TenantQuota, TenantUsage};
+pub use trash::{RestoreReport, Tombstone, Trash, DEFAULT_TRASH_RETENTION_DAYS};
+pub use prune::{PrunePolicy, PruneReport};
+pub use publish::{
+    content_digest, manifest_path, open_bundle, verify_bundle, BundleFormat, PublishFilter, PublishManifest, MANIFEST_SUFFIX,
+    PRIVATE_PROPERTY,
+};
+pub use overlay::{NodeMerge, Overlay};
+pub use sync::{merge_contexts, ContextSync, SyncConflict, SyncReport};
+pub use versioning::SUPERSEDES;
+pub(crate) use versioning::resolve_head;
+pub(crate) use emit_index::{natural_key_slot, EmitIndexes, NaturalKeySlot};
+pub use reader::ContextReader;
+pub use relocate::{detect_relocations, file_content_hash, file_inode, Relocation, RelocationReport, RelocationWatcher, INODE_PROPERTY, PATH_PROPERTIES};
+pub use sample::SampleStrategy;
+pub use node::{normalize_natural_key, Node, NodeBuilder, NodeId, PropertyValue, NATURAL_KEY_PROPERTY};
 
 #[allow(unused_imports)]
 pub use node::ContentType;
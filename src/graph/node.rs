@@ -23,13 +23,30 @@ pub mod dimension {
     pub const DEFAULT: &str = "default";
     /// Provenance dimension: chains, marks, and links for tracking decisions
     pub const PROVENANCE: &str = "provenance";
+    /// Spatial dimension: places with coordinates (optional)
+    pub const SPATIAL: &str = "spatial";
 
     /// Check if a dimension string is a known core dimension
     pub fn is_core_dimension(dim: &str) -> bool {
-        matches!(dim, STRUCTURE | SEMANTIC | RELATIONAL | TEMPORAL | DEFAULT | PROVENANCE)
+        matches!(dim, STRUCTURE | SEMANTIC | RELATIONAL | TEMPORAL | DEFAULT | PROVENANCE | SPATIAL)
     }
 }
 
+/// Reserved property holding a node's natural key.
+///
+/// Nodes sharing `(dimension, node_type, natural key)` are the same entity:
+/// the sink folds a keyed emission onto the existing node's ID instead of
+/// creating a duplicate under a differently-spelled ID.
+pub const NATURAL_KEY_PROPERTY: &str = "_natural_key";
+
+/// Normalize a natural key: trimmed, lowercased, inner whitespace collapsed.
+pub fn normalize_natural_key(key: &str) -> String {
+    key.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
 /// Unique identifier for a node
 ///
 /// Serializes as a plain string (UUID or semantic ID like "agent:security-reviewer")
@@ -82,8 +99,12 @@ impl From<String> for NodeId {
 ///
 /// Matches the contract schema: lowercase string enum.
 /// For content types with subtypes (e.g., code language), use properties.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+///
+/// The set is open: any string outside the built-in variants round-trips
+/// as `Other(name)`, so domain adapters (audio transcripts, chat logs,
+/// lab notebooks) can declare their own types without extending this enum.
+/// Names are normalized to lowercase.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ContentType {
     /// Source code
     Code,
@@ -99,26 +120,85 @@ pub enum ContentType {
     Agent,
     /// Provenance tracking (chains, marks, links)
     Provenance,
+    /// Consumer-declared content type (lowercase name)
+    Other(String),
+}
+
+impl ContentType {
+    /// Construct a content type from a name, mapping built-in names to
+    /// their variants and everything else to `Other`.
+    pub fn custom(name: impl AsRef<str>) -> Self {
+        let name = name.as_ref().to_lowercase();
+        match name.as_str() {
+            "code" => ContentType::Code,
+            "movement" => ContentType::Movement,
+            "narrative" => ContentType::Narrative,
+            "concept" => ContentType::Concept,
+            "document" => ContentType::Document,
+            "agent" => ContentType::Agent,
+            "provenance" => ContentType::Provenance,
+            _ => ContentType::Other(name),
+        }
+    }
+
+    /// The lowercase wire name of this content type.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ContentType::Code => "code",
+            ContentType::Movement => "movement",
+            ContentType::Narrative => "narrative",
+            ContentType::Concept => "concept",
+            ContentType::Document => "document",
+            ContentType::Agent => "agent",
+            ContentType::Provenance => "provenance",
+            ContentType::Other(name) => name,
+        }
+    }
+
+    /// True for consumer-declared content types.
+    pub fn is_custom(&self) -> bool {
+        matches!(self, ContentType::Other(_))
+    }
+}
+
+impl std::fmt::Display for ContentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 impl FromStr for ContentType {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
-            "code" => Ok(ContentType::Code),
-            "movement" => Ok(ContentType::Movement),
-            "narrative" => Ok(ContentType::Narrative),
-            "concept" => Ok(ContentType::Concept),
-            "document" => Ok(ContentType::Document),
-            "agent" => Ok(ContentType::Agent),
-            "provenance" => Ok(ContentType::Provenance),
-            _ => Err(format!("Unknown content type: {}", s)),
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            return Err("content type must not be empty".to_string());
         }
+        Ok(ContentType::custom(trimmed))
+    }
+}
+
+impl Serialize for ContentType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ContentType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
     }
 }
 
 /// Typed property values
+///
+/// Serialized untagged: scalars, arrays, and nested objects map directly to
+/// JSON. `DateTime` and `Bytes` use single-key wrapper objects
+/// (`{"$datetime": "<RFC-3339>"}`, `{"$bytes": "<hex>"}`) so they survive a
+/// JSON round trip — including through SQLite `properties_json` — without
+/// being confused with plain strings.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum PropertyValue {
@@ -127,9 +207,258 @@ pub enum PropertyValue {
     Float(f64),
     Bool(bool),
     Array(Vec<PropertyValue>),
+    /// UTC timestamp (parsed dates, EXIF capture times)
+    #[serde(with = "datetime_repr")]
+    DateTime(chrono::DateTime<chrono::Utc>),
+    /// Raw binary data (thumbnails, hashes)
+    #[serde(with = "bytes_repr")]
+    Bytes(Vec<u8>),
+    /// Nested map (frontmatter objects, structured metadata)
     Object(HashMap<String, PropertyValue>),
 }
 
+impl PropertyValue {
+    /// Order two values of compatible kinds.
+    ///
+    /// Ints and floats compare numerically with each other; a `DateTime`
+    /// compares with an RFC-3339 `String` by parsing it. Returns `None` for
+    /// incompatible kinds (and for arrays and objects).
+    pub fn compare(&self, other: &PropertyValue) -> Option<std::cmp::Ordering> {
+        use PropertyValue::*;
+        match (self, other) {
+            (Int(a), Int(b)) => Some(a.cmp(b)),
+            (Float(a), Float(b)) => a.partial_cmp(b),
+            (Int(a), Float(b)) => (*a as f64).partial_cmp(b),
+            (Float(a), Int(b)) => a.partial_cmp(&(*b as f64)),
+            (String(a), String(b)) => Some(a.cmp(b)),
+            (Bool(a), Bool(b)) => Some(a.cmp(b)),
+            (DateTime(a), DateTime(b)) => Some(a.cmp(b)),
+            (DateTime(a), String(b)) => parse_rfc3339(b).map(|b| a.cmp(&b)),
+            (String(a), DateTime(b)) => parse_rfc3339(a).map(|a| a.cmp(b)),
+            (Bytes(a), Bytes(b)) => Some(a.cmp(b)),
+            _ => None,
+        }
+    }
+
+    /// The string value, if this is a `String`.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            PropertyValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// The integer value, if this is an `Int`.
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            PropertyValue::Int(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// The numeric value of an `Int` or `Float`.
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            PropertyValue::Float(n) => Some(*n),
+            PropertyValue::Int(n) => Some(*n as f64),
+            _ => None,
+        }
+    }
+
+    /// The boolean value, if this is a `Bool`.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            PropertyValue::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// The elements, if this is an `Array`.
+    pub fn as_array(&self) -> Option<&[PropertyValue]> {
+        match self {
+            PropertyValue::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// The timestamp of a `DateTime`, or of an RFC-3339 `String`.
+    pub fn as_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        match self {
+            PropertyValue::DateTime(dt) => Some(*dt),
+            PropertyValue::String(s) => parse_rfc3339(s),
+            _ => None,
+        }
+    }
+
+    /// The bytes, if this is `Bytes`.
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            PropertyValue::Bytes(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    /// The nested map, if this is an `Object`.
+    pub fn as_object(&self) -> Option<&HashMap<String, PropertyValue>> {
+        match self {
+            PropertyValue::Object(map) => Some(map),
+            _ => None,
+        }
+    }
+
+    /// Look up a dotted path (`"exif.camera.model"`) through nested objects.
+    ///
+    /// An empty path returns `self`.
+    pub fn get_path(&self, path: &str) -> Option<&PropertyValue> {
+        if path.is_empty() {
+            return Some(self);
+        }
+        let mut current = self;
+        for segment in path.split('.') {
+            match current {
+                PropertyValue::Object(map) => current = map.get(segment)?,
+                _ => return None,
+            }
+        }
+        Some(current)
+    }
+}
+
+impl From<String> for PropertyValue {
+    fn from(s: String) -> Self {
+        PropertyValue::String(s)
+    }
+}
+
+impl From<&str> for PropertyValue {
+    fn from(s: &str) -> Self {
+        PropertyValue::String(s.to_string())
+    }
+}
+
+impl From<i64> for PropertyValue {
+    fn from(n: i64) -> Self {
+        PropertyValue::Int(n)
+    }
+}
+
+impl From<i32> for PropertyValue {
+    fn from(n: i32) -> Self {
+        PropertyValue::Int(n as i64)
+    }
+}
+
+impl From<u32> for PropertyValue {
+    fn from(n: u32) -> Self {
+        PropertyValue::Int(n as i64)
+    }
+}
+
+impl From<f64> for PropertyValue {
+    fn from(n: f64) -> Self {
+        PropertyValue::Float(n)
+    }
+}
+
+impl From<f32> for PropertyValue {
+    fn from(n: f32) -> Self {
+        PropertyValue::Float(n as f64)
+    }
+}
+
+impl From<bool> for PropertyValue {
+    fn from(b: bool) -> Self {
+        PropertyValue::Bool(b)
+    }
+}
+
+impl From<chrono::DateTime<chrono::Utc>> for PropertyValue {
+    fn from(dt: chrono::DateTime<chrono::Utc>) -> Self {
+        PropertyValue::DateTime(dt)
+    }
+}
+
+impl<T: Into<PropertyValue>> From<Vec<T>> for PropertyValue {
+    fn from(items: Vec<T>) -> Self {
+        PropertyValue::Array(items.into_iter().map(Into::into).collect())
+    }
+}
+
+impl From<HashMap<String, PropertyValue>> for PropertyValue {
+    fn from(map: HashMap<String, PropertyValue>) -> Self {
+        PropertyValue::Object(map)
+    }
+}
+
+fn parse_rfc3339(s: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::parse_from_rfc3339(s)
+        .ok()
+        .map(|dt| dt.with_timezone(&chrono::Utc))
+}
+
+/// `{"$datetime": "<RFC-3339>"}` wire form for `PropertyValue::DateTime`.
+mod datetime_repr {
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Repr {
+        #[serde(rename = "$datetime")]
+        value: String,
+    }
+
+    pub fn serialize<S: Serializer>(
+        dt: &chrono::DateTime<chrono::Utc>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Repr {
+            value: dt.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<chrono::DateTime<chrono::Utc>, D::Error> {
+        let repr = Repr::deserialize(deserializer)?;
+        super::parse_rfc3339(&repr.value)
+            .ok_or_else(|| de::Error::custom(format!("invalid RFC-3339 timestamp: {}", repr.value)))
+    }
+}
+
+/// `{"$bytes": "<lowercase hex>"}` wire form for `PropertyValue::Bytes`.
+mod bytes_repr {
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Repr {
+        #[serde(rename = "$bytes")]
+        value: String,
+    }
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        let value = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+        Repr { value }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let repr = Repr::deserialize(deserializer)?;
+        let hex = repr.value.as_bytes();
+        if hex.len() % 2 != 0 {
+            return Err(de::Error::custom("hex byte string has odd length"));
+        }
+        hex.chunks(2)
+            .map(|pair| {
+                std::str::from_utf8(pair)
+                    .ok()
+                    .and_then(|s| u8::from_str_radix(s, 16).ok())
+                    .ok_or_else(|| de::Error::custom("invalid hex byte string"))
+            })
+            .collect()
+    }
+}
+
 /// Properties collection
 pub type Properties = HashMap<String, PropertyValue>;
 
@@ -213,8 +542,8 @@ impl Node {
     }
 
     /// Add a property to the node
-    pub fn with_property(mut self, key: impl Into<String>, value: PropertyValue) -> Self {
-        self.properties.insert(key.into(), value);
+    pub fn with_property(mut self, key: impl Into<String>, value: impl Into<PropertyValue>) -> Self {
+        self.properties.insert(key.into(), value.into());
         self
     }
 
@@ -223,4 +552,132 @@ impl Node {
         self.metadata.source = Some(source.into());
         self
     }
+
+    /// Declare a natural key (normalized; see `NATURAL_KEY_PROPERTY`).
+    pub fn with_natural_key(mut self, key: &str) -> Self {
+        self.properties.insert(
+            NATURAL_KEY_PROPERTY.to_string(),
+            PropertyValue::String(normalize_natural_key(key)),
+        );
+        self
+    }
+
+    /// The declared natural key, if any.
+    pub fn natural_key(&self) -> Option<&str> {
+        self.get_str(NATURAL_KEY_PROPERTY)
+    }
+
+    /// Start a fluent builder for a node of the given type.
+    ///
+    /// Defaults: random ID, `ContentType::Document`, default dimension.
+    pub fn builder(node_type: impl Into<String>) -> NodeBuilder {
+        NodeBuilder {
+            node: Node::new(node_type, ContentType::Document),
+        }
+    }
+
+    /// String property value
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        self.properties.get(key).and_then(PropertyValue::as_str)
+    }
+
+    /// Integer property value
+    pub fn get_int(&self, key: &str) -> Option<i64> {
+        self.properties.get(key).and_then(PropertyValue::as_int)
+    }
+
+    /// Numeric property value (`Int` or `Float`)
+    pub fn get_float(&self, key: &str) -> Option<f64> {
+        self.properties.get(key).and_then(PropertyValue::as_float)
+    }
+
+    /// Boolean property value
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        self.properties.get(key).and_then(PropertyValue::as_bool)
+    }
+
+    /// Array property value
+    pub fn get_array(&self, key: &str) -> Option<&[PropertyValue]> {
+        self.properties.get(key).and_then(PropertyValue::as_array)
+    }
+
+    /// Timestamp property value (`DateTime` or RFC-3339 string)
+    pub fn get_datetime(&self, key: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.properties.get(key).and_then(PropertyValue::as_datetime)
+    }
+
+    /// Nested object property value
+    pub fn get_object(&self, key: &str) -> Option<&HashMap<String, PropertyValue>> {
+        self.properties.get(key).and_then(PropertyValue::as_object)
+    }
+
+    /// String entries of the `tags` array property (non-strings skipped)
+    pub fn tags(&self) -> Vec<&str> {
+        self.get_array("tags")
+            .map(|items| items.iter().filter_map(PropertyValue::as_str).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Fluent builder for `Node`, started with `Node::builder`.
+#[derive(Debug, Clone)]
+pub struct NodeBuilder {
+    node: Node,
+}
+
+impl NodeBuilder {
+    /// Set the node ID
+    pub fn id(mut self, id: impl Into<NodeId>) -> Self {
+        self.node.id = id.into();
+        self
+    }
+
+    /// Set the content type
+    pub fn content_type(mut self, content_type: ContentType) -> Self {
+        self.node.content_type = content_type;
+        self
+    }
+
+    /// Set the dimension
+    pub fn dimension(mut self, dimension: impl Into<String>) -> Self {
+        self.node.dimension = dimension.into();
+        self
+    }
+
+    /// Set a property
+    pub fn prop(mut self, key: impl Into<String>, value: impl Into<PropertyValue>) -> Self {
+        self.node.properties.insert(key.into(), value.into());
+        self
+    }
+
+    /// Append a tag to the `tags` array property
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        let entry = self
+            .node
+            .properties
+            .entry("tags".to_string())
+            .or_insert_with(|| PropertyValue::Array(Vec::new()));
+        match entry {
+            PropertyValue::Array(items) => items.push(PropertyValue::String(tag.into())),
+            other => *other = PropertyValue::Array(vec![PropertyValue::String(tag.into())]),
+        }
+        self
+    }
+
+    /// Set the source location
+    pub fn source(mut self, source: impl Into<String>) -> Self {
+        self.node.metadata.source = Some(source.into());
+        self
+    }
+
+    /// Declare a natural key
+    pub fn natural_key(mut self, key: &str) -> Self {
+        self.node = self.node.with_natural_key(key);
+        self
+    }
+
+    /// Finish building
+    pub fn build(self) -> Node {
+        self.node
+    }
 }
@@ -0,0 +1,219 @@
+//! Per-context relationship ontology
+//!
+//! Declares what each relationship means structurally: whether it is
+//! directed, the name of its inverse (`contains` / `contained_in`),
+//! whether it is transitive, and which dimension pairs it may connect.
+//! Stored on `ContextMetadata`. Traversals resolve inverse names to the
+//! stored relationship walked backwards; the sink rejects edges that
+//! connect dimensions their relationship doesn't allow and, in strict
+//! mode, edges whose relationship isn't declared at all.
+
+use super::edge::Edge;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// What a relationship means structurally.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelationshipType {
+    /// `false` for symmetric relationships (`similar_to`), which
+    /// traversals follow in both directions
+    #[serde(default = "default_directed")]
+    pub directed: bool,
+    /// Name of the reverse reading (`contained_in` for `contains`). Edges
+    /// are stored under the declared name only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inverse: Option<String>,
+    /// `a → b → c` implies `a → c`
+    #[serde(default)]
+    pub transitive: bool,
+    /// `(source dimension, target dimension)` pairs the relationship may
+    /// connect; empty allows any
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dimensions: Vec<(String, String)>,
+}
+
+fn default_directed() -> bool {
+    true
+}
+
+impl Default for RelationshipType {
+    fn default() -> Self {
+        Self { directed: true, inverse: None, transitive: false, dimensions: Vec::new() }
+    }
+}
+
+impl RelationshipType {
+    /// A directed relationship.
+    pub fn directed() -> Self {
+        Self::default()
+    }
+
+    /// A symmetric relationship.
+    pub fn undirected() -> Self {
+        Self { directed: false, ..Self::default() }
+    }
+
+    pub fn with_inverse(mut self, inverse: impl Into<String>) -> Self {
+        self.inverse = Some(inverse.into());
+        self
+    }
+
+    pub fn transitive(mut self) -> Self {
+        self.transitive = true;
+        self
+    }
+
+    /// Allow edges from `source` dimension nodes to `target` dimension nodes.
+    pub fn between(mut self, source: impl Into<String>, target: impl Into<String>) -> Self {
+        self.dimensions.push((source.into(), target.into()));
+        self
+    }
+}
+
+/// How a relationship name used in a query maps onto stored edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResolvedRelationship<'a> {
+    /// The relationship edges are stored under
+    pub name: &'a str,
+    /// Whether the name was an inverse, so edges are walked backwards
+    pub reversed: bool,
+    /// Whether edges may be walked either way
+    pub undirected: bool,
+}
+
+/// Relationship declarations for a context.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelationshipOntology {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub types: BTreeMap<String, RelationshipType>,
+    /// Reject edges whose relationship isn't declared (by name or inverse)
+    #[serde(default)]
+    pub strict: bool,
+}
+
+impl RelationshipOntology {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declarations for the relationships built-in adapters and
+    /// enrichments emit.
+    pub fn builtin() -> Self {
+        Self::new()
+            .with_type("contains", RelationshipType::directed().with_inverse("contained_in").transitive())
+            .with_type("tagged_with", RelationshipType::directed().with_inverse("tag_of"))
+            .with_type("links_to", RelationshipType::directed().with_inverse("linked_from"))
+            .with_type("references", RelationshipType::directed().with_inverse("referenced_by"))
+            .with_type(super::versioning::SUPERSEDES, RelationshipType::directed().with_inverse("superseded_by").transitive())
+            .with_type("similar_to", RelationshipType::undirected())
+            .with_type("may_be_related", RelationshipType::undirected())
+    }
+
+    pub fn with_type(mut self, name: impl Into<String>, relationship: RelationshipType) -> Self {
+        self.types.insert(name.into(), relationship);
+        self
+    }
+
+    /// Reject undeclared relationships at the sink.
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    /// Whether `name` is declared, either directly or as an inverse.
+    pub fn is_declared(&self, name: &str) -> bool {
+        self.types.contains_key(name) || self.inverse_of(name).is_some()
+    }
+
+    /// The declared relationship whose inverse is `name`.
+    pub fn inverse_of(&self, name: &str) -> Option<&str> {
+        self.types
+            .iter()
+            .find(|(_, t)| t.inverse.as_deref() == Some(name))
+            .map(|(declared, _)| declared.as_str())
+    }
+
+    /// How edges for `name` are stored. Undeclared names resolve to
+    /// themselves, directed.
+    pub fn resolve<'a>(&'a self, name: &'a str) -> ResolvedRelationship<'a> {
+        let (stored, reversed) = match (self.types.contains_key(name), self.inverse_of(name)) {
+            (false, Some(declared)) => (declared, true),
+            _ => (name, false),
+        };
+        let undirected = self.types.get(stored).is_some_and(|t| !t.directed);
+        ResolvedRelationship { name: stored, reversed, undirected }
+    }
+
+    /// Whether `name` (or the relationship it is the inverse of) is transitive.
+    pub fn is_transitive(&self, name: &str) -> bool {
+        self.types.get(self.resolve(name).name).is_some_and(|t| t.transitive)
+    }
+
+    /// Why `edge` may not be stored, if it may not.
+    pub fn check(&self, edge: &Edge) -> Result<(), String> {
+        let Some(declared) = self.types.get(self.resolve(&edge.relationship).name) else {
+            if self.strict {
+                return Err(format!("relationship '{}' is not in the ontology", edge.relationship));
+            }
+            return Ok(());
+        };
+        let (source, target) = if self.types.contains_key(&edge.relationship) {
+            (&edge.source_dimension, &edge.target_dimension)
+        } else {
+            (&edge.target_dimension, &edge.source_dimension)
+        };
+        if !declared.dimensions.is_empty() && !declared.dimensions.iter().any(|(s, t)| s == source && t == target) {
+            return Err(format!(
+                "relationship '{}' may not connect {} to {}",
+                edge.relationship, edge.source_dimension, edge.target_dimension
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{dimension, NodeId};
+
+    fn edge(relationship: &str, source: &str, target: &str) -> Edge {
+        let mut edge = Edge::new(NodeId::from_string("a"), NodeId::from_string("b"), relationship);
+        edge.source_dimension = source.to_string();
+        edge.target_dimension = target.to_string();
+        edge
+    }
+
+    // === Scenario: Inverse names resolve to the stored relationship ===
+    #[test]
+    fn inverses_resolve_backwards() {
+        let ontology = RelationshipOntology::builtin();
+        assert_eq!(
+            ontology.resolve("contained_in"),
+            ResolvedRelationship { name: "contains", reversed: true, undirected: false }
+        );
+        assert!(!ontology.resolve("contains").reversed);
+        assert!(ontology.resolve("similar_to").undirected);
+        assert_eq!(ontology.resolve("cites").name, "cites");
+        assert!(ontology.is_transitive("contained_in"));
+        assert!(!ontology.is_transitive("tagged_with"));
+    }
+
+    // === Scenario: Strict ontologies reject unknown and ill-placed edges ===
+    #[test]
+    fn check_enforces_declarations_and_dimensions() {
+        let ontology = RelationshipOntology::new().with_type(
+            "tagged_with",
+            RelationshipType::directed().with_inverse("tags").between(dimension::STRUCTURE, dimension::SEMANTIC),
+        );
+        assert!(ontology.check(&edge("cites", dimension::DEFAULT, dimension::DEFAULT)).is_ok(), "lenient by default");
+        assert!(ontology.check(&edge("tagged_with", dimension::STRUCTURE, dimension::SEMANTIC)).is_ok());
+        assert!(ontology.check(&edge("tags", dimension::SEMANTIC, dimension::STRUCTURE)).is_ok());
+        assert!(ontology.check(&edge("tagged_with", dimension::SEMANTIC, dimension::SEMANTIC)).is_err());
+
+        let strict = ontology.strict();
+        let err = strict.check(&edge("cites", dimension::DEFAULT, dimension::DEFAULT)).unwrap_err();
+        assert!(err.contains("cites"));
+        assert!(strict.check(&edge("tags", dimension::SEMANTIC, dimension::STRUCTURE)).is_ok());
+    }
+}
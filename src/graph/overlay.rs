@@ -0,0 +1,271 @@
+//! Overlays: named hypothesis layers over a context
+//!
+//! An overlay stages nodes and edges to add, items to hide, and node
+//! merges ("what if these two concepts were the same?") without touching
+//! the context itself. `PlexusEngine::overlay_view` applies it to a copy
+//! of the live context at query time, so any query can run against the
+//! hypothesis; `commit_overlay` writes it into the context (emitting the
+//! usual graph events) and `discard_overlay` drops it. Overlays live in
+//! the context's metadata, so staged proposals survive restarts without
+//! forking the context.
+
+use super::context::Context;
+use super::edge::{Edge, EdgeId};
+use super::node::{Node, NodeId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Fold `absorb` into `keep`: its edges are rewired to `keep`, its
+/// properties fill gaps in `keep`'s, and it goes to the trash.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeMerge {
+    pub keep: NodeId,
+    pub absorb: NodeId,
+}
+
+/// A named layer of changes applied on top of a context at query time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Overlay {
+    /// The hypothesis or proposal the overlay stages
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+    /// Nodes to add, or to replace base nodes with the same ID
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub nodes: Vec<Node>,
+    /// Edges to add; one matching a base edge merges into it
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub edges: Vec<Edge>,
+    /// Nodes to hide, with their edges
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hidden_nodes: Vec<NodeId>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hidden_edges: Vec<EdgeId>,
+    /// Applied in order, after additions and hides
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub merges: Vec<NodeMerge>,
+}
+
+impl Default for Overlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Overlay {
+    pub fn new() -> Self {
+        Self {
+            description: None,
+            created_at: Utc::now(),
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            hidden_nodes: Vec::new(),
+            hidden_edges: Vec::new(),
+            merges: Vec::new(),
+        }
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_node(mut self, node: Node) -> Self {
+        self.nodes.push(node);
+        self
+    }
+
+    pub fn with_edge(mut self, edge: Edge) -> Self {
+        self.edges.push(edge);
+        self
+    }
+
+    pub fn hiding_node(mut self, id: NodeId) -> Self {
+        self.hidden_nodes.push(id);
+        self
+    }
+
+    pub fn hiding_edge(mut self, id: EdgeId) -> Self {
+        self.hidden_edges.push(id);
+        self
+    }
+
+    pub fn merging(mut self, keep: NodeId, absorb: NodeId) -> Self {
+        self.merges.push(NodeMerge { keep, absorb });
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+            && self.edges.is_empty()
+            && self.hidden_nodes.is_empty()
+            && self.hidden_edges.is_empty()
+            && self.merges.is_empty()
+    }
+
+    /// Check the overlay against `base`: edges and merges must refer to
+    /// nodes in the base or the overlay, and a node can't absorb itself.
+    pub fn validate(&self, base: &Context) -> Result<(), String> {
+        let staged: HashSet<&NodeId> = self.nodes.iter().map(|n| &n.id).collect();
+        let known = |id: &NodeId| base.nodes.contains_key(id) || staged.contains(id);
+        for edge in &self.edges {
+            for endpoint in [&edge.source, &edge.target] {
+                if !known(endpoint) {
+                    return Err(format!("edge {} refers to unknown node {}", edge.id, endpoint));
+                }
+            }
+        }
+        for merge in &self.merges {
+            if merge.keep == merge.absorb {
+                return Err(format!("node {} can't be merged into itself", merge.keep));
+            }
+            for id in [&merge.keep, &merge.absorb] {
+                if !known(id) {
+                    return Err(format!("merge refers to unknown node {}", id));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// `base` with the overlay applied. Hidden and absorbed items go to
+    /// the trash, so queries skip them and a commit can be undone with
+    /// `restore_deleted`.
+    pub fn apply(&self, base: &Context) -> Context {
+        let mut context = base.clone();
+
+        let hidden: HashSet<&EdgeId> = self.hidden_edges.iter().collect();
+        context.trash_edges_where(|e| hidden.contains(&e.id));
+        for id in &self.hidden_nodes {
+            context.trash_node(id);
+        }
+
+        for node in &self.nodes {
+            context.nodes.insert(node.id.clone(), node.clone());
+        }
+        for edge in &self.edges {
+            context.add_edge(edge.clone());
+        }
+
+        for merge in &self.merges {
+            merge_node(&mut context, merge);
+        }
+        context.recompute_combined_weights();
+        context
+    }
+}
+
+fn merge_node(context: &mut Context, merge: &NodeMerge) {
+    let (Some(keep), Some(absorbed)) = (context.nodes.get(&merge.keep), context.nodes.get(&merge.absorb)) else {
+        return;
+    };
+    let dimension = keep.dimension.clone();
+    let inherited: Vec<_> = absorbed
+        .properties
+        .iter()
+        .filter(|(key, _)| !keep.properties.contains_key(*key))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+    if let Some(keep) = context.nodes.get_mut(&merge.keep) {
+        keep.properties.extend(inherited);
+    }
+
+    let (touching, rest): (Vec<Edge>, Vec<Edge>) =
+        std::mem::take(&mut context.edges).into_iter().partition(|e| e.source == merge.absorb || e.target == merge.absorb);
+    context.edges = rest;
+    for mut edge in touching {
+        if edge.source == merge.absorb {
+            edge.source = merge.keep.clone();
+            edge.source_dimension = dimension.clone();
+        }
+        if edge.target == merge.absorb {
+            edge.target = merge.keep.clone();
+            edge.target_dimension = dimension.clone();
+        }
+        // An edge between the two merged nodes has nowhere to go
+        if edge.source != edge.target {
+            context.add_edge(edge);
+        }
+    }
+    context.trash_node(&merge.absorb);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{ContentType, PlexusEngine};
+
+    fn concept(label: &str) -> Node {
+        let mut node = Node::new("concept", ContentType::Concept);
+        node.properties.insert("label".to_string(), crate::graph::PropertyValue::String(label.to_string()));
+        node
+    }
+
+    fn tagged_context() -> (Context, NodeId, NodeId, NodeId) {
+        let mut ctx = Context::new("overlay");
+        let doc = Node::new("document", ContentType::Document);
+        let (ml, machine_learning) = (concept("ml"), concept("machine learning"));
+        let ids = (doc.id.clone(), ml.id.clone(), machine_learning.id.clone());
+        ctx.add_node(doc);
+        ctx.add_node(ml);
+        ctx.add_node(machine_learning);
+        ctx.add_edge(Edge::new(ids.0.clone(), ids.1.clone(), "tagged_with").with_contribution("content", 1.0));
+        ctx.add_edge(Edge::new(ids.0.clone(), ids.2.clone(), "tagged_with").with_contribution("content", 0.5));
+        (ctx, ids.0, ids.1, ids.2)
+    }
+
+    // === Scenario: A merge hypothesis is visible only through the overlay ===
+    #[test]
+    fn merge_rewires_edges_in_the_view_only() {
+        let (base, doc, ml, machine_learning) = tagged_context();
+        let overlay = Overlay::new().merging(ml.clone(), machine_learning.clone());
+        overlay.validate(&base).unwrap();
+
+        let view = overlay.apply(&base);
+        assert!(view.get_node(&machine_learning).is_none());
+        assert_eq!(view.edges.len(), 1, "parallel tagged_with edges collapse into one");
+        assert_eq!(view.edges[0].source, doc);
+        assert_eq!(view.edges[0].target, ml);
+        assert_eq!(base.edges.len(), 2, "the base is untouched");
+    }
+
+    // === Scenario: Overlays referring to unknown nodes are rejected ===
+    #[test]
+    fn validate_rejects_unknown_nodes_and_self_merges() {
+        let (base, doc, ml, _) = tagged_context();
+        let stray = NodeId::new();
+        assert!(Overlay::new().with_edge(Edge::new(doc, stray.clone(), "cites")).validate(&base).is_err());
+        assert!(Overlay::new().merging(ml.clone(), ml).validate(&base).is_err());
+        assert!(Overlay::new().hiding_node(stray).validate(&base).is_ok(), "hiding something absent is harmless");
+    }
+
+    // === Scenario: Overlays are staged, viewed, committed or discarded ===
+    #[test]
+    fn engine_commits_and_discards_overlays() {
+        let engine = PlexusEngine::new();
+        let (base, doc, ml, machine_learning) = tagged_context();
+        let id = engine.upsert_context(base).unwrap();
+
+        let proposal = concept("deep learning");
+        let proposal_id = proposal.id.clone();
+        engine
+            .stage_overlay(&id, "llm-proposal", Overlay::new().with_node(proposal).with_edge(Edge::new(doc.clone(), proposal_id.clone(), "tagged_with")))
+            .unwrap();
+        engine.stage_overlay(&id, "merge", Overlay::new().merging(ml, machine_learning.clone())).unwrap();
+        assert_eq!(engine.overlays(&id).unwrap().len(), 2);
+
+        let view = engine.overlay_view(&id, "llm-proposal").unwrap();
+        assert!(view.get_node(&proposal_id).is_some());
+        assert!(engine.get_context(&id).unwrap().get_node(&proposal_id).is_none());
+
+        let sync = engine.commit_overlay(&id, "llm-proposal").unwrap();
+        assert_eq!(sync.nodes_added, vec![proposal_id.clone()]);
+        assert!(engine.get_context(&id).unwrap().get_node(&proposal_id).is_some());
+
+        assert!(engine.discard_overlay(&id, "merge").unwrap());
+        assert!(engine.overlays(&id).unwrap().is_empty());
+        assert!(engine.get_context(&id).unwrap().get_node(&machine_learning).is_some());
+        assert!(engine.overlay_view(&id, "merge").is_err());
+    }
+}
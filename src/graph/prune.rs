@@ -0,0 +1,129 @@
+//! Edge pruning policies
+//!
+//! Derived layers (co-occurrence, similarity, temporal proximity) grow
+//! with every ingest and nothing removes them. A `PrunePolicy` selects
+//! edges to drop; `PlexusEngine::prune` removes them and emits
+//! `EdgesRemoved`, or just reports them on a dry run.
+
+use super::context::Context;
+use super::edge::{Edge, EdgeId};
+use super::node::NodeId;
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// Which edges `PlexusEngine::prune` removes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PrunePolicy {
+    /// Edges whose combined weight is below `threshold`
+    WeightBelow { threshold: f32 },
+    /// Edges whose every contribution is zero. Edges added without
+    /// contributions are kept unless their combined weight is also zero.
+    ZeroContribution,
+    /// Derived edges — every contributor is in `derived_by` — with an
+    /// endpoint no longer touched by any non-derived edge: the evidence
+    /// they were derived from is gone.
+    UnreferencedDerived { derived_by: Vec<String> },
+    /// Edges created more than `max_age` ago
+    OlderThan { max_age: Duration },
+}
+
+impl PrunePolicy {
+    /// Short name used as the `EdgesRemoved` reason.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::WeightBelow { .. } => "weight_below",
+            Self::ZeroContribution => "zero_contribution",
+            Self::UnreferencedDerived { .. } => "unreferenced_derived",
+            Self::OlderThan { .. } => "older_than",
+        }
+    }
+}
+
+/// Outcome of a prune.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PruneReport {
+    /// Policy name, as in `PrunePolicy::name`
+    pub policy: String,
+    /// Nothing was removed; `edge_ids` lists what would have been
+    pub dry_run: bool,
+    pub edge_ids: Vec<EdgeId>,
+}
+
+impl Context {
+    /// Edges `policy` selects for removal, in edge order. `now` anchors
+    /// age-based policies.
+    pub fn prunable_edges(&self, policy: &PrunePolicy, now: DateTime<Utc>) -> Vec<EdgeId> {
+        let selected: Box<dyn Fn(&Edge) -> bool + '_> = match policy {
+            PrunePolicy::WeightBelow { threshold } => Box::new(move |e| e.combined_weight < *threshold),
+            PrunePolicy::ZeroContribution => Box::new(|e| {
+                e.contributions.values().all(|v| *v == 0.0)
+                    && (!e.contributions.is_empty() || e.combined_weight == 0.0)
+            }),
+            PrunePolicy::UnreferencedDerived { derived_by } => {
+                let is_derived =
+                    move |e: &Edge| !e.contributions.is_empty() && e.contributions.keys().all(|c| derived_by.contains(c));
+                let referenced: HashSet<&NodeId> = self
+                    .edges
+                    .iter()
+                    .filter(|e| !is_derived(e))
+                    .flat_map(|e| [&e.source, &e.target])
+                    .collect();
+                Box::new(move |e| {
+                    is_derived(e) && !(referenced.contains(&e.source) && referenced.contains(&e.target))
+                })
+            }
+            PrunePolicy::OlderThan { max_age } => {
+                // An age reaching before the representable range selects nothing
+                match now.checked_sub_signed(*max_age) {
+                    Some(cutoff) => Box::new(move |e| e.created_at < cutoff),
+                    None => Box::new(|_| false),
+                }
+            }
+        };
+        self.edges.iter().filter(|e| selected(e)).map(|e| e.id.clone()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{ContentType, Node};
+
+    fn edge(ctx: &mut Context, source: &NodeId, target: &NodeId, contributions: &[(&str, f32)]) -> EdgeId {
+        let mut edge = Edge::new(source.clone(), target.clone(), "related_to");
+        for (adapter, value) in contributions {
+            edge.contributions.insert(adapter.to_string(), *value);
+        }
+        let id = edge.id.clone();
+        ctx.edges.push(edge);
+        id
+    }
+
+    // === Scenario: Each policy selects its edges ===
+    #[test]
+    fn policies_select_matching_edges() {
+        let mut ctx = Context::new("prune");
+        let [a, b, c] = [(); 3].map(|_| ctx.add_node(Node::new("fragment", ContentType::Document)));
+
+        let primary = edge(&mut ctx, &a, &b, &[("content", 1.0)]);
+        let zeroed = edge(&mut ctx, &a, &b, &[("content", 0.0)]);
+        let referenced = edge(&mut ctx, &a, &b, &[("co_occurrence", 0.5)]);
+        let orphaned = edge(&mut ctx, &b, &c, &[("co_occurrence", 0.5)]);
+        ctx.edges.iter_mut().find(|e| e.id == zeroed).unwrap().combined_weight = 0.0;
+        ctx.edges.iter_mut().find(|e| e.id == orphaned).unwrap().created_at = Utc::now() - Duration::days(90);
+        let now = Utc::now();
+
+        assert_eq!(ctx.prunable_edges(&PrunePolicy::WeightBelow { threshold: 0.1 }, now), vec![zeroed.clone()]);
+        assert_eq!(ctx.prunable_edges(&PrunePolicy::ZeroContribution, now), vec![zeroed]);
+        let derived = PrunePolicy::UnreferencedDerived { derived_by: vec!["co_occurrence".into()] };
+        assert_eq!(ctx.prunable_edges(&derived, now), vec![orphaned.clone()]);
+        let old = PrunePolicy::OlderThan { max_age: Duration::days(30) };
+        assert_eq!(ctx.prunable_edges(&old, now), vec![orphaned]);
+
+        assert!(!ctx.prunable_edges(&PrunePolicy::WeightBelow { threshold: 0.1 }, now).contains(&primary));
+        assert!(!ctx.prunable_edges(&derived, now).contains(&referenced));
+        let ancient = PrunePolicy::OlderThan { max_age: Duration::MAX };
+        assert!(ctx.prunable_edges(&ancient, now).is_empty(), "an age past the calendar selects nothing");
+    }
+}
@@ -0,0 +1,448 @@
+//! Read-only sharing bundles
+//!
+//! `PlexusEngine::publish` copies a filtered subgraph of a context into a
+//! self-contained bundle — a fresh SQLite database or a static JSON file —
+//! to hand to collaborators or attach to a paper. Private properties are
+//! stripped on the way out, and a manifest beside the bundle
+//! (`<bundle>.manifest.json`) records what was published, with a digest
+//! `verify_bundle` re-checks. The bundle file is marked read-only.
+
+use super::context::{Context, ContextMetadata};
+use super::edge::Edge;
+use super::engine::{PlexusError, PlexusResult};
+use super::node::{dimension, Node, NodeId, Properties, PropertyValue};
+use crate::query::{provenance_for, QueryFilter};
+use crate::storage::{GraphStore, OpenStore, SqliteStore};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Suffix of the manifest written beside a bundle.
+pub const MANIFEST_SUFFIX: &str = ".manifest.json";
+
+/// Nodes with `private: true` are never published.
+pub const PRIVATE_PROPERTY: &str = "private";
+
+/// Bundle storage format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BundleFormat {
+    /// A SQLite database in the store's own schema
+    Sqlite,
+    /// The context as a single JSON document
+    Json,
+}
+
+impl BundleFormat {
+    /// JSON for `.json` paths, SQLite for anything else.
+    pub fn for_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Self::Json,
+            _ => Self::Sqlite,
+        }
+    }
+}
+
+/// What `PlexusEngine::publish` copies into a bundle. Empty lists match
+/// everything; edges are kept when both endpoints are and they pass `edges`.
+#[derive(Debug, Clone, Default)]
+pub struct PublishFilter {
+    pub node_types: Vec<String>,
+    pub dimensions: Vec<String>,
+    /// Publish only these nodes (before provenance is added)
+    pub node_ids: Option<Vec<NodeId>>,
+    pub edges: QueryFilter,
+    /// Property keys stripped from nodes and edges, in addition to the
+    /// internal `_`-prefixed ones
+    pub private_properties: Vec<String>,
+    /// Leave out the marks and chains citing the published nodes
+    pub exclude_provenance: bool,
+}
+
+impl PublishFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_node_types(mut self, node_types: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.node_types = node_types.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn with_dimensions(mut self, dimensions: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.dimensions = dimensions.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn with_node_ids(mut self, node_ids: Vec<NodeId>) -> Self {
+        self.node_ids = Some(node_ids);
+        self
+    }
+
+    pub fn with_edge_filter(mut self, filter: QueryFilter) -> Self {
+        self.edges = filter;
+        self
+    }
+
+    pub fn with_private_properties(mut self, keys: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.private_properties = keys.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn without_provenance(mut self) -> Self {
+        self.exclude_provenance = true;
+        self
+    }
+
+    fn selects(&self, node: &Node) -> bool {
+        (self.node_types.is_empty() || self.node_types.contains(&node.node_type))
+            && (self.dimensions.is_empty() || self.dimensions.contains(&node.dimension))
+            && self.node_ids.as_ref().is_none_or(|ids| ids.contains(&node.id))
+            && !is_private(node)
+    }
+
+    fn is_private_key(&self, key: &str) -> bool {
+        key.starts_with('_') || self.private_properties.iter().any(|k| k == key)
+    }
+}
+
+/// Record of a published bundle, written beside it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublishManifest {
+    pub format: BundleFormat,
+    /// Source context name
+    pub context: String,
+    pub published_at: DateTime<Utc>,
+    pub plexus_version: String,
+    pub node_count: usize,
+    pub edge_count: usize,
+    /// Property keys removed from at least one node or edge
+    pub stripped_properties: Vec<String>,
+    /// `content_digest` of the published context
+    pub digest: String,
+}
+
+/// Path of the manifest for the bundle at `bundle`.
+pub fn manifest_path(bundle: &Path) -> PathBuf {
+    let mut name = bundle.file_name().unwrap_or_default().to_os_string();
+    name.push(MANIFEST_SUFFIX);
+    bundle.with_file_name(name)
+}
+
+/// Digest (name-based UUID, SHA-1) of a context's nodes and edges in
+/// id order: type, dimension and properties of each node; endpoints,
+/// relationship, weight, contributions and properties of each edge.
+pub fn content_digest(context: &Context) -> String {
+    let mut nodes: Vec<&Node> = context.nodes.values().collect();
+    nodes.sort_by(|a, b| a.id.as_str().cmp(b.id.as_str()));
+    let mut edges: Vec<&Edge> = context.edges.iter().collect();
+    edges.sort_by(|a, b| a.id.as_str().cmp(b.id.as_str()));
+    let canonical = json!({
+        "nodes": nodes
+            .iter()
+            .map(|n| json!([n.id, n.node_type, n.dimension, n.properties]))
+            .collect::<Vec<_>>(),
+        "edges": edges
+            .iter()
+            .map(|e| json!([e.id, e.source, e.target, e.relationship, e.combined_weight, e.contributions, e.properties]))
+            .collect::<Vec<_>>(),
+    });
+    // serde_json maps are sorted, so the rendering is canonical
+    uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_OID, canonical.to_string().as_bytes()).to_string()
+}
+
+fn is_private(node: &Node) -> bool {
+    matches!(node.properties.get(PRIVATE_PROPERTY), Some(PropertyValue::Bool(true)))
+}
+
+impl Context {
+    /// The part of this context `filter` publishes, with private
+    /// properties stripped, and the keys that were stripped.
+    pub fn published_subgraph(&self, filter: &PublishFilter) -> (Context, Vec<String>) {
+        let mut kept: HashSet<NodeId> = self.nodes().filter(|n| filter.selects(n)).map(|n| n.id.clone()).collect();
+
+        if !filter.exclude_provenance {
+            let cited: Vec<&NodeId> = kept.iter().collect();
+            let marks: Vec<NodeId> = provenance_for(self, &cited).into_iter().map(|c| c.mark_id).collect();
+            let chains: Vec<NodeId> = self
+                .edges()
+                .filter(|e| e.relationship == "contains" && marks.contains(&e.target))
+                .filter(|e| self.get_node(&e.source).is_some_and(|n| n.dimension == dimension::PROVENANCE))
+                .map(|e| e.source.clone())
+                .collect();
+            kept.extend(marks.into_iter().chain(chains).filter(|id| self.get_node(id).is_some_and(|n| !is_private(n))));
+        }
+
+        let mut stripped = BTreeSet::new();
+        let mut strip = |properties: &mut Properties| {
+            properties.retain(|key, _| {
+                let private = filter.is_private_key(key);
+                if private {
+                    stripped.insert(key.clone());
+                }
+                !private
+            });
+        };
+
+        let mut published = Context::with_id(self.id.clone(), self.name.clone());
+        published.description = self.description.clone();
+        published.metadata = ContextMetadata {
+            created_at: self.metadata.created_at,
+            updated_at: self.metadata.updated_at,
+            tags: self.metadata.tags.clone(),
+            ..Default::default()
+        };
+        for node in self.nodes().filter(|n| kept.contains(&n.id)) {
+            let mut node = node.clone();
+            strip(&mut node.properties);
+            published.nodes.insert(node.id.clone(), node);
+        }
+        for edge in self
+            .edges()
+            .filter(|e| kept.contains(&e.source) && kept.contains(&e.target) && filter.edges.edge_passes(e))
+        {
+            let mut edge = edge.clone();
+            strip(&mut edge.properties);
+            edge.contribution_log.clear();
+            published.edges.push(edge);
+        }
+        (published, stripped.into_iter().collect())
+    }
+}
+
+/// Write the published part of `context` to `path` (format chosen by
+/// `BundleFormat::for_path`) and its manifest beside it. Refuses to
+/// overwrite an existing bundle. Both files are written under a
+/// temporary name and renamed into place, so a failed publish leaves
+/// neither behind.
+pub(crate) fn write_bundle(context: &Context, filter: &PublishFilter, path: &Path) -> PlexusResult<PublishManifest> {
+    if path.exists() {
+        return Err(PlexusError::AlreadyExists(format!("bundle already exists: {}", path.display())));
+    }
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    let (published, stripped_properties) = context.published_subgraph(filter);
+    let format = BundleFormat::for_path(path);
+    write_into_place(path, |partial| {
+        match format {
+            BundleFormat::Sqlite => {
+                let store = SqliteStore::open(partial)?;
+                store.save_context(&published)?;
+            }
+            BundleFormat::Json => {
+                std::fs::write(partial, serde_json::to_vec_pretty(&published)?).map_err(io_error)?;
+            }
+        }
+        let mut permissions = std::fs::metadata(partial).map_err(io_error)?.permissions();
+        permissions.set_readonly(true);
+        std::fs::set_permissions(partial, permissions).map_err(io_error)
+    })?;
+
+    let manifest = PublishManifest {
+        format,
+        context: context.name.clone(),
+        published_at: Utc::now(),
+        plexus_version: env!("CARGO_PKG_VERSION").to_string(),
+        node_count: published.node_count(),
+        edge_count: published.edge_count(),
+        stripped_properties,
+        digest: content_digest(&published),
+    };
+    let written = serde_json::to_vec_pretty(&manifest).map_err(PlexusError::from).and_then(|bytes| {
+        write_into_place(&manifest_path(path), |partial| std::fs::write(partial, bytes).map_err(io_error))
+    });
+    if let Err(e) = written {
+        // A bundle without its manifest can't be verified
+        let _ = std::fs::remove_file(path);
+        return Err(e);
+    }
+    Ok(manifest)
+}
+
+/// Run `write` against a temporary sibling of `path`, then rename it
+/// into place. On failure the temporary file (and any SQLite journal
+/// beside it) is removed.
+fn write_into_place(path: &Path, write: impl FnOnce(&Path) -> PlexusResult<()>) -> PlexusResult<()> {
+    let mut name = std::ffi::OsString::from(".");
+    name.push(path.file_name().unwrap_or_default());
+    name.push(".partial");
+    let partial = path.with_file_name(name);
+    let result = write(&partial).and_then(|()| std::fs::rename(&partial, path).map_err(io_error));
+    if result.is_err() {
+        for suffix in ["", "-wal", "-shm"] {
+            let mut leftover = partial.clone().into_os_string();
+            leftover.push(suffix);
+            let _ = std::fs::remove_file(leftover);
+        }
+    }
+    result
+}
+
+/// Read the context out of a bundle without writing to it.
+pub fn open_bundle(path: impl AsRef<Path>) -> PlexusResult<Context> {
+    let path = path.as_ref();
+    match BundleFormat::for_path(path) {
+        BundleFormat::Json => Ok(serde_json::from_slice(&std::fs::read(path).map_err(io_error)?)?),
+        BundleFormat::Sqlite => {
+            // Copy into memory: opening the file itself would migrate it
+            let store = SqliteStore::open_in_memory()?;
+            store.restore(path)?;
+            let id = store
+                .list_contexts()?
+                .into_iter()
+                .next()
+                .ok_or_else(|| PlexusError::InvalidInput(format!("bundle has no context: {}", path.display())))?;
+            store.load_context(&id)?.ok_or(PlexusError::ContextNotFound(id))
+        }
+    }
+}
+
+/// Check a bundle against its manifest: the digest and counts must match.
+pub fn verify_bundle(path: impl AsRef<Path>) -> PlexusResult<PublishManifest> {
+    let path = path.as_ref();
+    let manifest: PublishManifest =
+        serde_json::from_slice(&std::fs::read(manifest_path(path)).map_err(io_error)?)?;
+    let context = open_bundle(path)?;
+    let digest = content_digest(&context);
+    if digest != manifest.digest
+        || context.node_count() != manifest.node_count
+        || context.edge_count() != manifest.edge_count
+    {
+        return Err(PlexusError::InvalidInput(format!(
+            "bundle {} does not match its manifest (digest {}, expected {})",
+            path.display(),
+            digest,
+            manifest.digest
+        )));
+    }
+    Ok(manifest)
+}
+
+fn io_error(e: std::io::Error) -> PlexusError {
+    PlexusError::Storage(e.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{ContentType, PlexusEngine};
+
+    fn engine_with_graph() -> (PlexusEngine, crate::graph::ContextId) {
+        let engine = PlexusEngine::new();
+        let mut ctx = Context::new("paper");
+        ctx.metadata.owner = Some("alice".into());
+        let mut concept = Node::new_in_dimension("concept", ContentType::Concept, dimension::SEMANTIC);
+        concept.id = NodeId::from_string("concept:privacy");
+        concept.properties.insert("label".into(), PropertyValue::from("privacy"));
+        concept.properties.insert("reviewer_notes".into(), PropertyValue::from("draft"));
+        concept.properties.insert("_natural_key".into(), PropertyValue::from("privacy"));
+        ctx.add_node(concept);
+        let mut secret = Node::new_in_dimension("concept", ContentType::Concept, dimension::SEMANTIC);
+        secret.id = NodeId::from_string("concept:unpublished");
+        secret.properties.insert(PRIVATE_PROPERTY.into(), PropertyValue::Bool(true));
+        ctx.add_node(secret);
+        let mut chain = Node::new_in_dimension("chain", ContentType::Provenance, dimension::PROVENANCE);
+        chain.id = NodeId::from_string("chain:notes");
+        ctx.add_node(chain);
+        let mut mark = Node::new_in_dimension("mark", ContentType::Provenance, dimension::PROVENANCE);
+        mark.id = NodeId::from_string("mark:1");
+        ctx.add_node(mark);
+        let mut fragment = Node::new_in_dimension("fragment", ContentType::Document, dimension::STRUCTURE);
+        fragment.id = NodeId::from_string("fragment:1");
+        ctx.add_node(fragment);
+        for (source, target, relationship) in [
+            ("mark:1", "concept:privacy", "references"),
+            ("chain:notes", "mark:1", "contains"),
+            ("concept:privacy", "concept:unpublished", "related_to"),
+            ("fragment:1", "concept:privacy", "tagged_with"),
+        ] {
+            let mut edge = Edge::new(NodeId::from_string(source), NodeId::from_string(target), relationship);
+            edge.contributions.insert("test".into(), 1.0);
+            ctx.add_edge(edge);
+        }
+        let id = engine.upsert_context(ctx).unwrap();
+        (engine, id)
+    }
+
+    fn scratch(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("plexus-publish-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join(name)
+    }
+
+    // === Scenario: Filtering keeps provenance and drops private data ===
+    #[test]
+    fn published_subgraph_filters_and_strips() {
+        let (engine, id) = engine_with_graph();
+        let ctx = engine.get_context(&id).unwrap();
+        let filter = PublishFilter::new().with_node_types(["concept"]).with_private_properties(["reviewer_notes"]);
+
+        let (published, stripped) = ctx.published_subgraph(&filter);
+        let mut ids: Vec<&str> = published.nodes.keys().map(|n| n.as_str()).collect();
+        ids.sort();
+        assert_eq!(ids, ["chain:notes", "concept:privacy", "mark:1"], "private node and fragment left out");
+        assert_eq!(published.edge_count(), 2, "references and contains survive");
+        assert_eq!(stripped, ["_natural_key", "reviewer_notes"]);
+        let concept = published.get_node(&NodeId::from_string("concept:privacy")).unwrap();
+        assert!(concept.properties.contains_key("label"));
+        assert!(published.metadata.owner.is_none());
+
+        let (bare, _) = ctx.published_subgraph(&filter.without_provenance());
+        assert_eq!(bare.node_count(), 1);
+        assert_eq!(bare.edge_count(), 0);
+    }
+
+    // === Scenario: Both bundle formats verify against their manifests ===
+    #[test]
+    fn bundles_round_trip_and_verify() {
+        let (engine, id) = engine_with_graph();
+        for name in ["bundle.db", "bundle.json"] {
+            let path = scratch(name);
+            let manifest = engine.publish(&id, &PublishFilter::new(), &path).unwrap();
+            assert_eq!(manifest.format, BundleFormat::for_path(&path));
+            assert_eq!(manifest.node_count, 4);
+            assert_eq!(manifest.edge_count, 3);
+            assert_eq!(manifest.context, "paper");
+            assert!(std::fs::metadata(&path).unwrap().permissions().readonly());
+
+            assert_eq!(verify_bundle(&path).unwrap(), manifest);
+            let bundle = open_bundle(&path).unwrap();
+            assert_eq!(bundle.node_count(), 4);
+            assert!(engine.publish(&id, &PublishFilter::new(), &path).is_err(), "never overwrites");
+        }
+    }
+
+    // === Scenario: A failed publish leaves no partial files ===
+    #[test]
+    fn failed_publish_leaves_nothing_behind() {
+        let (engine, id) = engine_with_graph();
+        for name in ["bundle.db", "bundle.json"] {
+            let path = scratch(name);
+            // A directory in the manifest's place makes its rename fail
+            std::fs::create_dir_all(manifest_path(&path)).unwrap();
+
+            assert!(engine.publish(&id, &PublishFilter::new(), &path).is_err());
+            let left: Vec<_> = std::fs::read_dir(path.parent().unwrap())
+                .unwrap()
+                .map(|entry| entry.unwrap().file_name())
+                .filter(|file| file != manifest_path(&path).file_name().unwrap())
+                .collect();
+            assert!(left.is_empty(), "left behind: {left:?}");
+        }
+    }
+
+    // === Scenario: A tampered bundle fails verification ===
+    #[test]
+    fn tampered_manifest_fails_verification() {
+        let (engine, id) = engine_with_graph();
+        let path = scratch("bundle.json");
+        let mut manifest = engine.publish(&id, &PublishFilter::new(), &path).unwrap();
+        manifest.digest = uuid::Uuid::nil().to_string();
+        std::fs::write(manifest_path(&path), serde_json::to_vec(&manifest).unwrap()).unwrap();
+
+        assert!(verify_bundle(&path).is_err());
+    }
+}
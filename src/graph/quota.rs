@@ -0,0 +1,163 @@
+//! Context quotas: size guardrails for a single context
+//!
+//! Stored on `ContextMetadata::quota` and enforced by `EngineSink`, which
+//! rejects what would take a context past a limit with
+//! `RejectionReason::QuotaExceeded`; everything else in the emission still
+//! commits. Engine writes outside the sink (`add_node`, `upsert_context`,
+//! `merge_copy`, `commit_overlay`) fail whole with
+//! `PlexusError::ContextQuotaExceeded` instead. The ingest that takes a
+//! context past `warn_ratio` of a limit returns a `context_quota_warning`
+//! outbound event so operators hear about a runaway adapter or enrichment
+//! loop before writes start failing. Tenant-wide limits are
+//! `TenantQuota`'s job.
+
+use super::context::Context;
+use super::node::{Node, PropertyValue};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The share of a limit past which ingests warn, when unset.
+pub const DEFAULT_QUOTA_WARN_RATIO: f64 = 0.8;
+
+/// Outbound event kind for the advisory warning.
+pub const QUOTA_WARNING_EVENT: &str = "context_quota_warning";
+
+/// Limits on one context. `None` means unlimited.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ContextQuota {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_nodes: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_edges: Option<usize>,
+    /// Largest serialized size of one node's properties, in bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_property_bytes: Option<usize>,
+    /// Share of `max_nodes` / `max_edges` past which ingests warn, in
+    /// 0..=1; defaults to `DEFAULT_QUOTA_WARN_RATIO`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warn_ratio: Option<f64>,
+}
+
+impl ContextQuota {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_nodes(mut self, max: usize) -> Self {
+        self.max_nodes = Some(max);
+        self
+    }
+
+    pub fn with_max_edges(mut self, max: usize) -> Self {
+        self.max_edges = Some(max);
+        self
+    }
+
+    pub fn with_max_property_bytes(mut self, max: usize) -> Self {
+        self.max_property_bytes = Some(max);
+        self
+    }
+
+    pub fn with_warn_ratio(mut self, ratio: f64) -> Self {
+        self.warn_ratio = Some(ratio);
+        self
+    }
+
+    /// Why the quota is unusable, if it is: `warn_ratio` must lie in 0..=1.
+    pub fn validate(&self) -> Result<(), String> {
+        match self.warn_ratio {
+            Some(ratio) if !(0.0..=1.0).contains(&ratio) => Err(format!("warn_ratio must be between 0 and 1, got {ratio}")),
+            _ => Ok(()),
+        }
+    }
+
+    /// Why `properties` are too large for one node, if they are.
+    pub fn check_properties(&self, properties: &HashMap<String, PropertyValue>) -> Result<(), String> {
+        let Some(max) = self.max_property_bytes else {
+            return Ok(());
+        };
+        let bytes = property_bytes(properties);
+        if bytes > max {
+            Err(format!("{bytes} property bytes (limit {max})"))
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn check_node(&self, node: &Node) -> Result<(), String> {
+        self.check_properties(&node.properties)
+    }
+
+    /// Why adding or upserting `node` into `context` breaks the quota, if
+    /// it does.
+    pub fn check_insert(&self, context: &Context, node: &Node) -> Result<(), String> {
+        self.check_node(node)?;
+        match self.max_nodes {
+            Some(max) if context.get_node(&node.id).is_none() && context.node_count() >= max => {
+                Err(format!("context holds {} nodes (limit {max})", context.node_count()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Why replacing `before` with `after` breaks the quota, if it does:
+    /// growth past a node or edge limit, or a new or changed node with
+    /// oversized properties. A context already past a limit may keep
+    /// what it has.
+    pub fn check_growth(&self, before: &Context, after: &Context) -> Result<(), String> {
+        let usage = [
+            ("nodes", self.max_nodes, before.node_count(), after.node_count()),
+            ("edges", self.max_edges, before.edge_count(), after.edge_count()),
+        ];
+        for (what, max, was, now) in usage {
+            if let Some(max) = max.filter(|max| now > *max && now > was) {
+                return Err(format!("context would hold {now} {what} (limit {max})"));
+            }
+        }
+        if self.max_property_bytes.is_some() {
+            for node in after.nodes() {
+                if before.get_node(&node.id).is_some_and(|old| old.properties == node.properties) {
+                    continue;
+                }
+                self.check_node(node).map_err(|reason| format!("node {}: {reason}", node.id))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The advisory warning for `context`, if it is past `warn_ratio` of
+    /// its node or edge limit.
+    pub fn warning(&self, context: &Context) -> Option<String> {
+        self.warning_since(context, None)
+    }
+
+    /// The advisory warning for `context`, if it went past `warn_ratio`
+    /// of a limit since it held `before` (nodes, edges). A context that
+    /// was already past it stays quiet, so the warning comes once per
+    /// crossing rather than on every ingest.
+    pub fn crossed_warning(&self, context: &Context, before: (usize, usize)) -> Option<String> {
+        self.warning_since(context, Some(before))
+    }
+
+    fn warning_since(&self, context: &Context, before: Option<(usize, usize)>) -> Option<String> {
+        let ratio = self.warn_ratio.unwrap_or(DEFAULT_QUOTA_WARN_RATIO);
+        let usage = [
+            ("nodes", self.max_nodes, before.map(|b| b.0), context.node_count()),
+            ("edges", self.max_edges, before.map(|b| b.1), context.edge_count()),
+        ];
+        let warnings: Vec<String> = usage
+            .into_iter()
+            .filter_map(|(what, max, was, used)| {
+                let max = max?;
+                let past = |count: usize| count as f64 >= max as f64 * ratio;
+                (past(used) && !was.is_some_and(past)).then(|| format!("{used} of {max} {what}"))
+            })
+            .collect();
+        (!warnings.is_empty()).then(|| format!("context '{}' is near its quota: {}", context.name, warnings.join(", ")))
+    }
+}
+
+/// Serialized size of a property map, as the store would write it.
+fn property_bytes(properties: &HashMap<String, PropertyValue>) -> usize {
+    serde_json::to_vec(properties).map_or(0, |bytes| bytes.len())
+}
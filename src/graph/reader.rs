@@ -0,0 +1,82 @@
+//! Snapshot readers for long-running analytics
+//!
+//! `PlexusEngine::reader` copies a context once and hands back an
+//! immutable, shared handle. PageRank, community detection, and other
+//! whole-graph passes can run on it in a background thread while ingest
+//! keeps writing to the live context: the handle never changes, and
+//! holding it never blocks a writer.
+
+use super::context::Context;
+use chrono::{DateTime, Utc};
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// A consistent, read-only view of a context as of `taken_at`.
+///
+/// Cloning shares the snapshot rather than copying it, so one reader can
+/// be fanned out to several worker threads.
+#[derive(Debug, Clone)]
+pub struct ContextReader {
+    snapshot: Arc<Context>,
+    taken_at: DateTime<Utc>,
+}
+
+impl ContextReader {
+    pub(crate) fn new(context: Context) -> Self {
+        Self { snapshot: Arc::new(context), taken_at: Utc::now() }
+    }
+
+    /// When the snapshot was taken.
+    pub fn taken_at(&self) -> DateTime<Utc> {
+        self.taken_at
+    }
+
+    /// The snapshot as a plain context reference.
+    pub fn context(&self) -> &Context {
+        &self.snapshot
+    }
+}
+
+impl Deref for ContextReader {
+    type Target = Context;
+
+    fn deref(&self) -> &Context {
+        &self.snapshot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{ContentType, Context, Node, PlexusEngine};
+    use std::sync::Arc;
+
+    // === Scenario: A reader keeps its view while ingest continues ===
+    #[test]
+    fn reader_is_unaffected_by_later_writes() {
+        let engine = Arc::new(PlexusEngine::new());
+        let id = engine.upsert_context(Context::new("analytics")).unwrap();
+        for _ in 0..3 {
+            engine.add_node(&id, Node::new("concept", ContentType::Concept)).unwrap();
+        }
+
+        let reader = engine.reader(&id).unwrap();
+        let shared = reader.clone();
+        let analysis = std::thread::spawn(move || shared.node_count());
+
+        let writer = {
+            let engine = engine.clone();
+            let id = id.clone();
+            std::thread::spawn(move || {
+                for _ in 0..100 {
+                    engine.add_node(&id, Node::new("concept", ContentType::Concept)).unwrap();
+                }
+            })
+        };
+        writer.join().unwrap();
+
+        assert_eq!(analysis.join().unwrap(), 3);
+        assert_eq!(reader.node_count(), 3);
+        assert_eq!(engine.reader(&id).unwrap().node_count(), 103);
+        assert!(engine.reader(&"missing".into()).is_err());
+    }
+}
@@ -0,0 +1,445 @@
+//! Relocating a tracked file or directory
+//!
+//! A file's path is baked into the graph in several places: context
+//! sources, path properties on marks, chunks and file nodes, and the IDs
+//! of nodes keyed by path (`file:{path}`, `extraction-status:{path}`,
+//! `mark:{adapter}:{path}:…`), live or trashed. Renaming the file on disk
+//! leaves all of them pointing at nothing. `Context::relocate_path`
+//! rewrites them together; `PlexusEngine::relocate_path` commits the
+//! result in one save and moves the store's embeddings and emission
+//! records to the new IDs.
+//!
+//! Registration stamps file nodes with a content hash (and an inode on
+//! Unix) so `detect_relocations` can pair a vanished path with the
+//! untracked file it became. `RelocationWatcher` polls for such pairs.
+
+use super::context::{Context, Source, CONTENT_HASH_PROPERTY};
+use super::engine::{PlexusError, PlexusResult};
+use super::node::{Node, NodeId, PropertyValue};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// Node properties holding a file path.
+pub const PATH_PROPERTIES: &[&str] = &["file", "file_path", "path", "source"];
+
+/// Prefixes of node IDs that embed a file path.
+pub const PATH_KEYED_PREFIXES: &[&str] = &["file:", "extraction-status:", "mark:"];
+
+/// File node property holding the file's inode, where the platform has one.
+pub const INODE_PROPERTY: &str = "inode";
+
+/// What one relocation rewrote.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct RelocationReport {
+    pub from: String,
+    pub to: String,
+    /// Context sources rewritten
+    pub sources: usize,
+    /// Path and node-reference properties rewritten
+    pub properties: usize,
+    /// Nodes with rewritten properties, by their (new) IDs
+    pub updated: Vec<NodeId>,
+    /// Nodes whose path-derived IDs changed, as (old, new)
+    pub renamed: Vec<(NodeId, NodeId)>,
+    /// Trashed nodes whose path-derived IDs changed, as (old, new)
+    pub trashed: Vec<(NodeId, NodeId)>,
+    /// The rewritten property keys of each `updated` node
+    #[serde(skip)]
+    pub(crate) changed_keys: Vec<(NodeId, Vec<String>)>,
+}
+
+impl RelocationReport {
+    pub fn is_empty(&self) -> bool {
+        self.sources == 0 && self.properties == 0 && self.renamed.is_empty() && self.trashed.is_empty()
+    }
+
+    /// Every rename, live and trashed.
+    pub fn all_renames(&self) -> impl Iterator<Item = &(NodeId, NodeId)> {
+        self.renamed.iter().chain(&self.trashed)
+    }
+}
+
+/// A vanished path paired with the file it appears to have become.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Relocation {
+    pub from: String,
+    pub to: String,
+    /// `"inode"` or `"content_hash"`
+    pub matched_by: String,
+}
+
+/// Hash of a file's bytes, as stamped on file nodes at registration.
+pub fn file_content_hash(bytes: &[u8]) -> String {
+    uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_OID, bytes).to_string()
+}
+
+/// The file's inode, on platforms that have one.
+pub fn file_inode(metadata: &std::fs::Metadata) -> Option<i64> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        Some(metadata.ino() as i64)
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = metadata;
+        None
+    }
+}
+
+/// Tracked files whose paths no longer exist, each paired with the
+/// untracked file it became: the one with its inode, else the one with
+/// its content hash. Candidates are the files beside each vanished path
+/// and under the context's directory sources; a path with more than one
+/// candidate is left alone. Unresolved link placeholders never existed,
+/// so they are never looked for.
+pub fn detect_relocations(context: &Context) -> Vec<Relocation> {
+    let unresolved = |n: &Node| matches!(n.properties.get("unresolved"), Some(PropertyValue::Bool(true)));
+    let mut vanished: Vec<(&str, &Node)> = context
+        .nodes()
+        .filter(|n| n.node_type == "file" && !unresolved(n))
+        .filter_map(|n| n.get_str("path").map(|path| (path, n)))
+        .filter(|(path, _)| !Path::new(path).exists())
+        .collect();
+    if vanished.is_empty() {
+        return Vec::new();
+    }
+    vanished.sort_by_key(|(path, _)| *path);
+
+    let mut dirs: BTreeMap<PathBuf, bool> = BTreeMap::new();
+    for source in &context.metadata.sources {
+        if let Source::Directory { path, recursive } = source {
+            *dirs.entry(PathBuf::from(path)).or_default() |= *recursive;
+        }
+    }
+    for (path, _) in &vanished {
+        if let Some(parent) = Path::new(path).parent() {
+            dirs.entry(parent.to_path_buf()).or_default();
+        }
+    }
+    let mut candidates = Vec::new();
+    for (dir, recursive) in dirs {
+        list_files(&dir, recursive, &mut candidates);
+    }
+    candidates.sort();
+    candidates.dedup();
+    let candidates: Vec<String> = candidates
+        .into_iter()
+        .map(|p| p.to_string_lossy().into_owned())
+        .filter(|p| context.get_node(&NodeId::from_string(format!("file:{}", p))).is_none())
+        .collect();
+
+    let inodes: Vec<Option<i64>> = candidates.iter().map(|p| std::fs::metadata(p).ok().and_then(|m| file_inode(&m))).collect();
+    let mut hashes: HashMap<usize, Option<String>> = HashMap::new();
+    let mut claimed = HashSet::new();
+    let mut relocations = Vec::new();
+    for (from, node) in vanished {
+        let by_inode: Vec<usize> = match node.get_int(INODE_PROPERTY) {
+            Some(inode) => (0..candidates.len()).filter(|c| inodes[*c] == Some(inode)).collect(),
+            None => Vec::new(),
+        };
+        let (matches, matched_by) = match node.get_str(CONTENT_HASH_PROPERTY) {
+            _ if !by_inode.is_empty() => (by_inode, "inode"),
+            Some(hash) => {
+                let by_hash = (0..candidates.len())
+                    .filter(|c| {
+                        let digest = hashes
+                            .entry(*c)
+                            .or_insert_with(|| std::fs::read(&candidates[*c]).ok().map(|bytes| file_content_hash(&bytes)));
+                        digest.as_deref() == Some(hash)
+                    })
+                    .collect();
+                (by_hash, "content_hash")
+            }
+            None => continue,
+        };
+        let [only] = matches[..] else { continue };
+        if claimed.insert(only) {
+            relocations.push(Relocation { from: from.to_string(), to: candidates[only].clone(), matched_by: matched_by.to_string() });
+        }
+    }
+    relocations
+}
+
+/// Files under `dir`, skipping hidden entries and symlinks, which are
+/// never followed. Unreadable directories hold no candidates.
+fn list_files(dir: &Path, recursive: bool, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+        let path = entry.path();
+        let Ok(metadata) = std::fs::symlink_metadata(&path) else { continue };
+        if metadata.is_dir() {
+            if recursive {
+                list_files(&path, recursive, files);
+            }
+        } else if metadata.is_file() {
+            files.push(path);
+        }
+    }
+}
+
+/// `value` with the path `old` (or a path under it) moved to `new`.
+fn relocated(value: &str, old: &str, new: &str) -> Option<String> {
+    if value == old {
+        return Some(new.to_string());
+    }
+    let rest = value.strip_prefix(old)?;
+    rest.starts_with('/').then(|| format!("{}{}", new, rest))
+}
+
+/// The new ID of `node` when `old` moves to `new`: only for IDs keyed by
+/// path, or nodes whose path property is being moved.
+fn relocated_node_id(node: &Node, old: &str, new: &str) -> Option<NodeId> {
+    let id = node.id.as_str();
+    let keyed = PATH_KEYED_PREFIXES.iter().any(|prefix| id.starts_with(prefix))
+        || PATH_PROPERTIES.iter().any(|key| node.get_str(key).is_some_and(|path| relocated(path, old, new).is_some()));
+    keyed.then(|| relocated_id(id, old, new)).flatten().map(NodeId::from_string)
+}
+
+/// Rewrite `node`'s path properties and references to renamed nodes.
+/// Returns how many properties changed.
+fn relocate_properties(node: &mut Node, renames: &HashMap<NodeId, NodeId>, old: &str, new: &str) -> Vec<String> {
+    let mut changed = Vec::new();
+    for (key, value) in node.properties.iter_mut() {
+        let PropertyValue::String(text) = value else { continue };
+        let rewritten = match renames.get(&NodeId::from_string(text.as_str())) {
+            Some(to) => Some(to.to_string()),
+            None if PATH_PROPERTIES.contains(&key.as_str()) => relocated(text, old, new),
+            None => None,
+        };
+        if let Some(rewritten) = rewritten {
+            *text = rewritten;
+            changed.push(key.clone());
+        }
+    }
+    changed.sort();
+    changed
+}
+
+/// `id` with an embedded `:{old}` path segment moved to `new`.
+fn relocated_id(id: &str, old: &str, new: &str) -> Option<String> {
+    let needle = format!(":{}", old);
+    let mut from = 0;
+    while let Some(at) = id[from..].find(&needle).map(|i| i + from) {
+        let end = at + needle.len();
+        if id[end..].is_empty() || id[end..].starts_with([':', '/']) {
+            return Some(format!("{}:{}{}", &id[..at], new, &id[end..]));
+        }
+        from = at + 1;
+    }
+    None
+}
+
+/// A background thread polling for moved files (`detect_relocations`)
+/// and relocating them, until dropped. Created by
+/// `PlexusApi::watch_relocations`.
+pub struct RelocationWatcher {
+    stop: Option<mpsc::Sender<()>>,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+impl RelocationWatcher {
+    /// Run `poll` every `interval` on a new thread.
+    pub(crate) fn spawn(interval: Duration, poll: impl Fn() + Send + 'static) -> Self {
+        let (stop, stopped) = mpsc::channel::<()>();
+        let thread = std::thread::spawn(move || {
+            while let Err(mpsc::RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+                poll();
+            }
+        });
+        Self { stop: Some(stop), thread: Some(thread) }
+    }
+}
+
+impl Drop for RelocationWatcher {
+    fn drop(&mut self) {
+        self.stop.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Context {
+    /// Move everything recorded at `old` — a file, or a directory and the
+    /// files under it — to `new`. Fails if `new` is already tracked or
+    /// nothing references `old`.
+    pub fn relocate_path(&mut self, old: &str, new: &str) -> PlexusResult<RelocationReport> {
+        if old.is_empty() || new.is_empty() || old == new {
+            return Err(PlexusError::InvalidInput(format!("cannot relocate '{}' to '{}'", old, new)));
+        }
+        let renames: HashMap<NodeId, NodeId> = self
+            .nodes
+            .values()
+            .chain(self.trash.nodes.values().map(|tombstone| &tombstone.item))
+            .filter_map(|node| relocated_node_id(node, old, new).map(|to| (node.id.clone(), to)))
+            .collect();
+        let taken = |to: &NodeId| (self.nodes.contains_key(to) || self.trash.nodes.contains_key(to)) && !renames.contains_key(to);
+        if let Some(to) = renames.values().find(|to| taken(to)) {
+            return Err(PlexusError::AlreadyExists(format!("{} is already tracked", to)));
+        }
+
+        let mut report = RelocationReport { from: old.to_string(), to: new.to_string(), ..Default::default() };
+        for source in &mut self.metadata.sources {
+            let path = match source {
+                Source::File { path } | Source::Directory { path, .. } => path,
+                _ => continue,
+            };
+            if let Some(to) = relocated(path, old, new) {
+                *path = to;
+                report.sources += 1;
+            }
+        }
+
+        let mut live = Vec::new();
+        let mut trashed = Vec::new();
+        for (from, to) in &renames {
+            if let Some(mut node) = self.nodes.remove(from) {
+                node.id = to.clone();
+                self.nodes.insert(to.clone(), node);
+                live.push((from.clone(), to.clone()));
+            } else if let Some(mut tombstone) = self.trash.nodes.remove(from) {
+                tombstone.item.id = to.clone();
+                self.trash.nodes.insert(to.clone(), tombstone);
+                trashed.push((from.clone(), to.clone()));
+            }
+        }
+        for node in self.nodes.values_mut() {
+            let changed = relocate_properties(node, &renames, old, new);
+            if !changed.is_empty() {
+                report.properties += changed.len();
+                report.updated.push(node.id.clone());
+                report.changed_keys.push((node.id.clone(), changed));
+            }
+        }
+        for tombstone in self.trash.nodes.values_mut() {
+            report.properties += relocate_properties(&mut tombstone.item, &renames, old, new).len();
+        }
+        let edges = self.edges.iter_mut().chain(self.trash.edges.iter_mut().map(|tombstone| &mut tombstone.item));
+        for edge in edges {
+            if let Some(to) = renames.get(&edge.source) {
+                edge.source = to.clone();
+            }
+            if let Some(to) = renames.get(&edge.target) {
+                edge.target = to.clone();
+            }
+        }
+        report.renamed = live;
+        report.trashed = trashed;
+
+        if report.is_empty() {
+            return Err(PlexusError::NotFound(format!("nothing in context '{}' is tracked at {}", self.name, old)));
+        }
+        report.renamed.sort_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+        report.trashed.sort_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+        report.updated.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        report.changed_keys.sort_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{dimension, ContentType, Edge, Node};
+
+    fn node(ctx: &mut Context, node_type: &str, id: &str, properties: &[(&str, &str)]) -> NodeId {
+        let mut node = Node::new_in_dimension(node_type, ContentType::Document, dimension::STRUCTURE);
+        node.id = NodeId::from_string(id);
+        for (key, value) in properties {
+            node.properties.insert(key.to_string(), PropertyValue::from(*value));
+        }
+        ctx.add_node(node)
+    }
+
+    // === Scenario: Relocating a file rewrites sources, properties, IDs and edges together ===
+    #[test]
+    fn relocating_a_file_moves_everything_keyed_by_its_path() {
+        let mut ctx = Context::new("relocate");
+        ctx.metadata.sources.push(Source::File { path: "/notes/bread.md".into() });
+        ctx.metadata.sources.push(Source::File { path: "/notes/bread.md.bak".into() });
+        let file = node(&mut ctx, "file", "file:/notes/bread.md", &[("path", "/notes/bread.md")]);
+        let mark = node(&mut ctx, "mark", "mark:semantic:/notes/bread.md:intro", &[("file_path", "/notes/bread.md")]);
+        let cited = node(&mut ctx, "mark", "mark:content:fragment:1", &[("file", "/notes/bread.md"), ("chain_id", "chain:x")]);
+        let summary = node(&mut ctx, "summary", "summary:1", &[("of", "file:/notes/bread.md")]);
+        let quoted = node(&mut ctx, "concept", "concept:/notes/bread.md", &[]);
+        let rye = node(&mut ctx, "concept", "concept:rye", &[]);
+        ctx.add_edge(Edge::new(file.clone(), rye.clone(), "tagged_with"));
+        ctx.add_edge(Edge::new(mark, rye, "references"));
+
+        let report = ctx.relocate_path("/notes/bread.md", "/kitchen/bread.md").expect("relocated");
+        assert_eq!(report.sources, 1, "a path merely sharing a prefix stays put");
+        let moved = NodeId::from_string("file:/kitchen/bread.md");
+        assert_eq!(report.renamed[0], (file.clone(), moved.clone()));
+        assert!(ctx.get_node(&file).is_none());
+        assert_eq!(ctx.get_node(&moved).unwrap().get_str("path"), Some("/kitchen/bread.md"));
+        let moved_mark = NodeId::from_string("mark:semantic:/kitchen/bread.md:intro");
+        assert_eq!(ctx.get_node(&moved_mark).unwrap().get_str("file_path"), Some("/kitchen/bread.md"));
+        assert_eq!(ctx.get_node(&cited).unwrap().get_str("file"), Some("/kitchen/bread.md"));
+        assert_eq!(ctx.get_node(&summary).unwrap().get_str("of"), Some("file:/kitchen/bread.md"), "references follow renamed IDs");
+        assert!(ctx.edges().all(|e| e.source == moved || e.source == moved_mark));
+        assert!(ctx.get_node(&quoted).is_some(), "only path-keyed IDs are renamed");
+        assert!(report.changed_keys.contains(&(summary.clone(), vec!["of".to_string()])));
+        assert!(report.changed_keys.contains(&(cited.clone(), vec!["file".to_string()])), "unrelated keys aren't listed");
+
+        let err = ctx.relocate_path("/notes/bread.md", "/elsewhere.md").unwrap_err();
+        assert!(matches!(err, PlexusError::NotFound(_)), "{err}");
+    }
+
+    // === Scenario: Moving a directory relocates every file under it, but never onto a tracked one ===
+    #[test]
+    fn relocating_a_directory_moves_its_files_unless_the_target_is_taken() {
+        let mut ctx = Context::new("relocate");
+        ctx.metadata.sources.push(Source::Directory { path: "/notes".into(), recursive: true });
+        node(&mut ctx, "file", "file:/notes/a.md", &[("path", "/notes/a.md")]);
+        node(&mut ctx, "file", "file:/notes/sub/b.md", &[("path", "/notes/sub/b.md")]);
+        node(&mut ctx, "file", "file:/archive/a.md", &[("path", "/archive/a.md")]);
+
+        let err = ctx.relocate_path("/notes", "/archive").unwrap_err();
+        assert!(matches!(err, PlexusError::AlreadyExists(_)), "{err}");
+        assert!(ctx.get_node(&NodeId::from_string("file:/notes/a.md")).is_some(), "a refused relocation changes nothing");
+
+        let report = ctx.relocate_path("/notes", "/journal").expect("relocated");
+        assert_eq!((report.sources, report.renamed.len()), (1, 2));
+        assert_eq!(ctx.metadata.sources[0], Source::Directory { path: "/journal".into(), recursive: true });
+        assert!(ctx.get_node(&NodeId::from_string("file:/journal/sub/b.md")).is_some());
+    }
+
+    // === Scenario: Trashed nodes and edges move with the path ===
+    #[test]
+    fn relocating_moves_trashed_nodes_too() {
+        let mut ctx = Context::new("relocate");
+        let file = node(&mut ctx, "file", "file:/notes/a.md", &[("path", "/notes/a.md")]);
+        let mark = node(&mut ctx, "mark", "mark:semantic:/notes/a.md:1", &[("file_path", "/notes/a.md")]);
+        ctx.add_edge(Edge::new(mark.clone(), file.clone(), "in_file"));
+        ctx.trash_node(&mark);
+
+        let report = ctx.relocate_path("/notes/a.md", "/notes/b.md").expect("relocated");
+        let moved_mark = NodeId::from_string("mark:semantic:/notes/b.md:1");
+        assert_eq!(report.trashed, vec![(mark, moved_mark.clone())]);
+        let tombstone = &ctx.trash.nodes[&moved_mark];
+        assert_eq!(tombstone.item.get_str("file_path"), Some("/notes/b.md"));
+        assert!(ctx.trash.edges.iter().all(|t| t.item.target.as_str() == "file:/notes/b.md"));
+    }
+
+    // === Scenario: Symlinked files and directories aren't candidates ===
+    #[cfg(unix)]
+    #[test]
+    fn listing_files_skips_symlinks() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("real.md"), "rye").unwrap();
+        std::fs::create_dir(dir.path().join("sub")).unwrap();
+        std::os::unix::fs::symlink(dir.path().join("real.md"), dir.path().join("link.md")).unwrap();
+        std::os::unix::fs::symlink(dir.path(), dir.path().join("sub/loop")).unwrap();
+
+        let mut files = Vec::new();
+        list_files(dir.path(), true, &mut files);
+        assert_eq!(files, vec![dir.path().join("real.md")]);
+    }
+}
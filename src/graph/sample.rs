@@ -0,0 +1,348 @@
+//! Representative subgraph sampling for previews
+//!
+//! A UI cannot render a 500k-node context, but it can render a few
+//! thousand nodes that look like it. `Context::sample` picks `n` nodes,
+//! split across dimensions in the same proportions as the full context,
+//! and keeps every edge whose endpoints were both picked.
+
+use super::context::Context;
+use super::node::NodeId;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+/// How `Context::sample` chooses nodes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SampleStrategy {
+    /// Uniformly random nodes. Cheap, but sparse contexts sample to
+    /// mostly disconnected points.
+    RandomNode,
+    /// Forest-fire: ignite a random node, spread to a geometrically
+    /// distributed number of its neighbors, repeat. Keeps local
+    /// structure (clusters, chains) intact. `burn_probability` in
+    /// `[0, 1)` controls how far a fire spreads; 0.7 is a good default.
+    ForestFire { burn_probability: f64 },
+    /// Random nodes weighted by degree, so hubs are almost always kept.
+    DegreeWeighted,
+}
+
+impl Context {
+    /// Sample about `n` nodes and the edges between them.
+    ///
+    /// Each dimension contributes nodes in proportion to its share of
+    /// the context. If `n` covers the whole context, the result is a
+    /// copy of it. The sample keeps this context's ID, name, and
+    /// metadata.
+    pub fn sample(&self, strategy: SampleStrategy, n: usize) -> Context {
+        self.sample_seeded(strategy, n, entropy_seed())
+    }
+
+    /// `sample` with a fixed seed, for reproducible previews.
+    pub fn sample_seeded(&self, strategy: SampleStrategy, n: usize, seed: u64) -> Context {
+        if n >= self.nodes.len() {
+            return self.clone();
+        }
+
+        // Sort so a seed yields the same sample regardless of map order
+        let mut by_dimension: BTreeMap<&str, Vec<&NodeId>> = BTreeMap::new();
+        for node in self.nodes.values() {
+            by_dimension.entry(node.dimension.as_str()).or_default().push(&node.id);
+        }
+        for ids in by_dimension.values_mut() {
+            ids.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        }
+
+        let quotas = dimension_quotas(&by_dimension, n);
+        let mut rng = SplitMix64(seed);
+        let picked = match strategy {
+            SampleStrategy::RandomNode => sample_random(&by_dimension, &quotas, &mut rng),
+            SampleStrategy::DegreeWeighted => sample_degree_weighted(self, &by_dimension, &quotas, &mut rng),
+            SampleStrategy::ForestFire { burn_probability } => {
+                sample_forest_fire(self, &by_dimension, quotas, burn_probability, &mut rng)
+            }
+        };
+
+        Context {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            description: self.description.clone(),
+            nodes: picked
+                .iter()
+                .filter_map(|id| self.nodes.get_key_value(*id))
+                .map(|(id, node)| (id.clone(), node.clone()))
+                .collect(),
+            edges: self
+                .edges
+                .iter()
+                .filter(|e| picked.contains(&e.source) && picked.contains(&e.target))
+                .cloned()
+                .collect(),
+            metadata: self.metadata.clone(),
+            trash: Default::default(),
+        }
+    }
+}
+
+/// Split `n` across dimensions by share of nodes (largest remainder).
+fn dimension_quotas<'a>(by_dimension: &BTreeMap<&'a str, Vec<&NodeId>>, n: usize) -> HashMap<&'a str, usize> {
+    let total: usize = by_dimension.values().map(Vec::len).sum();
+    let mut quotas = HashMap::new();
+    let mut remainders = Vec::new();
+    let mut allocated = 0;
+    for (dimension, ids) in by_dimension {
+        let exact = ids.len() * n;
+        quotas.insert(*dimension, exact / total);
+        allocated += exact / total;
+        remainders.push((exact % total, *dimension));
+    }
+    // Largest remainder first; ties go to the alphabetically first dimension
+    remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(b.1)));
+    for (_, dimension) in remainders.into_iter().take(n - allocated) {
+        *quotas.get_mut(dimension).unwrap() += 1;
+    }
+    quotas
+}
+
+fn sample_random<'a>(
+    by_dimension: &BTreeMap<&str, Vec<&'a NodeId>>,
+    quotas: &HashMap<&str, usize>,
+    rng: &mut SplitMix64,
+) -> HashSet<&'a NodeId> {
+    let mut picked = HashSet::new();
+    for (dimension, ids) in by_dimension {
+        let mut ids = ids.clone();
+        let quota = quotas[dimension];
+        // Partial Fisher-Yates: the first `quota` slots end up uniform
+        for i in 0..quota {
+            let j = i + rng.below(ids.len() - i);
+            ids.swap(i, j);
+        }
+        picked.extend(ids.into_iter().take(quota));
+    }
+    picked
+}
+
+fn sample_degree_weighted<'a>(
+    context: &Context,
+    by_dimension: &BTreeMap<&str, Vec<&'a NodeId>>,
+    quotas: &HashMap<&str, usize>,
+    rng: &mut SplitMix64,
+) -> HashSet<&'a NodeId> {
+    let mut degree: HashMap<&NodeId, usize> = HashMap::new();
+    for edge in &context.edges {
+        *degree.entry(&edge.source).or_default() += 1;
+        *degree.entry(&edge.target).or_default() += 1;
+    }
+
+    let mut picked = HashSet::new();
+    for (dimension, ids) in by_dimension {
+        // Efraimidis-Spirakis: keep the top keys of ln(u) / weight.
+        // The +1 gives isolated nodes a chance.
+        let mut keyed: Vec<(f64, &NodeId)> = ids
+            .iter()
+            .map(|id| {
+                let weight = (degree.get(id).copied().unwrap_or(0) + 1) as f64;
+                (rng.unit_open().ln() / weight, *id)
+            })
+            .collect();
+        keyed.sort_by(|a, b| b.0.total_cmp(&a.0));
+        picked.extend(keyed.into_iter().take(quotas[dimension]).map(|(_, id)| id));
+    }
+    picked
+}
+
+fn sample_forest_fire<'a>(
+    context: &'a Context,
+    by_dimension: &BTreeMap<&str, Vec<&'a NodeId>>,
+    mut quotas: HashMap<&str, usize>,
+    burn_probability: f64,
+    rng: &mut SplitMix64,
+) -> HashSet<&'a NodeId> {
+    let burn_probability = burn_probability.clamp(0.0, 0.99);
+    let mut neighbors: HashMap<&NodeId, Vec<&NodeId>> = HashMap::new();
+    for edge in &context.edges {
+        neighbors.entry(&edge.source).or_default().push(&edge.target);
+        neighbors.entry(&edge.target).or_default().push(&edge.source);
+    }
+
+    // Ignition points, in random order. Every node is eventually either
+    // ignited or burned, so every quota fills.
+    let mut ignitions: Vec<&NodeId> = by_dimension.values().flatten().copied().collect();
+    for i in (1..ignitions.len()).rev() {
+        ignitions.swap(i, rng.below(i + 1));
+    }
+
+    let mut remaining: usize = quotas.values().sum();
+    let mut picked = HashSet::new();
+    let mut visited = HashSet::new();
+    for start in ignitions {
+        if remaining == 0 {
+            break;
+        }
+        if !visited.insert(start) {
+            continue;
+        }
+        let mut fire = VecDeque::from([start]);
+        while let Some(id) = fire.pop_front() {
+            let quota = quotas.get_mut(context.nodes[id].dimension.as_str()).unwrap();
+            if *quota > 0 {
+                *quota -= 1;
+                remaining -= 1;
+                picked.insert(id);
+                if remaining == 0 {
+                    break;
+                }
+            }
+
+            // Geometric burn count, mean p / (1 - p)
+            let mut burn = 0;
+            while rng.unit_open() < burn_probability {
+                burn += 1;
+            }
+            let mut unburned: Vec<&NodeId> = neighbors
+                .get(id)
+                .into_iter()
+                .flatten()
+                .copied()
+                .filter(|n| !visited.contains(n) && context.nodes.contains_key(*n))
+                .collect();
+            for i in 0..burn.min(unburned.len()) {
+                let j = i + rng.below(unburned.len() - i);
+                unburned.swap(i, j);
+                if visited.insert(unburned[i]) {
+                    fire.push_back(unburned[i]);
+                }
+            }
+        }
+    }
+    picked
+}
+
+fn entropy_seed() -> u64 {
+    let mut bytes = [0u8; 8];
+    if getrandom::getrandom(&mut bytes).is_ok() {
+        return u64::from_le_bytes(bytes);
+    }
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+/// Small seeded PRNG; sampling needs reproducibility, not crypto.
+pub(crate) struct SplitMix64(pub(crate) u64);
+
+impl SplitMix64 {
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `(0, 1)`, safe to take the log of
+    pub(crate) fn unit_open(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+    }
+
+    /// Uniform in `0..bound`
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{dimension, ContentType, Edge, Node};
+
+    fn node(dim: &str) -> Node {
+        Node::new_in_dimension("item", ContentType::Concept, dim)
+    }
+
+    /// 300 structure + 100 semantic nodes; structure nodes chained,
+    /// semantic node 0 linked to every other semantic node.
+    fn fixture() -> (Context, NodeId) {
+        let mut ctx = Context::new("large");
+        let mut previous: Option<NodeId> = None;
+        for _ in 0..300 {
+            let id = ctx.add_node(node(dimension::STRUCTURE));
+            if let Some(prev) = previous {
+                ctx.add_edge(Edge::new(prev, id.clone(), "next"));
+            }
+            previous = Some(id);
+        }
+        let hub = ctx.add_node(node(dimension::SEMANTIC));
+        for _ in 1..100 {
+            let id = ctx.add_node(node(dimension::SEMANTIC));
+            ctx.add_edge(Edge::new(hub.clone(), id, "related_to"));
+        }
+        (ctx, hub)
+    }
+
+    fn count_in(ctx: &Context, dim: &str) -> usize {
+        ctx.nodes().filter(|n| n.dimension == dim).count()
+    }
+
+    // === Scenario: Every strategy preserves dimension ratios ===
+    #[test]
+    fn strategies_preserve_dimension_ratios() {
+        let (ctx, _) = fixture();
+        for strategy in [
+            SampleStrategy::RandomNode,
+            SampleStrategy::DegreeWeighted,
+            SampleStrategy::ForestFire { burn_probability: 0.7 },
+        ] {
+            let sample = ctx.sample_seeded(strategy, 40, 7);
+            assert_eq!(sample.node_count(), 40, "{strategy:?}");
+            assert_eq!(count_in(&sample, dimension::STRUCTURE), 30, "{strategy:?}");
+            assert_eq!(count_in(&sample, dimension::SEMANTIC), 10, "{strategy:?}");
+            assert!(
+                sample.edges().all(|e| sample.nodes.contains_key(&e.source) && sample.nodes.contains_key(&e.target)),
+                "{strategy:?} keeps only induced edges"
+            );
+        }
+    }
+
+    // === Scenario: A seed makes the sample reproducible ===
+    #[test]
+    fn seeded_samples_are_reproducible() {
+        let (ctx, _) = fixture();
+        let strategy = SampleStrategy::ForestFire { burn_probability: 0.7 };
+        let ids = |c: Context| c.nodes.into_keys().collect::<HashSet<_>>();
+        assert_eq!(ids(ctx.sample_seeded(strategy, 25, 42)), ids(ctx.sample_seeded(strategy, 25, 42)));
+    }
+
+    // === Scenario: Asking for more than the context returns all of it ===
+    #[test]
+    fn oversized_sample_is_the_whole_context() {
+        let (ctx, _) = fixture();
+        let sample = ctx.sample(SampleStrategy::RandomNode, 1_000);
+        assert_eq!(sample.node_count(), ctx.node_count());
+        assert_eq!(sample.edge_count(), ctx.edge_count());
+        assert_eq!(sample.id, ctx.id);
+    }
+
+    // === Scenario: Forest-fire keeps more structure than random picks ===
+    #[test]
+    fn forest_fire_keeps_local_structure() {
+        let (ctx, _) = fixture();
+        let fire = ctx.sample_seeded(SampleStrategy::ForestFire { burn_probability: 0.7 }, 40, 3);
+        let random = ctx.sample_seeded(SampleStrategy::RandomNode, 40, 3);
+        assert!(
+            fire.edge_count() > random.edge_count(),
+            "fire {} edges vs random {}",
+            fire.edge_count(),
+            random.edge_count()
+        );
+    }
+
+    // === Scenario: Degree weighting keeps hubs ===
+    #[test]
+    fn degree_weighted_keeps_hubs() {
+        let (ctx, hub) = fixture();
+        let kept = (0..20)
+            .filter(|seed| ctx.sample_seeded(SampleStrategy::DegreeWeighted, 40, *seed).nodes.contains_key(&hub))
+            .count();
+        assert!(kept >= 18, "hub kept in {kept}/20 samples");
+    }
+}
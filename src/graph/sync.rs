@@ -0,0 +1,595 @@
+//! Offline merge of divergent context copies
+//!
+//! The same context edited on two machines diverges. `PlexusEngine::sync_with`
+//! reconciles this engine's contexts with another store's copies and writes
+//! the result to both. The merge is a state-based join, so whichever side
+//! runs it, both end up with the same graph:
+//!
+//! - Nodes and edges are a set union, keyed by node ID and by edge
+//!   identity (endpoints, relationship and dimensions; the ID for
+//!   `Parallel` relationships).
+//! - Properties and contribution slots are last-writer-wins: node
+//!   properties by the node's `modified_at` (else `created_at`), edge
+//!   properties by the edge's latest contribution, and each contribution
+//!   slot by its latest `contribution_log` entry.
+//! - A tombstone beats a live copy it postdates; an item written after its
+//!   deletion on the other machine survives.
+//!
+//! Where no timestamp decides, the value is picked by a fixed order (the
+//! larger contribution, or the later-sorting JSON) so both sides still
+//! agree, and the pick is reported as a `SyncConflict`.
+
+use super::context::Context;
+use super::edge::{Edge, EdgeId, EdgePolicy};
+use super::node::{Node, NodeId};
+use super::trash::Tombstone;
+use super::events::GraphEvent;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// A value the merge had to pick without a timestamp to decide by.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SyncConflict {
+    /// Node or edge ID
+    pub item: String,
+    /// Property key, `node_type`, `dimension`, or `contribution:<contributor>`
+    pub field: String,
+    pub kept: Value,
+    pub discarded: Value,
+}
+
+/// How one context changed locally when merged with its other copy.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ContextSync {
+    pub context: String,
+    /// New nodes, and nodes whose content changed
+    pub nodes_added: Vec<NodeId>,
+    pub nodes_removed: Vec<NodeId>,
+    pub edges_added: Vec<EdgeId>,
+    /// Edges whose contributions or properties changed
+    pub edges_updated: Vec<EdgeId>,
+    pub edges_removed: Vec<EdgeId>,
+    pub conflicts: Vec<SyncConflict>,
+}
+
+impl ContextSync {
+    /// Events that take the local copy to the merged one.
+    pub(crate) fn events(&self, context_id: &str, adapter_id: &str) -> Vec<GraphEvent> {
+        let mut events = Vec::new();
+        if !self.edges_removed.is_empty() {
+            events.push(GraphEvent::EdgesRemoved {
+                edge_ids: self.edges_removed.clone(),
+                adapter_id: adapter_id.to_string(),
+                context_id: context_id.to_string(),
+                reason: "direct".to_string(),
+            });
+        }
+        if !self.nodes_removed.is_empty() {
+            events.push(GraphEvent::NodesRemoved {
+                node_ids: self.nodes_removed.clone(),
+                adapter_id: adapter_id.to_string(),
+                context_id: context_id.to_string(),
+            });
+        }
+        if !self.nodes_added.is_empty() {
+            events.push(GraphEvent::NodesAdded {
+                node_ids: self.nodes_added.clone(),
+                adapter_id: adapter_id.to_string(),
+                context_id: context_id.to_string(),
+            });
+        }
+        if !self.edges_added.is_empty() {
+            events.push(GraphEvent::EdgesAdded {
+                edge_ids: self.edges_added.clone(),
+                adapter_id: adapter_id.to_string(),
+                context_id: context_id.to_string(),
+            });
+        }
+        if !self.edges_updated.is_empty() {
+            events.push(GraphEvent::WeightsChanged {
+                edge_ids: self.edges_updated.clone(),
+                adapter_id: adapter_id.to_string(),
+                context_id: context_id.to_string(),
+            });
+        }
+        events
+    }
+}
+
+/// Outcome of `PlexusEngine::sync_with`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SyncReport {
+    /// Contexts only the other store had, now copied here
+    pub pulled: Vec<String>,
+    /// Contexts only this engine had, now copied there
+    pub pushed: Vec<String>,
+    /// Contexts both had, merged
+    pub merged: Vec<ContextSync>,
+}
+
+impl SyncReport {
+    pub fn conflicts(&self) -> impl Iterator<Item = &SyncConflict> {
+        self.merged.iter().flat_map(|c| c.conflicts.iter())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum EdgeKey {
+    Id(EdgeId),
+    Logical(NodeId, NodeId, String, String, String),
+}
+
+/// Join two copies of a context. Symmetric: `merge_contexts(a, b)` and
+/// `merge_contexts(b, a)` build the same graph and report the same conflicts.
+pub fn merge_contexts(a: &Context, b: &Context) -> (Context, Vec<SyncConflict>) {
+    let mut conflicts = Vec::new();
+    let (base, other) = match order(a.metadata.updated_at, b.metadata.updated_at) {
+        Some(true) => (a, b),
+        Some(false) => (b, a),
+        None if render(&a.metadata) >= render(&b.metadata) => (a, b),
+        None => (b, a),
+    };
+    let mut merged = Context::with_id(base.id.clone(), base.name.clone());
+    merged.description = base.description.clone();
+    merged.metadata = base.metadata.clone();
+    for tag in &other.metadata.tags {
+        if !merged.metadata.tags.contains(tag) {
+            merged.metadata.tags.push(tag.clone());
+        }
+    }
+
+    // Nodes: union, with tombstones winning over older live copies
+    let node_ids: HashSet<&NodeId> =
+        [a, b].iter().flat_map(|c| c.nodes.keys().chain(c.trash.nodes.keys())).collect();
+    for id in node_ids {
+        let live = match (a.nodes.get(id), b.nodes.get(id)) {
+            (Some(x), Some(y)) => Some(merge_nodes(x, y, &mut conflicts)),
+            (x, y) => x.or(y).cloned(),
+        };
+        let tombstone = latest_tombstone(a.trash.nodes.get(id), b.trash.nodes.get(id));
+        match (live, tombstone) {
+            (Some(node), Some(t)) if node_stamp(&node).is_none_or(|at| at <= t.deleted_at) => {
+                merged.trash.nodes.insert(id.clone(), Tombstone { item: node, deleted_at: t.deleted_at });
+            }
+            (Some(node), _) => {
+                merged.nodes.insert(id.clone(), node);
+            }
+            (None, Some(t)) => {
+                merged.trash.nodes.insert(id.clone(), t.clone());
+            }
+            (None, None) => {}
+        }
+    }
+
+    // Edges: union by identity, with the same tombstone rule
+    let key = |e: &Edge| match merged.edge_policy(&e.relationship) {
+        EdgePolicy::Parallel => EdgeKey::Id(e.id.clone()),
+        EdgePolicy::Merge => EdgeKey::Logical(
+            e.source.clone(),
+            e.target.clone(),
+            e.relationship.clone(),
+            e.source_dimension.clone(),
+            e.target_dimension.clone(),
+        ),
+    };
+    let mut live: HashMap<EdgeKey, Vec<&Edge>> = HashMap::new();
+    for edge in a.edges.iter().chain(&b.edges) {
+        live.entry(key(edge)).or_default().push(edge);
+    }
+    let mut tombstones: HashMap<EdgeKey, &Tombstone<Edge>> = HashMap::new();
+    for t in a.trash.edges.iter().chain(&b.trash.edges) {
+        let slot = tombstones.entry(key(&t.item)).or_insert(t);
+        *slot = latest_tombstone(Some(*slot), Some(t)).unwrap_or(t);
+    }
+    let mut edges = Vec::new();
+    let mut trashed = Vec::new();
+    for (k, copies) in live {
+        let edge = copies
+            .into_iter()
+            .cloned()
+            .reduce(|x, y| merge_edges(&x, &y, &mut conflicts))
+            .expect("at least one copy");
+        match tombstones.remove(&k) {
+            Some(t) if edge_stamp(&edge) <= t.deleted_at => trashed.push(Tombstone { item: edge, deleted_at: t.deleted_at }),
+            _ => edges.push(edge),
+        }
+    }
+    trashed.extend(tombstones.into_values().cloned());
+
+    // Edges left dangling by a deleted endpoint follow it into the trash
+    let (kept, dangling): (Vec<Edge>, Vec<Edge>) = edges
+        .into_iter()
+        .partition(|e| merged.nodes.contains_key(&e.source) && merged.nodes.contains_key(&e.target));
+    for edge in dangling {
+        let deleted_at = [&edge.source, &edge.target]
+            .iter()
+            .filter_map(|id| merged.trash.nodes.get(*id).map(|t| t.deleted_at))
+            .max()
+            .unwrap_or_else(|| edge_stamp(&edge));
+        trashed.push(Tombstone { item: edge, deleted_at });
+    }
+    merged.edges = kept;
+    merged.edges.sort_by(|x, y| x.id.as_str().cmp(y.id.as_str()));
+    trashed.sort_by(|x, y| x.item.id.as_str().cmp(y.item.id.as_str()));
+    merged.trash.edges = trashed;
+    merged.recompute_combined_weights();
+
+    conflicts.sort_by(|x, y| (&x.item, &x.field).cmp(&(&y.item, &y.field)));
+    (merged, conflicts)
+}
+
+/// What merging changed relative to `before`.
+pub(crate) fn diff(before: &Context, merged: &Context, conflicts: Vec<SyncConflict>) -> ContextSync {
+    let before_edges: HashMap<&EdgeId, &Edge> = before.edges.iter().map(|e| (&e.id, e)).collect();
+    let after_edges: HashSet<&EdgeId> = merged.edges.iter().map(|e| &e.id).collect();
+    let mut sync = ContextSync { context: merged.name.clone(), conflicts, ..Default::default() };
+    for (id, node) in &merged.nodes {
+        if before.nodes.get(id).is_none_or(|old| render(old) != render(node)) {
+            sync.nodes_added.push(id.clone());
+        }
+    }
+    sync.nodes_removed = before.nodes.keys().filter(|id| !merged.nodes.contains_key(*id)).cloned().collect();
+    for edge in &merged.edges {
+        match before_edges.get(&edge.id) {
+            None => sync.edges_added.push(edge.id.clone()),
+            Some(old) if render(old) != render(edge) => sync.edges_updated.push(edge.id.clone()),
+            Some(_) => {}
+        }
+    }
+    sync.edges_removed = before_edges.keys().filter(|id| !after_edges.contains(*id)).map(|id| (*id).clone()).collect();
+    sync.nodes_added.sort_by(|x, y| x.as_str().cmp(y.as_str()));
+    sync.nodes_removed.sort_by(|x, y| x.as_str().cmp(y.as_str()));
+    sync.edges_added.sort_by(|x, y| x.as_str().cmp(y.as_str()));
+    sync.edges_updated.sort_by(|x, y| x.as_str().cmp(y.as_str()));
+    sync.edges_removed.sort_by(|x, y| x.as_str().cmp(y.as_str()));
+    sync
+}
+
+fn merge_nodes(a: &Node, b: &Node, conflicts: &mut Vec<SyncConflict>) -> Node {
+    let tied = order(node_stamp(a), node_stamp(b)).is_none();
+    let (winner, loser) = match order(node_stamp(a), node_stamp(b)) {
+        Some(true) => (a, b),
+        Some(false) => (b, a),
+        None if render(a) >= render(b) => (a, b),
+        None => (b, a),
+    };
+    let mut node = winner.clone();
+    node.metadata.modified_at = a.metadata.modified_at.max(b.metadata.modified_at);
+    if tied {
+        if winner.node_type != loser.node_type {
+            conflicts.push(conflict(node.id.as_str(), "node_type", &winner.node_type, &loser.node_type));
+        }
+        if winner.dimension != loser.dimension {
+            conflicts.push(conflict(node.id.as_str(), "dimension", &winner.dimension, &loser.dimension));
+        }
+    }
+    for (key, value) in sorted(&loser.properties) {
+        match node.properties.get(key) {
+            None => {
+                node.properties.insert(key.clone(), value.clone());
+            }
+            Some(kept) if tied && kept != value => conflicts.push(conflict(node.id.as_str(), key, kept, value)),
+            Some(_) => {}
+        }
+    }
+    node
+}
+
+fn merge_edges(a: &Edge, b: &Edge, conflicts: &mut Vec<SyncConflict>) -> Edge {
+    let mut edge = if a.id.as_str() <= b.id.as_str() { a.clone() } else { b.clone() };
+    edge.created_at = a.created_at.min(b.created_at);
+
+    let contributors: HashSet<&String> = a.contributions.keys().chain(b.contributions.keys()).collect();
+    let mut contributors: Vec<&String> = contributors.into_iter().collect();
+    contributors.sort();
+    edge.contributions.clear();
+    for contributor in contributors {
+        let value = match (a.contributions.get(contributor), b.contributions.get(contributor)) {
+            (Some(x), Some(y)) if x != y => match order(slot_stamp(a, contributor), slot_stamp(b, contributor)) {
+                Some(true) => *x,
+                Some(false) => *y,
+                None => {
+                    let (kept, discarded) = if x >= y { (x, y) } else { (y, x) };
+                    conflicts.push(conflict(edge.id.as_str(), &format!("contribution:{}", contributor), kept, discarded));
+                    *kept
+                }
+            },
+            (x, y) => *x.or(y).expect("contributor from either edge"),
+        };
+        edge.contributions.insert(contributor.clone(), value);
+    }
+    if a.contributions.is_empty() && b.contributions.is_empty() {
+        edge.combined_weight = a.combined_weight.max(b.combined_weight);
+    }
+
+    let mut log = a.contribution_log.clone();
+    for entry in &b.contribution_log {
+        if !log.contains(entry) {
+            log.push(entry.clone());
+        }
+    }
+    log.sort_by(|x, y| (x.at, &x.contributor).cmp(&(y.at, &y.contributor)).then(x.value.total_cmp(&y.value)));
+    edge.contribution_log = log;
+
+    // Some(true): `a` was written last
+    let newer = order(Some(edge_stamp(a)), Some(edge_stamp(b)));
+    let mut properties = a.properties.clone();
+    for (key, theirs) in sorted(&b.properties) {
+        let Some(mine) = properties.get(key).cloned() else {
+            properties.insert(key.clone(), theirs.clone());
+            continue;
+        };
+        if mine == *theirs {
+            continue;
+        }
+        match newer {
+            Some(true) => {}
+            Some(false) => {
+                properties.insert(key.clone(), theirs.clone());
+            }
+            None => {
+                let (kept, discarded) = if render(&mine) >= render(theirs) { (mine.clone(), theirs) } else { (theirs.clone(), &mine) };
+                conflicts.push(conflict(edge.id.as_str(), key, &kept, discarded));
+                properties.insert(key.clone(), kept);
+            }
+        }
+    }
+    edge.properties = properties;
+    edge
+}
+
+/// `Some(true)` when `a` is strictly later, `Some(false)` when `b` is,
+/// `None` on a tie (including both unknown).
+fn order(a: Option<DateTime<Utc>>, b: Option<DateTime<Utc>>) -> Option<bool> {
+    match a.cmp(&b) {
+        std::cmp::Ordering::Greater => Some(true),
+        std::cmp::Ordering::Less => Some(false),
+        std::cmp::Ordering::Equal => None,
+    }
+}
+
+fn node_stamp(node: &Node) -> Option<DateTime<Utc>> {
+    node.metadata.modified_at.or(node.metadata.created_at)
+}
+
+/// Latest write to an edge: its creation or its newest contribution.
+fn edge_stamp(edge: &Edge) -> DateTime<Utc> {
+    edge.contribution_log.iter().map(|c| c.at).max().unwrap_or(edge.created_at).max(edge.created_at)
+}
+
+fn slot_stamp(edge: &Edge, contributor: &str) -> Option<DateTime<Utc>> {
+    edge.contribution_log.iter().filter(|c| c.contributor == contributor).map(|c| c.at).max()
+}
+
+fn latest_tombstone<'a, T: Serialize>(
+    a: Option<&'a Tombstone<T>>,
+    b: Option<&'a Tombstone<T>>,
+) -> Option<&'a Tombstone<T>> {
+    match (a, b) {
+        (Some(x), Some(y)) => Some(match order(Some(x.deleted_at), Some(y.deleted_at)) {
+            Some(true) => x,
+            Some(false) => y,
+            None if render(&x.item) >= render(&y.item) => x,
+            None => y,
+        }),
+        (x, y) => x.or(y),
+    }
+}
+
+fn sorted<V>(properties: &HashMap<String, V>) -> BTreeMap<&String, &V> {
+    properties.iter().collect()
+}
+
+fn render(value: &impl Serialize) -> String {
+    serde_json::to_value(value).map(|v| v.to_string()).unwrap_or_default()
+}
+
+fn conflict(item: &str, field: &str, kept: &impl Serialize, discarded: &impl Serialize) -> SyncConflict {
+    SyncConflict {
+        item: item.to_string(),
+        field: field.to_string(),
+        kept: serde_json::to_value(kept).unwrap_or(Value::Null),
+        discarded: serde_json::to_value(discarded).unwrap_or(Value::Null),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{content_digest, dimension, ContentType, ContextId, PlexusEngine, PropertyValue};
+    use crate::storage::{GraphStore, OpenStore, SqliteStore};
+    use crate::storage::{StorageError, StorageResult};
+    use chrono::Duration;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    /// A SQLite store whose context saves can be made to fail.
+    struct FlakyStore {
+        inner: SqliteStore,
+        failing: AtomicBool,
+    }
+
+    impl FlakyStore {
+        fn new() -> Self {
+            Self { inner: SqliteStore::open_in_memory().unwrap(), failing: AtomicBool::new(false) }
+        }
+    }
+
+    impl GraphStore for FlakyStore {
+        fn save_context(&self, context: &Context) -> StorageResult<()> {
+            match self.failing.load(Ordering::SeqCst) {
+                true => Err(StorageError::Internal("disk full".into())),
+                false => self.inner.save_context(context),
+            }
+        }
+        fn save_context_metadata(&self, context: &Context) -> StorageResult<()> {
+            self.inner.save_context_metadata(context)
+        }
+        fn load_context(&self, id: &ContextId) -> StorageResult<Option<Context>> {
+            self.inner.load_context(id)
+        }
+        fn delete_context(&self, id: &ContextId) -> StorageResult<bool> {
+            self.inner.delete_context(id)
+        }
+        fn list_contexts(&self) -> StorageResult<Vec<ContextId>> {
+            self.inner.list_contexts()
+        }
+    }
+
+    fn node(id: &str, at: DateTime<Utc>) -> Node {
+        let mut node = Node::new_in_dimension("concept", ContentType::Concept, dimension::SEMANTIC);
+        node.id = NodeId::from_string(id);
+        node.metadata.created_at = Some(at);
+        node
+    }
+
+    fn edge(source: &str, target: &str, contributor: &str, value: f32) -> Edge {
+        let mut edge = Edge::new(NodeId::from_string(source), NodeId::from_string(target), "related_to");
+        edge.contributions.insert(contributor.into(), value);
+        edge
+    }
+
+    fn base() -> Context {
+        let t0 = Utc::now() - Duration::hours(1);
+        let mut ctx = Context::new("research");
+        ctx.metadata.updated_at = Some(t0);
+        for id in ["a", "b", "c"] {
+            ctx.add_node(node(id, t0));
+        }
+        ctx.add_edge(edge("a", "b", "manual", 1.0));
+        ctx
+    }
+
+    // === Scenario: Divergent edits union, and later writes win ===
+    #[test]
+    fn merge_unions_and_prefers_later_writes() {
+        let original = base();
+        let (mut laptop, mut desktop) = (original.clone(), original.clone());
+        let later = Utc::now();
+
+        laptop.add_node(node("d", later));
+        let mut a = laptop.get_node(&NodeId::from_string("a")).unwrap().clone();
+        a.metadata.modified_at = Some(later);
+        a.properties.insert("label".into(), PropertyValue::from("newer"));
+        laptop.add_node(a);
+        desktop.add_edge(edge("b", "c", "manual", 0.5));
+        let mut a = desktop.get_node(&NodeId::from_string("a")).unwrap().clone();
+        a.properties.insert("label".into(), PropertyValue::from("older"));
+        a.properties.insert("note".into(), PropertyValue::from("kept"));
+        desktop.add_node(a);
+
+        let (merged, conflicts) = merge_contexts(&laptop, &desktop);
+        assert_eq!(merged.node_count(), 4);
+        assert_eq!(merged.edge_count(), 2);
+        let a = merged.get_node(&NodeId::from_string("a")).unwrap();
+        assert_eq!(a.properties.get("label"), Some(&PropertyValue::from("newer")));
+        assert_eq!(a.properties.get("note"), Some(&PropertyValue::from("kept")));
+        assert!(conflicts.is_empty(), "timestamps decided everything: {:?}", conflicts);
+
+        let (reverse, _) = merge_contexts(&desktop, &laptop);
+        assert_eq!(content_digest(&merged), content_digest(&reverse), "merge is symmetric");
+    }
+
+    // === Scenario: Undecidable values are picked deterministically and reported ===
+    #[test]
+    fn ties_are_reported_as_conflicts() {
+        let original = base();
+        let (mut laptop, mut desktop) = (original.clone(), original.clone());
+        for (ctx, label, weight) in [(&mut laptop, "left", 2.0), (&mut desktop, "right", 3.0)] {
+            ctx.nodes.get_mut(&NodeId::from_string("b")).unwrap().properties.insert("label".into(), PropertyValue::from(label));
+            ctx.edges[0].contributions.insert("manual".into(), weight);
+        }
+
+        let (merged, conflicts) = merge_contexts(&laptop, &desktop);
+        let (reverse, reverse_conflicts) = merge_contexts(&desktop, &laptop);
+        assert_eq!(conflicts, reverse_conflicts);
+        assert_eq!(content_digest(&merged), content_digest(&reverse));
+        assert_eq!(conflicts.len(), 2);
+        let slot = conflicts.iter().find(|c| c.field == "contribution:manual").unwrap();
+        assert_eq!(slot.kept, serde_json::json!(3.0));
+        assert_eq!(merged.edges[0].contributions["manual"], 3.0);
+        assert!(conflicts.iter().any(|c| c.item == "b" && c.field == "label"));
+    }
+
+    // === Scenario: Deletions propagate unless the item was written afterwards ===
+    #[test]
+    fn tombstones_beat_older_copies() {
+        let original = base();
+        let (mut laptop, desktop) = (original.clone(), original.clone());
+        laptop.trash_node(&NodeId::from_string("b"));
+
+        let (merged, _) = merge_contexts(&laptop, &desktop);
+        assert!(merged.get_node(&NodeId::from_string("b")).is_none());
+        assert_eq!(merged.edge_count(), 0, "the edge followed its endpoint");
+        assert_eq!(merged.trash.len(), 2);
+
+        let mut revived = desktop.clone();
+        let b = revived.nodes.get_mut(&NodeId::from_string("b")).unwrap();
+        b.metadata.modified_at = Some(Utc::now() + Duration::minutes(1));
+        let (merged, _) = merge_contexts(&laptop, &revived);
+        assert!(merged.get_node(&NodeId::from_string("b")).is_some(), "edited after the delete");
+    }
+
+    // === Scenario: Syncing two stores leaves both with the merged graph ===
+    #[test]
+    fn sync_with_converges_both_stores() {
+        let original = base();
+        let laptop_store = Arc::new(SqliteStore::open_in_memory().unwrap());
+        let desktop_store = Arc::new(SqliteStore::open_in_memory().unwrap());
+        let laptop = PlexusEngine::with_store(laptop_store.clone());
+        laptop.upsert_context(original.clone()).unwrap();
+        let mut desktop = original.clone();
+        desktop.add_node(node("from-desktop", Utc::now()));
+        desktop_store.save_context(&desktop).unwrap();
+        desktop_store.save_context(&Context::new("desktop-only")).unwrap();
+        laptop.upsert_context(Context::new("laptop-only")).unwrap();
+
+        let report = laptop.sync_with(desktop_store.as_ref()).unwrap();
+        assert_eq!(report.pulled, ["desktop-only"]);
+        assert_eq!(report.pushed, ["laptop-only"]);
+        assert_eq!(report.merged.len(), 1);
+        assert_eq!(report.merged[0].nodes_added, [NodeId::from_string("from-desktop")]);
+
+        let here = laptop.get_context(&original.id).unwrap();
+        let there = desktop_store.load_context(&original.id).unwrap().unwrap();
+        assert_eq!(here.node_count(), 4);
+        assert_eq!(content_digest(&here), content_digest(&there));
+        assert_eq!(desktop_store.list_contexts().unwrap().len(), 3);
+        assert!(laptop_store.load_context(&original.id).unwrap().unwrap().get_node(&NodeId::from_string("from-desktop")).is_some());
+    }
+
+    // === Scenario: A failed save on either side leaves both copies as they were ===
+    #[test]
+    fn failed_sync_leaves_both_copies_unchanged() {
+        let original = base();
+        let mut desktop = original.clone();
+        desktop.add_node(node("from-desktop", Utc::now()));
+        let from_desktop = NodeId::from_string("from-desktop");
+
+        // The remote save fails: nothing is merged here
+        let laptop = PlexusEngine::with_store(Arc::new(SqliteStore::open_in_memory().unwrap()));
+        laptop.upsert_context(original.clone()).unwrap();
+        let desktop_store = FlakyStore::new();
+        desktop_store.save_context(&desktop).unwrap();
+        desktop_store.failing.store(true, Ordering::SeqCst);
+        assert!(laptop.sync_with(&desktop_store).is_err());
+        assert!(laptop.get_context(&original.id).unwrap().get_node(&from_desktop).is_none());
+
+        // The local save fails: the remote gets its own copy back
+        let laptop_store = Arc::new(FlakyStore::new());
+        let laptop = PlexusEngine::with_store(laptop_store.clone());
+        laptop.upsert_context(original.clone()).unwrap();
+        laptop.add_node(&original.id, node("from-laptop", Utc::now())).unwrap();
+        let desktop_store = SqliteStore::open_in_memory().unwrap();
+        desktop_store.save_context(&desktop).unwrap();
+        laptop_store.failing.store(true, Ordering::SeqCst);
+        assert!(laptop.sync_with(&desktop_store).is_err());
+        let here = laptop.get_context(&original.id).unwrap();
+        let there = desktop_store.load_context(&original.id).unwrap().unwrap();
+        assert!(here.get_node(&from_desktop).is_none());
+        assert!(there.get_node(&NodeId::from_string("from-laptop")).is_none());
+        assert_eq!(content_digest(&there), content_digest(&desktop));
+    }
+}
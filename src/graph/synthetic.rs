@@ -0,0 +1,135 @@
+//! Synthetic graph generators for benchmarks and scale tests
+//!
+//! Every generator is seeded and deterministic: the same arguments build
+//! the same context, node IDs included (`{node_type}:{index}`), so a
+//! benchmark measures the same graph across runs and branches.
+
+use super::context::Context;
+use super::edge::Edge;
+use super::node::{dimension, ContentType, Node, NodeId};
+use super::sample::SplitMix64;
+
+/// Contributor recorded on generated edges.
+pub const SYNTHETIC_ADAPTER: &str = "synthetic";
+
+fn add_nodes(ctx: &mut Context, node_type: &str, content_type: ContentType, dim: &str, count: usize) -> Vec<NodeId> {
+    (0..count)
+        .map(|i| {
+            let mut node = Node::new_in_dimension(node_type, content_type.clone(), dim);
+            node.id = NodeId::from_string(format!("{node_type}:{i}"));
+            ctx.add_node(node)
+        })
+        .collect()
+}
+
+fn weighted_edge(source: &NodeId, target: &NodeId, relationship: &str, rng: &mut SplitMix64) -> Edge {
+    let weight = 0.1 + 0.9 * rng.unit_open() as f32;
+    let mut edge = Edge::new_in_dimension(source.clone(), target.clone(), relationship, dimension::SEMANTIC)
+        .with_contribution(SYNTHETIC_ADAPTER, weight);
+    edge.combined_weight = weight;
+    edge
+}
+
+/// Scale-free graph by preferential attachment (Barabási–Albert): each
+/// node after the first `edges_per_node` links to that many distinct
+/// earlier nodes, chosen in proportion to their degree. Degrees follow a
+/// power law, so a few hubs touch much of the graph. `related_to` edges
+/// point from the newer node to the older.
+pub fn scale_free(nodes: usize, edges_per_node: usize, seed: u64) -> Context {
+    let mut rng = SplitMix64(seed);
+    let mut ctx = Context::new("synthetic-scale-free");
+    let ids = add_nodes(&mut ctx, "concept", ContentType::Concept, dimension::SEMANTIC, nodes);
+    let m = edges_per_node.max(1);
+
+    // Every edge endpoint, so a uniform pick is a degree-weighted pick
+    let mut endpoints: Vec<usize> = Vec::with_capacity(2 * nodes * m);
+    let mut targets: Vec<usize> = Vec::with_capacity(m);
+    for i in m.min(nodes)..nodes {
+        targets.clear();
+        while targets.len() < m.min(i) {
+            let t = if endpoints.is_empty() { rng.below(i) } else { endpoints[rng.below(endpoints.len())] };
+            if !targets.contains(&t) {
+                targets.push(t);
+            }
+        }
+        for &t in &targets {
+            ctx.edges.push(weighted_edge(&ids[i], &ids[t], "related_to", &mut rng));
+            endpoints.extend([i, t]);
+        }
+    }
+    ctx
+}
+
+/// Uniform random graph: `edges` `related_to` edges between endpoints
+/// drawn independently from `nodes` nodes.
+pub fn uniform(nodes: usize, edges: usize, seed: u64) -> Context {
+    let mut rng = SplitMix64(seed);
+    let mut ctx = Context::new("synthetic-uniform");
+    let ids = add_nodes(&mut ctx, "concept", ContentType::Concept, dimension::SEMANTIC, nodes.max(1));
+    for _ in 0..edges {
+        let (a, b) = (rng.below(ids.len()), rng.below(ids.len()));
+        ctx.edges.push(weighted_edge(&ids[a], &ids[b], "related_to", &mut rng));
+    }
+    ctx
+}
+
+/// Fragments each `tagged_with` `tags_per_fragment` distinct concepts
+/// drawn uniformly from `concepts` — the shape co-occurrence runs on.
+pub fn tagged_fragments(fragments: usize, concepts: usize, tags_per_fragment: usize, seed: u64) -> Context {
+    let mut rng = SplitMix64(seed);
+    let mut ctx = Context::new("synthetic-tagged");
+    let fragment_ids = add_nodes(&mut ctx, "fragment", ContentType::Document, dimension::STRUCTURE, fragments);
+    let concept_ids = add_nodes(&mut ctx, "concept", ContentType::Concept, dimension::SEMANTIC, concepts.max(1));
+    let per_fragment = tags_per_fragment.min(concept_ids.len());
+    let mut tags: Vec<usize> = Vec::with_capacity(per_fragment);
+    for fragment in &fragment_ids {
+        tags.clear();
+        while tags.len() < per_fragment {
+            let t = rng.below(concept_ids.len());
+            if !tags.contains(&t) {
+                tags.push(t);
+            }
+        }
+        for &t in &tags {
+            ctx.edges.push(weighted_edge(fragment, &concept_ids[t], "tagged_with", &mut rng));
+        }
+    }
+    ctx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    // === Scenario: Preferential attachment grows hubs ===
+    #[test]
+    fn scale_free_has_expected_size_and_hubs() {
+        let ctx = scale_free(2_000, 3, 7);
+        assert_eq!(ctx.node_count(), 2_000);
+        assert_eq!(ctx.edge_count(), (2_000 - 3) * 3);
+
+        let mut degree: HashMap<&NodeId, usize> = HashMap::new();
+        for edge in &ctx.edges {
+            *degree.entry(&edge.source).or_default() += 1;
+            *degree.entry(&edge.target).or_default() += 1;
+        }
+        let max = *degree.values().max().unwrap();
+        assert!(max > 10 * 6, "hubs far exceed the mean degree of ~6, got {max}");
+    }
+
+    // === Scenario: Generators are reproducible from their seed ===
+    #[test]
+    fn generators_are_deterministic() {
+        let endpoints = |ctx: &Context| -> Vec<(String, String)> {
+            ctx.edges.iter().map(|e| (e.source.to_string(), e.target.to_string())).collect()
+        };
+        assert_eq!(endpoints(&scale_free(300, 2, 1)), endpoints(&scale_free(300, 2, 1)));
+        assert_ne!(endpoints(&uniform(300, 900, 1)), endpoints(&uniform(300, 900, 2)));
+
+        let tagged = tagged_fragments(50, 10, 4, 3);
+        assert_eq!(tagged.node_count(), 60);
+        assert_eq!(tagged.edges.iter().filter(|e| e.relationship == "tagged_with").count(), 200);
+        assert_eq!(endpoints(&tagged), endpoints(&tagged_fragments(50, 10, 4, 3)));
+    }
+}
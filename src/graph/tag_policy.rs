@@ -0,0 +1,168 @@
+//! Per-context tag policy: which tags become concepts
+//!
+//! Stored on `ContextMetadata` and handed to adapters through
+//! `AdapterInput`, so every ingest into a context filters tags the same way.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+/// Rules applied to incoming tags before any concept is created.
+///
+/// Tags are compared case-insensitively with surrounding whitespace and a
+/// leading `#` ignored. Rules apply in order: rewrite, stoplist, allowlist,
+/// minimum length, de-duplication, then the per-fragment cap.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagPolicy {
+    /// Tags that never become concepts.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub stoplist: BTreeSet<String>,
+    /// When set, only these tags become concepts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowlist: Option<BTreeSet<String>>,
+    /// Maximum tags kept per fragment; the first ones in input order win.
+    /// Zero keeps none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tags: Option<usize>,
+    /// Minimum tag length in characters.
+    #[serde(default)]
+    pub min_length: usize,
+    /// Tag → replacement, e.g. `"ml" → "machine learning"`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub rewrites: BTreeMap<String, String>,
+}
+
+/// Comparison key for a tag under a policy.
+fn tag_key(tag: &str) -> String {
+    tag.trim().trim_start_matches('#').trim().to_lowercase()
+}
+
+impl TagPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a tag to the stoplist.
+    pub fn with_stop(mut self, tag: &str) -> Self {
+        self.stoplist.insert(tag_key(tag));
+        self
+    }
+
+    /// Add a tag to the allowlist (creating it if unset).
+    pub fn with_allowed(mut self, tag: &str) -> Self {
+        self.allowlist.get_or_insert_with(BTreeSet::new).insert(tag_key(tag));
+        self
+    }
+
+    pub fn with_max_tags(mut self, max: usize) -> Self {
+        self.max_tags = Some(max);
+        self
+    }
+
+    pub fn with_min_length(mut self, min: usize) -> Self {
+        self.min_length = min;
+        self
+    }
+
+    /// Rewrite `from` to `to` before the other rules run.
+    pub fn with_rewrite(mut self, from: &str, to: impl Into<String>) -> Self {
+        self.rewrites.insert(tag_key(from), to.into());
+        self
+    }
+
+    /// Whether the policy leaves every tag untouched.
+    pub fn is_permissive(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Filter and rewrite `tags`, preserving input order.
+    ///
+    /// Kept tags are returned as given unless rewritten.
+    pub fn apply(&self, tags: &[String]) -> Vec<String> {
+        let mut seen = HashSet::new();
+        let mut kept = Vec::new();
+        for tag in tags {
+            if self.max_tags.is_some_and(|max| kept.len() >= max) {
+                break;
+            }
+            let tag = match self.rewrites.get(&tag_key(tag)) {
+                Some(rewritten) => rewritten.clone(),
+                None => tag.clone(),
+            };
+            let key = tag_key(&tag);
+            if key.is_empty()
+                || self.stoplist.contains(&key)
+                || self.allowlist.as_ref().is_some_and(|allow| !allow.contains(&key))
+                || key.chars().count() < self.min_length
+                || !seen.insert(key)
+            {
+                continue;
+            }
+            kept.push(tag);
+        }
+        kept
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(list: &[&str]) -> Vec<String> {
+        list.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn default_policy_keeps_every_tag() {
+        let policy = TagPolicy::new();
+        assert!(policy.is_permissive());
+        assert_eq!(policy.apply(&tags(&["travel", "misc"])), tags(&["travel", "misc"]));
+    }
+
+    #[test]
+    fn stoplist_ignores_case_and_hash() {
+        let policy = TagPolicy::new().with_stop("misc").with_stop("#TODO");
+        assert_eq!(
+            policy.apply(&tags(&["Misc", "travel", "#todo", "todo "])),
+            tags(&["travel"])
+        );
+    }
+
+    #[test]
+    fn allowlist_and_min_length_filter() {
+        let policy = TagPolicy::new()
+            .with_allowed("travel")
+            .with_allowed("ai")
+            .with_min_length(3);
+        assert_eq!(policy.apply(&tags(&["travel", "ai", "paris"])), tags(&["travel"]));
+    }
+
+    #[test]
+    fn rewrites_run_first_and_collapse_duplicates() {
+        let policy = TagPolicy::new()
+            .with_rewrite("ML", "machine learning")
+            .with_stop("misc")
+            .with_rewrite("junk", "misc");
+        assert_eq!(
+            policy.apply(&tags(&["ml", "machine learning", "junk"])),
+            tags(&["machine learning"])
+        );
+    }
+
+    #[test]
+    fn max_tags_keeps_the_first_surviving_tags() {
+        let policy = TagPolicy::new().with_stop("misc").with_max_tags(2);
+        assert_eq!(
+            policy.apply(&tags(&["misc", "a1", "b2", "c3"])),
+            tags(&["a1", "b2"])
+        );
+        assert!(TagPolicy::new().with_max_tags(0).apply(&tags(&["a1", "b2"])).is_empty(), "zero keeps none");
+    }
+
+    #[test]
+    fn policy_round_trips_through_json() {
+        let policy = TagPolicy::new().with_stop("misc").with_max_tags(5);
+        let json = serde_json::to_string(&policy).unwrap();
+        assert_eq!(serde_json::from_str::<TagPolicy>(&json).unwrap(), policy);
+        assert_eq!(serde_json::from_str::<TagPolicy>("{}").unwrap(), TagPolicy::new());
+    }
+}
@@ -0,0 +1,202 @@
+//! Context templates: ready-made shapes for new contexts
+//!
+//! A template bundles the configuration a kind of workspace usually
+//! starts with: the dimensions it is laid out in, a relationship ontology
+//! constraining what may connect to what, the enrichments that should run
+//! on it, and the provenance chains to open. Three templates are built
+//! in; user-defined ones are saved through the engine, which persists
+//! them in the store.
+
+use super::context::Context;
+use super::node::{dimension, PropertyValue};
+use super::ontology::{RelationshipOntology, RelationshipType};
+use super::tag_policy::TagPolicy;
+use serde::{Deserialize, Serialize};
+
+/// Names of the built-in templates.
+pub const BUILTIN_TEMPLATES: &[&str] = &["research-workspace", "codebase", "writing-project"];
+
+/// The starting configuration for a context.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContextTemplate {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Dimensions the context is laid out in
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dimensions: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ontology: Option<RelationshipOntology>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag_policy: Option<TagPolicy>,
+    /// Which `GATED_ENRICHMENT_FAMILIES` run on the context
+    /// (`co_occurrence`, `temporal`); empty runs them all
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub enrichments: Vec<String>,
+    /// Names of the provenance chains to open
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chains: Vec<String>,
+}
+
+impl ContextTemplate {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into(), ..Self::default() }
+    }
+
+    /// A built-in template by name.
+    pub fn builtin(name: &str) -> Option<Self> {
+        let template = match name {
+            "research-workspace" => Self::new(name)
+                .with_description("Papers and notes: concepts, citations, and reading chains")
+                .with_dimensions(&[
+                    dimension::STRUCTURE,
+                    dimension::SEMANTIC,
+                    dimension::RELATIONAL,
+                    dimension::TEMPORAL,
+                    dimension::PROVENANCE,
+                ])
+                .with_ontology(
+                    RelationshipOntology::builtin()
+                        .with_type("cites", RelationshipType::directed().with_inverse("cited_by"))
+                        .with_type("discovery_gap", RelationshipType::undirected()),
+                )
+                .with_enrichments(&["co_occurrence", "discovery_gap", "temporal", "embedding", "citation"])
+                .with_chains(&["reading notes", "open questions"]),
+            "codebase" => Self::new(name)
+                .with_description("Source trees: modules, dependencies, and design decisions")
+                .with_dimensions(&[dimension::STRUCTURE, dimension::RELATIONAL, dimension::PROVENANCE])
+                .with_ontology(
+                    RelationshipOntology::builtin()
+                        .with_type("imports", RelationshipType::directed().with_inverse("imported_by"))
+                        .with_type("calls", RelationshipType::directed().with_inverse("called_by"))
+                        .with_type("co_located", RelationshipType::undirected()),
+                )
+                .with_enrichments(&["co_occurrence"])
+                .with_chains(&["design decisions", "review notes"]),
+            "writing-project" => Self::new(name)
+                .with_description("Drafts and sources: sections, revisions, and research notes")
+                .with_dimensions(&[
+                    dimension::STRUCTURE,
+                    dimension::SEMANTIC,
+                    dimension::TEMPORAL,
+                    dimension::PROVENANCE,
+                ])
+                .with_ontology(
+                    RelationshipOntology::builtin()
+                        .with_type("cites", RelationshipType::directed().with_inverse("cited_by"))
+                        .with_type("follows", RelationshipType::directed().with_inverse("precedes")),
+                )
+                .with_enrichments(&["co_occurrence", "temporal"])
+                .with_chains(&["outline", "research notes"]),
+            _ => return None,
+        };
+        Some(template)
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_dimensions(mut self, dimensions: &[&str]) -> Self {
+        self.dimensions = dimensions.iter().map(|d| d.to_string()).collect();
+        self
+    }
+
+    pub fn with_ontology(mut self, ontology: RelationshipOntology) -> Self {
+        self.ontology = Some(ontology);
+        self
+    }
+
+    pub fn with_tag_policy(mut self, policy: TagPolicy) -> Self {
+        self.tag_policy = Some(policy);
+        self
+    }
+
+    pub fn with_enrichments(mut self, enrichments: &[&str]) -> Self {
+        self.enrichments = enrichments.iter().map(|e| e.to_string()).collect();
+        self
+    }
+
+    pub fn with_chains(mut self, chains: &[&str]) -> Self {
+        self.chains = chains.iter().map(|c| c.to_string()).collect();
+        self
+    }
+
+    /// A new context named `name`, configured by this template.
+    pub fn instantiate(&self, name: impl Into<String>) -> Context {
+        let mut context = Context::new(name);
+        context.description = self.description.clone();
+        let metadata = &mut context.metadata;
+        metadata.dimensions = self.dimensions.clone();
+        metadata.relationship_ontology = self.ontology.clone();
+        metadata.tag_policy = self.tag_policy.clone();
+        if !self.enrichments.is_empty() {
+            metadata.enrichments = Some(self.enrichments.clone());
+        }
+        metadata.properties.insert(TEMPLATE_PROPERTY.to_string(), self.name.clone());
+        for name in &self.chains {
+            let mut chain = crate::adapter::chain_node(&crate::adapter::normalize_chain_name(name));
+            chain.properties.insert("name".to_string(), PropertyValue::String(name.clone()));
+            chain.properties.insert("status".to_string(), PropertyValue::String("active".to_string()));
+            context.add_node(chain);
+        }
+        context
+    }
+}
+
+/// Context property recording the template a context was created from.
+pub const TEMPLATE_PROPERTY: &str = "template";
+
+impl Context {
+    /// A context configured by the built-in template `name`, named after it.
+    pub fn from_template(name: &str) -> Option<Context> {
+        ContextTemplate::builtin(name).map(|template| template.instantiate(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::NodeId;
+
+    // === Scenario: Built-in templates configure the new context ===
+    #[test]
+    fn builtin_templates_preconfigure_contexts() {
+        for name in BUILTIN_TEMPLATES {
+            assert!(ContextTemplate::builtin(name).is_some(), "{name} is built in");
+        }
+        assert!(Context::from_template("spreadsheet").is_none());
+
+        let ctx = Context::from_template("research-workspace").unwrap();
+        assert_eq!(ctx.name, "research-workspace");
+        assert!(ctx.metadata.dimensions.iter().any(|d| d == dimension::PROVENANCE));
+        let ontology = ctx.metadata.relationship_ontology.as_ref().unwrap();
+        assert_eq!(ontology.resolve("cited_by").name, "cites");
+        assert!(ctx.metadata.runs_enrichment("co_occurrence:tagged_with:may_be_related"));
+        assert!(!ctx.metadata.runs_enrichment("keyword_extraction:tagged_with"));
+        assert!(ctx.metadata.runs_enrichment("lens:carrel"), "lenses aren't gated by the template");
+        assert!(ctx.metadata.runs_enrichment("rule:flag-drafts"));
+        assert!(ctx.metadata.runs_enrichment("co_occurrence_v2"), "only whole family names are gated");
+        assert_eq!(ctx.metadata.properties.get(TEMPLATE_PROPERTY).map(String::as_str), Some("research-workspace"));
+
+        let chain = ctx.get_node(&NodeId::from_string("chain:provenance:open-questions")).unwrap();
+        assert_eq!(chain.node_type, "chain");
+        assert_eq!(chain.dimension, dimension::PROVENANCE);
+        assert_eq!(chain.properties.get("name"), Some(&PropertyValue::String("open questions".into())));
+    }
+
+    // === Scenario: Templates round-trip through JSON ===
+    #[test]
+    fn templates_serialize_for_storage() {
+        let template = ContextTemplate::new("lab notebook")
+            .with_dimensions(&[dimension::TEMPORAL])
+            .with_chains(&["experiments"]);
+        let json = serde_json::to_string(&template).unwrap();
+        assert_eq!(serde_json::from_str::<ContextTemplate>(&json).unwrap(), template);
+
+        let ctx = template.instantiate("march");
+        assert!(ctx.metadata.runs_enrichment("keyword_extraction:tagged_with"), "no enrichment list runs everything");
+        assert_eq!(ctx.node_count(), 1);
+    }
+}
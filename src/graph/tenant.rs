@@ -0,0 +1,129 @@
+//! Tenants: an isolation layer above contexts for hosted deployments
+//!
+//! A context belongs to at most one tenant (`ContextMetadata::tenant`).
+//! Context names are unique per tenant rather than globally, and a
+//! tenant-scoped `PlexusApi` neither lists nor resolves other tenants'
+//! contexts. Untenanted contexts behave exactly as before.
+
+use super::engine::{PlexusError, PlexusResult};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Key for a context name in the engine's name index. Untenanted names
+/// are their own key, so single-tenant use is unchanged.
+pub(crate) fn scoped_name(tenant: Option<&str>, name: &str) -> String {
+    match tenant {
+        Some(tenant) => format!("{tenant}\u{1f}{name}"),
+        None => name.to_string(),
+    }
+}
+
+/// Limits on what one tenant may hold. `None` means unlimited.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TenantQuota {
+    #[serde(default)]
+    pub max_contexts: Option<usize>,
+    /// Nodes across all of the tenant's contexts
+    #[serde(default)]
+    pub max_nodes: Option<usize>,
+    /// Edges across all of the tenant's contexts
+    #[serde(default)]
+    pub max_edges: Option<usize>,
+}
+
+/// What a tenant currently holds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct TenantUsage {
+    pub contexts: usize,
+    pub nodes: usize,
+    pub edges: usize,
+}
+
+impl TenantQuota {
+    /// The first limit `usage` has reached, if any. Quotas are checked
+    /// before writes, so a tenant at a limit can't grow past it; a single
+    /// large ingest can still overshoot.
+    pub fn exceeded_by(&self, usage: &TenantUsage) -> Option<String> {
+        let limits = [
+            ("contexts", self.max_contexts, usage.contexts),
+            ("nodes", self.max_nodes, usage.nodes),
+            ("edges", self.max_edges, usage.edges),
+        ];
+        limits.into_iter().find_map(|(what, max, used)| {
+            max.filter(|max| used >= *max).map(|max| format!("{used} {what} (limit {max})"))
+        })
+    }
+}
+
+/// One tenant's credentials and quota in a `TenantDirectory`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TenantEntry {
+    /// API keys that authenticate as this tenant
+    pub api_keys: Vec<String>,
+    #[serde(default)]
+    pub quota: TenantQuota,
+}
+
+/// Maps credentials to tenants. Loaded from a JSON file of the form
+/// `{"tenants": {"acme": {"api_keys": ["..."], "quota": {"max_nodes": 100000}}}}`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TenantDirectory {
+    pub tenants: BTreeMap<String, TenantEntry>,
+}
+
+impl TenantDirectory {
+    pub fn load(path: impl AsRef<Path>) -> PlexusResult<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| PlexusError::Other(format!("cannot read tenant directory {}: {}", path.display(), e)))?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// The tenant an API key belongs to, with its quota.
+    pub fn authenticate(&self, api_key: &str) -> Option<(&str, &TenantQuota)> {
+        if api_key.is_empty() {
+            return None;
+        }
+        self.tenants
+            .iter()
+            .find(|(_, entry)| entry.api_keys.iter().any(|k| k == api_key))
+            .map(|(tenant, entry)| (tenant.as_str(), &entry.quota))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // === Scenario: API keys select a tenant ===
+    #[test]
+    fn directory_authenticates_keys() {
+        let directory: TenantDirectory = serde_json::from_str(
+            r#"{"tenants": {
+                "acme": {"api_keys": ["k-acme"], "quota": {"max_contexts": 2}},
+                "globex": {"api_keys": ["k-globex-1", "k-globex-2"]}
+            }}"#,
+        )
+        .unwrap();
+
+        let (tenant, quota) = directory.authenticate("k-acme").unwrap();
+        assert_eq!(tenant, "acme");
+        assert_eq!(quota.max_contexts, Some(2));
+        assert_eq!(directory.authenticate("k-globex-2").unwrap().0, "globex");
+        assert!(directory.authenticate("nope").is_none());
+        assert!(directory.authenticate("").is_none());
+    }
+
+    // === Scenario: A quota reports the first limit reached ===
+    #[test]
+    fn quota_reports_reached_limits() {
+        let quota = TenantQuota { max_contexts: Some(2), max_nodes: Some(10), max_edges: None };
+        assert_eq!(quota.exceeded_by(&TenantUsage { contexts: 1, nodes: 9, edges: 500 }), None);
+        assert_eq!(
+            quota.exceeded_by(&TenantUsage { contexts: 1, nodes: 10, edges: 0 }).as_deref(),
+            Some("10 nodes (limit 10)")
+        );
+        assert_eq!(TenantQuota::default().exceeded_by(&TenantUsage { contexts: 99, nodes: 99, edges: 99 }), None);
+    }
+}
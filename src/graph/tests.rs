@@ -55,6 +55,7 @@ mod serialization_tests {
         edge::Edge,
         node::{ContentType, Node, NodeId, PropertyValue},
     };
+    use std::collections::HashMap;
 
     #[test]
     fn node_id_serializes_as_string() {
@@ -90,6 +91,57 @@ mod serialization_tests {
         assert_eq!(ct, ContentType::Code);
     }
 
+    #[test]
+    fn custom_content_type_roundtrips_as_plain_string() {
+        let ct = ContentType::custom("Transcript");
+        assert_eq!(ct, ContentType::Other("transcript".to_string()));
+        assert_eq!(serde_json::to_string(&ct).unwrap(), "\"transcript\"");
+
+        let back: ContentType = serde_json::from_str("\"transcript\"").unwrap();
+        assert_eq!(back, ct);
+        assert!(back.is_custom());
+    }
+
+    #[test]
+    fn custom_content_type_never_shadows_builtin() {
+        assert_eq!(ContentType::custom("CODE"), ContentType::Code);
+        assert_eq!("narrative".parse::<ContentType>().unwrap(), ContentType::Narrative);
+        assert_eq!("lab-notebook".parse::<ContentType>().unwrap().to_string(), "lab-notebook");
+        assert!("  ".parse::<ContentType>().is_err());
+    }
+
+    #[test]
+    fn structured_property_values_roundtrip_json() {
+        let taken_at = chrono::DateTime::parse_from_rfc3339("2024-05-01T12:30:00.250Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let value = PropertyValue::Object(HashMap::from([
+            ("taken_at".to_string(), PropertyValue::DateTime(taken_at)),
+            ("thumb".to_string(), PropertyValue::Bytes(vec![0xde, 0xad, 0x01])),
+            ("title".to_string(), PropertyValue::String("2024-05-01T12:30:00Z".to_string())),
+        ]));
+
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json["taken_at"], serde_json::json!({"$datetime": "2024-05-01T12:30:00.250Z"}));
+        assert_eq!(json["thumb"], serde_json::json!({"$bytes": "dead01"}));
+        // Plain strings stay strings even when they look like timestamps
+        assert_eq!(json["title"], "2024-05-01T12:30:00Z");
+
+        let back: PropertyValue = serde_json::from_value(json).unwrap();
+        assert_eq!(back, value);
+    }
+
+    #[test]
+    fn malformed_wrapper_objects_fall_back_to_object() {
+        let value: PropertyValue =
+            serde_json::from_str(r#"{"$datetime": "yesterday"}"#).unwrap();
+        assert!(matches!(value, PropertyValue::Object(_)));
+
+        let value: PropertyValue =
+            serde_json::from_str(r#"{"$bytes": "abc", "extra": 1}"#).unwrap();
+        assert!(matches!(value, PropertyValue::Object(_)));
+    }
+
     #[test]
     fn node_roundtrip() {
         let node = Node::new("function", ContentType::Code)
@@ -205,3 +257,70 @@ mod serialization_tests {
         assert!(json["created_at"].is_string());
     }
 }
+
+#[cfg(test)]
+mod builder_tests {
+    use crate::graph::{dimension, ContentType, Edge, Node, PropertyValue};
+
+    #[test]
+    fn node_builder_sets_fields_and_accumulates_tags() {
+        let node = Node::builder("mark")
+            .id("mark:1")
+            .content_type(ContentType::Provenance)
+            .dimension(dimension::PROVENANCE)
+            .prop("file", "src/main.rs")
+            .prop("line", 42)
+            .prop("weight", 0.5)
+            .prop("pinned", true)
+            .tag("travel")
+            .tag("avignon")
+            .build();
+
+        assert_eq!(node.id.as_str(), "mark:1");
+        assert_eq!(node.content_type, ContentType::Provenance);
+        assert_eq!(node.dimension, dimension::PROVENANCE);
+        assert_eq!(node.get_str("file"), Some("src/main.rs"));
+        assert_eq!(node.get_int("line"), Some(42));
+        assert_eq!(node.get_float("weight"), Some(0.5));
+        assert_eq!(node.get_bool("pinned"), Some(true));
+        assert_eq!(node.tags(), vec!["travel", "avignon"]);
+    }
+
+    #[test]
+    fn typed_accessors_reject_mismatched_kinds() {
+        let node = Node::builder("fragment")
+            .prop("line", "42")
+            .prop("created_at", "2024-05-01T12:00:00Z")
+            .build();
+
+        assert_eq!(node.get_int("line"), None);
+        assert_eq!(node.get_str("missing"), None);
+        assert!(node.get_array("line").is_none());
+        // RFC-3339 strings read as timestamps (ADR-039 created_at)
+        assert!(node.get_datetime("created_at").is_some());
+        // Int widens to float; float never narrows to int
+        let node = Node::builder("n").prop("n", 3).prop("f", 3.0).build();
+        assert_eq!(node.get_float("n"), Some(3.0));
+        assert_eq!(node.get_int("f"), None);
+    }
+
+    #[test]
+    fn edge_builder_sets_dimensions_contributions_and_properties() {
+        let edge = Edge::builder("file:a", "concept:b", "tagged_with")
+            .source_dimension(dimension::STRUCTURE)
+            .target_dimension(dimension::SEMANTIC)
+            .contribution("extract-file", 0.8)
+            .prop("evidence", vec!["heading", "frontmatter"])
+            .build();
+
+        assert_eq!(edge.source.as_str(), "file:a");
+        assert_eq!(edge.target.as_str(), "concept:b");
+        assert!(edge.is_cross_dimensional());
+        assert_eq!(edge.contribution("extract-file"), Some(0.8));
+        assert_eq!(edge.contribution("other"), None);
+        assert_eq!(
+            edge.get_array("evidence"),
+            Some(&[PropertyValue::from("heading"), PropertyValue::from("frontmatter")][..])
+        );
+    }
+}
@@ -0,0 +1,246 @@
+//! Soft delete: the trash
+//!
+//! Removals through the sink and retraction pruning move nodes and edges
+//! into `Context::trash` instead of dropping them. Trashed items are
+//! stored with a `deleted_at` tombstone but live outside `nodes`/`edges`,
+//! so every query excludes them without knowing about the trash.
+//! `PlexusEngine::restore_deleted` brings them back; `purge_trash` drops
+//! tombstones older than the context's retention for good. Policy pruning
+//! (`PlexusEngine::prune`) is housekeeping for derived edges and stays a
+//! hard delete.
+
+use super::context::Context;
+use super::edge::{Edge, EdgeId};
+use super::enrichment_stats::EdgeFate;
+use super::node::{Node, NodeId};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Days a tombstone is kept when the context sets no retention.
+pub const DEFAULT_TRASH_RETENTION_DAYS: u32 = 30;
+
+/// A deleted item and when it was deleted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tombstone<T> {
+    pub item: T,
+    pub deleted_at: DateTime<Utc>,
+}
+
+/// Soft-deleted nodes and edges of a context.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Trash {
+    pub nodes: HashMap<NodeId, Tombstone<Node>>,
+    pub edges: Vec<Tombstone<Edge>>,
+}
+
+impl Trash {
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty() && self.edges.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len() + self.edges.len()
+    }
+}
+
+/// Outcome of `PlexusEngine::restore_deleted`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct RestoreReport {
+    pub nodes: Vec<NodeId>,
+    /// Restored edges, including those trashed along with a restored node.
+    /// An edge re-created while it was trashed merges into the live one,
+    /// whose ID is listed instead.
+    pub edges: Vec<EdgeId>,
+    /// IDs not in the trash, or edges whose endpoints aren't live
+    pub skipped: Vec<String>,
+}
+
+impl Context {
+    /// Move edges matching `selected` to the trash. Returns their IDs.
+    pub fn trash_edges_where(&mut self, selected: impl Fn(&Edge) -> bool) -> Vec<EdgeId> {
+        self.trash_edges_at(selected, Utc::now())
+    }
+
+    fn trash_edges_at(&mut self, selected: impl Fn(&Edge) -> bool, now: DateTime<Utc>) -> Vec<EdgeId> {
+        let (trashed, kept): (Vec<Edge>, Vec<Edge>) = std::mem::take(&mut self.edges).into_iter().partition(|e| selected(e));
+        self.edges = kept;
+        let ids: Vec<EdgeId> = trashed.iter().map(|e| e.id.clone()).collect();
+        self.trash.edges.extend(trashed.into_iter().map(|item| Tombstone { item, deleted_at: now }));
+        self.record_edge_fates(&ids, EdgeFate::Pruned);
+        ids
+    }
+
+    /// Move a node and its incident edges to the trash. Returns the
+    /// cascaded edge IDs, or `None` if the node isn't live.
+    pub fn trash_node(&mut self, id: &NodeId) -> Option<Vec<EdgeId>> {
+        let node = self.nodes.remove(id)?;
+        let now = Utc::now();
+        let cascaded = self.trash_edges_at(|e| &e.source == id || &e.target == id, now);
+        self.trash.nodes.insert(id.clone(), Tombstone { item: node, deleted_at: now });
+        Some(cascaded)
+    }
+
+    /// Bring trashed nodes and edges back by ID. A restored node brings
+    /// the edges trashed with it (or after it) whose other endpoint is
+    /// live; an edge is only restored once both endpoints are.
+    pub fn restore_from_trash(&mut self, ids: &[String]) -> RestoreReport {
+        let mut report = RestoreReport::default();
+        let mut pending_edges: HashSet<&str> = HashSet::new();
+        let mut restored_at: Vec<(NodeId, DateTime<Utc>)> = Vec::new();
+        for id in ids {
+            let node_id = NodeId::from_string(id.as_str());
+            match self.trash.nodes.remove(&node_id) {
+                Some(tombstone) if !self.nodes.contains_key(&node_id) => {
+                    restored_at.push((node_id.clone(), tombstone.deleted_at));
+                    self.nodes.insert(node_id.clone(), tombstone.item);
+                    report.nodes.push(node_id);
+                }
+                // Superseded by a live node of the same ID
+                Some(_) => report.skipped.push(id.clone()),
+                None => {
+                    pending_edges.insert(id.as_str());
+                }
+            }
+        }
+
+        let nodes = &self.nodes;
+        let cascaded = |edge: &Tombstone<Edge>| {
+            restored_at.iter().any(|(node, at)| {
+                (&edge.item.source == node || &edge.item.target == node) && edge.deleted_at >= *at
+            })
+        };
+        let mut restored = Vec::new();
+        let mut kept = Vec::new();
+        for tombstone in std::mem::take(&mut self.trash.edges) {
+            let requested = pending_edges.contains(tombstone.item.id.as_str());
+            let endpoints_live =
+                nodes.contains_key(&tombstone.item.source) && nodes.contains_key(&tombstone.item.target);
+            if (requested || cascaded(&tombstone)) && endpoints_live {
+                restored.push(tombstone.item);
+            } else {
+                kept.push(tombstone);
+            }
+        }
+        self.trash.edges = kept;
+
+        let restored_ids: HashSet<String> = restored.iter().map(|e| e.id.to_string()).collect();
+        for edge in restored {
+            // The edge's identity may have been taken by a new live edge
+            let id = match self.find_edge_identity(&edge) {
+                Some(idx) => {
+                    let live = self.edges[idx].id.clone();
+                    self.add_edge(edge);
+                    live
+                }
+                None => {
+                    let id = edge.id.clone();
+                    self.edges.push(edge);
+                    id
+                }
+            };
+            if !report.edges.contains(&id) {
+                report.edges.push(id);
+            }
+        }
+
+        for id in ids {
+            if pending_edges.contains(id.as_str()) && !restored_ids.contains(id.as_str()) {
+                report.skipped.push(id.clone());
+            }
+        }
+        if !report.nodes.is_empty() || !report.edges.is_empty() {
+            self.recompute_combined_weights();
+            self.metadata.updated_at = Some(Utc::now());
+        }
+        report
+    }
+
+    /// Permanently drop tombstones deleted before `cutoff`. Returns how
+    /// many were purged.
+    pub fn purge_trash(&mut self, cutoff: DateTime<Utc>) -> usize {
+        let before = self.trash.len();
+        self.trash.nodes.retain(|_, t| t.deleted_at >= cutoff);
+        self.trash.edges.retain(|t| t.deleted_at >= cutoff);
+        before - self.trash.len()
+    }
+
+    /// How long this context keeps tombstones.
+    pub fn trash_retention(&self) -> Duration {
+        Duration::days(self.metadata.trash_retention_days.unwrap_or(DEFAULT_TRASH_RETENTION_DAYS) as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::ContentType;
+
+    fn linked() -> (Context, NodeId, NodeId, EdgeId) {
+        let mut ctx = Context::new("trash");
+        let a = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let b = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let edge = Edge::new(a.clone(), b.clone(), "related_to").with_contribution("notes", 1.0);
+        let edge_id = edge.id.clone();
+        ctx.add_edge(edge);
+        (ctx, a, b, edge_id)
+    }
+
+    // === Scenario: A trashed node disappears and comes back with its edges ===
+    #[test]
+    fn trashed_node_restores_with_cascaded_edges() {
+        let (mut ctx, a, b, edge_id) = linked();
+        assert_eq!(ctx.trash_node(&a), Some(vec![edge_id.clone()]));
+        assert!(ctx.get_node(&a).is_none());
+        assert_eq!(ctx.edge_count(), 0);
+        assert_eq!(ctx.trash.len(), 2);
+        assert_eq!(ctx.trash_node(&a), None, "already trashed");
+
+        let report = ctx.restore_from_trash(&[a.to_string(), "concept:unknown".to_string()]);
+        assert_eq!(report.nodes, vec![a.clone()]);
+        assert_eq!(report.edges, vec![edge_id]);
+        assert_eq!(report.skipped, vec!["concept:unknown".to_string()]);
+        assert!(ctx.get_node(&b).is_some());
+        assert_eq!(ctx.edge_count(), 1);
+        assert!(ctx.trash.is_empty());
+    }
+
+    // === Scenario: A restored edge merges into the live edge that replaced it ===
+    #[test]
+    fn restored_edge_merges_into_its_live_duplicate() {
+        let (mut ctx, a, b, edge_id) = linked();
+        assert_eq!(ctx.trash_edges_where(|e| e.id == edge_id), vec![edge_id.clone()]);
+        let again = Edge::new(a.clone(), b.clone(), "related_to").with_contribution("review", 0.5);
+        let live_id = again.id.clone();
+        ctx.add_edge(again);
+
+        let report = ctx.restore_from_trash(&[edge_id.to_string()]);
+        assert_eq!(report.edges, vec![live_id]);
+        assert!(report.skipped.is_empty());
+        assert_eq!(ctx.edge_count(), 1, "no duplicate of the live edge");
+        let edge = ctx.edges().next().unwrap();
+        assert!(edge.contributions.contains_key("notes") && edge.contributions.contains_key("review"));
+        assert!(ctx.trash.is_empty());
+    }
+
+    // === Scenario: An edge can't be restored onto a missing endpoint ===
+    #[test]
+    fn edge_restore_requires_live_endpoints() {
+        let (mut ctx, a, _, edge_id) = linked();
+        ctx.trash_node(&a);
+        let report = ctx.restore_from_trash(&[edge_id.to_string()]);
+        assert!(report.edges.is_empty());
+        assert_eq!(report.skipped, vec![edge_id.to_string()]);
+        assert_eq!(ctx.trash.edges.len(), 1, "the edge stays in the trash");
+    }
+
+    // === Scenario: Purging drops only tombstones past retention ===
+    #[test]
+    fn purge_respects_cutoff() {
+        let (mut ctx, a, _, _) = linked();
+        ctx.trash_node(&a);
+        assert_eq!(ctx.purge_trash(Utc::now() - ctx.trash_retention()), 0);
+        assert_eq!(ctx.purge_trash(Utc::now() + Duration::seconds(1)), 2);
+        assert!(ctx.restore_from_trash(&[a.to_string()]).nodes.is_empty());
+    }
+}
@@ -0,0 +1,105 @@
+//! Node versioning: supersedes chains
+//!
+//! When a document is revised, its regenerated structure nodes supersede
+//! the old ones. A version edge `new --supersedes--> old` records this;
+//! following incoming version edges from any node reaches its head (the
+//! newest version). The sink redirects edges emitted against an old
+//! version to its head, and `FindQuery`/`TraverseQuery` can opt into
+//! resolving results the same way (`resolve_to_head`).
+
+use super::context::Context;
+use super::edge::{Edge, EdgeId};
+use super::node::NodeId;
+use std::collections::{HashMap, HashSet};
+
+/// Relationship of version edges, from the newer node to the one it replaces.
+pub const SUPERSEDES: &str = "supersedes";
+
+impl Context {
+    /// Map from each superseded node to the node that supersedes it.
+    pub fn version_successors(&self) -> HashMap<NodeId, NodeId> {
+        self.edges
+            .iter()
+            .filter(|e| e.relationship == SUPERSEDES)
+            .map(|e| (e.target.clone(), e.source.clone()))
+            .collect()
+    }
+
+    /// The newest version of `id`: `id` itself unless something
+    /// supersedes it.
+    pub fn head_version(&self, id: &NodeId) -> NodeId {
+        resolve_head(&self.version_successors(), id)
+    }
+
+    /// `id` and the versions it supersedes, newest first.
+    pub fn version_history(&self, id: &NodeId) -> Vec<NodeId> {
+        let mut history = vec![id.clone()];
+        let mut seen: HashSet<NodeId> = history.iter().cloned().collect();
+        while let Some(previous) = self
+            .edges
+            .iter()
+            .find(|e| e.relationship == SUPERSEDES && &e.source == history.last().unwrap())
+            .map(|e| e.target.clone())
+        {
+            if !seen.insert(previous.clone()) {
+                break;
+            }
+            history.push(previous);
+        }
+        history
+    }
+
+    /// Record that `new` supersedes `old` with a version edge. Returns its
+    /// ID, or `None` if either node is missing. Callers guard against
+    /// cycles and forks (`PlexusEngine::supersede_node` does).
+    pub fn supersede_node(&mut self, old: &NodeId, new: &NodeId) -> Option<EdgeId> {
+        let old_dimension = self.nodes.get(old)?.dimension.clone();
+        let new_dimension = self.nodes.get(new)?.dimension.clone();
+        let edge = Edge::new_cross_dimensional(new.clone(), new_dimension, old.clone(), old_dimension, SUPERSEDES);
+        let id = edge.id.clone();
+        self.add_edge(edge);
+        Some(id)
+    }
+}
+
+/// Follow `successors` from `id` to the head, stopping on a cycle.
+pub(crate) fn resolve_head(successors: &HashMap<NodeId, NodeId>, id: &NodeId) -> NodeId {
+    let mut current = id;
+    let mut seen = HashSet::new();
+    while let Some(next) = successors.get(current) {
+        if !seen.insert(current) {
+            break;
+        }
+        current = next;
+    }
+    current.clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{ContentType, Node};
+
+    fn section(ctx: &mut Context, id: &str) -> NodeId {
+        let mut node = Node::new("section", ContentType::Document);
+        node.id = NodeId::from_string(id);
+        ctx.add_node(node)
+    }
+
+    // === Scenario: A revised section resolves to its newest version ===
+    #[test]
+    fn head_version_follows_supersedes_chain() {
+        let mut ctx = Context::new("versions");
+        let v1 = section(&mut ctx, "section:intro:v1");
+        let v2 = section(&mut ctx, "section:intro:v2");
+        let v3 = section(&mut ctx, "section:intro:v3");
+        assert_eq!(ctx.head_version(&v1), v1);
+
+        ctx.supersede_node(&v1, &v2).unwrap();
+        ctx.supersede_node(&v2, &v3).unwrap();
+        assert_eq!(ctx.head_version(&v1), v3);
+        assert_eq!(ctx.head_version(&v3), v3);
+        assert_eq!(ctx.version_history(&v3), vec![v3.clone(), v2, v1]);
+        assert!(ctx.supersede_node(&v3, &"section:missing".into()).is_none());
+    }
+}
@@ -0,0 +1,74 @@
+//! Per-adapter write scopes: what an adapter may write to a context
+//!
+//! Stored on `ContextMetadata::write_scopes` by adapter ID and enforced by
+//! `EngineSink`, which rejects out-of-scope items with
+//! `RejectionReason::OutOfScope`. Adapters without a scope write anywhere.
+//! `Context::check_invariants` reports edges whose contributors are out of
+//! scope, e.g. ones written before the scope was set.
+
+use super::edge::Edge;
+use super::node::Node;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// The dimensions and relationships an adapter may write. Unset fields
+/// allow anything.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WriteScope {
+    /// Dimensions whose nodes the adapter may create, update or remove.
+    /// An edge is in scope when either endpoint is.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<BTreeSet<String>>,
+    /// Relationships the adapter may add or remove edges of
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relationships: Option<BTreeSet<String>>,
+}
+
+impl WriteScope {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow writes to `dimension` (restricting dimensions if unset).
+    pub fn with_dimension(mut self, dimension: impl Into<String>) -> Self {
+        self.dimensions.get_or_insert_with(BTreeSet::new).insert(dimension.into());
+        self
+    }
+
+    /// Allow edges of `relationship` (restricting relationships if unset).
+    pub fn with_relationship(mut self, relationship: impl Into<String>) -> Self {
+        self.relationships.get_or_insert_with(BTreeSet::new).insert(relationship.into());
+        self
+    }
+
+    pub fn allows_dimension(&self, dimension: &str) -> bool {
+        self.dimensions.as_ref().is_none_or(|allowed| allowed.contains(dimension))
+    }
+
+    pub fn allows_relationship(&self, relationship: &str) -> bool {
+        self.relationships.as_ref().is_none_or(|allowed| allowed.contains(relationship))
+    }
+
+    /// Why `node` is out of scope, if it is.
+    pub fn check_node(&self, node: &Node) -> Result<(), String> {
+        if self.allows_dimension(&node.dimension) {
+            Ok(())
+        } else {
+            Err(format!("dimension '{}' is outside the write scope", node.dimension))
+        }
+    }
+
+    /// Why `edge` is out of scope, if it is.
+    pub fn check_edge(&self, edge: &Edge) -> Result<(), String> {
+        if !self.allows_relationship(&edge.relationship) {
+            return Err(format!("relationship '{}' is outside the write scope", edge.relationship));
+        }
+        if !self.allows_dimension(&edge.source_dimension) && !self.allows_dimension(&edge.target_dimension) {
+            return Err(format!(
+                "dimensions '{}' and '{}' are outside the write scope",
+                edge.source_dimension, edge.target_dimension
+            ));
+        }
+        Ok(())
+    }
+}
@@ -20,25 +20,38 @@
 
 pub mod adapter;
 pub mod api;
+pub mod error;
 mod graph;
 pub mod llm_orc;
 pub mod mcp;
+mod parallel;
 pub mod provenance;
+pub mod qa;
 pub mod query;
+pub mod replication;
 pub mod storage;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
 pub use adapter::{
     AdapterError, AdapterSink, Annotation, AnnotatedEdge, AnnotatedNode,
     EmitResult, Emission, Rejection, RejectionReason, Removal,
 };
+pub use graph::synthetic;
 pub use graph::{
-    ContentType, Context, ContextId, ContextMetadata, Edge, EdgeId, Node, NodeId, PlexusEngine,
-    PlexusError, PlexusResult, PropertyValue, Source, dimension,
+    ContentType, Context, ContextId, ContextMetadata, ContextReader, Durability, EmbeddingConfig, ContextTemplate, ContributionAggregation, ContributionEntry, ContributionMode, Edge, EdgeBuilder, EdgeId, EdgePolicy, FnHook, GraphDiff, HistoricalView, MutationHook, Node, NodeBuilder,
+    GraphEntity, InvariantViolation, NodeId, PlexusEngine, PlexusError, PlexusResult, PropertyValue, PrunePolicy, PruneReport, BundleFormat, PublishFilter, PublishManifest, RelationshipOntology, ContextSync, SyncConflict, SyncReport, merge_contexts, NodeMerge, Overlay, RelationshipType, ResolvedRelationship, SampleStrategy, Source, TagPolicy, WriteScope, ContextQuota, EnrichmentOutcome, EnrichmentStats, DEFAULT_QUOTA_WARN_RATIO, QUOTA_WARNING_EVENT, EMISSION_RETENTION_DAYS, INGEST_CLAIM_LEASE_SECS, INGEST_RESULT_RETENTION_DAYS, MAX_OUTBOUND_EVENTS, TenantDirectory, TenantEntry, TenantQuota,
+    TenantUsage, Relocation, RelocationReport, RelocationWatcher, RestoreReport, BUILTIN_TEMPLATES, GATED_ENRICHMENT_FAMILIES, TEMPLATE_PROPERTY, Tombstone, Trash, DEFAULT_TRASH_RETENTION_DAYS, dimension,
+    normalize_natural_key, content_digest, manifest_path, open_bundle, verify_bundle, MANIFEST_SUFFIX, PRIVATE_PROPERTY, CONFIDENCE_PROPERTY, CONTENT_HASH_PROPERTY, NATURAL_KEY_PROPERTY, SUPERSEDES,
 };
-pub use query::{Direction, EvidenceTrailResult, FindQuery, PathQuery, PathResult, QueryFilter, QueryResult, RankBy, StepQuery, StepResult, TraversalResult, TraverseQuery, evidence_trail};
+pub use query::{Backlinks, CompareOp, ConceptDossier, ConceptDrift, ContextPack, Direction, DriftReport, EvidenceTrailResult, FindQuery, GeoFilter, HopScore, HybridHit, HybridQuery, HybridResult, JsonLd, MaterializedView, MlExport, MlExportFiles, PackedContext, PathConstraint, PathQuery, PathScoring, ScoredPath, GraphDistributions, Histogram, HistogramBucket, NodeDegree, PathResult, QueryFilter, QueryResult, RankBy, ReachabilityFilter, RdfExport, RdfFormat, RdfVocabulary, SavedQuery, SavedQueryResult, HubDampening, StepQuery, StepResult, TagStats, TimeBucket, TimeWindow, TimelineQuery, TimelineResult, TransitiveClosure, TraversalResult, TraverseQuery, Trend, ViewSnapshot, evidence_trail};
 pub use provenance::{ChainStatus, ChainView, MarkView, ProvenanceApi};
-pub use api::{PlexusApi, SpecLoadError, SpecLoadResult, SpecUnloadError};
-pub use storage::{GraphStore, OpenStore, PersistedSpec, SqliteStore, StorageError, StorageResult};
+pub use error::{ErrorCode, ErrorCoded, ErrorPayload};
+pub use api::{BootstrapOptions, BootstrapReport, PlexusApi, ReembedReport, SimilarNode, SimilarTo, SpecLoadError, SpecLoadResult, SpecUnloadError};
+pub use storage::{
+    BufferedStore, CompactionReport, ContextChange, ContextHeader, EdgeFilter, EmissionFilter, EventSourcedStore, JournalEntry, JournalRecord, DEFAULT_SNAPSHOT_EVERY, GraphStore, NodeFilter, OpenStore, PersistedEmbedding, PersistedEmission, PersistedIngestResult, PersistedLlmCost, PersistedOutboundEvent, PersistedSpec, PersistedTemplate, SqliteStore,
+    StorageError, StorageResult,
+};
 
 /// Library version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -43,6 +43,145 @@ impl InvokeResponse {
     pub fn has_errors(&self) -> bool {
         self.status == "completed_with_errors"
     }
+
+    /// Tokens and cost reported under `metadata.usage` — either totals
+    /// (directly or under `totals`) or per-agent entries, which are summed.
+    /// Zero when the ensemble reports no usage.
+    pub fn usage(&self) -> LlmUsage {
+        let Some(usage) = self.metadata.get("usage") else {
+            return LlmUsage::default();
+        };
+        if let Some(totals) = usage.get("totals") {
+            return LlmUsage::from_json(totals);
+        }
+        let direct = LlmUsage::from_json(usage);
+        if direct != LlmUsage::default() {
+            return direct;
+        }
+        usage
+            .as_object()
+            .map(|agents| agents.values().map(LlmUsage::from_json).fold(LlmUsage::default(), |a, b| a + b))
+            .unwrap_or_default()
+    }
+}
+
+/// Tokens and cost of LLM calls.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct LlmUsage {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cost_usd: f64,
+}
+
+impl LlmUsage {
+    pub fn total_tokens(&self) -> u64 {
+        self.input_tokens + self.output_tokens
+    }
+
+    /// Read `input_tokens`/`prompt_tokens`, `output_tokens`/`completion_tokens`
+    /// and `cost_usd`/`total_cost`/`cost`; a bare `total_tokens` counts as input.
+    fn from_json(value: &serde_json::Value) -> Self {
+        let number = |keys: &[&str]| keys.iter().find_map(|k| value.get(*k).and_then(|v| v.as_f64())).unwrap_or(0.0);
+        let mut usage = Self {
+            input_tokens: number(&["input_tokens", "prompt_tokens"]) as u64,
+            output_tokens: number(&["output_tokens", "completion_tokens"]) as u64,
+            cost_usd: number(&["cost_usd", "total_cost", "cost"]),
+        };
+        if usage.total_tokens() == 0 {
+            usage.input_tokens = number(&["total_tokens"]) as u64;
+        }
+        usage
+    }
+}
+
+impl std::ops::Add for LlmUsage {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            input_tokens: self.input_tokens + other.input_tokens,
+            output_tokens: self.output_tokens + other.output_tokens,
+            cost_usd: self.cost_usd + other.cost_usd,
+        }
+    }
+}
+
+/// Recorded LLM costs of a context (`PlexusEngine::llm_costs`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct LlmCostReport {
+    /// One record per invocation, oldest first
+    pub records: Vec<crate::storage::PersistedLlmCost>,
+}
+
+impl LlmCostReport {
+    /// Sum over all records.
+    pub fn total(&self) -> LlmUsage {
+        self.records.iter().map(LlmUsage::from).fold(LlmUsage::default(), |a, b| a + b)
+    }
+
+    /// Sums per content item.
+    pub fn by_item(&self) -> std::collections::BTreeMap<String, LlmUsage> {
+        let mut items = std::collections::BTreeMap::new();
+        for record in &self.records {
+            let usage = items.entry(record.item.clone()).or_insert_with(LlmUsage::default);
+            *usage = *usage + LlmUsage::from(record);
+        }
+        items
+    }
+}
+
+impl From<&crate::storage::PersistedLlmCost> for LlmUsage {
+    fn from(record: &crate::storage::PersistedLlmCost) -> Self {
+        Self { input_tokens: record.input_tokens, output_tokens: record.output_tokens, cost_usd: record.cost_usd }
+    }
+}
+
+/// Spending limit for one run of LLM calls.
+///
+/// Share one budget (behind an `Arc`) among the adapters of a run; each
+/// charges what its calls report, and stops calling once a limit is
+/// reached. A call in flight when the limit is crossed still completes,
+/// so a run can overshoot by one call. Start the next run with a fresh
+/// budget.
+#[derive(Debug, Default)]
+pub struct LlmBudget {
+    max_tokens: Option<u64>,
+    max_cost_usd: Option<f64>,
+    spent: std::sync::Mutex<LlmUsage>,
+}
+
+impl LlmBudget {
+    /// A budget without limits — it only tallies.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_tokens(mut self, max_tokens: u64) -> Self {
+        self.max_tokens = Some(max_tokens);
+        self
+    }
+
+    pub fn with_max_cost_usd(mut self, max_cost_usd: f64) -> Self {
+        self.max_cost_usd = Some(max_cost_usd);
+        self
+    }
+
+    /// Add `usage` to what the run has spent.
+    pub fn charge(&self, usage: LlmUsage) {
+        let mut spent = self.spent.lock().unwrap_or_else(|e| e.into_inner());
+        *spent = *spent + usage;
+    }
+
+    pub fn spent(&self) -> LlmUsage {
+        *self.spent.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Whether the run has reached a limit.
+    pub fn is_exhausted(&self) -> bool {
+        let spent = self.spent();
+        self.max_tokens.is_some_and(|max| spent.total_tokens() >= max)
+            || self.max_cost_usd.is_some_and(|max| spent.cost_usd >= max)
+    }
 }
 
 /// Result from a single agent in an ensemble.
@@ -424,4 +563,30 @@ mod tests {
         let err = client.invoke("test", "input").await.unwrap_err();
         assert!(matches!(err, LlmOrcError::Unavailable(_)));
     }
+
+    #[test]
+    fn usage_reads_totals_or_sums_agents() {
+        let mut response = mock_response(vec![]);
+        assert_eq!(response.usage(), LlmUsage::default());
+
+        response.metadata = serde_json::json!({"usage": {"totals": {"input_tokens": 100, "output_tokens": 20, "cost_usd": 0.5}}});
+        assert_eq!(response.usage(), LlmUsage { input_tokens: 100, output_tokens: 20, cost_usd: 0.5 });
+
+        response.metadata = serde_json::json!({"usage": {
+            "extractor": {"prompt_tokens": 10, "completion_tokens": 5, "cost": 0.25},
+            "synthesizer": {"total_tokens": 7}
+        }});
+        assert_eq!(response.usage(), LlmUsage { input_tokens: 17, output_tokens: 5, cost_usd: 0.25 });
+    }
+
+    #[test]
+    fn budget_is_exhausted_at_either_limit() {
+        let budget = LlmBudget::new().with_max_tokens(100).with_max_cost_usd(1.0);
+        budget.charge(LlmUsage { input_tokens: 60, output_tokens: 0, cost_usd: 0.5 });
+        assert!(!budget.is_exhausted());
+        budget.charge(LlmUsage { input_tokens: 30, output_tokens: 10, cost_usd: 0.1 });
+        assert!(budget.is_exhausted());
+        assert_eq!(budget.spent().total_tokens(), 100);
+        assert!(!LlmBudget::new().is_exhausted());
+    }
 }
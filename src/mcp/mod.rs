@@ -1,11 +1,16 @@
 //! MCP server for Plexus — knowledge graph engine via the Model Context Protocol.
 //!
-//! Tools: 18 total (1 session + 1 ingest + 6 context + 8 graph read + 2 spec lifecycle: load + unload).
+//! Tools: 36 total (1 session + 1 ingest + 1 provenance link + 7 context + 17 graph read + 3 saved query: save + list + run
+//! + 2 materialized view: materialize + read + 2 admin: maintain + prune + 2 spec lifecycle: load + unload).
 //!
 //! The single graph-data write path is `ingest` (ADR-028), which routes to
 //! adapters by input_kind (explicit or auto-classified from JSON shape).
 //! All ingests go through the full pipeline enforcing Invariant 7: all
-//! knowledge carries both semantic content and provenance. `load_spec`
+//! knowledge carries both semantic content and provenance. With `batch`
+//! set, `data` is an array whose elements are ingested in turn and
+//! reported on their own. `link_marks` joins existing marks, so it writes
+//! no new knowledge; it takes a batch of links for the same reason. Both
+//! cap a batch at `MAX_BATCH_ITEMS`. `load_spec`
 //! (ADR-037) is a separate surface — it installs a consumer's adapter +
 //! lens onto the active context, which is a configuration write rather
 //! than a graph-data write.
@@ -13,10 +18,15 @@
 pub mod params;
 
 use params::*;
-use crate::api::PlexusApi;
-use crate::adapter::{PipelineBuilder, classify_input};
-use crate::graph::{NodeId, Source};
-use crate::query::{CursorFilter, Direction, FindQuery, PathQuery, QueryFilter, RankBy, TraverseQuery};
+use crate::api::{BootstrapOptions, PlexusApi, SimilarTo};
+use crate::adapter::{IngestPipeline, PipelineBuilder, ReplayLog, classify_input};
+use crate::graph::{NodeId, PrunePolicy, Source, TenantDirectory};
+use crate::query::{
+    ContextPack, CursorFilter, Direction, FindQuery, GeoFilter, HubDampening, JsonLd, PathConstraint, PathQuery, PathScoring, QueryFilter, RankBy, SavedQuery, TimeBucket, TimelineQuery,
+    TraverseQuery,
+    DEFAULT_DOSSIER_RELATED, DEFAULT_SUPER_NODE_DEGREE,
+};
+use crate::error::{ErrorCode, ErrorCoded, ErrorPayload};
 use crate::{OpenStore, PlexusEngine, SqliteStore};
 use rmcp::{
     handler::server::{router::tool::ToolRouter, wrapper::Parameters},
@@ -26,6 +36,9 @@ use rmcp::{
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
+/// Most elements one batched `ingest` or `link_marks` call accepts.
+pub const MAX_BATCH_ITEMS: usize = 500;
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
@@ -34,8 +47,21 @@ fn ok_text(text: String) -> Result<CallToolResult, McpError> {
     Ok(CallToolResult::success(vec![Content::text(text)]))
 }
 
+/// A tool-call failure: the message as text, plus an `ErrorPayload`
+/// (code, retryable, causes) as structured content for clients to branch on.
+fn err_payload(payload: ErrorPayload) -> Result<CallToolResult, McpError> {
+    let mut result = CallToolResult::error(vec![Content::text(payload.message.clone())]);
+    result.structured_content = serde_json::to_value(&payload).ok();
+    Ok(result)
+}
+
+fn err<E: ErrorCoded + ?Sized>(e: &E) -> Result<CallToolResult, McpError> {
+    err_payload(ErrorPayload::from_error(e))
+}
+
+/// A failure detected by the tool itself, i.e. bad parameters.
 fn err_text(msg: String) -> Result<CallToolResult, McpError> {
-    Ok(CallToolResult::error(vec![Content::text(msg)]))
+    err_payload(ErrorPayload::new(ErrorCode::InvalidInput, msg))
 }
 
 // ---------------------------------------------------------------------------
@@ -53,8 +79,12 @@ pub struct PlexusMcpServer {
 impl PlexusMcpServer {
     pub fn new(engine: Arc<PlexusEngine>) -> Self {
         let pipeline = PipelineBuilder::default_pipeline(engine.clone());
+        Self::with_pipeline(engine, pipeline)
+    }
 
-        let api = PlexusApi::new(engine.clone(), Arc::new(pipeline));
+    /// Serve over a pre-built pipeline (e.g. one recording a replay log).
+    pub fn with_pipeline(engine: Arc<PlexusEngine>, pipeline: IngestPipeline) -> Self {
+        let api = PlexusApi::new(engine, Arc::new(pipeline));
 
         Self {
             api,
@@ -63,6 +93,12 @@ impl PlexusMcpServer {
         }
     }
 
+    /// Serve only `tenant`'s contexts.
+    pub fn with_tenant(mut self, tenant: impl Into<String>) -> Self {
+        self.api = self.api.with_tenant(tenant);
+        self
+    }
+
     // ── Session ─────────────────────────────────────────────────────────
 
     fn context(&self) -> Result<String, McpError> {
@@ -89,7 +125,7 @@ impl PlexusMcpServer {
         // Auto-create if the context doesn't exist
         if self.api.context_list(Some(&p.name)).unwrap_or_default().is_empty() {
             if let Err(e) = self.api.context_create(&p.name) {
-                return err_text(format!("failed to create context '{}': {}", p.name, e));
+                return err(&e);
             }
         }
         *self.active_context.lock().map_err(|_| McpError {
@@ -102,7 +138,7 @@ impl PlexusMcpServer {
 
     // ── Ingest (single write path — ADR-028, Invariant 7) ────────────────
 
-    #[tool(description = "Ingest data into the knowledge graph. Accepts JSON data and optional input_kind for routing. When input_kind is omitted, auto-detected from data shape: {\"text\": ...} → content, {\"file_path\": ...} → file extraction.")]
+    #[tool(description = "Ingest data into the knowledge graph. Accepts JSON data and optional input_kind for routing. When input_kind is omitted, auto-detected from data shape: {\"text\": ...} → content, {\"file_path\": ...} → file extraction. Set batch to ingest an array of up to 500 inputs, each reported on its own.")]
     async fn ingest(
         &self,
         Parameters(p): Parameters<IngestParams>,
@@ -111,6 +147,27 @@ impl PlexusMcpServer {
 
         tracing::debug!(context = %context_id, "mcp ingest");
 
+        if p.batch == Some(true) {
+            let serde_json::Value::Array(items) = p.data else {
+                return err_text("a batch ingest needs data to be an array".into());
+            };
+            if items.len() > MAX_BATCH_ITEMS {
+                return err_text(format!("a batch holds at most {} items, got {}", MAX_BATCH_ITEMS, items.len()));
+            }
+            let mut results = Vec::with_capacity(items.len());
+            for (index, data) in items.into_iter().enumerate() {
+                let key = p.idempotency_key.as_ref().map(|key| format!("{}:{}", key, index));
+                let result = self.ingest_item(&context_id, data, p.input_kind.clone(), key).await;
+                results.push(match result {
+                    Ok((input_kind, events)) => serde_json::json!({
+                        "index": index, "ok": true, "input_kind": input_kind, "events": events,
+                    }),
+                    Err(error) => serde_json::json!({ "index": index, "ok": false, "error": error }),
+                });
+            }
+            return ok_text(batch_report(results));
+        }
+
         // Resolve input_kind: explicit or classified from JSON
         let input_kind = match p.input_kind {
             Some(ref kind) => kind.clone(),
@@ -123,11 +180,11 @@ impl PlexusMcpServer {
                 })?,
         };
 
-        match self
-            .api
-            .ingest(&context_id, &input_kind, Box::new(p.data))
-            .await
-        {
+        let ingested = match p.idempotency_key {
+            Some(ref key) => self.api.ingest_json_idempotent(&context_id, &input_kind, p.data, key).await,
+            None => self.api.ingest_json(&context_id, &input_kind, p.data).await,
+        };
+        match ingested {
             Ok(events) => ok_text(
                 serde_json::to_string_pretty(&serde_json::json!({
                     "input_kind": input_kind,
@@ -135,13 +192,55 @@ impl PlexusMcpServer {
                 }))
                 .unwrap(),
             ),
-            Err(e) => err_text(e.to_string()),
+            Err(e) => err(&e),
+        }
+    }
+
+    /// Ingest one batch element, classifying it unless `input_kind` is given.
+    async fn ingest_item(
+        &self,
+        context_id: &str,
+        data: serde_json::Value,
+        input_kind: Option<String>,
+        idempotency_key: Option<String>,
+    ) -> Result<(String, usize), ErrorPayload> {
+        let input_kind = match input_kind {
+            Some(kind) => kind,
+            None => classify_input(&data)
+                .map_err(|e| ErrorPayload::new(ErrorCode::InvalidInput, e.to_string()))?
+                .to_string(),
+        };
+        let ingested = match idempotency_key {
+            Some(ref key) => self.api.ingest_json_idempotent(context_id, &input_kind, data, key).await,
+            None => self.api.ingest_json(context_id, &input_kind, data).await,
+        };
+        ingested.map(|events| (input_kind, events.len())).map_err(|e| ErrorPayload::from_error(&e))
+    }
+
+    // ── Provenance ─────────────────────────────────────────────────────
+
+    #[tool(description = "Link pairs of existing provenance marks in the active context with links_to edges. Takes up to 500 links in one call and reports success or error per link; a missing mark fails only its own link.")]
+    async fn link_marks(
+        &self,
+        Parameters(p): Parameters<LinkMarksParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let ctx = self.context()?;
+        if p.links.len() > MAX_BATCH_ITEMS {
+            return err_text(format!("a batch holds at most {} links, got {}", MAX_BATCH_ITEMS, p.links.len()));
         }
+        let mut results = Vec::with_capacity(p.links.len());
+        for (index, link) in p.links.iter().enumerate() {
+            results.push(match self.api.link_marks(&ctx, &link.source_id, &link.target_id).await {
+                Ok(()) => serde_json::json!({ "index": index, "ok": true }),
+                Err(e) => serde_json::json!({ "index": index, "ok": false, "error": ErrorPayload::from_error(&e) }),
+            });
+        }
+        ok_text(batch_report(results))
     }
 
     // ── Context management ─────────────────────────────────────────────
 
-    #[tool(description = "List all contexts with their sources")]
+    #[tool(description = "List all contexts with their sources, node and edge counts and last change")]
     fn context_list(&self) -> Result<CallToolResult, McpError> {
         match self.api.context_list_info() {
             Ok(infos) => {
@@ -152,11 +251,14 @@ impl PlexusMcpServer {
                         "id": ci.id,
                         "source_count": ci.sources.len(),
                         "sources": ci.sources,
+                        "node_count": ci.node_count,
+                        "edge_count": ci.edge_count,
+                        "updated_at": ci.updated_at,
                     }))
                     .collect();
                 ok_text(serde_json::to_string_pretty(&items).unwrap())
             }
-            Err(e) => err_text(e.to_string()),
+            Err(e) => err(&e),
         }
     }
 
@@ -167,7 +269,7 @@ impl PlexusMcpServer {
     ) -> Result<CallToolResult, McpError> {
         match self.api.context_create(&p.name) {
             Ok(id) => ok_text(format!("created context '{}' ({})", p.name, id)),
-            Err(e) => err_text(e.to_string()),
+            Err(e) => err(&e),
         }
     }
 
@@ -178,7 +280,7 @@ impl PlexusMcpServer {
     ) -> Result<CallToolResult, McpError> {
         match self.api.context_delete(&p.name) {
             Ok(()) => ok_text(format!("deleted context '{}'", p.name)),
-            Err(e) => err_text(e.to_string()),
+            Err(e) => err(&e),
         }
     }
 
@@ -189,7 +291,7 @@ impl PlexusMcpServer {
     ) -> Result<CallToolResult, McpError> {
         match self.api.context_rename(&p.old_name, &p.new_name) {
             Ok(()) => ok_text(format!("renamed '{}' to '{}'", p.old_name, p.new_name)),
-            Err(e) => err_text(e.to_string()),
+            Err(e) => err(&e),
         }
     }
 
@@ -207,7 +309,7 @@ impl PlexusMcpServer {
         }).collect();
         match self.api.context_add_sources(&p.name, &sources) {
             Ok(()) => ok_text(format!("added {} source(s) to '{}'", sources.len(), p.name)),
-            Err(e) => err_text(e.to_string()),
+            Err(e) => err(&e),
         }
     }
 
@@ -225,7 +327,37 @@ impl PlexusMcpServer {
         }).collect();
         match self.api.context_remove_sources(&p.name, &sources) {
             Ok(()) => ok_text(format!("removed {} source(s) from '{}'", sources.len(), p.name)),
-            Err(e) => err_text(e.to_string()),
+            Err(e) => err(&e),
+        }
+    }
+
+    #[tool(description = "Bootstrap a context from a directory in one call: creates the context (optionally from a template), adds the directory as a source, ingests every matching file through file extraction and enrichment, and makes it the active context. Returns a report of files ingested and failed, and the resulting node and edge counts.")]
+    async fn bootstrap(
+        &self,
+        Parameters(p): Parameters<BootstrapParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let mut options = BootstrapOptions {
+            context: p.context,
+            template: p.template,
+            max_files: p.max_files,
+            ..BootstrapOptions::default()
+        };
+        if let Some(recursive) = p.recursive {
+            options.recursive = recursive;
+        }
+        if let Some(extensions) = p.extensions {
+            options.extensions = extensions.into_iter().map(|e| e.trim_start_matches('.').to_string()).collect();
+        }
+        match self.api.bootstrap(&p.path, options).await {
+            Ok(report) => {
+                *self.active_context.lock().map_err(|_| McpError {
+                    code: rmcp::model::ErrorCode::INTERNAL_ERROR,
+                    message: "active_context mutex poisoned".into(),
+                    data: None,
+                })? = Some(report.context.clone());
+                ok_text(serde_json::to_string_pretty(&report).unwrap())
+            }
+            Err(e) => err(&e),
         }
     }
 
@@ -241,36 +373,43 @@ impl PlexusMcpServer {
             p.contributor_ids,
             p.relationship_prefix,
             p.min_corroboration,
+            p.min_confidence,
         );
         match self.api.evidence_trail(&ctx, &p.node_id, filter) {
             Ok(result) => ok_text(serde_json::to_string_pretty(&result).unwrap()),
-            Err(e) => err_text(e.to_string()),
+            Err(e) => err(&e),
         }
     }
 
     // ── Query surface (ADR-036 §1) — flat parameter wrappers ───────────
 
-    #[tool(description = "Find nodes in the active context. Optional filters: node_type, dimension, contributor_ids, relationship_prefix, min_corroboration. When a composable filter is specified, a node qualifies only if it has at least one incident edge passing the filter (ADR-034).")]
+    #[tool(description = "Find nodes in the active context. Optional filters: node_type, dimension, contributor_ids, relationship_prefix, min_corroboration, min_confidence, near ([lat, lon, radius_km]), bbox ([south, west, north, east]). When a composable filter is specified, a node qualifies only if it has at least one incident edge passing the filter (ADR-034).")]
     fn find_nodes(
         &self,
         Parameters(p): Parameters<FindNodesParams>,
     ) -> Result<CallToolResult, McpError> {
         let ctx = self.context()?;
+        let geo = match parse_geo(p.near, p.bbox) {
+            Ok(geo) => geo,
+            Err(e) => return err_text(e),
+        };
         let query = FindQuery {
             node_type: p.node_type,
             dimension: p.dimension,
             limit: p.limit,
             offset: p.offset,
+            geo,
             filter: composable_filter(
                 p.contributor_ids,
                 p.relationship_prefix,
                 p.min_corroboration,
+                p.min_confidence,
             ),
             ..Default::default()
         };
         match self.api.find_nodes(&ctx, query) {
             Ok(result) => ok_text(serde_json::to_string_pretty(&result).unwrap()),
-            Err(e) => err_text(e.to_string()),
+            Err(e) => err(&e),
         }
     }
 
@@ -299,23 +438,31 @@ impl PlexusMcpServer {
                 p.contributor_ids,
                 p.relationship_prefix,
                 p.min_corroboration,
+                p.min_confidence,
             ),
+            explain: p.explain.unwrap_or(false),
+            hub_dampening: match (p.max_hub_degree, p.min_inverse_degree_score) {
+                (Some(max_degree), _) => Some(HubDampening::Skip { max_degree }),
+                (None, Some(min_score)) => Some(HubDampening::InverseDegree { min_score }),
+                (None, None) => None,
+            },
+            resolve_to_head: false,
         };
 
         match self.api.traverse(&ctx, query) {
             Ok(mut result) => {
                 if let Some(rank) = rank_by {
                     if let Err(e) = self.api.rank_traversal(&ctx, &mut result, rank) {
-                        return err_text(e.to_string());
+                        return err(&e);
                     }
                 }
                 ok_text(serde_json::to_string_pretty(&result).unwrap())
             }
-            Err(e) => err_text(e.to_string()),
+            Err(e) => err(&e),
         }
     }
 
-    #[tool(description = "Find a path between two nodes in the active context. Returns the path if one exists within max_length hops (default 5).")]
+    #[tool(description = "Find a path between two nodes in the active context. Returns the path if one exists within max_length hops (default 5), scored by its normalized hop weights; with max_paths, returns up to that many paths, best score first.")]
     fn find_path(
         &self,
         Parameters(p): Parameters<FindPathParams>,
@@ -325,6 +472,19 @@ impl PlexusMcpServer {
             Ok(d) => d,
             Err(e) => return err_text(e),
         };
+        let mut pattern = Vec::new();
+        for step in p.pattern.unwrap_or_default() {
+            let direction = match parse_direction(step.direction.as_deref()) {
+                Ok(d) => d,
+                Err(e) => return err_text(e),
+            };
+            pattern.push(PathConstraint { relationship: step.relationship, direction, node_type: step.node_type });
+        }
+        let scoring = match p.scoring.as_deref() {
+            None | Some("product") => PathScoring::Product,
+            Some("min") => PathScoring::Min,
+            Some(other) => return err_text(format!("unknown scoring '{}': expected product or min", other)),
+        };
 
         let query = PathQuery {
             source: NodeId::from_string(&p.source),
@@ -336,11 +496,20 @@ impl PlexusMcpServer {
                 p.contributor_ids,
                 p.relationship_prefix,
                 p.min_corroboration,
+                p.min_confidence,
             ),
+            explain: p.explain.unwrap_or(false),
+            pattern,
         };
-        match self.api.find_path(&ctx, query) {
+        if let Some(limit) = p.max_paths {
+            return match self.api.find_paths(&ctx, query, limit, scoring) {
+                Ok(paths) => ok_text(serde_json::to_string_pretty(&paths).unwrap()),
+                Err(e) => err(&e),
+            };
+        }
+        match self.api.find_scored_path(&ctx, query, scoring) {
             Ok(result) => ok_text(serde_json::to_string_pretty(&result).unwrap()),
-            Err(e) => err_text(e.to_string()),
+            Err(e) => err(&e),
         }
     }
 
@@ -361,7 +530,7 @@ impl PlexusMcpServer {
         };
         match self.api.changes_since(&ctx, p.cursor, filter.as_ref()) {
             Ok(result) => ok_text(serde_json::to_string_pretty(&result).unwrap()),
-            Err(e) => err_text(e.to_string()),
+            Err(e) => err(&e),
         }
     }
 
@@ -370,7 +539,37 @@ impl PlexusMcpServer {
         let ctx = self.context()?;
         match self.api.list_tags(&ctx) {
             Ok(tags) => ok_text(serde_json::to_string_pretty(&tags).unwrap()),
-            Err(e) => err_text(e.to_string()),
+            Err(e) => err(&e),
+        }
+    }
+
+    #[tool(description = "Tag vocabulary for the active context across all dimensions: concept nodes and provenance mark tags, each with usage count, first/last seen timestamps, contributing adapters, recent uses, and trend (new, rising, steady, declining, dormant). Most-used first. Raw material for vocabulary cleanup.")]
+    fn vocabulary(&self) -> Result<CallToolResult, McpError> {
+        let ctx = self.context()?;
+        match self.api.vocabulary(&ctx) {
+            Ok(stats) => ok_text(serde_json::to_string_pretty(&stats).unwrap()),
+            Err(e) => err(&e),
+        }
+    }
+
+    #[tool(description = "Graph shape of the active context for monitoring: histograms of node degree and contributions per edge (power-of-two buckets) and of edge weight (equal-width buckets), plus the highest-degree nodes. A lone node far out in the degree tail is a runaway super-node.")]
+    fn distributions(&self) -> Result<CallToolResult, McpError> {
+        let ctx = self.context()?;
+        match self.api.distributions(&ctx) {
+            Ok(report) => ok_text(serde_json::to_string_pretty(&report).unwrap()),
+            Err(e) => err(&e),
+        }
+    }
+
+    #[tool(description = "List super-nodes in the active context: nodes with at least min_degree edges (default 1000), highest degree first. Traversals through these reach most of the graph; use traverse's hub dampening options to stop at them.")]
+    fn super_nodes(
+        &self,
+        Parameters(p): Parameters<SuperNodesParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let ctx = self.context()?;
+        match self.api.super_nodes(&ctx, p.min_degree.unwrap_or(DEFAULT_SUPER_NODE_DEGREE)) {
+            Ok(nodes) => ok_text(serde_json::to_string_pretty(&nodes).unwrap()),
+            Err(e) => err(&e),
         }
     }
 
@@ -381,7 +580,266 @@ impl PlexusMcpServer {
     ) -> Result<CallToolResult, McpError> {
         match self.api.shared_concepts(&p.context_a, &p.context_b) {
             Ok(nodes) => ok_text(serde_json::to_string_pretty(&nodes).unwrap()),
-            Err(e) => err_text(e.to_string()),
+            Err(e) => err(&e),
+        }
+    }
+
+    #[tool(description = "Find the nodes most similar to a node or to free text in the active context, by embedding similarity. Returns the top k (default 10) with cosine scores and each hit's provenance: the fragments tagged with it, the marks referencing it, and their chains. Requires an embedder (the `embeddings` feature).")]
+    fn find_similar(
+        &self,
+        Parameters(p): Parameters<FindSimilarParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let ctx = self.context()?;
+        let query = match (p.node_id, p.text) {
+            (Some(id), None) => SimilarTo::Node(NodeId::from_string(&id)),
+            (None, Some(text)) => SimilarTo::Text(text),
+            _ => return err_text("give exactly one of node_id or text".into()),
+        };
+        match self.api.find_similar(&ctx, query, p.k.unwrap_or(10)) {
+            Ok(hits) => ok_text(serde_json::to_string_pretty(&hits).unwrap()),
+            Err(e) => err(&e),
+        }
+    }
+
+    #[tool(description = "Answer a question from the active context's graph through llm-orc: a plan ensemble turns it into find, traverse and similarity queries, and an answer ensemble answers from the packed subgraph they reach. Returns the answer with the nodes and edges it cites (all from that evidence), citations it made outside it, the queries run and the LLM usage. Requires an llm-orc client.")]
+    async fn ask(
+        &self,
+        Parameters(p): Parameters<AskParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let ctx = self.context()?;
+        match self.api.ask(&ctx, &p.question).await {
+            Ok(answer) => ok_text(serde_json::to_string_pretty(&answer).unwrap()),
+            Err(e) => err(&e),
+        }
+    }
+
+    #[tool(description = "Pack the most relevant subgraph around seed nodes into a compact block for an LLM prompt: nodes with key properties, edges with their rationale, and the marks citing them, ranked by proximity to the seeds and cut to fit a token budget. format is \"text\" (default) or \"json\".")]
+    fn pack_context(
+        &self,
+        Parameters(p): Parameters<PackContextParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let ctx = self.context()?;
+        let seeds = p.seeds.iter().map(NodeId::from_string).collect();
+        let mut pack = ContextPack::new(seeds, p.token_budget);
+        if p.provenance == Some(false) {
+            pack = pack.without_provenance();
+        }
+        let packed = match self.api.pack_context(&ctx, pack) {
+            Ok(packed) => packed,
+            Err(e) => return err(&e),
+        };
+        match p.format.as_deref() {
+            None | Some("text") => ok_text(packed.to_text()),
+            Some("json") => ok_text(serde_json::to_string_pretty(&packed).unwrap()),
+            Some(other) => err_text(format!("unknown format '{}': expected text or json", other)),
+        }
+    }
+
+    #[tool(description = "Everything the active context knows about one concept: defining sentences from fragments, the strongest related concepts with weights (up to related, default 10), the marks and chains citing it, and a timeline of its mentions. format is \"markdown\" (default) or \"json\".")]
+    fn dossier(
+        &self,
+        Parameters(p): Parameters<DossierParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let ctx = self.context()?;
+        let dossier = match self.api.dossier(&ctx, &p.concept, p.related.unwrap_or(DEFAULT_DOSSIER_RELATED)) {
+            Ok(dossier) => dossier,
+            Err(e) => return err(&e),
+        };
+        match p.format.as_deref() {
+            None | Some("markdown") => ok_text(dossier.to_markdown()),
+            Some("json") => ok_text(serde_json::to_string_pretty(&dossier).unwrap()),
+            Some(other) => err_text(format!("unknown format '{}': expected markdown or json", other)),
+        }
+    }
+
+    #[tool(description = "What the active context knows about one file: the marks, fragments and structure nodes (file, chunks, linking files) referencing it, and the concepts they connect to, most connected first. Relative paths resolve against the context's sources, so relative and absolute forms of a path match each other.")]
+    fn backlinks(
+        &self,
+        Parameters(p): Parameters<BacklinksParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let ctx = self.context()?;
+        match self.api.backlinks(&ctx, &p.path) {
+            Ok(backlinks) => ok_text(serde_json::to_string_pretty(&backlinks).unwrap()),
+            Err(e) => err(&e),
+        }
+    }
+
+    #[tool(description = "Nodes in time order — by scheduled_for, else created_at — optionally filtered to nodes touching given concepts and bucketed by day, week, month or year. Each bucket tallies the concepts touched in it, showing how thinking about a topic evolved.")]
+    fn timeline(
+        &self,
+        Parameters(p): Parameters<TimelineParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let ctx = self.context()?;
+        let bucket = match p.bucket.as_deref() {
+            None => None,
+            Some("day") => Some(TimeBucket::Day),
+            Some("week") => Some(TimeBucket::Week),
+            Some("month") => Some(TimeBucket::Month),
+            Some("year") => Some(TimeBucket::Year),
+            Some(other) => return err_text(format!("unknown bucket '{}': expected day, week, month or year", other)),
+        };
+        let (from, until) = match (parse_time(p.from.as_deref()), parse_time(p.until.as_deref())) {
+            (Ok(from), Ok(until)) => (from, until),
+            (Err(e), _) | (_, Err(e)) => return err_text(e),
+        };
+        let mut query = TimelineQuery::new()
+            .with_concepts(p.concepts.unwrap_or_default())
+            .with_node_types(p.node_types.unwrap_or_default())
+            .between(from, until);
+        query.bucket = bucket;
+        query.limit = p.limit;
+        match self.api.timeline(&ctx, query) {
+            Ok(result) => ok_text(serde_json::to_string_pretty(&result).unwrap()),
+            Err(e) => err(&e),
+        }
+    }
+
+    #[tool(description = "Nodes and the edges between them as JSON-LD: each node with its type, dimension, properties, outgoing links and provenance marks; each edge as an rdf:Statement with weight and contributions. Self-describing via @context, for linking into external knowledge systems.")]
+    fn get_jsonld(
+        &self,
+        Parameters(p): Parameters<JsonLdParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let ctx = self.context()?;
+        let mut jsonld = JsonLd::default();
+        if let Some(url) = p.context_url {
+            jsonld = jsonld.with_context_url(url);
+        }
+        let node_ids: Option<Vec<NodeId>> = p.node_ids.map(|ids| ids.into_iter().map(NodeId::from_string).collect());
+        match self.api.jsonld(&ctx, &jsonld, node_ids.as_deref()) {
+            Ok(doc) => ok_text(serde_json::to_string_pretty(&doc).unwrap()),
+            Err(e) => err(&e),
+        }
+    }
+
+    // ── Saved queries ──────────────────────────────────────────────────
+
+    #[tool(description = "Save a named query on the active context so anyone can run it by name (e.g. \"open-threads\", \"ungrounded-concepts\"). The definition is a find or traverse query tagged by kind; it persists with the context. Omit the query to delete the saved query.")]
+    fn save_query(
+        &self,
+        Parameters(p): Parameters<SaveQueryParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let ctx = self.context()?;
+        let Some(definition) = p.query.filter(|q| !q.is_null()) else {
+            return match self.api.delete_saved_query(&ctx, &p.name) {
+                Ok(true) => ok_text(format!("deleted saved query '{}'", p.name)),
+                Ok(false) => err_payload(ErrorPayload::new(ErrorCode::NotFound, format!("saved query '{}' not found", p.name))),
+                Err(e) => err(&e),
+            };
+        };
+        let query: SavedQuery = match serde_json::from_value(definition) {
+            Ok(q) => q,
+            Err(e) => return err_text(format!("invalid query definition: {}", e)),
+        };
+        match self.api.save_query(&ctx, &p.name, query) {
+            Ok(()) => ok_text(format!("saved query '{}'", p.name)),
+            Err(e) => err(&e),
+        }
+    }
+
+    #[tool(description = "List the saved queries on the active context: name → definition.")]
+    fn list_saved_queries(&self) -> Result<CallToolResult, McpError> {
+        let ctx = self.context()?;
+        match self.api.saved_queries(&ctx) {
+            Ok(queries) => ok_text(serde_json::to_string_pretty(&queries).unwrap()),
+            Err(e) => err(&e),
+        }
+    }
+
+    #[tool(description = "Run a saved query on the active context by name. Returns the result tagged by kind: find results carry nodes and total_count, traverse results carry levels and edges.")]
+    fn run_saved_query(
+        &self,
+        Parameters(p): Parameters<RunSavedQueryParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let ctx = self.context()?;
+        match self.api.run_saved_query(&ctx, &p.name) {
+            Ok(result) => ok_text(serde_json::to_string_pretty(&result).unwrap()),
+            Err(e) => err(&e),
+        }
+    }
+
+    #[tool(description = "Materialize a named view on the active context: a find or traverse query (tagged by kind, as for save_query) whose result set is stored and maintained on every commit. Find views update in place; changes a view can't apply incrementally mark it stale until refreshed. Omit the query to drop the view.")]
+    fn materialize_view(
+        &self,
+        Parameters(p): Parameters<MaterializeViewParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let ctx = self.context()?;
+        let Some(definition) = p.query.filter(|q| !q.is_null()) else {
+            return match self.api.drop_view(&ctx, &p.name) {
+                Ok(true) => ok_text(format!("dropped materialized view '{}'", p.name)),
+                Ok(false) => err_payload(ErrorPayload::new(
+                    ErrorCode::NotFound,
+                    format!("materialized view '{}' not found", p.name),
+                )),
+                Err(e) => err(&e),
+            };
+        };
+        let query: SavedQuery = match serde_json::from_value(definition) {
+            Ok(q) => q,
+            Err(e) => return err_text(format!("invalid query definition: {}", e)),
+        };
+        match self.api.materialize_view(&ctx, &p.name, query) {
+            Ok(()) => ok_text(format!("materialized view '{}'", p.name)),
+            Err(e) => err(&e),
+        }
+    }
+
+    #[tool(description = "Read a materialized view on the active context. Returns its nodes and edges with staleness metadata: refreshed_at, maintained_at, stale, and events_since_refresh. Set refresh to recompute a stale view first.")]
+    fn read_view(
+        &self,
+        Parameters(p): Parameters<ReadViewParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let ctx = self.context()?;
+        match self.api.read_view(&ctx, &p.name, p.refresh.unwrap_or(false)) {
+            Ok(snapshot) => ok_text(serde_json::to_string_pretty(&snapshot).unwrap()),
+            Err(e) => err(&e),
+        }
+    }
+
+    // ── Admin ──────────────────────────────────────────────────────────
+
+    #[tool(description = "Compact the database after heavy retraction or decay: delete rows orphaned by deleted contexts and edges whose endpoints are gone, rebuild indexes, and vacuum. Reports counts removed and bytes reclaimed.")]
+    fn maintain(
+        &self,
+        Parameters(p): Parameters<MaintainParams>,
+    ) -> Result<CallToolResult, McpError> {
+        match self.api.maintain(p.context.as_deref()) {
+            Ok(report) => {
+                let mut json = serde_json::to_value(&report).unwrap();
+                json["bytes_reclaimed"] = report.bytes_reclaimed().into();
+                ok_text(serde_json::to_string_pretty(&json).unwrap())
+            }
+            Err(e) => err(&e),
+        }
+    }
+
+    #[tool(description = "Prune edges from the active context by policy: weight_below, zero_contribution, unreferenced_derived, or older_than. Dry run by default — returns the edge IDs that would be removed; pass dry_run: false to remove them and emit an EdgesRemoved event.")]
+    fn prune(
+        &self,
+        Parameters(p): Parameters<PruneParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let ctx = self.context()?;
+        let policy = match p.policy.as_str() {
+            "weight_below" => match p.threshold {
+                Some(threshold) => PrunePolicy::WeightBelow { threshold },
+                None => return err_text("weight_below requires threshold".into()),
+            },
+            "zero_contribution" => PrunePolicy::ZeroContribution,
+            "unreferenced_derived" => PrunePolicy::UnreferencedDerived { derived_by: p.derived_by.unwrap_or_default() },
+            "older_than" => match p.max_age_days {
+                Some(days) => match chrono::Duration::try_days(days).filter(|_| days >= 0) {
+                    Some(max_age) => PrunePolicy::OlderThan { max_age },
+                    None => return err_text(format!("max_age_days must be between 0 and {}", i64::MAX / 86_400_000)),
+                },
+                None => return err_text("older_than requires max_age_days".into()),
+            },
+            other => return err_text(format!(
+                "invalid policy: '{}' (expected weight_below, zero_contribution, unreferenced_derived, or older_than)",
+                other
+            )),
+        };
+        match self.api.prune(&ctx, policy, p.dry_run.unwrap_or(true)) {
+            Ok(report) => ok_text(serde_json::to_string_pretty(&report).unwrap()),
+            Err(e) => err(&e),
         }
     }
 
@@ -395,7 +853,7 @@ impl PlexusMcpServer {
         let ctx = self.context()?;
         match self.api.explain_edge(&ctx, &p.source, &p.target, p.relationship.as_deref()) {
             Ok(explanation) => ok_text(serde_json::to_string_pretty(&explanation).unwrap()),
-            Err(e) => err_text(format!("explain_edge failed: {}", e)),
+            Err(e) => err(&e),
         }
     }
 
@@ -414,7 +872,7 @@ impl PlexusMcpServer {
                 }))
                 .unwrap(),
             ),
-            Err(e) => err_text(e.to_string()),
+            Err(e) => err(&e),
         }
     }
 
@@ -429,7 +887,7 @@ impl PlexusMcpServer {
                 "unloaded spec '{}' from context '{}'",
                 p.adapter_id, ctx
             )),
-            Err(e) => err_text(e.to_string()),
+            Err(e) => err(&e),
         }
     }
 }
@@ -442,15 +900,54 @@ fn composable_filter(
     contributor_ids: Option<Vec<String>>,
     relationship_prefix: Option<String>,
     min_corroboration: Option<usize>,
+    min_confidence: Option<f64>,
 ) -> Option<QueryFilter> {
-    if contributor_ids.is_none() && relationship_prefix.is_none() && min_corroboration.is_none() {
+    if contributor_ids.is_none()
+        && relationship_prefix.is_none()
+        && min_corroboration.is_none()
+        && min_confidence.is_none()
+    {
         return None;
     }
     Some(QueryFilter {
         contributor_ids,
         relationship_prefix,
         min_corroboration,
+        min_confidence,
+    })
+}
+
+fn parse_geo(near: Option<Vec<f64>>, bbox: Option<Vec<f64>>) -> Result<Option<GeoFilter>, String> {
+    let geo = match (near.as_deref(), bbox.as_deref()) {
+        (None, None) => return Ok(None),
+        (Some(&[latitude, longitude, radius_km]), None) => GeoFilter::Radius { latitude, longitude, radius_km },
+        (None, Some(&[south, west, north, east])) => GeoFilter::BoundingBox { south, west, north, east },
+        (Some(_), Some(_)) => return Err("give near or bbox, not both".to_string()),
+        (Some(_), None) => return Err("near expects [latitude, longitude, radius_km]".to_string()),
+        (None, Some(_)) => return Err("bbox expects [south, west, north, east]".to_string()),
+    };
+    geo.validate()?;
+    Ok(Some(geo))
+}
+
+fn parse_time(s: Option<&str>) -> Result<Option<chrono::DateTime<chrono::Utc>>, String> {
+    s.map(|s| {
+        chrono::DateTime::parse_from_rfc3339(s)
+            .map(|t| t.with_timezone(&chrono::Utc))
+            .map_err(|e| format!("invalid timestamp '{}': {}", s, e))
     })
+    .transpose()
+}
+
+/// `{succeeded, failed, results}` for per-item batch results.
+fn batch_report(results: Vec<serde_json::Value>) -> String {
+    let succeeded = results.iter().filter(|r| r["ok"] == true).count();
+    serde_json::to_string_pretty(&serde_json::json!({
+        "succeeded": succeeded,
+        "failed": results.len() - succeeded,
+        "results": results,
+    }))
+    .unwrap()
 }
 
 fn parse_direction(s: Option<&str>) -> Result<Direction, String> {
@@ -494,7 +991,20 @@ impl ServerHandler for PlexusMcpServer {
 // Entry point
 // ---------------------------------------------------------------------------
 
-pub fn run_mcp_server(db_path: PathBuf) -> i32 {
+/// Run the MCP server on stdio against the database at `db_path`.
+///
+/// With `record` set, every ingest is appended to that replay log. With
+/// `tenant_keys` set, the `PLEXUS_API_KEY` environment variable must hold
+/// a key from that `TenantDirectory` file; the server then loads and
+/// serves only that tenant's contexts, under its quota. With
+/// `watch_relocations` set, moved source files are relocated every that
+/// many seconds while the server runs.
+pub fn run_mcp_server(
+    db_path: PathBuf,
+    record: Option<PathBuf>,
+    tenant_keys: Option<PathBuf>,
+    watch_relocations: Option<std::time::Duration>,
+) -> i32 {
     let rt = match tokio::runtime::Runtime::new() {
         Ok(rt) => rt,
         Err(e) => {
@@ -504,7 +1014,28 @@ pub fn run_mcp_server(db_path: PathBuf) -> i32 {
     };
 
     rt.block_on(async {
-        let engine = {
+        let tenant = match tenant_keys {
+            Some(path) => {
+                let directory = match TenantDirectory::load(&path) {
+                    Ok(d) => d,
+                    Err(e) => {
+                        tracing::error!(error = %e, "failed to load tenant directory");
+                        return 1;
+                    }
+                };
+                let api_key = std::env::var("PLEXUS_API_KEY").unwrap_or_default();
+                match directory.authenticate(&api_key) {
+                    Some((tenant, quota)) => Some((tenant.to_string(), quota.clone())),
+                    None => {
+                        tracing::error!("PLEXUS_API_KEY does not match any tenant");
+                        return 1;
+                    }
+                }
+            }
+            None => None,
+        };
+
+        let (engine, store) = {
             let store = match SqliteStore::open(&db_path) {
                 Ok(s) => Arc::new(s),
                 Err(e) => {
@@ -512,15 +1043,40 @@ pub fn run_mcp_server(db_path: PathBuf) -> i32 {
                     return 1;
                 }
             };
-            let eng = PlexusEngine::with_store(store);
-            if let Err(e) = eng.load_all() {
+            let eng = Arc::new(PlexusEngine::with_store(store.clone()));
+            // Untenanted servers list every context from the manifest at
+            // once and hydrate them in the background
+            let loaded = match &tenant {
+                Some((name, quota)) => {
+                    eng.set_tenant_quota(name, Some(quota.clone()));
+                    eng.load_tenant(name)
+                }
+                None => eng.warm_start(),
+            };
+            if let Err(e) = loaded {
                 tracing::error!(error = %e, "failed to load contexts");
                 return 1;
             }
-            eng
+            (eng, store)
         };
 
-        let server = PlexusMcpServer::new(Arc::new(engine));
+        let mut pipeline = PipelineBuilder::default_pipeline(engine.clone());
+        if let Some(path) = record {
+            match ReplayLog::open(&path) {
+                Ok(log) => pipeline = pipeline.with_replay_log(log),
+                Err(e) => {
+                    tracing::error!(path = %path.display(), error = %e, "failed to open replay log");
+                    return 1;
+                }
+            }
+        }
+        let mut server = PlexusMcpServer::with_pipeline(engine, pipeline);
+        if let Some((name, _)) = tenant {
+            tracing::info!(tenant = %name, "serving tenant");
+            server = server.with_tenant(name);
+        }
+        // Runs until dropped at the end of this block
+        let _watcher = watch_relocations.map(|interval| server.api.watch_relocations(interval));
 
         tracing::info!("plexus mcp server starting on stdio...");
 
@@ -532,7 +1088,11 @@ pub fn run_mcp_server(db_path: PathBuf) -> i32 {
             }
         };
 
-        if let Err(e) = service.waiting().await {
+        let served = service.waiting().await;
+        if let Err(e) = store.flush_deferred() {
+            tracing::error!(error = %e, "failed to flush deferred saves on shutdown");
+        }
+        if let Err(e) = served {
             tracing::error!(error = %e, "MCP server error");
             return 1;
         }
@@ -584,6 +1144,227 @@ mod tests {
             .expect("seed ingest");
     }
 
+    #[tokio::test]
+    async fn bootstrap_ingests_directory_and_activates_context() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.md"), "---\ntags: [graphs]\n---\n# A\n").unwrap();
+        std::fs::create_dir(dir.path().join("notes")).unwrap();
+        std::fs::write(dir.path().join("notes/b.md"), "# B\n").unwrap();
+        std::fs::write(dir.path().join("notes/skip.txt"), "plain").unwrap();
+        std::fs::write(dir.path().join(".hidden.md"), "# hidden\n").unwrap();
+        let server = server_with_context("t");
+
+        let result = server
+            .bootstrap(Parameters(BootstrapParams {
+                path: dir.path().to_string_lossy().into_owned(),
+                context: Some("notes".into()),
+                template: Some("writing-project".into()),
+                recursive: None,
+                extensions: Some(vec![".md".into()]),
+                max_files: None,
+            }))
+            .await
+            .expect("bootstrap");
+        let report: serde_json::Value = serde_json::from_str(&text_of(&result)).expect("json parse");
+        assert_eq!(report["created"], true);
+        assert_eq!(report["ingested"].as_array().unwrap().len(), 2, "hidden and .txt files are skipped");
+        assert!(report["failed"].as_array().unwrap().is_empty());
+        assert!(report["nodes"].as_u64().unwrap() > 0);
+        assert_eq!(server.context().unwrap(), "notes");
+
+        let info = server.api.context_info("notes").unwrap();
+        assert_eq!(info.sources.len(), 1);
+        let again = server.api.bootstrap(dir.path(), BootstrapOptions::default().with_context("notes")).await.unwrap();
+        assert!(!again.created, "an existing context is reused");
+        assert_eq!(server.api.context_info("notes").unwrap().sources.len(), 1);
+    }
+
+    /// Embeds text as its letter counts.
+    struct LetterEmbedder;
+
+    impl crate::adapter::Embedder for LetterEmbedder {
+        fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, crate::adapter::EmbeddingError> {
+            Ok(texts
+                .iter()
+                .map(|text| {
+                    let mut counts = vec![0.0; 26];
+                    for c in text.to_lowercase().bytes().filter(u8::is_ascii_lowercase) {
+                        counts[(c - b'a') as usize] += 1.0;
+                    }
+                    counts
+                })
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn find_similar_ranks_by_embedding_with_provenance() {
+        let engine = Arc::new(PlexusEngine::new());
+        engine.upsert_context(Context::new("t")).expect("upsert");
+        let pipeline = PipelineBuilder::new(engine.clone())
+            .with_default_adapters()
+            .with_embedder("letters", 0.99, Arc::new(LetterEmbedder))
+            .build();
+        let server = PlexusMcpServer::with_pipeline(engine, pipeline);
+        *server.active_context.lock().unwrap() = Some("t".to_string());
+        seed_fragment(&server, "t", "Notes on graph theory", vec!["graphs", "melody"]).await;
+
+        let result = server
+            .find_similar(Parameters(FindSimilarParams { node_id: None, text: Some("graph".into()), k: Some(1) }))
+            .expect("find_similar");
+        let hits: serde_json::Value = serde_json::from_str(&text_of(&result)).expect("json parse");
+        let hits = hits.as_array().expect("hits array");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0]["id"], "concept:graphs");
+        assert_eq!(hits[0]["fragments"].as_array().unwrap().len(), 1, "the tagging fragment is provenance");
+
+        let result = server
+            .find_similar(Parameters(FindSimilarParams { node_id: Some("concept:graphs".into()), text: None, k: None }))
+            .expect("find_similar");
+        let body = text_of(&result);
+        assert!(body.contains("concept:melody") && !body.contains("\"concept:graphs\""), "{body}");
+
+        let both = server
+            .find_similar(Parameters(FindSimilarParams { node_id: Some("x".into()), text: Some("y".into()), k: None }))
+            .expect("find_similar");
+        assert_eq!(both.is_error, Some(true));
+    }
+
+    #[tokio::test]
+    async fn ask_answers_through_the_qa_ensembles() {
+        use crate::llm_orc::{mock_response, MockClient};
+
+        let offline = server_with_context("t");
+        let result = offline.ask(Parameters(AskParams { question: "?".into() })).await.expect("ask");
+        assert_eq!(result.is_error, Some(true), "no llm-orc client configured");
+
+        let engine = Arc::new(PlexusEngine::new());
+        engine.upsert_context(Context::new("t")).expect("upsert");
+        let client = MockClient::available()
+            .with_response(crate::qa::PLAN_ENSEMBLE, mock_response(vec![("planner", r#"{"queries": [{"kind": "find", "node_type": "concept"}]}"#)]))
+            .with_response(crate::qa::ANSWER_ENSEMBLE, mock_response(vec![("answerer", "It covers [concept:graphs].")]));
+        let pipeline = PipelineBuilder::new(engine.clone()).with_default_adapters().with_llm_client(Arc::new(client)).build();
+        let server = PlexusMcpServer::with_pipeline(engine, pipeline);
+        *server.active_context.lock().unwrap() = Some("t".to_string());
+        seed_fragment(&server, "t", "Notes on graph theory", vec!["graphs"]).await;
+
+        let result = server
+            .ask(Parameters(AskParams { question: "What do the notes cover?".into() }))
+            .await
+            .expect("ask");
+        let answer: serde_json::Value = serde_json::from_str(&text_of(&result)).expect("json parse");
+        assert_eq!(answer["answer"], "It covers [concept:graphs].");
+        assert_eq!(answer["nodes"], serde_json::json!(["concept:graphs"]));
+    }
+
+    #[tokio::test]
+    async fn pack_context_renders_neighbourhood_within_budget() {
+        let server = server_with_context("t");
+        seed_fragment(&server, "t", "Notes on graph theory", vec!["graphs", "melody"]).await;
+
+        let params = |format: Option<&str>, token_budget| PackContextParams {
+            seeds: vec!["concept:graphs".into()],
+            token_budget,
+            provenance: None,
+            format: format.map(String::from),
+        };
+        let text = text_of(&server.pack_context(Parameters(params(None, 500))).expect("pack_context"));
+        assert!(text.starts_with("# Nodes\n- [concept:graphs]"), "{text}");
+        assert!(text.contains("concept:melody") && text.contains("# Edges"), "{text}");
+
+        let result = server.pack_context(Parameters(params(Some("json"), 10))).expect("pack_context");
+        let packed: serde_json::Value = serde_json::from_str(&text_of(&result)).expect("json parse");
+        assert_eq!(packed["truncated"], true);
+        assert!(packed["estimated_tokens"].as_u64().unwrap() <= 10);
+
+        let bad = server.pack_context(Parameters(params(Some("xml"), 10))).expect("pack_context");
+        assert_eq!(bad.is_error, Some(true));
+    }
+
+    #[tokio::test]
+    async fn dossier_renders_markdown_or_json() {
+        let server = server_with_context("t");
+        seed_fragment(&server, "t", "Graphs are networks of nodes.", vec!["graphs", "melody"]).await;
+
+        let params = |concept: &str, format: Option<&str>| DossierParams { concept: concept.into(), related: None, format: format.map(String::from) };
+        let md = text_of(&server.dossier(Parameters(params("graphs", None))).expect("dossier"));
+        assert!(md.starts_with("# graphs\n"), "{md}");
+        assert!(md.contains("Graphs are networks of nodes."), "{md}");
+
+        let json: serde_json::Value =
+            serde_json::from_str(&text_of(&server.dossier(Parameters(params("concept:graphs", Some("json")))).unwrap())).unwrap();
+        assert_eq!(json["definitions"][0]["defining"], true);
+
+        let missing = server.dossier(Parameters(params("nothing", None))).expect("dossier");
+        assert_eq!(missing.structured_content.as_ref().unwrap()["code"], "node_not_found");
+    }
+
+    #[tokio::test]
+    async fn backlinks_report_what_cites_a_file() {
+        let server = server_with_context("t");
+        server.api.context_add_sources("t", &[Source::Directory { path: "/home/me".into(), recursive: true }]).expect("add source");
+        let located = FragmentInput::new("Rye sours fast", vec!["rye".into()]).with_location("notes/bread.md", 3);
+        server.api.ingest("t", "content", Box::new(located)).await.expect("seed ingest");
+        seed_fragment(&server, "t", "Unrelated", vec!["oven"]).await;
+
+        let result = server.backlinks(Parameters(BacklinksParams { path: "/home/me/notes/bread.md".into() })).expect("backlinks");
+        let backlinks: serde_json::Value = serde_json::from_str(&text_of(&result)).expect("json parse");
+        assert_eq!(backlinks["marks"].as_array().unwrap().len(), 1, "{backlinks}");
+        assert_eq!(backlinks["marks"][0]["properties"]["file"], "notes/bread.md");
+        let concepts: Vec<&str> = backlinks["concepts"].as_array().unwrap().iter().map(|c| c["label"].as_str().unwrap()).collect();
+        assert_eq!(concepts, vec!["rye"]);
+        assert_eq!(backlinks["fragments"][0]["properties"]["text"], "Rye sours fast");
+    }
+
+    #[tokio::test]
+    async fn timeline_buckets_tagged_fragments() {
+        let server = server_with_context("t");
+        seed_fragment(&server, "t", "Federated averaging notes", vec!["federated-learning"]).await;
+        seed_fragment(&server, "t", "Sourdough", vec!["baking"]).await;
+
+        let params = |bucket: Option<&str>| TimelineParams {
+            concepts: Some(vec!["federated-learning".into()]),
+            node_types: None,
+            bucket: bucket.map(String::from),
+            from: None,
+            until: None,
+            limit: None,
+        };
+        let result = server.timeline(Parameters(params(Some("month")))).expect("timeline");
+        let timeline: serde_json::Value = serde_json::from_str(&text_of(&result)).expect("json parse");
+        let entries = timeline["entries"].as_array().unwrap();
+        assert_eq!(entries.len(), 1, "only the tagged fragment: {timeline}");
+        assert_eq!(entries[0]["node"]["node_type"], "fragment");
+        assert_eq!(timeline["buckets"][0]["concepts"][0][0], "concept:federated-learning");
+
+        let bad = server.timeline(Parameters(params(Some("fortnight")))).expect("timeline");
+        assert_eq!(bad.is_error, Some(true));
+    }
+
+    #[tokio::test]
+    async fn get_jsonld_returns_self_describing_subgraph() {
+        let server = server_with_context("t");
+        seed_fragment(&server, "t", "Federated averaging notes", vec!["federated-learning"]).await;
+
+        let params = |node_ids: Option<Vec<String>>| JsonLdParams { node_ids, context_url: None };
+        let doc = |result: CallToolResult| -> serde_json::Value { serde_json::from_str(&text_of(&result)).expect("json parse") };
+
+        let whole = doc(server.get_jsonld(Parameters(params(None))).expect("get_jsonld"));
+        assert_eq!(whole["@context"]["@vocab"], "urn:plexus:vocab#");
+        let fragment = whole["@graph"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|v| v["@type"] == "urn:plexus:vocab#fragment")
+            .expect("fragment node");
+        assert!(fragment["provenance"].as_array().is_some_and(|marks| !marks.is_empty()), "{whole}");
+
+        let one = doc(server.get_jsonld(Parameters(params(Some(vec!["concept:federated-learning".into()])))).expect("get_jsonld"));
+        let graph = one["@graph"].as_array().unwrap();
+        assert_eq!(graph.len(), 1, "one node, no edges within the subgraph: {one}");
+        assert_eq!(graph[0]["@id"], "urn:plexus:node:concept:federated-learning");
+    }
+
     #[tokio::test]
     async fn find_nodes_delegates_to_api_and_returns_json() {
         let server = server_with_context("t");
@@ -596,6 +1377,9 @@ mod tests {
                 contributor_ids: None,
                 relationship_prefix: None,
                 min_corroboration: None,
+                min_confidence: None,
+                near: None,
+                bbox: None,
                 limit: None,
                 offset: None,
             }))
@@ -623,6 +1407,10 @@ mod tests {
                 contributor_ids: None,
                 relationship_prefix: None,
                 min_corroboration: None,
+                min_confidence: None,
+                explain: None,
+                max_hub_degree: None,
+                min_inverse_degree_score: None,
             }))
             .expect("traverse");
 
@@ -644,6 +1432,10 @@ mod tests {
                 contributor_ids: None,
                 relationship_prefix: None,
                 min_corroboration: None,
+                min_confidence: None,
+                explain: None,
+                max_hub_degree: None,
+                min_inverse_degree_score: None,
             }))
             .expect("traverse returns ok with error content");
         assert_eq!(result.is_error, Some(true));
@@ -664,6 +1456,11 @@ mod tests {
                 contributor_ids: None,
                 relationship_prefix: None,
                 min_corroboration: None,
+                min_confidence: None,
+                explain: None,
+                pattern: None,
+                scoring: None,
+                max_paths: None,
             }))
             .expect("find_path");
 
@@ -707,6 +1504,245 @@ mod tests {
         assert!(tags.iter().any(|t| t == "beta"));
     }
 
+    #[tokio::test]
+    async fn saved_query_round_trip_through_tools() {
+        let server = server_with_context("t");
+        seed_fragment(&server, "t", "Open thread on rye", vec!["rye"]).await;
+
+        let saved = server
+            .save_query(Parameters(SaveQueryParams {
+                name: "concepts".into(),
+                query: Some(serde_json::json!({"kind": "find", "node_type": "concept"})),
+            }))
+            .expect("save_query");
+        assert_ne!(saved.is_error, Some(true));
+
+        let listed = text_of(&server.list_saved_queries().expect("list"));
+        assert!(listed.contains("\"concepts\""));
+
+        let result = server
+            .run_saved_query(Parameters(RunSavedQueryParams { name: "concepts".into() }))
+            .expect("run_saved_query");
+        let parsed: serde_json::Value = serde_json::from_str(&text_of(&result)).expect("json parse");
+        assert_eq!(parsed["kind"], "find");
+        assert_eq!(parsed["nodes"][0]["id"], "concept:rye");
+
+        let invalid = server
+            .save_query(Parameters(SaveQueryParams {
+                name: "bad".into(),
+                query: Some(serde_json::json!({"kind": "sideways"})),
+            }))
+            .expect("save_query");
+        assert_eq!(invalid.is_error, Some(true));
+        assert_eq!(invalid.structured_content.as_ref().unwrap()["code"], "invalid_input");
+
+        let deleted = server
+            .save_query(Parameters(SaveQueryParams { name: "concepts".into(), query: None }))
+            .expect("delete");
+        assert_ne!(deleted.is_error, Some(true));
+        let missing = server
+            .run_saved_query(Parameters(RunSavedQueryParams { name: "concepts".into() }))
+            .expect("run_saved_query");
+        assert_eq!(missing.is_error, Some(true));
+        let payload = missing.structured_content.as_ref().expect("structured error payload");
+        assert_eq!(payload["code"], "not_found");
+        assert_eq!(payload["retryable"], false);
+        assert_eq!(payload["message"], text_of(&missing));
+    }
+
+    // === Scenario: Batched marks and links report per item ===
+    #[tokio::test]
+    async fn batched_ingest_and_links_report_each_item() {
+        let server = server_with_context("t");
+        let marks = serde_json::json!([
+            {"text": "Entry point", "chain_name": "review", "file": "src/main.rs", "line": 1},
+            {"text": "Hot loop", "chain_name": "review", "file": "src/main.rs", "line": 40},
+            {"shape": "unrecognized"},
+        ]);
+        let result = server
+            .ingest(Parameters(IngestParams { data: marks.clone(), input_kind: None, idempotency_key: None, batch: Some(true) }))
+            .await
+            .expect("ingest");
+        let report: serde_json::Value = serde_json::from_str(&text_of(&result)).unwrap();
+        assert_eq!((report["succeeded"].as_u64(), report["failed"].as_u64()), (Some(2), Some(1)), "{report}");
+        assert_eq!(report["results"][0]["input_kind"], "content");
+        assert_eq!(report["results"][2]["error"]["code"], "invalid_input");
+
+        let unbatched = server.ingest(Parameters(IngestParams { data: marks, input_kind: None, idempotency_key: None, batch: None })).await;
+        assert!(unbatched.is_err(), "an array without batch is one input, not a batch");
+        let oversized = serde_json::Value::Array(vec![serde_json::json!({"text": "x"}); MAX_BATCH_ITEMS + 1]);
+        let refused = server
+            .ingest(Parameters(IngestParams { data: oversized, input_kind: None, idempotency_key: None, batch: Some(true) }))
+            .await
+            .expect("ingest");
+        assert_eq!(refused.structured_content.as_ref().unwrap()["code"], "invalid_input");
+
+        let ids: Vec<String> = server
+            .api
+            .list_marks("t", None, Some("src/main.rs"), None, None)
+            .unwrap()
+            .into_iter()
+            .map(|m| m.id)
+            .collect();
+        assert_eq!(ids.len(), 2);
+        let links = vec![
+            MarkLinkParams { source_id: ids[0].clone(), target_id: ids[1].clone() },
+            MarkLinkParams { source_id: ids[0].clone(), target_id: "mark:missing".into() },
+        ];
+        let result = server.link_marks(Parameters(LinkMarksParams { links })).await.expect("link_marks");
+        let report: serde_json::Value = serde_json::from_str(&text_of(&result)).unwrap();
+        assert_eq!(report["results"][0]["ok"], true);
+        assert_eq!(report["results"][1]["error"]["code"], "node_not_found");
+        assert_eq!(server.api.get_links("t", &ids[0]).unwrap().0.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn materialized_view_is_maintained_across_ingests() {
+        let server = server_with_context("t");
+        seed_fragment(&server, "t", "First", vec!["rye"]).await;
+
+        let created = server
+            .materialize_view(Parameters(MaterializeViewParams {
+                name: "concepts".into(),
+                query: Some(serde_json::json!({"kind": "find", "node_type": "concept"})),
+            }))
+            .expect("materialize_view");
+        assert_ne!(created.is_error, Some(true));
+
+        seed_fragment(&server, "t", "Second", vec!["spelt"]).await;
+
+        let result = server
+            .read_view(Parameters(ReadViewParams { name: "concepts".into(), refresh: None }))
+            .expect("read_view");
+        let parsed: serde_json::Value = serde_json::from_str(&text_of(&result)).expect("json parse");
+        assert_eq!(parsed["nodes"].as_array().unwrap().len(), 2);
+        assert_eq!(parsed["stale"], false);
+
+        let dropped = server
+            .materialize_view(Parameters(MaterializeViewParams { name: "concepts".into(), query: None }))
+            .expect("drop");
+        assert_ne!(dropped.is_error, Some(true));
+
+        let again = server
+            .materialize_view(Parameters(MaterializeViewParams { name: "concepts".into(), query: None }))
+            .expect("drop");
+        assert_eq!(again.is_error, Some(true));
+        assert_eq!(again.structured_content.as_ref().unwrap()["code"], "not_found");
+    }
+
+    #[tokio::test]
+    async fn maintain_reports_compaction() {
+        let server = server_with_context("t");
+        seed_fragment(&server, "t", "Compaction", vec!["rye"]).await;
+
+        let result = server
+            .maintain(Parameters(MaintainParams { context: Some("t".into()) }))
+            .expect("maintain");
+        let parsed: serde_json::Value = serde_json::from_str(&text_of(&result)).expect("json parse");
+        assert_eq!(parsed["dangling_edges"], 0);
+        assert!(parsed["bytes_reclaimed"].is_u64());
+
+        let missing = server
+            .maintain(Parameters(MaintainParams { context: Some("nope".into()) }))
+            .expect("maintain");
+        assert_eq!(missing.is_error, Some(true));
+    }
+
+    #[tokio::test]
+    async fn prune_defaults_to_dry_run() {
+        let server = server_with_context("t");
+        seed_fragment(&server, "t", "Pruning", vec!["rye"]).await;
+        let params = |dry_run| PruneParams {
+            policy: "weight_below".into(),
+            threshold: Some(f32::MAX),
+            derived_by: None,
+            max_age_days: None,
+            dry_run,
+        };
+
+        let preview = server.prune(Parameters(params(None))).expect("prune");
+        let parsed: serde_json::Value = serde_json::from_str(&text_of(&preview)).expect("json parse");
+        assert_eq!(parsed["dry_run"], true);
+        let would_remove = parsed["edge_ids"].as_array().unwrap().len();
+        assert!(would_remove > 0);
+
+        let pruned = server.prune(Parameters(params(Some(false)))).expect("prune");
+        let parsed: serde_json::Value = serde_json::from_str(&text_of(&pruned)).expect("json parse");
+        assert_eq!(parsed["edge_ids"].as_array().unwrap().len(), would_remove);
+        let again = server.prune(Parameters(params(None))).expect("prune");
+        assert!(text_of(&again).contains("\"edge_ids\": []"));
+
+        let invalid = server
+            .prune(Parameters(PruneParams { policy: "weight_below".into(), threshold: None, ..params(None) }))
+            .expect("prune");
+        assert_eq!(invalid.is_error, Some(true));
+    }
+
+    #[tokio::test]
+    async fn vocabulary_reports_concept_usage() {
+        let server = server_with_context("t");
+        seed_fragment(&server, "t", "Vocabulary surface test", vec!["alpha", "beta"]).await;
+        seed_fragment(&server, "t", "Second fragment", vec!["alpha"]).await;
+
+        let result = server.vocabulary().expect("vocabulary");
+        let stats: serde_json::Value =
+            serde_json::from_str(&text_of(&result)).expect("json parse");
+        let first = &stats.as_array().expect("array")[0];
+        assert_eq!(first["tag"], "alpha");
+        assert_eq!(first["usage_count"], 2);
+        assert_eq!(first["mark_count"], 2);
+        assert_eq!(first["trend"], "new");
+    }
+
+    #[tokio::test]
+    async fn distributions_report_degree_histogram() {
+        let server = server_with_context("t");
+        seed_fragment(&server, "t", "Distribution surface test", vec!["alpha", "beta"]).await;
+
+        let result = server.distributions().expect("distributions");
+        let parsed: serde_json::Value = serde_json::from_str(&text_of(&result)).expect("json parse");
+        assert!(parsed["node_count"].as_u64().unwrap() > 0);
+        assert!(parsed["degree"]["buckets"].is_array());
+        assert!(parsed["edge_weight"]["count"].as_u64().unwrap() > 0);
+        assert!(parsed["top_degree"][0]["degree"].as_u64().unwrap() > 0);
+    }
+
+    #[tokio::test]
+    async fn traverse_hub_dampening_and_super_nodes() {
+        let server = server_with_context("t");
+        seed_fragment(&server, "t", "First hub fragment", vec!["memory", "rare"]).await;
+        seed_fragment(&server, "t", "Second hub fragment", vec!["memory"]).await;
+
+        let result = server
+            .super_nodes(Parameters(SuperNodesParams { min_degree: Some(2) }))
+            .expect("super_nodes");
+        let supers: serde_json::Value = serde_json::from_str(&text_of(&result)).expect("json parse");
+        assert!(supers.as_array().unwrap().iter().any(|n| n["node_id"] == "concept:memory"));
+
+        let traverse = |max_hub_degree| {
+            server
+                .traverse(Parameters(TraverseParams {
+                    origin: "concept:rare".into(),
+                    max_depth: Some(3),
+                    direction: Some("both".into()),
+                    rank_by: None,
+                    contributor_ids: None,
+                    relationship_prefix: None,
+                    min_corroboration: None,
+                    min_confidence: None,
+                    explain: None,
+                    max_hub_degree,
+                    min_inverse_degree_score: None,
+                }))
+                .expect("traverse")
+        };
+        let count = |result: CallToolResult| {
+            let parsed: serde_json::Value = serde_json::from_str(&text_of(&result)).expect("json parse");
+            parsed["levels"].as_array().unwrap().iter().map(|l| l.as_array().unwrap().len()).sum::<usize>()
+        };
+        assert!(count(traverse(Some(1))) < count(traverse(None)));
+    }
+
     #[tokio::test]
     async fn query_tool_without_active_context_returns_error() {
         // No set_context was called — any tool touching self.context() must error.
@@ -720,6 +1756,9 @@ mod tests {
             contributor_ids: None,
             relationship_prefix: None,
             min_corroboration: None,
+            min_confidence: None,
+            near: None,
+            bbox: None,
             limit: None,
             offset: None,
         }));
@@ -846,6 +1885,7 @@ emit:
                 contributor_ids: Some(vec!["nonexistent-adapter".into()]),
                 relationship_prefix: None,
                 min_corroboration: None,
+                min_confidence: None,
             }))
             .expect("evidence_trail with filter");
 
@@ -7,10 +7,28 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct IngestParams {
-    #[schemars(description = "Input data as a JSON object. For content: {\"text\": \"...\", \"tags\": [...], \"source\": \"...\"}. For file extraction: {\"file_path\": \"...\"}. For annotations: include \"chain_name\", \"file\", \"line\".")]
+    #[schemars(description = "Input data as a JSON object. For content: {\"text\": \"...\", \"tags\": [...], \"source\": \"...\"}. For file extraction: {\"file_path\": \"...\"}. For annotations: include \"chain_name\", \"file\", \"line\". With batch set, an array of such inputs.")]
     pub data: serde_json::Value,
-    #[schemars(description = "Optional input kind for direct routing (e.g. \"content\", \"extract-file\"). When omitted, auto-detected from data shape.")]
+    #[schemars(description = "Optional input kind for direct routing (e.g. \"content\", \"extract-file\", \"conversation\", \"calendar\", \"transcript\", \"graph-import\", \"pkm\"). When omitted, auto-detected from data shape.")]
     pub input_kind: Option<String>,
+    #[schemars(description = "Optional idempotency key. Retrying a call with the same key returns the first call's result instead of ingesting again.")]
+    pub idempotency_key: Option<String>,
+    #[schemars(description = "Treat data as an array of up to 500 inputs (e.g. every mark on a file), ingesting each in turn and reporting success or error per element")]
+    pub batch: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct LinkMarksParams {
+    #[schemars(description = "Mark pairs to link with links_to edges, each {\"source_id\": ..., \"target_id\": ...}")]
+    pub links: Vec<MarkLinkParams>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MarkLinkParams {
+    #[schemars(description = "Mark the link starts from")]
+    pub source_id: String,
+    #[schemars(description = "Mark the link points to")]
+    pub target_id: String,
 }
 
 // ── Session params ─────────────────────────────────────────────────────
@@ -45,6 +63,22 @@ pub struct ContextSourceParams {
     pub paths: Vec<String>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct BootstrapParams {
+    #[schemars(description = "Directory to ingest")]
+    pub path: String,
+    #[schemars(description = "Context name (default: the directory's name); reused if it exists")]
+    pub context: Option<String>,
+    #[schemars(description = "Template for a new context: \"research-workspace\", \"codebase\", \"writing-project\", or a saved template")]
+    pub template: Option<String>,
+    #[schemars(description = "Descend into subdirectories (default true)")]
+    pub recursive: Option<bool>,
+    #[schemars(description = "File extensions to ingest, e.g. [\"md\", \"rs\"] (default: all)")]
+    pub extensions: Option<Vec<String>>,
+    #[schemars(description = "Ingest at most this many files, in path order")]
+    pub max_files: Option<usize>,
+}
+
 // ── Graph read params ──────────────────────────────────────────────────
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -57,6 +91,8 @@ pub struct EvidenceTrailParams {
     pub relationship_prefix: Option<String>,
     #[schemars(description = "Only include edges having at least this many distinct contributors (evidence diversity threshold).")]
     pub min_corroboration: Option<usize>,
+    #[schemars(description = "Only include edges whose extractor-reported confidence is at least this (0.0-1.0); edges without a confidence pass")]
+    pub min_confidence: Option<f64>,
 }
 
 // ── Query tools (ADR-036 §1) — flat parameter surface (§2) ─────────────
@@ -73,6 +109,12 @@ pub struct FindNodesParams {
     pub relationship_prefix: Option<String>,
     #[schemars(description = "Only include nodes with incident edges having at least this many distinct contributors")]
     pub min_corroboration: Option<usize>,
+    #[schemars(description = "Only include edges whose extractor-reported confidence is at least this (0.0-1.0); edges without a confidence pass")]
+    pub min_confidence: Option<f64>,
+    #[schemars(description = "Only include located nodes within a radius: [latitude, longitude, radius_km]")]
+    pub near: Option<Vec<f64>>,
+    #[schemars(description = "Only include located nodes inside a bounding box: [south, west, north, east]")]
+    pub bbox: Option<Vec<f64>>,
     #[schemars(description = "Maximum number of nodes to return")]
     pub limit: Option<usize>,
     #[schemars(description = "Number of nodes to skip (pagination offset)")]
@@ -95,6 +137,88 @@ pub struct TraverseParams {
     pub relationship_prefix: Option<String>,
     #[schemars(description = "Only traverse edges having at least this many distinct contributors")]
     pub min_corroboration: Option<usize>,
+    #[schemars(description = "Only include edges whose extractor-reported confidence is at least this (0.0-1.0); edges without a confidence pass")]
+    pub min_confidence: Option<f64>,
+    #[schemars(description = "Attach per-node explanations: the edge paths used, each edge's top contributions, and linked provenance marks")]
+    pub explain: Option<bool>,
+    #[schemars(description = "Hub dampening: reach but don't expand through nodes with more than this many edges (the origin is always expanded)")]
+    pub max_hub_degree: Option<usize>,
+    #[schemars(description = "Hub dampening: divide a path's score (1.0 at the origin) by the degree of each node it expands through, and stop reaching nodes scoring below this. Ignored when max_hub_degree is set.")]
+    pub min_inverse_degree_score: Option<f32>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SuperNodesParams {
+    #[schemars(description = "Report nodes with at least this many edges. Defaults to 1000.")]
+    pub min_degree: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct FindSimilarParams {
+    #[schemars(description = "Node to find neighbours of (give this or text)")]
+    pub node_id: Option<String>,
+    #[schemars(description = "Free text to find matching nodes for (give this or node_id)")]
+    pub text: Option<String>,
+    #[schemars(description = "Number of results (default 10)")]
+    pub k: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct AskParams {
+    #[schemars(description = "Question to answer from the active context's graph")]
+    pub question: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PackContextParams {
+    #[schemars(description = "Node IDs to pack context around")]
+    pub seeds: Vec<String>,
+    #[schemars(description = "Approximate token budget for the packed block")]
+    pub token_budget: usize,
+    #[schemars(description = "Include marks citing packed nodes (default true)")]
+    pub provenance: Option<bool>,
+    #[schemars(description = "\"text\" (default) for a prompt-ready block, or \"json\"")]
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct DossierParams {
+    #[schemars(description = "Concept ID (concept:...) or label")]
+    pub concept: String,
+    #[schemars(description = "Most related concepts to list (default 10)")]
+    pub related: Option<usize>,
+    #[schemars(description = "\"markdown\" (default) for a readable document, or \"json\"")]
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct BacklinksParams {
+    #[schemars(description = "File path, absolute or relative to the project root")]
+    pub path: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct TimelineParams {
+    #[schemars(description = "Only nodes touching one of these concepts (labels or concept: IDs)")]
+    pub concepts: Option<Vec<String>>,
+    #[schemars(description = "Only these node types (default: every type but concept)")]
+    pub node_types: Option<Vec<String>>,
+    #[schemars(description = "Bucket width: \"day\", \"week\", \"month\" or \"year\"")]
+    pub bucket: Option<String>,
+    #[schemars(description = "Inclusive lower bound, RFC 3339")]
+    pub from: Option<String>,
+    #[schemars(description = "Exclusive upper bound, RFC 3339")]
+    pub until: Option<String>,
+    #[schemars(description = "Maximum entries returned")]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct JsonLdParams {
+    #[schemars(description = "Nodes to include, with the edges between them (default: the whole context)")]
+    pub node_ids: Option<Vec<String>>,
+    #[schemars(description = "URL of a published @context to reference instead of inlining it")]
+    pub context_url: Option<String>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -113,6 +237,26 @@ pub struct FindPathParams {
     pub relationship_prefix: Option<String>,
     #[schemars(description = "Only consider edges having at least this many distinct contributors")]
     pub min_corroboration: Option<usize>,
+    #[schemars(description = "Only include edges whose extractor-reported confidence is at least this (0.0-1.0); edges without a confidence pass")]
+    pub min_confidence: Option<f64>,
+    #[schemars(description = "Attach per-node explanations: the hops that reached each path node, their top contributions, and linked provenance marks")]
+    pub explain: Option<bool>,
+    #[schemars(description = "Steps the path must take, in order. When given, max_length and direction are ignored.")]
+    pub pattern: Option<Vec<PathStepParams>>,
+    #[schemars(description = "How hop weights combine into the path score: \"product\" (default) or \"min\" (weakest link)")]
+    pub scoring: Option<String>,
+    #[schemars(description = "Return up to this many paths, best score first, instead of the single shortest path")]
+    pub max_paths: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PathStepParams {
+    #[schemars(description = "Relationship of the hop")]
+    pub relationship: String,
+    #[schemars(description = "Direction of the hop: \"outgoing\" (default), \"incoming\", or \"both\"")]
+    pub direction: Option<String>,
+    #[schemars(description = "Node type the hop must reach")]
+    pub node_type: Option<String>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -145,6 +289,58 @@ pub struct ExplainEdgeParams {
     pub relationship: Option<String>,
 }
 
+// ── Saved queries — named views stored on the context ─────────────────
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SaveQueryParams {
+    #[schemars(description = "Name of the saved query (e.g. \"open-threads\")")]
+    pub name: String,
+    #[schemars(description = "Query definition tagged by kind, e.g. {\"kind\": \"find\", \"node_type\": \"concept\"} or {\"kind\": \"traverse\", \"origin\": \"concept:x\", \"max_depth\": 2}. Fields mirror find_nodes and traverse. Omit or null to delete the saved query.")]
+    pub query: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RunSavedQueryParams {
+    #[schemars(description = "Name of the saved query to run")]
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MaterializeViewParams {
+    #[schemars(description = "Name of the materialized view (e.g. \"hubs\")")]
+    pub name: String,
+    #[schemars(description = "Query definition tagged by kind, as for save_query. The result is stored and maintained on every commit. Omit or null to drop the view.")]
+    pub query: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MaintainParams {
+    #[schemars(description = "Name of a context to limit dangling-edge cleanup to. Omit to maintain every context. Orphan cleanup and the vacuum always cover the whole database.")]
+    pub context: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PruneParams {
+    #[schemars(description = "Policy: \"weight_below\" (needs threshold), \"zero_contribution\", \"unreferenced_derived\" (derived edges whose endpoints lost their non-derived edges), or \"older_than\" (needs max_age_days)")]
+    pub policy: String,
+    #[schemars(description = "weight_below: remove edges with combined weight below this")]
+    pub threshold: Option<f32>,
+    #[schemars(description = "unreferenced_derived: contributor IDs whose edges count as derived. Defaults to the registered enrichments.")]
+    pub derived_by: Option<Vec<String>>,
+    #[schemars(description = "older_than: remove edges created more than this many days ago")]
+    pub max_age_days: Option<i64>,
+    #[schemars(description = "Report the edges that would be removed without removing them. Defaults to true.")]
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ReadViewParams {
+    #[schemars(description = "Name of the materialized view to read")]
+    pub name: String,
+    #[schemars(description = "Recompute the view first if it is stale. Defaults to false: stale views are served as stored, flagged stale.")]
+    pub refresh: Option<bool>,
+}
+
 // ── Spec loading (ADR-036 §2, ADR-037) — inline spec content ──────────
 
 #[derive(Debug, Deserialize, JsonSchema)]
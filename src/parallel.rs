@@ -0,0 +1,76 @@
+//! Data-parallel building blocks for analytics
+//!
+//! With the `parallel` feature these run on rayon's global pool; without
+//! it they are plain iterators. Either way the output is identical and
+//! independent of the thread count: maps keep input order, and sums use a
+//! fixed chunking whose partials are added in order, so floating-point
+//! results don't drift with scheduling.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Values per partial sum in `sum`. Fixed so results don't depend on how
+/// the work is split across threads.
+const SUM_CHUNK: usize = 4096;
+
+/// `f` applied to each of `0..n`, in order.
+pub(crate) fn map_range<R, F>(n: usize, f: F) -> Vec<R>
+where
+    R: Send,
+    F: Fn(usize) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    {
+        (0..n).into_par_iter().map(f).collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        (0..n).map(f).collect()
+    }
+}
+
+/// `f` applied to each item, in order.
+pub(crate) fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    {
+        items.par_iter().map(f).collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        items.iter().map(f).collect()
+    }
+}
+
+/// Deterministic sum: chunk partials (in parallel), then partials in order.
+pub(crate) fn sum(values: &[f64]) -> f64 {
+    let chunks: Vec<&[f64]> = values.chunks(SUM_CHUNK).collect();
+    map(&chunks, |chunk| chunk.iter().sum::<f64>()).into_iter().sum()
+}
+
+/// Sort `items`; equal items are indistinguishable, so stability is moot.
+pub(crate) fn sort<T: Ord + Send>(items: &mut [T]) {
+    #[cfg(feature = "parallel")]
+    items.par_sort_unstable();
+    #[cfg(not(feature = "parallel"))]
+    items.sort_unstable();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // === Scenario: Sums don't depend on how the work is split ===
+    #[test]
+    fn chunked_sum_matches_in_order_chunk_partials() {
+        let values: Vec<f64> = (0..10_000).map(|i| 1.0 / (i as f64 + 1.0)).collect();
+        let expected: f64 = values.chunks(SUM_CHUNK).map(|c| c.iter().sum::<f64>()).sum();
+        assert_eq!(sum(&values).to_bits(), expected.to_bits());
+        assert_eq!(map_range(4, |i| i * i), vec![0, 1, 4, 9]);
+        assert_eq!(sum(&[]), 0.0);
+    }
+}
@@ -0,0 +1,372 @@
+//! Question answering over the graph
+//!
+//! A question is answered in two llm-orc calls around ordinary queries.
+//! The plan ensemble receives the question and the context's schema
+//! (node types, relationships, tags) and replies with graph queries —
+//! find, traverse, or similarity. Plexus runs them, packs the subgraph
+//! around what they found into the answer ensemble's prompt, and keeps
+//! only the citations that point at nodes and edges of that evidence, so
+//! every cited item can be opened in the graph.
+//!
+//! Plan reply: `{"queries": [{"kind": "find", "node_type": "concept"},
+//! {"kind": "traverse", "origin": "concept:rye"}, {"kind": "similar",
+//! "text": "sourdough", "k": 5}]}`. Answer reply: `{"answer": "...",
+//! "nodes": ["concept:rye"], "edges": [{"source": "...", "target": "...",
+//! "relationship": "..."}]}`; a plain-text answer is accepted, citing
+//! the `[node id]`s it mentions.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::adapter::semantic::extract_json;
+use crate::api::{PlexusApi, SimilarTo};
+use crate::graph::{NodeId, PlexusError, PlexusResult};
+use crate::llm_orc::{InvokeResponse, LlmOrcClient, LlmUsage};
+use crate::query::{ContextPack, FindQuery, PackedContext, TraverseQuery};
+
+/// Ensemble translating a question into graph queries.
+pub const PLAN_ENSEMBLE: &str = "plexus-qa-plan";
+
+/// Ensemble answering from packed evidence.
+pub const ANSWER_ENSEMBLE: &str = "plexus-qa-answer";
+
+/// Adapter ID question-answering costs are recorded under.
+pub const QA_ADAPTER_ID: &str = "qa";
+
+/// A graph query proposed by the plan ensemble.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PlannedQuery {
+    Find(FindQuery),
+    Traverse(TraverseQuery),
+    /// Embedding similarity to free text
+    Similar {
+        text: String,
+        #[serde(default = "default_k")]
+        k: usize,
+    },
+}
+
+fn default_k() -> usize {
+    5
+}
+
+/// Answers questions about one context through llm-orc.
+pub struct QuestionAnswerer {
+    client: Arc<dyn LlmOrcClient>,
+    plan_ensemble: String,
+    answer_ensemble: String,
+    /// Token budget of the evidence packed for the answer
+    token_budget: usize,
+    /// Queries of a plan that are run; the rest are dropped
+    max_queries: usize,
+    /// Nodes each query contributes to the evidence seeds
+    seeds_per_query: usize,
+}
+
+impl QuestionAnswerer {
+    pub fn new(client: Arc<dyn LlmOrcClient>) -> Self {
+        Self {
+            client,
+            plan_ensemble: PLAN_ENSEMBLE.to_string(),
+            answer_ensemble: ANSWER_ENSEMBLE.to_string(),
+            token_budget: 2_000,
+            max_queries: 5,
+            seeds_per_query: 10,
+        }
+    }
+
+    pub fn with_ensembles(mut self, plan: impl Into<String>, answer: impl Into<String>) -> Self {
+        self.plan_ensemble = plan.into();
+        self.answer_ensemble = answer.into();
+        self
+    }
+
+    pub fn with_token_budget(mut self, token_budget: usize) -> Self {
+        self.token_budget = token_budget;
+        self
+    }
+
+    pub fn with_max_queries(mut self, max_queries: usize) -> Self {
+        self.max_queries = max_queries;
+        self
+    }
+
+    /// Answer `question` from the graph of `context`.
+    pub async fn ask(&self, api: &PlexusApi, context: &str, question: &str) -> PlexusResult<Answer> {
+        if !self.client.is_available().await {
+            return Err(PlexusError::Unavailable("llm-orc not available".into()));
+        }
+        let mut usage = LlmUsage::default();
+
+        let plan_input = serde_json::json!({ "question": question, "schema": schema(api, context)? });
+        let reply = self.invoke(api, context, question, &self.plan_ensemble, &plan_input.to_string(), &mut usage).await?;
+        let mut queries = parse_plan(&reply);
+        queries.truncate(self.max_queries);
+
+        let mut seeds: Vec<NodeId> = Vec::new();
+        let mut skipped = Vec::new();
+        for query in &queries {
+            match self.run(api, context, query) {
+                Ok(found) => {
+                    for id in found.into_iter().take(self.seeds_per_query) {
+                        if !seeds.contains(&id) {
+                            seeds.push(id);
+                        }
+                    }
+                }
+                Err(e) => skipped.push(e.to_string()),
+            }
+        }
+
+        let mut answer = Answer {
+            question: question.to_string(),
+            answer: None,
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            unverified: Vec::new(),
+            queries,
+            skipped,
+            evidence: PackedContext::default(),
+            usage,
+        };
+        if seeds.is_empty() {
+            return Ok(answer);
+        }
+
+        answer.evidence = api.pack_context(context, ContextPack::new(seeds, self.token_budget))?;
+        let answer_input = serde_json::json!({ "question": question, "evidence": answer.evidence.to_text() });
+        let reply = self
+            .invoke(api, context, question, &self.answer_ensemble, &answer_input.to_string(), &mut answer.usage)
+            .await?;
+        answer.cite(&reply);
+        Ok(answer)
+    }
+
+    /// Invoke `ensemble`, adding its usage to `usage` and recording it
+    /// as a cost of `context`, keyed by the question.
+    async fn invoke(
+        &self,
+        api: &PlexusApi,
+        context: &str,
+        question: &str,
+        ensemble: &str,
+        input: &str,
+        usage: &mut LlmUsage,
+    ) -> PlexusResult<String> {
+        let response = self
+            .client
+            .invoke(ensemble, input)
+            .await
+            .map_err(|e| PlexusError::Other(format!("{ensemble}: {e}")))?;
+        *usage = *usage + response.usage();
+        if let Err(e) = api.record_llm_cost(context, QA_ADAPTER_ID, question, ensemble, response.usage()) {
+            tracing::warn!(ensemble, error = %e, "question answering: could not record llm cost");
+        }
+        if response.is_failed() {
+            return Err(PlexusError::Other(format!("{ensemble}: ensemble execution failed")));
+        }
+        first_response(&response).ok_or_else(|| PlexusError::Other(format!("{ensemble}: no agent responded")))
+    }
+
+    /// Node IDs a planned query finds, most relevant first.
+    fn run(&self, api: &PlexusApi, context: &str, query: &PlannedQuery) -> PlexusResult<Vec<NodeId>> {
+        Ok(match query {
+            PlannedQuery::Find(find) => api.find_nodes(context, find.clone())?.nodes.into_iter().map(|n| n.id).collect(),
+            PlannedQuery::Traverse(traverse) => {
+                api.traverse(context, traverse.clone())?.levels.into_iter().flatten().map(|n| n.id).collect()
+            }
+            PlannedQuery::Similar { text, k } => api
+                .find_similar(context, SimilarTo::Text(text.clone()), *k)?
+                .into_iter()
+                .map(|hit| hit.id)
+                .collect(),
+        })
+    }
+}
+
+/// The context's node types, relationships, and tags, for the planner.
+fn schema(api: &PlexusApi, context: &str) -> PlexusResult<serde_json::Value> {
+    let reader = api.reader(context)?;
+    let snapshot = reader.context();
+    let mut node_types: BTreeMap<&str, usize> = BTreeMap::new();
+    for node in snapshot.nodes() {
+        *node_types.entry(node.node_type.as_str()).or_default() += 1;
+    }
+    let relationships: BTreeSet<&str> = snapshot.edges().map(|e| e.relationship.as_str()).collect();
+    let tags: Vec<String> = api.list_tags(context)?;
+    Ok(serde_json::json!({ "node_types": node_types, "relationships": relationships, "tags": tags }))
+}
+
+/// Response text of the first agent that answered, by agent name.
+fn first_response(response: &InvokeResponse) -> Option<String> {
+    let mut agents: Vec<_> = response.results.iter().collect();
+    agents.sort_by(|a, b| a.0.cmp(b.0));
+    agents.into_iter().find_map(|(_, result)| result.response.clone().filter(|r| !r.trim().is_empty()))
+}
+
+/// Queries of a plan reply; entries that don't parse are dropped.
+fn parse_plan(reply: &str) -> Vec<PlannedQuery> {
+    let Some(plan) = extract_json(reply) else { return Vec::new() };
+    plan.get("queries")
+        .and_then(|q| q.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|q| serde_json::from_value(q.clone()).ok())
+        .collect()
+}
+
+/// An edge cited by an answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EdgeCitation {
+    pub source: NodeId,
+    pub target: NodeId,
+    pub relationship: String,
+}
+
+/// An answer and what it rests on.
+#[derive(Debug, Clone, Serialize)]
+pub struct Answer {
+    pub question: String,
+    /// `None` when the queries found nothing to answer from
+    pub answer: Option<String>,
+    /// Cited nodes, all in `evidence`
+    pub nodes: Vec<NodeId>,
+    /// Cited edges, all in `evidence`
+    pub edges: Vec<EdgeCitation>,
+    /// Citations the answer made that aren't in the evidence
+    pub unverified: Vec<String>,
+    /// The plan, as run
+    pub queries: Vec<PlannedQuery>,
+    /// Errors of planned queries that could not run
+    pub skipped: Vec<String>,
+    /// What the answer ensemble was shown
+    pub evidence: PackedContext,
+    /// Tokens and cost of both calls
+    pub usage: LlmUsage,
+}
+
+impl Answer {
+    /// Take the answer and its verified citations from an answer reply.
+    fn cite(&mut self, reply: &str) {
+        let (text, nodes, edges) = match extract_json(reply) {
+            Some(parsed) if parsed.get("answer").is_some_and(|a| a.is_string()) => {
+                let nodes: Vec<String> = parsed
+                    .get("nodes")
+                    .and_then(|n| serde_json::from_value(n.clone()).ok())
+                    .unwrap_or_default();
+                let edges: Vec<EdgeCitation> = parsed
+                    .get("edges")
+                    .and_then(|e| serde_json::from_value(e.clone()).ok())
+                    .unwrap_or_default();
+                (parsed["answer"].as_str().unwrap_or_default().to_string(), nodes, edges)
+            }
+            _ => (reply.trim().to_string(), bracketed(reply), Vec::new()),
+        };
+        for id in nodes {
+            let id = NodeId::from_string(id);
+            if self.evidence.nodes.iter().any(|n| n.id == id) {
+                if !self.nodes.contains(&id) {
+                    self.nodes.push(id);
+                }
+            } else {
+                self.unverified.push(id.to_string());
+            }
+        }
+        for edge in edges {
+            let packed = self.evidence.edges.iter().any(|e| {
+                e.source == edge.source && e.target == edge.target && e.relationship == edge.relationship
+            });
+            if packed {
+                self.edges.push(edge);
+            } else {
+                self.unverified.push(format!("{} -{}-> {}", edge.source, edge.relationship, edge.target));
+            }
+        }
+        self.answer = Some(text);
+    }
+}
+
+/// `[...]` spans of plain text.
+fn bracketed(text: &str) -> Vec<String> {
+    text.split('[').skip(1).filter_map(|s| s.split_once(']')).map(|(id, _)| id.trim().to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::IngestPipeline;
+    use crate::graph::{dimension, ContentType, Context, Edge, Node, PlexusEngine, PropertyValue};
+    use crate::llm_orc::{mock_response, MockClient};
+
+    fn api() -> PlexusApi {
+        api_and_engine().0
+    }
+
+    fn api_and_engine() -> (PlexusApi, Arc<PlexusEngine>) {
+        let engine = Arc::new(PlexusEngine::new());
+        let mut ctx = Context::new("bread");
+        for (id, label) in [("concept:rye", "rye"), ("concept:starter", "starter"), ("concept:oven", "oven")] {
+            let mut node = Node::new_in_dimension("concept", ContentType::Concept, dimension::SEMANTIC);
+            node.id = NodeId::from_string(id);
+            node.properties.insert("label".into(), PropertyValue::from(label));
+            ctx.add_node(node);
+        }
+        ctx.add_edge(Edge::new(NodeId::from_string("concept:rye"), NodeId::from_string("concept:starter"), "may_be_related"));
+        engine.upsert_context(ctx).unwrap();
+        let pipeline = Arc::new(IngestPipeline::new(engine.clone()));
+        (PlexusApi::new(engine.clone(), pipeline), engine)
+    }
+
+    fn answerer(plan: &str, answer: &str) -> QuestionAnswerer {
+        let client = MockClient::available()
+            .with_response(PLAN_ENSEMBLE, mock_response(vec![("planner", plan)]))
+            .with_response(ANSWER_ENSEMBLE, mock_response(vec![("answerer", answer)]));
+        QuestionAnswerer::new(Arc::new(client))
+    }
+
+    // === Scenario: A planned traversal grounds a cited answer ===
+    #[tokio::test]
+    async fn answers_cite_only_packed_evidence() {
+        let plan = r#"```json
+{"queries": [{"kind": "traverse", "origin": "concept:rye"}, {"kind": "similar", "text": "rye"}, {"kind": "sql"}]}
+```"#;
+        let reply = r#"{"answer": "Rye pairs with a starter.", "nodes": ["concept:rye", "concept:oven"],
+            "edges": [{"source": "concept:rye", "target": "concept:starter", "relationship": "may_be_related"}]}"#;
+        let (api, engine) = api_and_engine();
+        let answer = answerer(plan, reply).ask(&api, "bread", "What goes with rye?").await.unwrap();
+
+        assert_eq!(answer.queries.len(), 2, "the unknown query kind is dropped");
+        assert_eq!(answer.skipped.len(), 1, "no embedder for the similarity query");
+        assert_eq!(answer.answer.as_deref(), Some("Rye pairs with a starter."));
+        assert_eq!(answer.nodes, vec![NodeId::from_string("concept:rye")]);
+        assert_eq!(answer.edges.len(), 1);
+        assert_eq!(answer.unverified, vec!["concept:oven".to_string()], "oven wasn't in the evidence");
+
+        let ctx_id = api.reader("bread").unwrap().context().id.clone();
+        let costs = engine.llm_costs(&ctx_id, chrono::DateTime::UNIX_EPOCH).unwrap();
+        let ensembles: Vec<&str> = costs.records.iter().map(|r| r.ensemble.as_str()).collect();
+        assert_eq!(ensembles, vec![PLAN_ENSEMBLE, ANSWER_ENSEMBLE], "both calls are recorded");
+        assert!(costs.records.iter().all(|r| r.adapter_id == QA_ADAPTER_ID && r.item == "What goes with rye?"));
+    }
+
+    // === Scenario: Plain-text answers and empty plans ===
+    #[tokio::test]
+    async fn plain_text_answers_cite_bracketed_ids() {
+        let plan = r#"{"queries": [{"kind": "find", "node_type": "concept", "limit": 3}]}"#;
+        let answer = answerer(plan, "Use [concept:starter] and [concept:flour].")
+            .ask(&api(), "bread", "What do I need?")
+            .await
+            .unwrap();
+        assert_eq!(answer.nodes, vec![NodeId::from_string("concept:starter")]);
+        assert_eq!(answer.unverified, vec!["concept:flour".to_string()]);
+
+        let nothing = answerer(r#"{"queries": []}"#, "unused").ask(&api(), "bread", "?").await.unwrap();
+        assert!(nothing.answer.is_none() && nothing.evidence.nodes.is_empty());
+
+        let offline = QuestionAnswerer::new(Arc::new(MockClient::unavailable()));
+        assert!(offline.ask(&api(), "bread", "?").await.is_err());
+    }
+}
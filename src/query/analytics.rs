@@ -0,0 +1,302 @@
+//! Whole-graph analytics: PageRank and community detection
+//!
+//! Both run over a dense index of the context (nodes ordered by ID) and
+//! compute each node's next value from the previous iteration only, so
+//! per-node work parallelizes under the `parallel` feature with results
+//! identical to a sequential run. Run them on a `ContextReader` to keep
+//! long passes off the live context.
+
+use crate::graph::{Context, NodeId};
+use crate::parallel;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// PageRank parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PageRankConfig {
+    /// Probability of following an edge rather than jumping
+    pub damping: f64,
+    pub max_iterations: usize,
+    /// Stop once the total (L1) rank change in an iteration falls below this
+    pub tolerance: f64,
+}
+
+impl Default for PageRankConfig {
+    fn default() -> Self {
+        Self { damping: 0.85, max_iterations: 100, tolerance: 1e-6 }
+    }
+}
+
+/// Default iteration cap for `communities`.
+pub const DEFAULT_COMMUNITY_ITERATIONS: usize = 20;
+
+/// Nodes in ID order plus weighted adjacency over their dense indices.
+/// Edges with non-positive weight or a missing endpoint are skipped.
+struct DenseGraph<'a> {
+    ids: Vec<&'a NodeId>,
+    /// (source, target, weight) per usable edge, in context edge order
+    edges: Vec<(u32, u32, f64)>,
+}
+
+impl<'a> DenseGraph<'a> {
+    fn new(ctx: &'a Context) -> Self {
+        let mut ids: Vec<&NodeId> = ctx.nodes.keys().collect();
+        ids.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        let index: HashMap<&NodeId, u32> = ids.iter().enumerate().map(|(i, id)| (*id, i as u32)).collect();
+        let edges = ctx
+            .edges
+            .iter()
+            .filter(|e| e.combined_weight > 0.0)
+            .filter_map(|e| Some((*index.get(&e.source)?, *index.get(&e.target)?, e.combined_weight as f64)))
+            .collect();
+        Self { ids, edges }
+    }
+
+    fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    fn into_map<T>(self, values: Vec<T>) -> HashMap<NodeId, T> {
+        self.ids.into_iter().cloned().zip(values).collect()
+    }
+}
+
+/// Weighted PageRank over directed edges. Scores sum to 1; rank held by
+/// nodes without outgoing edges is spread evenly over all nodes.
+pub fn pagerank(ctx: &Context, config: &PageRankConfig) -> HashMap<NodeId, f64> {
+    let graph = DenseGraph::new(ctx);
+    let n = graph.len();
+    if n == 0 {
+        return HashMap::new();
+    }
+
+    let mut out_weight = vec![0.0f64; n];
+    for &(source, _, weight) in &graph.edges {
+        out_weight[source as usize] += weight;
+    }
+    // Pull form: each node sums its in-edges, weighted by the share of
+    // the source's outgoing weight they carry
+    let mut incoming: Vec<Vec<(u32, f64)>> = vec![Vec::new(); n];
+    for &(source, target, weight) in &graph.edges {
+        incoming[target as usize].push((source, weight / out_weight[source as usize]));
+    }
+    let dangling: Vec<usize> = (0..n).filter(|&i| out_weight[i] == 0.0).collect();
+
+    let d = config.damping;
+    let mut rank = vec![1.0 / n as f64; n];
+    for _ in 0..config.max_iterations {
+        let dangling_rank = parallel::sum(&dangling.iter().map(|&i| rank[i]).collect::<Vec<_>>());
+        let base = (1.0 - d) / n as f64 + d * dangling_rank / n as f64;
+        let next = parallel::map(&incoming, |edges| {
+            base + d * edges.iter().map(|&(source, share)| rank[source as usize] * share).sum::<f64>()
+        });
+        let change = parallel::sum(&parallel::map_range(n, |i| (next[i] - rank[i]).abs()));
+        rank = next;
+        if change < config.tolerance {
+            break;
+        }
+    }
+    graph.into_map(rank)
+}
+
+/// PageRank personalized to `seeds`, edges taken as undirected: every
+/// jump returns to a seed, so a node's score measures its proximity to
+/// them. Rank held by isolated nodes returns to the seeds too. Seeds not
+/// in the context are ignored; with none left, the result is empty.
+pub fn personalized_pagerank(ctx: &Context, seeds: &[NodeId], config: &PageRankConfig) -> HashMap<NodeId, f64> {
+    let graph = DenseGraph::new(ctx);
+    let n = graph.len();
+    let mut teleport = vec![0.0f64; n];
+    let seeded: Vec<usize> = graph
+        .ids
+        .iter()
+        .enumerate()
+        .filter(|(_, id)| seeds.contains(id))
+        .map(|(i, _)| i)
+        .collect();
+    if seeded.is_empty() {
+        return HashMap::new();
+    }
+    for &i in &seeded {
+        teleport[i] = 1.0 / seeded.len() as f64;
+    }
+
+    let mut degree = vec![0.0f64; n];
+    for &(source, target, weight) in &graph.edges {
+        if source != target {
+            degree[source as usize] += weight;
+            degree[target as usize] += weight;
+        }
+    }
+    let mut incoming: Vec<Vec<(u32, f64)>> = vec![Vec::new(); n];
+    for &(source, target, weight) in &graph.edges {
+        if source != target {
+            incoming[target as usize].push((source, weight / degree[source as usize]));
+            incoming[source as usize].push((target, weight / degree[target as usize]));
+        }
+    }
+    let isolated: Vec<usize> = (0..n).filter(|&i| degree[i] == 0.0).collect();
+
+    let d = config.damping;
+    let mut rank = teleport.clone();
+    for _ in 0..config.max_iterations {
+        let isolated_rank = parallel::sum(&isolated.iter().map(|&i| rank[i]).collect::<Vec<_>>());
+        let next = parallel::map_range(n, |i| {
+            let jump = (1.0 - d + d * isolated_rank) * teleport[i];
+            jump + d * incoming[i].iter().map(|&(j, share)| rank[j as usize] * share).sum::<f64>()
+        });
+        let change = parallel::sum(&parallel::map_range(n, |i| (next[i] - rank[i]).abs()));
+        rank = next;
+        if change < config.tolerance {
+            break;
+        }
+    }
+    graph.into_map(rank)
+}
+
+/// Communities by weighted label propagation, edges taken as undirected.
+///
+/// Every node starts in its own community and repeatedly joins the one
+/// carrying the most edge weight among its neighbors, counting its own
+/// community as one more neighbor of average weight; ties go to the
+/// lowest-numbered community. Updates are synchronous, so the result is
+/// deterministic. Communities are numbered from 0 in order of their
+/// first member by node ID.
+pub fn communities(ctx: &Context, max_iterations: usize) -> HashMap<NodeId, usize> {
+    let graph = DenseGraph::new(ctx);
+    let n = graph.len();
+
+    let mut neighbors: Vec<Vec<(u32, f64)>> = vec![Vec::new(); n];
+    for &(source, target, weight) in &graph.edges {
+        if source != target {
+            neighbors[source as usize].push((target, weight));
+            neighbors[target as usize].push((source, weight));
+        }
+    }
+
+    let mut labels: Vec<u32> = (0..n as u32).collect();
+    for _ in 0..max_iterations {
+        let next = parallel::map_range(n, |i| {
+            let adjacent = &neighbors[i];
+            if adjacent.is_empty() {
+                return labels[i];
+            }
+            let mut votes: Vec<(u32, f64)> = adjacent.iter().map(|&(j, w)| (labels[j as usize], w)).collect();
+            let mean = votes.iter().map(|(_, w)| w).sum::<f64>() / votes.len() as f64;
+            votes.push((labels[i], mean));
+            votes.sort_by_key(|(label, _)| *label);
+
+            let mut best = (labels[i], f64::NEG_INFINITY);
+            let mut k = 0;
+            while k < votes.len() {
+                let label = votes[k].0;
+                let mut total = 0.0;
+                while k < votes.len() && votes[k].0 == label {
+                    total += votes[k].1;
+                    k += 1;
+                }
+                // Ascending labels: a strict improvement keeps ties on the lowest
+                if total > best.1 {
+                    best = (label, total);
+                }
+            }
+            best.0
+        });
+        let changed = next != labels;
+        labels = next;
+        if !changed {
+            break;
+        }
+    }
+
+    let mut numbering: HashMap<u32, usize> = HashMap::new();
+    let communities = labels
+        .iter()
+        .map(|label| {
+            let next = numbering.len();
+            *numbering.entry(*label).or_insert(next)
+        })
+        .collect();
+    graph.into_map(communities)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{ContentType, Edge, Node};
+
+    fn graph(node_count: usize, edges: &[(usize, usize, f32)]) -> (Context, Vec<NodeId>) {
+        let mut ctx = Context::new("analytics");
+        let ids: Vec<NodeId> = (0..node_count)
+            .map(|i| {
+                let mut node = Node::new("concept", ContentType::Concept);
+                node.id = NodeId::from_string(format!("n{i:02}"));
+                ctx.add_node(node)
+            })
+            .collect();
+        for &(a, b, w) in edges {
+            let mut edge = Edge::new(ids[a].clone(), ids[b].clone(), "related_to");
+            edge.combined_weight = w;
+            ctx.edges.push(edge);
+        }
+        (ctx, ids)
+    }
+
+    // === Scenario: PageRank favors nodes with more incoming weight ===
+    #[test]
+    fn pagerank_ranks_inbound_hubs_highest() {
+        // 1, 2, 3 all point at 0; 0 points back at 1
+        let (ctx, ids) = graph(4, &[(1, 0, 1.0), (2, 0, 1.0), (3, 0, 1.0), (0, 1, 1.0)]);
+        let ranks = pagerank(&ctx, &PageRankConfig::default());
+
+        let total: f64 = ranks.values().sum();
+        assert!((total - 1.0).abs() < 1e-6, "{total}");
+        assert!(ranks[&ids[0]] > ranks[&ids[1]]);
+        assert!(ranks[&ids[1]] > ranks[&ids[2]]);
+        assert!((ranks[&ids[2]] - ranks[&ids[3]]).abs() < 1e-12);
+        assert!(pagerank(&Context::new("empty"), &PageRankConfig::default()).is_empty());
+    }
+
+    // === Scenario: Personalized PageRank decays with distance from the seeds ===
+    #[test]
+    fn personalized_pagerank_measures_seed_proximity() {
+        // A chain 0 — 1 — 2 — 3, plus an unconnected 4
+        let (ctx, ids) = graph(5, &[(0, 1, 1.0), (2, 1, 1.0), (2, 3, 1.0)]);
+        let ranks = personalized_pagerank(&ctx, &[ids[0].clone()], &PageRankConfig::default());
+
+        let total: f64 = ranks.values().sum();
+        assert!((total - 1.0).abs() < 1e-6, "{total}");
+        assert!(ranks[&ids[1]] > ranks[&ids[2]], "edges count both ways");
+        assert!(ranks[&ids[2]] > ranks[&ids[3]]);
+        assert_eq!(ranks[&ids[4]], 0.0);
+        assert!(personalized_pagerank(&ctx, &[NodeId::from_string("missing")], &PageRankConfig::default()).is_empty());
+    }
+
+    // === Scenario: Label propagation separates weakly bridged clusters ===
+    #[test]
+    fn communities_split_bridged_triangles() {
+        let (ctx, ids) = graph(
+            7,
+            &[(0, 1, 1.0), (1, 2, 1.0), (2, 0, 1.0), (3, 4, 1.0), (4, 5, 1.0), (5, 3, 1.0), (2, 3, 0.1)],
+        );
+        let found = communities(&ctx, DEFAULT_COMMUNITY_ITERATIONS);
+
+        assert_eq!(found[&ids[0]], 0);
+        assert_eq!(found[&ids[1]], 0);
+        assert_eq!(found[&ids[2]], 0);
+        assert_eq!(found[&ids[3]], 1);
+        assert_eq!(found[&ids[4]], 1);
+        assert_eq!(found[&ids[5]], 1);
+        assert_eq!(found[&ids[6]], 2, "an isolated node is its own community");
+    }
+
+    // === Scenario: Repeated runs agree exactly ===
+    #[test]
+    fn analytics_are_deterministic() {
+        let edges: Vec<(usize, usize, f32)> = (0..40).map(|i| (i % 13, (i * 7 + 3) % 13, 0.5 + (i % 3) as f32)).collect();
+        let (a, _) = graph(13, &edges);
+        let (b, _) = graph(13, &edges);
+        assert_eq!(pagerank(&a, &PageRankConfig::default()), pagerank(&b, &PageRankConfig::default()));
+        assert_eq!(communities(&a, 10), communities(&b, 10));
+    }
+}
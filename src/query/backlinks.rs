@@ -0,0 +1,237 @@
+//! File backlinks — what the graph knows about one file
+//!
+//! Adapters record file locations under different keys: marks carry
+//! `file` (or `file_path` from semantic extraction), chunks and
+//! extraction-status nodes `file_path`, file nodes `path` and a
+//! `file:{path}` ID, fragments a `source`. Backlinks gathers every node
+//! located in the file, plus the structure nodes linking to its file node
+//! and the fragments its marks were ingested with or attached to, then
+//! the concepts all of them connect to — the answer an editor plugin
+//! shows beside an open file.
+
+use super::explain::ingest_mark_fragment;
+use crate::graph::{Context, Node, NodeId, PropertyValue, Source, PATH_PROPERTIES};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+
+/// A concept reached from the file's nodes.
+#[derive(Debug, Clone, Serialize)]
+pub struct BacklinkConcept {
+    pub id: NodeId,
+    pub label: String,
+    /// The file's nodes connected to it
+    pub via: Vec<NodeId>,
+}
+
+/// Everything referencing one file, grouped by kind.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Backlinks {
+    pub path: String,
+    pub marks: Vec<Node>,
+    pub fragments: Vec<Node>,
+    /// The file node, its chunks and status, and files linking to it
+    pub structure: Vec<Node>,
+    /// Most connected first
+    pub concepts: Vec<BacklinkConcept>,
+}
+
+impl Backlinks {
+    /// Collect the backlinks of `path` in `context`. Relative paths —
+    /// the query's or the ones recorded — are resolved against the
+    /// context's file and directory sources, so an editor's absolute path
+    /// finds marks recorded relative to a project root; paths then match
+    /// only when they name the same file.
+    pub fn collect(context: &Context, path: &str) -> Self {
+        let roots = source_roots(context);
+        let wanted = anchored(path, &roots);
+        let file_id = NodeId::from_string(format!("file:{}", path));
+        let mut referencing: HashSet<NodeId> = context
+            .nodes()
+            .filter(|n| n.node_type != "concept")
+            .filter(|n| n.id == file_id || located_in(n, &wanted, &roots))
+            .map(|n| n.id.clone())
+            .collect();
+
+        // Files linking to this one, and fragments attached to its marks
+        let located = referencing.clone();
+        for mark in located.iter().filter(|id| id.as_str().starts_with("mark:")) {
+            let fragment = ingest_mark_fragment(mark).map(NodeId::from_string);
+            if fragment.as_ref().and_then(|f| context.get_node(f)).is_some_and(|n| n.node_type == "fragment") {
+                referencing.extend(fragment);
+            }
+        }
+        for edge in context.edges() {
+            let (here, other) = if located.contains(&edge.target) {
+                (&edge.target, &edge.source)
+            } else if located.contains(&edge.source) {
+                (&edge.source, &edge.target)
+            } else {
+                continue;
+            };
+            let (Some(here), Some(node)) = (context.get_node(here), context.get_node(other)) else { continue };
+            let linking_file = node.node_type == "file" && here.node_type == "file" && edge.target == here.id;
+            let attached_fragment = node.node_type == "fragment" && here.node_type == "mark";
+            if linking_file || attached_fragment {
+                referencing.insert(other.clone());
+            }
+        }
+
+        let mut nodes: Vec<&Node> = referencing.iter().filter_map(|id| context.get_node(id)).collect();
+        nodes.sort_by(|a, b| a.id.as_str().cmp(b.id.as_str()));
+        let mut backlinks = Self { path: path.to_string(), ..Default::default() };
+        for node in nodes {
+            match node.node_type.as_str() {
+                "mark" => backlinks.marks.push(node.clone()),
+                "fragment" => backlinks.fragments.push(node.clone()),
+                _ => backlinks.structure.push(node.clone()),
+            }
+        }
+        backlinks.concepts = concepts_of(context, &referencing);
+        backlinks
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.marks.is_empty() && self.fragments.is_empty() && self.structure.is_empty()
+    }
+}
+
+/// Directories relative paths in `context` are taken to be under.
+fn source_roots(context: &Context) -> Vec<PathBuf> {
+    context
+        .metadata
+        .sources
+        .iter()
+        .filter_map(|source| match source {
+            Source::Directory { path, .. } => Some(PathBuf::from(path)),
+            Source::File { path } => Path::new(path.as_str()).parent().map(Path::to_path_buf),
+            _ => None,
+        })
+        .collect()
+}
+
+fn located_in(node: &Node, wanted: &HashSet<String>, roots: &[PathBuf]) -> bool {
+    let file_path = match node.node_type.as_str() {
+        "file" => node.id.as_str().strip_prefix("file:"),
+        _ => None,
+    };
+    let recorded = PATH_PROPERTIES.iter().filter_map(|key| match node.properties.get(*key) {
+        Some(PropertyValue::String(value)) => Some(value.as_str()),
+        _ => None,
+    });
+    file_path.into_iter().chain(recorded).any(|path| !anchored(path, roots).is_disjoint(wanted))
+}
+
+/// The paths `path` may name: itself, normalized, and — when relative —
+/// the same path under each source root.
+fn anchored(path: &str, roots: &[PathBuf]) -> HashSet<String> {
+    let path = Path::new(path);
+    if path.as_os_str().is_empty() {
+        return HashSet::new();
+    }
+    let mut paths = HashSet::from([normalize(path)]);
+    if path.is_relative() {
+        paths.extend(roots.iter().map(|root| normalize(&root.join(path))));
+    }
+    paths
+}
+
+/// Resolve `.` and `..` lexically.
+fn normalize(path: &Path) -> String {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push("..");
+                }
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized.to_string_lossy().into_owned()
+}
+
+/// Concepts joined by an edge, either way, to any of `nodes`.
+fn concepts_of(context: &Context, nodes: &HashSet<NodeId>) -> Vec<BacklinkConcept> {
+    let mut via: HashMap<&NodeId, Vec<NodeId>> = HashMap::new();
+    for edge in context.edges() {
+        let (from, concept) = if nodes.contains(&edge.source) {
+            (&edge.source, &edge.target)
+        } else if nodes.contains(&edge.target) {
+            (&edge.target, &edge.source)
+        } else {
+            continue;
+        };
+        if context.get_node(concept).is_some_and(|n| n.node_type == "concept") {
+            let from_nodes = via.entry(concept).or_default();
+            if !from_nodes.contains(from) {
+                from_nodes.push(from.clone());
+            }
+        }
+    }
+    let mut concepts: Vec<BacklinkConcept> = via
+        .into_iter()
+        .filter_map(|(id, mut via)| {
+            let label = match context.get_node(id)?.properties.get("label") {
+                Some(PropertyValue::String(label)) => label.clone(),
+                _ => id.as_str().trim_start_matches("concept:").to_string(),
+            };
+            via.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+            Some(BacklinkConcept { id: id.clone(), label, via })
+        })
+        .collect();
+    concepts.sort_by(|a, b| b.via.len().cmp(&a.via.len()).then_with(|| a.id.as_str().cmp(b.id.as_str())));
+    concepts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{dimension, ContentType, Edge};
+
+    fn node(ctx: &mut Context, node_type: &str, id: &str, properties: &[(&str, &str)]) -> NodeId {
+        let mut node = Node::new_in_dimension(node_type, ContentType::Document, dimension::STRUCTURE);
+        node.id = NodeId::from_string(id);
+        for (key, value) in properties {
+            node.properties.insert(key.to_string(), PropertyValue::from(*value));
+        }
+        ctx.add_node(node)
+    }
+
+    // === Scenario: Backlinks gather marks, fragments, structure and their concepts ===
+    #[test]
+    fn backlinks_find_everything_referencing_a_file() {
+        let mut ctx = Context::new("backlinks");
+        let rye = node(&mut ctx, "concept", "concept:rye", &[("label", "rye")]);
+        let oven = node(&mut ctx, "concept", "concept:oven", &[("label", "oven")]);
+        let file = node(&mut ctx, "file", "file:notes/bread.md", &[("path", "notes/bread.md")]);
+        let linking = node(&mut ctx, "file", "file:notes/index.md", &[("path", "notes/index.md")]);
+        let mark = node(&mut ctx, "mark", "mark:1", &[("file", "notes/bread.md")]);
+        let fragment = node(&mut ctx, "fragment", "fragment:1", &[("text", "Rye sours fast.")]);
+        let elsewhere = node(&mut ctx, "mark", "mark:2", &[("file", "notes/cake.md")]);
+        ctx.add_edge(Edge::new(linking.clone(), file.clone(), "links_to"));
+        ctx.add_edge(Edge::new(mark.clone(), fragment.clone(), "annotates"));
+        ctx.add_edge(Edge::new(mark.clone(), rye.clone(), "references"));
+        ctx.add_edge(Edge::new(fragment.clone(), rye.clone(), "tagged_with"));
+        ctx.add_edge(Edge::new(file.clone(), oven.clone(), "tagged_with"));
+        ctx.add_edge(Edge::new(elsewhere, oven.clone(), "references"));
+
+        assert!(Backlinks::collect(&ctx, "/home/me/notes/bread.md").is_empty(), "without a source, a relative path isn't anchored anywhere");
+        ctx.metadata.sources.push(Source::Directory { path: "/home/me".into(), recursive: true });
+        let backlinks = Backlinks::collect(&ctx, "/home/me/notes/bread.md");
+        let ids = |nodes: &[Node]| nodes.iter().map(|n| n.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&backlinks.marks), vec![mark], "the other file's mark isn't a backlink");
+        assert_eq!(ids(&backlinks.fragments), vec![fragment]);
+        assert_eq!(ids(&backlinks.structure), vec![file.clone(), linking]);
+        let concepts: Vec<(&str, usize)> = backlinks.concepts.iter().map(|c| (c.label.as_str(), c.via.len())).collect();
+        assert_eq!(concepts, vec![("rye", 2), ("oven", 1)]);
+        assert_eq!(backlinks.concepts[1].via, vec![file]);
+
+        assert_eq!(Backlinks::collect(&ctx, "./notes/bread.md").marks.len(), 1, "relative queries match too");
+        assert!(Backlinks::collect(&ctx, "bread.md/other").is_empty());
+        assert!(Backlinks::collect(&ctx, "bread.md").is_empty(), "a bare suffix isn't the same file");
+        assert!(Backlinks::collect(&ctx, "/elsewhere/notes/bread.md").is_empty(), "another project's file isn't this one");
+    }
+}
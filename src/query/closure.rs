@@ -0,0 +1,218 @@
+//! Transitive closure of hierarchy relationships
+//!
+//! "All marks under this chain" or "everything inside this directory"
+//! otherwise takes a recursive traversal on every request. A closure
+//! keeps, for one relationship (`contains`, `part_of`), each node's
+//! descendants and ancestors with their depth: the length of the
+//! shortest path of that relationship between them. Like materialized
+//! views it is maintained from each commit's events. Added edges extend
+//! it in place; removals rebuild it, since a removed edge may or may not
+//! have been the only path between two nodes.
+
+use crate::graph::events::GraphEvent;
+use crate::graph::{Context, NodeId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Ancestor/descendant reachability for one relationship, kept current
+/// from graph events.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransitiveClosure {
+    pub relationship: String,
+    /// node → descendant → depth
+    descendants: HashMap<NodeId, HashMap<NodeId, usize>>,
+    /// node → ancestor → depth
+    ancestors: HashMap<NodeId, HashMap<NodeId, usize>>,
+    /// When the closure was last rebuilt in full
+    pub refreshed_at: Option<DateTime<Utc>>,
+    /// When the closure last changed incrementally
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maintained_at: Option<DateTime<Utc>>,
+}
+
+impl TransitiveClosure {
+    /// Compute the closure of `relationship` over `context`.
+    pub fn new(relationship: impl Into<String>, context: &Context) -> Self {
+        let mut closure = Self {
+            relationship: relationship.into(),
+            descendants: HashMap::new(),
+            ancestors: HashMap::new(),
+            refreshed_at: None,
+            maintained_at: None,
+        };
+        closure.rebuild(context);
+        closure
+    }
+
+    /// Recompute from the current edges.
+    pub fn rebuild(&mut self, context: &Context) {
+        self.descendants.clear();
+        self.ancestors.clear();
+        let relationship = self.relationship.clone();
+        for edge in context.edges.iter().filter(|e| e.relationship == relationship) {
+            self.link(&edge.source, &edge.target);
+        }
+        self.refreshed_at = Some(Utc::now());
+        self.maintained_at = None;
+    }
+
+    /// Record an edge `parent → child`: every ancestor of `parent` (and
+    /// `parent`) reaches every descendant of `child` (and `child`).
+    /// Returns whether any depth changed.
+    fn link(&mut self, parent: &NodeId, child: &NodeId) -> bool {
+        let above: Vec<(NodeId, usize)> = std::iter::once((parent.clone(), 0))
+            .chain(self.ancestors(parent))
+            .collect();
+        let below: Vec<(NodeId, usize)> = std::iter::once((child.clone(), 0))
+            .chain(self.descendants(child))
+            .collect();
+        let mut changed = false;
+        for (ancestor, up) in &above {
+            for (descendant, down) in &below {
+                if ancestor == descendant {
+                    continue;
+                }
+                let depth = up + 1 + down;
+                let slot = self.descendants.entry(ancestor.clone()).or_default().entry(descendant.clone()).or_insert(usize::MAX);
+                if depth < *slot {
+                    *slot = depth;
+                    self.ancestors.entry(descendant.clone()).or_default().insert(ancestor.clone(), depth);
+                    changed = true;
+                }
+            }
+        }
+        changed
+    }
+
+    /// Nodes `id` reaches, with depth, nearest first.
+    pub fn descendants(&self, id: &NodeId) -> Vec<(NodeId, usize)> {
+        sorted(self.descendants.get(id))
+    }
+
+    /// Nodes reaching `id`, with depth, nearest first.
+    pub fn ancestors(&self, id: &NodeId) -> Vec<(NodeId, usize)> {
+        sorted(self.ancestors.get(id))
+    }
+
+    /// Depth from `ancestor` down to `descendant`, if it reaches it.
+    pub fn depth(&self, ancestor: &NodeId, descendant: &NodeId) -> Option<usize> {
+        self.descendants.get(ancestor)?.get(descendant).copied()
+    }
+
+    /// Apply one commit's events: extend the closure with added edges,
+    /// rebuild it after removals.
+    pub fn apply_events(&mut self, events: &[GraphEvent], context: &Context) {
+        let removed = events.iter().any(|event| match event {
+            GraphEvent::EdgesRemoved { .. } => true,
+            GraphEvent::NodesRemoved { node_ids, .. } => {
+                node_ids.iter().any(|id| self.descendants.contains_key(id) || self.ancestors.contains_key(id))
+            }
+            _ => false,
+        });
+        if removed {
+            self.rebuild(context);
+            return;
+        }
+        let relationship = self.relationship.clone();
+        let mut changed = false;
+        for event in events {
+            if let GraphEvent::EdgesAdded { edge_ids, .. } = event {
+                for edge in context.edges.iter().filter(|e| e.relationship == relationship && edge_ids.contains(&e.id)) {
+                    changed |= self.link(&edge.source, &edge.target);
+                }
+            }
+        }
+        if changed {
+            self.maintained_at = Some(Utc::now());
+        }
+    }
+}
+
+fn sorted(reached: Option<&HashMap<NodeId, usize>>) -> Vec<(NodeId, usize)> {
+    let mut reached: Vec<(NodeId, usize)> =
+        reached.into_iter().flatten().map(|(id, depth)| (id.clone(), *depth)).collect();
+    reached.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.as_str().cmp(b.0.as_str())));
+    reached
+}
+
+/// Maintain every transitive closure on `context` from one commit's events.
+pub(crate) fn maintain_closures(context: &mut Context, events: &[GraphEvent]) {
+    if events.is_empty() || context.metadata.closures.is_empty() {
+        return;
+    }
+    let mut closures = std::mem::take(&mut context.metadata.closures);
+    for closure in closures.values_mut() {
+        closure.apply_events(events, context);
+    }
+    context.metadata.closures = closures;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{ContentType, Edge, Node};
+
+    fn node(ctx: &mut Context, id: &str) -> NodeId {
+        let mut node = Node::new("mark", ContentType::Provenance);
+        node.id = NodeId::from_string(id);
+        ctx.add_node(node)
+    }
+
+    fn contains(ctx: &mut Context, parent: &NodeId, child: &NodeId) -> GraphEvent {
+        let edge = Edge::new(parent.clone(), child.clone(), "contains");
+        let id = edge.id.clone();
+        ctx.add_edge(edge);
+        GraphEvent::EdgesAdded { edge_ids: vec![id], adapter_id: "test".into(), context_id: "ctx".into() }
+    }
+
+    // === Scenario: Added edges extend the closure with shortest depths ===
+    #[test]
+    fn closure_tracks_descendants_incrementally() {
+        let mut ctx = Context::new("closure");
+        let [chain, section, mark, note] = ["chain", "section", "mark", "note"].map(|id| node(&mut ctx, id));
+        contains(&mut ctx, &chain, &section);
+        let mut closure = TransitiveClosure::new("contains", &ctx);
+        assert_eq!(closure.descendants(&chain), vec![(section.clone(), 1)]);
+
+        // Joining a subtree below an existing node
+        let events = [contains(&mut ctx, &mark, &note), contains(&mut ctx, &section, &mark)];
+        closure.apply_events(&events, &ctx);
+        assert_eq!(closure.descendants(&chain), vec![(section.clone(), 1), (mark.clone(), 2), (note.clone(), 3)]);
+        assert_eq!(closure.ancestors(&note), vec![(mark.clone(), 1), (section.clone(), 2), (chain.clone(), 3)]);
+        assert!(closure.maintained_at.is_some());
+
+        // A shortcut shortens the depth
+        let shortcut = contains(&mut ctx, &chain, &mark);
+        closure.apply_events(&[shortcut], &ctx);
+        assert_eq!(closure.depth(&chain, &note), Some(2));
+
+        let rebuilt = TransitiveClosure::new("contains", &ctx);
+        assert_eq!(rebuilt.descendants(&chain), closure.descendants(&chain));
+    }
+
+    // === Scenario: Removing an edge rebuilds the closure ===
+    #[test]
+    fn removals_rebuild_and_cycles_terminate() {
+        let mut ctx = Context::new("closure");
+        let [a, b, c] = ["a", "b", "c"].map(|id| node(&mut ctx, id));
+        contains(&mut ctx, &a, &b);
+        contains(&mut ctx, &b, &c);
+        contains(&mut ctx, &c, &a);
+        let mut closure = TransitiveClosure::new("contains", &ctx);
+        assert_eq!(closure.descendants(&a), vec![(b.clone(), 1), (c.clone(), 2)], "a cycle never reaches itself");
+
+        let removed = ctx.trash_edges_where(|e| e.source == b);
+        closure.apply_events(
+            &[GraphEvent::EdgesRemoved {
+                edge_ids: removed,
+                adapter_id: "test".into(),
+                context_id: "ctx".into(),
+                reason: "direct".into(),
+            }],
+            &ctx,
+        );
+        assert_eq!(closure.descendants(&a), vec![(b.clone(), 1)]);
+        assert_eq!(closure.ancestors(&a), vec![(c.clone(), 1)]);
+    }
+}
@@ -16,12 +16,17 @@ pub struct PersistedEvent {
     /// Context this event belongs to
     pub context_id: String,
     /// Event type: "NodesAdded", "EdgesAdded", "NodesRemoved", "EdgesRemoved",
-    /// "WeightsChanged", "ContributionsRetracted"
+    /// "WeightsChanged", "ContributionsRetracted", "NodePropertiesChanged",
+    /// "EdgeAnnotated", "ContextMetadataChanged"
     pub event_type: String,
     /// Affected node IDs (if applicable)
     pub node_ids: Vec<String>,
     /// Affected edge IDs (if applicable)
     pub edge_ids: Vec<String>,
+    /// Changed keys: node property keys for "NodePropertiesChanged"
+    /// (across its nodes), metadata fields for "ContextMetadataChanged"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keys: Vec<String>,
     /// Which adapter or enrichment produced this event
     pub adapter_id: String,
     /// When the event was created
@@ -70,6 +75,7 @@ mod tests {
             event_type: "NodesAdded".to_string(),
             node_ids: vec!["node-1".to_string()],
             edge_ids: vec![],
+            keys: vec![],
             adapter_id: "content-adapter".to_string(),
             created_at: "2026-03-28T00:00:00Z".to_string(),
         };
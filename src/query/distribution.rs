@@ -0,0 +1,253 @@
+//! Degree, weight, and contribution distributions for monitoring
+//!
+//! Healthy contexts have long-tailed degree distributions; pathological
+//! growth (one concept accumulating 50k edges, an adapter re-emitting the
+//! same edge forever) shows up as a bucket far out in the tail. Degree and
+//! contribution counts use power-of-two buckets so the tail stays
+//! readable; edge weights use equal-width buckets.
+
+use crate::graph::{Context, NodeId};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Number of equal-width buckets in the edge-weight histogram.
+pub const WEIGHT_BUCKETS: usize = 10;
+
+/// Highest-degree nodes listed alongside the degree histogram.
+pub const TOP_DEGREE_NODES: usize = 10;
+
+/// Degree at which `super_nodes` reports a node by default.
+pub const DEFAULT_SUPER_NODE_DEGREE: usize = 1000;
+
+/// One histogram bucket covering `[min, max)`, or `[min, max]` for the
+/// last bucket.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HistogramBucket {
+    pub min: f64,
+    pub max: f64,
+    pub count: usize,
+}
+
+/// Bucketed distribution of a set of values.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Histogram {
+    /// Values counted
+    pub count: usize,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    /// Empty buckets inside the range are kept so the shape reads directly.
+    pub buckets: Vec<HistogramBucket>,
+}
+
+impl Histogram {
+    /// Power-of-two buckets: `[0, 1)`, `[1, 2)`, `[2, 4)`, `[4, 8)`, ...
+    pub fn log2(values: &[usize]) -> Self {
+        let mut histogram = Self::summary(values.iter().map(|v| *v as f64));
+        let Some(top) = values.iter().max() else {
+            return histogram;
+        };
+        let bucket_of = |v: usize| if v == 0 { 0 } else { v.ilog2() as usize + 1 };
+        histogram.buckets = (0..=bucket_of(*top))
+            .map(|b| HistogramBucket {
+                min: if b == 0 { 0.0 } else { (1u64 << (b - 1)) as f64 },
+                max: (1u64 << b) as f64,
+                count: 0,
+            })
+            .collect();
+        for v in values {
+            histogram.buckets[bucket_of(*v)].count += 1;
+        }
+        histogram
+    }
+
+    /// `buckets` equal-width buckets spanning the observed range.
+    pub fn linear(values: &[f64], buckets: usize) -> Self {
+        let mut histogram = Self::summary(values.iter().copied());
+        if values.is_empty() || buckets == 0 {
+            return histogram;
+        }
+        let (min, max) = (histogram.min, histogram.max);
+        let width = (max - min) / buckets as f64;
+        if width == 0.0 {
+            histogram.buckets = vec![HistogramBucket { min, max, count: values.len() }];
+            return histogram;
+        }
+        histogram.buckets = (0..buckets)
+            .map(|b| HistogramBucket {
+                min: min + width * b as f64,
+                max: if b + 1 == buckets { max } else { min + width * (b + 1) as f64 },
+                count: 0,
+            })
+            .collect();
+        for v in values {
+            let b = (((v - min) / width) as usize).min(buckets - 1);
+            histogram.buckets[b].count += 1;
+        }
+        histogram
+    }
+
+    fn summary(values: impl Iterator<Item = f64>) -> Self {
+        let mut histogram = Self { min: f64::INFINITY, max: f64::NEG_INFINITY, ..Default::default() };
+        let mut sum = 0.0;
+        for v in values {
+            histogram.count += 1;
+            histogram.min = histogram.min.min(v);
+            histogram.max = histogram.max.max(v);
+            sum += v;
+        }
+        if histogram.count == 0 {
+            return Self::default();
+        }
+        histogram.mean = sum / histogram.count as f64;
+        histogram
+    }
+}
+
+/// A node and its degree (incoming + outgoing edges).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NodeDegree {
+    pub node_id: NodeId,
+    pub node_type: String,
+    pub dimension: String,
+    pub degree: usize,
+}
+
+/// Shape of a context's graph.
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphDistributions {
+    pub node_count: usize,
+    pub edge_count: usize,
+    /// Per-node degree, counting both directions
+    pub degree: Histogram,
+    /// `combined_weight` across edges
+    pub edge_weight: Histogram,
+    /// Number of contributing adapters per edge
+    pub contribution_count: Histogram,
+    /// The `TOP_DEGREE_NODES` highest-degree nodes, highest first
+    pub top_degree: Vec<NodeDegree>,
+}
+
+/// Compute degree, edge-weight, and contribution-count distributions.
+pub fn distributions(context: &Context) -> GraphDistributions {
+    let ranked = ranked_degrees(context);
+    let top_degree = ranked
+        .iter()
+        .take(TOP_DEGREE_NODES)
+        .filter(|(_, d)| *d > 0)
+        .map(|(id, d)| node_degree(context, id, *d))
+        .collect();
+
+    let degrees: Vec<usize> = ranked.iter().map(|(_, d)| *d).collect();
+    let weights: Vec<f64> = context.edges().map(|e| e.combined_weight as f64).collect();
+    let contributions: Vec<usize> = context.edges().map(|e| e.contributions.len()).collect();
+
+    GraphDistributions {
+        node_count: context.node_count(),
+        edge_count: context.edge_count(),
+        degree: Histogram::log2(&degrees),
+        edge_weight: Histogram::linear(&weights, WEIGHT_BUCKETS),
+        contribution_count: Histogram::log2(&contributions),
+        top_degree,
+    }
+}
+
+/// Every node with at least `min_degree` edges, highest degree first.
+pub fn super_nodes(context: &Context, min_degree: usize) -> Vec<NodeDegree> {
+    ranked_degrees(context)
+        .into_iter()
+        .take_while(|(_, d)| *d >= min_degree.max(1))
+        .map(|(id, d)| node_degree(context, id, d))
+        .collect()
+}
+
+/// Degree of every node, highest first (ties by ID).
+fn ranked_degrees(context: &Context) -> Vec<(&NodeId, usize)> {
+    let mut degree: HashMap<&NodeId, usize> = context.nodes.keys().map(|id| (id, 0)).collect();
+    for edge in context.edges() {
+        for endpoint in [&edge.source, &edge.target] {
+            if let Some(d) = degree.get_mut(endpoint) {
+                *d += 1;
+            }
+        }
+    }
+    let mut ranked: Vec<(&NodeId, usize)> = degree.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.as_str().cmp(b.0.as_str())));
+    ranked
+}
+
+fn node_degree(context: &Context, id: &NodeId, degree: usize) -> NodeDegree {
+    let node = &context.nodes[id];
+    NodeDegree {
+        node_id: id.clone(),
+        node_type: node.node_type.clone(),
+        dimension: node.dimension.clone(),
+        degree,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{ContentType, Edge, Node};
+
+    // === Scenario: Power-of-two buckets expose the tail ===
+    #[test]
+    fn log2_buckets_cover_zero_through_max() {
+        let histogram = Histogram::log2(&[0, 1, 2, 3, 4, 9]);
+        let counts: Vec<usize> = histogram.buckets.iter().map(|b| b.count).collect();
+        // [0,1) [1,2) [2,4) [4,8) [8,16)
+        assert_eq!(counts, vec![1, 1, 2, 1, 1]);
+        assert_eq!(histogram.buckets[4].min, 8.0);
+        assert_eq!(histogram.max, 9.0);
+        assert_eq!(histogram.mean, 19.0 / 6.0);
+    }
+
+    // === Scenario: Linear buckets span the observed range ===
+    #[test]
+    fn linear_buckets_include_the_maximum() {
+        let histogram = Histogram::linear(&[0.0, 0.5, 1.0], 2);
+        assert_eq!(histogram.buckets.len(), 2);
+        assert_eq!(histogram.buckets[0].count, 1);
+        assert_eq!(histogram.buckets[1].count, 2, "max lands in the last bucket");
+
+        let flat = Histogram::linear(&[1.0, 1.0], 10);
+        assert_eq!(flat.buckets.len(), 1);
+        assert_eq!(Histogram::linear(&[], 10), Histogram::default());
+    }
+
+    // === Scenario: A super-node stands out in the degree distribution ===
+    #[test]
+    fn super_node_tops_the_degree_report() {
+        let mut ctx = Context::new("hubby");
+        let hub = ctx.add_node(Node::new("concept", ContentType::Concept));
+        for i in 0..40 {
+            let leaf = ctx.add_node(Node::new("fragment", ContentType::Document));
+            let mut edge = Edge::new(leaf, hub.clone(), "tagged_with");
+            edge.contributions.insert("content".into(), 1.0);
+            if i % 2 == 0 {
+                edge.contributions.insert("extraction".into(), 1.0);
+            }
+            ctx.edges.push(edge);
+        }
+
+        let report = distributions(&ctx);
+
+        assert_eq!(report.node_count, 41);
+        assert_eq!(report.edge_count, 40);
+        assert_eq!(report.top_degree[0].node_id, hub);
+        assert_eq!(report.top_degree[0].degree, 40);
+        assert_eq!(report.top_degree.len(), TOP_DEGREE_NODES);
+        let last = report.degree.buckets.last().unwrap();
+        assert_eq!((last.min, last.count), (32.0, 1), "hub alone in the tail bucket");
+        assert_eq!(report.degree.buckets[1].count, 40, "leaves have degree 1");
+        // [0,1) [1,2) [2,4)
+        let contributions: Vec<usize> = report.contribution_count.buckets.iter().map(|b| b.count).collect();
+        assert_eq!(contributions, vec![0, 20, 20]);
+
+        let supers = super_nodes(&ctx, 10);
+        assert_eq!(supers.len(), 1);
+        assert_eq!(supers[0].node_id, hub);
+        assert!(super_nodes(&ctx, 41).is_empty());
+    }
+}
@@ -0,0 +1,282 @@
+//! Concept dossiers — everything the graph knows about one concept
+//!
+//! A dossier gathers a concept's definitions (the fragment sentences that
+//! say what it is), its strongest related concepts, the marks and chains
+//! citing it, and the timeline of its mentions. It serializes as JSON for
+//! UIs and renders as markdown for people.
+
+use super::step::evidence_trail;
+use super::timeline::{concept_id, TimelineQuery};
+use crate::graph::{Context, Node, NodeId, PropertyValue};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Related concepts a dossier lists by default.
+pub const DEFAULT_DOSSIER_RELATED: usize = 10;
+
+/// Phrases after a concept's label that mark a sentence as defining it.
+const DEFINING_PHRASES: &[&str] = &[" is ", " are ", " means ", " refers to ", " describes "];
+
+/// A sentence from a fragment that mentions the concept.
+#[derive(Debug, Clone, Serialize)]
+pub struct Definition {
+    pub fragment_id: NodeId,
+    pub text: String,
+    /// Whether the sentence reads as a definition ("rye is …") rather
+    /// than a passing mention
+    pub defining: bool,
+}
+
+/// A concept connected to the dossier's concept.
+#[derive(Debug, Clone, Serialize)]
+pub struct RelatedConcept {
+    pub id: NodeId,
+    pub label: String,
+    pub relationship: String,
+    /// The strongest connecting edge's combined weight
+    pub weight: f32,
+}
+
+/// A node mentioning the concept, at its point in time.
+#[derive(Debug, Clone, Serialize)]
+pub struct Mention {
+    pub node_id: NodeId,
+    pub node_type: String,
+    pub at: DateTime<Utc>,
+}
+
+/// Everything known about one concept.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConceptDossier {
+    pub concept: NodeId,
+    pub label: String,
+    /// Defining sentences first, then mentions, at most one per fragment
+    pub definitions: Vec<Definition>,
+    /// Strongest first
+    pub related: Vec<RelatedConcept>,
+    pub marks: Vec<Node>,
+    pub chains: Vec<Node>,
+    /// Oldest first
+    pub timeline: Vec<Mention>,
+}
+
+impl ConceptDossier {
+    /// Assemble the dossier of `concept` (an ID or a label), listing up
+    /// to `related_limit` related concepts. `None` if it isn't a concept
+    /// in `context`.
+    pub fn build(context: &Context, concept: &str, related_limit: usize) -> Option<Self> {
+        let id = match context.get_node(&NodeId::from_string(concept)) {
+            Some(_) => NodeId::from_string(concept),
+            None => concept_id(concept),
+        };
+        let node = context.get_node(&id).filter(|n| n.node_type == "concept")?;
+        let label = label_of(node);
+        let trail = evidence_trail(id.clone(), context, None);
+
+        let mut definitions: Vec<Definition> =
+            trail.fragments.iter().filter_map(|fragment| definition_in(fragment, &label)).collect();
+        definitions.sort_by_key(|d| !d.defining);
+
+        let timeline = TimelineQuery::new()
+            .with_concepts(vec![id.to_string()])
+            .execute(context)
+            .entries
+            .into_iter()
+            .map(|entry| Mention { node_id: entry.node.id, node_type: entry.node.node_type, at: entry.at })
+            .collect();
+
+        Some(Self {
+            related: related_concepts(context, &id, related_limit),
+            concept: id,
+            label,
+            definitions,
+            marks: trail.marks,
+            chains: trail.chains,
+            timeline,
+        })
+    }
+
+    /// The dossier as a markdown document.
+    pub fn to_markdown(&self) -> String {
+        let mut md = format!("# {}\n", self.label);
+        if !self.definitions.is_empty() {
+            md.push_str("\n## Definitions\n\n");
+            for definition in &self.definitions {
+                md.push_str(&format!("- {} _({})_\n", definition.text, definition.fragment_id));
+            }
+        }
+        if !self.related.is_empty() {
+            md.push_str("\n## Related concepts\n\n");
+            for related in &self.related {
+                md.push_str(&format!("- **{}** — {} ({:.2})\n", related.label, related.relationship, related.weight));
+            }
+        }
+        if !self.marks.is_empty() {
+            md.push_str("\n## Marks\n\n");
+            for mark in &self.marks {
+                let file = mark.get_str("file").unwrap_or("?");
+                let line = mark.get_int("line").unwrap_or(0);
+                let annotation = mark.get_str("annotation").unwrap_or("");
+                md.push_str(&format!("- `{}:{}` {}\n", file, line, annotation));
+            }
+        }
+        if !self.chains.is_empty() {
+            md.push_str("\n## Chains\n\n");
+            for chain in &self.chains {
+                md.push_str(&format!("- {}\n", chain.get_str("name").unwrap_or(chain.id.as_str())));
+            }
+        }
+        if !self.timeline.is_empty() {
+            md.push_str("\n## Timeline\n\n");
+            for mention in &self.timeline {
+                md.push_str(&format!("- {} {} `{}`\n", mention.at.format("%Y-%m-%d"), mention.node_type, mention.node_id));
+            }
+        }
+        md
+    }
+}
+
+fn label_of(node: &Node) -> String {
+    match node.properties.get("label") {
+        Some(PropertyValue::String(label)) => label.clone(),
+        _ => node.id.as_str().trim_start_matches("concept:").to_string(),
+    }
+}
+
+/// The fragment's best sentence about `label`: a defining one if any,
+/// else the first mentioning it. The label only counts as a whole word,
+/// so "rye" doesn't match "ryegrass".
+fn definition_in(fragment: &Node, label: &str) -> Option<Definition> {
+    let text = fragment.get_str("text")?;
+    let needle = label.to_lowercase();
+    let mentioning: Vec<(&str, Vec<usize>)> = sentences(text)
+        .map(|s| (s, word_matches(&s.to_lowercase(), &needle)))
+        .filter(|(_, at)| !at.is_empty())
+        .collect();
+    let defining = mentioning.iter().find(|(s, at)| {
+        let lower = s.to_lowercase();
+        at.iter().any(|&end| DEFINING_PHRASES.iter().any(|phrase| lower[end..].starts_with(phrase)))
+    });
+    let (sentence, defining) = match defining {
+        Some((sentence, _)) => (*sentence, true),
+        None => (mentioning.first()?.0, false),
+    };
+    Some(Definition { fragment_id: fragment.id.clone(), text: sentence.to_string(), defining })
+}
+
+/// Where each whole-word occurrence of `needle` in `haystack` ends.
+fn word_matches(haystack: &str, needle: &str) -> Vec<usize> {
+    let is_word = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+    haystack
+        .match_indices(needle)
+        .filter(|(start, _)| !is_word(haystack[..*start].chars().next_back()))
+        .map(|(start, found)| start + found.len())
+        .filter(|&end| !is_word(haystack[end..].chars().next()))
+        .collect()
+}
+
+fn sentences(text: &str) -> impl Iterator<Item = &str> {
+    text.split_inclusive(['.', '!', '?', '\n']).map(str::trim).filter(|s| !s.is_empty())
+}
+
+/// Concepts joined to `id` by an edge either way, strongest first.
+fn related_concepts(context: &Context, id: &NodeId, limit: usize) -> Vec<RelatedConcept> {
+    let mut best: HashMap<&NodeId, (&str, f32)> = HashMap::new();
+    for edge in context.edges() {
+        let other = match (&edge.source == id, &edge.target == id) {
+            (true, false) => &edge.target,
+            (false, true) => &edge.source,
+            _ => continue,
+        };
+        if context.get_node(other).is_none_or(|n| n.node_type != "concept") {
+            continue;
+        }
+        let slot = best.entry(other).or_insert((edge.relationship.as_str(), edge.combined_weight));
+        if edge.combined_weight > slot.1 {
+            *slot = (edge.relationship.as_str(), edge.combined_weight);
+        }
+    }
+    let mut related: Vec<RelatedConcept> = best
+        .into_iter()
+        .filter_map(|(other, (relationship, weight))| {
+            Some(RelatedConcept {
+                id: other.clone(),
+                label: label_of(context.get_node(other)?),
+                relationship: relationship.to_string(),
+                weight,
+            })
+        })
+        .collect();
+    related.sort_by(|a, b| b.weight.total_cmp(&a.weight).then_with(|| a.id.as_str().cmp(b.id.as_str())));
+    related.truncate(limit);
+    related
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{dimension, ContentType, Edge};
+
+    fn node(ctx: &mut Context, node_type: &str, id: &str, properties: &[(&str, &str)]) -> NodeId {
+        let mut node = Node::new_in_dimension(node_type, ContentType::Concept, dimension::SEMANTIC);
+        node.id = NodeId::from_string(id);
+        for (key, value) in properties {
+            node.properties.insert(key.to_string(), PropertyValue::from(*value));
+        }
+        ctx.add_node(node)
+    }
+
+    // === Scenario: A dossier gathers definitions, neighbours, citations and mentions ===
+    #[test]
+    fn dossier_collects_what_the_graph_knows() {
+        let mut ctx = Context::new("dossier");
+        let rye = node(&mut ctx, "concept", "concept:rye", &[("label", "rye")]);
+        let starter = node(&mut ctx, "concept", "concept:starter", &[("label", "starter")]);
+        let oven = node(&mut ctx, "concept", "concept:oven", &[("label", "oven")]);
+        let passing = node(&mut ctx, "fragment", "fragment:1", &[("text", "I bought rye today. It was cheap."), ("created_at", "2026-03-02T09:00:00Z")]);
+        let defining = node(&mut ctx, "fragment", "fragment:2", &[("text", "Bread notes. Rye is a grain that sours fast."), ("created_at", "2026-03-01T09:00:00Z")]);
+        let mark = node(&mut ctx, "mark", "mark:1", &[("file", "bread.md"), ("annotation", "hydration")]);
+        for fragment in [&passing, &defining] {
+            ctx.add_edge(Edge::new(fragment.clone(), rye.clone(), "tagged_with"));
+        }
+        ctx.add_edge(Edge::new(mark, rye.clone(), "references"));
+        let mut strong = Edge::new(rye.clone(), starter, "may_be_related");
+        strong.combined_weight = 0.9;
+        ctx.add_edge(strong);
+        let mut weak = Edge::new(oven, rye.clone(), "may_be_related");
+        weak.combined_weight = 0.2;
+        ctx.add_edge(weak);
+
+        let dossier = ConceptDossier::build(&ctx, "Rye", DEFAULT_DOSSIER_RELATED).expect("rye is a concept");
+        assert_eq!(dossier.concept, rye);
+        assert_eq!(dossier.definitions[0].text, "Rye is a grain that sours fast.");
+        assert!(dossier.definitions[0].defining && !dossier.definitions[1].defining);
+        let related: Vec<&str> = dossier.related.iter().map(|r| r.label.as_str()).collect();
+        assert_eq!(related, vec!["starter", "oven"]);
+        assert_eq!(dossier.marks.len(), 1);
+        let mentions: Vec<&str> = dossier.timeline.iter().map(|m| m.node_id.as_str()).collect();
+        assert_eq!(mentions, vec!["fragment:2", "fragment:1"]);
+
+        let md = dossier.to_markdown();
+        assert!(md.starts_with("# rye\n"));
+        assert!(md.contains("- **starter** — may_be_related (0.90)"));
+        assert!(md.contains("- `bread.md:0` hydration"));
+        assert!(ConceptDossier::build(&ctx, "fragment:1", 10).is_none(), "only concepts have dossiers");
+        assert_eq!(ConceptDossier::build(&ctx, "rye", 1).unwrap().related.len(), 1);
+    }
+
+    // === Scenario: A label only matches as a whole word ===
+    #[test]
+    fn definitions_match_the_label_as_a_whole_word() {
+        let mut ctx = Context::new("dossier");
+        let rye = node(&mut ctx, "concept", "concept:rye", &[("label", "rye")]);
+        let fragment = node(&mut ctx, "fragment", "fragment:1", &[("text", "Ryegrass is a weed. Some rye, is sown late. Rye is a grain.")]);
+        ctx.add_edge(Edge::new(fragment.clone(), rye, "tagged_with"));
+
+        let dossier = ConceptDossier::build(&ctx, "rye", DEFAULT_DOSSIER_RELATED).unwrap();
+        assert_eq!(dossier.definitions[0].text, "Rye is a grain.", "ryegrass isn't rye");
+        assert!(dossier.definitions[0].defining);
+        assert_eq!(word_matches("ryegrass and rye", "rye"), vec![16]);
+    }
+}
@@ -0,0 +1,300 @@
+//! Concept drift — how a concept's company changes between two windows
+//!
+//! A concept's neighborhood in a window is the set of other concepts that
+//! co-occur with it on the same nodes (fragments, marks, turns) dated in
+//! that window, each weighted by the share of the concept's nodes it
+//! appears on. Drift compares two neighborhoods: the score is one minus
+//! the cosine similarity of the weight vectors, so 0 is "used in the same
+//! company" and 1 is "nothing in common". The windows can be two time
+//! ranges in one context, or two snapshots (`PlexusEngine::context_as_of`)
+//! compared whole.
+
+use super::timeline::{concept_id, concepts_touched, timestamp_of};
+use crate::graph::{Context, NodeId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Default number of changed neighbors reported.
+pub const DEFAULT_DRIFT_TOP_K: usize = 10;
+
+/// A half-open time range; `None` bounds are open.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeWindow {
+    pub from: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl TimeWindow {
+    pub fn new(from: Option<DateTime<Utc>>, until: Option<DateTime<Utc>>) -> Self {
+        Self { from, until }
+    }
+
+    /// The whole context, regardless of dates (for snapshot comparison).
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    fn is_bounded(&self) -> bool {
+        self.from.is_some() || self.until.is_some()
+    }
+
+    fn contains(&self, at: DateTime<Utc>) -> bool {
+        self.from.is_none_or(|from| at >= from) && self.until.is_none_or(|until| at < until)
+    }
+}
+
+/// A concept's co-occurring concepts within one window.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConceptNeighborhood {
+    pub window: TimeWindow,
+    /// Nodes in the window that touch the concept
+    pub occurrences: usize,
+    /// Co-occurring concepts with the share of occurrences they appear on,
+    /// strongest first
+    pub neighbors: Vec<(NodeId, f64)>,
+}
+
+impl ConceptNeighborhood {
+    fn weight(&self, concept: &NodeId) -> f64 {
+        self.neighbors.iter().find(|(c, _)| c == concept).map_or(0.0, |(_, w)| *w)
+    }
+}
+
+/// How one neighbor's weight moved between the windows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NeighborShift {
+    /// Absent before, present after
+    Entered,
+    /// Present before, absent after
+    Left,
+    Strengthened,
+    Weakened,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NeighborChange {
+    pub concept: NodeId,
+    pub before: f64,
+    pub after: f64,
+    /// `after - before`
+    pub delta: f64,
+    pub shift: NeighborShift,
+}
+
+/// Result of a drift comparison.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DriftReport {
+    pub concept: NodeId,
+    /// 1 − cosine similarity of the two neighborhoods, in [0, 1]
+    pub drift_score: f64,
+    pub before: ConceptNeighborhood,
+    pub after: ConceptNeighborhood,
+    /// Largest moves first, at most `top_k`
+    pub changes: Vec<NeighborChange>,
+}
+
+/// Compare a concept's neighborhood between two windows.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConceptDrift {
+    /// Concept label or `concept:` ID
+    pub concept: String,
+    pub before: TimeWindow,
+    pub after: TimeWindow,
+    /// Properties read for a node's date, first match wins
+    pub timestamp_properties: Vec<String>,
+    pub top_k: usize,
+}
+
+impl ConceptDrift {
+    pub fn new(concept: impl Into<String>, before: TimeWindow, after: TimeWindow) -> Self {
+        Self {
+            concept: concept.into(),
+            before,
+            after,
+            timestamp_properties: vec!["created_at".into(), "scheduled_for".into()],
+            top_k: DEFAULT_DRIFT_TOP_K,
+        }
+    }
+
+    /// Compare two snapshots whole — both windows unbounded.
+    pub fn snapshots(concept: impl Into<String>) -> Self {
+        Self::new(concept, TimeWindow::all(), TimeWindow::all())
+    }
+
+    pub fn with_timestamp_properties(mut self, keys: Vec<String>) -> Self {
+        self.timestamp_properties = keys;
+        self
+    }
+
+    pub fn with_top_k(mut self, top_k: usize) -> Self {
+        self.top_k = top_k;
+        self
+    }
+
+    /// Compare the two windows within one context.
+    pub fn execute(&self, context: &Context) -> DriftReport {
+        self.compare(context, context)
+    }
+
+    /// Compare the `before` window of one context with the `after` window
+    /// of another — typically a historical snapshot and the live context.
+    pub fn compare(&self, before: &Context, after: &Context) -> DriftReport {
+        let concept = concept_id(&self.concept);
+        let before = self.neighborhood(before, &concept, self.before);
+        let after = self.neighborhood(after, &concept, self.after);
+
+        let union: HashSet<&NodeId> = before.neighbors.iter().chain(&after.neighbors).map(|(c, _)| c).collect();
+        let (mut dot, mut norm_before, mut norm_after) = (0.0, 0.0, 0.0);
+        let mut changes: Vec<NeighborChange> = Vec::new();
+        for neighbor in union {
+            let (b, a) = (before.weight(neighbor), after.weight(neighbor));
+            dot += a * b;
+            norm_before += b * b;
+            norm_after += a * a;
+            let shift = match (b > 0.0, a > 0.0) {
+                (false, _) => NeighborShift::Entered,
+                (_, false) => NeighborShift::Left,
+                _ if a > b => NeighborShift::Strengthened,
+                _ if a < b => NeighborShift::Weakened,
+                _ => continue,
+            };
+            changes.push(NeighborChange { concept: neighbor.clone(), before: b, after: a, delta: a - b, shift });
+        }
+        let drift_score = match (norm_before > 0.0, norm_after > 0.0) {
+            (true, true) => (1.0 - dot / (norm_before.sqrt() * norm_after.sqrt())).clamp(0.0, 1.0),
+            (false, false) => 0.0,
+            _ => 1.0,
+        };
+        changes.sort_by(|x, y| {
+            y.delta.abs().total_cmp(&x.delta.abs()).then_with(|| x.concept.as_str().cmp(y.concept.as_str()))
+        });
+        changes.truncate(self.top_k);
+
+        DriftReport { concept, drift_score, before, after, changes }
+    }
+
+    fn neighborhood(&self, context: &Context, concept: &NodeId, window: TimeWindow) -> ConceptNeighborhood {
+        let mut occurrences = 0;
+        let mut counts: HashMap<NodeId, usize> = HashMap::new();
+        for (node_id, concepts) in concepts_touched(context) {
+            if !concepts.contains(concept) {
+                continue;
+            }
+            let Some(node) = context.get_node(&node_id) else { continue };
+            if node.node_type == "concept" {
+                continue;
+            }
+            if window.is_bounded() {
+                match timestamp_of(node, &self.timestamp_properties) {
+                    Some(at) if window.contains(at) => {}
+                    _ => continue,
+                }
+            }
+            occurrences += 1;
+            for other in concepts.into_iter().filter(|c| c != concept) {
+                *counts.entry(other).or_default() += 1;
+            }
+        }
+        let mut neighbors: Vec<(NodeId, f64)> =
+            counts.into_iter().map(|(c, n)| (c, n as f64 / occurrences as f64)).collect();
+        neighbors.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.as_str().cmp(b.0.as_str())));
+        ConceptNeighborhood { window, occurrences, neighbors }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{dimension, ContentType, Edge, Node, PropertyValue};
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn fragment(ctx: &mut Context, id: &str, created_at: &str, concepts: &[&str]) {
+        let mut node = Node::new_in_dimension("fragment", ContentType::Document, dimension::STRUCTURE);
+        node.id = NodeId::from_string(id);
+        node.properties.insert("created_at".into(), PropertyValue::from(created_at));
+        ctx.add_node(node);
+        for concept in concepts {
+            let concept_id = NodeId::from_string(format!("concept:{}", concept));
+            if ctx.get_node(&concept_id).is_none() {
+                let mut node = Node::new_in_dimension("concept", ContentType::Concept, dimension::SEMANTIC);
+                node.id = concept_id.clone();
+                ctx.add_node(node);
+            }
+            ctx.add_edge(Edge::new_cross_dimensional(
+                NodeId::from_string(id),
+                dimension::STRUCTURE,
+                concept_id,
+                dimension::SEMANTIC,
+                "tagged_with",
+            ));
+        }
+    }
+
+    fn corpus() -> Context {
+        let mut ctx = Context::new("drift");
+        // 2025: "attention" keeps company with psychology
+        fragment(&mut ctx, "fragment:a", "2025-02-01T00:00:00Z", &["attention", "psychology", "memory"]);
+        fragment(&mut ctx, "fragment:b", "2025-05-01T00:00:00Z", &["attention", "psychology"]);
+        // 2026: with transformers
+        fragment(&mut ctx, "fragment:c", "2026-02-01T00:00:00Z", &["attention", "transformers"]);
+        fragment(&mut ctx, "fragment:d", "2026-03-01T00:00:00Z", &["attention", "transformers", "memory"]);
+        fragment(&mut ctx, "fragment:e", "2026-03-02T00:00:00Z", &["transformers"]);
+        ctx
+    }
+
+    fn year(y: i32) -> TimeWindow {
+        TimeWindow::new(Some(at(&format!("{y}-01-01T00:00:00Z"))), Some(at(&format!("{}-01-01T00:00:00Z", y + 1))))
+    }
+
+    // === Scenario: A concept's usage shifts between years ===
+    #[test]
+    fn drift_between_time_windows_reports_changed_neighbors() {
+        let report = ConceptDrift::new("Attention", year(2025), year(2026)).execute(&corpus());
+
+        assert_eq!(report.concept, NodeId::from_string("concept:attention"));
+        assert_eq!((report.before.occurrences, report.after.occurrences), (2, 2));
+        assert_eq!(report.before.neighbors[0], (NodeId::from_string("concept:psychology"), 1.0));
+        // before (psychology 1, memory .5), after (transformers 1, memory .5)
+        assert!((report.drift_score - 0.8).abs() < 1e-9, "score {}", report.drift_score);
+
+        let shift = |c: &str| report.changes.iter().find(|ch| ch.concept.as_str() == c).map(|ch| ch.shift);
+        assert_eq!(shift("concept:transformers"), Some(NeighborShift::Entered));
+        assert_eq!(shift("concept:psychology"), Some(NeighborShift::Left));
+        assert_eq!(shift("concept:memory"), None, "unchanged weight is not a change");
+        assert_eq!(report.changes[0].delta.abs(), 1.0);
+    }
+
+    // === Scenario: Stable and absent concepts ===
+    #[test]
+    fn identical_windows_do_not_drift_and_absence_is_total() {
+        let ctx = corpus();
+        let same = ConceptDrift::new("attention", year(2025), year(2025)).execute(&ctx);
+        assert!(same.drift_score < 1e-9);
+        assert!(same.changes.is_empty());
+
+        let gone = ConceptDrift::new("psychology", year(2025), year(2026)).with_top_k(1).execute(&ctx);
+        assert_eq!(gone.after.occurrences, 0);
+        assert_eq!(gone.drift_score, 1.0);
+        assert_eq!(gone.changes.len(), 1, "truncated to top_k");
+    }
+
+    // === Scenario: Comparing two snapshots whole ===
+    #[test]
+    fn snapshot_comparison_ignores_dates() {
+        let mut earlier = Context::new("drift");
+        fragment(&mut earlier, "fragment:a", "2025-02-01T00:00:00Z", &["attention", "psychology"]);
+        let later = corpus();
+
+        let report = ConceptDrift::snapshots("attention").compare(&earlier, &later);
+        assert_eq!((report.before.occurrences, report.after.occurrences), (1, 4));
+        assert!(report.drift_score > 0.0 && report.drift_score < 1.0);
+        let psychology = report.changes.iter().find(|c| c.concept.as_str() == "concept:psychology").unwrap();
+        assert_eq!(psychology.shift, NeighborShift::Weakened);
+        assert_eq!(psychology.after, 0.5);
+    }
+}
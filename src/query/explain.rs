@@ -7,9 +7,9 @@
 //! edges included), each with contributions, corroboration, and lens
 //! contribution keys parsed back into source relationships.
 
-use crate::graph::{Context, NodeId, PropertyValue};
+use crate::graph::{Context, Edge, Node, NodeId, PropertyValue};
 use serde::Serialize;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// An endpoint of the explained pair, with enough content to display.
 #[derive(Debug, Clone, Serialize)]
@@ -125,3 +125,203 @@ pub fn explain_pair(
         edges,
     })
 }
+
+/// Contributions listed per step of an explained path.
+pub const TOP_CONTRIBUTIONS: usize = 3;
+
+/// Paths kept per explained node; BFS fan-in can otherwise explode.
+pub const MAX_EXPLAINED_PATHS: usize = 5;
+
+/// One contribution slot on an explained edge.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ContributionShare {
+    pub contributor: String,
+    pub value: f32,
+}
+
+/// One hop of a path that reached a result node.
+#[derive(Debug, Clone, Serialize)]
+pub struct PathStep {
+    pub source: NodeId,
+    pub target: NodeId,
+    pub relationship: String,
+    pub raw_weight: f32,
+    /// Largest contributions first, at most `TOP_CONTRIBUTIONS`.
+    pub top_contributions: Vec<ContributionShare>,
+}
+
+/// A provenance mark grounding a node on an explained path.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProvenanceCitation {
+    pub mark_id: NodeId,
+    /// The path node this mark is evidence for.
+    pub cites: NodeId,
+    pub chain_id: Option<String>,
+    pub file: Option<String>,
+    pub line: Option<i64>,
+    pub annotation: Option<String>,
+}
+
+/// Why a query returned a node: the edge paths that reached it, and the
+/// marks behind the nodes on those paths.
+#[derive(Debug, Clone, Serialize)]
+pub struct NodeExplanation {
+    pub node_id: NodeId,
+    pub paths: Vec<Vec<PathStep>>,
+    pub provenance: Vec<ProvenanceCitation>,
+}
+
+fn path_step(edge: &Edge) -> PathStep {
+    let mut top: Vec<ContributionShare> = edge
+        .contributions
+        .iter()
+        .map(|(contributor, value)| ContributionShare {
+            contributor: contributor.clone(),
+            value: *value,
+        })
+        .collect();
+    top.sort_by(|a, b| {
+        b.value
+            .partial_cmp(&a.value)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.contributor.cmp(&b.contributor))
+    });
+    top.truncate(TOP_CONTRIBUTIONS);
+    PathStep {
+        source: edge.source.clone(),
+        target: edge.target.clone(),
+        relationship: edge.relationship.clone(),
+        raw_weight: edge.combined_weight,
+        top_contributions: top,
+    }
+}
+
+fn string_prop(node: &Node, key: &str) -> Option<String> {
+    match node.properties.get(key) {
+        Some(PropertyValue::String(s)) => Some(s.clone()),
+        _ => None,
+    }
+}
+
+/// Whether `mark` is the content-ingest mark for `node` — content
+/// marks are keyed `mark:{adapter}:{fragment_id}`.
+fn is_ingest_mark_for(mark: &NodeId, node: &NodeId) -> bool {
+    ingest_mark_fragment(mark) == Some(node.as_str())
+}
+
+/// The fragment ID a content-ingest mark is keyed by.
+pub(crate) fn ingest_mark_fragment(mark: &NodeId) -> Option<&str> {
+    mark.as_str().strip_prefix("mark:").and_then(|rest| rest.split_once(':')).map(|(_, fragment)| fragment)
+}
+
+/// Marks linked to any of `nodes`: via a `references` edge from the
+/// mark, or as the content-ingest mark of a fragment.
+pub(crate) fn provenance_for(context: &Context, nodes: &[&NodeId]) -> Vec<ProvenanceCitation> {
+    let mut seen: HashSet<NodeId> = HashSet::new();
+    let mut citations = Vec::new();
+    let mut cite = |mark: &Node, cites: &NodeId| {
+        if seen.insert(mark.id.clone()) {
+            citations.push(ProvenanceCitation {
+                mark_id: mark.id.clone(),
+                cites: cites.clone(),
+                chain_id: string_prop(mark, "chain_id"),
+                file: string_prop(mark, "file"),
+                line: match mark.properties.get("line") {
+                    Some(PropertyValue::Int(line)) => Some(*line),
+                    _ => None,
+                },
+                annotation: string_prop(mark, "annotation"),
+            });
+        }
+    };
+
+    for node_id in nodes {
+        for edge in context.edges.iter().filter(|e| {
+            e.relationship == "references" && e.target == **node_id
+        }) {
+            if let Some(mark) = context.get_node(&edge.source).filter(|n| n.node_type == "mark") {
+                cite(mark, node_id);
+            }
+        }
+        for mark in context
+            .nodes()
+            .filter(|n| n.node_type == "mark" && is_ingest_mark_for(&n.id, node_id))
+        {
+            cite(mark, node_id);
+        }
+    }
+    citations
+}
+
+/// Explain how `node_id` was reached along `paths` (each origin-first).
+pub fn explain_node(context: &Context, node_id: &NodeId, paths: &[Vec<Edge>]) -> NodeExplanation {
+    let mut on_paths: Vec<&NodeId> = vec![node_id];
+    for edge in paths.iter().flatten() {
+        for id in [&edge.source, &edge.target] {
+            if !on_paths.contains(&id) {
+                on_paths.push(id);
+            }
+        }
+    }
+    NodeExplanation {
+        node_id: node_id.clone(),
+        paths: paths
+            .iter()
+            .map(|path| path.iter().map(path_step).collect())
+            .collect(),
+        provenance: provenance_for(context, &on_paths),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{dimension, ContentType};
+
+    fn mark(ctx: &mut Context, id: &str, annotation: &str) -> NodeId {
+        let mut node = Node::new_in_dimension("mark", ContentType::Provenance, dimension::PROVENANCE);
+        node.id = NodeId::from_string(id);
+        node.properties.insert("annotation".into(), PropertyValue::from(annotation));
+        node.properties.insert("file".into(), PropertyValue::from("notes.md"));
+        node.properties.insert("line".into(), PropertyValue::Int(7));
+        ctx.add_node(node)
+    }
+
+    // === Scenario: Path steps carry their largest contributions ===
+    #[test]
+    fn path_steps_list_top_contributions_descending() {
+        let mut edge = Edge::new(NodeId::from_string("a"), NodeId::from_string("b"), "may_be_related");
+        for (adapter, value) in [("x", 0.2), ("y", 0.9), ("z", 0.5), ("w", 0.1)] {
+            edge.contributions.insert(adapter.to_string(), value);
+        }
+
+        let step = path_step(&edge);
+
+        let order: Vec<&str> = step.top_contributions.iter().map(|c| c.contributor.as_str()).collect();
+        assert_eq!(order, vec!["y", "z", "x"]);
+    }
+
+    // === Scenario: Marks cite path nodes by reference or ingest key ===
+    #[test]
+    fn provenance_includes_referencing_and_ingest_marks() {
+        let mut ctx = Context::new("explain");
+        let fragment = ctx.add_node(Node::new("fragment", ContentType::Document));
+        let concept = NodeId::from_string("concept:rye");
+        let mut concept_node = Node::new_in_dimension("concept", ContentType::Concept, dimension::SEMANTIC);
+        concept_node.id = concept.clone();
+        ctx.add_node(concept_node);
+        let ingest = mark(&mut ctx, &format!("mark:content:{}", fragment), "Fed the starter");
+        let note = mark(&mut ctx, "mark:note", "Rye ferments fast");
+        mark(&mut ctx, "mark:unrelated", "Elsewhere");
+        ctx.edges.push(Edge::new(note.clone(), concept.clone(), "references"));
+        let tagged = Edge::new(fragment.clone(), concept.clone(), "tagged_with");
+
+        let explanation = explain_node(&ctx, &concept, &[vec![tagged]]);
+
+        let marks: Vec<&NodeId> = explanation.provenance.iter().map(|c| &c.mark_id).collect();
+        assert_eq!(marks, vec![&note, &ingest]);
+        assert_eq!(explanation.provenance[1].cites, fragment);
+        assert_eq!(explanation.provenance[1].line, Some(7));
+        assert_eq!(explanation.paths[0][0].relationship, "tagged_with");
+    }
+}
@@ -4,12 +4,14 @@
 //! an edge must pass all non-`None` predicates.
 
 use crate::graph::Edge;
+use serde::{Deserialize, Serialize};
 
 /// Composable filter for provenance-scoped and corroboration-based filtering.
 ///
 /// When `None`, the field applies no constraint. When present, edges must satisfy
 /// all non-`None` predicates (AND semantics).
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct QueryFilter {
     /// Only include edges with a contribution from at least one of these IDs.
     pub contributor_ids: Option<Vec<String>>,
@@ -17,6 +19,9 @@ pub struct QueryFilter {
     pub relationship_prefix: Option<String>,
     /// Minimum corroboration: edges must have at least this many distinct contributors.
     pub min_corroboration: Option<usize>,
+    /// Minimum extractor confidence (`Edge::confidence`). Edges without a
+    /// reported confidence come from deterministic sources and pass.
+    pub min_confidence: Option<f64>,
 }
 
 impl QueryFilter {
@@ -44,6 +49,13 @@ impl QueryFilter {
             }
         }
 
+        // min_confidence: edge passes unless its reported confidence is lower
+        if let Some(min) = self.min_confidence {
+            if edge.confidence().is_some_and(|c| c < min) {
+                return false;
+            }
+        }
+
         true
     }
 }
@@ -146,12 +158,29 @@ mod tests {
         assert!(!filter.edge_passes(&fail_edge));
     }
 
+    #[test]
+    fn min_confidence_filters_low_confidence_edges_only() {
+        let filter = QueryFilter {
+            min_confidence: Some(0.7),
+            ..Default::default()
+        };
+
+        let confident = edge_with_contributions("r", &["a"]).with_property("confidence", 0.9);
+        let doubtful = edge_with_contributions("r", &["a"]).with_property("confidence", 0.4);
+        let unscored = edge_with_contributions("r", &["a"]);
+
+        assert!(filter.edge_passes(&confident));
+        assert!(!filter.edge_passes(&doubtful));
+        assert!(filter.edge_passes(&unscored), "no reported confidence: deterministic source");
+    }
+
     #[test]
     fn all_fields_compose_with_and_semantics() {
         let filter = QueryFilter {
             contributor_ids: Some(vec!["lens:trellis:thematic_connection:may_be_related".into()]),
             relationship_prefix: Some("lens:trellis:".into()),
             min_corroboration: Some(2),
+            min_confidence: None,
         };
 
         // Passes all three
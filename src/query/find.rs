@@ -1,11 +1,45 @@
 //! Find queries for locating nodes
 
-use crate::graph::{Context, ContentType, Node, PropertyValue};
+use crate::graph::{resolve_head, Context, ContentType, Node, PropertyValue};
+use std::collections::HashSet;
+use crate::storage::NodeFilter;
+use serde::{Deserialize, Serialize};
 use super::filter::QueryFilter;
+use super::geo::GeoFilter;
 use super::types::QueryResult;
 
+/// Comparison operator for property filters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CompareOp {
+    fn holds(self, ordering: std::cmp::Ordering) -> bool {
+        use std::cmp::Ordering::*;
+        match self {
+            CompareOp::Eq => ordering == Equal,
+            CompareOp::Ne => ordering != Equal,
+            CompareOp::Lt => ordering == Less,
+            CompareOp::Le => ordering != Greater,
+            CompareOp::Gt => ordering == Greater,
+            CompareOp::Ge => ordering != Less,
+        }
+    }
+}
+
 /// Query for finding nodes by various criteria
-#[derive(Debug, Clone, Default)]
+///
+/// Property keys may be dotted paths into nested objects
+/// (`"frontmatter.author"`); an exact top-level key takes precedence.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct FindQuery {
     /// Filter by node type (e.g., "function", "class")
     pub node_type: Option<String>,
@@ -17,6 +51,9 @@ pub struct FindQuery {
     pub has_property: Option<String>,
     /// Filter by property key-value match
     pub property_equals: Option<(String, PropertyValue)>,
+    /// Filter by property comparisons (all must hold). Values of
+    /// incomparable kinds never match — see `PropertyValue::compare`.
+    pub property_comparisons: Vec<(String, CompareOp, PropertyValue)>,
     /// Maximum number of results
     pub limit: Option<usize>,
     /// Number of results to skip
@@ -25,6 +62,10 @@ pub struct FindQuery {
     /// When present, a node qualifies only if it has at least one incident
     /// edge passing the filter.
     pub filter: Option<QueryFilter>,
+    /// Replace each match with its head version, dropping duplicates
+    pub resolve_to_head: bool,
+    /// Keep only located nodes inside this area
+    pub geo: Option<GeoFilter>,
 }
 
 impl FindQuery {
@@ -63,6 +104,18 @@ impl FindQuery {
         self
     }
 
+    /// Filter by property comparison (e.g., `created_at >= <date>`).
+    /// Repeated calls accumulate, so ranges take two calls.
+    pub fn with_property_cmp(
+        mut self,
+        key: impl Into<String>,
+        op: CompareOp,
+        value: PropertyValue,
+    ) -> Self {
+        self.property_comparisons.push((key.into(), op, value));
+        self
+    }
+
     /// Limit results
     pub fn limit(mut self, limit: usize) -> Self {
         self.limit = Some(limit);
@@ -81,15 +134,28 @@ impl FindQuery {
         self
     }
 
+    /// Keep only nodes within `radius_km` of a point.
+    pub fn within_radius(mut self, latitude: f64, longitude: f64, radius_km: f64) -> Self {
+        self.geo = Some(GeoFilter::Radius { latitude, longitude, radius_km });
+        self
+    }
+
+    /// Keep only nodes inside a bounding box (`west > east` spans the
+    /// antimeridian).
+    pub fn within_bounds(mut self, south: f64, west: f64, north: f64, east: f64) -> Self {
+        self.geo = Some(GeoFilter::BoundingBox { south, west, north, east });
+        self
+    }
+
+    /// Resolve superseded matches to their newest version
+    pub fn resolve_to_head(mut self) -> Self {
+        self.resolve_to_head = true;
+        self
+    }
+
     /// Execute the query against a context
     pub fn execute(&self, context: &Context) -> QueryResult {
-        let mut nodes: Vec<Node> = context
-            .nodes
-            .values()
-            .filter(|node| self.matches(node))
-            .filter(|node| self.passes_edge_filter(node, context))
-            .cloned()
-            .collect();
+        let mut nodes: Vec<Node> = self.selected(context).into_iter().cloned().collect();
 
         let total_count = nodes.len();
 
@@ -110,6 +176,62 @@ impl FindQuery {
         QueryResult { nodes, total_count }
     }
 
+    /// Execute against a historical view, or any other `Context`
+    /// snapshot: `query.against(&engine.context_as_of(&id, cutoff)?)`.
+    pub fn against(&self, view: &Context) -> QueryResult {
+        self.execute(view)
+    }
+
+    /// Number of matching nodes, ignoring limit and offset (the result's
+    /// `total_count`), without cloning any of them.
+    pub fn count(&self, context: &Context) -> usize {
+        if self.resolve_to_head {
+            return self.selected(context).len();
+        }
+        context.nodes.values().filter(|node| self.selects(node, context)).count()
+    }
+
+    /// Whether any node matches; stops at the first.
+    pub fn exists(&self, context: &Context) -> bool {
+        context.nodes.values().any(|node| self.selects(node, context))
+    }
+
+    /// The unpaged result, resolved to head versions if asked.
+    fn selected<'a>(&self, context: &'a Context) -> Vec<&'a Node> {
+        let matched = context.nodes.values().filter(|node| self.selects(node, context));
+        if !self.resolve_to_head {
+            return matched.collect();
+        }
+        let successors = context.version_successors();
+        let mut seen = HashSet::new();
+        matched
+            .filter_map(|node| {
+                let head = resolve_head(&successors, &node.id);
+                if seen.insert(head.clone()) { context.get_node(&head) } else { None }
+            })
+            .collect()
+    }
+
+    /// The equivalent storage filter, when the query only constrains
+    /// indexed columns, so `GraphStore::count_nodes` can answer in SQL.
+    pub fn to_node_filter(&self) -> Option<NodeFilter> {
+        let indexed_only = self.has_property.is_none()
+            && self.property_equals.is_none()
+            && self.property_comparisons.is_empty()
+            && self.filter.is_none()
+            && self.geo.is_none();
+        indexed_only.then(|| NodeFilter {
+            node_type: self.node_type.clone(),
+            content_type: self.content_type.clone(),
+            dimension: self.dimension.clone(),
+        })
+    }
+
+    /// Whether `node` belongs in this query's (unpaged) result.
+    pub(crate) fn selects(&self, node: &Node, context: &Context) -> bool {
+        self.matches(node) && self.passes_edge_filter(node, context)
+    }
+
     /// Check if a node has at least one incident edge passing the filter.
     /// Returns true if no filter is set.
     fn passes_edge_filter(&self, node: &Node, context: &Context) -> bool {
@@ -131,8 +253,8 @@ impl FindQuery {
         }
 
         // Check content type
-        if let Some(expected_content) = self.content_type {
-            if node.content_type != expected_content {
+        if let Some(expected_content) = &self.content_type {
+            if &node.content_type != expected_content {
                 return false;
             }
         }
@@ -146,23 +268,49 @@ impl FindQuery {
 
         // Check property existence
         if let Some(ref key) = self.has_property {
-            if !node.properties.contains_key(key) {
+            if lookup_property(node, key).is_none() {
                 return false;
             }
         }
 
         // Check property value
         if let Some((ref key, ref expected_value)) = self.property_equals {
-            match node.properties.get(key) {
+            match lookup_property(node, key) {
                 Some(value) if value == expected_value => {}
                 _ => return false,
             }
         }
 
+        // Check property comparisons
+        for (key, op, expected) in &self.property_comparisons {
+            let ordering = lookup_property(node, key).and_then(|v| v.compare(expected));
+            match ordering {
+                Some(ordering) if op.holds(ordering) => {}
+                _ => return false,
+            }
+        }
+
+        // Check location
+        if let Some(ref geo) = self.geo {
+            if !geo.contains(node) {
+                return false;
+            }
+        }
+
         true
     }
 }
 
+/// Resolve a property key on a node: exact key first, then a dotted path
+/// into nested objects.
+fn lookup_property<'a>(node: &'a Node, key: &str) -> Option<&'a PropertyValue> {
+    if let Some(value) = node.properties.get(key) {
+        return Some(value);
+    }
+    let (head, rest) = key.split_once('.')?;
+    node.properties.get(head)?.get_path(rest)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -245,6 +393,19 @@ mod tests {
         assert_eq!(result.total_count, 4);
     }
 
+    #[test]
+    fn test_count_and_exists_match_execute() {
+        let ctx = create_test_context();
+        let functions = FindQuery::new().with_node_type("function").limit(1);
+        assert_eq!(functions.count(&ctx), functions.execute(&ctx).total_count);
+        assert_eq!(functions.count(&ctx), 2);
+        assert!(functions.exists(&ctx));
+        assert!(!FindQuery::new().with_node_type("module").exists(&ctx));
+
+        assert!(functions.to_node_filter().is_some());
+        assert!(FindQuery::new().with_property("language").to_node_filter().is_none());
+    }
+
     #[test]
     fn test_find_combined_filters() {
         let ctx = create_test_context();
@@ -254,4 +415,70 @@ mod tests {
             .execute(&ctx);
         assert_eq!(result.nodes.len(), 2);
     }
+
+    #[test]
+    fn test_find_by_property_comparison() {
+        let mut ctx = Context::new("test");
+        for (id, version) in [("a", 1), ("b", 2), ("c", 3)] {
+            let mut node = Node::new("release", ContentType::Document);
+            node.id = crate::graph::NodeId::from_string(id);
+            node.properties.insert("version".to_string(), PropertyValue::Int(version));
+            ctx.add_node(node);
+        }
+
+        let result = FindQuery::new()
+            .with_property_cmp("version", CompareOp::Ge, PropertyValue::Int(2))
+            .with_property_cmp("version", CompareOp::Lt, PropertyValue::Float(3.0))
+            .execute(&ctx);
+        assert_eq!(result.nodes.len(), 1);
+        assert_eq!(result.nodes[0].id.as_str(), "b");
+
+        // Incomparable kinds never match
+        let result = FindQuery::new()
+            .with_property_cmp("version", CompareOp::Ne, PropertyValue::String("2".into()))
+            .execute(&ctx);
+        assert_eq!(result.nodes.len(), 0);
+    }
+
+    #[test]
+    fn test_find_by_datetime_and_nested_path() {
+        let mut ctx = Context::new("test");
+        let taken_at = |s: &str| {
+            chrono::DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&chrono::Utc)
+        };
+        for (id, when, author) in [
+            ("old", "2020-01-01T00:00:00Z", "ada"),
+            ("new", "2024-06-01T00:00:00Z", "grace"),
+        ] {
+            let mut node = Node::new("note", ContentType::Document);
+            node.id = crate::graph::NodeId::from_string(id);
+            node.properties.insert(
+                "frontmatter".to_string(),
+                PropertyValue::Object(std::collections::HashMap::from([
+                    ("author".to_string(), PropertyValue::String(author.to_string())),
+                    ("date".to_string(), PropertyValue::DateTime(taken_at(when))),
+                ])),
+            );
+            ctx.add_node(node);
+        }
+
+        // DateTime compares against RFC-3339 strings
+        let result = FindQuery::new()
+            .with_property_cmp(
+                "frontmatter.date",
+                CompareOp::Gt,
+                PropertyValue::String("2023-01-01T00:00:00Z".into()),
+            )
+            .execute(&ctx);
+        assert_eq!(result.nodes.len(), 1);
+        assert_eq!(result.nodes[0].id.as_str(), "new");
+
+        let result = FindQuery::new()
+            .with_property_value("frontmatter.author", PropertyValue::String("ada".into()))
+            .execute(&ctx);
+        assert_eq!(result.nodes.len(), 1);
+        assert_eq!(result.nodes[0].id.as_str(), "old");
+
+        assert_eq!(FindQuery::new().with_property("frontmatter.missing").execute(&ctx).nodes.len(), 0);
+    }
 }
@@ -0,0 +1,133 @@
+//! Geographic filters over nodes carrying coordinates
+//!
+//! A node is located when it has numeric `latitude` and `longitude`
+//! properties (decimal degrees) — place nodes in the spatial dimension,
+//! or anything else an adapter located, such as EXIF-tagged images.
+//! `GeoFilter` keeps the located nodes inside a radius or a bounding box;
+//! unlocated nodes never match.
+
+use crate::graph::Node;
+use serde::{Deserialize, Serialize};
+
+/// Node property holding latitude in decimal degrees (south negative).
+pub const LATITUDE_PROPERTY: &str = "latitude";
+/// Node property holding longitude in decimal degrees (west negative).
+pub const LONGITUDE_PROPERTY: &str = "longitude";
+
+/// Mean Earth radius used for great-circle distances.
+const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+/// A node's `(latitude, longitude)`, if it has both.
+pub fn coordinates(node: &Node) -> Option<(f64, f64)> {
+    let latitude = node.properties.get(LATITUDE_PROPERTY)?.as_float()?;
+    let longitude = node.properties.get(LONGITUDE_PROPERTY)?.as_float()?;
+    Some((latitude, longitude))
+}
+
+/// Great-circle (haversine) distance between two points, in kilometres.
+pub fn haversine_km(from: (f64, f64), to: (f64, f64)) -> f64 {
+    let (lat1, lat2) = (from.0.to_radians(), to.0.to_radians());
+    let dlat = lat2 - lat1;
+    let dlon = (to.1 - from.1).to_radians();
+    let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
+
+/// A spatial constraint on located nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum GeoFilter {
+    /// Within `radius_km` of a point
+    Radius { latitude: f64, longitude: f64, radius_km: f64 },
+    /// Inside a box; `west > east` spans the antimeridian
+    BoundingBox { south: f64, west: f64, north: f64, east: f64 },
+}
+
+impl GeoFilter {
+    /// Reject filters no point can satisfy meaningfully: non-finite
+    /// coordinates, or a radius that is negative or not finite.
+    pub fn validate(&self) -> Result<(), String> {
+        let coordinates = match *self {
+            GeoFilter::Radius { latitude, longitude, radius_km } => {
+                if !radius_km.is_finite() || radius_km < 0.0 {
+                    return Err(format!("radius must be a finite, non-negative number of km, got {radius_km}"));
+                }
+                vec![latitude, longitude]
+            }
+            GeoFilter::BoundingBox { south, west, north, east } => vec![south, west, north, east],
+        };
+        match coordinates.iter().all(|c| c.is_finite()) {
+            true => Ok(()),
+            false => Err("coordinates must be finite numbers".to_string()),
+        }
+    }
+
+    /// Whether `node` is located inside this filter's area.
+    pub fn contains(&self, node: &Node) -> bool {
+        coordinates(node).is_some_and(|point| self.contains_point(point))
+    }
+
+    pub fn contains_point(&self, (latitude, longitude): (f64, f64)) -> bool {
+        match *self {
+            GeoFilter::Radius { latitude: lat, longitude: lon, radius_km } => {
+                haversine_km((lat, lon), (latitude, longitude)) <= radius_km
+            }
+            GeoFilter::BoundingBox { south, west, north, east } => {
+                let in_longitude = if west <= east {
+                    (west..=east).contains(&longitude)
+                } else {
+                    longitude >= west || longitude <= east
+                };
+                (south..=north).contains(&latitude) && in_longitude
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{ContentType, PropertyValue};
+
+    fn place(latitude: f64, longitude: f64) -> Node {
+        let mut node = Node::new("place", ContentType::Other("place".into()));
+        node.properties.insert(LATITUDE_PROPERTY.into(), PropertyValue::Float(latitude));
+        node.properties.insert(LONGITUDE_PROPERTY.into(), PropertyValue::Float(longitude));
+        node
+    }
+
+    // === Scenario: Radius and bounding-box filters select located nodes ===
+    #[test]
+    fn radius_and_box_filters() {
+        let avignon = place(43.9493, 4.8055);
+        let arles = place(43.6768, 4.6303);
+        let paris = place(48.8566, 2.3522);
+
+        let distance = haversine_km((43.9493, 4.8055), (43.6768, 4.6303));
+        assert!((distance - 33.7).abs() < 1.0, "Avignon–Arles is about 34km, got {distance}");
+
+        let near_avignon = GeoFilter::Radius { latitude: 43.9493, longitude: 4.8055, radius_km: 50.0 };
+        assert!(near_avignon.contains(&avignon) && near_avignon.contains(&arles));
+        assert!(!near_avignon.contains(&paris));
+        assert!(!near_avignon.contains(&Node::new("fragment", ContentType::Document)), "unlocated nodes never match");
+
+        let provence = GeoFilter::BoundingBox { south: 43.0, west: 4.0, north: 44.5, east: 7.0 };
+        assert!(provence.contains(&arles) && !provence.contains(&paris));
+        let pacific = GeoFilter::BoundingBox { south: -30.0, west: 170.0, north: 0.0, east: -170.0 };
+        assert!(pacific.contains(&place(-17.7, 178.0)) && pacific.contains(&place(-14.3, -178.1)));
+        assert!(!pacific.contains(&place(-17.7, 160.0)));
+    }
+
+    // === Scenario: Meaningless radii and coordinates are rejected ===
+    #[test]
+    fn validate_rejects_bad_radii_and_coordinates() {
+        let radius = |radius_km| GeoFilter::Radius { latitude: 43.9493, longitude: 4.8055, radius_km };
+        assert!(radius(0.0).validate().is_ok());
+        assert!(radius(50.0).validate().is_ok());
+        for bad in [-1.0, f64::NAN, f64::INFINITY] {
+            assert!(radius(bad).validate().is_err(), "radius {bad} should be rejected");
+        }
+        assert!(GeoFilter::Radius { latitude: f64::NAN, longitude: 4.8, radius_km: 5.0 }.validate().is_err());
+        assert!(GeoFilter::BoundingBox { south: 43.0, west: f64::NEG_INFINITY, north: 44.5, east: 7.0 }.validate().is_err());
+    }
+}
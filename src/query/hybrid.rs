@@ -0,0 +1,219 @@
+//! Hybrid retrieval: embedding similarity blended with graph proximity
+//!
+//! RAG-style consumers want nodes that are both about the question and
+//! close to what they already have in hand. A `HybridQuery` scores each
+//! candidate by a weighted sum of its cosine similarity to a query vector
+//! and its proximity to seed nodes, measured by PageRank personalized to
+//! the seeds and scaled so the closest non-seed node scores 1. Each hit
+//! carries both components and its hop distance from the nearest seed,
+//! so a consumer can say why it was retrieved.
+
+use super::analytics::{personalized_pagerank, PageRankConfig};
+use crate::adapter::VectorStore;
+use crate::graph::{Context, Node, NodeId};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// A ranked retrieval over similarity and proximity.
+#[derive(Debug, Clone)]
+pub struct HybridQuery {
+    /// Embedding of the question; empty skips similarity
+    pub query_vector: Vec<f32>,
+    /// Nodes proximity is measured from; empty skips proximity
+    pub seeds: Vec<NodeId>,
+    pub similarity_weight: f64,
+    pub proximity_weight: f64,
+    /// Only return nodes of this type
+    pub node_type: Option<String>,
+    /// Return the seeds themselves when they score
+    pub include_seeds: bool,
+    pub limit: usize,
+    pub pagerank: PageRankConfig,
+}
+
+impl HybridQuery {
+    pub fn new(query_vector: Vec<f32>) -> Self {
+        Self {
+            query_vector,
+            seeds: Vec::new(),
+            similarity_weight: 0.5,
+            proximity_weight: 0.5,
+            node_type: None,
+            include_seeds: false,
+            limit: 10,
+            pagerank: PageRankConfig::default(),
+        }
+    }
+
+    pub fn with_seeds(mut self, seeds: Vec<NodeId>) -> Self {
+        self.seeds = seeds;
+        self
+    }
+
+    pub fn with_weights(mut self, similarity: f64, proximity: f64) -> Self {
+        self.similarity_weight = similarity;
+        self.proximity_weight = proximity;
+        self
+    }
+
+    pub fn with_node_type(mut self, node_type: impl Into<String>) -> Self {
+        self.node_type = Some(node_type.into());
+        self
+    }
+
+    pub fn including_seeds(mut self) -> Self {
+        self.include_seeds = true;
+        self
+    }
+
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Rank `context`'s nodes, reading their embeddings from `vectors`
+    /// (keyed by context ID, as the embedding enrichment stores them).
+    pub fn execute(&self, context: &Context, vectors: &dyn VectorStore) -> HybridResult {
+        let similarity: HashMap<NodeId, f32> = if self.query_vector.is_empty() {
+            HashMap::new()
+        } else {
+            vectors.find_similar(context.id.as_str(), &self.query_vector, f32::MIN).into_iter().collect()
+        };
+        let ranks = if self.seeds.is_empty() {
+            HashMap::new()
+        } else {
+            personalized_pagerank(context, &self.seeds, &self.pagerank)
+        };
+        let seeds: HashSet<&NodeId> = self.seeds.iter().collect();
+        let top_rank = ranks
+            .iter()
+            .filter(|(id, _)| !seeds.contains(id))
+            .map(|(_, rank)| *rank)
+            .fold(0.0, f64::max);
+        let hops = seed_distances(context, &self.seeds);
+
+        let mut hits: Vec<HybridHit> = context
+            .nodes()
+            .filter(|node| self.include_seeds || !seeds.contains(&node.id))
+            .filter(|node| self.node_type.as_ref().is_none_or(|t| &node.node_type == t))
+            .filter_map(|node| {
+                let similarity = similarity.get(&node.id).copied();
+                let proximity = match ranks.get(&node.id) {
+                    Some(rank) if top_rank > 0.0 => (rank / top_rank).min(1.0),
+                    _ => 0.0,
+                };
+                if similarity.is_none() && proximity == 0.0 {
+                    return None;
+                }
+                let score = self.similarity_weight * similarity.map_or(0.0, |s| s.max(0.0) as f64)
+                    + self.proximity_weight * proximity;
+                let (nearest_seed, hops) = match hops.get(&node.id) {
+                    Some((seed, hops)) => (Some(seed.clone()), Some(*hops)),
+                    None => (None, None),
+                };
+                Some(HybridHit { node: node.clone(), score, similarity, proximity, nearest_seed, hops })
+            })
+            .collect();
+        let candidates = hits.len();
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.node.id.as_str().cmp(b.node.id.as_str())));
+        hits.truncate(self.limit);
+        HybridResult { hits, candidates }
+    }
+}
+
+/// Hop distance from the nearest seed, edges taken as undirected.
+fn seed_distances(context: &Context, seeds: &[NodeId]) -> HashMap<NodeId, (NodeId, usize)> {
+    let mut neighbors: HashMap<&NodeId, Vec<&NodeId>> = HashMap::new();
+    for edge in context.edges() {
+        neighbors.entry(&edge.source).or_default().push(&edge.target);
+        neighbors.entry(&edge.target).or_default().push(&edge.source);
+    }
+    let mut distances: HashMap<NodeId, (NodeId, usize)> = HashMap::new();
+    let mut queue: VecDeque<&NodeId> = VecDeque::new();
+    for seed in seeds.iter().filter(|s| context.get_node(s).is_some()) {
+        if !distances.contains_key(seed) {
+            distances.insert(seed.clone(), (seed.clone(), 0));
+            queue.push_back(seed);
+        }
+    }
+    while let Some(id) = queue.pop_front() {
+        let (seed, hops) = distances[id].clone();
+        for next in neighbors.get(id).into_iter().flatten() {
+            if !distances.contains_key(*next) {
+                distances.insert((*next).clone(), (seed.clone(), hops + 1));
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+/// One ranked node and why it ranked.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct HybridHit {
+    pub node: Node,
+    /// Weighted sum of the components below
+    pub score: f64,
+    /// Cosine similarity to the query vector, when the node has an embedding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub similarity: Option<f32>,
+    /// Personalized PageRank relative to the closest non-seed node, 0–1
+    pub proximity: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nearest_seed: Option<NodeId>,
+    /// Hops from `nearest_seed`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hops: Option<usize>,
+}
+
+/// Result of a hybrid query.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct HybridResult {
+    /// Best first, at most `limit`
+    pub hits: Vec<HybridHit>,
+    /// Nodes that scored at all, before the limit
+    pub candidates: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::InMemoryVectorStore;
+    use crate::graph::{ContentType, Edge};
+
+    fn concept(ctx: &mut Context, id: &str) -> NodeId {
+        let mut node = Node::new("concept", ContentType::Concept);
+        node.id = NodeId::from_string(id);
+        ctx.add_node(node)
+    }
+
+    // === Scenario: Proximity breaks ties between equally similar nodes ===
+    #[test]
+    fn hybrid_blends_similarity_and_proximity() {
+        let mut ctx = Context::new("hybrid");
+        let [seed, near, far, unrelated] = ["seed", "near", "far", "unrelated"].map(|id| concept(&mut ctx, id));
+        let hub = concept(&mut ctx, "hub");
+        ctx.add_edge(Edge::new(seed.clone(), near.clone(), "related_to"));
+        ctx.add_edge(Edge::new(seed.clone(), hub.clone(), "related_to"));
+        ctx.add_edge(Edge::new(hub.clone(), far.clone(), "related_to"));
+
+        let store = InMemoryVectorStore::new();
+        store.store(ctx.id.as_str(), &near, vec![1.0, 0.0]);
+        store.store(ctx.id.as_str(), &far, vec![1.0, 0.0]);
+        store.store(ctx.id.as_str(), &unrelated, vec![0.0, 1.0]);
+
+        let result = HybridQuery::new(vec![1.0, 0.0]).with_seeds(vec![seed.clone()]).execute(&ctx, &store);
+        let ids: Vec<&str> = result.hits.iter().map(|h| h.node.id.as_str()).collect();
+        assert_eq!(ids[0], "near", "as similar as far, but closer to the seed");
+        assert!(ids.iter().position(|id| *id == "far") < ids.iter().position(|id| *id == "unrelated"));
+        assert!(!ids.contains(&"seed"));
+
+        let far_hit = result.hits.iter().find(|h| h.node.id == far).unwrap();
+        assert_eq!(far_hit.similarity, Some(1.0));
+        assert_eq!((far_hit.nearest_seed.as_ref(), far_hit.hops), (Some(&seed), Some(2)));
+        assert_eq!(result.candidates, 4, "hub scores on proximity alone");
+
+        let similarity_only = HybridQuery::new(vec![0.0, 1.0]).with_limit(1).execute(&ctx, &store);
+        assert_eq!(similarity_only.hits[0].node.id, unrelated);
+        assert_eq!(similarity_only.hits[0].proximity, 0.0);
+    }
+}
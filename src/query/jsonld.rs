@@ -0,0 +1,313 @@
+//! JSON-LD serialization of nodes, edges and subgraphs
+//!
+//! Shares its IRIs with the RDF export (`RdfVocabulary`), so a node has
+//! the same identity in Turtle and JSON-LD. The `@context` depends only on
+//! the vocabulary, never on the data, so it can be published once and
+//! referenced by URL (`with_context_url`) instead of inlined.
+//!
+//! - A node carries its type, dimension and properties, its outgoing
+//!   edges as links named by relationship, and `provenance` links to the
+//!   marks that cite it (the same marks `explain_node` reports).
+//! - An edge is an `rdf:Statement` with its weight and per-contributor
+//!   values, which plain links cannot carry.
+//! - A subgraph is a `@graph` of both, restricted to the chosen nodes.
+//!
+//! Properties named like a JSON-LD keyword or one of the writer's own
+//! terms (`dimension`, `provenance`, ...), and relationships named like
+//! one of those or like one of the node's properties, are keyed by their
+//! full IRI instead, so nothing overwrites anything else.
+
+use super::explain::provenance_for;
+use super::rdf::{RdfVocabulary, RDF, RDFS, XSD};
+use crate::graph::{Context, Edge, Node, NodeId, PropertyValue};
+use serde_json::{json, Map, Value};
+use std::collections::{HashMap, HashSet};
+
+/// Terms the `@context` defines itself; properties and relationships
+/// with these names are written under their IRIs.
+const RESERVED_TERMS: &[&str] = &["dimension", "provenance", "weight", "created_at", "contributions", "rdf", "rdfs", "xsd"];
+
+fn is_reserved(key: &str) -> bool {
+    key.starts_with('@') || RESERVED_TERMS.contains(&key)
+}
+
+/// JSON-LD writer over a vocabulary.
+#[derive(Debug, Clone, Default)]
+pub struct JsonLd {
+    vocabulary: RdfVocabulary,
+    context_url: Option<String>,
+}
+
+impl JsonLd {
+    pub fn new(vocabulary: RdfVocabulary) -> Self {
+        Self { vocabulary, context_url: None }
+    }
+
+    /// Reference a published copy of `context_document` instead of inlining it.
+    pub fn with_context_url(mut self, url: impl Into<String>) -> Self {
+        self.context_url = Some(url.into());
+        self
+    }
+
+    /// The `@context` document for this vocabulary.
+    pub fn context_document(&self) -> Value {
+        let vocab = &self.vocabulary;
+        let mut terms = Map::new();
+        terms.insert("@vocab".into(), json!(vocab.vocab));
+        terms.insert("rdf".into(), json!(RDF));
+        terms.insert("rdfs".into(), json!(RDFS));
+        terms.insert("xsd".into(), json!(XSD));
+        for (prefix, namespace) in &vocab.prefixes {
+            terms.insert(prefix.clone(), json!(namespace));
+        }
+        let link = |iri: String| json!({ "@id": iri, "@type": "@id" });
+        let typed = |iri: String, datatype: &str| json!({ "@id": iri, "@type": datatype });
+        terms.insert("dimension".into(), link(format!("{}dimension", vocab.vocab)));
+        terms.insert("provenance".into(), link(format!("{}provenance", vocab.vocab)));
+        terms.insert("weight".into(), typed(format!("{}weight", vocab.vocab), &format!("{}double", XSD)));
+        terms.insert("created_at".into(), typed(format!("{}created_at", vocab.vocab), &format!("{}dateTime", XSD)));
+        let mut keys: Vec<&String> = vocab.properties.keys().collect();
+        keys.sort();
+        for key in keys {
+            terms.insert(key.clone(), json!(vocab.property_iri(key)));
+        }
+        let mut relationships: Vec<&String> = vocab.relationships.keys().collect();
+        relationships.sort();
+        for relationship in relationships {
+            terms.insert(relationship.clone(), link(vocab.relationship_iri(relationship)));
+        }
+        json!({ "@context": terms })
+    }
+
+    /// A single node, with its outgoing links and provenance.
+    pub fn node(&self, context: &Context, node_id: &NodeId) -> Option<Value> {
+        let node = context.get_node(node_id)?;
+        let links: Vec<&Edge> = context.edges().filter(|e| e.source == node.id).collect();
+        let mut value = self.node_object(context, node, &links);
+        self.attach_context(&mut value);
+        Some(value)
+    }
+
+    /// A single edge as a statement.
+    pub fn edge(&self, edge: &Edge) -> Value {
+        let mut value = self.edge_object(edge);
+        self.attach_context(&mut value);
+        value
+    }
+
+    /// The given nodes (all nodes when `None`) and the edges between them.
+    pub fn subgraph(&self, context: &Context, node_ids: Option<&[NodeId]>) -> Value {
+        let keep: Option<HashSet<&NodeId>> = node_ids.map(|ids| ids.iter().collect());
+        let kept = |id: &NodeId| keep.as_ref().is_none_or(|k| k.contains(id));
+        let mut nodes: Vec<&Node> = context.nodes().filter(|n| kept(&n.id)).collect();
+        nodes.sort_by(|a, b| a.id.as_str().cmp(b.id.as_str()));
+        let mut edges: Vec<&Edge> = context.edges().filter(|e| kept(&e.source) && kept(&e.target)).collect();
+        edges.sort_by(|a, b| a.id.as_str().cmp(b.id.as_str()));
+
+        let mut outgoing: HashMap<&NodeId, Vec<&Edge>> = HashMap::new();
+        for edge in &edges {
+            outgoing.entry(&edge.source).or_default().push(edge);
+        }
+        let graph: Vec<Value> = nodes
+            .into_iter()
+            .map(|n| self.node_object(context, n, outgoing.get(&n.id).map_or(&[], Vec::as_slice)))
+            .chain(edges.into_iter().map(|e| self.edge_object(e)))
+            .collect();
+        let mut value = json!({ "@graph": graph });
+        self.attach_context(&mut value);
+        value
+    }
+
+    fn attach_context(&self, value: &mut Value) {
+        let context = match &self.context_url {
+            Some(url) => json!(url),
+            None => self.context_document()["@context"].clone(),
+        };
+        if let Value::Object(map) = value {
+            map.insert("@context".into(), context);
+        }
+    }
+
+    /// `links` are the node's outgoing edges to include.
+    fn node_object(&self, context: &Context, node: &Node, links: &[&Edge]) -> Value {
+        let vocab = &self.vocabulary;
+        let mut object = Map::new();
+        object.insert("@id".into(), json!(vocab.node_iri(node.id.as_str())));
+        object.insert("@type".into(), json!(vocab.node_type_iri(&node.node_type)));
+        object.insert("dimension".into(), json!(vocab.dimension_iri(&node.dimension)));
+        for (key, value) in &node.properties {
+            if let Some(value) = literal(value) {
+                let key = if is_reserved(key) { vocab.property_iri(key) } else { key.clone() };
+                object.insert(key, value);
+            }
+        }
+
+        let mut links = links.to_vec();
+        links.sort_by(|a, b| a.target.as_str().cmp(b.target.as_str()));
+        for edge in links {
+            let target = json!({ "@id": vocab.node_iri(edge.target.as_str()) });
+            let key = match is_reserved(&edge.relationship) || node.properties.contains_key(&edge.relationship) {
+                true => vocab.relationship_iri(&edge.relationship),
+                false => edge.relationship.clone(),
+            };
+            match object.get_mut(&key) {
+                Some(Value::Array(targets)) => targets.push(target),
+                // A property under the same IRI keeps its value alongside the links
+                Some(existing) => *existing = json!([existing.take(), target]),
+                None => {
+                    object.insert(key, json!([target]));
+                }
+            }
+        }
+
+        let marks: Vec<Value> = provenance_for(context, &[&node.id])
+            .into_iter()
+            .filter(|c| c.mark_id != node.id)
+            .map(|c| json!(vocab.node_iri(c.mark_id.as_str())))
+            .collect();
+        if !marks.is_empty() {
+            object.insert("provenance".into(), json!(marks));
+        }
+        Value::Object(object)
+    }
+
+    fn edge_object(&self, edge: &Edge) -> Value {
+        let vocab = &self.vocabulary;
+        let mut contributions: Vec<(&String, &f32)> = edge.contributions.iter().collect();
+        contributions.sort_by(|a, b| a.0.cmp(b.0));
+        json!({
+            "@id": format!("{}edge:{}", vocab.base, edge.id.as_str()),
+            "@type": "rdf:Statement",
+            "rdf:subject": { "@id": vocab.node_iri(edge.source.as_str()) },
+            "rdf:predicate": { "@id": vocab.relationship_iri(&edge.relationship) },
+            "rdf:object": { "@id": vocab.node_iri(edge.target.as_str()) },
+            "weight": edge.combined_weight,
+            "created_at": edge.created_at.to_rfc3339(),
+            "contributions": contributions
+                .into_iter()
+                .map(|(adapter, value)| json!({ "contributor": adapter, "value": value }))
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
+/// A property as a JSON-LD value; `None` for values with no literal form.
+fn literal(value: &PropertyValue) -> Option<Value> {
+    Some(match value {
+        PropertyValue::String(s) => json!(s),
+        PropertyValue::Int(i) => json!(i),
+        PropertyValue::Float(f) => json!(f),
+        PropertyValue::Bool(b) => json!(b),
+        PropertyValue::DateTime(at) => json!({ "@value": at.to_rfc3339(), "@type": format!("{}dateTime", XSD) }),
+        PropertyValue::Bytes(bytes) => json!({
+            "@value": bytes.iter().map(|b| format!("{:02X}", b)).collect::<String>(),
+            "@type": format!("{}hexBinary", XSD),
+        }),
+        PropertyValue::Object(_) => json!({ "@value": serde_json::to_value(value).ok()?, "@type": "@json" }),
+        PropertyValue::Array(items) => Value::Array(items.iter().filter_map(literal).collect()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{dimension, ContentType};
+
+    fn graph() -> Context {
+        let mut ctx = Context::new("jsonld");
+        let mut concept = Node::new_in_dimension("concept", ContentType::Concept, dimension::SEMANTIC);
+        concept.id = NodeId::from_string("concept:privacy");
+        concept.properties.insert("label".into(), PropertyValue::from("privacy"));
+        ctx.add_node(concept);
+        let mut fragment = Node::new_in_dimension("fragment", ContentType::Document, dimension::STRUCTURE);
+        fragment.id = NodeId::from_string("fragment:1");
+        ctx.add_node(fragment);
+        let mut mark = Node::new_in_dimension("mark", ContentType::Provenance, dimension::PROVENANCE);
+        mark.id = NodeId::from_string("mark:1");
+        ctx.add_node(mark);
+        let mut tagged = Edge::new_cross_dimensional(
+            NodeId::from_string("fragment:1"),
+            dimension::STRUCTURE,
+            NodeId::from_string("concept:privacy"),
+            dimension::SEMANTIC,
+            "tagged_with",
+        );
+        tagged.contributions.insert("fragment-adapter".into(), 1.0);
+        ctx.add_edge(tagged);
+        ctx.add_edge(Edge::new_cross_dimensional(
+            NodeId::from_string("mark:1"),
+            dimension::PROVENANCE,
+            NodeId::from_string("concept:privacy"),
+            dimension::SEMANTIC,
+            "references",
+        ));
+        ctx
+    }
+
+    // === Scenario: A node is self-describing, with links and provenance ===
+    #[test]
+    fn node_carries_context_links_and_provenance() {
+        let ctx = graph();
+        let jsonld = JsonLd::new(RdfVocabulary::new().map_relationship("tagged_with", "http://purl.org/dc/terms/subject"));
+
+        let concept = jsonld.node(&ctx, &NodeId::from_string("concept:privacy")).unwrap();
+        assert_eq!(concept["@id"], "urn:plexus:node:concept:privacy");
+        assert_eq!(concept["@type"], "urn:plexus:vocab#concept");
+        assert_eq!(concept["label"], "privacy");
+        assert_eq!(concept["provenance"], json!(["urn:plexus:node:mark:1"]));
+        assert_eq!(concept["@context"]["label"], format!("{}label", RDFS));
+        assert_eq!(
+            concept["@context"]["tagged_with"],
+            json!({ "@id": "http://purl.org/dc/terms/subject", "@type": "@id" })
+        );
+
+        let fragment = jsonld.node(&ctx, &NodeId::from_string("fragment:1")).unwrap();
+        assert_eq!(fragment["tagged_with"], json!([{ "@id": "urn:plexus:node:concept:privacy" }]));
+        assert!(jsonld.node(&ctx, &NodeId::from_string("missing")).is_none());
+    }
+
+    // === Scenario: Subgraphs and edges, with a published context ===
+    #[test]
+    fn subgraph_restricts_edges_and_references_context_url() {
+        let ctx = graph();
+        let jsonld = JsonLd::new(RdfVocabulary::new()).with_context_url("https://example.org/plexus.jsonld");
+        let ids = [NodeId::from_string("fragment:1"), NodeId::from_string("concept:privacy")];
+
+        let doc = jsonld.subgraph(&ctx, Some(&ids));
+        assert_eq!(doc["@context"], "https://example.org/plexus.jsonld");
+        let graph = doc["@graph"].as_array().unwrap();
+        assert_eq!(graph.len(), 3, "two nodes and the edge between them");
+        let statement = graph.iter().find(|v| v["@type"] == "rdf:Statement").unwrap();
+        assert_eq!(statement["rdf:predicate"]["@id"], "urn:plexus:vocab#tagged_with");
+        assert_eq!(statement["weight"], 1.0);
+        assert_eq!(statement["contributions"][0]["contributor"], "fragment-adapter");
+
+        assert_eq!(jsonld.subgraph(&ctx, None)["@graph"].as_array().unwrap().len(), 5);
+    }
+
+    // === Scenario: Reserved and clashing names don't overwrite each other ===
+    #[test]
+    fn reserved_and_clashing_names_are_keyed_by_iri() {
+        let mut ctx = graph();
+        let fragment = ctx.get_node_mut(&NodeId::from_string("fragment:1")).unwrap();
+        fragment.properties.insert("@id".into(), PropertyValue::from("spoofed"));
+        fragment.properties.insert("dimension".into(), PropertyValue::from("mine"));
+        fragment.properties.insert("tagged_with".into(), PropertyValue::from("by hand"));
+        ctx.add_edge(Edge::new_cross_dimensional(
+            NodeId::from_string("fragment:1"),
+            dimension::STRUCTURE,
+            NodeId::from_string("mark:1"),
+            dimension::PROVENANCE,
+            "provenance",
+        ));
+
+        let fragment = JsonLd::new(RdfVocabulary::new()).node(&ctx, &NodeId::from_string("fragment:1")).unwrap();
+        assert_eq!(fragment["@id"], "urn:plexus:node:fragment:1");
+        assert_eq!(fragment["dimension"], "urn:plexus:vocab#dimension/structure");
+        assert_eq!(fragment["urn:plexus:vocab#%40id"], "spoofed");
+        assert_eq!(fragment["urn:plexus:vocab#dimension"], "mine");
+        assert_eq!(fragment["tagged_with"], "by hand");
+        assert_eq!(fragment["urn:plexus:vocab#tagged_with"], json!([{ "@id": "urn:plexus:node:concept:privacy" }]));
+        assert_eq!(fragment["urn:plexus:vocab#provenance"], json!([{ "@id": "urn:plexus:node:mark:1" }]));
+    }
+}
@@ -0,0 +1,343 @@
+//! Materialized views — saved queries whose result set is stored
+//!
+//! Expensive analytic views (cluster membership, hub lists) are read by
+//! UIs on every page load. A materialized view stores the node and edge
+//! IDs its query returned and is maintained from the `GraphEvent`s of
+//! each commit: find views are updated in place, and any change a view
+//! can't apply incrementally marks it stale until the next refresh.
+
+use crate::graph::events::GraphEvent;
+use crate::graph::{Context, Edge, EdgeId, Node, NodeId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use super::find::FindQuery;
+use super::saved::{SavedQuery, SavedQueryResult};
+
+/// A stored query result kept current from graph events.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaterializedView {
+    pub query: SavedQuery,
+    /// Member nodes, in result order as of the last refresh
+    pub node_ids: Vec<NodeId>,
+    /// Member edges (traverse views)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub edge_ids: Vec<EdgeId>,
+    /// When the query last ran in full
+    pub refreshed_at: Option<DateTime<Utc>>,
+    /// When membership last changed incrementally
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maintained_at: Option<DateTime<Utc>>,
+    /// Whether graph changes since the refresh weren't applied
+    #[serde(default)]
+    pub stale: bool,
+    /// Graph events seen since the last refresh
+    #[serde(default)]
+    pub events_since_refresh: usize,
+}
+
+/// A view's members resolved against the current graph, with staleness.
+#[derive(Debug, Clone, Serialize)]
+pub struct ViewSnapshot {
+    pub name: String,
+    pub nodes: Vec<Node>,
+    pub edges: Vec<Edge>,
+    pub refreshed_at: Option<DateTime<Utc>>,
+    pub maintained_at: Option<DateTime<Utc>>,
+    pub stale: bool,
+    pub events_since_refresh: usize,
+}
+
+impl MaterializedView {
+    /// Materialize `query` against `context`.
+    pub fn new(query: SavedQuery, context: &Context) -> Self {
+        let mut view = Self {
+            query,
+            node_ids: Vec::new(),
+            edge_ids: Vec::new(),
+            refreshed_at: None,
+            maintained_at: None,
+            stale: false,
+            events_since_refresh: 0,
+        };
+        view.refresh(context);
+        view
+    }
+
+    /// Re-run the query in full.
+    pub fn refresh(&mut self, context: &Context) {
+        match self.query.execute(context) {
+            SavedQueryResult::Find(result) => {
+                self.node_ids = result.nodes.into_iter().map(|n| n.id).collect();
+                self.edge_ids.clear();
+            }
+            SavedQueryResult::Traverse(result) => {
+                self.node_ids = result.levels.into_iter().flatten().map(|n| n.id).collect();
+                self.edge_ids = result.edges.into_iter().map(|e| e.id).collect();
+            }
+        }
+        self.refreshed_at = Some(Utc::now());
+        self.maintained_at = None;
+        self.stale = false;
+        self.events_since_refresh = 0;
+    }
+
+    /// Apply one commit's events: update membership in place where the
+    /// query allows it, otherwise mark the view stale.
+    pub fn apply_events(&mut self, events: &[GraphEvent], context: &Context) {
+        self.events_since_refresh += events.len();
+        if self.stale {
+            return;
+        }
+        let changed = match &self.query {
+            SavedQuery::Find(query) if query.limit.is_some() || query.offset.is_some() => {
+                // Paging depends on the full match order
+                self.stale = true;
+                false
+            }
+            SavedQuery::Find(query) => {
+                let query = query.clone();
+                let mut changed = false;
+                for event in events {
+                    changed |= self.apply_find_event(&query, event, context);
+                }
+                changed
+            }
+            SavedQuery::Traverse(_) => {
+                self.stale = events.iter().any(|event| self.traverse_affected_by(event, context));
+                false
+            }
+        };
+        if changed {
+            self.maintained_at = Some(Utc::now());
+        }
+    }
+
+    /// Returns whether membership changed.
+    fn apply_find_event(&mut self, query: &FindQuery, event: &GraphEvent, context: &Context) -> bool {
+        let candidates: Vec<NodeId> = match event {
+            GraphEvent::NodesAdded { node_ids, .. } => node_ids.clone(),
+            GraphEvent::NodePropertiesChanged { changes, .. } => changes.iter().map(|(id, _)| id.clone()).collect(),
+            GraphEvent::ContextMetadataChanged { .. } => return false,
+            GraphEvent::NodesRemoved { node_ids, .. } => {
+                let before = self.node_ids.len();
+                self.node_ids.retain(|id| !node_ids.contains(id));
+                return self.node_ids.len() != before;
+            }
+            // Edge changes only matter through the incident-edge filter
+            _ if query.filter.is_none() => return false,
+            GraphEvent::EdgesAdded { edge_ids, .. }
+            | GraphEvent::WeightsChanged { edge_ids, .. }
+            | GraphEvent::EdgeAnnotated { edge_ids, .. } => context
+                .edges
+                .iter()
+                .filter(|e| edge_ids.contains(&e.id))
+                .flat_map(|e| [e.source.clone(), e.target.clone()])
+                .collect(),
+            // Removed edges are gone; members are the only nodes that can drop out
+            GraphEvent::EdgesRemoved { .. } | GraphEvent::ContributionsRetracted { .. } => {
+                self.node_ids.clone()
+            }
+        };
+
+        let mut changed = false;
+        for id in candidates {
+            let selected = context.get_node(&id).is_some_and(|node| query.selects(node, context));
+            let position = self.node_ids.iter().position(|member| *member == id);
+            match (selected, position) {
+                (true, None) => {
+                    self.node_ids.push(id);
+                    changed = true;
+                }
+                (false, Some(index)) => {
+                    self.node_ids.remove(index);
+                    changed = true;
+                }
+                _ => {}
+            }
+        }
+        changed
+    }
+
+    /// Whether an event can change a traversal's result.
+    fn traverse_affected_by(&self, event: &GraphEvent, context: &Context) -> bool {
+        match event {
+            // A new node only joins through an edge to a member
+            GraphEvent::NodesAdded { .. } | GraphEvent::ContextMetadataChanged { .. } => false,
+            // Traversal filters may read the changed properties
+            GraphEvent::NodePropertiesChanged { changes, .. } => {
+                changes.iter().any(|(id, _)| self.node_ids.contains(id))
+            }
+            GraphEvent::NodesRemoved { node_ids, .. } => {
+                node_ids.iter().any(|id| self.node_ids.contains(id))
+            }
+            GraphEvent::EdgesAdded { edge_ids, .. }
+            | GraphEvent::WeightsChanged { edge_ids, .. }
+            | GraphEvent::EdgeAnnotated { edge_ids, .. } => {
+                context.edges.iter().filter(|e| edge_ids.contains(&e.id)).any(|e| {
+                    self.node_ids.contains(&e.source) || self.node_ids.contains(&e.target)
+                })
+            }
+            GraphEvent::EdgesRemoved { edge_ids, .. } => {
+                edge_ids.iter().any(|id| self.edge_ids.contains(id))
+            }
+            GraphEvent::ContributionsRetracted { .. } => true,
+        }
+    }
+
+    /// Resolve members against the current graph. Members removed since
+    /// the last refresh are skipped.
+    pub fn snapshot(&self, name: &str, context: &Context) -> ViewSnapshot {
+        ViewSnapshot {
+            name: name.to_string(),
+            nodes: self
+                .node_ids
+                .iter()
+                .filter_map(|id| context.get_node(id).cloned())
+                .collect(),
+            edges: context
+                .edges
+                .iter()
+                .filter(|e| self.edge_ids.contains(&e.id))
+                .cloned()
+                .collect(),
+            refreshed_at: self.refreshed_at,
+            maintained_at: self.maintained_at,
+            stale: self.stale,
+            events_since_refresh: self.events_since_refresh,
+        }
+    }
+}
+
+/// Maintain every materialized view on `context` from one commit's events.
+pub(crate) fn maintain_views(context: &mut Context, events: &[GraphEvent]) {
+    if events.is_empty() || context.metadata.materialized_views.is_empty() {
+        return;
+    }
+    let mut views = std::mem::take(&mut context.metadata.materialized_views);
+    for view in views.values_mut() {
+        view.apply_events(events, context);
+    }
+    context.metadata.materialized_views = views;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{ContentType, PropertyValue};
+    use crate::query::{QueryFilter, TraverseQuery};
+
+    fn added(ids: &[&NodeId]) -> GraphEvent {
+        GraphEvent::NodesAdded {
+            node_ids: ids.iter().map(|id| (*id).clone()).collect(),
+            adapter_id: "test".into(),
+            context_id: "ctx".into(),
+        }
+    }
+
+    fn removed(ids: &[&NodeId]) -> GraphEvent {
+        GraphEvent::NodesRemoved {
+            node_ids: ids.iter().map(|id| (*id).clone()).collect(),
+            adapter_id: "test".into(),
+            context_id: "ctx".into(),
+        }
+    }
+
+    // === Scenario: Find views track node additions, updates, and removals ===
+    #[test]
+    fn find_view_updates_membership_incrementally() {
+        let mut ctx = Context::new("views");
+        let open = Node::new("thread", ContentType::Document)
+            .with_property("status", PropertyValue::from("open"));
+        let open_id = ctx.add_node(open);
+        let query = FindQuery::new()
+            .with_node_type("thread")
+            .with_property_value("status", PropertyValue::from("open"));
+        let mut view = MaterializedView::new(query.into(), &ctx);
+        assert_eq!(view.node_ids, vec![open_id.clone()]);
+
+        let late = ctx.add_node(
+            Node::new("thread", ContentType::Document).with_property("status", PropertyValue::from("open")),
+        );
+        view.apply_events(&[added(&[&late])], &ctx);
+        assert_eq!(view.node_ids, vec![open_id.clone(), late.clone()]);
+        assert!(view.maintained_at.is_some());
+
+        // A property update arrives as NodesAdded and drops the closed thread
+        ctx.get_node_mut(&open_id)
+            .unwrap()
+            .properties
+            .insert("status".into(), PropertyValue::from("closed"));
+        view.apply_events(&[added(&[&open_id])], &ctx);
+        assert_eq!(view.node_ids, vec![late.clone()]);
+
+        ctx.nodes.remove(&late);
+        view.apply_events(&[removed(&[&late])], &ctx);
+        assert!(view.node_ids.is_empty());
+        assert!(!view.stale);
+        assert_eq!(view.events_since_refresh, 3);
+    }
+
+    // === Scenario: Filtered find views re-check endpoints of new edges ===
+    #[test]
+    fn filtered_find_view_admits_nodes_gaining_a_passing_edge() {
+        let mut ctx = Context::new("views");
+        let a = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let b = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let query = FindQuery::new().with_node_type("concept").with_filter(QueryFilter {
+            relationship_prefix: Some("lens:".into()),
+            ..Default::default()
+        });
+        let mut view = MaterializedView::new(query.into(), &ctx);
+        assert!(view.node_ids.is_empty());
+
+        let edge = Edge::new(a.clone(), b.clone(), "lens:ui:related");
+        let edge_id = edge.id.clone();
+        ctx.add_edge(edge);
+        view.apply_events(
+            &[GraphEvent::EdgesAdded {
+                edge_ids: vec![edge_id],
+                adapter_id: "test".into(),
+                context_id: "ctx".into(),
+            }],
+            &ctx,
+        );
+
+        assert_eq!(view.node_ids.len(), 2);
+    }
+
+    // === Scenario: Traverse views go stale when their neighborhood changes ===
+    #[test]
+    fn traverse_view_marks_stale_and_refresh_recomputes() {
+        let mut ctx = Context::new("views");
+        let hub = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let spoke = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let far = ctx.add_node(Node::new("concept", ContentType::Concept));
+        ctx.add_edge(Edge::new(hub.clone(), spoke.clone(), "related"));
+        let mut view = MaterializedView::new(TraverseQuery::from(hub.clone()).into(), &ctx);
+        assert_eq!(view.node_ids.len(), 2);
+
+        // An unrelated node doesn't disturb the view
+        view.apply_events(&[added(&[&far])], &ctx);
+        assert!(!view.stale);
+
+        let edge = Edge::new(hub.clone(), far.clone(), "related");
+        let edge_id = edge.id.clone();
+        ctx.add_edge(edge);
+        view.apply_events(
+            &[GraphEvent::EdgesAdded {
+                edge_ids: vec![edge_id],
+                adapter_id: "test".into(),
+                context_id: "ctx".into(),
+            }],
+            &ctx,
+        );
+        assert!(view.stale);
+        assert_eq!(view.snapshot("hub", &ctx).nodes.len(), 2, "stale result is served as stored");
+
+        view.refresh(&ctx);
+        assert!(!view.stale);
+        assert_eq!(view.events_since_refresh, 0);
+        assert_eq!(view.snapshot("hub", &ctx).nodes.len(), 3);
+        assert_eq!(view.snapshot("hub", &ctx).edges.len(), 2);
+    }
+}
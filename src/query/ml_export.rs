@@ -0,0 +1,278 @@
+//! Graph export for GNN training (PyTorch Geometric, DGL)
+//!
+//! Writes a context as plain CSV tables that `numpy.loadtxt` and
+//! `pandas.read_csv` read directly:
+//!
+//! - `nodes.csv` — `idx,node_type,dimension,in_degree,out_degree,<features>`
+//! - `edges.csv` — `src,dst,relationship,weight` (an `edge_index` plus
+//!   `edge_attr`/`edge_type` in PyG terms)
+//! - `node_ids.csv` — `idx,node_id`, mapping rows back to `NodeId`s
+//! - `categories.csv` — `column,code,label`, decoding the integer-coded
+//!   `node_type`, `dimension` and `relationship` columns
+//!
+//! Node indices are dense from 0 in `NodeId` order, so an export of an
+//! unchanged context is byte-identical. Feature columns come from numeric
+//! node properties: scalars become one column, numeric arrays (stored
+//! embeddings, coordinates) one column per component. Missing values are
+//! written as `nan`.
+
+use crate::graph::{Context, Node, NodeId, PropertyValue};
+use std::collections::HashMap;
+use std::path::Path;
+
+pub const NODES_FILE: &str = "nodes.csv";
+pub const EDGES_FILE: &str = "edges.csv";
+pub const NODE_IDS_FILE: &str = "node_ids.csv";
+pub const CATEGORIES_FILE: &str = "categories.csv";
+
+/// Which part of a context to export, and which properties become features.
+#[derive(Debug, Clone, Default)]
+pub struct MlExport {
+    node_types: Vec<String>,
+    relationships: Vec<String>,
+    feature_properties: Vec<String>,
+}
+
+/// The exported tables, as CSV text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MlExportFiles {
+    pub nodes: String,
+    pub edges: String,
+    pub node_ids: String,
+    pub categories: String,
+    pub node_count: usize,
+    pub edge_count: usize,
+}
+
+impl MlExportFiles {
+    /// Write the four tables into `dir`, creating it if needed.
+    pub fn write_to(&self, dir: &Path) -> std::io::Result<()> {
+        std::fs::create_dir_all(dir)?;
+        std::fs::write(dir.join(NODES_FILE), &self.nodes)?;
+        std::fs::write(dir.join(EDGES_FILE), &self.edges)?;
+        std::fs::write(dir.join(NODE_IDS_FILE), &self.node_ids)?;
+        std::fs::write(dir.join(CATEGORIES_FILE), &self.categories)
+    }
+}
+
+/// Integer codes for a categorical column, in first-seen order.
+#[derive(Default)]
+struct Codes<'a> {
+    labels: Vec<&'a str>,
+    index: HashMap<&'a str, usize>,
+}
+
+impl<'a> Codes<'a> {
+    fn code(&mut self, label: &'a str) -> usize {
+        *self.index.entry(label).or_insert_with(|| {
+            self.labels.push(label);
+            self.labels.len() - 1
+        })
+    }
+}
+
+impl MlExport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only nodes of these types (default: all).
+    pub fn with_node_types(mut self, node_types: Vec<String>) -> Self {
+        self.node_types = node_types;
+        self
+    }
+
+    /// Only edges with these relationships (default: all).
+    pub fn with_relationships(mut self, relationships: Vec<String>) -> Self {
+        self.relationships = relationships;
+        self
+    }
+
+    /// Numeric node properties to export as feature columns.
+    pub fn with_feature_properties(mut self, properties: Vec<String>) -> Self {
+        self.feature_properties = properties;
+        self
+    }
+
+    pub fn execute(&self, context: &Context) -> MlExportFiles {
+        let mut nodes: Vec<&Node> = context
+            .nodes()
+            .filter(|n| self.node_types.is_empty() || self.node_types.iter().any(|t| t == &n.node_type))
+            .collect();
+        nodes.sort_by(|a, b| a.id.as_str().cmp(b.id.as_str()));
+        let index: HashMap<&NodeId, usize> = nodes.iter().enumerate().map(|(i, n)| (&n.id, i)).collect();
+
+        let mut edges: Vec<(usize, usize, &str, f32)> = context
+            .edges()
+            .filter(|e| self.relationships.is_empty() || self.relationships.iter().any(|r| r == &e.relationship))
+            .filter_map(|e| {
+                Some((*index.get(&e.source)?, *index.get(&e.target)?, e.relationship.as_str(), e.combined_weight))
+            })
+            .collect();
+        edges.sort_by(|a, b| (a.0, a.1, a.2).cmp(&(b.0, b.1, b.2)));
+
+        let mut in_degree = vec![0usize; nodes.len()];
+        let mut out_degree = vec![0usize; nodes.len()];
+        for (src, dst, _, _) in &edges {
+            out_degree[*src] += 1;
+            in_degree[*dst] += 1;
+        }
+
+        // Width of each feature: 1 for scalars, the longest array otherwise
+        let widths: Vec<Option<usize>> = self
+            .feature_properties
+            .iter()
+            .map(|key| {
+                nodes.iter().fold(None, |width, n| match n.properties.get(key) {
+                    Some(PropertyValue::Array(items)) => Some(width.unwrap_or(0).max(items.len())),
+                    _ => width,
+                })
+            })
+            .collect();
+
+        let mut node_type_codes = Codes::default();
+        let mut dimension_codes = Codes::default();
+        let mut relationship_codes = Codes::default();
+
+        let mut header = vec!["idx".to_string(), "node_type".into(), "dimension".into(), "in_degree".into(), "out_degree".into()];
+        for (key, width) in self.feature_properties.iter().zip(&widths) {
+            match width {
+                Some(width) => header.extend((0..*width).map(|i| format!("{}_{}", key, i))),
+                None => header.push(key.clone()),
+            }
+        }
+        let mut nodes_csv = header.iter().map(|h| csv_field(h)).collect::<Vec<_>>().join(",") + "\n";
+        let mut node_ids_csv = String::from("idx,node_id\n");
+        for (i, node) in nodes.iter().enumerate() {
+            let mut row = vec![
+                i.to_string(),
+                node_type_codes.code(&node.node_type).to_string(),
+                dimension_codes.code(&node.dimension).to_string(),
+                in_degree[i].to_string(),
+                out_degree[i].to_string(),
+            ];
+            for (key, width) in self.feature_properties.iter().zip(&widths) {
+                let value = node.properties.get(key);
+                match width {
+                    Some(width) => {
+                        let items = match value {
+                            Some(PropertyValue::Array(items)) => items.as_slice(),
+                            _ => &[],
+                        };
+                        row.extend((0..*width).map(|i| number(items.get(i))));
+                    }
+                    None => row.push(number(value)),
+                }
+            }
+            nodes_csv.push_str(&row.join(","));
+            nodes_csv.push('\n');
+            node_ids_csv.push_str(&format!("{},{}\n", i, csv_field(node.id.as_str())));
+        }
+
+        let mut edges_csv = String::from("src,dst,relationship,weight\n");
+        for (src, dst, relationship, weight) in &edges {
+            edges_csv.push_str(&format!("{},{},{},{}\n", src, dst, relationship_codes.code(relationship), weight));
+        }
+
+        let mut categories_csv = String::from("column,code,label\n");
+        for (column, codes) in [("node_type", &node_type_codes), ("dimension", &dimension_codes), ("relationship", &relationship_codes)] {
+            for (code, label) in codes.labels.iter().enumerate() {
+                categories_csv.push_str(&format!("{},{},{}\n", column, code, csv_field(label)));
+            }
+        }
+
+        MlExportFiles {
+            nodes: nodes_csv,
+            edges: edges_csv,
+            node_ids: node_ids_csv,
+            categories: categories_csv,
+            node_count: nodes.len(),
+            edge_count: edges.len(),
+        }
+    }
+}
+
+/// A property as a CSV number; `nan` when missing or non-numeric.
+fn number(value: Option<&PropertyValue>) -> String {
+    match value {
+        Some(PropertyValue::Int(i)) => i.to_string(),
+        Some(PropertyValue::Float(f)) if f.is_finite() => f.to_string(),
+        Some(PropertyValue::Bool(b)) => (*b as u8).to_string(),
+        _ => "nan".into(),
+    }
+}
+
+/// Quote a field when it holds a comma, quote or newline (RFC 4180).
+fn csv_field(value: &str) -> String {
+    match value.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", value.replace('"', "\"\"")),
+        false => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{dimension, ContentType, Edge};
+
+    fn node(ctx: &mut Context, id: &str, node_type: &str, properties: &[(&str, PropertyValue)]) {
+        let mut node = Node::new_in_dimension(node_type, ContentType::Document, dimension::SEMANTIC);
+        node.id = NodeId::from_string(id);
+        for (key, value) in properties {
+            node.properties.insert(key.to_string(), value.clone());
+        }
+        ctx.add_node(node);
+    }
+
+    fn edge(ctx: &mut Context, source: &str, target: &str, relationship: &str) {
+        ctx.add_edge(Edge::new(NodeId::from_string(source), NodeId::from_string(target), relationship));
+    }
+
+    fn graph() -> Context {
+        let mut ctx = Context::new("ml");
+        node(&mut ctx, "concept:a", "concept", &[("pagerank", PropertyValue::Float(0.5))]);
+        node(&mut ctx, "concept:b, quoted", "concept", &[
+            ("pagerank", PropertyValue::Int(1)),
+            ("embedding", PropertyValue::Array(vec![PropertyValue::Float(0.25), PropertyValue::Float(-1.0)])),
+        ]);
+        node(&mut ctx, "fragment:1", "fragment", &[("pagerank", PropertyValue::from("high"))]);
+        edge(&mut ctx, "concept:a", "concept:b, quoted", "may_be_related");
+        edge(&mut ctx, "fragment:1", "concept:a", "tagged_with");
+        ctx
+    }
+
+    // === Scenario: Exporting a graph as integer-indexed CSV tables ===
+    #[test]
+    fn export_maps_ids_to_dense_indices_with_features() {
+        let files = MlExport::new()
+            .with_feature_properties(vec!["pagerank".into(), "embedding".into()])
+            .execute(&graph());
+
+        assert_eq!((files.node_count, files.edge_count), (3, 2));
+        let nodes: Vec<&str> = files.nodes.lines().collect();
+        assert_eq!(nodes[0], "idx,node_type,dimension,in_degree,out_degree,pagerank,embedding_0,embedding_1");
+        assert_eq!(nodes[1], "0,0,0,1,1,0.5,nan,nan");
+        assert_eq!(nodes[2], "1,0,0,1,0,1,0.25,-1", "array features spread over columns");
+        assert_eq!(nodes[3], "2,1,0,0,1,nan,nan,nan", "non-numeric values are nan");
+
+        assert_eq!(files.edges, "src,dst,relationship,weight\n0,1,0,1\n2,0,1,1\n");
+        assert_eq!(files.node_ids.lines().nth(2), Some("1,\"concept:b, quoted\""));
+        assert!(files.categories.contains("node_type,1,fragment\n"));
+        assert!(files.categories.contains("relationship,1,tagged_with\n"));
+    }
+
+    // === Scenario: Exporting a subgraph ===
+    #[test]
+    fn filters_drop_nodes_and_their_edges() {
+        let dir = tempfile::tempdir().unwrap();
+        let files = MlExport::new().with_node_types(vec!["concept".into()]).execute(&graph());
+        files.write_to(&dir.path().join("out")).unwrap();
+
+        assert_eq!((files.node_count, files.edge_count), (2, 1), "tagged_with edge leaves with its fragment");
+        let edges = std::fs::read_to_string(dir.path().join("out").join(EDGES_FILE)).unwrap();
+        assert_eq!(edges, "src,dst,relationship,weight\n0,1,0,1\n");
+
+        let none = MlExport::new().with_relationships(vec!["cites".into()]).execute(&graph());
+        assert_eq!((none.node_count, none.edge_count), (3, 0));
+    }
+}
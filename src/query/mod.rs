@@ -3,24 +3,69 @@
 //! Provides capabilities for finding nodes, traversing edges,
 //! computing paths through the graph, and cursor-based change queries.
 
+mod analytics;
+mod backlinks;
+mod closure;
 mod cursor;
+mod distribution;
+mod drift;
+mod dossier;
 mod explain;
 mod filter;
 mod find;
+mod geo;
+mod hybrid;
+mod jsonld;
+mod materialized;
+mod ml_export;
 mod normalize;
+mod pack;
 mod path;
+mod rdf;
+mod reachability;
+mod saved;
 mod shared;
 mod step;
+mod timeline;
 mod traverse;
 mod types;
+mod vocabulary;
 
+pub use analytics::{DEFAULT_COMMUNITY_ITERATIONS, PageRankConfig, communities, pagerank, personalized_pagerank};
+pub use backlinks::{BacklinkConcept, Backlinks};
 pub use cursor::{PersistedEvent, ChangeSet, CursorFilter};
-pub use explain::{EdgeExplanation, ExplainedEdge, ExplainedNode, explain_pair};
+pub use drift::{
+    ConceptDrift, ConceptNeighborhood, DEFAULT_DRIFT_TOP_K, DriftReport, NeighborChange, NeighborShift, TimeWindow,
+};
+pub use distribution::{
+    DEFAULT_SUPER_NODE_DEGREE, GraphDistributions, Histogram, HistogramBucket, NodeDegree, TOP_DEGREE_NODES,
+    WEIGHT_BUCKETS, distributions, super_nodes,
+};
+pub(crate) use explain::provenance_for;
+pub use explain::{
+    ContributionShare, EdgeExplanation, ExplainedEdge, ExplainedNode, NodeExplanation, PathStep,
+    ProvenanceCitation, explain_node, explain_pair,
+};
+pub use dossier::{ConceptDossier, Definition, Mention, RelatedConcept, DEFAULT_DOSSIER_RELATED};
 pub use filter::{QueryFilter, RankBy};
-pub use find::FindQuery;
+pub use find::{CompareOp, FindQuery};
+pub use geo::{GeoFilter, LATITUDE_PROPERTY, LONGITUDE_PROPERTY, coordinates, haversine_km};
+pub use hybrid::{HybridHit, HybridQuery, HybridResult};
+pub use jsonld::JsonLd;
+pub use pack::{ContextPack, PackedContext, PackedEdge, PackedNode, estimate_tokens};
 pub use normalize::{NormalizationStrategy, NormalizedEdge, OutgoingDivisive, Softmax, normalized_weights};
-pub use path::PathQuery;
+pub use materialized::{MaterializedView, ViewSnapshot};
+pub use ml_export::{CATEGORIES_FILE, EDGES_FILE, MlExport, MlExportFiles, NODES_FILE, NODE_IDS_FILE};
+pub(crate) use materialized::maintain_views;
+pub use closure::TransitiveClosure;
+pub(crate) use closure::maintain_closures;
+pub use path::{PathConstraint, PathQuery};
+pub use reachability::{ReachabilityCache, ReachabilityFilter};
+pub use rdf::{RDF, RDFS, XSD, RdfExport, RdfFormat, RdfTerm, RdfVocabulary, Triple};
+pub use saved::{SavedQuery, SavedQueryResult};
 pub use step::{EvidenceTrailResult, StepQuery, StepResult, evidence_trail};
 pub use shared::shared_concepts;
-pub use traverse::TraverseQuery;
-pub use types::{QueryResult, TraversalResult, PathResult, Direction};
+pub use timeline::{TimeBucket, TimelineBucket, TimelineEntry, TimelineQuery, TimelineResult};
+pub use traverse::{HubDampening, TraverseQuery};
+pub use types::{QueryResult, TraversalResult, PathResult, Direction, HopScore, PathScoring, ScoredPath, sort_paths_by_score};
+pub use vocabulary::{TagStats, Trend, TREND_WINDOW_DAYS, vocabulary};
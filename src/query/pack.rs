@@ -0,0 +1,322 @@
+//! Context packing: the most relevant subgraph around seeds, within a
+//! token budget, serialized for a prompt
+//!
+//! Nodes are taken in order of PageRank personalized to the seeds (seeds
+//! first), each with a few display properties; edges between taken
+//! nodes follow, strongest first, with a rationale; then the marks
+//! citing taken nodes. Every item is costed by an estimate of its
+//! rendered size (about four characters per token) and skipped when it
+//! no longer fits, so the block never exceeds the budget.
+
+use super::analytics::{personalized_pagerank, PageRankConfig};
+use super::explain::{provenance_for, ProvenanceCitation};
+use crate::graph::{Context, Edge, Node, NodeId, PropertyValue};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// Properties shown for a packed node, in display order.
+pub const PACKED_PROPERTIES: &[&str] = &["label", "name", "title", "text", "annotation", "file", "line", "timecode"];
+
+/// Characters kept of any one property value.
+pub const MAX_PACKED_VALUE_CHARS: usize = 280;
+
+const SECTION_HEADERS: &str = "# Nodes\n# Edges\n# Provenance\n";
+
+/// Rough token count of rendered text.
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+/// What to pack: seeds and a budget.
+#[derive(Debug, Clone)]
+pub struct ContextPack {
+    pub seeds: Vec<NodeId>,
+    pub token_budget: usize,
+    /// Include marks citing packed nodes
+    pub provenance: bool,
+    pub pagerank: PageRankConfig,
+}
+
+impl ContextPack {
+    pub fn new(seeds: Vec<NodeId>, token_budget: usize) -> Self {
+        Self { seeds, token_budget, provenance: true, pagerank: PageRankConfig::default() }
+    }
+
+    pub fn without_provenance(mut self) -> Self {
+        self.provenance = false;
+        self
+    }
+
+    pub fn execute(&self, context: &Context) -> PackedContext {
+        let mut packed = PackedContext::default();
+        let headers = estimate_tokens(SECTION_HEADERS);
+        let mut budget = Budget { left: self.token_budget.saturating_sub(headers), used: headers };
+
+        // Seeds first, then everything reachable by proximity
+        let mut ranked: Vec<(NodeId, f64)> = personalized_pagerank(context, &self.seeds, &self.pagerank)
+            .into_iter()
+            .filter(|(_, rank)| *rank > 0.0)
+            .collect();
+        ranked.sort_by(|a, b| {
+            let seed = |id: &NodeId| self.seeds.contains(id);
+            seed(&b.0).cmp(&seed(&a.0)).then(b.1.total_cmp(&a.1)).then_with(|| a.0.as_str().cmp(b.0.as_str()))
+        });
+
+        let mut taken: HashSet<NodeId> = HashSet::new();
+        for (id, _) in &ranked {
+            let Some(node) = context.get_node(id) else { continue };
+            let entry = PackedNode::new(node);
+            if budget.take(&entry.render()) {
+                taken.insert(id.clone());
+                packed.nodes.push(entry);
+            } else {
+                packed.truncated = true;
+            }
+        }
+
+        let mut edges: Vec<&Edge> = context
+            .edges()
+            .filter(|e| e.source != e.target && taken.contains(&e.source) && taken.contains(&e.target))
+            .collect();
+        edges.sort_by(|a, b| b.combined_weight.total_cmp(&a.combined_weight).then_with(|| a.id.as_str().cmp(b.id.as_str())));
+        for edge in edges {
+            let entry = PackedEdge::new(edge);
+            if budget.take(&entry.render()) {
+                packed.edges.push(entry);
+            } else {
+                packed.truncated = true;
+            }
+        }
+
+        if self.provenance {
+            let ids: Vec<&NodeId> = packed.nodes.iter().map(|n| &n.id).collect();
+            for citation in provenance_for(context, &ids) {
+                if budget.take(&render_citation(&citation)) {
+                    packed.citations.push(citation);
+                } else {
+                    packed.truncated = true;
+                }
+            }
+        }
+        packed.estimated_tokens = budget.used;
+        packed
+    }
+}
+
+struct Budget {
+    left: usize,
+    used: usize,
+}
+
+impl Budget {
+    fn take(&mut self, rendered: &str) -> bool {
+        let cost = estimate_tokens(rendered) + 1;
+        if cost > self.left {
+            return false;
+        }
+        self.left -= cost;
+        self.used += cost;
+        true
+    }
+}
+
+/// A node as packed: identity and display properties.
+#[derive(Debug, Clone, Serialize)]
+pub struct PackedNode {
+    pub id: NodeId,
+    pub node_type: String,
+    /// `PACKED_PROPERTIES` the node has, values shortened
+    pub properties: Vec<(String, String)>,
+}
+
+impl PackedNode {
+    fn new(node: &Node) -> Self {
+        let properties = PACKED_PROPERTIES
+            .iter()
+            .filter_map(|key| {
+                let value = match node.properties.get(*key)? {
+                    PropertyValue::String(s) => s.clone(),
+                    PropertyValue::Int(i) => i.to_string(),
+                    PropertyValue::Float(f) => f.to_string(),
+                    _ => return None,
+                };
+                Some((key.to_string(), shorten(&value)))
+            })
+            .collect();
+        Self { id: node.id.clone(), node_type: node.node_type.clone(), properties }
+    }
+
+    fn render(&self) -> String {
+        let mut line = format!("- [{}] {}", self.id, self.node_type);
+        for (key, value) in &self.properties {
+            let _ = write!(line, " {key}={value:?}");
+        }
+        line
+    }
+}
+
+/// An edge as packed, with why it exists.
+#[derive(Debug, Clone, Serialize)]
+pub struct PackedEdge {
+    pub source: NodeId,
+    pub target: NodeId,
+    pub relationship: String,
+    pub weight: f32,
+    /// The edge's `rationale` property, or who asserted it
+    pub rationale: String,
+}
+
+impl PackedEdge {
+    fn new(edge: &Edge) -> Self {
+        let rationale = match edge.properties.get("rationale") {
+            Some(PropertyValue::String(s)) => shorten(s),
+            _ => {
+                let mut contributors: Vec<&str> = edge.contributions.keys().map(String::as_str).collect();
+                contributors.sort_unstable();
+                format!("asserted by {}", if contributors.is_empty() { "unknown".to_string() } else { contributors.join(", ") })
+            }
+        };
+        Self {
+            source: edge.source.clone(),
+            target: edge.target.clone(),
+            relationship: edge.relationship.clone(),
+            weight: edge.combined_weight,
+            rationale,
+        }
+    }
+
+    fn render(&self) -> String {
+        format!("- {} -{}-> {} ({:.2}; {})", self.source, self.relationship, self.target, self.weight, self.rationale)
+    }
+}
+
+fn render_citation(citation: &ProvenanceCitation) -> String {
+    let mut line = format!("- {} cites {}", citation.mark_id, citation.cites);
+    if let Some(ref file) = citation.file {
+        let _ = write!(line, ": {file}");
+        if let Some(l) = citation.line {
+            let _ = write!(line, ":{l}");
+        }
+    }
+    if let Some(ref annotation) = citation.annotation {
+        let _ = write!(line, " {:?}", shorten(annotation));
+    }
+    line
+}
+
+fn shorten(value: &str) -> String {
+    if value.chars().count() <= MAX_PACKED_VALUE_CHARS {
+        return value.to_string();
+    }
+    let mut short: String = value.chars().take(MAX_PACKED_VALUE_CHARS - 1).collect();
+    short.push('…');
+    short
+}
+
+/// The packed subgraph.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PackedContext {
+    pub nodes: Vec<PackedNode>,
+    pub edges: Vec<PackedEdge>,
+    pub citations: Vec<ProvenanceCitation>,
+    /// Estimated size of `to_text()`
+    pub estimated_tokens: usize,
+    /// Whether anything relevant was left out for budget
+    pub truncated: bool,
+}
+
+impl PackedContext {
+    /// A compact plain-text block: nodes, edges, provenance sections.
+    pub fn to_text(&self) -> String {
+        let mut text = String::from("# Nodes\n");
+        for node in &self.nodes {
+            text.push_str(&node.render());
+            text.push('\n');
+        }
+        if !self.edges.is_empty() {
+            text.push_str("# Edges\n");
+            for edge in &self.edges {
+                text.push_str(&edge.render());
+                text.push('\n');
+            }
+        }
+        if !self.citations.is_empty() {
+            text.push_str("# Provenance\n");
+            for citation in &self.citations {
+                text.push_str(&render_citation(citation));
+                text.push('\n');
+            }
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{dimension, ContentType};
+
+    fn concept(ctx: &mut Context, id: &str, label: &str) -> NodeId {
+        let mut node = Node::new_in_dimension("concept", ContentType::Concept, dimension::SEMANTIC);
+        node.id = NodeId::from_string(id);
+        node.properties.insert("label".into(), PropertyValue::from(label));
+        ctx.add_node(node)
+    }
+
+    fn context() -> (Context, NodeId) {
+        let mut ctx = Context::new("pack");
+        let rye = concept(&mut ctx, "concept:rye", "rye");
+        let starter = concept(&mut ctx, "concept:starter", "starter");
+        let far = concept(&mut ctx, "concept:oven", "oven");
+        concept(&mut ctx, "concept:unrelated", "unrelated");
+        let mut edge = Edge::new(rye.clone(), starter.clone(), "may_be_related");
+        edge.contributions.insert("co_occurrence".into(), 1.0);
+        edge.combined_weight = 1.0;
+        ctx.add_edge(edge);
+        let mut edge = Edge::new(starter, far, "may_be_related");
+        edge.properties.insert("rationale".into(), PropertyValue::from("baked together"));
+        edge.combined_weight = 0.5;
+        ctx.add_edge(edge);
+        let mut mark = Node::new_in_dimension("mark", ContentType::Provenance, dimension::PROVENANCE);
+        mark.id = NodeId::from_string("mark:note");
+        mark.properties.insert("file".into(), PropertyValue::from("bread.md"));
+        mark.properties.insert("line".into(), PropertyValue::Int(3));
+        mark.properties.insert("annotation".into(), PropertyValue::from("rye sours fast"));
+        let mark = ctx.add_node(mark);
+        ctx.add_edge(Edge::new(mark, rye.clone(), "references"));
+        (ctx, rye)
+    }
+
+    // === Scenario: A generous budget packs the seed's neighbourhood ===
+    #[test]
+    fn packs_nodes_edges_and_citations_around_seeds() {
+        let (ctx, rye) = context();
+        let packed = ContextPack::new(vec![rye.clone()], 1_000).execute(&ctx);
+
+        assert_eq!(packed.nodes[0].id, rye, "seeds come first");
+        assert!(!packed.nodes.iter().any(|n| n.id.as_str() == "concept:unrelated"));
+        assert!(!packed.truncated);
+        assert!(packed.edges.iter().any(|e| e.rationale == "asserted by co_occurrence"));
+        assert!(packed.edges.iter().any(|e| e.rationale == "baked together"));
+        assert_eq!(packed.citations.len(), 1);
+
+        let text = packed.to_text();
+        assert!(text.contains("- [concept:rye] concept label=\"rye\""), "{text}");
+        assert!(text.contains("- mark:note cites concept:rye: bread.md:3 \"rye sours fast\""), "{text}");
+        assert!(estimate_tokens(&text) <= packed.estimated_tokens);
+    }
+
+    // === Scenario: A tight budget keeps the most relevant items ===
+    #[test]
+    fn tight_budget_truncates_by_relevance() {
+        let (ctx, rye) = context();
+        let packed = ContextPack::new(vec![rye.clone()], 20).without_provenance().execute(&ctx);
+
+        assert!(packed.truncated);
+        assert!(packed.estimated_tokens <= 20);
+        assert_eq!(packed.nodes[0].id, rye);
+        assert!(packed.citations.is_empty());
+        assert!(ContextPack::new(vec![rye], 0).execute(&ctx).nodes.is_empty());
+    }
+}
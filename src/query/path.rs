@@ -1,9 +1,17 @@
 //! Path finding algorithms
 
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 use crate::graph::{Context, Edge, Node, NodeId};
+use super::explain::explain_node;
 use super::filter::QueryFilter;
-use super::types::{Direction, PathResult};
+use super::types::{sort_paths_by_score, Direction, HopWeights, PathResult, PathScoring, ScoredPath};
+
+/// Partial paths `execute_ranked` expands before giving up.
+const MAX_EXPANSIONS: usize = 100_000;
+
+/// Partial paths `execute_ranked` keeps waiting at once.
+const MAX_FRONTIER: usize = 10_000;
 
 /// Query for finding paths between nodes
 #[derive(Debug, Clone)]
@@ -20,6 +28,32 @@ pub struct PathQuery {
     pub relationship: Option<String>,
     /// Optional composable filter (ADR-034)
     pub filter: Option<QueryFilter>,
+    /// Attach a `NodeExplanation` per node after the source
+    pub explain: bool,
+    /// When non-empty, the path must take exactly these steps in order;
+    /// `direction`, `relationship`, and `max_length` are then ignored
+    pub pattern: Vec<PathConstraint>,
+}
+
+/// One step of a path pattern: the relationship and direction of the
+/// hop, and optionally the type of node it must reach.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathConstraint {
+    pub relationship: String,
+    pub direction: Direction,
+    pub node_type: Option<String>,
+}
+
+impl PathConstraint {
+    pub fn new(direction: Direction, relationship: impl Into<String>) -> Self {
+        Self { relationship: relationship.into(), direction, node_type: None }
+    }
+
+    /// Require the hop to reach a node of `node_type`.
+    pub fn to_node_type(mut self, node_type: impl Into<String>) -> Self {
+        self.node_type = Some(node_type.into());
+        self
+    }
 }
 
 impl PathQuery {
@@ -32,6 +66,8 @@ impl PathQuery {
             direction: Direction::Outgoing,
             relationship: None,
             filter: None,
+            explain: false,
+            pattern: Vec::new(),
         }
     }
 
@@ -59,8 +95,91 @@ impl PathQuery {
         self
     }
 
+    /// Explain each node on the found path: the hops that reached it,
+    /// their top contributions, and linked provenance marks
+    pub fn with_explanation(mut self) -> Self {
+        self.explain = true;
+        self
+    }
+
+    /// Add a pattern step. `mark -references-> concept <-references- mark`
+    /// is an outgoing `references` step to a `concept`, then an incoming
+    /// `references` step to a `mark`.
+    pub fn step(mut self, constraint: PathConstraint) -> Self {
+        self.pattern.push(constraint);
+        self
+    }
+
+    /// Up to `limit` simple paths within `max_length`, best score first
+    /// under `scoring`.
+    ///
+    /// Best-first search over partial paths: extending a path never raises
+    /// its product or min score, so the first paths to reach the target are
+    /// the best-scoring ones. The search expands at most `MAX_EXPANSIONS`
+    /// partial paths and keeps at most `MAX_FRONTIER` waiting, dropping the
+    /// weakest, so on large graphs it may miss some paths. Pattern queries
+    /// yield their one match.
+    pub fn execute_ranked(&self, context: &Context, limit: usize, scoring: PathScoring) -> Vec<ScoredPath> {
+        if !self.pattern.is_empty() || self.source == self.target {
+            let result = self.execute(context);
+            return if result.found && limit > 0 { vec![result.scored(context, scoring)] } else { Vec::new() };
+        }
+        if limit == 0 || context.get_node(&self.source).is_none() || context.get_node(&self.target).is_none() {
+            return Vec::new();
+        }
+        let edge_index = EdgeIndex::build(context, &self.relationship, &self.filter);
+        let weights = HopWeights::of(context);
+        let mut frontier = BinaryHeap::new();
+        frontier.push(Partial { score: 1.0, nodes: vec![self.source.clone()], edges: Vec::new() });
+        let mut paths = Vec::new();
+        let mut expansions = 0;
+
+        while let Some(partial) = frontier.pop() {
+            let current = partial.nodes.last().expect("partial paths start at the source");
+            if current == &self.target {
+                let nodes = partial.nodes.iter().filter_map(|id| context.get_node(id).cloned()).collect();
+                let edges = partial.edges.iter().map(|e| (*e).clone()).collect();
+                let result = self.finish(context, PathResult::found(nodes, edges));
+                paths.push(result.scored_by(&weights, scoring));
+                if paths.len() == limit {
+                    break;
+                }
+                continue;
+            }
+            if partial.edges.len() == self.max_length {
+                continue;
+            }
+            expansions += 1;
+            if expansions > MAX_EXPANSIONS {
+                break;
+            }
+            for edge in self.get_edges(current, &edge_index) {
+                let neighbor = if &edge.source == current { &edge.target } else { &edge.source };
+                if partial.nodes.contains(neighbor) {
+                    continue;
+                }
+                let mut next = partial.clone();
+                next.score = scoring.combine(partial.score, weights.share(edge, current));
+                next.nodes.push(neighbor.clone());
+                next.edges.push(edge);
+                frontier.push(next);
+            }
+            if frontier.len() > MAX_FRONTIER {
+                // Keep the stronger half
+                let mut kept = std::mem::take(&mut frontier).into_sorted_vec();
+                kept.drain(..kept.len() - MAX_FRONTIER / 2);
+                frontier = kept.into();
+            }
+        }
+        sort_paths_by_score(&mut paths);
+        paths
+    }
+
     /// Execute the path query (BFS for shortest path)
     pub fn execute(&self, context: &Context) -> PathResult {
+        if !self.pattern.is_empty() {
+            return self.execute_pattern(context);
+        }
         // Quick checks
         if self.source == self.target {
             if let Some(node) = context.get_node(&self.source) {
@@ -137,17 +256,67 @@ impl PathQuery {
         self.reconstruct_path(context, &predecessors)
     }
 
-    /// Get edges for a node based on direction
-    fn get_edges<'a>(&self, node_id: &NodeId, index: &'a EdgeIndex<'a>) -> Vec<&'a Edge> {
-        match self.direction {
-            Direction::Outgoing => index.outgoing(node_id),
-            Direction::Incoming => index.incoming(node_id),
-            Direction::Both => {
-                let mut edges = index.outgoing(node_id);
-                edges.extend(index.incoming(node_id));
-                edges
+    /// Step-by-step BFS: the frontier after step `i` is every node a path
+    /// matching the first `i` steps reaches. A matching path may revisit
+    /// a node (`mark -references-> concept <-references- mark` can return
+    /// to the source mark).
+    fn execute_pattern(&self, context: &Context) -> PathResult {
+        if context.get_node(&self.source).is_none() || context.get_node(&self.target).is_none() {
+            return PathResult::not_found();
+        }
+        let edge_index = EdgeIndex::build(context, &None, &self.filter);
+        let mut frontier: Vec<NodeId> = vec![self.source.clone()];
+        // Per step, how each reached node was reached
+        let mut reached: Vec<HashMap<NodeId, (NodeId, Edge)>> = Vec::new();
+
+        for constraint in &self.pattern {
+            let mut step: HashMap<NodeId, (NodeId, Edge)> = HashMap::new();
+            for current in &frontier {
+                for edge in edges_in_direction(constraint.direction, current, &edge_index) {
+                    if edge.relationship != constraint.relationship {
+                        continue;
+                    }
+                    let neighbor = if &edge.source == current { &edge.target } else { &edge.source };
+                    if step.contains_key(neighbor) {
+                        continue;
+                    }
+                    let allowed = match &constraint.node_type {
+                        Some(node_type) => context.get_node(neighbor).is_some_and(|n| &n.node_type == node_type),
+                        None => true,
+                    };
+                    if allowed {
+                        step.insert(neighbor.clone(), (current.clone(), edge.clone()));
+                    }
+                }
+            }
+            if step.is_empty() {
+                return PathResult::not_found();
             }
+            frontier = step.keys().cloned().collect();
+            reached.push(step);
+        }
+
+        if !reached.last().is_some_and(|last| last.contains_key(&self.target)) {
+            return PathResult::not_found();
         }
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        let mut current = self.target.clone();
+        for step in reached.iter().rev() {
+            let (previous, edge) = &step[&current];
+            nodes.push(context.get_node(&current).cloned().expect("reached nodes exist"));
+            edges.push(edge.clone());
+            current = previous.clone();
+        }
+        nodes.push(context.get_node(&self.source).cloned().expect("source exists"));
+        nodes.reverse();
+        edges.reverse();
+        self.finish(context, PathResult::found(nodes, edges))
+    }
+
+    /// Get edges for a node based on direction
+    fn get_edges<'a>(&self, node_id: &NodeId, index: &'a EdgeIndex<'a>) -> Vec<&'a Edge> {
+        edges_in_direction(self.direction, node_id, index)
     }
 
     /// Reconstruct the path from predecessors map
@@ -179,10 +348,65 @@ impl PathQuery {
         path_nodes.reverse();
         path_edges.reverse();
 
-        PathResult::found(path_nodes, path_edges)
+        self.finish(context, PathResult::found(path_nodes, path_edges))
+    }
+
+    /// Attach explanations to a found path when asked.
+    fn finish(&self, context: &Context, mut result: PathResult) -> PathResult {
+        if self.explain {
+            let mut reached = self.source.clone();
+            for hops in 1..=result.edges.len() {
+                let edge = &result.edges[hops - 1];
+                reached = if edge.source == reached { edge.target.clone() } else { edge.source.clone() };
+                let explanation = explain_node(context, &reached, &[result.edges[..hops].to_vec()]);
+                result.explanations.push(explanation);
+            }
+        }
+        result
+    }
+}
+
+fn edges_in_direction<'a>(direction: Direction, node_id: &NodeId, index: &'a EdgeIndex<'a>) -> Vec<&'a Edge> {
+    match direction {
+        Direction::Outgoing => index.outgoing(node_id),
+        Direction::Incoming => index.incoming(node_id),
+        Direction::Both => {
+            let mut edges = index.outgoing(node_id);
+            edges.extend(index.incoming(node_id));
+            edges
+        }
     }
 }
 
+/// A partial path on the best-first frontier, ordered by score and then
+/// by fewer hops.
+#[derive(Clone)]
+struct Partial<'a> {
+    score: f64,
+    nodes: Vec<NodeId>,
+    edges: Vec<&'a Edge>,
+}
+
+impl Ord for Partial<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score.total_cmp(&other.score).then(other.edges.len().cmp(&self.edges.len()))
+    }
+}
+
+impl PartialOrd for Partial<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Partial<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Partial<'_> {}
+
 /// Index for fast edge lookups with optional relationship filter
 struct EdgeIndex<'a> {
     outgoing: HashMap<NodeId, Vec<&'a Edge>>,
@@ -259,6 +483,113 @@ mod tests {
         (ctx, ids)
     }
 
+    fn weighted(source: &str, target: &str, weight: f32) -> Edge {
+        let mut edge = Edge::new(NodeId::from_string(source), NodeId::from_string(target), "related_to")
+            .with_contribution("reader", weight);
+        edge.combined_weight = weight;
+        edge
+    }
+
+    // === Scenario: Paths are scored by their normalized hop weights ===
+    #[test]
+    fn ranked_paths_prefer_the_strongest_connection() {
+        let mut ctx = Context::new("test");
+        for id in ["S", "X", "Z", "T"] {
+            let mut node = Node::new("concept", ContentType::Concept);
+            node.id = NodeId::from_string(id);
+            ctx.add_node(node);
+        }
+        // S's weight goes 3:1 to X over T; X splits evenly between T and Z
+        ctx.add_edge(weighted("S", "T", 1.0));
+        ctx.add_edge(weighted("S", "X", 3.0));
+        ctx.add_edge(weighted("X", "T", 1.0));
+        ctx.add_edge(weighted("X", "Z", 1.0));
+        let query = PathQuery::between(NodeId::from_string("S"), NodeId::from_string("T"));
+
+        let shortest = query.execute(&ctx).scored(&ctx, PathScoring::Product);
+        assert_eq!(shortest.path.length, 1);
+        assert!((shortest.score - 0.25).abs() < 1e-9);
+        assert_eq!(shortest.hop_scores[0].contributions["reader"], 1.0);
+
+        let ranked = query.execute_ranked(&ctx, 5, PathScoring::Product);
+        let scores: Vec<(usize, f64)> = ranked.iter().map(|p| (p.path.length, p.score)).collect();
+        assert_eq!(scores, vec![(2, 0.375), (1, 0.25)], "the detour through X is more trustworthy");
+        assert_eq!(ranked[0].hop_scores.iter().map(|h| h.normalized_weight).collect::<Vec<_>>(), vec![0.75, 0.5]);
+
+        let weakest_link = query.execute_ranked(&ctx, 1, PathScoring::Min);
+        assert_eq!(weakest_link.len(), 1);
+        assert_eq!(weakest_link[0].score, 0.5);
+    }
+
+    // === Scenario: A backward hop is scored by its target's incoming share ===
+    #[test]
+    fn backward_hops_use_the_incoming_share() {
+        let mut ctx = Context::new("test");
+        for id in ["S", "T", "U"] {
+            let mut node = Node::new("concept", ContentType::Concept);
+            node.id = NodeId::from_string(id);
+            ctx.add_node(node);
+        }
+        // T -> S is S's only incoming edge but a quarter of T's outgoing
+        ctx.add_edge(weighted("T", "S", 1.0));
+        ctx.add_edge(weighted("T", "U", 3.0));
+        let query = PathQuery::between(NodeId::from_string("S"), NodeId::from_string("T")).direction(Direction::Incoming);
+
+        let ranked = query.execute_ranked(&ctx, 1, PathScoring::Product);
+        assert_eq!(ranked[0].score, 1.0);
+        assert_eq!(query.execute(&ctx).scored(&ctx, PathScoring::Product).score, 1.0);
+    }
+
+    // === Scenario: Explained path covers each hop's endpoint ===
+    #[test]
+    fn explained_path_has_one_prefix_per_node() {
+        let (ctx, ids) = create_test_graph();
+        let result = PathQuery::between(ids[0].clone(), ids[3].clone())
+            .with_explanation()
+            .execute(&ctx);
+
+        assert_eq!(result.explanations.len(), 3);
+        let reached: Vec<&NodeId> = result.explanations.iter().map(|e| &e.node_id).collect();
+        assert_eq!(reached, vec![&ids[1], &ids[2], &ids[3]]);
+        assert_eq!(result.explanations[2].paths[0].len(), 3);
+        assert_eq!(result.explanations[2].paths[0][2].target, ids[3]);
+    }
+
+    // === Scenario: A relationship pattern selects a provenance-grounded path ===
+    #[test]
+    fn pattern_constrains_relationships_directions_and_node_types() {
+        let mut ctx = Context::new("test");
+        let mut add = |id: &str, node_type: &str| {
+            let mut node = Node::new(node_type, ContentType::Provenance);
+            node.id = NodeId::from_string(id);
+            ctx.add_node(node)
+        };
+        let [mark, concept, other, chain, stray] =
+            [("m1", "mark"), ("c", "concept"), ("m2", "mark"), ("chain", "chain"), ("x", "fragment")].map(|(id, t)| add(id, t));
+        ctx.add_edge(Edge::new(mark.clone(), concept.clone(), "references"));
+        ctx.add_edge(Edge::new(other.clone(), concept.clone(), "references"));
+        ctx.add_edge(Edge::new(stray.clone(), concept.clone(), "references"));
+        ctx.add_edge(Edge::new(chain.clone(), other.clone(), "contains"));
+        ctx.add_edge(Edge::new(mark.clone(), chain.clone(), "related_to"));
+
+        let grounded = PathQuery::between(mark.clone(), chain.clone())
+            .step(PathConstraint::new(Direction::Outgoing, "references").to_node_type("concept"))
+            .step(PathConstraint::new(Direction::Incoming, "references").to_node_type("mark"))
+            .step(PathConstraint::new(Direction::Incoming, "contains"))
+            .execute(&ctx);
+        assert!(grounded.found, "the shorter related_to hop doesn't match the pattern");
+        let ids: Vec<&NodeId> = grounded.path.iter().map(|n| &n.id).collect();
+        assert_eq!(ids, vec![&mark, &concept, &other, &chain]);
+        assert_eq!(grounded.edges[2].relationship, "contains");
+
+        let wrong_direction = PathQuery::between(mark.clone(), chain.clone())
+            .step(PathConstraint::new(Direction::Outgoing, "references"))
+            .step(PathConstraint::new(Direction::Incoming, "references").to_node_type("fragment"))
+            .step(PathConstraint::new(Direction::Incoming, "contains"))
+            .execute(&ctx);
+        assert!(!wrong_direction.found);
+    }
+
     #[test]
     fn test_path_same_node() {
         let (ctx, ids) = create_test_graph();
@@ -0,0 +1,499 @@
+//! RDF export — Turtle and N-Triples
+//!
+//! Each node becomes a subject IRI (the vocabulary's base plus its
+//! percent-encoded `NodeId`) typed by its node type, with its dimension
+//! and properties as further triples. Edges become `source relationship
+//! target` triples. Node types, relationships, dimensions and property
+//! keys map to IRIs through an `RdfVocabulary`; anything unmapped falls
+//! back to the vocabulary namespace, so a default export is complete and
+//! a configured one can speak schema.org, SKOS or a house ontology.
+//!
+//! Plain triples cannot carry an edge's weight. `with_reified_edges`
+//! additionally describes each edge as an `rdf:Statement` with a
+//! `weight`, for consumers that want it.
+
+use crate::graph::{Context, Edge, Node, PropertyValue};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+pub const RDF: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+pub const RDFS: &str = "http://www.w3.org/2000/01/rdf-schema#";
+pub const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+
+/// Output serialization.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RdfFormat {
+    #[default]
+    Turtle,
+    NTriples,
+}
+
+/// How Plexus names map to IRIs.
+///
+/// Mapped values are full IRIs or `prefix:local` names using a declared
+/// prefix. Deserializable, so a mapping can live in a config file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RdfVocabulary {
+    /// Prefix for node IRIs
+    pub base: String,
+    /// Namespace for unmapped types, relationships and properties
+    pub vocab: String,
+    pub prefixes: BTreeMap<String, String>,
+    pub node_types: HashMap<String, String>,
+    pub relationships: HashMap<String, String>,
+    pub dimensions: HashMap<String, String>,
+    pub properties: HashMap<String, String>,
+}
+
+impl Default for RdfVocabulary {
+    fn default() -> Self {
+        Self {
+            base: "urn:plexus:node:".into(),
+            vocab: "urn:plexus:vocab#".into(),
+            prefixes: BTreeMap::new(),
+            node_types: HashMap::new(),
+            relationships: HashMap::new(),
+            dimensions: HashMap::new(),
+            properties: HashMap::from([("label".to_string(), format!("{}label", RDFS))]),
+        }
+    }
+}
+
+impl RdfVocabulary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_base(mut self, base: impl Into<String>) -> Self {
+        self.base = base.into();
+        self
+    }
+
+    pub fn with_vocab(mut self, vocab: impl Into<String>) -> Self {
+        self.vocab = vocab.into();
+        self
+    }
+
+    pub fn with_prefix(mut self, prefix: impl Into<String>, namespace: impl Into<String>) -> Self {
+        self.prefixes.insert(prefix.into(), namespace.into());
+        self
+    }
+
+    pub fn map_node_type(mut self, node_type: impl Into<String>, iri: impl Into<String>) -> Self {
+        self.node_types.insert(node_type.into(), iri.into());
+        self
+    }
+
+    pub fn map_relationship(mut self, relationship: impl Into<String>, iri: impl Into<String>) -> Self {
+        self.relationships.insert(relationship.into(), iri.into());
+        self
+    }
+
+    pub fn map_dimension(mut self, dimension: impl Into<String>, iri: impl Into<String>) -> Self {
+        self.dimensions.insert(dimension.into(), iri.into());
+        self
+    }
+
+    pub fn map_property(mut self, key: impl Into<String>, iri: impl Into<String>) -> Self {
+        self.properties.insert(key.into(), iri.into());
+        self
+    }
+
+    /// IRI for a node.
+    pub fn node_iri(&self, node_id: &str) -> String {
+        format!("{}{}", self.base, encode(node_id))
+    }
+
+    pub fn node_type_iri(&self, node_type: &str) -> String {
+        self.lookup(&self.node_types, node_type, "")
+    }
+
+    pub fn relationship_iri(&self, relationship: &str) -> String {
+        self.lookup(&self.relationships, relationship, "")
+    }
+
+    pub fn dimension_iri(&self, dimension: &str) -> String {
+        self.lookup(&self.dimensions, dimension, "dimension/")
+    }
+
+    pub fn property_iri(&self, key: &str) -> String {
+        self.lookup(&self.properties, key, "")
+    }
+
+    /// Expand a `prefix:local` name over the declared prefixes.
+    pub fn expand(&self, name: &str) -> String {
+        match name.split_once(':') {
+            Some((prefix, local)) if !local.starts_with("//") => match self.prefixes.get(prefix) {
+                Some(namespace) => format!("{}{}", namespace, local),
+                None => name.to_string(),
+            },
+            _ => name.to_string(),
+        }
+    }
+
+    fn lookup(&self, map: &HashMap<String, String>, name: &str, path: &str) -> String {
+        match map.get(name) {
+            Some(mapped) => self.expand(mapped),
+            None => format!("{}{}{}", self.vocab, path, encode(name)),
+        }
+    }
+}
+
+/// An RDF term.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RdfTerm {
+    Iri(String),
+    Blank(String),
+    Literal { lexical: String, datatype: String },
+}
+
+impl RdfTerm {
+    fn literal(lexical: impl Into<String>, datatype: &str) -> Self {
+        Self::Literal { lexical: lexical.into(), datatype: datatype.to_string() }
+    }
+
+    /// A property value as a literal; arrays are handled by the caller.
+    fn from_property(value: &PropertyValue) -> Option<Self> {
+        Some(match value {
+            PropertyValue::String(s) => Self::literal(s.as_str(), &format!("{}string", XSD)),
+            PropertyValue::Int(i) => Self::literal(i.to_string(), &format!("{}integer", XSD)),
+            PropertyValue::Float(f) => Self::literal(double_lexical(*f), &format!("{}double", XSD)),
+            PropertyValue::Bool(b) => Self::literal(b.to_string(), &format!("{}boolean", XSD)),
+            PropertyValue::DateTime(at) => Self::literal(at.to_rfc3339(), &format!("{}dateTime", XSD)),
+            PropertyValue::Bytes(bytes) => Self::literal(
+                bytes.iter().map(|b| format!("{:02X}", b)).collect::<String>(),
+                &format!("{}hexBinary", XSD),
+            ),
+            PropertyValue::Object(_) => Self::literal(serde_json::to_string(value).ok()?, &format!("{}JSON", RDF)),
+            PropertyValue::Array(_) => return None,
+        })
+    }
+}
+
+/// One `subject predicate object` statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Triple {
+    pub subject: RdfTerm,
+    pub predicate: String,
+    pub object: RdfTerm,
+}
+
+/// Export a context as RDF.
+#[derive(Debug, Clone, Default)]
+pub struct RdfExport {
+    vocabulary: RdfVocabulary,
+    format: RdfFormat,
+    reify_edges: bool,
+}
+
+impl RdfExport {
+    pub fn new(vocabulary: RdfVocabulary) -> Self {
+        Self { vocabulary, ..Self::default() }
+    }
+
+    pub fn format(mut self, format: RdfFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Also describe each edge as an `rdf:Statement` carrying its weight.
+    pub fn with_reified_edges(mut self) -> Self {
+        self.reify_edges = true;
+        self
+    }
+
+    pub fn vocabulary(&self) -> &RdfVocabulary {
+        &self.vocabulary
+    }
+
+    /// The context's triples, nodes in `NodeId` order then edges.
+    pub fn triples(&self, context: &Context) -> Vec<Triple> {
+        let vocab = &self.vocabulary;
+        let mut nodes: Vec<&Node> = context.nodes().collect();
+        nodes.sort_by(|a, b| a.id.as_str().cmp(b.id.as_str()));
+        let mut edges: Vec<&Edge> = context.edges().collect();
+        edges.sort_by(|a, b| {
+            (a.source.as_str(), a.relationship.as_str(), a.target.as_str())
+                .cmp(&(b.source.as_str(), b.relationship.as_str(), b.target.as_str()))
+        });
+
+        let mut triples = Vec::new();
+        for node in nodes {
+            let subject = RdfTerm::Iri(vocab.node_iri(node.id.as_str()));
+            let mut push = |predicate: String, object: RdfTerm| {
+                triples.push(Triple { subject: subject.clone(), predicate, object })
+            };
+            push(format!("{}type", RDF), RdfTerm::Iri(vocab.node_type_iri(&node.node_type)));
+            push(format!("{}dimension", vocab.vocab), RdfTerm::Iri(vocab.dimension_iri(&node.dimension)));
+            let mut keys: Vec<&String> = node.properties.keys().collect();
+            keys.sort();
+            for key in keys {
+                let predicate = vocab.property_iri(key);
+                match &node.properties[key] {
+                    PropertyValue::Array(items) => {
+                        for object in items.iter().filter_map(RdfTerm::from_property) {
+                            push(predicate.clone(), object);
+                        }
+                    }
+                    value => {
+                        if let Some(object) = RdfTerm::from_property(value) {
+                            push(predicate, object);
+                        }
+                    }
+                }
+            }
+        }
+        for (i, edge) in edges.into_iter().enumerate() {
+            let (subject, predicate, object) = (
+                RdfTerm::Iri(vocab.node_iri(edge.source.as_str())),
+                vocab.relationship_iri(&edge.relationship),
+                RdfTerm::Iri(vocab.node_iri(edge.target.as_str())),
+            );
+            if self.reify_edges {
+                let statement = RdfTerm::Blank(format!("e{}", i));
+                let describe = [
+                    (format!("{}type", RDF), RdfTerm::Iri(format!("{}Statement", RDF))),
+                    (format!("{}subject", RDF), subject.clone()),
+                    (format!("{}predicate", RDF), RdfTerm::Iri(predicate.clone())),
+                    (format!("{}object", RDF), object.clone()),
+                    (
+                        format!("{}weight", vocab.vocab),
+                        RdfTerm::literal(double_lexical(f64::from(edge.combined_weight)), &format!("{}double", XSD)),
+                    ),
+                ];
+                triples.extend(
+                    describe.into_iter().map(|(p, o)| Triple { subject: statement.clone(), predicate: p, object: o }),
+                );
+            }
+            triples.push(Triple { subject, predicate, object });
+        }
+        triples
+    }
+
+    /// Serialize the context in the configured format.
+    pub fn execute(&self, context: &Context) -> String {
+        let triples = self.triples(context);
+        match self.format {
+            RdfFormat::NTriples => triples
+                .iter()
+                .map(|t| format!("{} <{}> {} .\n", ntriples_term(&t.subject), escape_iri(&t.predicate), ntriples_term(&t.object)))
+                .collect(),
+            RdfFormat::Turtle => self.turtle(&triples),
+        }
+    }
+
+    fn turtle(&self, triples: &[Triple]) -> String {
+        let mut prefixes: BTreeMap<&str, &str> =
+            BTreeMap::from([("rdf", RDF), ("rdfs", RDFS), ("xsd", XSD), ("plexus", self.vocabulary.vocab.as_str())]);
+        for (prefix, namespace) in &self.vocabulary.prefixes {
+            prefixes.insert(prefix, namespace);
+        }
+        let compact = |iri: &str| -> String {
+            if iri == format!("{}type", RDF) {
+                return "a".into();
+            }
+            prefixes
+                .iter()
+                .find_map(|(prefix, namespace)| {
+                    let local = iri.strip_prefix(namespace)?;
+                    let simple = local.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                        && local.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+                    simple.then(|| format!("{}:{}", prefix, local))
+                })
+                .unwrap_or_else(|| format!("<{}>", escape_iri(iri)))
+        };
+        let term = |t: &RdfTerm| match t {
+            RdfTerm::Iri(iri) => compact(iri),
+            other => turtle_literal(other, &compact),
+        };
+
+        let mut out: String = prefixes.iter().map(|(p, ns)| format!("@prefix {}: <{}> .\n", p, escape_iri(ns))).collect();
+        let mut last_subject: Option<&RdfTerm> = None;
+        for triple in triples {
+            let object = match &triple.object {
+                // `a` only abbreviates in predicate position
+                RdfTerm::Iri(iri) if compact(iri) == "a" => format!("<{}>", escape_iri(iri)),
+                other => term(other),
+            };
+            match last_subject {
+                Some(subject) if subject == &triple.subject => {
+                    out.push_str(&format!(" ;\n    {} {}", compact(&triple.predicate), object));
+                }
+                _ => {
+                    if last_subject.is_some() {
+                        out.push_str(" .\n");
+                    }
+                    out.push_str(&format!("\n{} {} {}", term(&triple.subject), compact(&triple.predicate), object));
+                }
+            }
+            last_subject = Some(&triple.subject);
+        }
+        if last_subject.is_some() {
+            out.push_str(" .\n");
+        }
+        out
+    }
+}
+
+/// Percent-encode everything outside unreserved characters, `:` and `/`.
+fn encode(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    for byte in name.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b':' | b'/' => out.push(byte as char),
+            _ => out.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    out
+}
+
+/// Percent-encode the characters an IRI reference can't contain (space,
+/// controls, `<>"{}|^`\`), so configured IRIs can't break the output.
+fn escape_iri(iri: &str) -> String {
+    let mut out = String::with_capacity(iri.len());
+    for c in iri.chars() {
+        match c {
+            '\0'..=' ' | '<' | '>' | '"' | '{' | '}' | '|' | '^' | '`' | '\\' => out.push_str(&format!("%{:02X}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// An `xsd:double` lexical form; Rust's `NaN`/`inf` spellings aren't valid.
+fn double_lexical(value: f64) -> String {
+    match value {
+        v if v.is_nan() => "NaN".into(),
+        f64::INFINITY => "INF".into(),
+        f64::NEG_INFINITY => "-INF".into(),
+        v => v.to_string(),
+    }
+}
+
+fn escape(lexical: &str) -> String {
+    let mut out = String::with_capacity(lexical.len());
+    for c in lexical.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn ntriples_term(term: &RdfTerm) -> String {
+    match term {
+        RdfTerm::Iri(iri) => format!("<{}>", escape_iri(iri)),
+        RdfTerm::Blank(label) => format!("_:{}", label),
+        RdfTerm::Literal { lexical, datatype } => format!("\"{}\"^^<{}>", escape(lexical), escape_iri(datatype)),
+    }
+}
+
+fn turtle_literal(term: &RdfTerm, compact: &dyn Fn(&str) -> String) -> String {
+    match term {
+        RdfTerm::Literal { lexical, datatype } if datatype == &format!("{}string", XSD) => {
+            format!("\"{}\"", escape(lexical))
+        }
+        RdfTerm::Literal { lexical, datatype } => format!("\"{}\"^^{}", escape(lexical), compact(datatype)),
+        other => ntriples_term(other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{dimension, ContentType, NodeId};
+
+    fn graph() -> Context {
+        let mut ctx = Context::new("rdf");
+        let mut concept = Node::new_in_dimension("concept", ContentType::Concept, dimension::SEMANTIC);
+        concept.id = NodeId::from_string("concept:federated learning");
+        concept.properties.insert("label".into(), PropertyValue::from("Federated \"FL\" learning"));
+        concept.properties.insert("score".into(), PropertyValue::Float(0.5));
+        ctx.add_node(concept);
+        let mut fragment = Node::new_in_dimension("fragment", ContentType::Document, dimension::STRUCTURE);
+        fragment.id = NodeId::from_string("fragment:1");
+        fragment.properties.insert(
+            "tags".into(),
+            PropertyValue::Array(vec![PropertyValue::from("fl"), PropertyValue::from("privacy")]),
+        );
+        ctx.add_node(fragment);
+        ctx.add_edge(Edge::new_cross_dimensional(
+            NodeId::from_string("fragment:1"),
+            dimension::STRUCTURE,
+            NodeId::from_string("concept:federated learning"),
+            dimension::SEMANTIC,
+            "tagged_with",
+        ));
+        ctx
+    }
+
+    // === Scenario: N-Triples with the default vocabulary ===
+    #[test]
+    fn ntriples_default_vocabulary() {
+        let out = RdfExport::new(RdfVocabulary::new()).format(RdfFormat::NTriples).execute(&graph());
+        let lines: Vec<&str> = out.lines().collect();
+
+        assert!(lines.contains(&"<urn:plexus:node:concept:federated%20learning> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <urn:plexus:vocab#concept> ."));
+        assert!(lines.contains(&"<urn:plexus:node:concept:federated%20learning> <http://www.w3.org/2000/01/rdf-schema#label> \"Federated \\\"FL\\\" learning\"^^<http://www.w3.org/2001/XMLSchema#string> ."));
+        assert!(lines.contains(&"<urn:plexus:node:fragment:1> <urn:plexus:vocab#dimension> <urn:plexus:vocab#dimension/structure> ."));
+        assert!(lines.contains(&"<urn:plexus:node:fragment:1> <urn:plexus:vocab#tagged_with> <urn:plexus:node:concept:federated%20learning> ."));
+        assert_eq!(lines.iter().filter(|l| l.contains("vocab#tags")).count(), 2, "one triple per array item");
+        assert_eq!(lines.len(), 9);
+    }
+
+    // === Scenario: Turtle with a mapped vocabulary ===
+    #[test]
+    fn turtle_uses_mapped_iris_and_prefixes() {
+        let vocab = RdfVocabulary::new()
+            .with_base("https://example.org/kb/")
+            .with_prefix("skos", "http://www.w3.org/2004/02/skos/core#")
+            .map_node_type("concept", "skos:Concept")
+            .map_relationship("tagged_with", "http://purl.org/dc/terms/subject")
+            .map_property("label", "skos:prefLabel");
+        let out = RdfExport::new(vocab).with_reified_edges().execute(&graph());
+
+        assert!(out.contains("@prefix skos: <http://www.w3.org/2004/02/skos/core#> ."));
+        assert!(out.contains("<https://example.org/kb/concept:federated%20learning> a skos:Concept ;\n    plexus:dimension"));
+        assert!(out.contains("skos:prefLabel \"Federated \\\"FL\\\" learning\""));
+        assert!(out.contains("plexus:score \"0.5\"^^xsd:double"));
+        assert!(out.contains("<https://example.org/kb/fragment:1> <http://purl.org/dc/terms/subject> <https://example.org/kb/concept:federated%20learning> .\n"));
+        assert!(out.contains("_:e0 a rdf:Statement ;"));
+        assert!(out.contains("plexus:weight \"1\"^^xsd:double"));
+    }
+
+    // === Scenario: Non-finite floats and unsafe IRIs still serialize validly ===
+    #[test]
+    fn non_finite_floats_and_unsafe_iris_are_escaped() {
+        let mut ctx = graph();
+        let concept = ctx.nodes.get_mut(&NodeId::from_string("concept:federated learning")).unwrap();
+        concept.properties.insert("score".into(), PropertyValue::Float(f64::NAN));
+        concept.properties.insert("upper".into(), PropertyValue::Float(f64::NEG_INFINITY));
+        let vocab = RdfVocabulary::new().map_relationship("tagged_with", "https://example.org/tagged with>");
+        let out = RdfExport::new(vocab).format(RdfFormat::NTriples).execute(&ctx);
+
+        assert!(out.contains("\"NaN\"^^<http://www.w3.org/2001/XMLSchema#double>"));
+        assert!(out.contains("\"-INF\"^^<http://www.w3.org/2001/XMLSchema#double>"));
+        assert!(out.contains("<https://example.org/tagged%20with%3E>"));
+        let turtle = RdfExport::new(RdfVocabulary::new().with_vocab("urn:my vocab#")).execute(&ctx);
+        assert!(turtle.contains("@prefix plexus: <urn:my%20vocab#> ."));
+    }
+
+    // === Scenario: Vocabularies load from config ===
+    #[test]
+    fn vocabulary_deserializes_with_defaults() {
+        let vocab: RdfVocabulary =
+            serde_json::from_str(r#"{"base": "https://example.org/", "relationships": {"cites": "https://example.org/cites"}}"#)
+                .unwrap();
+        assert_eq!(vocab.vocab, "urn:plexus:vocab#");
+        assert_eq!(vocab.relationship_iri("cites"), "https://example.org/cites");
+        assert_eq!(vocab.property_iri("label"), format!("{}label", RDFS));
+        assert_eq!(vocab.node_iri("a b"), "https://example.org/a%20b");
+    }
+}
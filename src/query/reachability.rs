@@ -0,0 +1,456 @@
+//! Reachability index for fast `is_reachable` checks
+//!
+//! Answering "does a reach b?" by BFS costs a traversal per call. The
+//! engine instead keeps, per context and `ReachabilityFilter`, every
+//! node's set of descendants and ancestors, built on the first check.
+//! The indexes live beside the context, not in it, so copying a context
+//! doesn't copy them.
+//!
+//! Each write to the context bumps the cache's write count. A write whose
+//! events reach the cache while it's otherwise current is applied in
+//! place: touched edges are reconciled with the context (added, removed,
+//! or moved in or out of the filter by a dimension change), and removals
+//! recompute only the sources' ancestors. Any other write leaves the
+//! cache behind, and the next check rebuilds it.
+//!
+//! A context keeps at most `MAX_INDEXES` filters (least recently used
+//! dropped), and a filter whose index would pass `MAX_PAIRS` reachable
+//! pairs is answered by search instead.
+
+use crate::graph::events::GraphEvent;
+use crate::graph::{Context, Edge, EdgeId, NodeId};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+/// Filters indexed per context.
+pub const MAX_INDEXES: usize = 4;
+
+/// Reachable pairs one index may hold.
+pub const MAX_PAIRS: usize = 4_000_000;
+
+/// The edges a reachability check may follow. Unset fields allow anything.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ReachabilityFilter {
+    /// Relationships to follow
+    pub relationships: Option<BTreeSet<String>>,
+    /// Only follow edges with both endpoints in this dimension
+    pub dimension: Option<String>,
+}
+
+impl ReachabilityFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Follow edges of `relationship` (restricting relationships if unset).
+    pub fn with_relationship(mut self, relationship: impl Into<String>) -> Self {
+        self.relationships.get_or_insert_with(BTreeSet::new).insert(relationship.into());
+        self
+    }
+
+    /// Follow only edges within `dimension`.
+    pub fn in_dimension(mut self, dimension: impl Into<String>) -> Self {
+        self.dimension = Some(dimension.into());
+        self
+    }
+
+    pub fn edge_passes(&self, edge: &Edge) -> bool {
+        let relationship = self.relationships.as_ref().is_none_or(|allowed| allowed.contains(&edge.relationship));
+        let dimension = self
+            .dimension
+            .as_ref()
+            .is_none_or(|d| &edge.source_dimension == d && &edge.target_dimension == d);
+        relationship && dimension
+    }
+}
+
+/// Descendant and ancestor sets for one filter, with the edges behind them.
+#[derive(Debug, Clone, Default)]
+struct ReachabilityIndex {
+    descendants: HashMap<NodeId, HashSet<NodeId>>,
+    ancestors: HashMap<NodeId, HashSet<NodeId>>,
+    /// Indexed edges (those passing the filter) and their endpoints
+    edges: HashMap<EdgeId, (NodeId, NodeId)>,
+    outgoing: HashMap<NodeId, HashSet<EdgeId>>,
+    incoming: HashMap<NodeId, HashSet<EdgeId>>,
+    /// Sum of the descendant sets' sizes
+    pairs: usize,
+    /// Cache clock at the last check, for eviction
+    last_used: u64,
+}
+
+impl ReachabilityIndex {
+    /// Index `context` under `filter`, or `None` past `MAX_PAIRS`.
+    fn build(filter: &ReachabilityFilter, context: &Context) -> Option<Self> {
+        let mut index = Self::default();
+        for edge in context.edges.iter().filter(|e| filter.edge_passes(e)) {
+            index.insert(edge);
+            if index.pairs > MAX_PAIRS {
+                return None;
+            }
+        }
+        Some(index)
+    }
+
+    fn insert(&mut self, edge: &Edge) {
+        if self.edges.insert(edge.id.clone(), (edge.source.clone(), edge.target.clone())).is_some() {
+            return;
+        }
+        self.outgoing.entry(edge.source.clone()).or_default().insert(edge.id.clone());
+        self.incoming.entry(edge.target.clone()).or_default().insert(edge.id.clone());
+        self.link(&edge.source, &edge.target);
+    }
+
+    /// Record `source → target`: `source` and its ancestors now reach
+    /// `target` and its descendants.
+    fn link(&mut self, source: &NodeId, target: &NodeId) {
+        if self.descendants.get(source).is_some_and(|d| d.contains(target)) {
+            return;
+        }
+        let mut above: Vec<NodeId> = self.ancestors.get(source).into_iter().flatten().cloned().collect();
+        above.push(source.clone());
+        let mut below: Vec<NodeId> = self.descendants.get(target).into_iter().flatten().cloned().collect();
+        below.push(target.clone());
+        for ancestor in &above {
+            let reached = self.descendants.entry(ancestor.clone()).or_default();
+            let before = reached.len();
+            reached.extend(below.iter().cloned());
+            self.pairs += reached.len() - before;
+        }
+        for descendant in &below {
+            self.ancestors.entry(descendant.clone()).or_default().extend(above.iter().cloned());
+        }
+    }
+
+    /// Drop edges, then recompute what the nodes that could reach their
+    /// sources still reach.
+    fn remove(&mut self, edge_ids: &[EdgeId]) {
+        let mut affected = HashSet::new();
+        for id in edge_ids {
+            let Some((source, target)) = self.edges.remove(id) else { continue };
+            if let Some(out) = self.outgoing.get_mut(&source) {
+                out.remove(id);
+            }
+            if let Some(inc) = self.incoming.get_mut(&target) {
+                inc.remove(id);
+            }
+            affected.extend(self.ancestors.get(&source).into_iter().flatten().cloned());
+            affected.insert(source);
+        }
+        for node in affected {
+            let reached = self.search(&node);
+            let before = self.descendants.remove(&node).unwrap_or_default();
+            for lost in before.difference(&reached) {
+                if let Some(reaching) = self.ancestors.get_mut(lost) {
+                    reaching.remove(&node);
+                }
+            }
+            self.pairs = self.pairs - before.len() + reached.len();
+            if !reached.is_empty() {
+                self.descendants.insert(node, reached);
+            }
+        }
+    }
+
+    /// Every node `from` reaches over indexed edges, by BFS.
+    fn search(&self, from: &NodeId) -> HashSet<NodeId> {
+        let mut reached = HashSet::new();
+        let mut queue = VecDeque::from([from.clone()]);
+        while let Some(node) = queue.pop_front() {
+            for edge_id in self.outgoing.get(&node).into_iter().flatten() {
+                let (_, target) = &self.edges[edge_id];
+                if reached.insert(target.clone()) {
+                    queue.push_back(target.clone());
+                }
+            }
+        }
+        reached
+    }
+
+    fn reaches(&self, from: &NodeId, to: &NodeId) -> bool {
+        self.descendants.get(from).is_some_and(|d| d.contains(to))
+    }
+
+    /// Bring the edges `events` touched in line with `context`.
+    fn reconcile(&mut self, filter: &ReachabilityFilter, context: &Context, edges: &HashSet<&EdgeId>, nodes: &HashSet<&NodeId>) {
+        let mut touched: HashSet<EdgeId> = edges.iter().map(|id| (*id).clone()).collect();
+        for node in nodes {
+            touched.extend(self.outgoing.get(*node).into_iter().flatten().cloned());
+            touched.extend(self.incoming.get(*node).into_iter().flatten().cloned());
+        }
+        let mut present = HashSet::new();
+        let mut added = Vec::new();
+        for edge in context.edges.iter() {
+            if !touched.contains(&edge.id) && !nodes.contains(&edge.source) && !nodes.contains(&edge.target) {
+                continue;
+            }
+            if filter.edge_passes(edge) {
+                present.insert(edge.id.clone());
+                added.push(edge);
+            }
+        }
+        let gone: Vec<EdgeId> = touched.into_iter().filter(|id| self.edges.contains_key(id) && !present.contains(id)).collect();
+        self.remove(&gone);
+        for edge in added {
+            self.insert(edge);
+        }
+    }
+}
+
+/// What one commit's events touched.
+struct Touched<'a> {
+    edges: HashSet<&'a EdgeId>,
+    nodes: HashSet<&'a NodeId>,
+}
+
+impl<'a> Touched<'a> {
+    fn of(events: &'a [GraphEvent]) -> Self {
+        let mut touched = Touched { edges: HashSet::new(), nodes: HashSet::new() };
+        for event in events {
+            match event {
+                GraphEvent::EdgesAdded { edge_ids, .. } | GraphEvent::EdgesRemoved { edge_ids, .. } => {
+                    touched.edges.extend(edge_ids)
+                }
+                // Upserts and property changes can move a node between dimensions
+                GraphEvent::NodesAdded { node_ids, .. } | GraphEvent::NodesRemoved { node_ids, .. } => {
+                    touched.nodes.extend(node_ids)
+                }
+                GraphEvent::NodePropertiesChanged { changes, .. } => touched.nodes.extend(changes.iter().map(|(id, _)| id)),
+                _ => {}
+            }
+        }
+        touched
+    }
+}
+
+/// The reachability indexes of one context, by filter.
+#[derive(Debug, Clone, Default)]
+pub struct ReachabilityCache {
+    indexes: HashMap<ReachabilityFilter, ReachabilityIndex>,
+    /// Filters past `MAX_PAIRS`, answered by search
+    oversized: HashSet<ReachabilityFilter>,
+    /// Writes to the context so far
+    writes: u64,
+    /// Writes the indexes reflect
+    applied: u64,
+    /// The write whose events may be applied in place
+    pending: Option<u64>,
+    clock: u64,
+}
+
+impl ReachabilityCache {
+    /// Note a write to the context; its events, if they arrive before
+    /// another write, keep the indexes current.
+    pub(crate) fn begin_write(&mut self) {
+        let current = self.applied == self.writes;
+        self.writes += 1;
+        self.pending = current.then_some(self.writes);
+    }
+
+    /// Note a write whose events won't arrive, such as a context replaced
+    /// wholesale; the next check rebuilds.
+    pub(crate) fn invalidate(&mut self) {
+        self.writes += 1;
+        self.pending = None;
+    }
+
+    /// Apply a committed write's events. Applying them again, or after a
+    /// later write, leaves the cache to rebuild.
+    pub(crate) fn commit(&mut self, context: &Context, events: &[GraphEvent]) {
+        if self.pending != Some(self.writes) {
+            return;
+        }
+        let touched = Touched::of(events);
+        if !touched.edges.is_empty() || !touched.nodes.is_empty() {
+            for (filter, index) in self.indexes.iter_mut() {
+                index.reconcile(filter, context, &touched.edges, &touched.nodes);
+            }
+            let oversized = &mut self.oversized;
+            self.indexes.retain(|filter, index| {
+                let keep = index.pairs <= MAX_PAIRS;
+                if !keep {
+                    oversized.insert(filter.clone());
+                }
+                keep
+            });
+        }
+        self.applied = self.writes;
+    }
+
+    /// Whether `from` reaches `to` along edges passing `filter`, building
+    /// or rebuilding the filter's index as needed. A node reaches itself.
+    pub fn is_reachable(&mut self, context: &Context, from: &NodeId, to: &NodeId, filter: &ReachabilityFilter) -> bool {
+        if from == to {
+            return context.get_node(from).is_some();
+        }
+        if self.applied != self.writes {
+            self.indexes.clear();
+            self.applied = self.writes;
+            self.pending = None;
+        }
+        if self.oversized.contains(filter) {
+            return search(context, from, to, filter);
+        }
+        self.clock += 1;
+        if !self.indexes.contains_key(filter) {
+            let Some(index) = ReachabilityIndex::build(filter, context) else {
+                self.oversized.insert(filter.clone());
+                return search(context, from, to, filter);
+            };
+            if self.indexes.len() >= MAX_INDEXES {
+                let coldest = self.indexes.iter().min_by_key(|(_, index)| index.last_used).map(|(f, _)| f.clone());
+                if let Some(coldest) = coldest {
+                    self.indexes.remove(&coldest);
+                }
+            }
+            self.indexes.insert(filter.clone(), index);
+        }
+        let index = self.indexes.get_mut(filter).expect("index just ensured");
+        index.last_used = self.clock;
+        index.reaches(from, to)
+    }
+
+    /// Whether the indexes reflect every write.
+    pub fn is_current(&self) -> bool {
+        self.applied == self.writes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.indexes.is_empty()
+    }
+}
+
+/// BFS from `from` for `to`, for filters too large to index.
+fn search(context: &Context, from: &NodeId, to: &NodeId, filter: &ReachabilityFilter) -> bool {
+    let mut outgoing: HashMap<&NodeId, Vec<&NodeId>> = HashMap::new();
+    for edge in context.edges.iter().filter(|e| filter.edge_passes(e)) {
+        outgoing.entry(&edge.source).or_default().push(&edge.target);
+    }
+    let mut seen = HashSet::from([from]);
+    let mut queue = VecDeque::from([from]);
+    while let Some(node) = queue.pop_front() {
+        for target in outgoing.get(node).into_iter().flatten() {
+            if *target == to {
+                return true;
+            }
+            if seen.insert(target) {
+                queue.push_back(target);
+            }
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{ContentType, Node};
+
+    fn node(ctx: &mut Context, id: &str) -> NodeId {
+        let mut node = Node::new("concept", ContentType::Concept);
+        node.id = NodeId::from_string(id);
+        ctx.add_node(node)
+    }
+
+    fn link(ctx: &mut Context, source: &NodeId, target: &NodeId, relationship: &str) -> GraphEvent {
+        let edge = Edge::new(source.clone(), target.clone(), relationship);
+        let id = edge.id.clone();
+        ctx.add_edge(edge);
+        GraphEvent::EdgesAdded { edge_ids: vec![id], adapter_id: "test".into(), context_id: "ctx".into() }
+    }
+
+    fn commit(cache: &mut ReachabilityCache, ctx: &Context, events: &[GraphEvent]) {
+        cache.begin_write();
+        cache.commit(ctx, events);
+    }
+
+    // === Scenario: Reachability follows added and removed edges in place ===
+    #[test]
+    fn index_tracks_edges_added_and_removed() {
+        let mut ctx = Context::new("reach");
+        let mut cache = ReachabilityCache::default();
+        let [a, b, c, d] = ["a", "b", "c", "d"].map(|id| node(&mut ctx, id));
+        link(&mut ctx, &a, &b, "contains");
+        let any = ReachabilityFilter::new();
+        assert!(cache.is_reachable(&ctx, &a, &b, &any));
+        assert!(!cache.is_reachable(&ctx, &b, &a, &any), "reachability is directed");
+
+        let events = [link(&mut ctx, &c, &d, "contains"), link(&mut ctx, &b, &c, "cites")];
+        commit(&mut cache, &ctx, &events);
+        assert!(cache.is_current(), "extended without a rebuild");
+        assert!(cache.is_reachable(&ctx, &a, &d, &any));
+
+        let contains = ReachabilityFilter::new().with_relationship("contains");
+        assert!(!cache.is_reachable(&ctx, &a, &d, &contains), "the cites hop doesn't pass the filter");
+
+        let removed = ctx.trash_edges_where(|e| e.relationship == "cites");
+        let removal = GraphEvent::EdgesRemoved {
+            edge_ids: removed,
+            adapter_id: "test".into(),
+            context_id: "ctx".into(),
+            reason: "direct".into(),
+        };
+        commit(&mut cache, &ctx, &[removal]);
+        assert!(cache.is_current(), "removals apply in place too");
+        assert!(!cache.is_reachable(&ctx, &a, &d, &any));
+        assert!(cache.is_reachable(&ctx, &c, &d, &any));
+        assert!(cache.is_reachable(&ctx, &a, &a, &any));
+    }
+
+    // === Scenario: Writes without events leave the cache to rebuild ===
+    #[test]
+    fn unreported_writes_invalidate_the_cache() {
+        let mut ctx = Context::new("reach");
+        let mut cache = ReachabilityCache::default();
+        let [a, b, c] = ["a", "b", "c"].map(|id| node(&mut ctx, id));
+        link(&mut ctx, &a, &b, "calls");
+        let any = ReachabilityFilter::new();
+        assert!(cache.is_reachable(&ctx, &a, &b, &any));
+
+        // Same edge count, different edge
+        cache.begin_write();
+        ctx.edges.clear();
+        link(&mut ctx, &a, &c, "calls");
+        assert!(!cache.is_current());
+        assert!(!cache.is_reachable(&ctx, &a, &b, &any));
+        assert!(cache.is_reachable(&ctx, &a, &c, &any));
+
+        // A late commit for a superseded write changes nothing
+        let late = link(&mut ctx, &c, &b, "calls");
+        cache.begin_write();
+        cache.begin_write();
+        cache.commit(&ctx, &[late]);
+        assert!(!cache.is_current());
+    }
+
+    // === Scenario: A node moving dimension moves its edges in or out of the filter ===
+    #[test]
+    fn dimension_moves_are_reconciled() {
+        let mut ctx = Context::new("reach");
+        let mut cache = ReachabilityCache::default();
+        let [a, b] = ["a", "b"].map(|id| node(&mut ctx, id));
+        link(&mut ctx, &a, &b, "calls");
+        let within = ReachabilityFilter::new().in_dimension(ctx.edges[0].source_dimension.clone());
+        assert!(cache.is_reachable(&ctx, &a, &b, &within));
+
+        ctx.edges[0].target_dimension = "elsewhere".to_string();
+        let moved = GraphEvent::NodesAdded { node_ids: vec![b.clone()], adapter_id: "test".into(), context_id: "ctx".into() };
+        commit(&mut cache, &ctx, &[moved]);
+        assert!(cache.is_current());
+        assert!(!cache.is_reachable(&ctx, &a, &b, &within));
+    }
+
+    // === Scenario: Each context indexes a bounded number of filters ===
+    #[test]
+    fn least_recently_used_filters_are_evicted() {
+        let mut ctx = Context::new("reach");
+        let mut cache = ReachabilityCache::default();
+        let [a, b] = ["a", "b"].map(|id| node(&mut ctx, id));
+        link(&mut ctx, &a, &b, "r0");
+        for i in 0..=MAX_INDEXES {
+            let filter = ReachabilityFilter::new().with_relationship(format!("r{i}"));
+            assert_eq!(cache.is_reachable(&ctx, &a, &b, &filter), i == 0);
+        }
+        assert_eq!(cache.indexes.len(), MAX_INDEXES);
+        assert!(!cache.indexes.contains_key(&ReachabilityFilter::new().with_relationship("r0")));
+    }
+}
@@ -0,0 +1,101 @@
+//! Saved queries — named graph views stored per context
+//!
+//! A team standardizes a useful view ("open-threads",
+//! "ungrounded-concepts") once; anyone can then run it by name. The
+//! definition is stored on `ContextMetadata`, so it persists with the
+//! context and every engine sharing the store sees it.
+
+use crate::graph::Context;
+use serde::{Deserialize, Serialize};
+use super::find::FindQuery;
+use super::traverse::TraverseQuery;
+use super::types::{QueryResult, TraversalResult};
+
+/// A stored query definition.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SavedQuery {
+    Find(FindQuery),
+    Traverse(TraverseQuery),
+}
+
+/// The result of running a saved query, tagged like its definition.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SavedQueryResult {
+    Find(QueryResult),
+    Traverse(TraversalResult),
+}
+
+impl SavedQuery {
+    pub fn execute(&self, context: &Context) -> SavedQueryResult {
+        match self {
+            SavedQuery::Find(query) => SavedQueryResult::Find(query.execute(context)),
+            SavedQuery::Traverse(query) => SavedQueryResult::Traverse(query.execute(context)),
+        }
+    }
+}
+
+impl From<FindQuery> for SavedQuery {
+    fn from(query: FindQuery) -> Self {
+        SavedQuery::Find(query)
+    }
+}
+
+impl From<TraverseQuery> for SavedQuery {
+    fn from(query: TraverseQuery) -> Self {
+        SavedQuery::Traverse(query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{ContentType, Edge, Node};
+
+    // === Scenario: Definitions round-trip through their wire form ===
+    #[test]
+    fn definitions_parse_with_defaults() {
+        let find: SavedQuery =
+            serde_json::from_str(r#"{"kind": "find", "node_type": "concept", "limit": 10}"#).unwrap();
+        let SavedQuery::Find(find) = find else { panic!("expected find") };
+        assert_eq!(find.node_type.as_deref(), Some("concept"));
+        assert_eq!(find.limit, Some(10));
+
+        let traverse: SavedQuery =
+            serde_json::from_str(r#"{"kind": "traverse", "origin": "concept:rye", "direction": "both"}"#)
+                .unwrap();
+        let SavedQuery::Traverse(traverse) = traverse else { panic!("expected traverse") };
+        assert_eq!(traverse.max_depth, 1);
+        assert_eq!(traverse.direction, crate::query::Direction::Both);
+
+        let json = serde_json::to_string(&SavedQuery::from(traverse)).unwrap();
+        assert!(matches!(
+            serde_json::from_str::<SavedQuery>(&json).unwrap(),
+            SavedQuery::Traverse(_)
+        ));
+    }
+
+    // === Scenario: Running a definition executes the wrapped query ===
+    #[test]
+    fn execute_dispatches_by_kind() {
+        let mut ctx = Context::new("saved");
+        let a = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let b = ctx.add_node(Node::new("fragment", ContentType::Document));
+        ctx.add_edge(Edge::new(b.clone(), a.clone(), "tagged_with"));
+
+        let SavedQueryResult::Find(found) =
+            SavedQuery::from(FindQuery::new().with_node_type("concept")).execute(&ctx)
+        else {
+            panic!("expected find result")
+        };
+        assert_eq!(found.total_count, 1);
+
+        let SavedQueryResult::Traverse(traversed) =
+            SavedQuery::from(TraverseQuery::from(b)).execute(&ctx)
+        else {
+            panic!("expected traverse result")
+        };
+        assert_eq!(traversed.at_depth(1)[0].id, a);
+    }
+}
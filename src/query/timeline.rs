@@ -0,0 +1,335 @@
+//! Timeline queries — nodes in time order, optionally bucketed
+//!
+//! A node's time is the first of the query's timestamp properties it has:
+//! by default `scheduled_for` (calendar events and tasks in the temporal
+//! dimension) and then `created_at` (fragments, turns, segments, images —
+//! ADR-039). Concept nodes are left out unless asked for by type: their
+//! `created_at` is a re-emission time, not when anything happened.
+//!
+//! Each entry carries the concepts its node touches — adjacent concept
+//! nodes, plus a mark's `tags` — and each bucket tallies them, so a
+//! month-by-month timeline filtered to one concept shows what it was
+//! being thought about alongside.
+
+use crate::graph::{Context, Node, NodeId, PropertyValue};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Timeline bucket width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeBucket {
+    Day,
+    /// ISO weeks, starting Monday
+    Week,
+    Month,
+    Year,
+}
+
+impl TimeBucket {
+    /// The start of the bucket containing `at`, and its label
+    /// (`2026-03-01`, `2026-W09`, `2026-03`, `2026`).
+    pub fn bucket(self, at: DateTime<Utc>) -> (DateTime<Utc>, String) {
+        let date = at.date_naive();
+        let start = match self {
+            TimeBucket::Day => date,
+            TimeBucket::Week => date - Duration::days(date.weekday().num_days_from_monday() as i64),
+            TimeBucket::Month => NaiveDate::from_ymd_opt(date.year(), date.month(), 1).unwrap_or(date),
+            TimeBucket::Year => NaiveDate::from_ymd_opt(date.year(), 1, 1).unwrap_or(date),
+        };
+        let label = match self {
+            TimeBucket::Day => start.format("%Y-%m-%d").to_string(),
+            TimeBucket::Week => {
+                let week = date.iso_week();
+                format!("{}-W{:02}", week.year(), week.week())
+            }
+            TimeBucket::Month => start.format("%Y-%m").to_string(),
+            TimeBucket::Year => start.format("%Y").to_string(),
+        };
+        (start.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc(), label)
+    }
+}
+
+/// Query for nodes in chronological order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TimelineQuery {
+    /// Properties read for a node's time, first present wins
+    pub timestamp_properties: Vec<String>,
+    /// Only these node types; empty means every type but `concept`
+    pub node_types: Vec<String>,
+    /// Only nodes touching one of these concepts (labels or `concept:` IDs)
+    pub concepts: Vec<String>,
+    /// Inclusive lower bound
+    pub from: Option<DateTime<Utc>>,
+    /// Exclusive upper bound
+    pub until: Option<DateTime<Utc>>,
+    pub bucket: Option<TimeBucket>,
+    /// Maximum entries returned (buckets still count every match)
+    pub limit: Option<usize>,
+}
+
+impl Default for TimelineQuery {
+    fn default() -> Self {
+        Self {
+            timestamp_properties: vec!["scheduled_for".to_string(), "created_at".to_string()],
+            node_types: Vec::new(),
+            concepts: Vec::new(),
+            from: None,
+            until: None,
+            bucket: None,
+            limit: None,
+        }
+    }
+}
+
+/// A node at its point on the timeline.
+#[derive(Debug, Clone, Serialize)]
+pub struct TimelineEntry {
+    pub node: Node,
+    pub at: DateTime<Utc>,
+    /// Concepts the node touches, sorted
+    pub concepts: Vec<NodeId>,
+}
+
+/// One bucket of a bucketed timeline.
+#[derive(Debug, Clone, Serialize)]
+pub struct TimelineBucket {
+    pub label: String,
+    pub start: DateTime<Utc>,
+    pub node_ids: Vec<NodeId>,
+    /// Concepts touched in this bucket with how many entries touch them,
+    /// most touched first
+    pub concepts: Vec<(NodeId, usize)>,
+}
+
+/// Result of a timeline query.
+#[derive(Debug, Clone, Serialize)]
+pub struct TimelineResult {
+    pub entries: Vec<TimelineEntry>,
+    /// Empty unless the query asked for buckets; in time order, and only
+    /// buckets with entries
+    pub buckets: Vec<TimelineBucket>,
+    /// Matches before `limit`
+    pub total_count: usize,
+}
+
+impl TimelineQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read a node's time from these properties, first present wins.
+    pub fn with_timestamp_properties(mut self, properties: Vec<String>) -> Self {
+        self.timestamp_properties = properties;
+        self
+    }
+
+    pub fn with_node_types(mut self, node_types: Vec<String>) -> Self {
+        self.node_types = node_types;
+        self
+    }
+
+    /// Only nodes touching one of these concepts.
+    pub fn with_concepts(mut self, concepts: Vec<String>) -> Self {
+        self.concepts = concepts;
+        self
+    }
+
+    /// Only nodes at or after `from` and before `until`.
+    pub fn between(mut self, from: Option<DateTime<Utc>>, until: Option<DateTime<Utc>>) -> Self {
+        self.from = from;
+        self.until = until;
+        self
+    }
+
+    pub fn bucketed(mut self, bucket: TimeBucket) -> Self {
+        self.bucket = Some(bucket);
+        self
+    }
+
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Execute the query against a context
+    pub fn execute(&self, context: &Context) -> TimelineResult {
+        let mut touched = concepts_touched(context);
+        let wanted: HashSet<NodeId> = self.concepts.iter().map(|c| concept_id(c)).collect();
+
+        let mut entries: Vec<TimelineEntry> = context
+            .nodes()
+            .filter(|node| match self.node_types.is_empty() {
+                true => node.node_type != "concept",
+                false => self.node_types.iter().any(|t| t == &node.node_type),
+            })
+            .filter_map(|node| {
+                let at = timestamp_of(node, &self.timestamp_properties)?;
+                if self.from.is_some_and(|from| at < from) || self.until.is_some_and(|until| at >= until) {
+                    return None;
+                }
+                let concepts = touched.remove(&node.id).unwrap_or_default();
+                if !wanted.is_empty() && concepts.is_disjoint(&wanted) {
+                    return None;
+                }
+                let mut concepts: Vec<NodeId> = concepts.into_iter().collect();
+                concepts.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+                Some(TimelineEntry { node: node.clone(), at, concepts })
+            })
+            .collect();
+        entries.sort_by(|a, b| a.at.cmp(&b.at).then_with(|| a.node.id.as_str().cmp(b.node.id.as_str())));
+
+        let mut buckets: Vec<TimelineBucket> = Vec::new();
+        if let Some(width) = self.bucket {
+            for entry in &entries {
+                let (start, label) = width.bucket(entry.at);
+                if buckets.last().is_none_or(|b| b.label != label) {
+                    buckets.push(TimelineBucket { label, start, node_ids: Vec::new(), concepts: Vec::new() });
+                }
+                let bucket = buckets.last_mut().expect("pushed above");
+                bucket.node_ids.push(entry.node.id.clone());
+                for concept in &entry.concepts {
+                    match bucket.concepts.iter_mut().find(|(c, _)| c == concept) {
+                        Some((_, count)) => *count += 1,
+                        None => bucket.concepts.push((concept.clone(), 1)),
+                    }
+                }
+            }
+            for bucket in &mut buckets {
+                bucket.concepts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.as_str().cmp(b.0.as_str())));
+            }
+        }
+
+        let total_count = entries.len();
+        if let Some(limit) = self.limit {
+            entries.truncate(limit);
+        }
+        TimelineResult { entries, buckets, total_count }
+    }
+}
+
+/// A concept label or ID as a concept node ID.
+pub(crate) fn concept_id(label: &str) -> NodeId {
+    let label = label.trim().to_lowercase();
+    match label.starts_with("concept:") {
+        true => NodeId::from_string(label),
+        false => NodeId::from_string(format!("concept:{}", label)),
+    }
+}
+
+/// The first of `keys` a node has as a time (RFC 3339 or epoch millis).
+pub(crate) fn timestamp_of(node: &Node, keys: &[String]) -> Option<DateTime<Utc>> {
+    keys.iter().find_map(|key| match node.properties.get(key)? {
+        PropertyValue::Int(ms) => DateTime::from_timestamp_millis(*ms),
+        value => value.as_datetime(),
+    })
+}
+
+/// Concepts each node touches: concept nodes adjacent in either
+/// direction, plus the concepts named by its `tags`.
+pub(crate) fn concepts_touched(context: &Context) -> HashMap<NodeId, HashSet<NodeId>> {
+    let mut touched: HashMap<NodeId, HashSet<NodeId>> = HashMap::new();
+    let is_concept = |id: &NodeId| context.get_node(id).is_some_and(|n| n.node_type == "concept");
+    for edge in context.edges() {
+        if is_concept(&edge.target) {
+            touched.entry(edge.source.clone()).or_default().insert(edge.target.clone());
+        }
+        if is_concept(&edge.source) {
+            touched.entry(edge.target.clone()).or_default().insert(edge.source.clone());
+        }
+    }
+    for node in context.nodes() {
+        if let Some(PropertyValue::Array(tags)) = node.properties.get("tags") {
+            touched.entry(node.id.clone()).or_default().extend(
+                tags.iter()
+                    .filter_map(|t| t.as_str())
+                    .map(|t| NodeId::from_string(format!("concept:{}", t.trim_start_matches('#').to_lowercase()))),
+            );
+        }
+    }
+    touched
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{dimension, ContentType, Edge};
+
+    fn node(ctx: &mut Context, id: &str, node_type: &str, at: Option<&str>) {
+        let mut node = Node::new_in_dimension(node_type, ContentType::Document, dimension::STRUCTURE);
+        node.id = NodeId::from_string(id);
+        if let Some(at) = at {
+            node.properties.insert("created_at".into(), PropertyValue::from(at));
+        }
+        ctx.add_node(node);
+    }
+
+    fn tag(ctx: &mut Context, source: &str, concept: &str) {
+        ctx.add_edge(Edge::new_cross_dimensional(
+            NodeId::from_string(source),
+            dimension::STRUCTURE,
+            NodeId::from_string(concept),
+            dimension::SEMANTIC,
+            "tagged_with",
+        ));
+    }
+
+    fn timeline() -> Context {
+        let mut ctx = Context::new("timeline");
+        node(&mut ctx, "concept:federated learning", "concept", Some("2026-05-01T00:00:00Z"));
+        node(&mut ctx, "concept:privacy", "concept", Some("2026-05-01T00:00:00Z"));
+        node(&mut ctx, "fragment:mar", "fragment", Some("2026-03-10T09:00:00Z"));
+        node(&mut ctx, "fragment:apr-a", "fragment", Some("2026-04-02T09:00:00Z"));
+        node(&mut ctx, "fragment:apr-b", "fragment", Some("2026-04-20T09:00:00Z"));
+        node(&mut ctx, "fragment:unrelated", "fragment", Some("2026-04-05T09:00:00Z"));
+        node(&mut ctx, "fragment:undated", "fragment", None);
+        tag(&mut ctx, "fragment:mar", "concept:federated learning");
+        tag(&mut ctx, "fragment:apr-a", "concept:federated learning");
+        tag(&mut ctx, "fragment:apr-a", "concept:privacy");
+        tag(&mut ctx, "fragment:apr-b", "concept:privacy");
+        let mut event = Node::new_in_dimension("event", ContentType::Document, dimension::TEMPORAL);
+        event.id = NodeId::from_string("event:reading-group");
+        event.properties.insert("scheduled_for".into(), PropertyValue::from("2026-04-15T18:00:00Z"));
+        event.properties.insert("created_at".into(), PropertyValue::from("2026-01-01T00:00:00Z"));
+        ctx.add_node(event);
+        tag(&mut ctx, "event:reading-group", "concept:federated learning");
+        ctx
+    }
+
+    // === Scenario: A concept's timeline, month by month ===
+    #[test]
+    fn concept_timeline_buckets_by_month() {
+        let ctx = timeline();
+        let result = TimelineQuery::new()
+            .with_concepts(vec!["Federated Learning".into()])
+            .bucketed(TimeBucket::Month)
+            .execute(&ctx);
+
+        let ids: Vec<&str> = result.entries.iter().map(|e| e.node.id.as_str()).collect();
+        assert_eq!(ids, vec!["fragment:mar", "fragment:apr-a", "event:reading-group"], "events by scheduled_for");
+        let labels: Vec<&str> = result.buckets.iter().map(|b| b.label.as_str()).collect();
+        assert_eq!(labels, vec!["2026-03", "2026-04"]);
+        let april = &result.buckets[1];
+        assert_eq!(april.node_ids.len(), 2);
+        assert_eq!(april.concepts[0], (NodeId::from_string("concept:federated learning"), 2));
+        assert_eq!(april.concepts[1], (NodeId::from_string("concept:privacy"), 1), "what came up alongside");
+    }
+
+    // === Scenario: Unfiltered timelines skip concepts and respect bounds ===
+    #[test]
+    fn unfiltered_timeline_with_bounds_and_limit() {
+        let ctx = timeline();
+        let april = |d| DateTime::parse_from_rfc3339(d).unwrap().with_timezone(&Utc);
+        let result = TimelineQuery::new()
+            .between(Some(april("2026-04-01T00:00:00Z")), Some(april("2026-04-20T09:00:00Z")))
+            .limit(2)
+            .execute(&ctx);
+        assert_eq!(result.total_count, 3, "until is exclusive; concepts and undated nodes are left out");
+        let ids: Vec<&str> = result.entries.iter().map(|e| e.node.id.as_str()).collect();
+        assert_eq!(ids, vec!["fragment:apr-a", "fragment:unrelated"]);
+        assert!(result.buckets.is_empty());
+        assert_eq!(TimeBucket::Week.bucket(april("2026-03-04T12:00:00Z")).1, "2026-W10");
+    }
+}
@@ -2,24 +2,61 @@
 
 use std::collections::{HashMap, HashSet};
 use crate::graph::{Context, Edge, Node, NodeId};
+use super::explain::{explain_node, MAX_EXPLAINED_PATHS};
 use super::filter::QueryFilter;
 use super::types::{Direction, TraversalResult};
+use serde::{Deserialize, Serialize};
 
 /// Query for traversing the graph from a starting node
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TraverseQuery {
     /// Starting node ID
     pub origin: NodeId,
     /// Maximum depth to traverse (0 = origin only, 1 = immediate neighbors, etc.)
+    #[serde(default = "default_max_depth")]
     pub max_depth: usize,
     /// Direction to traverse edges
+    #[serde(default)]
     pub direction: Direction,
     /// Optional relationship type filter
+    #[serde(default)]
     pub relationship: Option<String>,
     /// Minimum edge weight filter
+    #[serde(default)]
     pub min_weight: Option<f32>,
     /// Optional composable filter (ADR-034)
+    #[serde(default)]
     pub filter: Option<QueryFilter>,
+    /// Attach a `NodeExplanation` per discovered node
+    #[serde(default)]
+    pub explain: bool,
+    /// Keep super-hubs from pulling the whole graph into the result
+    #[serde(default)]
+    pub hub_dampening: Option<HubDampening>,
+    /// Start from the origin's head version when it has been superseded
+    #[serde(default)]
+    pub resolve_to_head: bool,
+}
+
+/// How a traversal treats high-degree nodes.
+///
+/// Degree counts every edge touching a node, in both directions and
+/// regardless of the query's filters.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum HubDampening {
+    /// Nodes with more than `max_degree` edges are reached but not
+    /// expanded. The origin is always expanded.
+    Skip { max_degree: usize },
+    /// Each node carries a score: 1.0 at the origin, divided by the
+    /// degree of every node expanded to reach it. Neighbors scoring
+    /// below `min_score` are not reached. Paths through a hub of
+    /// degree 500 score at most 0.002.
+    InverseDegree { min_score: f32 },
+}
+
+fn default_max_depth() -> usize {
+    1
 }
 
 impl TraverseQuery {
@@ -27,11 +64,14 @@ impl TraverseQuery {
     pub fn from(origin: NodeId) -> Self {
         Self {
             origin,
-            max_depth: 1,
+            max_depth: default_max_depth(),
             direction: Direction::Outgoing,
             relationship: None,
             min_weight: None,
             filter: None,
+            explain: false,
+            hub_dampening: None,
+            resolve_to_head: false,
         }
     }
 
@@ -65,8 +105,57 @@ impl TraverseQuery {
         self
     }
 
+    /// Explain each discovered node: the shortest paths that reached it,
+    /// their top contributions, and linked provenance marks
+    pub fn with_explanation(mut self) -> Self {
+        self.explain = true;
+        self
+    }
+
+    /// Dampen super-hubs during expansion
+    pub fn with_hub_dampening(mut self, dampening: HubDampening) -> Self {
+        self.hub_dampening = Some(dampening);
+        self
+    }
+
+    /// Start from the origin's newest version
+    pub fn resolve_to_head(mut self) -> Self {
+        self.resolve_to_head = true;
+        self
+    }
+
+    /// This query with its origin resolved, when `resolve_to_head` moves it.
+    fn headed(&self, context: &Context) -> Option<Self> {
+        if !self.resolve_to_head {
+            return None;
+        }
+        let head = context.head_version(&self.origin);
+        (head != self.origin).then(|| Self { origin: head, resolve_to_head: false, ..self.clone() })
+    }
+
+    /// This query in terms of stored edges, when the context's ontology
+    /// changes it: an inverse name (`contained_in`) becomes the declared
+    /// relationship walked the other way, and undirected relationships
+    /// are walked both ways.
+    fn canonical(&self, context: &Context) -> Option<Self> {
+        let ontology = context.metadata.relationship_ontology.as_ref()?;
+        let requested = self.relationship.as_deref()?;
+        let resolved = ontology.resolve(requested);
+        let direction = match (resolved.undirected, resolved.reversed, self.direction) {
+            (true, _, _) => Direction::Both,
+            (false, true, Direction::Outgoing) => Direction::Incoming,
+            (false, true, Direction::Incoming) => Direction::Outgoing,
+            (_, _, direction) => direction,
+        };
+        (resolved.name != requested || direction != self.direction)
+            .then(|| Self { relationship: Some(resolved.name.to_string()), direction, ..self.clone() })
+    }
+
     /// Execute the traversal against a context
     pub fn execute(&self, context: &Context) -> TraversalResult {
+        if let Some(rewritten) = self.headed(context).or_else(|| self.canonical(context)) {
+            return rewritten.execute(context);
+        }
         let mut result = TraversalResult::new(self.origin.clone());
 
         // Get origin node
@@ -82,6 +171,14 @@ impl TraverseQuery {
         let mut seen_edges: HashSet<crate::graph::EdgeId> = HashSet::new();
         let mut current_level: Vec<NodeId> = vec![self.origin.clone()];
         visited.insert(self.origin.clone());
+        let mut scores: HashMap<NodeId, f32> = HashMap::from([(self.origin.clone(), 1.0)]);
+
+        // Shortest paths (origin-first) to each node, tracked only when
+        // explaining. Every same-depth predecessor contributes a path.
+        let mut paths_to: HashMap<NodeId, Vec<Vec<Edge>>> = HashMap::new();
+        if self.explain {
+            paths_to.insert(self.origin.clone(), vec![Vec::new()]);
+        }
 
         // Level 0 is the origin
         result.levels.push(vec![origin_node.clone()]);
@@ -93,8 +190,13 @@ impl TraverseQuery {
 
             let mut next_level: Vec<NodeId> = Vec::new();
             let mut level_nodes: Vec<Node> = Vec::new();
+            let mut level_ids: HashSet<NodeId> = HashSet::new();
 
             for node_id in &current_level {
+                let Some(reach_score) = self.expansion_score(node_id, scores[node_id], &edge_index) else {
+                    continue;
+                };
+
                 // Get edges based on direction
                 let edges = self.get_edges(node_id, &edge_index);
 
@@ -111,6 +213,15 @@ impl TraverseQuery {
                         &edge.source
                     };
 
+                    // Dampened out: not reached along this edge
+                    if !self.reaches(reach_score) && !visited.contains(neighbor_id) {
+                        continue;
+                    }
+                    if level_ids.contains(neighbor_id) {
+                        let score = scores.get_mut(neighbor_id).expect("scored when reached");
+                        *score = score.max(reach_score);
+                    }
+
                     // Record every matching edge exactly once — parallel
                     // edges to an already-visited neighbor are evidence,
                     // not duplicates (issue #12: the first-edge-wins skip
@@ -119,12 +230,33 @@ impl TraverseQuery {
                         result.edges.push(edge.clone());
                     }
 
+                    if self.explain && (level_ids.contains(neighbor_id) || !visited.contains(neighbor_id)) {
+                        let extended: Vec<Vec<Edge>> = paths_to
+                            .get(node_id)
+                            .into_iter()
+                            .flatten()
+                            .map(|path| {
+                                let mut path = path.clone();
+                                path.push(edge.clone());
+                                path
+                            })
+                            .collect();
+                        let paths = paths_to.entry(neighbor_id.clone()).or_default();
+                        for path in extended {
+                            if paths.len() < MAX_EXPLAINED_PATHS {
+                                paths.push(path);
+                            }
+                        }
+                    }
+
                     // Each node still joins the frontier only once
                     if visited.contains(neighbor_id) {
                         continue;
                     }
                     if let Some(neighbor) = context.get_node(neighbor_id) {
                         visited.insert(neighbor_id.clone());
+                        scores.insert(neighbor_id.clone(), reach_score);
+                        level_ids.insert(neighbor_id.clone());
                         next_level.push(neighbor_id.clone());
                         level_nodes.push(neighbor.clone());
                     }
@@ -137,9 +269,87 @@ impl TraverseQuery {
             current_level = next_level;
         }
 
+        if self.explain {
+            result.explanations = result
+                .all_nodes()
+                .into_iter()
+                .map(|node| {
+                    let paths = paths_to.get(&node.id).map(Vec::as_slice).unwrap_or(&[]);
+                    explain_node(context, &node.id, paths)
+                })
+                .collect();
+        }
+
         result
     }
 
+    /// Number of nodes reachable within `max_depth`, excluding the
+    /// origin. Walks node IDs only: no levels, edges, or clones.
+    pub fn count_reachable(&self, context: &Context) -> usize {
+        if let Some(rewritten) = self.headed(context).or_else(|| self.canonical(context)) {
+            return rewritten.count_reachable(context);
+        }
+        if context.get_node(&self.origin).is_none() {
+            return 0;
+        }
+        let edge_index = EdgeIndex::build(context);
+        let mut visited: HashSet<&NodeId> = HashSet::from([&self.origin]);
+        let mut scores: HashMap<&NodeId, f32> = HashMap::from([(&self.origin, 1.0)]);
+        let mut current_level: Vec<&NodeId> = vec![&self.origin];
+
+        for _depth in 0..self.max_depth {
+            let mut next_level = Vec::new();
+            let mut level_ids: HashSet<&NodeId> = HashSet::new();
+            for node_id in current_level {
+                let Some(reach_score) = self.expansion_score(node_id, scores[node_id], &edge_index) else {
+                    continue;
+                };
+                if !self.reaches(reach_score) {
+                    continue;
+                }
+                for edge in self.get_edges(node_id, &edge_index) {
+                    if !self.edge_matches(edge) {
+                        continue;
+                    }
+                    let neighbor_id = if edge.source == *node_id { &edge.target } else { &edge.source };
+                    if level_ids.contains(neighbor_id) {
+                        let score = scores.get_mut(neighbor_id).expect("scored when reached");
+                        *score = score.max(reach_score);
+                    } else if context.get_node(neighbor_id).is_some() && visited.insert(neighbor_id) {
+                        scores.insert(neighbor_id, reach_score);
+                        level_ids.insert(neighbor_id);
+                        next_level.push(neighbor_id);
+                    }
+                }
+            }
+            if next_level.is_empty() {
+                break;
+            }
+            current_level = next_level;
+        }
+        visited.len() - 1
+    }
+
+    /// Score of neighbors reached by expanding `node_id`, or `None` when
+    /// hub dampening stops the traversal at it.
+    fn expansion_score(&self, node_id: &NodeId, score: f32, index: &EdgeIndex<'_>) -> Option<f32> {
+        match self.hub_dampening {
+            None => Some(score),
+            Some(HubDampening::Skip { max_degree }) => {
+                (*node_id == self.origin || index.degree(node_id) <= max_degree).then_some(score)
+            }
+            Some(HubDampening::InverseDegree { .. }) => Some(score / index.degree(node_id).max(1) as f32),
+        }
+    }
+
+    /// Whether a neighbor with this score is reached at all
+    fn reaches(&self, score: f32) -> bool {
+        match self.hub_dampening {
+            Some(HubDampening::InverseDegree { min_score }) => score >= min_score,
+            _ => true,
+        }
+    }
+
     /// Get edges for a node based on direction
     fn get_edges<'a>(&self, node_id: &NodeId, index: &'a EdgeIndex<'a>) -> Vec<&'a Edge> {
         match self.direction {
@@ -206,6 +416,10 @@ impl<'a> EdgeIndex<'a> {
     fn incoming(&self, node_id: &NodeId) -> Vec<&'a Edge> {
         self.incoming.get(node_id).cloned().unwrap_or_default()
     }
+
+    fn degree(&self, node_id: &NodeId) -> usize {
+        self.outgoing.get(node_id).map_or(0, Vec::len) + self.incoming.get(node_id).map_or(0, Vec::len)
+    }
 }
 
 #[cfg(test)]
@@ -237,6 +451,142 @@ mod tests {
         assert_eq!(result.levels[1].len(), 1, "neighbor appears once");
     }
 
+    // === Scenario: Reachability counts agree with full traversal ===
+    #[test]
+    fn count_reachable_matches_traversal_without_materializing() {
+        let mut ctx = Context::new("test");
+        let a = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let b = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let c = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let d = ctx.add_node(Node::new("concept", ContentType::Concept));
+        ctx.add_edge(Edge::new(a.clone(), b.clone(), "related"));
+        ctx.add_edge(Edge::new(b.clone(), c.clone(), "related"));
+        ctx.add_edge(Edge::new(a.clone(), c.clone(), "related"));
+        ctx.add_edge(Edge::new(d.clone(), a.clone(), "related"));
+
+        for query in [
+            TraverseQuery::from(a.clone()).depth(1),
+            TraverseQuery::from(a.clone()).depth(3),
+            TraverseQuery::from(a.clone()).depth(3).direction(Direction::Both),
+            TraverseQuery::from(c.clone()).depth(2).direction(Direction::Incoming),
+        ] {
+            let expected = query.execute(&ctx).all_nodes().len();
+            assert_eq!(query.count_reachable(&ctx), expected);
+        }
+        assert_eq!(TraverseQuery::from(NodeId::from_string("missing")).count_reachable(&ctx), 0);
+    }
+
+    // === Scenario: Inverse relationships traverse stored edges backwards ===
+    #[test]
+    fn ontology_inverses_and_undirected_relationships_resolve() {
+        use crate::graph::RelationshipOntology;
+
+        let mut ctx = Context::new("test");
+        let dir = ctx.add_node(Node::new("directory", ContentType::Document));
+        let file = ctx.add_node(Node::new("file", ContentType::Document));
+        let twin = ctx.add_node(Node::new("file", ContentType::Document));
+        ctx.add_edge(Edge::new(dir.clone(), file.clone(), "contains"));
+        ctx.add_edge(Edge::new(twin.clone(), file.clone(), "similar_to"));
+
+        let contained_in = TraverseQuery::from(file.clone()).with_relationship("contained_in");
+        let similar = TraverseQuery::from(file.clone()).with_relationship("similar_to");
+        assert!(contained_in.execute(&ctx).all_nodes().is_empty(), "no ontology, no inverse");
+        assert!(similar.execute(&ctx).all_nodes().is_empty());
+
+        ctx.metadata.relationship_ontology = Some(RelationshipOntology::builtin());
+        let result = contained_in.execute(&ctx);
+        assert_eq!(result.all_nodes().iter().map(|n| n.id.clone()).collect::<Vec<_>>(), vec![dir.clone()]);
+        assert_eq!(result.edges[0].relationship, "contains");
+        assert_eq!(contained_in.count_reachable(&ctx), 1);
+        assert_eq!(
+            TraverseQuery::from(dir.clone()).with_relationship("contained_in").execute(&ctx).all_nodes().len(),
+            0,
+            "the directory is inside nothing"
+        );
+        assert_eq!(similar.execute(&ctx).all_nodes()[0].id, twin);
+    }
+
+    // === Scenario: Explained traversal lists every shortest path ===
+    #[test]
+    fn explain_reports_each_same_depth_path_and_provenance() {
+        use crate::graph::{dimension, PropertyValue};
+
+        let mut ctx = Context::new("test");
+        let origin = ctx.add_node(Node::new("fragment", ContentType::Document));
+        let left = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let right = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let far = ctx.add_node(Node::new("fragment", ContentType::Document));
+        let mut mark = Node::new_in_dimension("mark", ContentType::Provenance, dimension::PROVENANCE);
+        mark.id = NodeId::from_string(format!("mark:content:{}", far));
+        mark.properties.insert("annotation".into(), PropertyValue::from("the far fragment"));
+        ctx.add_node(mark);
+        let mut via_left = Edge::new(origin.clone(), left.clone(), "tagged_with");
+        via_left.contributions.insert("content".into(), 1.0);
+        ctx.add_edge(via_left);
+        ctx.add_edge(Edge::new(origin.clone(), right.clone(), "tagged_with"));
+        ctx.add_edge(Edge::new(left.clone(), far.clone(), "tags"));
+        ctx.add_edge(Edge::new(right.clone(), far.clone(), "tags"));
+
+        let plain = TraverseQuery::from(origin.clone()).depth(2).execute(&ctx);
+        assert!(plain.explanations.is_empty(), "explanations are opt-in");
+
+        let result = TraverseQuery::from(origin.clone())
+            .depth(2)
+            .with_explanation()
+            .execute(&ctx);
+
+        assert_eq!(result.explanations.len(), 3);
+        let left_explained = result.explanations.iter().find(|e| e.node_id == left).unwrap();
+        assert_eq!(left_explained.paths.len(), 1);
+        assert_eq!(left_explained.paths[0][0].top_contributions[0].contributor, "content");
+        let far_explained = result.explanations.iter().find(|e| e.node_id == far).unwrap();
+        assert_eq!(far_explained.paths.len(), 2, "one path through each concept");
+        assert!(far_explained.paths.iter().all(|p| p.len() == 2));
+        assert_eq!(far_explained.provenance.len(), 1);
+        assert_eq!(far_explained.provenance[0].annotation.as_deref(), Some("the far fragment"));
+    }
+
+    // === Scenario: Hub dampening keeps a super-hub from reaching everything ===
+    #[test]
+    fn hub_dampening_stops_expansion_at_super_hubs() {
+        let mut ctx = Context::new("test");
+        let origin = ctx.add_node(Node::new("fragment", ContentType::Document));
+        let memory = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let niche = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let detail = ctx.add_node(Node::new("fragment", ContentType::Document));
+        ctx.add_edge(Edge::new(origin.clone(), memory.clone(), "tagged_with"));
+        ctx.add_edge(Edge::new(origin.clone(), niche.clone(), "tagged_with"));
+        ctx.add_edge(Edge::new(detail.clone(), niche.clone(), "tagged_with"));
+        for _ in 0..50 {
+            let other = ctx.add_node(Node::new("fragment", ContentType::Document));
+            ctx.add_edge(Edge::new(other, memory.clone(), "tagged_with"));
+        }
+
+        let base = TraverseQuery::from(origin.clone()).depth(2).direction(Direction::Both);
+        assert_eq!(base.execute(&ctx).all_nodes().len(), 53);
+
+        for dampening in [
+            HubDampening::Skip { max_degree: 10 },
+            HubDampening::InverseDegree { min_score: 0.05 },
+        ] {
+            let query = base.clone().with_hub_dampening(dampening);
+            let result = query.execute(&ctx);
+            let reached: HashSet<NodeId> = result.all_nodes().into_iter().map(|n| n.id.clone()).collect();
+            assert_eq!(reached, HashSet::from([memory.clone(), niche.clone(), detail.clone()]), "{dampening:?}");
+            assert_eq!(query.count_reachable(&ctx), 3, "{dampening:?}");
+            assert!(
+                result.edges.iter().all(|e| e.source != origin || reached.contains(&e.target)),
+                "{dampening:?}"
+            );
+        }
+
+        // The origin itself is expanded even when it is a hub
+        let from_hub = TraverseQuery::from(memory.clone())
+            .direction(Direction::Both)
+            .with_hub_dampening(HubDampening::Skip { max_degree: 10 });
+        assert_eq!(from_hub.count_reachable(&ctx), 51);
+    }
+
     fn create_test_graph() -> Context {
         let mut ctx = Context::new("test");
 
@@ -1,10 +1,13 @@
 //! Query types and result structures
 
-use crate::graph::{Edge, Node, NodeId};
+use crate::graph::{Context, Edge, EdgeId, Node, NodeId};
+use super::explain::NodeExplanation;
 use super::filter::RankBy;
+use std::collections::{BTreeMap, HashMap};
 
 /// Direction for edge traversal
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Direction {
     /// Follow outgoing edges (source -> target)
     #[default]
@@ -48,6 +51,9 @@ pub struct TraversalResult {
     pub levels: Vec<Vec<Node>>,
     /// Edges traversed
     pub edges: Vec<Edge>,
+    /// Per discovered node, when the query asked to explain
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub explanations: Vec<NodeExplanation>,
 }
 
 impl TraversalResult {
@@ -56,6 +62,7 @@ impl TraversalResult {
             origin,
             levels: Vec::new(),
             edges: Vec::new(),
+            explanations: Vec::new(),
         }
     }
 
@@ -220,6 +227,40 @@ mod tests {
     }
 }
 
+/// How a path's hop weights aggregate into its score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PathScoring {
+    /// Product of normalized hop weights: every weak hop costs
+    #[default]
+    Product,
+    /// The weakest hop's normalized weight: a path is as strong as its
+    /// weakest link
+    Min,
+}
+
+impl PathScoring {
+    /// Fold one more hop weight into a running score (1.0 for no hops).
+    pub fn combine(&self, score: f64, weight: f64) -> f64 {
+        match self {
+            PathScoring::Product => score * weight,
+            PathScoring::Min => score.min(weight),
+        }
+    }
+}
+
+/// One hop's part in a path score.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct HopScore {
+    pub edge_id: EdgeId,
+    /// The edge's share of the weight leaving the hop's start in the
+    /// direction it was followed: its source's outgoing weight forwards,
+    /// its target's incoming weight backwards
+    pub normalized_weight: f64,
+    /// Per-adapter contributions behind the edge
+    pub contributions: BTreeMap<String, f32>,
+}
+
 /// Result of a path query
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct PathResult {
@@ -231,6 +272,9 @@ pub struct PathResult {
     pub edges: Vec<Edge>,
     /// Path length (number of hops)
     pub length: usize,
+    /// Per node after the source, when the query asked to explain
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub explanations: Vec<NodeExplanation>,
 }
 
 impl PathResult {
@@ -240,6 +284,7 @@ impl PathResult {
             path: Vec::new(),
             edges: Vec::new(),
             length: 0,
+            explanations: Vec::new(),
         }
     }
 
@@ -250,6 +295,84 @@ impl PathResult {
             path,
             edges,
             length,
+            explanations: Vec::new(),
+        }
+    }
+
+    /// Score the path's hops under `scoring`.
+    pub fn scored(self, context: &Context, scoring: PathScoring) -> ScoredPath {
+        self.scored_by(&HopWeights::of(context), scoring)
+    }
+
+    pub(crate) fn scored_by(self, weights: &HopWeights<'_>, scoring: PathScoring) -> ScoredPath {
+        let mut from = self.path.first().map(|node| node.id.clone());
+        let hop_scores: Vec<HopScore> = self
+            .edges
+            .iter()
+            .map(|edge| {
+                let start = from.take().unwrap_or_else(|| edge.source.clone());
+                from = Some(if edge.source == start { edge.target.clone() } else { edge.source.clone() });
+                HopScore {
+                    edge_id: edge.id.clone(),
+                    normalized_weight: weights.share(edge, &start),
+                    contributions: edge.contributions.iter().map(|(k, v)| (k.clone(), *v)).collect(),
+                }
+            })
+            .collect();
+        let score = match self.found {
+            true => hop_scores.iter().fold(1.0, |score, hop| scoring.combine(score, hop.normalized_weight)),
+            false => 0.0,
+        };
+        ScoredPath { path: self, score, hop_scores }
+    }
+}
+
+/// A path with its hops' normalized weights and their aggregate.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ScoredPath {
+    #[serde(flatten)]
+    pub path: PathResult,
+    /// Aggregate of the hops' normalized weights under a `PathScoring`;
+    /// 1.0 for a zero-hop path, 0.0 when not found
+    pub score: f64,
+    /// Per hop, in path order
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub hop_scores: Vec<HopScore>,
+}
+
+/// Order paths best first: highest score, then fewest hops.
+pub fn sort_paths_by_score(paths: &mut [ScoredPath]) {
+    paths.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.path.length.cmp(&b.path.length)));
+}
+
+/// Every node's total outgoing and incoming combined weight, for the
+/// share of it a hop carries.
+pub(crate) struct HopWeights<'a> {
+    outgoing: HashMap<&'a NodeId, f64>,
+    incoming: HashMap<&'a NodeId, f64>,
+}
+
+impl<'a> HopWeights<'a> {
+    pub(crate) fn of(context: &'a Context) -> Self {
+        let mut outgoing: HashMap<&NodeId, f64> = HashMap::new();
+        let mut incoming: HashMap<&NodeId, f64> = HashMap::new();
+        for edge in context.edges() {
+            *outgoing.entry(&edge.source).or_default() += f64::from(edge.combined_weight);
+            *incoming.entry(&edge.target).or_default() += f64::from(edge.combined_weight);
+        }
+        Self { outgoing, incoming }
+    }
+
+    /// `edge`'s share of `from`'s weight: outgoing when the hop follows
+    /// the edge from its source, incoming when it follows it backwards.
+    pub(crate) fn share(&self, edge: &Edge, from: &NodeId) -> f64 {
+        let total = match &edge.source == from {
+            true => self.outgoing.get(&edge.source),
+            false => self.incoming.get(&edge.target),
+        };
+        match total {
+            Some(&total) if total > 0.0 => f64::from(edge.combined_weight) / total,
+            _ => 0.0,
         }
     }
 }
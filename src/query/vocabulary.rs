@@ -0,0 +1,270 @@
+//! Graph-wide tag vocabulary with usage statistics
+//!
+//! `list_tags` only sees provenance marks. Vocabulary cleanup (merging
+//! near-duplicates, retiring stale tags) needs every tag the graph knows
+//! about: concept nodes in any dimension, each `tagged_with` edge into them,
+//! and mark tags that never became concepts.
+
+use crate::graph::{Context, Node, NodeId, PropertyValue};
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Width of the recent and prior windows used for `Trend`.
+pub const TREND_WINDOW_DAYS: i64 = 30;
+
+/// Direction of a tag's usage over the last two trend windows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Trend {
+    /// First used within the recent window.
+    New,
+    /// More uses in the recent window than the prior one.
+    Rising,
+    /// Equal, non-zero use in both windows.
+    Steady,
+    /// Fewer uses in the recent window than the prior one.
+    Declining,
+    /// No uses in the recent window.
+    Dormant,
+}
+
+/// Usage statistics for one tag.
+#[derive(Debug, Clone, Serialize)]
+pub struct TagStats {
+    /// Normalized tag (lowercase, no leading `#`).
+    pub tag: String,
+    /// The concept node for this tag, if one exists.
+    pub concept_id: Option<NodeId>,
+    /// Dimensions of the concept node and of the nodes tagged with it.
+    pub dimensions: Vec<String>,
+    /// Tag applications: `tagged_with` edges into the concept, or marks
+    /// carrying the tag when it has no such edges. (Content ingest records
+    /// each application twice — edge and mark — so the two aren't summed.)
+    pub usage_count: usize,
+    /// Provenance marks carrying the tag.
+    pub mark_count: usize,
+    pub first_seen: Option<DateTime<Utc>>,
+    pub last_seen: Option<DateTime<Utc>>,
+    /// Adapters contributing to the tag's `tagged_with` edges.
+    pub adapters: Vec<String>,
+    /// Uses within the last `TREND_WINDOW_DAYS`.
+    pub recent_uses: usize,
+    pub trend: Trend,
+}
+
+#[derive(Default)]
+struct Accumulator {
+    concept_id: Option<NodeId>,
+    dimensions: BTreeSet<String>,
+    adapters: BTreeSet<String>,
+    edge_uses: Vec<Option<DateTime<Utc>>>,
+    mark_uses: Vec<Option<DateTime<Utc>>>,
+}
+
+impl Accumulator {
+    fn finish(self, tag: String, now: DateTime<Utc>) -> TagStats {
+        let mark_count = self.mark_uses.len();
+        let uses = if self.edge_uses.is_empty() { self.mark_uses } else { self.edge_uses };
+        let times: Vec<DateTime<Utc>> = uses.iter().flatten().copied().collect();
+        let first_seen = times.iter().min().copied();
+        let last_seen = times.iter().max().copied();
+
+        let window = Duration::days(TREND_WINDOW_DAYS);
+        let recent_start = now - window;
+        let prior_start = recent_start - window;
+        let recent_uses = times.iter().filter(|t| **t > recent_start).count();
+        let prior_uses = times
+            .iter()
+            .filter(|t| **t > prior_start && **t <= recent_start)
+            .count();
+
+        let trend = if recent_uses == 0 {
+            Trend::Dormant
+        } else if first_seen.is_some_and(|f| f > recent_start) {
+            Trend::New
+        } else if recent_uses > prior_uses {
+            Trend::Rising
+        } else if recent_uses < prior_uses {
+            Trend::Declining
+        } else {
+            Trend::Steady
+        };
+
+        TagStats {
+            tag,
+            concept_id: self.concept_id,
+            dimensions: self.dimensions.into_iter().collect(),
+            usage_count: uses.len(),
+            mark_count,
+            first_seen,
+            last_seen,
+            adapters: self.adapters.into_iter().collect(),
+            recent_uses,
+            trend,
+        }
+    }
+}
+
+/// Normalize a tag the way concept IDs are derived: lowercase, no `#`.
+fn normalize_tag(tag: &str) -> String {
+    tag.trim().trim_start_matches('#').to_lowercase()
+}
+
+fn concept_label(node: &Node) -> String {
+    match node.properties.get("label") {
+        Some(PropertyValue::String(label)) => normalize_tag(label),
+        _ => normalize_tag(node.id.as_str().trim_start_matches("concept:")),
+    }
+}
+
+/// Aggregate every tag in the context, most-used first.
+///
+/// `now` anchors the trend windows.
+pub fn vocabulary(context: &Context, now: DateTime<Utc>) -> Vec<TagStats> {
+    let mut tags: BTreeMap<String, Accumulator> = BTreeMap::new();
+    let mut label_of: HashMap<&NodeId, String> = HashMap::new();
+
+    for node in context.nodes().filter(|n| n.node_type == "concept") {
+        let label = concept_label(node);
+        let acc = tags.entry(label.clone()).or_default();
+        acc.concept_id = Some(node.id.clone());
+        acc.dimensions.insert(node.dimension.clone());
+        label_of.insert(&node.id, label);
+    }
+
+    for edge in context.edges().filter(|e| e.relationship == "tagged_with") {
+        let Some(label) = label_of.get(&edge.target) else {
+            continue;
+        };
+        let acc = tags.get_mut(label).expect("concept label indexed above");
+        acc.edge_uses.push(Some(edge.created_at));
+        acc.dimensions.insert(edge.source_dimension.clone());
+        acc.adapters.extend(edge.contributions.keys().cloned());
+    }
+
+    for mark in context.nodes().filter(|n| n.node_type == "mark") {
+        let Some(PropertyValue::Array(values)) = mark.properties.get("tags") else {
+            continue;
+        };
+        for value in values {
+            if let PropertyValue::String(tag) = value {
+                let tag = normalize_tag(tag);
+                if tag.is_empty() {
+                    continue;
+                }
+                let acc = tags.entry(tag).or_default();
+                acc.mark_uses.push(mark.metadata.created_at);
+                acc.dimensions.insert(mark.dimension.clone());
+            }
+        }
+    }
+
+    let mut stats: Vec<TagStats> = tags
+        .into_iter()
+        .map(|(tag, acc)| acc.finish(tag, now))
+        .collect();
+    stats.sort_by(|a, b| b.usage_count.cmp(&a.usage_count).then_with(|| a.tag.cmp(&b.tag)));
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::concept_node;
+    use crate::graph::{dimension, ContentType, Edge};
+
+    fn tag(ctx: &mut Context, source: &str, concept: &NodeId, adapter: &str, at: DateTime<Utc>) {
+        let mut edge = Edge::new_cross_dimensional(
+            NodeId::from_string(source),
+            dimension::STRUCTURE,
+            concept.clone(),
+            dimension::SEMANTIC,
+            "tagged_with",
+        );
+        edge.created_at = at;
+        edge.contributions.insert(adapter.to_string(), 1.0);
+        ctx.edges.push(edge);
+    }
+
+    fn mark(ctx: &mut Context, id: &str, tags: &[&str], at: DateTime<Utc>) {
+        let mut node = Node::new_in_dimension("mark", ContentType::Provenance, dimension::PROVENANCE);
+        node.id = NodeId::from_string(id);
+        node.metadata.created_at = Some(at);
+        node.properties.insert(
+            "tags".to_string(),
+            PropertyValue::Array(tags.iter().map(|t| PropertyValue::from(*t)).collect()),
+        );
+        ctx.add_node(node);
+    }
+
+    // === Scenario: Concept usage aggregates tagged_with edges ===
+    #[test]
+    fn concept_usage_counts_edges_and_adapters() {
+        let now = Utc::now();
+        let mut ctx = Context::new("vocab");
+        let (travel, node) = concept_node("travel");
+        ctx.add_node(node);
+        tag(&mut ctx, "frag:1", &travel, "content", now - Duration::days(40));
+        tag(&mut ctx, "frag:2", &travel, "extraction", now - Duration::days(2));
+
+        let stats = vocabulary(&ctx, now);
+
+        assert_eq!(stats.len(), 1);
+        let travel_stats = &stats[0];
+        assert_eq!(travel_stats.tag, "travel");
+        assert_eq!(travel_stats.concept_id, Some(travel));
+        assert_eq!(travel_stats.usage_count, 2);
+        assert_eq!(travel_stats.adapters, vec!["content", "extraction"]);
+        assert_eq!(travel_stats.dimensions, vec![dimension::SEMANTIC, dimension::STRUCTURE]);
+        assert_eq!(travel_stats.first_seen, Some(now - Duration::days(40)));
+        assert_eq!(travel_stats.last_seen, Some(now - Duration::days(2)));
+        assert_eq!(travel_stats.recent_uses, 1);
+        assert_eq!(travel_stats.trend, Trend::Steady);
+    }
+
+    // === Scenario: Mark tags join their concept or stand alone ===
+    #[test]
+    fn mark_tags_merge_with_concepts_by_normalized_label() {
+        let now = Utc::now();
+        let mut ctx = Context::new("vocab");
+        let (_, node) = concept_node("avignon");
+        ctx.add_node(node);
+        mark(&mut ctx, "mark:1", &["#Avignon", "#todo"], now);
+
+        let stats = vocabulary(&ctx, now);
+
+        let avignon = stats.iter().find(|s| s.tag == "avignon").unwrap();
+        assert!(avignon.concept_id.is_some());
+        assert_eq!(avignon.usage_count, 1);
+        assert_eq!(avignon.mark_count, 1);
+        let todo = stats.iter().find(|s| s.tag == "todo").unwrap();
+        assert!(todo.concept_id.is_none());
+        assert_eq!(todo.dimensions, vec![dimension::PROVENANCE]);
+    }
+
+    // === Scenario: Trend compares the recent window to the prior one ===
+    #[test]
+    fn trend_reflects_recent_versus_prior_usage() {
+        let now = Utc::now();
+        let mut ctx = Context::new("vocab");
+        mark(&mut ctx, "m1", &["rising"], now - Duration::days(45));
+        mark(&mut ctx, "m2", &["rising"], now - Duration::days(5));
+        mark(&mut ctx, "m3", &["rising"], now - Duration::days(4));
+        mark(&mut ctx, "m4", &["declining"], now - Duration::days(50));
+        mark(&mut ctx, "m5", &["declining"], now - Duration::days(40));
+        mark(&mut ctx, "m6", &["declining"], now - Duration::days(1));
+        mark(&mut ctx, "m7", &["dormant"], now - Duration::days(90));
+        mark(&mut ctx, "m8", &["fresh"], now - Duration::days(3));
+
+        let stats = vocabulary(&ctx, now);
+        let trend = |t: &str| stats.iter().find(|s| s.tag == t).unwrap().trend;
+
+        assert_eq!(trend("rising"), Trend::Rising);
+        assert_eq!(trend("declining"), Trend::Declining);
+        assert_eq!(trend("dormant"), Trend::Dormant);
+        assert_eq!(trend("fresh"), Trend::New);
+        // Most-used first
+        assert_eq!(stats[0].usage_count, 3);
+    }
+}
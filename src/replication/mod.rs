@@ -0,0 +1,657 @@
+//! Peer-to-peer context replication
+//!
+//! Two Plexus instances mirror each other's contexts by exchanging
+//! changelog entries: a home server runs a `ReplicationServer`, and a
+//! laptop runs a `Replica` that pushes its changes and pulls the
+//! server's, each side tracking how far into the other's event log
+//! (ADR-035 cursors) it has got.
+//!
+//! The wire protocol is JSON `WireMessage`s — newline-delimited over TCP,
+//! or one per text message over WebSocket (`ws://` peers, see
+//! `websocket`) — one request and one response per exchange: `Pull` is
+//! answered with `Changes`, and `Changes` with `Ack`. A server with a
+//! `TenantDirectory` expects `Hello` with an API key first, and then
+//! serves and accepts only that tenant's contexts. Frames past the
+//! size limit (`MAX_FRAME_BYTES` by default) drop the connection.
+//!
+//! A `ContextDelta` is a partial copy of a context holding the nodes and
+//! edges named by events after the cursor (trashed ones as tombstones),
+//! or the whole context from cursor 0. Receivers join it with
+//! `PlexusEngine::merge_copy`, so replayed and echoed deltas change
+//! nothing. Hard deletes (`prune`) and contribution retractions carry no
+//! item in the log and stay local. Without a store there is no event log
+//! and every exchange ships whole contexts.
+
+mod websocket;
+
+use crate::graph::{Context, ContextId, ContextSync, PlexusEngine, PlexusError, PlexusResult, TenantDirectory};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::io::ErrorKind;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::warn;
+use websocket::WebSocket;
+
+/// Adapter ID the merge events of replicated changes are attributed to.
+pub const REPLICATION_ADAPTER: &str = "replication";
+
+/// Default limit on one wire message, in bytes.
+pub const MAX_FRAME_BYTES: usize = 64 * 1024 * 1024;
+
+/// Changes to one context between two cursors of the sender's event log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContextDelta {
+    pub context_id: ContextId,
+    pub since: u64,
+    /// The sender's latest sequence; the receiver's next cursor
+    pub until: u64,
+    /// The changed nodes and edges (the whole context when `since` is 0)
+    pub changes: Context,
+}
+
+/// One message of the replication protocol.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WireMessage {
+    /// Authenticate the connection; answered with `Welcome`
+    Hello { api_key: String },
+    /// The connection acts for `tenant` (`None`: every context)
+    Welcome { tenant: Option<String> },
+    /// Ask for changes after each context's cursor; contexts not listed
+    /// start from 0
+    Pull { cursors: BTreeMap<String, u64> },
+    /// Changes for the contexts that have any
+    Changes { deltas: Vec<ContextDelta> },
+    /// The receiver applied `Changes`; the cursors it reached
+    Ack { cursors: BTreeMap<String, u64> },
+    Error { message: String },
+}
+
+/// Changes to a context after `cursor`, or `None` when there are none.
+pub fn changes_since(engine: &PlexusEngine, id: &ContextId, cursor: u64) -> PlexusResult<Option<ContextDelta>> {
+    // The cursor is read before the context, so the copy is at least as
+    // new as `until`; events racing the read are resent next time
+    let until = engine.latest_sequence(id.as_str())?;
+    let context = engine.get_context(id).ok_or_else(|| PlexusError::ContextNotFound(id.clone()))?;
+    if cursor == 0 || !engine.has_store() {
+        return Ok(Some(ContextDelta { context_id: id.clone(), since: 0, until, changes: context }));
+    }
+    if until <= cursor {
+        return Ok(None);
+    }
+
+    let mut node_ids = HashSet::new();
+    let mut edge_ids = HashSet::new();
+    for event in engine.query_events_since(id.as_str(), cursor, None)? {
+        node_ids.extend(event.node_ids);
+        edge_ids.extend(event.edge_ids);
+    }
+    let mut changes = Context::with_id(context.id.clone(), context.name.clone());
+    changes.description = context.description.clone();
+    changes.metadata = context.metadata.clone();
+    for edge in context.edges.iter().filter(|e| edge_ids.contains(e.id.as_str())) {
+        // Endpoints travel with the edge so the receiver can place it
+        node_ids.insert(edge.source.as_str().to_string());
+        node_ids.insert(edge.target.as_str().to_string());
+        changes.edges.push(edge.clone());
+    }
+    for (node_id, node) in context.nodes.iter().filter(|(n, _)| node_ids.contains(n.as_str())) {
+        changes.nodes.insert(node_id.clone(), node.clone());
+    }
+    for (node_id, tombstone) in context.trash.nodes.iter().filter(|(n, _)| node_ids.contains(n.as_str())) {
+        changes.trash.nodes.insert(node_id.clone(), tombstone.clone());
+    }
+    changes.trash.edges = context
+        .trash
+        .edges
+        .iter()
+        .filter(|t| edge_ids.contains(t.item.id.as_str()))
+        .cloned()
+        .collect();
+    Ok(Some(ContextDelta { context_id: id.clone(), since: cursor, until, changes }))
+}
+
+/// Join a delta into the local copy (adopting a context new here).
+pub fn apply_delta(engine: &PlexusEngine, delta: ContextDelta) -> PlexusResult<Option<ContextSync>> {
+    engine.merge_copy(delta.changes, REPLICATION_ADAPTER)
+}
+
+/// What one replication exchange changed locally.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ReplicationReport {
+    /// Contexts copied from the peer for the first time
+    pub adopted: Vec<String>,
+    /// Contexts merged with the peer's changes
+    pub merged: Vec<ContextSync>,
+    /// Deltas sent to the peer
+    pub pushed: usize,
+}
+
+/// The contexts a connection may read and write.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Scope {
+    All,
+    Tenant(String),
+}
+
+impl Scope {
+    fn tenant(&self) -> Option<&str> {
+        match self {
+            Scope::All => None,
+            Scope::Tenant(tenant) => Some(tenant),
+        }
+    }
+}
+
+/// A connection carrying wire messages.
+enum Channel {
+    Lines { reader: BufReader<OwnedReadHalf>, writer: OwnedWriteHalf, max_frame_bytes: usize },
+    WebSocket(WebSocket),
+}
+
+impl Channel {
+    fn lines(stream: TcpStream, max_frame_bytes: usize) -> Self {
+        let (reader, writer) = stream.into_split();
+        Channel::Lines { reader: BufReader::new(reader), writer, max_frame_bytes }
+    }
+
+    /// The next message's text, or `None` once the peer hangs up.
+    async fn recv(&mut self) -> std::io::Result<Option<String>> {
+        match self {
+            Channel::Lines { reader, max_frame_bytes, .. } => read_line(reader, *max_frame_bytes).await,
+            Channel::WebSocket(socket) => socket.recv().await,
+        }
+    }
+
+    async fn send(&mut self, message: &WireMessage) -> std::io::Result<()> {
+        let text = serde_json::to_string(message)?;
+        match self {
+            Channel::Lines { writer, .. } => {
+                writer.write_all(text.as_bytes()).await?;
+                writer.write_all(b"\n").await?;
+                writer.flush().await
+            }
+            Channel::WebSocket(socket) => socket.send(&text).await,
+        }
+    }
+}
+
+/// Read one newline-terminated line of at most `max` bytes.
+async fn read_line(reader: &mut BufReader<OwnedReadHalf>, max: usize) -> std::io::Result<Option<String>> {
+    let mut line = Vec::new();
+    (&mut *reader).take(max as u64 + 1).read_until(b'\n', &mut line).await?;
+    if line.is_empty() {
+        return Ok(None);
+    }
+    if line.last() == Some(&b'\n') {
+        line.pop();
+    } else if line.len() > max {
+        return Err(std::io::Error::new(ErrorKind::InvalidData, format!("message exceeds {} bytes", max)));
+    }
+    String::from_utf8(line).map(Some).map_err(|_| std::io::Error::new(ErrorKind::InvalidData, "message isn't UTF-8"))
+}
+
+/// Answers replication requests from peers against an engine.
+pub struct ReplicationServer {
+    engine: Arc<PlexusEngine>,
+    /// API keys and their tenants; `None` serves every context to any peer
+    tenants: Option<TenantDirectory>,
+    max_frame_bytes: usize,
+}
+
+impl ReplicationServer {
+    pub fn new(engine: Arc<PlexusEngine>) -> Self {
+        Self { engine, tenants: None, max_frame_bytes: MAX_FRAME_BYTES }
+    }
+
+    /// Require peers to authenticate with a key from `directory`, and
+    /// confine each to its tenant's contexts.
+    pub fn with_tenants(mut self, directory: TenantDirectory) -> Self {
+        self.tenants = Some(directory);
+        self
+    }
+
+    /// Drop connections sending a message longer than `bytes`.
+    pub fn with_max_frame_bytes(mut self, bytes: usize) -> Self {
+        self.max_frame_bytes = bytes;
+        self
+    }
+
+    /// Accept peers over newline-delimited TCP until the listener fails,
+    /// one task per connection.
+    pub async fn serve(self, listener: TcpListener) -> std::io::Result<()> {
+        self.accept_loop(listener, false).await
+    }
+
+    /// Accept peers over WebSocket until the listener fails.
+    pub async fn serve_websocket(self, listener: TcpListener) -> std::io::Result<()> {
+        self.accept_loop(listener, true).await
+    }
+
+    async fn accept_loop(self, listener: TcpListener, websocket: bool) -> std::io::Result<()> {
+        if self.tenants.is_none() {
+            warn!("replication server accepts unauthenticated peers");
+        }
+        let server = Arc::new(self);
+        loop {
+            let (stream, peer) = listener.accept().await?;
+            let server = server.clone();
+            tokio::spawn(async move {
+                let channel = match websocket {
+                    true => WebSocket::accept(stream, server.max_frame_bytes).await.map(Channel::WebSocket),
+                    false => Ok(Channel::lines(stream, server.max_frame_bytes)),
+                };
+                if let Err(e) = async { server.handle(channel?).await }.await {
+                    warn!(peer = %peer, error = %e, "replication connection failed");
+                }
+            });
+        }
+    }
+
+    async fn handle(&self, mut channel: Channel) -> std::io::Result<()> {
+        let mut scope = match self.tenants {
+            Some(_) => None,
+            None => Some(Scope::All),
+        };
+        loop {
+            let line = match channel.recv().await {
+                Ok(Some(line)) => line,
+                Ok(None) => return Ok(()),
+                Err(e) => {
+                    let _ = channel.send(&WireMessage::Error { message: e.to_string() }).await;
+                    return Err(e);
+                }
+            };
+            let response = match (serde_json::from_str(&line), &scope) {
+                (Ok(WireMessage::Hello { api_key }), _) => match self.authenticate(&api_key) {
+                    Some(granted) => {
+                        let welcome = WireMessage::Welcome { tenant: granted.tenant().map(str::to_string) };
+                        scope = Some(granted);
+                        welcome
+                    }
+                    None => WireMessage::Error { message: "unknown API key".to_string() },
+                },
+                (Ok(request), Some(scope)) => self.respond_in(scope, request),
+                (Ok(_), None) => WireMessage::Error { message: "authenticate with hello first".to_string() },
+                (Err(e), _) => WireMessage::Error { message: format!("malformed message: {}", e) },
+            };
+            channel.send(&response).await?;
+        }
+    }
+
+    /// The scope an API key grants: its tenant's, or every context when
+    /// the server has no directory.
+    fn authenticate(&self, api_key: &str) -> Option<Scope> {
+        match &self.tenants {
+            None => Some(Scope::All),
+            Some(directory) => directory.authenticate(api_key).map(|(tenant, _)| Scope::Tenant(tenant.to_string())),
+        }
+    }
+
+    /// The response to one request from an in-process caller, who may
+    /// see every context.
+    pub fn respond(&self, request: WireMessage) -> WireMessage {
+        self.respond_in(&Scope::All, request)
+    }
+
+    fn respond_in(&self, scope: &Scope, request: WireMessage) -> WireMessage {
+        let result = match request {
+            WireMessage::Pull { cursors } => self.pull(scope, &cursors).map(|deltas| WireMessage::Changes { deltas }),
+            WireMessage::Changes { deltas } => self.apply(scope, deltas).map(|cursors| WireMessage::Ack { cursors }),
+            other => Err(PlexusError::InvalidInput(format!("unexpected request: {:?}", other))),
+        };
+        result.unwrap_or_else(|e| WireMessage::Error { message: e.to_string() })
+    }
+
+    fn contexts(&self, scope: &Scope) -> Vec<ContextId> {
+        match scope {
+            Scope::All => self.engine.list_contexts(),
+            Scope::Tenant(tenant) => self.engine.list_contexts_in_tenant(Some(tenant)),
+        }
+    }
+
+    fn pull(&self, scope: &Scope, cursors: &BTreeMap<String, u64>) -> PlexusResult<Vec<ContextDelta>> {
+        let mut deltas = Vec::new();
+        for id in self.contexts(scope) {
+            let cursor = cursors.get(id.as_str()).copied().unwrap_or(0);
+            deltas.extend(changes_since(&self.engine, &id, cursor)?);
+        }
+        Ok(deltas)
+    }
+
+    fn apply(&self, scope: &Scope, mut deltas: Vec<ContextDelta>) -> PlexusResult<BTreeMap<String, u64>> {
+        // Check every delta before applying any
+        let own: HashSet<ContextId> = self.contexts(scope).into_iter().collect();
+        for delta in &mut deltas {
+            if delta.changes.id != delta.context_id {
+                return Err(PlexusError::InvalidInput(format!(
+                    "delta for {} carries changes to {}",
+                    delta.context_id, delta.changes.id
+                )));
+            }
+            if let Scope::Tenant(tenant) = scope {
+                if self.engine.has_context(&delta.context_id) && !own.contains(&delta.context_id) {
+                    return Err(PlexusError::InvalidInput(format!("context {} belongs to another tenant", delta.context_id)));
+                }
+                delta.changes.metadata.tenant = Some(tenant.clone());
+            }
+        }
+        let mut cursors = BTreeMap::new();
+        for delta in deltas {
+            cursors.insert(delta.context_id.as_str().to_string(), delta.until);
+            apply_delta(&self.engine, delta)?;
+        }
+        Ok(cursors)
+    }
+}
+
+/// A local engine mirroring its contexts with a peer's `ReplicationServer`.
+pub struct Replica {
+    engine: Arc<PlexusEngine>,
+    /// The server's `host:port`, or `ws://host:port/path` for WebSocket
+    peer: String,
+    api_key: Option<String>,
+    max_frame_bytes: usize,
+    /// How far into the peer's log we have pulled
+    pull_cursors: BTreeMap<String, u64>,
+    /// How far into our log the peer has acknowledged
+    push_cursors: BTreeMap<String, u64>,
+}
+
+impl Replica {
+    /// `peer` is the server's `host:port`, or a `ws://` URL.
+    pub fn new(engine: Arc<PlexusEngine>, peer: impl Into<String>) -> Self {
+        Self {
+            engine,
+            peer: peer.into(),
+            api_key: None,
+            max_frame_bytes: MAX_FRAME_BYTES,
+            pull_cursors: BTreeMap::new(),
+            push_cursors: BTreeMap::new(),
+        }
+    }
+
+    /// Authenticate to the peer with `api_key`.
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Fail exchanges whose response is longer than `bytes`.
+    pub fn with_max_frame_bytes(mut self, bytes: usize) -> Self {
+        self.max_frame_bytes = bytes;
+        self
+    }
+
+    /// Resume from cursors saved by an earlier run (see `cursors`).
+    pub fn with_cursors(mut self, pull: BTreeMap<String, u64>, push: BTreeMap<String, u64>) -> Self {
+        self.pull_cursors = pull;
+        self.push_cursors = push;
+        self
+    }
+
+    /// The pull and push cursors reached so far.
+    pub fn cursors(&self) -> (&BTreeMap<String, u64>, &BTreeMap<String, u64>) {
+        (&self.pull_cursors, &self.push_cursors)
+    }
+
+    /// Send local changes the peer hasn't acknowledged.
+    pub async fn push(&mut self) -> PlexusResult<usize> {
+        let mut deltas = Vec::new();
+        for id in self.engine.list_contexts() {
+            let cursor = self.push_cursors.get(id.as_str()).copied().unwrap_or(0);
+            deltas.extend(changes_since(&self.engine, &id, cursor)?);
+        }
+        if deltas.is_empty() {
+            return Ok(0);
+        }
+        let sent = deltas.len();
+        match self.exchange(&WireMessage::Changes { deltas }).await? {
+            WireMessage::Ack { cursors } => {
+                self.push_cursors.extend(cursors);
+                Ok(sent)
+            }
+            other => Err(unexpected(other)),
+        }
+    }
+
+    /// Fetch and apply the peer's changes since the last pull.
+    pub async fn pull(&mut self) -> PlexusResult<ReplicationReport> {
+        let request = WireMessage::Pull { cursors: self.pull_cursors.clone() };
+        let WireMessage::Changes { deltas } = self.exchange(&request).await? else {
+            return Err(PlexusError::Unavailable("replication peer did not answer with changes".to_string()));
+        };
+        let mut report = ReplicationReport::default();
+        for delta in deltas {
+            let id = delta.context_id.as_str().to_string();
+            let (name, until) = (delta.changes.name.clone(), delta.until);
+            match apply_delta(&self.engine, delta)? {
+                Some(sync) => report.merged.push(sync),
+                None => report.adopted.push(name),
+            }
+            self.pull_cursors.insert(id, until);
+        }
+        Ok(report)
+    }
+
+    /// Push, then pull.
+    pub async fn sync_once(&mut self) -> PlexusResult<ReplicationReport> {
+        let pushed = self.push().await?;
+        let mut report = self.pull().await?;
+        report.pushed = pushed;
+        Ok(report)
+    }
+
+    /// Mirror continuously, one `sync_once` per `interval`. Failed rounds
+    /// are logged and retried on the next tick.
+    pub async fn run(mut self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = self.sync_once().await {
+                warn!(peer = %self.peer, error = %e, "replication round failed");
+            }
+        }
+    }
+
+    async fn exchange(&self, request: &WireMessage) -> PlexusResult<WireMessage> {
+        let mut channel = match self.peer.starts_with("ws://") {
+            true => Channel::WebSocket(WebSocket::connect(&self.peer, self.max_frame_bytes).await.map_err(|e| wire_error(&self.peer, e))?),
+            false => Channel::lines(TcpStream::connect(&self.peer).await.map_err(|e| wire_error(&self.peer, e))?, self.max_frame_bytes),
+        };
+        if let Some(api_key) = &self.api_key {
+            match self.round_trip(&mut channel, &WireMessage::Hello { api_key: api_key.clone() }).await? {
+                WireMessage::Welcome { .. } => {}
+                other => return Err(unexpected(other)),
+            }
+        }
+        self.round_trip(&mut channel, request).await
+    }
+
+    async fn round_trip(&self, channel: &mut Channel, request: &WireMessage) -> PlexusResult<WireMessage> {
+        channel.send(request).await.map_err(|e| wire_error(&self.peer, e))?;
+        let line = channel
+            .recv()
+            .await
+            .map_err(|e| wire_error(&self.peer, e))?
+            .ok_or_else(|| PlexusError::Unavailable(format!("replication peer {} closed the connection", self.peer)))?;
+        match serde_json::from_str(&line)? {
+            WireMessage::Error { message } => Err(PlexusError::Other(format!("replication peer {}: {}", self.peer, message))),
+            response => Ok(response),
+        }
+    }
+}
+
+fn wire_error(peer: &str, e: std::io::Error) -> PlexusError {
+    PlexusError::Unavailable(format!("replication peer {}: {}", peer, e))
+}
+
+fn unexpected(message: WireMessage) -> PlexusError {
+    PlexusError::Other(format!("unexpected replication response: {:?}", message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{dimension, ContentType, Node, NodeId};
+    use crate::graph::events::GraphEvent;
+    use crate::storage::{OpenStore, SqliteStore};
+
+    fn engine() -> Arc<PlexusEngine> {
+        Arc::new(PlexusEngine::with_store(Arc::new(SqliteStore::open_in_memory().unwrap())))
+    }
+
+    fn add_node(engine: &PlexusEngine, ctx: &ContextId, id: &str) {
+        let mut node = Node::new_in_dimension("concept", ContentType::Concept, dimension::SEMANTIC);
+        node.id = NodeId::from_string(id);
+        engine.with_context_mut(ctx, |c| c.add_node(node)).unwrap();
+        engine.persist_events(&[GraphEvent::NodesAdded {
+            node_ids: vec![NodeId::from_string(id)],
+            adapter_id: "test".into(),
+            context_id: ctx.as_str().into(),
+        }]);
+    }
+
+    async fn server() -> (Arc<PlexusEngine>, String) {
+        let engine = engine();
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        tokio::spawn(ReplicationServer::new(engine.clone()).serve(listener));
+        (engine, addr)
+    }
+
+    // === Scenario: Deltas carry only what changed after the cursor ===
+    #[test]
+    fn delta_holds_changes_after_cursor() {
+        let engine = engine();
+        let id = engine.upsert_context(Context::new("notes")).unwrap();
+        add_node(&engine, &id, "a");
+        let cursor = engine.latest_sequence(id.as_str()).unwrap();
+        add_node(&engine, &id, "b");
+
+        let full = changes_since(&engine, &id, 0).unwrap().unwrap();
+        assert_eq!(full.changes.node_count(), 2);
+        let delta = changes_since(&engine, &id, cursor).unwrap().unwrap();
+        assert_eq!(delta.since, cursor);
+        assert_eq!(delta.changes.nodes.keys().collect::<Vec<_>>(), [&NodeId::from_string("b")]);
+        assert!(changes_since(&engine, &id, delta.until).unwrap().is_none());
+    }
+
+    // === Scenario: A laptop and a home server mirror each other over TCP ===
+    #[tokio::test]
+    async fn replica_pushes_and_pulls_over_tcp() {
+        let (home, addr) = server().await;
+        let laptop = engine();
+        let id = laptop.upsert_context(Context::new("research")).unwrap();
+        add_node(&laptop, &id, "from-laptop");
+
+        let mut replica = Replica::new(laptop.clone(), addr);
+        let first = replica.sync_once().await.unwrap();
+        assert_eq!(first.pushed, 1);
+        assert!(home.get_context(&id).unwrap().get_node(&NodeId::from_string("from-laptop")).is_some());
+
+        add_node(&home, &id, "from-home");
+        add_node(&laptop, &id, "also-laptop");
+        let second = replica.sync_once().await.unwrap();
+        assert_eq!(second.pushed, 1);
+        let here = laptop.get_context(&id).unwrap();
+        let there = home.get_context(&id).unwrap();
+        assert_eq!(here.node_count(), 3);
+        assert_eq!(there.node_count(), 3);
+
+        let third = replica.sync_once().await.unwrap();
+        assert!(third.merged.iter().all(|s| s.nodes_added.is_empty()), "replays change nothing");
+    }
+
+    // === Scenario: Malformed and unexpected requests get an error reply ===
+    #[test]
+    fn server_rejects_unexpected_requests() {
+        let server = ReplicationServer::new(engine());
+        let reply = server.respond(WireMessage::Ack { cursors: BTreeMap::new() });
+        assert!(matches!(reply, WireMessage::Error { .. }));
+        let reply = server.respond(WireMessage::Pull { cursors: BTreeMap::new() });
+        assert!(matches!(reply, WireMessage::Changes { deltas } if deltas.is_empty()));
+    }
+
+    fn tenant_context(engine: &PlexusEngine, name: &str, tenant: &str) -> ContextId {
+        let mut context = Context::new(name);
+        context.metadata.tenant = Some(tenant.to_string());
+        engine.upsert_context(context).unwrap()
+    }
+
+    // === Scenario: Replicas sync over WebSocket too ===
+    #[tokio::test]
+    async fn replica_syncs_over_websocket() {
+        let home = engine();
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/replicate", listener.local_addr().unwrap());
+        tokio::spawn(ReplicationServer::new(home.clone()).serve_websocket(listener));
+
+        let laptop = engine();
+        let id = laptop.upsert_context(Context::new("research")).unwrap();
+        add_node(&laptop, &id, "from-laptop");
+        let mut replica = Replica::new(laptop.clone(), url);
+        assert_eq!(replica.sync_once().await.unwrap().pushed, 1);
+        assert!(home.get_context(&id).unwrap().get_node(&NodeId::from_string("from-laptop")).is_some());
+
+        add_node(&home, &id, "from-home");
+        replica.sync_once().await.unwrap();
+        assert_eq!(laptop.get_context(&id).unwrap().node_count(), 2);
+    }
+
+    // === Scenario: With a tenant directory, peers authenticate and see only their tenant ===
+    #[tokio::test]
+    async fn tenant_servers_authenticate_and_scope_peers() {
+        let home = engine();
+        let ours = tenant_context(&home, "ours", "acme");
+        let theirs = tenant_context(&home, "theirs", "globex");
+        add_node(&home, &theirs, "secret");
+        let directory: TenantDirectory = serde_json::from_value(serde_json::json!({
+            "tenants": {"acme": {"api_keys": ["acme-key"]}, "globex": {"api_keys": ["globex-key"]}}
+        }))
+        .unwrap();
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        tokio::spawn(ReplicationServer::new(home.clone()).with_tenants(directory).serve(listener));
+
+        let err = Replica::new(engine(), addr.clone()).pull().await.unwrap_err();
+        assert!(err.to_string().contains("authenticate"), "{err}");
+        let err = Replica::new(engine(), addr.clone()).with_api_key("wrong").pull().await.unwrap_err();
+        assert!(err.to_string().contains("unknown API key"), "{err}");
+
+        let laptop = engine();
+        let report = Replica::new(laptop.clone(), addr.clone()).with_api_key("acme-key").pull().await.unwrap();
+        assert_eq!(report.adopted, vec!["ours".to_string()]);
+        assert!(laptop.get_context(&ours).is_some());
+        assert!(laptop.get_context(&theirs).is_none(), "another tenant's context isn't served");
+
+        // Pushing into another tenant's context is refused
+        let intruder = engine();
+        let mut copy = Context::new("theirs");
+        copy.id = theirs.clone();
+        intruder.upsert_context(copy).unwrap();
+        add_node(&intruder, &theirs, "planted");
+        let err = Replica::new(intruder, addr).with_api_key("acme-key").push().await.unwrap_err();
+        assert!(err.to_string().contains("another tenant"), "{err}");
+        assert!(home.get_context(&theirs).unwrap().get_node(&NodeId::from_string("planted")).is_none());
+    }
+
+    // === Scenario: Oversized messages drop the connection ===
+    #[tokio::test]
+    async fn oversized_frames_are_refused() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(ReplicationServer::new(engine()).with_max_frame_bytes(64).serve(listener));
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream.write_all(&[b'x'; 200]).await.unwrap();
+        stream.write_all(b"\n").await.unwrap();
+        let mut reply = String::new();
+        BufReader::new(stream).read_line(&mut reply).await.unwrap();
+        assert!(reply.contains("exceeds 64 bytes"), "{reply}");
+    }
+}
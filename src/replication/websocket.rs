@@ -0,0 +1,247 @@
+//! WebSocket transport for replication (RFC 6455)
+//!
+//! Carries the same `WireMessage`s as the TCP transport, one per text
+//! message, for peers behind proxies that only pass HTTP. Just enough of
+//! the protocol for that: the upgrade handshake, masked client frames,
+//! fragmented messages, ping/pong and close. Extensions and
+//! subprotocols aren't negotiated.
+
+use base64::Engine;
+use std::io::{Error, ErrorKind, Result};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+
+/// Appended to the client's key before hashing (RFC 6455 §1.3).
+const ACCEPT_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// Longest handshake request or response head read.
+const MAX_HEAD_BYTES: usize = 16 * 1024;
+
+const OP_CONTINUATION: u8 = 0x0;
+const OP_TEXT: u8 = 0x1;
+const OP_CLOSE: u8 = 0x8;
+const OP_PING: u8 = 0x9;
+const OP_PONG: u8 = 0xA;
+
+/// Which end of the connection this is: clients mask what they send,
+/// servers require it of what they receive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    Client,
+    Server,
+}
+
+/// An open WebSocket connection exchanging text messages.
+pub(super) struct WebSocket {
+    stream: BufReader<TcpStream>,
+    role: Role,
+    max_message_bytes: usize,
+}
+
+impl WebSocket {
+    /// Complete the server side of the upgrade handshake on `stream`.
+    pub(super) async fn accept(stream: TcpStream, max_message_bytes: usize) -> Result<Self> {
+        let mut stream = BufReader::new(stream);
+        let head = read_head(&mut stream).await?;
+        let key = head
+            .lines()
+            .skip(1)
+            .filter_map(|line| line.split_once(':'))
+            .find(|(name, _)| name.trim().eq_ignore_ascii_case("sec-websocket-key"))
+            .map(|(_, value)| value.trim().to_string());
+        let upgrade = head.lines().next().is_some_and(|line| line.starts_with("GET "))
+            && header_has(&head, "upgrade", "websocket");
+        let Some(key) = key.filter(|_| upgrade) else {
+            stream.write_all(b"HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\n\r\n").await?;
+            return Err(Error::new(ErrorKind::InvalidData, "not a WebSocket upgrade request"));
+        };
+        let response = format!(
+            "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n",
+            accept_key(&key)
+        );
+        stream.write_all(response.as_bytes()).await?;
+        stream.flush().await?;
+        Ok(Self { stream, role: Role::Server, max_message_bytes })
+    }
+
+    /// Open a client connection to `url` (`ws://host:port/path`).
+    pub(super) async fn connect(url: &str, max_message_bytes: usize) -> Result<Self> {
+        let rest = url
+            .strip_prefix("ws://")
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, format!("'{url}' is not a ws:// URL")))?;
+        let (host, path) = match rest.find('/') {
+            Some(slash) => (&rest[..slash], &rest[slash..]),
+            None => (rest, "/"),
+        };
+        let mut stream = BufReader::new(TcpStream::connect(host).await?);
+
+        let key = base64::engine::general_purpose::STANDARD.encode(random_bytes::<16>()?);
+        let request = format!(
+            "GET {path} HTTP/1.1\r\nHost: {host}\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Key: {key}\r\nSec-WebSocket-Version: 13\r\n\r\n"
+        );
+        stream.write_all(request.as_bytes()).await?;
+        stream.flush().await?;
+
+        let head = read_head(&mut stream).await?;
+        let switched = head.lines().next().is_some_and(|line| line.split_whitespace().nth(1) == Some("101"));
+        if !switched || !header_has(&head, "sec-websocket-accept", &accept_key(&key)) {
+            return Err(Error::new(ErrorKind::InvalidData, format!("{url} refused the WebSocket upgrade")));
+        }
+        Ok(Self { stream, role: Role::Client, max_message_bytes })
+    }
+
+    /// The next text message, or `None` once the peer closes.
+    pub(super) async fn recv(&mut self) -> Result<Option<String>> {
+        let mut message = Vec::new();
+        let mut in_message = false;
+        loop {
+            let Some((fin, opcode, payload)) = self.read_frame(message.len()).await? else {
+                return Ok(None);
+            };
+            match opcode {
+                OP_PING => self.write_frame(OP_PONG, &payload).await?,
+                OP_PONG => {}
+                OP_CLOSE => {
+                    // Echo the close; the peer hangs up after it
+                    let _ = self.write_frame(OP_CLOSE, &payload).await;
+                    return Ok(None);
+                }
+                OP_TEXT if !in_message => {
+                    message = payload;
+                    in_message = true;
+                }
+                OP_CONTINUATION if in_message => message.extend(payload),
+                other => return Err(Error::new(ErrorKind::InvalidData, format!("unexpected WebSocket opcode {other:#x}"))),
+            }
+            if in_message && fin {
+                return String::from_utf8(message)
+                    .map(Some)
+                    .map_err(|_| Error::new(ErrorKind::InvalidData, "WebSocket text message isn't UTF-8"));
+            }
+        }
+    }
+
+    /// Send `text` as one text message.
+    pub(super) async fn send(&mut self, text: &str) -> Result<()> {
+        self.write_frame(OP_TEXT, text.as_bytes()).await
+    }
+
+    /// Read one frame as (fin, opcode, unmasked payload). `buffered` bytes
+    /// of the current message count against the size limit.
+    async fn read_frame(&mut self, buffered: usize) -> Result<Option<(bool, u8, Vec<u8>)>> {
+        let mut header = [0u8; 2];
+        match self.stream.read_exact(&mut header).await {
+            Ok(_) => {}
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e),
+        }
+        let fin = header[0] & 0x80 != 0;
+        let opcode = header[0] & 0x0F;
+        let masked = header[1] & 0x80 != 0;
+        if masked != (self.role == Role::Server) {
+            return Err(Error::new(ErrorKind::InvalidData, "WebSocket frame masking doesn't match the peer's role"));
+        }
+        let length = match header[1] & 0x7F {
+            126 => u64::from(self.stream.read_u16().await?),
+            127 => self.stream.read_u64().await?,
+            n => u64::from(n),
+        };
+        if length.saturating_add(buffered as u64) > self.max_message_bytes as u64 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("WebSocket message exceeds {} bytes", self.max_message_bytes),
+            ));
+        }
+        let mut mask = [0u8; 4];
+        if masked {
+            self.stream.read_exact(&mut mask).await?;
+        }
+        let mut payload = vec![0u8; length as usize];
+        self.stream.read_exact(&mut payload).await?;
+        if masked {
+            apply_mask(&mut payload, mask);
+        }
+        Ok(Some((fin, opcode, payload)))
+    }
+
+    async fn write_frame(&mut self, opcode: u8, payload: &[u8]) -> Result<()> {
+        let mut frame = Vec::with_capacity(payload.len() + 14);
+        frame.push(0x80 | opcode);
+        let mask_bit = if self.role == Role::Client { 0x80 } else { 0 };
+        match payload.len() {
+            n if n < 126 => frame.push(mask_bit | n as u8),
+            n if n <= usize::from(u16::MAX) => {
+                frame.push(mask_bit | 126);
+                frame.extend((n as u16).to_be_bytes());
+            }
+            n => {
+                frame.push(mask_bit | 127);
+                frame.extend((n as u64).to_be_bytes());
+            }
+        }
+        let start = frame.len();
+        frame.extend_from_slice(payload);
+        if self.role == Role::Client {
+            let mask = random_bytes::<4>()?;
+            apply_mask(&mut frame[start..], mask);
+            frame.splice(start..start, mask);
+        }
+        self.stream.write_all(&frame).await?;
+        self.stream.flush().await
+    }
+}
+
+/// The `Sec-WebSocket-Accept` value answering `key`.
+fn accept_key(key: &str) -> String {
+    let digest = sha1_smol::Sha1::from(format!("{key}{ACCEPT_GUID}")).digest().bytes();
+    base64::engine::general_purpose::STANDARD.encode(digest)
+}
+
+/// Whether `head` has header `name` with a comma-separated token
+/// equal to `value` (case-insensitively).
+fn header_has(head: &str, name: &str, value: &str) -> bool {
+    head.lines()
+        .skip(1)
+        .filter_map(|line| line.split_once(':'))
+        .filter(|(header, _)| header.trim().eq_ignore_ascii_case(name))
+        .any(|(_, values)| values.split(',').any(|token| token.trim().eq_ignore_ascii_case(value)))
+}
+
+/// Read an HTTP head up to its blank line.
+async fn read_head(stream: &mut BufReader<TcpStream>) -> Result<String> {
+    let mut head = Vec::new();
+    while !head.ends_with(b"\r\n\r\n") {
+        let before = head.len();
+        (&mut *stream).take((MAX_HEAD_BYTES - before) as u64).read_until(b'\n', &mut head).await?;
+        if head.len() == before {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "connection closed during the WebSocket handshake"));
+        }
+        if head.len() >= MAX_HEAD_BYTES {
+            return Err(Error::new(ErrorKind::InvalidData, "WebSocket handshake head too long"));
+        }
+    }
+    String::from_utf8(head).map_err(|_| Error::new(ErrorKind::InvalidData, "WebSocket handshake isn't UTF-8"))
+}
+
+fn apply_mask(payload: &mut [u8], mask: [u8; 4]) {
+    for (i, byte) in payload.iter_mut().enumerate() {
+        *byte ^= mask[i % 4];
+    }
+}
+
+fn random_bytes<const N: usize>() -> Result<[u8; N]> {
+    let mut bytes = [0u8; N];
+    getrandom::getrandom(&mut bytes).map_err(|e| Error::other(format!("no randomness for WebSocket: {e}")))?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // === Scenario: The handshake answers the RFC's sample key ===
+    #[test]
+    fn accept_key_matches_rfc_example() {
+        assert_eq!(accept_key("dGhlIHNhbXBsZSBub25jZQ=="), "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=");
+    }
+}
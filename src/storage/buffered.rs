@@ -0,0 +1,517 @@
+//! Write-behind buffering over another `GraphStore`
+//!
+//! `PlexusEngine` already serves reads from memory; what makes a
+//! persistent engine slow under heavy ingest is that every commit saves
+//! the whole context synchronously. `BufferedStore` keeps the latest
+//! snapshot of each written context and hands them to the inner store on
+//! `flush` — explicitly, after a configurable number of writes, on a
+//! timer (`spawn_periodic_flush`), or on drop.
+//!
+//! Crash consistency:
+//! - Context writes since the last flush are lost on a crash; the inner
+//!   store holds the state as of that flush.
+//! - A flush saves contexts one by one, so a crash mid-flush can leave
+//!   some contexts newer than others. Each context is saved whole.
+//! - Events and specs are not buffered (`persist_event` must return a
+//!   sequence number), so after a crash the event log can run ahead of
+//!   the graph it describes.
+
+use super::traits::{
+    CompactionReport, EdgeFilter, EmissionFilter, GraphStore, ManifestEntry, NodeFilter, PersistedEmbedding, PersistedEmission, PersistedIngestResult, PersistedJournalEntry, PersistedLlmCost, PersistedOutboundEvent, PersistedSpec, PersistedTemplate, StorageError, StorageResult,
+};
+use crate::graph::{Context, ContextId, Edge, Node};
+use crate::query::{CursorFilter, PersistedEvent};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+/// A write awaiting flush. Later writes to a context replace earlier ones.
+enum Pending {
+    Save(Box<Context>),
+    /// Context row only; nodes and edges are untouched
+    Metadata(Box<Context>),
+    Delete,
+}
+
+/// Pending writes, with the order contexts were first written in.
+type PendingWrites = (HashMap<ContextId, Pending>, Vec<ContextId>);
+
+/// `GraphStore` wrapper batching context writes in memory.
+pub struct BufferedStore {
+    inner: Arc<dyn GraphStore>,
+    pending: Mutex<PendingWrites>,
+    /// Flush automatically once this many writes are buffered
+    flush_threshold: Option<usize>,
+    buffered_writes: Mutex<usize>,
+}
+
+impl BufferedStore {
+    /// Buffer writes to `inner` until `flush` is called.
+    pub fn new(inner: Arc<dyn GraphStore>) -> Self {
+        Self {
+            inner,
+            pending: Mutex::new((HashMap::new(), Vec::new())),
+            flush_threshold: None,
+            buffered_writes: Mutex::new(0),
+        }
+    }
+
+    /// Flush automatically after `writes` buffered context writes.
+    pub fn with_flush_threshold(mut self, writes: usize) -> Self {
+        self.flush_threshold = Some(writes.max(1));
+        self
+    }
+
+    /// Number of contexts with unflushed writes.
+    pub fn pending_contexts(&self) -> usize {
+        self.pending().map(|p| p.0.len()).unwrap_or(0)
+    }
+
+    /// Write every pending change to the inner store, in the order
+    /// contexts were first written. On error the failed write and those
+    /// after it stay pending.
+    pub fn flush(&self) -> StorageResult<usize> {
+        let mut pending = self.pending()?;
+        let (writes, order) = &mut *pending;
+        let mut flushed = 0;
+        while let Some(id) = order.first().cloned() {
+            if let Some(write) = writes.get(&id) {
+                match write {
+                    Pending::Save(context) => self.inner.save_context(context)?,
+                    Pending::Metadata(context) => self.inner.save_context_metadata(context)?,
+                    Pending::Delete => {
+                        self.inner.delete_context(&id)?;
+                    }
+                }
+                writes.remove(&id);
+                flushed += 1;
+            }
+            order.remove(0);
+        }
+        *self.buffered_writes.lock().map_err(poisoned)? = 0;
+        Ok(flushed)
+    }
+
+    /// Flush `store` every `interval` on a background thread, until the
+    /// last other reference to it is dropped.
+    pub fn spawn_periodic_flush(store: &Arc<Self>, interval: Duration) -> std::thread::JoinHandle<()> {
+        let weak = Arc::downgrade(store);
+        std::thread::spawn(move || loop {
+            std::thread::sleep(interval);
+            let Some(store) = weak.upgrade() else { break };
+            if let Err(e) = store.flush() {
+                tracing::warn!(error = %e, "buffered store: periodic flush failed");
+            }
+        })
+    }
+
+    fn pending(&self) -> StorageResult<MutexGuard<'_, PendingWrites>> {
+        self.pending.lock().map_err(poisoned)
+    }
+
+    /// IDs of the contexts with unflushed writes.
+    fn buffered_ids(&self) -> StorageResult<Vec<ContextId>> {
+        Ok(self.pending()?.0.keys().cloned().collect())
+    }
+
+    /// Whether a pending write replaces `id`'s nodes and edges, so the
+    /// inner store's are stale.
+    fn buffers_graph(&self, id: &ContextId) -> StorageResult<bool> {
+        Ok(matches!(self.pending()?.0.get(id), Some(Pending::Save(_) | Pending::Delete)))
+    }
+
+    fn buffer(&self, id: &ContextId, write: Pending) -> StorageResult<()> {
+        {
+            let mut pending = self.pending()?;
+            let (writes, order) = &mut *pending;
+            let write = match (writes.remove(id), write) {
+                // A metadata update folds into a pending full save
+                (Some(Pending::Save(mut saved)), Pending::Metadata(context)) => {
+                    saved.name = context.name;
+                    saved.description = context.description;
+                    saved.metadata = context.metadata;
+                    Pending::Save(saved)
+                }
+                (previous, write) => {
+                    if previous.is_none() {
+                        order.push(id.clone());
+                    }
+                    write
+                }
+            };
+            writes.insert(id.clone(), write);
+        }
+
+        let mut count = self.buffered_writes.lock().map_err(poisoned)?;
+        *count += 1;
+        if self.flush_threshold.is_some_and(|threshold| *count >= threshold) {
+            drop(count);
+            self.flush()?;
+        }
+        Ok(())
+    }
+}
+
+fn poisoned<T>(e: std::sync::PoisonError<T>) -> StorageError {
+    StorageError::Internal(format!("mutex poisoned: {e}"))
+}
+
+impl Drop for BufferedStore {
+    fn drop(&mut self) {
+        if let Err(e) = self.flush() {
+            tracing::warn!(error = %e, "buffered store: flush on drop failed, writes lost");
+        }
+    }
+}
+
+impl GraphStore for BufferedStore {
+    fn save_context(&self, context: &Context) -> StorageResult<()> {
+        self.buffer(&context.id, Pending::Save(Box::new(context.clone())))
+    }
+
+    fn save_context_metadata(&self, context: &Context) -> StorageResult<()> {
+        let mut metadata_only = Context::with_id(context.id.clone(), &context.name);
+        metadata_only.description = context.description.clone();
+        metadata_only.metadata = context.metadata.clone();
+        self.buffer(&context.id, Pending::Metadata(Box::new(metadata_only)))
+    }
+
+    fn load_context(&self, id: &ContextId) -> StorageResult<Option<Context>> {
+        let metadata = match self.pending()?.0.get(id) {
+            Some(Pending::Save(context)) => return Ok(Some((**context).clone())),
+            Some(Pending::Delete) => return Ok(None),
+            Some(Pending::Metadata(context)) => Some((**context).clone()),
+            None => None,
+        };
+        let mut loaded = self.inner.load_context(id)?;
+        if let (Some(loaded), Some(update)) = (loaded.as_mut(), metadata) {
+            loaded.name = update.name;
+            loaded.description = update.description;
+            loaded.metadata = update.metadata;
+        }
+        Ok(loaded)
+    }
+
+    fn delete_context(&self, id: &ContextId) -> StorageResult<bool> {
+        let existed = match self.pending()?.0.get(id) {
+            Some(Pending::Delete) => false,
+            Some(_) => true,
+            None => self.inner.list_contexts()?.contains(id),
+        };
+        if existed {
+            self.buffer(id, Pending::Delete)?;
+        }
+        Ok(existed)
+    }
+
+    fn list_contexts(&self) -> StorageResult<Vec<ContextId>> {
+        let mut ids = self.inner.list_contexts()?;
+        let pending = self.pending()?;
+        ids.retain(|id| !matches!(pending.0.get(id), Some(Pending::Delete)));
+        for (id, write) in &pending.0 {
+            if matches!(write, Pending::Save(_)) && !ids.contains(id) {
+                ids.push(id.clone());
+            }
+        }
+        Ok(ids)
+    }
+
+    fn list_contexts_for_tenant(&self, tenant: Option<&str>) -> StorageResult<Vec<ContextId>> {
+        let mut ids = self.inner.list_contexts_for_tenant(tenant)?;
+        let buffered = self.buffered_ids()?;
+        ids.retain(|id| !buffered.contains(id));
+        for id in buffered {
+            if self.load_context(&id)?.is_some_and(|ctx| ctx.metadata.tenant.as_deref() == tenant) {
+                ids.push(id);
+            }
+        }
+        Ok(ids)
+    }
+
+    fn load_manifest(&self) -> StorageResult<Vec<ManifestEntry>> {
+        let mut entries = self.inner.load_manifest()?;
+        let buffered = self.buffered_ids()?;
+        entries.retain(|entry| !buffered.contains(&entry.id));
+        for id in buffered {
+            if let Some(ctx) = self.load_context(&id)? {
+                entries.push(ManifestEntry::of(&ctx));
+            }
+        }
+        Ok(entries)
+    }
+
+    fn load_nodes(&self, context_id: &ContextId, filter: &NodeFilter) -> StorageResult<Vec<Node>> {
+        match self.pending()?.0.get(context_id) {
+            Some(Pending::Save(ctx)) => return Ok(ctx.nodes.values().filter(|n| filter.matches(n)).cloned().collect()),
+            Some(Pending::Delete) => return Ok(Vec::new()),
+            _ => {}
+        }
+        self.inner.load_nodes(context_id, filter)
+    }
+
+    fn load_edges(&self, context_id: &ContextId, filter: &EdgeFilter) -> StorageResult<Vec<Edge>> {
+        match self.pending()?.0.get(context_id) {
+            Some(Pending::Save(ctx)) => return Ok(ctx.edges.iter().filter(|e| filter.matches(e)).cloned().collect()),
+            Some(Pending::Delete) => return Ok(Vec::new()),
+            _ => {}
+        }
+        self.inner.load_edges(context_id, filter)
+    }
+
+    fn count_nodes(&self, context_id: &ContextId, filter: &NodeFilter) -> StorageResult<usize> {
+        match self.buffers_graph(context_id)? {
+            true => Ok(self.load_nodes(context_id, filter)?.len()),
+            false => self.inner.count_nodes(context_id, filter),
+        }
+    }
+
+    fn count_edges(&self, context_id: &ContextId, filter: &EdgeFilter) -> StorageResult<usize> {
+        match self.buffers_graph(context_id)? {
+            true => Ok(self.load_edges(context_id, filter)?.len()),
+            false => self.inner.count_edges(context_id, filter),
+        }
+    }
+
+    fn data_version(&self) -> StorageResult<u64> {
+        self.inner.data_version()
+    }
+
+    fn defers_saves(&self) -> bool {
+        self.inner.defers_saves()
+    }
+
+    fn flush_due(&self) -> StorageResult<usize> {
+        self.inner.flush_due()
+    }
+
+    fn schema_version(&self) -> StorageResult<u32> {
+        self.inner.schema_version()
+    }
+
+    fn backup(&self, path: &Path) -> StorageResult<()> {
+        self.flush()?;
+        self.inner.backup(path)
+    }
+
+    fn restore(&self, path: &Path) -> StorageResult<()> {
+        // The snapshot supersedes anything not yet written
+        let mut pending = self.pending()?;
+        pending.0.clear();
+        pending.1.clear();
+        drop(pending);
+        *self.buffered_writes.lock().map_err(poisoned)? = 0;
+        self.inner.restore(path)
+    }
+
+    fn compact(&self, context_id: Option<&ContextId>) -> StorageResult<CompactionReport> {
+        self.flush()?;
+        self.inner.compact(context_id)
+    }
+
+    fn persist_event(
+        &self,
+        context_id: &str,
+        event_type: &str,
+        node_ids: &[String],
+        edge_ids: &[String],
+        keys: &[String],
+        adapter_id: &str,
+    ) -> StorageResult<u64> {
+        self.inner.persist_event(context_id, event_type, node_ids, edge_ids, keys, adapter_id)
+    }
+
+    fn query_events_since(
+        &self,
+        context_id: &str,
+        cursor: u64,
+        filter: Option<&CursorFilter>,
+    ) -> StorageResult<Vec<PersistedEvent>> {
+        self.inner.query_events_since(context_id, cursor, filter)
+    }
+
+    fn latest_sequence(&self, context_id: &str) -> StorageResult<u64> {
+        self.inner.latest_sequence(context_id)
+    }
+
+    fn persist_spec(&self, spec: &PersistedSpec) -> StorageResult<()> {
+        self.inner.persist_spec(spec)
+    }
+
+    fn query_specs_for_context(&self, context_id: &str) -> StorageResult<Vec<PersistedSpec>> {
+        self.inner.query_specs_for_context(context_id)
+    }
+
+    fn delete_spec(&self, context_id: &str, adapter_id: &str) -> StorageResult<bool> {
+        self.inner.delete_spec(context_id, adapter_id)
+    }
+
+    fn persist_llm_cost(&self, cost: &PersistedLlmCost) -> StorageResult<()> {
+        self.inner.persist_llm_cost(cost)
+    }
+
+    fn query_llm_costs_since(&self, context_id: &str, since: &str) -> StorageResult<Vec<PersistedLlmCost>> {
+        self.inner.query_llm_costs_since(context_id, since)
+    }
+
+    fn persist_emission(&self, emission: &PersistedEmission) -> StorageResult<()> {
+        self.inner.persist_emission(emission)
+    }
+
+    fn query_emissions(&self, context_id: &str, filter: &EmissionFilter) -> StorageResult<Vec<PersistedEmission>> {
+        self.inner.query_emissions(context_id, filter)
+    }
+
+    fn prune_emissions(&self, context_id: &str, before: &str) -> StorageResult<usize> {
+        self.inner.prune_emissions(context_id, before)
+    }
+
+    fn rename_nodes(&self, context_id: &str, renamed: &[(String, String)]) -> StorageResult<()> {
+        self.inner.rename_nodes(context_id, renamed)
+    }
+
+    fn append_outbound_event(&self, event: &PersistedOutboundEvent) -> StorageResult<u64> {
+        self.inner.append_outbound_event(event)
+    }
+
+    fn query_outbound_since(
+        &self,
+        context_id: &str,
+        after: u64,
+        limit: Option<usize>,
+    ) -> StorageResult<Vec<PersistedOutboundEvent>> {
+        self.inner.query_outbound_since(context_id, after, limit)
+    }
+
+    fn outbound_ack(&self, context_id: &str, subscriber: &str) -> StorageResult<Option<u64>> {
+        self.inner.outbound_ack(context_id, subscriber)
+    }
+
+    fn set_outbound_ack(&self, context_id: &str, subscriber: &str, offset: u64) -> StorageResult<()> {
+        self.inner.set_outbound_ack(context_id, subscriber, offset)
+    }
+
+    fn prune_outbound(&self, context_id: &str, keep: usize) -> StorageResult<usize> {
+        self.inner.prune_outbound(context_id, keep)
+    }
+
+    fn persist_template(&self, template: &PersistedTemplate) -> StorageResult<()> {
+        self.inner.persist_template(template)
+    }
+
+    fn load_template(&self, name: &str) -> StorageResult<Option<PersistedTemplate>> {
+        self.inner.load_template(name)
+    }
+
+    fn list_templates(&self) -> StorageResult<Vec<String>> {
+        self.inner.list_templates()
+    }
+
+    fn delete_template(&self, name: &str) -> StorageResult<bool> {
+        self.inner.delete_template(name)
+    }
+
+    fn persist_embedding(&self, embedding: &PersistedEmbedding) -> StorageResult<()> {
+        self.inner.persist_embedding(embedding)
+    }
+
+    fn load_embeddings(&self, context_id: &str, model: &str) -> StorageResult<Vec<PersistedEmbedding>> {
+        self.inner.load_embeddings(context_id, model)
+    }
+
+    fn delete_embeddings(&self, context_id: &str, model: &str) -> StorageResult<usize> {
+        self.inner.delete_embeddings(context_id, model)
+    }
+
+    fn append_journal(&self, entry: &PersistedJournalEntry) -> StorageResult<u64> {
+        self.inner.append_journal(entry)
+    }
+
+    fn query_journal(&self, context_id: &str, after: u64) -> StorageResult<Vec<PersistedJournalEntry>> {
+        self.inner.query_journal(context_id, after)
+    }
+
+    fn truncate_journal(&self, context_id: &str, before: u64) -> StorageResult<usize> {
+        self.inner.truncate_journal(context_id, before)
+    }
+
+    fn claim_ingest_key(&self, claim: &PersistedIngestResult, stale_before: &str) -> StorageResult<bool> {
+        self.inner.claim_ingest_key(claim, stale_before)
+    }
+
+    fn persist_ingest_result(&self, result: &PersistedIngestResult) -> StorageResult<()> {
+        self.inner.persist_ingest_result(result)
+    }
+
+    fn release_ingest_key(&self, context_id: &str, key: &str) -> StorageResult<()> {
+        self.inner.release_ingest_key(context_id, key)
+    }
+
+    fn load_ingest_result(&self, context_id: &str, key: &str) -> StorageResult<Option<PersistedIngestResult>> {
+        self.inner.load_ingest_result(context_id, key)
+    }
+
+    fn prune_ingest_results(&self, before: &str) -> StorageResult<usize> {
+        self.inner.prune_ingest_results(before)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{ContentType, Node, PlexusEngine};
+    use crate::storage::{OpenStore, SqliteStore};
+
+    // === Scenario: Writes reach disk only on flush ===
+    #[test]
+    fn writes_are_buffered_until_flush() {
+        let disk = Arc::new(SqliteStore::open_in_memory().unwrap());
+        let buffered = Arc::new(BufferedStore::new(disk.clone()));
+        let engine = PlexusEngine::with_store(buffered.clone());
+
+        let id = engine.upsert_context(Context::new("journal")).unwrap();
+        for _ in 0..3 {
+            engine.add_node(&id, Node::new("fragment", ContentType::Document)).unwrap();
+        }
+        engine.rename_context(&id, "diary").unwrap();
+
+        assert!(disk.load_context(&id).unwrap().is_none());
+        assert_eq!(buffered.load_context(&id).unwrap().unwrap().node_count(), 3);
+        assert_eq!(buffered.list_contexts().unwrap(), vec![id.clone()]);
+        assert_eq!(buffered.pending_contexts(), 1, "writes to one context coalesce");
+        // Filtered loads, counts and listings see the buffered writes too
+        assert_eq!(buffered.count_nodes(&id, &NodeFilter::default()).unwrap(), 3);
+        assert_eq!(buffered.list_contexts_for_tenant(None).unwrap(), vec![id.clone()]);
+        let manifest = buffered.load_manifest().unwrap();
+        assert_eq!((manifest[0].name.as_str(), manifest[0].node_count), ("diary", 3));
+
+        assert_eq!(buffered.flush().unwrap(), 1);
+        let stored = disk.load_context(&id).unwrap().unwrap();
+        assert_eq!(stored.node_count(), 3);
+        assert_eq!(stored.name, "diary");
+
+        engine.delete_context(&id).unwrap();
+        assert!(buffered.load_context(&id).unwrap().is_none());
+        assert!(buffered.load_nodes(&id, &NodeFilter::default()).unwrap().is_empty());
+        assert!(buffered.load_manifest().unwrap().is_empty());
+        assert!(disk.load_context(&id).unwrap().is_some());
+        drop(engine);
+        drop(buffered);
+        assert!(disk.load_context(&id).unwrap().is_none(), "drop flushes");
+    }
+
+    // === Scenario: A flush threshold bounds unflushed writes ===
+    #[test]
+    fn threshold_flushes_automatically() {
+        let disk = Arc::new(SqliteStore::open_in_memory().unwrap());
+        let buffered = BufferedStore::new(disk.clone()).with_flush_threshold(2);
+
+        let first = Context::new("a");
+        buffered.save_context(&first).unwrap();
+        assert!(disk.list_contexts().unwrap().is_empty());
+        buffered.save_context(&Context::new("b")).unwrap();
+
+        assert_eq!(disk.list_contexts().unwrap().len(), 2);
+        assert_eq!(buffered.pending_contexts(), 0);
+    }
+}
@@ -0,0 +1,757 @@
+//! Event-sourced persistence over another `GraphStore`
+//!
+//! By default a context's rows are the source of truth and the event log
+//! (ADR-035) records only which IDs changed. `EventSourcedStore` inverts
+//! that: each save appends a `ContextChange` to the context's journal,
+//! carrying the full content of every node, edge and trash entry that
+//! differs from the previous save. Loading a context replays its journal,
+//! so the in-memory context is a projection.
+//!
+//! - Every `snapshot_every` changes a full snapshot is journaled, and
+//!   replay starts from the newest one. Snapshots are also written to the
+//!   inner store's rows, for tools that read the database directly; between
+//!   snapshots only the context row is kept current.
+//! - `project_as_of` replays up to a moment exactly. Unlike
+//!   `HistoricalView::rewind`, nothing is unrecoverable.
+//! - `compact_journal` snapshots a context and drops the entries before
+//!   the snapshot, giving up the history older than it.
+//!
+//! Select it with `PlexusEngine::event_sourced`.
+
+use super::traits::{
+    CompactionReport, EdgeFilter, EmissionFilter, GraphStore, ManifestEntry, NodeFilter, PersistedEmbedding, PersistedEmission, PersistedIngestResult, PersistedJournalEntry, PersistedLlmCost, PersistedOutboundEvent, PersistedSpec, PersistedTemplate, StorageError, StorageResult,
+};
+use crate::graph::{Context, ContextId, ContextMetadata, Edge, EdgeId, Node, NodeId, Tombstone};
+use crate::query::{CursorFilter, PersistedEvent};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Changes journaled between snapshots unless configured otherwise
+pub const DEFAULT_SNAPSHOT_EVERY: usize = 100;
+
+const CHANGE: &str = "change";
+const SNAPSHOT: &str = "snapshot";
+
+/// A context's name, description and metadata.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContextHeader {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub metadata: ContextMetadata,
+}
+
+impl ContextHeader {
+    fn of(context: &Context) -> Self {
+        Self { name: context.name.clone(), description: context.description.clone(), metadata: context.metadata.clone() }
+    }
+}
+
+/// What changed in a context between two saves, with full content.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ContextChange {
+    /// Set when the name, description or metadata changed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub header: Option<ContextHeader>,
+    /// Live nodes added or modified
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub nodes: Vec<Node>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed_nodes: Vec<NodeId>,
+    /// Live edges added or modified
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub edges: Vec<Edge>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed_edges: Vec<EdgeId>,
+    /// Trash entries added or modified
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trashed_nodes: Vec<Tombstone<Node>>,
+    /// Trash entries gone (restored or purged)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub untrashed_nodes: Vec<NodeId>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trashed_edges: Vec<Tombstone<Edge>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub untrashed_edges: Vec<EdgeId>,
+}
+
+impl ContextChange {
+    pub fn is_empty(&self) -> bool {
+        self.header.is_none()
+            && self.nodes.is_empty()
+            && self.removed_nodes.is_empty()
+            && self.edges.is_empty()
+            && self.removed_edges.is_empty()
+            && self.trashed_nodes.is_empty()
+            && self.untrashed_nodes.is_empty()
+            && self.trashed_edges.is_empty()
+            && self.untrashed_edges.is_empty()
+    }
+
+    /// Apply this change to `context`.
+    pub fn apply_to(&self, context: &mut Context) {
+        if let Some(header) = &self.header {
+            context.name = header.name.clone();
+            context.description = header.description.clone();
+            context.metadata = header.metadata.clone();
+        }
+
+        for id in &self.removed_nodes {
+            context.nodes.remove(id);
+        }
+        for node in &self.nodes {
+            context.nodes.insert(node.id.clone(), node.clone());
+        }
+
+        let removed: HashSet<&EdgeId> = self.removed_edges.iter().collect();
+        context.edges.retain(|e| !removed.contains(&e.id));
+        for edge in &self.edges {
+            match context.edges.iter_mut().find(|e| e.id == edge.id) {
+                Some(existing) => *existing = edge.clone(),
+                None => context.edges.push(edge.clone()),
+            }
+        }
+
+        for id in &self.untrashed_nodes {
+            context.trash.nodes.remove(id);
+        }
+        for tombstone in &self.trashed_nodes {
+            context.trash.nodes.insert(tombstone.item.id.clone(), tombstone.clone());
+        }
+
+        let untrashed: HashSet<&EdgeId> = self.untrashed_edges.iter().collect();
+        context.trash.edges.retain(|t| !untrashed.contains(&t.item.id));
+        for tombstone in &self.trashed_edges {
+            match context.trash.edges.iter_mut().find(|t| t.item.id == tombstone.item.id) {
+                Some(existing) => *existing = tombstone.clone(),
+                None => context.trash.edges.push(tombstone.clone()),
+            }
+        }
+    }
+}
+
+/// The content of a journal entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum JournalRecord {
+    Change(ContextChange),
+    /// The whole context; replay starts from the newest one
+    Snapshot(Context),
+}
+
+/// One entry of a context's journal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub sequence: u64,
+    pub recorded_at: DateTime<Utc>,
+    pub record: JournalRecord,
+}
+
+impl JournalEntry {
+    fn decode(entry: &PersistedJournalEntry) -> StorageResult<Self> {
+        let record = match entry.kind.as_str() {
+            SNAPSHOT => JournalRecord::Snapshot(serde_json::from_str(&entry.payload)?),
+            CHANGE => JournalRecord::Change(serde_json::from_str(&entry.payload)?),
+            other => return Err(StorageError::Internal(format!("unknown journal entry kind '{other}'"))),
+        };
+        let recorded_at = DateTime::parse_from_rfc3339(&entry.recorded_at)
+            .map_err(|e| StorageError::DateParse(e.to_string()))?
+            .with_timezone(&Utc);
+        Ok(Self { sequence: entry.sequence, recorded_at, record })
+    }
+}
+
+/// Content digests of a context as last journaled, for diffing the next save.
+#[derive(Default)]
+struct Tracked {
+    header: u64,
+    nodes: HashMap<NodeId, u64>,
+    edges: HashMap<EdgeId, u64>,
+    trashed_nodes: HashMap<NodeId, u64>,
+    trashed_edges: HashMap<EdgeId, u64>,
+    /// Changes journaled since the last snapshot
+    since_snapshot: usize,
+    /// Sequence of the newest snapshot, where replay starts
+    snapshot_at: Option<u64>,
+}
+
+impl Tracked {
+    fn of(context: &Context) -> Self {
+        Self {
+            header: digest(&ContextHeader::of(context)),
+            nodes: context.nodes.iter().map(|(id, n)| (id.clone(), digest(n))).collect(),
+            edges: context.edges.iter().map(|e| (e.id.clone(), digest(e))).collect(),
+            trashed_nodes: context.trash.nodes.iter().map(|(id, t)| (id.clone(), digest(t))).collect(),
+            trashed_edges: context.trash.edges.iter().map(|t| (t.item.id.clone(), digest(t))).collect(),
+            since_snapshot: 0,
+            snapshot_at: None,
+        }
+    }
+
+    /// What `context` (digested as `next`) changes relative to `self`.
+    fn change_to(&self, context: &Context, next: &Tracked) -> ContextChange {
+        ContextChange {
+            header: (self.header != next.header).then(|| ContextHeader::of(context)),
+            nodes: context.nodes.iter().filter(|(id, _)| changed(&self.nodes, *id, &next.nodes[*id])).map(|(_, n)| n.clone()).collect(),
+            removed_nodes: self.nodes.keys().filter(|id| !next.nodes.contains_key(*id)).cloned().collect(),
+            edges: context.edges.iter().filter(|e| changed(&self.edges, &e.id, &next.edges[&e.id])).cloned().collect(),
+            removed_edges: self.edges.keys().filter(|id| !next.edges.contains_key(*id)).cloned().collect(),
+            trashed_nodes: context
+                .trash
+                .nodes
+                .iter()
+                .filter(|(id, _)| changed(&self.trashed_nodes, *id, &next.trashed_nodes[*id]))
+                .map(|(_, t)| t.clone())
+                .collect(),
+            untrashed_nodes: self.trashed_nodes.keys().filter(|id| !next.trashed_nodes.contains_key(*id)).cloned().collect(),
+            trashed_edges: context
+                .trash
+                .edges
+                .iter()
+                .filter(|t| changed(&self.trashed_edges, &t.item.id, &next.trashed_edges[&t.item.id]))
+                .cloned()
+                .collect(),
+            untrashed_edges: self.trashed_edges.keys().filter(|id| !next.trashed_edges.contains_key(*id)).cloned().collect(),
+        }
+    }
+}
+
+fn changed<K: std::hash::Hash + Eq>(before: &HashMap<K, u64>, id: &K, hash: &u64) -> bool {
+    before.get(id) != Some(hash)
+}
+
+/// FNV-1a over canonical JSON; `serde_json::Value` orders object keys,
+/// so map iteration order doesn't matter.
+fn digest(value: &impl Serialize) -> u64 {
+    let canonical = serde_json::to_value(value).map(|v| v.to_string()).unwrap_or_default();
+    canonical.bytes().fold(0xcbf2_9ce4_8422_2325, |h, b| (h ^ b as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+/// Replay `entries` (oldest first). `None` when there are none, i.e. the
+/// context didn't exist yet or compaction dropped the history needed.
+fn replay(id: &ContextId, entries: &[JournalEntry]) -> Option<Context> {
+    if entries.is_empty() {
+        return None;
+    }
+    let start = entries.iter().rposition(|e| matches!(e.record, JournalRecord::Snapshot(_)));
+    let (mut context, rest) = match start {
+        Some(i) => match &entries[i].record {
+            JournalRecord::Snapshot(snapshot) => (snapshot.clone(), &entries[i + 1..]),
+            JournalRecord::Change(_) => unreachable!("position of a snapshot"),
+        },
+        // A journal without a snapshot starts at the context's creation
+        None => (Context::with_id(id.clone(), ""), entries),
+    };
+    for entry in rest {
+        if let JournalRecord::Change(change) = &entry.record {
+            change.apply_to(&mut context);
+        }
+    }
+    Some(context)
+}
+
+/// `GraphStore` wrapper keeping each context as a journal of changes.
+pub struct EventSourcedStore {
+    inner: Arc<dyn GraphStore>,
+    snapshot_every: usize,
+    tracked: Mutex<HashMap<ContextId, Tracked>>,
+}
+
+impl EventSourcedStore {
+    /// Journal contexts into `inner`, snapshotting every
+    /// `DEFAULT_SNAPSHOT_EVERY` changes.
+    pub fn new(inner: Arc<dyn GraphStore>) -> Self {
+        Self { inner, snapshot_every: DEFAULT_SNAPSHOT_EVERY, tracked: Mutex::new(HashMap::new()) }
+    }
+
+    /// Snapshot after every `changes` journaled changes.
+    pub fn with_snapshot_every(mut self, changes: usize) -> Self {
+        self.snapshot_every = changes.max(1);
+        self
+    }
+
+    /// A context's journal entries with sequence greater than `after`, oldest first.
+    pub fn journal(&self, id: &ContextId, after: u64) -> StorageResult<Vec<JournalEntry>> {
+        self.inner.query_journal(id.as_str(), after)?.iter().map(JournalEntry::decode).collect()
+    }
+
+    /// The context as it stood at `as_of`, replayed from the journal.
+    /// `None` if it didn't exist yet or that history was compacted away.
+    /// Only entries from the last snapshot before `as_of` up to `as_of`
+    /// are decoded.
+    pub fn project_as_of(&self, id: &ContextId, as_of: DateTime<Utc>) -> StorageResult<Option<Context>> {
+        let rows = self.inner.query_journal(id.as_str(), 0)?;
+        let mut visible = 0;
+        for row in &rows {
+            let recorded_at = DateTime::parse_from_rfc3339(&row.recorded_at)
+                .map_err(|e| StorageError::DateParse(e.to_string()))?;
+            if recorded_at > as_of {
+                break;
+            }
+            visible += 1;
+        }
+        let start = rows[..visible].iter().rposition(|row| row.kind == SNAPSHOT).unwrap_or(0);
+        let entries = rows[start..visible].iter().map(JournalEntry::decode).collect::<StorageResult<Vec<_>>>()?;
+        Ok(replay(id, &entries))
+    }
+
+    /// Journal a full snapshot of `context` and write it to the inner
+    /// store's rows. Returns the snapshot's sequence number.
+    pub fn snapshot(&self, context: &Context) -> StorageResult<u64> {
+        let mut tracked = self.tracked()?;
+        self.snapshot_tracked(&mut tracked, context)
+    }
+
+    /// `snapshot` under the already held tracking lock.
+    fn snapshot_tracked(&self, tracked: &mut HashMap<ContextId, Tracked>, context: &Context) -> StorageResult<u64> {
+        let sequence = self.append(&context.id, SNAPSHOT, serde_json::to_string(context)?)?;
+        self.inner.save_context(context)?;
+        let mut next = Tracked::of(context);
+        next.snapshot_at = Some(sequence);
+        tracked.insert(context.id.clone(), next);
+        Ok(sequence)
+    }
+
+    /// A context's journal entries from its newest snapshot on — all that
+    /// replay needs — oldest first. Where the snapshot's sequence is known,
+    /// older rows aren't read at all.
+    fn latest_entries(&self, id: &ContextId) -> StorageResult<Vec<JournalEntry>> {
+        let known = self.tracked()?.get(id).and_then(|t| t.snapshot_at);
+        let rows = self.inner.query_journal(id.as_str(), known.map_or(0, |sequence| sequence.saturating_sub(1)))?;
+        let start = rows.iter().rposition(|row| row.kind == SNAPSHOT).unwrap_or(0);
+        rows[start..].iter().map(JournalEntry::decode).collect()
+    }
+
+    /// The context as last journaled. `Ok(None)` when it has no journal,
+    /// in which case the inner store's rows are authoritative.
+    fn project(&self, id: &ContextId) -> StorageResult<Option<Context>> {
+        Ok(replay(id, &self.latest_entries(id)?))
+    }
+
+    /// Snapshot a context and drop the journal entries before the
+    /// snapshot. Returns the number of entries dropped.
+    pub fn compact_journal(&self, id: &ContextId) -> StorageResult<usize> {
+        let Some(context) = replay(id, &self.journal(id, 0)?) else {
+            return Ok(0);
+        };
+        let sequence = self.snapshot(&context)?;
+        self.inner.truncate_journal(id.as_str(), sequence)
+    }
+
+    fn tracked(&self) -> StorageResult<MutexGuard<'_, HashMap<ContextId, Tracked>>> {
+        self.tracked.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))
+    }
+
+    fn append(&self, id: &ContextId, kind: &str, payload: String) -> StorageResult<u64> {
+        self.inner.append_journal(&PersistedJournalEntry {
+            sequence: 0,
+            context_id: id.to_string(),
+            kind: kind.to_string(),
+            payload,
+            recorded_at: Utc::now().to_rfc3339(),
+        })
+    }
+
+    /// Journal `change` and bring the context row up to date, under the
+    /// tracking lock held since `change` was diffed.
+    fn append_change(
+        &self,
+        tracked: &mut HashMap<ContextId, Tracked>,
+        context: &Context,
+        change: &ContextChange,
+        next: Tracked,
+    ) -> StorageResult<()> {
+        self.append(&context.id, CHANGE, serde_json::to_string(change)?)?;
+        self.inner.save_context_metadata(context)?;
+        let due = next.since_snapshot >= self.snapshot_every;
+        tracked.insert(context.id.clone(), next);
+        if due {
+            self.snapshot_tracked(tracked, context)?;
+        }
+        Ok(())
+    }
+}
+
+impl GraphStore for EventSourcedStore {
+    fn save_context(&self, context: &Context) -> StorageResult<()> {
+        let mut next = Tracked::of(context);
+        // Held until the change is journaled, so concurrent saves append
+        // in the order they were diffed
+        let mut tracked = self.tracked()?;
+        let Some(last) = tracked.get(&context.id) else {
+            // Not loaded through this store: its journal (if any) may hold
+            // items this copy lacks, so only a snapshot is authoritative
+            return self.snapshot_tracked(&mut tracked, context).map(|_| ());
+        };
+        next.since_snapshot = last.since_snapshot + 1;
+        next.snapshot_at = last.snapshot_at;
+        let change = last.change_to(context, &next);
+        if change.is_empty() {
+            return Ok(());
+        }
+        self.append_change(&mut tracked, context, &change, next)
+    }
+
+    fn save_context_metadata(&self, context: &Context) -> StorageResult<()> {
+        let header = ContextHeader::of(context);
+        let hash = digest(&header);
+        let mut tracked = self.tracked()?;
+        let Some(last) = tracked.get_mut(&context.id) else {
+            drop(tracked);
+            return self.save_context(context);
+        };
+        if last.header == hash {
+            return Ok(());
+        }
+        last.header = hash;
+        last.since_snapshot += 1;
+        let change = ContextChange { header: Some(header), ..Default::default() };
+        self.append(&context.id, CHANGE, serde_json::to_string(&change)?)?;
+        self.inner.save_context_metadata(context)
+    }
+
+    fn load_context(&self, id: &ContextId) -> StorageResult<Option<Context>> {
+        let entries = self.latest_entries(id)?;
+        if entries.is_empty() {
+            // Written before this store was in use: adopt the rows
+            let loaded = self.inner.load_context(id)?;
+            if let Some(context) = &loaded {
+                self.snapshot(context)?;
+            }
+            return Ok(loaded);
+        }
+
+        let Some(context) = replay(id, &entries) else {
+            return Ok(None);
+        };
+        let mut tracked = Tracked::of(&context);
+        tracked.since_snapshot =
+            entries.iter().rev().take_while(|e| matches!(e.record, JournalRecord::Change(_))).count();
+        tracked.snapshot_at =
+            entries.iter().rfind(|e| matches!(e.record, JournalRecord::Snapshot(_))).map(|e| e.sequence);
+        self.tracked()?.insert(id.clone(), tracked);
+        Ok(Some(context))
+    }
+
+    // The context row (counts included) is rewritten on every journaled
+    // change, so the inner store's manifest is current
+    fn load_manifest(&self) -> StorageResult<Vec<ManifestEntry>> {
+        self.inner.load_manifest()
+    }
+
+    fn load_nodes(&self, context_id: &ContextId, filter: &NodeFilter) -> StorageResult<Vec<Node>> {
+        match self.project(context_id)? {
+            Some(context) => Ok(context.nodes.into_values().filter(|n| filter.matches(n)).collect()),
+            None => self.inner.load_nodes(context_id, filter),
+        }
+    }
+
+    fn load_edges(&self, context_id: &ContextId, filter: &EdgeFilter) -> StorageResult<Vec<Edge>> {
+        match self.project(context_id)? {
+            Some(context) => Ok(context.edges.into_iter().filter(|e| filter.matches(e)).collect()),
+            None => self.inner.load_edges(context_id, filter),
+        }
+    }
+
+    fn count_nodes(&self, context_id: &ContextId, filter: &NodeFilter) -> StorageResult<usize> {
+        match self.project(context_id)? {
+            Some(context) => Ok(context.nodes.values().filter(|n| filter.matches(n)).count()),
+            None => self.inner.count_nodes(context_id, filter),
+        }
+    }
+
+    fn count_edges(&self, context_id: &ContextId, filter: &EdgeFilter) -> StorageResult<usize> {
+        match self.project(context_id)? {
+            Some(context) => Ok(context.edges.iter().filter(|e| filter.matches(e)).count()),
+            None => self.inner.count_edges(context_id, filter),
+        }
+    }
+
+    fn delete_context(&self, id: &ContextId) -> StorageResult<bool> {
+        self.tracked()?.remove(id);
+        self.inner.truncate_journal(id.as_str(), u64::MAX)?;
+        self.inner.delete_context(id)
+    }
+
+    fn list_contexts(&self) -> StorageResult<Vec<ContextId>> {
+        self.inner.list_contexts()
+    }
+
+    fn list_contexts_for_tenant(&self, tenant: Option<&str>) -> StorageResult<Vec<ContextId>> {
+        self.inner.list_contexts_for_tenant(tenant)
+    }
+
+    fn data_version(&self) -> StorageResult<u64> {
+        self.inner.data_version()
+    }
+
+    fn defers_saves(&self) -> bool {
+        self.inner.defers_saves()
+    }
+
+    fn flush_due(&self) -> StorageResult<usize> {
+        self.inner.flush_due()
+    }
+
+    fn schema_version(&self) -> StorageResult<u32> {
+        self.inner.schema_version()
+    }
+
+    fn backup(&self, path: &Path) -> StorageResult<()> {
+        self.inner.backup(path)
+    }
+
+    fn restore(&self, path: &Path) -> StorageResult<()> {
+        // Digests describe the replaced database
+        self.tracked()?.clear();
+        self.inner.restore(path)
+    }
+
+    fn compact(&self, context_id: Option<&ContextId>) -> StorageResult<CompactionReport> {
+        self.inner.compact(context_id)
+    }
+
+    fn persist_event(
+        &self,
+        context_id: &str,
+        event_type: &str,
+        node_ids: &[String],
+        edge_ids: &[String],
+        keys: &[String],
+        adapter_id: &str,
+    ) -> StorageResult<u64> {
+        self.inner.persist_event(context_id, event_type, node_ids, edge_ids, keys, adapter_id)
+    }
+
+    fn query_events_since(
+        &self,
+        context_id: &str,
+        cursor: u64,
+        filter: Option<&CursorFilter>,
+    ) -> StorageResult<Vec<PersistedEvent>> {
+        self.inner.query_events_since(context_id, cursor, filter)
+    }
+
+    fn latest_sequence(&self, context_id: &str) -> StorageResult<u64> {
+        self.inner.latest_sequence(context_id)
+    }
+
+    fn persist_spec(&self, spec: &PersistedSpec) -> StorageResult<()> {
+        self.inner.persist_spec(spec)
+    }
+
+    fn query_specs_for_context(&self, context_id: &str) -> StorageResult<Vec<PersistedSpec>> {
+        self.inner.query_specs_for_context(context_id)
+    }
+
+    fn delete_spec(&self, context_id: &str, adapter_id: &str) -> StorageResult<bool> {
+        self.inner.delete_spec(context_id, adapter_id)
+    }
+
+    fn persist_llm_cost(&self, cost: &PersistedLlmCost) -> StorageResult<()> {
+        self.inner.persist_llm_cost(cost)
+    }
+
+    fn query_llm_costs_since(&self, context_id: &str, since: &str) -> StorageResult<Vec<PersistedLlmCost>> {
+        self.inner.query_llm_costs_since(context_id, since)
+    }
+
+    fn persist_emission(&self, emission: &PersistedEmission) -> StorageResult<()> {
+        self.inner.persist_emission(emission)
+    }
+
+    fn query_emissions(&self, context_id: &str, filter: &EmissionFilter) -> StorageResult<Vec<PersistedEmission>> {
+        self.inner.query_emissions(context_id, filter)
+    }
+
+    fn prune_emissions(&self, context_id: &str, before: &str) -> StorageResult<usize> {
+        self.inner.prune_emissions(context_id, before)
+    }
+
+    fn rename_nodes(&self, context_id: &str, renamed: &[(String, String)]) -> StorageResult<()> {
+        self.inner.rename_nodes(context_id, renamed)
+    }
+
+    fn append_outbound_event(&self, event: &PersistedOutboundEvent) -> StorageResult<u64> {
+        self.inner.append_outbound_event(event)
+    }
+
+    fn query_outbound_since(
+        &self,
+        context_id: &str,
+        after: u64,
+        limit: Option<usize>,
+    ) -> StorageResult<Vec<PersistedOutboundEvent>> {
+        self.inner.query_outbound_since(context_id, after, limit)
+    }
+
+    fn outbound_ack(&self, context_id: &str, subscriber: &str) -> StorageResult<Option<u64>> {
+        self.inner.outbound_ack(context_id, subscriber)
+    }
+
+    fn set_outbound_ack(&self, context_id: &str, subscriber: &str, offset: u64) -> StorageResult<()> {
+        self.inner.set_outbound_ack(context_id, subscriber, offset)
+    }
+
+    fn prune_outbound(&self, context_id: &str, keep: usize) -> StorageResult<usize> {
+        self.inner.prune_outbound(context_id, keep)
+    }
+
+    fn persist_template(&self, template: &PersistedTemplate) -> StorageResult<()> {
+        self.inner.persist_template(template)
+    }
+
+    fn load_template(&self, name: &str) -> StorageResult<Option<PersistedTemplate>> {
+        self.inner.load_template(name)
+    }
+
+    fn list_templates(&self) -> StorageResult<Vec<String>> {
+        self.inner.list_templates()
+    }
+
+    fn delete_template(&self, name: &str) -> StorageResult<bool> {
+        self.inner.delete_template(name)
+    }
+
+    fn persist_embedding(&self, embedding: &PersistedEmbedding) -> StorageResult<()> {
+        self.inner.persist_embedding(embedding)
+    }
+
+    fn load_embeddings(&self, context_id: &str, model: &str) -> StorageResult<Vec<PersistedEmbedding>> {
+        self.inner.load_embeddings(context_id, model)
+    }
+
+    fn delete_embeddings(&self, context_id: &str, model: &str) -> StorageResult<usize> {
+        self.inner.delete_embeddings(context_id, model)
+    }
+
+    fn append_journal(&self, entry: &PersistedJournalEntry) -> StorageResult<u64> {
+        self.inner.append_journal(entry)
+    }
+
+    fn query_journal(&self, context_id: &str, after: u64) -> StorageResult<Vec<PersistedJournalEntry>> {
+        self.inner.query_journal(context_id, after)
+    }
+
+    fn truncate_journal(&self, context_id: &str, before: u64) -> StorageResult<usize> {
+        self.inner.truncate_journal(context_id, before)
+    }
+
+    fn claim_ingest_key(&self, claim: &PersistedIngestResult, stale_before: &str) -> StorageResult<bool> {
+        self.inner.claim_ingest_key(claim, stale_before)
+    }
+
+    fn persist_ingest_result(&self, result: &PersistedIngestResult) -> StorageResult<()> {
+        self.inner.persist_ingest_result(result)
+    }
+
+    fn release_ingest_key(&self, context_id: &str, key: &str) -> StorageResult<()> {
+        self.inner.release_ingest_key(context_id, key)
+    }
+
+    fn load_ingest_result(&self, context_id: &str, key: &str) -> StorageResult<Option<PersistedIngestResult>> {
+        self.inner.load_ingest_result(context_id, key)
+    }
+
+    fn prune_ingest_results(&self, before: &str) -> StorageResult<usize> {
+        self.inner.prune_ingest_results(before)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{ContentType, PlexusEngine};
+    use crate::storage::{OpenStore, SqliteStore};
+
+    fn journaled(snapshot_every: usize) -> (Arc<SqliteStore>, Arc<EventSourcedStore>) {
+        let disk = Arc::new(SqliteStore::open_in_memory().unwrap());
+        let store = Arc::new(EventSourcedStore::new(disk.clone()).with_snapshot_every(snapshot_every));
+        (disk, store)
+    }
+
+    // === Scenario: The journal is the source of truth ===
+    #[test]
+    fn contexts_are_replayed_from_the_journal() {
+        let (disk, store) = journaled(DEFAULT_SNAPSHOT_EVERY);
+        let engine = PlexusEngine::event_sourced(store.clone());
+
+        let id = engine.upsert_context(Context::new("journal")).unwrap();
+        let a = Node::new("fragment", ContentType::Document);
+        let b = Node::new("fragment", ContentType::Document);
+        let (a_id, b_id) = (a.id.clone(), b.id.clone());
+        engine.add_node(&id, a).unwrap();
+        engine.add_node(&id, b).unwrap();
+        engine.add_edge(&id, Edge::new(a_id.clone(), b_id.clone(), "follows")).unwrap();
+        engine.with_context_mut(&id, |ctx| ctx.trash_node(&b_id)).unwrap();
+        engine.rename_context(&id, "diary").unwrap();
+
+        let entries = store.journal(&id, 0).unwrap();
+        assert!(matches!(entries[0].record, JournalRecord::Snapshot(_)), "a new context starts with a snapshot");
+        assert!(entries[1..].iter().all(|e| matches!(e.record, JournalRecord::Change(_))));
+        assert_eq!(disk.load_context(&id).unwrap().unwrap().name, "diary", "the context row stays current");
+
+        let reopened = EventSourcedStore::new(disk.clone());
+        let replayed = reopened.load_context(&id).unwrap().unwrap();
+        assert_eq!(replayed.name, "diary");
+        assert_eq!(replayed.node_count(), 1);
+        assert!(replayed.get_node(&a_id).is_some());
+        assert!(replayed.edges.is_empty(), "the edge went with its endpoint");
+        assert_eq!(replayed.trash.len(), 2);
+    }
+
+    // === Scenario: Snapshots bound replay ===
+    #[test]
+    fn snapshots_are_taken_on_cadence_and_compaction_drops_history() {
+        let (disk, store) = journaled(3);
+        let engine = PlexusEngine::event_sourced(store.clone());
+        let id = engine.upsert_context(Context::new("cadence")).unwrap();
+        for _ in 0..7 {
+            engine.add_node(&id, Node::new("fragment", ContentType::Document)).unwrap();
+        }
+
+        let snapshots = |s: &EventSourcedStore| {
+            s.journal(&id, 0).unwrap().iter().filter(|e| matches!(e.record, JournalRecord::Snapshot(_))).count()
+        };
+        assert_eq!(snapshots(&store), 3, "genesis, then one per three changes");
+        assert_eq!(disk.load_context(&id).unwrap().unwrap().node_count(), 6, "snapshots materialize rows");
+        assert_eq!(store.load_nodes(&id, &NodeFilter::new()).unwrap().len(), 7, "filtered loads see the journal");
+        assert_eq!(store.count_nodes(&id, &NodeFilter::new()).unwrap(), 7);
+        assert_eq!(store.count_edges(&id, &EdgeFilter::default()).unwrap(), 0);
+        let manifest = store.load_manifest().unwrap();
+        assert_eq!(manifest.iter().find(|e| e.id == id).map(|e| e.node_count), Some(7), "the context row is current");
+
+        let before = store.journal(&id, 0).unwrap().len();
+        assert_eq!(store.compact_journal(&id).unwrap(), before);
+        let entries = store.journal(&id, 0).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(EventSourcedStore::new(disk).load_context(&id).unwrap().unwrap().node_count(), 7);
+    }
+
+    // === Scenario: Projections as of a past moment are exact ===
+    #[test]
+    fn project_as_of_replays_up_to_the_cutoff() {
+        let (_, store) = journaled(DEFAULT_SNAPSHOT_EVERY);
+        let engine = PlexusEngine::event_sourced(store.clone());
+        let id = engine.upsert_context(Context::new("history")).unwrap();
+        let node = Node::new("fragment", ContentType::Document);
+        let node_id = node.id.clone();
+        engine.add_node(&id, node).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        let cutoff = Utc::now();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        engine.with_context_mut(&id, |ctx| ctx.nodes.remove(&node_id)).unwrap();
+
+        let past = store.project_as_of(&id, cutoff).unwrap().unwrap();
+        assert!(past.get_node(&node_id).is_some(), "a hard-deleted node is still in the journal");
+        let view = engine.context_as_of(&id, cutoff).unwrap();
+        assert!(view.get_node(&node_id).is_some());
+        assert!(view.unrecoverable_nodes().is_empty());
+
+        store.compact_journal(&id).unwrap();
+        assert!(store.project_as_of(&id, cutoff).unwrap().is_none(), "compacted history is gone");
+    }
+}
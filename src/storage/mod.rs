@@ -3,12 +3,19 @@
 //! Plexus supports multiple storage backends through the `GraphStore` trait.
 //! The primary implementation is `SqliteStore` for persistent storage.
 
+mod buffered;
+mod event_sourced;
 mod sqlite;
 #[cfg(feature = "embeddings")]
 mod sqlite_vec;
 mod traits;
 
-pub use sqlite::SqliteStore;
-pub use traits::{GraphStore, OpenStore, PersistedSpec, StorageError, StorageResult};
+pub use buffered::BufferedStore;
+pub use event_sourced::{ContextChange, ContextHeader, EventSourcedStore, JournalEntry, JournalRecord, DEFAULT_SNAPSHOT_EVERY};
+pub use sqlite::{SqliteStore, RELAXED_IDLE};
+pub use traits::{
+    CompactionReport, EdgeFilter, EmissionFilter, GraphStore, ManifestEntry, NodeFilter, OpenStore, PersistedEmbedding, PersistedEmission, PersistedIngestResult, PersistedJournalEntry, PersistedLlmCost, PersistedOutboundEvent, PersistedSpec, PersistedTemplate, StorageError,
+    StorageResult,
+};
 #[cfg(feature = "embeddings")]
 pub use sqlite_vec::{SqliteVecStore, DEFAULT_EMBEDDING_DIMENSIONS};
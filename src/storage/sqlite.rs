@@ -1,17 +1,66 @@
 //! SQLite storage backend for Plexus
 
-use super::traits::{GraphStore, OpenStore, PersistedSpec, StorageError, StorageResult};
-use crate::graph::{Context, ContextId, Edge, Node, NodeId};
+use super::traits::{
+    CompactionReport, EdgeFilter, EmissionFilter, GraphStore, ManifestEntry, NodeFilter, OpenStore, PersistedEmbedding, PersistedEmission, PersistedIngestResult, PersistedJournalEntry, PersistedLlmCost, PersistedOutboundEvent, PersistedSpec, PersistedTemplate, StorageError, StorageResult,
+};
+use crate::graph::{Context, ContextId, ContextMetadata, Durability, Edge, Node, NodeId, Tombstone, Trash};
 use crate::query::{CursorFilter, PersistedEvent};
-use rusqlite::{params, Connection, OptionalExtension};
+use rusqlite::backup::Backup;
+use rusqlite::types::Value;
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension};
 use std::collections::{HashMap, HashSet};
-use std::path::Path;
-use std::sync::Mutex;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
 
 /// Per-context baseline: the set of node/edge IDs that were last loaded or saved.
 /// Used by incremental `save_context()` to determine which IDs to delete.
 type Baseline = (HashSet<String>, HashSet<String>); // (node_ids, edge_ids)
 
+/// How long a context must go without saves before `flush_due` commits
+/// it under `Durability::Relaxed`.
+pub const RELAXED_IDLE: Duration = Duration::from_secs(2);
+
+/// A save held back by its context's durability level.
+struct Held {
+    context: Box<Context>,
+    /// When the first save still held arrived
+    since: Instant,
+    /// When the latest save arrived
+    last: Instant,
+    /// Emission records about the held graph, committed with it
+    emissions: Vec<PersistedEmission>,
+}
+
+/// Saves held back by `Durability::Batched` and `Relaxed` contexts.
+#[derive(Default)]
+struct Deferred {
+    contexts: HashMap<ContextId, Held>,
+}
+
+impl Deferred {
+    /// Held contexts whose save is due at `now`.
+    fn due(&self, now: Instant) -> Vec<ContextId> {
+        self.contexts
+            .iter()
+            .filter(|(_, held)| match held.context.metadata.durability.unwrap_or_default() {
+                Durability::Batched { interval_ms } => now.duration_since(held.since) >= Duration::from_millis(interval_ms),
+                Durability::Relaxed => now.duration_since(held.last) >= RELAXED_IDLE,
+                Durability::Strict => true,
+            })
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+
+    fn hold(&mut self, context: &Context, now: Instant) {
+        let (since, emissions) = match self.contexts.remove(&context.id) {
+            Some(held) => (held.since, held.emissions),
+            None => (now, Vec::new()),
+        };
+        self.contexts.insert(context.id.clone(), Held { context: Box::new(context.clone()), since, last: now, emissions });
+    }
+}
+
 /// SQLite-backed graph store
 ///
 /// Uses a single SQLite database file with tables for contexts, nodes, and edges.
@@ -21,20 +70,76 @@ type Baseline = (HashSet<String>, HashSet<String>); // (node_ids, edge_ids)
 /// perform incremental upserts: nodes/edges added by other engines since the
 /// last load are preserved, while nodes/edges explicitly removed by this
 /// engine are deleted.
+///
+/// Saves honor each context's `Durability`: strict contexts commit (and,
+/// with `synchronous = FULL`, sync) on every save; batched and relaxed
+/// contexts are held in memory and group-committed when due — on the
+/// next save or `flush_due` (run periodically by an engine over the store,
+/// or see `spawn_deferred_flush`), before any read of them through this
+/// store or write to their events, journal, outbound queue or ingest
+/// results, and on `flush_deferred` or drop. Emission records about a
+/// held save are held with it and commit in its transaction. Other
+/// connections to the database don't see held saves.
 pub struct SqliteStore {
     conn: Mutex<Connection>,
+    /// Database file, or None for in-memory stores. Backups read through
+    /// their own connection so they don't hold `conn` while copying.
+    path: Option<PathBuf>,
     /// Baselines keyed by context ID string.
     baselines: Mutex<HashMap<String, Baseline>>,
+    deferred: Mutex<Deferred>,
 }
 
+/// A schema migration: the version it upgrades to and the step itself.
+type Migration = (u32, &'static str, fn(&Connection) -> StorageResult<()>);
+
+/// Ordered schema migrations. Each step is idempotent (it detects whether
+/// its change is already present), so databases that predate version
+/// tracking upgrade from version 0 safely. Append new steps; never reorder.
+const MIGRATIONS: &[Migration] = &[
+    (1, "legacy edge columns", SqliteStore::migrate_legacy_edge_columns),
+    (2, "dimension columns", SqliteStore::migrate_add_dimensions),
+    (3, "edge contributions", SqliteStore::migrate_add_contributions),
+    (4, "events table", SqliteStore::migrate_add_events_table),
+    (5, "specs table", SqliteStore::migrate_add_specs_table),
+    (6, "edge contribution log", SqliteStore::migrate_add_contribution_log),
+    (7, "context tenant", SqliteStore::migrate_add_context_tenant),
+    (8, "soft delete", SqliteStore::migrate_add_deleted_at),
+    (9, "llm costs table", SqliteStore::migrate_add_llm_costs_table),
+    (10, "outbound queue", SqliteStore::migrate_add_outbound_queue),
+    (11, "context templates", SqliteStore::migrate_add_context_templates),
+    (12, "embeddings table", SqliteStore::migrate_add_embeddings_table),
+    (13, "change journal", SqliteStore::migrate_add_journal),
+    (14, "ingest results", SqliteStore::migrate_add_ingest_results),
+    (15, "context manifest counts", SqliteStore::migrate_add_manifest_counts),
+    (16, "emissions table", SqliteStore::migrate_add_emissions_table),
+    (17, "embeddings keyed by context id", SqliteStore::migrate_key_embeddings_by_context_id),
+    (18, "ingest result claims", SqliteStore::migrate_add_ingest_claims),
+    (19, "event keys", SqliteStore::migrate_add_event_keys),
+];
+
 impl SqliteStore {
+    /// Schema version this build reads and writes.
+    pub const SCHEMA_VERSION: u32 = MIGRATIONS[MIGRATIONS.len() - 1].0;
+
     /// Initialize the database schema
     ///
-    /// Uses a two-phase approach for migration compatibility:
+    /// Uses a three-phase approach for migration compatibility:
     /// 1. Create base tables (without new dimension columns) - safe for existing DBs
-    /// 2. Run migrations to add dimension columns to existing tables
+    /// 2. Run migrations newer than the recorded schema version
     /// 3. Create dimension indexes (now columns exist)
-    fn init_schema(conn: &Connection) -> StorageResult<()> {
+    ///
+    /// When `backup_path` is given and an existing database needs
+    /// migrating, a copy is written there first.
+    fn init_schema(conn: &Connection, backup_path: Option<&Path>) -> StorageResult<()> {
+        let existing: bool = conn
+            .query_row(
+                "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type='table' AND name='contexts'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap_or(false);
+
         // Phase 1: Create base tables (compatible with pre-Phase 5.0 databases)
         // Note: CREATE TABLE IF NOT EXISTS won't modify existing tables,
         // so we use the minimal schema here and add columns via migration.
@@ -93,15 +198,14 @@ impl SqliteStore {
 
             -- Enable WAL mode for concurrent reads during writes (ADR-017 §1)
             PRAGMA journal_mode = WAL;
+
+            -- Sync every commit: strict contexts' saves are durable on return
+            PRAGMA synchronous = FULL;
             "#,
         )?;
 
-        // Phase 2: Run migrations (order matters — legacy rebuild before column additions)
-        Self::migrate_legacy_edge_columns(conn)?;
-        Self::migrate_add_dimensions(conn)?;
-        Self::migrate_add_contributions(conn)?;
-        Self::migrate_add_events_table(conn)?;
-        Self::migrate_add_specs_table(conn)?;
+        // Phase 2: Run pending migrations (order matters — legacy rebuild before column additions)
+        Self::run_migrations(conn, if existing { backup_path } else { None })?;
 
         // Phase 3: Create dimension indexes (now that columns exist)
         Self::create_dimension_indexes(conn)?;
@@ -109,6 +213,121 @@ impl SqliteStore {
         Ok(())
     }
 
+    /// Read the recorded schema version; 0 for databases that predate
+    /// version tracking.
+    fn read_schema_version(conn: &Connection) -> StorageResult<u32> {
+        conn.execute("CREATE TABLE IF NOT EXISTS schema_version (version INTEGER NOT NULL)", [])?;
+        let version: Option<u32> = conn
+            .query_row("SELECT MAX(version) FROM schema_version", [], |row| row.get(0))?;
+        Ok(version.unwrap_or(0))
+    }
+
+    /// Upgrade to `SCHEMA_VERSION`, one migration per transaction.
+    ///
+    /// A database written by a newer build is refused rather than
+    /// opened with a schema this build doesn't understand.
+    fn run_migrations(conn: &Connection, backup_path: Option<&Path>) -> StorageResult<()> {
+        let current = Self::read_schema_version(conn)?;
+        if current > Self::SCHEMA_VERSION {
+            return Err(StorageError::UnsupportedSchemaVersion {
+                found: current,
+                supported: Self::SCHEMA_VERSION,
+            });
+        }
+        if current == Self::SCHEMA_VERSION {
+            return Ok(());
+        }
+
+        if let Some(backup) = backup_path {
+            // VACUUM INTO writes a consistent copy, WAL contents included.
+            // A backup left by an interrupted migration is kept as is.
+            if !backup.exists() {
+                conn.execute("VACUUM INTO ?1", params![backup.to_string_lossy()])?;
+            }
+            tracing::info!(
+                from = current,
+                to = Self::SCHEMA_VERSION,
+                backup = %backup.display(),
+                "migrating database schema"
+            );
+        }
+
+        for (version, name, migrate) in MIGRATIONS.iter().filter(|(v, _, _)| *v > current) {
+            let tx = conn.unchecked_transaction()?;
+            migrate(&tx).map_err(|e| {
+                StorageError::Internal(format!("schema migration {} ({}) failed: {}", version, name, e))
+            })?;
+            tx.execute("DELETE FROM schema_version", [])?;
+            tx.execute("INSERT INTO schema_version (version) VALUES (?1)", params![version])?;
+            tx.commit()?;
+        }
+        Ok(())
+    }
+
+    /// WHERE condition and parameters selecting `filter`'s nodes.
+    fn node_condition(context_id: &ContextId, filter: &NodeFilter) -> StorageResult<(String, Vec<Value>)> {
+        let mut condition = String::from("context_id = ? AND deleted_at IS NULL");
+        let mut values: Vec<Value> = vec![context_id.as_str().to_string().into()];
+        let mut clause = |column: &str, value: Value| {
+            condition.push_str(&format!(" AND {} = ?", column));
+            values.push(value);
+        };
+        if let Some(node_type) = &filter.node_type {
+            clause("node_type", node_type.clone().into());
+        }
+        if let Some(content_type) = &filter.content_type {
+            clause("content_type", serde_json::to_string(content_type)?.into());
+        }
+        if let Some(dimension) = &filter.dimension {
+            clause("dimension", dimension.clone().into());
+        }
+        Ok((condition, values))
+    }
+
+    /// WHERE condition and parameters selecting `filter`'s edges.
+    fn edge_condition(context_id: &ContextId, filter: &EdgeFilter) -> (String, Vec<Value>) {
+        let mut condition = String::from("context_id = ? AND deleted_at IS NULL");
+        let mut values: Vec<Value> = vec![context_id.as_str().to_string().into()];
+        let mut clause = |comparison: &str, value: Value| {
+            condition.push_str(&format!(" AND {} ?", comparison));
+            values.push(value);
+        };
+        if let Some(source) = &filter.source {
+            clause("source_id =", source.as_str().to_string().into());
+        }
+        if let Some(target) = &filter.target {
+            clause("target_id =", target.as_str().to_string().into());
+        }
+        if let Some(relationship) = &filter.relationship {
+            clause("relationship =", relationship.clone().into());
+        }
+        if let Some(dimension) = &filter.source_dimension {
+            clause("source_dimension =", dimension.clone().into());
+        }
+        if let Some(dimension) = &filter.target_dimension {
+            clause("target_dimension =", dimension.clone().into());
+        }
+        if let Some(weight) = filter.min_weight {
+            clause("raw_weight >=", f64::from(weight).into());
+        }
+        (condition, values)
+    }
+
+    /// Size of the database after checkpointing the WAL into it.
+    fn database_bytes(conn: &Connection) -> StorageResult<u64> {
+        conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))?;
+        let pages: i64 = conn.query_row("PRAGMA page_count", [], |row| row.get(0))?;
+        let page_size: i64 = conn.query_row("PRAGMA page_size", [], |row| row.get(0))?;
+        Ok((pages * page_size) as u64)
+    }
+
+    /// Backup location for a database about to be migrated from `version`.
+    fn backup_path_for(path: &Path, version: u32) -> std::path::PathBuf {
+        let mut name = path.file_name().unwrap_or_default().to_os_string();
+        name.push(format!(".v{}.bak", version));
+        path.with_file_name(name)
+    }
+
     /// Create indexes for dimension columns (Phase 5.0)
     ///
     /// Called after migration ensures dimension columns exist.
@@ -266,6 +485,70 @@ impl SqliteStore {
         Ok(())
     }
 
+    /// Migration: Add contribution_log_json column to edges table.
+    ///
+    /// Holds the edge's timestamped contribution history as compact
+    /// `[contributor, value, unix_millis]` tuples. Existing edges get '[]'.
+    fn migrate_add_contribution_log(conn: &Connection) -> StorageResult<()> {
+        let has_log: bool = conn
+            .query_row(
+                "SELECT COUNT(*) > 0 FROM pragma_table_info('edges') WHERE name = 'contribution_log_json'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap_or(false);
+
+        if !has_log {
+            conn.execute(
+                "ALTER TABLE edges ADD COLUMN contribution_log_json TEXT NOT NULL DEFAULT '[]'",
+                [],
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Migration: Add tenant_id column to contexts table.
+    ///
+    /// Mirrors `ContextMetadata::tenant` so one tenant's contexts can be
+    /// listed without decoding every row's metadata.
+    fn migrate_add_context_tenant(conn: &Connection) -> StorageResult<()> {
+        let has_tenant: bool = conn
+            .query_row(
+                "SELECT COUNT(*) > 0 FROM pragma_table_info('contexts') WHERE name = 'tenant_id'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap_or(false);
+
+        if !has_tenant {
+            conn.execute("ALTER TABLE contexts ADD COLUMN tenant_id TEXT", [])?;
+        }
+        conn.execute("CREATE INDEX IF NOT EXISTS idx_contexts_tenant ON contexts(tenant_id)", [])?;
+
+        Ok(())
+    }
+
+    /// Migration: Add deleted_at tombstone columns to nodes and edges.
+    ///
+    /// Trashed rows keep their data with `deleted_at` set; every query
+    /// other than `load_context` reads only rows where it is NULL.
+    fn migrate_add_deleted_at(conn: &Connection) -> StorageResult<()> {
+        for table in ["nodes", "edges"] {
+            let has_column: bool = conn
+                .query_row(
+                    &format!("SELECT COUNT(*) > 0 FROM pragma_table_info('{table}') WHERE name = 'deleted_at'"),
+                    [],
+                    |row| row.get(0),
+                )
+                .unwrap_or(false);
+            if !has_column {
+                conn.execute(&format!("ALTER TABLE {table} ADD COLUMN deleted_at TEXT"), [])?;
+            }
+        }
+        Ok(())
+    }
+
     /// Migration: Add events table for cursor-based change queries (ADR-035).
     ///
     /// The events table persists graph events with sequence numbers, enabling
@@ -329,6 +612,211 @@ impl SqliteStore {
         Ok(())
     }
 
+    /// Migration: add `llm_costs` table for LLM cost accounting.
+    fn migrate_add_llm_costs_table(conn: &Connection) -> StorageResult<()> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS llm_costs (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                context_id TEXT NOT NULL,
+                adapter_id TEXT NOT NULL,
+                item TEXT NOT NULL,
+                ensemble TEXT NOT NULL,
+                input_tokens INTEGER NOT NULL,
+                output_tokens INTEGER NOT NULL,
+                cost_usd REAL NOT NULL,
+                recorded_at TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_llm_costs_context_time ON llm_costs(context_id, recorded_at);
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// Migration: add `outbound_events` and `outbound_acks` tables for the
+    /// outbound delivery queue.
+    fn migrate_add_outbound_queue(conn: &Connection) -> StorageResult<()> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS outbound_events (
+                seq INTEGER PRIMARY KEY AUTOINCREMENT,
+                context_id TEXT NOT NULL,
+                kind TEXT NOT NULL,
+                detail TEXT NOT NULL,
+                recorded_at TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_outbound_events_context_seq ON outbound_events(context_id, seq);
+            CREATE TABLE IF NOT EXISTS outbound_acks (
+                context_id TEXT NOT NULL,
+                subscriber TEXT NOT NULL,
+                acked_seq INTEGER NOT NULL,
+                PRIMARY KEY (context_id, subscriber)
+            );
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// Migration: add the `context_templates` table for user-defined
+    /// context templates.
+    fn migrate_add_context_templates(conn: &Connection) -> StorageResult<()> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS context_templates (
+                name TEXT PRIMARY KEY,
+                template_json TEXT NOT NULL,
+                saved_at TEXT NOT NULL
+            );
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// Migration: add the `embeddings` table, so embedding caches survive
+    /// restarts. Vectors are little-endian `f32` blobs.
+    fn migrate_add_embeddings_table(conn: &Connection) -> StorageResult<()> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS embeddings (
+                context_id TEXT NOT NULL,
+                model TEXT NOT NULL,
+                node_id TEXT NOT NULL,
+                text_hash TEXT NOT NULL,
+                vector BLOB NOT NULL,
+                PRIMARY KEY (context_id, model, node_id)
+            );
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// Migration: embeddings used to be keyed by context name. Rows whose
+    /// name picks out exactly one context move to its ID; the rest are
+    /// dropped and re-embedded on next use.
+    fn migrate_key_embeddings_by_context_id(conn: &Connection) -> StorageResult<()> {
+        conn.execute_batch(
+            r#"
+            UPDATE OR REPLACE embeddings
+            SET context_id = (SELECT id FROM contexts WHERE contexts.name = embeddings.context_id)
+            WHERE context_id NOT IN (SELECT id FROM contexts)
+              AND (SELECT COUNT(*) FROM contexts WHERE contexts.name = embeddings.context_id) = 1;
+            DELETE FROM embeddings WHERE context_id NOT IN (SELECT id FROM contexts);
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// Migration: add the `journal` table for event-sourced contexts.
+    fn migrate_add_journal(conn: &Connection) -> StorageResult<()> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS journal (
+                seq INTEGER PRIMARY KEY AUTOINCREMENT,
+                context_id TEXT NOT NULL,
+                kind TEXT NOT NULL,
+                payload TEXT NOT NULL,
+                recorded_at TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_journal_context_seq ON journal(context_id, seq);
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// Migration: add the `ingest_results` table for idempotency keys.
+    fn migrate_add_ingest_results(conn: &Connection) -> StorageResult<()> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS ingest_results (
+                context_id TEXT NOT NULL,
+                key TEXT NOT NULL,
+                input_kind TEXT NOT NULL,
+                outbound_json TEXT NOT NULL,
+                recorded_at TEXT NOT NULL,
+                PRIMARY KEY (context_id, key)
+            );
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// Migration: record each idempotency key's payload hash, and whether
+    /// its ingest finished or is only claimed.
+    fn migrate_add_ingest_claims(conn: &Connection) -> StorageResult<()> {
+        for (column, definition) in [("payload_hash", "TEXT"), ("completed", "INTEGER NOT NULL DEFAULT 1")] {
+            let has_column: bool = conn
+                .query_row(
+                    "SELECT COUNT(*) > 0 FROM pragma_table_info('ingest_results') WHERE name = ?1",
+                    params![column],
+                    |row| row.get(0),
+                )
+                .unwrap_or(false);
+            if !has_column {
+                conn.execute(&format!("ALTER TABLE ingest_results ADD COLUMN {column} {definition}"), [])?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Migration: add `keys_json` to the events table, for the property
+    /// keys or metadata fields an event changed.
+    fn migrate_add_event_keys(conn: &Connection) -> StorageResult<()> {
+        let has_column: bool = conn
+            .query_row("SELECT COUNT(*) > 0 FROM pragma_table_info('events') WHERE name = 'keys_json'", [], |row| row.get(0))
+            .unwrap_or(false);
+        if !has_column {
+            conn.execute("ALTER TABLE events ADD COLUMN keys_json TEXT", [])?;
+        }
+        Ok(())
+    }
+
+    /// Migration: add `node_count` / `edge_count` to the contexts table,
+    /// backfilled from the live rows, so `load_manifest` can summarize
+    /// contexts without reading their nodes.
+    fn migrate_add_manifest_counts(conn: &Connection) -> StorageResult<()> {
+        for column in ["node_count", "edge_count"] {
+            let has_column: bool = conn
+                .query_row(
+                    "SELECT COUNT(*) > 0 FROM pragma_table_info('contexts') WHERE name = ?1",
+                    params![column],
+                    |row| row.get(0),
+                )
+                .unwrap_or(false);
+            if !has_column {
+                conn.execute(&format!("ALTER TABLE contexts ADD COLUMN {column} INTEGER"), [])?;
+            }
+        }
+        conn.execute_batch(
+            r#"
+            UPDATE contexts SET
+                node_count = (SELECT COUNT(*) FROM nodes WHERE nodes.context_id = contexts.id AND deleted_at IS NULL),
+                edge_count = (SELECT COUNT(*) FROM edges WHERE edges.context_id = contexts.id AND deleted_at IS NULL)
+            WHERE node_count IS NULL OR edge_count IS NULL;
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// Migration: add the `emissions` table recording which adapter
+    /// committed what to each context.
+    fn migrate_add_emissions_table(conn: &Connection) -> StorageResult<()> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS emissions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                context_id TEXT NOT NULL,
+                adapter_id TEXT NOT NULL,
+                input_summary TEXT,
+                node_ids TEXT NOT NULL,
+                edge_ids TEXT NOT NULL,
+                recorded_at TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_emissions_context_time ON emissions(context_id, recorded_at);
+            "#,
+        )?;
+        Ok(())
+    }
+
     /// Serialize a node to database columns (includes dimension field)
     fn node_to_row(node: &Node) -> StorageResult<(String, String, String, String, String, String)> {
         Ok((
@@ -375,6 +863,7 @@ impl SqliteStore {
         String,
         String,
         String,
+        String,
     )> {
         Ok((
             edge.id.as_str().to_string(),
@@ -387,6 +876,7 @@ impl SqliteStore {
             edge.created_at.to_rfc3339(),
             serde_json::to_string(&edge.properties)?,
             serde_json::to_string(&edge.contributions)?,
+            serde_json::to_string(&edge.contribution_log)?,
         ))
     }
 
@@ -403,6 +893,7 @@ impl SqliteStore {
         created_at: String,
         properties_json: String,
         contributions_json: String,
+        contribution_log_json: String,
     ) -> StorageResult<Edge> {
         use chrono::DateTime;
         use crate::graph::EdgeId;
@@ -420,174 +911,74 @@ impl SqliteStore {
                 .map_err(|e| StorageError::DateParse(e.to_string()))?
                 .with_timezone(&chrono::Utc),
             properties: serde_json::from_str(&properties_json)?,
+            contribution_log: serde_json::from_str(&contribution_log_json)?,
         })
     }
-}
-
-impl OpenStore for SqliteStore {
-    fn open(path: impl AsRef<Path>) -> StorageResult<Self> {
-        // Ensure parent directory exists
-        if let Some(parent) = path.as_ref().parent() {
-            std::fs::create_dir_all(parent)?;
-        }
-
-        let conn = Connection::open(path)?;
-        Self::init_schema(&conn)?;
-
-        Ok(Self {
-            conn: Mutex::new(conn),
-            baselines: Mutex::new(HashMap::new()),
-        })
-    }
-
-    fn open_in_memory() -> StorageResult<Self> {
-        let conn = Connection::open_in_memory()?;
-        Self::init_schema(&conn)?;
 
-        Ok(Self {
-            conn: Mutex::new(conn),
-            baselines: Mutex::new(HashMap::new()),
-        })
+    fn parse_deleted_at(deleted_at: &str) -> StorageResult<chrono::DateTime<chrono::Utc>> {
+        Ok(chrono::DateTime::parse_from_rfc3339(deleted_at)
+            .map_err(|e| StorageError::DateParse(e.to_string()))?
+            .with_timezone(&chrono::Utc))
     }
-}
-
-impl GraphStore for SqliteStore {
-    // === Context Operations ===
-
-    fn save_context_metadata(&self, context: &Context) -> StorageResult<()> {
-        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
-        let metadata_json = serde_json::to_string(&context.metadata)?;
 
+    /// Upsert a context's row (name, description, metadata, counts).
+    fn write_context_row(conn: &Connection, context: &Context) -> StorageResult<()> {
         conn.execute(
             r#"
-            INSERT INTO contexts (id, name, description, metadata_json)
-            VALUES (?1, ?2, ?3, ?4)
+            INSERT INTO contexts (id, name, description, metadata_json, tenant_id, node_count, edge_count)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
             ON CONFLICT(id) DO UPDATE SET
                 name = excluded.name,
                 description = excluded.description,
-                metadata_json = excluded.metadata_json
+                metadata_json = excluded.metadata_json,
+                tenant_id = excluded.tenant_id,
+                node_count = excluded.node_count,
+                edge_count = excluded.edge_count
             "#,
             params![
                 context.id.as_str(),
                 context.name,
                 context.description,
-                metadata_json,
+                serde_json::to_string(&context.metadata)?,
+                context.metadata.tenant,
+                context.node_count() as i64,
+                context.edge_count() as i64,
             ],
         )?;
-
         Ok(())
     }
 
-    fn save_context(&self, context: &Context) -> StorageResult<()> {
-        // Save the context row (name, description, metadata) — single upsert, atomic by itself
-        self.save_context_metadata(context)?;
-
+    /// Save `contexts` in one transaction, so a group commit syncs once.
+    ///
+    /// Incremental upsert (ADR-017 §3): upsert nodes/edges that are in
+    /// each context, then delete only those that the context explicitly
+    /// does NOT contain. This preserves nodes/edges written by other
+    /// engines sharing the same database. The explicit transaction means
+    /// an interrupted write cannot leave partial node/edge state.
+    fn commit_contexts(&self, contexts: &[&Context], emissions: &[&PersistedEmission]) -> StorageResult<()> {
         let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
-
-        // Incremental upsert (ADR-017 §3): upsert nodes/edges that are in
-        // the context, then delete only those that the context explicitly
-        // does NOT contain. This preserves nodes/edges written by other
-        // engines sharing the same database.
-        //
-        // Wrapped in an explicit transaction so an interrupted write cannot
-        // leave partial node/edge state.
-
         conn.execute_batch("BEGIN IMMEDIATE")?;
 
-        let result = (|| -> StorageResult<(HashSet<String>, HashSet<String>)> {
-            // --- Nodes: upsert all in-memory nodes ---
-            let context_node_ids: HashSet<String> = context
-                .nodes
-                .keys()
-                .map(|id| id.to_string())
-                .collect();
-
-            for node in context.nodes.values() {
-                let (id, node_type, content_type, dimension, properties, metadata) = Self::node_to_row(node)?;
-                conn.execute(
-                    r#"
-                    INSERT INTO nodes (id, context_id, node_type, content_type, dimension, properties_json, metadata_json)
-                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
-                    ON CONFLICT(context_id, id) DO UPDATE SET
-                        node_type = excluded.node_type,
-                        content_type = excluded.content_type,
-                        dimension = excluded.dimension,
-                        properties_json = excluded.properties_json,
-                        metadata_json = excluded.metadata_json
-                    "#,
-                    params![id, context.id.as_str(), node_type, content_type, dimension, properties, metadata],
-                )?;
-            }
-
-            // --- Edges: upsert all in-memory edges ---
-            let context_edge_ids: HashSet<String> = context
-                .edges
-                .iter()
-                .map(|e| e.id.to_string())
-                .collect();
-
-            for edge in &context.edges {
-                let (id, source, target, source_dim, target_dim, rel, raw_weight, created, props, contributions) =
-                    Self::edge_to_row(edge)?;
-
-                conn.execute(
-                    r#"
-                    INSERT INTO edges (id, context_id, source_id, target_id, source_dimension, target_dimension,
-                                       relationship, raw_weight, created_at, properties_json, contributions_json)
-                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
-                    ON CONFLICT(context_id, id) DO UPDATE SET
-                        source_id = excluded.source_id,
-                        target_id = excluded.target_id,
-                        source_dimension = excluded.source_dimension,
-                        target_dimension = excluded.target_dimension,
-                        relationship = excluded.relationship,
-                        raw_weight = excluded.raw_weight,
-                        properties_json = excluded.properties_json,
-                        contributions_json = excluded.contributions_json
-                    "#,
-                    params![id, context.id.as_str(), source, target, source_dim, target_dim, rel, raw_weight, created, props, contributions],
-                )?;
-            }
-
-            // --- Delete nodes/edges that were in our baseline but are no longer
-            // in the context (i.e., explicitly removed by this engine). ---
-            // Nodes/edges added by other engines are NOT in our baseline, so
-            // they survive this save.
-            {
-                let baselines = self.baselines.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
-                if let Some((baseline_nodes, baseline_edges)) = baselines.get(context.id.as_str()) {
-                    // Delete edges first (foreign key safety)
-                    for baseline_edge_id in baseline_edges {
-                        if !context_edge_ids.contains(baseline_edge_id) {
-                            conn.execute(
-                                "DELETE FROM edges WHERE context_id = ?1 AND id = ?2",
-                                params![context.id.as_str(), baseline_edge_id],
-                            )?;
-                        }
-                    }
-                    // Delete nodes
-                    for baseline_node_id in baseline_nodes {
-                        if !context_node_ids.contains(baseline_node_id) {
-                            conn.execute(
-                                "DELETE FROM nodes WHERE context_id = ?1 AND id = ?2",
-                                params![context.id.as_str(), baseline_node_id],
-                            )?;
-                        }
-                    }
-                }
-            }
-
-            Ok((context_node_ids, context_edge_ids))
-        })();
+        let result = contexts
+            .iter()
+            .map(|context| {
+                Self::write_context_row(&conn, context)?;
+                self.write_context_rows(&conn, context)
+            })
+            .collect::<StorageResult<Vec<Baseline>>>()
+            .and_then(|baselines| {
+                emissions.iter().try_for_each(|emission| Self::insert_emission(&conn, emission))?;
+                Ok(baselines)
+            });
 
         match result {
-            Ok((context_node_ids, context_edge_ids)) => {
+            Ok(baselines) => {
                 conn.execute_batch("COMMIT")?;
-                // Update baseline to match current context state
-                self.baselines.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?.insert(
-                    context.id.as_str().to_string(),
-                    (context_node_ids, context_edge_ids),
-                );
+                // Update baselines to match current context state
+                let mut stored = self.baselines.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+                for (context, baseline) in contexts.iter().zip(baselines) {
+                    stored.insert(context.id.as_str().to_string(), baseline);
+                }
                 Ok(())
             }
             Err(e) => {
@@ -597,25 +988,306 @@ impl GraphStore for SqliteStore {
         }
     }
 
-    fn load_context(&self, id: &ContextId) -> StorageResult<Option<Context>> {
-        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+    fn insert_emission(conn: &Connection, emission: &PersistedEmission) -> StorageResult<()> {
+        conn.execute(
+            "INSERT INTO emissions (context_id, adapter_id, input_summary, node_ids, edge_ids, recorded_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                emission.context_id,
+                emission.adapter_id,
+                emission.input_summary,
+                serde_json::to_string(&emission.node_ids)?,
+                serde_json::to_string(&emission.edge_ids)?,
+                emission.recorded_at,
+            ],
+        )?;
+        Ok(())
+    }
 
-        // Load context metadata
-        let context_row: Option<(String, Option<String>, String)> = conn
-            .query_row(
-                "SELECT name, description, metadata_json FROM contexts WHERE id = ?1",
-                params![id.as_str()],
-                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
-            )
-            .optional()?;
+    /// Upsert one context's nodes and edges and delete those it dropped
+    /// since its baseline, inside the caller's transaction. Returns the
+    /// new baseline.
+    fn write_context_rows(&self, conn: &Connection, context: &Context) -> StorageResult<Baseline> {
+        // --- Nodes: upsert all in-memory nodes, then trashed ones ---
+        let mut context_node_ids: HashSet<String> = context
+            .nodes
+            .keys()
+            .map(|id| id.to_string())
+            .collect();
 
-        let Some((name, description, metadata_json)) = context_row else {
-            return Ok(None);
-        };
+        let trashed_nodes = context
+            .trash
+            .nodes
+            .values()
+            .filter(|t| !context.nodes.contains_key(&t.item.id))
+            .map(|t| (&t.item, Some(t.deleted_at.to_rfc3339())));
+        for (node, deleted_at) in context.nodes.values().map(|n| (n, None)).chain(trashed_nodes) {
+            let (id, node_type, content_type, dimension, properties, metadata) = Self::node_to_row(node)?;
+            conn.execute(
+                r#"
+                INSERT INTO nodes (id, context_id, node_type, content_type, dimension, properties_json, metadata_json,
+                                   deleted_at)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                ON CONFLICT(context_id, id) DO UPDATE SET
+                    node_type = excluded.node_type,
+                    content_type = excluded.content_type,
+                    dimension = excluded.dimension,
+                    properties_json = excluded.properties_json,
+                    metadata_json = excluded.metadata_json,
+                    deleted_at = excluded.deleted_at
+                "#,
+                params![id, context.id.as_str(), node_type, content_type, dimension, properties, metadata, deleted_at],
+            )?;
+            context_node_ids.insert(node.id.to_string());
+        }
 
-        // Load nodes
+        // --- Edges: upsert all in-memory edges, then trashed ones ---
+        let mut context_edge_ids: HashSet<String> = context
+            .edges
+            .iter()
+            .map(|e| e.id.to_string())
+            .collect();
+
+        let live_edge_ids = context_edge_ids.clone();
+        let trashed_edges = context
+            .trash
+            .edges
+            .iter()
+            .filter(|t| !live_edge_ids.contains(t.item.id.as_str()))
+            .map(|t| (&t.item, Some(t.deleted_at.to_rfc3339())));
+        for (edge, deleted_at) in context.edges.iter().map(|e| (e, None)).chain(trashed_edges) {
+            let (id, source, target, source_dim, target_dim, rel, raw_weight, created, props, contributions, log) =
+                Self::edge_to_row(edge)?;
+
+            conn.execute(
+                r#"
+                INSERT INTO edges (id, context_id, source_id, target_id, source_dimension, target_dimension,
+                                   relationship, raw_weight, created_at, properties_json, contributions_json,
+                                   contribution_log_json, deleted_at)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
+                ON CONFLICT(context_id, id) DO UPDATE SET
+                    source_id = excluded.source_id,
+                    target_id = excluded.target_id,
+                    source_dimension = excluded.source_dimension,
+                    target_dimension = excluded.target_dimension,
+                    relationship = excluded.relationship,
+                    raw_weight = excluded.raw_weight,
+                    properties_json = excluded.properties_json,
+                    contributions_json = excluded.contributions_json,
+                    contribution_log_json = excluded.contribution_log_json,
+                    deleted_at = excluded.deleted_at
+                "#,
+                params![id, context.id.as_str(), source, target, source_dim, target_dim, rel, raw_weight, created, props, contributions, log, deleted_at],
+            )?;
+            context_edge_ids.insert(edge.id.to_string());
+        }
+
+        // --- Delete nodes/edges that were in our baseline but are no longer
+        // in the context (i.e., explicitly removed by this engine). ---
+        // Nodes/edges added by other engines are NOT in our baseline, so
+        // they survive this save.
+        {
+            let baselines = self.baselines.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+            if let Some((baseline_nodes, baseline_edges)) = baselines.get(context.id.as_str()) {
+                // Delete edges first (foreign key safety)
+                for baseline_edge_id in baseline_edges {
+                    if !context_edge_ids.contains(baseline_edge_id) {
+                        conn.execute(
+                            "DELETE FROM edges WHERE context_id = ?1 AND id = ?2",
+                            params![context.id.as_str(), baseline_edge_id],
+                        )?;
+                    }
+                }
+                // Delete nodes
+                for baseline_node_id in baseline_nodes {
+                    if !context_node_ids.contains(baseline_node_id) {
+                        conn.execute(
+                            "DELETE FROM nodes WHERE context_id = ?1 AND id = ?2",
+                            params![context.id.as_str(), baseline_node_id],
+                        )?;
+                    }
+                }
+            }
+        }
+
+        Ok((context_node_ids, context_edge_ids))
+    }
+
+    fn deferred(&self) -> StorageResult<MutexGuard<'_, Deferred>> {
+        self.deferred.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))
+    }
+
+    /// Group-commit the held saves of `ids`, with the emission records
+    /// held alongside them. On error they stay held.
+    fn commit_held(&self, deferred: &mut Deferred, ids: &[ContextId]) -> StorageResult<usize> {
+        let held: Vec<&Held> = ids.iter().filter_map(|id| deferred.contexts.get(id)).collect();
+        if held.is_empty() {
+            return Ok(0);
+        }
+        let contexts: Vec<&Context> = held.iter().map(|held| held.context.as_ref()).collect();
+        let emissions: Vec<&PersistedEmission> = held.iter().flat_map(|held| &held.emissions).collect();
+        self.commit_contexts(&contexts, &emissions)?;
+        let committed = held.len();
+        for id in ids {
+            deferred.contexts.remove(id);
+        }
+        Ok(committed)
+    }
+
+    /// Commit held saves of `id` (or of every context) before a read, so
+    /// this store always reads its own writes.
+    fn settle(&self, id: Option<&ContextId>) -> StorageResult<()> {
+        let mut deferred = self.deferred()?;
+        let ids: Vec<ContextId> = match id {
+            Some(id) => vec![id.clone()],
+            None => deferred.contexts.keys().cloned().collect(),
+        };
+        self.commit_held(&mut deferred, &ids).map(|_| ())
+    }
+
+    /// Commit held saves of `context_id` before a side-table row about it
+    /// (event, journal entry…) commits, so those rows never describe a
+    /// graph that isn't on disk, or before such rows are read.
+    fn settle_side_write(&self, context_id: &str) -> StorageResult<()> {
+        self.settle(Some(&ContextId::from(context_id)))
+    }
+
+    /// Commit every held save now, as on shutdown.
+    pub fn flush_deferred(&self) -> StorageResult<usize> {
+        let mut deferred = self.deferred()?;
+        let ids: Vec<ContextId> = deferred.contexts.keys().cloned().collect();
+        self.commit_held(&mut deferred, &ids)
+    }
+
+    /// Number of contexts with saves held by their durability level.
+    pub fn deferred_contexts(&self) -> usize {
+        self.deferred().map(|d| d.contexts.len()).unwrap_or(0)
+    }
+
+    /// Run `flush_due` every `tick` on a background thread, until the last
+    /// other reference to `store` is dropped.
+    pub fn spawn_deferred_flush(store: &Arc<Self>, tick: Duration) -> std::thread::JoinHandle<()> {
+        let weak = Arc::downgrade(store);
+        std::thread::spawn(move || loop {
+            std::thread::sleep(tick);
+            let Some(store) = weak.upgrade() else { break };
+            if let Err(e) = store.flush_due() {
+                tracing::warn!(error = %e, "sqlite store: deferred flush failed");
+            }
+        })
+    }
+}
+
+impl Drop for SqliteStore {
+    fn drop(&mut self) {
+        if let Err(e) = self.flush_deferred() {
+            tracing::warn!(error = %e, "sqlite store: flush of deferred saves on drop failed, writes lost");
+        }
+    }
+}
+
+impl OpenStore for SqliteStore {
+    fn open(path: impl AsRef<Path>) -> StorageResult<Self> {
+        // Ensure parent directory exists
+        if let Some(parent) = path.as_ref().parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let conn = Connection::open(&path)?;
+        let backup = Self::backup_path_for(path.as_ref(), Self::read_schema_version(&conn)?);
+        Self::init_schema(&conn, Some(&backup))?;
+
+        Ok(Self {
+            conn: Mutex::new(conn),
+            path: Some(path.as_ref().to_path_buf()),
+            baselines: Mutex::new(HashMap::new()),
+            deferred: Mutex::new(Deferred::default()),
+        })
+    }
+
+    fn open_in_memory() -> StorageResult<Self> {
+        let conn = Connection::open_in_memory()?;
+        Self::init_schema(&conn, None)?;
+
+        Ok(Self {
+            conn: Mutex::new(conn),
+            path: None,
+            baselines: Mutex::new(HashMap::new()),
+            deferred: Mutex::new(Deferred::default()),
+        })
+    }
+}
+
+impl GraphStore for SqliteStore {
+    // === Context Operations ===
+
+    fn save_context_metadata(&self, context: &Context) -> StorageResult<()> {
+        let mut deferred = self.deferred()?;
+        // A held save takes the new metadata and keeps waiting, unless the
+        // context just became strict
+        if let Some(Held { context: held, .. }) = deferred.contexts.get_mut(&context.id) {
+            held.name = context.name.clone();
+            held.description = context.description.clone();
+            held.metadata = context.metadata.clone();
+            if context.metadata.durability.unwrap_or_default() == Durability::Strict {
+                return self.commit_held(&mut deferred, std::slice::from_ref(&context.id)).map(|_| ());
+            }
+            return Ok(());
+        }
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        Self::write_context_row(&conn, context)
+    }
+
+    fn save_context(&self, context: &Context) -> StorageResult<()> {
+        let mut deferred = self.deferred()?;
+        match context.metadata.durability.unwrap_or_default() {
+            Durability::Strict => {
+                // Emissions about an earlier held save commit with this one
+                let emissions = deferred.contexts.remove(&context.id).map(|held| held.emissions).unwrap_or_default();
+                self.commit_contexts(&[context], &emissions.iter().collect::<Vec<_>>())
+            }
+            Durability::Batched { .. } | Durability::Relaxed => {
+                let now = Instant::now();
+                deferred.hold(context, now);
+                let due = deferred.due(now);
+                self.commit_held(&mut deferred, &due).map(|_| ())
+            }
+        }
+    }
+
+    fn defers_saves(&self) -> bool {
+        true
+    }
+
+    /// Commit the held saves that are due: batched contexts whose window
+    /// has passed, relaxed ones once no save has arrived for them in
+    /// `RELAXED_IDLE`.
+    fn flush_due(&self) -> StorageResult<usize> {
+        let mut deferred = self.deferred()?;
+        let due = deferred.due(Instant::now());
+        self.commit_held(&mut deferred, &due)
+    }
+
+    fn load_context(&self, id: &ContextId) -> StorageResult<Option<Context>> {
+        self.settle(Some(id))?;
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+
+        // Load context metadata
+        let context_row: Option<(String, Option<String>, String)> = conn
+            .query_row(
+                "SELECT name, description, metadata_json FROM contexts WHERE id = ?1",
+                params![id.as_str()],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .optional()?;
+
+        let Some((name, description, metadata_json)) = context_row else {
+            return Ok(None);
+        };
+
+        // Load nodes
         let mut stmt = conn.prepare(
-            "SELECT id, node_type, content_type, dimension, properties_json, metadata_json
+            "SELECT id, node_type, content_type, dimension, properties_json, metadata_json, deleted_at
              FROM nodes WHERE context_id = ?1",
         )?;
         let nodes_iter = stmt.query_map(params![id.as_str()], |row| {
@@ -626,47 +1298,65 @@ impl GraphStore for SqliteStore {
                 row.get::<_, String>(3)?,
                 row.get::<_, String>(4)?,
                 row.get::<_, String>(5)?,
+                row.get::<_, Option<String>>(6)?,
             ))
         })?;
 
         let mut nodes = HashMap::new();
+        let mut trash = Trash::default();
         for row in nodes_iter {
-            let (node_id, node_type, content_type, dimension, properties, metadata) = row?;
+            let (node_id, node_type, content_type, dimension, properties, metadata, deleted_at) = row?;
             let node = Self::row_to_node(node_id, node_type, content_type, dimension, properties, metadata)?;
-            nodes.insert(node.id.clone(), node);
+            match deleted_at {
+                Some(at) => {
+                    trash.nodes.insert(node.id.clone(), Tombstone { item: node, deleted_at: Self::parse_deleted_at(&at)? });
+                }
+                None => {
+                    nodes.insert(node.id.clone(), node);
+                }
+            }
         }
 
         // Load edges
         let mut stmt = conn.prepare(
             "SELECT id, source_id, target_id, source_dimension, target_dimension, relationship,
-                    raw_weight, created_at, properties_json, contributions_json
+                    raw_weight, created_at, properties_json, contributions_json, contribution_log_json, deleted_at
              FROM edges WHERE context_id = ?1",
         )?;
         let edges_iter = stmt.query_map(params![id.as_str()], |row| {
             Ok((
-                row.get::<_, String>(0)?,
-                row.get::<_, String>(1)?,
-                row.get::<_, String>(2)?,
-                row.get::<_, String>(3)?,
-                row.get::<_, String>(4)?,
-                row.get::<_, String>(5)?,
-                row.get::<_, f64>(6)?,
-                row.get::<_, String>(7)?,
-                row.get::<_, String>(8)?,
-                row.get::<_, String>(9)?,
+                (
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, String>(4)?,
+                    row.get::<_, String>(5)?,
+                    row.get::<_, f64>(6)?,
+                    row.get::<_, String>(7)?,
+                    row.get::<_, String>(8)?,
+                    row.get::<_, String>(9)?,
+                    row.get::<_, String>(10)?,
+                ),
+                row.get::<_, Option<String>>(11)?,
             ))
         })?;
 
         let mut edges = Vec::new();
         for row in edges_iter {
-            let (id, source, target, source_dim, target_dim, rel, rw, created, props, contributions) = row?;
-            let edge = Self::row_to_edge(id, source, target, source_dim, target_dim, rel, rw, created, props, contributions)?;
-            edges.push(edge);
+            let ((id, source, target, source_dim, target_dim, rel, rw, created, props, contributions, log), deleted_at) = row?;
+            let edge = Self::row_to_edge(id, source, target, source_dim, target_dim, rel, rw, created, props, contributions, log)?;
+            match deleted_at {
+                Some(at) => trash.edges.push(Tombstone { item: edge, deleted_at: Self::parse_deleted_at(&at)? }),
+                None => edges.push(edge),
+            }
         }
 
         // Record baseline for incremental save_context (ADR-017 §3)
-        let baseline_nodes: HashSet<String> = nodes.keys().map(|k| k.to_string()).collect();
-        let baseline_edges: HashSet<String> = edges.iter().map(|e| e.id.to_string()).collect();
+        let baseline_nodes: HashSet<String> =
+            nodes.keys().chain(trash.nodes.keys()).map(|k| k.to_string()).collect();
+        let baseline_edges: HashSet<String> =
+            edges.iter().chain(trash.edges.iter().map(|t| &t.item)).map(|e| e.id.to_string()).collect();
         self.baselines.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?.insert(
             id.as_str().to_string(),
             (baseline_nodes, baseline_edges),
@@ -679,16 +1369,24 @@ impl GraphStore for SqliteStore {
             nodes,
             edges,
             metadata: serde_json::from_str(&metadata_json)?,
+            trash,
         }))
     }
 
     fn delete_context(&self, id: &ContextId) -> StorageResult<bool> {
+        let held = self.deferred()?.contexts.remove(id).is_some();
         let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
-        let rows = conn.execute("DELETE FROM contexts WHERE id = ?1", params![id.as_str()])?;
-        Ok(rows > 0)
+        let tx = conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM embeddings WHERE context_id = ?1", params![id.as_str()])?;
+        tx.execute("DELETE FROM ingest_results WHERE context_id = ?1", params![id.as_str()])?;
+        tx.execute("DELETE FROM emissions WHERE context_id = ?1", params![id.as_str()])?;
+        let rows = tx.execute("DELETE FROM contexts WHERE id = ?1", params![id.as_str()])?;
+        tx.commit()?;
+        Ok(rows > 0 || held)
     }
 
     fn list_contexts(&self) -> StorageResult<Vec<ContextId>> {
+        self.settle(None)?;
         let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
         let mut stmt = conn.prepare("SELECT id FROM contexts")?;
         let ids = stmt
@@ -698,29 +1396,233 @@ impl GraphStore for SqliteStore {
         Ok(ids)
     }
 
+    fn load_manifest(&self) -> StorageResult<Vec<ManifestEntry>> {
+        self.settle(None)?;
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let mut stmt = conn.prepare("SELECT id, name, metadata_json, node_count, edge_count FROM contexts")?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, Option<i64>>(3)?,
+                    row.get::<_, Option<i64>>(4)?,
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut entries = Vec::with_capacity(rows.len());
+        for (id, name, metadata_json, node_count, edge_count) in rows {
+            let metadata: ContextMetadata = serde_json::from_str(&metadata_json)?;
+            entries.push(ManifestEntry {
+                id: ContextId::from(id),
+                name,
+                tenant: metadata.tenant,
+                sources: metadata.sources,
+                node_count: node_count.unwrap_or(0) as usize,
+                edge_count: edge_count.unwrap_or(0) as usize,
+                updated_at: metadata.updated_at.or(metadata.created_at),
+            });
+        }
+        Ok(entries)
+    }
+
+    fn list_contexts_for_tenant(&self, tenant: Option<&str>) -> StorageResult<Vec<ContextId>> {
+        self.settle(None)?;
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let mut stmt = conn.prepare("SELECT id FROM contexts WHERE tenant_id IS ?1")?;
+        let ids = stmt
+            .query_map(params![tenant], |row| row.get::<_, String>(0))?
+            .map(|r| r.map(ContextId::from))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ids)
+    }
+
+    fn load_nodes(&self, context_id: &ContextId, filter: &NodeFilter) -> StorageResult<Vec<Node>> {
+        self.settle(Some(context_id))?;
+        let (condition, values) = Self::node_condition(context_id, filter)?;
+        let sql = format!(
+            "SELECT id, node_type, content_type, dimension, properties_json, metadata_json
+             FROM nodes WHERE {}",
+            condition
+        );
+
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map(params_from_iter(values), |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?, row.get(5)?))
+        })?;
+        let mut nodes = Vec::new();
+        for row in rows {
+            let (id, node_type, content_type, dimension, properties, metadata) = row?;
+            nodes.push(Self::row_to_node(id, node_type, content_type, dimension, properties, metadata)?);
+        }
+        Ok(nodes)
+    }
+
+    fn load_edges(&self, context_id: &ContextId, filter: &EdgeFilter) -> StorageResult<Vec<Edge>> {
+        self.settle(Some(context_id))?;
+        let (condition, values) = Self::edge_condition(context_id, filter);
+        let sql = format!(
+            "SELECT id, source_id, target_id, source_dimension, target_dimension, relationship,
+                    raw_weight, created_at, properties_json, contributions_json, contribution_log_json
+             FROM edges WHERE {}",
+            condition
+        );
+
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map(params_from_iter(values), |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, String>(4)?,
+                row.get::<_, String>(5)?,
+                row.get::<_, f64>(6)?,
+                row.get::<_, String>(7)?,
+                row.get::<_, String>(8)?,
+                row.get::<_, String>(9)?,
+                row.get::<_, String>(10)?,
+            ))
+        })?;
+        let mut edges = Vec::new();
+        for row in rows {
+            let (id, source, target, source_dim, target_dim, rel, rw, created, props, contributions, log) = row?;
+            edges.push(Self::row_to_edge(id, source, target, source_dim, target_dim, rel, rw, created, props, contributions, log)?);
+        }
+        Ok(edges)
+    }
+
+    fn count_nodes(&self, context_id: &ContextId, filter: &NodeFilter) -> StorageResult<usize> {
+        self.settle(Some(context_id))?;
+        let (condition, values) = Self::node_condition(context_id, filter)?;
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let count: i64 = conn.query_row(
+            &format!("SELECT COUNT(*) FROM nodes WHERE {}", condition),
+            params_from_iter(values),
+            |row| row.get(0),
+        )?;
+        Ok(count as usize)
+    }
+
+    fn count_edges(&self, context_id: &ContextId, filter: &EdgeFilter) -> StorageResult<usize> {
+        self.settle(Some(context_id))?;
+        let (condition, values) = Self::edge_condition(context_id, filter);
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let count: i64 = conn.query_row(
+            &format!("SELECT COUNT(*) FROM edges WHERE {}", condition),
+            params_from_iter(values),
+            |row| row.get(0),
+        )?;
+        Ok(count as usize)
+    }
+
     fn data_version(&self) -> StorageResult<u64> {
         let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
         let version: i64 = conn.query_row("PRAGMA data_version", [], |row| row.get(0))?;
         Ok(version as u64)
     }
 
+    fn schema_version(&self) -> StorageResult<u32> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        Self::read_schema_version(&conn)
+    }
+
+    fn backup(&self, path: &Path) -> StorageResult<()> {
+        self.settle(None)?;
+        let mut dst = Connection::open(path)?;
+        // One step copies every page inside a single read transaction: a
+        // consistent snapshot, and under WAL writers carry on meanwhile.
+        match &self.path {
+            Some(source) => {
+                let src = Connection::open(source)?;
+                Backup::new(&src, &mut dst)?.step(-1)?;
+            }
+            None => {
+                let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+                Backup::new(&conn, &mut dst)?.step(-1)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn restore(&self, path: &Path) -> StorageResult<()> {
+        if !path.exists() {
+            return Err(StorageError::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("backup not found: {}", path.display()),
+            )));
+        }
+        let src = Connection::open(path)?;
+        let mut conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        Backup::new(&src, &mut conn)?.step(-1)?;
+        // Snapshots from older builds upgrade like any opened database
+        Self::init_schema(&conn, None)?;
+        self.baselines.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?.clear();
+        // Held saves predate the restored snapshot
+        drop(conn);
+        self.deferred()?.contexts.clear();
+        Ok(())
+    }
+
+    fn compact(&self, context_id: Option<&ContextId>) -> StorageResult<CompactionReport> {
+        self.settle(None)?;
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let scope = context_id.map(|id| id.as_str().to_string());
+
+        let bytes_before = Self::database_bytes(&conn)?;
+        let tx = conn.unchecked_transaction()?;
+        let orphaned = |table: &str| -> StorageResult<usize> {
+            Ok(tx.execute(
+                &format!("DELETE FROM {table} WHERE context_id NOT IN (SELECT id FROM contexts)"),
+                [],
+            )?)
+        };
+        let mut report = CompactionReport {
+            bytes_before,
+            orphan_nodes: orphaned("nodes")?,
+            orphan_edges: orphaned("edges")?,
+            orphan_events: orphaned("events")?,
+            orphan_specs: orphaned("specs")?,
+            ..Default::default()
+        };
+        report.dangling_edges = tx.execute(
+            "DELETE FROM edges
+             WHERE (?1 IS NULL OR context_id = ?1)
+               AND (NOT EXISTS (SELECT 1 FROM nodes n WHERE n.context_id = edges.context_id AND n.id = edges.source_id)
+                 OR NOT EXISTS (SELECT 1 FROM nodes n WHERE n.context_id = edges.context_id AND n.id = edges.target_id))",
+            params![scope],
+        )?;
+        tx.commit()?;
+
+        // VACUUM can't run inside a transaction
+        conn.execute_batch("REINDEX; VACUUM;")?;
+        report.bytes_after = Self::database_bytes(&conn)?;
+        Ok(report)
+    }
+
     fn persist_event(
         &self,
         context_id: &str,
         event_type: &str,
         node_ids: &[String],
         edge_ids: &[String],
+        keys: &[String],
         adapter_id: &str,
     ) -> StorageResult<u64> {
+        self.settle_side_write(context_id)?;
         let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
         let node_ids_json = serde_json::to_string(node_ids)?;
         let edge_ids_json = serde_json::to_string(edge_ids)?;
+        let keys_json = serde_json::to_string(keys)?;
         let created_at = chrono::Utc::now().to_rfc3339();
 
         conn.execute(
-            "INSERT INTO events (context_id, event_type, node_ids_json, edge_ids_json, adapter_id, created_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-            params![context_id, event_type, node_ids_json, edge_ids_json, adapter_id, created_at],
+            "INSERT INTO events (context_id, event_type, node_ids_json, edge_ids_json, keys_json, adapter_id, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![context_id, event_type, node_ids_json, edge_ids_json, keys_json, adapter_id, created_at],
         )?;
 
         let sequence = conn.last_insert_rowid() as u64;
@@ -737,7 +1639,7 @@ impl GraphStore for SqliteStore {
 
         // Build query dynamically based on filter
         let mut sql = String::from(
-            "SELECT sequence, context_id, event_type, node_ids_json, edge_ids_json, adapter_id, created_at
+            "SELECT sequence, context_id, event_type, node_ids_json, edge_ids_json, adapter_id, created_at, keys_json
              FROM events WHERE context_id = ?1 AND sequence > ?2"
         );
         let mut param_idx = 3;
@@ -760,7 +1662,6 @@ impl GraphStore for SqliteStore {
                     sql.push_str(&format!(" AND event_type IN ({})", placeholders.join(",")));
                     for et in event_types {
                         param_values.push(Box::new(et.clone()));
-                        param_idx += 1;
                     }
                 }
             }
@@ -774,72 +1675,454 @@ impl GraphStore for SqliteStore {
             }
         }
 
-        let params_refs: Vec<&dyn rusqlite::types::ToSql> = param_values.iter()
-            .map(|p| p.as_ref())
-            .collect();
+        let params_refs: Vec<&dyn rusqlite::types::ToSql> = param_values.iter()
+            .map(|p| p.as_ref())
+            .collect();
+
+        let mut stmt = conn.prepare(&sql)?;
+        let events = stmt.query_map(params_refs.as_slice(), |row| {
+            let node_ids_json: String = row.get::<_, String>(3)?;
+            let edge_ids_json: String = row.get::<_, String>(4)?;
+            Ok(PersistedEvent {
+                sequence: row.get::<_, i64>(0)? as u64,
+                context_id: row.get(1)?,
+                event_type: row.get(2)?,
+                node_ids: serde_json::from_str(&node_ids_json).unwrap_or_default(),
+                edge_ids: serde_json::from_str(&edge_ids_json).unwrap_or_default(),
+                keys: row
+                    .get::<_, Option<String>>(7)?
+                    .and_then(|json| serde_json::from_str(&json).ok())
+                    .unwrap_or_default(),
+                adapter_id: row.get(5)?,
+                created_at: row.get(6)?,
+            })
+        })?.collect::<Result<Vec<_>, _>>()?;
+
+        Ok(events)
+    }
+
+    fn latest_sequence(&self, context_id: &str) -> StorageResult<u64> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let seq: Option<i64> = conn.query_row(
+            "SELECT MAX(sequence) FROM events WHERE context_id = ?1",
+            params![context_id],
+            |row| row.get(0),
+        ).optional()?.flatten();
+        Ok(seq.unwrap_or(0) as u64)
+    }
+
+    fn persist_spec(&self, spec: &PersistedSpec) -> StorageResult<()> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        conn.execute(
+            "INSERT OR REPLACE INTO specs (context_id, adapter_id, spec_yaml, loaded_at)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![spec.context_id, spec.adapter_id, spec.spec_yaml, spec.loaded_at],
+        )?;
+        Ok(())
+    }
+
+    fn query_specs_for_context(&self, context_id: &str) -> StorageResult<Vec<PersistedSpec>> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let mut stmt = conn.prepare(
+            "SELECT context_id, adapter_id, spec_yaml, loaded_at
+             FROM specs WHERE context_id = ?1 ORDER BY loaded_at ASC"
+        )?;
+        let specs = stmt.query_map(params![context_id], |row| {
+            Ok(PersistedSpec {
+                context_id: row.get(0)?,
+                adapter_id: row.get(1)?,
+                spec_yaml: row.get(2)?,
+                loaded_at: row.get(3)?,
+            })
+        })?.collect::<Result<Vec<_>, _>>()?;
+        Ok(specs)
+    }
+
+    fn delete_spec(&self, context_id: &str, adapter_id: &str) -> StorageResult<bool> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let rows = conn.execute(
+            "DELETE FROM specs WHERE context_id = ?1 AND adapter_id = ?2",
+            params![context_id, adapter_id],
+        )?;
+        Ok(rows > 0)
+    }
+
+    fn persist_llm_cost(&self, cost: &PersistedLlmCost) -> StorageResult<()> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        conn.execute(
+            "INSERT INTO llm_costs (context_id, adapter_id, item, ensemble, input_tokens, output_tokens, cost_usd, recorded_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                cost.context_id,
+                cost.adapter_id,
+                cost.item,
+                cost.ensemble,
+                cost.input_tokens as i64,
+                cost.output_tokens as i64,
+                cost.cost_usd,
+                cost.recorded_at,
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn query_llm_costs_since(&self, context_id: &str, since: &str) -> StorageResult<Vec<PersistedLlmCost>> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let mut stmt = conn.prepare(
+            "SELECT context_id, adapter_id, item, ensemble, input_tokens, output_tokens, cost_usd, recorded_at
+             FROM llm_costs WHERE context_id = ?1 AND recorded_at >= ?2 ORDER BY recorded_at ASC, id ASC"
+        )?;
+        let costs = stmt.query_map(params![context_id, since], |row| {
+            Ok(PersistedLlmCost {
+                context_id: row.get(0)?,
+                adapter_id: row.get(1)?,
+                item: row.get(2)?,
+                ensemble: row.get(3)?,
+                input_tokens: row.get::<_, i64>(4)? as u64,
+                output_tokens: row.get::<_, i64>(5)? as u64,
+                cost_usd: row.get(6)?,
+                recorded_at: row.get(7)?,
+            })
+        })?.collect::<Result<Vec<_>, _>>()?;
+        Ok(costs)
+    }
+
+    /// Emissions about a held save wait for it and commit in its
+    /// transaction, so the record and the graph it describes land together.
+    fn persist_emission(&self, emission: &PersistedEmission) -> StorageResult<()> {
+        let mut deferred = self.deferred()?;
+        if let Some(held) = deferred.contexts.get_mut(&ContextId::from(emission.context_id.as_str())) {
+            held.emissions.push(emission.clone());
+            return Ok(());
+        }
+        drop(deferred);
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        Self::insert_emission(&conn, emission)
+    }
+
+    fn query_emissions(&self, context_id: &str, filter: &EmissionFilter) -> StorageResult<Vec<PersistedEmission>> {
+        self.settle_side_write(context_id)?;
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let mut stmt = conn.prepare(
+            "SELECT context_id, adapter_id, input_summary, node_ids, edge_ids, recorded_at
+             FROM emissions WHERE context_id = ?1
+               AND (?2 IS NULL OR adapter_id = ?2)
+               AND (?3 IS NULL OR recorded_at >= ?3)
+               AND (?4 IS NULL OR recorded_at < ?4)
+             ORDER BY recorded_at ASC, id ASC"
+        )?;
+        let since = filter.since.map(|t| t.to_rfc3339());
+        let until = filter.until.map(|t| t.to_rfc3339());
+        let rows = stmt.query_map(params![context_id, filter.adapter_id, since, until], |row| {
+            Ok((
+                PersistedEmission {
+                    context_id: row.get(0)?,
+                    adapter_id: row.get(1)?,
+                    input_summary: row.get(2)?,
+                    node_ids: Vec::new(),
+                    edge_ids: Vec::new(),
+                    recorded_at: row.get(5)?,
+                },
+                row.get::<_, String>(3)?,
+                row.get::<_, String>(4)?,
+            ))
+        })?.collect::<Result<Vec<_>, _>>()?;
+        rows.into_iter()
+            .map(|(mut emission, node_ids, edge_ids)| {
+                emission.node_ids = serde_json::from_str(&node_ids)?;
+                emission.edge_ids = serde_json::from_str(&edge_ids)?;
+                Ok(emission)
+            })
+            .collect()
+    }
+
+    fn append_outbound_event(&self, event: &PersistedOutboundEvent) -> StorageResult<u64> {
+        self.settle_side_write(&event.context_id)?;
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        conn.execute(
+            "INSERT INTO outbound_events (context_id, kind, detail, recorded_at) VALUES (?1, ?2, ?3, ?4)",
+            params![event.context_id, event.kind, event.detail, event.recorded_at],
+        )?;
+        Ok(conn.last_insert_rowid() as u64)
+    }
+
+    fn query_outbound_since(
+        &self,
+        context_id: &str,
+        after: u64,
+        limit: Option<usize>,
+    ) -> StorageResult<Vec<PersistedOutboundEvent>> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let mut stmt = conn.prepare(
+            "SELECT seq, context_id, kind, detail, recorded_at FROM outbound_events
+             WHERE context_id = ?1 AND seq > ?2 ORDER BY seq ASC LIMIT ?3"
+        )?;
+        let limit = limit.map_or(-1, |l| l as i64);
+        let events = stmt.query_map(params![context_id, after as i64, limit], |row| {
+            Ok(PersistedOutboundEvent {
+                offset: row.get::<_, i64>(0)? as u64,
+                context_id: row.get(1)?,
+                kind: row.get(2)?,
+                detail: row.get(3)?,
+                recorded_at: row.get(4)?,
+            })
+        })?.collect::<Result<Vec<_>, _>>()?;
+        Ok(events)
+    }
+
+    fn outbound_ack(&self, context_id: &str, subscriber: &str) -> StorageResult<Option<u64>> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let acked = conn
+            .query_row(
+                "SELECT acked_seq FROM outbound_acks WHERE context_id = ?1 AND subscriber = ?2",
+                params![context_id, subscriber],
+                |row| row.get::<_, i64>(0),
+            )
+            .optional()?;
+        Ok(acked.map(|seq| seq as u64))
+    }
+
+    fn set_outbound_ack(&self, context_id: &str, subscriber: &str, offset: u64) -> StorageResult<()> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        conn.execute(
+            "INSERT INTO outbound_acks (context_id, subscriber, acked_seq) VALUES (?1, ?2, ?3)
+             ON CONFLICT(context_id, subscriber) DO UPDATE SET acked_seq = excluded.acked_seq",
+            params![context_id, subscriber, offset as i64],
+        )?;
+        Ok(())
+    }
+
+    fn prune_outbound(&self, context_id: &str, keep: usize) -> StorageResult<usize> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let rows = conn.execute(
+            "DELETE FROM outbound_events WHERE context_id = ?1 AND seq <= (
+                SELECT seq FROM outbound_events WHERE context_id = ?1 ORDER BY seq DESC LIMIT 1 OFFSET ?2
+             )",
+            params![context_id, keep.min(i64::MAX as usize) as i64],
+        )?;
+        Ok(rows)
+    }
+
+    fn persist_template(&self, template: &PersistedTemplate) -> StorageResult<()> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        conn.execute(
+            "INSERT OR REPLACE INTO context_templates (name, template_json, saved_at) VALUES (?1, ?2, ?3)",
+            params![template.name, template.template_json, template.saved_at],
+        )?;
+        Ok(())
+    }
+
+    fn load_template(&self, name: &str) -> StorageResult<Option<PersistedTemplate>> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let template = conn
+            .query_row(
+                "SELECT name, template_json, saved_at FROM context_templates WHERE name = ?1",
+                params![name],
+                |row| {
+                    Ok(PersistedTemplate {
+                        name: row.get(0)?,
+                        template_json: row.get(1)?,
+                        saved_at: row.get(2)?,
+                    })
+                },
+            )
+            .optional()?;
+        Ok(template)
+    }
+
+    fn list_templates(&self) -> StorageResult<Vec<String>> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let mut stmt = conn.prepare("SELECT name FROM context_templates ORDER BY name")?;
+        let names = stmt.query_map([], |row| row.get(0))?.collect::<Result<Vec<String>, _>>()?;
+        Ok(names)
+    }
+
+    fn delete_template(&self, name: &str) -> StorageResult<bool> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let rows = conn.execute("DELETE FROM context_templates WHERE name = ?1", params![name])?;
+        Ok(rows > 0)
+    }
+
+    fn rename_nodes(&self, context_id: &str, renamed: &[(String, String)]) -> StorageResult<()> {
+        self.settle_side_write(context_id)?;
+        let renames: HashMap<&str, &str> = renamed.iter().map(|(from, to)| (from.as_str(), to.as_str())).collect();
+        let mut conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let tx = conn.transaction()?;
+        for (from, to) in renamed {
+            tx.execute(
+                "UPDATE OR REPLACE embeddings SET node_id = ?3 WHERE context_id = ?1 AND node_id = ?2",
+                params![context_id, from, to],
+            )?;
+        }
+        let rows: Vec<(i64, String)> = tx
+            .prepare("SELECT id, node_ids FROM emissions WHERE context_id = ?1")?
+            .query_map(params![context_id], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<_, _>>()?;
+        for (id, node_ids) in rows {
+            let before: Vec<String> = serde_json::from_str(&node_ids)?;
+            let after: Vec<String> = before.iter().map(|n| renames.get(n.as_str()).map_or_else(|| n.clone(), |to| to.to_string())).collect();
+            if after != before {
+                tx.execute("UPDATE emissions SET node_ids = ?2 WHERE id = ?1", params![id, serde_json::to_string(&after)?])?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn persist_embedding(&self, embedding: &PersistedEmbedding) -> StorageResult<()> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let vector: Vec<u8> = embedding.vector.iter().flat_map(|x| x.to_le_bytes()).collect();
+        conn.execute(
+            "INSERT OR REPLACE INTO embeddings (context_id, model, node_id, text_hash, vector) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![embedding.context_id, embedding.model, embedding.node_id, embedding.text_hash, vector],
+        )?;
+        Ok(())
+    }
 
-        let mut stmt = conn.prepare(&sql)?;
-        let events = stmt.query_map(params_refs.as_slice(), |row| {
-            let node_ids_json: String = row.get::<_, String>(3)?;
-            let edge_ids_json: String = row.get::<_, String>(4)?;
-            Ok(PersistedEvent {
+    fn load_embeddings(&self, context_id: &str, model: &str) -> StorageResult<Vec<PersistedEmbedding>> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let mut stmt = conn.prepare(
+            "SELECT node_id, text_hash, vector FROM embeddings WHERE context_id = ?1 AND model = ?2 ORDER BY node_id",
+        )?;
+        let embeddings = stmt
+            .query_map(params![context_id, model], |row| {
+                let vector: Vec<u8> = row.get(2)?;
+                Ok(PersistedEmbedding {
+                    context_id: context_id.to_string(),
+                    node_id: row.get(0)?,
+                    model: model.to_string(),
+                    vector: vector.chunks_exact(4).map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]])).collect(),
+                    text_hash: row.get(1)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(embeddings)
+    }
+
+    fn delete_embeddings(&self, context_id: &str, model: &str) -> StorageResult<usize> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let rows = conn.execute(
+            "DELETE FROM embeddings WHERE context_id = ?1 AND model = ?2",
+            params![context_id, model],
+        )?;
+        Ok(rows)
+    }
+
+    fn append_journal(&self, entry: &PersistedJournalEntry) -> StorageResult<u64> {
+        self.settle_side_write(&entry.context_id)?;
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        conn.execute(
+            "INSERT INTO journal (context_id, kind, payload, recorded_at) VALUES (?1, ?2, ?3, ?4)",
+            params![entry.context_id, entry.kind, entry.payload, entry.recorded_at],
+        )?;
+        Ok(conn.last_insert_rowid() as u64)
+    }
+
+    fn query_journal(&self, context_id: &str, after: u64) -> StorageResult<Vec<PersistedJournalEntry>> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let mut stmt = conn.prepare(
+            "SELECT seq, context_id, kind, payload, recorded_at FROM journal
+             WHERE context_id = ?1 AND seq > ?2 ORDER BY seq ASC"
+        )?;
+        let entries = stmt.query_map(params![context_id, after as i64], |row| {
+            Ok(PersistedJournalEntry {
                 sequence: row.get::<_, i64>(0)? as u64,
                 context_id: row.get(1)?,
-                event_type: row.get(2)?,
-                node_ids: serde_json::from_str(&node_ids_json).unwrap_or_default(),
-                edge_ids: serde_json::from_str(&edge_ids_json).unwrap_or_default(),
-                adapter_id: row.get(5)?,
-                created_at: row.get(6)?,
+                kind: row.get(2)?,
+                payload: row.get(3)?,
+                recorded_at: row.get(4)?,
             })
         })?.collect::<Result<Vec<_>, _>>()?;
+        Ok(entries)
+    }
 
-        Ok(events)
+    fn truncate_journal(&self, context_id: &str, before: u64) -> StorageResult<usize> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        let rows = conn.execute(
+            "DELETE FROM journal WHERE context_id = ?1 AND seq < ?2",
+            params![context_id, before.min(i64::MAX as u64) as i64],
+        )?;
+        Ok(rows)
     }
 
-    fn latest_sequence(&self, context_id: &str) -> StorageResult<u64> {
+    fn claim_ingest_key(&self, claim: &PersistedIngestResult, stale_before: &str) -> StorageResult<bool> {
+        self.settle_side_write(&claim.context_id)?;
         let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
-        let seq: Option<i64> = conn.query_row(
-            "SELECT MAX(sequence) FROM events WHERE context_id = ?1",
-            params![context_id],
-            |row| row.get(0),
-        ).optional()?.flatten();
-        Ok(seq.unwrap_or(0) as u64)
+        let inserted = conn.execute(
+            "INSERT OR IGNORE INTO ingest_results (context_id, key, input_kind, payload_hash, outbound_json, completed, recorded_at)
+             VALUES (?1, ?2, ?3, ?4, '', 0, ?5)",
+            params![claim.context_id, claim.key, claim.input_kind, claim.payload_hash, claim.recorded_at],
+        )?;
+        if inserted > 0 {
+            return Ok(true);
+        }
+        // Take over a claim whose ingest never finished
+        let taken = conn.execute(
+            "UPDATE ingest_results SET input_kind = ?3, payload_hash = ?4, recorded_at = ?5
+             WHERE context_id = ?1 AND key = ?2 AND completed = 0 AND recorded_at < ?6",
+            params![claim.context_id, claim.key, claim.input_kind, claim.payload_hash, claim.recorded_at, stale_before],
+        )?;
+        Ok(taken > 0)
     }
 
-    fn persist_spec(&self, spec: &PersistedSpec) -> StorageResult<()> {
+    fn persist_ingest_result(&self, result: &PersistedIngestResult) -> StorageResult<()> {
+        self.settle_side_write(&result.context_id)?;
         let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
         conn.execute(
-            "INSERT OR REPLACE INTO specs (context_id, adapter_id, spec_yaml, loaded_at)
-             VALUES (?1, ?2, ?3, ?4)",
-            params![spec.context_id, spec.adapter_id, spec.spec_yaml, spec.loaded_at],
+            "INSERT INTO ingest_results (context_id, key, input_kind, payload_hash, outbound_json, completed, recorded_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, 1, ?6)
+             ON CONFLICT (context_id, key) DO UPDATE SET
+                input_kind = excluded.input_kind, payload_hash = excluded.payload_hash,
+                outbound_json = excluded.outbound_json, completed = 1, recorded_at = excluded.recorded_at
+             WHERE ingest_results.completed = 0",
+            params![result.context_id, result.key, result.input_kind, result.payload_hash, result.outbound_json, result.recorded_at],
         )?;
         Ok(())
     }
 
-    fn query_specs_for_context(&self, context_id: &str) -> StorageResult<Vec<PersistedSpec>> {
+    fn release_ingest_key(&self, context_id: &str, key: &str) -> StorageResult<()> {
         let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
-        let mut stmt = conn.prepare(
-            "SELECT context_id, adapter_id, spec_yaml, loaded_at
-             FROM specs WHERE context_id = ?1 ORDER BY loaded_at ASC"
+        conn.execute(
+            "DELETE FROM ingest_results WHERE context_id = ?1 AND key = ?2 AND completed = 0",
+            params![context_id, key],
         )?;
-        let specs = stmt.query_map(params![context_id], |row| {
-            Ok(PersistedSpec {
-                context_id: row.get(0)?,
-                adapter_id: row.get(1)?,
-                spec_yaml: row.get(2)?,
-                loaded_at: row.get(3)?,
-            })
-        })?.collect::<Result<Vec<_>, _>>()?;
-        Ok(specs)
+        Ok(())
     }
 
-    fn delete_spec(&self, context_id: &str, adapter_id: &str) -> StorageResult<bool> {
+    fn load_ingest_result(&self, context_id: &str, key: &str) -> StorageResult<Option<PersistedIngestResult>> {
         let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
-        let rows = conn.execute(
-            "DELETE FROM specs WHERE context_id = ?1 AND adapter_id = ?2",
-            params![context_id, adapter_id],
-        )?;
-        Ok(rows > 0)
+        let result = conn
+            .query_row(
+                "SELECT context_id, key, input_kind, payload_hash, outbound_json, completed, recorded_at FROM ingest_results
+                 WHERE context_id = ?1 AND key = ?2",
+                params![context_id, key],
+                |row| {
+                    Ok(PersistedIngestResult {
+                        context_id: row.get(0)?,
+                        key: row.get(1)?,
+                        input_kind: row.get(2)?,
+                        payload_hash: row.get(3)?,
+                        outbound_json: row.get(4)?,
+                        completed: row.get(5)?,
+                        recorded_at: row.get(6)?,
+                    })
+                },
+            )
+            .optional()?;
+        Ok(result)
+    }
+
+    fn prune_ingest_results(&self, before: &str) -> StorageResult<usize> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        Ok(conn.execute("DELETE FROM ingest_results WHERE recorded_at < ?1", params![before])?)
+    }
+
+    fn prune_emissions(&self, context_id: &str, before: &str) -> StorageResult<usize> {
+        let conn = self.conn.lock().map_err(|e| StorageError::Internal(format!("mutex poisoned: {e}")))?;
+        Ok(conn.execute(
+            "DELETE FROM emissions WHERE context_id = ?1 AND recorded_at < ?2",
+            params![context_id, before],
+        )?)
     }
 }
 
@@ -862,6 +2145,63 @@ mod tests {
         node
     }
 
+    #[test]
+    fn test_filtered_loads_push_down_to_sql() {
+        let store = create_test_store();
+        let mut ctx = create_test_context();
+
+        let mut heading = Node::new("heading", ContentType::Document);
+        heading.dimension = dimension::STRUCTURE.to_string();
+        let heading = ctx.add_node(heading);
+        let mut concept = Node::new("concept", ContentType::Concept);
+        concept.dimension = dimension::SEMANTIC.to_string();
+        let concept = ctx.add_node(concept);
+        let function = ctx.add_node(create_test_node("fn:main", "function"));
+
+        ctx.add_edge(Edge::new_cross_dimensional(
+            heading.clone(),
+            dimension::STRUCTURE,
+            concept.clone(),
+            dimension::SEMANTIC,
+            "discusses",
+        ));
+        let mut calls = Edge::new(function.clone(), heading.clone(), "calls");
+        calls.combined_weight = 0.2;
+        ctx.add_edge(calls);
+        store.save_context(&ctx).unwrap();
+
+        let semantic = store
+            .load_nodes(&ctx.id, &NodeFilter::new().with_dimension(dimension::SEMANTIC))
+            .unwrap();
+        assert_eq!(semantic.len(), 1);
+        assert_eq!(semantic[0].id, concept);
+        let code = store
+            .load_nodes(&ctx.id, &NodeFilter::new().with_content_type(ContentType::Code))
+            .unwrap();
+        assert_eq!(code[0].id, function);
+        let none = NodeFilter::new().with_node_type("heading").with_dimension(dimension::SEMANTIC);
+        assert!(store.load_nodes(&ctx.id, &none).unwrap().is_empty());
+
+        let discusses = store
+            .load_edges(&ctx.id, &EdgeFilter::new().with_relationship("discusses"))
+            .unwrap();
+        assert_eq!(discusses.len(), 1);
+        assert_eq!(discusses[0].target_dimension, dimension::SEMANTIC);
+        let into_heading = EdgeFilter::new().with_target(heading.clone()).with_min_weight(0.5);
+        assert!(store.load_edges(&ctx.id, &into_heading).unwrap().is_empty());
+        assert_eq!(store.load_edges(&ctx.id, &EdgeFilter::new()).unwrap().len(), 2);
+
+        // SQL results agree with the in-memory predicate
+        for filter in [NodeFilter::new().with_node_type("concept"), NodeFilter::new()] {
+            let expected = ctx.nodes().filter(|n| filter.matches(n)).count();
+            assert_eq!(store.load_nodes(&ctx.id, &filter).unwrap().len(), expected);
+            assert_eq!(store.count_nodes(&ctx.id, &filter).unwrap(), expected);
+        }
+        assert_eq!(store.count_edges(&ctx.id, &EdgeFilter::new().with_min_weight(0.5)).unwrap(), 1);
+        let pushed = crate::query::FindQuery::new().with_dimension(dimension::STRUCTURE).to_node_filter().unwrap();
+        assert_eq!(store.count_nodes(&ctx.id, &pushed).unwrap(), 1);
+    }
+
     #[test]
     fn test_context_with_dimensional_data() {
         let store = create_test_store();
@@ -923,6 +2263,38 @@ mod tests {
         assert_eq!(loaded.description, Some("A test context".to_string()));
     }
 
+    #[test]
+    fn test_structured_property_values_survive_persistence() {
+        let store = create_test_store();
+        let mut ctx = Context::new("structured");
+        let taken_at = chrono::DateTime::parse_from_rfc3339("2024-05-01T12:30:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let exif = PropertyValue::Object(HashMap::from([
+            ("camera".to_string(), PropertyValue::String("X100V".to_string())),
+            ("taken_at".to_string(), PropertyValue::DateTime(taken_at)),
+        ]));
+        let mut node = Node::new("image", ContentType::Document);
+        node.id = NodeId::from_string("image:1");
+        node.properties.insert("exif".to_string(), exif.clone());
+        node.properties.insert("thumbnail".to_string(), PropertyValue::Bytes(vec![0, 127, 255]));
+        node.properties.insert("captured".to_string(), PropertyValue::DateTime(taken_at));
+        ctx.add_node(node);
+        store.save_context(&ctx).unwrap();
+
+        let loaded = store.load_context(&ctx.id).unwrap().unwrap();
+        let image = loaded.nodes.get(&NodeId::from_string("image:1")).unwrap();
+        assert_eq!(image.properties.get("exif"), Some(&exif));
+        assert_eq!(
+            image.properties.get("thumbnail"),
+            Some(&PropertyValue::Bytes(vec![0, 127, 255]))
+        );
+        assert_eq!(
+            image.properties.get("captured"),
+            Some(&PropertyValue::DateTime(taken_at))
+        );
+    }
+
     #[test]
     fn test_list_contexts() {
         let store = create_test_store();
@@ -952,6 +2324,59 @@ mod tests {
         assert!(store.load_context(&ctx_id).unwrap().is_none());
     }
 
+    #[test]
+    fn delete_context_drops_its_embeddings() {
+        let store = create_test_store();
+        let ctx = create_test_context();
+        store.save_context(&ctx).unwrap();
+        for context_id in [ctx.id.as_str(), "other"] {
+            store
+                .persist_embedding(&PersistedEmbedding {
+                    context_id: context_id.into(),
+                    node_id: "concept:a".into(),
+                    model: "m1".into(),
+                    vector: vec![1.0],
+                    text_hash: "hash".into(),
+                })
+                .unwrap();
+        }
+
+        store.delete_context(&ctx.id).unwrap();
+        assert!(store.load_embeddings(ctx.id.as_str(), "m1").unwrap().is_empty());
+        assert_eq!(store.load_embeddings("other", "m1").unwrap().len(), 1);
+    }
+
+    // === Scenario: An idempotency key is claimed once, completed once and dropped with its context ===
+    #[test]
+    fn ingest_keys_claim_complete_and_delete_with_the_context() {
+        let store = create_test_store();
+        let ctx = create_test_context();
+        store.save_context(&ctx).unwrap();
+        let result = |completed: bool, recorded_at: &str| PersistedIngestResult {
+            context_id: ctx.id.to_string(),
+            key: "k".into(),
+            input_kind: "content".into(),
+            payload_hash: Some("h".into()),
+            outbound_json: if completed { "[]".into() } else { String::new() },
+            completed,
+            recorded_at: recorded_at.into(),
+        };
+
+        assert!(store.claim_ingest_key(&result(false, "2026-01-01T00:00:00+00:00"), "2025-12-31T00:00:00+00:00").unwrap());
+        assert!(!store.claim_ingest_key(&result(false, "2026-01-01T00:01:00+00:00"), "2025-12-31T00:00:00+00:00").unwrap(), "a live claim holds");
+        assert!(store.claim_ingest_key(&result(false, "2026-01-02T00:00:00+00:00"), "2026-01-01T12:00:00+00:00").unwrap(), "a stale claim is taken over");
+
+        store.persist_ingest_result(&result(true, "2026-01-02T00:05:00+00:00")).unwrap();
+        store.release_ingest_key(ctx.id.as_str(), "k").unwrap();
+        let recorded = store.load_ingest_result(ctx.id.as_str(), "k").unwrap().expect("completed result");
+        assert!(recorded.completed && recorded.outbound_json == "[]", "release leaves a completed result");
+        assert!(!store.claim_ingest_key(&result(false, "2026-03-01T00:00:00+00:00"), "2026-02-01T00:00:00+00:00").unwrap());
+
+        assert_eq!(store.prune_ingest_results("2026-01-01T00:00:00+00:00").unwrap(), 0);
+        store.delete_context(&ctx.id).unwrap();
+        assert!(store.load_ingest_result(ctx.id.as_str(), "k").unwrap().is_none());
+    }
+
     // ========================================================================
     // ADR-017 §1: WAL Mode Tests
     // ========================================================================
@@ -1030,6 +2455,32 @@ mod tests {
         assert_eq!(loaded.edges[0].contributions.get("co-occurrence"), Some(&0.75));
     }
 
+    #[test]
+    fn test_contribution_log_survives_save_load() {
+        use crate::graph::ContributionAggregation;
+
+        let store = create_test_store();
+        let mut ctx = create_test_context();
+        ctx.metadata.contribution_history = Some(ContributionAggregation::Sum);
+        let ctx_id = ctx.id.clone();
+        let node_a = create_test_node("node:a", "concept");
+        let node_b = create_test_node("node:b", "concept");
+        ctx.add_node(node_a.clone());
+        ctx.add_node(node_b.clone());
+        for value in [1.0, 0.5] {
+            let edge = Edge::new(node_a.id.clone(), node_b.id.clone(), "tagged_with").with_contribution("content", value);
+            ctx.add_edge(edge);
+        }
+
+        store.save_context(&ctx).unwrap();
+
+        let loaded = store.load_context(&ctx_id).unwrap().unwrap();
+        assert_eq!(loaded.metadata.contribution_history, Some(ContributionAggregation::Sum));
+        assert_eq!(loaded.edges[0].contribution_log, ctx.edges[0].contribution_log);
+        assert_eq!(loaded.edges[0].contribution_history("content").count(), 2);
+        assert_eq!(loaded.edges[0].contributions.get("content"), Some(&1.5));
+    }
+
     // ========================================================================
     // ADR-017 §3: Incremental Upsert Tests
     // ========================================================================
@@ -1280,6 +2731,84 @@ mod tests {
         SqliteStore::create_dimension_indexes(&conn).unwrap();
     }
 
+    // ========================================================================
+    // Schema Versioning Tests
+    // ========================================================================
+
+    #[test]
+    fn test_fresh_database_is_at_current_schema_version() {
+        let store = create_test_store();
+        assert_eq!(store.schema_version().unwrap(), SqliteStore::SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_unversioned_database_migrates_on_open_with_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("old.db");
+        {
+            let conn = Connection::open(&path).unwrap();
+            conn.execute_batch(
+                r#"
+                CREATE TABLE contexts (id TEXT PRIMARY KEY, name TEXT NOT NULL, description TEXT, metadata_json TEXT NOT NULL);
+                CREATE TABLE nodes (id TEXT NOT NULL, context_id TEXT NOT NULL, node_type TEXT NOT NULL, content_type TEXT NOT NULL, properties_json TEXT NOT NULL, metadata_json TEXT NOT NULL, PRIMARY KEY (context_id, id));
+                CREATE TABLE edges (id TEXT NOT NULL, context_id TEXT NOT NULL, source_id TEXT NOT NULL, target_id TEXT NOT NULL, relationship TEXT NOT NULL, weight REAL NOT NULL, created_at TEXT NOT NULL, properties_json TEXT NOT NULL, PRIMARY KEY (context_id, id));
+                INSERT INTO contexts VALUES ('ctx:test', 'test', NULL, '{}');
+                INSERT INTO nodes VALUES ('node:a', 'ctx:test', 'concept', '"code"', '{}', '{}');
+                INSERT INTO nodes VALUES ('node:b', 'ctx:test', 'concept', '"code"', '{}', '{}');
+                INSERT INTO edges VALUES ('edge:1', 'ctx:test', 'node:a', 'node:b', 'calls', 0.75, '2024-01-01T00:00:00Z', '{}');
+                "#,
+            )
+            .unwrap();
+        }
+
+        let store = SqliteStore::open(&path).unwrap();
+        assert_eq!(store.schema_version().unwrap(), SqliteStore::SCHEMA_VERSION);
+        let raw_weight: f64 = store
+            .conn
+            .lock()
+            .unwrap()
+            .query_row("SELECT raw_weight FROM edges WHERE id = 'edge:1'", [], |row| row.get(0))
+            .unwrap();
+        assert!((raw_weight - 0.75).abs() < f64::EPSILON);
+
+        // The backup keeps the pre-migration schema
+        let backup = Connection::open(dir.path().join("old.db.v0.bak")).unwrap();
+        let has_old_weight: bool = backup
+            .query_row(
+                "SELECT COUNT(*) > 0 FROM pragma_table_info('edges') WHERE name = 'weight'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert!(has_old_weight);
+
+        // Reopening a current database neither migrates nor backs up again
+        drop(store);
+        std::fs::remove_file(dir.path().join("old.db.v0.bak")).unwrap();
+        SqliteStore::open(&path).unwrap();
+        assert!(!dir.path().join(format!("old.db.v{}.bak", SqliteStore::SCHEMA_VERSION)).exists());
+    }
+
+    #[test]
+    fn test_newer_schema_version_is_refused() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("future.db");
+        drop(SqliteStore::open(&path).unwrap());
+        {
+            let conn = Connection::open(&path).unwrap();
+            conn.execute("UPDATE schema_version SET version = ?1", params![SqliteStore::SCHEMA_VERSION + 1])
+                .unwrap();
+        }
+
+        match SqliteStore::open(&path) {
+            Err(StorageError::UnsupportedSchemaVersion { found, supported }) => {
+                assert_eq!(found, SqliteStore::SCHEMA_VERSION + 1);
+                assert_eq!(supported, SqliteStore::SCHEMA_VERSION);
+            }
+            other => panic!("expected UnsupportedSchemaVersion, got {:?}", other.map(|_| ())),
+        }
+    }
+
     #[test]
     fn test_migration_noop_on_current_schema() {
         // Running the legacy migration on a database that already has raw_weight
@@ -1328,17 +2857,17 @@ mod tests {
     #[test]
     fn persist_event_returns_monotonic_sequences() {
         let store = create_test_store();
-        let seq1 = store.persist_event("ctx", "NodesAdded", &["n1".into()], &[], "adapter-1").unwrap();
-        let seq2 = store.persist_event("ctx", "EdgesAdded", &[], &["e1".into()], "adapter-1").unwrap();
+        let seq1 = store.persist_event("ctx", "NodesAdded", &["n1".into()], &[], &[], "adapter-1").unwrap();
+        let seq2 = store.persist_event("ctx", "EdgesAdded", &[], &["e1".into()], &[], "adapter-1").unwrap();
         assert!(seq2 > seq1, "sequences must be monotonically increasing");
     }
 
     #[test]
     fn query_events_since_returns_events_after_cursor() {
         let store = create_test_store();
-        let _s1 = store.persist_event("ctx", "NodesAdded", &["n1".into()], &[], "a1").unwrap();
-        let _s2 = store.persist_event("ctx", "EdgesAdded", &[], &["e1".into()], "a1").unwrap();
-        let s3 = store.persist_event("ctx", "NodesAdded", &["n2".into()], &[], "a1").unwrap();
+        let _s1 = store.persist_event("ctx", "NodesAdded", &["n1".into()], &[], &[], "a1").unwrap();
+        let _s2 = store.persist_event("ctx", "EdgesAdded", &[], &["e1".into()], &[], "a1").unwrap();
+        let s3 = store.persist_event("ctx", "NodesAdded", &["n2".into()], &[], &[], "a1").unwrap();
 
         // Query after sequence 1 should return events 2 and 3
         let events = store.query_events_since("ctx", 1, None).unwrap();
@@ -1350,8 +2879,8 @@ mod tests {
     #[test]
     fn query_events_since_cursor_zero_returns_all() {
         let store = create_test_store();
-        store.persist_event("ctx", "NodesAdded", &["n1".into()], &[], "a1").unwrap();
-        store.persist_event("ctx", "EdgesAdded", &[], &["e1".into()], "a1").unwrap();
+        store.persist_event("ctx", "NodesAdded", &["n1".into()], &[], &[], "a1").unwrap();
+        store.persist_event("ctx", "EdgesAdded", &[], &["e1".into()], &[], "a1").unwrap();
 
         let events = store.query_events_since("ctx", 0, None).unwrap();
         assert_eq!(events.len(), 2);
@@ -1362,9 +2891,9 @@ mod tests {
         use crate::query::CursorFilter;
 
         let store = create_test_store();
-        store.persist_event("ctx", "NodesAdded", &["n1".into()], &[], "a1").unwrap();
-        store.persist_event("ctx", "EdgesAdded", &[], &["e1".into()], "a1").unwrap();
-        store.persist_event("ctx", "NodesAdded", &["n2".into()], &[], "a1").unwrap();
+        store.persist_event("ctx", "NodesAdded", &["n1".into()], &[], &[], "a1").unwrap();
+        store.persist_event("ctx", "EdgesAdded", &[], &["e1".into()], &[], "a1").unwrap();
+        store.persist_event("ctx", "NodesAdded", &["n2".into()], &[], &[], "a1").unwrap();
 
         let filter = CursorFilter {
             event_types: Some(vec!["EdgesAdded".into()]),
@@ -1380,8 +2909,8 @@ mod tests {
         use crate::query::CursorFilter;
 
         let store = create_test_store();
-        store.persist_event("ctx", "NodesAdded", &["n1".into()], &[], "adapter-a").unwrap();
-        store.persist_event("ctx", "EdgesAdded", &[], &["e1".into()], "adapter-b").unwrap();
+        store.persist_event("ctx", "NodesAdded", &["n1".into()], &[], &[], "adapter-a").unwrap();
+        store.persist_event("ctx", "EdgesAdded", &[], &["e1".into()], &[], "adapter-b").unwrap();
 
         let filter = CursorFilter {
             adapter_id: Some("adapter-b".into()),
@@ -1395,8 +2924,8 @@ mod tests {
     #[test]
     fn query_events_scoped_to_context() {
         let store = create_test_store();
-        store.persist_event("ctx-a", "NodesAdded", &["n1".into()], &[], "a1").unwrap();
-        store.persist_event("ctx-b", "NodesAdded", &["n2".into()], &[], "a1").unwrap();
+        store.persist_event("ctx-a", "NodesAdded", &["n1".into()], &[], &[], "a1").unwrap();
+        store.persist_event("ctx-b", "NodesAdded", &["n2".into()], &[], &[], "a1").unwrap();
 
         let events = store.query_events_since("ctx-a", 0, None).unwrap();
         assert_eq!(events.len(), 1);
@@ -1406,8 +2935,8 @@ mod tests {
     #[test]
     fn latest_sequence_returns_max_for_context() {
         let store = create_test_store();
-        store.persist_event("ctx", "NodesAdded", &[], &[], "a1").unwrap();
-        let seq2 = store.persist_event("ctx", "EdgesAdded", &[], &[], "a1").unwrap();
+        store.persist_event("ctx", "NodesAdded", &[], &[], &[], "a1").unwrap();
+        let seq2 = store.persist_event("ctx", "EdgesAdded", &[], &[], &[], "a1").unwrap();
 
         assert_eq!(store.latest_sequence("ctx").unwrap(), seq2);
     }
@@ -1426,8 +2955,8 @@ mod tests {
         // Write events
         {
             let store = SqliteStore::open(&db_path).unwrap();
-            store.persist_event("ctx", "NodesAdded", &["n1".into()], &[], "a1").unwrap();
-            store.persist_event("ctx", "EdgesAdded", &[], &["e1".into()], "a1").unwrap();
+            store.persist_event("ctx", "NodesAdded", &["n1".into()], &[], &[], "a1").unwrap();
+            store.persist_event("ctx", "EdgesAdded", &[], &["e1".into()], &[], "a1").unwrap();
         }
 
         // Reopen and verify
@@ -1532,6 +3061,126 @@ mod tests {
         assert!(specs.is_empty());
     }
 
+    #[test]
+    fn llm_costs_round_trip_by_context_and_time() {
+        let store = create_test_store();
+        let cost = |context: &str, item: &str, at: &str| PersistedLlmCost {
+            context_id: context.into(),
+            adapter_id: "extract-semantic".into(),
+            item: item.into(),
+            ensemble: "semantic-extraction".into(),
+            input_tokens: 120,
+            output_tokens: 30,
+            cost_usd: 0.01,
+            recorded_at: at.into(),
+        };
+        store.persist_llm_cost(&cost("ctx:a", "/docs/1.md", "2026-01-01T00:00:00+00:00")).unwrap();
+        store.persist_llm_cost(&cost("ctx:a", "/docs/2.md", "2026-02-01T00:00:00+00:00")).unwrap();
+        store.persist_llm_cost(&cost("ctx:b", "/docs/3.md", "2026-02-01T00:00:00+00:00")).unwrap();
+
+        let costs = store.query_llm_costs_since("ctx:a", "2026-01-15T00:00:00+00:00").unwrap();
+        assert_eq!(costs, vec![cost("ctx:a", "/docs/2.md", "2026-02-01T00:00:00+00:00")]);
+        assert_eq!(store.query_llm_costs_since("ctx:a", "").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn emissions_round_trip_filtered_by_adapter_and_time() {
+        let store = create_test_store();
+        let emission = |context: &str, adapter: &str, at: &str| PersistedEmission {
+            context_id: context.into(),
+            adapter_id: adapter.into(),
+            input_summary: Some("notes/bread.md".into()),
+            node_ids: vec!["concept:rye".into()],
+            edge_ids: vec!["e1".into()],
+            recorded_at: at.into(),
+        };
+        store.persist_emission(&emission("ctx:a", "llm-extract", "2026-10-05T09:00:00+00:00")).unwrap();
+        store.persist_emission(&emission("ctx:a", "llm-extract", "2026-10-06T09:00:00+00:00")).unwrap();
+        store.persist_emission(&emission("ctx:a", "content", "2026-10-06T10:00:00+00:00")).unwrap();
+        store.persist_emission(&emission("ctx:b", "llm-extract", "2026-10-06T09:00:00+00:00")).unwrap();
+
+        let tuesday = |hour| chrono::DateTime::parse_from_rfc3339(&format!("2026-10-06T{hour:02}:00:00+00:00")).unwrap().with_timezone(&chrono::Utc);
+        let filter = EmissionFilter::new().with_adapter("llm-extract").since(tuesday(0)).until(tuesday(23));
+        let found = store.query_emissions("ctx:a", &filter).unwrap();
+        assert_eq!(found, vec![emission("ctx:a", "llm-extract", "2026-10-06T09:00:00+00:00")]);
+        assert_eq!(store.query_emissions("ctx:a", &EmissionFilter::new()).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn outbound_queue_orders_pages_and_tracks_acks() {
+        let store = create_test_store();
+        let event = |context: &str, kind: &str| PersistedOutboundEvent {
+            offset: 0,
+            context_id: context.into(),
+            kind: kind.into(),
+            detail: String::new(),
+            recorded_at: "2026-03-01T00:00:00+00:00".into(),
+        };
+        let first = store.append_outbound_event(&event("ctx:a", "concepts_detected")).unwrap();
+        store.append_outbound_event(&event("ctx:b", "other")).unwrap();
+        let third = store.append_outbound_event(&event("ctx:a", "fragment_indexed")).unwrap();
+        assert!(third > first);
+
+        let all = store.query_outbound_since("ctx:a", 0, None).unwrap();
+        let kinds: Vec<&str> = all.iter().map(|e| e.kind.as_str()).collect();
+        assert_eq!(kinds, vec!["concepts_detected", "fragment_indexed"]);
+        assert_eq!(all[1].offset, third);
+        assert_eq!(store.query_outbound_since("ctx:a", first, Some(5)).unwrap().len(), 1);
+        assert_eq!(store.query_outbound_since("ctx:a", 0, Some(1)).unwrap().len(), 1);
+
+        assert_eq!(store.outbound_ack("ctx:a", "carrel").unwrap(), None);
+        store.set_outbound_ack("ctx:a", "carrel", first).unwrap();
+        store.set_outbound_ack("ctx:a", "carrel", third).unwrap();
+        assert_eq!(store.outbound_ack("ctx:a", "carrel").unwrap(), Some(third));
+        assert_eq!(store.outbound_ack("ctx:b", "carrel").unwrap(), None);
+
+        assert_eq!(store.prune_outbound("ctx:a", 1).unwrap(), 1);
+        let kept = store.query_outbound_since("ctx:a", 0, None).unwrap();
+        assert_eq!(kept.iter().map(|e| e.offset).collect::<Vec<_>>(), vec![third], "the newest event is kept");
+        assert_eq!(store.query_outbound_since("ctx:b", 0, None).unwrap().len(), 1, "other contexts are untouched");
+        let fourth = store.append_outbound_event(&event("ctx:a", "later")).unwrap();
+        assert!(fourth > third, "offsets keep increasing after a prune");
+    }
+
+    #[test]
+    fn templates_upsert_list_and_delete() {
+        let store = create_test_store();
+        let template = |json: &str| PersistedTemplate {
+            name: "lab notebook".into(),
+            template_json: json.into(),
+            saved_at: "2026-03-01T00:00:00+00:00".into(),
+        };
+        store.persist_template(&template("{\"name\":\"v1\"}")).unwrap();
+        store.persist_template(&template("{\"name\":\"v2\"}")).unwrap();
+        assert_eq!(store.list_templates().unwrap(), vec!["lab notebook".to_string()]);
+        assert_eq!(store.load_template("lab notebook").unwrap().unwrap().template_json, "{\"name\":\"v2\"}");
+        assert!(store.load_template("missing").unwrap().is_none());
+        assert!(store.delete_template("lab notebook").unwrap());
+        assert!(!store.delete_template("lab notebook").unwrap());
+    }
+
+    #[test]
+    fn embeddings_upsert_load_and_delete_by_model() {
+        let store = create_test_store();
+        let embedding = |node: &str, model: &str, vector: Vec<f32>| PersistedEmbedding {
+            context_id: "notes".into(),
+            node_id: node.into(),
+            model: model.into(),
+            vector,
+            text_hash: format!("hash-{node}"),
+        };
+        store.persist_embedding(&embedding("concept:a", "m1", vec![0.5, -1.25])).unwrap();
+        store.persist_embedding(&embedding("concept:a", "m1", vec![0.25, 2.0])).unwrap();
+        store.persist_embedding(&embedding("concept:b", "m1", vec![1.0, 0.0])).unwrap();
+        store.persist_embedding(&embedding("concept:a", "m2", vec![3.0])).unwrap();
+
+        let loaded = store.load_embeddings("notes", "m1").unwrap();
+        assert_eq!(loaded, vec![embedding("concept:a", "m1", vec![0.25, 2.0]), embedding("concept:b", "m1", vec![1.0, 0.0])]);
+        assert!(store.load_embeddings("other", "m1").unwrap().is_empty());
+        assert_eq!(store.delete_embeddings("notes", "m1").unwrap(), 2);
+        assert_eq!(store.load_embeddings("notes", "m2").unwrap().len(), 1, "other models are kept");
+    }
+
     #[test]
     fn delete_spec_returns_false_for_nonexistent() {
         let store = create_test_store();
@@ -1564,4 +3213,172 @@ mod tests {
             assert_eq!(specs[0].spec_yaml, "adapter_id: trellis");
         }
     }
+
+    #[test]
+    fn test_list_contexts_for_tenant() {
+        let store = SqliteStore::open_in_memory().unwrap();
+        let acme = Context::new("notes").with_tenant("acme");
+        let globex = Context::new("notes").with_tenant("globex");
+        let shared = Context::new("shared");
+        for ctx in [&acme, &globex, &shared] {
+            store.save_context(ctx).unwrap();
+        }
+
+        assert_eq!(store.list_contexts_for_tenant(Some("acme")).unwrap(), vec![acme.id.clone()]);
+        assert_eq!(store.list_contexts_for_tenant(None).unwrap(), vec![shared.id.clone()]);
+        assert!(store.list_contexts_for_tenant(Some("initech")).unwrap().is_empty());
+
+        let loaded = store.load_context(&globex.id).unwrap().unwrap();
+        assert_eq!(loaded.metadata.tenant.as_deref(), Some("globex"));
+    }
+
+    #[test]
+    fn test_manifest_lists_counts_without_loading_graphs() {
+        let store = SqliteStore::open_in_memory().unwrap();
+        let mut ctx = Context::new("notes").with_tenant("acme");
+        let a = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let b = ctx.add_node(Node::new("concept", ContentType::Concept));
+        ctx.add_edge(Edge::new(a, b, "related_to"));
+        ctx.metadata.updated_at = Some(chrono::Utc::now());
+        store.save_context(&ctx).unwrap();
+        store.save_context(&Context::new("empty")).unwrap();
+
+        let mut manifest = store.load_manifest().unwrap();
+        manifest.sort_by(|x, y| x.name.cmp(&y.name));
+        let rows: Vec<(&str, usize, usize)> = manifest.iter().map(|m| (m.name.as_str(), m.node_count, m.edge_count)).collect();
+        assert_eq!(rows, vec![("empty", 0, 0), ("notes", 2, 1)]);
+        assert_eq!(manifest[1].tenant.as_deref(), Some("acme"));
+        assert_eq!(manifest[1].updated_at, ctx.metadata.updated_at);
+    }
+
+    #[test]
+    fn test_durability_levels_hold_saves_until_due() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("durability.db");
+        let store = SqliteStore::open(&db_path).unwrap();
+        let other = SqliteStore::open(&db_path).unwrap();
+        let visible = |ctx: &Context| other.list_contexts().unwrap().contains(&ctx.id);
+
+        let primary = Context::new("primary");
+        store.save_context(&primary).unwrap();
+        assert!(visible(&primary), "strict saves commit at once");
+
+        let mut scratch = Context::new("scratch");
+        scratch.metadata.durability = Some(Durability::Relaxed);
+        scratch.add_node(Node::new("concept", ContentType::Concept));
+        store.save_context(&scratch).unwrap();
+        assert!(!visible(&scratch));
+        assert_eq!(store.flush_due().unwrap(), 0, "not idle yet");
+        assert_eq!(store.count_nodes(&scratch.id, &NodeFilter::new()).unwrap(), 1, "the store reads its own held saves");
+        assert!(visible(&scratch));
+
+        let mut batched = Context::new("batched");
+        batched.metadata.durability = Some(Durability::Batched { interval_ms: 20 });
+        store.save_context(&batched).unwrap();
+        store.save_context(&batched).unwrap();
+        assert_eq!(store.deferred_contexts(), 1, "repeat saves fold into one held save");
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(store.flush_due().unwrap(), 1);
+        assert!(visible(&batched));
+
+        scratch.add_node(Node::new("concept", ContentType::Concept));
+        store.save_context(&scratch).unwrap();
+        drop(store);
+        assert_eq!(other.count_nodes(&scratch.id, &NodeFilter::new()).unwrap(), 2, "held saves commit on drop");
+    }
+
+    #[test]
+    fn test_relaxed_idle_is_tracked_per_context() {
+        let start = Instant::now();
+        let mut quiet = Context::new("quiet");
+        quiet.metadata.durability = Some(Durability::Relaxed);
+        let mut busy = Context::new("busy");
+        busy.metadata.durability = Some(Durability::Relaxed);
+
+        let mut deferred = Deferred::default();
+        deferred.hold(&quiet, start);
+        deferred.hold(&busy, start + RELAXED_IDLE);
+        assert_eq!(deferred.due(start + RELAXED_IDLE), vec![quiet.id.clone()], "another context's saves don't keep it waiting");
+        assert!(deferred.due(start + RELAXED_IDLE * 2).contains(&busy.id));
+    }
+
+    #[test]
+    fn test_side_table_writes_commit_held_saves_first() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("side.db");
+        let store = SqliteStore::open(&db_path).unwrap();
+        let other = SqliteStore::open(&db_path).unwrap();
+
+        let mut scratch = Context::new("scratch");
+        scratch.metadata.durability = Some(Durability::Relaxed);
+        store.save_context(&scratch).unwrap();
+        assert_eq!(store.deferred_contexts(), 1);
+
+        store.persist_event(scratch.id.as_str(), "NodesAdded", &[], &[], &[], "test").unwrap();
+        assert_eq!(store.deferred_contexts(), 0);
+        assert!(other.list_contexts().unwrap().contains(&scratch.id), "the event never lands ahead of its graph");
+    }
+
+    #[test]
+    fn test_emissions_commit_with_their_held_save_and_expire() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("emissions.db");
+        let store = SqliteStore::open(&db_path).unwrap();
+        let other = SqliteStore::open(&db_path).unwrap();
+        let emission = |context: &Context, at: &str| PersistedEmission {
+            context_id: context.id.to_string(),
+            adapter_id: "content".into(),
+            input_summary: None,
+            node_ids: vec!["concept:rye".into()],
+            edge_ids: Vec::new(),
+            recorded_at: at.into(),
+        };
+
+        let mut scratch = Context::new("scratch");
+        scratch.metadata.durability = Some(Durability::Relaxed);
+        store.save_context(&scratch).unwrap();
+        store.persist_emission(&emission(&scratch, "2026-10-01T00:00:00+00:00")).unwrap();
+        assert_eq!(store.deferred_contexts(), 1, "the record doesn't force the save");
+        assert!(other.query_emissions(scratch.id.as_str(), &EmissionFilter::new()).unwrap().is_empty());
+        store.flush_deferred().unwrap();
+        assert_eq!(other.query_emissions(scratch.id.as_str(), &EmissionFilter::new()).unwrap().len(), 1);
+
+        store.persist_emission(&emission(&scratch, "2026-10-09T00:00:00+00:00")).unwrap();
+        assert_eq!(store.prune_emissions(scratch.id.as_str(), "2026-10-05T00:00:00+00:00").unwrap(), 1);
+        store.delete_context(&scratch.id).unwrap();
+        assert!(store.query_emissions(scratch.id.as_str(), &EmissionFilter::new()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_trash_round_trips_and_stays_out_of_queries() {
+        let store = SqliteStore::open_in_memory().unwrap();
+        let mut ctx = Context::new("trash");
+        let a = ctx.add_node(Node::new("concept", ContentType::Concept));
+        let b = ctx.add_node(Node::new("concept", ContentType::Concept));
+        ctx.add_edge(Edge::new(a.clone(), b.clone(), "related_to"));
+        store.save_context(&ctx).unwrap();
+
+        ctx.trash_node(&a).unwrap();
+        store.save_context(&ctx).unwrap();
+        assert_eq!(store.count_nodes(&ctx.id, &NodeFilter::new()).unwrap(), 1);
+        assert_eq!(store.count_edges(&ctx.id, &EdgeFilter::new()).unwrap(), 0);
+        assert!(store.load_edges(&ctx.id, &EdgeFilter::new()).unwrap().is_empty());
+
+        let mut loaded = store.load_context(&ctx.id).unwrap().unwrap();
+        assert_eq!(loaded.node_count(), 1);
+        assert!(loaded.trash.nodes.contains_key(&a));
+        assert_eq!(loaded.trash.edges.len(), 1);
+
+        // Restored rows clear their tombstone; purged rows are deleted
+        loaded.restore_from_trash(&[a.to_string()]);
+        store.save_context(&loaded).unwrap();
+        assert_eq!(store.count_nodes(&ctx.id, &NodeFilter::new()).unwrap(), 2);
+
+        loaded.trash_node(&b).unwrap();
+        loaded.purge_trash(chrono::Utc::now() + chrono::Duration::seconds(1));
+        store.save_context(&loaded).unwrap();
+        let reloaded = store.load_context(&ctx.id).unwrap().unwrap();
+        assert_eq!(reloaded.node_count(), 1);
+        assert!(reloaded.trash.is_empty());
+    }
 }
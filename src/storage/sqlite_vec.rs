@@ -181,6 +181,15 @@ mod inner {
 
             results
         }
+
+        fn clear(&self, context_id: &str) {
+            let conn = self.conn.lock().unwrap();
+            conn.execute(
+                "DELETE FROM vec_embeddings WHERE context_id = ?1",
+                rusqlite::params![context_id],
+            )
+            .expect("vec_embeddings DELETE failed");
+        }
     }
 
     #[cfg(test)]
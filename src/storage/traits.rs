@@ -1,7 +1,9 @@
 //! Storage trait definitions
 
-use crate::graph::{Context, ContextId};
+use crate::graph::{ContentType, Context, ContextId, Edge, Node, NodeId, Source};
+use chrono::{DateTime, Utc};
 use crate::query::{CursorFilter, PersistedEvent};
+use serde::Serialize;
 use std::path::Path;
 use thiserror::Error;
 
@@ -28,6 +30,9 @@ pub enum StorageError {
 
     #[error("Internal error: {0}")]
     Internal(String),
+
+    #[error("Database schema version {found} is newer than supported version {supported}")]
+    UnsupportedSchemaVersion { found: u32, supported: u32 },
 }
 
 /// Result type for storage operations
@@ -55,6 +60,69 @@ pub trait GraphStore: Send + Sync {
     /// List all context IDs
     fn list_contexts(&self) -> StorageResult<Vec<ContextId>>;
 
+    /// List the IDs of `tenant`'s contexts (`None`: untenanted ones).
+    ///
+    /// The default loads every context to read its tenant; backends
+    /// should answer from an index.
+    fn list_contexts_for_tenant(&self, tenant: Option<&str>) -> StorageResult<Vec<ContextId>> {
+        let mut ids = Vec::new();
+        for id in self.list_contexts()? {
+            if let Some(ctx) = self.load_context(&id)? {
+                if ctx.metadata.tenant.as_deref() == tenant {
+                    ids.push(id);
+                }
+            }
+        }
+        Ok(ids)
+    }
+
+    /// A summary of every stored context, for a warm start that lists
+    /// contexts before loading them (`PlexusEngine::warm_start`).
+    ///
+    /// The default loads each context to summarize it; backends should
+    /// answer from stored counts.
+    fn load_manifest(&self) -> StorageResult<Vec<ManifestEntry>> {
+        let mut entries = Vec::new();
+        for id in self.list_contexts()? {
+            if let Some(ctx) = self.load_context(&id)? {
+                entries.push(ManifestEntry::of(&ctx));
+            }
+        }
+        Ok(entries)
+    }
+
+    // === Filtered Loads ===
+
+    /// Load the nodes of a context matching `filter`.
+    ///
+    /// The default loads the whole context and filters in memory;
+    /// backends should push the filter down to their indexes.
+    fn load_nodes(&self, context_id: &ContextId, filter: &NodeFilter) -> StorageResult<Vec<Node>> {
+        Ok(self
+            .load_context(context_id)?
+            .map(|ctx| ctx.nodes.into_values().filter(|n| filter.matches(n)).collect())
+            .unwrap_or_default())
+    }
+
+    /// Load the edges of a context matching `filter`.
+    fn load_edges(&self, context_id: &ContextId, filter: &EdgeFilter) -> StorageResult<Vec<Edge>> {
+        Ok(self
+            .load_context(context_id)?
+            .map(|ctx| ctx.edges.into_iter().filter(|e| filter.matches(e)).collect())
+            .unwrap_or_default())
+    }
+
+    /// Count the nodes matching `filter` without loading them, where the
+    /// backend allows. The default counts `load_nodes`.
+    fn count_nodes(&self, context_id: &ContextId, filter: &NodeFilter) -> StorageResult<usize> {
+        Ok(self.load_nodes(context_id, filter)?.len())
+    }
+
+    /// Count the edges matching `filter`.
+    fn count_edges(&self, context_id: &ContextId, filter: &EdgeFilter) -> StorageResult<usize> {
+        Ok(self.load_edges(context_id, filter)?.len())
+    }
+
     // === Coherence ===
 
     /// Return the database version counter for cache coherence (ADR-017 §2).
@@ -67,9 +135,61 @@ pub trait GraphStore: Send + Sync {
         Ok(0)
     }
 
+    // === Durability ===
+
+    /// Whether the store may hold saves back under a context's
+    /// `Durability`, so someone must call `flush_due` periodically.
+    /// Returns false by default (every save commits).
+    fn defers_saves(&self) -> bool {
+        false
+    }
+
+    /// Commit the held saves that are due, returning how many contexts
+    /// committed. Default no-op returns 0.
+    fn flush_due(&self) -> StorageResult<usize> {
+        Ok(0)
+    }
+
+    // === Schema ===
+
+    /// Return the storage schema version of the open database.
+    ///
+    /// Stores upgrade older schemas on open, so this is the version
+    /// the store reads and writes. Returns 0 by default (unversioned).
+    fn schema_version(&self) -> StorageResult<u32> {
+        Ok(0)
+    }
+
+    // === Backup ===
+
+    /// Write a consistent snapshot of the store to `path`.
+    fn backup(&self, path: &Path) -> StorageResult<()> {
+        let _ = path;
+        Err(StorageError::Internal("backup not supported by this store".to_string()))
+    }
+
+    /// Replace the store's contents with the snapshot at `path`.
+    fn restore(&self, path: &Path) -> StorageResult<()> {
+        let _ = path;
+        Err(StorageError::Internal("restore not supported by this store".to_string()))
+    }
+
+    // === Maintenance ===
+
+    /// Reclaim space: delete orphaned rows, rebuild indexes, and vacuum.
+    ///
+    /// With `context_id`, dangling-edge cleanup is limited to that
+    /// context; orphans and the vacuum are always store-wide. Default
+    /// no-op returns an empty report.
+    fn compact(&self, context_id: Option<&ContextId>) -> StorageResult<CompactionReport> {
+        let _ = context_id;
+        Ok(CompactionReport::default())
+    }
+
     // === Event Cursor Operations (ADR-035) ===
 
-    /// Persist a graph event to the event log.
+    /// Persist a graph event to the event log, with the keys it changed
+    /// (see `PersistedEvent::keys`).
     ///
     /// Returns the assigned sequence number. Default no-op returns 0 —
     /// backends that don't support event persistence silently skip it.
@@ -79,9 +199,10 @@ pub trait GraphStore: Send + Sync {
         event_type: &str,
         node_ids: &[String],
         edge_ids: &[String],
+        keys: &[String],
         adapter_id: &str,
     ) -> StorageResult<u64> {
-        let _ = (context_id, event_type, node_ids, edge_ids, adapter_id);
+        let _ = (context_id, event_type, node_ids, edge_ids, keys, adapter_id);
         Ok(0)
     }
 
@@ -130,6 +251,209 @@ pub trait GraphStore: Send + Sync {
         let _ = (context_id, adapter_id);
         Ok(false)
     }
+
+    // === LLM Cost Accounting ===
+
+    /// Record the usage of one LLM invocation. Default no-op.
+    fn persist_llm_cost(&self, cost: &PersistedLlmCost) -> StorageResult<()> {
+        let _ = cost;
+        Ok(())
+    }
+
+    /// Costs recorded for a context at or after `since` (RFC 3339),
+    /// oldest first. Default no-op returns empty vec.
+    fn query_llm_costs_since(&self, context_id: &str, since: &str) -> StorageResult<Vec<PersistedLlmCost>> {
+        let _ = (context_id, since);
+        Ok(Vec::new())
+    }
+
+    // === Emission Records ===
+
+    /// Record one committed emission. Default no-op.
+    fn persist_emission(&self, emission: &PersistedEmission) -> StorageResult<()> {
+        let _ = emission;
+        Ok(())
+    }
+
+    /// Emissions recorded for a context that match `filter`, oldest
+    /// first. Default no-op returns empty vec.
+    fn query_emissions(&self, context_id: &str, filter: &EmissionFilter) -> StorageResult<Vec<PersistedEmission>> {
+        let _ = (context_id, filter);
+        Ok(Vec::new())
+    }
+
+    /// Drop a context's emissions recorded before `before` (RFC 3339).
+    /// Returns how many were dropped. Default no-op returns 0.
+    fn prune_emissions(&self, context_id: &str, before: &str) -> StorageResult<usize> {
+        let _ = (context_id, before);
+        Ok(0)
+    }
+
+    // === Renamed Nodes ===
+
+    /// Move a context's embeddings and emission records from each old
+    /// node ID to its new one, as (old, new), after a relocation. Default
+    /// no-op.
+    fn rename_nodes(&self, context_id: &str, renamed: &[(String, String)]) -> StorageResult<()> {
+        let _ = (context_id, renamed);
+        Ok(())
+    }
+
+    // === Outbound Delivery Queue ===
+
+    /// Append an outbound event to a context's delivery queue. Returns its
+    /// offset, which increases with every append. Default no-op returns 0.
+    fn append_outbound_event(&self, event: &PersistedOutboundEvent) -> StorageResult<u64> {
+        let _ = event;
+        Ok(0)
+    }
+
+    /// Queued events of a context with offset greater than `after`,
+    /// oldest first, at most `limit`. Default no-op returns empty vec.
+    fn query_outbound_since(
+        &self,
+        context_id: &str,
+        after: u64,
+        limit: Option<usize>,
+    ) -> StorageResult<Vec<PersistedOutboundEvent>> {
+        let _ = (context_id, after, limit);
+        Ok(Vec::new())
+    }
+
+    /// The offset a subscriber has acknowledged up to. Default no-op
+    /// returns `None` (nothing acknowledged).
+    fn outbound_ack(&self, context_id: &str, subscriber: &str) -> StorageResult<Option<u64>> {
+        let _ = (context_id, subscriber);
+        Ok(None)
+    }
+
+    /// Set a subscriber's acknowledged offset. Default no-op.
+    fn set_outbound_ack(&self, context_id: &str, subscriber: &str, offset: u64) -> StorageResult<()> {
+        let _ = (context_id, subscriber, offset);
+        Ok(())
+    }
+
+    /// Drop all but the newest `keep` queued events of a context. Returns
+    /// how many were dropped. Default no-op returns 0.
+    fn prune_outbound(&self, context_id: &str, keep: usize) -> StorageResult<usize> {
+        let _ = (context_id, keep);
+        Ok(0)
+    }
+
+    // === Context Templates ===
+
+    /// Save a user-defined context template, replacing one of the same
+    /// name. Default no-op.
+    fn persist_template(&self, template: &PersistedTemplate) -> StorageResult<()> {
+        let _ = template;
+        Ok(())
+    }
+
+    /// A saved template by name. Default no-op returns `None`.
+    fn load_template(&self, name: &str) -> StorageResult<Option<PersistedTemplate>> {
+        let _ = name;
+        Ok(None)
+    }
+
+    /// Names of all saved templates, sorted. Default no-op returns empty vec.
+    fn list_templates(&self) -> StorageResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    /// Delete a saved template. Returns true if a row was deleted.
+    /// Default no-op returns false.
+    fn delete_template(&self, name: &str) -> StorageResult<bool> {
+        let _ = name;
+        Ok(false)
+    }
+
+    // === Embeddings ===
+
+    /// Save a node's embedding, replacing the one stored for the same
+    /// context, model and node. Default no-op.
+    fn persist_embedding(&self, embedding: &PersistedEmbedding) -> StorageResult<()> {
+        let _ = embedding;
+        Ok(())
+    }
+
+    /// Every embedding a model stored for a context. Default no-op
+    /// returns empty vec.
+    fn load_embeddings(&self, context_id: &str, model: &str) -> StorageResult<Vec<PersistedEmbedding>> {
+        let _ = (context_id, model);
+        Ok(Vec::new())
+    }
+
+    /// Delete a model's embeddings for a context. Returns the number
+    /// deleted. Default no-op returns 0.
+    fn delete_embeddings(&self, context_id: &str, model: &str) -> StorageResult<usize> {
+        let _ = (context_id, model);
+        Ok(0)
+    }
+
+    // === Change Journal ===
+
+    /// Append an entry to a context's change journal (see
+    /// `EventSourcedStore`). Returns its sequence, which increases with
+    /// every append. Default no-op returns 0.
+    fn append_journal(&self, entry: &PersistedJournalEntry) -> StorageResult<u64> {
+        let _ = entry;
+        Ok(0)
+    }
+
+    /// Journal entries of a context with sequence greater than `after`,
+    /// oldest first. Default no-op returns empty vec.
+    fn query_journal(&self, context_id: &str, after: u64) -> StorageResult<Vec<PersistedJournalEntry>> {
+        let _ = (context_id, after);
+        Ok(Vec::new())
+    }
+
+    /// Delete a context's journal entries with sequence below `before`.
+    /// Returns the number deleted. Default no-op returns 0.
+    fn truncate_journal(&self, context_id: &str, before: u64) -> StorageResult<usize> {
+        let _ = (context_id, before);
+        Ok(0)
+    }
+
+    // === Idempotency Keys ===
+
+    /// Claim an idempotency key before ingesting under it: record
+    /// `claim` (a pending result) unless the key already has a completed
+    /// result or a pending claim recorded at or after `stale_before`.
+    /// Returns whether the claim was taken; the check and the write are
+    /// one atomic step, so two processes can't both claim a key. Default
+    /// always claims.
+    fn claim_ingest_key(&self, claim: &PersistedIngestResult, stale_before: &str) -> StorageResult<bool> {
+        let _ = (claim, stale_before);
+        Ok(true)
+    }
+
+    /// Record the result of an ingest made under an idempotency key,
+    /// completing its claim. A completed result is never replaced.
+    /// Default no-op.
+    fn persist_ingest_result(&self, result: &PersistedIngestResult) -> StorageResult<()> {
+        let _ = result;
+        Ok(())
+    }
+
+    /// Drop a key's pending claim, after the ingest under it failed.
+    /// Default no-op.
+    fn release_ingest_key(&self, context_id: &str, key: &str) -> StorageResult<()> {
+        let _ = (context_id, key);
+        Ok(())
+    }
+
+    /// The result recorded for an idempotency key. Default no-op returns `None`.
+    fn load_ingest_result(&self, context_id: &str, key: &str) -> StorageResult<Option<PersistedIngestResult>> {
+        let _ = (context_id, key);
+        Ok(None)
+    }
+
+    /// Drop results and claims recorded before `before` (RFC 3339).
+    /// Returns how many were dropped. Default no-op returns 0.
+    fn prune_ingest_results(&self, before: &str) -> StorageResult<usize> {
+        let _ = before;
+        Ok(0)
+    }
 }
 
 /// A persisted consumer spec row from the `specs` table (ADR-037 §2).
@@ -144,6 +468,281 @@ pub struct PersistedSpec {
     pub loaded_at: String,
 }
 
+/// A row of the `llm_costs` table: what one LLM invocation used, and
+/// which context and content item (a file path, usually) it was for.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PersistedLlmCost {
+    pub context_id: String,
+    pub adapter_id: String,
+    pub item: String,
+    pub ensemble: String,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cost_usd: f64,
+    pub recorded_at: String,
+}
+
+/// A row of the `emissions` table: which adapter committed what to a
+/// context, when, and from which input.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PersistedEmission {
+    pub context_id: String,
+    pub adapter_id: String,
+    pub input_summary: Option<String>,
+    /// Nodes the emission added or updated
+    pub node_ids: Vec<String>,
+    /// Edges the emission added or reinforced
+    pub edge_ids: Vec<String>,
+    pub recorded_at: String,
+}
+
+/// Emission selection for `GraphStore::query_emissions`. Unset fields
+/// match anything.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EmissionFilter {
+    pub adapter_id: Option<String>,
+    /// Recorded at or after (inclusive)
+    pub since: Option<DateTime<Utc>>,
+    /// Recorded before (exclusive)
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl EmissionFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_adapter(mut self, adapter_id: impl Into<String>) -> Self {
+        self.adapter_id = Some(adapter_id.into());
+        self
+    }
+
+    pub fn since(mut self, since: DateTime<Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn until(mut self, until: DateTime<Utc>) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    pub fn matches(&self, emission: &PersistedEmission) -> bool {
+        self.adapter_id.as_ref().is_none_or(|a| emission.adapter_id == *a)
+            && self.since.is_none_or(|since| emission.recorded_at >= since.to_rfc3339())
+            && self.until.is_none_or(|until| emission.recorded_at < until.to_rfc3339())
+    }
+}
+
+/// A row of the `context_templates` table: a user-defined
+/// `ContextTemplate`, serialized as JSON.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PersistedTemplate {
+    pub name: String,
+    pub template_json: String,
+    pub saved_at: String,
+}
+
+/// A row of the `embeddings` table: one node's vector under one model,
+/// with a hash of the text it embeds so changed text is re-embedded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PersistedEmbedding {
+    pub context_id: String,
+    pub node_id: String,
+    pub model: String,
+    pub vector: Vec<f32>,
+    pub text_hash: String,
+}
+
+/// A row of the `outbound_events` table: an `OutboundEvent` returned
+/// from an ingest, kept for subscribers that weren't listening.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PersistedOutboundEvent {
+    /// Position in the queue; assigned on append
+    pub offset: u64,
+    pub context_id: String,
+    pub kind: String,
+    pub detail: String,
+    pub recorded_at: String,
+}
+
+/// A row of the `journal` table: one change to a context, or a full
+/// snapshot of it, serialized as JSON.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PersistedJournalEntry {
+    /// Position in the journal; assigned on append
+    pub sequence: u64,
+    pub context_id: String,
+    /// "change" or "snapshot"
+    pub kind: String,
+    pub payload: String,
+    pub recorded_at: String,
+}
+
+/// A row of the `ingest_results` table: what an ingest under an
+/// idempotency key returned, so a retry can be answered without
+/// re-ingesting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PersistedIngestResult {
+    pub context_id: String,
+    pub key: String,
+    pub input_kind: String,
+    /// Hash of the ingested JSON payload, so a key reused for different
+    /// data is caught. `None` for inputs with no JSON form.
+    pub payload_hash: Option<String>,
+    /// The outbound events returned, as a JSON array (empty while pending)
+    pub outbound_json: String,
+    /// Whether the ingest finished; `false` for a claim still in flight
+    pub completed: bool,
+    pub recorded_at: String,
+}
+
+/// Node selection for `GraphStore::load_nodes`. Unset fields match anything.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NodeFilter {
+    pub node_type: Option<String>,
+    pub content_type: Option<ContentType>,
+    pub dimension: Option<String>,
+}
+
+impl NodeFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_node_type(mut self, node_type: impl Into<String>) -> Self {
+        self.node_type = Some(node_type.into());
+        self
+    }
+
+    pub fn with_content_type(mut self, content_type: ContentType) -> Self {
+        self.content_type = Some(content_type);
+        self
+    }
+
+    pub fn with_dimension(mut self, dimension: impl Into<String>) -> Self {
+        self.dimension = Some(dimension.into());
+        self
+    }
+
+    pub fn matches(&self, node: &Node) -> bool {
+        self.node_type.as_ref().is_none_or(|t| node.node_type == *t)
+            && self.content_type.as_ref().is_none_or(|c| node.content_type == *c)
+            && self.dimension.as_ref().is_none_or(|d| node.dimension == *d)
+    }
+}
+
+/// Edge selection for `GraphStore::load_edges`. Unset fields match anything.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EdgeFilter {
+    pub source: Option<NodeId>,
+    pub target: Option<NodeId>,
+    pub relationship: Option<String>,
+    pub source_dimension: Option<String>,
+    pub target_dimension: Option<String>,
+    /// Minimum combined weight (inclusive)
+    pub min_weight: Option<f32>,
+}
+
+impl EdgeFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_source(mut self, source: NodeId) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    pub fn with_target(mut self, target: NodeId) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_relationship(mut self, relationship: impl Into<String>) -> Self {
+        self.relationship = Some(relationship.into());
+        self
+    }
+
+    pub fn with_source_dimension(mut self, dimension: impl Into<String>) -> Self {
+        self.source_dimension = Some(dimension.into());
+        self
+    }
+
+    pub fn with_target_dimension(mut self, dimension: impl Into<String>) -> Self {
+        self.target_dimension = Some(dimension.into());
+        self
+    }
+
+    pub fn with_min_weight(mut self, weight: f32) -> Self {
+        self.min_weight = Some(weight);
+        self
+    }
+
+    pub fn matches(&self, edge: &Edge) -> bool {
+        self.source.as_ref().is_none_or(|s| edge.source == *s)
+            && self.target.as_ref().is_none_or(|t| edge.target == *t)
+            && self.relationship.as_ref().is_none_or(|r| edge.relationship == *r)
+            && self.source_dimension.as_ref().is_none_or(|d| edge.source_dimension == *d)
+            && self.target_dimension.as_ref().is_none_or(|d| edge.target_dimension == *d)
+            && self.min_weight.is_none_or(|w| edge.combined_weight >= w)
+    }
+}
+
+/// One context as the warm-start manifest describes it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ManifestEntry {
+    pub id: ContextId,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
+    pub sources: Vec<Source>,
+    pub node_count: usize,
+    pub edge_count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl ManifestEntry {
+    /// The entry describing `context` as it stands.
+    pub fn of(context: &Context) -> Self {
+        Self {
+            id: context.id.clone(),
+            name: context.name.clone(),
+            tenant: context.metadata.tenant.clone(),
+            sources: context.metadata.sources.clone(),
+            node_count: context.node_count(),
+            edge_count: context.edge_count(),
+            updated_at: context.metadata.updated_at.or(context.metadata.created_at),
+        }
+    }
+}
+
+/// What `GraphStore::compact` removed and reclaimed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct CompactionReport {
+    pub bytes_before: u64,
+    pub bytes_after: u64,
+    /// Node rows whose context no longer exists
+    pub orphan_nodes: usize,
+    /// Edge rows whose context no longer exists
+    pub orphan_edges: usize,
+    /// Edge rows whose source or target node no longer exists
+    pub dangling_edges: usize,
+    /// Event and spec rows whose context no longer exists
+    pub orphan_events: usize,
+    pub orphan_specs: usize,
+    /// Trashed nodes and edges past their context's retention, purged by
+    /// `PlexusEngine::maintain`
+    pub trash_purged: usize,
+}
+
+impl CompactionReport {
+    pub fn bytes_reclaimed(&self) -> u64 {
+        self.bytes_before.saturating_sub(self.bytes_after)
+    }
+}
+
 /// Extension trait for opening stores from paths
 pub trait OpenStore: GraphStore + Sized {
     /// Open or create a store at the given path
@@ -0,0 +1,112 @@
+//! Property-based testing utilities (feature `testing`)
+//!
+//! Proptest strategies for nodes, edges, and emissions, drawn from a
+//! small ID pool so generated edges often hit existing nodes and
+//! sometimes dangle. Adapter authors can push generated (or their own
+//! adapter's) emissions through an `EngineSink` and assert
+//! `Context::check_invariants` afterwards:
+//!
+//! ```ignore
+//! proptest! {
+//!     #[test]
+//!     fn my_emissions_keep_invariants(emissions in vec(arb_emission(), 1..5)) {
+//!         // emit each, then: prop_assert!(ctx.check_invariants().is_ok());
+//!     }
+//! }
+//! ```
+
+use crate::adapter::{AnnotatedEdge, AnnotatedNode, Emission};
+use crate::graph::{dimension, ContentType, Edge, Node, NodeId};
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+/// Size of the node ID pool (`node:0` .. `node:{N-1}`).
+pub const NODE_ID_POOL: usize = 12;
+
+/// A node ID from the shared pool.
+pub fn arb_node_id() -> impl Strategy<Value = NodeId> {
+    (0..NODE_ID_POOL).prop_map(|i| NodeId::from_string(format!("node:{i}")))
+}
+
+/// One of the built-in dimensions.
+pub fn arb_dimension() -> impl Strategy<Value = &'static str> {
+    prop::sample::select(vec![
+        dimension::DEFAULT,
+        dimension::STRUCTURE,
+        dimension::SEMANTIC,
+        dimension::RELATIONAL,
+        dimension::TEMPORAL,
+    ])
+}
+
+/// An edge weight: mostly in a signed range, occasionally non-finite.
+pub fn arb_weight() -> impl Strategy<Value = f32> {
+    prop_oneof![
+        8 => -1.0f32..2.0,
+        1 => Just(f32::NAN),
+        1 => Just(f32::INFINITY),
+    ]
+}
+
+/// A node with a pooled ID, any dimension, and a small string property.
+pub fn arb_node() -> impl Strategy<Value = Node> {
+    (arb_node_id(), arb_dimension(), prop::sample::select(vec!["concept", "fragment", "document"]), "[a-z]{0,8}")
+        .prop_map(|(id, dim, node_type, label)| {
+            let mut node = Node::new_in_dimension(node_type, ContentType::Concept, dim);
+            node.id = id;
+            node.properties.insert("label".into(), crate::graph::PropertyValue::String(label));
+            node
+        })
+}
+
+/// An edge between pooled IDs, with dimensions that may not match its
+/// endpoints' and an arbitrary weight.
+pub fn arb_edge() -> impl Strategy<Value = Edge> {
+    (
+        arb_node_id(),
+        arb_node_id(),
+        prop::sample::select(vec!["related_to", "tagged_with", "cites"]),
+        arb_dimension(),
+        arb_weight(),
+    )
+        .prop_map(|(source, target, relationship, dim, weight)| {
+            let mut edge = Edge::new_in_dimension(source, target, relationship, dim);
+            edge.combined_weight = weight;
+            edge
+        })
+}
+
+/// An emission of up to 6 nodes and 10 edges.
+pub fn arb_emission() -> impl Strategy<Value = Emission> {
+    (vec(arb_node(), 0..6), vec(arb_edge(), 0..10)).prop_map(|(nodes, edges)| {
+        let emission = nodes.into_iter().fold(Emission::new(), |e, n| e.with_node(AnnotatedNode::new(n)));
+        edges.into_iter().fold(emission, |e, edge| e.with_edge(AnnotatedEdge::new(edge)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::{AdapterSink, EngineSink, FrameworkContext};
+    use crate::graph::Context;
+    use std::sync::{Arc, Mutex};
+
+    proptest! {
+        // === Scenario: The sink keeps invariants for arbitrary emissions ===
+        #[test]
+        fn sink_upholds_invariants(emissions in vec(arb_emission(), 1..6)) {
+            let ctx = Arc::new(Mutex::new(Context::new("fuzz")));
+            let sink = EngineSink::new(ctx.clone()).with_framework_context(FrameworkContext {
+                adapter_id: "fuzz".to_string(),
+                context_id: "fuzz".to_string(),
+                input_summary: None,
+            });
+            let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+            for emission in emissions {
+                rt.block_on(sink.emit(emission)).unwrap();
+                let ctx = ctx.lock().unwrap();
+                prop_assert_eq!(ctx.check_invariants(), Ok(()));
+            }
+        }
+    }
+}
@@ -489,7 +489,6 @@ emit:
     assert!(lens.is_some(), "adapter with lens: section should return Some");
 
     // Verify the lens enrichment has the correct ID
-    use plexus::adapter::Enrichment;
     assert_eq!(lens.unwrap().id(), "lens:trellis");
 }
 
@@ -506,7 +505,7 @@ emit:
 /// 3. Both are committed to the context
 #[tokio::test]
 async fn lens_in_real_pipeline_enrichment_loop() {
-    use plexus::adapter::{PipelineBuilder, Enrichment, CoOccurrenceEnrichment, FragmentInput};
+    use plexus::adapter::{PipelineBuilder, CoOccurrenceEnrichment, FragmentInput};
     use plexus::adapter::declarative::{LensSpec, TranslationRule};
     use plexus::adapter::lens::LensEnrichment;
     use plexus::storage::{OpenStore, SqliteStore};